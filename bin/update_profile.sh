@@ -1,2 +1,2 @@
 #!/bin/sh
-exec cargo run --bin generate-fit-profile -- "$@"
+exec cargo run --bin generate-fit-profile -- generate "$@"
@@ -0,0 +1,49 @@
+//! Time decoding the test fixtures many times over, like a bulk import of files from the same
+//! devices. Run with `cargo bench --bench decode`.
+use fitparser::{from_bytes, from_bytes_borrowed, DecoderContext};
+use std::time::{Duration, Instant};
+
+const ROUNDS: u32 = 200;
+
+const FILES: [&[u8]; 3] = [
+    include_bytes!("../tests/fixtures/Activity.fit"),
+    include_bytes!("../tests/fixtures/garmin-fenix-5-bike.fit"),
+    include_bytes!("../tests/fixtures/MonitoringFile.fit"),
+];
+
+fn time<F: FnMut(&[u8]) -> usize>(name: &str, mut decode: F) -> Duration {
+    let start = Instant::now();
+    let mut records = 0;
+    for _ in 0..ROUNDS {
+        for data in FILES {
+            records += decode(data);
+        }
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{:<32} {:>8.2?} per round, {} records",
+        name,
+        elapsed / ROUNDS,
+        records / ROUNDS as usize
+    );
+    elapsed
+}
+
+fn main() {
+    time("from_bytes", |data| from_bytes(data).unwrap().len());
+    let mut context = DecoderContext::default();
+    time("DecoderContext::decode", |data| {
+        context.decode(data).unwrap().len()
+    });
+    let uncached = time("from_bytes_borrowed", |data| {
+        from_bytes_borrowed(data).unwrap().len()
+    });
+    let mut context = DecoderContext::default();
+    let cached = time("DecoderContext::decode_borrowed", |data| {
+        context.decode_borrowed(data).unwrap().len()
+    });
+    println!(
+        "cached field lookups: {:.2}x",
+        uncached.as_secs_f64() / cached.as_secs_f64()
+    );
+}
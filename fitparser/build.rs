@@ -0,0 +1,21 @@
+//! Detect whether the committed profile was generated with `--compact`, the library then leaves
+//! out the helpers relying on the names of enum variants
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+const FIELD_TYPES: &str = "src/profile/field_types.rs";
+
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(compact_profile)");
+    println!("cargo::rerun-if-changed={FIELD_TYPES}");
+    let file = File::open(FIELD_TYPES).expect("the generated profile exists");
+    // the generator marks the release in the module doc of a compact profile
+    let compact = BufReader::new(file)
+        .lines()
+        .take(8)
+        .map_while(Result::ok)
+        .any(|line| line.contains("SDK Release") && line.contains("(compact)"));
+    if compact {
+        println!("cargo::rustc-cfg=compact_profile");
+    }
+}
@@ -0,0 +1,99 @@
+//! Example of an `Enricher` that attaches weather data to an activity. A real implementation
+//! would query a weather service in `begin`, this one derives a fake forecast from the activity
+//! start time so it can run without network access.
+//!
+//! The `edit::enrich` module is not available when the profile was generated in compact mode.
+#![cfg_attr(compact_profile, allow(dead_code, unused_imports))]
+#[cfg(not(feature = "time"))]
+use chrono::Timelike;
+#[cfg(not(compact_profile))]
+use fitparser::edit::{enrich, EnrichedField, Enricher};
+use fitparser::profile::field_types::FitBaseType;
+use fitparser::{FitDataRecord, Value};
+use std::error::Error;
+use std::fs::File;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "enrich_weather")]
+struct Cli {
+    /// FIT file to enrich
+    #[structopt(name = "FILE", parse(from_os_str))]
+    file: PathBuf,
+}
+
+/// Temperature in degrees celsius and a short description of the conditions
+#[derive(Default)]
+struct OfflineWeather {
+    temperature: f64,
+    conditions: String,
+}
+
+#[cfg(not(compact_profile))]
+impl Enricher for OfflineWeather {
+    fn fields(&self) -> Vec<EnrichedField> {
+        vec![
+            EnrichedField {
+                number: 0,
+                name: "air_temperature".to_string(),
+                units: "C".to_string(),
+                base_type: FitBaseType::Float32,
+            },
+            EnrichedField {
+                number: 1,
+                name: "conditions".to_string(),
+                units: String::new(),
+                base_type: FitBaseType::String,
+            },
+        ]
+    }
+
+    fn begin(&mut self, records: &[FitDataRecord]) {
+        let hour = records
+            .iter()
+            .find_map(|r| match r.field("timestamp").map(|f| f.value()) {
+                Some(Value::Timestamp(t)) => Some(f64::from(t.hour())),
+                _ => None,
+            })
+            .unwrap_or(12.0);
+        // warmest mid afternoon, coolest before sunrise
+        self.temperature = 12.0 + 8.0 * ((hour - 9.0) / 24.0 * std::f64::consts::TAU).sin();
+        self.conditions = if self.temperature > 15.0 {
+            "sunny".to_string()
+        } else {
+            "overcast".to_string()
+        };
+    }
+
+    fn enrich_record(&mut self, _record: &FitDataRecord) -> Vec<(u8, Value)> {
+        vec![(0, Value::Float64(self.temperature))]
+    }
+
+    fn enrich_activity(&mut self, _session: &FitDataRecord) -> Vec<(u8, Value)> {
+        vec![
+            (0, Value::Float64(self.temperature)),
+            (1, Value::String(self.conditions.clone())),
+        ]
+    }
+}
+
+#[cfg(not(compact_profile))]
+fn main() -> Result<(), Box<dyn Error>> {
+    let opt = Cli::from_args();
+    let mut records = fitparser::from_reader(&mut File::open(&opt.file)?)?;
+    let added = enrich(&mut records, &mut OfflineWeather::default(), 0);
+    println!("Added {} weather values", added);
+    for record in records
+        .iter()
+        .filter(|r| r.kind() == fitparser::profile::MesgNum::Session)
+    {
+        println!("{:#?}", record);
+    }
+    Ok(())
+}
+
+#[cfg(compact_profile)]
+fn main() {
+    eprintln!("enriching requires a profile generated without compact mode");
+}
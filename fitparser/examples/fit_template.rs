@@ -0,0 +1,45 @@
+//! Render the records of a FIT file through a template
+use fitparser::export::template::Template;
+use fitparser::filter::Filter;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Render each record of a FIT file through a template and print the result
+#[derive(Debug, StructOpt)]
+#[structopt(name = "fit_template")]
+struct Cli {
+    /// Template file, see the `export::template` module docs for the syntax
+    #[structopt(parse(from_os_str))]
+    template: PathBuf,
+
+    /// FIT file to render
+    #[structopt(name = "FILE", parse(from_os_str))]
+    file: PathBuf,
+
+    /// Only render records matching the filter expression
+    #[structopt(long)]
+    filter: Option<Filter>,
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let opt = Cli::from_args();
+    let template = Template::parse(&fs::read_to_string(&opt.template)?)?;
+    let mut records = fitparser::from_path(&opt.file)?;
+    if let Some(filter) = &opt.filter {
+        records.retain(|r| filter.matches(r));
+    }
+    print!("{}", template.render_records(&records)?);
+    Ok(())
+}
+
+fn main() {
+    std::process::exit(match run() {
+        Ok(_) => 0,
+        Err(err) => {
+            eprintln!("{}", err);
+            1
+        }
+    });
+}
@@ -1,6 +1,7 @@
-//! Read one or more FIT files and dump their contents as JSON
-use fitparser;
+//! Read one or more FIT files and dump their contents as JSON or text
 use fitparser::de::{from_reader_with_options, DecodeOption};
+use fitparser::filter::Filter;
+use fitparser::format::default_registry;
 use serde::Serialize;
 use std::collections::{BTreeMap, HashSet};
 use std::error::Error;
@@ -45,6 +46,16 @@ struct Cli {
     /// Skip checking the header and data section CRC values
     #[structopt(long)]
     no_crc_check: bool,
+
+    /// Only output records matching the filter expression, e.g.
+    /// 'mesg == "record" && field("power") > 200'
+    #[structopt(long)]
+    filter: Option<Filter>,
+
+    /// Write the records as human readable text with one field per line instead of JSON,
+    /// durations and positions are formatted and the files get a '.txt' extension
+    #[structopt(long)]
+    text: bool,
 }
 
 /// Alternate serialization format
@@ -67,6 +78,20 @@ impl FitDataMap {
     }
 }
 
+/// Write each record as its kind followed by its formatted fields
+fn records_text(data: &[fitparser::FitDataRecord]) -> String {
+    let registry = default_registry();
+    let mut text = String::new();
+    for record in data {
+        text.push_str(&format!("{}\n", record.kind()));
+        for field in record.fields() {
+            let value = registry.format_field(field);
+            text.push_str(&format!("  {}: {}\n", field.name(), value));
+        }
+    }
+    text
+}
+
 #[derive(Clone, Debug)]
 enum OutputLocation {
     Inplace,
@@ -86,29 +111,34 @@ impl OutputLocation {
         }
     }
 
-    fn write_json_file(
+    fn write_file(
         &self,
         filename: &Path,
         data: Vec<fitparser::FitDataRecord>,
+        text: bool,
     ) -> Result<(), Box<dyn Error>> {
-        // convert data to a name: {value, units} map before serializing
-        let data: Vec<FitDataMap> = data.into_iter().map(FitDataMap::new).collect();
-        let json = serde_json::to_string(&data)?;
+        let (content, extension) = if text {
+            (records_text(&data), "txt")
+        } else {
+            // convert data to a name: {value, units} map before serializing
+            let data: Vec<FitDataMap> = data.into_iter().map(FitDataMap::new).collect();
+            (serde_json::to_string(&data)?, "json")
+        };
 
         let outname = match self {
-            Self::Inplace => filename.with_extension("json"),
+            Self::Inplace => filename.with_extension(extension),
             Self::LocalDirectory(dest) => dest
                 .clone()
                 .join(filename.file_name().unwrap())
-                .with_extension("json"),
+                .with_extension(extension),
             Self::LocalFile(dest) => dest.clone(),
             Self::Stdout => {
-                println!("{}", json);
+                println!("{}", content);
                 return Ok(());
             }
         };
         let mut fp = File::create(outname)?;
-        match fp.write_all(json.as_bytes()) {
+        match fp.write_all(content.as_bytes()) {
             Ok(_) => Ok(()),
             Err(e) => Err(Box::new(e)),
         }
@@ -147,8 +177,11 @@ fn run() -> Result<(), Box<dyn Error>> {
     // read from STDIN if no files were given
     if opt.files.is_empty() {
         let mut stdin = io::stdin();
-        let data = from_reader_with_options(&mut stdin, &decode_opts)?;
-        output_loc.write_json_file(&PathBuf::from("<stdin>"), data)?;
+        let mut data = from_reader_with_options(&mut stdin, &decode_opts)?;
+        if let Some(filter) = &opt.filter {
+            data.retain(|r| filter.matches(r));
+        }
+        output_loc.write_file(&PathBuf::from("<stdin>"), data, opt.text)?;
         return Ok(());
     }
 
@@ -158,17 +191,20 @@ fn run() -> Result<(), Box<dyn Error>> {
         // open file and parse data
         let mut fp = File::open(&file)?;
         let mut data = from_reader_with_options(&mut fp, &decode_opts)?;
+        if let Some(filter) = &opt.filter {
+            data.retain(|r| filter.matches(r));
+        }
 
         // output a single fit file's data into a single output file
         if collect_all {
             all_fit_data.append(&mut data);
         } else {
-            output_loc.write_json_file(&file, data)?;
+            output_loc.write_file(&file, data, opt.text)?;
         }
     }
     // output fit data from all files into a single file
     if collect_all {
-        output_loc.write_json_file(&PathBuf::new(), all_fit_data)?;
+        output_loc.write_file(&PathBuf::new(), all_fit_data, opt.text)?;
     }
 
     Ok(())
@@ -72,7 +72,7 @@ fn run() -> Result<(), Box<dyn Error>> {
             let (buf, obj) = match processor.deserialize_next(buffer) {
                 Ok(r) => r,
                 Err(e) => match *e {
-                    fitparser::ErrorKind::UnexpectedEof(_) => {
+                    fitparser::ErrorKind::UnexpectedEof(..) => {
                         // break out of loop to read more data
                         break;
                     }
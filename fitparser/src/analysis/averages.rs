@@ -0,0 +1,134 @@
+//! Averages of record channels weighted by the time each sample covers.
+//!
+//! Devices using smart recording only write a record when a value changes, so samples can be
+//! anything from one to several seconds apart and a plain mean over-weights the parts of an
+//! activity where values change often. Each value is instead held until the next sample of the
+//! channel, the way devices compute the averages of their `lap` and `session` messages.
+use super::record_time;
+use crate::edit::{field_as_f64, is_record_message};
+use crate::FitDataRecord;
+use chrono::{DateTime, Local};
+
+/// Longest time in seconds a sample is held by default, longer gaps are usually pauses of the
+/// timer or sensor dropouts that shouldn't count towards the average
+pub const DEFAULT_MAX_GAP: f64 = 30.0;
+
+/// Running average of a channel weighting each sample by the time until the next one
+#[derive(Clone, Copy, Debug)]
+pub struct WeightedAverage {
+    sum: f64,
+    duration: f64,
+    last: Option<(DateTime<Local>, f64)>,
+    max_gap: f64,
+}
+
+impl WeightedAverage {
+    /// Create an average holding samples for at most `DEFAULT_MAX_GAP` seconds
+    pub fn new() -> Self {
+        Self::with_max_gap(DEFAULT_MAX_GAP)
+    }
+
+    /// Create an average holding samples for at most `max_gap` seconds
+    pub fn with_max_gap(max_gap: f64) -> Self {
+        WeightedAverage {
+            sum: 0.0,
+            duration: 0.0,
+            last: None,
+            max_gap,
+        }
+    }
+
+    /// Add a sample, samples must be added in chronological order and a sample older than the
+    /// previous one doesn't add any weight to it
+    pub fn add(&mut self, time: DateTime<Local>, value: f64) {
+        self.hold_until(time);
+        self.last = Some((time, value));
+    }
+
+    fn hold_until(&mut self, end: DateTime<Local>) {
+        if let Some((time, value)) = self.last {
+            let seconds = ((end - time).num_milliseconds() as f64 / 1e3).clamp(0.0, self.max_gap);
+            self.sum += value * seconds;
+            self.duration += seconds;
+        }
+    }
+
+    /// Average of the samples so far, the last sample has no weight yet unless it's the only one
+    pub fn average(&self) -> Option<f64> {
+        match self.last {
+            _ if self.duration > 0.0 => Some(self.sum / self.duration),
+            Some((_, value)) => Some(value),
+            None => None,
+        }
+    }
+
+    /// Average of the samples with the last sample held until `end`, e.g. the end of a lap
+    pub fn average_until(&self, end: DateTime<Local>) -> Option<f64> {
+        let mut average = *self;
+        average.hold_until(end);
+        average.average()
+    }
+}
+
+impl Default for WeightedAverage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Time weighted average of a field of the `record` messages, e.g. `heart_rate`, `power` or
+/// `temperature`. Records without a timestamp or the field are skipped.
+pub fn weighted_average(records: &[FitDataRecord], field: &str) -> Option<f64> {
+    let mut average = WeightedAverage::new();
+    for record in records.iter().filter(|r| is_record_message(r)) {
+        if let (Some(time), Some(value)) = (record_time(record), field_as_f64(record, field)) {
+            average.add(time, value);
+        }
+    }
+    average.average()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::profile::MesgNum;
+    use crate::timestamp::from_chrono;
+    use crate::Value;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn weight_samples_by_time() {
+        let start = Local.timestamp_opt(1_600_000_000, 0).unwrap();
+        let at = |seconds| start + Duration::seconds(seconds);
+        let mut average = WeightedAverage::new();
+        assert_eq!(average.average(), None);
+        average.add(at(0), 100.0);
+        assert_eq!(average.average(), Some(100.0));
+        // 100 W held for 9 seconds then 200 W sampled every second
+        average.add(at(9), 200.0);
+        average.add(at(10), 200.0);
+        assert_eq!(average.average(), Some(110.0));
+        assert_eq!(average.average_until(at(18)), Some(150.0));
+        // a long pause only counts up to the maximum gap
+        let mut average = WeightedAverage::with_max_gap(10.0);
+        average.add(at(0), 100.0);
+        average.add(at(100), 200.0);
+        assert_eq!(average.average_until(at(110)), Some(150.0));
+
+        let records: Vec<_> = [(0, 120), (1, 130), (5, 150)]
+            .iter()
+            .map(|&(seconds, heart_rate)| {
+                fixtures::record(
+                    MesgNum::Record,
+                    &[
+                        ("timestamp", Value::Timestamp(from_chrono(at(seconds)))),
+                        ("heart_rate", Value::UInt8(heart_rate)),
+                    ],
+                )
+            })
+            .collect();
+        assert_eq!(weighted_average(&records, "heart_rate"), Some(128.0));
+        assert_eq!(weighted_average(&records, "power"), None);
+    }
+}
@@ -0,0 +1,188 @@
+//! Detect sustained climbs in an activity or course and categorize them.
+use super::{distance_altitude_profile, record_time};
+use crate::FitDataRecord;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+/// Climb categories as used by the major cycling tours, from easiest to hardest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClimbCategory {
+    /// Meets the detection settings but is too easy to be categorized
+    Uncategorized,
+    /// Category 4
+    Fourth,
+    /// Category 3
+    Third,
+    /// Category 2
+    Second,
+    /// Category 1
+    First,
+    /// Hors catégorie, harder than any other category
+    HorsCategorie,
+}
+
+impl ClimbCategory {
+    /// Categorize a climb using the product of its length in meters and average grade in percent
+    pub fn from_score(score: f64) -> Self {
+        if score >= 80_000.0 {
+            ClimbCategory::HorsCategorie
+        } else if score >= 64_000.0 {
+            ClimbCategory::First
+        } else if score >= 32_000.0 {
+            ClimbCategory::Second
+        } else if score >= 16_000.0 {
+            ClimbCategory::Third
+        } else if score >= 8_000.0 {
+            ClimbCategory::Fourth
+        } else {
+            ClimbCategory::Uncategorized
+        }
+    }
+}
+
+/// Settings that determine what counts as a climb
+#[derive(Clone, Debug)]
+pub struct ClimbDetection {
+    /// Minimum climb length in meters
+    pub min_length: f64,
+    /// Minimum average grade in percent
+    pub min_grade: f64,
+    /// Descent in meters allowed below the highest point so far before the climb is considered
+    /// finished, this lets short dips be part of a longer climb
+    pub max_descent: f64,
+}
+
+impl Default for ClimbDetection {
+    fn default() -> Self {
+        ClimbDetection {
+            min_length: 500.0,
+            min_grade: 3.0,
+            max_descent: 10.0,
+        }
+    }
+}
+
+/// A climb found in the record stream
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Climb {
+    /// Index of the record at the bottom of the climb
+    pub start_index: usize,
+    /// Index of the record at the top of the climb
+    pub end_index: usize,
+    /// Distance along the track where the climb starts in meters
+    pub start_distance: f64,
+    /// Distance along the track where the climb ends in meters
+    pub end_distance: f64,
+    /// Time the bottom of the climb was reached if the records are timestamped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<Local>>,
+    /// Time the top of the climb was reached if the records are timestamped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<DateTime<Local>>,
+    /// Altitude difference between the top and bottom in meters
+    pub elevation_gain: f64,
+    /// Average grade in percent
+    pub average_grade: f64,
+    /// Difficulty category of the climb
+    pub category: ClimbCategory,
+}
+
+impl Climb {
+    /// Length of the climb in meters
+    pub fn length(&self) -> f64 {
+        self.end_distance - self.start_distance
+    }
+}
+
+/// Find every climb in the `record` messages that satisfies the detection settings. Both
+/// activities and courses can be analyzed as long as the records contain an altitude.
+pub fn climbs(records: &[FitDataRecord], opts: &ClimbDetection) -> Vec<Climb> {
+    let profile = distance_altitude_profile(records);
+    let mut climbs = Vec::new();
+    if profile.is_empty() {
+        return climbs;
+    }
+
+    let mut check_climb = |valley: usize, peak: usize| {
+        let (start_index, start_distance, start_alt) = profile[valley];
+        let (end_index, end_distance, end_alt) = profile[peak];
+        let length = end_distance - start_distance;
+        if length <= 0.0 || length < opts.min_length {
+            return;
+        }
+        let average_grade = (end_alt - start_alt) / length * 100.0;
+        if average_grade < opts.min_grade {
+            return;
+        }
+        climbs.push(Climb {
+            start_index,
+            end_index,
+            start_distance,
+            end_distance,
+            start_time: record_time(&records[start_index]),
+            end_time: record_time(&records[end_index]),
+            elevation_gain: end_alt - start_alt,
+            average_grade,
+            category: ClimbCategory::from_score(length * average_grade),
+        });
+    };
+
+    let mut valley = 0;
+    let mut peak = 0;
+    for (idx, (_, _, alt)) in profile.iter().enumerate().skip(1) {
+        if profile[peak].2 - alt > opts.max_descent {
+            check_climb(valley, peak);
+            valley = idx;
+            peak = idx;
+        } else if *alt <= profile[valley].2 {
+            valley = idx;
+            peak = idx;
+        } else if *alt >= profile[peak].2 {
+            peak = idx;
+        }
+    }
+    check_climb(valley, peak);
+
+    climbs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::profile::MesgNum;
+    use crate::Value;
+
+    #[test]
+    fn detect_single_climb() {
+        // 1km flat, 3km at 6% with a small dip in the middle, then a 1km descent
+        let profile = (0..10)
+            .map(|i| (i as f64 * 100.0, 100.0))
+            .chain((0..=30).map(|i| {
+                let dip = if i == 15 { -9.0 } else { 0.0 };
+                (1000.0 + i as f64 * 100.0, 100.0 + i as f64 * 6.0 + dip)
+            }))
+            .chain((1..=10).map(|i| (4000.0 + i as f64 * 100.0, 280.0 - i as f64 * 8.0)));
+        let records: Vec<FitDataRecord> = profile
+            .map(|(distance, altitude)| {
+                fixtures::record(
+                    MesgNum::Record,
+                    &[
+                        ("distance", Value::Float64(distance)),
+                        ("enhanced_altitude", Value::Float64(altitude)),
+                    ],
+                )
+            })
+            .collect();
+
+        let found = climbs(&records, &ClimbDetection::default());
+        assert_eq!(found.len(), 1);
+        let climb = &found[0];
+        assert_eq!(climb.start_distance, 1000.0);
+        assert_eq!(climb.average_grade, 6.0);
+        assert_eq!(climb.end_distance, 4000.0);
+        assert_eq!(climb.elevation_gain, 180.0);
+        assert_eq!(climb.category, ClimbCategory::Third);
+    }
+}
@@ -0,0 +1,373 @@
+//! Compare an executed activity with the workout it followed, scoring how much of each step was
+//! spent inside the target range of the step.
+//!
+//! Steps are matched to the laps of the activity by the `wkt_step_index` recorded in each lap.
+//! Without it the laps are matched in order to the steps of the workout with repeat steps
+//! expanded, the way a device walks through the workout. Only custom targets can be scored since
+//! zone targets depend on the zones configured on the device, steps with a zone or open target are
+//! still reported but without a compliance.
+use super::{record_time, Activity, Segment, DEFAULT_MAX_GAP};
+use crate::edit::field_as_f64;
+use crate::error::Result;
+use crate::profile::field_types::{Intensity, WktStepDuration, WktStepTarget};
+use crate::profile::messages::WorkoutStepMessage;
+use crate::profile::MesgNum;
+use crate::FitDataRecord;
+use chrono::{DateTime, Local};
+use serde::Serialize;
+use std::convert::TryFrom;
+
+/// Thresholds of the athlete used to resolve targets given as a percentage
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Thresholds {
+    /// Maximum heart rate in bpm
+    pub max_heart_rate: Option<f64>,
+    /// Functional threshold power in watts
+    pub ftp: Option<f64>,
+}
+
+/// Record channel a workout step targets
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetChannel {
+    /// Speed in m/s
+    Speed,
+    /// Heart rate in bpm
+    HeartRate,
+    /// Cadence in rpm
+    Cadence,
+    /// Power in watts
+    Power,
+}
+
+impl TargetChannel {
+    /// Value of the channel in a `record` message
+    fn value(self, record: &FitDataRecord) -> Option<f64> {
+        match self {
+            TargetChannel::Speed => {
+                field_as_f64(record, "enhanced_speed").or_else(|| field_as_f64(record, "speed"))
+            }
+            TargetChannel::HeartRate => field_as_f64(record, "heart_rate"),
+            TargetChannel::Cadence => field_as_f64(record, "cadence"),
+            TargetChannel::Power => field_as_f64(record, "power"),
+        }
+    }
+}
+
+/// Inclusive range of a channel the athlete should stay in during a step
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct StepTarget {
+    /// Targeted channel
+    pub channel: TargetChannel,
+    /// Lower end of the range
+    pub low: f64,
+    /// Upper end of the range
+    pub high: f64,
+}
+
+/// Resolve the custom target of a workout step into an absolute range. Heart rates below 100 and
+/// powers below 1000 are percentages of the maximum heart rate and FTP, larger values are offset
+/// by 100 bpm and 1000 watts. Returns `None` for open and zone targets and for percentages the
+/// thresholds don't cover.
+pub fn step_target(step: &WorkoutStepMessage, thresholds: &Thresholds) -> Option<StepTarget> {
+    let (channel, low, high) = match step.target_type? {
+        WktStepTarget::Speed | WktStepTarget::SpeedLap => (
+            TargetChannel::Speed,
+            step.custom_target_speed_low?,
+            step.custom_target_speed_high?,
+        ),
+        WktStepTarget::HeartRate | WktStepTarget::HeartRateLap => {
+            let resolve = |value: u32| match value {
+                v if v >= 100 => Some(f64::from(v - 100)),
+                v => thresholds
+                    .max_heart_rate
+                    .map(|max| max * f64::from(v) / 100.0),
+            };
+            (
+                TargetChannel::HeartRate,
+                resolve(step.custom_target_heart_rate_low?.as_u32())?,
+                resolve(step.custom_target_heart_rate_high?.as_u32())?,
+            )
+        }
+        WktStepTarget::Cadence => (
+            TargetChannel::Cadence,
+            f64::from(step.custom_target_cadence_low?),
+            f64::from(step.custom_target_cadence_high?),
+        ),
+        WktStepTarget::Power
+        | WktStepTarget::Power3s
+        | WktStepTarget::Power10s
+        | WktStepTarget::Power30s
+        | WktStepTarget::PowerLap => {
+            let resolve = |value: u32| match value {
+                v if v >= 1000 => Some(f64::from(v - 1000)),
+                v => thresholds.ftp.map(|ftp| ftp * f64::from(v) / 100.0),
+            };
+            (
+                TargetChannel::Power,
+                resolve(step.custom_target_power_low?.as_u32())?,
+                resolve(step.custom_target_power_high?.as_u32())?,
+            )
+        }
+        _ => return None,
+    };
+    (low > 0.0 || high > 0.0).then_some(StepTarget { channel, low, high })
+}
+
+/// How closely a lap followed the workout step it was recorded for
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct StepCompliance {
+    /// Index of the workout step
+    pub step_index: u16,
+    /// Name of the workout step
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Intensity of the workout step
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intensity: Option<Intensity>,
+    /// Target of the step, `None` if it can't be scored
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<StepTarget>,
+    /// Number of the lap the step was executed in starting at 1
+    pub lap: usize,
+    /// Seconds covered by samples of the targeted channel
+    pub duration: f64,
+    /// Seconds spent inside the target range
+    pub time_in_target: f64,
+    /// Seconds spent below the target range
+    pub time_below: f64,
+    /// Seconds spent above the target range
+    pub time_above: f64,
+}
+
+impl StepCompliance {
+    /// Fraction of the step spent inside the target range, `None` without a target or samples
+    pub fn compliance(&self) -> Option<f64> {
+        self.target?;
+        (self.duration > 0.0).then(|| self.time_in_target / self.duration)
+    }
+}
+
+/// Compliance of every executed step of a workout
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct WorkoutCompliance {
+    /// One entry per lap matched to a workout step, in the order of the laps
+    pub steps: Vec<StepCompliance>,
+}
+
+impl WorkoutCompliance {
+    /// Overall fraction of time spent inside the targets over all scored steps, longer steps
+    /// weigh more. `None` if no step could be scored.
+    pub fn score(&self) -> Option<f64> {
+        let (in_target, duration) = self
+            .steps
+            .iter()
+            .filter(|s| s.target.is_some())
+            .fold((0.0, 0.0), |(t, d), s| {
+                (t + s.time_in_target, d + s.duration)
+            });
+        (duration > 0.0).then(|| in_target / duration)
+    }
+}
+
+/// Order in which a device executes the steps of a workout, as indices into `steps`. Steps
+/// repeating a fixed number of times are expanded, other repeat steps can only be resolved from
+/// the recorded laps and are passed through once.
+fn executed_steps(steps: &[WorkoutStepMessage]) -> Vec<usize> {
+    let mut order = Vec::new();
+    let mut repeats = vec![0; steps.len()];
+    let mut index = 0;
+    while index < steps.len() {
+        let step = &steps[index];
+        if step.duration_type == Some(WktStepDuration::RepeatUntilStepsCmplt) {
+            let from = step.duration_step.map_or(index, |s| s as usize);
+            let count = step.repeat_steps.unwrap_or(1) as usize;
+            repeats[index] += 1;
+            if from < index && repeats[index] < count {
+                index = from;
+            } else {
+                repeats[index] = 0;
+                index += 1;
+            }
+            continue;
+        }
+        if !is_repeat(step) {
+            order.push(index);
+        }
+        index += 1;
+    }
+    order
+}
+
+/// Check if the step repeats earlier steps rather than being executed itself
+fn is_repeat(step: &WorkoutStepMessage) -> bool {
+    step.duration_type
+        .is_some_and(|d| d.to_string().starts_with("repeat_until"))
+}
+
+/// Hold each sample of the channel until the next one or the end of the lap and sort the time
+/// into the parts of the target range
+fn score_lap(lap: &Segment, target: Option<StepTarget>, compliance: &mut StepCompliance) {
+    let target = match target {
+        Some(target) => target,
+        None => return,
+    };
+    let samples: Vec<(DateTime<Local>, f64)> = lap
+        .records()
+        .filter_map(|r| Some((record_time(r)?, target.channel.value(r)?)))
+        .collect();
+    let ends = samples.iter().skip(1).map(|(time, _)| *time);
+    for (&(time, value), until) in samples.iter().zip(ends.chain(Some(lap.end_time()))) {
+        let seconds = ((until - time).num_milliseconds() as f64 / 1e3).clamp(0.0, DEFAULT_MAX_GAP);
+        compliance.duration += seconds;
+        if value < target.low {
+            compliance.time_below += seconds;
+        } else if value > target.high {
+            compliance.time_above += seconds;
+        } else {
+            compliance.time_in_target += seconds;
+        }
+    }
+}
+
+/// Score an activity against the workout it followed, `workout` holds the decoded messages of
+/// the workout file. Laps that can't be matched to a step are skipped.
+pub fn workout_compliance(
+    workout: &[FitDataRecord],
+    activity: &Activity,
+    thresholds: &Thresholds,
+) -> Result<WorkoutCompliance> {
+    let steps = workout
+        .iter()
+        .filter(|r| r.is_kind(MesgNum::WorkoutStep))
+        .map(WorkoutStepMessage::try_from)
+        .collect::<Result<Vec<_>>>()?;
+    let step_index = |position: usize| {
+        steps[position]
+            .message_index
+            .map_or(position as u16, |i| i.as_u16())
+    };
+    let mut order = executed_steps(&steps).into_iter();
+    let mut compliance = WorkoutCompliance::default();
+    for (number, lap) in activity.laps().enumerate() {
+        let position = match field_as_f64(lap.summary(), "wkt_step_index") {
+            Some(index) => (0..steps.len()).find(|&p| f64::from(step_index(p)) == index),
+            None => order.next(),
+        };
+        let step = match position {
+            Some(position) if !is_repeat(&steps[position]) => position,
+            _ => continue,
+        };
+        let target = step_target(&steps[step], thresholds);
+        let mut result = StepCompliance {
+            step_index: step_index(step),
+            name: steps[step].wkt_step_name.clone(),
+            intensity: steps[step].intensity,
+            target,
+            lap: number + 1,
+            duration: 0.0,
+            time_in_target: 0.0,
+            time_below: 0.0,
+            time_above: 0.0,
+        };
+        score_lap(&lap, target, &mut result);
+        compliance.steps.push(result);
+    }
+    Ok(compliance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, RecordBuilder};
+    use crate::timestamp::from_chrono;
+    use crate::{from_bytes, Value};
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn score_workout_steps() {
+        let workout = from_bytes(include_bytes!(
+            "../../tests/fixtures/WorkoutRepeatSteps.fit"
+        ))
+        .unwrap();
+        let steps: Vec<WorkoutStepMessage> = workout
+            .iter()
+            .filter(|r| r.is_kind(MesgNum::WorkoutStep))
+            .map(|r| WorkoutStepMessage::try_from(r).unwrap())
+            .collect();
+        assert_eq!(executed_steps(&steps), vec![0, 1, 2, 1, 2, 1, 2, 4]);
+
+        let workout = from_bytes(include_bytes!(
+            "../../tests/fixtures/WorkoutCustomTargetValues.fit"
+        ))
+        .unwrap();
+        let steps: Vec<WorkoutStepMessage> = workout
+            .iter()
+            .filter(|r| r.is_kind(MesgNum::WorkoutStep))
+            .map(|r| WorkoutStepMessage::try_from(r).unwrap())
+            .collect();
+        // the first step targets 50-60 % of the maximum heart rate
+        assert_eq!(step_target(&steps[0], &Thresholds::default()), None);
+        let thresholds = Thresholds {
+            max_heart_rate: Some(200.0),
+            ftp: None,
+        };
+        let target = step_target(&steps[0], &thresholds).unwrap();
+        assert_eq!(
+            (target.channel, target.low, target.high),
+            (TargetChannel::HeartRate, 100.0, 120.0)
+        );
+        let target = step_target(&steps[1], &thresholds).unwrap();
+        assert_eq!(
+            (target.channel, target.low, target.high),
+            (TargetChannel::Power, 300.0, 310.0)
+        );
+
+        // one minute at 110 bpm followed by a minute with power drifting from 280 to 339 watts
+        let start = Local.timestamp_opt(1_600_000_000, 0).unwrap();
+        let at = |second| Value::Timestamp(from_chrono(start + Duration::seconds(second)));
+        let mut records: Vec<FitDataRecord> = (0..120)
+            .map(|second| {
+                let mut record = RecordBuilder::new(MesgNum::Record)
+                    .field("timestamp", at(second))
+                    .field("heart_rate", Value::UInt8(110));
+                if second >= 60 {
+                    record = record.field("power", Value::UInt16(220 + second as u16));
+                }
+                record.build()
+            })
+            .collect();
+        for (lap_start, lap_end) in [(0, 60), (60, 120)] {
+            records.push(fixtures::record(
+                MesgNum::Lap,
+                &[("start_time", at(lap_start)), ("timestamp", at(lap_end))],
+            ));
+        }
+        let result = workout_compliance(&workout, &Activity::new(&records), &thresholds).unwrap();
+        assert_eq!(result.steps.len(), 2);
+        assert_eq!(result.steps[0].name.as_deref(), Some("_A_"));
+        assert_eq!(result.steps[0].compliance(), Some(1.0));
+        let step = &result.steps[1];
+        assert_eq!(step.step_index, 1);
+        assert_eq!(step.duration, 60.0);
+        assert_eq!(
+            (step.time_below, step.time_in_target, step.time_above),
+            (20.0, 11.0, 29.0)
+        );
+        assert_eq!(result.score(), Some(71.0 / 120.0));
+
+        // laps naming their step are matched by index
+        records.pop();
+        records.push(fixtures::record(
+            MesgNum::Lap,
+            &[
+                ("start_time", at(60)),
+                ("timestamp", at(120)),
+                ("wkt_step_index", Value::UInt16(2)),
+            ],
+        ));
+        let result = workout_compliance(&workout, &Activity::new(&records), &thresholds).unwrap();
+        assert_eq!(result.steps[1].step_index, 2);
+        assert_eq!(result.steps[1].time_in_target, 0.0);
+    }
+}
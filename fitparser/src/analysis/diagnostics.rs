@@ -0,0 +1,212 @@
+//! Data quality diagnostics for the power channel. Power meters drift unless they are zero offset
+//! calibrated before a ride, and misbehaving meters produce isolated spikes that wreck averages
+//! and power curves. Coaching tools use these diagnostics to decide which data to trust.
+use super::record_time;
+use crate::edit::{field_as_f64, is_record_message};
+use crate::profile::field_types::Event;
+use crate::profile::MesgNum;
+use crate::{FitDataRecord, Value};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+/// Kind of issue found in the data
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticKind {
+    /// A calibration event, the start and end index point at the event message
+    Calibration,
+    /// Power recorded before the first calibration event of the activity
+    PowerBeforeCalibration,
+    /// Power recorded in an activity without any calibration event
+    PowerUncalibrated,
+    /// A power sample far above the surrounding samples or the plausible maximum
+    PowerSpike,
+}
+
+/// A single data quality finding covering a range of messages
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    /// What was found
+    pub kind: DiagnosticKind,
+    /// Index of the first message covered by the finding
+    pub start_index: usize,
+    /// Index of the last message covered by the finding
+    pub end_index: usize,
+    /// Timestamp of the first message if it has one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<Local>>,
+    /// Timestamp of the last message if it has one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<DateTime<Local>>,
+    /// Value associated with the finding, the event data for calibrations and the power in
+    /// watts for spikes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+}
+
+impl Diagnostic {
+    fn new(
+        kind: DiagnosticKind,
+        records: &[FitDataRecord],
+        start_index: usize,
+        end_index: usize,
+        value: Option<f64>,
+    ) -> Self {
+        Diagnostic {
+            kind,
+            start_index,
+            end_index,
+            start_time: record_time(&records[start_index]),
+            end_time: record_time(&records[end_index]),
+            value,
+        }
+    }
+}
+
+/// Settings for the power diagnostics
+#[derive(Clone, Debug)]
+pub struct PowerDiagnostics {
+    /// Samples above this many watts are always reported as spikes
+    pub max_power: f64,
+    /// Samples more than this many times the median of their neighbours are reported as spikes
+    pub spike_ratio: f64,
+    /// Number of samples on each side of a sample used for the neighbourhood median
+    pub window: usize,
+    /// Spikes below this many watts are ignored by the ratio check, short sprints from
+    /// coasting easily reach a high ratio
+    pub min_spike_power: f64,
+}
+
+impl Default for PowerDiagnostics {
+    fn default() -> Self {
+        PowerDiagnostics {
+            max_power: 2500.0,
+            spike_ratio: 3.0,
+            window: 5,
+            min_spike_power: 800.0,
+        }
+    }
+}
+
+/// Check if the record is a calibration event
+fn is_calibration(record: &FitDataRecord) -> bool {
+    record.is_kind(MesgNum::Event)
+        && matches!(
+            record.field("event").map(|f| f.value()),
+            Some(Value::String(event)) if *event == Event::Calibration.to_string()
+        )
+}
+
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(|a, b| a.total_cmp(b));
+    values[values.len() / 2]
+}
+
+/// Find calibration events, power recorded before calibration and power spikes. Findings are
+/// returned in the order of the messages they start at.
+pub fn power_diagnostics(records: &[FitDataRecord], opts: &PowerDiagnostics) -> Vec<Diagnostic> {
+    let power: Vec<(usize, f64)> = records
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| is_record_message(r))
+        .filter_map(|(idx, r)| field_as_f64(r, "power").map(|p| (idx, p)))
+        .collect();
+    let mut diagnostics: Vec<Diagnostic> = records
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| is_calibration(r))
+        .map(|(idx, r)| {
+            Diagnostic::new(
+                DiagnosticKind::Calibration,
+                records,
+                idx,
+                idx,
+                field_as_f64(r, "data"),
+            )
+        })
+        .collect();
+    if power.is_empty() {
+        return diagnostics;
+    }
+
+    let first_calibration = diagnostics.first().map(|d| d.start_index);
+    let uncalibrated: Vec<usize> = power
+        .iter()
+        .map(|(idx, _)| *idx)
+        .filter(|idx| first_calibration.is_none_or(|c| *idx < c))
+        .collect();
+    if let (Some(start), Some(end)) = (uncalibrated.first(), uncalibrated.last()) {
+        let kind = match first_calibration {
+            Some(_) => DiagnosticKind::PowerBeforeCalibration,
+            None => DiagnosticKind::PowerUncalibrated,
+        };
+        diagnostics.push(Diagnostic::new(kind, records, *start, *end, None));
+    }
+
+    for (pos, (idx, watts)) in power.iter().enumerate() {
+        let lo = pos.saturating_sub(opts.window);
+        let hi = (pos + opts.window + 1).min(power.len());
+        let mut neighbours: Vec<f64> = power[lo..hi]
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| lo + i != pos)
+            .map(|(_, (_, p))| *p)
+            .collect();
+        let is_spike = *watts > opts.max_power
+            || (*watts >= opts.min_spike_power
+                && !neighbours.is_empty()
+                && *watts > opts.spike_ratio * median(&mut neighbours).max(1.0));
+        if is_spike {
+            diagnostics.push(Diagnostic::new(
+                DiagnosticKind::PowerSpike,
+                records,
+                *idx,
+                *idx,
+                Some(*watts),
+            ));
+        }
+    }
+    diagnostics.sort_by_key(|d| d.start_index);
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn find_power_issues() {
+        let calibration = fixtures::record(
+            MesgNum::Event,
+            &[
+                ("event", Value::String("calibration".to_string())),
+                ("data", Value::UInt32(512)),
+            ],
+        );
+        let mut records: Vec<FitDataRecord> = [150, 160, 200, 210, 1500, 205, 3000, 200]
+            .iter()
+            .map(|w| fixtures::record(MesgNum::Record, &[("power", Value::UInt16(*w))]))
+            .collect();
+        records.insert(2, calibration);
+
+        let found = power_diagnostics(&records, &PowerDiagnostics::default());
+        let kinds: Vec<(DiagnosticKind, usize, usize)> = found
+            .iter()
+            .map(|d| (d.kind, d.start_index, d.end_index))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                (DiagnosticKind::PowerBeforeCalibration, 0, 1),
+                (DiagnosticKind::Calibration, 2, 2),
+                (DiagnosticKind::PowerSpike, 5, 5),
+                (DiagnosticKind::PowerSpike, 7, 7),
+            ]
+        );
+        assert_eq!(found[1].value, Some(512.0));
+
+        let found = power_diagnostics(&records[3..], &PowerDiagnostics::default());
+        assert_eq!(found[0].kind, DiagnosticKind::PowerUncalibrated);
+    }
+}
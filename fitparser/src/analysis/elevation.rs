@@ -0,0 +1,100 @@
+//! Elevation profiles for charts, the altitude of an activity or course resampled along a
+//! distance axis so the samples are evenly spaced no matter how often the device recorded.
+//!
+//! Courses store their track as `record` messages just like activities, so both file types are
+//! handled the same way. `course_point` messages only carry a distance, `altitude_at` places them
+//! on the profile.
+use super::distance_altitude_profile;
+use crate::FitDataRecord;
+
+/// Resample the altitude of the `record` messages every `interval` meters, returning
+/// (distance, altitude) pairs in meters from the first record with an altitude to the last.
+/// Altitudes between records are interpolated linearly and the final record is always included
+/// so the profile covers the full distance. Records going back in distance are skipped and a
+/// non-positive interval returns an empty profile.
+pub fn elevation_profile(records: &[FitDataRecord], interval: f64) -> Vec<(f64, f64)> {
+    if interval <= 0.0 {
+        return Vec::new();
+    }
+    let mut samples: Vec<(f64, f64)> = Vec::new();
+    for (_, distance, altitude) in distance_altitude_profile(records) {
+        match samples.last() {
+            Some(&(last, _)) if distance < last => continue,
+            _ => samples.push((distance, altitude)),
+        }
+    }
+    let (first, last) = match (samples.first(), samples.last()) {
+        (Some(&(first, _)), Some(&(last, _))) => (first, last),
+        _ => return Vec::new(),
+    };
+    let mut profile = Vec::new();
+    let mut step = 0;
+    loop {
+        let distance = first + step as f64 * interval;
+        if distance >= last {
+            break;
+        }
+        profile.extend(altitude_at(&samples, distance).map(|altitude| (distance, altitude)));
+        step += 1;
+    }
+    profile.extend(samples.last().copied());
+    profile
+}
+
+/// Altitude at a distance along a profile of (distance, altitude) pairs sorted by distance,
+/// interpolated between the surrounding pairs. `None` outside the range of the profile.
+pub fn altitude_at(profile: &[(f64, f64)], distance: f64) -> Option<f64> {
+    let after = profile.partition_point(|&(d, _)| d < distance);
+    match (after.checked_sub(1).map(|i| profile[i]), profile.get(after)) {
+        (_, Some(&(d, altitude))) if d == distance => Some(altitude),
+        (Some((d0, a0)), Some(&(d1, a1))) => Some(a0 + (a1 - a0) * (distance - d0) / (d1 - d0)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::edit::field_as_f64;
+    use crate::fixtures::RecordBuilder;
+    use crate::profile::MesgNum;
+    use crate::Value;
+
+    fn message(kind: MesgNum, distance: f64, altitude: Option<f64>) -> FitDataRecord {
+        let mut record = RecordBuilder::new(kind).field("distance", Value::Float64(distance));
+        if let Some(altitude) = altitude {
+            record = record.field("enhanced_altitude", Value::Float64(altitude));
+        }
+        record.build()
+    }
+
+    #[test]
+    fn resample_profile() {
+        // records every 30 m climbing 3 m each, with a course point halfway
+        let mut records: Vec<FitDataRecord> = (0..=10)
+            .map(|i| message(MesgNum::Record, i as f64 * 30.0, Some(i as f64 * 3.0)))
+            .collect();
+        records.insert(5, message(MesgNum::CoursePoint, 135.0, None));
+
+        let profile = elevation_profile(&records, 50.0);
+        assert_eq!(
+            profile,
+            vec![
+                (0.0, 0.0),
+                (50.0, 5.0),
+                (100.0, 10.0),
+                (150.0, 15.0),
+                (200.0, 20.0),
+                (250.0, 25.0),
+                (300.0, 30.0)
+            ]
+        );
+        assert!(elevation_profile(&records, 0.0).is_empty());
+        assert!(elevation_profile(&records[..0], 50.0).is_empty());
+
+        let point = field_as_f64(&records[5], "distance").unwrap();
+        assert_eq!(altitude_at(&profile, point), Some(13.5));
+        assert_eq!(altitude_at(&profile, 300.0), Some(30.0));
+        assert_eq!(altitude_at(&profile, 301.0), None);
+    }
+}
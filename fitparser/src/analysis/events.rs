@@ -0,0 +1,192 @@
+//! Typed view of the `event` messages of an activity. The raw messages encode everything in an
+//! `event`, `event_type` and `data` triple whose meaning depends on the event, the decoder
+//! already resolves the `data` subfields so this module only needs to pick them up.
+use super::record_time;
+use crate::edit::field_as_f64;
+use crate::profile::field_types::{Event, EventType};
+use crate::profile::MesgNum;
+use crate::{FitDataRecord, Value};
+use chrono::{DateTime, Local};
+use serde::Serialize;
+
+/// What happened, along with the payload decoded from the event's `data` field
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventKind {
+    /// The activity timer was started, the trigger is e.g. `manual` or `auto`
+    TimerStart {
+        /// What started the timer
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trigger: Option<String>,
+    },
+    /// The activity timer was stopped or paused
+    TimerStop {
+        /// What stopped the timer
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trigger: Option<String>,
+        /// Set for `stop_all` and `stop_disable_all` which end every timer
+        all: bool,
+    },
+    /// A lap ended
+    Lap,
+    /// A session ended
+    Session,
+    /// Battery status was reported
+    Battery {
+        /// Battery voltage in volts
+        #[serde(skip_serializing_if = "Option::is_none")]
+        voltage: Option<f64>,
+    },
+    /// The device warned about a low battery
+    BatteryLow {
+        /// Battery voltage in volts
+        #[serde(skip_serializing_if = "Option::is_none")]
+        voltage: Option<f64>,
+    },
+    /// The user left the course being navigated
+    OffCourse,
+    /// Heart rate recorded at the end of the recovery period
+    RecoveryHr {
+        /// Heart rate in beats per minute
+        #[serde(skip_serializing_if = "Option::is_none")]
+        heart_rate: Option<f64>,
+    },
+    /// Any other event, holding the raw values
+    Other {
+        /// Event name, or the number for events unknown to the profile
+        event: String,
+        /// Event type name
+        #[serde(skip_serializing_if = "Option::is_none")]
+        event_type: Option<String>,
+        /// Raw data value
+        #[serde(skip_serializing_if = "Option::is_none")]
+        data: Option<Value>,
+    },
+}
+
+/// A single event of the activity
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ActivityEvent {
+    /// When the event occurred
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<DateTime<Local>>,
+    /// Group the event belongs to, used to relate start and stop events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_group: Option<u8>,
+    /// What happened
+    pub kind: EventKind,
+}
+
+fn string_field(record: &FitDataRecord, name: &str) -> Option<String> {
+    record.field(name).map(|f| f.value().to_string())
+}
+
+/// Convert a single `event` message, `None` for other message kinds or events without an
+/// `event` field
+pub fn activity_event(record: &FitDataRecord) -> Option<ActivityEvent> {
+    if !record.is_kind(MesgNum::Event) {
+        return None;
+    }
+    let event = string_field(record, "event")?;
+    let event_type = string_field(record, "event_type");
+    let is = |e: Event| event == e.to_string();
+    let type_is = |t: EventType| event_type.as_deref() == Some(t.to_string().as_str());
+
+    let kind = if is(Event::Timer) && type_is(EventType::Start) {
+        EventKind::TimerStart {
+            trigger: string_field(record, "timer_trigger"),
+        }
+    } else if is(Event::Timer)
+        && [
+            EventType::Stop,
+            EventType::StopAll,
+            EventType::StopDisable,
+            EventType::StopDisableAll,
+        ]
+        .into_iter()
+        .any(type_is)
+    {
+        EventKind::TimerStop {
+            trigger: string_field(record, "timer_trigger"),
+            all: type_is(EventType::StopAll) || type_is(EventType::StopDisableAll),
+        }
+    } else if is(Event::Lap) {
+        EventKind::Lap
+    } else if is(Event::Session) {
+        EventKind::Session
+    } else if is(Event::Battery) {
+        EventKind::Battery {
+            voltage: field_as_f64(record, "battery_level"),
+        }
+    } else if is(Event::BatteryLow) {
+        EventKind::BatteryLow {
+            voltage: field_as_f64(record, "battery_level"),
+        }
+    } else if is(Event::OffCourse) {
+        EventKind::OffCourse
+    } else if is(Event::RecoveryHr) {
+        EventKind::RecoveryHr {
+            heart_rate: field_as_f64(record, "data"),
+        }
+    } else {
+        EventKind::Other {
+            event,
+            event_type,
+            data: record.field("data").map(|f| f.value().clone()),
+        }
+    };
+    Some(ActivityEvent {
+        timestamp: record_time(record),
+        event_group: field_as_f64(record, "event_group").map(|g| g as u8),
+        kind,
+    })
+}
+
+/// Convert every `event` message of the activity in order
+pub fn events(records: &[FitDataRecord]) -> Vec<ActivityEvent> {
+    records.iter().filter_map(activity_event).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+
+    #[test]
+    fn typed_events() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let events = events(&from_bytes(data).unwrap());
+        let kinds: Vec<&EventKind> = events.iter().map(|e| &e.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                &EventKind::TimerStart {
+                    trigger: Some("manual".to_string())
+                },
+                &EventKind::TimerStop {
+                    trigger: Some("manual".to_string()),
+                    all: true
+                },
+                &EventKind::Session,
+            ]
+        );
+        assert!(events.iter().all(|e| e.timestamp.is_some()));
+        assert_eq!(events[2].event_group, Some(1));
+    }
+
+    #[test]
+    fn clean_json() {
+        let event = ActivityEvent {
+            timestamp: None,
+            event_group: Some(1),
+            kind: EventKind::TimerStop {
+                trigger: None,
+                all: true,
+            },
+        };
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"event_group":1,"kind":{"type":"timer_stop","all":true}}"#
+        );
+    }
+}
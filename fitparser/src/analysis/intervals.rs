@@ -0,0 +1,329 @@
+//! Automatically detect work and rest efforts from the power or speed channel.
+//!
+//! The channel is split into segments of constant mean using binary segmentation, a split is
+//! accepted when it produces two segments that are both long enough and whose means differ by
+//! a meaningful amount. Adjacent segments with the same classification are then merged.
+use super::record_time;
+use crate::edit::{field_as_f64, is_record_message};
+use crate::profile::field_types::{Event, EventType, Intensity, LapTrigger};
+use crate::profile::MesgNum;
+use crate::timestamp::from_chrono;
+use crate::{FitDataField, FitDataRecord, Value};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+/// The record channel used to detect efforts
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntervalChannel {
+    /// The `power` field in watts
+    Power,
+    /// The `enhanced_speed` or `speed` field in m/s, i.e. pace based efforts
+    Speed,
+}
+
+impl IntervalChannel {
+    fn value(self, record: &FitDataRecord) -> Option<f64> {
+        match self {
+            IntervalChannel::Power => field_as_f64(record, "power"),
+            IntervalChannel::Speed => {
+                field_as_f64(record, "enhanced_speed").or_else(|| field_as_f64(record, "speed"))
+            }
+        }
+    }
+}
+
+/// Classification of a detected interval
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntervalKind {
+    /// A hard effort
+    Work,
+    /// Recovery between efforts
+    Rest,
+}
+
+/// Settings for interval detection
+#[derive(Clone, Debug)]
+pub struct IntervalDetection {
+    /// Channel the efforts are detected from
+    pub channel: IntervalChannel,
+    /// Shortest interval that can be detected in seconds
+    pub min_duration: f64,
+    /// Minimum change in the channel mean between two segments as a fraction of the overall mean
+    pub min_change: f64,
+    /// Segments with a mean at or above this value are work intervals, the overall mean of the
+    /// channel is used when not provided
+    pub threshold: Option<f64>,
+}
+
+impl Default for IntervalDetection {
+    fn default() -> Self {
+        IntervalDetection {
+            channel: IntervalChannel::Power,
+            min_duration: 30.0,
+            min_change: 0.2,
+            threshold: None,
+        }
+    }
+}
+
+/// A detected work or rest interval
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Interval {
+    /// Work or rest
+    pub kind: IntervalKind,
+    /// Channel the interval was detected from
+    pub channel: IntervalChannel,
+    /// Index of the first record in the interval
+    pub start_index: usize,
+    /// Index of the last record in the interval
+    pub end_index: usize,
+    /// Timestamp of the first record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<Local>>,
+    /// Timestamp of the last record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<DateTime<Local>>,
+    /// Duration in seconds, the number of samples is used when records lack timestamps
+    pub duration: f64,
+    /// Mean value of the channel
+    pub average: f64,
+    /// Max value of the channel
+    pub maximum: f64,
+}
+
+impl Interval {
+    /// Convert the interval into a `lap` message so it can be written back into an activity
+    pub fn to_lap(&self, message_index: u16) -> FitDataRecord {
+        let mut lap = FitDataRecord::new(MesgNum::Lap);
+        let mut push = |name: &str, number: u8, value: Value, units: &str| {
+            lap.push(FitDataField::new(
+                name.to_string(),
+                number,
+                value,
+                units.to_string(),
+            ))
+        };
+        push("event", 0, Value::String(Event::Lap.to_string()), "");
+        push(
+            "event_type",
+            1,
+            Value::String(EventType::Stop.to_string()),
+            "",
+        );
+        if let Some(start) = self.start_time {
+            push("start_time", 2, Value::Timestamp(from_chrono(start)), "");
+        }
+        push("total_elapsed_time", 7, Value::Float64(self.duration), "s");
+        push("total_timer_time", 8, Value::Float64(self.duration), "s");
+        match self.channel {
+            IntervalChannel::Power => {
+                push(
+                    "avg_power",
+                    19,
+                    Value::UInt16(self.average.round() as u16),
+                    "watts",
+                );
+                push(
+                    "max_power",
+                    20,
+                    Value::UInt16(self.maximum.round() as u16),
+                    "watts",
+                );
+            }
+            IntervalChannel::Speed => {
+                push(
+                    "enhanced_avg_speed",
+                    110,
+                    Value::Float64(self.average),
+                    "m/s",
+                );
+                push(
+                    "enhanced_max_speed",
+                    111,
+                    Value::Float64(self.maximum),
+                    "m/s",
+                );
+            }
+        }
+        let intensity = match self.kind {
+            IntervalKind::Work => Intensity::Active,
+            IntervalKind::Rest => Intensity::Rest,
+        };
+        push("intensity", 23, Value::String(intensity.to_string()), "");
+        push(
+            "lap_trigger",
+            24,
+            Value::String(LapTrigger::Manual.to_string()),
+            "",
+        );
+        if let Some(end) = self.end_time {
+            push("timestamp", 253, Value::Timestamp(from_chrono(end)), "s");
+        }
+        push("message_index", 254, Value::UInt16(message_index), "");
+        lap
+    }
+}
+
+/// Sum of squared errors of `values[start..end]` using prefix sums of the values and squares
+fn sse(sums: &[f64], squares: &[f64], start: usize, end: usize) -> f64 {
+    let n = (end - start) as f64;
+    let sum = sums[end] - sums[start];
+    (squares[end] - squares[start]) - sum * sum / n
+}
+
+/// Split the values into segments of constant mean, returning the segment boundaries
+fn change_points(values: &[f64], min_len: usize, min_change: f64) -> Vec<usize> {
+    let mut sums = vec![0.0; values.len() + 1];
+    let mut squares = vec![0.0; values.len() + 1];
+    for (i, v) in values.iter().enumerate() {
+        sums[i + 1] = sums[i] + v;
+        squares[i + 1] = squares[i] + v * v;
+    }
+
+    let mut boundaries = vec![0, values.len()];
+    let mut stack = vec![(0, values.len())];
+    while let Some((start, end)) = stack.pop() {
+        if end - start < 2 * min_len {
+            continue;
+        }
+        let total = sse(&sums, &squares, start, end);
+        let mut best: Option<(usize, f64)> = None;
+        for split in (start + min_len)..=(end - min_len) {
+            let cost = sse(&sums, &squares, start, split) + sse(&sums, &squares, split, end);
+            if best.is_none_or(|(_, c)| cost < c) {
+                best = Some((split, cost));
+            }
+        }
+        if let Some((split, cost)) = best {
+            let left = (sums[split] - sums[start]) / (split - start) as f64;
+            let right = (sums[end] - sums[split]) / (end - split) as f64;
+            if cost < total && (left - right).abs() >= min_change {
+                boundaries.push(split);
+                stack.push((start, split));
+                stack.push((split, end));
+            }
+        }
+    }
+    boundaries.sort_unstable();
+    boundaries
+}
+
+/// Detect work and rest intervals in the `record` messages, the returned intervals are ordered
+/// and cover every record that has a value for the selected channel.
+pub fn intervals(records: &[FitDataRecord], opts: &IntervalDetection) -> Vec<Interval> {
+    let samples: Vec<(usize, f64)> = records
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| is_record_message(r))
+        .filter_map(|(idx, r)| opts.channel.value(r).map(|v| (idx, v)))
+        .collect();
+    if samples.is_empty() {
+        return Vec::new();
+    }
+    let values: Vec<f64> = samples.iter().map(|(_, v)| *v).collect();
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+
+    // convert the min duration into a number of samples using the average recording interval
+    let first = record_time(&records[samples[0].0]);
+    let last = record_time(&records[samples[samples.len() - 1].0]);
+    let spacing = match (first, last) {
+        (Some(a), Some(b)) if samples.len() > 1 => {
+            (b - a).num_milliseconds() as f64 / 1000.0 / (samples.len() - 1) as f64
+        }
+        _ => 1.0,
+    };
+    let min_len = ((opts.min_duration / spacing.max(f64::EPSILON)).round() as usize).max(1);
+    let threshold = opts.threshold.unwrap_or(mean);
+
+    let mut result: Vec<Interval> = Vec::new();
+    let boundaries = change_points(&values, min_len, opts.min_change * mean.abs());
+    for window in boundaries.windows(2) {
+        let (start, end) = (window[0], window[1]);
+        let segment = &values[start..end];
+        let average = segment.iter().sum::<f64>() / segment.len() as f64;
+        let maximum = segment.iter().cloned().fold(f64::MIN, f64::max);
+        let kind = if average >= threshold {
+            IntervalKind::Work
+        } else {
+            IntervalKind::Rest
+        };
+        let (start_index, end_index) = (samples[start].0, samples[end - 1].0);
+
+        // merge into the previous interval if the classification didn't change
+        if let Some(prev) = result.last_mut() {
+            if prev.kind == kind {
+                let prev_len = prev.duration;
+                let len = segment.len() as f64;
+                prev.average = (prev.average * prev_len + average * len) / (prev_len + len);
+                prev.maximum = prev.maximum.max(maximum);
+                prev.end_index = end_index;
+                prev.duration += len;
+                continue;
+            }
+        }
+        result.push(Interval {
+            kind,
+            channel: opts.channel,
+            start_index,
+            end_index,
+            start_time: None,
+            end_time: None,
+            duration: segment.len() as f64,
+            average,
+            maximum,
+        });
+    }
+
+    // durations were accumulated as sample counts for the merge weighting, use real time if known
+    for interval in &mut result {
+        interval.start_time = record_time(&records[interval.start_index]);
+        interval.end_time = record_time(&records[interval.end_index]);
+        if let (Some(a), Some(b)) = (interval.start_time, interval.end_time) {
+            interval.duration = (b - a).num_milliseconds() as f64 / 1000.0 + spacing;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn detect_work_and_rest() {
+        // 3 x (60s @ 300W, 60s @ 120W) with some noise
+        let records: Vec<FitDataRecord> = (0..360)
+            .map(|i| {
+                let noise = (i % 7) as u16 * 3;
+                let power = if (i / 60) % 2 == 0 { 300 } else { 120 } + noise;
+                fixtures::record(MesgNum::Record, &[("power", Value::UInt16(power))])
+            })
+            .collect();
+
+        let found = intervals(&records, &IntervalDetection::default());
+        let kinds: Vec<IntervalKind> = found.iter().map(|i| i.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                IntervalKind::Work,
+                IntervalKind::Rest,
+                IntervalKind::Work,
+                IntervalKind::Rest,
+                IntervalKind::Work,
+                IntervalKind::Rest
+            ]
+        );
+        assert_eq!(found[2].start_index, 120);
+        assert_eq!(found[2].end_index, 179);
+
+        let lap = found[0].to_lap(0);
+        assert_eq!(lap.kind(), MesgNum::Lap);
+        assert_eq!(
+            lap.field("intensity").unwrap().value().to_string(),
+            "active"
+        );
+    }
+}
@@ -0,0 +1,324 @@
+//! Split an activity into laps at each crossing of a start/finish line, e.g. for track sessions
+//! and criteriums recorded without auto lap configured on the device.
+//!
+//! The line is given by two coordinates and only extends between them, so riding past its ends
+//! doesn't count. Only crossings in the direction of the first crossing close a lap and crossings
+//! closer together than the minimum lap time are ignored since GPS jitter around the line would
+//! otherwise produce very short laps.
+use super::record_time;
+use crate::edit::is_record_message;
+use crate::geo::{haversine, record_position, to_local_xy, Point};
+use crate::profile::field_types::{Event, EventType, LapTrigger};
+use crate::profile::MesgNum;
+use crate::timestamp::from_chrono;
+use crate::{FitDataField, FitDataRecord, Value};
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+
+/// A start/finish line between two positions given as (latitude, longitude) in decimal degrees
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LapLine {
+    /// One end of the line
+    pub start: (f64, f64),
+    /// The other end of the line
+    pub end: (f64, f64),
+}
+
+/// Settings for splitting an activity at a lap line
+#[derive(Clone, Debug)]
+pub struct LapLineDetection {
+    /// The start/finish line
+    pub line: LapLine,
+    /// Shortest lap in seconds, crossings within this time of the previous lap are ignored
+    pub min_lap_time: f64,
+}
+
+impl LapLineDetection {
+    /// Detect laps at the line ignoring crossings less than 10 seconds apart
+    pub fn new(line: LapLine) -> Self {
+        LapLineDetection {
+            line,
+            min_lap_time: 10.0,
+        }
+    }
+}
+
+/// A crossing of the lap line by the track of the activity
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LineCrossing {
+    /// Index of the first record past the line
+    pub index: usize,
+    /// Time of the crossing interpolated between the records on either side of the line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime<Local>>,
+    /// Direction of the crossing, `true` when crossing from the left of the line going from
+    /// `start` to `end` to its right
+    pub forward: bool,
+}
+
+/// A lap between two crossings of the line, the first and last laps run from the start of the
+/// activity to the first crossing and from the last crossing to the end of the activity
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PositionLap {
+    /// Index of the first record in the lap
+    pub start_index: usize,
+    /// Index of the last record in the lap
+    pub end_index: usize,
+    /// Time the lap started
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<Local>>,
+    /// Time the lap ended
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<DateTime<Local>>,
+    /// Duration in seconds, zero when records lack timestamps
+    pub duration: f64,
+    /// Distance covered by the positions of the lap in meters
+    pub distance: f64,
+}
+
+impl PositionLap {
+    /// Convert the lap into a `lap` message so it can be written back into an activity
+    pub fn to_lap(&self, message_index: u16) -> FitDataRecord {
+        let mut lap = FitDataRecord::new(MesgNum::Lap);
+        let mut push = |name: &str, number: u8, value: Value, units: &str| {
+            lap.push(FitDataField::new(
+                name.to_string(),
+                number,
+                value,
+                units.to_string(),
+            ))
+        };
+        push("event", 0, Value::String(Event::Lap.to_string()), "");
+        push(
+            "event_type",
+            1,
+            Value::String(EventType::Stop.to_string()),
+            "",
+        );
+        if let Some(start) = self.start_time {
+            push("start_time", 2, Value::Timestamp(from_chrono(start)), "");
+        }
+        push("total_elapsed_time", 7, Value::Float64(self.duration), "s");
+        push("total_timer_time", 8, Value::Float64(self.duration), "s");
+        push("total_distance", 9, Value::Float64(self.distance), "m");
+        push(
+            "lap_trigger",
+            24,
+            Value::String(LapTrigger::PositionLap.to_string()),
+            "",
+        );
+        if let Some(end) = self.end_time {
+            push("timestamp", 253, Value::Timestamp(from_chrono(end)), "s");
+        }
+        push("message_index", 254, Value::UInt16(message_index), "");
+        lap
+    }
+}
+
+fn cross(a: (f64, f64), b: (f64, f64)) -> f64 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+/// Fraction along the track segment `p`-`q` where it crosses the line along with the direction,
+/// `None` if it doesn't cross
+fn intersection(line: &LapLine, p: Point, q: Point) -> Option<(f64, bool)> {
+    let origin = line.start;
+    let s = to_local_xy(origin, line.end);
+    let p = to_local_xy(origin, p);
+    let q = to_local_xy(origin, q);
+    let r = (q.0 - p.0, q.1 - p.1);
+    let denom = cross(r, s);
+    if denom == 0.0 {
+        return None;
+    }
+    let t = cross((-p.0, -p.1), s) / denom;
+    let u = cross((-p.0, -p.1), r) / denom;
+    // a point exactly on the line only counts for the segment ending there
+    (t > 0.0 && t <= 1.0 && (0.0..=1.0).contains(&u)).then_some((t, denom > 0.0))
+}
+
+/// Find every crossing of the line by the positions of the `record` messages
+pub fn line_crossings(records: &[FitDataRecord], line: &LapLine) -> Vec<LineCrossing> {
+    let mut crossings = Vec::new();
+    let mut last: Option<(Point, Option<DateTime<Local>>)> = None;
+    for (index, record) in records.iter().enumerate() {
+        if !is_record_message(record) {
+            continue;
+        }
+        let point = match record_position(record) {
+            Some(point) => point,
+            None => continue,
+        };
+        let time = record_time(record);
+        if let Some((previous, previous_time)) = last {
+            if let Some((t, forward)) = intersection(line, previous, point) {
+                let time = match (previous_time, time) {
+                    (Some(a), Some(b)) => {
+                        let millis = (b - a).num_milliseconds() as f64 * t;
+                        Some(a + Duration::milliseconds(millis.round() as i64))
+                    }
+                    _ => time,
+                };
+                crossings.push(LineCrossing {
+                    index,
+                    time,
+                    forward,
+                });
+            }
+        }
+        last = Some((point, time));
+    }
+    crossings
+}
+
+fn lap(
+    records: &[FitDataRecord],
+    start_index: usize,
+    end_index: usize,
+    start_time: Option<DateTime<Local>>,
+    end_time: Option<DateTime<Local>>,
+) -> PositionLap {
+    let positions: Vec<Point> = records[start_index..=end_index]
+        .iter()
+        .filter(|r| is_record_message(r))
+        .filter_map(record_position)
+        .collect();
+    let distance = positions.windows(2).map(|w| haversine(w[0], w[1])).sum();
+    let duration = match (start_time, end_time) {
+        (Some(a), Some(b)) => (b - a).num_milliseconds() as f64 / 1000.0,
+        _ => 0.0,
+    };
+    PositionLap {
+        start_index,
+        end_index,
+        start_time,
+        end_time,
+        duration,
+        distance,
+    }
+}
+
+/// Split the `record` messages into laps at the crossings of the line, an activity that never
+/// crosses the line returns no laps
+pub fn position_laps(records: &[FitDataRecord], opts: &LapLineDetection) -> Vec<PositionLap> {
+    let mut crossings: Vec<LineCrossing> = Vec::new();
+    for crossing in line_crossings(records, &opts.line) {
+        if let Some(first) = crossings.first() {
+            if crossing.forward != first.forward {
+                continue;
+            }
+        }
+        if let Some(previous) = crossings.last() {
+            if let (Some(a), Some(b)) = (previous.time, crossing.time) {
+                if ((b - a).num_milliseconds() as f64 / 1000.0) < opts.min_lap_time {
+                    continue;
+                }
+            }
+        }
+        crossings.push(crossing);
+    }
+    let indices: Vec<usize> = records
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| is_record_message(r))
+        .map(|(idx, _)| idx)
+        .collect();
+    let (first, last) = match (indices.first(), indices.last()) {
+        (Some(first), Some(last)) if !crossings.is_empty() => (*first, *last),
+        _ => return Vec::new(),
+    };
+
+    let mut laps = Vec::new();
+    let mut start = (first, record_time(&records[first]));
+    for crossing in &crossings {
+        if crossing.index > start.0 {
+            laps.push(lap(
+                records,
+                start.0,
+                crossing.index - 1,
+                start.1,
+                crossing.time,
+            ));
+        }
+        start = (crossing.index, crossing.time);
+    }
+    laps.push(lap(
+        records,
+        start.0,
+        last,
+        start.1,
+        record_time(&records[last]),
+    ));
+    laps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::geo::{degrees_to_semicircles, EARTH_RADIUS};
+    use chrono::TimeZone;
+
+    /// A point on a circular track with a radius of 100 m, angles count counterclockwise from
+    /// east
+    fn track_point(angle: f64) -> Point {
+        let (lat, lon) = (52.0_f64, 13.0);
+        let dlat = (100.0 * angle.sin() / EARTH_RADIUS).to_degrees();
+        let dlon = (100.0 * angle.cos() / (EARTH_RADIUS * lat.to_radians().cos())).to_degrees();
+        (lat + dlat, lon + dlon)
+    }
+
+    #[test]
+    fn split_laps_at_line() {
+        let start = Local.timestamp_opt(1_600_000_000, 0).unwrap();
+        // three and a half laps of a minute each, starting on the east side of the track
+        let records: Vec<FitDataRecord> = (0..210)
+            .map(|second| {
+                let (lat, lon) = track_point(std::f64::consts::TAU * second as f64 / 60.0);
+                fixtures::record(
+                    MesgNum::Record,
+                    &[
+                        ("position_lat", Value::SInt32(degrees_to_semicircles(lat))),
+                        ("position_long", Value::SInt32(degrees_to_semicircles(lon))),
+                        (
+                            "timestamp",
+                            Value::Timestamp(from_chrono(start + Duration::seconds(second))),
+                        ),
+                    ],
+                )
+            })
+            .collect();
+        // a line across the south side of the track, half a second after the lap started
+        let inner = track_point(std::f64::consts::PI * 1.525);
+        let line = LapLine {
+            start: (inner.0 + 0.0002, inner.1),
+            end: (inner.0 - 0.0002, inner.1),
+        };
+        let crossings = line_crossings(&records, &line);
+        assert_eq!(crossings.len(), 3);
+        assert!(crossings.iter().all(|c| c.forward == crossings[0].forward));
+        assert_eq!(crossings[0].index, 46);
+        let offset = crossings[0].time.unwrap() - start;
+        assert!((offset.num_milliseconds() - 45_750).abs() < 50);
+
+        let laps = position_laps(&records, &LapLineDetection::new(line));
+        let durations: Vec<f64> = laps.iter().map(|l| l.duration.round()).collect();
+        assert_eq!(durations, vec![46.0, 60.0, 60.0, 43.0]);
+        assert_eq!(laps[1].start_index, 46);
+        assert_eq!(laps[1].end_index, 105);
+        assert!(laps[1].distance > 600.0 && laps[1].distance < 630.0);
+
+        let lap = laps[1].to_lap(1);
+        assert_eq!(lap.kind(), MesgNum::Lap);
+        assert_eq!(
+            lap.field("lap_trigger").unwrap().value().to_string(),
+            "position_lap"
+        );
+
+        let far = LapLine {
+            start: (53.0, 13.0),
+            end: (53.001, 13.0),
+        };
+        assert!(position_laps(&records, &LapLineDetection::new(far)).is_empty());
+    }
+}
@@ -0,0 +1,236 @@
+//! Compare the laps of an activity side by side, one row of aligned metrics per lap ready to be
+//! rendered as a table or written as CSV.
+//!
+//! Metrics are taken from the `lap` message when the device recorded them and computed from the
+//! `record` messages of the lap otherwise, so laps added after the fact, e.g. by
+//! `position_laps`, still get a complete row.
+use super::{record_altitude, weighted_average, Activity, Segment};
+use crate::edit::field_as_f64;
+use crate::error::Result;
+use chrono::{DateTime, Local, SecondsFormat, Utc};
+use serde::Serialize;
+use std::io::Write;
+
+/// Metrics of a single lap, `None` when neither the lap message nor its records provide them
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LapRow {
+    /// Number of the lap starting at 1
+    pub lap: usize,
+    /// Time the lap started
+    pub start_time: DateTime<Local>,
+    /// Elapsed time in seconds
+    pub elapsed_time: f64,
+    /// Time the timer was running in seconds
+    pub timer_time: Option<f64>,
+    /// Distance in meters
+    pub distance: Option<f64>,
+    /// Average speed in m/s
+    pub avg_speed: Option<f64>,
+    /// Average heart rate in bpm
+    pub avg_heart_rate: Option<f64>,
+    /// Max heart rate in bpm
+    pub max_heart_rate: Option<f64>,
+    /// Average power in watts
+    pub avg_power: Option<f64>,
+    /// Max power in watts
+    pub max_power: Option<f64>,
+    /// Elevation gained in meters
+    pub ascent: Option<f64>,
+    /// Elevation lost in meters
+    pub descent: Option<f64>,
+}
+
+/// The rows of every lap of an activity
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct LapTable {
+    /// One row per lap in the order of the laps
+    pub rows: Vec<LapRow>,
+}
+
+impl LapTable {
+    /// Names of the columns in the order of `cells`, numeric columns are named after their units
+    pub const COLUMNS: &'static [&'static str] = &[
+        "lap",
+        "start_time",
+        "elapsed_time (s)",
+        "timer_time (s)",
+        "distance (m)",
+        "avg_speed (m/s)",
+        "avg_heart_rate (bpm)",
+        "max_heart_rate (bpm)",
+        "avg_power (watts)",
+        "max_power (watts)",
+        "ascent (m)",
+        "descent (m)",
+    ];
+
+    /// The rows formatted as text cells aligned with `COLUMNS`, missing metrics are left empty
+    pub fn cells(&self) -> Vec<Vec<String>> {
+        let cell = |value: Option<f64>, decimals: usize| {
+            value.map_or(String::new(), |v| format!("{:.*}", decimals, v))
+        };
+        self.rows
+            .iter()
+            .map(|row| {
+                vec![
+                    row.lap.to_string(),
+                    row.start_time
+                        .with_timezone(&Utc)
+                        .to_rfc3339_opts(SecondsFormat::Secs, true),
+                    cell(Some(row.elapsed_time), 1),
+                    cell(row.timer_time, 1),
+                    cell(row.distance, 1),
+                    cell(row.avg_speed, 3),
+                    cell(row.avg_heart_rate, 0),
+                    cell(row.max_heart_rate, 0),
+                    cell(row.avg_power, 0),
+                    cell(row.max_power, 0),
+                    cell(row.ascent, 0),
+                    cell(row.descent, 0),
+                ]
+            })
+            .collect()
+    }
+
+    /// Write the table as CSV with a header row naming the columns
+    pub fn write_csv<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writeln!(writer, "{}", Self::COLUMNS.join(","))?;
+        for row in self.cells() {
+            writeln!(writer, "{}", row.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+/// Largest value of a channel in the records of the lap
+fn max_value(lap: &Segment, field: &str) -> Option<f64> {
+    lap.records()
+        .filter_map(|r| field_as_f64(r, field))
+        .reduce(f64::max)
+}
+
+/// Total elevation gained and lost going by the altitude of the records of the lap
+fn elevation_change(lap: &Segment) -> Option<(f64, f64)> {
+    let altitudes: Vec<f64> = lap.records().filter_map(record_altitude).collect();
+    if altitudes.len() < 2 {
+        return None;
+    }
+    Some(altitudes.windows(2).fold((0.0, 0.0), |(up, down), w| {
+        let change = w[1] - w[0];
+        (up + change.max(0.0), down - change.min(0.0))
+    }))
+}
+
+fn lap_row(number: usize, lap: &Segment) -> LapRow {
+    let summary = lap.summary();
+    let field = |name: &str| field_as_f64(summary, name);
+    let elapsed = (lap.end_time() - lap.start_time()).num_milliseconds() as f64 / 1000.0;
+    let distance = field("total_distance").or_else(|| {
+        let mut distances = lap.records().filter_map(|r| field_as_f64(r, "distance"));
+        let first = distances.next()?;
+        Some(distances.last().unwrap_or(first) - first)
+    });
+    let timer_time = field("total_timer_time");
+    let avg_speed = field("enhanced_avg_speed")
+        .or_else(|| field("avg_speed"))
+        .or_else(|| {
+            let time = timer_time.unwrap_or(elapsed);
+            distance.filter(|_| time > 0.0).map(|d| d / time)
+        });
+    let elevation = elevation_change(lap);
+    LapRow {
+        lap: number,
+        start_time: lap.start_time(),
+        elapsed_time: field("total_elapsed_time").unwrap_or(elapsed),
+        timer_time,
+        distance,
+        avg_speed,
+        avg_heart_rate: field("avg_heart_rate")
+            .or_else(|| weighted_average(lap.messages(), "heart_rate")),
+        max_heart_rate: field("max_heart_rate").or_else(|| max_value(lap, "heart_rate")),
+        avg_power: field("avg_power").or_else(|| weighted_average(lap.messages(), "power")),
+        max_power: field("max_power").or_else(|| max_value(lap, "power")),
+        ascent: field("total_ascent").or(elevation.map(|(up, _)| up)),
+        descent: field("total_descent").or(elevation.map(|(_, down)| down)),
+    }
+}
+
+/// Build the table comparing the laps of the activity
+pub fn lap_table(activity: &Activity) -> LapTable {
+    LapTable {
+        rows: activity
+            .laps()
+            .enumerate()
+            .map(|(index, lap)| lap_row(index + 1, &lap))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::profile::MesgNum;
+    use crate::timestamp::from_chrono;
+    use crate::{from_bytes, FitDataRecord, Value};
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn compare_laps() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let records = from_bytes(data).unwrap();
+        let activity = Activity::new(&records);
+        let table = lap_table(&activity);
+        assert_eq!(table.rows.len(), activity.laps().count());
+        let lap = records.iter().find(|r| r.is_kind(MesgNum::Lap)).unwrap();
+        assert_eq!(table.rows[0].distance, field_as_f64(lap, "total_distance"));
+        assert_eq!(
+            table.rows[0].avg_heart_rate,
+            field_as_f64(lap, "avg_heart_rate")
+        );
+        let mut csv = Vec::new();
+        table.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.lines();
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("lap,start_time,elapsed_time (s),"));
+        let cells: Vec<&str> = lines.next().unwrap().split(',').collect();
+        assert_eq!(cells.len(), LapTable::COLUMNS.len());
+        assert_eq!(cells[0], "1");
+
+        // a lap without totals is computed from its records
+        let start = Local.timestamp_opt(1_600_000_000, 0).unwrap();
+        let at = |second| Value::Timestamp(from_chrono(start + Duration::seconds(second)));
+        let mut records: Vec<FitDataRecord> = (0..=10)
+            .map(|second| {
+                fixtures::record(
+                    MesgNum::Record,
+                    &[
+                        ("timestamp", at(second)),
+                        ("distance", Value::Float64(second as f64 * 5.0)),
+                        ("power", Value::UInt16(200 + second as u16)),
+                        (
+                            "enhanced_altitude",
+                            Value::Float64(100.0 + (second % 4) as f64),
+                        ),
+                    ],
+                )
+            })
+            .collect();
+        records.push(fixtures::record(
+            MesgNum::Lap,
+            &[("start_time", at(0)), ("timestamp", at(10))],
+        ));
+        let table = lap_table(&Activity::new(&records));
+        let row = &table.rows[0];
+        assert_eq!(row.elapsed_time, 10.0);
+        assert_eq!(row.distance, Some(50.0));
+        assert_eq!(row.avg_speed, Some(5.0));
+        assert_eq!(row.avg_power, Some(204.5));
+        assert_eq!(row.max_power, Some(210.0));
+        assert_eq!((row.ascent, row.descent), (Some(8.0), Some(6.0)));
+        assert_eq!(row.avg_heart_rate, None);
+    }
+}
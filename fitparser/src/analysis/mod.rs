@@ -0,0 +1,91 @@
+//! Derived metrics computed from decoded FIT data, these functions never modify the records
+//! they are given.
+use crate::edit::{field_as_f64, is_record_message};
+use crate::geo::{haversine, record_position};
+use crate::timestamp::{to_chrono, utc_to_local};
+use crate::{FitDataRecord, Value};
+use chrono::{DateTime, Local};
+
+mod averages;
+pub use averages::{weighted_average, WeightedAverage, DEFAULT_MAX_GAP};
+mod climbs;
+pub use climbs::{climbs, Climb, ClimbCategory, ClimbDetection};
+#[cfg(not(compact_profile))]
+mod compliance;
+#[cfg(not(compact_profile))]
+pub use compliance::{
+    step_target, workout_compliance, StepCompliance, StepTarget, TargetChannel, Thresholds,
+    WorkoutCompliance,
+};
+#[cfg(not(compact_profile))]
+mod diagnostics;
+#[cfg(not(compact_profile))]
+pub use diagnostics::{power_diagnostics, Diagnostic, DiagnosticKind, PowerDiagnostics};
+mod elevation;
+pub use elevation::{altitude_at, elevation_profile};
+#[cfg(not(compact_profile))]
+mod events;
+#[cfg(not(compact_profile))]
+pub use events::{activity_event, events, ActivityEvent, EventKind};
+#[cfg(not(compact_profile))]
+mod intervals;
+#[cfg(not(compact_profile))]
+pub use intervals::{intervals, Interval, IntervalChannel, IntervalDetection, IntervalKind};
+#[cfg(not(compact_profile))]
+mod lap_line;
+mod lap_table;
+#[cfg(not(compact_profile))]
+pub use lap_line::{
+    line_crossings, position_laps, LapLine, LapLineDetection, LineCrossing, PositionLap,
+};
+pub use lap_table::{lap_table, LapRow, LapTable};
+mod pace;
+pub use pace::{format_pace, pace, pace_to_speed, record_pace, PaceUnit};
+mod running;
+pub use running::{running_channels, RunningPowerModel, RunningSample};
+mod segments;
+pub use segments::{Activity, Segment};
+
+/// Return the timestamp stored in a record if present
+pub(crate) fn record_time(record: &FitDataRecord) -> Option<DateTime<Local>> {
+    match record.field("timestamp").map(|f| f.value()) {
+        Some(Value::Timestamp(val)) => Some(to_chrono(val)),
+        Some(Value::UtcTimestamp(val)) => Some(to_chrono(&utc_to_local(*val))),
+        _ => None,
+    }
+}
+
+/// Return the altitude of a record preferring the higher resolution enhanced field
+pub(crate) fn record_altitude(record: &FitDataRecord) -> Option<f64> {
+    field_as_f64(record, "enhanced_altitude").or_else(|| field_as_f64(record, "altitude"))
+}
+
+/// Build the (record index, distance, altitude) profile of the `record` messages. The distance
+/// recorded by the device is used when available otherwise it is computed from the positions.
+/// Records without an altitude or any way to determine distance are skipped.
+pub(crate) fn distance_altitude_profile(records: &[FitDataRecord]) -> Vec<(usize, f64, f64)> {
+    let mut profile = Vec::new();
+    let mut computed = 0.0;
+    let mut last_point = None;
+    for (index, record) in records.iter().enumerate() {
+        if !is_record_message(record) {
+            continue;
+        }
+        let point = record_position(record);
+        if let (Some(last), Some(point)) = (last_point, point) {
+            computed += haversine(last, point);
+        }
+        if point.is_some() {
+            last_point = point;
+        }
+        let distance = match field_as_f64(record, "distance") {
+            Some(d) => d,
+            None if last_point.is_some() => computed,
+            None => continue,
+        };
+        if let Some(altitude) = record_altitude(record) {
+            profile.push((index, distance, altitude));
+        }
+    }
+    profile
+}
@@ -0,0 +1,102 @@
+//! Pace, the time it takes to cover a kilometer or mile, converted from and into speeds in m/s
+//! as the FIT profile stores them.
+use crate::edit::field_as_f64;
+use crate::FitDataRecord;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Distance a pace is given for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PaceUnit {
+    /// Time per kilometer
+    Kilometer,
+    /// Time per statute mile
+    Mile,
+}
+
+impl PaceUnit {
+    /// Length of the distance in meters
+    pub fn meters(self) -> f64 {
+        match self {
+            PaceUnit::Kilometer => 1000.0,
+            PaceUnit::Mile => 1609.344,
+        }
+    }
+
+    /// Suffix written after a formatted pace, e.g. `/km`
+    pub fn suffix(self) -> &'static str {
+        match self {
+            PaceUnit::Kilometer => "/km",
+            PaceUnit::Mile => "/mi",
+        }
+    }
+}
+
+/// Pace of a speed in m/s, `None` when stationary
+pub fn pace(speed: f64, unit: PaceUnit) -> Option<Duration> {
+    if speed > 0.0 && speed.is_finite() {
+        Duration::try_from_secs_f64(unit.meters() / speed).ok()
+    } else {
+        None
+    }
+}
+
+/// Speed in m/s of a pace, `None` for a pace of zero
+pub fn pace_to_speed(pace: Duration, unit: PaceUnit) -> Option<f64> {
+    if pace.is_zero() {
+        None
+    } else {
+        Some(unit.meters() / pace.as_secs_f64())
+    }
+}
+
+/// Pace of a `record` message from its `enhanced_speed` or `speed` field
+pub fn record_pace(record: &FitDataRecord, unit: PaceUnit) -> Option<Duration> {
+    let speed = field_as_f64(record, "enhanced_speed").or_else(|| field_as_f64(record, "speed"))?;
+    pace(speed, unit)
+}
+
+/// Format a pace as `m:ss/km`, rounded to the second. Hours are added to paces of an hour or
+/// more, e.g. `1:02:03/mi`.
+pub fn format_pace(pace: Duration, unit: PaceUnit) -> String {
+    let seconds = pace.as_secs_f64().round() as u64;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}{}", unit.suffix())
+    } else {
+        format!("{minutes}:{seconds:02}{}", unit.suffix())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::profile::MesgNum;
+    use crate::Value;
+
+    #[test]
+    fn pace_conversions() {
+        let five_minutes = Duration::from_secs(300);
+        assert_eq!(
+            pace(1000.0 / 300.0, PaceUnit::Kilometer),
+            Some(five_minutes)
+        );
+        assert_eq!(pace(0.0, PaceUnit::Kilometer), None);
+        let speed = pace_to_speed(five_minutes, PaceUnit::Mile).unwrap();
+        assert!((speed - 5.36448).abs() < 1e-9);
+        assert_eq!(pace_to_speed(Duration::ZERO, PaceUnit::Mile), None);
+
+        assert_eq!(format_pace(five_minutes, PaceUnit::Kilometer), "5:00/km");
+        assert_eq!(
+            format_pace(Duration::from_secs_f64(3723.4), PaceUnit::Mile),
+            "1:02:03/mi"
+        );
+
+        let record = fixtures::record(MesgNum::Record, &[("speed", Value::Float64(4.0))]);
+        assert_eq!(
+            record_pace(&record, PaceUnit::Kilometer),
+            Some(Duration::from_secs(250))
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! Derived running channels, grade adjusted pace (GAP) and an estimated running power for
+//! devices that don't record power natively.
+//!
+//! Both channels use the energy cost of running on a slope measured by Minetti et al. (2002),
+//! the cost relative to flat ground converts the actual speed into the equivalent flat speed and
+//! multiplying that by the runner's mass and flat ground cost of running gives the power.
+use super::{distance_altitude_profile, record_time};
+use crate::edit::field_as_f64;
+use crate::FitDataRecord;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+/// Minetti's valid range of grades, the polynomial misbehaves outside of it
+const MAX_GRADE: f64 = 0.45;
+
+/// Settings for the running power model
+#[derive(Clone, Debug)]
+pub struct RunningPowerModel {
+    /// Mass of the runner in kg
+    pub mass: f64,
+    /// Energy cost of running on flat ground in J/kg/m, roughly 1.04 for most runners
+    pub energy_cost: f64,
+    /// Grade is computed over at least this many meters to smooth out altitude noise
+    pub grade_window: f64,
+}
+
+impl Default for RunningPowerModel {
+    fn default() -> Self {
+        RunningPowerModel {
+            mass: 70.0,
+            energy_cost: 1.04,
+            grade_window: 50.0,
+        }
+    }
+}
+
+/// Derived values for a single `record` message
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RunningSample {
+    /// Index of the record the values were derived from
+    pub index: usize,
+    /// Timestamp of the record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<DateTime<Local>>,
+    /// Recorded speed in m/s
+    pub speed: f64,
+    /// Grade as a fraction, 0.05 is a 5% incline
+    pub grade: f64,
+    /// Speed that would take the same effort on flat ground in m/s
+    pub grade_adjusted_speed: f64,
+    /// Estimated running power in watts
+    pub power: f64,
+}
+
+impl RunningSample {
+    /// Grade adjusted pace in seconds per kilometer, `None` when stationary
+    pub fn grade_adjusted_pace(&self) -> Option<f64> {
+        if self.grade_adjusted_speed > 0.0 {
+            Some(1000.0 / self.grade_adjusted_speed)
+        } else {
+            None
+        }
+    }
+}
+
+/// Metabolic cost of running at the given grade in J/kg/m
+fn energy_cost(grade: f64) -> f64 {
+    let i = grade.clamp(-MAX_GRADE, MAX_GRADE);
+    155.4 * i.powi(5) - 30.4 * i.powi(4) - 43.3 * i.powi(3) + 46.3 * i.powi(2) + 19.5 * i + 3.6
+}
+
+/// Compute GAP and running power for every `record` message with a speed and altitude.
+pub fn running_channels(
+    records: &[FitDataRecord],
+    model: &RunningPowerModel,
+) -> Vec<RunningSample> {
+    let profile = distance_altitude_profile(records);
+    let flat_cost = energy_cost(0.0);
+    let mut samples = Vec::with_capacity(profile.len());
+    let mut window_start = 0;
+    for (pos, (index, distance, altitude)) in profile.iter().enumerate() {
+        let record = &records[*index];
+        let speed = match field_as_f64(record, "enhanced_speed")
+            .or_else(|| field_as_f64(record, "speed"))
+        {
+            Some(s) => s,
+            None => continue,
+        };
+        // advance the start of the window as long as it stays at least grade_window long
+        while window_start + 1 < pos {
+            if distance - profile[window_start + 1].1 < model.grade_window {
+                break;
+            }
+            window_start += 1;
+        }
+        let (_, start_distance, start_altitude) = profile[window_start];
+        let grade = if distance - start_distance > 0.0 {
+            (altitude - start_altitude) / (distance - start_distance)
+        } else {
+            0.0
+        };
+        let grade_adjusted_speed = speed * energy_cost(grade) / flat_cost;
+        samples.push(RunningSample {
+            index: *index,
+            timestamp: record_time(record),
+            speed,
+            grade,
+            grade_adjusted_speed,
+            power: model.mass * grade_adjusted_speed * model.energy_cost,
+        });
+    }
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::profile::MesgNum;
+    use crate::Value;
+
+    #[test]
+    fn uphill_running_is_harder() {
+        let course = |climb: f64| -> Vec<FitDataRecord> {
+            (0..60)
+                .map(|i| {
+                    fixtures::record(
+                        MesgNum::Record,
+                        &[
+                            ("distance", Value::Float64(i as f64 * 3.0)),
+                            ("enhanced_speed", Value::Float64(3.0)),
+                            ("enhanced_altitude", Value::Float64(10.0 + i as f64 * climb)),
+                        ],
+                    )
+                })
+                .collect()
+        };
+        let flat = course(0.0);
+        let hill = course(0.3);
+        let model = RunningPowerModel::default();
+
+        let flat = running_channels(&flat, &model);
+        let last = flat.last().unwrap();
+        assert_eq!(last.grade, 0.0);
+        assert_eq!(last.grade_adjusted_speed, 3.0);
+        assert!((last.power - 70.0 * 3.0 * 1.04).abs() < 1e-9);
+
+        let hill = running_channels(&hill, &model);
+        let last = hill.last().unwrap();
+        assert!((last.grade - 0.1).abs() < 1e-9);
+        assert!(last.grade_adjusted_speed > 3.0);
+        assert!(last.grade_adjusted_pace().unwrap() < 1000.0 / 3.0);
+    }
+}
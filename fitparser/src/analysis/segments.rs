@@ -0,0 +1,174 @@
+//! Split the records of an activity by lap or session so per lap analysis can work on the
+//! samples of a single lap without windowing timestamps by hand.
+use super::record_time;
+use crate::edit::{field_as_f64, is_record_message};
+use crate::profile::MesgNum;
+use crate::timestamp::to_chrono;
+use crate::{FitDataRecord, Value};
+use chrono::{DateTime, Duration, Local};
+
+/// Borrowed view over the decoded messages of an activity
+#[derive(Clone, Copy, Debug)]
+pub struct Activity<'a> {
+    records: &'a [FitDataRecord],
+}
+
+/// A lap or session along with the messages recorded during its time window
+#[derive(Clone, Copy, Debug)]
+pub struct Segment<'a> {
+    summary: &'a FitDataRecord,
+    messages: &'a [FitDataRecord],
+    start: DateTime<Local>,
+    end: DateTime<Local>,
+    end_inclusive: bool,
+}
+
+impl<'a> Activity<'a> {
+    /// Create a view over the decoded messages, they are expected in recording order
+    pub fn new(records: &'a [FitDataRecord]) -> Self {
+        Activity { records }
+    }
+
+    /// All messages of the activity
+    pub fn messages(&self) -> &'a [FitDataRecord] {
+        self.records
+    }
+
+    /// Iterate over the `record` messages of the whole activity
+    pub fn records(&self) -> impl Iterator<Item = &'a FitDataRecord> {
+        self.records.iter().filter(|r| is_record_message(r))
+    }
+
+    /// The laps of the activity in order, laps without a usable time window are skipped
+    pub fn laps(&self) -> impl Iterator<Item = Segment<'a>> {
+        self.segments(MesgNum::Lap).into_iter()
+    }
+
+    /// The sessions of the activity in order, sessions without a usable time window are skipped
+    pub fn sessions(&self) -> impl Iterator<Item = Segment<'a>> {
+        self.segments(MesgNum::Session).into_iter()
+    }
+
+    fn segments(&self, kind: MesgNum) -> Vec<Segment<'a>> {
+        let windows: Vec<(&'a FitDataRecord, DateTime<Local>, DateTime<Local>)> = self
+            .records
+            .iter()
+            .filter(|r| r.is_kind(kind))
+            .filter_map(|r| time_window(r).map(|(start, end)| (r, start, end)))
+            .collect();
+        windows
+            .iter()
+            .map(|(summary, start, end)| {
+                let mut segment = Segment {
+                    summary,
+                    messages: &self.records[..0],
+                    start: *start,
+                    end: *end,
+                    // a window ending where another starts leaves the shared sample to the later
+                    end_inclusive: !windows.iter().any(|(_, s, _)| s == end),
+                };
+                let in_window = |r: &FitDataRecord| is_record_message(r) && segment.contains(r);
+                let first = self.records.iter().position(in_window);
+                let last = self.records.iter().rposition(in_window);
+                if let (Some(first), Some(last)) = (first, last) {
+                    segment.messages = &self.records[first..=last];
+                }
+                segment
+            })
+            .collect()
+    }
+}
+
+/// Time window of a lap or session from its start time and either its end timestamp or the
+/// elapsed time, whichever ends later
+fn time_window(summary: &FitDataRecord) -> Option<(DateTime<Local>, DateTime<Local>)> {
+    let start = match summary.field("start_time").map(|f| f.value()) {
+        Some(Value::Timestamp(start)) => to_chrono(start),
+        _ => return None,
+    };
+    let elapsed = field_as_f64(summary, "total_elapsed_time")
+        .map(|s| start + Duration::milliseconds((s * 1000.0) as i64));
+    let end = match (record_time(summary), elapsed) {
+        (Some(a), Some(b)) => a.max(b),
+        (a, b) => a.or(b)?,
+    };
+    Some((start, end))
+}
+
+impl<'a> Segment<'a> {
+    /// The `lap` or `session` message holding the totals of the segment
+    pub fn summary(&self) -> &'a FitDataRecord {
+        self.summary
+    }
+
+    /// Start of the time window
+    pub fn start_time(&self) -> DateTime<Local> {
+        self.start
+    }
+
+    /// End of the time window
+    pub fn end_time(&self) -> DateTime<Local> {
+        self.end
+    }
+
+    /// Contiguous slice of messages from the first to the last `record` message of the window,
+    /// this includes any other messages, such as events, recorded in between
+    pub fn messages(&self) -> &'a [FitDataRecord] {
+        self.messages
+    }
+
+    /// Iterate over the `record` messages inside the time window
+    pub fn records(&self) -> impl Iterator<Item = &'a FitDataRecord> + 'a {
+        let segment = *self;
+        self.messages
+            .iter()
+            .filter(move |r| is_record_message(r) && segment.contains(r))
+    }
+
+    /// Check if the timestamp of the message falls inside the time window
+    fn contains(&self, record: &FitDataRecord) -> bool {
+        record_time(record).is_some_and(|t| {
+            t >= self.start && (t < self.end || (self.end_inclusive && t == self.end))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::timestamp::from_chrono;
+    use chrono::TimeZone;
+
+    #[test]
+    fn split_records_by_lap() {
+        let t0 = Local.timestamp_opt(1_600_000_000, 0).unwrap();
+        let at = |s: i64| Value::Timestamp(from_chrono(t0 + Duration::seconds(s)));
+        let mut records: Vec<FitDataRecord> = (0..=10)
+            .map(|s| fixtures::record(MesgNum::Record, &[("timestamp", at(s))]))
+            .collect();
+        records.insert(
+            6,
+            fixtures::record(MesgNum::Lap, &[("timestamp", at(5)), ("start_time", at(0))]),
+        );
+        records.push(fixtures::record(
+            MesgNum::Lap,
+            &[
+                ("start_time", at(5)),
+                ("total_elapsed_time", Value::Float64(5.0)),
+            ],
+        ));
+        records.push(fixtures::record(
+            MesgNum::Session,
+            &[("timestamp", at(10)), ("start_time", at(0))],
+        ));
+
+        let activity = Activity::new(&records);
+        let laps: Vec<usize> = activity.laps().map(|lap| lap.records().count()).collect();
+        assert_eq!(laps, [5, 6]);
+        let session = activity.sessions().next().unwrap();
+        assert_eq!(session.records().count(), 11);
+        assert_eq!(session.summary().kind(), MesgNum::Session);
+        assert_eq!(activity.laps().next().unwrap().messages().len(), 5);
+    }
+}
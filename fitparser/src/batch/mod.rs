@@ -0,0 +1,344 @@
+//! Bulk processing of many FIT files at once.
+//!
+//! File access goes through the `Storage` trait so the same batch operations work against a
+//! local directory, an in-memory collection or a user provided object store client that streams
+//! objects without touching the disk. Directories are converted and lists of files decoded on the
+//! `rayon` thread pool with the `parallel` feature and on scoped threads without it, or from an
+//! async stream of buffers with the `async` feature.
+use crate::de::{from_reader_with_options, DecodeOption, DecoderContext};
+use crate::error::Result;
+use crate::export::Format;
+use crate::FitDataRecord;
+use std::collections::{BTreeMap, HashSet};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+#[cfg(feature = "async")]
+mod stream;
+#[cfg(feature = "async")]
+pub use stream::{decode_stream, DecodeStream};
+
+/// A source of FIT files addressed by string keys, e.g. relative paths or object store keys
+pub trait Storage {
+    /// List the keys of every FIT file available in the storage, sorted
+    fn list(&self) -> Result<Vec<String>>;
+
+    /// Open a reader for the contents of a FIT file
+    fn open(&self, key: &str) -> Result<Box<dyn Read + '_>>;
+
+    /// Last modification time of a FIT file if the storage tracks it, used to skip unchanged
+    /// files when re-processing
+    fn modified(&self, _key: &str) -> Result<Option<SystemTime>> {
+        Ok(None)
+    }
+}
+
+/// Storage backed by a directory on the local file system, files with a `.fit` extension are
+/// listed using their path relative to the root with `/` separators as key
+#[derive(Clone, Debug)]
+pub struct LocalStorage {
+    root: PathBuf,
+    recursive: bool,
+}
+
+impl LocalStorage {
+    /// Create a storage for the FIT files directly inside the directory
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        LocalStorage {
+            root: root.as_ref().to_path_buf(),
+            recursive: false,
+        }
+    }
+
+    /// Also include FIT files located in sub directories
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Root directory of the storage
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Full path of the file stored under the key
+    pub fn path(&self, key: &str) -> PathBuf {
+        key.split('/')
+            .fold(self.root.clone(), |p, part| p.join(part))
+    }
+
+    fn visit(&self, dir: &Path, keys: &mut Vec<String>) -> Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                if self.recursive {
+                    self.visit(&path, keys)?;
+                }
+                continue;
+            }
+            let is_fit = path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("fit"));
+            if let (true, Ok(relative)) = (is_fit, path.strip_prefix(&self.root)) {
+                let parts: Vec<String> = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                    .collect();
+                keys.push(parts.join("/"));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Storage for LocalStorage {
+    fn list(&self) -> Result<Vec<String>> {
+        let mut keys = Vec::new();
+        self.visit(&self.root, &mut keys)?;
+        keys.sort();
+        Ok(keys)
+    }
+
+    fn open(&self, key: &str) -> Result<Box<dyn Read + '_>> {
+        Ok(Box::new(BufReader::new(File::open(self.path(key))?)))
+    }
+
+    fn modified(&self, key: &str) -> Result<Option<SystemTime>> {
+        Ok(Some(fs::metadata(self.path(key))?.modified()?))
+    }
+}
+
+/// In memory storage, useful for tests or data that has already been downloaded
+impl Storage for BTreeMap<String, Vec<u8>> {
+    fn list(&self) -> Result<Vec<String>> {
+        Ok(self.keys().cloned().collect())
+    }
+
+    fn open(&self, key: &str) -> Result<Box<dyn Read + '_>> {
+        match self.get(key) {
+            Some(data) => Ok(Box::new(data.as_slice())),
+            None => Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("no FIT file stored under {}", key),
+            )
+            .into()),
+        }
+    }
+}
+
+/// Decode every file in the storage, calling `f` with the key and the decode result of each file
+/// in turn so only a single file needs to be held in memory. The files are decoded with a shared
+/// `DecoderContext`. Failing to list the storage is an error, failures for individual files are
+/// passed along to `f`.
+pub fn for_each_file<S, F>(storage: &S, options: &HashSet<DecodeOption>, mut f: F) -> Result<()>
+where
+    S: Storage + ?Sized,
+    F: FnMut(&str, Result<Vec<FitDataRecord>>),
+{
+    let mut context = DecoderContext::new(options);
+    for key in storage.list()? {
+        let result = storage
+            .open(&key)
+            .and_then(|mut reader| context.decode_reader(&mut reader));
+        f(&key, result);
+    }
+    Ok(())
+}
+
+/// Decode every file in the storage, returning the key and decode result of each
+pub fn decode_all<S: Storage + ?Sized>(
+    storage: &S,
+    options: &HashSet<DecodeOption>,
+) -> Result<Vec<(String, Result<Vec<FitDataRecord>>)>> {
+    let mut results = Vec::new();
+    for_each_file(storage, options, |key, result| {
+        results.push((key.to_string(), result))
+    })?;
+    Ok(results)
+}
+
+/// Outcome of converting a single file with `convert_dir`
+#[derive(Debug)]
+pub struct Conversion {
+    /// Key of the input file relative to the input directory
+    pub key: String,
+    /// Path of the converted file
+    pub output: PathBuf,
+    /// Error decoding the input or writing the output, the output may be incomplete on error
+    pub result: Result<()>,
+}
+
+fn convert_file(
+    storage: &LocalStorage,
+    key: &str,
+    output: &Path,
+    format: Format,
+    options: &HashSet<DecodeOption>,
+) -> Result<()> {
+    let records = from_reader_with_options(&mut storage.open(key)?, options)?;
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut writer = BufWriter::new(File::create(output)?);
+    format.write(&records, &mut writer)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Convert every FIT file in the input directory and its sub directories into the format,
+/// writing the results to the same relative paths inside the output directory with the
+/// extension of the format. Files are converted in parallel and the outcome of every file is
+/// returned in key order, failing to list the input directory is an error.
+pub fn convert_dir<P: AsRef<Path>, Q: AsRef<Path>>(
+    in_dir: P,
+    out_dir: Q,
+    format: Format,
+) -> Result<Vec<Conversion>> {
+    convert_dir_with_options(in_dir, out_dir, format, &HashSet::new())
+}
+
+/// Convert every FIT file in the input directory using the given decode options, see
+/// `convert_dir`
+pub fn convert_dir_with_options<P: AsRef<Path>, Q: AsRef<Path>>(
+    in_dir: P,
+    out_dir: Q,
+    format: Format,
+    options: &HashSet<DecodeOption>,
+) -> Result<Vec<Conversion>> {
+    let storage = LocalStorage::new(in_dir).recursive(true);
+    let out_dir = out_dir.as_ref();
+    let keys = storage.list()?;
+    Ok(map_files(&keys, |key| {
+        let output = key
+            .split('/')
+            .fold(out_dir.to_path_buf(), |p, part| p.join(part))
+            .with_extension(format.extension());
+        let result = convert_file(&storage, key, &output, format, options);
+        Conversion {
+            key: key.clone(),
+            output,
+            result,
+        }
+    }))
+}
+
+/// Decode the FIT files at the paths in parallel, returning the decode result of every file in the
+/// order of the paths. A file that can't be read or decoded only fails its own entry.
+pub fn decode_files<P: AsRef<Path> + Sync>(
+    paths: &[P],
+    options: &HashSet<DecodeOption>,
+) -> Vec<Result<Vec<FitDataRecord>>> {
+    map_files(paths, |path| {
+        let mut reader = BufReader::new(File::open(path)?);
+        from_reader_with_options(&mut reader, options)
+    })
+}
+
+/// Apply `f` to every item on the `rayon` thread pool, the results are returned in the order of
+/// the items
+#[cfg(feature = "parallel")]
+fn map_files<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    use rayon::prelude::*;
+    items.par_iter().map(&f).collect()
+}
+
+/// Apply `f` to every item on scoped threads, one contiguous chunk of the items per available
+/// core, the results are returned in the order of the items
+#[cfg(not(feature = "parallel"))]
+fn map_files<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = items.len().div_ceil(threads).max(1);
+    let f = &f;
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(f).collect::<Vec<_>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_local_and_memory_storage() {
+        let local = LocalStorage::new("tests/fixtures");
+        let keys = local.list().unwrap();
+        assert!(keys.contains(&"Activity.fit".to_string()));
+
+        let mut memory = BTreeMap::new();
+        let mut data = Vec::new();
+        local
+            .open("Activity.fit")
+            .unwrap()
+            .read_to_end(&mut data)
+            .unwrap();
+        let truncated = data[..20].to_vec();
+        memory.insert("a/Activity.fit".to_string(), data);
+        memory.insert("b/truncated.fit".to_string(), truncated);
+
+        let results = decode_all(&memory, &HashSet::new()).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].1.as_ref().unwrap().len(), 22);
+        assert!(results[1].1.is_err());
+    }
+
+    #[test]
+    fn convert_directory() {
+        let tmp = std::env::temp_dir().join(format!("fitparser-convert-{}", std::process::id()));
+        let (in_dir, out_dir) = (tmp.join("in"), tmp.join("out"));
+        fs::create_dir_all(in_dir.join("2021")).unwrap();
+        fs::copy(
+            "tests/fixtures/Activity.fit",
+            in_dir.join("2021").join("activity.fit"),
+        )
+        .unwrap();
+        fs::write(in_dir.join("broken.fit"), [0x0e, 0x10]).unwrap();
+
+        let results = convert_dir(&in_dir, &out_dir, Format::Gpx).unwrap();
+        let keys: Vec<&str> = results.iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(keys, ["2021/activity.fit", "broken.fit"]);
+        assert!(results[0].result.is_ok());
+        assert!(results[1].result.is_err());
+        let gpx = fs::read_to_string(out_dir.join("2021").join("activity.gpx")).unwrap();
+        assert!(gpx.contains("<trkseg>"));
+        fs::remove_dir_all(tmp).unwrap();
+    }
+
+    #[test]
+    fn decode_file_list() {
+        let paths = [
+            "tests/fixtures/Activity.fit",
+            "tests/fixtures/missing.fit",
+            "tests/fixtures/Settings.fit",
+            "tests/fixtures/Activity.fit",
+        ];
+        let results = decode_files(&paths, &HashSet::new());
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap().len(), 22);
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+        assert_eq!(results[3].as_ref().unwrap().len(), 22);
+    }
+}
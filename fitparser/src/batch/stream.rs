@@ -0,0 +1,112 @@
+//! Decode FIT files arriving as a `futures_core::Stream` of buffers, e.g. the uploads of an
+//! ingestion service, without a thread pool.
+use crate::de::{files_from_bytes_with_options, DecodeOption, FitFile};
+use crate::error::Result;
+use futures_core::Stream;
+use std::collections::HashSet;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Stream of the files decoded from a stream of buffers, created by `decode_stream`
+pub struct DecodeStream<S> {
+    source: S,
+    options: HashSet<DecodeOption>,
+}
+
+impl<S> DecodeStream<S> {
+    /// Return the underlying stream of buffers
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, B> Stream for DecodeStream<S>
+where
+    S: Stream<Item = B> + Unpin,
+    B: AsRef<[u8]>,
+{
+    type Item = Result<Vec<FitFile>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        match Pin::new(&mut this.source).poll_next(cx) {
+            Poll::Ready(Some(buffer)) => Poll::Ready(Some(files_from_bytes_with_options(
+                buffer.as_ref(),
+                &this.options,
+            ))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+/// Decode every buffer of a stream, e.g. of `bytes::Bytes`, holding a whole FIT file each.
+///
+/// Each buffer yields a result with the files it holds, more than one for chained files. Buffers
+/// are decoded on the task polling the returned stream, one at a time and only when the next
+/// result is requested, so a slow consumer holds back the source and no more than one buffer is
+/// decoded at any time. Streams that aren't `Unpin` can be pinned with `Box::pin`.
+pub fn decode_stream<S, B>(source: S, options: &HashSet<DecodeOption>) -> DecodeStream<S>
+where
+    S: Stream<Item = B> + Unpin,
+    B: AsRef<[u8]>,
+{
+    DecodeStream {
+        source,
+        options: options.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+    use std::task::Waker;
+
+    /// Stream of buffers counting how many were taken from it
+    struct Buffers {
+        buffers: Vec<Vec<u8>>,
+        taken: usize,
+    }
+
+    impl Stream for Buffers {
+        type Item = Vec<u8>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Vec<u8>>> {
+            let next = self.buffers.get(self.taken).cloned();
+            self.taken += next.is_some() as usize;
+            Poll::Ready(next)
+        }
+    }
+
+    #[test]
+    fn decode_buffer_stream() {
+        let activity = include_bytes!("../../tests/fixtures/Activity.fit").to_vec();
+        let chained = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit").to_vec();
+        let source = Buffers {
+            buffers: vec![activity.clone(), b"not a fit file".to_vec(), chained],
+            taken: 0,
+        };
+        let mut stream = decode_stream(source, &HashSet::new());
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut next = || match Pin::new(&mut stream).poll_next(&mut cx) {
+            Poll::Ready(next) => next,
+            Poll::Pending => panic!("source is always ready"),
+        };
+
+        let files = next().unwrap().unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(
+            files[0].records().len(),
+            from_bytes(&activity).unwrap().len()
+        );
+        assert!(next().unwrap().is_err());
+        assert!(next().unwrap().unwrap().len() > 1);
+        assert!(next().is_none());
+        assert_eq!(stream.into_inner().taken, 3);
+    }
+}
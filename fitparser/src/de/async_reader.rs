@@ -0,0 +1,137 @@
+//! Decode FIT files read from a `tokio::io::AsyncRead` source without blocking the executor,
+//! e.g. uploads arriving over the network.
+use super::stream::{StreamDecoder, CHUNK_SIZE};
+use super::{from_bytes_with_options, read_error, DecodeOption};
+use crate::error::Result;
+use crate::FitDataRecord;
+use std::collections::HashSet;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Deserialize a FIT file read from an async source, with additional decode options. The whole
+/// file is read into memory before it's decoded, `FitAsyncStreamReader` decodes records as the
+/// bytes arrive.
+pub async fn from_async_reader_with_options<T: AsyncRead + Unpin>(
+    source: &mut T,
+    options: &HashSet<DecodeOption>,
+) -> Result<Vec<FitDataRecord>> {
+    let mut buffer = Vec::new();
+    if let Err(e) = source.read_to_end(&mut buffer).await {
+        return Err(read_error(e, buffer.len()));
+    }
+    from_bytes_with_options(&buffer, options)
+}
+
+/// Deserialize a FIT file read from an async source.
+pub async fn from_async_reader<T: AsyncRead + Unpin>(source: &mut T) -> Result<Vec<FitDataRecord>> {
+    from_async_reader_with_options(source, &HashSet::new()).await
+}
+
+/// Decode the records of a FIT stream read from a `tokio::io::AsyncRead` source as they arrive.
+///
+/// This is the async counterpart of `FitStreamReader`, the source is read in chunks as records
+/// are requested and only the bytes that haven't been decoded yet are kept in memory. Chained
+/// files are decoded one after the other. No more records are returned after the first error.
+pub struct FitAsyncStreamReader<R: AsyncRead + Unpin> {
+    source: R,
+    decoder: StreamDecoder,
+    failed: bool,
+}
+
+impl<R: AsyncRead + Unpin> FitAsyncStreamReader<R> {
+    /// Create a reader decoding the source with the default options
+    pub fn new(source: R) -> Self {
+        Self::with_options(source, &HashSet::new())
+    }
+
+    /// Create a reader decoding the source with additional decode options
+    pub fn with_options(source: R, options: &HashSet<DecodeOption>) -> Self {
+        FitAsyncStreamReader {
+            source,
+            decoder: StreamDecoder::new(options),
+            failed: false,
+        }
+    }
+
+    /// Return the underlying source, any bytes read ahead of the last record are lost
+    pub fn into_inner(self) -> R {
+        self.source
+    }
+
+    /// Decode the next record, `None` once the source ends or after an error was returned
+    pub async fn next_record(&mut self) -> Option<Result<FitDataRecord>> {
+        if self.failed {
+            return None;
+        }
+        let next = self.decode_next().await;
+        self.failed = next.is_err();
+        next.transpose()
+    }
+
+    /// Decode every remaining record
+    pub async fn collect_records(mut self) -> Result<Vec<FitDataRecord>> {
+        let mut records = Vec::new();
+        while let Some(record) = self.next_record().await {
+            records.push(record?);
+        }
+        Ok(records)
+    }
+
+    async fn decode_next(&mut self) -> Result<Option<FitDataRecord>> {
+        let mut chunk = [0; CHUNK_SIZE];
+        loop {
+            while self.decoder.needs_input() {
+                let n = match self.source.read(&mut chunk).await {
+                    Ok(n) => n,
+                    Err(e) => return Err(read_error(e, self.decoder.bytes_read())),
+                };
+                self.decoder.push_input(&chunk[..n]);
+            }
+            if !self.decoder.has_input() {
+                return Ok(None);
+            }
+            if let Some(record) = self.decoder.decode_next()? {
+                return Ok(Some(record));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    /// Run a future reading from a slice, which is always ready so no runtime is needed
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn async_records() {
+        let data = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit");
+        let expected = serde_json::to_value(from_bytes(data).unwrap()).unwrap();
+        let records = block_on(from_async_reader(&mut &data[..])).unwrap();
+        assert_eq!(serde_json::to_value(&records).unwrap(), expected);
+
+        let reader = FitAsyncStreamReader::new(&data[..]);
+        let records = block_on(reader.collect_records()).unwrap();
+        assert_eq!(serde_json::to_value(&records).unwrap(), expected);
+
+        let mut reader = FitAsyncStreamReader::new(&data[..data.len() / 2]);
+        let mut decoded = 0;
+        while let Some(Ok(_)) = block_on(reader.next_record()) {
+            decoded += 1;
+        }
+        assert!(decoded > 0);
+        assert!(block_on(reader.next_record()).is_none());
+    }
+}
@@ -0,0 +1,425 @@
+//! Decode a FIT file without copying its strings and byte arrays.
+//!
+//! Decoding into `FitDataRecord`s allocates a `String` for every string, field name and enum
+//! variant, which dominates the time spent on large batches of files. The records returned by
+//! `from_bytes_borrowed` instead borrow strings and byte arrays from the input buffer and name
+//! fields with the static names of the profile. Values are kept as they are stored in the file,
+//! `FitDataField::to_value` applies the scale, offset and enum names of the profile when needed.
+//! Components aren't expanded, subfields and accumulated fields aren't resolved and developer
+//! fields are returned as their raw bytes, use `from_bytes` for a fully decoded file.
+use super::parser::{borrowed_data_message, BorrowedDataMessage};
+use super::{Deserializer, FitObject};
+use crate::error::Result;
+use crate::profile::{convert_value, field_info, field_name, FieldInfo, MesgNum};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// A value as stored in the file, strings and byte arrays borrow from the decoded buffer
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value<'a> {
+    /// Enum base type
+    Enum(u8),
+    /// Signed 8 bit integer
+    SInt8(i8),
+    /// Unsigned 8 bit integer
+    UInt8(u8),
+    /// Signed 16 bit integer
+    SInt16(i16),
+    /// Unsigned 16 bit integer
+    UInt16(u16),
+    /// Signed 32 bit integer
+    SInt32(i32),
+    /// Unsigned 32 bit integer
+    UInt32(u32),
+    /// String up to its NUL terminator
+    String(&'a str),
+    /// 32 bit float
+    Float32(f32),
+    /// 64 bit float
+    Float64(f64),
+    /// Unsigned 8 bit integer with zero as its invalid value
+    UInt8z(u8),
+    /// Unsigned 16 bit integer with zero as its invalid value
+    UInt16z(u16),
+    /// Unsigned 32 bit integer with zero as its invalid value
+    UInt32z(u32),
+    /// Bytes of a byte field
+    Byte(&'a [u8]),
+    /// Signed 64 bit integer
+    SInt64(i64),
+    /// Unsigned 64 bit integer
+    UInt64(u64),
+    /// Unsigned 64 bit integer with zero as its invalid value
+    UInt64z(u64),
+    /// Values of an array field or a field listed more than once
+    Array(Vec<Value<'a>>),
+}
+
+impl Value<'_> {
+    /// Copy the value into an owned value, byte arrays become arrays of `UInt8` values like
+    /// `from_bytes` decodes them
+    pub fn to_owned_value(&self) -> crate::Value {
+        match self {
+            Value::Enum(val) => crate::Value::Enum(*val),
+            Value::SInt8(val) => crate::Value::SInt8(*val),
+            Value::UInt8(val) => crate::Value::UInt8(*val),
+            Value::SInt16(val) => crate::Value::SInt16(*val),
+            Value::UInt16(val) => crate::Value::UInt16(*val),
+            Value::SInt32(val) => crate::Value::SInt32(*val),
+            Value::UInt32(val) => crate::Value::UInt32(*val),
+            Value::String(val) => crate::Value::String(val.to_string()),
+            Value::Float32(val) => crate::Value::Float32(*val),
+            Value::Float64(val) => crate::Value::Float64(*val),
+            Value::UInt8z(val) => crate::Value::UInt8z(*val),
+            Value::UInt16z(val) => crate::Value::UInt16z(*val),
+            Value::UInt32z(val) => crate::Value::UInt32z(*val),
+            Value::Byte([byte]) => crate::Value::UInt8(*byte),
+            Value::Byte(bytes) => {
+                crate::Value::Array(bytes.iter().map(|b| crate::Value::UInt8(*b)).collect())
+            }
+            Value::SInt64(val) => crate::Value::SInt64(*val),
+            Value::UInt64(val) => crate::Value::UInt64(*val),
+            Value::UInt64z(val) => crate::Value::UInt64z(*val),
+            Value::Array(vals) => {
+                crate::Value::Array(vals.iter().map(Value::to_owned_value).collect())
+            }
+        }
+    }
+
+    /// Integer value, `None` for strings, floats and arrays
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Enum(val) | Value::UInt8(val) | Value::UInt8z(val) => Some(*val as i64),
+            Value::SInt8(val) => Some(*val as i64),
+            Value::SInt16(val) => Some(*val as i64),
+            Value::UInt16(val) | Value::UInt16z(val) => Some(*val as i64),
+            Value::SInt32(val) => Some(*val as i64),
+            Value::UInt32(val) | Value::UInt32z(val) => Some(*val as i64),
+            Value::SInt64(val) => Some(*val),
+            Value::UInt64(val) | Value::UInt64z(val) => (*val).try_into().ok(),
+            Value::Byte([byte]) => Some(*byte as i64),
+            _ => None,
+        }
+    }
+}
+
+impl From<crate::Value> for Value<'_> {
+    /// Convert the numeric values returned by the parser, strings and bytes are parsed into
+    /// borrowed values directly
+    fn from(value: crate::Value) -> Self {
+        match value {
+            crate::Value::Enum(val) => Value::Enum(val),
+            crate::Value::SInt8(val) => Value::SInt8(val),
+            crate::Value::UInt8(val) => Value::UInt8(val),
+            crate::Value::SInt16(val) => Value::SInt16(val),
+            crate::Value::UInt16(val) => Value::UInt16(val),
+            crate::Value::SInt32(val) => Value::SInt32(val),
+            crate::Value::UInt32(val) => Value::UInt32(val),
+            crate::Value::Float32(val) => Value::Float32(val),
+            crate::Value::Float64(val) => Value::Float64(val),
+            crate::Value::UInt8z(val) => Value::UInt8z(val),
+            crate::Value::UInt16z(val) => Value::UInt16z(val),
+            crate::Value::UInt32z(val) => Value::UInt32z(val),
+            crate::Value::SInt64(val) => Value::SInt64(val),
+            crate::Value::UInt64(val) => Value::UInt64(val),
+            crate::Value::UInt64z(val) => Value::UInt64z(val),
+            crate::Value::Byte(val) => Value::UInt8(val),
+            crate::Value::Array(vals) => Value::Array(vals.into_iter().map(Value::from).collect()),
+            crate::Value::String(_)
+            | crate::Value::Timestamp(_)
+            | crate::Value::UtcTimestamp(_)
+            | crate::Value::LocalTimestamp(_)
+            | crate::Value::Duration(_) => {
+                unreachable!("the parser only returns numeric values for numeric base types")
+            }
+        }
+    }
+}
+
+impl fmt::Display for Value<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::String(val) => write!(f, "{}", val),
+            _ => write!(f, "{}", self.to_owned_value()),
+        }
+    }
+}
+
+/// A field of a message borrowing its value from the decoded buffer
+#[derive(Clone, Debug)]
+pub struct FitDataField<'a> {
+    number: u8,
+    info: Option<(&'static str, FieldInfo)>,
+    value: Value<'a>,
+}
+
+/// Name and profile information of a field, `None` for fields the profile doesn't define
+type ResolvedField = Option<(&'static str, FieldInfo)>;
+
+/// Profile information of the fields resolved so far, by global message number and field
+/// definition number. The profile looks fields up by matching their names, resolving each
+/// (message, field) pair once keeps that out of the loop over the fields of every message.
+#[derive(Clone, Debug, Default)]
+pub(super) struct FieldInfoCache {
+    messages: HashMap<u16, Box<[Option<ResolvedField>]>>,
+}
+
+impl FieldInfoCache {
+    /// Fields of a message kind, filled in as they are resolved
+    fn message(&mut self, global_message_number: u16) -> &mut [Option<ResolvedField>] {
+        self.messages
+            .entry(global_message_number)
+            .or_insert_with(|| vec![None; 256].into_boxed_slice())
+    }
+
+    /// Number of (message, field) pairs resolved
+    pub(super) fn len(&self) -> usize {
+        self.messages
+            .values()
+            .map(|fields| fields.iter().filter(|f| f.is_some()).count())
+            .sum()
+    }
+}
+
+/// Look up the profile information of a field
+fn resolve_field(kind: MesgNum, number: u8) -> ResolvedField {
+    field_name(kind, number).and_then(|name| Some((name, field_info(kind, name)?)))
+}
+
+impl<'a> FitDataField<'a> {
+    fn new(number: u8, info: ResolvedField, value: Value<'a>) -> Self {
+        FitDataField {
+            number,
+            info,
+            value,
+        }
+    }
+
+    /// Field definition number
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Name of the field in the profile, `None` for fields the profile doesn't define.
+    /// Subfields aren't resolved so this is the name of the field they are part of.
+    pub fn name(&self) -> Option<&'static str> {
+        self.info.map(|(name, _)| name)
+    }
+
+    /// Profile information of the field
+    pub fn info(&self) -> Option<&FieldInfo> {
+        self.info.as_ref().map(|(_, info)| info)
+    }
+
+    /// Units of the value after applying the profile, empty for unknown fields
+    pub fn units(&self) -> &'static str {
+        self.info.map(|(_, info)| info.units).unwrap_or_default()
+    }
+
+    /// Value as stored in the file
+    pub fn value(&self) -> &Value<'a> {
+        &self.value
+    }
+
+    /// Check if the profile doesn't define the field
+    pub fn is_unknown(&self) -> bool {
+        self.info.is_none()
+    }
+
+    /// Apply the profile to the value, i.e. its scale and offset, the name of enum variants and
+    /// the conversion of timestamps. Values of unknown fields are copied as they are.
+    pub fn to_value(&self) -> Result<crate::Value> {
+        let value = self.value.to_owned_value();
+        match self.info {
+            Some((_, info)) => convert_value(
+                info.field_type,
+                info.scale,
+                info.offset,
+                value,
+                &HashSet::new(),
+            ),
+            None => Ok(value),
+        }
+    }
+}
+
+/// Developer field stored as the raw bytes of the file
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeveloperField<'a> {
+    /// Index of the developer data id message that defines the field
+    pub developer_data_index: u8,
+    /// Field number within the developer data set, maps to a field description message
+    pub field_number: u8,
+    /// Raw bytes of the field, in the byte order of the definition message
+    pub bytes: &'a [u8],
+}
+
+/// A data message borrowing its strings and byte arrays from the decoded buffer
+#[derive(Clone, Debug)]
+pub struct FitDataRecord<'a> {
+    kind: MesgNum,
+    fields: Vec<FitDataField<'a>>,
+    developer_fields: Vec<DeveloperField<'a>>,
+}
+
+impl<'a> FitDataRecord<'a> {
+    /// Global message of the record
+    pub fn kind(&self) -> MesgNum {
+        self.kind
+    }
+
+    /// Check if the record is the global message
+    pub fn is_kind(&self, kind: MesgNum) -> bool {
+        self.kind == kind
+    }
+
+    /// Fields in the order of the definition message, the timestamp of a compressed timestamp
+    /// header comes last and is stored as seconds since the FIT epoch like any other timestamp
+    pub fn fields(&self) -> &[FitDataField<'a>] {
+        &self.fields
+    }
+
+    /// First field with the profile name
+    pub fn field(&self, name: &str) -> Option<&FitDataField<'a>> {
+        self.fields.iter().find(|f| f.name() == Some(name))
+    }
+
+    /// First field with the definition number
+    pub fn field_by_number(&self, number: u8) -> Option<&FitDataField<'a>> {
+        self.fields.iter().find(|f| f.number == number)
+    }
+
+    /// Developer fields in the order of the definition message
+    pub fn developer_fields(&self) -> &[DeveloperField<'a>] {
+        &self.developer_fields
+    }
+}
+
+impl Deserializer {
+    /// Parse the next data message borrowing from the input, `None` is returned for the header,
+    /// definition messages and the CRC which are parsed by `deserialize_next`
+    fn deserialize_borrowed<'de>(
+        &mut self,
+        input: &'de [u8],
+    ) -> Result<(&'de [u8], Option<BorrowedDataMessage<'de>>)> {
+        if self.position == 0 || self.position >= self.end_of_messages {
+            return Ok((input, None));
+        }
+        let (remaining, message) = borrowed_data_message(input, &self.definitions)
+            .map_err(|e| self.to_parse_err(input, Some(self.message_context(input)), e))?;
+        if message.is_some() {
+            let consumed = input.len() - remaining.len();
+            self.update_crc(&input[..consumed]);
+            self.position += consumed;
+        }
+        Ok((remaining, message))
+    }
+}
+
+/// Deserialize a FIT file stored as an array of bytes into records borrowing from it. Chained
+/// files are decoded one after the other and both checksums are validated.
+pub fn from_bytes_borrowed(buffer: &[u8]) -> Result<Vec<FitDataRecord<'_>>> {
+    decode_borrowed(buffer, &mut FieldInfoCache::default())
+}
+
+/// Decode the records borrowing from the buffer, looking the fields up through the cache
+pub(super) fn decode_borrowed<'a>(
+    mut buffer: &'a [u8],
+    cache: &mut FieldInfoCache,
+) -> Result<Vec<FitDataRecord<'a>>> {
+    let mut deserializer = Deserializer::new();
+    let mut records = Vec::new();
+    let mut timestamp: i64 = 0;
+    while !buffer.is_empty() {
+        let (buf, message) = deserializer.deserialize_borrowed(buffer)?;
+        let message = match message {
+            Some(message) => message,
+            None => {
+                let (buf, obj) = deserializer.deserialize_next(buffer)?;
+                if let FitObject::Crc(..) = obj {
+                    deserializer.reset();
+                    timestamp = 0;
+                }
+                buffer = buf;
+                continue;
+            }
+        };
+        buffer = buf;
+
+        let kind = MesgNum::from(message.global_message_number);
+        let known = cache.message(message.global_message_number);
+        let mut info =
+            |number: u8| *known[number as usize].get_or_insert_with(|| resolve_field(kind, number));
+        let mut fields: Vec<FitDataField> = Vec::with_capacity(message.fields.len() + 1);
+        for (number, value) in message.fields {
+            if number == 253 {
+                timestamp = value.as_i64().unwrap_or(0);
+            }
+            fields.push(FitDataField::new(number, info(number), value));
+        }
+        // a compressed timestamp header stores the lower 5 bits of the time
+        if let Some(offset) = message.time_offset {
+            let offset = offset as i64;
+            let mut time = offset + (timestamp & !0x1F);
+            if offset < (timestamp & 0x1F) {
+                time += 0x20;
+            }
+            timestamp = time;
+            fields.push(FitDataField::new(
+                253,
+                info(253),
+                Value::UInt32(time as u32),
+            ));
+        }
+        let developer_fields = message
+            .developer_fields
+            .into_iter()
+            .map(|(def, bytes)| DeveloperField {
+                developer_data_index: def.developer_data_index(),
+                field_number: def.field_number(),
+                bytes,
+            })
+            .collect();
+        records.push(FitDataRecord {
+            kind,
+            fields,
+            developer_fields,
+        });
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrowed_records() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let borrowed = from_bytes_borrowed(data).unwrap();
+        let owned = crate::from_bytes(data).unwrap();
+        assert_eq!(borrowed.len(), owned.len());
+
+        // strings point into the input
+        let name = borrowed
+            .iter()
+            .flat_map(|r| r.fields())
+            .find_map(|f| match f.value() {
+                Value::String(name) => Some(*name),
+                _ => None,
+            })
+            .unwrap();
+        assert!(data.as_ptr_range().contains(&name.as_ptr()));
+
+        // applying the profile gives the values of the decoder
+        for (borrowed, owned) in borrowed.iter().zip(&owned) {
+            assert_eq!(borrowed.kind(), owned.kind());
+            for name in ["timestamp", "heart_rate", "altitude", "manufacturer"] {
+                if let (Some(field), Some(expected)) = (borrowed.field(name), owned.field(name)) {
+                    assert_eq!(&field.to_value().unwrap(), expected.value());
+                    assert_eq!(field.units(), expected.units());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Decode chained FIT files, several files stored back to back in a single stream, into one
+//! `FitFile` per file instead of a flat list of records.
+use super::{parser, process_object, DecodeOption, FitObject, FitStreamProcessor};
+use crate::error::Result;
+use crate::FitDataRecord;
+use std::collections::HashSet;
+
+/// A single file of a FIT stream along with the records decoded from it
+#[derive(Clone, Debug)]
+pub struct FitFile {
+    offset: usize,
+    header: parser::FitFileHeader,
+    records: Vec<FitDataRecord>,
+    crc: Option<u16>,
+}
+
+impl FitFile {
+    /// Position of the file header in the stream
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Header of the file
+    pub fn header(&self) -> &parser::FitFileHeader {
+        &self.header
+    }
+
+    /// Records decoded from the data messages of the file
+    pub fn records(&self) -> &[FitDataRecord] {
+        &self.records
+    }
+
+    /// Consume the file and return its records
+    pub fn into_records(self) -> Vec<FitDataRecord> {
+        self.records
+    }
+
+    /// CRC at the end of the file, `None` if the file was cut off before it
+    pub fn crc(&self) -> Option<u16> {
+        self.crc
+    }
+}
+
+/// Check if the stream holds more than one FIT file
+pub fn is_chained(buffer: &[u8]) -> bool {
+    parser::file_ranges(buffer).len() > 1
+}
+
+/// Deserialize each file of a FIT stream separately, with additional decode options. A stream
+/// without chained files returns a single file.
+pub fn files_from_bytes_with_options(
+    mut buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+) -> Result<Vec<FitFile>> {
+    let mut processor = FitStreamProcessor::new();
+    let mut files: Vec<FitFile> = Vec::new();
+    let length = buffer.len();
+
+    options.iter().for_each(|o| processor.add_option(*o));
+    while !buffer.is_empty() {
+        let offset = length - buffer.len();
+        let (buf, obj) = match processor.deserialize_next(buffer) {
+            Ok(next) => next,
+            Err(_) if processor.can_salvage() => break,
+            Err(e) => return Err(e),
+        };
+        match &obj {
+            FitObject::Header(header) => files.push(FitFile {
+                offset,
+                header: header.clone(),
+                records: Vec::new(),
+                crc: None,
+            }),
+            FitObject::Crc(crc) => {
+                if let Some(file) = files.last_mut() {
+                    file.crc = Some(*crc);
+                }
+            }
+            _ => {}
+        }
+        if let Some(rec) = process_object(&mut processor, obj)? {
+            if let Some(file) = files.last_mut() {
+                file.records.push(rec);
+            }
+        }
+        buffer = buf;
+    }
+
+    Ok(files)
+}
+
+/// Deserialize each file of a FIT stream separately
+pub fn files_from_bytes(buffer: &[u8]) -> Result<Vec<FitFile>> {
+    files_from_bytes_with_options(buffer, &HashSet::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+
+    #[test]
+    fn chained_files() {
+        let data = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit");
+        let ranges = parser::file_ranges(data);
+        assert!(is_chained(data));
+        let files = files_from_bytes(data).unwrap();
+        assert_eq!(files.len(), ranges.len());
+        for (file, range) in files.iter().zip(&ranges) {
+            assert_eq!(file.offset(), range.start);
+            assert!(file.crc().is_some());
+            let records = from_bytes(&data[range.clone()]).unwrap();
+            assert_eq!(
+                serde_json::to_value(file.records()).unwrap(),
+                serde_json::to_value(&records).unwrap()
+            );
+        }
+        let flattened: Vec<_> = files.into_iter().flat_map(FitFile::into_records).collect();
+        assert_eq!(
+            serde_json::to_value(&flattened).unwrap(),
+            serde_json::to_value(from_bytes(data).unwrap()).unwrap()
+        );
+
+        let single = include_bytes!("../../tests/fixtures/Activity.fit");
+        assert!(!is_chained(single));
+        assert_eq!(files_from_bytes(single).unwrap().len(), 1);
+    }
+}
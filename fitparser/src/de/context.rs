@@ -0,0 +1,197 @@
+//! Decode options resolved once and reused across many files, e.g. by a bulk import decoding
+//! thousands of files written by the same devices.
+use super::borrowed::{self, FieldInfoCache};
+use super::{decode_with_processor, read_stream, DecodeOption, FitStreamProcessor};
+use crate::error::Result;
+use crate::profile::MesgNum;
+use crate::FitDataRecord;
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+
+/// How the data messages of a kind are handled, resolved from the decode options
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct MessageHandling {
+    /// Requested by the `KeepMessage` options, other messages are skipped without decoding them
+    pub(super) requested: bool,
+    /// Returned once decoded, false for unknown messages with `DropUnknownMessages`
+    pub(super) returned: bool,
+}
+
+impl MessageHandling {
+    pub(super) fn resolve(options: &HashSet<DecodeOption>, global_message_number: u16) -> Self {
+        let kind = MesgNum::from(global_message_number);
+        let requested = options.contains(&DecodeOption::KeepMessage(kind))
+            || !options
+                .iter()
+                .any(|o| matches!(o, DecodeOption::KeepMessage(_)));
+        MessageHandling {
+            requested,
+            returned: !options.contains(&DecodeOption::DropUnknownMessages)
+                || MesgNum::is_named_variant(kind.as_i64()),
+        }
+    }
+}
+
+/// Options resolved by a `DecoderContext`, handed to the processor decoding a file
+#[derive(Clone, Debug, Default)]
+pub(super) struct ResolvedOptions {
+    /// Options consulted while decoding the fields of a message, the profile checks them for
+    /// every field so they're kept apart from the options handling whole messages and files
+    pub(super) field_options: HashSet<DecodeOption>,
+    /// Handling of the messages seen so far, by global message number
+    pub(super) messages: HashMap<u16, MessageHandling>,
+}
+
+impl ResolvedOptions {
+    pub(super) fn handling(
+        &mut self,
+        options: &HashSet<DecodeOption>,
+        global_message_number: u16,
+    ) -> MessageHandling {
+        *self
+            .messages
+            .entry(global_message_number)
+            .or_insert_with(|| MessageHandling::resolve(options, global_message_number))
+    }
+}
+
+/// Decoding context reused across files with the same options.
+///
+/// `decode` resolves the options once: which message kinds are decoded and returned is cached
+/// by global message number as the kinds are seen, and the options handling whole messages or
+/// files, e.g. `KeepMessage` or `CrcPolicy`, are taken out of the set checked for every field.
+/// The profile of the fields decoded by `decode` is compiled into the decoder so there is
+/// nothing to look up per field.
+///
+/// `decode_borrowed` looks the fields up in the profile at runtime, the context caches the name,
+/// scale, offset, units and type resolved for every (message, field) pair so files from the same
+/// devices only pay for the lookups once. Keeping a context per thread of a bulk import avoids
+/// repeating that work for every file, the records are the same as decoded by
+/// `from_bytes_with_options` and `from_bytes_borrowed`.
+#[derive(Clone, Debug, Default)]
+pub struct DecoderContext {
+    options: HashSet<DecodeOption>,
+    resolved: ResolvedOptions,
+    fields: FieldInfoCache,
+}
+
+impl DecoderContext {
+    /// Create a context decoding with the options
+    pub fn new(options: &HashSet<DecodeOption>) -> Self {
+        DecoderContext {
+            options: options.clone(),
+            resolved: ResolvedOptions {
+                field_options: options
+                    .iter()
+                    .filter(|o| o.is_field_option())
+                    .copied()
+                    .collect(),
+                messages: HashMap::new(),
+            },
+            fields: FieldInfoCache::default(),
+        }
+    }
+
+    /// Options the context decodes with
+    pub fn options(&self) -> &HashSet<DecodeOption> {
+        &self.options
+    }
+
+    /// Number of message kinds whose handling is cached
+    pub fn cached_messages(&self) -> usize {
+        self.resolved.messages.len()
+    }
+
+    /// Number of (message, field) pairs whose profile information is cached
+    pub fn cached_fields(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Deserialize a FIT file stored as an array of bytes and return the decoded data messages,
+    /// reusing what was resolved while decoding the previous files
+    pub fn decode(&mut self, buffer: &[u8]) -> Result<Vec<FitDataRecord>> {
+        let mut processor = FitStreamProcessor::new();
+        self.options.iter().for_each(|o| processor.add_option(*o));
+        processor.resolved = Some(std::mem::take(&mut self.resolved));
+        let result = decode_with_processor(&mut processor, buffer, |_| {});
+        self.resolved = processor.resolved.take().unwrap_or_default();
+        result.map(|(records, _)| records)
+    }
+
+    /// Deserialize a FIT file from a reader, see `decode`
+    pub fn decode_reader<T: Read>(&mut self, source: &mut T) -> Result<Vec<FitDataRecord>> {
+        let buffer = read_stream(source)?;
+        self.decode(&buffer)
+    }
+
+    /// Deserialize a FIT file into records borrowing from the buffer like `from_bytes_borrowed`,
+    /// reusing the profile information of the fields resolved for the previous files. The
+    /// options don't apply to borrowed records.
+    pub fn decode_borrowed<'a>(
+        &mut self,
+        buffer: &'a [u8],
+    ) -> Result<Vec<borrowed::FitDataRecord<'a>>> {
+        borrowed::decode_borrowed(buffer, &mut self.fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::{from_bytes_with_options, CrcPolicy};
+
+    #[test]
+    fn reuse_context() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let options: HashSet<_> = [
+            DecodeOption::DropUnknownMessages,
+            DecodeOption::KeepRawValues,
+            DecodeOption::CrcPolicy(CrcPolicy::Skip),
+        ]
+        .into();
+        let mut context = DecoderContext::new(&options);
+        assert_eq!(context.resolved.field_options.len(), 1);
+        let json = |records: Vec<FitDataRecord>| serde_json::to_value(records).unwrap();
+        let expected = json(from_bytes_with_options(data, &options).unwrap());
+        assert_eq!(json(context.decode(data).unwrap()), expected);
+        let cached = context.cached_messages();
+        assert!(cached > 0);
+        assert_eq!(json(context.decode(data).unwrap()), expected);
+        assert_eq!(context.cached_messages(), cached);
+
+        let options = DecodeOption::with_message_filter(&[MesgNum::Session]);
+        let mut context = DecoderContext::new(&options);
+        let activity = include_bytes!("../../tests/fixtures/Activity.fit");
+        for data in [&data[..], &activity[..]] {
+            let records = context.decode(data).unwrap();
+            assert!(records.iter().all(|r| r.kind() == MesgNum::Session));
+            let expected = from_bytes_with_options(data, &options).unwrap();
+            assert_eq!(json(records), json(expected));
+        }
+        assert!(context.decode(b"not a fit file").is_err());
+    }
+
+    #[test]
+    fn reuse_field_info() {
+        let mut context = DecoderContext::default();
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let activity = include_bytes!("../../tests/fixtures/Activity.fit");
+        for data in [&data[..], &activity[..], &data[..]] {
+            let records = context.decode_borrowed(data).unwrap();
+            let expected = borrowed::from_bytes_borrowed(data).unwrap();
+            assert_eq!(records.len(), expected.len());
+            for (record, expected) in records.iter().zip(&expected) {
+                assert_eq!(record.kind(), expected.kind());
+                for (field, expected) in record.fields().iter().zip(expected.fields()) {
+                    assert_eq!(field.name(), expected.name());
+                    assert_eq!(field.units(), expected.units());
+                    assert_eq!(field.to_value().unwrap(), expected.to_value().unwrap());
+                }
+            }
+        }
+        let cached = context.cached_fields();
+        assert!(cached > 0);
+        context.decode_borrowed(data).unwrap();
+        assert_eq!(context.cached_fields(), cached);
+    }
+}
@@ -1,19 +1,24 @@
 //! Helper functions and structures needed to decode a FIT file using the defined profile.
-use super::parser::FitDataMessage;
+use super::parser::{developer_field_value, FitDataMessage};
 use super::DecodeOption;
 use crate::error::Result;
 use crate::profile::{MesgNum, TimestampField};
-use crate::{FitDataField, FitDataRecord, Value};
+use crate::timestamp;
+use crate::{DeveloperFieldInfo, FitDataField, FitDataRecord, Value};
 use std::collections::{HashMap, HashSet};
 use std::convert::{From, TryInto};
 
 /// Decodes a raw FitDataMessage using the defined profile. Additional logic is used to handle
 /// values that need to accumlate across multiple messages as well as applying the
 /// time offset to the current base timestamp.
-/// time offset to the current base timestamp.
 pub struct Decoder {
     base_timestamp: TimestampField,
-    accumulate_fields: HashMap<u32, Value>,
+    accumulate_fields: Accumulators,
+    /// Application ids from `developer_data_id` messages keyed by developer data index
+    application_ids: HashMap<u8, String>,
+    /// Developer field metadata from `field_description` messages keyed by developer data index
+    /// and field number
+    developer_fields: HashMap<(u8, u8), DeveloperFieldInfo>,
 }
 
 impl Decoder {
@@ -21,14 +26,18 @@ impl Decoder {
     pub fn new() -> Self {
         Decoder {
             base_timestamp: TimestampField::Utc(0),
-            accumulate_fields: HashMap::new(),
+            accumulate_fields: Accumulators::new(),
+            application_ids: HashMap::new(),
+            developer_fields: HashMap::new(),
         }
     }
 
     /// Reset accumation related fields
     pub fn reset(&mut self) {
         self.base_timestamp = TimestampField::Utc(0);
-        self.accumulate_fields = HashMap::new();
+        self.accumulate_fields.clear();
+        self.application_ids = HashMap::new();
+        self.developer_fields = HashMap::new();
     }
 
     /// Decode a raw FIT data message by applying the defined profile
@@ -40,12 +49,7 @@ impl Decoder {
         let mesg_num = MesgNum::from(message.global_message_number());
         let mut record = FitDataRecord::new(mesg_num);
 
-        // check if we have a real timestamp field to set the reference
-        // field id 253 always appears to be a timestamp with the type
-        // FieldDataType::DateTime
-        if let Some(value) = message.fields().get(&253) {
-            self.base_timestamp = TimestampField::Utc(value.clone().try_into().unwrap_or(0));
-        }
+        self.track_message(&message);
 
         // process raw data
         let mut fields =
@@ -55,19 +59,122 @@ impl Decoder {
 
         // Add a timestamp field if we have a time offset
         if let Some(time_offset) = message.time_offset() {
+            let timestamp = match self.update_timestamp(time_offset) {
+                Value::Timestamp(time) if options.contains(&DecodeOption::UtcTimestamps) => {
+                    Value::UtcTimestamp(timestamp::to_utc(&time))
+                }
+                timestamp => timestamp,
+            };
             record.push(FitDataField::new(
                 String::from("timestamp"),
                 253,
-                self.update_timestamp(time_offset),
+                timestamp,
                 String::new(),
             ));
         }
 
-        // TODO: process developer fields
+        for (def, value) in message.developer_fields() {
+            let key = (def.developer_data_index(), def.field_number());
+            let mut info = match self.developer_fields.get(&key) {
+                Some(desc) => desc.clone(),
+                None => DeveloperFieldInfo {
+                    developer_data_index: key.0,
+                    ..Default::default()
+                },
+            };
+            // without a description the raw bytes are the best we can do
+            let value = match info.base_type {
+                Some(base_type) => match developer_field_value(value, def, base_type) {
+                    Some(v) => v,
+                    None => continue,
+                },
+                None => value.clone(),
+            };
+            let (value, raw_value) = match (info.scale, info.offset) {
+                (None, None) => (value, None),
+                (scale, offset) => {
+                    let scaled = scale_value(&value, scale.unwrap_or(1.0), offset.unwrap_or(0.0));
+                    (scaled, Some(value))
+                }
+            };
+            info.application_id = self.application_ids.get(&key.0).cloned();
+            let name = if info.field_name.is_empty() {
+                format!("developer_field_{}_{}", key.0, key.1)
+            } else {
+                info.field_name.clone()
+            };
+            let mut field = FitDataField::new(name, def.field_number(), value, info.units.clone());
+            field.set_developer_info(Some(info));
+            if options.contains(&DecodeOption::KeepRawValues) {
+                field.set_raw_value(raw_value);
+            }
+            record.push(field);
+        }
 
         Ok(record)
     }
 
+    /// Skip a message without decoding it, only keeping track of its timestamp and developer
+    /// data definitions so the messages decoded after it are still correct
+    pub fn skip_message(&mut self, message: &FitDataMessage) {
+        self.track_message(message);
+        if let Some(time_offset) = message.time_offset() {
+            self.update_timestamp(time_offset);
+        }
+    }
+
+    fn track_message(&mut self, message: &FitDataMessage) {
+        // check if we have a real timestamp field to set the reference
+        // field id 253 always appears to be a timestamp with the type
+        // FieldDataType::DateTime
+        if let Some(value) = message.fields().get(&253) {
+            self.base_timestamp = TimestampField::Utc(value.clone().try_into().unwrap_or(0));
+        }
+
+        // track the developer data definitions before the raw values get consumed
+        match MesgNum::from(message.global_message_number()) {
+            MesgNum::DeveloperDataId => self.register_developer_data_id(message.fields()),
+            MesgNum::FieldDescription => self.register_field_description(message.fields()),
+            _ => {}
+        }
+    }
+
+    /// Store the application id of a `developer_data_id` message
+    fn register_developer_data_id(&mut self, fields: &HashMap<u8, Value>) {
+        if let Some(index) = raw_u8(fields, 3) {
+            if let Some(id) = fields.get(&1).and_then(format_application_id) {
+                self.application_ids.insert(index, id);
+            }
+        }
+    }
+
+    /// Store the metadata of a `field_description` message
+    fn register_field_description(&mut self, fields: &HashMap<u8, Value>) {
+        let (index, number) = match (raw_u8(fields, 0), raw_u8(fields, 1)) {
+            (Some(index), Some(number)) => (index, number),
+            _ => return,
+        };
+        let info = DeveloperFieldInfo {
+            developer_data_index: index,
+            application_id: None,
+            field_name: raw_string(fields, 3).unwrap_or_default(),
+            units: raw_string(fields, 8).unwrap_or_default(),
+            native_mesg_num: fields
+                .get(&14)
+                .and_then(|v| v.clone().try_into().ok())
+                .map(|v: i64| MesgNum::from(v as u16)),
+            native_field_num: raw_u8(fields, 15),
+            base_type: raw_u8(fields, 2),
+            // a scale of zero would divide by zero, treat it like a missing scale
+            scale: raw_u8(fields, 6).filter(|&s| s != 0).map(f64::from),
+            offset: fields
+                .get(&7)
+                .and_then(|v| v.clone().try_into().ok())
+                .map(|v: i64| v as f64),
+        };
+        self.developer_fields.insert((index, number), info);
+    }
+
     /// Update the timestamp with a new offset and return the value
     fn update_timestamp(&mut self, offset: u8) -> Value {
         let offset: i64 = offset as i64;
@@ -91,3 +198,170 @@ impl Decoder {
         Value::from(self.base_timestamp)
     }
 }
+
+/// Running totals of the fields components are accumulated into, e.g. the `distance` of a
+/// `record` message packed into `compressed_speed_distance`. A component only stores the lower
+/// bits of the total so each value adds its change since the previous value, counting rollovers
+/// of the component, which keeps the totals increasing across messages like the FIT SDK does.
+/// Totals are kept in the raw units of the last value, a component with a different scale or
+/// offset than the field it adds to first converts the total into its own units.
+#[derive(Clone, Debug, Default)]
+pub struct Accumulators {
+    totals: HashMap<(MesgNum, u8), Accumulator>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Accumulator {
+    last: u64,
+    total: u64,
+    scale: f64,
+    offset: f64,
+}
+
+impl Accumulators {
+    /// Create a registry without any totals
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the raw value of a component `bits` wide to the total of a field and return the new
+    /// total, array values are added one element after the other
+    pub fn accumulate(
+        &mut self,
+        mesg_num: MesgNum,
+        field: u8,
+        value: Value,
+        bits: u8,
+        scale: f64,
+        offset: f64,
+    ) -> Result<Value> {
+        let accumulator = self.totals.entry((mesg_num, field)).or_insert(Accumulator {
+            last: 0,
+            total: 0,
+            scale,
+            offset,
+        });
+        accumulator.convert(scale, offset);
+        accumulator.add(value, bits)
+    }
+
+    /// Reset the total of a field to a raw value stored in full, the components that follow add
+    /// to it. Arrays set the total to their last element and values that aren't integers are
+    /// ignored.
+    pub fn set(&mut self, mesg_num: MesgNum, field: u8, value: &Value, scale: f64, offset: f64) {
+        let value = match value {
+            Value::Array(values) => values.last(),
+            value => Some(value),
+        };
+        if let Some(Ok(value)) = value.map(TryInto::<i64>::try_into) {
+            let value = value as u64;
+            self.totals.insert(
+                (mesg_num, field),
+                Accumulator {
+                    last: value,
+                    total: value,
+                    scale,
+                    offset,
+                },
+            );
+        }
+    }
+
+    /// Remove every total, e.g. at the start of the next file
+    pub fn clear(&mut self) {
+        self.totals.clear();
+    }
+}
+
+impl Accumulator {
+    /// Convert the total into raw units with a different scale and offset
+    fn convert(&mut self, scale: f64, offset: f64) {
+        if (self.scale - scale).abs() < f64::EPSILON && (self.offset - offset).abs() < f64::EPSILON
+        {
+            return;
+        }
+        let total = (self.total as f64 / self.scale - self.offset + offset) * scale;
+        self.total = total.round().max(0.0) as u64;
+        self.last = self.total;
+        self.scale = scale;
+        self.offset = offset;
+    }
+
+    fn add(&mut self, value: Value, bits: u8) -> Result<Value> {
+        if let Value::Array(values) = value {
+            return values
+                .into_iter()
+                .map(|v| self.add(v, bits))
+                .collect::<Result<_>>()
+                .map(Value::Array);
+        }
+        let value = TryInto::<i64>::try_into(value)? as u64;
+        let mask = u64::MAX >> (64 - u32::from(bits.clamp(1, 64)));
+        self.total = self
+            .total
+            .wrapping_add(value.wrapping_sub(self.last) & mask);
+        self.last = value;
+        Ok(Value::UInt64(self.total))
+    }
+}
+
+/// Read a raw single byte value from the message fields
+fn raw_u8(fields: &HashMap<u8, Value>, number: u8) -> Option<u8> {
+    fields
+        .get(&number)
+        .and_then(|v| v.clone().try_into().ok())
+        .map(|v: i64| v as u8)
+}
+
+/// Apply the scale and offset of a field description to numeric values, other values are kept
+fn scale_value(value: &Value, scale: f64, offset: f64) -> Value {
+    match value {
+        Value::Array(values) => Value::Array(
+            values
+                .iter()
+                .map(|v| scale_value(v, scale, offset))
+                .collect(),
+        ),
+        Value::String(_) | Value::Byte(_) => value.clone(),
+        _ => match TryInto::<f64>::try_into(value.clone()) {
+            Ok(val) => Value::Float64(val / scale - offset),
+            Err(_) => value.clone(),
+        },
+    }
+}
+
+/// Read a raw string value from the message fields, the first entry is used for string arrays
+fn raw_string(fields: &HashMap<u8, Value>, number: u8) -> Option<String> {
+    match fields.get(&number)? {
+        Value::String(s) => Some(s.clone()),
+        Value::Array(values) => values.iter().find_map(|v| match v {
+            Value::String(s) => Some(s.clone()),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// Format the 16 byte application id as a UUID, other lengths are formatted as plain hex
+fn format_application_id(value: &Value) -> Option<String> {
+    let bytes: Vec<u8> = match value {
+        Value::Array(values) => values
+            .iter()
+            .filter_map(|v| v.clone().try_into().ok().map(|b: i64| b as u8))
+            .collect(),
+        v => vec![v.clone().try_into().ok().map(|b: i64| b as u8)?],
+    };
+    let hex: Vec<String> = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    if hex.len() == 16 {
+        Some(format!(
+            "{}-{}-{}-{}-{}",
+            hex[0..4].concat(),
+            hex[4..6].concat(),
+            hex[6..8].concat(),
+            hex[8..10].concat(),
+            hex[10..16].concat()
+        ))
+    } else {
+        Some(hex.concat())
+    }
+}
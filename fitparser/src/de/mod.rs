@@ -1,19 +1,45 @@
 //! Deserialize a stream of FIT file data into the serde data model by parsing the file and
 //! applying the packaged FIT profile to the data.
-use crate::error::{ErrorKind, Result};
+use crate::error::{ErrorContext, ErrorKind, Result};
+use crate::profile::field_types::File;
 use crate::profile::MesgNum;
+use crate::timestamp::{self, UtcOffset};
 use crate::FitDataRecord;
 use nom::number::complete::le_u16;
 use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
 use std::io::Read;
+use std::path::Path;
 use std::sync::Arc;
 
-mod crc;
+pub mod borrowed;
+pub use borrowed::from_bytes_borrowed;
+mod chained;
+pub use chained::{files_from_bytes, files_from_bytes_with_options, is_chained, FitFile};
+mod context;
+pub use context::DecoderContext;
+use context::{MessageHandling, ResolvedOptions};
+pub(crate) mod crc;
 use crc::{caculate_crc, update_crc};
 mod decode;
+pub use decode::Accumulators;
 use decode::Decoder;
-mod parser;
-pub use parser::{FitDataMessage, FitDefinitionMessage, FitFileHeader};
+pub(crate) mod parser;
+pub use parser::{
+    file_ranges, BaseType, DeveloperFieldDefinition, FieldDefinition, FitDataMessage,
+    FitDefinitionMessage, FitFileHeader,
+};
+mod sniff;
+pub use sniff::{is_fit, sniff, FitHeaderInfo};
+mod stream;
+pub use stream::FitStreamReader;
+mod summary;
+pub use summary::FitFileSummary;
+#[cfg(feature = "async")]
+mod async_reader;
+#[cfg(feature = "async")]
+pub use async_reader::{from_async_reader, from_async_reader_with_options, FitAsyncStreamReader};
 
 /// Decoding options for the deserializer
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
@@ -33,6 +59,157 @@ pub enum DecodeOption {
     SkipDataCrcValidation,
     /// Keep the generic name when resolving subfields in the FIT profile
     UseGenericSubFieldName,
+    /// Return the raw value of a single field instead of applying the scale and offset defined
+    /// by the profile, e.g. `SkipScaleAndOffset(MesgNum::Record, record::ALTITUDE)` using the
+    /// constants of `profile::field_numbers`. Fields expanded from components are matched by
+    /// their own definition number.
+    SkipScaleAndOffset(MesgNum, u8),
+    /// Return fields measured in seconds or milliseconds as `Value::Duration`, their units
+    /// become seconds. Values that can't be a duration, e.g. negative ones, are kept as numbers.
+    ReturnDurations,
+    /// Return coordinates measured in semicircles as decimal degrees, their units become
+    /// `degrees`. This covers every position of the profile, e.g. the `position_lat` and
+    /// `position_long` of records, laps and course points.
+    ReturnDegrees,
+    /// Lossy mode salvaging what can be decoded from corrupt or truncated files, e.g. written by
+    /// a device that crashed mid-activity. CRCs aren't validated, data messages that can't be
+    /// decoded are skipped, parsing resumes at the next valid definition message after data that
+    /// can't be parsed and the records decoded before a file is cut off are returned.
+    RecoverCorruptData,
+    /// Keep the value stored in the file alongside the converted value of each profile field,
+    /// returned by `FitDataField::raw_value`. The encoder writes the raw value when present so
+    /// records can be encoded again without rounding the scaled values.
+    KeepRawValues,
+    /// Only decode the data messages of the given kind, once any message is requested every
+    /// other data message is skipped without decoding its fields. Timestamps and developer field
+    /// descriptions are still tracked from the skipped messages.
+    KeepMessage(MesgNum),
+    /// How the checksums of the header and data sections are handled, `CrcPolicy::Strict` when
+    /// missing. The most lenient policy wins if several are given.
+    CrcPolicy(CrcPolicy),
+    /// Return `local_date_time` fields, e.g. the `local_timestamp` of an `activity`, as
+    /// `Value::LocalTimestamp` holding the wall clock time of the device instead of interpreting
+    /// it in the time zone of the machine decoding the file
+    NaiveLocalTimes,
+    /// Interpret `local_date_time` fields at a fixed offset in seconds east of UTC, e.g. the
+    /// time zone the activity was recorded in, see `DecodeOption::local_time_offset`. Takes
+    /// precedence over `NaiveLocalTimes`.
+    LocalTimeOffset(i32),
+    /// Return `date_time` fields, e.g. the `timestamp` of every message, as
+    /// `Value::UtcTimestamp` instead of converting them into the local time zone of the machine
+    /// decoding the file
+    UtcTimestamps,
+}
+
+/// Handling of the header and data checksums of a file
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub enum CrcPolicy {
+    /// Calculate both checksums and fail on a mismatch
+    #[default]
+    Strict,
+    /// Calculate both checksums and record a `CrcWarning` for a mismatch, the records are
+    /// still returned
+    WarnOnly,
+    /// Don't calculate the checksums at all, which saves time decoding trusted data
+    Skip,
+}
+
+/// A checksum that didn't match the calculated value, recorded by `CrcPolicy::WarnOnly`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CrcWarning {
+    /// Byte offset of the file the checksum belongs to
+    pub offset: usize,
+    /// The checksum of the header rather than the data section
+    pub header: bool,
+    /// Checksum stored in the file
+    pub expected: u16,
+    /// Checksum calculated from the data
+    pub calculated: u16,
+}
+
+impl fmt::Display for CrcWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} CRC of the file at byte {} did not match, expected value {}, calculated value {}",
+            if self.header { "header" } else { "data" },
+            self.offset,
+            self.expected,
+            self.calculated
+        )
+    }
+}
+
+impl DecodeOption {
+    /// Option interpreting `local_date_time` fields at the given offset from UTC
+    pub fn local_time_offset(offset: UtcOffset) -> DecodeOption {
+        DecodeOption::LocalTimeOffset(timestamp::offset_seconds(offset))
+    }
+
+    /// Options decoding only the data messages of the given kinds, e.g. `session` and `lap`
+    /// summaries without paying for the decoding of every `record` message. More options can be
+    /// added to the returned set.
+    pub fn with_message_filter(kinds: &[MesgNum]) -> HashSet<DecodeOption> {
+        kinds
+            .iter()
+            .map(|k| DecodeOption::KeepMessage(*k))
+            .collect()
+    }
+
+    /// Check if the profile consults the option while decoding the fields of a message, rather
+    /// than when handling whole messages or files
+    fn is_field_option(&self) -> bool {
+        !matches!(
+            self,
+            DecodeOption::DropUnknownMessages
+                | DecodeOption::SkipHeaderCrcValidation
+                | DecodeOption::SkipDataCrcValidation
+                | DecodeOption::RecoverCorruptData
+                | DecodeOption::KeepMessage(_)
+                | DecodeOption::CrcPolicy(_)
+        )
+    }
+
+    /// Sensible decode options for a FIT file type, covering the expansions and validations as
+    /// well as the messages and fields kept.
+    ///
+    /// * Activities drop the undocumented messages devices write and return durations and
+    ///   positions as `Value::Duration` and decimal degrees. A checksum mismatch is recorded as
+    ///   a `CrcWarning` rather than losing the activity.
+    /// * Monitoring files drop the undocumented messages, return durations and only warn about
+    ///   checksum mismatches.
+    /// * Courses, workouts and schedules are meant to be re-used, they are reduced to the
+    ///   documented messages and fields and keep the raw values so they can be encoded again
+    ///   without rounding. Their checksums are validated strictly.
+    /// * Other file types, such as settings, are decoded as completely as possible, keeping
+    ///   the composite fields and raw values, with strict checksums.
+    pub fn preset(file_type: File) -> HashSet<DecodeOption> {
+        let options: &[DecodeOption] = match file_type {
+            File::Activity | File::ActivitySummary => &[
+                DecodeOption::DropUnknownMessages,
+                DecodeOption::ReturnDurations,
+                DecodeOption::ReturnDegrees,
+                DecodeOption::CrcPolicy(CrcPolicy::WarnOnly),
+            ],
+            File::MonitoringA | File::MonitoringB | File::MonitoringDaily => &[
+                DecodeOption::DropUnknownMessages,
+                DecodeOption::ReturnDurations,
+                DecodeOption::CrcPolicy(CrcPolicy::WarnOnly),
+            ],
+            File::Course | File::Workout | File::Schedules => &[
+                DecodeOption::DropUnknownMessages,
+                DecodeOption::DropUnknownFields,
+                DecodeOption::KeepRawValues,
+                DecodeOption::CrcPolicy(CrcPolicy::Strict),
+            ],
+            _ => &[
+                DecodeOption::KeepCompositeFields,
+                DecodeOption::KeepRawValues,
+                DecodeOption::CrcPolicy(CrcPolicy::Strict),
+            ],
+        };
+        options.iter().copied().collect()
+    }
 }
 
 /// Stores a FIT file object (header, message or CRC)
@@ -63,8 +240,19 @@ struct Deserializer {
     end_of_messages: usize,
     /// Stores the current CRC value
     crc: u16,
+    /// Offset of the header of the current file
+    file_offset: usize,
+    /// Checksum mismatches let through by `CrcPolicy::WarnOnly`
+    crc_warnings: Vec<CrcWarning>,
+    /// Number of data messages parsed, used to emit progress events periodically
+    #[cfg(feature = "tracing")]
+    data_messages: usize,
 }
 
+/// Emit a progress event after every N data messages when tracing is enabled
+#[cfg(feature = "tracing")]
+const TRACE_MESSAGE_INTERVAL: usize = 1000;
+
 impl Deserializer {
     /// Create the deserializer with an empty state
     fn new() -> Self {
@@ -74,6 +262,10 @@ impl Deserializer {
             position: 0,
             end_of_messages: 0,
             crc: 0,
+            file_offset: 0,
+            crc_warnings: Vec::new(),
+            #[cfg(feature = "tracing")]
+            data_messages: 0,
         }
     }
 
@@ -87,6 +279,69 @@ impl Deserializer {
         &mut self.options
     }
 
+    /// Check if corrupt data is skipped rather than returned as an error
+    fn is_lossy(&self) -> bool {
+        self.options.contains(&DecodeOption::RecoverCorruptData)
+    }
+
+    /// The policy checksums are handled with, lossy mode records mismatches rather than failing
+    fn crc_policy(&self) -> CrcPolicy {
+        let policy = self
+            .options
+            .iter()
+            .filter_map(|o| match o {
+                DecodeOption::CrcPolicy(policy) => Some(*policy),
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default();
+        if self.is_lossy() {
+            policy.max(CrcPolicy::WarnOnly)
+        } else {
+            policy
+        }
+    }
+
+    /// Add consumed bytes to the data CRC unless checksums are skipped
+    fn update_crc(&mut self, bytes: &[u8]) {
+        if !self
+            .options
+            .contains(&DecodeOption::CrcPolicy(CrcPolicy::Skip))
+        {
+            self.crc = update_crc(self.crc, bytes);
+        }
+    }
+
+    /// Apply the checksum policy to a mismatch, returning true if decoding has to fail
+    fn crc_mismatch(&mut self, header: bool, expected: u16, calculated: u16) -> bool {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            position = self.position,
+            expected,
+            calculated,
+            header,
+            "CRC mismatch"
+        );
+        let skip_option = match header {
+            true => DecodeOption::SkipHeaderCrcValidation,
+            false => DecodeOption::SkipDataCrcValidation,
+        };
+        match self.crc_policy() {
+            CrcPolicy::Skip => false,
+            _ if self.options.contains(&skip_option) => false,
+            CrcPolicy::WarnOnly => {
+                self.crc_warnings.push(CrcWarning {
+                    offset: self.file_offset,
+                    header,
+                    expected,
+                    calculated,
+                });
+                false
+            }
+            CrcPolicy::Strict => true,
+        }
+    }
+
     /// Clear the definition messages used to decode data messages and reset the CRC value. This
     /// can be called between distinct FIT files but if they are properly formed it should not be
     /// necessary since new definitions will replace the old in the mapping.
@@ -115,7 +370,17 @@ impl Deserializer {
     /// Parse the FIT header
     fn deserialize_header<'de>(&mut self, input: &'de [u8]) -> Result<(&'de [u8], FitObject)> {
         let (remaining, header) =
-            parser::fit_file_header(input).map_err(|e| self.to_parse_err(e))?;
+            parser::fit_file_header(input).map_err(|e| self.to_parse_err(input, None, e))?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            position = self.position,
+            header_size = header.header_size(),
+            data_size = header.data_size(),
+            protocol_version = header.protocol_ver_enc(),
+            profile_version = header.profile_ver_enc(),
+            "parsed FIT file header"
+        );
+        self.file_offset = self.position;
         self.end_of_messages =
             self.position + header.header_size() as usize + header.data_size() as usize;
         self.position += header.header_size() as usize;
@@ -125,19 +390,21 @@ impl Deserializer {
         // exist. This behavior doesn't appear to be documented but was verified using the
         // FitTestTool.jar utility included with the SDK.
         let crc_value = header.crc().unwrap_or(0);
+        if self.crc_policy() == CrcPolicy::Skip {
+            return Ok((remaining, FitObject::Header(header)));
+        }
         if crc_value > 0 {
             let checksum = caculate_crc(&input[0..(header.header_size() - 2) as usize]);
-            if !self
-                .options
-                .contains(&DecodeOption::SkipHeaderCrcValidation)
-                && checksum != crc_value
-            {
-                return Err(Box::new(ErrorKind::InvalidCrc((
-                    Vec::from(remaining),
-                    FitObject::Header(header),
-                    crc_value,
-                    checksum,
-                ))));
+            if checksum != crc_value && self.crc_mismatch(true, crc_value, checksum) {
+                return Err(Box::new(ErrorKind::InvalidCrc(
+                    (
+                        Vec::from(remaining),
+                        FitObject::Header(header),
+                        crc_value,
+                        checksum,
+                    ),
+                    ErrorContext::at(self.file_offset),
+                )));
             }
         } else {
             // if the header doesn't have its own CRC then the header bytes are included in
@@ -150,58 +417,156 @@ impl Deserializer {
 
     /// Extract a 2 byte CRC
     fn deserialize_crc<'de>(&mut self, input: &'de [u8]) -> Result<(&'de [u8], FitObject)> {
-        let (input, crc) = le_u16(input).map_err(|e| self.to_parse_err(e))?;
+        let (input, crc) = le_u16(input).map_err(|e| self.to_parse_err(input, None, e))?;
         self.position += 2;
-        if !self.options.contains(&DecodeOption::SkipDataCrcValidation) && crc != self.crc {
-            return Err(Box::new(ErrorKind::InvalidCrc((
-                Vec::from(input),
-                FitObject::Crc(crc),
-                crc,
-                self.crc,
-            ))));
+        if self.crc_policy() != CrcPolicy::Skip
+            && crc != self.crc
+            && self.crc_mismatch(false, crc, self.crc)
+        {
+            return Err(Box::new(ErrorKind::InvalidCrc(
+                (Vec::from(input), FitObject::Crc(crc), crc, self.crc),
+                ErrorContext::at(self.position - 2),
+            )));
         }
         Ok((input, FitObject::Crc(crc)))
     }
 
-    /// Parse a FIT data or definition message
+    /// Parse a FIT data or definition message, in lossy mode the parser resynchronizes on the
+    /// next definition message if the message can't be parsed
     fn deserialize_message<'de>(&mut self, input: &'de [u8]) -> Result<(&'de [u8], FitObject)> {
+        match self.parse_message(input) {
+            Err(err) if self.is_lossy() => self.resynchronize(input, err),
+            result => result,
+        }
+    }
+
+    /// Skip ahead to the next valid definition message of a known message after data that can't
+    /// be parsed, the error is returned if there is none before the end of the messages
+    fn resynchronize<'de>(
+        &mut self,
+        input: &'de [u8],
+        err: crate::Error,
+    ) -> Result<(&'de [u8], FitObject)> {
+        let end = self
+            .end_of_messages
+            .saturating_sub(self.position)
+            .min(input.len());
+        let skip = (1..end).find(|&skip| {
+            let candidate = &input[skip..];
+            parser::is_definition_start(candidate)
+                && matches!(
+                    parser::fit_message(candidate, &self.definitions),
+                    Ok((_, parser::FitMessage::Definition(definition)))
+                        if MesgNum::is_named_variant(definition.global_message_number().into())
+                )
+        });
+        match skip {
+            Some(skip) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    position = self.position,
+                    skipped = skip,
+                    error = %err,
+                    "skipped corrupt data"
+                );
+                self.position += skip;
+                self.parse_message(&input[skip..])
+            }
+            None => Err(err),
+        }
+    }
+
+    /// Parse a single FIT data or definition message
+    fn parse_message<'de>(&mut self, input: &'de [u8]) -> Result<(&'de [u8], FitObject)> {
         // parse a single message of either variety
         let init_len = input.len();
-        let (remaining, message) =
-            parser::fit_message(input, &self.definitions).map_err(|e| self.to_parse_err(e))?;
+        let (remaining, message) = parser::fit_message(input, &self.definitions)
+            .map_err(|e| self.to_parse_err(input, Some(self.message_context(input)), e))?;
         // update CRC with the consumed bytes
-        self.crc = update_crc(self.crc, &input[0..(input.len() - remaining.len())]);
+        self.update_crc(&input[0..(input.len() - remaining.len())]);
 
         match message {
-            parser::FitMessage::Data(message) => {
+            parser::FitMessage::Data(mut message) => {
+                message.set_offset(self.position);
                 self.position += init_len - remaining.len();
+                #[cfg(feature = "tracing")]
+                {
+                    self.data_messages += 1;
+                    if self.data_messages.is_multiple_of(TRACE_MESSAGE_INTERVAL) {
+                        tracing::debug!(
+                            position = self.position,
+                            data_messages = self.data_messages,
+                            "parsed data messages"
+                        );
+                    }
+                }
                 Ok((remaining, FitObject::DataMessage(message)))
             }
             parser::FitMessage::Definition(message) => {
                 // Use an Arc to avoid an expensive clone of the DefinitionMessage itself
                 let msg_rc = Arc::new(message);
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    position = self.position,
+                    local_message_number = msg_rc.local_message_number(),
+                    global_message_number = msg_rc.global_message_number(),
+                    fields = msg_rc.field_definitions().len(),
+                    developer_fields = msg_rc.developer_field_definitions().len(),
+                    "parsed definition message"
+                );
                 self.definitions
                     .insert(msg_rc.local_message_number(), Arc::clone(&msg_rc));
                 self.position += init_len - remaining.len();
                 Ok((remaining, FitObject::DefinitionMessage(msg_rc)))
             }
             parser::FitMessage::MissingDefinitionMessage(n) => {
-                Err(ErrorKind::MissingDefinitionMessage(n, self.position).into())
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    position = self.position,
+                    local_message_number = n,
+                    "missing definition message"
+                );
+                let ctx = ErrorContext {
+                    local_message_number: Some(n),
+                    ..ErrorContext::at(self.position)
+                };
+                Err(ErrorKind::MissingDefinitionMessage(ctx).into())
             }
         }
     }
 
+    /// Describe the message starting at the current position, the local message number comes
+    /// from the message header and the global number from the matching definition if one exists
+    fn message_context(&self, input: &[u8]) -> ErrorContext {
+        let mut ctx = ErrorContext::at(self.position);
+        if let Ok((_, header)) = parser::message_header(input) {
+            ctx.local_message_number = Some(header.local_message_number);
+            ctx.global_message_number = self
+                .definitions
+                .get(&header.local_message_number)
+                .map(|d| d.global_message_number());
+        }
+        ctx
+    }
+
     /// Inject the byte stream position into the Error when converting a nom parsing error. This
-    /// is not easy to get using the vanilla From trait since we need outside information.
-    fn to_parse_err(&self, err: nom::Err<nom::error::Error<&[u8]>>) -> crate::Error {
+    /// is not easy to get using the vanilla From trait since we need outside information. The
+    /// offset points at the byte that failed to parse when nom reports it.
+    fn to_parse_err(
+        &self,
+        input: &[u8],
+        ctx: Option<ErrorContext>,
+        err: nom::Err<nom::error::Error<&[u8]>>,
+    ) -> crate::Error {
+        let mut ctx = ctx.unwrap_or_else(|| ErrorContext::at(self.position));
         match err {
-            nom::Err::Error(inner_err) => {
-                ErrorKind::ParseError(self.position, inner_err.code).into()
+            nom::Err::Error(inner_err) | nom::Err::Failure(inner_err) => {
+                ctx.offset = self.position + input.len().saturating_sub(inner_err.input.len());
+                #[cfg(feature = "tracing")]
+                tracing::warn!(position = ctx.offset, code = ?inner_err.code, "parse error");
+                ErrorKind::ParseError(ctx, inner_err.code).into()
             }
-            nom::Err::Failure(inner_err) => {
-                ErrorKind::ParseError(self.position, inner_err.code).into()
-            }
-            nom::Err::Incomplete(needed) => ErrorKind::UnexpectedEof(needed).into(),
+            nom::Err::Incomplete(needed) => ErrorKind::UnexpectedEof(needed, ctx).into(),
         }
     }
 }
@@ -210,6 +575,8 @@ impl Deserializer {
 pub struct FitStreamProcessor {
     decoder: Decoder,
     deserializer: Deserializer,
+    /// Options resolved by the `DecoderContext` the processor decodes a file for
+    resolved: Option<ResolvedOptions>,
 }
 
 impl Default for FitStreamProcessor {
@@ -217,6 +584,7 @@ impl Default for FitStreamProcessor {
         FitStreamProcessor {
             decoder: Decoder::new(),
             deserializer: Deserializer::new(),
+            resolved: None,
         }
     }
 }
@@ -242,6 +610,27 @@ impl FitStreamProcessor {
         self.deserializer.options()
     }
 
+    /// Checksum mismatches recorded so far with `CrcPolicy::WarnOnly`
+    pub fn crc_warnings(&self) -> &[CrcWarning] {
+        &self.deserializer.crc_warnings
+    }
+
+    /// Check if a data message is requested by the `DecodeOption::KeepMessage` options, all
+    /// messages are requested when there are none, and if it's returned once decoded
+    fn message_handling(&mut self, msg: &FitDataMessage) -> MessageHandling {
+        let options = self.deserializer.options();
+        match &mut self.resolved {
+            Some(resolved) => resolved.handling(options, msg.global_message_number()),
+            None => MessageHandling::resolve(options, msg.global_message_number()),
+        }
+    }
+
+    /// Check if decoding can end at an error keeping the records decoded so far, which is the
+    /// case in lossy mode once the first file header was parsed
+    fn can_salvage(&self) -> bool {
+        self.deserializer.is_lossy() && self.deserializer.position > 0
+    }
+
     /// Reset the decoder state and definition messages in use, this should be called at the end of
     /// each FIT file to ensure the accumlator fields in the decoder will produce the right values
     /// per file.
@@ -257,48 +646,157 @@ impl FitStreamProcessor {
 
     /// Decode a FIT data message into a FIT data record using the defined FIT profile.
     pub fn decode_message(&mut self, msg: FitDataMessage) -> Result<FitDataRecord> {
+        let ctx = ErrorContext {
+            offset: msg.offset(),
+            local_message_number: Some(msg.local_message_number()),
+            global_message_number: Some(msg.global_message_number()),
+        };
+        let options = match &self.resolved {
+            Some(resolved) => &resolved.field_options,
+            None => self.deserializer.options(),
+        };
         self.decoder
-            .decode_message(msg, self.deserializer.options())
+            .decode_message(msg, options)
+            .map_err(|e| match *e {
+                ErrorKind::ValueError(message) => ErrorKind::DecodeError(message, ctx).into(),
+                _ if e.context().is_none() => ErrorKind::DecodeError(e.to_string(), ctx).into(),
+                _ => e,
+            })
     }
 }
 
 /// Deserialize a FIT file stored as an array of bytes and return the decoded data messages,
 /// with additional decode options
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(bytes = buffer.len()), err)
+)]
 pub fn from_bytes_with_options(
-    mut buffer: &[u8],
+    buffer: &[u8],
     options: &HashSet<DecodeOption>,
 ) -> Result<Vec<FitDataRecord>> {
+    from_bytes_with_crc_warnings(buffer, options).map(|(records, _)| records)
+}
+
+/// Deserialize a FIT file stored as an array of bytes, also returning the checksum mismatches
+/// let through by `CrcPolicy::WarnOnly` or `DecodeOption::RecoverCorruptData`
+pub fn from_bytes_with_crc_warnings(
+    buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+) -> Result<(Vec<FitDataRecord>, Vec<CrcWarning>)> {
+    decode_buffer(buffer, options, |_| {})
+}
+
+/// Deserialize a FIT file stored as an array of bytes, also returning a summary of the messages
+/// it contains. The summary counts every data message of the file, including the ones dropped
+/// by the decode options.
+pub fn from_bytes_with_summary(
+    buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+) -> Result<(Vec<FitDataRecord>, FitFileSummary)> {
+    let mut summary = FitFileSummary::default();
+    let (records, _) = decode_buffer(buffer, options, |obj| summary.add(obj))?;
+    Ok((records, summary))
+}
+
+/// Decode every object of the buffer, passing each one to `inspect` before it's processed
+fn decode_buffer(
+    buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+    inspect: impl FnMut(&FitObject),
+) -> Result<(Vec<FitDataRecord>, Vec<CrcWarning>)> {
     let mut processor = FitStreamProcessor::new();
+    options.iter().for_each(|o| processor.add_option(*o));
+    decode_with_processor(&mut processor, buffer, inspect)
+}
+
+/// Decode every object of the buffer with a processor set up with the decode options
+fn decode_with_processor(
+    processor: &mut FitStreamProcessor,
+    mut buffer: &[u8],
+    mut inspect: impl FnMut(&FitObject),
+) -> Result<(Vec<FitDataRecord>, Vec<CrcWarning>)> {
     let mut records = Vec::new();
+    while !buffer.is_empty() {
+        let (buf, obj) = match processor.deserialize_next(buffer) {
+            Ok(next) => next,
+            Err(_) if processor.can_salvage() => break,
+            Err(e) => return Err(e),
+        };
+        inspect(&obj);
+        if let Some(rec) = process_object(processor, obj)? {
+            records.push(rec);
+        }
+        buffer = buf;
+    }
 
-    options.iter().for_each(|o| processor.add_option(*o));
+    Ok((
+        records,
+        std::mem::take(&mut processor.deserializer.crc_warnings),
+    ))
+}
+
+/// Handle an object of the stream, returning the record decoded from a data message unless the
+/// options drop it. The processor is reset at the end of each file.
+fn process_object(
+    processor: &mut FitStreamProcessor,
+    obj: FitObject,
+) -> Result<Option<FitDataRecord>> {
+    match obj {
+        FitObject::Crc(..) => processor.reset(),
+        FitObject::Header(..) => {}
+        FitObject::DataMessage(msg) => {
+            let handling = processor.message_handling(&msg);
+            if !handling.requested {
+                processor.decoder.skip_message(&msg);
+                return Ok(None);
+            }
+            let rec = match processor.decode_message(msg) {
+                Ok(rec) => rec,
+                Err(_) if processor.deserializer.is_lossy() => return Ok(None),
+                Err(e) => return Err(e),
+            };
+            // drop the unknown messages if desired but we still need to
+            // decode them just incase the header contains a time-offset
+            // otherwise we'll get incorrect timestamps down the line
+            if handling.returned {
+                return Ok(Some(rec));
+            }
+        }
+        FitObject::DefinitionMessage(..) => {}
+    }
+    Ok(None)
+}
+
+/// Detect the file type from the `type` field of the first `file_id` message, only the messages
+/// up to the `file_id` message are parsed. `None` is returned if the file lacks the message.
+pub fn file_type(mut buffer: &[u8]) -> Result<Option<File>> {
+    let mut deserializer = Deserializer::new();
+    deserializer
+        .options_mut()
+        .insert(DecodeOption::CrcPolicy(CrcPolicy::Skip));
     while !buffer.is_empty() {
-        let (buf, obj) = processor.deserialize_next(buffer)?;
-        match obj {
-            FitObject::Crc(..) => processor.reset(),
-            FitObject::Header(..) => {}
-            FitObject::DataMessage(msg) => {
-                let rec = processor.decode_message(msg)?;
-                // drop the unknown messages if desired but we still need to
-                // decode them just incase the header contains a time-offset
-                // otherwise we'll get incorrect timestamps down the line
-                if processor
-                    .options()
-                    .contains(&DecodeOption::DropUnknownMessages)
-                {
-                    if MesgNum::is_named_variant(rec.kind().as_i64()) {
-                        records.push(rec);
-                    }
-                } else {
-                    records.push(rec);
-                }
+        let (buf, obj) = deserializer.deserialize_next(buffer)?;
+        if let FitObject::DataMessage(msg) = obj {
+            if MesgNum::from(msg.global_message_number()) == MesgNum::FileId {
+                let value = msg.fields().get(&0).cloned();
+                return Ok(value
+                    .and_then(|v| v.try_into().ok())
+                    .map(|v: i64| File::from(v)));
             }
-            FitObject::DefinitionMessage(..) => {}
         }
         buffer = buf;
     }
+    Ok(None)
+}
 
-    Ok(records)
+/// Deserialize a FIT file using the decode options preset for its file type, see
+/// `DecodeOption::preset`. Files without a `file_id` message use the default options.
+pub fn from_bytes_with_preset(buffer: &[u8]) -> Result<Vec<FitDataRecord>> {
+    let options = file_type(buffer)?
+        .map(DecodeOption::preset)
+        .unwrap_or_default();
+    from_bytes_with_options(buffer, &options)
 }
 
 /// Deserialize a FIT file stored as an array of bytes and return the decoded data messages.
@@ -306,13 +804,46 @@ pub fn from_bytes(buffer: &[u8]) -> Result<Vec<FitDataRecord>> {
     from_bytes_with_options(buffer, &HashSet::new())
 }
 
-/// Deserialize a FIT file stored in a source that implements io::Read, with additional decode options
+/// Largest buffer reserved up front from the data size of a file header, a corrupt header
+/// shouldn't be able to request gigabytes of memory
+const MAX_RESERVED_BUFFER: usize = 64 * 1024 * 1024;
+
+/// Read a complete FIT stream into memory. The buffer is sized from the data size in the file
+/// header so the remaining bytes are read with a few large reads straight into their final
+/// location, which keeps unbuffered readers like a `File` as fast as decoding from a slice.
+/// Chained files simply grow the buffer past the first file.
+fn read_stream<T: Read>(source: &mut T) -> Result<Vec<u8>> {
+    let mut buffer = Vec::with_capacity(14);
+    source
+        .by_ref()
+        .take(12)
+        .read_to_end(&mut buffer)
+        .map_err(|e| read_error(e, buffer.len()))?;
+    if buffer.len() == 12 && &buffer[8..12] == b".FIT" {
+        let header_size = buffer[0] as usize;
+        let data_size = u32::from_le_bytes([buffer[4], buffer[5], buffer[6], buffer[7]]) as usize;
+        let total = (header_size + data_size + 2).min(MAX_RESERVED_BUFFER);
+        buffer.reserve(total.saturating_sub(buffer.len()));
+    }
+    source
+        .read_to_end(&mut buffer)
+        .map_err(|e| read_error(e, buffer.len()))?;
+    Ok(buffer)
+}
+
+/// Error reading a FIT stream after the given number of bytes
+fn read_error(error: std::io::Error, offset: usize) -> crate::Error {
+    ErrorKind::ReadError(error, ErrorContext::at(offset)).into()
+}
+
+/// Deserialize a FIT file stored in a source that implements io::Read, with additional decode
+/// options. The source doesn't need to be buffered, wrapping it in a `BufReader` only adds a copy.
+/// The whole file is read into memory, `FitStreamReader` decodes large files incrementally.
 pub fn from_reader_with_options<T: Read>(
     source: &mut T,
     options: &HashSet<DecodeOption>,
 ) -> Result<Vec<FitDataRecord>> {
-    let mut buffer = Vec::new();
-    source.read_to_end(&mut buffer)?;
+    let buffer = read_stream(source)?;
     from_bytes_with_options(&buffer, options)
 }
 
@@ -320,3 +851,276 @@ pub fn from_reader_with_options<T: Read>(
 pub fn from_reader<T: Read>(source: &mut T) -> Result<Vec<FitDataRecord>> {
     from_reader_with_options(source, &HashSet::new())
 }
+
+/// Deserialize the FIT file located at the path, with additional decode options
+pub fn from_path_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &HashSet<DecodeOption>,
+) -> Result<Vec<FitDataRecord>> {
+    let buffer = fs::read(path)?;
+    from_bytes_with_options(&buffer, options)
+}
+
+/// Deserialize the FIT file located at the path.
+pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Vec<FitDataRecord>> {
+    from_path_with_options(path, &HashSet::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recover_corrupt_data() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let options: HashSet<_> = [DecodeOption::RecoverCorruptData].into_iter().collect();
+        let expected = from_bytes(data).unwrap();
+        let json = |records: &[FitDataRecord]| serde_json::to_value(records).unwrap();
+
+        // bad CRC
+        let mut corrupt = data.to_vec();
+        let last = corrupt.len() - 1;
+        corrupt[last] ^= 0xFF;
+        assert!(from_bytes(&corrupt).is_err());
+        let records = from_bytes_with_options(&corrupt, &options).unwrap();
+        assert_eq!(json(&records), json(&expected));
+
+        // truncated mid-message
+        let truncated = &data[..data.len() * 3 / 5 + 1];
+        assert!(from_bytes(truncated).is_err());
+        let records = from_bytes_with_options(truncated, &options).unwrap();
+        assert!(!records.is_empty());
+        assert_eq!(json(&records), json(&expected[..records.len()]));
+
+        // garbage in the middle of the file
+        let mut corrupt = data.to_vec();
+        let middle = corrupt.len() / 2;
+        corrupt[middle..middle + 64].fill(0x4F);
+        assert!(from_bytes(&corrupt).is_err());
+        let records = from_bytes_with_options(&corrupt, &options).unwrap();
+        assert_eq!(
+            json(&records[records.len() - 10..]),
+            json(&expected[expected.len() - 10..])
+        );
+        let streamed: Vec<_> = FitStreamReader::with_options(&corrupt[..], &options)
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(json(&streamed), json(&records));
+    }
+
+    #[test]
+    fn local_time_options() {
+        use crate::profile::MesgNum;
+        use crate::timestamp::UnixSeconds;
+        use crate::Value;
+
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let local_timestamp = |options: &[DecodeOption]| {
+            let options = options.iter().cloned().collect();
+            let records = from_bytes_with_options(data, &options).unwrap();
+            let activity = records
+                .iter()
+                .find(|r| r.is_kind(MesgNum::Activity))
+                .unwrap();
+            let timestamp = match activity.field("timestamp").unwrap().value() {
+                Value::Timestamp(time) => *time,
+                value => panic!("unexpected timestamp {:?}", value),
+            };
+            (
+                timestamp,
+                activity.field("local_timestamp").unwrap().value().clone(),
+            )
+        };
+        let (timestamp, value) = local_timestamp(&[DecodeOption::NaiveLocalTimes]);
+        let wall_clock = match value {
+            Value::LocalTimestamp(time) => time,
+            value => panic!("unexpected local timestamp {:?}", value),
+        };
+        let offset = (wall_clock.unix_seconds() - timestamp.unix_seconds()) as i32;
+        // recorded at UTC-7
+        let json = serde_json::to_value(Value::LocalTimestamp(wall_clock)).unwrap();
+        assert_eq!(json, "2017-06-12T09:10:55");
+        assert_eq!(offset, -7 * 3600);
+        let (_, value) = local_timestamp(&[
+            DecodeOption::LocalTimeOffset(offset),
+            DecodeOption::NaiveLocalTimes,
+        ]);
+        assert_eq!(value, Value::Timestamp(timestamp));
+        let (_, value) = local_timestamp(&[DecodeOption::LocalTimeOffset(offset + 3600)]);
+        let earlier = timestamp::local(timestamp.unix_seconds() - 3600);
+        assert_eq!(value, Value::Timestamp(earlier));
+    }
+
+    #[test]
+    fn utc_timestamps() {
+        use crate::fixtures::FitFileBuilder;
+        use crate::profile::MesgNum;
+        use crate::Value;
+
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let options = [DecodeOption::UtcTimestamps].into();
+        let local = from_bytes(data).unwrap();
+        let utc = from_bytes_with_options(data, &options).unwrap();
+        for (local, utc) in local.iter().zip(&utc) {
+            let (local, utc) = match (local.field("timestamp"), utc.field("timestamp")) {
+                (Some(local), Some(utc)) => (local.value(), utc.value()),
+                _ => continue,
+            };
+            match (local, utc) {
+                (Value::Timestamp(local), Value::UtcTimestamp(utc)) => {
+                    assert_eq!(timestamp::to_utc(local), *utc)
+                }
+                values => panic!("unexpected timestamps {:?}", values),
+            }
+        }
+        // local_date_time fields aren't affected
+        let activity = utc.iter().find(|r| r.is_kind(MesgNum::Activity)).unwrap();
+        assert!(matches!(
+            activity.field("local_timestamp").unwrap().value(),
+            Value::Timestamp(_)
+        ));
+        let json = serde_json::to_value(activity.field("timestamp").unwrap().value()).unwrap();
+        assert_eq!(json, "2017-06-12T16:10:55Z");
+        // encoded back into the same seconds since the FIT epoch
+        let encoded = crate::ser::to_bytes(&utc).unwrap();
+        assert_eq!(
+            serde_json::to_value(from_bytes(&encoded).unwrap()).unwrap(),
+            serde_json::to_value(&local).unwrap()
+        );
+
+        // 2024-05-01 08:00:00 UTC
+        let start = timestamp::utc(1_714_550_400);
+        let file = FitFileBuilder::new()
+            .message(
+                MesgNum::Record,
+                &[(253, Value::UtcTimestamp(start)), (3, Value::UInt8(120))],
+            )
+            .compressed_timestamp_message(MesgNum::Record, 5, &[(3, Value::UInt8(125))])
+            .build();
+        let records = from_bytes_with_options(&file, &options).unwrap();
+        assert_eq!(
+            records[1].field("timestamp").unwrap().value(),
+            &Value::UtcTimestamp(timestamp::utc(1_714_550_405))
+        );
+    }
+
+    #[test]
+    fn crc_policies() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let expected = from_bytes(data).unwrap().len();
+        let policy = |p| {
+            [DecodeOption::CrcPolicy(p)]
+                .into_iter()
+                .collect::<HashSet<_>>()
+        };
+        let mut corrupt = data.to_vec();
+        let last = corrupt.len() - 1;
+        corrupt[last] ^= 0xFF;
+        corrupt[12] ^= 0xFF;
+
+        let err = from_bytes_with_options(&corrupt, &policy(CrcPolicy::Strict)).unwrap_err();
+        assert!(matches!(*err, ErrorKind::InvalidCrc(..)));
+        let (records, warnings) =
+            from_bytes_with_crc_warnings(&corrupt, &policy(CrcPolicy::WarnOnly)).unwrap();
+        assert_eq!(records.len(), expected);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].header && !warnings[1].header);
+        assert_eq!(
+            warnings[1].expected,
+            u16::from_le_bytes([data[last - 1], !data[last]])
+        );
+        assert_eq!(
+            warnings[1].calculated,
+            u16::from_le_bytes([data[last - 1], data[last]])
+        );
+        let (records, warnings) =
+            from_bytes_with_crc_warnings(&corrupt, &policy(CrcPolicy::Skip)).unwrap();
+        assert_eq!(records.len(), expected);
+        assert!(warnings.is_empty());
+
+        let mut reader = FitStreamReader::with_options(&corrupt[..], &policy(CrcPolicy::WarnOnly));
+        assert_eq!(reader.by_ref().count(), expected);
+        assert_eq!(reader.crc_warnings().len(), 2);
+    }
+
+    #[test]
+    fn filter_messages() {
+        let json = |records: Vec<&FitDataRecord>| serde_json::to_value(records).unwrap();
+        for (data, kinds) in [
+            (
+                &include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit")[..],
+                &[MesgNum::Session, MesgNum::Lap][..],
+            ),
+            (
+                &include_bytes!("../../tests/fixtures/DeveloperData.fit")[..],
+                &[MesgNum::Record][..],
+            ),
+        ] {
+            let options = DecodeOption::with_message_filter(kinds);
+            let filtered = from_bytes_with_options(data, &options).unwrap();
+            assert!(!filtered.is_empty());
+            let records = from_bytes(data).unwrap();
+            let expected = records.iter().filter(|r| kinds.contains(&r.kind()));
+            assert_eq!(json(filtered.iter().collect()), json(expected.collect()));
+        }
+        let data = include_bytes!("../../tests/fixtures/DeveloperData.fit");
+        let options = DecodeOption::with_message_filter(&[MesgNum::Record]);
+        let records = from_bytes_with_options(data, &options).unwrap();
+        assert!(records[0].fields().iter().any(|f| f.is_developer_field()));
+    }
+
+    #[test]
+    fn accumulated_fields() {
+        use crate::{FitDataField, Value};
+        let field = |name: &str, number, value| {
+            FitDataField::new(name.to_string(), number, value, String::new())
+        };
+        let mut file_id = FitDataRecord::new(MesgNum::FileId);
+        file_id.push(field("type", 0, Value::String("activity".to_string())));
+        let mut records = vec![file_id];
+        // the 12 bit distance rolls over between the second and third record, the fifth record
+        // stores the distance in full and the compressed distances continue from it
+        for (speed, distance) in [(250, 4000), (300, 4090), (310, 10), (320, 100)] {
+            let packed: u32 = speed | (distance << 12);
+            let bytes = packed.to_le_bytes()[..3]
+                .iter()
+                .map(|b| Value::Byte(*b))
+                .collect();
+            let mut record = FitDataRecord::new(MesgNum::Record);
+            record.push(field("compressed_speed_distance", 8, Value::Array(bytes)));
+            records.push(record);
+        }
+        let mut record = FitDataRecord::new(MesgNum::Record);
+        record.push(field("distance", 5, Value::Float64(1000.0)));
+        records.push(record);
+        let mut record = FitDataRecord::new(MesgNum::Record);
+        let packed: u32 = 330 | (((16_000 + 160) % 4096) << 12);
+        let bytes = packed.to_le_bytes()[..3]
+            .iter()
+            .map(|b| Value::Byte(*b))
+            .collect();
+        record.push(field("compressed_speed_distance", 8, Value::Array(bytes)));
+        records.push(record);
+
+        let encoded = crate::ser::to_bytes(&records).unwrap();
+        let distances: Vec<f64> = from_bytes(&encoded)
+            .unwrap()
+            .iter()
+            .filter_map(|r| r.field("distance")?.value().clone().try_into().ok())
+            .collect();
+        let expected: Vec<f64> = [4000, 4090, 4106, 4196, 16_000, 16_160]
+            .iter()
+            .map(|d| *d as f64 / 16.0)
+            .collect();
+        assert_eq!(distances, expected);
+
+        let mut accumulators = Accumulators::new();
+        let timestamps = Value::Array([4000, 4090, 5].map(Value::UInt64).to_vec());
+        assert_eq!(
+            accumulators
+                .accumulate(MesgNum::Hr, 9, timestamps, 12, 1024.0, 0.0)
+                .unwrap(),
+            Value::Array([4000, 4090, 4101].map(Value::UInt64).to_vec())
+        );
+    }
+}
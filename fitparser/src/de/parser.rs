@@ -1,4 +1,5 @@
 //! Helper functions and structures needed to parse a FIT file.
+use super::borrowed;
 use crate::Value;
 use nom::bytes::streaming::{tag, take};
 use nom::combinator::cond;
@@ -9,8 +10,9 @@ use nom::number::streaming::{
 use nom::number::Endianness;
 use nom::sequence::tuple;
 use nom::{Err, IResult, Needed};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::convert::From;
+use std::ops::Range;
 use std::sync::Arc;
 
 /// Define an is_valid function needed for parsing here, this function is not needed for normal use
@@ -25,7 +27,8 @@ impl Value {
             Value::SInt32(val) => *val != 0x7FFF_FFFF,
             Value::UInt32(val) => *val != 0xFFFF_FFFF,
             Value::String(val) => !val.contains('\0'),
-            Value::Timestamp(_) => true, // timestamps are always valid
+            Value::Timestamp(_) | Value::UtcTimestamp(_) | Value::LocalTimestamp(_) => true, // timestamps are always valid
+            Value::Duration(_) => true,
             Value::Float32(val) => val.is_finite(),
             Value::Float64(val) => val.is_finite(),
             Value::UInt8z(val) => *val != 0x0,
@@ -108,9 +111,9 @@ enum FitMessageType {
 /// The value of the bits inside is different for the two message header types but for simplicity
 /// we treat them the same here and make the time_offset optionnal.
 #[derive(Clone, Debug)]
-struct FitMessageHeader {
+pub(crate) struct FitMessageHeader {
     contains_developer_data: bool,
-    local_message_number: u8,
+    pub(crate) local_message_number: u8,
     message_type: FitMessageType,
     time_offset: Option<u8>,
 }
@@ -191,6 +194,23 @@ pub struct FieldDefinition {
     base_type: BaseType,
 }
 
+impl FieldDefinition {
+    /// Field definition number within the global message
+    pub fn field_definition_number(&self) -> u8 {
+        self.field_definition_number
+    }
+
+    /// Size of the field in bytes
+    pub fn size(&self) -> u8 {
+        self.size
+    }
+
+    /// Base type the field is encoded with
+    pub fn base_type(&self) -> BaseType {
+        self.base_type
+    }
+}
+
 /// Developer data fields allow for files to define the meaning of data without requiring changes to
 /// the FIT profile being used. Rather than having information like Field Name, Units, and Base Type
 /// encoded into the profile this information is included in 2 special global messages that act as
@@ -201,6 +221,59 @@ pub struct DeveloperFieldDefinition {
     field_number: u8,
     size: u8,
     developer_data_index: u8,
+    byte_order: Endianness,
+}
+
+impl DeveloperFieldDefinition {
+    /// Field number within the developer data set, maps to a field description message
+    pub fn field_number(&self) -> u8 {
+        self.field_number
+    }
+
+    /// Size of the field in bytes
+    pub fn size(&self) -> u8 {
+        self.size
+    }
+
+    /// Index of the developer data id message that defines this field
+    pub fn developer_data_index(&self) -> u8 {
+        self.developer_data_index
+    }
+
+    /// Byte order of the field data, set by the containing definition message
+    pub fn byte_order(&self) -> Endianness {
+        self.byte_order
+    }
+}
+
+/// Reinterpret the raw bytes of a developer field using the base type from its field description,
+/// `None` is returned if the bytes don't fit the type or hold an invalid value
+pub(crate) fn developer_field_value(
+    raw: &Value,
+    def: &DeveloperFieldDefinition,
+    base_type: u8,
+) -> Option<Value> {
+    let bytes: Vec<u8> = match raw {
+        Value::Array(values) => values
+            .iter()
+            .map(|v| match v {
+                Value::UInt8(b) => Some(*b),
+                _ => None,
+            })
+            .collect::<Option<Vec<u8>>>()?,
+        Value::UInt8(b) => vec![*b],
+        _ => return None,
+    };
+    let base_type = BaseType::from(base_type);
+    if bytes.is_empty()
+        || !bytes.len().is_multiple_of(base_type.size() as usize)
+        || bytes.len() > 255
+    {
+        return None;
+    }
+    data_field_value(&bytes, base_type, def.byte_order, bytes.len() as u8)
+        .ok()
+        .and_then(|(_, v)| v)
 }
 
 /// Stores a vector of raw fields described by the preceding Definition message, a Definition message
@@ -209,9 +282,11 @@ pub struct DeveloperFieldDefinition {
 #[derive(Clone, Debug)]
 pub struct FitDataMessage {
     global_message_number: u16,
+    local_message_number: u8,
+    offset: usize,
     time_offset: Option<u8>,
     fields: HashMap<u8, Value>,
-    developer_fields: Vec<Value>,
+    developer_fields: Vec<(DeveloperFieldDefinition, Value)>,
 }
 
 impl FitDataMessage {
@@ -220,12 +295,29 @@ impl FitDataMessage {
         self.global_message_number
     }
 
+    /// Local message number from the message header
+    pub fn local_message_number(&self) -> u8 {
+        self.local_message_number
+    }
+
+    /// Absolute byte offset of the message header in the FIT stream
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Set the absolute offset, the parser only sees a slice of the stream so this is done by
+    /// the deserializer which tracks the position
+    pub(crate) fn set_offset(&mut self, offset: usize) {
+        self.offset = offset;
+    }
+
     /// Time offset used to generate a full timestamp from a reference time
     pub fn time_offset(&self) -> Option<u8> {
         self.time_offset
     }
 
-    /// Data field mapping of <field_number, Value>
+    /// Data field mapping of <field_number, Value>, field numbers that the definition message
+    /// lists more than once hold a `Value::Array` with the value of each occurrence
     pub fn fields(&self) -> &HashMap<u8, Value> {
         &self.fields
     }
@@ -235,39 +327,55 @@ impl FitDataMessage {
         &mut self.fields
     }
 
-    #[allow(dead_code)]
-    /// Developer field data
-    pub fn developer_fields(&self) -> &[Value] {
+    /// Developer field data along with the definition used to read it
+    pub fn developer_fields(&self) -> &[(DeveloperFieldDefinition, Value)] {
         &self.developer_fields
     }
 }
 
 /// Base types defined by the FIT protocol. The "z" variants have a different invalid value
 /// than the versions without the suffix.
-#[derive(Clone, Copy, Debug)]
-enum BaseType {
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BaseType {
+    /// Unsigned 8bit value of a profile enum
     Enum = 0x00,
+    /// Signed 8bit integer
     SInt8 = 0x01,
+    /// Unsigned 8bit integer
     UInt8 = 0x02,
+    /// Signed 16bit integer
     SInt16 = 0x83,
+    /// Unsigned 16bit integer
     UInt16 = 0x84,
+    /// Signed 32bit integer
     SInt32 = 0x85,
+    /// Unsigned 32bit integer
     UInt32 = 0x86,
+    /// Null terminated UTF-8 string
     String = 0x07,
+    /// 32bit floating point number
     Float32 = 0x88,
+    /// 64bit floating point number
     Float64 = 0x89,
+    /// Unsigned 8bit integer where 0 is invalid
     UInt8z = 0x0A,
+    /// Unsigned 16bit integer where 0 is invalid
     UInt16z = 0x8B,
+    /// Unsigned 32bit integer where 0 is invalid
     UInt32z = 0x8C,
+    /// Array of bytes
     Byte = 0x0D,
+    /// Signed 64bit integer
     SInt64 = 0x8E,
+    /// Unsigned 64bit integer
     UInt64 = 0x8F,
+    /// Unsigned 64bit integer where 0 is invalid
     UInt64z = 0x90,
 }
 
 impl BaseType {
     /// The size for fixed width numeric values, for variable Llength types it's the smallest chunk size.
-    fn size(&self) -> u8 {
+    pub fn size(&self) -> u8 {
         match *self {
             BaseType::Enum => 1,
             BaseType::SInt8 => 1,
@@ -356,6 +464,24 @@ fn fit_file_header_impl(input: &[u8]) -> IResult<&[u8], FitFileHeader> {
     ))
 }
 
+/// Byte ranges of the files stored in a FIT stream. Devices often chain several files back to
+/// back, each with its own header, data and CRC. Scanning stops at the first range that doesn't
+/// start with a file header and the last range ends with the input if the file is truncated.
+pub fn file_ranges(input: &[u8]) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while let Ok((_, header)) = fit_file_header(&input[start..]) {
+        let size = header.header_size as usize + header.data_size as usize + 2;
+        let end = (start + size).min(input.len());
+        ranges.push(start..end);
+        if end == input.len() {
+            break;
+        }
+        start = end;
+    }
+    ranges
+}
+
 /// Convert a split decimal style value with fix precision into a single floating point value
 fn split_decimal_to_float(left: u16, right: u16) -> f32 {
     let scale = ((right as f32).log10() + 1f32).floor() as i32;
@@ -383,6 +509,8 @@ pub fn fit_message<'a>(
                         fields,
                         developer_fields,
                         global_message_number: def_mesg.global_message_number,
+                        local_message_number: header.local_message_number,
+                        offset: 0,
                         time_offset: header.time_offset,
                     }),
                 ))
@@ -402,7 +530,7 @@ pub fn fit_message<'a>(
 }
 
 /// Parse the header of a single FIT message
-fn message_header(input: &[u8]) -> IResult<&[u8], FitMessageHeader> {
+pub(crate) fn message_header(input: &[u8]) -> IResult<&[u8], FitMessageHeader> {
     let (input, msg_header_byte) = le_u8(input)?;
     let contains_developer_data: bool;
     let local_message_number: u8;
@@ -439,6 +567,28 @@ fn message_header(input: &[u8]) -> IResult<&[u8], FitMessageHeader> {
     ))
 }
 
+/// Check if the bytes look like the start of a definition message, used to find the next
+/// message after corrupt data. The reserved bits and bytes must be zero and every field must
+/// have a known base type and a size that's a multiple of it.
+pub(crate) fn is_definition_start(input: &[u8]) -> bool {
+    let (header, arch, number_of_fields) = match input {
+        [header, 0, arch, _, _, number_of_fields, ..] => (*header, *arch, *number_of_fields),
+        _ => return false,
+    };
+    if header & 0xD0 != 0x40 || arch > 1 || number_of_fields == 0 {
+        return false;
+    }
+    let fields = match input.get(6..6 + 3 * number_of_fields as usize) {
+        Some(fields) => fields,
+        None => return false,
+    };
+    fields.chunks(3).all(|field| {
+        // unknown base types are parsed as bytes
+        let base_type = BaseType::from(field[2]);
+        base_type as u8 == field[2] && field[1] > 0 && field[1] % base_type.size() == 0
+    })
+}
+
 /// parse a definition message
 fn definition_message<'a>(
     input: &'a [u8],
@@ -456,7 +606,10 @@ fn definition_message<'a>(
     let (input, field_definitions) = count(field_definition, number_of_fields as usize)(input)?;
     let (input, developer_field_definitions) = if header.contains_developer_data {
         let (input, nflds) = le_u8(input)?;
-        let (input, dev_fld_defs) = count(developer_field_definition, nflds as usize)(input)?;
+        let (input, mut dev_fld_defs) = count(developer_field_definition, nflds as usize)(input)?;
+        dev_fld_defs
+            .iter_mut()
+            .for_each(|d| d.byte_order = byte_order);
         (input, dev_fld_defs)
     } else {
         (input, Vec::new())
@@ -513,16 +666,19 @@ fn developer_field_definition(input: &[u8]) -> IResult<&[u8], DeveloperFieldDefi
             field_number,
             size,
             developer_data_index,
+            byte_order: Endianness::Little,
         },
     ))
 }
 
+/// Native fields by definition number and developer fields with their definition
+type DataMessageFields = (HashMap<u8, Value>, Vec<(DeveloperFieldDefinition, Value)>);
+
 /// Parse a data message
-#[allow(clippy::type_complexity)]
 fn data_message_fields<'a>(
     input: &'a [u8],
     def_mesg: &FitDefinitionMessage,
-) -> IResult<&'a [u8], (HashMap<u8, Value>, Vec<Value>)> {
+) -> IResult<&'a [u8], DataMessageFields> {
     match data_message_fields_impl(input, def_mesg) {
         Ok(r) => Ok(r),
         Err(Err::Incomplete(_)) => {
@@ -541,8 +697,9 @@ fn data_message_fields<'a>(
 fn data_message_fields_impl<'a>(
     input: &'a [u8],
     def_mesg: &FitDefinitionMessage,
-) -> IResult<&'a [u8], (HashMap<u8, Value>, Vec<Value>)> {
+) -> IResult<&'a [u8], DataMessageFields> {
     let mut fields = HashMap::new();
+    let mut repeated = HashSet::new();
     let mut input = input;
     for field_def in &def_mesg.field_definitions {
         let (i, value) = data_field_value(
@@ -552,12 +709,17 @@ fn data_message_fields_impl<'a>(
             field_def.size,
         )?;
         if let Some(value) = value {
-            fields.insert(field_def.field_definition_number, value);
+            insert_field_value(
+                &mut fields,
+                &mut repeated,
+                field_def.field_definition_number,
+                value,
+            );
         }
         input = i;
     }
-    // store developer data as a byte array since we don't handle these fields in the final data
-    // output yet
+    // store developer data as a byte array, the field description needed to interpret the bytes
+    // is only known to the decoder
     let mut developer_fields = Vec::new();
     for field_def in &def_mesg.developer_field_definitions {
         let (i, value) = data_field_value(
@@ -567,7 +729,7 @@ fn data_message_fields_impl<'a>(
             field_def.size,      // the whole thing as needed might not be valid if the field isn't
         )?; // a single integer value.
         if let Some(value) = value {
-            developer_fields.push(value);
+            developer_fields.push((field_def.clone(), value));
         }
         input = i;
     }
@@ -575,6 +737,129 @@ fn data_message_fields_impl<'a>(
     Ok((input, (fields, developer_fields)))
 }
 
+/// A data message whose strings and byte arrays borrow from the parsed input
+pub(crate) struct BorrowedDataMessage<'a> {
+    pub(crate) global_message_number: u16,
+    pub(crate) time_offset: Option<u8>,
+    /// Fields in the order of the definition message, repeated field numbers are grouped into
+    /// an array like the fields of a `FitDataMessage`
+    pub(crate) fields: Vec<(u8, borrowed::Value<'a>)>,
+    pub(crate) developer_fields: Vec<(DeveloperFieldDefinition, &'a [u8])>,
+}
+
+/// Parse the next message without copying its strings and byte arrays if it's a data message.
+/// `None` is returned for definition messages and data messages without a definition, leaving
+/// them to `fit_message`.
+pub(crate) fn borrowed_data_message<'a>(
+    input: &'a [u8],
+    definitions: &HashMap<u8, Arc<FitDefinitionMessage>>,
+) -> IResult<&'a [u8], Option<BorrowedDataMessage<'a>>> {
+    let (remaining, header) = message_header(input)?;
+    let def_mesg = match (
+        header.message_type,
+        definitions.get(&header.local_message_number),
+    ) {
+        (FitMessageType::Data, Some(def_mesg)) => def_mesg,
+        _ => return Ok((input, None)),
+    };
+    let mut input = remaining;
+    let mut fields: Vec<(u8, borrowed::Value)> =
+        Vec::with_capacity(def_mesg.field_definitions.len());
+    let mut repeated = Vec::new();
+    for field_def in &def_mesg.field_definitions {
+        let (i, value) = borrowed_field_value(
+            input,
+            field_def.base_type,
+            def_mesg.byte_order,
+            field_def.size,
+        )?;
+        input = i;
+        let value = match value {
+            Some(value) => value,
+            None => continue,
+        };
+        let number = field_def.field_definition_number;
+        match fields.iter_mut().find(|(n, _)| *n == number) {
+            Some((_, borrowed::Value::Array(values))) if repeated.contains(&number) => {
+                values.push(value)
+            }
+            Some((_, existing)) => {
+                let first = std::mem::replace(existing, borrowed::Value::Array(Vec::new()));
+                *existing = borrowed::Value::Array(vec![first, value]);
+                repeated.push(number);
+            }
+            None => fields.push((number, value)),
+        }
+    }
+    let mut developer_fields = Vec::new();
+    for field_def in &def_mesg.developer_field_definitions {
+        let (i, bytes) = take(field_def.size as usize)(input)?;
+        input = i;
+        if bytes.iter().any(|b| *b != 0xFF) {
+            developer_fields.push((field_def.clone(), bytes));
+        }
+    }
+    Ok((
+        input,
+        Some(BorrowedDataMessage {
+            global_message_number: def_mesg.global_message_number,
+            time_offset: header.time_offset,
+            fields,
+            developer_fields,
+        }),
+    ))
+}
+
+/// Parse a single data value, strings and byte arrays borrow from the input and are checked for
+/// validity the same way `data_field_value` does
+fn borrowed_field_value(
+    input: &[u8],
+    base_type: BaseType,
+    byte_order: Endianness,
+    size: u8,
+) -> IResult<&[u8], Option<borrowed::Value<'_>>> {
+    match base_type {
+        BaseType::String => {
+            let (input, bytes) = take(size as usize)(input)?;
+            let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+            let value = std::str::from_utf8(&bytes[..end])
+                .ok()
+                .map(borrowed::Value::String);
+            Ok((input, value))
+        }
+        BaseType::Byte => {
+            let (input, bytes) = take(size as usize)(input)?;
+            let is_valid = bytes.iter().any(|b| *b != 0xFF);
+            Ok((input, is_valid.then_some(borrowed::Value::Byte(bytes))))
+        }
+        _ => data_field_value(input, base_type, byte_order, size)
+            .map(|(input, value)| (input, value.map(borrowed::Value::from))),
+    }
+}
+
+/// Store a field value, a definition message may legally list the same field number more than
+/// once in which case the values of every occurrence are grouped into a `Value::Array` in the
+/// order they appear. Invalid occurrences are skipped so a repeated field with a single valid
+/// occurrence is stored as a plain value.
+fn insert_field_value(
+    fields: &mut HashMap<u8, Value>,
+    repeated: &mut HashSet<u8>,
+    number: u8,
+    value: Value,
+) {
+    match fields.get_mut(&number) {
+        Some(Value::Array(values)) if repeated.contains(&number) => values.push(value),
+        Some(existing) => {
+            let first = std::mem::replace(existing, Value::Array(Vec::new()));
+            *existing = Value::Array(vec![first, value]);
+            repeated.insert(number);
+        }
+        None => {
+            fields.insert(number, value);
+        }
+    }
+}
+
 /// Parse a single raw data value.
 ///
 /// This can panic if the size is greater than `255 - base_type.size()` but that should only
@@ -638,8 +923,13 @@ fn data_field_value(
         Value::Array(values)
     };
 
-    // Only return "something" if it's in the valid range
-    if value.is_valid() {
+    // Only return "something" if it's in the valid range, a byte array is only invalid when
+    // every byte is, e.g. packed components like `compressed_speed_distance` can hold 0xFF bytes
+    let is_valid = match (&value, base_type) {
+        (Value::Array(values), BaseType::Byte) => values.iter().any(|v| v.is_valid()),
+        (value, _) => value.is_valid(),
+    };
+    if is_valid {
         Ok((input, Some(value)))
     } else {
         Ok((input, None))
@@ -647,6 +937,11 @@ fn data_field_value(
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::assertions_on_constants,
+    clippy::bool_assert_comparison,
+    clippy::single_match
+)]
 mod tests {
     use super::*;
 
@@ -695,7 +990,7 @@ mod tests {
             Some(_) => assert!(false, "None should be returned for invalid bytes."),
             None => {}
         }
-        assert_eq!(rem, &[]);
+        assert!(rem.is_empty());
 
         // parse two byte values with defined endianess
         let (rem, val) = data_field_value(&data, BaseType::UInt16, Endianness::Big, 2).unwrap();
@@ -703,14 +998,14 @@ mod tests {
             Some(v) => assert_eq!(v, Value::UInt16(0x01FF)),
             None => assert!(false, "No value returned."),
         }
-        assert_eq!(rem, &[]);
+        assert!(rem.is_empty());
 
         let (rem, val) = data_field_value(&data, BaseType::UInt16, Endianness::Little, 2).unwrap();
         match val {
             Some(v) => assert_eq!(v, Value::UInt16(0xFF01)),
             None => assert!(false, "No value returned."),
         }
-        assert_eq!(rem, &[]);
+        assert!(rem.is_empty());
     }
 
     #[test]
@@ -739,7 +1034,13 @@ mod tests {
             Some(_) => assert!(false, "None should be returned for invalid bytes."),
             None => {}
         }
-        assert_eq!(rem, &[]);
+        assert!(rem.is_empty());
+
+        // byte arrays are only invalid if every byte is
+        let (_, val) = data_field_value(&data, BaseType::Byte, Endianness::Native, 5).unwrap();
+        assert!(matches!(val, Some(Value::Array(values)) if values.len() == 5));
+        let (_, val) = data_field_value(&[0xFF; 3], BaseType::Byte, Endianness::Native, 3).unwrap();
+        assert!(val.is_none());
 
         match val {
             Some(_) => assert!(
@@ -748,7 +1049,7 @@ mod tests {
             ),
             None => {}
         }
-        assert_eq!(rem, &[]);
+        assert!(rem.is_empty());
     }
 
     #[test]
@@ -0,0 +1,76 @@
+//! Recognize FIT files from their first bytes without decoding them, e.g. to route uploads
+//! between parsers for different file types.
+use super::crc::caculate_crc;
+use super::parser::fit_file_header;
+
+/// What the header of a FIT file declares, returned by `sniff`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FitHeaderInfo {
+    /// Length of the header in bytes, 12 or 14
+    pub header_size: u8,
+    /// Protocol version, e.g. `2.0`
+    pub protocol_version: f32,
+    /// Profile version, e.g. `21.32`
+    pub profile_version: f32,
+    /// Length of the data records in bytes
+    pub data_size: u32,
+    /// Header CRC, `None` for 12 byte headers and headers with a zero CRC
+    pub header_crc: Option<u16>,
+    /// The header CRC matches the first 12 bytes, also true without a header CRC
+    pub header_crc_valid: bool,
+}
+
+impl FitHeaderInfo {
+    /// Length of the whole file including the header and trailing CRC, the first file of
+    /// chained files
+    pub fn file_size(&self) -> usize {
+        self.header_size as usize + self.data_size as usize + 2
+    }
+}
+
+/// Check for the `.FIT` signature at the start of the bytes and return what the header declares.
+/// Only the header is read, `None` if the bytes don't start with a FIT header of at least 12
+/// bytes.
+pub fn sniff(bytes: &[u8]) -> Option<FitHeaderInfo> {
+    let (_, header) = fit_file_header(bytes).ok()?;
+    if header.header_size() < 12 {
+        return None;
+    }
+    let header_crc = header.crc().filter(|&crc| crc != 0);
+    Some(FitHeaderInfo {
+        header_size: header.header_size(),
+        protocol_version: header.protocol_ver_enc(),
+        profile_version: header.profile_ver_enc(),
+        data_size: header.data_size(),
+        header_crc,
+        header_crc_valid: header_crc.is_none_or(|crc| crc == caculate_crc(&bytes[..12])),
+    })
+}
+
+/// Whether the bytes start with a FIT file header
+pub fn is_fit(bytes: &[u8]) -> bool {
+    sniff(bytes).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sniff_header() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let info = sniff(&data[..14]).unwrap();
+        assert_eq!(info.header_size, 14);
+        assert_eq!(info.file_size(), data.len());
+        assert!(info.header_crc_valid);
+        assert!(is_fit(data));
+
+        let mut corrupt = data[..14].to_vec();
+        corrupt[4] ^= 0xFF;
+        assert!(!sniff(&corrupt).unwrap().header_crc_valid);
+        assert!(!is_fit(&data[..10]));
+        let short = include_bytes!("../../tests/fixtures/Activity.fit");
+        assert_eq!(sniff(short).unwrap().header_crc, None);
+        assert!(!is_fit(b"<?xml version=\"1.0\"?><gpx></gpx>"));
+    }
+}
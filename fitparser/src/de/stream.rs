@@ -0,0 +1,207 @@
+//! Decode records incrementally from a reader, only holding the messages that haven't been
+//! decoded yet in memory.
+use super::{process_object, read_error, CrcWarning, DecodeOption, FitStreamProcessor};
+use crate::error::Result;
+use crate::FitDataRecord;
+use std::collections::HashSet;
+use std::io::{ErrorKind, Read};
+
+/// Bytes kept ahead of the parser, larger than the largest possible message so a message is
+/// never cut off unless the source ends
+const READ_AHEAD: usize = 128 * 1024;
+
+/// Size of a single read from the source
+pub(super) const CHUNK_SIZE: usize = 8192;
+
+/// Buffer of a stream being decoded, shared by the blocking and async stream readers which only
+/// differ in how they fill it
+pub(super) struct StreamDecoder {
+    processor: FitStreamProcessor,
+    buffer: Vec<u8>,
+    /// Number of bytes at the front of the buffer that were already parsed
+    consumed: usize,
+    /// Number of bytes read from the source so far
+    bytes_read: usize,
+    end_of_source: bool,
+}
+
+impl StreamDecoder {
+    pub(super) fn new(options: &HashSet<DecodeOption>) -> Self {
+        let mut processor = FitStreamProcessor::new();
+        options.iter().for_each(|o| processor.add_option(*o));
+        StreamDecoder {
+            processor,
+            buffer: Vec::with_capacity(2 * READ_AHEAD),
+            consumed: 0,
+            bytes_read: 0,
+            end_of_source: false,
+        }
+    }
+
+    /// Check if more bytes should be read before decoding the next message, dropping the bytes
+    /// that were already parsed once they fill the read ahead
+    pub(super) fn needs_input(&mut self) -> bool {
+        if self.consumed > READ_AHEAD {
+            self.buffer.drain(..self.consumed);
+            self.consumed = 0;
+        }
+        !self.end_of_source && self.buffer.len() - self.consumed < READ_AHEAD
+    }
+
+    /// Append bytes read from the source, an empty read marks the end of the source
+    pub(super) fn push_input(&mut self, chunk: &[u8]) {
+        if chunk.is_empty() {
+            self.end_of_source = true;
+        } else {
+            self.buffer.extend_from_slice(chunk);
+            self.bytes_read += chunk.len();
+        }
+    }
+
+    /// Number of bytes read from the source so far, the offset of a read error
+    pub(super) fn bytes_read(&self) -> usize {
+        self.bytes_read
+    }
+
+    /// Check if any bytes are left to decode
+    pub(super) fn has_input(&self) -> bool {
+        self.consumed < self.buffer.len()
+    }
+
+    pub(super) fn crc_warnings(&self) -> &[CrcWarning] {
+        self.processor.crc_warnings()
+    }
+
+    /// Decode the next message in the buffer, `None` if it isn't a data message. In lossy mode
+    /// an error that can't be recovered from ends the stream instead.
+    pub(super) fn decode_next(&mut self) -> Result<Option<FitDataRecord>> {
+        let input = &self.buffer[self.consumed..];
+        let (remaining, obj) = match self.processor.deserialize_next(input) {
+            Ok(next) => next,
+            Err(_) if self.processor.can_salvage() => {
+                self.consumed = self.buffer.len();
+                self.end_of_source = true;
+                return Ok(None);
+            }
+            Err(e) => return Err(e),
+        };
+        self.consumed += input.len() - remaining.len();
+        process_object(&mut self.processor, obj)
+    }
+}
+
+/// Iterator decoding the records of a FIT stream read from any `io::Read` source.
+///
+/// Unlike `from_reader` neither the file nor the decoded records are held in memory, the source is
+/// read in chunks as the records are consumed which keeps memory use flat for large monitoring
+/// files. Chained files are decoded one after the other. The iterator ends after the first error.
+pub struct FitStreamReader<R: Read> {
+    source: R,
+    decoder: StreamDecoder,
+    failed: bool,
+}
+
+impl<R: Read> FitStreamReader<R> {
+    /// Create a reader decoding the source with the default options
+    pub fn new(source: R) -> Self {
+        Self::with_options(source, &HashSet::new())
+    }
+
+    /// Create a reader decoding the source with additional decode options
+    pub fn with_options(source: R, options: &HashSet<DecodeOption>) -> Self {
+        FitStreamReader {
+            source,
+            decoder: StreamDecoder::new(options),
+            failed: false,
+        }
+    }
+
+    /// Checksum mismatches of the files read so far, recorded with `CrcPolicy::WarnOnly`
+    pub fn crc_warnings(&self) -> &[CrcWarning] {
+        self.decoder.crc_warnings()
+    }
+
+    /// Return the underlying source, any bytes read ahead of the last record are lost
+    pub fn into_inner(self) -> R {
+        self.source
+    }
+
+    /// Read from the source until the read ahead is filled or the source ends
+    fn fill_buffer(&mut self) -> Result<()> {
+        let mut chunk = [0; CHUNK_SIZE];
+        while self.decoder.needs_input() {
+            match self.source.read(&mut chunk) {
+                Ok(n) => self.decoder.push_input(&chunk[..n]),
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(read_error(e, self.decoder.bytes_read())),
+            }
+        }
+        Ok(())
+    }
+
+    fn next_record(&mut self) -> Result<Option<FitDataRecord>> {
+        loop {
+            self.fill_buffer()?;
+            if !self.decoder.has_input() {
+                return Ok(None);
+            }
+            if let Some(record) = self.decoder.decode_next()? {
+                return Ok(Some(record));
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for FitStreamReader<R> {
+    type Item = Result<FitDataRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let next = self.next_record();
+        self.failed = next.is_err();
+        next.transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+
+    /// Source whose reads always fail
+    struct FailingSource;
+
+    impl Read for FailingSource {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("connection reset"))
+        }
+    }
+
+    #[test]
+    fn stream_records() {
+        let data = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit");
+        let records: Vec<_> = FitStreamReader::new(&data[..])
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&records).unwrap(),
+            serde_json::to_value(from_bytes(data).unwrap()).unwrap()
+        );
+
+        // records up to the truncation are returned before the error ends the iterator
+        let mut reader = FitStreamReader::new(&data[..data.len() / 2]);
+        let decoded = reader.by_ref().take_while(|r| r.is_ok()).count();
+        assert!(decoded > 0);
+        assert!(reader.next().is_none());
+
+        // a source failing part way reports how many bytes were read before the failure
+        let failing = (&data[..100]).chain(FailingSource);
+        let error = FitStreamReader::new(failing).find_map(|r| r.err()).unwrap();
+        assert!(matches!(*error, crate::ErrorKind::ReadError(..)));
+        assert_eq!(error.offset(), Some(100));
+        let error = crate::from_reader(&mut (&data[..100]).chain(FailingSource)).unwrap_err();
+        assert_eq!(error.offset(), Some(100));
+    }
+}
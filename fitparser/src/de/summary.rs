@@ -0,0 +1,86 @@
+//! Statistics about the messages of a FIT file collected while it's decoded, for triage without
+//! walking the records.
+use super::FitObject;
+use crate::profile::MesgNum;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Summary of a decoded FIT file, returned by `from_bytes_with_summary`
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct FitFileSummary {
+    /// Number of files, more than one for chained files
+    pub files: usize,
+    /// Protocol version of the first file
+    pub protocol_version: Option<f32>,
+    /// Profile version of the first file
+    pub profile_version: Option<f32>,
+    /// Length of the data records of every file in bytes, as declared by their headers
+    pub data_size: u64,
+    /// Number of definition messages
+    pub definition_messages: usize,
+    /// Number of data messages of each kind
+    pub message_counts: BTreeMap<MesgNum, usize>,
+    /// Number of data messages of kinds that don't exist in the profile
+    pub unknown_messages: usize,
+    /// Number of developer field values over all data messages
+    pub developer_fields: usize,
+}
+
+impl FitFileSummary {
+    /// Number of data messages of the kind
+    pub fn count(&self, kind: MesgNum) -> usize {
+        self.message_counts.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// Number of data messages of all kinds
+    pub fn data_messages(&self) -> usize {
+        self.message_counts.values().sum()
+    }
+
+    pub(super) fn add(&mut self, obj: &FitObject) {
+        match obj {
+            FitObject::Header(header) => {
+                self.files += 1;
+                self.protocol_version
+                    .get_or_insert(header.protocol_ver_enc());
+                self.profile_version.get_or_insert(header.profile_ver_enc());
+                self.data_size += header.data_size() as u64;
+            }
+            FitObject::DefinitionMessage(_) => self.definition_messages += 1,
+            FitObject::DataMessage(msg) => {
+                let kind = MesgNum::from(msg.global_message_number());
+                *self.message_counts.entry(kind).or_default() += 1;
+                if !MesgNum::is_named_variant(kind.as_i64()) {
+                    self.unknown_messages += 1;
+                }
+                self.developer_fields += msg.developer_fields().len();
+            }
+            FitObject::Crc(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::de::{from_bytes_with_summary, DecodeOption};
+    use crate::profile::MesgNum;
+    use std::collections::HashSet;
+
+    #[test]
+    fn summarize_file() {
+        let data = include_bytes!("../../tests/fixtures/DeveloperData.fit");
+        let (records, summary) = from_bytes_with_summary(data, &HashSet::new()).unwrap();
+        assert_eq!(summary.files, 1);
+        assert_eq!(summary.data_size as usize, data.len() - 16);
+        assert_eq!(summary.data_messages(), records.len());
+        assert_eq!(summary.count(MesgNum::Record), 3);
+        assert_eq!(summary.developer_fields, 3);
+
+        let options = [DecodeOption::KeepMessage(MesgNum::FileId)].into();
+        let (records, filtered) = from_bytes_with_summary(data, &options).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(filtered, summary);
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["message_counts"]["record"], 3);
+    }
+}
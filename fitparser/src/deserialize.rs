@@ -0,0 +1,222 @@
+//! Deserialize records from the form they are serialized in, e.g. to read a JSON dump of a FIT
+//! file back into records that can be edited and encoded again.
+//!
+//! Self-describing formats only keep the kind of a value, i.e. integer, float, string or array,
+//! so the variants of the values are restored from the FIT profile: fields defined by the profile
+//! get the variant they are decoded with and timestamps are parsed back from their RFC 3339 form.
+//! Values of unknown and developer fields become `SInt64`, `UInt64`, `Float64`, `String` or
+//! `Array` values, the encoder writes developer fields with the base type of their description.
+//! Fields decoded as `Value::Duration` are restored as durations when their units or values show
+//! they were converted.
+use crate::de::BaseType;
+use crate::profile::{field_info, FieldDataType, FieldInfo, MesgNum};
+use crate::timestamp;
+use crate::{DeveloperFieldInfo, FitDataField, FitDataRecord, Value};
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use std::convert::TryInto;
+use std::fmt;
+use std::time::Duration;
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ValueVisitor;
+
+        impl<'de> Visitor<'de> for ValueVisitor {
+            type Value = Value;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a number, string or array")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Value, E> {
+                Ok(Value::SInt64(value))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Value, E> {
+                Ok(i64::try_from(value).map_or(Value::UInt64(value), Value::SInt64))
+            }
+
+            fn visit_f64<E: de::Error>(self, value: f64) -> Result<Value, E> {
+                Ok(Value::Float64(value))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Value, E> {
+                Ok(Value::String(value.to_string()))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+                let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(value) = seq.next_element()? {
+                    values.push(value);
+                }
+                Ok(Value::Array(values))
+            }
+        }
+
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+/// Restore the variant a value of a profile field is decoded with, `None` if the value doesn't
+/// fit the field
+fn profile_value(info: &FieldInfo, value: Value) -> Option<Value> {
+    let is_scaled = (info.scale - 1.0).abs() > f64::EPSILON || info.offset.abs() > f64::EPSILON;
+    match value {
+        Value::Array(values) => values
+            .into_iter()
+            .map(|v| profile_value(info, v))
+            .collect::<Option<_>>()
+            .map(Value::Array),
+        Value::String(text) => match info.field_type {
+            FieldDataType::DateTime | FieldDataType::LocalDateTime => {
+                match timestamp::parse_rfc3339(&text) {
+                    Some(time) => Some(Value::Timestamp(time)),
+                    // wall clock times of `DecodeOption::NaiveLocalTimes` have no offset
+                    None if matches!(info.field_type, FieldDataType::LocalDateTime) => {
+                        timestamp::parse_wall_clock(&text).map(Value::LocalTimestamp)
+                    }
+                    None => None,
+                }
+            }
+            _ => Some(Value::String(text)),
+        },
+        Value::Float64(val) if info.base_type == 0x88 && !is_scaled => {
+            Some(Value::Float32(val as f32))
+        }
+        Value::SInt64(val) if info.field_type.is_enum_type() => Some(Value::SInt64(val)),
+        Value::SInt64(val) if is_scaled => Some(Value::Float64(val as f64)),
+        // byte fields are decoded as unsigned integers
+        Value::SInt64(val) if info.base_type == 0x0D => val.try_into().ok().map(Value::UInt8),
+        value @ Value::SInt64(_) => value.coerce_to(BaseType::from(info.base_type)).ok(),
+        value => Some(value),
+    }
+}
+
+/// Check if the value was decoded as a `Value::Duration`, which is serialized as seconds. That's
+/// the case if the units were changed to seconds or a fraction is stored in an integer field.
+fn is_duration(info: &FieldInfo, units: &str, value: &Value) -> bool {
+    let is_scaled = (info.scale - 1.0).abs() > f64::EPSILON || info.offset.abs() > f64::EPSILON;
+    let is_float = |value: &Value| match value {
+        Value::Array(values) => values.iter().all(|v| matches!(v, Value::Float64(_))),
+        value => matches!(value, Value::Float64(_)),
+    };
+    match (info.units, units) {
+        ("ms", "s") => true,
+        ("s", "s") => !is_scaled && !matches!(info.base_type, 0x88 | 0x89) && is_float(value),
+        _ => false,
+    }
+}
+
+fn duration_value(value: Value) -> Option<Value> {
+    match value {
+        Value::Array(values) => values
+            .into_iter()
+            .map(duration_value)
+            .collect::<Option<_>>()
+            .map(Value::Array),
+        value => {
+            let seconds: f64 = value.try_into().ok()?;
+            Duration::try_from_secs_f64(seconds)
+                .ok()
+                .map(Value::Duration)
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct FieldRepr {
+    name: String,
+    number: u8,
+    value: Value,
+    units: String,
+    #[serde(default)]
+    unknown: bool,
+    #[serde(default)]
+    developer: Option<DeveloperFieldInfo>,
+    #[serde(default)]
+    raw_value: Option<Value>,
+}
+
+impl FieldRepr {
+    fn into_field(self, kind: MesgNum) -> FitDataField {
+        let info = match (self.unknown, &self.developer) {
+            (false, None) => field_info(kind, &self.name),
+            _ => None,
+        };
+        let raw_value = match (&info, self.raw_value) {
+            (Some(info), Some(raw)) => {
+                Some(raw.coerce_to(BaseType::from(info.base_type)).unwrap_or(raw))
+            }
+            (_, raw) => raw,
+        };
+        let value = match info {
+            Some(info) if is_duration(&info, &self.units, &self.value) => {
+                duration_value(self.value.clone()).unwrap_or(self.value)
+            }
+            Some(info) => profile_value(&info, self.value.clone()).unwrap_or(self.value),
+            None => self.value,
+        };
+        let mut field = FitDataField::new(self.name, self.number, value, self.units);
+        field.set_unknown(self.unknown);
+        field.set_developer_info(self.developer);
+        field.set_raw_value(raw_value);
+        field
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct RecordRepr {
+    kind: MesgNum,
+    fields: Vec<FieldRepr>,
+}
+
+impl<'de> Deserialize<'de> for FitDataRecord {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr = RecordRepr::deserialize(deserializer)?;
+        let mut record = FitDataRecord::new(repr.kind);
+        for field in repr.fields {
+            record.push(field.into_field(repr.kind));
+        }
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+    use crate::ser::to_bytes;
+
+    #[test]
+    fn deserialize_records() {
+        for data in [
+            &include_bytes!("../tests/fixtures/Activity.fit")[..],
+            &include_bytes!("../tests/fixtures/DeveloperData.fit")[..],
+            &include_bytes!("../tests/fixtures/garmin-fenix-5-bike.fit")[..],
+        ] {
+            let records = from_bytes(data).unwrap();
+            let json = serde_json::to_string(&records).unwrap();
+            let restored: Vec<FitDataRecord> = serde_json::from_str(&json).unwrap();
+            assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+            for (record, restored) in records.iter().zip(&restored) {
+                for (field, restored) in record.fields().iter().zip(restored.fields()) {
+                    if !field.is_developer_field() && !field.is_unknown() {
+                        assert_eq!(field.value(), restored.value(), "{}", field.name());
+                    }
+                }
+            }
+            // the restored records encode into the same file
+            let encoded = from_bytes(&to_bytes(&restored).unwrap()).unwrap();
+            assert_eq!(
+                serde_json::to_value(&encoded).unwrap(),
+                serde_json::to_value(from_bytes(&to_bytes(&records).unwrap()).unwrap()).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Correct barometric or GPS altitude drift using known elevations, or replace the altitude
+//! channel entirely with values from a digital elevation model.
+use super::is_record_message;
+use crate::analysis::record_altitude;
+use crate::geo::record_position;
+use crate::{FitDataField, FitDataRecord, Value};
+
+/// Known elevation at a record, the index refers to the position in the record vector
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AltitudeControlPoint {
+    /// Index of the `record` message in the record vector
+    pub index: usize,
+    /// True elevation at that record in meters
+    pub altitude: f64,
+}
+
+/// Source of elevations for a position, e.g. a lookup in a DEM raster or an online service
+pub trait ElevationModel {
+    /// Elevation in meters at the position given in decimal degrees, `None` if the model has
+    /// no data for the position
+    fn elevation(&mut self, lat: f64, long: f64) -> Option<f64>;
+}
+
+/// Write the altitude to every altitude field of the record, adding `altitude` and
+/// `enhanced_altitude` if neither exists
+fn set_altitude(record: &mut FitDataRecord, altitude: f64) {
+    let mut found = false;
+    for field in record.fields_mut() {
+        if matches!(field.name(), "altitude" | "enhanced_altitude") && !field.is_developer_field() {
+            field.set_value(Value::Float64(altitude));
+            found = true;
+        }
+    }
+    if !found {
+        for (name, number) in [("altitude", 2), ("enhanced_altitude", 78)] {
+            record.push(FitDataField::new(
+                name.to_string(),
+                number,
+                Value::Float64(altitude),
+                "m".to_string(),
+            ));
+        }
+    }
+}
+
+/// Shift the altitude of every `record` message so it matches the control points. A single
+/// point applies a constant offset, with several points the offset is linearly interpolated
+/// between them and held constant before the first and after the last. Points referring to a
+/// record without altitude are ignored. The number of records updated is returned.
+pub fn calibrate_altitude(
+    records: &mut [FitDataRecord],
+    control_points: &[AltitudeControlPoint],
+) -> usize {
+    let mut offsets: Vec<(usize, f64)> = control_points
+        .iter()
+        .filter_map(|p| {
+            let recorded = records.get(p.index).and_then(record_altitude)?;
+            Some((p.index, p.altitude - recorded))
+        })
+        .collect();
+    offsets.sort_by_key(|(idx, _)| *idx);
+    let (first, last) = match (offsets.first(), offsets.last()) {
+        (Some(first), Some(last)) => (*first, *last),
+        _ => return 0,
+    };
+
+    let mut updated = 0;
+    for (idx, record) in records.iter_mut().enumerate() {
+        if !is_record_message(record) {
+            continue;
+        }
+        let altitude = match record_altitude(record) {
+            Some(altitude) => altitude,
+            None => continue,
+        };
+        let offset = if idx <= first.0 {
+            first.1
+        } else if idx >= last.0 {
+            last.1
+        } else {
+            let pos = offsets.partition_point(|(i, _)| *i <= idx);
+            let (a, b) = (offsets[pos - 1], offsets[pos]);
+            a.1 + (b.1 - a.1) * (idx - a.0) as f64 / (b.0 - a.0) as f64
+        };
+        set_altitude(record, altitude + offset);
+        updated += 1;
+    }
+    updated
+}
+
+/// Calibrate the altitude using the known elevation of the start and/or end of the activity,
+/// see `calibrate_altitude`
+pub fn calibrate_altitude_endpoints(
+    records: &mut [FitDataRecord],
+    start: Option<f64>,
+    end: Option<f64>,
+) -> usize {
+    let mut with_altitude = records
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| is_record_message(r) && record_altitude(r).is_some())
+        .map(|(idx, _)| idx);
+    let first = with_altitude.next();
+    let last = with_altitude.next_back().or(first);
+    let points: Vec<AltitudeControlPoint> = [(first, start), (last, end)]
+        .iter()
+        .filter_map(|(index, altitude)| {
+            Some(AltitudeControlPoint {
+                index: (*index)?,
+                altitude: (*altitude)?,
+            })
+        })
+        .collect();
+    calibrate_altitude(records, &points)
+}
+
+/// Replace the altitude of every `record` message with a position by the elevation model's
+/// value, records the model has no data for are left untouched. The number of records updated
+/// is returned.
+pub fn replace_altitude<M: ElevationModel + ?Sized>(
+    records: &mut [FitDataRecord],
+    model: &mut M,
+) -> usize {
+    let mut updated = 0;
+    for record in records.iter_mut().filter(|r| is_record_message(r)) {
+        let elevation = record_position(record).and_then(|(lat, long)| model.elevation(lat, long));
+        if let Some(elevation) = elevation {
+            set_altitude(record, elevation);
+            updated += 1;
+        }
+    }
+    updated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::profile::MesgNum;
+
+    struct Slope;
+
+    impl ElevationModel for Slope {
+        fn elevation(&mut self, lat: f64, _long: f64) -> Option<f64> {
+            (lat > 0.0).then_some(lat * 1000.0)
+        }
+    }
+
+    #[test]
+    fn calibrate_and_replace_altitude() {
+        let mut records: Vec<FitDataRecord> = (0..5)
+            .map(|lat| {
+                fixtures::record(
+                    MesgNum::Record,
+                    &[
+                        ("position_lat", Value::SInt32(lat)),
+                        ("position_long", Value::SInt32(0)),
+                        ("enhanced_altitude", Value::Float64(100.0)),
+                    ],
+                )
+            })
+            .collect();
+        records.insert(2, FitDataRecord::new(MesgNum::Lap));
+        assert_eq!(
+            calibrate_altitude_endpoints(&mut records, Some(110.0), Some(90.0)),
+            5
+        );
+        let altitudes: Vec<f64> = records.iter().filter_map(record_altitude).collect();
+        assert_eq!(altitudes, [110.0, 106.0, 98.0, 94.0, 90.0]);
+
+        assert_eq!(replace_altitude(&mut records, &mut Slope), 4);
+        assert_eq!(record_altitude(&records[0]), Some(110.0));
+        assert!(record_altitude(&records[1]).unwrap() > 0.0);
+    }
+}
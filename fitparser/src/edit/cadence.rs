@@ -0,0 +1,156 @@
+//! Normalize running cadence. The FIT profile stores running cadence in strides per minute with
+//! the `fractional_cadence` field holding the fraction, but some devices and apps write steps per
+//! minute into the `cadence` field instead, doubling the apparent cadence.
+use super::{field_as_f64, is_record_message};
+use crate::profile::field_numbers::record::{CADENCE, FRACTIONAL_CADENCE};
+use crate::profile::MesgNum;
+use crate::{FitDataField, FitDataRecord, Value};
+
+/// Median cadence above which the values are assumed to be steps per minute, running stride
+/// rates stay well below this while step rates stay well above it
+const STEPS_THRESHOLD: f64 = 120.0;
+
+/// Unit the running cadence of an activity was recorded in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CadenceScale {
+    /// Strides (full gait cycles) per minute as defined by the FIT profile
+    StridesPerMinute,
+    /// Steps per minute, twice the stride rate
+    StepsPerMinute,
+}
+
+/// Cadence of a record including the `fractional_cadence` field
+fn record_cadence(record: &FitDataRecord) -> Option<f64> {
+    let cadence = field_as_f64(record, "cadence")?;
+    Some(cadence + field_as_f64(record, "fractional_cadence").unwrap_or(0.0))
+}
+
+/// Running cadence of a record in steps per minute, assumes the record follows the FIT profile
+/// which `normalize_running_cadence` ensures
+pub fn steps_per_minute(record: &FitDataRecord) -> Option<f64> {
+    record_cadence(record).map(|c| c * 2.0)
+}
+
+/// Check if the activity is a run or walk based on its `session` or `sport` messages, an
+/// activity without sport information is treated as one
+fn is_running(records: &[FitDataRecord]) -> bool {
+    let mut sports = records
+        .iter()
+        .filter(|r| r.is_kind(MesgNum::Session) || r.is_kind(MesgNum::Sport))
+        .filter_map(|r| match r.field("sport").map(|f| f.value()) {
+            Some(Value::String(sport)) => Some(sport.as_str()),
+            _ => None,
+        })
+        .peekable();
+    sports.peek().is_none() || sports.any(|s| matches!(s, "running" | "walking" | "hiking"))
+}
+
+/// Guess the unit of the running cadence from the median of the non zero values
+pub fn detect_cadence_scale(records: &[FitDataRecord]) -> Option<CadenceScale> {
+    let mut cadences: Vec<f64> = records
+        .iter()
+        .filter(|r| is_record_message(r))
+        .filter_map(record_cadence)
+        .filter(|c| *c > 0.0)
+        .collect();
+    if cadences.is_empty() {
+        return None;
+    }
+    cadences.sort_by(|a, b| a.total_cmp(b));
+    if cadences[cadences.len() / 2] > STEPS_THRESHOLD {
+        Some(CadenceScale::StepsPerMinute)
+    } else {
+        Some(CadenceScale::StridesPerMinute)
+    }
+}
+
+/// Set a field value, adding the field if it's missing
+fn set_field(record: &mut FitDataRecord, name: &str, number: u8, value: Value) {
+    match record
+        .fields_mut()
+        .iter_mut()
+        .find(|f| !f.is_developer_field() && f.number() == number)
+    {
+        Some(field) => field.set_value(value),
+        None => record.push(FitDataField::new(
+            name.to_string(),
+            number,
+            value,
+            "rpm".to_string(),
+        )),
+    }
+}
+
+/// Rewrite the running cadence of every `record` message of a run or walk recorded in steps per
+/// minute into strides per minute, splitting odd step counts into `cadence` and
+/// `fractional_cadence`. Use `steps_per_minute` to read a consistent steps per minute channel
+/// afterwards. The detected scale is returned, `None` if the activity isn't a run or has no
+/// cadence.
+pub fn normalize_running_cadence(records: &mut [FitDataRecord]) -> Option<CadenceScale> {
+    if !is_running(records) {
+        return None;
+    }
+    let scale = detect_cadence_scale(records)?;
+    if scale == CadenceScale::StridesPerMinute {
+        return Some(scale);
+    }
+    for record in records.iter_mut().filter(|r| is_record_message(r)) {
+        let strides = match record_cadence(record) {
+            Some(steps) => steps / 2.0,
+            None => continue,
+        };
+        let whole = strides.floor();
+        // fractional cadence has a resolution of 1/128 rpm
+        let fraction = ((strides - whole) * 128.0).round() / 128.0;
+        set_field(
+            record,
+            "cadence",
+            CADENCE,
+            Value::UInt8(whole.min(254.0) as u8),
+        );
+        set_field(
+            record,
+            "fractional_cadence",
+            FRACTIONAL_CADENCE,
+            Value::Float64(fraction),
+        );
+    }
+    Some(scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn halve_steps_per_minute() {
+        let mut records: Vec<FitDataRecord> = [170, 171, 0, 172]
+            .iter()
+            .map(|c| fixtures::record(MesgNum::Record, &[("cadence", Value::UInt8(*c))]))
+            .collect();
+        assert_eq!(
+            normalize_running_cadence(&mut records),
+            Some(CadenceScale::StepsPerMinute)
+        );
+        assert_eq!(field_as_f64(&records[0], "cadence"), Some(85.0));
+        assert_eq!(field_as_f64(&records[1], "cadence"), Some(85.0));
+        assert_eq!(field_as_f64(&records[1], "fractional_cadence"), Some(0.5));
+        assert_eq!(steps_per_minute(&records[1]), Some(171.0));
+
+        // already normalized files are left alone
+        assert_eq!(
+            normalize_running_cadence(&mut records),
+            Some(CadenceScale::StridesPerMinute)
+        );
+        assert_eq!(steps_per_minute(&records[3]), Some(172.0));
+
+        let session = fixtures::record(
+            MesgNum::Session,
+            &[("sport", Value::String("cycling".to_string()))],
+        );
+        let cadence = fixtures::record(MesgNum::Record, &[("cadence", Value::UInt8(90))]);
+        let mut ride = vec![session, cadence];
+        assert_eq!(normalize_running_cadence(&mut ride), None);
+    }
+}
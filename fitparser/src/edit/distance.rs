@@ -0,0 +1,122 @@
+//! Correct the recorded distance of an activity to a known true distance, e.g. after a treadmill
+//! run where the foot pod or wrist based distance is off by a few percent.
+use super::{field_as_f64, is_record_message};
+use crate::profile::MesgNum;
+use crate::{FitDataRecord, Value};
+
+/// Distance and speed fields of `record` messages
+const RECORD_FIELDS: [&str; 3] = ["distance", "speed", "enhanced_speed"];
+
+/// Distance and speed totals of `lap`, `length` and `session` messages
+const SUMMARY_FIELDS: [&str; 5] = [
+    "total_distance",
+    "avg_speed",
+    "max_speed",
+    "enhanced_avg_speed",
+    "enhanced_max_speed",
+];
+
+/// Scale a numeric value keeping its type, integer values are rounded and saturate at the
+/// limits of their type
+fn scale_value(value: &Value, factor: f64) -> Value {
+    let scale = |v: f64| (v * factor).round();
+    match value {
+        Value::Float64(v) => Value::Float64(v * factor),
+        Value::Float32(v) => Value::Float32((*v as f64 * factor) as f32),
+        Value::UInt8(v) => Value::UInt8(scale(*v as f64) as u8),
+        Value::UInt16(v) => Value::UInt16(scale(*v as f64) as u16),
+        Value::UInt32(v) => Value::UInt32(scale(*v as f64) as u32),
+        Value::UInt64(v) => Value::UInt64(scale(*v as f64) as u64),
+        Value::SInt8(v) => Value::SInt8(scale(*v as f64) as i8),
+        Value::SInt16(v) => Value::SInt16(scale(*v as f64) as i16),
+        Value::SInt32(v) => Value::SInt32(scale(*v as f64) as i32),
+        Value::SInt64(v) => Value::SInt64(scale(*v as f64) as i64),
+        Value::Array(values) => {
+            Value::Array(values.iter().map(|v| scale_value(v, factor)).collect())
+        }
+        other => other.clone(),
+    }
+}
+
+/// Total distance of the activity in meters, taken from the session totals or the last
+/// cumulative distance of the records if there is no session
+fn recorded_distance(records: &[FitDataRecord]) -> Option<f64> {
+    let sessions: f64 = records
+        .iter()
+        .filter(|r| r.is_kind(MesgNum::Session))
+        .filter_map(|r| field_as_f64(r, "total_distance"))
+        .sum();
+    if sessions > 0.0 {
+        return Some(sessions);
+    }
+    records
+        .iter()
+        .filter(|r| is_record_message(r))
+        .filter_map(|r| field_as_f64(r, "distance"))
+        .fold(None, |max: Option<f64>, d| {
+            Some(max.map_or(d, |m| m.max(d)))
+        })
+        .filter(|d| *d > 0.0)
+}
+
+/// Proportionally rescale the distance and speed channels of the records along with the lap,
+/// length and session totals so the activity covers `actual_distance` meters. The applied
+/// factor is returned, `None` if the activity has no recorded distance to scale.
+pub fn rescale_distance(records: &mut [FitDataRecord], actual_distance: f64) -> Option<f64> {
+    let factor = actual_distance / recorded_distance(records)?;
+    for record in records.iter_mut() {
+        let names: &[&str] = match record.kind() {
+            MesgNum::Record => &RECORD_FIELDS,
+            MesgNum::Lap | MesgNum::Length | MesgNum::Session => &SUMMARY_FIELDS,
+            _ => continue,
+        };
+        for field in record.fields_mut() {
+            if names.contains(&field.name()) && !field.is_developer_field() {
+                field.set_value(scale_value(field.value(), factor));
+            }
+        }
+    }
+    Some(factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::record;
+
+    #[test]
+    fn rescale_treadmill_run() {
+        let mut records = vec![
+            record(
+                MesgNum::Record,
+                &[
+                    ("distance", Value::Float64(0.0)),
+                    ("speed", Value::Float64(2.5)),
+                ],
+            ),
+            record(
+                MesgNum::Record,
+                &[
+                    ("distance", Value::Float64(4000.0)),
+                    ("speed", Value::Float64(3.0)),
+                ],
+            ),
+            record(
+                MesgNum::Session,
+                &[
+                    ("total_distance", Value::Float64(4000.0)),
+                    ("avg_speed", Value::Float64(2.75)),
+                    ("total_calories", Value::UInt16(300)),
+                ],
+            ),
+        ];
+        assert_eq!(rescale_distance(&mut records, 5000.0), Some(1.25));
+        assert_eq!(field_as_f64(&records[1], "distance"), Some(5000.0));
+        assert_eq!(field_as_f64(&records[1], "speed"), Some(3.75));
+        assert_eq!(field_as_f64(&records[2], "total_distance"), Some(5000.0));
+        assert_eq!(field_as_f64(&records[2], "total_calories"), Some(300.0));
+
+        let mut empty = vec![FitDataRecord::new(MesgNum::Record)];
+        assert_eq!(rescale_distance(&mut empty, 5000.0), None);
+    }
+}
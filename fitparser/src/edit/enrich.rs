@@ -0,0 +1,204 @@
+//! Hook for injecting external data, such as the weather or user notes, into an activity as
+//! developer fields.
+use crate::profile::field_types::FitBaseType;
+use crate::profile::MesgNum;
+use crate::{DeveloperFieldInfo, FitDataField, FitDataRecord, Value};
+
+/// Description of a developer field produced by an `Enricher`, this is written out as a
+/// `field_description` message so other tools can interpret the values
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnrichedField {
+    /// Field definition number, unique within the developer data index
+    pub number: u8,
+    /// Name of the field
+    pub name: String,
+    /// Units of the field values
+    pub units: String,
+    /// Base type the values should be encoded as
+    pub base_type: FitBaseType,
+}
+
+/// Source of external data that gets attached to an activity. The `enrich` function calls
+/// `begin` once, then `enrich_record` for every `record` message and finally `enrich_activity`
+/// for each `session` message. Values are matched to their description by field number.
+pub trait Enricher {
+    /// Developer fields this enricher can produce
+    fn fields(&self) -> Vec<EnrichedField>;
+
+    /// Called with the full activity before any values are requested, lets an implementation
+    /// fetch the data it needs in one go (e.g. weather for the activity's time and place)
+    fn begin(&mut self, _records: &[FitDataRecord]) {}
+
+    /// Values to attach to a single `record` message as (field number, value) pairs
+    fn enrich_record(&mut self, _record: &FitDataRecord) -> Vec<(u8, Value)> {
+        Vec::new()
+    }
+
+    /// Values to attach to a `session` message as (field number, value) pairs
+    fn enrich_activity(&mut self, _session: &FitDataRecord) -> Vec<(u8, Value)> {
+        Vec::new()
+    }
+}
+
+fn push(record: &mut FitDataRecord, name: &str, number: u8, value: Value, units: &str) {
+    record.push(FitDataField::new(
+        name.to_string(),
+        number,
+        value,
+        units.to_string(),
+    ));
+}
+
+/// Attach every value returned by the enricher that matches a described field
+fn attach(
+    record: &mut FitDataRecord,
+    fields: &[EnrichedField],
+    values: Vec<(u8, Value)>,
+    developer_data_index: u8,
+) -> usize {
+    let mut added = 0;
+    for (number, value) in values {
+        if let Some(desc) = fields.iter().find(|f| f.number == number) {
+            let mut field =
+                FitDataField::new(desc.name.clone(), desc.number, value, desc.units.clone());
+            field.set_developer_info(Some(DeveloperFieldInfo {
+                developer_data_index,
+                field_name: desc.name.clone(),
+                units: desc.units.clone(),
+                ..Default::default()
+            }));
+            record.push(field);
+            added += 1;
+        }
+    }
+    added
+}
+
+/// Run the enricher over the activity, adding a `developer_data_id` message and a
+/// `field_description` message per developer field after the `file_id` message. Values are
+/// appended to the `record` and `session` messages as fields named after their description.
+/// The number of values added is returned.
+pub fn enrich<E: Enricher + ?Sized>(
+    records: &mut Vec<FitDataRecord>,
+    enricher: &mut E,
+    developer_data_index: u8,
+) -> usize {
+    let fields = enricher.fields();
+    enricher.begin(records);
+
+    let mut added = 0;
+    for record in records.iter_mut() {
+        let values = match record.kind() {
+            MesgNum::Record => enricher.enrich_record(record),
+            MesgNum::Session => enricher.enrich_activity(record),
+            _ => continue,
+        };
+        added += attach(record, &fields, values, developer_data_index);
+    }
+
+    let mut header = Vec::with_capacity(fields.len() + 1);
+    let mut developer_id = FitDataRecord::new(MesgNum::DeveloperDataId);
+    push(
+        &mut developer_id,
+        "developer_data_index",
+        3,
+        Value::UInt8(developer_data_index),
+        "",
+    );
+    header.push(developer_id);
+    for field in &fields {
+        let mut desc = FitDataRecord::new(MesgNum::FieldDescription);
+        push(
+            &mut desc,
+            "developer_data_index",
+            0,
+            Value::UInt8(developer_data_index),
+            "",
+        );
+        push(
+            &mut desc,
+            "field_definition_number",
+            1,
+            Value::UInt8(field.number),
+            "",
+        );
+        push(
+            &mut desc,
+            "fit_base_type_id",
+            2,
+            Value::String(field.base_type.to_string()),
+            "",
+        );
+        push(
+            &mut desc,
+            "field_name",
+            3,
+            Value::String(field.name.clone()),
+            "",
+        );
+        push(
+            &mut desc,
+            "units",
+            8,
+            Value::String(field.units.clone()),
+            "",
+        );
+        header.push(desc);
+    }
+    let position = records
+        .iter()
+        .position(|r| r.is_kind(MesgNum::FileId))
+        .map_or(0, |p| p + 1);
+    records.splice(position..position, header);
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Notes;
+
+    impl Enricher for Notes {
+        fn fields(&self) -> Vec<EnrichedField> {
+            vec![EnrichedField {
+                number: 0,
+                name: "notes".to_string(),
+                units: String::new(),
+                base_type: FitBaseType::String,
+            }]
+        }
+
+        fn enrich_activity(&mut self, _session: &FitDataRecord) -> Vec<(u8, Value)> {
+            vec![
+                (0, Value::String("windy".to_string())),
+                (1, Value::String("not described".to_string())),
+            ]
+        }
+    }
+
+    #[test]
+    fn enrich_session() {
+        let mut records = vec![
+            FitDataRecord::new(MesgNum::FileId),
+            FitDataRecord::new(MesgNum::Record),
+            FitDataRecord::new(MesgNum::Session),
+        ];
+        assert_eq!(enrich(&mut records, &mut Notes, 0), 1);
+        let kinds: Vec<MesgNum> = records.iter().map(|r| r.kind()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                MesgNum::FileId,
+                MesgNum::DeveloperDataId,
+                MesgNum::FieldDescription,
+                MesgNum::Record,
+                MesgNum::Session
+            ]
+        );
+        let session = records.last().unwrap();
+        let notes = session.field("notes").unwrap();
+        assert_eq!(notes.value().to_string(), "windy");
+        assert!(notes.is_developer_field());
+    }
+}
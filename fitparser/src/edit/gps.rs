@@ -0,0 +1,168 @@
+//! Remove or flag poor quality GPS samples, bad points create absurd tracks once exported to
+//! formats like GPX where every position is drawn.
+use super::{field_as_f64, is_record_message};
+use crate::profile::MesgNum;
+use crate::FitDataRecord;
+
+/// Fields removed from a record when its position is discarded
+const POSITION_FIELDS: [&str; 2] = ["position_lat", "position_long"];
+
+/// Determines what happens to a `record` message that fails the GPS quality checks
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GpsFilterAction {
+    /// Remove the entire record message
+    DropRecord,
+    /// Remove only the position fields, other channels like heart rate or power are kept
+    StripPosition,
+    /// Leave the data untouched, offending records are only reported back to the caller
+    Flag,
+}
+
+/// Settings used to decide if a position sample can be trusted
+#[derive(Clone, Debug)]
+pub struct GpsFilter {
+    /// Maximum allowed value of the `gps_accuracy` field in meters, records without the field
+    /// are never rejected by this check
+    pub max_accuracy: Option<f64>,
+    /// Reject positions recorded while the receiver had no fix. The fix is considered lost when
+    /// a `gps_metadata` message is missing its position until the next one that includes it, and
+    /// positions reported at exactly 0, 0 are always treated as a lost fix.
+    pub detect_no_fix: bool,
+    /// What to do with records that fail either check
+    pub action: GpsFilterAction,
+}
+
+impl Default for GpsFilter {
+    fn default() -> Self {
+        GpsFilter {
+            max_accuracy: None,
+            detect_no_fix: true,
+            action: GpsFilterAction::StripPosition,
+        }
+    }
+}
+
+fn has_position(record: &FitDataRecord) -> bool {
+    POSITION_FIELDS.iter().all(|n| record.field(n).is_some())
+}
+
+fn at_null_island(record: &FitDataRecord) -> bool {
+    POSITION_FIELDS
+        .iter()
+        .all(|n| field_as_f64(record, n) == Some(0.0))
+}
+
+/// Check every `record` message that contains a position against the filter settings and apply
+/// the configured action to the ones that fail. The indices of the offending records, relative
+/// to the input vector before any records were dropped, are returned in ascending order.
+pub fn filter_gps(records: &mut Vec<FitDataRecord>, filter: &GpsFilter) -> Vec<usize> {
+    let mut flagged = Vec::new();
+    let mut fix_lost = false;
+    for (idx, record) in records.iter().enumerate() {
+        if record.is_kind(MesgNum::GpsMetadata) {
+            fix_lost = !has_position(record);
+            continue;
+        }
+        if !is_record_message(record) || !has_position(record) {
+            continue;
+        }
+        let inaccurate = match (filter.max_accuracy, field_as_f64(record, "gps_accuracy")) {
+            (Some(max), Some(accuracy)) => accuracy > max,
+            _ => false,
+        };
+        let no_fix = filter.detect_no_fix && (fix_lost || at_null_island(record));
+        if inaccurate || no_fix {
+            flagged.push(idx);
+        }
+    }
+
+    match filter.action {
+        GpsFilterAction::DropRecord => {
+            let mut idx = 0;
+            records.retain(|_| {
+                let keep = flagged.binary_search(&idx).is_err();
+                idx += 1;
+                keep
+            });
+        }
+        GpsFilterAction::StripPosition => {
+            for idx in &flagged {
+                records[*idx]
+                    .fields_mut()
+                    .retain(|f| !POSITION_FIELDS.contains(&f.name()));
+            }
+        }
+        GpsFilterAction::Flag => {}
+    }
+
+    flagged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::Value;
+
+    #[test]
+    fn drop_inaccurate_and_null_island_records() {
+        let mut records: Vec<FitDataRecord> = [
+            (446332520, -1456340701, 3),
+            (446332620, -1456340801, 40),
+            (0, 0, 3),
+            (446332720, -1456340901, 5),
+        ]
+        .iter()
+        .map(|&(lat, long, accuracy)| {
+            fixtures::record(
+                MesgNum::Record,
+                &[
+                    ("position_lat", Value::SInt32(lat)),
+                    ("position_long", Value::SInt32(long)),
+                    ("gps_accuracy", Value::UInt8(accuracy)),
+                ],
+            )
+        })
+        .collect();
+        let filter = GpsFilter {
+            max_accuracy: Some(10.0),
+            detect_no_fix: true,
+            action: GpsFilterAction::DropRecord,
+        };
+        assert_eq!(filter_gps(&mut records, &filter), vec![1, 2]);
+        assert_eq!(records.len(), 2);
+    }
+
+    #[test]
+    fn strip_positions_while_fix_is_lost() {
+        let mut records: Vec<FitDataRecord> = [
+            (446332520, -1456340701),
+            (446332620, -1456340801),
+            (446332720, -1456340901),
+            (446332820, -1456341001),
+        ]
+        .iter()
+        .map(|&(lat, long)| {
+            fixtures::record(
+                MesgNum::Record,
+                &[
+                    ("position_lat", Value::SInt32(lat)),
+                    ("position_long", Value::SInt32(long)),
+                    ("gps_accuracy", Value::UInt8(3)),
+                ],
+            )
+        })
+        .collect();
+        records.insert(1, FitDataRecord::new(MesgNum::GpsMetadata));
+        let mut fix = FitDataRecord::new(MesgNum::GpsMetadata);
+        fix.extend(records[0].fields()[0..2].to_vec());
+        records.insert(4, fix);
+
+        let flagged = filter_gps(&mut records, &GpsFilter::default());
+        assert_eq!(flagged, vec![2, 3]);
+        assert_eq!(records.len(), 6);
+        assert!(records[2].field("position_lat").is_none());
+        assert!(records[2].field("gps_accuracy").is_some());
+        assert!(records[5].field("position_lat").is_some());
+    }
+}
@@ -0,0 +1,198 @@
+//! Pick a single heart rate channel when an activity recorded heart rate from several sensors,
+//! e.g. the watch's optical sensor and a chest strap broadcasting to a Connect IQ data field.
+use super::is_record_message;
+use crate::profile::field_numbers::record::HEART_RATE as HEART_RATE_FIELD;
+use crate::profile::MesgNum;
+use crate::{FitDataField, FitDataRecord, Value};
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+/// Kind of sensor a heart rate channel came from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HeartRateSource {
+    /// Chest or arm strap measuring electrical activity, connected over ANT+ or Bluetooth
+    Strap,
+    /// Optical sensor on the wrist
+    Optical,
+    /// The source could not be determined
+    Unknown,
+}
+
+/// Order in which heart rate sources are preferred, the default prefers straps over optical
+/// sensors since they respond faster and are less affected by motion
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeartRatePriority {
+    /// Sources from most to least preferred, sources that aren't listed are never used
+    pub order: Vec<HeartRateSource>,
+}
+
+impl Default for HeartRatePriority {
+    fn default() -> Self {
+        HeartRatePriority {
+            order: vec![
+                HeartRateSource::Strap,
+                HeartRateSource::Optical,
+                HeartRateSource::Unknown,
+            ],
+        }
+    }
+}
+
+/// Result of resolving the heart rate channels
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HeartRateResolution {
+    /// Source of the native `heart_rate` field as determined from the `device_info` messages
+    pub native_source: Option<HeartRateSource>,
+    /// Number of records whose heart rate was taken from each source
+    pub records: HashMap<HeartRateSource, usize>,
+}
+
+/// Determine the sensor behind the native heart rate channel from the `device_info` messages, a
+/// connected ANT+ or Bluetooth heart rate sensor takes precedence over the wrist sensor since
+/// the device records the external sensor when one is paired
+pub fn native_heart_rate_source(records: &[FitDataRecord]) -> HeartRateSource {
+    let device_types = records
+        .iter()
+        .filter(|r| r.is_kind(MesgNum::DeviceInfo))
+        .flat_map(|r| r.fields())
+        .filter_map(|f| match f.value() {
+            Value::String(value) => Some((f.name(), value.as_str())),
+            _ => None,
+        });
+    let mut source = HeartRateSource::Unknown;
+    for (name, value) in device_types {
+        match (name, value) {
+            ("antplus_device_type" | "ble_device_type", "heart_rate") => {
+                return HeartRateSource::Strap
+            }
+            ("local_device_type", "whr") => source = HeartRateSource::Optical,
+            _ => {}
+        }
+    }
+    source
+}
+
+/// Classify a developer field as a heart rate channel, fields that declare the native heart
+/// rate field or have a heart rate like name qualify. The source is guessed from the name.
+fn developer_heart_rate_source(field: &FitDataField) -> Option<HeartRateSource> {
+    let info = field.developer_info()?;
+    let name = info.field_name.to_lowercase();
+    let words: Vec<&str> = name.split(|c: char| !c.is_ascii_alphanumeric()).collect();
+    let has_word = |candidates: &[&str]| words.iter().any(|w| candidates.contains(w));
+    let declared = info.native_field_num == Some(HEART_RATE_FIELD)
+        && info.native_mesg_num.is_none_or(|m| m == MesgNum::Record);
+    if !declared && !name.contains("heart") && !has_word(&["hr", "bpm"]) {
+        return None;
+    }
+    if has_word(&["strap", "chest", "ant", "antplus", "ble", "bluetooth"]) {
+        Some(HeartRateSource::Strap)
+    } else if has_word(&["wrist", "optical", "ohr", "whr"]) {
+        Some(HeartRateSource::Optical)
+    } else {
+        Some(HeartRateSource::Unknown)
+    }
+}
+
+/// Return the heart rate in bpm if the value is a plausible reading
+fn valid_heart_rate(value: &Value) -> Option<u8> {
+    let bpm: i64 = value.try_into().ok()?;
+    (1..255).contains(&bpm).then_some(bpm as u8)
+}
+
+/// Write the heart rate of the most preferred source with a valid reading into the native
+/// `heart_rate` field of every `record` message, falling back to less preferred sources where
+/// the preferred one has gaps. Developer fields are left in place so the original channels can
+/// still be inspected.
+pub fn resolve_heart_rate(
+    records: &mut [FitDataRecord],
+    priority: &HeartRatePriority,
+) -> HeartRateResolution {
+    let native_source = native_heart_rate_source(records);
+    let mut resolution = HeartRateResolution::default();
+    for record in records.iter_mut().filter(|r| is_record_message(r)) {
+        let mut candidates: Vec<(HeartRateSource, u8)> = Vec::new();
+        for field in record.fields() {
+            let source = if field.is_developer_field() {
+                developer_heart_rate_source(field)
+            } else if field.number() == HEART_RATE_FIELD {
+                resolution.native_source = Some(native_source);
+                Some(native_source)
+            } else {
+                None
+            };
+            if let (Some(source), Some(bpm)) = (source, valid_heart_rate(field.value())) {
+                candidates.push((source, bpm));
+            }
+        }
+        let chosen = priority
+            .order
+            .iter()
+            .find_map(|s| candidates.iter().find(|(source, _)| source == s));
+        let (source, bpm) = match chosen {
+            Some(chosen) => *chosen,
+            None => continue,
+        };
+        *resolution.records.entry(source).or_insert(0) += 1;
+        let native = record
+            .fields_mut()
+            .iter_mut()
+            .find(|f| !f.is_developer_field() && f.number() == HEART_RATE_FIELD);
+        match native {
+            Some(field) => field.set_value(Value::UInt8(bpm)),
+            None => record.push(FitDataField::new(
+                "heart_rate".to_string(),
+                HEART_RATE_FIELD,
+                Value::UInt8(bpm),
+                "bpm".to_string(),
+            )),
+        }
+    }
+    resolution
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, RecordBuilder};
+
+    #[test]
+    fn prefer_strap_over_wrist() {
+        let device = fixtures::record(
+            MesgNum::DeviceInfo,
+            &[("local_device_type", Value::String("whr".to_string()))],
+        );
+        let mut records = vec![
+            device,
+            RecordBuilder::new(MesgNum::Record)
+                .field("heart_rate", Value::UInt8(120))
+                .developer_field("Strap HR", "bpm", Value::UInt8(130), None)
+                .build(),
+            fixtures::record(MesgNum::Record, &[("heart_rate", Value::UInt8(121))]),
+            RecordBuilder::new(MesgNum::Record)
+                .developer_field("Strap HR", "bpm", Value::UInt8(132), None)
+                .build(),
+            RecordBuilder::new(MesgNum::Record)
+                .field("heart_rate", Value::UInt8(122))
+                .developer_field("Strap HR", "bpm", Value::UInt8(255), None)
+                .build(),
+        ];
+
+        let resolution = resolve_heart_rate(&mut records, &HeartRatePriority::default());
+        assert_eq!(resolution.native_source, Some(HeartRateSource::Optical));
+        assert_eq!(resolution.records[&HeartRateSource::Strap], 2);
+        assert_eq!(resolution.records[&HeartRateSource::Optical], 2);
+        let bpm: Vec<&Value> = records[1..]
+            .iter()
+            .map(|r| r.field("heart_rate").unwrap().value())
+            .collect();
+        assert_eq!(
+            bpm,
+            [
+                &Value::UInt8(130),
+                &Value::UInt8(121),
+                &Value::UInt8(132),
+                &Value::UInt8(122)
+            ]
+        );
+    }
+}
@@ -0,0 +1,59 @@
+//! Transforms that clean up or modify decoded FIT data before it is exported or re-used.
+//!
+//! Every transform operates on the `FitDataRecord` vector returned by the deserializer so they
+//! can be freely chained together in whatever order an application needs.
+use crate::profile::MesgNum;
+use crate::FitDataRecord;
+use std::convert::TryInto;
+
+mod altitude;
+pub use altitude::{
+    calibrate_altitude, calibrate_altitude_endpoints, replace_altitude, AltitudeControlPoint,
+    ElevationModel,
+};
+mod cadence;
+pub use cadence::{
+    detect_cadence_scale, normalize_running_cadence, steps_per_minute, CadenceScale,
+};
+mod distance;
+pub use distance::rescale_distance;
+#[cfg(not(compact_profile))]
+mod enrich;
+#[cfg(not(compact_profile))]
+pub use enrich::{enrich, EnrichedField, Enricher};
+mod gps;
+pub use gps::{filter_gps, GpsFilter, GpsFilterAction};
+mod heart_rate;
+pub use heart_rate::{
+    native_heart_rate_source, resolve_heart_rate, HeartRatePriority, HeartRateResolution,
+    HeartRateSource,
+};
+mod native;
+pub use native::apply_native_developer_fields;
+#[cfg(not(compact_profile))]
+mod platform;
+#[cfg(not(compact_profile))]
+pub use platform::{
+    ensure_activity_message, fix_for_garmin_connect, fix_for_strava, fix_for_trainingpeaks,
+    fix_session_durations, remove_zero_duration_sessions, sort_records_by_timestamp, PlatformFixes,
+};
+mod simplify;
+pub use simplify::simplify_track;
+mod slim;
+pub use slim::{estimated_size, slim, SlimOptions, SlimStats};
+#[cfg(not(compact_profile))]
+mod turns;
+#[cfg(not(compact_profile))]
+pub use turns::{add_turn_course_points, TurnDetection};
+
+/// Return the value of a field converted to a floating point number if it exists and is numeric
+pub(crate) fn field_as_f64(record: &FitDataRecord, name: &str) -> Option<f64> {
+    record
+        .field(name)
+        .and_then(|f| f.value().clone().try_into().ok())
+}
+
+/// Check if the record is a `record` message, i.e. a single sample of the activity channels
+pub(crate) fn is_record_message(record: &FitDataRecord) -> bool {
+    record.is_kind(MesgNum::Record)
+}
@@ -0,0 +1,89 @@
+//! Map developer fields that declare a native field onto that native field, e.g. the running
+//! power recorded by a Connect IQ app or foot pod becomes the regular `power` field.
+use crate::de::Accumulators;
+use crate::profile::MesgNum;
+use crate::{FitDataField, FitDataRecord, Value};
+use std::collections::{HashMap, HashSet};
+
+/// Look up the profile name and units of a native field by decoding a probe message holding
+/// just that field, `None` when the field isn't defined in the profile
+fn native_field_info(kind: MesgNum, number: u8, value: &Value) -> Option<(String, String)> {
+    let mut data = HashMap::new();
+    data.insert(number, value.clone());
+    let fields = kind
+        .decode_message(&mut data, &mut Accumulators::new(), &HashSet::new())
+        .ok()?;
+    fields
+        .into_iter()
+        .find(|f| f.number() == number && !f.is_unknown())
+        .map(|f| (f.name().to_string(), f.units().to_string()))
+}
+
+/// Copy every developer field that declares a `native_field_num` into the corresponding native
+/// field when the record doesn't already have it, this matches how Garmin Connect treats these
+/// fields. Developer fields that declare a different `native_mesg_num` than the record are
+/// ignored. The developer fields themselves are kept and the number of native fields added is
+/// returned.
+pub fn apply_native_developer_fields(records: &mut [FitDataRecord]) -> usize {
+    let mut added = 0;
+    for record in records.iter_mut() {
+        let kind = record.kind();
+        let mut native = Vec::new();
+        for field in record.fields().iter().filter(|f| f.is_developer_field()) {
+            let info = field.developer_info().unwrap();
+            let number = match info.native_field_num {
+                Some(n) if info.native_mesg_num.is_none_or(|m| m == kind) => n,
+                _ => continue,
+            };
+            let exists = record
+                .fields()
+                .iter()
+                .chain(native.iter())
+                .any(|f| !f.is_developer_field() && f.number() == number);
+            if exists {
+                continue;
+            }
+            if let Some((name, units)) = native_field_info(kind, number, field.value()) {
+                native.push(FitDataField::new(
+                    name,
+                    number,
+                    field.value().clone(),
+                    units,
+                ));
+            }
+        }
+        added += native.len();
+        record.extend(native);
+    }
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::RecordBuilder;
+
+    #[test]
+    fn copy_developer_power() {
+        let native = Some((MesgNum::Record, 7));
+        let mut records = vec![
+            RecordBuilder::new(MesgNum::Record)
+                .developer_field("Power", "Watts", Value::UInt16(250), native)
+                .build(),
+            RecordBuilder::new(MesgNum::Record)
+                .field("power", Value::UInt16(255))
+                .developer_field("Power", "Watts", Value::UInt16(260), native)
+                .build(),
+        ];
+        assert_eq!(apply_native_developer_fields(&mut records), 1);
+
+        let power = records[0].field("power").unwrap();
+        assert_eq!(power.value(), &Value::UInt16(250));
+        assert_eq!(power.units(), "watts");
+        assert!(!power.is_developer_field());
+        assert_eq!(
+            records[1].field("power").unwrap().value(),
+            &Value::UInt16(255)
+        );
+    }
+}
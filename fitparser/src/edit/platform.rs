@@ -0,0 +1,261 @@
+//! Bundles of transforms that make an activity acceptable to a specific platform. Each upload
+//! platform rejects or mangles files over a slightly different set of problems, these presets
+//! collect the known ones so they don't have to be rediscovered by trial and error.
+use super::{apply_native_developer_fields, field_as_f64, is_record_message};
+use crate::analysis::record_time;
+use crate::profile::field_types::{Activity, Event, EventType};
+use crate::profile::MesgNum;
+use crate::timestamp::{from_chrono, to_chrono};
+use crate::{FitDataField, FitDataRecord, Value};
+use chrono::{DateTime, Local};
+
+/// Summary of the changes made by a platform preset
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlatformFixes {
+    /// Number of `record` messages moved to restore timestamp order
+    pub records_reordered: usize,
+    /// Number of sessions whose zero or missing duration was recalculated from the records
+    pub sessions_fixed: usize,
+    /// Number of sessions removed because no duration could be calculated
+    pub sessions_removed: usize,
+    /// Set if an `activity` message had to be added
+    pub activity_added: bool,
+    /// Number of native fields filled in from developer fields
+    pub native_fields_added: usize,
+}
+
+/// Strava rejects files with records out of order and ignores power and cadence that only exist
+/// as developer fields, sessions without a duration show up as empty activities.
+pub fn fix_for_strava(records: &mut Vec<FitDataRecord>) -> PlatformFixes {
+    PlatformFixes {
+        records_reordered: sort_records_by_timestamp(records),
+        sessions_fixed: fix_session_durations(records),
+        sessions_removed: remove_zero_duration_sessions(records),
+        native_fields_added: apply_native_developer_fields(records),
+        ..Default::default()
+    }
+}
+
+/// Garmin Connect refuses files without an `activity` message or with a zero duration session
+/// and requires the records to be in order.
+pub fn fix_for_garmin_connect(records: &mut Vec<FitDataRecord>) -> PlatformFixes {
+    let mut fixes = PlatformFixes {
+        records_reordered: sort_records_by_timestamp(records),
+        sessions_fixed: fix_session_durations(records),
+        sessions_removed: remove_zero_duration_sessions(records),
+        ..Default::default()
+    };
+    fixes.activity_added = ensure_activity_message(records);
+    fixes
+}
+
+/// TrainingPeaks needs every fix Garmin Connect needs and, like Strava, only reads the native
+/// power, cadence and heart rate fields.
+pub fn fix_for_trainingpeaks(records: &mut Vec<FitDataRecord>) -> PlatformFixes {
+    let mut fixes = fix_for_garmin_connect(records);
+    fixes.native_fields_added = apply_native_developer_fields(records);
+    fixes
+}
+
+/// Stable sort the `record` messages by timestamp while keeping every other message in place,
+/// records without a timestamp keep their position. The number of records moved is returned.
+pub fn sort_records_by_timestamp(records: &mut [FitDataRecord]) -> usize {
+    let slots: Vec<usize> = records
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| is_record_message(r) && record_time(r).is_some())
+        .map(|(idx, _)| idx)
+        .collect();
+    let mut order = slots.clone();
+    order.sort_by_key(|idx| record_time(&records[*idx]));
+    let moved = order.iter().zip(&slots).filter(|(a, b)| a != b).count();
+    if moved > 0 {
+        let sorted: Vec<FitDataRecord> = order.iter().map(|idx| records[*idx].clone()).collect();
+        for (slot, record) in slots.into_iter().zip(sorted) {
+            records[slot] = record;
+        }
+    }
+    moved
+}
+
+/// Calculate the elapsed and timer time of sessions missing them, or having a zero value, from
+/// the session start time and the last record at or before the session timestamp. The number of
+/// sessions updated is returned.
+pub fn fix_session_durations(records: &mut [FitDataRecord]) -> usize {
+    let record_times = records
+        .iter()
+        .filter(|r| is_record_message(r))
+        .filter_map(record_time)
+        .collect::<Vec<_>>();
+    let mut fixed = 0;
+    for session in records.iter_mut().filter(|r| r.is_kind(MesgNum::Session)) {
+        if field_as_f64(session, "total_elapsed_time").is_some_and(|t| t > 0.0) {
+            continue;
+        }
+        let start = match session.field("start_time").map(|f| f.value()) {
+            Some(Value::Timestamp(start)) => to_chrono(start),
+            _ => continue,
+        };
+        let end = record_time(session);
+        let last = record_times
+            .iter()
+            .filter(|t| end.is_none_or(|end| **t <= end))
+            .max()
+            .copied();
+        let duration = match last {
+            Some(last) if last > start => (last - start).num_milliseconds() as f64 / 1000.0,
+            _ => continue,
+        };
+        set_field(
+            session,
+            "total_elapsed_time",
+            7,
+            Value::Float64(duration),
+            "s",
+        );
+        if !field_as_f64(session, "total_timer_time").is_some_and(|t| t > 0.0) {
+            set_field(
+                session,
+                "total_timer_time",
+                8,
+                Value::Float64(duration),
+                "s",
+            );
+        }
+        fixed += 1;
+    }
+    fixed
+}
+
+/// Remove sessions with a zero or missing elapsed time, returning the number removed
+pub fn remove_zero_duration_sessions(records: &mut Vec<FitDataRecord>) -> usize {
+    let before = records.len();
+    records.retain(|r| {
+        !r.is_kind(MesgNum::Session)
+            || field_as_f64(r, "total_elapsed_time").is_some_and(|t| t > 0.0)
+    });
+    before - records.len()
+}
+
+/// Append an `activity` message summarizing the sessions if the file lacks one, returning
+/// whether a message was added
+pub fn ensure_activity_message(records: &mut Vec<FitDataRecord>) -> bool {
+    if records.iter().any(|r| r.is_kind(MesgNum::Activity)) {
+        return false;
+    }
+    let sessions: Vec<&FitDataRecord> = records
+        .iter()
+        .filter(|r| r.is_kind(MesgNum::Session))
+        .collect();
+    let timer_time: f64 = sessions
+        .iter()
+        .filter_map(|s| field_as_f64(s, "total_timer_time"))
+        .sum();
+    let num_sessions = sessions.len() as u16;
+    let timestamp: Option<DateTime<Local>> = records.iter().filter_map(record_time).max();
+
+    let mut activity = FitDataRecord::new(MesgNum::Activity);
+    let mut push = |name: &str, number: u8, value: Value, units: &str| {
+        activity.push(FitDataField::new(
+            name.to_string(),
+            number,
+            value,
+            units.to_string(),
+        ))
+    };
+    push("total_timer_time", 0, Value::Float64(timer_time), "s");
+    push("num_sessions", 1, Value::UInt16(num_sessions), "");
+    push("type", 2, Value::String(Activity::Manual.to_string()), "");
+    push("event", 3, Value::String(Event::Activity.to_string()), "");
+    push(
+        "event_type",
+        4,
+        Value::String(EventType::Stop.to_string()),
+        "",
+    );
+    if let Some(timestamp) = timestamp {
+        push(
+            "timestamp",
+            253,
+            Value::Timestamp(from_chrono(timestamp)),
+            "s",
+        );
+    }
+    records.push(activity);
+    true
+}
+
+/// Replace the value of a field or add it if it's missing
+fn set_field(record: &mut FitDataRecord, name: &str, number: u8, value: Value, units: &str) {
+    match record
+        .fields_mut()
+        .iter_mut()
+        .find(|f| f.number() == number)
+    {
+        Some(field) => {
+            *field = FitDataField::new(name.to_string(), number, value, units.to_string())
+        }
+        None => record.push(FitDataField::new(
+            name.to_string(),
+            number,
+            value,
+            units.to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, RecordBuilder};
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn garmin_connect_preset() {
+        let start = Local.timestamp_opt(1_600_000_000, 0).unwrap();
+        let mut records = vec![FitDataRecord::new(MesgNum::FileId)];
+        for offset in [0, 2, 1, 3] {
+            let time = from_chrono(start + Duration::seconds(offset));
+            records.push(fixtures::record(
+                MesgNum::Record,
+                &[("timestamp", Value::Timestamp(time))],
+            ));
+        }
+        let session = RecordBuilder::new(MesgNum::Session)
+            .field(
+                "timestamp",
+                Value::Timestamp(from_chrono(start + Duration::seconds(3))),
+            )
+            .field("start_time", Value::Timestamp(from_chrono(start)))
+            .field("total_elapsed_time", Value::Float64(0.0))
+            .build();
+        records.push(session);
+        // a second session that can't be repaired
+        records.push(FitDataRecord::new(MesgNum::Session));
+
+        let fixes = fix_for_garmin_connect(&mut records);
+        assert_eq!(
+            fixes,
+            PlatformFixes {
+                records_reordered: 2,
+                sessions_fixed: 1,
+                sessions_removed: 1,
+                activity_added: true,
+                native_fields_added: 0,
+            }
+        );
+        let times: Vec<_> = records[1..5].iter().filter_map(record_time).collect();
+        assert!(times.windows(2).all(|w| w[0] <= w[1]));
+        let session = records
+            .iter()
+            .find(|r| r.is_kind(MesgNum::Session))
+            .unwrap();
+        assert_eq!(field_as_f64(session, "total_elapsed_time"), Some(3.0));
+        let activity = records.last().unwrap();
+        assert_eq!(field_as_f64(activity, "total_timer_time"), Some(3.0));
+        assert_eq!(field_as_f64(activity, "num_sessions"), Some(1.0));
+
+        let fixes = fix_for_garmin_connect(&mut records);
+        assert_eq!(fixes, PlatformFixes::default());
+    }
+}
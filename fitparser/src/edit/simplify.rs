@@ -0,0 +1,142 @@
+//! Reduce the number of track points using the Ramer–Douglas–Peucker algorithm, a full 1 Hz
+//! track is far more detail than a web map needs.
+use super::is_record_message;
+use crate::geo::{record_position, to_local_xy};
+use crate::FitDataRecord;
+
+/// Perpendicular distance from `p` to the segment running from `a` to `b`
+fn segment_distance(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len_sq = dx * dx + dy * dy;
+    if len_sq == 0.0 {
+        return ((p.0 - a.0).powi(2) + (p.1 - a.1).powi(2)).sqrt();
+    }
+    let t = (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len_sq).clamp(0.0, 1.0);
+    ((p.0 - a.0 - t * dx).powi(2) + (p.1 - a.1 - t * dy).powi(2)).sqrt()
+}
+
+/// Return a keep mask for the points using a tolerance in the same units as the points
+fn douglas_peucker(points: &[(f64, f64)], tolerance: f64) -> Vec<bool> {
+    let mut keep = vec![false; points.len()];
+    if points.len() < 3 {
+        keep.iter_mut().for_each(|k| *k = true);
+        return keep;
+    }
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    // use an explicit stack instead of recursion, long tracks can have 100k+ points
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = stack.pop() {
+        let mut max_dist = 0.0;
+        let mut max_idx = start;
+        for idx in (start + 1)..end {
+            let dist = segment_distance(points[idx], points[start], points[end]);
+            if dist > max_dist {
+                max_dist = dist;
+                max_idx = idx;
+            }
+        }
+        if max_dist > tolerance {
+            keep[max_idx] = true;
+            stack.push((start, max_idx));
+            stack.push((max_idx, end));
+        }
+    }
+    keep
+}
+
+/// Simplify the track formed by the positions of all `record` messages, removing the records
+/// whose position lies within `tolerance` meters of the simplified line. Records without a
+/// position and all other message types are left untouched. The number of records removed
+/// is returned.
+pub fn simplify_track(records: &mut Vec<FitDataRecord>, tolerance: f64) -> usize {
+    let positions: Vec<(usize, (f64, f64))> = records
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| is_record_message(r))
+        .filter_map(|(idx, r)| record_position(r).map(|p| (idx, p)))
+        .collect();
+    let origin = match positions.first() {
+        Some((_, p)) => *p,
+        None => return 0,
+    };
+    let points: Vec<(f64, f64)> = positions
+        .iter()
+        .map(|(_, p)| to_local_xy(origin, *p))
+        .collect();
+
+    let mut drop = vec![false; records.len()];
+    for ((idx, _), keep) in positions.iter().zip(douglas_peucker(&points, tolerance)) {
+        drop[*idx] = !keep;
+    }
+    let init_len = records.len();
+    let mut idx = 0;
+    records.retain(|_| {
+        let keep = !drop[idx];
+        idx += 1;
+        keep
+    });
+    init_len - records.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::profile::MesgNum;
+    use crate::Value;
+
+    /// Record at a position given in meters east and north of 45°N 7°E
+    fn record(east: f64, north: f64) -> FitDataRecord {
+        let semicircles = |deg: f64| Value::SInt32((deg * 2_147_483_648.0 / 180.0) as i32);
+        let lat = 45.0 + north / 111_195.0;
+        let long = 7.0 + east / (111_195.0 * 45f64.to_radians().cos());
+        fixtures::record(
+            MesgNum::Record,
+            &[
+                ("position_lat", semicircles(lat)),
+                ("position_long", semicircles(long)),
+            ],
+        )
+    }
+
+    #[test]
+    fn collinear_points_are_removed() {
+        let points = [(0.0, 0.0), (1.0, 0.01), (2.0, 0.0), (3.0, 5.0), (4.0, 0.0)];
+        assert_eq!(
+            douglas_peucker(&points, 0.5),
+            vec![true, false, true, true, true]
+        );
+        assert_eq!(
+            douglas_peucker(&points, 10.0),
+            vec![true, false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn simplify_records() {
+        // a straight line east with a little noise, then a corner heading north
+        let mut records: Vec<FitDataRecord> = (0..=10)
+            .map(|i| record(i as f64 * 10.0, if i % 2 == 0 { 0.5 } else { -0.5 }))
+            .collect();
+        records.extend((1..=10).map(|i| record(100.0, i as f64 * 10.0)));
+        records.insert(5, fixtures::record(MesgNum::Lap, &[]));
+        records.insert(8, FitDataRecord::new(MesgNum::Record));
+        let total = records.len();
+
+        // the noise exceeds a tolerance of 0.1 m so only the points along the straight north leg
+        // are dropped
+        assert_eq!(simplify_track(&mut records.clone(), 0.1), 9);
+        assert_eq!(simplify_track(&mut records, 2.0), 18);
+        assert_eq!(records.len(), total - 18);
+        let positions: Vec<(f64, f64)> = records.iter().filter_map(record_position).collect();
+        assert_eq!(positions.len(), 3);
+        assert!((positions[1].0 - 45.0).abs() < 1e-5);
+        assert!(records.iter().any(|r| r.is_kind(MesgNum::Lap)));
+        assert!(records.iter().any(|r| r.fields().is_empty()));
+
+        let mut empty = vec![fixtures::record(MesgNum::Lap, &[])];
+        assert_eq!(simplify_track(&mut empty, 2.0), 0);
+    }
+}
@@ -0,0 +1,186 @@
+//! Strip a file down to its essentials to shrink it, for archiving or sharing large numbers of
+//! activities where the full detail isn't needed.
+use super::is_record_message;
+use crate::profile::MesgNum;
+use crate::{FitDataRecord, Value};
+use std::collections::HashSet;
+
+/// What to remove when slimming the records
+#[derive(Clone, Debug)]
+pub struct SlimOptions {
+    /// Remove messages that aren't defined in the FIT profile
+    pub remove_unknown_messages: bool,
+    /// Remove developer fields along with the messages describing them
+    pub remove_developer_fields: bool,
+    /// Remove `device_info` messages repeating a device already described by an earlier one
+    pub remove_duplicate_device_info: bool,
+    /// Keep every Nth `record` message, 1 keeps all of them
+    pub keep_every: usize,
+    /// Largest estimated size of the encoded file in bytes, `keep_every` is raised until the
+    /// estimate fits or only the first and last `record` messages are left
+    pub target_size: Option<usize>,
+}
+
+impl Default for SlimOptions {
+    fn default() -> Self {
+        SlimOptions {
+            remove_unknown_messages: true,
+            remove_developer_fields: true,
+            remove_duplicate_device_info: true,
+            keep_every: 1,
+            target_size: None,
+        }
+    }
+}
+
+/// Outcome of slimming the records
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SlimStats {
+    /// Number of messages removed
+    pub removed_messages: usize,
+    /// Number of developer fields removed from the remaining messages
+    pub removed_fields: usize,
+    /// Interval of the `record` messages that were kept
+    pub keep_every: usize,
+    /// Estimated size of the encoded file in bytes
+    pub estimated_size: usize,
+}
+
+/// Number of bytes a value takes up in a data message
+fn value_size(value: &Value) -> usize {
+    match value {
+        Value::Byte(_) | Value::Enum(_) | Value::SInt8(_) | Value::UInt8(_) | Value::UInt8z(_) => 1,
+        Value::SInt16(_) | Value::UInt16(_) | Value::UInt16z(_) => 2,
+        Value::SInt64(_) | Value::UInt64(_) | Value::UInt64z(_) | Value::Float64(_) => 8,
+        Value::String(val) => val.len() + 1,
+        Value::Array(vals) => vals.iter().map(value_size).sum(),
+        // scaled values are decoded as floats but most are stored as 32 bit or smaller integers
+        _ => 4,
+    }
+}
+
+/// Estimate the size of the records encoded as a FIT file, assuming a definition message is
+/// written for every change of message layout. Scaled fields are counted as 4 bytes.
+pub fn estimated_size(records: &[FitDataRecord]) -> usize {
+    // 14 byte header and 2 byte CRC
+    let mut size = 16;
+    let mut layout: Option<(MesgNum, Vec<u8>)> = None;
+    for record in records {
+        let numbers: Vec<u8> = record.fields().iter().map(|f| f.number()).collect();
+        let current = Some((record.kind(), numbers));
+        if current != layout {
+            size += 6 + 3 * record.fields().len();
+            layout = current;
+        }
+        size += 1 + record
+            .fields()
+            .iter()
+            .map(|f| value_size(f.value()))
+            .sum::<usize>();
+    }
+    size
+}
+
+/// Keep the first and last `record` messages along with every Nth one in between
+fn decimate(records: &[FitDataRecord], keep_every: usize) -> Vec<FitDataRecord> {
+    let total = records.iter().filter(|r| is_record_message(r)).count();
+    let mut seen = 0;
+    records
+        .iter()
+        .filter(|r| {
+            if !is_record_message(r) {
+                return true;
+            }
+            seen += 1;
+            (seen - 1) % keep_every == 0 || seen == total
+        })
+        .cloned()
+        .collect()
+}
+
+/// Remove everything the options ask for from the records and return what was removed
+pub fn slim(records: &mut Vec<FitDataRecord>, options: &SlimOptions) -> SlimStats {
+    let before = records.len();
+    let mut stats = SlimStats::default();
+    let mut devices = HashSet::new();
+    records.retain(|r| {
+        if options.remove_unknown_messages && r.is_unknown() {
+            return false;
+        }
+        if options.remove_developer_fields
+            && (r.is_kind(MesgNum::DeveloperDataId) || r.is_kind(MesgNum::FieldDescription))
+        {
+            return false;
+        }
+        if options.remove_duplicate_device_info && r.is_kind(MesgNum::DeviceInfo) {
+            let device = r.field("device_index").map(|f| f.value().to_string());
+            return devices.insert(device);
+        }
+        true
+    });
+    if options.remove_developer_fields {
+        for record in records.iter_mut() {
+            let fields = record.fields_mut();
+            let count = fields.len();
+            fields.retain(|f| !f.is_developer_field());
+            stats.removed_fields += count - fields.len();
+        }
+    }
+
+    let record_count = records.iter().filter(|r| is_record_message(r)).count();
+    let mut keep_every = options.keep_every.max(1);
+    let mut slimmed = decimate(records, keep_every);
+    if let Some(target) = options.target_size {
+        while estimated_size(&slimmed) > target && keep_every < record_count {
+            keep_every = (keep_every * 2).min(record_count);
+            slimmed = decimate(records, keep_every);
+        }
+    }
+    *records = slimmed;
+
+    stats.removed_messages = before - records.len();
+    stats.keep_every = keep_every;
+    stats.estimated_size = estimated_size(records);
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+
+    #[test]
+    fn slim_activity() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let original = from_bytes(data).unwrap();
+        let original_records = original.iter().filter(|r| is_record_message(r)).count();
+
+        let mut records = original.clone();
+        let stats = slim(
+            &mut records,
+            &SlimOptions {
+                keep_every: 5,
+                ..Default::default()
+            },
+        );
+        let kept = records.iter().filter(|r| is_record_message(r)).count();
+        assert_eq!(
+            kept,
+            (original_records - 1) / 5 + 1 + usize::from((original_records - 1) % 5 != 0)
+        );
+        assert!(records.iter().all(|r| !r.is_unknown()));
+        assert_eq!(stats.removed_messages, original.len() - records.len());
+
+        let target = stats.estimated_size - 100;
+        let mut records = original.clone();
+        let stats = slim(
+            &mut records,
+            &SlimOptions {
+                target_size: Some(target),
+                ..Default::default()
+            },
+        );
+        assert!(stats.estimated_size <= target);
+        assert!(stats.keep_every > 1);
+    }
+}
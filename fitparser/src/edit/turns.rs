@@ -0,0 +1,204 @@
+//! Detect turns along a track and add `course_point` messages for them so generated courses
+//! give turn cues on devices.
+use super::{field_as_f64, is_record_message};
+use crate::geo::{bearing, haversine, record_position, Point};
+use crate::profile::field_types::CoursePoint;
+use crate::profile::MesgNum;
+use crate::{records_of_kind, FitDataField, FitDataRecord, Value};
+
+/// Settings that control which changes in bearing are reported as turns
+#[derive(Clone, Debug)]
+pub struct TurnDetection {
+    /// Minimum change in bearing, in degrees, for a turn to be reported
+    pub min_angle: f64,
+    /// The track is sampled into segments at least this many meters long before bearings are
+    /// compared, this keeps GPS jitter from being reported as a turn
+    pub segment_length: f64,
+    /// Minimum distance in meters between two consecutive turn cues
+    pub min_spacing: f64,
+}
+
+impl Default for TurnDetection {
+    fn default() -> Self {
+        TurnDetection {
+            min_angle: 45.0,
+            segment_length: 25.0,
+            min_spacing: 50.0,
+        }
+    }
+}
+
+/// A track vertex, the record index it came from and the distance along the track
+struct Vertex {
+    index: usize,
+    point: Point,
+    distance: f64,
+}
+
+/// Sample the track so consecutive vertices are at least `segment_length` meters apart
+fn track_vertices(records: &[FitDataRecord], segment_length: f64) -> Vec<Vertex> {
+    let mut vertices: Vec<Vertex> = Vec::new();
+    let mut distance = 0.0;
+    let mut last_point: Option<Point> = None;
+    for (index, record) in records.iter().enumerate() {
+        if !is_record_message(record) {
+            continue;
+        }
+        let point = match record_position(record) {
+            Some(p) => p,
+            None => continue,
+        };
+        if let Some(last) = last_point {
+            distance += haversine(last, point);
+        }
+        last_point = Some(point);
+        // prefer the distance recorded by the device when available
+        let distance = field_as_f64(record, "distance").unwrap_or(distance);
+        match vertices.last() {
+            Some(v) if haversine(v.point, point) < segment_length => {}
+            _ => vertices.push(Vertex {
+                index,
+                point,
+                distance,
+            }),
+        }
+    }
+    vertices
+}
+
+/// Build a course point message for a turn located at the provided record
+fn course_point(
+    record: &FitDataRecord,
+    kind: CoursePoint,
+    distance: f64,
+    index: u16,
+) -> FitDataRecord {
+    let mut point = FitDataRecord::new(MesgNum::CoursePoint);
+    if let Some(field) = record.field("timestamp") {
+        point.push(FitDataField::new(
+            "timestamp".to_string(),
+            1,
+            field.value().clone(),
+            String::new(),
+        ));
+    }
+    for (name, number) in [("position_lat", 2), ("position_long", 3)] {
+        if let Some(field) = record.field(name) {
+            point.push(FitDataField::new(
+                name.to_string(),
+                number,
+                field.value().clone(),
+                field.units().to_string(),
+            ));
+        }
+    }
+    point.push(FitDataField::new(
+        "distance".to_string(),
+        4,
+        Value::Float64(distance),
+        "m".to_string(),
+    ));
+    point.push(FitDataField::new(
+        "type".to_string(),
+        5,
+        Value::String(kind.to_string()),
+        String::new(),
+    ));
+    let name = match kind {
+        CoursePoint::Left => "Left",
+        _ => "Right",
+    };
+    point.push(FitDataField::new(
+        "name".to_string(),
+        6,
+        Value::String(name.to_string()),
+        String::new(),
+    ));
+    point.push(FitDataField::new(
+        "message_index".to_string(),
+        254,
+        Value::UInt16(index),
+        String::new(),
+    ));
+    point
+}
+
+/// Detect turns in the track formed by the `record` messages and insert a `course_point` message
+/// with a `left` or `right` type directly after the record where each turn occurs. Message
+/// indices continue on from any course points already present. The number of course points
+/// added is returned.
+pub fn add_turn_course_points(records: &mut Vec<FitDataRecord>, opts: &TurnDetection) -> usize {
+    let vertices = track_vertices(records, opts.segment_length);
+    let next_index = records_of_kind(records, MesgNum::CoursePoint).count() as u16;
+
+    let mut turns = Vec::new();
+    let mut last_cue: Option<f64> = None;
+    for window in vertices.windows(3) {
+        let incoming = bearing(window[0].point, window[1].point);
+        let outgoing = bearing(window[1].point, window[2].point);
+        // normalize into (-180, 180], positive values are clockwise i.e. right hand turns
+        let mut angle = outgoing - incoming;
+        if angle > 180.0 {
+            angle -= 360.0;
+        } else if angle <= -180.0 {
+            angle += 360.0;
+        }
+        if angle.abs() < opts.min_angle {
+            continue;
+        }
+        let vertex = &window[1];
+        if last_cue.is_some_and(|d| vertex.distance - d < opts.min_spacing) {
+            continue;
+        }
+        last_cue = Some(vertex.distance);
+        let kind = if angle > 0.0 {
+            CoursePoint::Right
+        } else {
+            CoursePoint::Left
+        };
+        turns.push((vertex.index, kind, vertex.distance));
+    }
+
+    // insert in reverse so the stored indices stay valid
+    for (pos, (index, kind, distance)) in turns.iter().enumerate().rev() {
+        let point = course_point(&records[*index], *kind, *distance, next_index + pos as u16);
+        records.insert(index + 1, point);
+    }
+    turns.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn detect_right_then_left_turn() {
+        // head north, turn east (right), then turn north again (left), ~11m per step
+        let semicircles = |deg: f64| Value::SInt32((deg * 2_147_483_648.0 / 180.0) as i32);
+        let track = (0..10)
+            .map(|i| (45.0 + i as f64 * 0.0001, 7.0))
+            .chain((1..10).map(|i| (45.0009, 7.0 + i as f64 * 0.00014)))
+            .chain((1..10).map(|i| (45.0009 + i as f64 * 0.0001, 7.00126)));
+        let mut records: Vec<FitDataRecord> = track
+            .map(|(lat, long)| {
+                fixtures::record(
+                    MesgNum::Record,
+                    &[
+                        ("position_lat", semicircles(lat)),
+                        ("position_long", semicircles(long)),
+                    ],
+                )
+            })
+            .collect();
+
+        let added = add_turn_course_points(&mut records, &TurnDetection::default());
+        assert_eq!(added, 2);
+        let types: Vec<String> = records
+            .iter()
+            .filter(|r| r.kind() == MesgNum::CoursePoint)
+            .map(|r| r.field("type").unwrap().value().to_string())
+            .collect();
+        assert_eq!(types, vec!["right", "left"]);
+    }
+}
@@ -9,6 +9,40 @@ pub type Result<T> = ::std::result::Result<T, Error>;
 /// An error that can be produced during deserializing.
 pub type Error = Box<ErrorKind>;
 
+/// Location in the FIT stream where a decode error occurred
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// Absolute byte offset from the start of the stream
+    pub offset: usize,
+    /// Local message number of the message being processed, if any
+    pub local_message_number: Option<u8>,
+    /// Global message number of the message being processed, if known
+    pub global_message_number: Option<u16>,
+}
+
+impl ErrorContext {
+    /// Create a context that only knows the byte offset
+    pub fn at(offset: usize) -> Self {
+        ErrorContext {
+            offset,
+            ..Default::default()
+        }
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "position {:#x}", self.offset)?;
+        if let Some(local) = self.local_message_number {
+            write!(fmt, ", local message number {}", local)?;
+        }
+        if let Some(global) = self.global_message_number {
+            write!(fmt, ", global message number {}", global)?;
+        }
+        Ok(())
+    }
+}
+
 /// The kind of error that can be produced during deserialization.
 /// TODO: Handle errors produced by nom cleanly
 #[derive(Debug)]
@@ -17,19 +51,63 @@ pub enum ErrorKind {
     /// We store the successful parsing result incase we want to ignore the CRC failure and containue
     /// parsing. The first u16 value is the expected CRC, the second is what was calculated from the
     /// data.
-    InvalidCrc((Vec<u8>, FitObject, u16, u16)),
-    /// Errors tied to IO issues and not the actual parsing steps.
+    InvalidCrc((Vec<u8>, FitObject, u16, u16), ErrorContext),
+    /// Errors tied to IO issues and not the actual parsing steps, e.g. opening a file or
+    /// writing an export.
     Io(io::Error),
-    /// If a definition mesage can't be found, postion of message and local message number
-    MissingDefinitionMessage(u8, usize),
+    /// Error reading the source of a FIT stream while it's decoded, the context holds the number
+    /// of bytes read before the error
+    ReadError(io::Error, ErrorContext),
+    /// If a definition mesage can't be found, the context holds the missing local message number
+    MissingDefinitionMessage(ErrorContext),
     /// Trailing bytes remain after parsing
-    TrailingBytes(usize),
+    TrailingBytes(usize, ErrorContext),
     /// Errors generated by trying to parse invalid data with a nom combinator
-    ParseError(usize, nom::error::ErrorKind),
+    ParseError(ErrorContext, nom::error::ErrorKind),
     /// Errors tied to insufficent data in the buffer, similar to an IO error but coming from nom
-    UnexpectedEof(nom::Needed),
+    UnexpectedEof(nom::Needed, ErrorContext),
     /// Errors related to interactions with a Value enum
     ValueError(String),
+    /// Errors raised while applying the FIT profile to a data message
+    DecodeError(String, ErrorContext),
+    /// Invalid filter expression, the position is the character offset of the problem
+    FilterError(String, usize),
+    /// Invalid export template, the position is the byte offset of the problem
+    TemplateError(String, usize),
+    /// A record can't be encoded as a FIT message
+    EncodeError(String),
+    /// A document in another format can't be imported as FIT records
+    ImportError(String),
+}
+
+impl ErrorKind {
+    /// Location in the FIT stream the error occurred at. Every error raised while decoding a
+    /// stream has one, `None` is only returned for errors that don't come from a FIT stream:
+    /// `Io` errors opening files or writing exports, `ValueError`s converting values outside of
+    /// decoding, `FilterError`s and `TemplateError`s whose position is in the expression or
+    /// template, and `EncodeError`s and `ImportError`s.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            ErrorKind::InvalidCrc(_, ctx)
+            | ErrorKind::ReadError(_, ctx)
+            | ErrorKind::MissingDefinitionMessage(ctx)
+            | ErrorKind::TrailingBytes(_, ctx)
+            | ErrorKind::ParseError(ctx, _)
+            | ErrorKind::UnexpectedEof(_, ctx)
+            | ErrorKind::DecodeError(_, ctx) => Some(ctx),
+            ErrorKind::Io(_)
+            | ErrorKind::ValueError(_)
+            | ErrorKind::FilterError(..)
+            | ErrorKind::TemplateError(..)
+            | ErrorKind::EncodeError(_)
+            | ErrorKind::ImportError(_) => None,
+        }
+    }
+
+    /// Absolute byte offset in the FIT stream the error occurred at
+    pub fn offset(&self) -> Option<usize> {
+        self.context().map(|ctx| ctx.offset)
+    }
 }
 
 impl StdError for ErrorKind {
@@ -37,11 +115,17 @@ impl StdError for ErrorKind {
         match *self {
             ErrorKind::InvalidCrc(..) => None,
             ErrorKind::Io(ref err) => Some(err),
+            ErrorKind::ReadError(ref err, _) => Some(err),
             ErrorKind::MissingDefinitionMessage(..) => None,
-            ErrorKind::TrailingBytes(_) => None,
+            ErrorKind::TrailingBytes(..) => None,
             ErrorKind::ParseError(..) => None, // TODO, I should chain nom's error in here somehow
             ErrorKind::UnexpectedEof(..) => None,
             ErrorKind::ValueError(..) => None,
+            ErrorKind::DecodeError(..) => None,
+            ErrorKind::FilterError(..) => None,
+            ErrorKind::TemplateError(..) => None,
+            ErrorKind::EncodeError(..) => None,
+            ErrorKind::ImportError(..) => None,
         }
     }
 }
@@ -55,40 +139,47 @@ impl From<io::Error> for Error {
 impl fmt::Display for ErrorKind {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            ErrorKind::InvalidCrc((_, obj, exp_val, calc_val)) => match obj {
+            ErrorKind::InvalidCrc((_, obj, exp_val, calc_val), ctx) => match obj {
                 FitObject::Header(_) => write!(
                     fmt,
-                    "CRC value for header did not match, expected value {}, calculated value {}",
-                    exp_val, calc_val
+                    "CRC value for header did not match, expected value {}, calculated value {} at {}",
+                    exp_val, calc_val, ctx
                 ),
                 _ => write!(
                     fmt,
-                    "CRC value for data did not match, expected value {}, calculated value {}",
-                    exp_val, calc_val
+                    "CRC value for data did not match, expected value {}, calculated value {} at {}",
+                    exp_val, calc_val, ctx
                 ),
             },
             ErrorKind::Io(ref ioerr) => write!(fmt, "io error: {}", ioerr),
-            ErrorKind::TrailingBytes(rem) => {
-                write!(fmt, "{} bytes remain past expected EOF location", rem)
+            ErrorKind::ReadError(ref ioerr, ctx) => write!(fmt, "read error: {} at {}", ioerr, ctx),
+            ErrorKind::TrailingBytes(rem, ctx) => {
+                write!(fmt, "{} bytes remain past expected EOF location at {}", rem, ctx)
+            }
+            ErrorKind::MissingDefinitionMessage(ctx) => {
+                write!(fmt, "No definition found for message at {}", ctx)
+            }
+            ErrorKind::ParseError(ctx, ref err) => {
+                write!(fmt, "parser error: '{}' at {}", err.description(), ctx)
             }
-            ErrorKind::MissingDefinitionMessage(local_number, position) => write!(
-                fmt,
-                "No definition found for local message number {} at position {:#x}",
-                local_number, position
-            ),
-            ErrorKind::ParseError(pos, ref err) => write!(
-                fmt,
-                "parser error: '{}' at position: {:#x}",
-                err.description(),
-                pos
-            ),
-            ErrorKind::UnexpectedEof(nom::Needed::Size(n)) => {
-                write!(fmt, "parser error: requires {} more bytes", n)
+            ErrorKind::UnexpectedEof(nom::Needed::Size(n), ctx) => {
+                write!(fmt, "parser error: requires {} more bytes at {}", n, ctx)
             }
-            ErrorKind::UnexpectedEof(nom::Needed::Unknown) => {
-                write!(fmt, "parser error: requires more data")
+            ErrorKind::UnexpectedEof(nom::Needed::Unknown, ctx) => {
+                write!(fmt, "parser error: requires more data at {}", ctx)
             }
             ErrorKind::ValueError(ref message) => write!(fmt, "value error: {}", message),
+            ErrorKind::DecodeError(ref message, ctx) => {
+                write!(fmt, "decode error: {} at {}", message, ctx)
+            }
+            ErrorKind::FilterError(ref message, position) => {
+                write!(fmt, "filter error: {} at character {}", message, position)
+            }
+            ErrorKind::TemplateError(ref message, position) => {
+                write!(fmt, "template error: {} at byte {}", message, position)
+            }
+            ErrorKind::EncodeError(ref message) => write!(fmt, "encode error: {}", message),
+            ErrorKind::ImportError(ref message) => write!(fmt, "import error: {}", message),
         }
     }
 }
@@ -0,0 +1,119 @@
+//! Canonical channels of the `record` messages for each sport, used by the tabular exporters so
+//! exports only contain the columns that matter for the activity.
+//!
+//! Channels are named after the record fields they read, `speed` and `altitude` prefer the
+//! enhanced fields when present and `pace` is derived from the speed in seconds per kilometer.
+use crate::edit::field_as_f64;
+use crate::profile::field_types::Sport;
+use crate::profile::{enum_value, FieldDataType, MesgNum};
+use crate::FitDataRecord;
+#[cfg(not(compact_profile))]
+use crate::Value;
+
+const CYCLING: &[&str] = &["power", "heart_rate", "cadence", "speed", "altitude"];
+const RUNNING: &[&str] = &["pace", "heart_rate", "cadence", "stance_time", "altitude"];
+// the cadence of swimming records is the stroke rate
+const SWIMMING: &[&str] = &["distance", "speed", "cadence", "heart_rate"];
+const GENERIC: &[&str] = &[
+    "distance",
+    "speed",
+    "altitude",
+    "heart_rate",
+    "cadence",
+    "power",
+];
+
+/// Channels exported by default for a sport, sports without a dedicated set use a generic one
+pub fn sport_channels(sport: Option<&str>) -> &'static [&'static str] {
+    match sport {
+        Some("cycling" | "e_biking") => CYCLING,
+        Some("running" | "walking" | "hiking") => RUNNING,
+        Some("swimming") => SWIMMING,
+        _ => GENERIC,
+    }
+}
+
+/// Sport of the activity, taken from the first `session` or `sport` message that has one
+#[cfg(not(compact_profile))]
+pub fn activity_sport(records: &[FitDataRecord]) -> Option<&str> {
+    records
+        .iter()
+        .filter(|r| r.is_kind(MesgNum::Session) || r.is_kind(MesgNum::Sport))
+        .find_map(|r| match r.field("sport").map(|f| f.value()) {
+            Some(Value::String(sport)) => Some(sport.as_str()),
+            _ => None,
+        })
+}
+
+/// Channels exported by default for the activity
+pub fn default_channels(records: &[FitDataRecord]) -> &'static [&'static str] {
+    // matched by code rather than name so it works with a compact profile as well
+    let sport = records
+        .iter()
+        .filter(|r| r.is_kind(MesgNum::Session) || r.is_kind(MesgNum::Sport))
+        .find_map(|r| enum_value(FieldDataType::Sport, r.field("sport")?.value()))
+        .map(Sport::from);
+    match sport {
+        Some(Sport::Cycling | Sport::EBiking) => CYCLING,
+        Some(Sport::Running | Sport::Walking | Sport::Hiking) => RUNNING,
+        Some(Sport::Swimming) => SWIMMING,
+        _ => GENERIC,
+    }
+}
+
+/// Numeric value of a channel for a record
+pub(crate) fn channel_value(record: &FitDataRecord, channel: &str) -> Option<f64> {
+    match channel {
+        "speed" => field_as_f64(record, "enhanced_speed").or_else(|| field_as_f64(record, "speed")),
+        "altitude" => {
+            field_as_f64(record, "enhanced_altitude").or_else(|| field_as_f64(record, "altitude"))
+        }
+        "pace" => channel_value(record, "speed")
+            .filter(|speed| *speed > 0.0)
+            .map(|speed| 1000.0 / speed),
+        _ => field_as_f64(record, channel),
+    }
+}
+
+/// Units of a channel for a record, empty if the record doesn't contain it
+pub(crate) fn channel_units<'a>(record: &'a FitDataRecord, channel: &str) -> &'a str {
+    match channel {
+        "pace" => "s/km",
+        "speed" | "altitude" => record
+            .field(&format!("enhanced_{}", channel))
+            .or_else(|| record.field(channel))
+            .map_or("", |f| f.units()),
+        _ => record.field(channel).map_or("", |f| f.units()),
+    }
+}
+
+#[cfg(all(test, not(compact_profile)))]
+mod tests {
+    use super::*;
+    use crate::de::{from_bytes_with_options, DecodeOption};
+    use crate::from_bytes;
+
+    #[test]
+    fn channels_for_sport() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let records = from_bytes(data).unwrap();
+        assert_eq!(activity_sport(&records), Some("cycling"));
+        assert_eq!(default_channels(&records), CYCLING);
+        assert_eq!(sport_channels(Some("running"))[0], "pace");
+        assert_eq!(sport_channels(None), GENERIC);
+
+        // numeric enums, as decoded with a compact profile
+        let options = [DecodeOption::ReturnNumericEnumValues].into();
+        let numeric = from_bytes_with_options(data, &options).unwrap();
+        assert_eq!(default_channels(&numeric), CYCLING);
+
+        let record = records
+            .iter()
+            .find(|r| channel_value(r, "speed").is_some_and(|s| s > 0.0))
+            .unwrap();
+        let speed = channel_value(record, "speed").unwrap();
+        let pace = channel_value(record, "pace").unwrap();
+        assert!((pace * speed - 1000.0).abs() < 1e-9);
+        assert_eq!(channel_units(record, "speed"), "m/s");
+    }
+}
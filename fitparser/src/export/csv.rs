@@ -0,0 +1,312 @@
+//! Write the `record` messages of an activity as a CSV table with one row per record.
+//!
+//! The first column is the timestamp, followed by the channels of the activity's sport or the
+//! channels given by the caller. Channels that aren't numeric are written using their displayed
+//! value and missing values are left empty.
+//!
+//! `write_message_csv` instead writes every message of a FIT file in the layout of the SDK's
+//! FitCSVTool, a `Definition` row for each definition message followed by a `Data` row for each
+//! data message, so the output can be compared with the reference output of the SDK.
+use super::channels::{channel_units, channel_value, default_channels};
+use crate::analysis::record_time;
+use crate::de::{BaseType, FitDefinitionMessage, FitObject, FitStreamProcessor};
+use crate::edit::is_record_message;
+use crate::error::Result;
+use crate::profile::{enum_value, field_info, field_name, FieldDataType, MesgNum};
+use crate::ser::FIT_EPOCH_OFFSET;
+use crate::timestamp::{to_wall_clock, UnixSeconds};
+use crate::{FitDataField, FitDataRecord, Value};
+use chrono::{SecondsFormat, Utc};
+use std::collections::HashMap;
+use std::io::Write;
+
+/// Quote a CSV cell if it contains a separator, quote or line break
+fn escape_csv(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+fn cell(record: &FitDataRecord, channel: &str) -> String {
+    match channel_value(record, channel) {
+        Some(value) => value.to_string(),
+        None => record
+            .field(channel)
+            .map_or(String::new(), |f| escape_csv(&f.value().to_string())),
+    }
+}
+
+/// Write the records using the default channels of the activity's sport
+pub fn write_csv<W: Write>(records: &[FitDataRecord], writer: &mut W) -> Result<()> {
+    write_csv_with_channels(records, default_channels(records), writer)
+}
+
+/// Write the records using the given channels, the header row names each channel along with its
+/// units, e.g. `heart_rate (bpm)`
+pub fn write_csv_with_channels<W: Write, S: AsRef<str>>(
+    records: &[FitDataRecord],
+    channels: &[S],
+    writer: &mut W,
+) -> Result<()> {
+    let rows: Vec<&FitDataRecord> = records.iter().filter(|r| is_record_message(r)).collect();
+    let mut header = vec!["timestamp".to_string()];
+    for channel in channels {
+        let channel = channel.as_ref();
+        let units = rows
+            .iter()
+            .map(|r| channel_units(r, channel))
+            .find(|u| !u.is_empty());
+        header.push(match units {
+            Some(units) => escape_csv(&format!("{} ({})", channel, units)),
+            None => escape_csv(channel),
+        });
+    }
+    writeln!(writer, "{}", header.join(","))?;
+    for record in rows {
+        let time = record_time(record).map_or(String::new(), |t| {
+            t.with_timezone(&Utc)
+                .to_rfc3339_opts(SecondsFormat::Secs, true)
+        });
+        write!(writer, "{}", time)?;
+        for channel in channels {
+            write!(writer, ",{}", cell(record, channel.as_ref()))?;
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+/// Convert the records of an activity into a CSV table using the default channels of its sport
+pub fn to_csv(records: &[FitDataRecord]) -> Result<String> {
+    let mut out = Vec::new();
+    write_csv(records, &mut out)?;
+    Ok(String::from_utf8_lossy(&out).into_owned())
+}
+
+/// Name and base type of the developer fields described by `field_description` messages, keyed by
+/// developer data index and field number
+type DeveloperFields = HashMap<(u8, u8), (String, Option<BaseType>)>;
+
+fn register_field_description(record: &FitDataRecord, developer_fields: &mut DeveloperFields) {
+    let number = |name| {
+        record
+            .field(name)
+            .and_then(|f| TryInto::<i64>::try_into(f.value().clone()).ok())
+            .and_then(|n| u8::try_from(n).ok())
+    };
+    if let (Some(index), Some(number)) = (
+        number("developer_data_index"),
+        number("field_definition_number"),
+    ) {
+        let name = record.field("field_name").map(|f| f.value().to_string());
+        let base_type = record
+            .field("fit_base_type_id")
+            .and_then(|f| enum_value(FieldDataType::FitBaseType, f.value()))
+            .map(|id| BaseType::from(id as u8));
+        developer_fields.insert(
+            (index, number),
+            (
+                name.unwrap_or_else(|| format!("developer_field_{}_{}", index, number)),
+                base_type,
+            ),
+        );
+    }
+}
+
+/// Name of a message, messages missing from the profile are named `unknown`
+fn message_name(kind: MesgNum) -> String {
+    if MesgNum::is_named_variant(kind.as_i64()) {
+        kind.to_string()
+    } else {
+        "unknown".to_string()
+    }
+}
+
+/// Row of a definition message listing each field with its number of elements
+fn definition_row(definition: &FitDefinitionMessage, developer_fields: &DeveloperFields) -> String {
+    let kind = MesgNum::from(definition.global_message_number());
+    let mut row = format!(
+        "Definition,{},{},",
+        definition.local_message_number(),
+        message_name(kind)
+    );
+    for field in definition.field_definitions() {
+        let name = field_name(kind, field.field_definition_number()).unwrap_or("unknown");
+        let count = field.size() / field.base_type().size().max(1);
+        row.push_str(&format!("{},{},,", name, count));
+    }
+    for field in definition.developer_field_definitions() {
+        let key = (field.developer_data_index(), field.field_number());
+        let (name, count) = match developer_fields.get(&key) {
+            Some((name, Some(base_type))) => (name.clone(), field.size() / base_type.size()),
+            Some((name, None)) => (name.clone(), field.size()),
+            None => (format!("developer_field_{}_{}", key.0, key.1), field.size()),
+        };
+        row.push_str(&format!("{},{},,", escape_csv(&name), count));
+    }
+    row
+}
+
+/// Value of a field as FitCSVTool writes it, timestamps in seconds since the FIT epoch and the
+/// elements of arrays separated by `|`
+fn message_value(kind: MesgNum, field: &FitDataField, value: &Value) -> String {
+    match value {
+        Value::Array(values) => values
+            .iter()
+            .map(|v| message_value(kind, field, v))
+            .collect::<Vec<_>>()
+            .join("|"),
+        Value::Timestamp(time) => {
+            let local = field_info(kind, field.name())
+                .is_some_and(|info| matches!(info.field_type, FieldDataType::LocalDateTime));
+            let seconds = if local {
+                to_wall_clock(time).unix_seconds()
+            } else {
+                time.unix_seconds()
+            };
+            (seconds - FIT_EPOCH_OFFSET).to_string()
+        }
+        Value::UtcTimestamp(time) => (time.unix_seconds() - FIT_EPOCH_OFFSET).to_string(),
+        Value::LocalTimestamp(time) => (time.unix_seconds() - FIT_EPOCH_OFFSET).to_string(),
+        value => value.to_string(),
+    }
+}
+
+/// Row of a data message with the name, value and units of each field
+fn data_row(local_message_number: u8, record: &FitDataRecord) -> String {
+    let mut row = format!(
+        "Data,{},{},",
+        local_message_number,
+        message_name(record.kind())
+    );
+    for field in record.fields() {
+        let name = if field.is_unknown() {
+            "unknown"
+        } else {
+            field.name()
+        };
+        let value = message_value(record.kind(), field, field.value());
+        row.push_str(&format!(
+            "{},\"{}\",{},",
+            escape_csv(name),
+            value.replace('"', "\"\""),
+            escape_csv(field.units())
+        ));
+    }
+    row
+}
+
+/// Write the messages of a FIT file in the CSV layout of the SDK's FitCSVTool, a row per
+/// definition and data message holding the local message number, the message name and a
+/// triplet of columns per field. Definition rows hold the number of elements of each field and
+/// data rows the decoded value and units, fields unknown to the profile are named `unknown`.
+/// Chained files are written one after another.
+pub fn write_message_csv<W: Write>(mut data: &[u8], writer: &mut W) -> Result<()> {
+    let mut processor = FitStreamProcessor::new();
+    let mut developer_fields = DeveloperFields::new();
+    let mut rows = Vec::new();
+    let mut max_fields = 0;
+
+    while !data.is_empty() {
+        let (buf, obj) = processor.deserialize_next(data)?;
+        let row = match obj {
+            FitObject::DefinitionMessage(definition) => {
+                max_fields = max_fields.max(
+                    definition.field_definitions().len()
+                        + definition.developer_field_definitions().len(),
+                );
+                Some(definition_row(&definition, &developer_fields))
+            }
+            FitObject::DataMessage(message) => {
+                let local_message_number = message.local_message_number();
+                let record = processor.decode_message(message)?;
+                if record.is_kind(MesgNum::FieldDescription) {
+                    register_field_description(&record, &mut developer_fields);
+                }
+                max_fields = max_fields.max(record.fields().len());
+                Some(data_row(local_message_number, &record))
+            }
+            FitObject::Crc(..) => {
+                processor.reset();
+                developer_fields.clear();
+                None
+            }
+            FitObject::Header(..) => None,
+        };
+        rows.extend(row);
+        data = buf;
+    }
+
+    write!(writer, "Type,Local Number,Message,")?;
+    for i in 1..=max_fields {
+        write!(writer, "Field {i},Value {i},Units {i},")?;
+    }
+    writeln!(writer)?;
+    for row in rows {
+        writeln!(writer, "{}", row)?;
+    }
+    Ok(())
+}
+
+/// Convert the messages of a FIT file into the CSV layout of the SDK's FitCSVTool
+pub fn to_message_csv(data: &[u8]) -> Result<String> {
+    let mut out = Vec::new();
+    write_message_csv(data, &mut out)?;
+    Ok(String::from_utf8_lossy(&out).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+
+    #[test]
+    fn write_sport_channels() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let records = from_bytes(data).unwrap();
+        let csv = to_csv(&records).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("timestamp,power,heart_rate (bpm),cadence,speed (m/s),altitude (m)")
+        );
+        let row: Vec<&str> = lines.next().unwrap().split(',').collect();
+        assert_eq!(row.len(), 6);
+        assert!(row[0].ends_with('Z'));
+
+        let mut out = Vec::new();
+        write_csv_with_channels(&records, &["distance", "activity_type"], &mut out).unwrap();
+        let csv = String::from_utf8(out).unwrap();
+        assert!(csv.starts_with("timestamp,distance (m),activity_type\n"));
+    }
+
+    #[test]
+    fn write_fit_csv_tool_layout() {
+        let data = include_bytes!("../../tests/fixtures/DeveloperData.fit");
+        let csv = to_message_csv(data).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].starts_with("Type,Local Number,Message,Field 1,Value 1,Units 1,"));
+        assert!(lines[1].starts_with("Definition,0,file_id,"));
+        assert!(lines[2].starts_with("Data,0,file_id,"));
+        let records = from_bytes(data).unwrap();
+        let data_rows = lines.iter().filter(|l| l.starts_with("Data,")).count();
+        assert_eq!(data_rows, records.len());
+        assert!(lines
+            .iter()
+            .any(|l| l.starts_with("Definition,") && l.contains("doughnuts_earned,1,,")));
+        assert!(lines
+            .iter()
+            .any(|l| l.starts_with("Data,") && l.contains("doughnuts_earned,\"")));
+
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let csv = to_message_csv(data).unwrap();
+        let timestamp = csv
+            .lines()
+            .find(|l| l.starts_with("Data,") && l.contains(",record,"))
+            .unwrap();
+        assert!(timestamp.contains("timestamp,\""));
+        assert!(!timestamp.contains("T"));
+    }
+}
@@ -0,0 +1,131 @@
+//! Write the track of an activity as a GPX 1.1 document
+#[cfg(not(compact_profile))]
+use super::channels::activity_sport;
+use super::TrackOptions;
+use crate::analysis::{record_altitude, record_time};
+use crate::edit::{field_as_f64, is_record_message};
+use crate::error::Result;
+use crate::geo::record_position;
+use crate::FitDataRecord;
+use chrono::{SecondsFormat, Utc};
+use std::io::Write;
+
+/// Namespace of the Garmin track point extension holding heart rate and cadence
+const TRACK_POINT_EXTENSION: &str = "http://www.garmin.com/xmlschemas/TrackPointExtension/v1";
+
+/// Escape the XML special characters of a text value
+#[cfg_attr(compact_profile, allow(dead_code))]
+pub(crate) fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Write heart rate, cadence and temperature of a record using the Garmin track point extension
+/// understood by most applications reading GPX files
+fn write_extensions<W: Write>(record: &FitDataRecord, writer: &mut W) -> Result<()> {
+    let values = [
+        ("atemp", field_as_f64(record, "temperature")),
+        ("hr", field_as_f64(record, "heart_rate")),
+        ("cad", field_as_f64(record, "cadence")),
+    ];
+    if values.iter().all(|(_, value)| value.is_none()) {
+        return Ok(());
+    }
+    write!(writer, "<extensions><gpxtpx:TrackPointExtension>")?;
+    for (name, value) in values {
+        if let Some(value) = value {
+            write!(writer, "<gpxtpx:{name}>{}</gpxtpx:{name}>", value.round())?;
+        }
+    }
+    write!(writer, "</gpxtpx:TrackPointExtension></extensions>")?;
+    Ok(())
+}
+
+/// Write every `record` message with a position as a track point of a single track segment,
+/// heart rate, cadence and temperature are written as track point extensions
+pub fn write_gpx<W: Write>(records: &[FitDataRecord], writer: &mut W) -> Result<()> {
+    write_gpx_with_options(records, &TrackOptions::default(), writer)
+}
+
+/// Write the track as `write_gpx` does, simplifying it first when the options set a tolerance
+pub fn write_gpx_with_options<W: Write>(
+    records: &[FitDataRecord],
+    options: &TrackOptions,
+    writer: &mut W,
+) -> Result<()> {
+    let records = options.track(records);
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<gpx version="1.1" creator="fitparser" xmlns="http://www.topografix.com/GPX/1/1" xmlns:gpxtpx="{}">"#,
+        TRACK_POINT_EXTENSION
+    )?;
+    writeln!(writer, "  <trk>")?;
+    #[cfg(not(compact_profile))]
+    if let Some(name) = activity_sport(&records) {
+        writeln!(writer, "    <name>{}</name>", escape_xml(name))?;
+    }
+    writeln!(writer, "    <trkseg>")?;
+    for record in records.iter().filter(|r| is_record_message(r)) {
+        let (lat, lon) = match record_position(record) {
+            Some(position) => position,
+            None => continue,
+        };
+        write!(writer, r#"      <trkpt lat="{:.7}" lon="{:.7}">"#, lat, lon)?;
+        if let Some(altitude) = record_altitude(record) {
+            write!(writer, "<ele>{:.1}</ele>", altitude)?;
+        }
+        if let Some(time) = record_time(record) {
+            let time = time
+                .with_timezone(&Utc)
+                .to_rfc3339_opts(SecondsFormat::Secs, true);
+            write!(writer, "<time>{}</time>", time)?;
+        }
+        write_extensions(record, writer)?;
+        writeln!(writer, "</trkpt>")?;
+    }
+    writeln!(writer, "    </trkseg>")?;
+    writeln!(writer, "  </trk>")?;
+    writeln!(writer, "</gpx>")?;
+    Ok(())
+}
+
+/// Convert the track of an activity into a GPX document
+pub fn to_gpx(records: &[FitDataRecord]) -> Result<String> {
+    let mut out = Vec::new();
+    write_gpx(records, &mut out)?;
+    Ok(String::from_utf8_lossy(&out).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+
+    #[test]
+    fn write_track() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let records = from_bytes(data).unwrap();
+        let gpx = to_gpx(&records).unwrap();
+        assert!(gpx.starts_with("<?xml"));
+        assert!(gpx.contains("<name>cycling</name>"));
+        let points = gpx.matches("<trkpt ").count();
+        assert!(points > 0);
+        assert_eq!(points, gpx.matches("<time>").count());
+        assert_eq!(points, gpx.matches("<gpxtpx:hr>").count());
+        assert!(gpx.contains("</gpxtpx:TrackPointExtension></extensions></trkpt>"));
+        assert!(gpx.trim_end().ends_with("</gpx>"));
+
+        let options = TrackOptions {
+            simplify_tolerance: Some(5.0),
+        };
+        let mut simplified = Vec::new();
+        write_gpx_with_options(&records, &options, &mut simplified).unwrap();
+        let simplified = String::from_utf8(simplified).unwrap();
+        let simplified_points = simplified.matches("<trkpt ").count();
+        assert!(simplified_points > 1 && simplified_points < points / 2);
+        assert_eq!(simplified_points, simplified.matches("<gpxtpx:hr>").count());
+    }
+}
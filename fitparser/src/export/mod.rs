@@ -0,0 +1,85 @@
+//! Export decoded records into other formats
+use crate::edit::simplify_track;
+#[cfg(feature = "json")]
+use crate::error::ErrorKind;
+use crate::error::Result;
+use crate::FitDataRecord;
+use std::borrow::Cow;
+use std::io::Write;
+
+pub mod channels;
+pub mod csv;
+pub mod gpx;
+pub mod summary;
+// TCX names the sport and lap triggers, which a compact profile doesn't decode
+#[cfg(not(compact_profile))]
+pub mod tcx;
+#[cfg(feature = "templates")]
+pub mod template;
+#[cfg(feature = "zip")]
+pub mod zip;
+
+/// Options of the exporters writing the track of an activity, GPX and TCX
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TrackOptions {
+    /// Simplify the track before writing it, dropping the records whose position lies within
+    /// this many meters of the simplified line, see `edit::simplify_track`. A full 1 Hz track
+    /// is far more detail than a web map needs, a few meters keeps the shape of the route.
+    pub simplify_tolerance: Option<f64>,
+}
+
+impl TrackOptions {
+    /// Records to write, simplified when a tolerance is set
+    pub(crate) fn track<'a>(&self, records: &'a [FitDataRecord]) -> Cow<'a, [FitDataRecord]> {
+        match self.simplify_tolerance {
+            Some(tolerance) => {
+                let mut records = records.to_vec();
+                simplify_track(&mut records, tolerance);
+                Cow::Owned(records)
+            }
+            None => Cow::Borrowed(records),
+        }
+    }
+}
+
+/// Output formats supported by the bulk conversion in `batch`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// The decoded records serialized as a JSON array
+    #[cfg(feature = "json")]
+    Json,
+    /// The track of the activity as a GPX 1.1 document
+    Gpx,
+    /// The `record` messages as a CSV table with the default channels of the sport
+    Csv,
+    /// The sessions, laps and records of the activity as a Training Center XML document
+    #[cfg(not(compact_profile))]
+    Tcx,
+}
+
+impl Format {
+    /// File extension used for the format
+    pub fn extension(self) -> &'static str {
+        match self {
+            #[cfg(feature = "json")]
+            Format::Json => "json",
+            Format::Gpx => "gpx",
+            Format::Csv => "csv",
+            #[cfg(not(compact_profile))]
+            Format::Tcx => "tcx",
+        }
+    }
+
+    /// Write the records in this format
+    pub fn write<W: Write>(self, records: &[FitDataRecord], writer: &mut W) -> Result<()> {
+        match self {
+            #[cfg(feature = "json")]
+            Format::Json => serde_json::to_writer(writer, records)
+                .map_err(|e| ErrorKind::ValueError(e.to_string()).into()),
+            Format::Gpx => gpx::write_gpx(records, writer),
+            Format::Csv => csv::write_csv(records, writer),
+            #[cfg(not(compact_profile))]
+            Format::Tcx => tcx::write_tcx(records, writer),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Downsample the `record` messages into fixed length buckets holding the minimum, average and
+//! maximum of each channel, a lightweight table for dashboards that don't need every sample.
+//!
+//! Buckets start at the timestamp of the first record, buckets without any records, e.g. while
+//! the activity was paused, are left out.
+use super::channels::{channel_value, default_channels};
+use crate::analysis::record_time;
+use crate::edit::is_record_message;
+use crate::error::Result;
+use crate::FitDataRecord;
+use chrono::{DateTime, Local, SecondsFormat, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::Write;
+
+/// Minimum, average and maximum of a channel within a bucket
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct ChannelStats {
+    /// Smallest value
+    pub min: f64,
+    /// Mean of the values
+    pub avg: f64,
+    /// Largest value
+    pub max: f64,
+    /// Number of records that had a value
+    pub count: usize,
+}
+
+impl ChannelStats {
+    fn new(value: f64) -> Self {
+        ChannelStats {
+            min: value,
+            avg: value,
+            max: value,
+            count: 1,
+        }
+    }
+
+    fn add(&mut self, value: f64) {
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.count += 1;
+        self.avg += (value - self.avg) / self.count as f64;
+    }
+}
+
+/// Aggregated channels of a single bucket
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SummaryBucket {
+    /// Start of the bucket
+    pub start: DateTime<Local>,
+    /// Statistics of every channel with at least one value in the bucket
+    pub channels: BTreeMap<String, ChannelStats>,
+}
+
+/// Aggregate the channels of the records into buckets of the given length in seconds
+pub fn summarize<S: AsRef<str>>(
+    records: &[FitDataRecord],
+    channels: &[S],
+    seconds: u32,
+) -> Vec<SummaryBucket> {
+    let seconds = i64::from(seconds.max(1));
+    let mut buckets: BTreeMap<i64, SummaryBucket> = BTreeMap::new();
+    let mut first = None;
+    for record in records.iter().filter(|r| is_record_message(r)) {
+        let time = match record_time(record) {
+            Some(time) => time,
+            None => continue,
+        };
+        let first = *first.get_or_insert(time);
+        let index = (time - first).num_seconds().div_euclid(seconds);
+        let bucket = buckets.entry(index).or_insert_with(|| SummaryBucket {
+            start: first + chrono::Duration::seconds(index * seconds),
+            channels: BTreeMap::new(),
+        });
+        for channel in channels {
+            let channel = channel.as_ref();
+            if let Some(value) = channel_value(record, channel) {
+                bucket
+                    .channels
+                    .entry(channel.to_string())
+                    .and_modify(|s| s.add(value))
+                    .or_insert_with(|| ChannelStats::new(value));
+            }
+        }
+    }
+    buckets.into_values().collect()
+}
+
+/// Aggregate the default channels of the activity's sport per minute
+pub fn per_minute(records: &[FitDataRecord]) -> Vec<SummaryBucket> {
+    summarize(records, default_channels(records), 60)
+}
+
+/// Write the buckets as a CSV table with a `min`, `avg` and `max` column for each channel
+pub fn write_summary_csv<W: Write, S: AsRef<str>>(
+    buckets: &[SummaryBucket],
+    channels: &[S],
+    writer: &mut W,
+) -> Result<()> {
+    write!(writer, "start")?;
+    for channel in channels {
+        let channel = channel.as_ref();
+        write!(writer, ",{0}_min,{0}_avg,{0}_max", channel)?;
+    }
+    writeln!(writer)?;
+    for bucket in buckets {
+        let start = bucket
+            .start
+            .with_timezone(&Utc)
+            .to_rfc3339_opts(SecondsFormat::Secs, true);
+        write!(writer, "{}", start)?;
+        for channel in channels {
+            match bucket.channels.get(channel.as_ref()) {
+                Some(stats) => write!(writer, ",{},{},{}", stats.min, stats.avg, stats.max)?,
+                None => write!(writer, ",,,")?,
+            }
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+/// Convert the records into a per minute CSV summary of the default channels of the sport
+pub fn to_minute_csv(records: &[FitDataRecord]) -> Result<String> {
+    let channels = default_channels(records);
+    let mut out = Vec::new();
+    write_summary_csv(&per_minute(records), channels, &mut out)?;
+    Ok(String::from_utf8_lossy(&out).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+
+    #[test]
+    fn summarize_per_minute() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let records = from_bytes(data).unwrap();
+        let buckets = per_minute(&records);
+        assert!(!buckets.is_empty());
+        for pair in buckets.windows(2) {
+            let gap = (pair[1].start - pair[0].start).num_seconds();
+            assert!(gap >= 60 && gap % 60 == 0);
+        }
+        let hr = buckets
+            .iter()
+            .find_map(|b| b.channels.get("heart_rate"))
+            .unwrap();
+        assert!(hr.min <= hr.avg && hr.avg <= hr.max);
+
+        let csv = to_minute_csv(&records).unwrap();
+        let header = csv.lines().next().unwrap();
+        assert!(header.starts_with("start,power_min,power_avg,power_max,heart_rate_min"));
+        assert_eq!(csv.lines().count(), buckets.len() + 1);
+    }
+}
@@ -0,0 +1,361 @@
+//! Write an activity as a Training Center XML (TCX) document, still the only format accepted by
+//! some coaching platforms
+use super::gpx::escape_xml;
+use super::TrackOptions;
+use crate::analysis::{record_altitude, record_time, Activity, Segment};
+use crate::edit::field_as_f64;
+use crate::error::{ErrorKind, Result};
+use crate::geo::record_position;
+use crate::{FitDataRecord, Value};
+use chrono::{DateTime, Local, SecondsFormat, Utc};
+use std::io::Write;
+
+/// Namespace of the TCX schema
+const TCX_NAMESPACE: &str = "http://www.garmin.com/xmlschemas/TrainingCenterDatabase/v2";
+
+/// Namespace of the activity extension holding speed and power
+const ACTIVITY_EXTENSION: &str = "http://www.garmin.com/xmlschemas/ActivityExtension/v2";
+
+/// A lap to write, the `lap` message holding its totals if there is one
+struct TcxLap<'a> {
+    start: DateTime<Local>,
+    summary: Option<&'a FitDataRecord>,
+    records: Vec<&'a FitDataRecord>,
+}
+
+/// A session to write along with its laps
+struct TcxActivity<'a> {
+    sport: Option<&'a str>,
+    start: DateTime<Local>,
+    laps: Vec<TcxLap<'a>>,
+}
+
+fn text_field<'a>(record: &'a FitDataRecord, name: &str) -> Option<&'a str> {
+    match record.field(name).map(|f| f.value()) {
+        Some(Value::String(text)) => Some(text.as_str()),
+        _ => None,
+    }
+}
+
+fn tcx_time(time: DateTime<Local>) -> String {
+    time.with_timezone(&Utc)
+        .to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn tcx_sport(sport: Option<&str>) -> &'static str {
+    match sport {
+        Some("running") => "Running",
+        Some("cycling") => "Biking",
+        _ => "Other",
+    }
+}
+
+fn trigger_method(trigger: Option<&str>) -> &'static str {
+    match trigger {
+        Some("distance") => "Distance",
+        Some("time") => "Time",
+        Some(trigger) if trigger.starts_with("position") => "Location",
+        _ => "Manual",
+    }
+}
+
+fn segment_lap<'a>(segment: &Segment<'a>) -> TcxLap<'a> {
+    TcxLap {
+        start: segment.start_time(),
+        summary: Some(segment.summary()),
+        records: segment.records().collect(),
+    }
+}
+
+/// Group the laps and records by session, falling back to a single activity holding every lap
+/// or, without laps, a single lap holding every record
+fn activities(records: &[FitDataRecord]) -> Vec<TcxActivity<'_>> {
+    let activity = Activity::new(records);
+    let laps: Vec<_> = activity.laps().collect();
+    let mut activities: Vec<TcxActivity> = activity
+        .sessions()
+        .map(|session| {
+            let in_session: Vec<_> = laps
+                .iter()
+                .filter(|l| {
+                    l.start_time() >= session.start_time() && l.start_time() < session.end_time()
+                })
+                .map(segment_lap)
+                .collect();
+            TcxActivity {
+                sport: text_field(session.summary(), "sport"),
+                start: session.start_time(),
+                laps: match in_session.is_empty() {
+                    true => vec![segment_lap(&session)],
+                    false => in_session,
+                },
+            }
+        })
+        .collect();
+    if activities.is_empty() {
+        let sport = super::channels::activity_sport(records);
+        if !laps.is_empty() {
+            activities.push(TcxActivity {
+                sport,
+                start: laps[0].start_time(),
+                laps: laps.iter().map(segment_lap).collect(),
+            });
+        } else if let Some(start) = activity.records().find_map(record_time) {
+            activities.push(TcxActivity {
+                sport,
+                start,
+                laps: vec![TcxLap {
+                    start,
+                    summary: None,
+                    records: activity.records().collect(),
+                }],
+            });
+        }
+    }
+    activities
+}
+
+fn write_trackpoint<W: Write>(record: &FitDataRecord, running: bool, writer: &mut W) -> Result<()> {
+    let time = match record_time(record) {
+        Some(time) => time,
+        None => return Ok(()),
+    };
+    write!(writer, "<Trackpoint><Time>{}</Time>", tcx_time(time))?;
+    if let Some((lat, lon)) = record_position(record) {
+        write!(
+            writer,
+            "<Position><LatitudeDegrees>{:.7}</LatitudeDegrees><LongitudeDegrees>{:.7}</LongitudeDegrees></Position>",
+            lat, lon
+        )?;
+    }
+    if let Some(altitude) = record_altitude(record) {
+        write!(writer, "<AltitudeMeters>{:.1}</AltitudeMeters>", altitude)?;
+    }
+    if let Some(distance) = field_as_f64(record, "distance") {
+        write!(writer, "<DistanceMeters>{:.2}</DistanceMeters>", distance)?;
+    }
+    if let Some(heart_rate) = field_as_f64(record, "heart_rate") {
+        write!(
+            writer,
+            "<HeartRateBpm><Value>{}</Value></HeartRateBpm>",
+            heart_rate.round()
+        )?;
+    }
+    let cadence = field_as_f64(record, "cadence");
+    if let (Some(cadence), false) = (cadence, running) {
+        write!(writer, "<Cadence>{}</Cadence>", cadence.round())?;
+    }
+    let speed = field_as_f64(record, "enhanced_speed").or_else(|| field_as_f64(record, "speed"));
+    let run_cadence = cadence.filter(|_| running);
+    let power = field_as_f64(record, "power");
+    if speed.is_some() || run_cadence.is_some() || power.is_some() {
+        write!(writer, "<Extensions><ns3:TPX>")?;
+        if let Some(speed) = speed {
+            write!(writer, "<ns3:Speed>{:.3}</ns3:Speed>", speed)?;
+        }
+        if let Some(cadence) = run_cadence {
+            write!(
+                writer,
+                "<ns3:RunCadence>{}</ns3:RunCadence>",
+                cadence.round()
+            )?;
+        }
+        if let Some(power) = power {
+            write!(writer, "<ns3:Watts>{}</ns3:Watts>", power.round())?;
+        }
+        write!(writer, "</ns3:TPX></Extensions>")?;
+    }
+    writeln!(writer, "</Trackpoint>")?;
+    Ok(())
+}
+
+/// Write a lap using the totals of its `lap` message, totals missing from it are taken from the
+/// records of the lap where the schema requires them
+fn write_lap<W: Write>(lap: &TcxLap, running: bool, writer: &mut W) -> Result<()> {
+    let field = |name: &str| lap.summary.and_then(|s| field_as_f64(s, name));
+    let first = lap.records.first().copied();
+    let last = lap.records.last().copied();
+    let total_time = field("total_timer_time").or_else(|| {
+        let (start, end) = (record_time(first?)?, record_time(last?)?);
+        Some((end - start).num_milliseconds() as f64 / 1000.0)
+    });
+    let distance = field("total_distance")
+        .or_else(|| Some(field_as_f64(last?, "distance")? - field_as_f64(first?, "distance")?));
+    writeln!(writer, r#"      <Lap StartTime="{}">"#, tcx_time(lap.start))?;
+    writeln!(
+        writer,
+        "        <TotalTimeSeconds>{:.3}</TotalTimeSeconds>",
+        total_time.unwrap_or(0.0)
+    )?;
+    writeln!(
+        writer,
+        "        <DistanceMeters>{:.2}</DistanceMeters>",
+        distance.unwrap_or(0.0)
+    )?;
+    if let Some(speed) = field("enhanced_max_speed").or_else(|| field("max_speed")) {
+        writeln!(writer, "        <MaximumSpeed>{:.3}</MaximumSpeed>", speed)?;
+    }
+    writeln!(
+        writer,
+        "        <Calories>{}</Calories>",
+        field("total_calories").unwrap_or(0.0).round()
+    )?;
+    for (element, name) in [
+        ("AverageHeartRateBpm", "avg_heart_rate"),
+        ("MaximumHeartRateBpm", "max_heart_rate"),
+    ] {
+        if let Some(heart_rate) = field(name) {
+            writeln!(
+                writer,
+                "        <{element}><Value>{}</Value></{element}>",
+                heart_rate.round()
+            )?;
+        }
+    }
+    let summary_text = |name| lap.summary.and_then(|s| text_field(s, name));
+    let intensity = match summary_text("intensity") {
+        Some("rest") => "Resting",
+        _ => "Active",
+    };
+    writeln!(writer, "        <Intensity>{}</Intensity>", intensity)?;
+    let avg_cadence = field("avg_cadence");
+    if let (Some(cadence), false) = (avg_cadence, running) {
+        writeln!(writer, "        <Cadence>{}</Cadence>", cadence.round())?;
+    }
+    writeln!(
+        writer,
+        "        <TriggerMethod>{}</TriggerMethod>",
+        trigger_method(summary_text("lap_trigger"))
+    )?;
+    writeln!(writer, "        <Track>")?;
+    for record in &lap.records {
+        write!(writer, "          ")?;
+        write_trackpoint(record, running, writer)?;
+    }
+    writeln!(writer, "        </Track>")?;
+
+    let avg_speed = field("enhanced_avg_speed").or_else(|| field("avg_speed"));
+    let avg_run_cadence = avg_cadence.filter(|_| running);
+    let values = [
+        ("AvgSpeed", avg_speed.map(|v| format!("{:.3}", v))),
+        (
+            "AvgRunCadence",
+            avg_run_cadence.map(|v| v.round().to_string()),
+        ),
+        (
+            "AvgWatts",
+            field("avg_power").map(|v| v.round().to_string()),
+        ),
+        (
+            "MaxWatts",
+            field("max_power").map(|v| v.round().to_string()),
+        ),
+    ];
+    if values.iter().any(|(_, value)| value.is_some()) {
+        write!(writer, "        <Extensions><ns3:LX>")?;
+        for (name, value) in values {
+            if let Some(value) = value {
+                write!(writer, "<ns3:{name}>{value}</ns3:{name}>")?;
+            }
+        }
+        writeln!(writer, "</ns3:LX></Extensions>")?;
+    }
+    writeln!(writer, "      </Lap>")?;
+    Ok(())
+}
+
+/// Write the sessions of an activity as TCX activities holding their laps and track points.
+///
+/// Heart rate and cadence are written as TCX elements and speed and power with the Garmin
+/// activity extension, `TPX` for track points and `LX` for laps. Lap totals come from the `lap`
+/// messages, a session without laps is written as a single lap. Files without sessions are
+/// written as a single activity. Records without a timestamp are skipped.
+pub fn write_tcx<W: Write>(records: &[FitDataRecord], writer: &mut W) -> Result<()> {
+    write_tcx_with_options(records, &TrackOptions::default(), writer)
+}
+
+/// Write the activity as `write_tcx` does, simplifying the track first when the options set a
+/// tolerance. The lap totals still come from the `lap` messages.
+pub fn write_tcx_with_options<W: Write>(
+    records: &[FitDataRecord],
+    options: &TrackOptions,
+    writer: &mut W,
+) -> Result<()> {
+    let records = options.track(records);
+    let activities = activities(&records);
+    if activities.is_empty() {
+        return Err(ErrorKind::ValueError("no laps or timestamped records".to_string()).into());
+    }
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<TrainingCenterDatabase xmlns="{}" xmlns:ns3="{}">"#,
+        TCX_NAMESPACE, ACTIVITY_EXTENSION
+    )?;
+    writeln!(writer, "  <Activities>")?;
+    for activity in &activities {
+        let running = activity.sport == Some("running");
+        writeln!(
+            writer,
+            r#"    <Activity Sport="{}">"#,
+            escape_xml(tcx_sport(activity.sport))
+        )?;
+        writeln!(writer, "      <Id>{}</Id>", tcx_time(activity.start))?;
+        for lap in &activity.laps {
+            write_lap(lap, running, writer)?;
+        }
+        writeln!(writer, "    </Activity>")?;
+    }
+    writeln!(writer, "  </Activities>")?;
+    writeln!(writer, "</TrainingCenterDatabase>")?;
+    Ok(())
+}
+
+/// Convert an activity into a TCX document
+pub fn to_tcx(records: &[FitDataRecord]) -> Result<String> {
+    let mut out = Vec::new();
+    write_tcx(records, &mut out)?;
+    Ok(String::from_utf8_lossy(&out).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::MesgNum;
+    use crate::{from_bytes, import};
+
+    #[test]
+    fn write_activity() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let records = from_bytes(data).unwrap();
+        let tcx = to_tcx(&records).unwrap();
+        assert!(tcx.contains(r#"<Activity Sport="Biking">"#));
+        let count = |kind| records.iter().filter(|r| r.is_kind(kind)).count();
+        assert_eq!(tcx.matches("<Lap ").count(), count(MesgNum::Lap));
+        let points = tcx.matches("<Trackpoint>").count();
+        assert!(points > 0 && points <= count(MesgNum::Record));
+        assert!(tcx.contains("<HeartRateBpm><Value>"));
+        assert!(tcx.trim_end().ends_with("</TrainingCenterDatabase>"));
+
+        let imported = import::tcx(tcx.as_bytes()).unwrap();
+        let imported_count = |kind| imported.iter().filter(|r| r.is_kind(kind)).count();
+        assert_eq!(imported_count(MesgNum::Record), points);
+        assert_eq!(imported_count(MesgNum::Lap), count(MesgNum::Lap));
+        let distance = |records: &[FitDataRecord]| {
+            let session = records.iter().find(|r| r.is_kind(MesgNum::Session));
+            field_as_f64(session.unwrap(), "total_distance").unwrap()
+        };
+        assert!((distance(&imported) - distance(&records)).abs() < 1.0);
+
+        let options = TrackOptions {
+            simplify_tolerance: Some(5.0),
+        };
+        let mut simplified = Vec::new();
+        write_tcx_with_options(&records, &options, &mut simplified).unwrap();
+        let simplified = String::from_utf8(simplified).unwrap();
+        assert!(simplified.matches("<Trackpoint>").count() < points / 2);
+        assert_eq!(simplified.matches("<Lap ").count(), count(MesgNum::Lap));
+
+        assert!(to_tcx(&[]).is_err());
+    }
+}
@@ -0,0 +1,156 @@
+//! Render records and summaries through a user supplied template to produce custom text or JSON
+//! shapes. Templates are rendered by [minijinja](https://docs.rs/minijinja) and use its Jinja2
+//! syntax:
+//!
+//! * `{{ fields.heart_rate }}` inserts a value, missing values and `null` insert nothing
+//! * `{% for name, value in fields|items %} ... {% endfor %}` repeats the block for each item of
+//!   a map or array, `loop.index0` is the position
+//! * `{% if fields.power %} ... {% else %} ... {% endif %}` renders the block when the value is
+//!   present and not `false`, `0`, empty or `null`
+//! * `{{ fields|tojson }}` inserts a value as JSON
+//!
+//! Records are rendered with the context `{"kind", "global_message_number", "index", "fields",
+//! "units"}` where `fields` and `units` map field names to their value and units. Any other
+//! serializable value, such as a `SessionSummary`, is rendered using its serialized form.
+//! Values are inserted without escaping.
+use crate::error::{Error, ErrorKind, Result};
+use crate::FitDataRecord;
+use minijinja::{escape_formatter, Environment, Value as TemplateValue};
+use serde::Serialize;
+use serde_json::{json, Map, Value as JsonValue};
+
+/// Name of the single template of the environment
+const NAME: &str = "template";
+
+/// A parsed template
+#[derive(Clone, Debug)]
+pub struct Template {
+    env: Environment<'static>,
+}
+
+fn template_error(err: minijinja::Error) -> Error {
+    let message = match err.detail() {
+        Some(detail) => format!("{}: {}", err.kind(), detail),
+        None => err.kind().to_string(),
+    };
+    ErrorKind::TemplateError(message, err.range().map_or(0, |range| range.start)).into()
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<JsonValue> {
+    serde_json::to_value(value).map_err(|e| ErrorKind::ValueError(e.to_string()).into())
+}
+
+/// Build the rendering context of a record
+fn record_context(record: &FitDataRecord, index: usize) -> Result<JsonValue> {
+    let fields: Result<Map<String, JsonValue>> = record
+        .to_map()
+        .iter()
+        .map(|(name, value)| Ok((name.clone(), to_json(value)?)))
+        .collect();
+    let units: Map<String, JsonValue> = record
+        .fields()
+        .iter()
+        .map(|f| {
+            (
+                f.name().to_string(),
+                JsonValue::String(f.units().to_string()),
+            )
+        })
+        .collect();
+    Ok(json!({
+        "kind": record.kind().to_string(),
+        "global_message_number": record.global_message_number(),
+        "index": index,
+        "fields": fields?,
+        "units": units,
+    }))
+}
+
+impl Template {
+    /// Parse a template
+    pub fn parse(source: &str) -> Result<Self> {
+        let mut env = Environment::new();
+        // `null` values of the records and summaries insert nothing rather than `none`
+        env.set_formatter(|out, state, value| {
+            let value = if value.is_none() {
+                &TemplateValue::UNDEFINED
+            } else {
+                value
+            };
+            escape_formatter(out, state, value)
+        });
+        env.add_template_owned(NAME, source.to_string())
+            .map_err(template_error)?;
+        Ok(Template { env })
+    }
+
+    /// Render the template with any serializable value as the context
+    pub fn render<T: Serialize>(&self, context: &T) -> Result<String> {
+        self.env
+            .get_template(NAME)
+            .and_then(|template| template.render(context))
+            .map_err(template_error)
+    }
+
+    /// Render a single record, the index is exposed to the template as `index`
+    pub fn render_record(&self, record: &FitDataRecord, index: usize) -> Result<String> {
+        self.render(&record_context(record, index)?)
+    }
+
+    /// Render every record and concatenate the output
+    pub fn render_records(&self, records: &[FitDataRecord]) -> Result<String> {
+        let mut out = String::new();
+        for (index, record) in records.iter().enumerate() {
+            out.push_str(&self.render_record(record, index)?);
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+    use crate::library::SessionSummary;
+
+    #[test]
+    fn render_templates() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        let template = Template::parse(
+            "{% if fields.timer_trigger %}{{ index }} {{ kind }} {{ fields.event }}\n{% endif %}",
+        )
+        .unwrap();
+        let text = template.render_records(&records).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with(" event timer"));
+
+        let template = Template::parse(
+            "{% for name, value in fields|items %}{{ name }}={{ value }}{{ units.timestamp }};{% endfor %}",
+        )
+        .unwrap();
+        let text = template.render_record(&records[0], 0).unwrap();
+        assert!(text.starts_with("garmin_product="));
+        assert!(text.contains("type=activity;"));
+
+        let template = Template::parse(
+            r#"{"sport": {{ sport|tojson }}{% if total_distance %}, "km": {{ total_distance }}{% else %}, "km": null{% endif %}, "name": "{{ name }}"}"#,
+        )
+        .unwrap();
+        let summary = SessionSummary {
+            sport: Some("running".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            template.render(&summary).unwrap(),
+            r#"{"sport": "running", "km": null, "name": ""}"#
+        );
+
+        let err = Template::parse("{% for field in fields %}{{ field }}").unwrap_err();
+        assert!(matches!(*err, ErrorKind::TemplateError(..)));
+        let err = Template::parse("{{ a }}{% if a %}{% endfor %}").unwrap_err();
+        // the position is the byte offset of the unexpected `endfor`
+        assert!(matches!(*err, ErrorKind::TemplateError(_, 20)));
+    }
+}
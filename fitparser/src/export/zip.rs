@@ -0,0 +1,120 @@
+//! Write many activities into a single zip archive, e.g. for an "export all" feature.
+//!
+//! Every entry is compressed and written to the underlying writer as it's added, so only the
+//! entry being written is held in memory.
+use super::Format;
+use crate::error::{ErrorKind, Result};
+use crate::{ser, FitDataRecord};
+use std::collections::HashSet;
+use std::io::{Seek, Write};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Writer adding FIT files and converted activities to a zip archive
+pub struct ZipExport<W: Write + Seek> {
+    writer: ZipWriter<W>,
+    names: HashSet<String>,
+}
+
+fn zip_error(error: zip::result::ZipError) -> Box<ErrorKind> {
+    ErrorKind::Io(error.into()).into()
+}
+
+impl<W: Write + Seek> ZipExport<W> {
+    /// Start an archive written to the writer, e.g. a `File` or an in memory `Cursor`
+    pub fn new(writer: W) -> Self {
+        ZipExport {
+            writer: ZipWriter::new(writer),
+            names: HashSet::new(),
+        }
+    }
+
+    /// Start an entry named after the stem and extension, a number is appended to stems already
+    /// used so no entry is shadowed. Returns the name of the entry.
+    fn start_entry(&mut self, stem: &str, extension: &str) -> Result<String> {
+        let mut name = format!("{}.{}", stem, extension);
+        let mut copy = 1;
+        while self.names.contains(&name) {
+            copy += 1;
+            name = format!("{}-{}.{}", stem, copy, extension);
+        }
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        self.writer
+            .start_file(name.as_str(), options)
+            .map_err(zip_error)?;
+        self.names.insert(name.clone());
+        Ok(name)
+    }
+
+    /// Add an encoded FIT file, e.g. the original upload of an activity
+    pub fn add_bytes(&mut self, stem: &str, bytes: &[u8]) -> Result<String> {
+        let name = self.start_entry(stem, "fit")?;
+        self.writer.write_all(bytes)?;
+        Ok(name)
+    }
+
+    /// Encode the records with `ser::to_bytes` and add them as a FIT file
+    pub fn add_fit(&mut self, stem: &str, records: &[FitDataRecord]) -> Result<String> {
+        let bytes = ser::to_bytes(records)?;
+        self.add_bytes(stem, &bytes)
+    }
+
+    /// Convert the records into the format and add them, e.g. as a GPX track
+    pub fn add_converted(
+        &mut self,
+        stem: &str,
+        records: &[FitDataRecord],
+        format: Format,
+    ) -> Result<String> {
+        let name = self.start_entry(stem, format.extension())?;
+        format.write(records, &mut self.writer)?;
+        Ok(name)
+    }
+
+    /// Write the central directory of the archive and return the writer
+    pub fn finish(mut self) -> Result<W> {
+        self.writer.finish().map_err(zip_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+    use std::io::{Cursor, Read};
+    use zip::ZipArchive;
+
+    #[test]
+    fn export_zip() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let records = from_bytes(data).unwrap();
+        let mut export = ZipExport::new(Cursor::new(Vec::new()));
+        assert_eq!(export.add_bytes("ride", data).unwrap(), "ride.fit");
+        assert_eq!(export.add_fit("ride", &records).unwrap(), "ride-2.fit");
+        assert_eq!(
+            export.add_converted("ride", &records, Format::Gpx).unwrap(),
+            "ride.gpx"
+        );
+        let archive = export.finish().unwrap().into_inner();
+
+        let mut archive = ZipArchive::new(Cursor::new(archive)).unwrap();
+        assert_eq!(archive.len(), 3);
+        let mut read = |name: &str| {
+            let mut bytes = Vec::new();
+            archive
+                .by_name(name)
+                .unwrap()
+                .read_to_end(&mut bytes)
+                .unwrap();
+            bytes
+        };
+        assert_eq!(read("ride.fit"), data.to_vec());
+        assert_eq!(
+            from_bytes(&read("ride-2.fit")).unwrap().len(),
+            records.len()
+        );
+        assert!(String::from_utf8(read("ride.gpx"))
+            .unwrap()
+            .contains("<trkpt"));
+    }
+}
@@ -0,0 +1,563 @@
+//! A small expression language to select records, e.g. for export. Expressions compare the
+//! message name and field values with literals and combine the comparisons with boolean
+//! operators:
+//!
+//! ```text
+//! mesg == "record" && field("power") > 200
+//! mesg == "lap" || (mesg == "event" && !has("data"))
+//! ```
+//!
+//! * `mesg` is the message name, or the global message number for messages missing from the
+//!   profile. Compared with a number it's always the global message number. With a compact
+//!   profile messages have no names and it's always the number.
+//! * `field("name")` is the value of a field, comparisons against a missing field are false
+//! * `has("name")` checks if a field is present
+//! * literals are numbers, double quoted strings, `true` and `false`
+//! * operators are `==`, `!=`, `<`, `<=`, `>`, `>=`, `&&`, `||` and `!` along with parentheses
+//!
+//! Values are compared as numbers when both sides can be read as a number and as strings
+//! otherwise.
+use crate::error::{ErrorKind, Result};
+use crate::{FitDataRecord, Value};
+use std::cmp::Ordering;
+use std::convert::TryInto;
+use std::str::FromStr;
+
+/// A compiled filter expression
+#[derive(Clone, Debug, PartialEq)]
+pub struct Filter {
+    expr: Expr,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Expr {
+    Or(Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Compare(Operand, CompareOp, Operand),
+    Truthy(Operand),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Operand {
+    Mesg,
+    Field(String),
+    Has(String),
+    Number(f64),
+    Str(String),
+    Bool(bool),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(f64),
+    Str(String),
+    Op(&'static str),
+    LParen,
+    RParen,
+}
+
+fn filter_error<T>(message: &str, position: usize) -> Result<T> {
+    Err(ErrorKind::FilterError(message.to_string(), position).into())
+}
+
+/// Split the expression into tokens along with their character offsets
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>> {
+    const OPERATORS: [&str; 9] = ["==", "!=", "<=", ">=", "&&", "||", "<", ">", "!"];
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < chars.len() {
+        let c = chars[pos];
+        let start = pos;
+        if c.is_whitespace() {
+            pos += 1;
+        } else if c == '(' || c == ')' {
+            tokens.push((
+                if c == '(' {
+                    Token::LParen
+                } else {
+                    Token::RParen
+                },
+                start,
+            ));
+            pos += 1;
+        } else if c == '"' {
+            let mut value = String::new();
+            pos += 1;
+            loop {
+                match chars.get(pos) {
+                    Some('"') => break,
+                    Some('\\') if chars.get(pos + 1).is_some() => {
+                        value.push(chars[pos + 1]);
+                        pos += 2;
+                    }
+                    Some(c) => {
+                        value.push(*c);
+                        pos += 1;
+                    }
+                    None => return filter_error("unterminated string", start),
+                }
+            }
+            tokens.push((Token::Str(value), start));
+            pos += 1;
+        } else if c.is_ascii_digit() || c == '-' || c == '.' {
+            pos += 1;
+            while pos < chars.len() && (chars[pos].is_ascii_digit() || chars[pos] == '.') {
+                pos += 1;
+            }
+            let text: String = chars[start..pos].iter().collect();
+            match text.parse() {
+                Ok(value) => tokens.push((Token::Number(value), start)),
+                Err(_) => return filter_error("invalid number", start),
+            }
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            while pos < chars.len() && (chars[pos].is_ascii_alphanumeric() || chars[pos] == '_') {
+                pos += 1;
+            }
+            tokens.push((Token::Ident(chars[start..pos].iter().collect()), start));
+        } else {
+            let rest: String = chars[pos..chars.len().min(pos + 2)].iter().collect();
+            match OPERATORS.iter().find(|op| rest.starts_with(*op)) {
+                Some(op) => {
+                    tokens.push((Token::Op(op), start));
+                    pos += op.chars().count();
+                }
+                None => return filter_error(&format!("unexpected character '{}'", c), start),
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser over the token stream
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens.get(self.pos).map_or(self.end, |(_, p)| *p)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(t, _)| t.clone());
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: &Token, what: &str) -> Result<()> {
+        let position = self.position();
+        match self.next() {
+            Some(token) if token == *expected => Ok(()),
+            _ => filter_error(&format!("expected {}", what), position),
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr> {
+        let mut expr = self.and()?;
+        while self.peek() == Some(&Token::Op("||")) {
+            self.pos += 1;
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr> {
+        let mut expr = self.not()?;
+        while self.peek() == Some(&Token::Op("&&")) {
+            self.pos += 1;
+            expr = Expr::And(Box::new(expr), Box::new(self.not()?));
+        }
+        Ok(expr)
+    }
+
+    fn not(&mut self) -> Result<Expr> {
+        if self.peek() == Some(&Token::Op("!")) {
+            self.pos += 1;
+            return Ok(Expr::Not(Box::new(self.not()?)));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<Expr> {
+        if self.peek() == Some(&Token::LParen) {
+            self.pos += 1;
+            let expr = self.or()?;
+            self.expect(&Token::RParen, "')'")?;
+            return Ok(expr);
+        }
+        let lhs = self.operand()?;
+        let op = match self.peek() {
+            Some(Token::Op("==")) => CompareOp::Eq,
+            Some(Token::Op("!=")) => CompareOp::Ne,
+            Some(Token::Op("<")) => CompareOp::Lt,
+            Some(Token::Op("<=")) => CompareOp::Le,
+            Some(Token::Op(">")) => CompareOp::Gt,
+            Some(Token::Op(">=")) => CompareOp::Ge,
+            _ => return Ok(Expr::Truthy(lhs)),
+        };
+        self.pos += 1;
+        Ok(Expr::Compare(lhs, op, self.operand()?))
+    }
+
+    fn operand(&mut self) -> Result<Operand> {
+        let position = self.position();
+        match self.next() {
+            Some(Token::Number(value)) => Ok(Operand::Number(value)),
+            Some(Token::Str(value)) => Ok(Operand::Str(value)),
+            Some(Token::Ident(ident)) => match ident.as_str() {
+                "mesg" => Ok(Operand::Mesg),
+                "true" => Ok(Operand::Bool(true)),
+                "false" => Ok(Operand::Bool(false)),
+                "field" | "has" => {
+                    self.expect(&Token::LParen, "'('")?;
+                    let position = self.position();
+                    let name = match self.next() {
+                        Some(Token::Str(name)) => name,
+                        _ => return filter_error("expected a field name string", position),
+                    };
+                    self.expect(&Token::RParen, "')'")?;
+                    if ident == "field" {
+                        Ok(Operand::Field(name))
+                    } else {
+                        Ok(Operand::Has(name))
+                    }
+                }
+                _ => filter_error(&format!("unknown identifier '{}'", ident), position),
+            },
+            _ => filter_error("expected a value", position),
+        }
+    }
+}
+
+/// Value of an operand for a single record
+enum Resolved {
+    Number(f64),
+    Str(String),
+    Bool(bool),
+    Missing,
+}
+
+impl Resolved {
+    fn from_value(value: &Value) -> Self {
+        match value {
+            Value::String(value) => Resolved::Str(value.clone()),
+            Value::Array(_) | Value::Timestamp(_) => Resolved::Str(value.to_string()),
+            _ => match value.clone().try_into() {
+                Ok(value) => Resolved::Number(value),
+                Err(_) => Resolved::Str(value.to_string()),
+            },
+        }
+    }
+
+    fn as_number(&self) -> Option<f64> {
+        match self {
+            Resolved::Number(value) => Some(*value),
+            Resolved::Str(value) => value.parse().ok(),
+            _ => None,
+        }
+    }
+
+    fn is_truthy(&self) -> bool {
+        match self {
+            Resolved::Number(value) => *value != 0.0,
+            Resolved::Str(value) => !value.is_empty(),
+            Resolved::Bool(value) => *value,
+            Resolved::Missing => false,
+        }
+    }
+
+    fn compare(&self, other: &Resolved) -> Option<Ordering> {
+        match (self, other) {
+            (Resolved::Missing, _) | (_, Resolved::Missing) => None,
+            (Resolved::Bool(a), Resolved::Bool(b)) => Some(a.cmp(b)),
+            (Resolved::Str(a), Resolved::Str(b)) => Some(a.cmp(b)),
+            _ => self.as_number()?.partial_cmp(&other.as_number()?),
+        }
+    }
+}
+
+impl Operand {
+    fn resolve(&self, record: &FitDataRecord) -> Resolved {
+        match self {
+            Operand::Mesg => Resolved::Str(if record.is_unknown() {
+                record.global_message_number().to_string()
+            } else {
+                record.kind().to_string()
+            }),
+            Operand::Field(name) => record
+                .field(name)
+                .map_or(Resolved::Missing, |f| Resolved::from_value(f.value())),
+            Operand::Has(name) => Resolved::Bool(record.field(name).is_some()),
+            Operand::Number(value) => Resolved::Number(*value),
+            Operand::Str(value) => Resolved::Str(value.clone()),
+            Operand::Bool(value) => Resolved::Bool(*value),
+        }
+    }
+}
+
+impl Expr {
+    fn eval(&self, record: &FitDataRecord) -> bool {
+        match self {
+            Expr::Or(a, b) => a.eval(record) || b.eval(record),
+            Expr::And(a, b) => a.eval(record) && b.eval(record),
+            Expr::Not(expr) => !expr.eval(record),
+            Expr::Truthy(operand) => operand.resolve(record).is_truthy(),
+            Expr::Compare(lhs, op, rhs) => {
+                // compare the message against numbers using the global message number
+                let resolve = |operand: &Operand, other: &Operand| match (operand, other) {
+                    (Operand::Mesg, Operand::Number(_)) => {
+                        Resolved::Number(f64::from(record.global_message_number()))
+                    }
+                    _ => operand.resolve(record),
+                };
+                let ordering = match resolve(lhs, rhs).compare(&resolve(rhs, lhs)) {
+                    Some(ordering) => ordering,
+                    None => return false,
+                };
+                match op {
+                    CompareOp::Eq => ordering == Ordering::Equal,
+                    CompareOp::Ne => ordering != Ordering::Equal,
+                    CompareOp::Lt => ordering == Ordering::Less,
+                    CompareOp::Le => ordering != Ordering::Greater,
+                    CompareOp::Gt => ordering == Ordering::Greater,
+                    CompareOp::Ge => ordering != Ordering::Less,
+                }
+            }
+        }
+    }
+}
+
+impl Filter {
+    /// Compile a filter expression
+    pub fn parse(expression: &str) -> Result<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(expression)?,
+            pos: 0,
+            end: expression.chars().count(),
+        };
+        let expr = parser.or()?;
+        if parser.pos < parser.tokens.len() {
+            return filter_error("unexpected trailing input", parser.position());
+        }
+        Ok(Filter { expr })
+    }
+
+    /// Check if the record is selected by the filter
+    pub fn matches(&self, record: &FitDataRecord) -> bool {
+        self.expr.eval(record)
+    }
+
+    /// Keep the records selected by the filter
+    pub fn apply(&self, records: Vec<FitDataRecord>) -> Vec<FitDataRecord> {
+        records.into_iter().filter(|r| self.matches(r)).collect()
+    }
+}
+
+impl FromStr for Filter {
+    type Err = crate::Error;
+
+    fn from_str(expression: &str) -> Result<Self> {
+        Filter::parse(expression)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{record, RecordBuilder};
+    use crate::from_bytes;
+    use crate::profile::MesgNum;
+
+    fn error_position(expression: &str) -> (String, usize) {
+        match *Filter::parse(expression).unwrap_err() {
+            ErrorKind::FilterError(message, position) => (message, position),
+            error => panic!("unexpected error {}", error),
+        }
+    }
+
+    #[test]
+    fn select_records() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        let count = |expression: &str| {
+            let filter: Filter = expression.parse().unwrap();
+            records.iter().filter(|r| filter.matches(r)).count()
+        };
+        let total_records = count(r#"mesg == "record""#);
+        assert!(total_records > 0);
+        assert_eq!(count(r#"mesg == "event" || mesg == "session""#), 4);
+        assert_eq!(count("mesg == 20"), total_records);
+        assert_eq!(
+            count(r#"mesg == "record" && has("distance") && field("distance") >= 0"#),
+            count(r#"mesg == "record" && has("distance")"#)
+        );
+        assert_eq!(count(r#"field("no_such_field") != 1"#), 0);
+        assert_eq!(
+            count(r#"!(mesg == "record")"#),
+            records.len() - total_records
+        );
+        assert_eq!(count(r#"field("event_type") == "stop_all""#), 1);
+
+        let err = Filter::parse(r#"mesg == "record" &&"#).unwrap_err();
+        assert!(matches!(*err, ErrorKind::FilterError(_, 19)));
+        assert!(Filter::parse(r#"power > 200"#).is_err());
+    }
+
+    #[test]
+    fn negative_numbers() {
+        let expr = Filter::parse(r#"field("a")>-1"#).unwrap().expr;
+        assert_eq!(
+            expr,
+            Expr::Compare(
+                Operand::Field("a".to_string()),
+                CompareOp::Gt,
+                Operand::Number(-1.0)
+            )
+        );
+        let power = record(MesgNum::Record, &[("power", Value::UInt16(0))]);
+        assert!(Filter::parse(r#"field("power") > -1.5"#)
+            .unwrap()
+            .matches(&power));
+        // the minus sign is part of the number literal, there's no unary minus
+        assert_eq!(
+            error_position(r#"field("a") > - 1"#),
+            ("invalid number".to_string(), 13)
+        );
+        assert_eq!(error_position("1 > -").1, 4);
+    }
+
+    #[test]
+    fn string_escapes() {
+        let parse = |expression: &str| match Filter::parse(expression).unwrap().expr {
+            Expr::Truthy(Operand::Str(value)) => value,
+            expr => panic!("unexpected expression {:?}", expr),
+        };
+        assert_eq!(parse(r#""say \"hi\"""#), r#"say "hi""#);
+        assert_eq!(parse(r#""back\\slash""#), r"back\slash");
+        assert_eq!(parse(r#""\n""#), "n");
+        assert_eq!(parse(r#""field(\"x\") && mesg""#), r#"field("x") && mesg"#);
+
+        let event = record(
+            MesgNum::Event,
+            &[("event", Value::String(r#"a "quoted" event"#.to_string()))],
+        );
+        let filter = Filter::parse(r#"field("event") == "a \"quoted\" event""#).unwrap();
+        assert!(filter.matches(&event));
+    }
+
+    #[test]
+    fn not_precedence() {
+        // `!` applies to the whole comparison, `!=` is a single operator
+        let has_power = Operand::Has("power".to_string());
+        assert_eq!(
+            Filter::parse(r#"!has("power") == true"#).unwrap().expr,
+            Expr::Not(Box::new(Expr::Compare(
+                has_power.clone(),
+                CompareOp::Eq,
+                Operand::Bool(true)
+            )))
+        );
+        assert_eq!(
+            Filter::parse(r#"has("power") != true"#).unwrap().expr,
+            Expr::Compare(has_power.clone(), CompareOp::Ne, Operand::Bool(true))
+        );
+        assert_eq!(
+            Filter::parse(r#"!!has("power")"#).unwrap().expr,
+            Expr::Not(Box::new(Expr::Not(Box::new(Expr::Truthy(has_power)))))
+        );
+        // `!` binds tighter than `&&` and `||`
+        let expr = Filter::parse(r#"!has("a") && has("b")"#).unwrap().expr;
+        assert!(matches!(expr, Expr::And(a, _) if matches!(*a, Expr::Not(_))));
+
+        let power = record(MesgNum::Record, &[("power", Value::UInt16(200))]);
+        let matches = |expression: &str| Filter::parse(expression).unwrap().matches(&power);
+        assert!(!matches(r#"!has("power") == true"#));
+        assert!(matches(r#"!has("cadence") == true"#));
+        assert!(matches(r#"!field("power") != 200"#));
+        assert!(matches(r#"!(field("power") != 200) && has("power")"#));
+    }
+
+    #[test]
+    fn truthy_operands() {
+        let lap = RecordBuilder::new(MesgNum::Lap)
+            .field("total_distance", Value::Float64(0.0))
+            .field("avg_power", Value::UInt16(180))
+            .field("event", Value::String(String::new()))
+            .field("sport", Value::String("running".to_string()))
+            .build();
+        let matches = |expression: &str| Filter::parse(expression).unwrap().matches(&lap);
+        assert!(matches(r#"field("avg_power")"#));
+        assert!(!matches(r#"field("total_distance")"#));
+        assert!(!matches(r#"field("event")"#));
+        assert!(matches(r#"field("sport")"#));
+        assert!(!matches(r#"field("no_such_field")"#));
+        assert!(matches(r#"!field("no_such_field")"#));
+        assert!(matches(r#"has("sport")"#));
+        assert!(matches("mesg"));
+        assert!(matches("true") && !matches("false"));
+        assert!(matches("1") && !matches("0"));
+        assert!(matches(r#""x""#) && !matches(r#""""#));
+    }
+
+    #[test]
+    fn compare_unknown_message_numbers() {
+        let unknown = record(MesgNum::from(0xFF10_u16), &[]);
+        let known = record(MesgNum::Record, &[]);
+        let matches = |expression: &str, record: &FitDataRecord| {
+            Filter::parse(expression).unwrap().matches(record)
+        };
+        assert!(matches("mesg == 65296", &unknown));
+        assert!(matches("65296 == mesg", &unknown));
+        assert!(matches(r#"mesg == "65296""#, &unknown));
+        assert!(matches("mesg >= 65280", &unknown));
+        assert!(!matches("mesg >= 65280", &known));
+        // compared with a string a known message is its name, with a number its global number
+        assert!(matches("mesg == 20", &known));
+        assert!(!matches(r#"mesg == "20""#, &known));
+        assert!(matches(r#"mesg == "record""#, &known));
+    }
+
+    #[test]
+    fn error_positions() {
+        assert_eq!(
+            error_position(r#"mesg == "record"#),
+            ("unterminated string".to_string(), 8)
+        );
+        assert_eq!(
+            error_position(r#"field("ab\") > 1"#),
+            ("unterminated string".to_string(), 6)
+        );
+        assert_eq!(
+            error_position(r#"mesg == "lap" || powr > 200"#),
+            ("unknown identifier 'powr'".to_string(), 17)
+        );
+        assert_eq!(
+            error_position(r#"fields("x")"#),
+            ("unknown identifier 'fields'".to_string(), 0)
+        );
+        assert_eq!(error_position("(mesg == 20").1, 11);
+        assert_eq!(error_position("mesg # 20").1, 5);
+        assert_eq!(error_position("has(power)").1, 4);
+        // positions count characters, not bytes
+        assert_eq!(error_position(r#"field("höhe") > x"#).1, 16);
+    }
+}
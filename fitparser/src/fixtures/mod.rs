@@ -0,0 +1,229 @@
+//! Build small, valid FIT byte streams for tests.
+//!
+//! `FitFileBuilder` writes the definition messages, header and CRC values so tests can focus on
+//! the messages they care about, including the less common parts of the protocol such as
+//! compressed timestamp headers, developer fields and big endian definitions. Files can be
+//! concatenated with `chain` to produce a chained FIT file, and `synthetic_activity` generates a
+//! complete activity from a seed. `RecordBuilder` and `record` build decoded records directly
+//! for code working on records rather than bytes. The module is only compiled with the
+//! `fixtures` feature, enable it in `[dev-dependencies]` to use it from another crate.
+use crate::profile::MesgNum;
+use crate::ser::{base_type, encode_value, encoded_size, file_bytes, DefinitionState, Layout};
+use crate::Value;
+
+mod record;
+mod synthetic;
+pub use record::{record, RecordBuilder};
+pub use synthetic::synthetic_activity;
+
+/// A developer field value attached to a data message
+#[derive(Clone, Debug)]
+pub struct DeveloperValue {
+    /// Index of the `developer_data_id` message defining the field
+    pub developer_data_index: u8,
+    /// Field number from the `field_description` message
+    pub field_number: u8,
+    /// Value to encode, it must match the base type of the field description
+    pub value: Value,
+}
+
+/// Builds the data section of a FIT file one message at a time
+#[derive(Clone, Debug, Default)]
+pub struct FitFileBuilder {
+    definitions: DefinitionState,
+    data: Vec<u8>,
+}
+
+impl FitFileBuilder {
+    /// Create a builder for an empty file using little endian definitions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write definition messages and the values of following data messages in big endian order
+    pub fn big_endian(mut self, big_endian: bool) -> Self {
+        self.definitions = self.definitions.big_endian(big_endian);
+        self
+    }
+
+    /// Append a data message with a normal header
+    pub fn message(self, kind: MesgNum, fields: &[(u8, Value)]) -> Self {
+        self.message_with_developer_fields(kind, fields, &[])
+    }
+
+    /// Append a data message with a normal header that also carries developer fields, the
+    /// developer fields should be described by `developer_data_id` and `field_description`
+    /// messages first, see `developer_data_id` and `field_description`.
+    pub fn message_with_developer_fields(
+        mut self,
+        kind: MesgNum,
+        fields: &[(u8, Value)],
+        developer_fields: &[DeveloperValue],
+    ) -> Self {
+        let layout = self.layout(kind, fields, developer_fields);
+        let local = self.local_message_number(layout, 16);
+        self.data.push(local);
+        self.write_values(fields, developer_fields);
+        self
+    }
+
+    /// Append a data message using a compressed timestamp header, the time offset holds the
+    /// 5 least significant bits of the timestamp. The message must not contain a timestamp field.
+    pub fn compressed_timestamp_message(
+        mut self,
+        kind: MesgNum,
+        time_offset: u8,
+        fields: &[(u8, Value)],
+    ) -> Self {
+        let layout = self.layout(kind, fields, &[]);
+        // compressed headers only have room for local message numbers 0 to 3
+        let local = self.local_message_number(layout, 4);
+        self.data.push(0x80 | (local << 5) | (time_offset & 0x1F));
+        self.write_values(fields, &[]);
+        self
+    }
+
+    /// Append a `developer_data_id` message, the application id is written as the 16 byte UUID
+    pub fn developer_data_id(self, developer_data_index: u8, application_id: [u8; 16]) -> Self {
+        let application_id = application_id.iter().map(|b| Value::Byte(*b)).collect();
+        self.message(
+            MesgNum::DeveloperDataId,
+            &[
+                (1, Value::Array(application_id)),
+                (3, Value::UInt8(developer_data_index)),
+            ],
+        )
+    }
+
+    /// Append a `field_description` message for a developer field. The base type uses the FIT
+    /// base type id, e.g. `0x84` for a uint16, and the native field links the developer field
+    /// to the field of the given message it can stand in for.
+    pub fn field_description(
+        self,
+        developer_data_index: u8,
+        field_number: u8,
+        base_type: u8,
+        name: &str,
+        units: &str,
+        native_field: Option<(MesgNum, u8)>,
+    ) -> Self {
+        let mut fields = vec![
+            (0, Value::UInt8(developer_data_index)),
+            (1, Value::UInt8(field_number)),
+            (2, Value::UInt8(base_type)),
+            (3, Value::String(name.to_string())),
+            (8, Value::String(units.to_string())),
+        ];
+        if let Some((kind, number)) = native_field {
+            fields.push((14, Value::UInt16(kind.as_u16())));
+            fields.push((15, Value::UInt8(number)));
+        }
+        self.message(MesgNum::FieldDescription, &fields)
+    }
+
+    /// Return the data section written so far, without header or CRC
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Build the complete file using a 14 byte header with a CRC and the trailing file CRC
+    pub fn build(&self) -> Vec<u8> {
+        file_bytes(&self.data)
+    }
+
+    fn layout(
+        &self,
+        kind: MesgNum,
+        fields: &[(u8, Value)],
+        developer_fields: &[DeveloperValue],
+    ) -> Layout {
+        Layout {
+            global: kind.as_u16(),
+            fields: fields
+                .iter()
+                .map(|(number, value)| (*number, encoded_size(value) as u8, base_type(value)))
+                .collect(),
+            developer_fields: developer_fields
+                .iter()
+                .map(|f| {
+                    (
+                        f.field_number,
+                        encoded_size(&f.value) as u8,
+                        f.developer_data_index,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn local_message_number(&mut self, layout: Layout, limit: usize) -> u8 {
+        self.definitions
+            .local_message_number(layout, limit, &mut self.data)
+    }
+
+    fn write_values(&mut self, fields: &[(u8, Value)], developer_fields: &[DeveloperValue]) {
+        let values = fields
+            .iter()
+            .map(|(_, v)| v)
+            .chain(developer_fields.iter().map(|f| &f.value));
+        for value in values {
+            encode_value(&mut self.data, value, self.definitions.is_big_endian())
+                .expect("timestamps of fixtures are within the range of FIT timestamps");
+        }
+    }
+}
+
+/// Concatenate several FIT files into a single chained FIT file
+pub fn chain(files: &[Vec<u8>]) -> Vec<u8> {
+    files.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+    use crate::timestamp::from_fit_seconds;
+
+    #[test]
+    fn build_quirky_files() {
+        let start = from_fit_seconds(1_000_000_000);
+        let file = FitFileBuilder::new()
+            .big_endian(true)
+            .message(
+                MesgNum::FileId,
+                &[(0, Value::Enum(4)), (1, Value::UInt16(1))],
+            )
+            .developer_data_id(0, [7; 16])
+            .field_description(0, 0, 0x84, "Power", "Watts", Some((MesgNum::Record, 7)))
+            .message_with_developer_fields(
+                MesgNum::Record,
+                &[(253, Value::Timestamp(start)), (3, Value::UInt8(120))],
+                &[DeveloperValue {
+                    developer_data_index: 0,
+                    field_number: 0,
+                    value: Value::UInt16(300),
+                }],
+            )
+            .compressed_timestamp_message(MesgNum::Record, 5, &[(3, Value::UInt8(125))])
+            .build();
+
+        let records = from_bytes(&chain(&[file.clone(), file])).unwrap();
+        assert_eq!(records.len(), 10);
+        assert_eq!(records[0].kind(), MesgNum::FileId);
+        let power = records[3].field("Power").unwrap();
+        assert_eq!(power.value(), &Value::UInt16(300));
+        assert_eq!(
+            records[3].field("heart_rate").unwrap().value(),
+            &Value::UInt8(120)
+        );
+        let compressed = &records[4];
+        assert_eq!(
+            compressed.field("heart_rate").unwrap().value(),
+            &Value::UInt8(125)
+        );
+        assert_eq!(
+            compressed.field("timestamp").unwrap().value(),
+            &Value::Timestamp(from_fit_seconds(1_000_000_005))
+        );
+    }
+}
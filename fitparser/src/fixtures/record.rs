@@ -0,0 +1,117 @@
+//! Build decoded records directly, for tests of code working on records rather than bytes.
+use crate::profile::{encode::field_info, MesgNum};
+use crate::{DeveloperFieldInfo, FitDataField, FitDataRecord, Value};
+
+/// Builds a record as `from_bytes` returns it, one field at a time
+#[derive(Clone, Debug)]
+pub struct RecordBuilder {
+    record: FitDataRecord,
+    developer_fields: u8,
+}
+
+impl RecordBuilder {
+    /// Create a builder for a record of the given kind without any fields
+    pub fn new(kind: MesgNum) -> Self {
+        RecordBuilder {
+            record: FitDataRecord::new(kind),
+            developer_fields: 0,
+        }
+    }
+
+    /// Add a profile field by the name it's decoded with, holding the decoded value, e.g. the
+    /// scaled altitude in meters. The definition number and units come from the profile.
+    ///
+    /// Panics if the message has no field with that name.
+    pub fn field(mut self, name: &str, value: Value) -> Self {
+        let info = field_info(self.record.kind(), name).unwrap_or_else(|| {
+            panic!(
+                "{} messages have no field named {}",
+                self.record.kind(),
+                name
+            )
+        });
+        self.record.push(FitDataField::new(
+            name.to_string(),
+            info.number,
+            value,
+            info.units.to_string(),
+        ));
+        self
+    }
+
+    /// Add a developer field, numbered in the order the developer fields are added. The native
+    /// field links it to the field of the given message it can stand in for.
+    pub fn developer_field(
+        mut self,
+        name: &str,
+        units: &str,
+        value: Value,
+        native_field: Option<(MesgNum, u8)>,
+    ) -> Self {
+        let mut field = FitDataField::new(
+            name.to_string(),
+            self.developer_fields,
+            value,
+            units.to_string(),
+        );
+        field.set_developer_info(Some(DeveloperFieldInfo {
+            field_name: name.to_string(),
+            units: units.to_string(),
+            native_mesg_num: native_field.map(|(kind, _)| kind),
+            native_field_num: native_field.map(|(_, number)| number),
+            ..Default::default()
+        }));
+        self.record.push(field);
+        self.developer_fields += 1;
+        self
+    }
+
+    /// Return the record built so far
+    pub fn build(self) -> FitDataRecord {
+        self.record
+    }
+}
+
+/// Build a record holding the given profile fields, see `RecordBuilder::field`
+pub fn record(kind: MesgNum, fields: &[(&str, Value)]) -> FitDataRecord {
+    fields
+        .iter()
+        .fold(RecordBuilder::new(kind), |builder, (name, value)| {
+            builder.field(name, value.clone())
+        })
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+    use crate::fixtures::FitFileBuilder;
+
+    #[test]
+    fn match_decoded_records() {
+        let data = FitFileBuilder::new()
+            .message(
+                MesgNum::Record,
+                &[(3, Value::UInt8(120)), (78, Value::UInt32(3000))],
+            )
+            .build();
+        let decoded = from_bytes(&data).unwrap();
+        let built = record(
+            MesgNum::Record,
+            &[
+                ("heart_rate", Value::UInt8(120)),
+                ("enhanced_altitude", Value::Float64(100.0)),
+            ],
+        );
+        assert_eq!(
+            serde_json::to_value(&decoded[0]).unwrap(),
+            serde_json::to_value(&built).unwrap()
+        );
+
+        let power = RecordBuilder::new(MesgNum::Record)
+            .developer_field("Power", "Watts", Value::UInt16(300), None)
+            .build();
+        assert!(power.field("Power").unwrap().is_developer_field());
+    }
+}
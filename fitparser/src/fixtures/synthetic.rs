@@ -0,0 +1,132 @@
+//! Generate realistic looking activities from a seed, so tests and demos have sample files that
+//! don't come from anyone's real training.
+use crate::profile::field_types::Sport;
+use crate::recorder::{Recorder, Sample};
+use chrono::{Duration, Local, TimeZone};
+use std::f64::consts::PI;
+
+/// Start of every synthetic activity, 2021-06-01T06:00:00Z
+const START: i64 = 1_622_527_200;
+
+/// Meters per degree of latitude
+const METERS_PER_DEGREE: f64 = 111_195.0;
+
+/// Small deterministic random number generator (SplitMix64), the output must never change
+/// between releases so it doesn't depend on an external crate
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `[-1, 1)`
+    fn noise(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+    }
+}
+
+/// Typical speed in m/s, heart rate and cadence of a sport
+fn sport_profile(sport: Sport) -> (f64, f64, f64) {
+    match sport {
+        Sport::Cycling => (8.0, 145.0, 88.0),
+        Sport::Running => (3.2, 155.0, 84.0),
+        _ => (1.4, 105.0, 55.0),
+    }
+}
+
+/// Encode a synthetic activity of the sport lasting the given duration with one sample per
+/// second. The route meanders through rolling hills, heart rate follows the effort with some
+/// lag and cycling activities carry power, a lap is recorded every kilometer. The same seed
+/// produces the same file, although the trigonometry comes from the platform's math library so
+/// a value may differ in its last digit between platforms.
+pub fn synthetic_activity(duration: std::time::Duration, sport: Sport, seed: u64) -> Vec<u8> {
+    let mut rng = SplitMix64(seed);
+    let (base_speed, base_heart_rate, base_cadence) = sport_profile(sport);
+    let start = Local.timestamp_opt(START, 0).unwrap();
+    let mut recorder = Recorder::new(start, sport).expect("recorder start is valid");
+
+    let mut position = (46.5 + rng.noise() * 0.5, 7.5 + rng.noise() * 0.5);
+    let mut heading = rng.noise() * PI;
+    let hill_phase = rng.noise() * PI;
+    let mut heart_rate = base_heart_rate - 40.0;
+    let mut distance = 0.0;
+    let mut next_lap = 1000.0;
+    let seconds = duration.as_secs();
+    for second in 0..seconds {
+        let timestamp = start + Duration::seconds(second as i64);
+        // rolling hills with a wavelength of 3 km and an amplitude of 40 m
+        let altitude = 500.0 + 40.0 * (distance * 2.0 * PI / 3000.0 + hill_phase).sin();
+        let grade = 40.0 * 2.0 * PI / 3000.0 * (distance * 2.0 * PI / 3000.0 + hill_phase).cos();
+        let speed = (base_speed * (1.0 - 4.0 * grade) * (1.0 + 0.05 * rng.noise())).max(0.5);
+        let effort = 1.0 + 6.0 * grade;
+        heart_rate += (base_heart_rate * effort - heart_rate) / 30.0 + rng.noise();
+
+        let mut sample = Sample::new(timestamp);
+        sample.position = Some(position);
+        sample.altitude = Some(altitude);
+        sample.speed = Some(speed);
+        sample.heart_rate = Some(heart_rate.clamp(60.0, 200.0).round() as u8);
+        sample.cadence = Some((base_cadence + 3.0 * rng.noise()).round() as u8);
+        if sport == Sport::Cycling {
+            // rolling resistance, climbing and air drag of a 80 kg rider and bike
+            let watts = 80.0 * 9.81 * speed * (0.005 + grade) + 0.2 * speed.powi(3);
+            sample.power = Some(watts.clamp(0.0, 1500.0).round() as u16);
+        }
+        recorder.sample(sample).expect("samples are chronological");
+
+        heading += 0.05 * rng.noise();
+        let north = speed * heading.cos();
+        let east = speed * heading.sin();
+        position.0 += north / METERS_PER_DEGREE;
+        position.1 += east / (METERS_PER_DEGREE * position.0.to_radians().cos());
+        distance += speed;
+        if distance >= next_lap && second + 1 < seconds {
+            recorder
+                .lap(timestamp + Duration::seconds(1))
+                .expect("laps are chronological");
+            next_lap += 1000.0;
+        }
+    }
+    recorder
+        .finish(start + Duration::seconds(seconds as i64))
+        .expect("the activity ends after the last sample")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+    use crate::profile::MesgNum;
+    use crate::Value;
+
+    #[test]
+    fn deterministic_activity() {
+        let duration = std::time::Duration::from_secs(600);
+        let file = synthetic_activity(duration, Sport::Cycling, 42);
+        assert_eq!(file, synthetic_activity(duration, Sport::Cycling, 42));
+        assert_ne!(file, synthetic_activity(duration, Sport::Cycling, 43));
+
+        let records = from_bytes(&file).unwrap();
+        let samples: Vec<_> = records
+            .iter()
+            .filter(|r| r.is_kind(MesgNum::Record))
+            .collect();
+        assert_eq!(samples.len(), 600);
+        assert!(samples.iter().all(|r| r.field("power").is_some()));
+        assert!(samples.iter().all(|r| r.field("position_lat").is_some()));
+        let session = records
+            .iter()
+            .find(|r| r.is_kind(MesgNum::Session))
+            .unwrap();
+        let laps = match session.field("num_laps").unwrap().value() {
+            Value::UInt16(laps) => *laps,
+            value => panic!("unexpected number of laps {:?}", value),
+        };
+        assert!((3..=7).contains(&laps));
+    }
+}
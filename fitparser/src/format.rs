@@ -0,0 +1,160 @@
+//! Format field values as human readable text, e.g. durations as `1:00:00` rather than `3600 s`.
+//!
+//! A `FormatRegistry` maps units to a function formatting the numeric values of that unit,
+//! values of other units and values that aren't numbers are written with their units appended,
+//! except for timestamps.
+//! The `Display` implementations of `FitDataField` and `ValueWithUnits` and the text reports use
+//! the built-in formats of `FormatRegistry::default`.
+use crate::geo::semicircles_to_degrees;
+use crate::{FitDataField, Value};
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::fmt;
+use std::sync::OnceLock;
+
+type Formatter = Box<dyn Fn(f64) -> String + Send + Sync>;
+
+/// Formats of the values of each unit
+pub struct FormatRegistry {
+    formatters: HashMap<String, Formatter>,
+}
+
+impl FormatRegistry {
+    /// Create a registry without any formats, every value is written with its units appended
+    pub fn new() -> Self {
+        FormatRegistry {
+            formatters: HashMap::new(),
+        }
+    }
+
+    /// Format the numeric values of the units with a function, replacing any previous format
+    pub fn register<F>(&mut self, units: &str, formatter: F)
+    where
+        F: Fn(f64) -> String + Send + Sync + 'static,
+    {
+        self.formatters
+            .insert(units.to_string(), Box::new(formatter));
+    }
+
+    /// Remove the format of the units, returns `false` if there wasn't one
+    pub fn unregister(&mut self, units: &str) -> bool {
+        self.formatters.remove(units).is_some()
+    }
+
+    /// Format a value of the units, the elements of arrays are formatted individually
+    pub fn format(&self, value: &Value, units: &str) -> String {
+        if let Some(formatter) = self.formatters.get(units) {
+            if let Some(text) = self.apply(formatter, value) {
+                return text;
+            }
+        }
+        // the units of a timestamp describe the value stored in the file
+        if units.is_empty()
+            || matches!(
+                value,
+                Value::Timestamp(_) | Value::UtcTimestamp(_) | Value::LocalTimestamp(_)
+            )
+        {
+            value.to_string()
+        } else {
+            format!("{} {}", value, units)
+        }
+    }
+
+    /// Format the value of a field using its units
+    pub fn format_field(&self, field: &FitDataField) -> String {
+        self.format(field.value(), field.units())
+    }
+
+    fn apply(&self, formatter: &Formatter, value: &Value) -> Option<String> {
+        match value {
+            Value::Timestamp(_)
+            | Value::UtcTimestamp(_)
+            | Value::LocalTimestamp(_)
+            | Value::String(_) => None,
+            Value::Array(values) => {
+                let values = values
+                    .iter()
+                    .map(|v| self.apply(formatter, v))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!("[{}]", values.join(", ")))
+            }
+            _ => value.clone().try_into().ok().map(formatter),
+        }
+    }
+}
+
+impl Default for FormatRegistry {
+    /// Registry formatting durations in seconds as `h:mm:ss` and semicircles as decimal degrees
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry.register("s", format_duration);
+        registry.register("semicircles", |value| {
+            format!("{:.6}°", semicircles_to_degrees(value))
+        });
+        registry
+    }
+}
+
+impl fmt::Debug for FormatRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut units: Vec<_> = self.formatters.keys().collect();
+        units.sort();
+        f.debug_struct("FormatRegistry")
+            .field("units", &units)
+            .finish()
+    }
+}
+
+/// The built-in formats used by the `Display` implementations
+pub fn default_registry() -> &'static FormatRegistry {
+    static REGISTRY: OnceLock<FormatRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(FormatRegistry::default)
+}
+
+/// Format a number of seconds as `h:mm:ss`, fractions are kept to the millisecond
+pub fn format_duration(seconds: f64) -> String {
+    let sign = if seconds < 0.0 { "-" } else { "" };
+    let millis = (seconds.abs() * 1000.0).round() as u64;
+    let (hours, minutes) = (millis / 3_600_000, millis / 60_000 % 60);
+    let (secs, millis) = (millis / 1000 % 60, millis % 1000);
+    if millis == 0 {
+        format!("{sign}{hours}:{minutes:02}:{secs:02}")
+    } else {
+        format!("{sign}{hours}:{minutes:02}:{secs:02}.{millis:03}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_values() {
+        let registry = FormatRegistry::default();
+        assert_eq!(registry.format(&Value::Float64(3600.0), "s"), "1:00:00");
+        assert_eq!(registry.format(&Value::Float64(75.25), "s"), "0:01:15.250");
+        assert_eq!(
+            registry.format(&Value::SInt32(621_857_052), "semicircles"),
+            "52.123456°"
+        );
+        assert_eq!(registry.format(&Value::UInt8(140), "bpm"), "140 bpm");
+        assert_eq!(registry.format(&Value::UInt8(3), ""), "3");
+        assert_eq!(
+            registry.format(
+                &Value::Array(vec![Value::UInt32(60), Value::UInt32(7200)]),
+                "s"
+            ),
+            "[0:01:00, 2:00:00]"
+        );
+        let name = Value::String("manual".to_string());
+        assert_eq!(registry.format(&name, "s"), "manual s");
+
+        let mut registry = FormatRegistry::new();
+        registry.register("m", |meters| format!("{:.2} km", meters / 1000.0));
+        assert_eq!(registry.format(&Value::Float64(12345.0), "m"), "12.35 km");
+        assert_eq!(registry.format(&Value::Float64(3600.0), "s"), "3600 s");
+        assert!(registry.unregister("m"));
+        assert_eq!(registry.format(&Value::Float64(12.0), "m"), "12 m");
+    }
+}
@@ -0,0 +1,52 @@
+//! Geometry helpers shared by the transforms and analyses that work with positions.
+use crate::FitDataRecord;
+
+/// Mean radius of the earth in meters
+pub(crate) const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// A latitude, longitude pair in decimal degrees
+pub(crate) type Point = (f64, f64);
+
+/// Convert a FIT semicircle value into decimal degrees
+pub(crate) fn semicircles_to_degrees(value: f64) -> f64 {
+    value * (180.0 / 2_147_483_648.0)
+}
+
+/// Convert decimal degrees into a FIT semicircle value
+pub(crate) fn degrees_to_semicircles(value: f64) -> i32 {
+    (value * (2_147_483_648.0 / 180.0)).round() as i32
+}
+
+/// Extract the position of a record in decimal degrees if both coordinates are present
+pub(crate) fn record_position(record: &FitDataRecord) -> Option<Point> {
+    let lat = record.field("position_lat")?.degrees()?;
+    let long = record.field("position_long")?.degrees()?;
+    Some((lat, long))
+}
+
+/// Great circle distance between two points in meters
+pub(crate) fn haversine(a: Point, b: Point) -> f64 {
+    let (lat1, lat2) = (a.0.to_radians(), b.0.to_radians());
+    let dlat = lat2 - lat1;
+    let dlong = (b.1 - a.1).to_radians();
+    let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlong / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS * h.sqrt().asin()
+}
+
+/// Initial bearing from `a` towards `b` in degrees clockwise from north, in the range [0, 360)
+#[cfg_attr(compact_profile, allow(dead_code))]
+pub(crate) fn bearing(a: Point, b: Point) -> f64 {
+    let (lat1, lat2) = (a.0.to_radians(), b.0.to_radians());
+    let dlong = (b.1 - a.1).to_radians();
+    let y = dlong.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlong.cos();
+    (y.atan2(x).to_degrees() + 360.0) % 360.0
+}
+
+/// Project a point onto a local plane centered at `origin`, returning (x, y) in meters. The
+/// equirectangular approximation is plenty accurate over the extent of a single activity.
+pub(crate) fn to_local_xy(origin: Point, p: Point) -> (f64, f64) {
+    let x = (p.1 - origin.1).to_radians() * origin.0.to_radians().cos() * EARTH_RADIUS;
+    let y = (p.0 - origin.0).to_radians() * EARTH_RADIUS;
+    (x, y)
+}
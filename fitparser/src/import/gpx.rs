@@ -0,0 +1,347 @@
+//! Turn the route or track of a GPX document into a FIT course
+use super::xml::{Event, Reader};
+use super::{field, position_fields, text, timer_event};
+use crate::error::{ErrorKind, Result};
+use crate::geo::{haversine, Point};
+use crate::profile::field_types::{File, Sport};
+use crate::profile::MesgNum;
+use crate::ser::FileIdPolicy;
+use crate::timestamp::from_chrono;
+use crate::{FitDataRecord, Value};
+use chrono::{DateTime, Duration, Local, SubsecRound};
+use std::io::Read;
+
+/// Settings for the course created from a GPX document
+#[derive(Clone, Debug)]
+pub struct CourseImport {
+    /// Name of the course, the name of the route or track is used when `None`
+    pub name: Option<String>,
+    /// Sport the course is meant for
+    pub sport: Sport,
+    /// Target speed in m/s, the timestamps of the points are calculated from it
+    pub speed: f64,
+    /// Time the course starts at, the time of the first point or the current time is used when
+    /// `None`
+    pub start_time: Option<DateTime<Local>>,
+    /// Identity written into the `file_id` message
+    pub file_id: FileIdPolicy,
+}
+
+impl Default for CourseImport {
+    fn default() -> Self {
+        CourseImport {
+            name: None,
+            sport: Sport::Generic,
+            speed: 5.0,
+            start_time: None,
+            file_id: FileIdPolicy::default(),
+        }
+    }
+}
+
+/// A route point, track point or waypoint of the document
+#[derive(Clone, Debug, Default)]
+struct GpxPoint {
+    position: Point,
+    elevation: Option<f64>,
+    time: Option<DateTime<Local>>,
+    name: Option<String>,
+}
+
+/// The parts of a GPX document a course is built from
+#[derive(Debug, Default)]
+struct Gpx {
+    name: Option<String>,
+    track: Vec<GpxPoint>,
+    route: Vec<GpxPoint>,
+    waypoints: Vec<GpxPoint>,
+}
+
+fn parse_coordinate(attributes: &[(&str, String)], name: &str) -> Result<f64> {
+    attributes
+        .iter()
+        .find(|(n, _)| *n == name)
+        .and_then(|(_, v)| v.trim().parse::<f64>().ok())
+        .filter(|v| v.is_finite())
+        .ok_or_else(|| ErrorKind::ImportError(format!("point without a valid '{}'", name)).into())
+}
+
+fn parse_gpx(text: &str) -> Result<Gpx> {
+    let mut gpx = Gpx::default();
+    let mut path: Vec<&str> = Vec::new();
+    let mut point: Option<GpxPoint> = None;
+    for event in Reader::new(text) {
+        match event? {
+            Event::Start(name, attributes) => {
+                if matches!(name, "trkpt" | "rtept" | "wpt") {
+                    let lat = parse_coordinate(&attributes, "lat")?;
+                    let lon = parse_coordinate(&attributes, "lon")?;
+                    point = Some(GpxPoint {
+                        position: (lat, lon),
+                        ..Default::default()
+                    });
+                }
+                path.push(name);
+            }
+            Event::End(name) => {
+                if path.pop() != Some(name) {
+                    return Err(ErrorKind::ImportError(format!(
+                        "unexpected closing tag '{}'",
+                        name
+                    ))
+                    .into());
+                }
+                let list = match name {
+                    "trkpt" => &mut gpx.track,
+                    "rtept" => &mut gpx.route,
+                    "wpt" => &mut gpx.waypoints,
+                    _ => continue,
+                };
+                list.extend(point.take());
+            }
+            Event::Text(text) => match (path.as_slice(), point.as_mut()) {
+                ([.., "ele"], Some(point)) => point.elevation = text.parse().ok(),
+                ([.., "time"], Some(point)) => {
+                    point.time = DateTime::parse_from_rfc3339(&text)
+                        .ok()
+                        .map(|t| t.with_timezone(&Local))
+                }
+                ([.., "name"], Some(point)) => point.name = Some(text),
+                (["gpx", "trk" | "rte", "name"], None) if gpx.name.is_none() => {
+                    gpx.name = Some(text)
+                }
+                _ => {}
+            },
+        }
+    }
+    if !path.is_empty() {
+        return Err(ErrorKind::ImportError(format!("unclosed tag '{}'", path[0])).into());
+    }
+    Ok(gpx)
+}
+
+/// Create the records of a FIT course from the route or track of a GPX document.
+///
+/// The points of every track segment are joined into a single path, a route is only used if the
+/// document has no track. The distance along the path is accumulated between the points and the
+/// timestamps are calculated from the target speed, starting at the configured start time.
+/// Waypoints and named points along the path become `course_point` messages at the distance of
+/// the closest point of the path. The result holds the `file_id`, `course`, `lap`, timer
+/// `event`, `record` and `course_point` messages of a course file and can be written with
+/// `ser::to_bytes`.
+pub fn gpx_to_course<R: Read>(mut reader: R, options: &CourseImport) -> Result<Vec<FitDataRecord>> {
+    if !(options.speed.is_finite() && options.speed > 0.0) {
+        return Err(ErrorKind::ImportError(format!(
+            "target speed {} must be a positive number",
+            options.speed
+        ))
+        .into());
+    }
+    let mut document = String::new();
+    reader.read_to_string(&mut document)?;
+    let gpx = parse_gpx(&document)?;
+    let points = if gpx.track.is_empty() {
+        &gpx.route
+    } else {
+        &gpx.track
+    };
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Err(ErrorKind::ImportError("no route or track points".to_string()).into()),
+    };
+    let start = options
+        .start_time
+        .or(first.time)
+        .unwrap_or_else(Local::now)
+        .trunc_subsecs(0);
+
+    let mut distances = Vec::with_capacity(points.len());
+    let mut distance = 0.0;
+    for (i, point) in points.iter().enumerate() {
+        if i > 0 {
+            distance += haversine(points[i - 1].position, point.position);
+        }
+        distances.push(distance);
+    }
+    let time_at =
+        |distance: f64| start + Duration::seconds((distance / options.speed).round() as i64);
+    let end = time_at(distance);
+
+    let mut records = Vec::new();
+    records.push(options.file_id.record(File::Course, from_chrono(start)));
+
+    let mut course = FitDataRecord::new(MesgNum::Course);
+    course.push(field("sport", 4, text(&options.sport.to_string()), ""));
+    if let Some(name) = options.name.as_ref().or(gpx.name.as_ref()) {
+        course.push(field("name", 5, text(name), ""));
+    }
+    records.push(course);
+
+    let mut lap = FitDataRecord::new(MesgNum::Lap);
+    lap.push(field(
+        "timestamp",
+        253,
+        Value::Timestamp(from_chrono(end)),
+        "s",
+    ));
+    lap.push(field("event", 0, text("lap"), ""));
+    lap.push(field("event_type", 1, text("stop"), ""));
+    lap.push(field(
+        "start_time",
+        2,
+        Value::Timestamp(from_chrono(start)),
+        "s",
+    ));
+    position_fields(
+        &mut lap,
+        [("start_position_lat", 3), ("start_position_long", 4)],
+        first.position,
+    );
+    position_fields(
+        &mut lap,
+        [("end_position_lat", 5), ("end_position_long", 6)],
+        last.position,
+    );
+    let duration = (end - start).num_seconds() as f64;
+    lap.push(field(
+        "total_elapsed_time",
+        7,
+        Value::Float64(duration),
+        "s",
+    ));
+    lap.push(field("total_timer_time", 8, Value::Float64(duration), "s"));
+    lap.push(field("total_distance", 9, Value::Float64(distance), "m"));
+    records.push(lap);
+
+    records.push(timer_event(start, "start"));
+    for (point, &distance) in points.iter().zip(&distances) {
+        let mut record = FitDataRecord::new(MesgNum::Record);
+        record.push(field(
+            "timestamp",
+            253,
+            Value::Timestamp(from_chrono(time_at(distance))),
+            "s",
+        ));
+        position_fields(
+            &mut record,
+            [("position_lat", 0), ("position_long", 1)],
+            point.position,
+        );
+        if let Some(elevation) = point.elevation {
+            record.push(field("altitude", 2, Value::Float64(elevation), "m"));
+        }
+        record.push(field("distance", 5, Value::Float64(distance), "m"));
+        records.push(record);
+    }
+    records.push(timer_event(end, "stop_all"));
+
+    // waypoints are placed at the closest point of the path
+    let mut course_points: Vec<(f64, &GpxPoint)> = points
+        .iter()
+        .zip(&distances)
+        .filter(|(point, _)| point.name.is_some())
+        .map(|(point, &distance)| (distance, point))
+        .collect();
+    for waypoint in &gpx.waypoints {
+        let closest = points
+            .iter()
+            .zip(&distances)
+            .map(|(point, &distance)| (haversine(point.position, waypoint.position), distance))
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+        if let Some((_, distance)) = closest {
+            course_points.push((distance, waypoint));
+        }
+    }
+    course_points.sort_by(|a, b| a.0.total_cmp(&b.0));
+    for (index, (distance, point)) in course_points.into_iter().enumerate() {
+        let mut course_point = FitDataRecord::new(MesgNum::CoursePoint);
+        course_point.push(field("message_index", 254, Value::UInt16(index as u16), ""));
+        course_point.push(field(
+            "timestamp",
+            1,
+            Value::Timestamp(from_chrono(time_at(distance))),
+            "s",
+        ));
+        position_fields(
+            &mut course_point,
+            [("position_lat", 2), ("position_long", 3)],
+            point.position,
+        );
+        course_point.push(field("distance", 4, Value::Float64(distance), "m"));
+        course_point.push(field("type", 5, text("generic"), ""));
+        if let Some(name) = &point.name {
+            course_point.push(field("name", 6, text(name), ""));
+        }
+        records.push(course_point);
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::gpx::to_gpx;
+    use crate::{from_bytes, ser};
+    use chrono::TimeZone;
+
+    const ROUTE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- exported route -->
+<gpx version="1.1" creator="test" xmlns="http://www.topografix.com/GPX/1/1">
+  <wpt lat="47.0011" lon="8.0"><name>Caf&#233; &amp; water</name></wpt>
+  <rte>
+    <name>Lake loop</name>
+    <rtept lat="47.0" lon="8.0"><ele>400</ele></rtept>
+    <rtept lat="47.001" lon="8.0"><ele>410</ele></rtept>
+    <rtept lat="47.002" lon="8.0"><ele>420</ele><name>Summit</name></rtept>
+  </rte>
+</gpx>"#;
+
+    #[test]
+    fn gpx_route_round_trip() {
+        let start = Local.with_ymd_and_hms(2022, 5, 1, 9, 0, 0).unwrap();
+        let options = CourseImport {
+            sport: Sport::Cycling,
+            speed: 5.0,
+            start_time: Some(start),
+            ..Default::default()
+        };
+        let records = gpx_to_course(ROUTE.as_bytes(), &options).unwrap();
+        let course = records.iter().find(|r| r.is_kind(MesgNum::Course)).unwrap();
+        assert_eq!(course.field("name").unwrap().value(), &text("Lake loop"));
+
+        let bytes = ser::to_bytes(&records).unwrap();
+        let decoded = from_bytes(&bytes).unwrap();
+        let points: Vec<_> = decoded
+            .iter()
+            .filter(|r| r.is_kind(MesgNum::Record))
+            .collect();
+        assert_eq!(points.len(), 3);
+        // 0.001 degrees of latitude are about 111 m, covered in 22 s at 5 m/s
+        let distance: f64 = points[2]
+            .field("distance")
+            .unwrap()
+            .value()
+            .clone()
+            .try_into()
+            .unwrap();
+        assert!((distance - 222.4).abs() < 0.5, "{}", distance);
+        assert_eq!(
+            points[1].field("timestamp").unwrap().value(),
+            &Value::Timestamp(from_chrono(start + Duration::seconds(22)))
+        );
+
+        let cues: Vec<_> = decoded
+            .iter()
+            .filter(|r| r.is_kind(MesgNum::CoursePoint))
+            .map(|r| r.field("name").unwrap().value().to_string())
+            .collect();
+        assert_eq!(cues, ["Café & water", "Summit"]);
+
+        let gpx = to_gpx(&decoded).unwrap();
+        assert_eq!(gpx.matches("<trkpt").count(), 3);
+        assert!(gpx.contains("<ele>410.0</ele>"));
+
+        assert!(gpx_to_course("<gpx><rte></rte></gpx>".as_bytes(), &options).is_err());
+        assert!(gpx_to_course("<gpx><trk>".as_bytes(), &options).is_err());
+    }
+}
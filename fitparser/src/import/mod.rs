@@ -0,0 +1,53 @@
+//! Import tracks and routes from other formats as FIT records
+use crate::geo::{degrees_to_semicircles, Point};
+use crate::profile::MesgNum;
+use crate::timestamp::from_chrono;
+use crate::{FitDataField, FitDataRecord, Value};
+use chrono::{DateTime, Local};
+
+#[cfg(not(compact_profile))]
+pub mod gpx;
+#[cfg(all(feature = "json", not(compact_profile)))]
+pub mod streams;
+pub mod tcx;
+mod xml;
+
+#[cfg(not(compact_profile))]
+pub use gpx::{gpx_to_course, CourseImport};
+#[cfg(all(feature = "json", not(compact_profile)))]
+pub use streams::streams;
+pub use tcx::tcx;
+
+fn field(name: &str, number: u8, value: Value, units: &str) -> FitDataField {
+    FitDataField::new(name.to_string(), number, value, units.to_string())
+}
+
+fn text(value: &str) -> Value {
+    Value::String(value.to_string())
+}
+
+/// Add a latitude, longitude pair in semicircles to the record
+fn position_fields(record: &mut FitDataRecord, names: [(&str, u8); 2], position: Point) {
+    for ((name, number), degrees) in names.into_iter().zip([position.0, position.1]) {
+        record.push(field(
+            name,
+            number,
+            Value::SInt32(degrees_to_semicircles(degrees)),
+            "semicircles",
+        ));
+    }
+}
+
+fn timer_event(timestamp: DateTime<Local>, event_type: &str) -> FitDataRecord {
+    let mut event = FitDataRecord::new(MesgNum::Event);
+    event.push(field(
+        "timestamp",
+        253,
+        Value::Timestamp(from_chrono(timestamp)),
+        "s",
+    ));
+    event.push(field("event", 0, text("timer"), ""));
+    event.push(field("event_type", 1, text(event_type), ""));
+    event.push(field("event_group", 4, Value::UInt8(0), ""));
+    event
+}
@@ -0,0 +1,174 @@
+//! Rebuild a FIT activity from the data streams returned by the APIs of fitness platforms
+use super::tcx::{activity_records, Activity, Lap, Trackpoint};
+use crate::error::{ErrorKind, Result};
+use crate::geo::haversine;
+use crate::profile::field_types::Sport;
+use crate::FitDataRecord;
+use chrono::{DateTime, Duration, Local};
+use serde_json::Value as Json;
+use std::collections::HashMap;
+use std::io::Read;
+
+/// Collect the `data` array of every stream by its type. Streams are either a list of objects
+/// with a `type` and `data` entry, an object keyed by type holding such objects or an object
+/// holding the plain arrays.
+fn collect_streams(json: Json) -> Result<HashMap<String, Vec<Json>>> {
+    let entries: Vec<(String, Json)> = match json {
+        Json::Array(streams) => streams
+            .into_iter()
+            .filter_map(|mut stream| {
+                let kind = stream.get("type")?.as_str()?.to_string();
+                Some((kind, stream.get_mut("data")?.take()))
+            })
+            .collect(),
+        Json::Object(streams) => streams
+            .into_iter()
+            .map(|(kind, mut stream)| match stream.get_mut("data") {
+                Some(data) => (kind, data.take()),
+                None => (kind, stream),
+            })
+            .collect(),
+        _ => {
+            return Err(
+                ErrorKind::ImportError("streams must be a list or an object".to_string()).into(),
+            )
+        }
+    };
+    Ok(entries
+        .into_iter()
+        .filter_map(|(kind, data)| match data {
+            Json::Array(values) => Some((kind, values)),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Create the messages of a FIT activity from the streams of an activity, as returned by the
+/// Strava API or in the same shape by other platforms, when the original file isn't available.
+///
+/// The `time` stream holds the seconds since `start` and is required, every other stream is
+/// optional: `latlng` pairs in degrees, `distance` in meters, `altitude` in meters,
+/// `velocity_smooth` in m/s, `heartrate` in bpm, `cadence` in rpm and `watts`. Streams must
+/// have a value for every time, `null` marks a missing value. The distance is accumulated from
+/// the positions when there is no `distance` stream. Every sample becomes a `record` message,
+/// the activity is summarized by a single lap and session. The `file_id` holds the default
+/// `FileIdPolicy`, `FileIdPolicy::apply` replaces it.
+pub fn streams<R: Read>(
+    reader: R,
+    start: DateTime<Local>,
+    sport: Sport,
+) -> Result<Vec<FitDataRecord>> {
+    let json: Json = serde_json::from_reader(reader)
+        .map_err(|e| ErrorKind::ImportError(format!("invalid streams JSON: {}", e)))?;
+    let streams = collect_streams(json)?;
+    let times = streams
+        .get("time")
+        .ok_or_else(|| ErrorKind::ImportError("no time stream".to_string()))?;
+    if let Some((kind, values)) = streams.iter().find(|(_, v)| v.len() != times.len()) {
+        return Err(ErrorKind::ImportError(format!(
+            "stream '{}' has {} values for {} times",
+            kind,
+            values.len(),
+            times.len()
+        ))
+        .into());
+    }
+    let channel = |names: &[&str], index: usize| {
+        names
+            .iter()
+            .find_map(|name| streams.get(*name))
+            .and_then(|values| values[index].as_f64())
+    };
+
+    let has_distance = streams.contains_key("distance");
+    let mut points = Vec::with_capacity(times.len());
+    let mut distance = 0.0;
+    let mut previous = None;
+    for (index, time) in times.iter().enumerate() {
+        let seconds = time.as_f64().ok_or_else(|| {
+            ErrorKind::ImportError(format!("invalid time {} at sample {}", time, index))
+        })?;
+        let position = streams.get("latlng").and_then(|values| {
+            let pair = values[index].as_array()?;
+            Some((pair.first()?.as_f64()?, pair.get(1)?.as_f64()?))
+        });
+        if let Some(position) = position {
+            if let Some(previous) = previous {
+                distance += haversine(previous, position);
+            }
+            previous = Some(position);
+        }
+        points.push(Trackpoint {
+            time: Some(start + Duration::milliseconds((seconds * 1e3).round() as i64)),
+            latitude: position.map(|p| p.0),
+            longitude: position.map(|p| p.1),
+            altitude: channel(&["altitude"], index),
+            distance: match has_distance {
+                true => channel(&["distance"], index),
+                false => position.map(|_| distance),
+            },
+            heart_rate: channel(&["heartrate", "heart_rate"], index),
+            cadence: channel(&["cadence"], index),
+            speed: channel(&["velocity_smooth", "speed"], index),
+            power: channel(&["watts", "power"], index),
+        });
+    }
+    let activity = Activity {
+        sport: sport.to_string(),
+        laps: vec![Lap::from_points(start, points)],
+    };
+    activity_records(&[activity])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::MesgNum;
+    use crate::{from_bytes, ser, Value};
+    use chrono::TimeZone;
+
+    #[test]
+    fn import_streams() {
+        let start = Local.with_ymd_and_hms(2023, 4, 2, 8, 30, 0).unwrap();
+        let keyed = r#"{
+            "time": {"data": [0, 1, 2, 4], "series_type": "distance"},
+            "latlng": {"data": [[47.0, 8.0], [47.0001, 8.0], [47.0002, 8.0], [47.0003, 8.0]]},
+            "heartrate": {"data": [100, 110, null, 130]},
+            "watts": {"data": [200, 200, 300, 300]}
+        }"#;
+        let list = r#"[
+            {"type": "time", "data": [0, 1, 2, 4]},
+            {"type": "latlng", "data": [[47.0, 8.0], [47.0001, 8.0], [47.0002, 8.0], [47.0003, 8.0]]},
+            {"type": "heartrate", "data": [100, 110, null, 130]},
+            {"type": "watts", "data": [200, 200, 300, 300]}
+        ]"#;
+        let records = streams(keyed.as_bytes(), start, Sport::Cycling).unwrap();
+        let json = |records: &[FitDataRecord]| serde_json::to_value(records).unwrap();
+        assert_eq!(
+            json(&records),
+            json(&streams(list.as_bytes(), start, Sport::Cycling).unwrap())
+        );
+
+        let decoded = from_bytes(&ser::to_bytes(&records).unwrap()).unwrap();
+        let samples: Vec<_> = decoded
+            .iter()
+            .filter(|r| r.is_kind(MesgNum::Record))
+            .collect();
+        assert_eq!(samples.len(), 4);
+        assert!(samples[2].field("heart_rate").is_none());
+        let session = decoded
+            .iter()
+            .find(|r| r.is_kind(MesgNum::Session))
+            .unwrap();
+        let value = |name| session.field(name).expect(name).value().clone();
+        assert_eq!(value("total_timer_time"), Value::Float64(4.0));
+        assert_eq!(value("avg_power"), Value::UInt16(250));
+        assert_eq!(value("max_heart_rate"), Value::UInt8(130));
+        let distance: f64 = value("total_distance").try_into().unwrap();
+        assert!((distance - 33.36).abs() < 0.01, "{}", distance);
+
+        let short = r#"{"time": [0, 1], "watts": [100]}"#;
+        assert!(streams(short.as_bytes(), start, Sport::Cycling).is_err());
+        assert!(streams("{}".as_bytes(), start, Sport::Cycling).is_err());
+    }
+}
@@ -0,0 +1,611 @@
+//! Turn the activities of a Training Center XML document into the messages of a FIT activity
+use super::xml::{Event, Reader};
+use super::{position_fields, text, timer_event};
+use crate::analysis::WeightedAverage;
+use crate::error::{ErrorKind, Result};
+use crate::profile::field_types::File;
+use crate::profile::{field_info, MesgNum};
+use crate::ser::FileIdPolicy;
+use crate::timestamp::from_chrono;
+use crate::{FitDataField, FitDataRecord, Value};
+use chrono::{DateTime, Duration, Local};
+use std::io::Read;
+
+/// A trackpoint of a lap
+#[derive(Clone, Debug, Default)]
+pub(super) struct Trackpoint {
+    pub(super) time: Option<DateTime<Local>>,
+    pub(super) latitude: Option<f64>,
+    pub(super) longitude: Option<f64>,
+    pub(super) altitude: Option<f64>,
+    pub(super) distance: Option<f64>,
+    pub(super) heart_rate: Option<f64>,
+    pub(super) cadence: Option<f64>,
+    pub(super) speed: Option<f64>,
+    pub(super) power: Option<f64>,
+}
+
+/// The totals of a lap as written in the document, missing averages and maximums are calculated
+/// from the trackpoints
+#[derive(Clone, Debug, Default)]
+pub(super) struct Lap {
+    start: DateTime<Local>,
+    total_time: Option<f64>,
+    distance: Option<f64>,
+    calories: Option<f64>,
+    max_speed: Option<f64>,
+    avg_heart_rate: Option<f64>,
+    max_heart_rate: Option<f64>,
+    avg_cadence: Option<f64>,
+    avg_speed: Option<f64>,
+    avg_power: Option<f64>,
+    max_power: Option<f64>,
+    intensity: Option<&'static str>,
+    trigger: Option<&'static str>,
+    points: Vec<Trackpoint>,
+}
+
+/// The laps of a session and the name of its sport in the FIT profile
+#[derive(Clone, Debug)]
+pub(super) struct Activity {
+    pub(super) sport: String,
+    pub(super) laps: Vec<Lap>,
+}
+
+/// The summary written into a `lap` or `session` message
+#[derive(Clone, Debug, Default)]
+struct Summary {
+    timer_time: f64,
+    distance: Option<f64>,
+    calories: Option<f64>,
+    avg_speed: Option<f64>,
+    max_speed: Option<f64>,
+    avg_heart_rate: Option<f64>,
+    max_heart_rate: Option<f64>,
+    avg_cadence: Option<f64>,
+    avg_power: Option<f64>,
+    max_power: Option<f64>,
+}
+
+fn sport(name: &str) -> String {
+    match name {
+        "Running" => "running",
+        "Biking" => "cycling",
+        _ => "generic",
+    }
+    .to_string()
+}
+
+fn lap_trigger(method: &str) -> Option<&'static str> {
+    match method {
+        "Manual" => Some("manual"),
+        "Distance" => Some("distance"),
+        "Location" => Some("position_lap"),
+        "Time" => Some("time"),
+        _ => None,
+    }
+}
+
+fn parse_time(text: &str) -> Result<DateTime<Local>> {
+    DateTime::parse_from_rfc3339(text)
+        .map(|t| t.with_timezone(&Local))
+        .map_err(|_| ErrorKind::ImportError(format!("invalid time '{}'", text)).into())
+}
+
+fn parse_number(text: &str) -> Option<f64> {
+    text.parse().ok().filter(|v: &f64| v.is_finite())
+}
+
+fn parse_tcx(document: &str) -> Result<Vec<Activity>> {
+    let mut activities = Vec::new();
+    let mut path: Vec<&str> = Vec::new();
+    let mut activity: Option<Activity> = None;
+    let mut lap: Option<Lap> = None;
+    let mut point: Option<Trackpoint> = None;
+    for event in Reader::new(document) {
+        match event? {
+            Event::Start(name, attributes) => {
+                let attribute = |key: &str| {
+                    attributes
+                        .iter()
+                        .find(|(n, _)| *n == key)
+                        .map(|(_, v)| v.as_str())
+                };
+                match name {
+                    "Activity" => {
+                        activity = Some(Activity {
+                            sport: sport(attribute("Sport").unwrap_or_default()),
+                            laps: Vec::new(),
+                        })
+                    }
+                    "Lap" if activity.is_some() => {
+                        let start = attribute("StartTime").ok_or_else(|| {
+                            ErrorKind::ImportError("lap without a start time".to_string())
+                        })?;
+                        lap = Some(Lap {
+                            start: parse_time(start)?,
+                            ..Default::default()
+                        });
+                    }
+                    "Trackpoint" if lap.is_some() => point = Some(Trackpoint::default()),
+                    _ => {}
+                }
+                path.push(name);
+            }
+            Event::End(name) => {
+                if path.pop() != Some(name) {
+                    return Err(ErrorKind::ImportError(format!(
+                        "unexpected closing tag '{}'",
+                        name
+                    ))
+                    .into());
+                }
+                match name {
+                    "Trackpoint" => {
+                        // trackpoints without a time can't be placed in the activity
+                        let point = point.take().filter(|p| p.time.is_some());
+                        if let (Some(lap), Some(point)) = (lap.as_mut(), point) {
+                            lap.points.push(point);
+                        }
+                    }
+                    "Lap" => {
+                        if let (Some(activity), Some(lap)) = (activity.as_mut(), lap.take()) {
+                            activity.laps.push(lap);
+                        }
+                    }
+                    "Activity" => activities.extend(activity.take()),
+                    _ => {}
+                }
+            }
+            Event::Text(text) => {
+                let value = parse_number(&text);
+                if let Some(point) = point.as_mut() {
+                    match path.as_slice() {
+                        [.., "Trackpoint", "Time"] => point.time = Some(parse_time(&text)?),
+                        [.., "Position", "LatitudeDegrees"] => point.latitude = value,
+                        [.., "Position", "LongitudeDegrees"] => point.longitude = value,
+                        [.., "Trackpoint", "AltitudeMeters"] => point.altitude = value,
+                        [.., "Trackpoint", "DistanceMeters"] => point.distance = value,
+                        [.., "HeartRateBpm", "Value"] => point.heart_rate = value,
+                        [.., "Trackpoint", "Cadence"] | [.., "RunCadence"] => point.cadence = value,
+                        [.., "Speed"] => point.speed = value,
+                        [.., "Watts"] => point.power = value,
+                        _ => {}
+                    }
+                } else if let Some(lap) = lap.as_mut() {
+                    match path.as_slice() {
+                        [.., "Lap", "TotalTimeSeconds"] => lap.total_time = value,
+                        [.., "Lap", "DistanceMeters"] => lap.distance = value,
+                        [.., "Lap", "Calories"] => lap.calories = value,
+                        [.., "Lap", "MaximumSpeed"] => lap.max_speed = value,
+                        [.., "AverageHeartRateBpm", "Value"] => lap.avg_heart_rate = value,
+                        [.., "MaximumHeartRateBpm", "Value"] => lap.max_heart_rate = value,
+                        [.., "Lap", "Cadence"] | [.., "AvgRunCadence"] => lap.avg_cadence = value,
+                        [.., "AvgSpeed"] => lap.avg_speed = value,
+                        [.., "AvgWatts"] => lap.avg_power = value,
+                        [.., "MaxWatts"] => lap.max_power = value,
+                        [.., "Lap", "Intensity"] => {
+                            lap.intensity = match text.as_str() {
+                                "Resting" => Some("rest"),
+                                _ => Some("active"),
+                            }
+                        }
+                        [.., "Lap", "TriggerMethod"] => lap.trigger = lap_trigger(&text),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    if !path.is_empty() {
+        return Err(ErrorKind::ImportError(format!("unclosed tag '{}'", path[0])).into());
+    }
+    Ok(activities)
+}
+
+/// Time weighted average and maximum of a trackpoint channel
+fn channel(
+    points: &[Trackpoint],
+    end: DateTime<Local>,
+    value: fn(&Trackpoint) -> Option<f64>,
+) -> (Option<f64>, Option<f64>) {
+    let mut average = WeightedAverage::new();
+    let mut max: Option<f64> = None;
+    for point in points {
+        if let (Some(time), Some(value)) = (point.time, value(point)) {
+            average.add(time, value);
+            max = Some(max.map_or(value, |m| m.max(value)));
+        }
+    }
+    (average.average_until(end), max)
+}
+
+impl Lap {
+    /// A lap without totals, they are all calculated from the trackpoints
+    #[cfg_attr(any(compact_profile, not(feature = "json")), allow(dead_code))]
+    pub(super) fn from_points(start: DateTime<Local>, points: Vec<Trackpoint>) -> Self {
+        Lap {
+            start,
+            points,
+            ..Default::default()
+        }
+    }
+
+    /// End of the lap, from its total time or the last trackpoint
+    fn end(&self) -> DateTime<Local> {
+        match self.total_time {
+            Some(seconds) => self.start + Duration::milliseconds((seconds * 1e3).round() as i64),
+            None => self
+                .points
+                .iter()
+                .filter_map(|p| p.time)
+                .max()
+                .unwrap_or(self.start),
+        }
+    }
+
+    fn summary(&self) -> Summary {
+        let end = self.end();
+        let (avg_heart_rate, max_heart_rate) = channel(&self.points, end, |p| p.heart_rate);
+        let (avg_cadence, _) = channel(&self.points, end, |p| p.cadence);
+        let (avg_power, max_power) = channel(&self.points, end, |p| p.power);
+        let (_, max_speed) = channel(&self.points, end, |p| p.speed);
+        let mut distances = self.points.iter().filter_map(|p| p.distance);
+        let distance = self.distance.or_else(|| {
+            let (first, last) = (distances.clone().next()?, distances.next_back()?);
+            Some(last - first)
+        });
+        let timer_time = self.total_time.unwrap_or_else(|| seconds(self.start, end));
+        Summary {
+            timer_time,
+            distance,
+            calories: self.calories,
+            avg_speed: self.avg_speed.or_else(|| {
+                distance
+                    .filter(|_| timer_time > 0.0)
+                    .map(|d| d / timer_time)
+            }),
+            max_speed: self.max_speed.or(max_speed),
+            avg_heart_rate: self.avg_heart_rate.or(avg_heart_rate),
+            max_heart_rate: self.max_heart_rate.or(max_heart_rate),
+            avg_cadence: self.avg_cadence.or(avg_cadence),
+            avg_power: self.avg_power.or(avg_power),
+            max_power: self.max_power.or(max_power),
+        }
+    }
+}
+
+fn seconds(start: DateTime<Local>, end: DateTime<Local>) -> f64 {
+    (end - start).num_milliseconds() as f64 / 1e3
+}
+
+/// Combine the summaries of the laps, averages are weighted by the timer time of each lap
+fn session_summary(laps: &[Summary]) -> Summary {
+    let sum = |value: fn(&Summary) -> Option<f64>| {
+        laps.iter()
+            .filter_map(value)
+            .fold(None, |total: Option<f64>, v| Some(total.unwrap_or(0.0) + v))
+    };
+    let max = |value: fn(&Summary) -> Option<f64>| {
+        laps.iter()
+            .filter_map(value)
+            .fold(None, |max: Option<f64>, v| {
+                Some(max.map_or(v, |m| m.max(v)))
+            })
+    };
+    let average = |value: fn(&Summary) -> Option<f64>| {
+        let (total, time) = laps
+            .iter()
+            .filter_map(|lap| value(lap).map(|v| (v * lap.timer_time, lap.timer_time)))
+            .fold((0.0, 0.0), |(total, time), (v, t)| (total + v, time + t));
+        Some(total / time).filter(|_| time > 0.0)
+    };
+    let timer_time = laps.iter().map(|lap| lap.timer_time).sum();
+    let distance = sum(|s| s.distance);
+    Summary {
+        timer_time,
+        distance,
+        calories: sum(|s| s.calories),
+        avg_speed: distance
+            .filter(|_| timer_time > 0.0)
+            .map(|d| d / timer_time),
+        max_speed: max(|s| s.max_speed),
+        avg_heart_rate: average(|s| s.avg_heart_rate),
+        max_heart_rate: max(|s| s.max_heart_rate),
+        avg_cadence: average(|s| s.avg_cadence),
+        avg_power: average(|s| s.avg_power),
+        max_power: max(|s| s.max_power),
+    }
+}
+
+/// Add a field of the FIT profile, the definition number and units come from the profile
+fn push(record: &mut FitDataRecord, name: &str, value: Value) {
+    let info = field_info(record.kind(), name).expect("field of the FIT profile");
+    record.push(FitDataField::new(
+        name.to_string(),
+        info.number,
+        value,
+        info.units.to_string(),
+    ));
+}
+
+/// Add the totals shared by the `lap` and `session` messages
+fn push_summary(
+    record: &mut FitDataRecord,
+    start: DateTime<Local>,
+    end: DateTime<Local>,
+    summary: &Summary,
+) {
+    push(record, "timestamp", Value::Timestamp(from_chrono(end)));
+    push(record, "start_time", Value::Timestamp(from_chrono(start)));
+    push(
+        record,
+        "total_elapsed_time",
+        Value::Float64(seconds(start, end)),
+    );
+    push(
+        record,
+        "total_timer_time",
+        Value::Float64(summary.timer_time),
+    );
+    let values = [
+        ("total_distance", summary.distance.map(Value::Float64)),
+        (
+            "total_calories",
+            summary.calories.map(|v| Value::UInt16(v.round() as u16)),
+        ),
+        ("avg_speed", summary.avg_speed.map(Value::Float64)),
+        ("max_speed", summary.max_speed.map(Value::Float64)),
+        (
+            "avg_heart_rate",
+            summary
+                .avg_heart_rate
+                .map(|v| Value::UInt8(v.round() as u8)),
+        ),
+        (
+            "max_heart_rate",
+            summary
+                .max_heart_rate
+                .map(|v| Value::UInt8(v.round() as u8)),
+        ),
+        (
+            "avg_cadence",
+            summary.avg_cadence.map(|v| Value::UInt8(v.round() as u8)),
+        ),
+        (
+            "avg_power",
+            summary.avg_power.map(|v| Value::UInt16(v.round() as u16)),
+        ),
+        (
+            "max_power",
+            summary.max_power.map(|v| Value::UInt16(v.round() as u16)),
+        ),
+    ];
+    for (name, value) in values {
+        if let Some(value) = value {
+            push(record, name, value);
+        }
+    }
+}
+
+fn record_message(point: &Trackpoint, time: DateTime<Local>) -> FitDataRecord {
+    let mut record = FitDataRecord::new(MesgNum::Record);
+    push(
+        &mut record,
+        "timestamp",
+        Value::Timestamp(from_chrono(time)),
+    );
+    if let (Some(lat), Some(long)) = (point.latitude, point.longitude) {
+        position_fields(
+            &mut record,
+            [("position_lat", 0), ("position_long", 1)],
+            (lat, long),
+        );
+    }
+    let values = [
+        ("altitude", point.altitude.map(Value::Float64)),
+        (
+            "heart_rate",
+            point.heart_rate.map(|v| Value::UInt8(v.round() as u8)),
+        ),
+        (
+            "cadence",
+            point.cadence.map(|v| Value::UInt8(v.round() as u8)),
+        ),
+        ("distance", point.distance.map(Value::Float64)),
+        ("speed", point.speed.map(Value::Float64)),
+        (
+            "power",
+            point.power.map(|v| Value::UInt16(v.round() as u16)),
+        ),
+    ];
+    for (name, value) in values {
+        if let Some(value) = value {
+            push(&mut record, name, value);
+        }
+    }
+    record
+}
+
+/// Create the messages of a FIT activity from the activities of a Training Center XML (TCX)
+/// document, e.g. from an archive of a service that predates FIT.
+///
+/// Every trackpoint becomes a `record` message followed by a `lap` message for each lap, and
+/// each activity of the document becomes a `session` message. The totals written for the laps
+/// are used as they are, averages and maximums missing from a lap are calculated from its
+/// trackpoints. The records are framed by timer events, start with a `file_id` and end with an
+/// `activity` message so they can be written with `ser::to_bytes`. The `file_id` holds the
+/// default `FileIdPolicy`, `FileIdPolicy::apply` replaces it.
+pub fn tcx<R: Read>(mut reader: R) -> Result<Vec<FitDataRecord>> {
+    let mut document = String::new();
+    reader.read_to_string(&mut document)?;
+    activity_records(&parse_tcx(&document)?)
+}
+
+/// Build the messages of a FIT activity from the laps of one or more sessions
+pub(super) fn activity_records(activities: &[Activity]) -> Result<Vec<FitDataRecord>> {
+    let laps = || activities.iter().flat_map(|a| &a.laps);
+    let (start, end) = match (laps().map(|l| l.start).min(), laps().map(Lap::end).max()) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return Err(ErrorKind::ImportError("no activity laps".to_string()).into()),
+    };
+
+    let mut records = vec![
+        FileIdPolicy::default().record(File::Activity, from_chrono(start)),
+        timer_event(start, "start"),
+    ];
+    let mut sessions = Vec::new();
+    let mut lap_index = 0;
+    for activity in activities.iter().filter(|a| !a.laps.is_empty()) {
+        let mut summaries = Vec::new();
+        for lap in &activity.laps {
+            for point in &lap.points {
+                records.push(record_message(point, point.time.unwrap_or(lap.start)));
+            }
+            let summary = lap.summary();
+            let mut message = FitDataRecord::new(MesgNum::Lap);
+            push(&mut message, "event", text("lap"));
+            push(&mut message, "event_type", text("stop"));
+            push_summary(&mut message, lap.start, lap.end(), &summary);
+            if let Some(intensity) = lap.intensity {
+                push(&mut message, "intensity", text(intensity));
+            }
+            if let Some(trigger) = lap.trigger {
+                push(&mut message, "lap_trigger", text(trigger));
+            }
+            push(&mut message, "sport", text(&activity.sport));
+            records.push(message);
+            summaries.push(summary);
+        }
+
+        let (first, last) = (&activity.laps[0], &activity.laps[activity.laps.len() - 1]);
+        let mut session = FitDataRecord::new(MesgNum::Session);
+        push(&mut session, "event", text("session"));
+        push(&mut session, "event_type", text("stop"));
+        push_summary(
+            &mut session,
+            first.start,
+            last.end(),
+            &session_summary(&summaries),
+        );
+        push(&mut session, "sport", text(&activity.sport));
+        push(&mut session, "first_lap_index", Value::UInt16(lap_index));
+        push(
+            &mut session,
+            "num_laps",
+            Value::UInt16(activity.laps.len() as u16),
+        );
+        lap_index += activity.laps.len() as u16;
+        sessions.push(session);
+    }
+    records.push(timer_event(end, "stop_all"));
+
+    let num_sessions = sessions.len() as u16;
+    records.extend(sessions);
+    let mut activity = FitDataRecord::new(MesgNum::Activity);
+    push(
+        &mut activity,
+        "timestamp",
+        Value::Timestamp(from_chrono(end)),
+    );
+    push(
+        &mut activity,
+        "total_timer_time",
+        Value::Float64(laps().filter_map(|l| l.total_time).sum()),
+    );
+    push(&mut activity, "num_sessions", Value::UInt16(num_sessions));
+    push(&mut activity, "type", text("manual"));
+    push(&mut activity, "event", text("activity"));
+    push(&mut activity, "event_type", text("stop"));
+    records.push(activity);
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_bytes, ser};
+
+    const ACTIVITY: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<TrainingCenterDatabase xmlns="http://www.garmin.com/xmlschemas/TrainingCenterDatabase/v2"
+    xmlns:ns3="http://www.garmin.com/xmlschemas/ActivityExtension/v2">
+  <Activities>
+    <Activity Sport="Biking">
+      <Id>2010-06-26T10:00:00Z</Id>
+      <Lap StartTime="2010-06-26T10:00:00Z">
+        <TotalTimeSeconds>20</TotalTimeSeconds>
+        <DistanceMeters>150</DistanceMeters>
+        <Calories>12</Calories>
+        <AverageHeartRateBpm><Value>120</Value></AverageHeartRateBpm>
+        <Intensity>Active</Intensity>
+        <TriggerMethod>Distance</TriggerMethod>
+        <Track>
+          <Trackpoint>
+            <Time>2010-06-26T10:00:00Z</Time>
+            <Position><LatitudeDegrees>47.0</LatitudeDegrees><LongitudeDegrees>8.0</LongitudeDegrees></Position>
+            <AltitudeMeters>400.2</AltitudeMeters>
+            <DistanceMeters>0</DistanceMeters>
+            <HeartRateBpm><Value>110</Value></HeartRateBpm>
+            <Extensions><ns3:TPX><ns3:Speed>7.5</ns3:Speed><ns3:Watts>180</ns3:Watts></ns3:TPX></Extensions>
+          </Trackpoint>
+          <Trackpoint>
+            <Time>2010-06-26T10:00:10Z</Time>
+            <DistanceMeters>75</DistanceMeters>
+            <HeartRateBpm><Value>130</Value></HeartRateBpm>
+            <Extensions><ns3:TPX><ns3:Speed>7.5</ns3:Speed><ns3:Watts>220</ns3:Watts></ns3:TPX></Extensions>
+          </Trackpoint>
+        </Track>
+      </Lap>
+      <Lap StartTime="2010-06-26T10:00:20Z">
+        <TotalTimeSeconds>30</TotalTimeSeconds>
+        <DistanceMeters>150</DistanceMeters>
+        <Track>
+          <Trackpoint>
+            <Time>2010-06-26T10:00:20Z</Time>
+            <DistanceMeters>150</DistanceMeters>
+            <HeartRateBpm><Value>140</Value></HeartRateBpm>
+          </Trackpoint>
+          <Trackpoint><AltitudeMeters>1</AltitudeMeters></Trackpoint>
+        </Track>
+      </Lap>
+    </Activity>
+  </Activities>
+</TrainingCenterDatabase>"#;
+
+    #[test]
+    fn import_tcx_activity() {
+        let records = tcx(ACTIVITY.as_bytes()).unwrap();
+        let decoded = from_bytes(&ser::to_bytes(&records).unwrap()).unwrap();
+        let count = |kind| decoded.iter().filter(|r| r.is_kind(kind)).count();
+        assert_eq!(count(MesgNum::Record), 3);
+        assert_eq!(count(MesgNum::Lap), 2);
+        assert_eq!(count(MesgNum::Session), 1);
+        assert_eq!(decoded.last().unwrap().kind(), MesgNum::Activity);
+
+        let first = decoded.iter().find(|r| r.is_kind(MesgNum::Record)).unwrap();
+        let value = |record: &FitDataRecord, name| record.field(name).expect(name).value().clone();
+        assert_eq!(value(first, "power"), Value::UInt16(180));
+        assert_eq!(value(first, "enhanced_speed"), Value::Float64(7.5));
+        assert_eq!(value(first, "position_lat"), Value::SInt32(560731841));
+
+        let lap = decoded.iter().find(|r| r.is_kind(MesgNum::Lap)).unwrap();
+        assert_eq!(value(lap, "avg_heart_rate"), Value::UInt8(120));
+        assert_eq!(value(lap, "max_heart_rate"), Value::UInt8(130));
+        assert_eq!(value(lap, "avg_power"), Value::UInt16(200));
+        assert_eq!(value(lap, "lap_trigger"), text("distance"));
+
+        let session = decoded
+            .iter()
+            .find(|r| r.is_kind(MesgNum::Session))
+            .unwrap();
+        assert_eq!(value(session, "sport"), text("cycling"));
+        assert_eq!(value(session, "total_distance"), Value::Float64(300.0));
+        assert_eq!(value(session, "total_timer_time"), Value::Float64(50.0));
+        assert_eq!(value(session, "enhanced_avg_speed"), Value::Float64(6.0));
+        // 20 s at 120 bpm and 30 s at 140 bpm
+        assert_eq!(value(session, "avg_heart_rate"), Value::UInt8(132));
+        assert_eq!(value(session, "num_laps"), Value::UInt16(2));
+
+        assert!(tcx("<TrainingCenterDatabase/>".as_bytes()).is_err());
+    }
+}
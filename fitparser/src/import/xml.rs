@@ -0,0 +1,183 @@
+//! A minimal pull parser for the XML documents written by GPS applications. Only elements,
+//! attributes and text are reported, the declaration, comments, processing instructions and the
+//! doctype are skipped and namespace prefixes are stripped from the names.
+use crate::error::{ErrorKind, Result};
+
+/// A piece of the document
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Event<'a> {
+    /// Opening tag with its attributes, self closing tags are followed by an `End`
+    Start(&'a str, Vec<(&'a str, String)>),
+    /// Closing tag
+    End(&'a str),
+    /// Text between tags with the entities resolved, whitespace only text is skipped
+    Text(String),
+}
+
+/// Iterate over the events of a document
+pub(crate) struct Reader<'a> {
+    text: &'a str,
+    position: usize,
+    /// Name of a self closing tag that still needs its `End` event
+    pending_end: Option<&'a str>,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        Reader {
+            text,
+            position: 0,
+            pending_end: None,
+        }
+    }
+
+    fn error(&self, message: &str) -> Box<ErrorKind> {
+        ErrorKind::ImportError(format!("{} at byte {}", message, self.position)).into()
+    }
+
+    /// Skip past the next occurrence of `end`
+    fn skip_past(&mut self, end: &str) -> Result<()> {
+        match self.text[self.position..].find(end) {
+            Some(offset) => {
+                self.position += offset + end.len();
+                Ok(())
+            }
+            None => Err(self.error("unterminated markup")),
+        }
+    }
+
+    fn tag(&mut self) -> Result<Event<'a>> {
+        let text = self.text;
+        let end = match text[self.position..].find('>') {
+            Some(offset) => self.position + offset,
+            None => return Err(self.error("unterminated tag")),
+        };
+        let mut tag = &text[self.position + 1..end];
+        self.position = end + 1;
+        if let Some(name) = tag.strip_prefix('/') {
+            return Ok(Event::End(local_name(name.trim())));
+        }
+        let self_closing = tag.ends_with('/');
+        if self_closing {
+            tag = &tag[..tag.len() - 1];
+        }
+        let name_end = tag
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(tag.len());
+        let name = local_name(&tag[..name_end]);
+        if name.is_empty() {
+            return Err(self.error("tag without a name"));
+        }
+        let attributes = attributes(&tag[name_end..]).ok_or_else(|| self.error("bad attribute"))?;
+        if self_closing {
+            self.pending_end = Some(name);
+        }
+        Ok(Event::Start(name, attributes))
+    }
+}
+
+impl<'a> Iterator for Reader<'a> {
+    type Item = Result<Event<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(name) = self.pending_end.take() {
+            return Some(Ok(Event::End(name)));
+        }
+        loop {
+            let rest = &self.text[self.position..];
+            if rest.is_empty() {
+                return None;
+            }
+            if rest.starts_with("<!--") {
+                if let Err(e) = self.skip_past("-->") {
+                    return Some(Err(e));
+                }
+            } else if rest.starts_with("<![CDATA[") {
+                let start = self.position + 9;
+                if let Err(e) = self.skip_past("]]>") {
+                    return Some(Err(e));
+                }
+                let data = &self.text[start..self.position - 3];
+                if !data.trim().is_empty() {
+                    return Some(Ok(Event::Text(data.trim().to_string())));
+                }
+            } else if rest.starts_with("<?") || rest.starts_with("<!") {
+                if let Err(e) = self.skip_past(">") {
+                    return Some(Err(e));
+                }
+            } else if rest.starts_with('<') {
+                return Some(self.tag());
+            } else {
+                let end = rest.find('<').unwrap_or(rest.len());
+                self.position += end;
+                let text = rest[..end].trim();
+                if !text.is_empty() {
+                    return Some(Ok(Event::Text(unescape(text))));
+                }
+            }
+        }
+    }
+}
+
+/// Strip the namespace prefix of a name
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// Parse the `name="value"` pairs of a tag, `None` if they are malformed
+fn attributes(mut text: &str) -> Option<Vec<(&str, String)>> {
+    let mut attributes = Vec::new();
+    loop {
+        text = text.trim_start();
+        if text.is_empty() {
+            return Some(attributes);
+        }
+        let equals = text.find('=')?;
+        let name = text[..equals].trim();
+        let rest = text[equals + 1..].trim_start();
+        let quote = rest.chars().next().filter(|&c| c == '"' || c == '\'')?;
+        let end = rest[1..].find(quote)? + 1;
+        attributes.push((local_name(name), unescape(&rest[1..end])));
+        text = &rest[end + 1..];
+    }
+}
+
+/// Resolve the predefined and numeric character entities, unknown entities are kept as they are
+pub(crate) fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let resolved = entity.and_then(|(entity, end)| {
+            let c = match entity {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                _ => {
+                    let code = match entity.strip_prefix("#x") {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => entity.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end))
+        });
+        match resolved {
+            Some((c, end)) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
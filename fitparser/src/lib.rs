@@ -2,14 +2,14 @@
 //!
 //! `fitparser` is a utility to parse an ANT FIT file based on a given profile into a more
 //! useful form for consuming applications. To that end the [serde](https://github.com/serde-rs/serde)
-//! framework is used to allow the data to be serialized into any format supported by serde. This
-//! library currently does not support writing FIT files.
+//! framework is used to allow the data to be serialized into any format supported by serde. The
+//! `ser` module encodes records back into FIT messages.
 //!
 //! ## Example
-//! Open a file or pass in any other object that implements the Read
-//! trait. A vector of data records is returned if deserialization is
-//! successful. See the `fit_to_json` example for a command line utility
-//! that parses FIT files and exports them as JSON.
+//! Open a file or pass in any other object that implements the Read trait, a path or a byte
+//! slice. A vector of data records is returned if deserialization is successful. Every entry
+//! point has a `_with_options` variant accepting decode options. See the `fit_to_json` example
+//! for a command line utility that parses FIT files and exports them as JSON.
 //! ```
 //! use fitparser;
 //! use fitparser::de::{DecodeOption, from_reader_with_options};
@@ -32,31 +32,108 @@
 //!     println!("{:#?}", data);
 //! }
 //!
+//! // Or decode straight from a path
+//! let records = fitparser::from_path_with_options("tests/fixtures/Activity.fit", &opts)?;
+//! println!("{} records", records.len());
+//!
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
+//!
+//! ## Output ordering
+//! Decoding the same bytes with the same options always produces the same output. Records are
+//! returned in the order their data messages appear in the file, chained files one after the
+//! other unless they are decoded into a `FitFile` each with `files_from_bytes`. The fields of a
+//! record are ordered by their definition number, fields expanded from components are placed by
+//! their own number, followed by the timestamp of a compressed timestamp header and finally
+//! developer fields in the order of the definition message.
+//!
+//! ## Features
+//! * `tracing` - emit [tracing](https://github.com/tokio-rs/tracing) spans and events while
+//!   decoding (file headers, definition messages, progress every 1000 data messages and errors)
+//!   so services can correlate slow or failing decodes with specific uploads.
+//! * `json` (default) - write records as JSON with `export::Format::Json`, persist a
+//!   `library::LibraryIndex` and import activity streams with `import::streams`.
+//! * `fixtures` - expose the `fixtures` module to build synthetic FIT files in tests.
+//! * `templates` - expose `export::template` to render records through user supplied templates.
+//! * `wasm` - expose `wasm::parse_fit_to_json` to JavaScript through `wasm-bindgen` for builds
+//!   targeting `wasm32-unknown-unknown`, timestamps are kept in UTC there.
+//! * `time` - back timestamp values with the [time](https://docs.rs/time) crate instead of
+//!   chrono, see the `timestamp` module.
 #![warn(missing_docs)]
-use chrono::{DateTime, Local};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::convert;
 use std::fmt;
+use std::time::Duration;
+use timestamp::{LocalTimestamp, Timestamp, UnixSeconds, UtcTimestamp};
 
+pub mod analysis;
+pub mod batch;
 pub mod de;
+mod deserialize;
+pub mod edit;
 mod error;
+pub mod export;
+pub mod filter;
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
+pub mod format;
+mod geo;
+pub mod import;
+pub mod library;
+pub mod monitoring;
 pub mod profile;
+pub mod recorder;
+pub mod repair;
+pub mod ser;
+pub mod settings;
+pub mod timestamp;
+pub mod validate;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-pub use de::{from_bytes, from_reader};
-pub use error::{Error, ErrorKind, Result};
+pub use de::{
+    files_from_bytes, files_from_bytes_with_options, from_bytes, from_bytes_borrowed,
+    from_bytes_with_crc_warnings, from_bytes_with_options, from_bytes_with_summary, from_path,
+    from_path_with_options, from_reader, from_reader_with_options, is_fit, sniff, CrcPolicy,
+    DecodeOption, DecoderContext, FitFile, FitFileSummary, FitHeaderInfo,
+};
+#[cfg(feature = "async")]
+pub use de::{from_async_reader, from_async_reader_with_options};
+pub use error::{Error, ErrorContext, ErrorKind, Result};
 
 /// Defines a set of data derived from a FIT Data message.
 #[derive(Clone, Debug, Serialize)]
 pub struct FitDataRecord {
     /// The kind of message the data came from, the FIT profile defines several messages and
     /// custom messages can be defined by altering the profile
+    #[serde(serialize_with = "serialize_kind")]
     kind: profile::MesgNum,
     /// All the fields present in this message, a record may not have every possible field defined
     fields: Vec<FitDataField>,
 }
 
+/// Check if a message number isn't a message defined by the FIT profile
+fn is_unknown_kind(kind: profile::MesgNum) -> bool {
+    matches!(
+        kind,
+        profile::MesgNum::MfgRangeMin | profile::MesgNum::MfgRangeMax
+    ) || !profile::MesgNum::is_named_variant(kind.as_i64())
+}
+
+/// Serialize the message name, falling back to the global message number for messages the
+/// profile doesn't define so the original number always survives serialization
+fn serialize_kind<S>(kind: &profile::MesgNum, serializer: S) -> std::result::Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    if is_unknown_kind(*kind) {
+        serializer.serialize_u16(kind.as_u16())
+    } else {
+        kind.serialize(serializer)
+    }
+}
+
 impl FitDataRecord {
     /// Create an empty data record with a given kind
     pub fn new(kind: profile::MesgNum) -> Self {
@@ -66,16 +143,56 @@ impl FitDataRecord {
         }
     }
 
-    /// Return the kind of FitDataRecord, this value is defined by the FIT profile.
+    /// Return the kind of FitDataRecord, this value is defined by the FIT profile. Messages that
+    /// aren't part of the profile use the `MesgNum::Value` variant holding the global number.
     pub fn kind(&self) -> profile::MesgNum {
         self.kind
     }
 
+    /// Return the global message number the record was decoded from
+    pub fn global_message_number(&self) -> u16 {
+        self.kind.as_u16()
+    }
+
+    /// Check if the message number of the record is not defined in the FIT profile. The bounds
+    /// of the manufacturer specific range are named by the profile but aren't messages, so
+    /// records using them count as unknown.
+    pub fn is_unknown(&self) -> bool {
+        is_unknown_kind(self.kind)
+    }
+
+    /// Check if the record is of the given kind
+    pub fn is_kind(&self, kind: profile::MesgNum) -> bool {
+        self.kind == kind
+    }
+
     /// Get all fields as a slice
     pub fn fields(&self) -> &[FitDataField] {
         &self.fields
     }
 
+    /// Get mutable access to the field vector for in place editing
+    pub fn fields_mut(&mut self) -> &mut Vec<FitDataField> {
+        &mut self.fields
+    }
+
+    /// Return the first field with the given name if it exists
+    pub fn field(&self, name: &str) -> Option<&FitDataField> {
+        self.fields.iter().find(|f| f.name() == name)
+    }
+
+    /// Return the first field with the given definition number if it exists. Unlike names,
+    /// which can change between profile versions, definition numbers are stable.
+    pub fn field_by_number(&self, number: u8) -> Option<&FitDataField> {
+        self.fields.iter().find(|f| f.number == number)
+    }
+
+    /// Return every field with the given definition number, component expansion and subfields
+    /// can produce more than one field per number
+    pub fn fields_by_number(&self, number: u8) -> impl Iterator<Item = &FitDataField> {
+        self.fields.iter().filter(move |f| f.number == number)
+    }
+
     /// Add a field to the record
     pub fn push(&mut self, field: FitDataField) {
         self.fields.push(field)
@@ -90,6 +207,82 @@ impl FitDataRecord {
     pub fn into_vec(self) -> Vec<FitDataField> {
         self.fields
     }
+
+    /// Flatten the record into a map of field name to value, fields sharing a name are stored
+    /// under `name_1`, `name_2`, ... in the order they appear in the record. See `to_map_with`
+    /// for other ways of handling fields that share a name.
+    pub fn to_map(&self) -> BTreeMap<String, Value> {
+        self.to_map_with(FieldCollision::default())
+    }
+
+    /// Flatten the record into a map of field name to value, using the collision policy for
+    /// fields that share a name
+    pub fn to_map_with(&self, collision: FieldCollision) -> BTreeMap<String, Value> {
+        let mut map = BTreeMap::new();
+        for field in &self.fields {
+            let name = field.name.clone();
+            let value = field.value.clone();
+            match collision {
+                FieldCollision::KeepFirst => {
+                    map.entry(name).or_insert(value);
+                }
+                FieldCollision::KeepLast => {
+                    map.insert(name, value);
+                }
+                FieldCollision::Suffix => {
+                    let mut key = name.clone();
+                    let mut index = 0;
+                    while map.contains_key(&key) {
+                        index += 1;
+                        key = format!("{}_{}", name, index);
+                    }
+                    map.insert(key, value);
+                }
+                FieldCollision::Array => match map.get_mut(&name) {
+                    Some(Value::Array(values)) => values.push(value),
+                    Some(existing) => {
+                        let first = std::mem::replace(existing, Value::Array(Vec::new()));
+                        *existing = Value::Array(vec![first, value]);
+                    }
+                    None => {
+                        map.insert(name, value);
+                    }
+                },
+            }
+        }
+        map
+    }
+}
+
+/// How `FitDataRecord::to_map_with` handles several fields sharing the same name
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FieldCollision {
+    /// Keep the value of the first field with the name
+    KeepFirst,
+    /// Keep the value of the last field with the name
+    KeepLast,
+    /// Store later fields under the name followed by `_1`, `_2`, ...
+    #[default]
+    Suffix,
+    /// Collect the values of all fields with the name into a `Value::Array`, note that a field
+    /// whose value already is an array gets further values appended to it
+    Array,
+}
+
+/// Iterate over the records of a single kind
+pub fn records_of_kind(
+    records: &[FitDataRecord],
+    kind: profile::MesgNum,
+) -> impl Iterator<Item = &FitDataRecord> {
+    records.iter().filter(move |r| r.kind == kind)
+}
+
+/// Iterate over the records whose kind is one of the provided kinds
+pub fn records_of_kinds<'a>(
+    records: &'a [FitDataRecord],
+    kinds: &'a [profile::MesgNum],
+) -> impl Iterator<Item = &'a FitDataRecord> {
+    records.iter().filter(move |r| kinds.contains(&r.kind))
 }
 
 /// Stores a value and it's defined units which are set by the FIT profile during decoding
@@ -99,6 +292,43 @@ pub struct FitDataField {
     number: u8,
     value: Value,
     units: String,
+    /// Set when the field definition number isn't defined in the FIT profile for the message
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    unknown: bool,
+    /// Metadata for developer fields, `None` for fields defined by the FIT profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    developer: Option<Box<DeveloperFieldInfo>>,
+    /// Value stored in the file before the profile was applied, only kept when decoding with
+    /// `DecodeOption::KeepRawValues`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    raw_value: Option<Box<Value>>,
+}
+
+/// Describes where a developer field came from and how it relates to the FIT profile, built from
+/// the `developer_data_id` and `field_description` messages that define the field
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeveloperFieldInfo {
+    /// Index linking the field to its `developer_data_id` message
+    pub developer_data_index: u8,
+    /// Application UUID of the Connect IQ app or device that recorded the field
+    pub application_id: Option<String>,
+    /// Field name from the field description
+    pub field_name: String,
+    /// Units from the field description
+    pub units: String,
+    /// Message the field provides data for, if it maps onto a native profile field
+    pub native_mesg_num: Option<profile::MesgNum>,
+    /// Native field definition number the developer field can stand in for
+    pub native_field_num: Option<u8>,
+    /// Base type the values are stored with, as the `fit_base_type_id` of the field description
+    #[serde(default)]
+    pub base_type: Option<u8>,
+    /// Scale the stored values were divided by, if the field description declares one
+    #[serde(default)]
+    pub scale: Option<f64>,
+    /// Offset subtracted from the scaled values, if the field description declares one
+    #[serde(default)]
+    pub offset: Option<f64>,
 }
 
 impl FitDataField {
@@ -109,6 +339,9 @@ impl FitDataField {
             number,
             value,
             units,
+            unknown: false,
+            developer: None,
+            raw_value: None,
         }
     }
 
@@ -122,16 +355,101 @@ impl FitDataField {
         self.number
     }
 
+    /// Return the field definition number, this is the stable key for a field and should be
+    /// preferred over the name when data is stored long term
+    pub fn field_def_number(&self) -> u8 {
+        self.number
+    }
+
+    /// Check if the field definition number was unknown to the FIT profile, the value of these
+    /// fields is the raw data without any scaling or units applied
+    pub fn is_unknown(&self) -> bool {
+        self.unknown
+    }
+
+    /// Mark the field as known or unknown to the FIT profile
+    pub fn set_unknown(&mut self, unknown: bool) {
+        self.unknown = unknown;
+    }
+
+    /// Return the developer field metadata if this is a developer field
+    pub fn developer_info(&self) -> Option<&DeveloperFieldInfo> {
+        self.developer.as_deref()
+    }
+
+    /// Check if the field was defined by a developer rather than the FIT profile
+    pub fn is_developer_field(&self) -> bool {
+        self.developer.is_some()
+    }
+
+    /// Attach developer field metadata to the field
+    pub fn set_developer_info(&mut self, info: Option<DeveloperFieldInfo>) {
+        self.developer = info.map(Box::new);
+    }
+
     /// Return a reference to the stored value
     pub fn value(&self) -> &Value {
         &self.value
     }
 
+    /// Replace the stored value, the units are kept as is and the raw value is dropped since
+    /// it no longer matches the value
+    pub fn set_value(&mut self, value: Value) {
+        self.value = value;
+        self.raw_value = None;
+    }
+
+    /// Return the value stored in the file before the scale, offset and any other conversion of
+    /// the profile were applied, `None` unless decoded with `DecodeOption::KeepRawValues`
+    pub fn raw_value(&self) -> Option<&Value> {
+        self.raw_value.as_deref()
+    }
+
+    /// Set the value stored in the file, the encoder writes it in place of the value when set
+    pub fn set_raw_value(&mut self, raw_value: Option<Value>) {
+        self.raw_value = raw_value.map(Box::new);
+    }
+
     /// Return units associated with the value
     pub fn units(&self) -> &str {
         &self.units
     }
 
+    /// Return the units parsed into a `Unit`, `None` if the field has no units or they aren't
+    /// used by the FIT profile, e.g. units of developer fields
+    pub fn unit(&self) -> Option<profile::Unit> {
+        profile::Unit::from_units(&self.units)
+    }
+
+    /// Return the value as a span of time, either a `Value::Duration` or a number of seconds or
+    /// milliseconds going by the units. `None` for other values and negative numbers.
+    pub fn duration(&self) -> Option<Duration> {
+        match &self.value {
+            Value::Duration(val) => Some(*val),
+            Value::Array(_)
+            | Value::String(_)
+            | Value::Timestamp(_)
+            | Value::UtcTimestamp(_)
+            | Value::LocalTimestamp(_) => None,
+            value => {
+                let seconds_per_unit = profile::seconds_per_unit(&self.units)?;
+                let value: f64 = value.clone().try_into().ok()?;
+                Duration::try_from_secs_f64(value * seconds_per_unit).ok()
+            }
+        }
+    }
+
+    /// Return a coordinate in decimal degrees, converting values measured in semicircles. `None`
+    /// for fields measured in other units and values that aren't numbers.
+    pub fn degrees(&self) -> Option<f64> {
+        let value: f64 = self.value.clone().try_into().ok()?;
+        match self.units.as_str() {
+            "degrees" => Some(value),
+            "semicircles" => Some(geo::semicircles_to_degrees(value)),
+            _ => None,
+        }
+    }
+
     /// Consume the field and return the value
     pub fn into_value(self) -> Value {
         self.value
@@ -140,20 +458,45 @@ impl FitDataField {
 
 impl fmt::Display for FitDataField {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.units.is_empty() {
-            write!(f, "{}", self.value)
-        } else {
-            write!(f, "{} {}", self.value, self.units)
-        }
+        let text = format::default_registry().format(&self.value, &self.units);
+        f.write_str(&text)
     }
 }
 
+fn serialize_duration<S>(duration: &Duration, serializer: S) -> std::result::Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
 /// Contains arbitrary data in the defined format.
 #[derive(Clone, Debug, PartialEq, PartialOrd, Serialize)]
 #[serde(untagged)]
 pub enum Value {
     /// Timestamp field converted to the local timezone
-    Timestamp(DateTime<Local>),
+    #[cfg_attr(
+        feature = "time",
+        serde(serialize_with = "timestamp::serialize_timestamp")
+    )]
+    Timestamp(Timestamp),
+    /// Timestamp field kept in UTC, only returned when decoding with
+    /// `DecodeOption::UtcTimestamps`
+    #[cfg_attr(
+        feature = "time",
+        serde(serialize_with = "timestamp::serialize_timestamp")
+    )]
+    UtcTimestamp(UtcTimestamp),
+    /// Wall clock time of a `local_date_time` field without a time zone, only returned when
+    /// decoding with `DecodeOption::NaiveLocalTimes`
+    #[cfg_attr(
+        feature = "time",
+        serde(serialize_with = "timestamp::serialize_wall_clock")
+    )]
+    LocalTimestamp(LocalTimestamp),
+    /// Span of time of a field measured in seconds or milliseconds, only returned when decoding
+    /// with `DecodeOption::ReturnDurations`. Serialized as a number of seconds.
+    Duration(#[serde(serialize_with = "serialize_duration")] Duration),
     /// Unsigned 8bit integer data
     Byte(u8), // TODO: I think this should actually be a Vec<u8> type
     /// Unsigned 8bit integer that gets mapped to a FieldType enum
@@ -197,6 +540,9 @@ impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self {
             Value::Timestamp(val) => write!(f, "{}", val),
+            Value::UtcTimestamp(val) => write!(f, "{}", val),
+            Value::LocalTimestamp(val) => write!(f, "{}", val),
+            Value::Duration(val) => write!(f, "{}", val.as_secs_f64()),
             Value::Byte(val) => write!(f, "{}", val),
             Value::Enum(val) => write!(f, "{}", val),
             Value::SInt8(val) => write!(f, "{}", val),
@@ -224,7 +570,10 @@ impl convert::TryInto<f64> for Value {
 
     fn try_into(self) -> Result<f64> {
         match self {
-            Value::Timestamp(val) => Ok(val.timestamp() as f64),
+            Value::Timestamp(val) => Ok(val.unix_seconds() as f64),
+            Value::UtcTimestamp(val) => Ok(val.unix_seconds() as f64),
+            Value::LocalTimestamp(val) => Ok(val.unix_seconds() as f64),
+            Value::Duration(val) => Ok(val.as_secs_f64()),
             Value::Byte(val) => Ok(val as f64),
             Value::Enum(val) => Ok(val as f64),
             Value::SInt8(val) => Ok(val as f64),
@@ -256,7 +605,10 @@ impl convert::TryInto<i64> for Value {
 
     fn try_into(self) -> Result<i64> {
         match self {
-            Value::Timestamp(val) => Ok(val.timestamp()),
+            Value::Timestamp(val) => Ok(val.unix_seconds()),
+            Value::UtcTimestamp(val) => Ok(val.unix_seconds()),
+            Value::LocalTimestamp(val) => Ok(val.unix_seconds()),
+            Value::Duration(val) => Ok(val.as_secs() as i64),
             Value::Byte(val) => Ok(val as i64),
             Value::Enum(val) => Ok(val as i64),
             Value::SInt8(val) => Ok(val as i64),
@@ -292,7 +644,10 @@ impl convert::TryInto<i64> for &Value {
 
     fn try_into(self) -> Result<i64> {
         match self {
-            Value::Timestamp(val) => Ok(val.timestamp()),
+            Value::Timestamp(val) => Ok(val.unix_seconds()),
+            Value::UtcTimestamp(val) => Ok(val.unix_seconds()),
+            Value::LocalTimestamp(val) => Ok(val.unix_seconds()),
+            Value::Duration(val) => Ok(val.as_secs() as i64),
             Value::Byte(val) => Ok(*val as i64),
             Value::Enum(val) => Ok(*val as i64),
             Value::SInt8(val) => Ok(*val as i64),
@@ -323,6 +678,93 @@ impl convert::TryInto<i64> for &Value {
     }
 }
 
+impl Value {
+    /// Convert the value into the variant of a FIT base type, e.g. an `SInt64` into the `UInt8`
+    /// of a `uint8` field, returning an error if it's out of range for the type. Floats are only
+    /// converted into integers if they don't have a fraction, timestamps become seconds since
+    /// the FIT epoch, durations seconds and arrays are converted element by element. Strings
+    /// can't be converted into or from any other type.
+    pub fn coerce_to(&self, base_type: de::BaseType) -> Result<Value> {
+        use de::BaseType;
+
+        let err = || -> Error {
+            ErrorKind::ValueError(format!("cannot convert {} into {:?}", self, base_type)).into()
+        };
+        match (self, base_type) {
+            (Value::Array(values), _) => values
+                .iter()
+                .map(|v| v.coerce_to(base_type))
+                .collect::<Result<_>>()
+                .map(Value::Array),
+            (Value::String(val), BaseType::String) => Ok(Value::String(val.clone())),
+            (Value::String(_), _) | (_, BaseType::String) => Err(err()),
+            (
+                Value::Timestamp(_) | Value::UtcTimestamp(_) | Value::LocalTimestamp(_),
+                BaseType::Float32 | BaseType::Float64,
+            ) => Err(err()),
+            (_, BaseType::Float32) => {
+                let val: f64 = self.clone().try_into()?;
+                match val as f32 {
+                    single if single.is_finite() || !val.is_finite() => Ok(Value::Float32(single)),
+                    _ => Err(err()),
+                }
+            }
+            (_, BaseType::Float64) => Ok(Value::Float64(self.clone().try_into()?)),
+            _ => {
+                let value = self.as_integer().ok_or_else(err)?;
+                macro_rules! checked {
+                    ($variant:ident) => {
+                        Value::$variant(value.try_into().map_err(|_| err())?)
+                    };
+                }
+                Ok(match base_type {
+                    BaseType::Enum => checked!(Enum),
+                    BaseType::SInt8 => checked!(SInt8),
+                    BaseType::UInt8 => checked!(UInt8),
+                    BaseType::SInt16 => checked!(SInt16),
+                    BaseType::UInt16 => checked!(UInt16),
+                    BaseType::SInt32 => checked!(SInt32),
+                    BaseType::UInt32 => checked!(UInt32),
+                    BaseType::UInt8z => checked!(UInt8z),
+                    BaseType::UInt16z => checked!(UInt16z),
+                    BaseType::UInt32z => checked!(UInt32z),
+                    BaseType::Byte => checked!(Byte),
+                    BaseType::SInt64 => checked!(SInt64),
+                    BaseType::UInt64 => checked!(UInt64),
+                    BaseType::UInt64z => checked!(UInt64z),
+                    BaseType::String | BaseType::Float32 | BaseType::Float64 => {
+                        unreachable!("handled above")
+                    }
+                })
+            }
+        }
+    }
+
+    /// Integer value of a number without a fraction, timestamps in seconds since the FIT epoch
+    fn as_integer(&self) -> Option<i128> {
+        let float = |val: f64| (val.is_finite() && val.fract() == 0.0).then_some(val as i128);
+        match self {
+            Value::Timestamp(val) => Some((val.unix_seconds() - ser::FIT_EPOCH_OFFSET).into()),
+            Value::UtcTimestamp(val) => Some((val.unix_seconds() - ser::FIT_EPOCH_OFFSET).into()),
+            Value::LocalTimestamp(val) => Some((val.unix_seconds() - ser::FIT_EPOCH_OFFSET).into()),
+            Value::Duration(val) => float(val.as_secs_f64()),
+            Value::Byte(val) | Value::Enum(val) | Value::UInt8(val) | Value::UInt8z(val) => {
+                Some((*val).into())
+            }
+            Value::SInt8(val) => Some((*val).into()),
+            Value::SInt16(val) => Some((*val).into()),
+            Value::UInt16(val) | Value::UInt16z(val) => Some((*val).into()),
+            Value::SInt32(val) => Some((*val).into()),
+            Value::UInt32(val) | Value::UInt32z(val) => Some((*val).into()),
+            Value::SInt64(val) => Some((*val).into()),
+            Value::UInt64(val) | Value::UInt64z(val) => Some((*val).into()),
+            Value::Float32(val) => float((*val).into()),
+            Value::Float64(val) => float(*val),
+            Value::String(_) | Value::Array(_) => None,
+        }
+    }
+}
+
 /// Describes a field value along with its defined units (if any), this struct is useful for
 /// serializing data in a key-value store where the key is either the name or definition number
 /// since it can be created from a `FitDataField` with minimal data cloning.
@@ -347,17 +789,17 @@ impl convert::From<FitDataField> for ValueWithUnits {
 
 impl fmt::Display for ValueWithUnits {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.units.is_empty() {
-            write!(f, "{}", self.value)
-        } else {
-            write!(f, "{} {}", self.value, self.units)
-        }
+        let text = format::default_registry().format(&self.value, &self.units);
+        f.write_str(&text)
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod tests {
     use super::*;
+    use crate::profile::MesgNum;
+    use serde_json::json;
     use std::collections::HashSet;
 
     #[test]
@@ -367,11 +809,183 @@ mod tests {
         assert_eq!(fit_data.len(), 22);
     }
 
+    #[test]
+    fn filter_records_by_kind() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit").to_vec();
+        let fit_data = from_bytes(&data).unwrap();
+        assert_eq!(records_of_kind(&fit_data, MesgNum::Record).count(), 14);
+        assert!(records_of_kind(&fit_data, MesgNum::Lap).all(|r| r.is_kind(MesgNum::Lap)));
+        let summaries = records_of_kinds(&fit_data, &[MesgNum::Session, MesgNum::Activity]);
+        assert_eq!(summaries.count(), 2);
+    }
+
+    #[test]
+    fn decode_with_file_type_preset() {
+        use crate::de::{file_type, from_bytes_with_preset, CrcPolicy};
+        use crate::profile::field_types::File;
+        let data = include_bytes!("../tests/fixtures/Activity.fit").to_vec();
+        assert_eq!(file_type(&data).unwrap(), Some(File::Activity));
+        let data = include_bytes!("../tests/fixtures/Settings.fit").to_vec();
+        assert_eq!(file_type(&data).unwrap(), Some(File::Settings));
+        let data = include_bytes!("../tests/fixtures/WorkoutRepeatSteps.fit").to_vec();
+        assert_eq!(file_type(&data).unwrap(), Some(File::Workout));
+        let fit_data = from_bytes_with_preset(&data).unwrap();
+        assert!(fit_data.iter().all(|r| !r.is_unknown()));
+
+        let data = include_bytes!("../tests/fixtures/garmin-fenix-5-bike.fit").to_vec();
+        let fit_data = from_bytes_with_preset(&data).unwrap();
+        assert!(fit_data.iter().all(|r| !r.is_unknown()));
+        let session = records_of_kind(&fit_data, MesgNum::Session).next().unwrap();
+        assert!(matches!(
+            session.field("total_elapsed_time").unwrap().value(),
+            Value::Duration(_)
+        ));
+        let record = records_of_kind(&fit_data, MesgNum::Record).next().unwrap();
+        assert_eq!(record.field("position_lat").unwrap().units(), "degrees");
+
+        let presets = [File::Activity, File::Workout, File::Settings].map(DecodeOption::preset);
+        assert!(presets[0].contains(&DecodeOption::CrcPolicy(CrcPolicy::WarnOnly)));
+        assert!(presets[1].contains(&DecodeOption::KeepRawValues));
+        assert!(presets[2].contains(&DecodeOption::KeepCompositeFields));
+    }
+
+    #[test]
+    fn entry_point_parity() {
+        let path = "tests/fixtures/Activity.fit";
+        let data = std::fs::read(path).unwrap();
+        let options = [DecodeOption::DropUnknownMessages]
+            .iter()
+            .copied()
+            .collect();
+        let from_bytes = from_bytes_with_options(&data, &options).unwrap();
+        let from_reader = from_reader_with_options(&mut data.as_slice(), &options).unwrap();
+        let from_path = from_path_with_options(path, &options).unwrap();
+        assert_eq!(from_bytes.len(), from_reader.len());
+        assert_eq!(from_bytes.len(), from_path.len());
+        assert_eq!(crate::from_path(path).unwrap().len(), 22);
+        assert!(matches!(
+            *crate::from_path("tests/fixtures/missing.fit").unwrap_err(),
+            ErrorKind::Io(_)
+        ));
+    }
+
+    #[test]
+    fn flatten_record_to_map() {
+        let mut record = FitDataRecord::new(MesgNum::Record);
+        record.push(FitDataField::new(
+            "speed".to_string(),
+            6,
+            Value::Float64(2.5),
+            "m/s".to_string(),
+        ));
+        for value in [1, 2] {
+            record.push(FitDataField::new(
+                "unknown_field_250".to_string(),
+                250,
+                Value::UInt8(value),
+                String::new(),
+            ));
+        }
+
+        let map = record.to_map();
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["speed"], Value::Float64(2.5));
+        assert_eq!(map["unknown_field_250"], Value::UInt8(1));
+        assert_eq!(map["unknown_field_250_1"], Value::UInt8(2));
+
+        let map = record.to_map_with(FieldCollision::KeepLast);
+        assert_eq!(map["unknown_field_250"], Value::UInt8(2));
+        let map = record.to_map_with(FieldCollision::Array);
+        assert_eq!(
+            map["unknown_field_250"],
+            Value::Array(vec![Value::UInt8(1), Value::UInt8(2)])
+        );
+    }
+
+    #[test]
+    fn repeated_field_definitions() {
+        // definition: record message with timestamp and heart_rate listed twice, a data message
+        // and a zeroed CRC since validation is skipped
+        let mut data = vec![12, 0x10, 0x54, 0x08, 0, 0, 0, 0, b'.', b'F', b'I', b'T'];
+        let records = [
+            0x40, 0, 0, 20, 0, 3, 253, 4, 0x86, 3, 1, 0x02, 3, 1, 0x02, // definition
+            0x00, 0x00, 0x00, 0x00, 0x40, 120, 125, // data
+        ];
+        data[4..8].copy_from_slice(&(records.len() as u32).to_le_bytes());
+        data.extend_from_slice(&records);
+        data.extend_from_slice(&[0, 0]);
+
+        let options = [
+            DecodeOption::SkipHeaderCrcValidation,
+            DecodeOption::SkipDataCrcValidation,
+        ]
+        .iter()
+        .copied()
+        .collect();
+        let fit_data = from_bytes_with_options(&data, &options).unwrap();
+        assert_eq!(fit_data.len(), 1);
+        let heart_rate = fit_data[0].field("heart_rate").unwrap();
+        assert_eq!(
+            heart_rate.value(),
+            &Value::Array(vec![Value::UInt8(120), Value::UInt8(125)])
+        );
+        assert_eq!(heart_rate.units(), "bpm");
+    }
+
+    #[test]
+    fn lookup_fields_by_number() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit").to_vec();
+        let fit_data = from_bytes(&data).unwrap();
+        let file_id = &fit_data[0];
+        let field = file_id.field_by_number(4).unwrap();
+        assert_eq!(field.name(), "time_created");
+        assert_eq!(field.field_def_number(), 4);
+        assert!(file_id.field_by_number(200).is_none());
+        assert_eq!(file_id.fields_by_number(4).count(), 1);
+    }
+
+    #[test]
+    fn flag_unknown_records_and_fields() {
+        let data = include_bytes!("../tests/fixtures/garmin-fenix-5-bike.fit").to_vec();
+        let fit_data = from_bytes(&data).unwrap();
+        assert!(fit_data.iter().any(|r| r.is_unknown()));
+        assert!(fit_data
+            .iter()
+            .filter(|r| r.is_unknown())
+            .all(|r| matches!(r.kind(), MesgNum::Value(_))));
+        let unknown_fields = fit_data
+            .iter()
+            .flat_map(|r| r.fields())
+            .filter(|f| f.is_unknown());
+        assert!(
+            unknown_fields
+                .inspect(|f| assert!(f.name().starts_with("unknown_field_")))
+                .count()
+                > 0
+        );
+        assert!(!fit_data[0].is_unknown());
+    }
+
     #[test]
     fn parse_developer_data() {
         let data = include_bytes!("../tests/fixtures/DeveloperData.fit").to_vec();
         let fit_data = from_bytes(&data).unwrap();
         assert_eq!(fit_data.len(), 6);
+
+        let field = fit_data[3].field("doughnuts_earned").unwrap();
+        assert_eq!(field.units(), "doughnuts");
+        assert_eq!(field.unit(), None);
+        let info = field.developer_info().unwrap();
+        assert_eq!(info.developer_data_index, 0);
+        assert_eq!(
+            info.application_id.as_deref(),
+            Some("01010203-0508-0d15-2237-5990e97962db")
+        );
+        assert_eq!(info.native_field_num, None);
+        assert!(!fit_data[3]
+            .field("heart_rate")
+            .unwrap()
+            .is_developer_field());
     }
 
     #[test]
@@ -485,7 +1099,7 @@ mod tests {
                 "This test should fail without the SkipHeaderCrcValidation option."
             ),
             Err(e) => match *e {
-                ErrorKind::InvalidCrc(..) => {}
+                ErrorKind::InvalidCrc(..) => assert_eq!(e.offset(), Some(0)),
                 _ => assert!(false, "Incorrect error returned {:?}", e),
             },
         }
@@ -510,7 +1124,7 @@ mod tests {
                 "This test should fail without the SkipDataCrcValidation option."
             ),
             Err(e) => match *e {
-                ErrorKind::InvalidCrc(..) => {}
+                ErrorKind::InvalidCrc(..) => assert_eq!(e.offset(), Some(leng - 2)),
                 _ => assert!(false, "Incorrect error returned {:?}", e),
             },
         }
@@ -520,4 +1134,186 @@ mod tests {
         let fit_data = de::from_bytes_with_options(&data, &options).unwrap();
         assert_eq!(fit_data.len(), 355);
     }
+
+    #[test]
+    fn truncated_file_error_context() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit").to_vec();
+        let err = from_bytes(&data[..100]).unwrap_err();
+        match *err {
+            ErrorKind::UnexpectedEof(_, ctx) => {
+                assert!(ctx.offset > 14 && ctx.offset < 100);
+                assert!(ctx.local_message_number.is_some());
+            }
+            _ => panic!("Incorrect error returned {:?}", err),
+        }
+        assert!(err.to_string().contains("local message number"));
+    }
+
+    #[test]
+    fn field_number_constants() {
+        use crate::profile::field_numbers::{file_id, record};
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let fit_data = from_bytes(data).unwrap();
+        assert_eq!(fit_data[0].field("type").unwrap().number(), file_id::TYPE);
+        let sample = fit_data
+            .iter()
+            .find(|r| r.kind() == MesgNum::Record && r.field("timestamp").is_some())
+            .unwrap();
+        assert_eq!(
+            sample.field("timestamp").unwrap().number(),
+            record::TIMESTAMP
+        );
+    }
+
+    #[test]
+    fn parse_field_units() {
+        use crate::profile::{Unit, UnitCategory};
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let fit_data = from_bytes(data).unwrap();
+        let session = fit_data
+            .iter()
+            .find(|r| r.kind() == MesgNum::Session)
+            .unwrap();
+        let elapsed = session.field("total_elapsed_time").unwrap().unit().unwrap();
+        assert_eq!(elapsed, Unit::Seconds);
+        assert_eq!(elapsed.category(), UnitCategory::Time);
+        assert_eq!(session.field("sport").unwrap().unit(), None);
+        assert_eq!(Unit::from_units("mps"), Some(Unit::MetersPerSecond));
+        #[cfg(not(compact_profile))]
+        {
+            assert_eq!(elapsed.to_string(), "s");
+            assert_eq!(Unit::MetersPerSecond.as_str(), "m/s");
+        }
+    }
+
+    #[test]
+    fn skip_scale_and_offset_per_field() {
+        use crate::profile::field_numbers::session;
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let options = [DecodeOption::SkipScaleAndOffset(
+            MesgNum::Session,
+            session::TOTAL_ELAPSED_TIME,
+        )]
+        .iter()
+        .copied()
+        .collect();
+        let scaled = from_bytes(data).unwrap();
+        let raw = from_bytes_with_options(data, &options).unwrap();
+        let session = |records: &[FitDataRecord]| {
+            records
+                .iter()
+                .find(|r| r.kind() == MesgNum::Session)
+                .cloned()
+                .unwrap()
+        };
+        let (scaled, raw) = (session(&scaled), session(&raw));
+        let elapsed: f64 = scaled
+            .field("total_elapsed_time")
+            .unwrap()
+            .value()
+            .clone()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            raw.field("total_elapsed_time").unwrap().value(),
+            &Value::UInt32((elapsed * 1000.0).round() as u32)
+        );
+        assert_eq!(
+            raw.field("total_timer_time").unwrap().value(),
+            scaled.field("total_timer_time").unwrap().value()
+        );
+    }
+
+    #[test]
+    fn keep_unknown_message_numbers() {
+        let data = fixtures::FitFileBuilder::new()
+            .message(MesgNum::FileId, &[(0, Value::Enum(4))])
+            .message(MesgNum::Value(65300), &[(0, Value::UInt8(1))])
+            .message(MesgNum::MfgRangeMin, &[(0, Value::UInt8(2))])
+            .build();
+        let fit_data = from_bytes(&data).unwrap();
+        let numbers: Vec<u16> = fit_data.iter().map(|r| r.global_message_number()).collect();
+        assert_eq!(numbers, [0, 65300, 65280]);
+        assert!(!fit_data[0].is_unknown());
+        assert!(fit_data[1].is_unknown() && fit_data[2].is_unknown());
+        let kinds: Vec<serde_json::Value> = fit_data
+            .iter()
+            .map(|r| serde_json::to_value(r).unwrap()["kind"].clone())
+            .collect();
+        assert_eq!(kinds, [json!("file_id"), json!(65300), json!(65280)]);
+    }
+
+    #[test]
+    fn deterministic_output() {
+        let data = include_bytes!("../tests/fixtures/garmin-fenix-5-bike.fit");
+        let expected = serde_json::to_string(&from_bytes(data).unwrap()).unwrap();
+        // each decode uses fresh hash maps with their own random iteration order
+        for _ in 0..10 {
+            let fit_data = from_bytes(data).unwrap();
+            assert_eq!(serde_json::to_string(&fit_data).unwrap(), expected);
+            for record in &fit_data {
+                let numbers: Vec<u8> = record
+                    .fields()
+                    .iter()
+                    .filter(|f| !f.is_developer_field())
+                    .map(|f| f.number())
+                    .collect();
+                assert!(numbers.windows(2).all(|w| w[0] <= w[1]));
+            }
+        }
+    }
+
+    /// Reader returning a few bytes per call like a slow unbuffered source
+    struct Trickle<'a>(&'a [u8]);
+
+    impl std::io::Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(self.0.len()).min(7);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn read_unbuffered_source() {
+        let data = include_bytes!("../tests/fixtures/sample_mulitple_header.fit");
+        let fit_data = from_reader(&mut Trickle(data)).unwrap();
+        assert_eq!(
+            serde_json::to_value(&fit_data).unwrap(),
+            serde_json::to_value(from_bytes(data).unwrap()).unwrap()
+        );
+        assert!(from_reader(&mut Trickle(&data[..5])).is_err());
+    }
+
+    #[test]
+    fn coerce_values() {
+        use crate::de::BaseType;
+        assert_eq!(
+            Value::SInt64(42).coerce_to(BaseType::UInt8).unwrap(),
+            Value::UInt8(42)
+        );
+        assert!(Value::SInt64(300).coerce_to(BaseType::UInt8).is_err());
+        assert!(Value::SInt64(-1).coerce_to(BaseType::UInt32).is_err());
+        assert_eq!(
+            Value::Float64(12.0).coerce_to(BaseType::SInt16).unwrap(),
+            Value::SInt16(12)
+        );
+        assert!(Value::Float64(12.5).coerce_to(BaseType::SInt16).is_err());
+        assert_eq!(
+            Value::UInt16(7).coerce_to(BaseType::Float32).unwrap(),
+            Value::Float32(7.0)
+        );
+        assert!(Value::Float64(1e300).coerce_to(BaseType::Float32).is_err());
+        assert!(Value::String("7".to_string())
+            .coerce_to(BaseType::UInt8)
+            .is_err());
+        assert!(Value::UInt8(7).coerce_to(BaseType::String).is_err());
+        assert_eq!(
+            Value::Array(vec![Value::SInt64(1), Value::Float64(2.0)])
+                .coerce_to(BaseType::UInt16)
+                .unwrap(),
+            Value::Array(vec![Value::UInt16(1), Value::UInt16(2)])
+        );
+    }
 }
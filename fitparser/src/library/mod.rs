@@ -0,0 +1,445 @@
+//! Build a queryable index of an activity library, the starting point of a self-hosted training
+//! log.
+//!
+//! Only the `file_id` and `session` messages of each file are decoded which keeps scanning large
+//! archives fast, and re-scans use the stored content hash and modification time of every file
+//! to only decode files that are new or changed. Files recorded by the same device at the same
+//! time are treated as duplicates, e.g. an activity that was both synced from the watch and
+//! exported from a web service.
+use crate::batch::{LocalStorage, Storage};
+use crate::de::{FitObject, FitStreamProcessor};
+use crate::error::Result;
+use crate::profile::MesgNum;
+use crate::timestamp::to_chrono;
+use crate::{FitDataRecord, Value};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::io::Read;
+#[cfg(feature = "json")]
+use std::io::Write;
+use std::path::Path;
+
+/// Summary of a single `session` message
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionSummary {
+    /// Start time of the session
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<Local>>,
+    /// Sport name, e.g. `cycling`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sport: Option<String>,
+    /// Sub sport name, e.g. `road`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_sport: Option<String>,
+    /// Total elapsed time in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_elapsed_time: Option<f64>,
+    /// Total timer time in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_timer_time: Option<f64>,
+    /// Total distance in meters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_distance: Option<f64>,
+    /// Total ascent in meters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_ascent: Option<f64>,
+    /// Total calories in kcal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_calories: Option<f64>,
+}
+
+/// An indexed FIT file
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LibraryEntry {
+    /// Storage key of the file, the relative path for files on disk
+    pub path: String,
+    /// File type from the `file_id` message, e.g. `activity`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_type: Option<String>,
+    /// Device manufacturer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manufacturer: Option<String>,
+    /// Device product, resolved to the manufacturer specific name when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<String>,
+    /// Device serial number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_number: Option<u32>,
+    /// Time the file was created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_created: Option<DateTime<Local>>,
+    /// Sessions contained in the file
+    pub sessions: Vec<SessionSummary>,
+}
+
+impl LibraryEntry {
+    /// Time the activity started, the first session start time or the file creation time
+    pub fn start_time(&self) -> Option<DateTime<Local>> {
+        self.sessions
+            .iter()
+            .find_map(|s| s.start_time)
+            .or(self.time_created)
+    }
+
+    /// Key identifying the recording, `None` if the file lacks a creation time
+    fn recording_key(&self) -> Option<String> {
+        self.time_created.map(|t| {
+            format!(
+                "{}/{}/{}/{}",
+                self.manufacturer.as_deref().unwrap_or_default(),
+                self.product.as_deref().unwrap_or_default(),
+                self.serial_number.unwrap_or_default(),
+                t.timestamp()
+            )
+        })
+    }
+
+    fn from_records(path: &str, records: &[FitDataRecord]) -> Self {
+        let mut entry = LibraryEntry {
+            path: path.to_string(),
+            ..Default::default()
+        };
+        for record in records {
+            match record.kind() {
+                MesgNum::FileId if entry.file_type.is_none() => {
+                    entry.file_type = string_field(record, "type");
+                    entry.manufacturer = string_field(record, "manufacturer");
+                    entry.product = record
+                        .fields()
+                        .iter()
+                        .find(|f| f.name().ends_with("product"))
+                        .map(|f| f.value().to_string());
+                    entry.serial_number = record
+                        .field("serial_number")
+                        .and_then(|f| f.value().clone().try_into().ok())
+                        .map(|v: i64| v as u32);
+                    entry.time_created = time_field(record, "time_created");
+                }
+                MesgNum::Session => entry.sessions.push(SessionSummary {
+                    start_time: time_field(record, "start_time"),
+                    sport: string_field(record, "sport"),
+                    sub_sport: string_field(record, "sub_sport"),
+                    total_elapsed_time: float_field(record, "total_elapsed_time"),
+                    total_timer_time: float_field(record, "total_timer_time"),
+                    total_distance: float_field(record, "total_distance"),
+                    total_ascent: float_field(record, "total_ascent"),
+                    total_calories: float_field(record, "total_calories"),
+                }),
+                _ => {}
+            }
+        }
+        entry
+    }
+}
+
+fn string_field(record: &FitDataRecord, name: &str) -> Option<String> {
+    record.field(name).map(|f| f.value().to_string())
+}
+
+fn float_field(record: &FitDataRecord, name: &str) -> Option<f64> {
+    record
+        .field(name)
+        .and_then(|f| f.value().clone().try_into().ok())
+}
+
+fn time_field(record: &FitDataRecord, name: &str) -> Option<DateTime<Local>> {
+    match record.field(name).map(|f| f.value()) {
+        Some(Value::Timestamp(t)) => Some(to_chrono(t)),
+        _ => None,
+    }
+}
+
+/// Decode only the `file_id` and `session` messages of a FIT file, other data messages are
+/// deserialized but skip the comparatively expensive profile decoding step
+fn decode_summary_messages(mut buffer: &[u8]) -> Result<Vec<FitDataRecord>> {
+    let mut processor = FitStreamProcessor::new();
+    let mut records = Vec::new();
+    while !buffer.is_empty() {
+        let (buf, obj) = processor.deserialize_next(buffer)?;
+        match obj {
+            FitObject::Crc(..) => processor.reset(),
+            FitObject::DataMessage(msg) => {
+                let kind = MesgNum::from(msg.global_message_number());
+                if kind == MesgNum::FileId || kind == MesgNum::Session {
+                    records.push(processor.decode_message(msg)?);
+                }
+            }
+            FitObject::Header(..) | FitObject::DefinitionMessage(..) => {}
+        }
+        buffer = buf;
+    }
+    Ok(records)
+}
+
+/// A file that was skipped because it records the same activity as an already indexed file
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Duplicate {
+    /// Storage key of the skipped file
+    pub path: String,
+    /// Storage key of the indexed file it duplicates
+    pub original: String,
+}
+
+/// State of a scanned file used to detect changes between scans
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileState {
+    /// Modification time reported by the storage
+    pub modified: Option<DateTime<Local>>,
+    /// 64 bit FNV-1a hash of the file contents as hex
+    pub hash: String,
+}
+
+/// Counts of what changed during an index update
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UpdateStats {
+    /// Files that weren't in the index before
+    pub added: usize,
+    /// Files whose contents changed and were decoded again
+    pub changed: usize,
+    /// Files that no longer exist in the storage
+    pub removed: usize,
+    /// Files that were skipped because they didn't change
+    pub unchanged: usize,
+}
+
+/// Hash the contents of a file, FNV-1a is plenty to detect changes and stable across releases
+fn content_hash(data: &[u8]) -> String {
+    let hash = data.iter().fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+        (h ^ u64::from(*b)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Queryable index of a collection of FIT files
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LibraryIndex {
+    /// Indexed files sorted by storage key
+    pub entries: Vec<LibraryEntry>,
+    /// Files that were not indexed because they duplicate another entry
+    pub duplicates: Vec<Duplicate>,
+    /// Files that could not be decoded and the reason why
+    pub errors: BTreeMap<String, String>,
+    /// State of every scanned file, including duplicates and files that failed to decode
+    #[serde(default)]
+    pub files: BTreeMap<String, FileState>,
+}
+
+impl LibraryIndex {
+    /// Index every FIT file in a folder on the local file system, including sub folders
+    pub fn scan<P: AsRef<Path>>(root: P) -> Result<Self> {
+        Self::build(&LocalStorage::new(root).recursive(true))
+    }
+
+    /// Index every FIT file available in the storage
+    pub fn build<S: Storage + ?Sized>(storage: &S) -> Result<Self> {
+        let mut index = LibraryIndex::default();
+        index.update(storage)?;
+        Ok(index)
+    }
+
+    /// Re-scan the storage, only files that are new or whose modification time and content hash
+    /// changed are decoded. Files that no longer exist are dropped from the index.
+    pub fn update<S: Storage + ?Sized>(&mut self, storage: &S) -> Result<UpdateStats> {
+        let mut stats = UpdateStats::default();
+        let keys = storage.list()?;
+
+        let removed: Vec<String> = self
+            .files
+            .keys()
+            .filter(|k| keys.binary_search(k).is_err())
+            .cloned()
+            .collect();
+        for key in &removed {
+            self.forget(key);
+            stats.removed += 1;
+        }
+        // duplicates of a removed file need to be decoded again, one of them becomes the original
+        let orphaned: Vec<String> = self
+            .duplicates
+            .iter()
+            .filter(|d| removed.contains(&d.original))
+            .map(|d| d.path.clone())
+            .collect();
+        for key in &orphaned {
+            self.forget(key);
+        }
+
+        let mut recordings: HashMap<String, String> = self
+            .entries
+            .iter()
+            .filter_map(|e| e.recording_key().map(|r| (r, e.path.clone())))
+            .collect();
+        for key in keys {
+            let modified = storage.modified(&key)?.map(DateTime::<Local>::from);
+            let known = self.files.get(&key).cloned();
+            if let Some(state) = &known {
+                if modified.is_some() && state.modified == modified {
+                    stats.unchanged += 1;
+                    continue;
+                }
+            }
+
+            let mut buffer = Vec::new();
+            if let Err(e) = storage
+                .open(&key)
+                .and_then(|mut r| Ok(r.read_to_end(&mut buffer)?))
+            {
+                self.forget(&key);
+                self.errors.insert(key, e.to_string());
+                continue;
+            }
+            let hash = content_hash(&buffer);
+            match known {
+                Some(state) if state.hash == hash => {
+                    stats.unchanged += 1;
+                    self.files.insert(key, FileState { modified, hash });
+                    continue;
+                }
+                Some(_) => {
+                    stats.changed += 1;
+                    self.forget(&key);
+                    recordings.retain(|_, path| *path != key);
+                }
+                None => stats.added += 1,
+            }
+            self.files.insert(key.clone(), FileState { modified, hash });
+
+            let records = match decode_summary_messages(&buffer) {
+                Ok(records) => records,
+                Err(e) => {
+                    self.errors.insert(key, e.to_string());
+                    continue;
+                }
+            };
+            let entry = LibraryEntry::from_records(&key, &records);
+            if let Some(recording) = entry.recording_key() {
+                if let Some(original) = recordings.get(&recording) {
+                    self.duplicates.push(Duplicate {
+                        path: key,
+                        original: original.clone(),
+                    });
+                    continue;
+                }
+                recordings.insert(recording, key.clone());
+            }
+            self.entries.push(entry);
+        }
+        self.entries.sort_by(|a, b| a.path.cmp(&b.path));
+        self.duplicates.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(stats)
+    }
+
+    /// Remove everything the index knows about a file
+    fn forget(&mut self, key: &str) {
+        self.entries.retain(|e| e.path != key);
+        self.duplicates.retain(|d| d.path != key);
+        self.errors.remove(key);
+        self.files.remove(key);
+    }
+
+    /// Entries that started within the provided time range, `start` inclusive and `end` exclusive
+    pub fn between(
+        &self,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+    ) -> impl Iterator<Item = &LibraryEntry> {
+        self.entries
+            .iter()
+            .filter(move |e| e.start_time().is_some_and(|t| t >= start && t < end))
+    }
+
+    /// Entries with at least one session of the sport
+    pub fn by_sport<'a>(&'a self, sport: &'a str) -> impl Iterator<Item = &'a LibraryEntry> {
+        self.entries
+            .iter()
+            .filter(move |e| e.sessions.iter().any(|s| s.sport.as_deref() == Some(sport)))
+    }
+
+    /// Entries recorded by a device of the manufacturer, optionally limited to a single product
+    pub fn by_device<'a>(
+        &'a self,
+        manufacturer: &'a str,
+        product: Option<&'a str>,
+    ) -> impl Iterator<Item = &'a LibraryEntry> {
+        self.entries.iter().filter(move |e| {
+            e.manufacturer.as_deref() == Some(manufacturer)
+                && product.is_none_or(|p| e.product.as_deref() == Some(p))
+        })
+    }
+
+    /// Persist the index as JSON
+    #[cfg(feature = "json")]
+    pub fn save<W: Write>(&self, writer: W) -> Result<()> {
+        serde_json::to_writer_pretty(writer, self).map_err(std::io::Error::from)?;
+        Ok(())
+    }
+
+    /// Load an index previously persisted with `save`
+    #[cfg(feature = "json")]
+    pub fn load<R: Read>(reader: R) -> Result<Self> {
+        Ok(serde_json::from_reader(reader).map_err(std::io::Error::from)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_and_dedup() {
+        let local = LocalStorage::new("tests/fixtures");
+        let mut data = Vec::new();
+        local
+            .open("Activity.fit")
+            .unwrap()
+            .read_to_end(&mut data)
+            .unwrap();
+        let mut storage = BTreeMap::new();
+        storage.insert("2021/activity.fit".to_string(), data.clone());
+        storage.insert("export/activity-copy.fit".to_string(), data);
+        storage.insert("broken.fit".to_string(), vec![0x0e, 0x10]);
+
+        let mut index = LibraryIndex::build(&storage).unwrap();
+        assert_eq!(index.entries.len(), 1);
+        assert_eq!(index.errors.len(), 1);
+        assert_eq!(
+            index.duplicates,
+            vec![Duplicate {
+                path: "export/activity-copy.fit".to_string(),
+                original: "2021/activity.fit".to_string()
+            }]
+        );
+        let entry = &index.entries[0];
+        assert_eq!(entry.file_type.as_deref(), Some("activity"));
+        assert_eq!(entry.sessions.len(), 1);
+        let sport = entry.sessions[0].sport.clone().unwrap();
+        assert_eq!(index.by_sport(&sport).count(), 1);
+        assert_eq!(index.by_sport("swimming_underwater").count(), 0);
+
+        // only the modified file is decoded again, removing the original promotes its duplicate
+        storage.insert("broken.fit".to_string(), vec![0x0e, 0x20]);
+        storage.remove("2021/activity.fit");
+        let stats = index.update(&storage).unwrap();
+        assert_eq!(
+            stats,
+            UpdateStats {
+                added: 1,
+                changed: 1,
+                removed: 1,
+                unchanged: 0
+            }
+        );
+        assert_eq!(index.entries[0].path, "export/activity-copy.fit");
+        assert!(index.duplicates.is_empty());
+        assert_eq!(index.files.len(), 2);
+        assert_eq!(index.update(&storage).unwrap().unchanged, 2);
+
+        #[cfg(feature = "json")]
+        {
+            let mut json = Vec::new();
+            index.save(&mut json).unwrap();
+            assert_eq!(LibraryIndex::load(json.as_slice()).unwrap(), index);
+        }
+    }
+}
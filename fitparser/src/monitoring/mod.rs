@@ -0,0 +1,342 @@
+//! Helpers for the daily data of monitoring files, e.g. steps, sleep and stress recorded around
+//! the clock by a watch or fitness tracker.
+//!
+//! Monitoring data is summarized per day, and a day starts at midnight on the clock of the
+//! device rather than in UTC. Timestamps in FIT files are UTC, the device reports its local time
+//! alongside through the `local_timestamp` field of messages like `monitoring_info`, which is
+//! written again whenever the offset changes, e.g. at the switch to daylight saving time. A
+//! `DayClock` tracks these offsets, or a time zone given explicitly, to bucket messages into
+//! local days which may be 23 or 25 hours long.
+use crate::analysis::record_time;
+use crate::edit::field_as_f64;
+use crate::profile::MesgNum;
+use crate::ser::FIT_EPOCH_OFFSET;
+use crate::timestamp::{to_chrono, wall_clock_to_chrono};
+use crate::{FitDataRecord, Value};
+use chrono::{
+    DateTime, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc,
+};
+
+mod sleep;
+pub use sleep::{hypnogram, Hypnogram, SleepDetection, SleepSegment, SleepStage};
+#[cfg(not(compact_profile))]
+mod steps;
+#[cfg(not(compact_profile))]
+pub use steps::{daily_activity, device_summaries, ActivityTotals, DailyActivity};
+mod stress;
+pub use stress::{daily_curves, CurveValue, DailyCurve, GapReason};
+
+/// Time zone used to find the local midnight starting a day
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Zone {
+    /// UTC offsets reported by the device starting at the given times, sorted by time
+    Device(Vec<(DateTime<Utc>, FixedOffset)>),
+    /// Time zone of the system, including its daylight saving rules
+    Local,
+    /// A fixed offset from UTC
+    Fixed(FixedOffset),
+}
+
+/// Clock converting UTC timestamps into the local days they belong to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayClock {
+    zone: Zone,
+}
+
+impl DayClock {
+    /// Use the UTC offsets reported by the device. Every message with both a `timestamp` and a
+    /// `local_timestamp` sets the offset from its time on, the first offset also applies to
+    /// messages before it. The `time_offset` of `device_settings` is used for files without
+    /// local timestamps and UTC when neither is present.
+    pub fn from_records(records: &[FitDataRecord]) -> Self {
+        let mut offsets: Vec<(DateTime<Utc>, FixedOffset)> = Vec::new();
+        for record in records {
+            let local = match record.field("local_timestamp").map(|f| f.value()) {
+                Some(Value::Timestamp(local)) => to_chrono(local).naive_local(),
+                Some(Value::LocalTimestamp(local)) => wall_clock_to_chrono(local),
+                _ => continue,
+            };
+            let time = match record_time(record) {
+                Some(time) => time.with_timezone(&Utc),
+                None => continue,
+            };
+            let offset = match FixedOffset::east_opt((local - time.naive_utc()).num_seconds() as i32)
+            {
+                Some(offset) => offset,
+                None => continue,
+            };
+            match offsets.last() {
+                Some((_, last)) if *last == offset => {}
+                _ => offsets.push((time, offset)),
+            }
+        }
+        if offsets.is_empty() {
+            // the offset is unsigned in the profile, negative offsets wrap around
+            let offset = records
+                .iter()
+                .filter(|r| r.is_kind(MesgNum::DeviceSettings))
+                .find_map(|r| field_as_f64(r, "time_offset"))
+                .and_then(|offset| FixedOffset::east_opt(offset as u32 as i32))
+                .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+            return Self::fixed(offset);
+        }
+        offsets.sort_by_key(|(time, _)| *time);
+        Self {
+            zone: Zone::Device(offsets),
+        }
+    }
+
+    /// Use the time zone of the system
+    pub fn local() -> Self {
+        Self { zone: Zone::Local }
+    }
+
+    /// Use a fixed offset from UTC
+    pub fn fixed(offset: FixedOffset) -> Self {
+        Self {
+            zone: Zone::Fixed(offset),
+        }
+    }
+
+    /// Offset from UTC on the clock at a point in time
+    pub fn offset_at(&self, time: DateTime<Local>) -> FixedOffset {
+        let time = time.with_timezone(&Utc);
+        match &self.zone {
+            Zone::Device(offsets) => {
+                let after = offsets.partition_point(|(start, _)| *start <= time);
+                offsets[after.saturating_sub(1)].1
+            }
+            Zone::Local => time.with_timezone(&Local).offset().fix(),
+            Zone::Fixed(offset) => *offset,
+        }
+    }
+
+    /// Wall clock time of the device at a point in time
+    pub fn local_time(&self, time: DateTime<Local>) -> NaiveDateTime {
+        time.naive_utc() + Duration::seconds(self.offset_at(time).local_minus_utc() as i64)
+    }
+
+    /// Local day a point in time belongs to
+    pub fn day(&self, time: DateTime<Local>) -> NaiveDate {
+        self.local_time(time).date()
+    }
+
+    /// Time the local day started. A midnight skipped by a change of the offset starts the day
+    /// at the first time after it.
+    pub fn day_start(&self, day: NaiveDate) -> DateTime<Local> {
+        let midnight = day.and_hms_opt(0, 0, 0).unwrap();
+        let utc = |offset: FixedOffset| {
+            let time = midnight - Duration::seconds(offset.local_minus_utc() as i64);
+            Local.from_utc_datetime(&time)
+        };
+        match &self.zone {
+            Zone::Fixed(offset) => utc(*offset),
+            Zone::Device(offsets) => {
+                // take the offset in effect at midnight going by the offset before it
+                let guess = utc(offsets[0].1);
+                let start = utc(self.offset_at(guess));
+                let start = utc(self.offset_at(start));
+                match self.day(start) {
+                    d if d < day => self.offset_change_after(start).unwrap_or(start),
+                    _ => start,
+                }
+            }
+            Zone::Local => (0..24)
+                .find_map(|hour| {
+                    Local
+                        .from_local_datetime(&(midnight + Duration::hours(hour)))
+                        .earliest()
+                })
+                .unwrap_or_else(|| utc(FixedOffset::east_opt(0).unwrap())),
+        }
+    }
+
+    /// Time the local day ended, which is the start of the next day
+    pub fn day_end(&self, day: NaiveDate) -> DateTime<Local> {
+        self.day_start(day.succ_opt().unwrap_or(day))
+    }
+
+    fn offset_change_after(&self, time: DateTime<Local>) -> Option<DateTime<Local>> {
+        match &self.zone {
+            Zone::Device(offsets) => offsets
+                .iter()
+                .map(|(start, _)| start.with_timezone(&Local))
+                .find(|start| *start > time),
+            _ => None,
+        }
+    }
+}
+
+/// Messages of a local day
+#[derive(Clone, Debug)]
+pub struct Day<'a> {
+    /// Date on the clock of the device
+    pub date: NaiveDate,
+    /// Local midnight starting the day
+    pub start: DateTime<Local>,
+    /// Local midnight ending the day, days with a change of the offset aren't 24 hours long
+    pub end: DateTime<Local>,
+    /// The messages of the day paired with their resolved timestamp, in the order of the file
+    pub records: Vec<(DateTime<Local>, &'a FitDataRecord)>,
+}
+
+impl Day<'_> {
+    /// Length of the day
+    pub fn length(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+/// Resolve the time of every message. Monitoring messages often only store the lower 16 bits of
+/// the timestamp in `timestamp_16`, counted from the FIT epoch, which are completed from the last
+/// full timestamp before them.
+/// `None` for messages without a timestamp or a `timestamp_16` before any full timestamp.
+pub fn monitoring_times(records: &[FitDataRecord]) -> Vec<Option<DateTime<Local>>> {
+    let mut last: Option<DateTime<Local>> = None;
+    records
+        .iter()
+        .map(|record| {
+            if let Some(time) = record_time(record) {
+                last = Some(time);
+                return last;
+            }
+            let low = field_as_f64(record, "timestamp_16")? as i64;
+            let time = last?;
+            let fit_time = time.timestamp() - FIT_EPOCH_OFFSET;
+            let rollover = (low - (fit_time & 0xFFFF)) & 0xFFFF;
+            last = Some(time + Duration::seconds(rollover));
+            last
+        })
+        .collect()
+}
+
+/// Group the timed messages of a monitoring file into local days, sorted by date. Days without
+/// any messages are left out.
+pub fn days<'a>(records: &'a [FitDataRecord], clock: &DayClock) -> Vec<Day<'a>> {
+    let mut days: Vec<Day> = Vec::new();
+    for (time, record) in monitoring_times(records).into_iter().zip(records) {
+        let time = match time {
+            Some(time) => time,
+            None => continue,
+        };
+        let date = clock.day(time);
+        let index = match days.binary_search_by_key(&date, |d| d.date) {
+            Ok(index) => index,
+            Err(index) => {
+                let day = Day {
+                    date,
+                    start: clock.day_start(date),
+                    end: clock.day_end(date),
+                    records: Vec::new(),
+                };
+                days.insert(index, day);
+                index
+            }
+        };
+        days[index].records.push((time, record));
+    }
+    days
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::from_chrono;
+    use crate::FitDataField;
+
+    fn timestamp(record: &mut FitDataRecord, name: &str, number: u8, time: DateTime<Local>) {
+        record.push(FitDataField::new(
+            name.to_string(),
+            number,
+            Value::Timestamp(from_chrono(time)),
+            "s".to_string(),
+        ));
+    }
+
+    #[test]
+    fn device_days() {
+        let data = include_bytes!("../../tests/fixtures/MonitoringFile.fit");
+        let records = crate::from_bytes(data).unwrap();
+        let times = monitoring_times(&records);
+        let monitoring: Vec<_> = records
+            .iter()
+            .zip(&times)
+            .filter(|(r, _)| r.is_kind(MesgNum::Monitoring))
+            .collect();
+        assert!(monitoring.iter().all(|(_, time)| time.is_some()));
+
+        // the vivofit is 6 hours behind UTC
+        let clock = DayClock::from_records(&records);
+        assert_eq!(
+            clock.offset_at(Local::now()),
+            FixedOffset::west_opt(6 * 3600).unwrap()
+        );
+        let days = days(&records, &clock);
+        let first = NaiveDate::from_ymd_opt(2014, 12, 27).unwrap();
+        assert_eq!(days[0].date, first);
+        assert_eq!(
+            days[0].start.with_timezone(&Utc),
+            Utc.with_ymd_and_hms(2014, 12, 27, 6, 0, 0).unwrap()
+        );
+        assert!(days.iter().all(|d| d.length() == Duration::hours(24)));
+        assert!(days
+            .iter()
+            .flat_map(|d| d.records.iter().map(move |(time, _)| (d, time)))
+            .all(|(d, time)| d.start <= *time && *time < d.end));
+        let timed = times.iter().filter(|t| t.is_some()).count();
+        assert_eq!(days.iter().map(|d| d.records.len()).sum::<usize>(), timed);
+    }
+
+    #[test]
+    fn daylight_saving_days() {
+        // the device switches from UTC-6 to UTC-5 at 2am local time on March 8th
+        let utc = |day, hour| {
+            Local.from_utc_datetime(
+                &Utc.with_ymd_and_hms(2015, 3, day, hour, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+            )
+        };
+        let info = |time: DateTime<Local>, offset: i64| {
+            let mut info = FitDataRecord::new(MesgNum::MonitoringInfo);
+            timestamp(&mut info, "timestamp", 253, time);
+            timestamp(
+                &mut info,
+                "local_timestamp",
+                0,
+                time + Duration::hours(offset),
+            );
+            info
+        };
+        let mut records = vec![info(utc(7, 6), -6), info(utc(8, 8), -5)];
+        for hour in [9, 12, 20] {
+            let mut monitoring = FitDataRecord::new(MesgNum::Monitoring);
+            monitoring.push(FitDataField::new(
+                "timestamp_16".to_string(),
+                26,
+                Value::UInt16(((utc(8, hour).timestamp() - FIT_EPOCH_OFFSET) & 0xFFFF) as u16),
+                "s".to_string(),
+            ));
+            records.push(monitoring);
+        }
+        records.push(info(utc(9, 5), -5));
+        let clock = DayClock::from_records(&records);
+        let days = days(&records, &clock);
+        let dates: Vec<_> = days
+            .iter()
+            .map(|d| (d.date.to_string(), d.records.len()))
+            .collect();
+        assert_eq!(
+            dates,
+            [("2015-03-07", 1), ("2015-03-08", 4), ("2015-03-09", 1)]
+                .map(|(date, count)| (date.to_string(), count))
+        );
+        let dst = &days[1];
+        assert_eq!((dst.start, dst.end), (utc(8, 6), utc(9, 5)));
+        assert_eq!(dst.length(), Duration::hours(23));
+
+        let fixed = DayClock::fixed(FixedOffset::east_opt(3600).unwrap());
+        assert_eq!(fixed.day(utc(7, 23)).to_string(), "2015-03-08");
+        assert_eq!(fixed.day_start(dst.date), utc(7, 23));
+    }
+}
@@ -0,0 +1,352 @@
+//! Reconstruct the sleep stages of a night, the hypnogram, from a sleep file.
+//!
+//! Newer devices write a `sleep_level` message whenever a stage is sampled, each one holding the
+//! stage from its timestamp until the next message. Files synced in chunks repeat messages or
+//! write them out of order, so the samples are sorted and a repeated time keeps the message
+//! written last. Older devices only mark falling asleep and waking up with `event` messages,
+//! these become segments of the `Asleep` stage without any depth.
+use super::monitoring_times;
+use crate::profile::field_types::{Event, EventType, SleepLevel};
+use crate::profile::messages::{EventMessage, SleepAssessmentMessage, SleepLevelMessage};
+use crate::profile::MesgNum;
+use crate::FitDataRecord;
+use chrono::{DateTime, Duration, Local};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+/// Stage of sleep
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SleepStage {
+    /// The device couldn't determine the stage, e.g. it was taken off
+    Unmeasurable,
+    /// Awake
+    Awake,
+    /// Light sleep
+    Light,
+    /// Deep sleep
+    Deep,
+    /// REM sleep
+    Rem,
+    /// Asleep without a known stage, from the markers of older devices
+    Asleep,
+}
+
+impl SleepStage {
+    /// Check if the stage is one of the stages of sleep
+    pub fn is_asleep(&self) -> bool {
+        matches!(self, Self::Light | Self::Deep | Self::Rem | Self::Asleep)
+    }
+}
+
+impl From<SleepLevel> for SleepStage {
+    fn from(level: SleepLevel) -> Self {
+        match level {
+            SleepLevel::Awake => Self::Awake,
+            SleepLevel::Light => Self::Light,
+            SleepLevel::Deep => Self::Deep,
+            SleepLevel::Rem => Self::Rem,
+            SleepLevel::Unmeasurable | SleepLevel::UnknownVariant(_) => Self::Unmeasurable,
+        }
+    }
+}
+
+/// Settings for reconstructing the hypnogram
+#[derive(Clone, Debug)]
+pub struct SleepDetection {
+    /// Longest time in seconds a `sleep_level` message lasts until the next one, a sample
+    /// followed by a longer gap only lasts the usual spacing of the samples and leaves the rest
+    /// of the gap out of the hypnogram
+    pub max_gap: f64,
+    /// Value of the `event` field marking sleep on older devices, `start` events mark falling
+    /// asleep and `stop` events waking up. The value isn't part of the published profile and
+    /// differs between devices, markers are ignored unless it is set.
+    pub marker_event: Option<u8>,
+}
+
+impl Default for SleepDetection {
+    fn default() -> Self {
+        SleepDetection {
+            max_gap: 600.0,
+            marker_event: None,
+        }
+    }
+}
+
+/// A stretch of time spent in one stage
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SleepSegment {
+    /// Stage of sleep
+    pub stage: SleepStage,
+    /// Time the stage started
+    pub start: DateTime<Local>,
+    /// Time the stage ended
+    pub end: DateTime<Local>,
+}
+
+impl SleepSegment {
+    /// Time spent in the stage
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+/// The stages of a night of sleep in order
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Hypnogram {
+    /// Consecutive segments of the same stage are merged, segments are separated by gaps when
+    /// the device didn't record anything
+    pub segments: Vec<SleepSegment>,
+    /// Overall score of the `sleep_assessment` message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<u8>,
+}
+
+impl Hypnogram {
+    /// Time spent in each stage recorded
+    pub fn totals(&self) -> BTreeMap<SleepStage, Duration> {
+        let mut totals = BTreeMap::new();
+        for segment in &self.segments {
+            *totals.entry(segment.stage).or_insert_with(Duration::zero) += segment.duration();
+        }
+        totals
+    }
+
+    /// Time spent asleep in any stage
+    pub fn time_asleep(&self) -> Duration {
+        self.segments
+            .iter()
+            .filter(|s| s.stage.is_asleep())
+            .fold(Duration::zero(), |total, s| total + s.duration())
+    }
+
+    /// Time the hypnogram starts
+    pub fn start(&self) -> Option<DateTime<Local>> {
+        self.segments.first().map(|s| s.start)
+    }
+
+    /// Time the hypnogram ends
+    pub fn end(&self) -> Option<DateTime<Local>> {
+        self.segments.last().map(|s| s.end)
+    }
+
+    fn push(&mut self, stage: SleepStage, start: DateTime<Local>, end: DateTime<Local>) {
+        match self.segments.last_mut() {
+            Some(last) if last.stage == stage && last.end == start => last.end = end,
+            _ => self.segments.push(SleepSegment { stage, start, end }),
+        }
+    }
+}
+
+/// Build the hypnogram from the `sleep_level` messages, falling back to the sleep markers of
+/// older devices when there are none
+pub fn hypnogram(records: &[FitDataRecord], opts: &SleepDetection) -> Hypnogram {
+    let times = monitoring_times(records);
+    let mut samples: Vec<(DateTime<Local>, SleepStage)> = records
+        .iter()
+        .zip(&times)
+        .filter(|(r, _)| r.is_kind(MesgNum::SleepLevel))
+        .filter_map(|(r, time)| {
+            let level = SleepLevelMessage::try_from(r).ok()?.sleep_level?;
+            Some(((*time)?, SleepStage::from(level)))
+        })
+        .collect();
+    // sorting is stable so the last message written for a time comes last
+    samples.sort_by_key(|(time, _)| *time);
+    let mut deduped: Vec<(DateTime<Local>, SleepStage)> = Vec::with_capacity(samples.len());
+    for sample in samples {
+        match deduped.last_mut() {
+            Some(last) if last.0 == sample.0 => *last = sample,
+            _ => deduped.push(sample),
+        }
+    }
+
+    let mut hypnogram = Hypnogram {
+        segments: Vec::new(),
+        score: records
+            .iter()
+            .rev()
+            .filter(|r| r.is_kind(MesgNum::SleepAssessment))
+            .find_map(|r| {
+                SleepAssessmentMessage::try_from(r)
+                    .ok()?
+                    .overall_sleep_score
+            }),
+    };
+    if deduped.is_empty() {
+        if let Some(event) = opts.marker_event {
+            sleep_markers(records, &times, Event::from(event), &mut hypnogram);
+        }
+        return hypnogram;
+    }
+
+    let max_gap = Duration::milliseconds((opts.max_gap * 1000.0) as i64);
+    let mut spacings: Vec<Duration> = deduped.windows(2).map(|w| w[1].0 - w[0].0).collect();
+    spacings.sort();
+    let spacing = spacings
+        .get(spacings.len() / 2)
+        .copied()
+        .filter(|s| *s <= max_gap)
+        .unwrap_or_else(|| Duration::seconds(60));
+    for (index, &(start, stage)) in deduped.iter().enumerate() {
+        let end = match deduped.get(index + 1) {
+            Some(&(next, _)) if next - start <= max_gap => next,
+            _ => start + spacing,
+        };
+        hypnogram.push(stage, start, end);
+    }
+    hypnogram
+}
+
+/// Add a segment asleep between every `start` and the following `stop` marker
+fn sleep_markers(
+    records: &[FitDataRecord],
+    times: &[Option<DateTime<Local>>],
+    event: Event,
+    hypnogram: &mut Hypnogram,
+) {
+    let mut markers: Vec<(DateTime<Local>, EventType)> = records
+        .iter()
+        .zip(times)
+        .filter(|(r, _)| r.is_kind(MesgNum::Event))
+        .filter_map(|(r, time)| {
+            let message = EventMessage::try_from(r).ok()?;
+            match (message.event, message.event_type) {
+                (Some(e), Some(kind @ (EventType::Start | EventType::Stop))) if e == event => {
+                    Some(((*time)?, kind))
+                }
+                _ => None,
+            }
+        })
+        .collect();
+    markers.sort_by_key(|(time, _)| *time);
+    let mut asleep: Option<DateTime<Local>> = None;
+    for (time, kind) in markers {
+        match (kind, asleep) {
+            // a repeated start keeps the earlier time
+            (EventType::Start, None) => asleep = Some(time),
+            (EventType::Stop, Some(start)) => {
+                hypnogram.push(SleepStage::Asleep, start, time);
+                asleep = None;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::from_chrono;
+    use crate::{FitDataField, Value};
+    use chrono::TimeZone;
+
+    fn message(
+        kind: MesgNum,
+        time: DateTime<Local>,
+        fields: &[(&str, u8, Value)],
+    ) -> FitDataRecord {
+        let mut record = FitDataRecord::new(kind);
+        record.push(FitDataField::new(
+            "timestamp".to_string(),
+            253,
+            Value::Timestamp(from_chrono(time)),
+            "s".to_string(),
+        ));
+        for (name, number, value) in fields {
+            record.push(FitDataField::new(
+                name.to_string(),
+                *number,
+                value.clone(),
+                String::new(),
+            ));
+        }
+        record
+    }
+
+    #[test]
+    fn sleep_stages() {
+        let start = Local.timestamp_opt(1_600_000_000, 0).unwrap();
+        let minute = |m: i64| start + Duration::minutes(m);
+        let level = |m: i64, level: &str| {
+            let value = Value::String(level.to_string());
+            message(MesgNum::SleepLevel, minute(m), &[("sleep_level", 0, value)])
+        };
+        // the second chunk repeats minutes 3 and 4 and corrects minute 4, the device was off
+        // between minutes 7 and 30
+        let mut records: Vec<FitDataRecord> = [(0, "awake"), (1, "light"), (2, "light")]
+            .into_iter()
+            .chain([(3, "deep"), (4, "deep")])
+            .map(|(m, l)| level(m, l))
+            .collect();
+        records.extend(
+            [
+                (3, "deep"),
+                (4, "rem"),
+                (5, "rem"),
+                (6, "light"),
+                (30, "awake"),
+            ]
+            .map(|(m, l)| level(m, l)),
+        );
+        records.push(message(
+            MesgNum::SleepAssessment,
+            minute(31),
+            &[("overall_sleep_score", 6, Value::UInt8(81))],
+        ));
+
+        let night = hypnogram(&records, &SleepDetection::default());
+        let segments: Vec<_> = night
+            .segments
+            .iter()
+            .map(|s| {
+                (
+                    s.stage,
+                    (s.start - start).num_minutes(),
+                    (s.end - start).num_minutes(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            segments,
+            vec![
+                (SleepStage::Awake, 0, 1),
+                (SleepStage::Light, 1, 3),
+                (SleepStage::Deep, 3, 4),
+                (SleepStage::Rem, 4, 6),
+                (SleepStage::Light, 6, 7),
+                (SleepStage::Awake, 30, 31),
+            ]
+        );
+        assert_eq!(night.score, Some(81));
+        assert_eq!(night.time_asleep(), Duration::minutes(6));
+        let totals = night.totals();
+        assert_eq!(totals[&SleepStage::Light], Duration::minutes(3));
+        assert_eq!(totals[&SleepStage::Awake], Duration::minutes(2));
+
+        // older devices only mark the start and end of sleep
+        let marker = |m: i64, kind: &str| {
+            message(
+                MesgNum::Event,
+                minute(m),
+                &[
+                    ("event", 0, Value::UInt8(74)),
+                    ("event_type", 1, Value::String(kind.to_string())),
+                ],
+            )
+        };
+        let records = vec![marker(0, "start"), marker(5, "start"), marker(480, "stop")];
+        assert!(hypnogram(&records, &SleepDetection::default())
+            .segments
+            .is_empty());
+        let opts = SleepDetection {
+            marker_event: Some(74),
+            ..Default::default()
+        };
+        let night = hypnogram(&records, &opts);
+        assert_eq!(night.segments.len(), 1);
+        assert_eq!(night.segments[0].stage, SleepStage::Asleep);
+        assert_eq!(night.time_asleep(), Duration::hours(8));
+    }
+}
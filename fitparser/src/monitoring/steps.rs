@@ -0,0 +1,362 @@
+//! Daily steps, distance and intensity minutes added up the way the device counts them.
+//!
+//! The `cycles`, `distance`, `active_time` and `calories` of `monitoring` messages are running
+//! totals kept for each activity type. They restart at local midnight and whenever the device
+//! resets, so a value lower than the one before starts a new run instead of being subtracted.
+//! Devices save space by only storing the lower 16 bits in `cycles_16`, `distance_16` and
+//! `active_time_16`, which continue the last total of the same activity type. A message
+//! describes the interval ending at its timestamp, so a message at midnight still counts towards
+//! the day before. Messages with a `duration_min` are summaries the device writes at the end of
+//! a day, they aren't added to the totals but can be read with `device_summaries`.
+use super::{monitoring_times, DayClock};
+use crate::profile::field_types::ActivityType;
+use crate::profile::messages::{MonitoringInfoMessage, MonitoringMessage};
+use crate::profile::MesgNum;
+use crate::FitDataRecord;
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+/// Totals of an activity type over a day
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ActivityTotals {
+    /// Cycles counted, a step is half a cycle when walking or running
+    pub cycles: f64,
+    /// Distance in meters, estimated from the cycles with the `cycles_to_distance` factor of
+    /// `monitoring_info` when the device didn't record it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+    /// Time in seconds spent active
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_time: Option<f64>,
+    /// Total calories in kcal including the resting metabolic rate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calories: Option<f64>,
+    /// Calories in kcal burned by the activity, estimated from the cycles with the
+    /// `cycles_to_calories` factor of `monitoring_info` when the device didn't record them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_calories: Option<f64>,
+}
+
+/// Activity of a local day
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DailyActivity {
+    /// Date on the clock of the device
+    pub date: NaiveDate,
+    /// Totals of every activity type recorded
+    pub activities: BTreeMap<ActivityType, ActivityTotals>,
+    /// Minutes of moderate activity
+    pub moderate_minutes: u32,
+    /// Minutes of vigorous activity
+    pub vigorous_minutes: u32,
+}
+
+impl DailyActivity {
+    fn new(date: NaiveDate) -> Self {
+        DailyActivity {
+            date,
+            activities: BTreeMap::new(),
+            moderate_minutes: 0,
+            vigorous_minutes: 0,
+        }
+    }
+
+    /// Steps taken walking and running
+    pub fn steps(&self) -> u32 {
+        [ActivityType::Walking, ActivityType::Running]
+            .iter()
+            .filter_map(|kind| self.activities.get(kind))
+            .map(|totals| (totals.cycles * 2.0).round() as u32)
+            .sum()
+    }
+
+    /// Intensity minutes, a minute of vigorous activity counts twice
+    pub fn intensity_minutes(&self) -> u32 {
+        self.moderate_minutes + 2 * self.vigorous_minutes
+    }
+}
+
+/// Running total of a monitoring field, in the units of its 16 bit field
+#[derive(Clone, Debug, Default)]
+struct Counter {
+    /// Sum of the runs ended by a reset
+    done: f64,
+    last: Option<f64>,
+}
+
+impl Counter {
+    fn full(&mut self, value: f64) {
+        if let Some(last) = self.last {
+            if value < last {
+                self.done += last;
+            }
+        }
+        self.last = Some(value);
+    }
+
+    fn lower_bits(&mut self, low: u16) {
+        let value = match self.last {
+            Some(last) => last + ((low as i64 - last as i64) & 0xFFFF) as f64,
+            // totals restart at midnight, so a first value is small enough to be complete
+            None => low as f64,
+        };
+        self.last = Some(value);
+    }
+
+    fn total(&self) -> Option<f64> {
+        self.last.map(|last| self.done + last)
+    }
+}
+
+/// Counters of an activity type over a day
+#[derive(Clone, Debug, Default)]
+struct Counters {
+    /// Cycles in steps, i.e. half cycles
+    steps: Counter,
+    /// Distance in centimeters
+    distance: Counter,
+    /// Active time in seconds
+    active_time: Counter,
+    calories: Counter,
+}
+
+impl Counters {
+    fn add(&mut self, message: &MonitoringMessage) {
+        let steps = message.steps.map(f64::from).or_else(|| {
+            message
+                .cycles
+                .or(message.strokes)
+                .map(|cycles| cycles * 2.0)
+        });
+        match (steps, message.cycles_16) {
+            (Some(steps), _) => self.steps.full(steps),
+            (None, Some(low)) => self.steps.lower_bits(low),
+            _ => {}
+        }
+        match (message.distance, message.distance_16) {
+            (Some(distance), _) => self.distance.full((distance * 100.0).round()),
+            (None, Some(low)) => self.distance.lower_bits(low),
+            _ => {}
+        }
+        match (message.active_time, message.active_time_16) {
+            (Some(active_time), _) => self.active_time.full(active_time),
+            (None, Some(low)) => self.active_time.lower_bits(low),
+            _ => {}
+        }
+        if let Some(calories) = message.calories {
+            self.calories.full(calories as f64);
+        }
+    }
+
+    fn totals(&self) -> ActivityTotals {
+        ActivityTotals {
+            cycles: self.steps.total().unwrap_or(0.0) / 2.0,
+            distance: self.distance.total().map(|cm| cm / 100.0),
+            active_time: self.active_time.total(),
+            calories: self.calories.total(),
+            active_calories: None,
+        }
+    }
+}
+
+/// Meters and kcal per cycle of every activity type from the `monitoring_info` messages, later
+/// messages replace the factors of earlier ones
+fn cycle_factors(records: &[FitDataRecord]) -> BTreeMap<ActivityType, (f64, f64)> {
+    let mut factors = BTreeMap::new();
+    for record in records
+        .iter()
+        .filter(|r| r.is_kind(MesgNum::MonitoringInfo))
+    {
+        let info = match MonitoringInfoMessage::try_from(record) {
+            Ok(info) => info,
+            Err(_) => continue,
+        };
+        let (kinds, distance, calories) = match (
+            info.activity_type,
+            info.cycles_to_distance,
+            info.cycles_to_calories,
+        ) {
+            (Some(kinds), Some(distance), Some(calories)) => (kinds, distance, calories),
+            _ => continue,
+        };
+        for ((kind, distance), calories) in kinds.into_iter().zip(distance).zip(calories) {
+            factors.insert(kind, (distance, calories));
+        }
+    }
+    factors
+}
+
+/// Monitoring messages with their resolved time in order of time, written order is kept for
+/// messages at the same time
+fn monitoring_messages(records: &[FitDataRecord]) -> Vec<(DateTime<Local>, MonitoringMessage)> {
+    let mut messages: Vec<_> = monitoring_times(records)
+        .into_iter()
+        .zip(records)
+        .filter(|(_, r)| r.is_kind(MesgNum::Monitoring))
+        .filter_map(|(time, r)| Some((time?, MonitoringMessage::try_from(r).ok()?)))
+        .collect();
+    messages.sort_by_key(|(time, _)| *time);
+    messages
+}
+
+/// Add up the monitoring messages of every local day, sorted by date. Messages without an
+/// activity type are counted as `generic`. Distance and active calories are estimated from the
+/// cycles when the device didn't record them and `monitoring_info` holds the factors of the
+/// activity type.
+pub fn daily_activity(records: &[FitDataRecord], clock: &DayClock) -> Vec<DailyActivity> {
+    let mut days: BTreeMap<NaiveDate, (DailyActivity, BTreeMap<ActivityType, Counters>)> =
+        BTreeMap::new();
+    for (time, message) in monitoring_messages(records) {
+        if message.duration_min.is_some() {
+            continue;
+        }
+        // the message describes the interval ending at its timestamp
+        let date = clock.day(time - Duration::seconds(1));
+        let (day, counters) = days
+            .entry(date)
+            .or_insert_with(|| (DailyActivity::new(date), BTreeMap::new()));
+        day.moderate_minutes += message.moderate_activity_minutes.unwrap_or(0) as u32;
+        day.vigorous_minutes += message.vigorous_activity_minutes.unwrap_or(0) as u32;
+        let kind = message.activity_type.unwrap_or(ActivityType::Generic);
+        counters.entry(kind).or_default().add(&message);
+    }
+
+    let factors = cycle_factors(records);
+    days.into_values()
+        .map(|(mut day, counters)| {
+            for (kind, counters) in counters {
+                let mut totals = counters.totals();
+                if let Some((distance, calories)) = factors.get(&kind) {
+                    totals.distance = totals.distance.or(Some(totals.cycles * distance));
+                    totals.active_calories = Some(totals.cycles * calories);
+                }
+                day.activities.insert(kind, totals);
+            }
+            day
+        })
+        .collect()
+}
+
+/// Daily totals the device wrote itself, the messages with a `duration_min` summarizing the
+/// local day ending at their timestamp. Compare them with `daily_activity` to check a file is
+/// complete.
+pub fn device_summaries(records: &[FitDataRecord], clock: &DayClock) -> Vec<DailyActivity> {
+    let mut days: BTreeMap<NaiveDate, DailyActivity> = BTreeMap::new();
+    for (time, message) in monitoring_messages(records) {
+        let minutes = match message.duration_min {
+            Some(minutes) => minutes,
+            None => continue,
+        };
+        let date = clock.day(time - Duration::minutes(minutes as i64));
+        let day = days.entry(date).or_insert_with(|| DailyActivity::new(date));
+        day.moderate_minutes += message.moderate_activity_minutes.unwrap_or(0) as u32;
+        day.vigorous_minutes += message.vigorous_activity_minutes.unwrap_or(0) as u32;
+        let kind = message.activity_type.unwrap_or(ActivityType::Generic);
+        let mut counters = Counters::default();
+        counters.add(&message);
+        let mut totals = counters.totals();
+        totals.active_calories = message.active_calories.map(f64::from);
+        day.activities.insert(kind, totals);
+    }
+    days.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::from_chrono;
+    use crate::{FitDataField, Value};
+    use chrono::{FixedOffset, TimeZone, Utc};
+
+    #[test]
+    fn vivofit_daily_steps() {
+        let data = include_bytes!("../../tests/fixtures/MonitoringFile.fit");
+        let records = crate::from_bytes(data).unwrap();
+        let clock = DayClock::from_records(&records);
+        let date = NaiveDate::from_ymd_opt(2014, 12, 27).unwrap();
+
+        // the counts of the day match the summary the vivofit wrote at midnight
+        let summaries = device_summaries(&records, &clock);
+        assert_eq!(summaries.len(), 1);
+        let summary = &summaries[0];
+        assert_eq!(summary.date, date);
+        assert_eq!(summary.steps(), 3173);
+        let days = daily_activity(&records, &clock);
+        let day = days.iter().find(|d| d.date == date).unwrap();
+        assert_eq!(day.steps(), summary.steps());
+        for kind in [ActivityType::Walking, ActivityType::Running] {
+            let (counted, written) = (&day.activities[&kind], &summary.activities[&kind]);
+            assert_eq!(counted.cycles, written.cycles);
+            assert_eq!(
+                counted.active_calories.map(f64::floor),
+                written.active_calories
+            );
+        }
+        assert_eq!(day.activities[&ActivityType::Walking].cycles, 1571.0);
+    }
+
+    #[test]
+    fn counter_resets() {
+        let midnight = Local.from_utc_datetime(
+            &Utc.with_ymd_and_hms(2021, 6, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        );
+        let message = |minutes: i64, kind: &str, fields: &[(&str, u8, Value)]| {
+            let mut record = FitDataRecord::new(MesgNum::Monitoring);
+            let time = midnight + Duration::minutes(minutes);
+            for (name, number, value) in [
+                ("timestamp", 253, Value::Timestamp(from_chrono(time))),
+                ("activity_type", 5, Value::String(kind.to_string())),
+            ]
+            .iter()
+            .chain(fields)
+            {
+                record.push(FitDataField::new(
+                    name.to_string(),
+                    *number,
+                    value.clone(),
+                    String::new(),
+                ));
+            }
+            record
+        };
+        let records = vec![
+            message(-60, "walking", &[("steps", 3, Value::UInt32(9000))]),
+            // the total of the previous day is written at midnight
+            message(0, "walking", &[("steps", 3, Value::UInt32(9500))]),
+            message(60, "walking", &[("steps", 3, Value::UInt32(65_000))]),
+            // the lower bits roll over to 65_000 + 1036
+            message(120, "walking", &[("cycles_16", 9, Value::UInt16(500))]),
+            // the device restarted
+            message(180, "walking", &[("steps", 3, Value::UInt32(200))]),
+            message(
+                240,
+                "running",
+                &[
+                    ("cycles", 3, Value::Float64(100.0)),
+                    ("moderate_activity_minutes", 33, Value::UInt16(10)),
+                    ("vigorous_activity_minutes", 34, Value::UInt16(15)),
+                ],
+            ),
+        ];
+        let clock = DayClock::fixed(FixedOffset::east_opt(0).unwrap());
+        let days = daily_activity(&records, &clock);
+        let dates: Vec<_> = days
+            .iter()
+            .map(|d| (d.date.to_string(), d.steps()))
+            .collect();
+        assert_eq!(
+            dates,
+            [
+                ("2021-05-31", 9500),
+                ("2021-06-01", 65_000 + 1036 + 200 + 200)
+            ]
+            .map(|(date, steps)| (date.to_string(), steps))
+        );
+        assert_eq!(days[1].activities[&ActivityType::Running].cycles, 100.0);
+        assert_eq!(days[1].intensity_minutes(), 40);
+        assert!(device_summaries(&records, &clock).is_empty());
+    }
+}
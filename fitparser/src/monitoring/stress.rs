@@ -0,0 +1,317 @@
+//! Daily stress and body battery curves on a common time grid.
+//!
+//! Devices store a negative number instead of a score when they can't measure stress, e.g. while
+//! the device is off the wrist or during exercise. Treating these sentinels as scores drags
+//! averages down and draws dips into charts, so they are turned into gaps that keep the reason
+//! the score is missing. `stress_level` messages hold a single score, the `hsa_stress_data` and
+//! `hsa_body_battery_data` messages hold an array of scores spaced by their processing interval
+//! starting at their timestamp.
+use super::DayClock;
+use crate::profile::messages::{
+    HsaBodyBatteryDataMessage, HsaStressDataMessage, StressLevelMessage,
+};
+use crate::profile::MesgNum;
+use crate::timestamp::to_chrono;
+use crate::FitDataRecord;
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+/// Why a sample doesn't hold a score
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GapReason {
+    /// The device wasn't worn
+    OffWrist,
+    /// Too much motion to measure, e.g. during an activity
+    Motion,
+    /// Not enough data was recorded to calculate a score
+    NotEnoughData,
+    /// The body was recovering from exercise
+    Recovering,
+    /// Any other value outside the range of scores
+    Invalid,
+}
+
+/// A slot of a curve
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CurveValue {
+    /// Score between 0 and 100
+    Score(u8),
+    /// The device recorded why there is no score
+    Gap(GapReason),
+    /// Nothing was recorded
+    Missing,
+}
+
+impl CurveValue {
+    /// Score of the slot, `None` for gaps and missing slots
+    pub fn score(&self) -> Option<u8> {
+        match self {
+            Self::Score(score) => Some(*score),
+            _ => None,
+        }
+    }
+}
+
+/// Interpret a stress value, both the small sentinels of `stress_level` messages and the
+/// multiples of 100 documented for `hsa_stress_data` are recognized
+fn stress_value(value: i64) -> CurveValue {
+    match value {
+        0..=100 => CurveValue::Score(value as u8),
+        -1 | -100 => CurveValue::Gap(GapReason::OffWrist),
+        -2 | -200 => CurveValue::Gap(GapReason::Motion),
+        -3 => CurveValue::Gap(GapReason::NotEnoughData),
+        -4 => CurveValue::Gap(GapReason::Recovering),
+        _ => CurveValue::Gap(GapReason::Invalid),
+    }
+}
+
+fn body_battery_value(value: i64) -> CurveValue {
+    match value {
+        0..=100 => CurveValue::Score(value as u8),
+        _ => CurveValue::Gap(GapReason::Invalid),
+    }
+}
+
+/// Stress and body battery of a local day sampled at a fixed interval
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DailyCurve {
+    /// Date on the clock of the device
+    pub date: NaiveDate,
+    /// Start of every slot starting at local midnight, shared by both curves
+    pub times: Vec<DateTime<Local>>,
+    /// Stress score of every slot
+    pub stress: Vec<CurveValue>,
+    /// Body battery level of every slot
+    pub body_battery: Vec<CurveValue>,
+}
+
+impl DailyCurve {
+    /// Average stress score ignoring gaps and missing slots
+    pub fn average_stress(&self) -> Option<f64> {
+        average(&self.stress)
+    }
+
+    /// Average body battery level ignoring gaps and missing slots
+    pub fn average_body_battery(&self) -> Option<f64> {
+        average(&self.body_battery)
+    }
+
+    /// Number of slots the device reported being off the wrist
+    pub fn off_wrist_slots(&self) -> usize {
+        self.stress
+            .iter()
+            .filter(|v| **v == CurveValue::Gap(GapReason::OffWrist))
+            .count()
+    }
+}
+
+fn average(values: &[CurveValue]) -> Option<f64> {
+    let scores: Vec<f64> = values
+        .iter()
+        .filter_map(|v| v.score().map(f64::from))
+        .collect();
+    match scores.len() {
+        0 => None,
+        count => Some(scores.iter().sum::<f64>() / count as f64),
+    }
+}
+
+/// Channel a sample belongs to
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Channel {
+    Stress,
+    BodyBattery,
+}
+
+/// Samples of the scores in the records in file order
+fn samples(records: &[FitDataRecord]) -> Vec<(DateTime<Local>, Channel, CurveValue)> {
+    let mut samples = Vec::new();
+    // arrays of scores spaced by the processing interval starting at the timestamp
+    let mut extend = |time: Option<DateTime<Local>>,
+                      interval: Option<u16>,
+                      values: Option<Vec<i64>>,
+                      channel: Channel| {
+        let (time, values) = match (time, values) {
+            (Some(time), Some(values)) => (time, values),
+            _ => return,
+        };
+        let interval = Duration::seconds(interval.unwrap_or(1).max(1) as i64);
+        for (index, value) in values.into_iter().enumerate() {
+            let value = match channel {
+                Channel::Stress => stress_value(value),
+                Channel::BodyBattery => body_battery_value(value),
+            };
+            samples.push((time + interval * index as i32, channel, value));
+        }
+    };
+    for record in records {
+        match record.kind() {
+            MesgNum::StressLevel => {
+                if let Ok(message) = StressLevelMessage::try_from(record) {
+                    let values = message.stress_level_value.map(|v| vec![v as i64]);
+                    extend(
+                        message.stress_level_time.as_ref().map(to_chrono),
+                        None,
+                        values,
+                        Channel::Stress,
+                    );
+                }
+            }
+            MesgNum::HsaStressData => {
+                if let Ok(message) = HsaStressDataMessage::try_from(record) {
+                    let values = message
+                        .stress_level
+                        .map(|v| v.into_iter().map(i64::from).collect());
+                    let interval = message.processing_interval;
+                    extend(
+                        message.timestamp.as_ref().map(to_chrono),
+                        interval,
+                        values,
+                        Channel::Stress,
+                    );
+                }
+            }
+            MesgNum::HsaBodyBatteryData => {
+                if let Ok(message) = HsaBodyBatteryDataMessage::try_from(record) {
+                    let values = message
+                        .level
+                        .map(|v| v.into_iter().map(i64::from).collect());
+                    let interval = message.processing_interval;
+                    extend(
+                        message.timestamp.as_ref().map(to_chrono),
+                        interval,
+                        values,
+                        Channel::BodyBattery,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+    samples
+}
+
+/// Build the stress and body battery curves of every local day with a sample, sorted by date.
+/// Days are divided into slots of `interval` seconds starting at local midnight, a slot takes
+/// the last sample written for it and slots without any sample are `Missing`. Returns no curves
+/// for a non-positive interval.
+pub fn daily_curves(records: &[FitDataRecord], clock: &DayClock, interval: f64) -> Vec<DailyCurve> {
+    let interval = Duration::milliseconds((interval * 1000.0) as i64);
+    if interval <= Duration::zero() {
+        return Vec::new();
+    }
+    let mut curves: BTreeMap<NaiveDate, DailyCurve> = BTreeMap::new();
+    for (time, channel, value) in samples(records) {
+        let date = clock.day(time);
+        let curve = curves.entry(date).or_insert_with(|| {
+            let (start, end) = (clock.day_start(date), clock.day_end(date));
+            let mut times = Vec::new();
+            let mut slot = start;
+            while slot < end {
+                times.push(slot);
+                slot += interval;
+            }
+            DailyCurve {
+                date,
+                stress: vec![CurveValue::Missing; times.len()],
+                body_battery: vec![CurveValue::Missing; times.len()],
+                times,
+            }
+        });
+        let start = match curve.times.first() {
+            Some(start) => *start,
+            None => continue,
+        };
+        let slot = ((time - start).num_milliseconds() / interval.num_milliseconds()) as usize;
+        let slot = slot.min(curve.times.len() - 1);
+        match channel {
+            Channel::Stress => curve.stress[slot] = value,
+            Channel::BodyBattery => curve.body_battery[slot] = value,
+        }
+    }
+    curves.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::from_chrono;
+    use crate::{FitDataField, Value};
+    use chrono::{FixedOffset, TimeZone, Utc};
+
+    #[test]
+    fn stress_curves() {
+        let midnight = Local.from_utc_datetime(
+            &Utc.with_ymd_and_hms(2021, 6, 1, 0, 0, 0)
+                .unwrap()
+                .naive_utc(),
+        );
+        let field = |name: &str, number, value| {
+            FitDataField::new(name.to_string(), number, value, String::new())
+        };
+        // single scores every 3 minutes, the device is taken off and then records motion
+        let mut records: Vec<FitDataRecord> = [30, -1, -1, -2, 45]
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let time = midnight + Duration::minutes(3 * index as i64 + 1);
+                let mut record = FitDataRecord::new(MesgNum::StressLevel);
+                record.push(field("stress_level_value", 0, Value::SInt16(value)));
+                record.push(field(
+                    "stress_level_time",
+                    1,
+                    Value::Timestamp(from_chrono(time)),
+                ));
+                record
+            })
+            .collect();
+        let mut battery = FitDataRecord::new(MesgNum::HsaBodyBatteryData);
+        battery.push(field("processing_interval", 0, Value::UInt16(180)));
+        battery.push(field(
+            "level",
+            1,
+            Value::Array([80, 79, -16].map(Value::SInt8).to_vec()),
+        ));
+        battery.push(field(
+            "timestamp",
+            253,
+            Value::Timestamp(from_chrono(midnight)),
+        ));
+        records.push(battery);
+
+        let clock = DayClock::fixed(FixedOffset::east_opt(0).unwrap());
+        let curves = daily_curves(&records, &clock, 180.0);
+        assert_eq!(curves.len(), 1);
+        let curve = &curves[0];
+        assert_eq!(curve.times.len(), 480);
+        assert_eq!(curve.times[1], midnight + Duration::minutes(3));
+        assert_eq!(
+            curve.stress[..6],
+            [
+                CurveValue::Score(30),
+                CurveValue::Gap(GapReason::OffWrist),
+                CurveValue::Gap(GapReason::OffWrist),
+                CurveValue::Gap(GapReason::Motion),
+                CurveValue::Score(45),
+                CurveValue::Missing,
+            ]
+        );
+        assert_eq!(curve.average_stress(), Some(37.5));
+        assert_eq!(curve.off_wrist_slots(), 2);
+        assert_eq!(
+            curve.body_battery[..4],
+            [
+                CurveValue::Score(80),
+                CurveValue::Score(79),
+                CurveValue::Gap(GapReason::Invalid),
+                CurveValue::Missing,
+            ]
+        );
+        assert_eq!(curve.average_body_battery(), Some(79.5));
+        assert!(daily_curves(&records, &clock, 0.0).is_empty());
+    }
+}
@@ -0,0 +1,14689 @@
+#![doc = "Auto generated profile information used to encode messages from FIT SDK Release: 21.141.00"]
+use super::field_types::{FieldDataType, MesgNum};
+use super::{FieldInfo, SubField};
+#[doc = " Look up a field by the name it's decoded with, subfields are found by their own name"]
+pub fn field_info(mesg_num: MesgNum, name: &str) -> Option<FieldInfo> {
+    match mesg_num {
+        MesgNum::FileId => match name {
+            "type" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::File,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "manufacturer" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::Manufacturer,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "favero_product" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::FaveroProduct,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "garmin_product" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::GarminProduct,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "product" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "serial_number" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32z,
+                140u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "time_created" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "number" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "product_name" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::FileCreator => match name {
+            "software_version" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "hardware_version" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::TimestampCorrelation => match name {
+            "fractional_timestamp" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                32768f64,
+                0f64,
+                "s",
+            )),
+            "system_timestamp" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "fractional_system_timestamp" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                32768f64,
+                0f64,
+                "s",
+            )),
+            "local_timestamp" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::LocalDateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "timestamp_ms" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "system_timestamp_ms" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::Software => match name {
+            "version" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "",
+            )),
+            "part_number" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::SlaveDevice => match name {
+            "manufacturer" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Manufacturer,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "favero_product" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::FaveroProduct,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "garmin_product" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::GarminProduct,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "product" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Capabilities => match name {
+            "languages" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sports" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SportBits0,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "workouts_supported" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::WorkoutCapabilities,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "connectivity_supported" => Some(FieldInfo::new(
+                23u8,
+                FieldDataType::ConnectivityCapabilities,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::FileCapabilities => match name {
+            "type" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::File,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "flags" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::FileFlags,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "directory" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "max_count" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "max_size" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "bytes",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::MesgCapabilities => match name {
+            "file" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::File,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "mesg_num" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::MesgNum,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "count_type" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::MesgCount,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "num_per_file" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "max_per_file" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "max_per_file_type" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "count" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::FieldCapabilities => match name {
+            "file" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::File,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "mesg_num" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::MesgNum,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "field_num" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "count" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::DeviceSettings => match name {
+            "active_time_zone" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "utc_offset" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "time_offset" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "time_mode" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::TimeMode,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "time_zone_offset" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::SInt8,
+                1u8,
+                4f64,
+                0f64,
+                "hr",
+            )),
+            "backlight_mode" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::BacklightMode,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "activity_tracker_enabled" => Some(FieldInfo::new(
+                36u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "clock_time" => Some(FieldInfo::new(
+                39u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "pages_enabled" => Some(FieldInfo::new(
+                40u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "move_alert_enabled" => Some(FieldInfo::new(
+                46u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "date_mode" => Some(FieldInfo::new(
+                47u8,
+                FieldDataType::DateMode,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "display_orientation" => Some(FieldInfo::new(
+                55u8,
+                FieldDataType::DisplayOrientation,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "mounting_side" => Some(FieldInfo::new(
+                56u8,
+                FieldDataType::Side,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "default_page" => Some(FieldInfo::new(
+                57u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "autosync_min_steps" => Some(FieldInfo::new(
+                58u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "steps",
+            )),
+            "autosync_min_time" => Some(FieldInfo::new(
+                59u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "minutes",
+            )),
+            "lactate_threshold_autodetect_enabled" => Some(FieldInfo::new(
+                80u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "ble_auto_upload_enabled" => Some(FieldInfo::new(
+                86u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "auto_sync_frequency" => Some(FieldInfo::new(
+                89u8,
+                FieldDataType::AutoSyncFrequency,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "auto_activity_detect" => Some(FieldInfo::new(
+                90u8,
+                FieldDataType::AutoActivityDetect,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "number_of_screens" => Some(FieldInfo::new(
+                94u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "smart_notification_display_orientation" => Some(FieldInfo::new(
+                95u8,
+                FieldDataType::DisplayOrientation,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "tap_interface" => Some(FieldInfo::new(
+                134u8,
+                FieldDataType::Switch,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "tap_sensitivity" => Some(FieldInfo::new(
+                174u8,
+                FieldDataType::TapSensitivity,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::UserProfile => match name {
+            "friendly_name" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "gender" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::Gender,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "age" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "years",
+            )),
+            "height" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "weight" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "kg",
+            )),
+            "language" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::Language,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "elev_setting" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::DisplayMeasure,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "weight_setting" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::DisplayMeasure,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "resting_heart_rate" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "default_max_running_heart_rate" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "default_max_biking_heart_rate" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "default_max_heart_rate" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "hr_setting" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::DisplayHeart,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "speed_setting" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::DisplayMeasure,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "dist_setting" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::DisplayMeasure,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "power_setting" => Some(FieldInfo::new(
+                16u8,
+                FieldDataType::DisplayPower,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "activity_class" => Some(FieldInfo::new(
+                17u8,
+                FieldDataType::ActivityClass,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "position_setting" => Some(FieldInfo::new(
+                18u8,
+                FieldDataType::DisplayPosition,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "temperature_setting" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::DisplayMeasure,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "local_id" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::UserLocalId,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "global_id" => Some(FieldInfo::new(
+                23u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "wake_time" => Some(FieldInfo::new(
+                28u8,
+                FieldDataType::LocaltimeIntoDay,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sleep_time" => Some(FieldInfo::new(
+                29u8,
+                FieldDataType::LocaltimeIntoDay,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "height_setting" => Some(FieldInfo::new(
+                30u8,
+                FieldDataType::DisplayMeasure,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "user_running_step_length" => Some(FieldInfo::new(
+                31u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "user_walking_step_length" => Some(FieldInfo::new(
+                32u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "depth_setting" => Some(FieldInfo::new(
+                47u8,
+                FieldDataType::DisplayMeasure,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "dive_count" => Some(FieldInfo::new(
+                49u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::HrmProfile => match name {
+            "enabled" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "hrm_ant_id" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16z,
+                139u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "log_hrv" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "hrm_ant_id_trans_type" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::SdmProfile => match name {
+            "enabled" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sdm_ant_id" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16z,
+                139u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sdm_cal_factor" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "%",
+            )),
+            "odometer" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "speed_source" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sdm_ant_id_trans_type" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "odometer_rollover" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::BikeProfile => match name {
+            "name" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sport" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::Sport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sub_sport" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::SubSport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "odometer" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "bike_spd_ant_id" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16z,
+                139u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "bike_cad_ant_id" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt16z,
+                139u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "bike_spdcad_ant_id" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt16z,
+                139u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "bike_power_ant_id" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt16z,
+                139u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "custom_wheelsize" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "auto_wheelsize" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "bike_weight" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "kg",
+            )),
+            "power_cal_factor" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "%",
+            )),
+            "auto_wheel_cal" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "auto_power_zero" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "id" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "spd_enabled" => Some(FieldInfo::new(
+                15u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "cad_enabled" => Some(FieldInfo::new(
+                16u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "spdcad_enabled" => Some(FieldInfo::new(
+                17u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "power_enabled" => Some(FieldInfo::new(
+                18u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "crank_length" => Some(FieldInfo::new(
+                19u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "mm",
+            )),
+            "enabled" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "bike_spd_ant_id_trans_type" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "bike_cad_ant_id_trans_type" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "bike_spdcad_ant_id_trans_type" => Some(FieldInfo::new(
+                23u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "bike_power_ant_id_trans_type" => Some(FieldInfo::new(
+                24u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "odometer_rollover" => Some(FieldInfo::new(
+                37u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "front_gear_num" => Some(FieldInfo::new(
+                38u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "front_gear" => Some(FieldInfo::new(
+                39u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "rear_gear_num" => Some(FieldInfo::new(
+                40u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "rear_gear" => Some(FieldInfo::new(
+                41u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "shimano_di2_enabled" => Some(FieldInfo::new(
+                44u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Connectivity => match name {
+            "bluetooth_enabled" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "bluetooth_le_enabled" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "ant_enabled" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "name" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "live_tracking_enabled" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "weather_conditions_enabled" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "weather_alerts_enabled" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "auto_activity_upload_enabled" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "course_download_enabled" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "workout_download_enabled" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "gps_ephemeris_download_enabled" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "incident_detection_enabled" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "grouptrack_enabled" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::WatchfaceSettings => match name {
+            "mode" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::WatchfaceMode,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "digital_layout" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::DigitalWatchfaceLayout,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "analog_layout" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::AnalogWatchfaceLayout,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "layout" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::OhrSettings => match name {
+            "enabled" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Switch,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::TimeInZone => match name {
+            "reference_mesg" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::MesgNum,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "reference_index" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "time_in_hr_zone" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "time_in_speed_zone" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "time_in_cadence_zone" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "time_in_power_zone" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "hr_zone_high_boundary" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "speed_zone_high_boundary" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "cadence_zone_high_bondary" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "power_zone_high_boundary" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "hr_calc_type" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::HrZoneCalc,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "max_heart_rate" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "resting_heart_rate" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "threshold_heart_rate" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "pwr_calc_type" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::PwrZoneCalc,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "functional_threshold_power" => Some(FieldInfo::new(
+                15u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::ZonesTarget => match name {
+            "max_heart_rate" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "threshold_heart_rate" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "functional_threshold_power" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "hr_calc_type" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::HrZoneCalc,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "pwr_calc_type" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::PwrZoneCalc,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Sport => match name {
+            "sport" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Sport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sub_sport" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SubSport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "name" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::HrZone => match name {
+            "high_bpm" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "name" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::SpeedZone => match name {
+            "high_value" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "name" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::CadenceZone => match name {
+            "high_value" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "name" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::PowerZone => match name {
+            "high_value" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "name" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::MetZone => match name {
+            "high_bpm" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "calories" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "kcal / min",
+            )),
+            "fat_calories" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                10f64,
+                0f64,
+                "kcal / min",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::DiveSettings => match name {
+            "name" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "model" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::TissueModelType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "gf_low" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "gf_high" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "water_type" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::WaterType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "water_density" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "kg/m^3",
+            )),
+            "po2_warn" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "po2_critical" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "po2_deco" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "safety_stop_enabled" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "bottom_depth" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "bottom_time" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "apnea_countdown_enabled" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "apnea_countdown_time" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "backlight_mode" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::DiveBacklightMode,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "backlight_brightness" => Some(FieldInfo::new(
+                15u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "backlight_timeout" => Some(FieldInfo::new(
+                16u8,
+                FieldDataType::BacklightTimeout,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "repeat_dive_interval" => Some(FieldInfo::new(
+                17u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "safety_stop_time" => Some(FieldInfo::new(
+                18u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "heart_rate_source_type" => Some(FieldInfo::new(
+                19u8,
+                FieldDataType::SourceType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "heart_rate_antplus_device_type" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::AntplusDeviceType,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "heart_rate_local_device_type" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::LocalDeviceType,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "heart_rate_source" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "travel_gas" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "ccr_low_setpoint_switch_mode" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::CcrSetpointSwitchMode,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "ccr_low_setpoint" => Some(FieldInfo::new(
+                23u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "ccr_low_setpoint_depth" => Some(FieldInfo::new(
+                24u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "ccr_high_setpoint_switch_mode" => Some(FieldInfo::new(
+                25u8,
+                FieldDataType::CcrSetpointSwitchMode,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "ccr_high_setpoint" => Some(FieldInfo::new(
+                26u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "ccr_high_setpoint_depth" => Some(FieldInfo::new(
+                27u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "gas_consumption_display" => Some(FieldInfo::new(
+                29u8,
+                FieldDataType::GasConsumptionRateType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "up_key_enabled" => Some(FieldInfo::new(
+                30u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "dive_sounds" => Some(FieldInfo::new(
+                35u8,
+                FieldDataType::Tone,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "last_stop_multiple" => Some(FieldInfo::new(
+                36u8,
+                FieldDataType::UInt8,
+                2u8,
+                10f64,
+                0f64,
+                "",
+            )),
+            "no_fly_time_mode" => Some(FieldInfo::new(
+                37u8,
+                FieldDataType::NoFlyTimeMode,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::DiveAlarm => match name {
+            "depth" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "time" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "enabled" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "alarm_type" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::DiveAlarmType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sound" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::Tone,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "dive_types" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::SubSport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "id" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "popup_enabled" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "trigger_on_descent" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "trigger_on_ascent" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "repeating" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "speed" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::SInt32,
+                133u8,
+                1000f64,
+                0f64,
+                "mps",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::DiveApneaAlarm => match name {
+            "depth" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "time" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "enabled" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "alarm_type" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::DiveAlarmType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sound" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::Tone,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "dive_types" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::SubSport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "id" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "popup_enabled" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "trigger_on_descent" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "trigger_on_ascent" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "repeating" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "speed" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::SInt32,
+                133u8,
+                1000f64,
+                0f64,
+                "mps",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::DiveGas => match name {
+            "helium_content" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "oxygen_content" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "status" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::DiveGasStatus,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "mode" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::DiveGasMode,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Goal => match name {
+            "sport" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Sport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sub_sport" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SubSport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_date" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "end_date" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "type" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::Goal,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "value" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "repeat" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "target_value" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "recurrence" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::GoalRecurrence,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "recurrence_value" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "enabled" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "source" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::GoalSource,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Activity => match name {
+            "total_timer_time" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "num_sessions" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "type" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::Activity,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "event" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::Event,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "event_type" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::EventType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "local_timestamp" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::LocalDateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "event_group" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Session => match name {
+            "event" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Event,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "event_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::EventType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_time" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_position_lat" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "start_position_long" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "sport" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::Sport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sub_sport" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::SubSport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "total_elapsed_time" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "total_timer_time" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "total_distance" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "total_strides" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "strides",
+            )),
+            "total_strokes" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "strokes",
+            )),
+            "total_cycles" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "cycles",
+            )),
+            "total_calories" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "kcal",
+            )),
+            "total_fat_calories" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "kcal",
+            )),
+            "avg_speed" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "max_speed" => Some(FieldInfo::new(
+                15u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "avg_heart_rate" => Some(FieldInfo::new(
+                16u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "max_heart_rate" => Some(FieldInfo::new(
+                17u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "avg_running_cadence" => Some(FieldInfo::new(
+                18u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "strides/min",
+            )),
+            "avg_cadence" => Some(FieldInfo::new(
+                18u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "max_running_cadence" => Some(FieldInfo::new(
+                19u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "strides/min",
+            )),
+            "max_cadence" => Some(FieldInfo::new(
+                19u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "avg_power" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "max_power" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "total_ascent" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "total_descent" => Some(FieldInfo::new(
+                23u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "total_training_effect" => Some(FieldInfo::new(
+                24u8,
+                FieldDataType::UInt8,
+                2u8,
+                10f64,
+                0f64,
+                "",
+            )),
+            "first_lap_index" => Some(FieldInfo::new(
+                25u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "num_laps" => Some(FieldInfo::new(
+                26u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "event_group" => Some(FieldInfo::new(
+                27u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "trigger" => Some(FieldInfo::new(
+                28u8,
+                FieldDataType::SessionTrigger,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "nec_lat" => Some(FieldInfo::new(
+                29u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "nec_long" => Some(FieldInfo::new(
+                30u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "swc_lat" => Some(FieldInfo::new(
+                31u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "swc_long" => Some(FieldInfo::new(
+                32u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "num_lengths" => Some(FieldInfo::new(
+                33u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "lengths",
+            )),
+            "normalized_power" => Some(FieldInfo::new(
+                34u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "training_stress_score" => Some(FieldInfo::new(
+                35u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "tss",
+            )),
+            "intensity_factor" => Some(FieldInfo::new(
+                36u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "if",
+            )),
+            "left_right_balance" => Some(FieldInfo::new(
+                37u8,
+                FieldDataType::LeftRightBalance100,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "end_position_lat" => Some(FieldInfo::new(
+                38u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "end_position_long" => Some(FieldInfo::new(
+                39u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "avg_stroke_count" => Some(FieldInfo::new(
+                41u8,
+                FieldDataType::UInt32,
+                134u8,
+                10f64,
+                0f64,
+                "strokes/lap",
+            )),
+            "avg_stroke_distance" => Some(FieldInfo::new(
+                42u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "swim_stroke" => Some(FieldInfo::new(
+                43u8,
+                FieldDataType::SwimStroke,
+                0u8,
+                1f64,
+                0f64,
+                "swim_stroke",
+            )),
+            "pool_length" => Some(FieldInfo::new(
+                44u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "threshold_power" => Some(FieldInfo::new(
+                45u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "pool_length_unit" => Some(FieldInfo::new(
+                46u8,
+                FieldDataType::DisplayMeasure,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "num_active_lengths" => Some(FieldInfo::new(
+                47u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "lengths",
+            )),
+            "total_work" => Some(FieldInfo::new(
+                48u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "J",
+            )),
+            "avg_altitude" => Some(FieldInfo::new(
+                49u8,
+                FieldDataType::UInt16,
+                132u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "max_altitude" => Some(FieldInfo::new(
+                50u8,
+                FieldDataType::UInt16,
+                132u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "gps_accuracy" => Some(FieldInfo::new(
+                51u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "avg_grade" => Some(FieldInfo::new(
+                52u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "avg_pos_grade" => Some(FieldInfo::new(
+                53u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "avg_neg_grade" => Some(FieldInfo::new(
+                54u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "max_pos_grade" => Some(FieldInfo::new(
+                55u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "max_neg_grade" => Some(FieldInfo::new(
+                56u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "avg_temperature" => Some(FieldInfo::new(
+                57u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "C",
+            )),
+            "max_temperature" => Some(FieldInfo::new(
+                58u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "C",
+            )),
+            "total_moving_time" => Some(FieldInfo::new(
+                59u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "avg_pos_vertical_speed" => Some(FieldInfo::new(
+                60u8,
+                FieldDataType::SInt16,
+                131u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "avg_neg_vertical_speed" => Some(FieldInfo::new(
+                61u8,
+                FieldDataType::SInt16,
+                131u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "max_pos_vertical_speed" => Some(FieldInfo::new(
+                62u8,
+                FieldDataType::SInt16,
+                131u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "max_neg_vertical_speed" => Some(FieldInfo::new(
+                63u8,
+                FieldDataType::SInt16,
+                131u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "min_heart_rate" => Some(FieldInfo::new(
+                64u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "time_in_hr_zone" => Some(FieldInfo::new(
+                65u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "time_in_speed_zone" => Some(FieldInfo::new(
+                66u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "time_in_cadence_zone" => Some(FieldInfo::new(
+                67u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "time_in_power_zone" => Some(FieldInfo::new(
+                68u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "avg_lap_time" => Some(FieldInfo::new(
+                69u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "best_lap_index" => Some(FieldInfo::new(
+                70u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "min_altitude" => Some(FieldInfo::new(
+                71u8,
+                FieldDataType::UInt16,
+                132u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "player_score" => Some(FieldInfo::new(
+                82u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "opponent_score" => Some(FieldInfo::new(
+                83u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "opponent_name" => Some(FieldInfo::new(
+                84u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "stroke_count" => Some(FieldInfo::new(
+                85u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "zone_count" => Some(FieldInfo::new(
+                86u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "max_ball_speed" => Some(FieldInfo::new(
+                87u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "m/s",
+            )),
+            "avg_ball_speed" => Some(FieldInfo::new(
+                88u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "m/s",
+            )),
+            "avg_vertical_oscillation" => Some(FieldInfo::new(
+                89u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "mm",
+            )),
+            "avg_stance_time_percent" => Some(FieldInfo::new(
+                90u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "avg_stance_time" => Some(FieldInfo::new(
+                91u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "ms",
+            )),
+            "avg_fractional_cadence" => Some(FieldInfo::new(
+                92u8,
+                FieldDataType::UInt8,
+                2u8,
+                128f64,
+                0f64,
+                "rpm",
+            )),
+            "max_fractional_cadence" => Some(FieldInfo::new(
+                93u8,
+                FieldDataType::UInt8,
+                2u8,
+                128f64,
+                0f64,
+                "rpm",
+            )),
+            "total_fractional_cycles" => Some(FieldInfo::new(
+                94u8,
+                FieldDataType::UInt8,
+                2u8,
+                128f64,
+                0f64,
+                "cycles",
+            )),
+            "avg_total_hemoglobin_conc" => Some(FieldInfo::new(
+                95u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "g/dL",
+            )),
+            "min_total_hemoglobin_conc" => Some(FieldInfo::new(
+                96u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "g/dL",
+            )),
+            "max_total_hemoglobin_conc" => Some(FieldInfo::new(
+                97u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "g/dL",
+            )),
+            "avg_saturated_hemoglobin_percent" => Some(FieldInfo::new(
+                98u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "%",
+            )),
+            "min_saturated_hemoglobin_percent" => Some(FieldInfo::new(
+                99u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "%",
+            )),
+            "max_saturated_hemoglobin_percent" => Some(FieldInfo::new(
+                100u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "%",
+            )),
+            "avg_left_torque_effectiveness" => Some(FieldInfo::new(
+                101u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_right_torque_effectiveness" => Some(FieldInfo::new(
+                102u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_left_pedal_smoothness" => Some(FieldInfo::new(
+                103u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_right_pedal_smoothness" => Some(FieldInfo::new(
+                104u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_combined_pedal_smoothness" => Some(FieldInfo::new(
+                105u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "sport_profile_name" => Some(FieldInfo::new(
+                110u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sport_index" => Some(FieldInfo::new(
+                111u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "time_standing" => Some(FieldInfo::new(
+                112u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "stand_count" => Some(FieldInfo::new(
+                113u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "avg_left_pco" => Some(FieldInfo::new(
+                114u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "mm",
+            )),
+            "avg_right_pco" => Some(FieldInfo::new(
+                115u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "mm",
+            )),
+            "avg_left_power_phase" => Some(FieldInfo::new(
+                116u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "avg_left_power_phase_peak" => Some(FieldInfo::new(
+                117u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "avg_right_power_phase" => Some(FieldInfo::new(
+                118u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "avg_right_power_phase_peak" => Some(FieldInfo::new(
+                119u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "avg_power_position" => Some(FieldInfo::new(
+                120u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "max_power_position" => Some(FieldInfo::new(
+                121u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "avg_cadence_position" => Some(FieldInfo::new(
+                122u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "max_cadence_position" => Some(FieldInfo::new(
+                123u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "enhanced_avg_speed" => Some(FieldInfo::new(
+                124u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "enhanced_max_speed" => Some(FieldInfo::new(
+                125u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "enhanced_avg_altitude" => Some(FieldInfo::new(
+                126u8,
+                FieldDataType::UInt32,
+                134u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "enhanced_min_altitude" => Some(FieldInfo::new(
+                127u8,
+                FieldDataType::UInt32,
+                134u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "enhanced_max_altitude" => Some(FieldInfo::new(
+                128u8,
+                FieldDataType::UInt32,
+                134u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "avg_lev_motor_power" => Some(FieldInfo::new(
+                129u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "max_lev_motor_power" => Some(FieldInfo::new(
+                130u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "lev_battery_consumption" => Some(FieldInfo::new(
+                131u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_vertical_ratio" => Some(FieldInfo::new(
+                132u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "avg_stance_time_balance" => Some(FieldInfo::new(
+                133u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "avg_step_length" => Some(FieldInfo::new(
+                134u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "mm",
+            )),
+            "total_anaerobic_training_effect" => Some(FieldInfo::new(
+                137u8,
+                FieldDataType::UInt8,
+                2u8,
+                10f64,
+                0f64,
+                "",
+            )),
+            "avg_vam" => Some(FieldInfo::new(
+                139u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "avg_depth" => Some(FieldInfo::new(
+                140u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "max_depth" => Some(FieldInfo::new(
+                141u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "surface_interval" => Some(FieldInfo::new(
+                142u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "start_cns" => Some(FieldInfo::new(
+                143u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "end_cns" => Some(FieldInfo::new(
+                144u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "start_n2" => Some(FieldInfo::new(
+                145u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "end_n2" => Some(FieldInfo::new(
+                146u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "avg_respiration_rate" => Some(FieldInfo::new(
+                147u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "max_respiration_rate" => Some(FieldInfo::new(
+                148u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "min_respiration_rate" => Some(FieldInfo::new(
+                149u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "min_temperature" => Some(FieldInfo::new(
+                150u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "C",
+            )),
+            "o2_toxicity" => Some(FieldInfo::new(
+                155u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "OTUs",
+            )),
+            "dive_number" => Some(FieldInfo::new(
+                156u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "training_load_peak" => Some(FieldInfo::new(
+                168u8,
+                FieldDataType::SInt32,
+                133u8,
+                65536f64,
+                0f64,
+                "",
+            )),
+            "enhanced_avg_respiration_rate" => Some(FieldInfo::new(
+                169u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "Breaths/min",
+            )),
+            "enhanced_max_respiration_rate" => Some(FieldInfo::new(
+                170u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "Breaths/min",
+            )),
+            "enhanced_min_respiration_rate" => Some(FieldInfo::new(
+                180u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "",
+            )),
+            "total_grit" => Some(FieldInfo::new(
+                181u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "kGrit",
+            )),
+            "total_flow" => Some(FieldInfo::new(
+                182u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "Flow",
+            )),
+            "jump_count" => Some(FieldInfo::new(
+                183u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "avg_grit" => Some(FieldInfo::new(
+                186u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "kGrit",
+            )),
+            "avg_flow" => Some(FieldInfo::new(
+                187u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "Flow",
+            )),
+            "workout_feel" => Some(FieldInfo::new(
+                192u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "workout_rpe" => Some(FieldInfo::new(
+                193u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "avg_spo2" => Some(FieldInfo::new(
+                194u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "avg_stress" => Some(FieldInfo::new(
+                195u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "sdrr_hrv" => Some(FieldInfo::new(
+                197u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "mS",
+            )),
+            "rmssd_hrv" => Some(FieldInfo::new(
+                198u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "mS",
+            )),
+            "total_fractional_ascent" => Some(FieldInfo::new(
+                199u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "total_fractional_descent" => Some(FieldInfo::new(
+                200u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "avg_core_temperature" => Some(FieldInfo::new(
+                208u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "C",
+            )),
+            "min_core_temperature" => Some(FieldInfo::new(
+                209u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "C",
+            )),
+            "max_core_temperature" => Some(FieldInfo::new(
+                210u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "C",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Lap => match name {
+            "event" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Event,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "event_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::EventType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_time" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_position_lat" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "start_position_long" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "end_position_lat" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "end_position_long" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "total_elapsed_time" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "total_timer_time" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "total_distance" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "total_strides" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "strides",
+            )),
+            "total_strokes" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "strokes",
+            )),
+            "total_cycles" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "cycles",
+            )),
+            "total_calories" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "kcal",
+            )),
+            "total_fat_calories" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "kcal",
+            )),
+            "avg_speed" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "max_speed" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "avg_heart_rate" => Some(FieldInfo::new(
+                15u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "max_heart_rate" => Some(FieldInfo::new(
+                16u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "avg_running_cadence" => Some(FieldInfo::new(
+                17u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "strides/min",
+            )),
+            "avg_cadence" => Some(FieldInfo::new(
+                17u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "max_running_cadence" => Some(FieldInfo::new(
+                18u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "strides/min",
+            )),
+            "max_cadence" => Some(FieldInfo::new(
+                18u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "avg_power" => Some(FieldInfo::new(
+                19u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "max_power" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "total_ascent" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "total_descent" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "intensity" => Some(FieldInfo::new(
+                23u8,
+                FieldDataType::Intensity,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "lap_trigger" => Some(FieldInfo::new(
+                24u8,
+                FieldDataType::LapTrigger,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sport" => Some(FieldInfo::new(
+                25u8,
+                FieldDataType::Sport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "event_group" => Some(FieldInfo::new(
+                26u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "num_lengths" => Some(FieldInfo::new(
+                32u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "lengths",
+            )),
+            "normalized_power" => Some(FieldInfo::new(
+                33u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "left_right_balance" => Some(FieldInfo::new(
+                34u8,
+                FieldDataType::LeftRightBalance100,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "first_length_index" => Some(FieldInfo::new(
+                35u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "avg_stroke_distance" => Some(FieldInfo::new(
+                37u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "swim_stroke" => Some(FieldInfo::new(
+                38u8,
+                FieldDataType::SwimStroke,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sub_sport" => Some(FieldInfo::new(
+                39u8,
+                FieldDataType::SubSport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "num_active_lengths" => Some(FieldInfo::new(
+                40u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "lengths",
+            )),
+            "total_work" => Some(FieldInfo::new(
+                41u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "J",
+            )),
+            "avg_altitude" => Some(FieldInfo::new(
+                42u8,
+                FieldDataType::UInt16,
+                132u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "max_altitude" => Some(FieldInfo::new(
+                43u8,
+                FieldDataType::UInt16,
+                132u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "gps_accuracy" => Some(FieldInfo::new(
+                44u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "avg_grade" => Some(FieldInfo::new(
+                45u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "avg_pos_grade" => Some(FieldInfo::new(
+                46u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "avg_neg_grade" => Some(FieldInfo::new(
+                47u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "max_pos_grade" => Some(FieldInfo::new(
+                48u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "max_neg_grade" => Some(FieldInfo::new(
+                49u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "avg_temperature" => Some(FieldInfo::new(
+                50u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "C",
+            )),
+            "max_temperature" => Some(FieldInfo::new(
+                51u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "C",
+            )),
+            "total_moving_time" => Some(FieldInfo::new(
+                52u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "avg_pos_vertical_speed" => Some(FieldInfo::new(
+                53u8,
+                FieldDataType::SInt16,
+                131u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "avg_neg_vertical_speed" => Some(FieldInfo::new(
+                54u8,
+                FieldDataType::SInt16,
+                131u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "max_pos_vertical_speed" => Some(FieldInfo::new(
+                55u8,
+                FieldDataType::SInt16,
+                131u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "max_neg_vertical_speed" => Some(FieldInfo::new(
+                56u8,
+                FieldDataType::SInt16,
+                131u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "time_in_hr_zone" => Some(FieldInfo::new(
+                57u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "time_in_speed_zone" => Some(FieldInfo::new(
+                58u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "time_in_cadence_zone" => Some(FieldInfo::new(
+                59u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "time_in_power_zone" => Some(FieldInfo::new(
+                60u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "repetition_num" => Some(FieldInfo::new(
+                61u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "min_altitude" => Some(FieldInfo::new(
+                62u8,
+                FieldDataType::UInt16,
+                132u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "min_heart_rate" => Some(FieldInfo::new(
+                63u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "wkt_step_index" => Some(FieldInfo::new(
+                71u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "opponent_score" => Some(FieldInfo::new(
+                74u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "stroke_count" => Some(FieldInfo::new(
+                75u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "zone_count" => Some(FieldInfo::new(
+                76u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "avg_vertical_oscillation" => Some(FieldInfo::new(
+                77u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "mm",
+            )),
+            "avg_stance_time_percent" => Some(FieldInfo::new(
+                78u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "avg_stance_time" => Some(FieldInfo::new(
+                79u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "ms",
+            )),
+            "avg_fractional_cadence" => Some(FieldInfo::new(
+                80u8,
+                FieldDataType::UInt8,
+                2u8,
+                128f64,
+                0f64,
+                "rpm",
+            )),
+            "max_fractional_cadence" => Some(FieldInfo::new(
+                81u8,
+                FieldDataType::UInt8,
+                2u8,
+                128f64,
+                0f64,
+                "rpm",
+            )),
+            "total_fractional_cycles" => Some(FieldInfo::new(
+                82u8,
+                FieldDataType::UInt8,
+                2u8,
+                128f64,
+                0f64,
+                "cycles",
+            )),
+            "player_score" => Some(FieldInfo::new(
+                83u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "avg_total_hemoglobin_conc" => Some(FieldInfo::new(
+                84u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "g/dL",
+            )),
+            "min_total_hemoglobin_conc" => Some(FieldInfo::new(
+                85u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "g/dL",
+            )),
+            "max_total_hemoglobin_conc" => Some(FieldInfo::new(
+                86u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "g/dL",
+            )),
+            "avg_saturated_hemoglobin_percent" => Some(FieldInfo::new(
+                87u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "%",
+            )),
+            "min_saturated_hemoglobin_percent" => Some(FieldInfo::new(
+                88u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "%",
+            )),
+            "max_saturated_hemoglobin_percent" => Some(FieldInfo::new(
+                89u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "%",
+            )),
+            "avg_left_torque_effectiveness" => Some(FieldInfo::new(
+                91u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_right_torque_effectiveness" => Some(FieldInfo::new(
+                92u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_left_pedal_smoothness" => Some(FieldInfo::new(
+                93u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_right_pedal_smoothness" => Some(FieldInfo::new(
+                94u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_combined_pedal_smoothness" => Some(FieldInfo::new(
+                95u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "time_standing" => Some(FieldInfo::new(
+                98u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "stand_count" => Some(FieldInfo::new(
+                99u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "avg_left_pco" => Some(FieldInfo::new(
+                100u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "mm",
+            )),
+            "avg_right_pco" => Some(FieldInfo::new(
+                101u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "mm",
+            )),
+            "avg_left_power_phase" => Some(FieldInfo::new(
+                102u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "avg_left_power_phase_peak" => Some(FieldInfo::new(
+                103u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "avg_right_power_phase" => Some(FieldInfo::new(
+                104u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "avg_right_power_phase_peak" => Some(FieldInfo::new(
+                105u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "avg_power_position" => Some(FieldInfo::new(
+                106u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "max_power_position" => Some(FieldInfo::new(
+                107u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "avg_cadence_position" => Some(FieldInfo::new(
+                108u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "max_cadence_position" => Some(FieldInfo::new(
+                109u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "enhanced_avg_speed" => Some(FieldInfo::new(
+                110u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "enhanced_max_speed" => Some(FieldInfo::new(
+                111u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "enhanced_avg_altitude" => Some(FieldInfo::new(
+                112u8,
+                FieldDataType::UInt32,
+                134u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "enhanced_min_altitude" => Some(FieldInfo::new(
+                113u8,
+                FieldDataType::UInt32,
+                134u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "enhanced_max_altitude" => Some(FieldInfo::new(
+                114u8,
+                FieldDataType::UInt32,
+                134u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "avg_lev_motor_power" => Some(FieldInfo::new(
+                115u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "max_lev_motor_power" => Some(FieldInfo::new(
+                116u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "lev_battery_consumption" => Some(FieldInfo::new(
+                117u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_vertical_ratio" => Some(FieldInfo::new(
+                118u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "avg_stance_time_balance" => Some(FieldInfo::new(
+                119u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "avg_step_length" => Some(FieldInfo::new(
+                120u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "mm",
+            )),
+            "avg_vam" => Some(FieldInfo::new(
+                121u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "avg_depth" => Some(FieldInfo::new(
+                122u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "max_depth" => Some(FieldInfo::new(
+                123u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "min_temperature" => Some(FieldInfo::new(
+                124u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "C",
+            )),
+            "enhanced_avg_respiration_rate" => Some(FieldInfo::new(
+                136u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "Breaths/min",
+            )),
+            "enhanced_max_respiration_rate" => Some(FieldInfo::new(
+                137u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "Breaths/min",
+            )),
+            "avg_respiration_rate" => Some(FieldInfo::new(
+                147u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "max_respiration_rate" => Some(FieldInfo::new(
+                148u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "total_grit" => Some(FieldInfo::new(
+                149u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "kGrit",
+            )),
+            "total_flow" => Some(FieldInfo::new(
+                150u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "Flow",
+            )),
+            "jump_count" => Some(FieldInfo::new(
+                151u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "avg_grit" => Some(FieldInfo::new(
+                153u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "kGrit",
+            )),
+            "avg_flow" => Some(FieldInfo::new(
+                154u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "Flow",
+            )),
+            "total_fractional_ascent" => Some(FieldInfo::new(
+                156u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "total_fractional_descent" => Some(FieldInfo::new(
+                157u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "avg_core_temperature" => Some(FieldInfo::new(
+                158u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "C",
+            )),
+            "min_core_temperature" => Some(FieldInfo::new(
+                159u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "C",
+            )),
+            "max_core_temperature" => Some(FieldInfo::new(
+                160u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "C",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Length => match name {
+            "event" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Event,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "event_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::EventType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_time" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "total_elapsed_time" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "total_timer_time" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "total_strokes" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "strokes",
+            )),
+            "avg_speed" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "swim_stroke" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::SwimStroke,
+                0u8,
+                1f64,
+                0f64,
+                "swim_stroke",
+            )),
+            "avg_swimming_cadence" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "strokes/min",
+            )),
+            "event_group" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "total_calories" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "kcal",
+            )),
+            "length_type" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::LengthType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "player_score" => Some(FieldInfo::new(
+                18u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "opponent_score" => Some(FieldInfo::new(
+                19u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "stroke_count" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "zone_count" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "enhanced_avg_respiration_rate" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "Breaths/min",
+            )),
+            "enhanced_max_respiration_rate" => Some(FieldInfo::new(
+                23u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "Breaths/min",
+            )),
+            "avg_respiration_rate" => Some(FieldInfo::new(
+                24u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "max_respiration_rate" => Some(FieldInfo::new(
+                25u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Record => match name {
+            "position_lat" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "position_long" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "altitude" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "heart_rate" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "cadence" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "distance" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "speed" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "power" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "compressed_speed_distance" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "m/s,m",
+            )),
+            "grade" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "resistance" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "time_from_course" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::SInt32,
+                133u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "cycle_length" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "temperature" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "C",
+            )),
+            "speed_1s" => Some(FieldInfo::new(
+                17u8,
+                FieldDataType::UInt8,
+                2u8,
+                16f64,
+                0f64,
+                "m/s",
+            )),
+            "cycles" => Some(FieldInfo::new(
+                18u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "cycles",
+            )),
+            "total_cycles" => Some(FieldInfo::new(
+                19u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "cycles",
+            )),
+            "compressed_accumulated_power" => Some(FieldInfo::new(
+                28u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "accumulated_power" => Some(FieldInfo::new(
+                29u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "left_right_balance" => Some(FieldInfo::new(
+                30u8,
+                FieldDataType::LeftRightBalance,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "gps_accuracy" => Some(FieldInfo::new(
+                31u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "vertical_speed" => Some(FieldInfo::new(
+                32u8,
+                FieldDataType::SInt16,
+                131u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "calories" => Some(FieldInfo::new(
+                33u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "kcal",
+            )),
+            "vertical_oscillation" => Some(FieldInfo::new(
+                39u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "mm",
+            )),
+            "stance_time_percent" => Some(FieldInfo::new(
+                40u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "stance_time" => Some(FieldInfo::new(
+                41u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "ms",
+            )),
+            "activity_type" => Some(FieldInfo::new(
+                42u8,
+                FieldDataType::ActivityType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "left_torque_effectiveness" => Some(FieldInfo::new(
+                43u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "right_torque_effectiveness" => Some(FieldInfo::new(
+                44u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "left_pedal_smoothness" => Some(FieldInfo::new(
+                45u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "right_pedal_smoothness" => Some(FieldInfo::new(
+                46u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "combined_pedal_smoothness" => Some(FieldInfo::new(
+                47u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "time128" => Some(FieldInfo::new(
+                48u8,
+                FieldDataType::UInt8,
+                2u8,
+                128f64,
+                0f64,
+                "s",
+            )),
+            "stroke_type" => Some(FieldInfo::new(
+                49u8,
+                FieldDataType::StrokeType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "zone" => Some(FieldInfo::new(
+                50u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "ball_speed" => Some(FieldInfo::new(
+                51u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "m/s",
+            )),
+            "cadence256" => Some(FieldInfo::new(
+                52u8,
+                FieldDataType::UInt16,
+                132u8,
+                256f64,
+                0f64,
+                "rpm",
+            )),
+            "fractional_cadence" => Some(FieldInfo::new(
+                53u8,
+                FieldDataType::UInt8,
+                2u8,
+                128f64,
+                0f64,
+                "rpm",
+            )),
+            "total_hemoglobin_conc" => Some(FieldInfo::new(
+                54u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "g/dL",
+            )),
+            "total_hemoglobin_conc_min" => Some(FieldInfo::new(
+                55u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "g/dL",
+            )),
+            "total_hemoglobin_conc_max" => Some(FieldInfo::new(
+                56u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "g/dL",
+            )),
+            "saturated_hemoglobin_percent" => Some(FieldInfo::new(
+                57u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "%",
+            )),
+            "saturated_hemoglobin_percent_min" => Some(FieldInfo::new(
+                58u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "%",
+            )),
+            "saturated_hemoglobin_percent_max" => Some(FieldInfo::new(
+                59u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "%",
+            )),
+            "device_index" => Some(FieldInfo::new(
+                62u8,
+                FieldDataType::DeviceIndex,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "left_pco" => Some(FieldInfo::new(
+                67u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "mm",
+            )),
+            "right_pco" => Some(FieldInfo::new(
+                68u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "mm",
+            )),
+            "left_power_phase" => Some(FieldInfo::new(
+                69u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "left_power_phase_peak" => Some(FieldInfo::new(
+                70u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "right_power_phase" => Some(FieldInfo::new(
+                71u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "right_power_phase_peak" => Some(FieldInfo::new(
+                72u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "enhanced_speed" => Some(FieldInfo::new(
+                73u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "enhanced_altitude" => Some(FieldInfo::new(
+                78u8,
+                FieldDataType::UInt32,
+                134u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "battery_soc" => Some(FieldInfo::new(
+                81u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "motor_power" => Some(FieldInfo::new(
+                82u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "vertical_ratio" => Some(FieldInfo::new(
+                83u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "stance_time_balance" => Some(FieldInfo::new(
+                84u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "step_length" => Some(FieldInfo::new(
+                85u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "mm",
+            )),
+            "cycle_length16" => Some(FieldInfo::new(
+                87u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "absolute_pressure" => Some(FieldInfo::new(
+                91u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "Pa",
+            )),
+            "depth" => Some(FieldInfo::new(
+                92u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "next_stop_depth" => Some(FieldInfo::new(
+                93u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "next_stop_time" => Some(FieldInfo::new(
+                94u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "time_to_surface" => Some(FieldInfo::new(
+                95u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "ndl_time" => Some(FieldInfo::new(
+                96u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "cns_load" => Some(FieldInfo::new(
+                97u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "n2_load" => Some(FieldInfo::new(
+                98u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "respiration_rate" => Some(FieldInfo::new(
+                99u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "enhanced_respiration_rate" => Some(FieldInfo::new(
+                108u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "Breaths/min",
+            )),
+            "grit" => Some(FieldInfo::new(
+                114u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "flow" => Some(FieldInfo::new(
+                115u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "current_stress" => Some(FieldInfo::new(
+                116u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "",
+            )),
+            "ebike_travel_range" => Some(FieldInfo::new(
+                117u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "km",
+            )),
+            "ebike_battery_level" => Some(FieldInfo::new(
+                118u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "ebike_assist_mode" => Some(FieldInfo::new(
+                119u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "depends on sensor",
+            )),
+            "ebike_assist_level_percent" => Some(FieldInfo::new(
+                120u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "air_time_remaining" => Some(FieldInfo::new(
+                123u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "pressure_sac" => Some(FieldInfo::new(
+                124u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "bar/min",
+            )),
+            "volume_sac" => Some(FieldInfo::new(
+                125u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "L/min",
+            )),
+            "rmv" => Some(FieldInfo::new(
+                126u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "L/min",
+            )),
+            "ascent_rate" => Some(FieldInfo::new(
+                127u8,
+                FieldDataType::SInt32,
+                133u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "po2" => Some(FieldInfo::new(
+                129u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "percent",
+            )),
+            "core_temperature" => Some(FieldInfo::new(
+                139u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "C",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::Event => match name {
+            "event" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Event,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "event_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::EventType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "data16" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timer_trigger" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::TimerTrigger,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "course_point_index" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "battery_level" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "V",
+            )),
+            "virtual_partner_speed" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "hr_high_alert" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "hr_low_alert" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "speed_high_alert" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "speed_low_alert" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "cad_high_alert" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "cad_low_alert" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "power_high_alert" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "power_low_alert" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "time_duration_alert" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "distance_duration_alert" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "calorie_duration_alert" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "calories",
+            )),
+            "fitness_equipment_state" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::FitnessEquipmentState,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sport_point" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "gear_change_data" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "rider_position" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::RiderPositionType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "comm_timeout" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::CommTimeoutType,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "dive_alert" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::DiveAlert,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "auto_activity_detect_duration" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "min",
+            )),
+            "radar_threat_alert" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "data" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "event_group" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "score" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "opponent_score" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "front_gear_num" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "front_gear" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "rear_gear_num" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "rear_gear" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "device_index" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::DeviceIndex,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "activity_type" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::ActivityType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "auto_activity_detect_start_timestamp" => Some(FieldInfo::new(
+                15u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "start_timestamp" => Some(FieldInfo::new(
+                15u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "radar_threat_level_max" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::RadarThreatLevelType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "radar_threat_count" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "radar_threat_avg_approach_speed" => Some(FieldInfo::new(
+                23u8,
+                FieldDataType::UInt8,
+                2u8,
+                10f64,
+                0f64,
+                "m/s",
+            )),
+            "radar_threat_max_approach_speed" => Some(FieldInfo::new(
+                24u8,
+                FieldDataType::UInt8,
+                2u8,
+                10f64,
+                0f64,
+                "m/s",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::DeviceInfo => match name {
+            "device_index" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::DeviceIndex,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "ble_device_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::BleDeviceType,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "antplus_device_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::AntplusDeviceType,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "ant_device_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "local_device_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::LocalDeviceType,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "device_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "manufacturer" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::Manufacturer,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "serial_number" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32z,
+                140u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "favero_product" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::FaveroProduct,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "garmin_product" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::GarminProduct,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "product" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "software_version" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "",
+            )),
+            "hardware_version" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "cum_operating_time" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "battery_voltage" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt16,
+                132u8,
+                256f64,
+                0f64,
+                "V",
+            )),
+            "battery_status" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::BatteryStatus,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sensor_position" => Some(FieldInfo::new(
+                18u8,
+                FieldDataType::BodyLocation,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "descriptor" => Some(FieldInfo::new(
+                19u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "ant_transmission_type" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "ant_device_number" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::UInt16z,
+                139u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "ant_network" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::AntNetwork,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "source_type" => Some(FieldInfo::new(
+                25u8,
+                FieldDataType::SourceType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "product_name" => Some(FieldInfo::new(
+                27u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "battery_level" => Some(FieldInfo::new(
+                32u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "%",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::DeviceAuxBatteryInfo => match name {
+            "device_index" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::DeviceIndex,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "battery_voltage" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                256f64,
+                0f64,
+                "V",
+            )),
+            "battery_status" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::BatteryStatus,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "battery_identifier" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::TrainingFile => match name {
+            "type" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::File,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "manufacturer" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::Manufacturer,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "favero_product" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::FaveroProduct,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "garmin_product" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::GarminProduct,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "product" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "serial_number" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32z,
+                140u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "time_created" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::WeatherConditions => match name {
+            "weather_report" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::WeatherReport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "temperature" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "C",
+            )),
+            "condition" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::WeatherStatus,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "wind_direction" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "degrees",
+            )),
+            "wind_speed" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "precipitation_probability" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "temperature_feels_like" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "C",
+            )),
+            "relative_humidity" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "location" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "observed_at_time" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "observed_location_lat" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "observed_location_long" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "day_of_week" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::DayOfWeek,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "high_temperature" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "C",
+            )),
+            "low_temperature" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "C",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::WeatherAlert => match name {
+            "report_id" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "issue_time" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "expire_time" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "severity" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::WeatherSeverity,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "type" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::WeatherSevereType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::GpsMetadata => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "position_lat" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "position_long" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "enhanced_altitude" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "enhanced_speed" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "heading" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "degrees",
+            )),
+            "utc_timestamp" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "velocity" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "m/s",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::CameraEvent => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "camera_event_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::CameraEventType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "camera_file_uuid" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "camera_orientation" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::CameraOrientationType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::GyroscopeData => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "sample_time_offset" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "gyro_x" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "gyro_y" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "gyro_z" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "calibrated_gyro_x" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "deg/s",
+            )),
+            "calibrated_gyro_y" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "deg/s",
+            )),
+            "calibrated_gyro_z" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "deg/s",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::AccelerometerData => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "sample_time_offset" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "accel_x" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "accel_y" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "accel_z" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "calibrated_accel_x" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "g",
+            )),
+            "calibrated_accel_y" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "g",
+            )),
+            "calibrated_accel_z" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "g",
+            )),
+            "compressed_calibrated_accel_x" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::SInt16,
+                131u8,
+                1f64,
+                0f64,
+                "mG",
+            )),
+            "compressed_calibrated_accel_y" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::SInt16,
+                131u8,
+                1f64,
+                0f64,
+                "mG",
+            )),
+            "compressed_calibrated_accel_z" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::SInt16,
+                131u8,
+                1f64,
+                0f64,
+                "mG",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::MagnetometerData => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "sample_time_offset" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "mag_x" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "mag_y" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "mag_z" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "calibrated_mag_x" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "G",
+            )),
+            "calibrated_mag_y" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "G",
+            )),
+            "calibrated_mag_z" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "G",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::BarometerData => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "sample_time_offset" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "baro_pres" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "Pa",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::ThreeDSensorCalibration => match name {
+            "sensor_type" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::SensorType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "accel_cal_factor" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "g",
+            )),
+            "gyro_cal_factor" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "deg/s",
+            )),
+            "calibration_factor" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "calibration_divisor" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "level_shift" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "offset_cal" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "orientation_matrix" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::SInt32,
+                133u8,
+                65535f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::OneDSensorCalibration => match name {
+            "sensor_type" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::SensorType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "baro_cal_factor" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "Pa",
+            )),
+            "calibration_factor" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "calibration_divisor" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "counts",
+            )),
+            "level_shift" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "offset_cal" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::VideoFrame => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "frame_number" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::ObdiiData => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "time_offset" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "pid" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "raw_data" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "pid_data_size" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "system_time" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_timestamp" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_timestamp_ms" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::NmeaSentence => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "sentence" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::AviationAttitude => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "system_time" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "pitch" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::SInt16,
+                131u8,
+                10430.38f64,
+                0f64,
+                "radians",
+            )),
+            "roll" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::SInt16,
+                131u8,
+                10430.38f64,
+                0f64,
+                "radians",
+            )),
+            "accel_lateral" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "m/s^2",
+            )),
+            "accel_normal" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "m/s^2",
+            )),
+            "turn_rate" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::SInt16,
+                131u8,
+                1024f64,
+                0f64,
+                "radians/second",
+            )),
+            "stage" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::AttitudeStage,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "attitude_stage_complete" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "%",
+            )),
+            "track" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt16,
+                132u8,
+                10430.38f64,
+                0f64,
+                "radians",
+            )),
+            "validity" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::AttitudeValidity,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::Video => match name {
+            "url" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "hosting_provider" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "duration" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            _ => None,
+        },
+        MesgNum::VideoTitle => match name {
+            "message_count" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "text" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::VideoDescription => match name {
+            "message_count" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "text" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::VideoClip => match name {
+            "clip_number" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_timestamp" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_timestamp_ms" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "end_timestamp" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "end_timestamp_ms" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "clip_start" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "clip_end" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            _ => None,
+        },
+        MesgNum::Set => match name {
+            "duration" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "repetitions" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "weight" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                16f64,
+                0f64,
+                "kg",
+            )),
+            "set_type" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::SetType,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_time" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "category" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::ExerciseCategory,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "category_subtype" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "weight_display_unit" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::FitBaseUnit,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "wkt_step_index" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Jump => match name {
+            "distance" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "height" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "rotations" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "hang_time" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "score" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "position_lat" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "position_long" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "speed" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "enhanced_speed" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::Split => match name {
+            "split_type" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::SplitType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "total_elapsed_time" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "total_timer_time" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "total_distance" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "avg_speed" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "start_time" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "total_ascent" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "total_descent" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "start_position_lat" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "start_position_long" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "end_position_lat" => Some(FieldInfo::new(
+                23u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "end_position_long" => Some(FieldInfo::new(
+                24u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "max_speed" => Some(FieldInfo::new(
+                25u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "avg_vert_speed" => Some(FieldInfo::new(
+                26u8,
+                FieldDataType::SInt32,
+                133u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "end_time" => Some(FieldInfo::new(
+                27u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "total_calories" => Some(FieldInfo::new(
+                28u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "kcal",
+            )),
+            "start_elevation" => Some(FieldInfo::new(
+                74u8,
+                FieldDataType::UInt32,
+                134u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "total_moving_time" => Some(FieldInfo::new(
+                110u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::SplitSummary => match name {
+            "split_type" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::SplitType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "num_splits" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "total_timer_time" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "total_distance" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "avg_speed" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "max_speed" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "total_ascent" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "total_descent" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "avg_heart_rate" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "max_heart_rate" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "avg_vert_speed" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::SInt32,
+                133u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "total_calories" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "kcal",
+            )),
+            "total_moving_time" => Some(FieldInfo::new(
+                77u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::ClimbPro => match name {
+            "position_lat" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "position_long" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "climb_pro_event" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::ClimbProEvent,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "climb_number" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "climb_category" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "current_dist" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::FieldDescription => match name {
+            "developer_data_index" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "field_definition_number" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "fit_base_type_id" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::FitBaseType,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "field_name" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "array" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "components" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "scale" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "offset" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "units" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "bits" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "accumulate" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "fit_base_unit_id" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::FitBaseUnit,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "native_mesg_num" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::MesgNum,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "native_field_num" => Some(FieldInfo::new(
+                15u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::DeveloperDataId => match name {
+            "developer_id" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "application_id" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "manufacturer_id" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::Manufacturer,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "developer_data_index" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "application_version" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Course => match name {
+            "sport" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::Sport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "name" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "capabilities" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::CourseCapabilities,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sub_sport" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::SubSport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::CoursePoint => match name {
+            "timestamp" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "position_lat" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "position_long" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "distance" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "type" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::CoursePoint,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "name" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "favorite" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::SegmentId => match name {
+            "name" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "uuid" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sport" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::Sport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "enabled" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "user_profile_primary_key" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "device_id" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "default_race_leader" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "delete_status" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::SegmentDeleteStatus,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "selection_type" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::SegmentSelectionType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::SegmentLeaderboardEntry => match name {
+            "name" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SegmentLeaderboardType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "group_primary_key" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "activity_id" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "segment_time" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "activity_id_string" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::SegmentPoint => match name {
+            "position_lat" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "position_long" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "distance" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "altitude" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "leader_time" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "enhanced_altitude" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt32,
+                134u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::SegmentLap => match name {
+            "event" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Event,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "event_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::EventType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_time" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_position_lat" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "start_position_long" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "end_position_lat" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "end_position_long" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "total_elapsed_time" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "total_timer_time" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "total_distance" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "total_strokes" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "strokes",
+            )),
+            "total_cycles" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "cycles",
+            )),
+            "total_calories" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "kcal",
+            )),
+            "total_fat_calories" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "kcal",
+            )),
+            "avg_speed" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "max_speed" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "avg_heart_rate" => Some(FieldInfo::new(
+                15u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "max_heart_rate" => Some(FieldInfo::new(
+                16u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "avg_cadence" => Some(FieldInfo::new(
+                17u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "max_cadence" => Some(FieldInfo::new(
+                18u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "avg_power" => Some(FieldInfo::new(
+                19u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "max_power" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "total_ascent" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "total_descent" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "sport" => Some(FieldInfo::new(
+                23u8,
+                FieldDataType::Sport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "event_group" => Some(FieldInfo::new(
+                24u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "nec_lat" => Some(FieldInfo::new(
+                25u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "nec_long" => Some(FieldInfo::new(
+                26u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "swc_lat" => Some(FieldInfo::new(
+                27u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "swc_long" => Some(FieldInfo::new(
+                28u8,
+                FieldDataType::SInt32,
+                133u8,
+                1f64,
+                0f64,
+                "semicircles",
+            )),
+            "name" => Some(FieldInfo::new(
+                29u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "normalized_power" => Some(FieldInfo::new(
+                30u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "left_right_balance" => Some(FieldInfo::new(
+                31u8,
+                FieldDataType::LeftRightBalance100,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sub_sport" => Some(FieldInfo::new(
+                32u8,
+                FieldDataType::SubSport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "total_work" => Some(FieldInfo::new(
+                33u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "J",
+            )),
+            "avg_altitude" => Some(FieldInfo::new(
+                34u8,
+                FieldDataType::UInt16,
+                132u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "max_altitude" => Some(FieldInfo::new(
+                35u8,
+                FieldDataType::UInt16,
+                132u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "gps_accuracy" => Some(FieldInfo::new(
+                36u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "avg_grade" => Some(FieldInfo::new(
+                37u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "avg_pos_grade" => Some(FieldInfo::new(
+                38u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "avg_neg_grade" => Some(FieldInfo::new(
+                39u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "max_pos_grade" => Some(FieldInfo::new(
+                40u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "max_neg_grade" => Some(FieldInfo::new(
+                41u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "avg_temperature" => Some(FieldInfo::new(
+                42u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "C",
+            )),
+            "max_temperature" => Some(FieldInfo::new(
+                43u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "C",
+            )),
+            "total_moving_time" => Some(FieldInfo::new(
+                44u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "avg_pos_vertical_speed" => Some(FieldInfo::new(
+                45u8,
+                FieldDataType::SInt16,
+                131u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "avg_neg_vertical_speed" => Some(FieldInfo::new(
+                46u8,
+                FieldDataType::SInt16,
+                131u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "max_pos_vertical_speed" => Some(FieldInfo::new(
+                47u8,
+                FieldDataType::SInt16,
+                131u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "max_neg_vertical_speed" => Some(FieldInfo::new(
+                48u8,
+                FieldDataType::SInt16,
+                131u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "time_in_hr_zone" => Some(FieldInfo::new(
+                49u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "time_in_speed_zone" => Some(FieldInfo::new(
+                50u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "time_in_cadence_zone" => Some(FieldInfo::new(
+                51u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "time_in_power_zone" => Some(FieldInfo::new(
+                52u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "repetition_num" => Some(FieldInfo::new(
+                53u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "min_altitude" => Some(FieldInfo::new(
+                54u8,
+                FieldDataType::UInt16,
+                132u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "min_heart_rate" => Some(FieldInfo::new(
+                55u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "active_time" => Some(FieldInfo::new(
+                56u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "wkt_step_index" => Some(FieldInfo::new(
+                57u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sport_event" => Some(FieldInfo::new(
+                58u8,
+                FieldDataType::SportEvent,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "avg_left_torque_effectiveness" => Some(FieldInfo::new(
+                59u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_right_torque_effectiveness" => Some(FieldInfo::new(
+                60u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_left_pedal_smoothness" => Some(FieldInfo::new(
+                61u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_right_pedal_smoothness" => Some(FieldInfo::new(
+                62u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "avg_combined_pedal_smoothness" => Some(FieldInfo::new(
+                63u8,
+                FieldDataType::UInt8,
+                2u8,
+                2f64,
+                0f64,
+                "percent",
+            )),
+            "status" => Some(FieldInfo::new(
+                64u8,
+                FieldDataType::SegmentLapStatus,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "uuid" => Some(FieldInfo::new(
+                65u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "avg_fractional_cadence" => Some(FieldInfo::new(
+                66u8,
+                FieldDataType::UInt8,
+                2u8,
+                128f64,
+                0f64,
+                "rpm",
+            )),
+            "max_fractional_cadence" => Some(FieldInfo::new(
+                67u8,
+                FieldDataType::UInt8,
+                2u8,
+                128f64,
+                0f64,
+                "rpm",
+            )),
+            "total_fractional_cycles" => Some(FieldInfo::new(
+                68u8,
+                FieldDataType::UInt8,
+                2u8,
+                128f64,
+                0f64,
+                "cycles",
+            )),
+            "front_gear_shift_count" => Some(FieldInfo::new(
+                69u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "rear_gear_shift_count" => Some(FieldInfo::new(
+                70u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "time_standing" => Some(FieldInfo::new(
+                71u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "stand_count" => Some(FieldInfo::new(
+                72u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "avg_left_pco" => Some(FieldInfo::new(
+                73u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "mm",
+            )),
+            "avg_right_pco" => Some(FieldInfo::new(
+                74u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "mm",
+            )),
+            "avg_left_power_phase" => Some(FieldInfo::new(
+                75u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "avg_left_power_phase_peak" => Some(FieldInfo::new(
+                76u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "avg_right_power_phase" => Some(FieldInfo::new(
+                77u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "avg_right_power_phase_peak" => Some(FieldInfo::new(
+                78u8,
+                FieldDataType::UInt8,
+                2u8,
+                0.7111111f64,
+                0f64,
+                "degrees",
+            )),
+            "avg_power_position" => Some(FieldInfo::new(
+                79u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "max_power_position" => Some(FieldInfo::new(
+                80u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "watts",
+            )),
+            "avg_cadence_position" => Some(FieldInfo::new(
+                81u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "max_cadence_position" => Some(FieldInfo::new(
+                82u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "manufacturer" => Some(FieldInfo::new(
+                83u8,
+                FieldDataType::Manufacturer,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "total_grit" => Some(FieldInfo::new(
+                84u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "kGrit",
+            )),
+            "total_flow" => Some(FieldInfo::new(
+                85u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "Flow",
+            )),
+            "avg_grit" => Some(FieldInfo::new(
+                86u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "kGrit",
+            )),
+            "avg_flow" => Some(FieldInfo::new(
+                87u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "Flow",
+            )),
+            "total_fractional_ascent" => Some(FieldInfo::new(
+                89u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "total_fractional_descent" => Some(FieldInfo::new(
+                90u8,
+                FieldDataType::UInt8,
+                2u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "enhanced_avg_altitude" => Some(FieldInfo::new(
+                91u8,
+                FieldDataType::UInt32,
+                134u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "enhanced_max_altitude" => Some(FieldInfo::new(
+                92u8,
+                FieldDataType::UInt32,
+                134u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "enhanced_min_altitude" => Some(FieldInfo::new(
+                93u8,
+                FieldDataType::UInt32,
+                134u8,
+                5f64,
+                500f64,
+                "m",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::SegmentFile => match name {
+            "file_uuid" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "enabled" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "user_profile_primary_key" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "leader_type" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::SegmentLeaderboardType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "leader_group_primary_key" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "leader_activity_id" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "leader_activity_id_string" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "default_race_leader" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Workout => match name {
+            "sport" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::Sport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "capabilities" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::WorkoutCapabilities,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "num_valid_steps" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "wkt_name" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sub_sport" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::SubSport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "pool_length" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "pool_length_unit" => Some(FieldInfo::new(
+                15u8,
+                FieldDataType::DisplayMeasure,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::WorkoutSession => match name {
+            "sport" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Sport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sub_sport" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SubSport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "num_valid_steps" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "first_step_index" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "pool_length" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "pool_length_unit" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::DisplayMeasure,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::WorkoutStep => match name {
+            "wkt_step_name" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "duration_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::WktStepDuration,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "duration_time" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "duration_distance" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "duration_hr" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::WorkoutHr,
+                134u8,
+                1f64,
+                0f64,
+                "% or bpm",
+            )),
+            "duration_calories" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "calories",
+            )),
+            "duration_step" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "duration_power" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::WorkoutPower,
+                134u8,
+                1f64,
+                0f64,
+                "% or watts",
+            )),
+            "duration_reps" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "duration_value" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "target_type" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::WktStepTarget,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "target_speed_zone" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "target_hr_zone" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "target_cadence_zone" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "target_power_zone" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "repeat_steps" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "repeat_time" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "repeat_distance" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "repeat_calories" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "calories",
+            )),
+            "repeat_hr" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::WorkoutHr,
+                134u8,
+                1f64,
+                0f64,
+                "% or bpm",
+            )),
+            "repeat_power" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::WorkoutPower,
+                134u8,
+                1f64,
+                0f64,
+                "% or watts",
+            )),
+            "target_stroke_type" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::SwimStroke,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "target_value" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "custom_target_speed_low" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "custom_target_heart_rate_low" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::WorkoutHr,
+                134u8,
+                1f64,
+                0f64,
+                "% or bpm",
+            )),
+            "custom_target_cadence_low" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "custom_target_power_low" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::WorkoutPower,
+                134u8,
+                1f64,
+                0f64,
+                "% or watts",
+            )),
+            "custom_target_value_low" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "custom_target_speed_high" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "custom_target_heart_rate_high" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::WorkoutHr,
+                134u8,
+                1f64,
+                0f64,
+                "% or bpm",
+            )),
+            "custom_target_cadence_high" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "custom_target_power_high" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::WorkoutPower,
+                134u8,
+                1f64,
+                0f64,
+                "% or watts",
+            )),
+            "custom_target_value_high" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "intensity" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::Intensity,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "notes" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "equipment" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::WorkoutEquipment,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "exercise_category" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::ExerciseCategory,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "exercise_name" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "exercise_weight" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "kg",
+            )),
+            "weight_display_unit" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::FitBaseUnit,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "secondary_target_type" => Some(FieldInfo::new(
+                19u8,
+                FieldDataType::WktStepTarget,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "secondary_target_speed_zone" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "secondary_target_hr_zone" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "secondary_target_cadence_zone" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "secondary_target_power_zone" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "secondary_target_stroke_type" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::SwimStroke,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "secondary_target_value" => Some(FieldInfo::new(
+                20u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "secondary_custom_target_speed_low" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "secondary_custom_target_heart_rate_low" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::WorkoutHr,
+                134u8,
+                1f64,
+                0f64,
+                "% or bpm",
+            )),
+            "secondary_custom_target_cadence_low" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "secondary_custom_target_power_low" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::WorkoutPower,
+                134u8,
+                1f64,
+                0f64,
+                "% or watts",
+            )),
+            "secondary_custom_target_value_low" => Some(FieldInfo::new(
+                21u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "secondary_custom_target_speed_high" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "secondary_custom_target_heart_rate_high" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::WorkoutHr,
+                134u8,
+                1f64,
+                0f64,
+                "% or bpm",
+            )),
+            "secondary_custom_target_cadence_high" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "rpm",
+            )),
+            "secondary_custom_target_power_high" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::WorkoutPower,
+                134u8,
+                1f64,
+                0f64,
+                "% or watts",
+            )),
+            "secondary_custom_target_value_high" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::ExerciseTitle => match name {
+            "exercise_category" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::ExerciseCategory,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "exercise_name" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "wkt_step_name" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Schedule => match name {
+            "manufacturer" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Manufacturer,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "favero_product" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::FaveroProduct,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "garmin_product" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::GarminProduct,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "product" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "serial_number" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32z,
+                140u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "time_created" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "completed" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "type" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::Schedule,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "scheduled_time" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::LocalDateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Totals => match name {
+            "timer_time" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "distance" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "m",
+            )),
+            "calories" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "kcal",
+            )),
+            "sport" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::Sport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "elapsed_time" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "sessions" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "active_time" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "sport_index" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "message_index" => Some(FieldInfo::new(
+                254u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::WeightScale => match name {
+            "weight" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Weight,
+                132u8,
+                100f64,
+                0f64,
+                "kg",
+            )),
+            "percent_fat" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "percent_hydration" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "%",
+            )),
+            "visceral_fat_mass" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "kg",
+            )),
+            "bone_mass" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "kg",
+            )),
+            "muscle_mass" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "kg",
+            )),
+            "basal_met" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt16,
+                132u8,
+                4f64,
+                0f64,
+                "kcal/day",
+            )),
+            "physique_rating" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "active_met" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt16,
+                132u8,
+                4f64,
+                0f64,
+                "kcal/day",
+            )),
+            "metabolic_age" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "years",
+            )),
+            "visceral_fat_rating" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "user_profile_index" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "bmi" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "kg/m^2",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::BloodPressure => match name {
+            "systolic_pressure" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "mmHg",
+            )),
+            "diastolic_pressure" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "mmHg",
+            )),
+            "mean_arterial_pressure" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "mmHg",
+            )),
+            "map_3_sample_mean" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "mmHg",
+            )),
+            "map_morning_values" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "mmHg",
+            )),
+            "map_evening_values" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "mmHg",
+            )),
+            "heart_rate" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "heart_rate_type" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::HrType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "status" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::BpStatus,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "user_profile_index" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::MonitoringInfo => match name {
+            "local_timestamp" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::LocalDateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "activity_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::ActivityType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "cycles_to_distance" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                5000f64,
+                0f64,
+                "m/cycle",
+            )),
+            "cycles_to_calories" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                5000f64,
+                0f64,
+                "kcal/cycle",
+            )),
+            "resting_metabolic_rate" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "kcal / day",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::Monitoring => match name {
+            "device_index" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::DeviceIndex,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "calories" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "kcal",
+            )),
+            "distance" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "m",
+            )),
+            "steps" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "steps",
+            )),
+            "strokes" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                2f64,
+                0f64,
+                "strokes",
+            )),
+            "cycles" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                2f64,
+                0f64,
+                "cycles",
+            )),
+            "active_time" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "activity_type" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::ActivityType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "activity_subtype" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::ActivitySubtype,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "activity_level" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::ActivityLevel,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "distance_16" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "100 * m",
+            )),
+            "cycles_16" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "2 * cycles (steps)",
+            )),
+            "active_time_16" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "local_timestamp" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::LocalDateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "temperature" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "C",
+            )),
+            "temperature_min" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "C",
+            )),
+            "temperature_max" => Some(FieldInfo::new(
+                15u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "C",
+            )),
+            "activity_time" => Some(FieldInfo::new(
+                16u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "minutes",
+            )),
+            "active_calories" => Some(FieldInfo::new(
+                19u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "kcal",
+            )),
+            "current_activity_type_intensity" => Some(FieldInfo::new(
+                24u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp_min_8" => Some(FieldInfo::new(
+                25u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "min",
+            )),
+            "timestamp_16" => Some(FieldInfo::new(
+                26u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "heart_rate" => Some(FieldInfo::new(
+                27u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "intensity" => Some(FieldInfo::new(
+                28u8,
+                FieldDataType::UInt8,
+                2u8,
+                10f64,
+                0f64,
+                "",
+            )),
+            "duration_min" => Some(FieldInfo::new(
+                29u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "min",
+            )),
+            "duration" => Some(FieldInfo::new(
+                30u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "ascent" => Some(FieldInfo::new(
+                31u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "descent" => Some(FieldInfo::new(
+                32u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "moderate_activity_minutes" => Some(FieldInfo::new(
+                33u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "minutes",
+            )),
+            "vigorous_activity_minutes" => Some(FieldInfo::new(
+                34u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "minutes",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::MonitoringHrData => match name {
+            "resting_heart_rate" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "current_day_resting_heart_rate" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::Spo2Data => match name {
+            "reading_spo2" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "reading_confidence" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "mode" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::Spo2MeasurementType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::Hr => match name {
+            "fractional_timestamp" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                32768f64,
+                0f64,
+                "s",
+            )),
+            "time256" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                256f64,
+                0f64,
+                "s",
+            )),
+            "filtered_bpm" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "event_timestamp" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt32,
+                134u8,
+                1024f64,
+                0f64,
+                "s",
+            )),
+            "event_timestamp_12" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::StressLevel => match name {
+            "stress_level_value" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::SInt16,
+                131u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "stress_level_time" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::MaxMetData => match name {
+            "update_time" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "vo2_max" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                10f64,
+                0f64,
+                "mL/kg/min",
+            )),
+            "sport" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::Sport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sub_sport" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::SubSport,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "max_met_category" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::MaxMetCategory,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "calibrated_data" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "hr_source" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::MaxMetHeartRateSource,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "speed_source" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::MaxMetSpeedSource,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::HsaBodyBatteryData => match name {
+            "processing_interval" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "level" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "charged" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::SInt16,
+                131u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "uncharged" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::SInt16,
+                131u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::HsaEvent => match name {
+            "event_id" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::HsaAccelerometerData => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "sampling_interval" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "accel_x" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::SInt16,
+                131u8,
+                1.024f64,
+                0f64,
+                "mG",
+            )),
+            "accel_y" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::SInt16,
+                131u8,
+                1.024f64,
+                0f64,
+                "mG",
+            )),
+            "accel_z" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::SInt16,
+                131u8,
+                1.024f64,
+                0f64,
+                "mG",
+            )),
+            "timestamp_32k" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::HsaGyroscopeData => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "sampling_interval" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "1/32768 s",
+            )),
+            "gyro_x" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::SInt16,
+                131u8,
+                28.57143f64,
+                0f64,
+                "deg/s",
+            )),
+            "gyro_y" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::SInt16,
+                131u8,
+                28.57143f64,
+                0f64,
+                "deg/s",
+            )),
+            "gyro_z" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::SInt16,
+                131u8,
+                28.57143f64,
+                0f64,
+                "deg/s",
+            )),
+            "timestamp_32k" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "1/32768 s",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::HsaStepData => match name {
+            "processing_interval" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "steps" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "steps",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::HsaSpo2Data => match name {
+            "processing_interval" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "reading_spo2" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "confidence" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::HsaStressData => match name {
+            "processing_interval" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "stress_level" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SInt8,
+                1u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::HsaRespirationData => match name {
+            "processing_interval" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "respiration_rate" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "breaths/min",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::HsaHeartRateData => match name {
+            "processing_interval" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "status" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "heart_rate" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "bpm",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::HsaConfigurationData => match name {
+            "data" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "data_size" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::HsaWristTemperatureData => match name {
+            "processing_interval" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "value" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "degC",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::MemoGlob => match name {
+            "memo" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "mesg_num" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::MesgNum,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "parent_index" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "field_num" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "data" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "part_index" => Some(FieldInfo::new(
+                250u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::SleepLevel => match name {
+            "sleep_level" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::SleepLevel,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::AntChannelId => match name {
+            "channel_number" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "device_type" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "device_number" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16z,
+                139u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "transmission_type" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8z,
+                10u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "device_index" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::DeviceIndex,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::AntRx => match name {
+            "fractional_timestamp" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                32768f64,
+                0f64,
+                "s",
+            )),
+            "mesg_id" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "mesg_data" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "channel_number" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "data" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::AntTx => match name {
+            "fractional_timestamp" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                32768f64,
+                0f64,
+                "s",
+            )),
+            "mesg_id" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "mesg_data" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "channel_number" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "data" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::ExdScreenConfiguration => match name {
+            "screen_index" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "field_count" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "layout" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::ExdLayout,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "screen_enabled" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::ExdDataFieldConfiguration => match name {
+            "screen_index" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "concept_field" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "field_id" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "concept_count" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "display_type" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::ExdDisplayType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "title" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::String,
+                7u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::ExdDataConceptConfiguration => match name {
+            "screen_index" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "concept_field" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::Byte,
+                13u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "field_id" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "concept_index" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "data_page" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "concept_key" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "scaling" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "data_units" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::ExdDataUnits,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "qualifier" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::ExdQualifiers,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "descriptor" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::ExdDescriptors,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "is_signed" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::Bool,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::DiveSummary => match name {
+            "reference_mesg" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::MesgNum,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "reference_index" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::MessageIndex,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "avg_depth" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "max_depth" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m",
+            )),
+            "surface_interval" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "start_cns" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "end_cns" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "start_n2" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "end_n2" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "percent",
+            )),
+            "o2_toxicity" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "OTUs",
+            )),
+            "dive_number" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "bottom_time" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "avg_pressure_sac" => Some(FieldInfo::new(
+                12u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "bar/min",
+            )),
+            "avg_volume_sac" => Some(FieldInfo::new(
+                13u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "L/min",
+            )),
+            "avg_rmv" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "L/min",
+            )),
+            "descent_time" => Some(FieldInfo::new(
+                15u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "ascent_time" => Some(FieldInfo::new(
+                16u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "avg_ascent_rate" => Some(FieldInfo::new(
+                17u8,
+                FieldDataType::SInt32,
+                133u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "avg_descent_rate" => Some(FieldInfo::new(
+                22u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "max_ascent_rate" => Some(FieldInfo::new(
+                23u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "max_descent_rate" => Some(FieldInfo::new(
+                24u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "hang_time" => Some(FieldInfo::new(
+                25u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::AadAccelFeatures => match name {
+            "time" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            "energy_total" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt32,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "zero_cross_cnt" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "instance" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "time_above_threshold" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                25f64,
+                0f64,
+                "s",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::Hrv => match name {
+            "time" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1000f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::BeatIntervals => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "time" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::HrvStatusSummary => match name {
+            "weekly_average" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                128f64,
+                0f64,
+                "ms",
+            )),
+            "last_night_average" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                128f64,
+                0f64,
+                "ms",
+            )),
+            "last_night_5_min_high" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                128f64,
+                0f64,
+                "ms",
+            )),
+            "baseline_low_upper" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                128f64,
+                0f64,
+                "ms",
+            )),
+            "baseline_balanced_lower" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt16,
+                132u8,
+                128f64,
+                0f64,
+                "ms",
+            )),
+            "baseline_balanced_upper" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt16,
+                132u8,
+                128f64,
+                0f64,
+                "ms",
+            )),
+            "status" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::HrvStatus,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::HrvValue => match name {
+            "value" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                128f64,
+                0f64,
+                "ms",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::RawBbi => match name {
+            "timestamp_ms" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "data" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "time" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "ms",
+            )),
+            "quality" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "gap" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::RespirationRate => match name {
+            "respiration_rate" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::SInt16,
+                131u8,
+                100f64,
+                0f64,
+                "breaths/min",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::ChronoShotSession => match name {
+            "min_speed" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "max_speed" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "avg_speed" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "shot_count" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "projectile_type" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::ProjectileType,
+                0u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "grain_weight" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt32,
+                134u8,
+                10f64,
+                0f64,
+                "gr",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::ChronoShotData => match name {
+            "shot_speed" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt32,
+                134u8,
+                1000f64,
+                0f64,
+                "m/s",
+            )),
+            "shot_num" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::TankUpdate => match name {
+            "sensor" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::AntChannelId,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "pressure" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "bar",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::TankSummary => match name {
+            "sensor" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::AntChannelId,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "start_pressure" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "bar",
+            )),
+            "end_pressure" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "bar",
+            )),
+            "volume_used" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt32,
+                134u8,
+                100f64,
+                0f64,
+                "L",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "s",
+            )),
+            _ => None,
+        },
+        MesgNum::SleepAssessment => match name {
+            "combined_awake_score" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "awake_time_score" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "awakenings_count_score" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "deep_sleep_score" => Some(FieldInfo::new(
+                3u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sleep_duration_score" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "light_sleep_score" => Some(FieldInfo::new(
+                5u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "overall_sleep_score" => Some(FieldInfo::new(
+                6u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sleep_quality_score" => Some(FieldInfo::new(
+                7u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sleep_recovery_score" => Some(FieldInfo::new(
+                8u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "rem_sleep_score" => Some(FieldInfo::new(
+                9u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "sleep_restlessness_score" => Some(FieldInfo::new(
+                10u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "awakenings_count" => Some(FieldInfo::new(
+                11u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "interruptions_score" => Some(FieldInfo::new(
+                14u8,
+                FieldDataType::UInt8,
+                2u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "average_stress_during_sleep" => Some(FieldInfo::new(
+                15u8,
+                FieldDataType::UInt16,
+                132u8,
+                100f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        MesgNum::SkinTempOvernight => match name {
+            "local_timestamp" => Some(FieldInfo::new(
+                0u8,
+                FieldDataType::LocalDateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "average_deviation" => Some(FieldInfo::new(
+                1u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "average_7_day_deviation" => Some(FieldInfo::new(
+                2u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "nightly_value" => Some(FieldInfo::new(
+                4u8,
+                FieldDataType::Float32,
+                136u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            "timestamp" => Some(FieldInfo::new(
+                253u8,
+                FieldDataType::DateTime,
+                134u8,
+                1f64,
+                0f64,
+                "",
+            )),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+#[doc = " Name of a field by its definition number, subfields are named after their parent field"]
+pub fn field_name(mesg_num: MesgNum, number: u8) -> Option<&'static str> {
+    match mesg_num {
+        MesgNum::FileId => match number {
+            0u8 => Some("type"),
+            1u8 => Some("manufacturer"),
+            2u8 => Some("product"),
+            3u8 => Some("serial_number"),
+            4u8 => Some("time_created"),
+            5u8 => Some("number"),
+            8u8 => Some("product_name"),
+            _ => None,
+        },
+        MesgNum::FileCreator => match number {
+            0u8 => Some("software_version"),
+            1u8 => Some("hardware_version"),
+            _ => None,
+        },
+        MesgNum::TimestampCorrelation => match number {
+            0u8 => Some("fractional_timestamp"),
+            1u8 => Some("system_timestamp"),
+            2u8 => Some("fractional_system_timestamp"),
+            3u8 => Some("local_timestamp"),
+            4u8 => Some("timestamp_ms"),
+            5u8 => Some("system_timestamp_ms"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::Software => match number {
+            3u8 => Some("version"),
+            5u8 => Some("part_number"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::SlaveDevice => match number {
+            0u8 => Some("manufacturer"),
+            1u8 => Some("product"),
+            _ => None,
+        },
+        MesgNum::Capabilities => match number {
+            0u8 => Some("languages"),
+            1u8 => Some("sports"),
+            21u8 => Some("workouts_supported"),
+            23u8 => Some("connectivity_supported"),
+            _ => None,
+        },
+        MesgNum::FileCapabilities => match number {
+            0u8 => Some("type"),
+            1u8 => Some("flags"),
+            2u8 => Some("directory"),
+            3u8 => Some("max_count"),
+            4u8 => Some("max_size"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::MesgCapabilities => match number {
+            0u8 => Some("file"),
+            1u8 => Some("mesg_num"),
+            2u8 => Some("count_type"),
+            3u8 => Some("count"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::FieldCapabilities => match number {
+            0u8 => Some("file"),
+            1u8 => Some("mesg_num"),
+            2u8 => Some("field_num"),
+            3u8 => Some("count"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::DeviceSettings => match number {
+            0u8 => Some("active_time_zone"),
+            1u8 => Some("utc_offset"),
+            2u8 => Some("time_offset"),
+            4u8 => Some("time_mode"),
+            5u8 => Some("time_zone_offset"),
+            12u8 => Some("backlight_mode"),
+            36u8 => Some("activity_tracker_enabled"),
+            39u8 => Some("clock_time"),
+            40u8 => Some("pages_enabled"),
+            46u8 => Some("move_alert_enabled"),
+            47u8 => Some("date_mode"),
+            55u8 => Some("display_orientation"),
+            56u8 => Some("mounting_side"),
+            57u8 => Some("default_page"),
+            58u8 => Some("autosync_min_steps"),
+            59u8 => Some("autosync_min_time"),
+            80u8 => Some("lactate_threshold_autodetect_enabled"),
+            86u8 => Some("ble_auto_upload_enabled"),
+            89u8 => Some("auto_sync_frequency"),
+            90u8 => Some("auto_activity_detect"),
+            94u8 => Some("number_of_screens"),
+            95u8 => Some("smart_notification_display_orientation"),
+            134u8 => Some("tap_interface"),
+            174u8 => Some("tap_sensitivity"),
+            _ => None,
+        },
+        MesgNum::UserProfile => match number {
+            0u8 => Some("friendly_name"),
+            1u8 => Some("gender"),
+            2u8 => Some("age"),
+            3u8 => Some("height"),
+            4u8 => Some("weight"),
+            5u8 => Some("language"),
+            6u8 => Some("elev_setting"),
+            7u8 => Some("weight_setting"),
+            8u8 => Some("resting_heart_rate"),
+            9u8 => Some("default_max_running_heart_rate"),
+            10u8 => Some("default_max_biking_heart_rate"),
+            11u8 => Some("default_max_heart_rate"),
+            12u8 => Some("hr_setting"),
+            13u8 => Some("speed_setting"),
+            14u8 => Some("dist_setting"),
+            16u8 => Some("power_setting"),
+            17u8 => Some("activity_class"),
+            18u8 => Some("position_setting"),
+            21u8 => Some("temperature_setting"),
+            22u8 => Some("local_id"),
+            23u8 => Some("global_id"),
+            28u8 => Some("wake_time"),
+            29u8 => Some("sleep_time"),
+            30u8 => Some("height_setting"),
+            31u8 => Some("user_running_step_length"),
+            32u8 => Some("user_walking_step_length"),
+            47u8 => Some("depth_setting"),
+            49u8 => Some("dive_count"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::HrmProfile => match number {
+            0u8 => Some("enabled"),
+            1u8 => Some("hrm_ant_id"),
+            2u8 => Some("log_hrv"),
+            3u8 => Some("hrm_ant_id_trans_type"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::SdmProfile => match number {
+            0u8 => Some("enabled"),
+            1u8 => Some("sdm_ant_id"),
+            2u8 => Some("sdm_cal_factor"),
+            3u8 => Some("odometer"),
+            4u8 => Some("speed_source"),
+            5u8 => Some("sdm_ant_id_trans_type"),
+            7u8 => Some("odometer_rollover"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::BikeProfile => match number {
+            0u8 => Some("name"),
+            1u8 => Some("sport"),
+            2u8 => Some("sub_sport"),
+            3u8 => Some("odometer"),
+            4u8 => Some("bike_spd_ant_id"),
+            5u8 => Some("bike_cad_ant_id"),
+            6u8 => Some("bike_spdcad_ant_id"),
+            7u8 => Some("bike_power_ant_id"),
+            8u8 => Some("custom_wheelsize"),
+            9u8 => Some("auto_wheelsize"),
+            10u8 => Some("bike_weight"),
+            11u8 => Some("power_cal_factor"),
+            12u8 => Some("auto_wheel_cal"),
+            13u8 => Some("auto_power_zero"),
+            14u8 => Some("id"),
+            15u8 => Some("spd_enabled"),
+            16u8 => Some("cad_enabled"),
+            17u8 => Some("spdcad_enabled"),
+            18u8 => Some("power_enabled"),
+            19u8 => Some("crank_length"),
+            20u8 => Some("enabled"),
+            21u8 => Some("bike_spd_ant_id_trans_type"),
+            22u8 => Some("bike_cad_ant_id_trans_type"),
+            23u8 => Some("bike_spdcad_ant_id_trans_type"),
+            24u8 => Some("bike_power_ant_id_trans_type"),
+            37u8 => Some("odometer_rollover"),
+            38u8 => Some("front_gear_num"),
+            39u8 => Some("front_gear"),
+            40u8 => Some("rear_gear_num"),
+            41u8 => Some("rear_gear"),
+            44u8 => Some("shimano_di2_enabled"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::Connectivity => match number {
+            0u8 => Some("bluetooth_enabled"),
+            1u8 => Some("bluetooth_le_enabled"),
+            2u8 => Some("ant_enabled"),
+            3u8 => Some("name"),
+            4u8 => Some("live_tracking_enabled"),
+            5u8 => Some("weather_conditions_enabled"),
+            6u8 => Some("weather_alerts_enabled"),
+            7u8 => Some("auto_activity_upload_enabled"),
+            8u8 => Some("course_download_enabled"),
+            9u8 => Some("workout_download_enabled"),
+            10u8 => Some("gps_ephemeris_download_enabled"),
+            11u8 => Some("incident_detection_enabled"),
+            12u8 => Some("grouptrack_enabled"),
+            _ => None,
+        },
+        MesgNum::WatchfaceSettings => match number {
+            0u8 => Some("mode"),
+            1u8 => Some("layout"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::OhrSettings => match number {
+            0u8 => Some("enabled"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::TimeInZone => match number {
+            0u8 => Some("reference_mesg"),
+            1u8 => Some("reference_index"),
+            2u8 => Some("time_in_hr_zone"),
+            3u8 => Some("time_in_speed_zone"),
+            4u8 => Some("time_in_cadence_zone"),
+            5u8 => Some("time_in_power_zone"),
+            6u8 => Some("hr_zone_high_boundary"),
+            7u8 => Some("speed_zone_high_boundary"),
+            8u8 => Some("cadence_zone_high_bondary"),
+            9u8 => Some("power_zone_high_boundary"),
+            10u8 => Some("hr_calc_type"),
+            11u8 => Some("max_heart_rate"),
+            12u8 => Some("resting_heart_rate"),
+            13u8 => Some("threshold_heart_rate"),
+            14u8 => Some("pwr_calc_type"),
+            15u8 => Some("functional_threshold_power"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::ZonesTarget => match number {
+            1u8 => Some("max_heart_rate"),
+            2u8 => Some("threshold_heart_rate"),
+            3u8 => Some("functional_threshold_power"),
+            5u8 => Some("hr_calc_type"),
+            7u8 => Some("pwr_calc_type"),
+            _ => None,
+        },
+        MesgNum::Sport => match number {
+            0u8 => Some("sport"),
+            1u8 => Some("sub_sport"),
+            3u8 => Some("name"),
+            _ => None,
+        },
+        MesgNum::HrZone => match number {
+            1u8 => Some("high_bpm"),
+            2u8 => Some("name"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::SpeedZone => match number {
+            0u8 => Some("high_value"),
+            1u8 => Some("name"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::CadenceZone => match number {
+            0u8 => Some("high_value"),
+            1u8 => Some("name"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::PowerZone => match number {
+            1u8 => Some("high_value"),
+            2u8 => Some("name"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::MetZone => match number {
+            1u8 => Some("high_bpm"),
+            2u8 => Some("calories"),
+            3u8 => Some("fat_calories"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::DiveSettings => match number {
+            0u8 => Some("name"),
+            1u8 => Some("model"),
+            2u8 => Some("gf_low"),
+            3u8 => Some("gf_high"),
+            4u8 => Some("water_type"),
+            5u8 => Some("water_density"),
+            6u8 => Some("po2_warn"),
+            7u8 => Some("po2_critical"),
+            8u8 => Some("po2_deco"),
+            9u8 => Some("safety_stop_enabled"),
+            10u8 => Some("bottom_depth"),
+            11u8 => Some("bottom_time"),
+            12u8 => Some("apnea_countdown_enabled"),
+            13u8 => Some("apnea_countdown_time"),
+            14u8 => Some("backlight_mode"),
+            15u8 => Some("backlight_brightness"),
+            16u8 => Some("backlight_timeout"),
+            17u8 => Some("repeat_dive_interval"),
+            18u8 => Some("safety_stop_time"),
+            19u8 => Some("heart_rate_source_type"),
+            20u8 => Some("heart_rate_source"),
+            21u8 => Some("travel_gas"),
+            22u8 => Some("ccr_low_setpoint_switch_mode"),
+            23u8 => Some("ccr_low_setpoint"),
+            24u8 => Some("ccr_low_setpoint_depth"),
+            25u8 => Some("ccr_high_setpoint_switch_mode"),
+            26u8 => Some("ccr_high_setpoint"),
+            27u8 => Some("ccr_high_setpoint_depth"),
+            29u8 => Some("gas_consumption_display"),
+            30u8 => Some("up_key_enabled"),
+            35u8 => Some("dive_sounds"),
+            36u8 => Some("last_stop_multiple"),
+            37u8 => Some("no_fly_time_mode"),
+            253u8 => Some("timestamp"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::DiveAlarm => match number {
+            0u8 => Some("depth"),
+            1u8 => Some("time"),
+            2u8 => Some("enabled"),
+            3u8 => Some("alarm_type"),
+            4u8 => Some("sound"),
+            5u8 => Some("dive_types"),
+            6u8 => Some("id"),
+            7u8 => Some("popup_enabled"),
+            8u8 => Some("trigger_on_descent"),
+            9u8 => Some("trigger_on_ascent"),
+            10u8 => Some("repeating"),
+            11u8 => Some("speed"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::DiveApneaAlarm => match number {
+            0u8 => Some("depth"),
+            1u8 => Some("time"),
+            2u8 => Some("enabled"),
+            3u8 => Some("alarm_type"),
+            4u8 => Some("sound"),
+            5u8 => Some("dive_types"),
+            6u8 => Some("id"),
+            7u8 => Some("popup_enabled"),
+            8u8 => Some("trigger_on_descent"),
+            9u8 => Some("trigger_on_ascent"),
+            10u8 => Some("repeating"),
+            11u8 => Some("speed"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::DiveGas => match number {
+            0u8 => Some("helium_content"),
+            1u8 => Some("oxygen_content"),
+            2u8 => Some("status"),
+            3u8 => Some("mode"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::Goal => match number {
+            0u8 => Some("sport"),
+            1u8 => Some("sub_sport"),
+            2u8 => Some("start_date"),
+            3u8 => Some("end_date"),
+            4u8 => Some("type"),
+            5u8 => Some("value"),
+            6u8 => Some("repeat"),
+            7u8 => Some("target_value"),
+            8u8 => Some("recurrence"),
+            9u8 => Some("recurrence_value"),
+            10u8 => Some("enabled"),
+            11u8 => Some("source"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::Activity => match number {
+            0u8 => Some("total_timer_time"),
+            1u8 => Some("num_sessions"),
+            2u8 => Some("type"),
+            3u8 => Some("event"),
+            4u8 => Some("event_type"),
+            5u8 => Some("local_timestamp"),
+            6u8 => Some("event_group"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::Session => match number {
+            0u8 => Some("event"),
+            1u8 => Some("event_type"),
+            2u8 => Some("start_time"),
+            3u8 => Some("start_position_lat"),
+            4u8 => Some("start_position_long"),
+            5u8 => Some("sport"),
+            6u8 => Some("sub_sport"),
+            7u8 => Some("total_elapsed_time"),
+            8u8 => Some("total_timer_time"),
+            9u8 => Some("total_distance"),
+            10u8 => Some("total_cycles"),
+            11u8 => Some("total_calories"),
+            13u8 => Some("total_fat_calories"),
+            14u8 => Some("avg_speed"),
+            15u8 => Some("max_speed"),
+            16u8 => Some("avg_heart_rate"),
+            17u8 => Some("max_heart_rate"),
+            18u8 => Some("avg_cadence"),
+            19u8 => Some("max_cadence"),
+            20u8 => Some("avg_power"),
+            21u8 => Some("max_power"),
+            22u8 => Some("total_ascent"),
+            23u8 => Some("total_descent"),
+            24u8 => Some("total_training_effect"),
+            25u8 => Some("first_lap_index"),
+            26u8 => Some("num_laps"),
+            27u8 => Some("event_group"),
+            28u8 => Some("trigger"),
+            29u8 => Some("nec_lat"),
+            30u8 => Some("nec_long"),
+            31u8 => Some("swc_lat"),
+            32u8 => Some("swc_long"),
+            33u8 => Some("num_lengths"),
+            34u8 => Some("normalized_power"),
+            35u8 => Some("training_stress_score"),
+            36u8 => Some("intensity_factor"),
+            37u8 => Some("left_right_balance"),
+            38u8 => Some("end_position_lat"),
+            39u8 => Some("end_position_long"),
+            41u8 => Some("avg_stroke_count"),
+            42u8 => Some("avg_stroke_distance"),
+            43u8 => Some("swim_stroke"),
+            44u8 => Some("pool_length"),
+            45u8 => Some("threshold_power"),
+            46u8 => Some("pool_length_unit"),
+            47u8 => Some("num_active_lengths"),
+            48u8 => Some("total_work"),
+            49u8 => Some("avg_altitude"),
+            50u8 => Some("max_altitude"),
+            51u8 => Some("gps_accuracy"),
+            52u8 => Some("avg_grade"),
+            53u8 => Some("avg_pos_grade"),
+            54u8 => Some("avg_neg_grade"),
+            55u8 => Some("max_pos_grade"),
+            56u8 => Some("max_neg_grade"),
+            57u8 => Some("avg_temperature"),
+            58u8 => Some("max_temperature"),
+            59u8 => Some("total_moving_time"),
+            60u8 => Some("avg_pos_vertical_speed"),
+            61u8 => Some("avg_neg_vertical_speed"),
+            62u8 => Some("max_pos_vertical_speed"),
+            63u8 => Some("max_neg_vertical_speed"),
+            64u8 => Some("min_heart_rate"),
+            65u8 => Some("time_in_hr_zone"),
+            66u8 => Some("time_in_speed_zone"),
+            67u8 => Some("time_in_cadence_zone"),
+            68u8 => Some("time_in_power_zone"),
+            69u8 => Some("avg_lap_time"),
+            70u8 => Some("best_lap_index"),
+            71u8 => Some("min_altitude"),
+            82u8 => Some("player_score"),
+            83u8 => Some("opponent_score"),
+            84u8 => Some("opponent_name"),
+            85u8 => Some("stroke_count"),
+            86u8 => Some("zone_count"),
+            87u8 => Some("max_ball_speed"),
+            88u8 => Some("avg_ball_speed"),
+            89u8 => Some("avg_vertical_oscillation"),
+            90u8 => Some("avg_stance_time_percent"),
+            91u8 => Some("avg_stance_time"),
+            92u8 => Some("avg_fractional_cadence"),
+            93u8 => Some("max_fractional_cadence"),
+            94u8 => Some("total_fractional_cycles"),
+            95u8 => Some("avg_total_hemoglobin_conc"),
+            96u8 => Some("min_total_hemoglobin_conc"),
+            97u8 => Some("max_total_hemoglobin_conc"),
+            98u8 => Some("avg_saturated_hemoglobin_percent"),
+            99u8 => Some("min_saturated_hemoglobin_percent"),
+            100u8 => Some("max_saturated_hemoglobin_percent"),
+            101u8 => Some("avg_left_torque_effectiveness"),
+            102u8 => Some("avg_right_torque_effectiveness"),
+            103u8 => Some("avg_left_pedal_smoothness"),
+            104u8 => Some("avg_right_pedal_smoothness"),
+            105u8 => Some("avg_combined_pedal_smoothness"),
+            110u8 => Some("sport_profile_name"),
+            111u8 => Some("sport_index"),
+            112u8 => Some("time_standing"),
+            113u8 => Some("stand_count"),
+            114u8 => Some("avg_left_pco"),
+            115u8 => Some("avg_right_pco"),
+            116u8 => Some("avg_left_power_phase"),
+            117u8 => Some("avg_left_power_phase_peak"),
+            118u8 => Some("avg_right_power_phase"),
+            119u8 => Some("avg_right_power_phase_peak"),
+            120u8 => Some("avg_power_position"),
+            121u8 => Some("max_power_position"),
+            122u8 => Some("avg_cadence_position"),
+            123u8 => Some("max_cadence_position"),
+            124u8 => Some("enhanced_avg_speed"),
+            125u8 => Some("enhanced_max_speed"),
+            126u8 => Some("enhanced_avg_altitude"),
+            127u8 => Some("enhanced_min_altitude"),
+            128u8 => Some("enhanced_max_altitude"),
+            129u8 => Some("avg_lev_motor_power"),
+            130u8 => Some("max_lev_motor_power"),
+            131u8 => Some("lev_battery_consumption"),
+            132u8 => Some("avg_vertical_ratio"),
+            133u8 => Some("avg_stance_time_balance"),
+            134u8 => Some("avg_step_length"),
+            137u8 => Some("total_anaerobic_training_effect"),
+            139u8 => Some("avg_vam"),
+            140u8 => Some("avg_depth"),
+            141u8 => Some("max_depth"),
+            142u8 => Some("surface_interval"),
+            143u8 => Some("start_cns"),
+            144u8 => Some("end_cns"),
+            145u8 => Some("start_n2"),
+            146u8 => Some("end_n2"),
+            147u8 => Some("avg_respiration_rate"),
+            148u8 => Some("max_respiration_rate"),
+            149u8 => Some("min_respiration_rate"),
+            150u8 => Some("min_temperature"),
+            155u8 => Some("o2_toxicity"),
+            156u8 => Some("dive_number"),
+            168u8 => Some("training_load_peak"),
+            169u8 => Some("enhanced_avg_respiration_rate"),
+            170u8 => Some("enhanced_max_respiration_rate"),
+            180u8 => Some("enhanced_min_respiration_rate"),
+            181u8 => Some("total_grit"),
+            182u8 => Some("total_flow"),
+            183u8 => Some("jump_count"),
+            186u8 => Some("avg_grit"),
+            187u8 => Some("avg_flow"),
+            192u8 => Some("workout_feel"),
+            193u8 => Some("workout_rpe"),
+            194u8 => Some("avg_spo2"),
+            195u8 => Some("avg_stress"),
+            197u8 => Some("sdrr_hrv"),
+            198u8 => Some("rmssd_hrv"),
+            199u8 => Some("total_fractional_ascent"),
+            200u8 => Some("total_fractional_descent"),
+            208u8 => Some("avg_core_temperature"),
+            209u8 => Some("min_core_temperature"),
+            210u8 => Some("max_core_temperature"),
+            253u8 => Some("timestamp"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::Lap => match number {
+            0u8 => Some("event"),
+            1u8 => Some("event_type"),
+            2u8 => Some("start_time"),
+            3u8 => Some("start_position_lat"),
+            4u8 => Some("start_position_long"),
+            5u8 => Some("end_position_lat"),
+            6u8 => Some("end_position_long"),
+            7u8 => Some("total_elapsed_time"),
+            8u8 => Some("total_timer_time"),
+            9u8 => Some("total_distance"),
+            10u8 => Some("total_cycles"),
+            11u8 => Some("total_calories"),
+            12u8 => Some("total_fat_calories"),
+            13u8 => Some("avg_speed"),
+            14u8 => Some("max_speed"),
+            15u8 => Some("avg_heart_rate"),
+            16u8 => Some("max_heart_rate"),
+            17u8 => Some("avg_cadence"),
+            18u8 => Some("max_cadence"),
+            19u8 => Some("avg_power"),
+            20u8 => Some("max_power"),
+            21u8 => Some("total_ascent"),
+            22u8 => Some("total_descent"),
+            23u8 => Some("intensity"),
+            24u8 => Some("lap_trigger"),
+            25u8 => Some("sport"),
+            26u8 => Some("event_group"),
+            32u8 => Some("num_lengths"),
+            33u8 => Some("normalized_power"),
+            34u8 => Some("left_right_balance"),
+            35u8 => Some("first_length_index"),
+            37u8 => Some("avg_stroke_distance"),
+            38u8 => Some("swim_stroke"),
+            39u8 => Some("sub_sport"),
+            40u8 => Some("num_active_lengths"),
+            41u8 => Some("total_work"),
+            42u8 => Some("avg_altitude"),
+            43u8 => Some("max_altitude"),
+            44u8 => Some("gps_accuracy"),
+            45u8 => Some("avg_grade"),
+            46u8 => Some("avg_pos_grade"),
+            47u8 => Some("avg_neg_grade"),
+            48u8 => Some("max_pos_grade"),
+            49u8 => Some("max_neg_grade"),
+            50u8 => Some("avg_temperature"),
+            51u8 => Some("max_temperature"),
+            52u8 => Some("total_moving_time"),
+            53u8 => Some("avg_pos_vertical_speed"),
+            54u8 => Some("avg_neg_vertical_speed"),
+            55u8 => Some("max_pos_vertical_speed"),
+            56u8 => Some("max_neg_vertical_speed"),
+            57u8 => Some("time_in_hr_zone"),
+            58u8 => Some("time_in_speed_zone"),
+            59u8 => Some("time_in_cadence_zone"),
+            60u8 => Some("time_in_power_zone"),
+            61u8 => Some("repetition_num"),
+            62u8 => Some("min_altitude"),
+            63u8 => Some("min_heart_rate"),
+            71u8 => Some("wkt_step_index"),
+            74u8 => Some("opponent_score"),
+            75u8 => Some("stroke_count"),
+            76u8 => Some("zone_count"),
+            77u8 => Some("avg_vertical_oscillation"),
+            78u8 => Some("avg_stance_time_percent"),
+            79u8 => Some("avg_stance_time"),
+            80u8 => Some("avg_fractional_cadence"),
+            81u8 => Some("max_fractional_cadence"),
+            82u8 => Some("total_fractional_cycles"),
+            83u8 => Some("player_score"),
+            84u8 => Some("avg_total_hemoglobin_conc"),
+            85u8 => Some("min_total_hemoglobin_conc"),
+            86u8 => Some("max_total_hemoglobin_conc"),
+            87u8 => Some("avg_saturated_hemoglobin_percent"),
+            88u8 => Some("min_saturated_hemoglobin_percent"),
+            89u8 => Some("max_saturated_hemoglobin_percent"),
+            91u8 => Some("avg_left_torque_effectiveness"),
+            92u8 => Some("avg_right_torque_effectiveness"),
+            93u8 => Some("avg_left_pedal_smoothness"),
+            94u8 => Some("avg_right_pedal_smoothness"),
+            95u8 => Some("avg_combined_pedal_smoothness"),
+            98u8 => Some("time_standing"),
+            99u8 => Some("stand_count"),
+            100u8 => Some("avg_left_pco"),
+            101u8 => Some("avg_right_pco"),
+            102u8 => Some("avg_left_power_phase"),
+            103u8 => Some("avg_left_power_phase_peak"),
+            104u8 => Some("avg_right_power_phase"),
+            105u8 => Some("avg_right_power_phase_peak"),
+            106u8 => Some("avg_power_position"),
+            107u8 => Some("max_power_position"),
+            108u8 => Some("avg_cadence_position"),
+            109u8 => Some("max_cadence_position"),
+            110u8 => Some("enhanced_avg_speed"),
+            111u8 => Some("enhanced_max_speed"),
+            112u8 => Some("enhanced_avg_altitude"),
+            113u8 => Some("enhanced_min_altitude"),
+            114u8 => Some("enhanced_max_altitude"),
+            115u8 => Some("avg_lev_motor_power"),
+            116u8 => Some("max_lev_motor_power"),
+            117u8 => Some("lev_battery_consumption"),
+            118u8 => Some("avg_vertical_ratio"),
+            119u8 => Some("avg_stance_time_balance"),
+            120u8 => Some("avg_step_length"),
+            121u8 => Some("avg_vam"),
+            122u8 => Some("avg_depth"),
+            123u8 => Some("max_depth"),
+            124u8 => Some("min_temperature"),
+            136u8 => Some("enhanced_avg_respiration_rate"),
+            137u8 => Some("enhanced_max_respiration_rate"),
+            147u8 => Some("avg_respiration_rate"),
+            148u8 => Some("max_respiration_rate"),
+            149u8 => Some("total_grit"),
+            150u8 => Some("total_flow"),
+            151u8 => Some("jump_count"),
+            153u8 => Some("avg_grit"),
+            154u8 => Some("avg_flow"),
+            156u8 => Some("total_fractional_ascent"),
+            157u8 => Some("total_fractional_descent"),
+            158u8 => Some("avg_core_temperature"),
+            159u8 => Some("min_core_temperature"),
+            160u8 => Some("max_core_temperature"),
+            253u8 => Some("timestamp"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::Length => match number {
+            0u8 => Some("event"),
+            1u8 => Some("event_type"),
+            2u8 => Some("start_time"),
+            3u8 => Some("total_elapsed_time"),
+            4u8 => Some("total_timer_time"),
+            5u8 => Some("total_strokes"),
+            6u8 => Some("avg_speed"),
+            7u8 => Some("swim_stroke"),
+            9u8 => Some("avg_swimming_cadence"),
+            10u8 => Some("event_group"),
+            11u8 => Some("total_calories"),
+            12u8 => Some("length_type"),
+            18u8 => Some("player_score"),
+            19u8 => Some("opponent_score"),
+            20u8 => Some("stroke_count"),
+            21u8 => Some("zone_count"),
+            22u8 => Some("enhanced_avg_respiration_rate"),
+            23u8 => Some("enhanced_max_respiration_rate"),
+            24u8 => Some("avg_respiration_rate"),
+            25u8 => Some("max_respiration_rate"),
+            253u8 => Some("timestamp"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::Record => match number {
+            0u8 => Some("position_lat"),
+            1u8 => Some("position_long"),
+            2u8 => Some("altitude"),
+            3u8 => Some("heart_rate"),
+            4u8 => Some("cadence"),
+            5u8 => Some("distance"),
+            6u8 => Some("speed"),
+            7u8 => Some("power"),
+            8u8 => Some("compressed_speed_distance"),
+            9u8 => Some("grade"),
+            10u8 => Some("resistance"),
+            11u8 => Some("time_from_course"),
+            12u8 => Some("cycle_length"),
+            13u8 => Some("temperature"),
+            17u8 => Some("speed_1s"),
+            18u8 => Some("cycles"),
+            19u8 => Some("total_cycles"),
+            28u8 => Some("compressed_accumulated_power"),
+            29u8 => Some("accumulated_power"),
+            30u8 => Some("left_right_balance"),
+            31u8 => Some("gps_accuracy"),
+            32u8 => Some("vertical_speed"),
+            33u8 => Some("calories"),
+            39u8 => Some("vertical_oscillation"),
+            40u8 => Some("stance_time_percent"),
+            41u8 => Some("stance_time"),
+            42u8 => Some("activity_type"),
+            43u8 => Some("left_torque_effectiveness"),
+            44u8 => Some("right_torque_effectiveness"),
+            45u8 => Some("left_pedal_smoothness"),
+            46u8 => Some("right_pedal_smoothness"),
+            47u8 => Some("combined_pedal_smoothness"),
+            48u8 => Some("time128"),
+            49u8 => Some("stroke_type"),
+            50u8 => Some("zone"),
+            51u8 => Some("ball_speed"),
+            52u8 => Some("cadence256"),
+            53u8 => Some("fractional_cadence"),
+            54u8 => Some("total_hemoglobin_conc"),
+            55u8 => Some("total_hemoglobin_conc_min"),
+            56u8 => Some("total_hemoglobin_conc_max"),
+            57u8 => Some("saturated_hemoglobin_percent"),
+            58u8 => Some("saturated_hemoglobin_percent_min"),
+            59u8 => Some("saturated_hemoglobin_percent_max"),
+            62u8 => Some("device_index"),
+            67u8 => Some("left_pco"),
+            68u8 => Some("right_pco"),
+            69u8 => Some("left_power_phase"),
+            70u8 => Some("left_power_phase_peak"),
+            71u8 => Some("right_power_phase"),
+            72u8 => Some("right_power_phase_peak"),
+            73u8 => Some("enhanced_speed"),
+            78u8 => Some("enhanced_altitude"),
+            81u8 => Some("battery_soc"),
+            82u8 => Some("motor_power"),
+            83u8 => Some("vertical_ratio"),
+            84u8 => Some("stance_time_balance"),
+            85u8 => Some("step_length"),
+            87u8 => Some("cycle_length16"),
+            91u8 => Some("absolute_pressure"),
+            92u8 => Some("depth"),
+            93u8 => Some("next_stop_depth"),
+            94u8 => Some("next_stop_time"),
+            95u8 => Some("time_to_surface"),
+            96u8 => Some("ndl_time"),
+            97u8 => Some("cns_load"),
+            98u8 => Some("n2_load"),
+            99u8 => Some("respiration_rate"),
+            108u8 => Some("enhanced_respiration_rate"),
+            114u8 => Some("grit"),
+            115u8 => Some("flow"),
+            116u8 => Some("current_stress"),
+            117u8 => Some("ebike_travel_range"),
+            118u8 => Some("ebike_battery_level"),
+            119u8 => Some("ebike_assist_mode"),
+            120u8 => Some("ebike_assist_level_percent"),
+            123u8 => Some("air_time_remaining"),
+            124u8 => Some("pressure_sac"),
+            125u8 => Some("volume_sac"),
+            126u8 => Some("rmv"),
+            127u8 => Some("ascent_rate"),
+            129u8 => Some("po2"),
+            139u8 => Some("core_temperature"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::Event => match number {
+            0u8 => Some("event"),
+            1u8 => Some("event_type"),
+            2u8 => Some("data16"),
+            3u8 => Some("data"),
+            4u8 => Some("event_group"),
+            7u8 => Some("score"),
+            8u8 => Some("opponent_score"),
+            9u8 => Some("front_gear_num"),
+            10u8 => Some("front_gear"),
+            11u8 => Some("rear_gear_num"),
+            12u8 => Some("rear_gear"),
+            13u8 => Some("device_index"),
+            14u8 => Some("activity_type"),
+            15u8 => Some("start_timestamp"),
+            21u8 => Some("radar_threat_level_max"),
+            22u8 => Some("radar_threat_count"),
+            23u8 => Some("radar_threat_avg_approach_speed"),
+            24u8 => Some("radar_threat_max_approach_speed"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::DeviceInfo => match number {
+            0u8 => Some("device_index"),
+            1u8 => Some("device_type"),
+            2u8 => Some("manufacturer"),
+            3u8 => Some("serial_number"),
+            4u8 => Some("product"),
+            5u8 => Some("software_version"),
+            6u8 => Some("hardware_version"),
+            7u8 => Some("cum_operating_time"),
+            10u8 => Some("battery_voltage"),
+            11u8 => Some("battery_status"),
+            18u8 => Some("sensor_position"),
+            19u8 => Some("descriptor"),
+            20u8 => Some("ant_transmission_type"),
+            21u8 => Some("ant_device_number"),
+            22u8 => Some("ant_network"),
+            25u8 => Some("source_type"),
+            27u8 => Some("product_name"),
+            32u8 => Some("battery_level"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::DeviceAuxBatteryInfo => match number {
+            0u8 => Some("device_index"),
+            1u8 => Some("battery_voltage"),
+            2u8 => Some("battery_status"),
+            3u8 => Some("battery_identifier"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::TrainingFile => match number {
+            0u8 => Some("type"),
+            1u8 => Some("manufacturer"),
+            2u8 => Some("product"),
+            3u8 => Some("serial_number"),
+            4u8 => Some("time_created"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::WeatherConditions => match number {
+            0u8 => Some("weather_report"),
+            1u8 => Some("temperature"),
+            2u8 => Some("condition"),
+            3u8 => Some("wind_direction"),
+            4u8 => Some("wind_speed"),
+            5u8 => Some("precipitation_probability"),
+            6u8 => Some("temperature_feels_like"),
+            7u8 => Some("relative_humidity"),
+            8u8 => Some("location"),
+            9u8 => Some("observed_at_time"),
+            10u8 => Some("observed_location_lat"),
+            11u8 => Some("observed_location_long"),
+            12u8 => Some("day_of_week"),
+            13u8 => Some("high_temperature"),
+            14u8 => Some("low_temperature"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::WeatherAlert => match number {
+            0u8 => Some("report_id"),
+            1u8 => Some("issue_time"),
+            2u8 => Some("expire_time"),
+            3u8 => Some("severity"),
+            4u8 => Some("type"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::GpsMetadata => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("position_lat"),
+            2u8 => Some("position_long"),
+            3u8 => Some("enhanced_altitude"),
+            4u8 => Some("enhanced_speed"),
+            5u8 => Some("heading"),
+            6u8 => Some("utc_timestamp"),
+            7u8 => Some("velocity"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::CameraEvent => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("camera_event_type"),
+            2u8 => Some("camera_file_uuid"),
+            3u8 => Some("camera_orientation"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::GyroscopeData => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("sample_time_offset"),
+            2u8 => Some("gyro_x"),
+            3u8 => Some("gyro_y"),
+            4u8 => Some("gyro_z"),
+            5u8 => Some("calibrated_gyro_x"),
+            6u8 => Some("calibrated_gyro_y"),
+            7u8 => Some("calibrated_gyro_z"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::AccelerometerData => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("sample_time_offset"),
+            2u8 => Some("accel_x"),
+            3u8 => Some("accel_y"),
+            4u8 => Some("accel_z"),
+            5u8 => Some("calibrated_accel_x"),
+            6u8 => Some("calibrated_accel_y"),
+            7u8 => Some("calibrated_accel_z"),
+            8u8 => Some("compressed_calibrated_accel_x"),
+            9u8 => Some("compressed_calibrated_accel_y"),
+            10u8 => Some("compressed_calibrated_accel_z"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::MagnetometerData => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("sample_time_offset"),
+            2u8 => Some("mag_x"),
+            3u8 => Some("mag_y"),
+            4u8 => Some("mag_z"),
+            5u8 => Some("calibrated_mag_x"),
+            6u8 => Some("calibrated_mag_y"),
+            7u8 => Some("calibrated_mag_z"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::BarometerData => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("sample_time_offset"),
+            2u8 => Some("baro_pres"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::ThreeDSensorCalibration => match number {
+            0u8 => Some("sensor_type"),
+            1u8 => Some("calibration_factor"),
+            2u8 => Some("calibration_divisor"),
+            3u8 => Some("level_shift"),
+            4u8 => Some("offset_cal"),
+            5u8 => Some("orientation_matrix"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::OneDSensorCalibration => match number {
+            0u8 => Some("sensor_type"),
+            1u8 => Some("calibration_factor"),
+            2u8 => Some("calibration_divisor"),
+            3u8 => Some("level_shift"),
+            4u8 => Some("offset_cal"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::VideoFrame => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("frame_number"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::ObdiiData => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("time_offset"),
+            2u8 => Some("pid"),
+            3u8 => Some("raw_data"),
+            4u8 => Some("pid_data_size"),
+            5u8 => Some("system_time"),
+            6u8 => Some("start_timestamp"),
+            7u8 => Some("start_timestamp_ms"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::NmeaSentence => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("sentence"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::AviationAttitude => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("system_time"),
+            2u8 => Some("pitch"),
+            3u8 => Some("roll"),
+            4u8 => Some("accel_lateral"),
+            5u8 => Some("accel_normal"),
+            6u8 => Some("turn_rate"),
+            7u8 => Some("stage"),
+            8u8 => Some("attitude_stage_complete"),
+            9u8 => Some("track"),
+            10u8 => Some("validity"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::Video => match number {
+            0u8 => Some("url"),
+            1u8 => Some("hosting_provider"),
+            2u8 => Some("duration"),
+            _ => None,
+        },
+        MesgNum::VideoTitle => match number {
+            0u8 => Some("message_count"),
+            1u8 => Some("text"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::VideoDescription => match number {
+            0u8 => Some("message_count"),
+            1u8 => Some("text"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::VideoClip => match number {
+            0u8 => Some("clip_number"),
+            1u8 => Some("start_timestamp"),
+            2u8 => Some("start_timestamp_ms"),
+            3u8 => Some("end_timestamp"),
+            4u8 => Some("end_timestamp_ms"),
+            6u8 => Some("clip_start"),
+            7u8 => Some("clip_end"),
+            _ => None,
+        },
+        MesgNum::Set => match number {
+            0u8 => Some("duration"),
+            3u8 => Some("repetitions"),
+            4u8 => Some("weight"),
+            5u8 => Some("set_type"),
+            6u8 => Some("start_time"),
+            7u8 => Some("category"),
+            8u8 => Some("category_subtype"),
+            9u8 => Some("weight_display_unit"),
+            10u8 => Some("message_index"),
+            11u8 => Some("wkt_step_index"),
+            254u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::Jump => match number {
+            0u8 => Some("distance"),
+            1u8 => Some("height"),
+            2u8 => Some("rotations"),
+            3u8 => Some("hang_time"),
+            4u8 => Some("score"),
+            5u8 => Some("position_lat"),
+            6u8 => Some("position_long"),
+            7u8 => Some("speed"),
+            8u8 => Some("enhanced_speed"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::Split => match number {
+            0u8 => Some("split_type"),
+            1u8 => Some("total_elapsed_time"),
+            2u8 => Some("total_timer_time"),
+            3u8 => Some("total_distance"),
+            4u8 => Some("avg_speed"),
+            9u8 => Some("start_time"),
+            13u8 => Some("total_ascent"),
+            14u8 => Some("total_descent"),
+            21u8 => Some("start_position_lat"),
+            22u8 => Some("start_position_long"),
+            23u8 => Some("end_position_lat"),
+            24u8 => Some("end_position_long"),
+            25u8 => Some("max_speed"),
+            26u8 => Some("avg_vert_speed"),
+            27u8 => Some("end_time"),
+            28u8 => Some("total_calories"),
+            74u8 => Some("start_elevation"),
+            110u8 => Some("total_moving_time"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::SplitSummary => match number {
+            0u8 => Some("split_type"),
+            3u8 => Some("num_splits"),
+            4u8 => Some("total_timer_time"),
+            5u8 => Some("total_distance"),
+            6u8 => Some("avg_speed"),
+            7u8 => Some("max_speed"),
+            8u8 => Some("total_ascent"),
+            9u8 => Some("total_descent"),
+            10u8 => Some("avg_heart_rate"),
+            11u8 => Some("max_heart_rate"),
+            12u8 => Some("avg_vert_speed"),
+            13u8 => Some("total_calories"),
+            77u8 => Some("total_moving_time"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::ClimbPro => match number {
+            0u8 => Some("position_lat"),
+            1u8 => Some("position_long"),
+            2u8 => Some("climb_pro_event"),
+            3u8 => Some("climb_number"),
+            4u8 => Some("climb_category"),
+            5u8 => Some("current_dist"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::FieldDescription => match number {
+            0u8 => Some("developer_data_index"),
+            1u8 => Some("field_definition_number"),
+            2u8 => Some("fit_base_type_id"),
+            3u8 => Some("field_name"),
+            4u8 => Some("array"),
+            5u8 => Some("components"),
+            6u8 => Some("scale"),
+            7u8 => Some("offset"),
+            8u8 => Some("units"),
+            9u8 => Some("bits"),
+            10u8 => Some("accumulate"),
+            13u8 => Some("fit_base_unit_id"),
+            14u8 => Some("native_mesg_num"),
+            15u8 => Some("native_field_num"),
+            _ => None,
+        },
+        MesgNum::DeveloperDataId => match number {
+            0u8 => Some("developer_id"),
+            1u8 => Some("application_id"),
+            2u8 => Some("manufacturer_id"),
+            3u8 => Some("developer_data_index"),
+            4u8 => Some("application_version"),
+            _ => None,
+        },
+        MesgNum::Course => match number {
+            4u8 => Some("sport"),
+            5u8 => Some("name"),
+            6u8 => Some("capabilities"),
+            7u8 => Some("sub_sport"),
+            _ => None,
+        },
+        MesgNum::CoursePoint => match number {
+            1u8 => Some("timestamp"),
+            2u8 => Some("position_lat"),
+            3u8 => Some("position_long"),
+            4u8 => Some("distance"),
+            5u8 => Some("type"),
+            6u8 => Some("name"),
+            8u8 => Some("favorite"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::SegmentId => match number {
+            0u8 => Some("name"),
+            1u8 => Some("uuid"),
+            2u8 => Some("sport"),
+            3u8 => Some("enabled"),
+            4u8 => Some("user_profile_primary_key"),
+            5u8 => Some("device_id"),
+            6u8 => Some("default_race_leader"),
+            7u8 => Some("delete_status"),
+            8u8 => Some("selection_type"),
+            _ => None,
+        },
+        MesgNum::SegmentLeaderboardEntry => match number {
+            0u8 => Some("name"),
+            1u8 => Some("type"),
+            2u8 => Some("group_primary_key"),
+            3u8 => Some("activity_id"),
+            4u8 => Some("segment_time"),
+            5u8 => Some("activity_id_string"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::SegmentPoint => match number {
+            1u8 => Some("position_lat"),
+            2u8 => Some("position_long"),
+            3u8 => Some("distance"),
+            4u8 => Some("altitude"),
+            5u8 => Some("leader_time"),
+            6u8 => Some("enhanced_altitude"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::SegmentLap => match number {
+            0u8 => Some("event"),
+            1u8 => Some("event_type"),
+            2u8 => Some("start_time"),
+            3u8 => Some("start_position_lat"),
+            4u8 => Some("start_position_long"),
+            5u8 => Some("end_position_lat"),
+            6u8 => Some("end_position_long"),
+            7u8 => Some("total_elapsed_time"),
+            8u8 => Some("total_timer_time"),
+            9u8 => Some("total_distance"),
+            10u8 => Some("total_cycles"),
+            11u8 => Some("total_calories"),
+            12u8 => Some("total_fat_calories"),
+            13u8 => Some("avg_speed"),
+            14u8 => Some("max_speed"),
+            15u8 => Some("avg_heart_rate"),
+            16u8 => Some("max_heart_rate"),
+            17u8 => Some("avg_cadence"),
+            18u8 => Some("max_cadence"),
+            19u8 => Some("avg_power"),
+            20u8 => Some("max_power"),
+            21u8 => Some("total_ascent"),
+            22u8 => Some("total_descent"),
+            23u8 => Some("sport"),
+            24u8 => Some("event_group"),
+            25u8 => Some("nec_lat"),
+            26u8 => Some("nec_long"),
+            27u8 => Some("swc_lat"),
+            28u8 => Some("swc_long"),
+            29u8 => Some("name"),
+            30u8 => Some("normalized_power"),
+            31u8 => Some("left_right_balance"),
+            32u8 => Some("sub_sport"),
+            33u8 => Some("total_work"),
+            34u8 => Some("avg_altitude"),
+            35u8 => Some("max_altitude"),
+            36u8 => Some("gps_accuracy"),
+            37u8 => Some("avg_grade"),
+            38u8 => Some("avg_pos_grade"),
+            39u8 => Some("avg_neg_grade"),
+            40u8 => Some("max_pos_grade"),
+            41u8 => Some("max_neg_grade"),
+            42u8 => Some("avg_temperature"),
+            43u8 => Some("max_temperature"),
+            44u8 => Some("total_moving_time"),
+            45u8 => Some("avg_pos_vertical_speed"),
+            46u8 => Some("avg_neg_vertical_speed"),
+            47u8 => Some("max_pos_vertical_speed"),
+            48u8 => Some("max_neg_vertical_speed"),
+            49u8 => Some("time_in_hr_zone"),
+            50u8 => Some("time_in_speed_zone"),
+            51u8 => Some("time_in_cadence_zone"),
+            52u8 => Some("time_in_power_zone"),
+            53u8 => Some("repetition_num"),
+            54u8 => Some("min_altitude"),
+            55u8 => Some("min_heart_rate"),
+            56u8 => Some("active_time"),
+            57u8 => Some("wkt_step_index"),
+            58u8 => Some("sport_event"),
+            59u8 => Some("avg_left_torque_effectiveness"),
+            60u8 => Some("avg_right_torque_effectiveness"),
+            61u8 => Some("avg_left_pedal_smoothness"),
+            62u8 => Some("avg_right_pedal_smoothness"),
+            63u8 => Some("avg_combined_pedal_smoothness"),
+            64u8 => Some("status"),
+            65u8 => Some("uuid"),
+            66u8 => Some("avg_fractional_cadence"),
+            67u8 => Some("max_fractional_cadence"),
+            68u8 => Some("total_fractional_cycles"),
+            69u8 => Some("front_gear_shift_count"),
+            70u8 => Some("rear_gear_shift_count"),
+            71u8 => Some("time_standing"),
+            72u8 => Some("stand_count"),
+            73u8 => Some("avg_left_pco"),
+            74u8 => Some("avg_right_pco"),
+            75u8 => Some("avg_left_power_phase"),
+            76u8 => Some("avg_left_power_phase_peak"),
+            77u8 => Some("avg_right_power_phase"),
+            78u8 => Some("avg_right_power_phase_peak"),
+            79u8 => Some("avg_power_position"),
+            80u8 => Some("max_power_position"),
+            81u8 => Some("avg_cadence_position"),
+            82u8 => Some("max_cadence_position"),
+            83u8 => Some("manufacturer"),
+            84u8 => Some("total_grit"),
+            85u8 => Some("total_flow"),
+            86u8 => Some("avg_grit"),
+            87u8 => Some("avg_flow"),
+            89u8 => Some("total_fractional_ascent"),
+            90u8 => Some("total_fractional_descent"),
+            91u8 => Some("enhanced_avg_altitude"),
+            92u8 => Some("enhanced_max_altitude"),
+            93u8 => Some("enhanced_min_altitude"),
+            253u8 => Some("timestamp"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::SegmentFile => match number {
+            1u8 => Some("file_uuid"),
+            3u8 => Some("enabled"),
+            4u8 => Some("user_profile_primary_key"),
+            7u8 => Some("leader_type"),
+            8u8 => Some("leader_group_primary_key"),
+            9u8 => Some("leader_activity_id"),
+            10u8 => Some("leader_activity_id_string"),
+            11u8 => Some("default_race_leader"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::Workout => match number {
+            4u8 => Some("sport"),
+            5u8 => Some("capabilities"),
+            6u8 => Some("num_valid_steps"),
+            8u8 => Some("wkt_name"),
+            11u8 => Some("sub_sport"),
+            14u8 => Some("pool_length"),
+            15u8 => Some("pool_length_unit"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::WorkoutSession => match number {
+            0u8 => Some("sport"),
+            1u8 => Some("sub_sport"),
+            2u8 => Some("num_valid_steps"),
+            3u8 => Some("first_step_index"),
+            4u8 => Some("pool_length"),
+            5u8 => Some("pool_length_unit"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::WorkoutStep => match number {
+            0u8 => Some("wkt_step_name"),
+            1u8 => Some("duration_type"),
+            2u8 => Some("duration_value"),
+            3u8 => Some("target_type"),
+            4u8 => Some("target_value"),
+            5u8 => Some("custom_target_value_low"),
+            6u8 => Some("custom_target_value_high"),
+            7u8 => Some("intensity"),
+            8u8 => Some("notes"),
+            9u8 => Some("equipment"),
+            10u8 => Some("exercise_category"),
+            11u8 => Some("exercise_name"),
+            12u8 => Some("exercise_weight"),
+            13u8 => Some("weight_display_unit"),
+            19u8 => Some("secondary_target_type"),
+            20u8 => Some("secondary_target_value"),
+            21u8 => Some("secondary_custom_target_value_low"),
+            22u8 => Some("secondary_custom_target_value_high"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::ExerciseTitle => match number {
+            0u8 => Some("exercise_category"),
+            1u8 => Some("exercise_name"),
+            2u8 => Some("wkt_step_name"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::Schedule => match number {
+            0u8 => Some("manufacturer"),
+            1u8 => Some("product"),
+            2u8 => Some("serial_number"),
+            3u8 => Some("time_created"),
+            4u8 => Some("completed"),
+            5u8 => Some("type"),
+            6u8 => Some("scheduled_time"),
+            _ => None,
+        },
+        MesgNum::Totals => match number {
+            0u8 => Some("timer_time"),
+            1u8 => Some("distance"),
+            2u8 => Some("calories"),
+            3u8 => Some("sport"),
+            4u8 => Some("elapsed_time"),
+            5u8 => Some("sessions"),
+            6u8 => Some("active_time"),
+            9u8 => Some("sport_index"),
+            253u8 => Some("timestamp"),
+            254u8 => Some("message_index"),
+            _ => None,
+        },
+        MesgNum::WeightScale => match number {
+            0u8 => Some("weight"),
+            1u8 => Some("percent_fat"),
+            2u8 => Some("percent_hydration"),
+            3u8 => Some("visceral_fat_mass"),
+            4u8 => Some("bone_mass"),
+            5u8 => Some("muscle_mass"),
+            7u8 => Some("basal_met"),
+            8u8 => Some("physique_rating"),
+            9u8 => Some("active_met"),
+            10u8 => Some("metabolic_age"),
+            11u8 => Some("visceral_fat_rating"),
+            12u8 => Some("user_profile_index"),
+            13u8 => Some("bmi"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::BloodPressure => match number {
+            0u8 => Some("systolic_pressure"),
+            1u8 => Some("diastolic_pressure"),
+            2u8 => Some("mean_arterial_pressure"),
+            3u8 => Some("map_3_sample_mean"),
+            4u8 => Some("map_morning_values"),
+            5u8 => Some("map_evening_values"),
+            6u8 => Some("heart_rate"),
+            7u8 => Some("heart_rate_type"),
+            8u8 => Some("status"),
+            9u8 => Some("user_profile_index"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::MonitoringInfo => match number {
+            0u8 => Some("local_timestamp"),
+            1u8 => Some("activity_type"),
+            3u8 => Some("cycles_to_distance"),
+            4u8 => Some("cycles_to_calories"),
+            5u8 => Some("resting_metabolic_rate"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::Monitoring => match number {
+            0u8 => Some("device_index"),
+            1u8 => Some("calories"),
+            2u8 => Some("distance"),
+            3u8 => Some("cycles"),
+            4u8 => Some("active_time"),
+            5u8 => Some("activity_type"),
+            6u8 => Some("activity_subtype"),
+            7u8 => Some("activity_level"),
+            8u8 => Some("distance_16"),
+            9u8 => Some("cycles_16"),
+            10u8 => Some("active_time_16"),
+            11u8 => Some("local_timestamp"),
+            12u8 => Some("temperature"),
+            14u8 => Some("temperature_min"),
+            15u8 => Some("temperature_max"),
+            16u8 => Some("activity_time"),
+            19u8 => Some("active_calories"),
+            24u8 => Some("current_activity_type_intensity"),
+            25u8 => Some("timestamp_min_8"),
+            26u8 => Some("timestamp_16"),
+            27u8 => Some("heart_rate"),
+            28u8 => Some("intensity"),
+            29u8 => Some("duration_min"),
+            30u8 => Some("duration"),
+            31u8 => Some("ascent"),
+            32u8 => Some("descent"),
+            33u8 => Some("moderate_activity_minutes"),
+            34u8 => Some("vigorous_activity_minutes"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::MonitoringHrData => match number {
+            0u8 => Some("resting_heart_rate"),
+            1u8 => Some("current_day_resting_heart_rate"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::Spo2Data => match number {
+            0u8 => Some("reading_spo2"),
+            1u8 => Some("reading_confidence"),
+            2u8 => Some("mode"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::Hr => match number {
+            0u8 => Some("fractional_timestamp"),
+            1u8 => Some("time256"),
+            6u8 => Some("filtered_bpm"),
+            9u8 => Some("event_timestamp"),
+            10u8 => Some("event_timestamp_12"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::StressLevel => match number {
+            0u8 => Some("stress_level_value"),
+            1u8 => Some("stress_level_time"),
+            _ => None,
+        },
+        MesgNum::MaxMetData => match number {
+            0u8 => Some("update_time"),
+            2u8 => Some("vo2_max"),
+            5u8 => Some("sport"),
+            6u8 => Some("sub_sport"),
+            8u8 => Some("max_met_category"),
+            9u8 => Some("calibrated_data"),
+            12u8 => Some("hr_source"),
+            13u8 => Some("speed_source"),
+            _ => None,
+        },
+        MesgNum::HsaBodyBatteryData => match number {
+            0u8 => Some("processing_interval"),
+            1u8 => Some("level"),
+            2u8 => Some("charged"),
+            3u8 => Some("uncharged"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::HsaEvent => match number {
+            0u8 => Some("event_id"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::HsaAccelerometerData => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("sampling_interval"),
+            2u8 => Some("accel_x"),
+            3u8 => Some("accel_y"),
+            4u8 => Some("accel_z"),
+            5u8 => Some("timestamp_32k"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::HsaGyroscopeData => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("sampling_interval"),
+            2u8 => Some("gyro_x"),
+            3u8 => Some("gyro_y"),
+            4u8 => Some("gyro_z"),
+            5u8 => Some("timestamp_32k"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::HsaStepData => match number {
+            0u8 => Some("processing_interval"),
+            1u8 => Some("steps"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::HsaSpo2Data => match number {
+            0u8 => Some("processing_interval"),
+            1u8 => Some("reading_spo2"),
+            2u8 => Some("confidence"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::HsaStressData => match number {
+            0u8 => Some("processing_interval"),
+            1u8 => Some("stress_level"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::HsaRespirationData => match number {
+            0u8 => Some("processing_interval"),
+            1u8 => Some("respiration_rate"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::HsaHeartRateData => match number {
+            0u8 => Some("processing_interval"),
+            1u8 => Some("status"),
+            2u8 => Some("heart_rate"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::HsaConfigurationData => match number {
+            0u8 => Some("data"),
+            1u8 => Some("data_size"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::HsaWristTemperatureData => match number {
+            0u8 => Some("processing_interval"),
+            1u8 => Some("value"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::MemoGlob => match number {
+            0u8 => Some("memo"),
+            1u8 => Some("mesg_num"),
+            2u8 => Some("parent_index"),
+            3u8 => Some("field_num"),
+            4u8 => Some("data"),
+            250u8 => Some("part_index"),
+            _ => None,
+        },
+        MesgNum::SleepLevel => match number {
+            0u8 => Some("sleep_level"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::AntChannelId => match number {
+            0u8 => Some("channel_number"),
+            1u8 => Some("device_type"),
+            2u8 => Some("device_number"),
+            3u8 => Some("transmission_type"),
+            4u8 => Some("device_index"),
+            _ => None,
+        },
+        MesgNum::AntRx => match number {
+            0u8 => Some("fractional_timestamp"),
+            1u8 => Some("mesg_id"),
+            2u8 => Some("mesg_data"),
+            3u8 => Some("channel_number"),
+            4u8 => Some("data"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::AntTx => match number {
+            0u8 => Some("fractional_timestamp"),
+            1u8 => Some("mesg_id"),
+            2u8 => Some("mesg_data"),
+            3u8 => Some("channel_number"),
+            4u8 => Some("data"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::ExdScreenConfiguration => match number {
+            0u8 => Some("screen_index"),
+            1u8 => Some("field_count"),
+            2u8 => Some("layout"),
+            3u8 => Some("screen_enabled"),
+            _ => None,
+        },
+        MesgNum::ExdDataFieldConfiguration => match number {
+            0u8 => Some("screen_index"),
+            1u8 => Some("concept_field"),
+            2u8 => Some("field_id"),
+            3u8 => Some("concept_count"),
+            4u8 => Some("display_type"),
+            5u8 => Some("title"),
+            _ => None,
+        },
+        MesgNum::ExdDataConceptConfiguration => match number {
+            0u8 => Some("screen_index"),
+            1u8 => Some("concept_field"),
+            2u8 => Some("field_id"),
+            3u8 => Some("concept_index"),
+            4u8 => Some("data_page"),
+            5u8 => Some("concept_key"),
+            6u8 => Some("scaling"),
+            8u8 => Some("data_units"),
+            9u8 => Some("qualifier"),
+            10u8 => Some("descriptor"),
+            11u8 => Some("is_signed"),
+            _ => None,
+        },
+        MesgNum::DiveSummary => match number {
+            0u8 => Some("reference_mesg"),
+            1u8 => Some("reference_index"),
+            2u8 => Some("avg_depth"),
+            3u8 => Some("max_depth"),
+            4u8 => Some("surface_interval"),
+            5u8 => Some("start_cns"),
+            6u8 => Some("end_cns"),
+            7u8 => Some("start_n2"),
+            8u8 => Some("end_n2"),
+            9u8 => Some("o2_toxicity"),
+            10u8 => Some("dive_number"),
+            11u8 => Some("bottom_time"),
+            12u8 => Some("avg_pressure_sac"),
+            13u8 => Some("avg_volume_sac"),
+            14u8 => Some("avg_rmv"),
+            15u8 => Some("descent_time"),
+            16u8 => Some("ascent_time"),
+            17u8 => Some("avg_ascent_rate"),
+            22u8 => Some("avg_descent_rate"),
+            23u8 => Some("max_ascent_rate"),
+            24u8 => Some("max_descent_rate"),
+            25u8 => Some("hang_time"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::AadAccelFeatures => match number {
+            0u8 => Some("time"),
+            1u8 => Some("energy_total"),
+            2u8 => Some("zero_cross_cnt"),
+            3u8 => Some("instance"),
+            4u8 => Some("time_above_threshold"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::Hrv => match number {
+            0u8 => Some("time"),
+            _ => None,
+        },
+        MesgNum::BeatIntervals => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("time"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::HrvStatusSummary => match number {
+            0u8 => Some("weekly_average"),
+            1u8 => Some("last_night_average"),
+            2u8 => Some("last_night_5_min_high"),
+            3u8 => Some("baseline_low_upper"),
+            4u8 => Some("baseline_balanced_lower"),
+            5u8 => Some("baseline_balanced_upper"),
+            6u8 => Some("status"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::HrvValue => match number {
+            0u8 => Some("value"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::RawBbi => match number {
+            0u8 => Some("timestamp_ms"),
+            1u8 => Some("data"),
+            2u8 => Some("time"),
+            3u8 => Some("quality"),
+            4u8 => Some("gap"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::RespirationRate => match number {
+            0u8 => Some("respiration_rate"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::ChronoShotSession => match number {
+            0u8 => Some("min_speed"),
+            1u8 => Some("max_speed"),
+            2u8 => Some("avg_speed"),
+            3u8 => Some("shot_count"),
+            4u8 => Some("projectile_type"),
+            5u8 => Some("grain_weight"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::ChronoShotData => match number {
+            0u8 => Some("shot_speed"),
+            1u8 => Some("shot_num"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::TankUpdate => match number {
+            0u8 => Some("sensor"),
+            1u8 => Some("pressure"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::TankSummary => match number {
+            0u8 => Some("sensor"),
+            1u8 => Some("start_pressure"),
+            2u8 => Some("end_pressure"),
+            3u8 => Some("volume_used"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        MesgNum::SleepAssessment => match number {
+            0u8 => Some("combined_awake_score"),
+            1u8 => Some("awake_time_score"),
+            2u8 => Some("awakenings_count_score"),
+            3u8 => Some("deep_sleep_score"),
+            4u8 => Some("sleep_duration_score"),
+            5u8 => Some("light_sleep_score"),
+            6u8 => Some("overall_sleep_score"),
+            7u8 => Some("sleep_quality_score"),
+            8u8 => Some("sleep_recovery_score"),
+            9u8 => Some("rem_sleep_score"),
+            10u8 => Some("sleep_restlessness_score"),
+            11u8 => Some("awakenings_count"),
+            14u8 => Some("interruptions_score"),
+            15u8 => Some("average_stress_during_sleep"),
+            _ => None,
+        },
+        MesgNum::SkinTempOvernight => match number {
+            0u8 => Some("local_timestamp"),
+            1u8 => Some("average_deviation"),
+            2u8 => Some("average_7_day_deviation"),
+            4u8 => Some("nightly_value"),
+            253u8 => Some("timestamp"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[doc = " Subfields of a field in the order they are checked, the first one whose reference"]
+#[doc = " field holds its reference value is active"]
+pub fn subfields(mesg_num: MesgNum, number: u8) -> &'static [SubField] {
+    match mesg_num {
+        MesgNum::FileId => match number {
+            2u8 => &[
+                SubField {
+                    name: "favero_product",
+                    reference: 1u8,
+                    value: 263i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 1u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 1u8,
+                    value: 15i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 1u8,
+                    value: 13i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 1u8,
+                    value: 89i64,
+                },
+            ],
+            _ => &[],
+        },
+        MesgNum::SlaveDevice => match number {
+            1u8 => &[
+                SubField {
+                    name: "favero_product",
+                    reference: 0u8,
+                    value: 263i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 0u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 0u8,
+                    value: 15i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 0u8,
+                    value: 13i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 0u8,
+                    value: 89i64,
+                },
+            ],
+            _ => &[],
+        },
+        MesgNum::MesgCapabilities => match number {
+            3u8 => &[
+                SubField {
+                    name: "num_per_file",
+                    reference: 2u8,
+                    value: 0i64,
+                },
+                SubField {
+                    name: "max_per_file",
+                    reference: 2u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "max_per_file_type",
+                    reference: 2u8,
+                    value: 2i64,
+                },
+            ],
+            _ => &[],
+        },
+        MesgNum::WatchfaceSettings => match number {
+            1u8 => &[
+                SubField {
+                    name: "digital_layout",
+                    reference: 0u8,
+                    value: 0i64,
+                },
+                SubField {
+                    name: "analog_layout",
+                    reference: 0u8,
+                    value: 1i64,
+                },
+            ],
+            _ => &[],
+        },
+        MesgNum::DiveSettings => match number {
+            20u8 => &[
+                SubField {
+                    name: "heart_rate_antplus_device_type",
+                    reference: 19u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "heart_rate_local_device_type",
+                    reference: 19u8,
+                    value: 5i64,
+                },
+            ],
+            _ => &[],
+        },
+        MesgNum::Session => match number {
+            10u8 => &[
+                SubField {
+                    name: "total_strides",
+                    reference: 5u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "total_strides",
+                    reference: 5u8,
+                    value: 11i64,
+                },
+                SubField {
+                    name: "total_strokes",
+                    reference: 5u8,
+                    value: 2i64,
+                },
+                SubField {
+                    name: "total_strokes",
+                    reference: 5u8,
+                    value: 5i64,
+                },
+                SubField {
+                    name: "total_strokes",
+                    reference: 5u8,
+                    value: 15i64,
+                },
+                SubField {
+                    name: "total_strokes",
+                    reference: 5u8,
+                    value: 37i64,
+                },
+            ],
+            18u8 => &[SubField {
+                name: "avg_running_cadence",
+                reference: 5u8,
+                value: 1i64,
+            }],
+            19u8 => &[SubField {
+                name: "max_running_cadence",
+                reference: 5u8,
+                value: 1i64,
+            }],
+            _ => &[],
+        },
+        MesgNum::Lap => match number {
+            10u8 => &[
+                SubField {
+                    name: "total_strides",
+                    reference: 25u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "total_strides",
+                    reference: 25u8,
+                    value: 11i64,
+                },
+                SubField {
+                    name: "total_strokes",
+                    reference: 25u8,
+                    value: 2i64,
+                },
+                SubField {
+                    name: "total_strokes",
+                    reference: 25u8,
+                    value: 5i64,
+                },
+                SubField {
+                    name: "total_strokes",
+                    reference: 25u8,
+                    value: 15i64,
+                },
+                SubField {
+                    name: "total_strokes",
+                    reference: 25u8,
+                    value: 37i64,
+                },
+            ],
+            17u8 => &[SubField {
+                name: "avg_running_cadence",
+                reference: 25u8,
+                value: 1i64,
+            }],
+            18u8 => &[SubField {
+                name: "max_running_cadence",
+                reference: 25u8,
+                value: 1i64,
+            }],
+            _ => &[],
+        },
+        MesgNum::Event => match number {
+            3u8 => &[
+                SubField {
+                    name: "timer_trigger",
+                    reference: 0u8,
+                    value: 0i64,
+                },
+                SubField {
+                    name: "course_point_index",
+                    reference: 0u8,
+                    value: 10i64,
+                },
+                SubField {
+                    name: "battery_level",
+                    reference: 0u8,
+                    value: 11i64,
+                },
+                SubField {
+                    name: "virtual_partner_speed",
+                    reference: 0u8,
+                    value: 12i64,
+                },
+                SubField {
+                    name: "hr_high_alert",
+                    reference: 0u8,
+                    value: 13i64,
+                },
+                SubField {
+                    name: "hr_low_alert",
+                    reference: 0u8,
+                    value: 14i64,
+                },
+                SubField {
+                    name: "speed_high_alert",
+                    reference: 0u8,
+                    value: 15i64,
+                },
+                SubField {
+                    name: "speed_low_alert",
+                    reference: 0u8,
+                    value: 16i64,
+                },
+                SubField {
+                    name: "cad_high_alert",
+                    reference: 0u8,
+                    value: 17i64,
+                },
+                SubField {
+                    name: "cad_low_alert",
+                    reference: 0u8,
+                    value: 18i64,
+                },
+                SubField {
+                    name: "power_high_alert",
+                    reference: 0u8,
+                    value: 19i64,
+                },
+                SubField {
+                    name: "power_low_alert",
+                    reference: 0u8,
+                    value: 20i64,
+                },
+                SubField {
+                    name: "time_duration_alert",
+                    reference: 0u8,
+                    value: 23i64,
+                },
+                SubField {
+                    name: "distance_duration_alert",
+                    reference: 0u8,
+                    value: 24i64,
+                },
+                SubField {
+                    name: "calorie_duration_alert",
+                    reference: 0u8,
+                    value: 25i64,
+                },
+                SubField {
+                    name: "fitness_equipment_state",
+                    reference: 0u8,
+                    value: 27i64,
+                },
+                SubField {
+                    name: "sport_point",
+                    reference: 0u8,
+                    value: 33i64,
+                },
+                SubField {
+                    name: "gear_change_data",
+                    reference: 0u8,
+                    value: 42i64,
+                },
+                SubField {
+                    name: "gear_change_data",
+                    reference: 0u8,
+                    value: 43i64,
+                },
+                SubField {
+                    name: "rider_position",
+                    reference: 0u8,
+                    value: 44i64,
+                },
+                SubField {
+                    name: "comm_timeout",
+                    reference: 0u8,
+                    value: 47i64,
+                },
+                SubField {
+                    name: "dive_alert",
+                    reference: 0u8,
+                    value: 56i64,
+                },
+                SubField {
+                    name: "auto_activity_detect_duration",
+                    reference: 0u8,
+                    value: 54i64,
+                },
+                SubField {
+                    name: "radar_threat_alert",
+                    reference: 0u8,
+                    value: 75i64,
+                },
+            ],
+            15u8 => &[SubField {
+                name: "auto_activity_detect_start_timestamp",
+                reference: 0u8,
+                value: 54i64,
+            }],
+            _ => &[],
+        },
+        MesgNum::DeviceInfo => match number {
+            1u8 => &[
+                SubField {
+                    name: "ble_device_type",
+                    reference: 25u8,
+                    value: 3i64,
+                },
+                SubField {
+                    name: "antplus_device_type",
+                    reference: 25u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "ant_device_type",
+                    reference: 25u8,
+                    value: 0i64,
+                },
+                SubField {
+                    name: "local_device_type",
+                    reference: 25u8,
+                    value: 5i64,
+                },
+            ],
+            4u8 => &[
+                SubField {
+                    name: "favero_product",
+                    reference: 2u8,
+                    value: 263i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 2u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 2u8,
+                    value: 15i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 2u8,
+                    value: 13i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 2u8,
+                    value: 89i64,
+                },
+            ],
+            _ => &[],
+        },
+        MesgNum::TrainingFile => match number {
+            2u8 => &[
+                SubField {
+                    name: "favero_product",
+                    reference: 1u8,
+                    value: 263i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 1u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 1u8,
+                    value: 15i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 1u8,
+                    value: 13i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 1u8,
+                    value: 89i64,
+                },
+            ],
+            _ => &[],
+        },
+        MesgNum::ThreeDSensorCalibration => match number {
+            1u8 => &[
+                SubField {
+                    name: "accel_cal_factor",
+                    reference: 0u8,
+                    value: 0i64,
+                },
+                SubField {
+                    name: "gyro_cal_factor",
+                    reference: 0u8,
+                    value: 1i64,
+                },
+            ],
+            _ => &[],
+        },
+        MesgNum::OneDSensorCalibration => match number {
+            1u8 => &[SubField {
+                name: "baro_cal_factor",
+                reference: 0u8,
+                value: 3i64,
+            }],
+            _ => &[],
+        },
+        MesgNum::SegmentLap => match number {
+            10u8 => &[SubField {
+                name: "total_strokes",
+                reference: 23u8,
+                value: 2i64,
+            }],
+            _ => &[],
+        },
+        MesgNum::WorkoutStep => match number {
+            2u8 => &[
+                SubField {
+                    name: "duration_time",
+                    reference: 1u8,
+                    value: 0i64,
+                },
+                SubField {
+                    name: "duration_time",
+                    reference: 1u8,
+                    value: 28i64,
+                },
+                SubField {
+                    name: "duration_distance",
+                    reference: 1u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "duration_hr",
+                    reference: 1u8,
+                    value: 2i64,
+                },
+                SubField {
+                    name: "duration_hr",
+                    reference: 1u8,
+                    value: 3i64,
+                },
+                SubField {
+                    name: "duration_calories",
+                    reference: 1u8,
+                    value: 4i64,
+                },
+                SubField {
+                    name: "duration_step",
+                    reference: 1u8,
+                    value: 6i64,
+                },
+                SubField {
+                    name: "duration_step",
+                    reference: 1u8,
+                    value: 7i64,
+                },
+                SubField {
+                    name: "duration_step",
+                    reference: 1u8,
+                    value: 8i64,
+                },
+                SubField {
+                    name: "duration_step",
+                    reference: 1u8,
+                    value: 9i64,
+                },
+                SubField {
+                    name: "duration_step",
+                    reference: 1u8,
+                    value: 10i64,
+                },
+                SubField {
+                    name: "duration_step",
+                    reference: 1u8,
+                    value: 11i64,
+                },
+                SubField {
+                    name: "duration_step",
+                    reference: 1u8,
+                    value: 12i64,
+                },
+                SubField {
+                    name: "duration_step",
+                    reference: 1u8,
+                    value: 13i64,
+                },
+                SubField {
+                    name: "duration_power",
+                    reference: 1u8,
+                    value: 14i64,
+                },
+                SubField {
+                    name: "duration_power",
+                    reference: 1u8,
+                    value: 15i64,
+                },
+                SubField {
+                    name: "duration_reps",
+                    reference: 1u8,
+                    value: 29i64,
+                },
+            ],
+            4u8 => &[
+                SubField {
+                    name: "target_speed_zone",
+                    reference: 3u8,
+                    value: 0i64,
+                },
+                SubField {
+                    name: "target_hr_zone",
+                    reference: 3u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "target_cadence_zone",
+                    reference: 3u8,
+                    value: 3i64,
+                },
+                SubField {
+                    name: "target_power_zone",
+                    reference: 3u8,
+                    value: 4i64,
+                },
+                SubField {
+                    name: "repeat_steps",
+                    reference: 1u8,
+                    value: 6i64,
+                },
+                SubField {
+                    name: "repeat_time",
+                    reference: 1u8,
+                    value: 7i64,
+                },
+                SubField {
+                    name: "repeat_distance",
+                    reference: 1u8,
+                    value: 8i64,
+                },
+                SubField {
+                    name: "repeat_calories",
+                    reference: 1u8,
+                    value: 9i64,
+                },
+                SubField {
+                    name: "repeat_hr",
+                    reference: 1u8,
+                    value: 10i64,
+                },
+                SubField {
+                    name: "repeat_hr",
+                    reference: 1u8,
+                    value: 11i64,
+                },
+                SubField {
+                    name: "repeat_power",
+                    reference: 1u8,
+                    value: 12i64,
+                },
+                SubField {
+                    name: "repeat_power",
+                    reference: 1u8,
+                    value: 13i64,
+                },
+                SubField {
+                    name: "target_stroke_type",
+                    reference: 3u8,
+                    value: 11i64,
+                },
+            ],
+            5u8 => &[
+                SubField {
+                    name: "custom_target_speed_low",
+                    reference: 3u8,
+                    value: 0i64,
+                },
+                SubField {
+                    name: "custom_target_heart_rate_low",
+                    reference: 3u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "custom_target_cadence_low",
+                    reference: 3u8,
+                    value: 3i64,
+                },
+                SubField {
+                    name: "custom_target_power_low",
+                    reference: 3u8,
+                    value: 4i64,
+                },
+            ],
+            6u8 => &[
+                SubField {
+                    name: "custom_target_speed_high",
+                    reference: 3u8,
+                    value: 0i64,
+                },
+                SubField {
+                    name: "custom_target_heart_rate_high",
+                    reference: 3u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "custom_target_cadence_high",
+                    reference: 3u8,
+                    value: 3i64,
+                },
+                SubField {
+                    name: "custom_target_power_high",
+                    reference: 3u8,
+                    value: 4i64,
+                },
+            ],
+            20u8 => &[
+                SubField {
+                    name: "secondary_target_speed_zone",
+                    reference: 19u8,
+                    value: 0i64,
+                },
+                SubField {
+                    name: "secondary_target_hr_zone",
+                    reference: 19u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "secondary_target_cadence_zone",
+                    reference: 19u8,
+                    value: 3i64,
+                },
+                SubField {
+                    name: "secondary_target_power_zone",
+                    reference: 19u8,
+                    value: 4i64,
+                },
+                SubField {
+                    name: "secondary_target_stroke_type",
+                    reference: 19u8,
+                    value: 11i64,
+                },
+            ],
+            254u8 => &[
+                SubField {
+                    name: "message_index",
+                    reference: 19u8,
+                    value: 0i64,
+                },
+                SubField {
+                    name: "message_index",
+                    reference: 19u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "message_index",
+                    reference: 19u8,
+                    value: 3i64,
+                },
+                SubField {
+                    name: "message_index",
+                    reference: 19u8,
+                    value: 4i64,
+                },
+            ],
+            _ => &[],
+        },
+        MesgNum::Schedule => match number {
+            1u8 => &[
+                SubField {
+                    name: "favero_product",
+                    reference: 0u8,
+                    value: 263i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 0u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 0u8,
+                    value: 15i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 0u8,
+                    value: 13i64,
+                },
+                SubField {
+                    name: "garmin_product",
+                    reference: 0u8,
+                    value: 89i64,
+                },
+            ],
+            _ => &[],
+        },
+        MesgNum::Monitoring => match number {
+            3u8 => &[
+                SubField {
+                    name: "steps",
+                    reference: 5u8,
+                    value: 6i64,
+                },
+                SubField {
+                    name: "steps",
+                    reference: 5u8,
+                    value: 1i64,
+                },
+                SubField {
+                    name: "strokes",
+                    reference: 5u8,
+                    value: 2i64,
+                },
+                SubField {
+                    name: "strokes",
+                    reference: 5u8,
+                    value: 5i64,
+                },
+            ],
+            _ => &[],
+        },
+        _ => &[],
+    }
+}
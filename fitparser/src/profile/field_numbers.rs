@@ -0,0 +1,3012 @@
+#![allow(missing_docs)]
+#![doc = "Auto generated field definition numbers from FIT SDK Release: 21.141.00"]
+#![doc = "Each message has a module holding a constant per field, e.g. `record::HEART_RATE`"]
+#[doc = "Must be first message in file."]
+pub mod file_id {
+    pub const TYPE: u8 = 0u8;
+    pub const MANUFACTURER: u8 = 1u8;
+    pub const PRODUCT: u8 = 2u8;
+    pub const SERIAL_NUMBER: u8 = 3u8;
+    #[doc = " * time_created: Only set for files that are can be created/erased."]
+    pub const TIME_CREATED: u8 = 4u8;
+    #[doc = " * number: Only set for files that are not created/erased."]
+    pub const NUMBER: u8 = 5u8;
+    #[doc = " * product_name: Optional free form string to indicate the devices name or model"]
+    pub const PRODUCT_NAME: u8 = 8u8;
+}
+#[doc = "file_creator message definition"]
+pub mod file_creator {
+    pub const SOFTWARE_VERSION: u8 = 0u8;
+    pub const HARDWARE_VERSION: u8 = 1u8;
+}
+#[doc = "timestamp_correlation message definition"]
+pub mod timestamp_correlation {
+    #[doc = " * fractional_timestamp: Fractional part of the UTC timestamp at the time the system timestamp was recorded."]
+    #[doc = " * units: s"]
+    pub const FRACTIONAL_TIMESTAMP: u8 = 0u8;
+    #[doc = " * system_timestamp: Whole second part of the system timestamp"]
+    #[doc = " * units: s"]
+    pub const SYSTEM_TIMESTAMP: u8 = 1u8;
+    #[doc = " * fractional_system_timestamp: Fractional part of the system timestamp"]
+    #[doc = " * units: s"]
+    pub const FRACTIONAL_SYSTEM_TIMESTAMP: u8 = 2u8;
+    #[doc = " * local_timestamp: timestamp epoch expressed in local time used to convert timestamps to local time"]
+    #[doc = " * units: s"]
+    pub const LOCAL_TIMESTAMP: u8 = 3u8;
+    #[doc = " * timestamp_ms: Millisecond part of the UTC timestamp at the time the system timestamp was recorded."]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 4u8;
+    #[doc = " * system_timestamp_ms: Millisecond part of the system timestamp"]
+    #[doc = " * units: ms"]
+    pub const SYSTEM_TIMESTAMP_MS: u8 = 5u8;
+    #[doc = " * timestamp: Whole second part of UTC timestamp at the time the system timestamp was recorded."]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "software message definition"]
+pub mod software {
+    pub const VERSION: u8 = 3u8;
+    pub const PART_NUMBER: u8 = 5u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "slave_device message definition"]
+pub mod slave_device {
+    pub const MANUFACTURER: u8 = 0u8;
+    pub const PRODUCT: u8 = 1u8;
+}
+#[doc = "capabilities message definition"]
+pub mod capabilities {
+    #[doc = " * languages: Use language_bits_x types where x is index of array."]
+    pub const LANGUAGES: u8 = 0u8;
+    #[doc = " * sports: Use sport_bits_x types where x is index of array."]
+    pub const SPORTS: u8 = 1u8;
+    pub const WORKOUTS_SUPPORTED: u8 = 21u8;
+    pub const CONNECTIVITY_SUPPORTED: u8 = 23u8;
+}
+#[doc = "file_capabilities message definition"]
+pub mod file_capabilities {
+    pub const TYPE: u8 = 0u8;
+    pub const FLAGS: u8 = 1u8;
+    pub const DIRECTORY: u8 = 2u8;
+    pub const MAX_COUNT: u8 = 3u8;
+    #[doc = " * units: bytes"]
+    pub const MAX_SIZE: u8 = 4u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "mesg_capabilities message definition"]
+pub mod mesg_capabilities {
+    pub const FILE: u8 = 0u8;
+    pub const MESG_NUM: u8 = 1u8;
+    pub const COUNT_TYPE: u8 = 2u8;
+    pub const COUNT: u8 = 3u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "field_capabilities message definition"]
+pub mod field_capabilities {
+    pub const FILE: u8 = 0u8;
+    pub const MESG_NUM: u8 = 1u8;
+    pub const FIELD_NUM: u8 = 2u8;
+    pub const COUNT: u8 = 3u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "device_settings message definition"]
+pub mod device_settings {
+    #[doc = " * active_time_zone: Index into time zone arrays."]
+    pub const ACTIVE_TIME_ZONE: u8 = 0u8;
+    #[doc = " * utc_offset: Offset from system time. Required to convert timestamp from system time to UTC."]
+    pub const UTC_OFFSET: u8 = 1u8;
+    #[doc = " * time_offset: Offset from system time."]
+    #[doc = " * units: s"]
+    pub const TIME_OFFSET: u8 = 2u8;
+    #[doc = " * time_mode: Display mode for the time"]
+    pub const TIME_MODE: u8 = 4u8;
+    #[doc = " * time_zone_offset: timezone offset in 1/4 hour increments"]
+    #[doc = " * units: hr"]
+    pub const TIME_ZONE_OFFSET: u8 = 5u8;
+    #[doc = " * backlight_mode: Mode for backlight"]
+    pub const BACKLIGHT_MODE: u8 = 12u8;
+    #[doc = " * activity_tracker_enabled: Enabled state of the activity tracker functionality"]
+    pub const ACTIVITY_TRACKER_ENABLED: u8 = 36u8;
+    #[doc = " * clock_time: UTC timestamp used to set the devices clock and date"]
+    pub const CLOCK_TIME: u8 = 39u8;
+    #[doc = " * pages_enabled: Bitfield to configure enabled screens for each supported loop"]
+    pub const PAGES_ENABLED: u8 = 40u8;
+    #[doc = " * move_alert_enabled: Enabled state of the move alert"]
+    pub const MOVE_ALERT_ENABLED: u8 = 46u8;
+    #[doc = " * date_mode: Display mode for the date"]
+    pub const DATE_MODE: u8 = 47u8;
+    pub const DISPLAY_ORIENTATION: u8 = 55u8;
+    pub const MOUNTING_SIDE: u8 = 56u8;
+    #[doc = " * default_page: Bitfield to indicate one page as default for each supported loop"]
+    pub const DEFAULT_PAGE: u8 = 57u8;
+    #[doc = " * autosync_min_steps: Minimum steps before an autosync can occur"]
+    #[doc = " * units: steps"]
+    pub const AUTOSYNC_MIN_STEPS: u8 = 58u8;
+    #[doc = " * autosync_min_time: Minimum minutes before an autosync can occur"]
+    #[doc = " * units: minutes"]
+    pub const AUTOSYNC_MIN_TIME: u8 = 59u8;
+    #[doc = " * lactate_threshold_autodetect_enabled: Enable auto-detect setting for the lactate threshold feature."]
+    pub const LACTATE_THRESHOLD_AUTODETECT_ENABLED: u8 = 80u8;
+    #[doc = " * ble_auto_upload_enabled: Automatically upload using BLE"]
+    pub const BLE_AUTO_UPLOAD_ENABLED: u8 = 86u8;
+    #[doc = " * auto_sync_frequency: Helps to conserve battery by changing modes"]
+    pub const AUTO_SYNC_FREQUENCY: u8 = 89u8;
+    #[doc = " * auto_activity_detect: Allows setting specific activities auto-activity detect enabled/disabled settings"]
+    pub const AUTO_ACTIVITY_DETECT: u8 = 90u8;
+    #[doc = " * number_of_screens: Number of screens configured to display"]
+    pub const NUMBER_OF_SCREENS: u8 = 94u8;
+    #[doc = " * smart_notification_display_orientation: Smart Notification display orientation"]
+    pub const SMART_NOTIFICATION_DISPLAY_ORIENTATION: u8 = 95u8;
+    pub const TAP_INTERFACE: u8 = 134u8;
+    #[doc = " * tap_sensitivity: Used to hold the tap threshold setting"]
+    pub const TAP_SENSITIVITY: u8 = 174u8;
+}
+#[doc = "user_profile message definition"]
+pub mod user_profile {
+    #[doc = " * friendly_name: Used for Morning Report greeting"]
+    pub const FRIENDLY_NAME: u8 = 0u8;
+    pub const GENDER: u8 = 1u8;
+    #[doc = " * units: years"]
+    pub const AGE: u8 = 2u8;
+    #[doc = " * units: m"]
+    pub const HEIGHT: u8 = 3u8;
+    #[doc = " * units: kg"]
+    pub const WEIGHT: u8 = 4u8;
+    pub const LANGUAGE: u8 = 5u8;
+    pub const ELEV_SETTING: u8 = 6u8;
+    pub const WEIGHT_SETTING: u8 = 7u8;
+    #[doc = " * units: bpm"]
+    pub const RESTING_HEART_RATE: u8 = 8u8;
+    #[doc = " * units: bpm"]
+    pub const DEFAULT_MAX_RUNNING_HEART_RATE: u8 = 9u8;
+    #[doc = " * units: bpm"]
+    pub const DEFAULT_MAX_BIKING_HEART_RATE: u8 = 10u8;
+    #[doc = " * units: bpm"]
+    pub const DEFAULT_MAX_HEART_RATE: u8 = 11u8;
+    pub const HR_SETTING: u8 = 12u8;
+    pub const SPEED_SETTING: u8 = 13u8;
+    pub const DIST_SETTING: u8 = 14u8;
+    pub const POWER_SETTING: u8 = 16u8;
+    pub const ACTIVITY_CLASS: u8 = 17u8;
+    pub const POSITION_SETTING: u8 = 18u8;
+    pub const TEMPERATURE_SETTING: u8 = 21u8;
+    pub const LOCAL_ID: u8 = 22u8;
+    pub const GLOBAL_ID: u8 = 23u8;
+    #[doc = " * wake_time: Typical wake time"]
+    pub const WAKE_TIME: u8 = 28u8;
+    #[doc = " * sleep_time: Typical bed time"]
+    pub const SLEEP_TIME: u8 = 29u8;
+    pub const HEIGHT_SETTING: u8 = 30u8;
+    #[doc = " * user_running_step_length: User defined running step length set to 0 for auto length"]
+    #[doc = " * units: m"]
+    pub const USER_RUNNING_STEP_LENGTH: u8 = 31u8;
+    #[doc = " * user_walking_step_length: User defined walking step length set to 0 for auto length"]
+    #[doc = " * units: m"]
+    pub const USER_WALKING_STEP_LENGTH: u8 = 32u8;
+    pub const DEPTH_SETTING: u8 = 47u8;
+    pub const DIVE_COUNT: u8 = 49u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "hrm_profile message definition"]
+pub mod hrm_profile {
+    pub const ENABLED: u8 = 0u8;
+    pub const HRM_ANT_ID: u8 = 1u8;
+    pub const LOG_HRV: u8 = 2u8;
+    pub const HRM_ANT_ID_TRANS_TYPE: u8 = 3u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "sdm_profile message definition"]
+pub mod sdm_profile {
+    pub const ENABLED: u8 = 0u8;
+    pub const SDM_ANT_ID: u8 = 1u8;
+    #[doc = " * units: %"]
+    pub const SDM_CAL_FACTOR: u8 = 2u8;
+    #[doc = " * units: m"]
+    pub const ODOMETER: u8 = 3u8;
+    #[doc = " * speed_source: Use footpod for speed source instead of GPS"]
+    pub const SPEED_SOURCE: u8 = 4u8;
+    pub const SDM_ANT_ID_TRANS_TYPE: u8 = 5u8;
+    #[doc = " * odometer_rollover: Rollover counter that can be used to extend the odometer"]
+    pub const ODOMETER_ROLLOVER: u8 = 7u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "bike_profile message definition"]
+pub mod bike_profile {
+    pub const NAME: u8 = 0u8;
+    pub const SPORT: u8 = 1u8;
+    pub const SUB_SPORT: u8 = 2u8;
+    #[doc = " * units: m"]
+    pub const ODOMETER: u8 = 3u8;
+    pub const BIKE_SPD_ANT_ID: u8 = 4u8;
+    pub const BIKE_CAD_ANT_ID: u8 = 5u8;
+    pub const BIKE_SPDCAD_ANT_ID: u8 = 6u8;
+    pub const BIKE_POWER_ANT_ID: u8 = 7u8;
+    #[doc = " * units: m"]
+    pub const CUSTOM_WHEELSIZE: u8 = 8u8;
+    #[doc = " * units: m"]
+    pub const AUTO_WHEELSIZE: u8 = 9u8;
+    #[doc = " * units: kg"]
+    pub const BIKE_WEIGHT: u8 = 10u8;
+    #[doc = " * units: %"]
+    pub const POWER_CAL_FACTOR: u8 = 11u8;
+    pub const AUTO_WHEEL_CAL: u8 = 12u8;
+    pub const AUTO_POWER_ZERO: u8 = 13u8;
+    pub const ID: u8 = 14u8;
+    pub const SPD_ENABLED: u8 = 15u8;
+    pub const CAD_ENABLED: u8 = 16u8;
+    pub const SPDCAD_ENABLED: u8 = 17u8;
+    pub const POWER_ENABLED: u8 = 18u8;
+    #[doc = " * units: mm"]
+    pub const CRANK_LENGTH: u8 = 19u8;
+    pub const ENABLED: u8 = 20u8;
+    pub const BIKE_SPD_ANT_ID_TRANS_TYPE: u8 = 21u8;
+    pub const BIKE_CAD_ANT_ID_TRANS_TYPE: u8 = 22u8;
+    pub const BIKE_SPDCAD_ANT_ID_TRANS_TYPE: u8 = 23u8;
+    pub const BIKE_POWER_ANT_ID_TRANS_TYPE: u8 = 24u8;
+    #[doc = " * odometer_rollover: Rollover counter that can be used to extend the odometer"]
+    pub const ODOMETER_ROLLOVER: u8 = 37u8;
+    #[doc = " * front_gear_num: Number of front gears"]
+    pub const FRONT_GEAR_NUM: u8 = 38u8;
+    #[doc = " * front_gear: Number of teeth on each gear 0 is innermost"]
+    pub const FRONT_GEAR: u8 = 39u8;
+    #[doc = " * rear_gear_num: Number of rear gears"]
+    pub const REAR_GEAR_NUM: u8 = 40u8;
+    #[doc = " * rear_gear: Number of teeth on each gear 0 is innermost"]
+    pub const REAR_GEAR: u8 = 41u8;
+    pub const SHIMANO_DI2_ENABLED: u8 = 44u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "connectivity message definition"]
+pub mod connectivity {
+    #[doc = " * bluetooth_enabled: Use Bluetooth for connectivity features"]
+    pub const BLUETOOTH_ENABLED: u8 = 0u8;
+    #[doc = " * bluetooth_le_enabled: Use Bluetooth Low Energy for connectivity features"]
+    pub const BLUETOOTH_LE_ENABLED: u8 = 1u8;
+    #[doc = " * ant_enabled: Use ANT for connectivity features"]
+    pub const ANT_ENABLED: u8 = 2u8;
+    pub const NAME: u8 = 3u8;
+    pub const LIVE_TRACKING_ENABLED: u8 = 4u8;
+    pub const WEATHER_CONDITIONS_ENABLED: u8 = 5u8;
+    pub const WEATHER_ALERTS_ENABLED: u8 = 6u8;
+    pub const AUTO_ACTIVITY_UPLOAD_ENABLED: u8 = 7u8;
+    pub const COURSE_DOWNLOAD_ENABLED: u8 = 8u8;
+    pub const WORKOUT_DOWNLOAD_ENABLED: u8 = 9u8;
+    pub const GPS_EPHEMERIS_DOWNLOAD_ENABLED: u8 = 10u8;
+    pub const INCIDENT_DETECTION_ENABLED: u8 = 11u8;
+    pub const GROUPTRACK_ENABLED: u8 = 12u8;
+}
+#[doc = "watchface_settings message definition"]
+pub mod watchface_settings {
+    pub const MODE: u8 = 0u8;
+    pub const LAYOUT: u8 = 1u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "ohr_settings message definition"]
+pub mod ohr_settings {
+    pub const ENABLED: u8 = 0u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "time_in_zone message definition"]
+pub mod time_in_zone {
+    pub const REFERENCE_MESG: u8 = 0u8;
+    pub const REFERENCE_INDEX: u8 = 1u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_HR_ZONE: u8 = 2u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_SPEED_ZONE: u8 = 3u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_CADENCE_ZONE: u8 = 4u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_POWER_ZONE: u8 = 5u8;
+    #[doc = " * units: bpm"]
+    pub const HR_ZONE_HIGH_BOUNDARY: u8 = 6u8;
+    #[doc = " * units: m/s"]
+    pub const SPEED_ZONE_HIGH_BOUNDARY: u8 = 7u8;
+    #[doc = " * units: rpm"]
+    pub const CADENCE_ZONE_HIGH_BONDARY: u8 = 8u8;
+    #[doc = " * units: watts"]
+    pub const POWER_ZONE_HIGH_BOUNDARY: u8 = 9u8;
+    pub const HR_CALC_TYPE: u8 = 10u8;
+    pub const MAX_HEART_RATE: u8 = 11u8;
+    pub const RESTING_HEART_RATE: u8 = 12u8;
+    pub const THRESHOLD_HEART_RATE: u8 = 13u8;
+    pub const PWR_CALC_TYPE: u8 = 14u8;
+    pub const FUNCTIONAL_THRESHOLD_POWER: u8 = 15u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "zones_target message definition"]
+pub mod zones_target {
+    pub const MAX_HEART_RATE: u8 = 1u8;
+    pub const THRESHOLD_HEART_RATE: u8 = 2u8;
+    pub const FUNCTIONAL_THRESHOLD_POWER: u8 = 3u8;
+    pub const HR_CALC_TYPE: u8 = 5u8;
+    pub const PWR_CALC_TYPE: u8 = 7u8;
+}
+#[doc = "sport message definition"]
+pub mod sport {
+    pub const SPORT: u8 = 0u8;
+    pub const SUB_SPORT: u8 = 1u8;
+    pub const NAME: u8 = 3u8;
+}
+#[doc = "hr_zone message definition"]
+pub mod hr_zone {
+    #[doc = " * units: bpm"]
+    pub const HIGH_BPM: u8 = 1u8;
+    pub const NAME: u8 = 2u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "speed_zone message definition"]
+pub mod speed_zone {
+    #[doc = " * units: m/s"]
+    pub const HIGH_VALUE: u8 = 0u8;
+    pub const NAME: u8 = 1u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "cadence_zone message definition"]
+pub mod cadence_zone {
+    #[doc = " * units: rpm"]
+    pub const HIGH_VALUE: u8 = 0u8;
+    pub const NAME: u8 = 1u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "power_zone message definition"]
+pub mod power_zone {
+    #[doc = " * units: watts"]
+    pub const HIGH_VALUE: u8 = 1u8;
+    pub const NAME: u8 = 2u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "met_zone message definition"]
+pub mod met_zone {
+    pub const HIGH_BPM: u8 = 1u8;
+    #[doc = " * units: kcal / min"]
+    pub const CALORIES: u8 = 2u8;
+    #[doc = " * units: kcal / min"]
+    pub const FAT_CALORIES: u8 = 3u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "dive_settings message definition"]
+pub mod dive_settings {
+    pub const NAME: u8 = 0u8;
+    pub const MODEL: u8 = 1u8;
+    #[doc = " * units: percent"]
+    pub const GF_LOW: u8 = 2u8;
+    #[doc = " * units: percent"]
+    pub const GF_HIGH: u8 = 3u8;
+    pub const WATER_TYPE: u8 = 4u8;
+    #[doc = " * water_density: Fresh water is usually 1000; salt water is usually 1025"]
+    #[doc = " * units: kg/m^3"]
+    pub const WATER_DENSITY: u8 = 5u8;
+    #[doc = " * po2_warn: Typically 1.40"]
+    #[doc = " * units: percent"]
+    pub const PO2_WARN: u8 = 6u8;
+    #[doc = " * po2_critical: Typically 1.60"]
+    #[doc = " * units: percent"]
+    pub const PO2_CRITICAL: u8 = 7u8;
+    #[doc = " * units: percent"]
+    pub const PO2_DECO: u8 = 8u8;
+    pub const SAFETY_STOP_ENABLED: u8 = 9u8;
+    pub const BOTTOM_DEPTH: u8 = 10u8;
+    pub const BOTTOM_TIME: u8 = 11u8;
+    pub const APNEA_COUNTDOWN_ENABLED: u8 = 12u8;
+    pub const APNEA_COUNTDOWN_TIME: u8 = 13u8;
+    pub const BACKLIGHT_MODE: u8 = 14u8;
+    pub const BACKLIGHT_BRIGHTNESS: u8 = 15u8;
+    pub const BACKLIGHT_TIMEOUT: u8 = 16u8;
+    #[doc = " * repeat_dive_interval: Time between surfacing and ending the activity"]
+    #[doc = " * units: s"]
+    pub const REPEAT_DIVE_INTERVAL: u8 = 17u8;
+    #[doc = " * safety_stop_time: Time at safety stop (if enabled)"]
+    #[doc = " * units: s"]
+    pub const SAFETY_STOP_TIME: u8 = 18u8;
+    pub const HEART_RATE_SOURCE_TYPE: u8 = 19u8;
+    pub const HEART_RATE_SOURCE: u8 = 20u8;
+    #[doc = " * travel_gas: Index of travel dive_gas message"]
+    pub const TRAVEL_GAS: u8 = 21u8;
+    #[doc = " * ccr_low_setpoint_switch_mode: If low PO2 should be switched to automatically"]
+    pub const CCR_LOW_SETPOINT_SWITCH_MODE: u8 = 22u8;
+    #[doc = " * ccr_low_setpoint: Target PO2 when using low setpoint"]
+    #[doc = " * units: percent"]
+    pub const CCR_LOW_SETPOINT: u8 = 23u8;
+    #[doc = " * ccr_low_setpoint_depth: Depth to switch to low setpoint in automatic mode"]
+    #[doc = " * units: m"]
+    pub const CCR_LOW_SETPOINT_DEPTH: u8 = 24u8;
+    #[doc = " * ccr_high_setpoint_switch_mode: If high PO2 should be switched to automatically"]
+    pub const CCR_HIGH_SETPOINT_SWITCH_MODE: u8 = 25u8;
+    #[doc = " * ccr_high_setpoint: Target PO2 when using high setpoint"]
+    #[doc = " * units: percent"]
+    pub const CCR_HIGH_SETPOINT: u8 = 26u8;
+    #[doc = " * ccr_high_setpoint_depth: Depth to switch to high setpoint in automatic mode"]
+    #[doc = " * units: m"]
+    pub const CCR_HIGH_SETPOINT_DEPTH: u8 = 27u8;
+    #[doc = " * gas_consumption_display: Type of gas consumption rate to display. Some values are only valid if tank volume is known."]
+    pub const GAS_CONSUMPTION_DISPLAY: u8 = 29u8;
+    #[doc = " * up_key_enabled: Indicates whether the up key is enabled during dives"]
+    pub const UP_KEY_ENABLED: u8 = 30u8;
+    #[doc = " * dive_sounds: Sounds and vibration enabled or disabled in-dive"]
+    pub const DIVE_SOUNDS: u8 = 35u8;
+    #[doc = " * last_stop_multiple: Usually 1.0/1.5/2.0 representing 3/4.5/6m or 10/15/20ft"]
+    pub const LAST_STOP_MULTIPLE: u8 = 36u8;
+    #[doc = " * no_fly_time_mode: Indicates which guidelines to use for no-fly surface interval."]
+    pub const NO_FLY_TIME_MODE: u8 = 37u8;
+    pub const TIMESTAMP: u8 = 253u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "dive_alarm message definition"]
+pub mod dive_alarm {
+    #[doc = " * depth: Depth setting (m) for depth type alarms"]
+    #[doc = " * units: m"]
+    pub const DEPTH: u8 = 0u8;
+    #[doc = " * time: Time setting (s) for time type alarms"]
+    #[doc = " * units: s"]
+    pub const TIME: u8 = 1u8;
+    #[doc = " * enabled: Enablement flag"]
+    pub const ENABLED: u8 = 2u8;
+    #[doc = " * alarm_type: Alarm type setting"]
+    pub const ALARM_TYPE: u8 = 3u8;
+    #[doc = " * sound: Tone and Vibe setting for the alarm"]
+    pub const SOUND: u8 = 4u8;
+    #[doc = " * dive_types: Dive types the alarm will trigger on"]
+    pub const DIVE_TYPES: u8 = 5u8;
+    #[doc = " * id: Alarm ID"]
+    pub const ID: u8 = 6u8;
+    #[doc = " * popup_enabled: Show a visible pop-up for this alarm"]
+    pub const POPUP_ENABLED: u8 = 7u8;
+    #[doc = " * trigger_on_descent: Trigger the alarm on descent"]
+    pub const TRIGGER_ON_DESCENT: u8 = 8u8;
+    #[doc = " * trigger_on_ascent: Trigger the alarm on ascent"]
+    pub const TRIGGER_ON_ASCENT: u8 = 9u8;
+    #[doc = " * repeating: Repeat alarm each time threshold is crossed?"]
+    pub const REPEATING: u8 = 10u8;
+    #[doc = " * speed: Ascent/descent rate (mps) setting for speed type alarms"]
+    #[doc = " * units: mps"]
+    pub const SPEED: u8 = 11u8;
+    #[doc = " * message_index: Index of the alarm"]
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "dive_apnea_alarm message definition"]
+pub mod dive_apnea_alarm {
+    #[doc = " * depth: Depth setting (m) for depth type alarms"]
+    #[doc = " * units: m"]
+    pub const DEPTH: u8 = 0u8;
+    #[doc = " * time: Time setting (s) for time type alarms"]
+    #[doc = " * units: s"]
+    pub const TIME: u8 = 1u8;
+    #[doc = " * enabled: Enablement flag"]
+    pub const ENABLED: u8 = 2u8;
+    #[doc = " * alarm_type: Alarm type setting"]
+    pub const ALARM_TYPE: u8 = 3u8;
+    #[doc = " * sound: Tone and Vibe setting for the alarm."]
+    pub const SOUND: u8 = 4u8;
+    #[doc = " * dive_types: Dive types the alarm will trigger on"]
+    pub const DIVE_TYPES: u8 = 5u8;
+    #[doc = " * id: Alarm ID"]
+    pub const ID: u8 = 6u8;
+    #[doc = " * popup_enabled: Show a visible pop-up for this alarm"]
+    pub const POPUP_ENABLED: u8 = 7u8;
+    #[doc = " * trigger_on_descent: Trigger the alarm on descent"]
+    pub const TRIGGER_ON_DESCENT: u8 = 8u8;
+    #[doc = " * trigger_on_ascent: Trigger the alarm on ascent"]
+    pub const TRIGGER_ON_ASCENT: u8 = 9u8;
+    #[doc = " * repeating: Repeat alarm each time threshold is crossed?"]
+    pub const REPEATING: u8 = 10u8;
+    #[doc = " * speed: Ascent/descent rate (mps) setting for speed type alarms"]
+    #[doc = " * units: mps"]
+    pub const SPEED: u8 = 11u8;
+    #[doc = " * message_index: Index of the alarm"]
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "dive_gas message definition"]
+pub mod dive_gas {
+    #[doc = " * units: percent"]
+    pub const HELIUM_CONTENT: u8 = 0u8;
+    #[doc = " * units: percent"]
+    pub const OXYGEN_CONTENT: u8 = 1u8;
+    pub const STATUS: u8 = 2u8;
+    pub const MODE: u8 = 3u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "goal message definition"]
+pub mod goal {
+    pub const SPORT: u8 = 0u8;
+    pub const SUB_SPORT: u8 = 1u8;
+    pub const START_DATE: u8 = 2u8;
+    pub const END_DATE: u8 = 3u8;
+    pub const TYPE: u8 = 4u8;
+    pub const VALUE: u8 = 5u8;
+    pub const REPEAT: u8 = 6u8;
+    pub const TARGET_VALUE: u8 = 7u8;
+    pub const RECURRENCE: u8 = 8u8;
+    pub const RECURRENCE_VALUE: u8 = 9u8;
+    pub const ENABLED: u8 = 10u8;
+    pub const SOURCE: u8 = 11u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "activity message definition"]
+pub mod activity {
+    #[doc = " * total_timer_time: Exclude pauses"]
+    #[doc = " * units: s"]
+    pub const TOTAL_TIMER_TIME: u8 = 0u8;
+    pub const NUM_SESSIONS: u8 = 1u8;
+    pub const TYPE: u8 = 2u8;
+    pub const EVENT: u8 = 3u8;
+    pub const EVENT_TYPE: u8 = 4u8;
+    #[doc = " * local_timestamp: timestamp epoch expressed in local time, used to convert activity timestamps to local time"]
+    pub const LOCAL_TIMESTAMP: u8 = 5u8;
+    pub const EVENT_GROUP: u8 = 6u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "session message definition"]
+pub mod session {
+    #[doc = " * event: session"]
+    pub const EVENT: u8 = 0u8;
+    #[doc = " * event_type: stop"]
+    pub const EVENT_TYPE: u8 = 1u8;
+    pub const START_TIME: u8 = 2u8;
+    #[doc = " * units: semicircles"]
+    pub const START_POSITION_LAT: u8 = 3u8;
+    #[doc = " * units: semicircles"]
+    pub const START_POSITION_LONG: u8 = 4u8;
+    pub const SPORT: u8 = 5u8;
+    pub const SUB_SPORT: u8 = 6u8;
+    #[doc = " * total_elapsed_time: Time (includes pauses)"]
+    #[doc = " * units: s"]
+    pub const TOTAL_ELAPSED_TIME: u8 = 7u8;
+    #[doc = " * total_timer_time: Timer Time (excludes pauses)"]
+    #[doc = " * units: s"]
+    pub const TOTAL_TIMER_TIME: u8 = 8u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_DISTANCE: u8 = 9u8;
+    #[doc = " * units: cycles"]
+    pub const TOTAL_CYCLES: u8 = 10u8;
+    #[doc = " * units: kcal"]
+    pub const TOTAL_CALORIES: u8 = 11u8;
+    #[doc = " * units: kcal"]
+    pub const TOTAL_FAT_CALORIES: u8 = 13u8;
+    #[doc = " * avg_speed: total_distance / total_timer_time"]
+    #[doc = " * units: m/s"]
+    pub const AVG_SPEED: u8 = 14u8;
+    #[doc = " * units: m/s"]
+    pub const MAX_SPEED: u8 = 15u8;
+    #[doc = " * avg_heart_rate: average heart rate (excludes pause time)"]
+    #[doc = " * units: bpm"]
+    pub const AVG_HEART_RATE: u8 = 16u8;
+    #[doc = " * units: bpm"]
+    pub const MAX_HEART_RATE: u8 = 17u8;
+    #[doc = " * avg_cadence: total_cycles / total_timer_time if non_zero_avg_cadence otherwise total_cycles / total_elapsed_time"]
+    #[doc = " * units: rpm"]
+    pub const AVG_CADENCE: u8 = 18u8;
+    #[doc = " * units: rpm"]
+    pub const MAX_CADENCE: u8 = 19u8;
+    #[doc = " * avg_power: total_power / total_timer_time if non_zero_avg_power otherwise total_power / total_elapsed_time"]
+    #[doc = " * units: watts"]
+    pub const AVG_POWER: u8 = 20u8;
+    #[doc = " * units: watts"]
+    pub const MAX_POWER: u8 = 21u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_ASCENT: u8 = 22u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_DESCENT: u8 = 23u8;
+    pub const TOTAL_TRAINING_EFFECT: u8 = 24u8;
+    pub const FIRST_LAP_INDEX: u8 = 25u8;
+    pub const NUM_LAPS: u8 = 26u8;
+    pub const EVENT_GROUP: u8 = 27u8;
+    pub const TRIGGER: u8 = 28u8;
+    #[doc = " * nec_lat: North east corner latitude"]
+    #[doc = " * units: semicircles"]
+    pub const NEC_LAT: u8 = 29u8;
+    #[doc = " * nec_long: North east corner longitude"]
+    #[doc = " * units: semicircles"]
+    pub const NEC_LONG: u8 = 30u8;
+    #[doc = " * swc_lat: South west corner latitude"]
+    #[doc = " * units: semicircles"]
+    pub const SWC_LAT: u8 = 31u8;
+    #[doc = " * swc_long: South west corner longitude"]
+    #[doc = " * units: semicircles"]
+    pub const SWC_LONG: u8 = 32u8;
+    #[doc = " * num_lengths: # of lengths of swim pool"]
+    #[doc = " * units: lengths"]
+    pub const NUM_LENGTHS: u8 = 33u8;
+    #[doc = " * units: watts"]
+    pub const NORMALIZED_POWER: u8 = 34u8;
+    #[doc = " * units: tss"]
+    pub const TRAINING_STRESS_SCORE: u8 = 35u8;
+    #[doc = " * units: if"]
+    pub const INTENSITY_FACTOR: u8 = 36u8;
+    pub const LEFT_RIGHT_BALANCE: u8 = 37u8;
+    #[doc = " * units: semicircles"]
+    pub const END_POSITION_LAT: u8 = 38u8;
+    #[doc = " * units: semicircles"]
+    pub const END_POSITION_LONG: u8 = 39u8;
+    #[doc = " * units: strokes/lap"]
+    pub const AVG_STROKE_COUNT: u8 = 41u8;
+    #[doc = " * units: m"]
+    pub const AVG_STROKE_DISTANCE: u8 = 42u8;
+    #[doc = " * units: swim_stroke"]
+    pub const SWIM_STROKE: u8 = 43u8;
+    #[doc = " * units: m"]
+    pub const POOL_LENGTH: u8 = 44u8;
+    #[doc = " * units: watts"]
+    pub const THRESHOLD_POWER: u8 = 45u8;
+    pub const POOL_LENGTH_UNIT: u8 = 46u8;
+    #[doc = " * num_active_lengths: # of active lengths of swim pool"]
+    #[doc = " * units: lengths"]
+    pub const NUM_ACTIVE_LENGTHS: u8 = 47u8;
+    #[doc = " * units: J"]
+    pub const TOTAL_WORK: u8 = 48u8;
+    #[doc = " * units: m"]
+    pub const AVG_ALTITUDE: u8 = 49u8;
+    #[doc = " * units: m"]
+    pub const MAX_ALTITUDE: u8 = 50u8;
+    #[doc = " * units: m"]
+    pub const GPS_ACCURACY: u8 = 51u8;
+    #[doc = " * units: %"]
+    pub const AVG_GRADE: u8 = 52u8;
+    #[doc = " * units: %"]
+    pub const AVG_POS_GRADE: u8 = 53u8;
+    #[doc = " * units: %"]
+    pub const AVG_NEG_GRADE: u8 = 54u8;
+    #[doc = " * units: %"]
+    pub const MAX_POS_GRADE: u8 = 55u8;
+    #[doc = " * units: %"]
+    pub const MAX_NEG_GRADE: u8 = 56u8;
+    #[doc = " * units: C"]
+    pub const AVG_TEMPERATURE: u8 = 57u8;
+    #[doc = " * units: C"]
+    pub const MAX_TEMPERATURE: u8 = 58u8;
+    #[doc = " * units: s"]
+    pub const TOTAL_MOVING_TIME: u8 = 59u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_POS_VERTICAL_SPEED: u8 = 60u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_NEG_VERTICAL_SPEED: u8 = 61u8;
+    #[doc = " * units: m/s"]
+    pub const MAX_POS_VERTICAL_SPEED: u8 = 62u8;
+    #[doc = " * units: m/s"]
+    pub const MAX_NEG_VERTICAL_SPEED: u8 = 63u8;
+    #[doc = " * units: bpm"]
+    pub const MIN_HEART_RATE: u8 = 64u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_HR_ZONE: u8 = 65u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_SPEED_ZONE: u8 = 66u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_CADENCE_ZONE: u8 = 67u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_POWER_ZONE: u8 = 68u8;
+    #[doc = " * units: s"]
+    pub const AVG_LAP_TIME: u8 = 69u8;
+    pub const BEST_LAP_INDEX: u8 = 70u8;
+    #[doc = " * units: m"]
+    pub const MIN_ALTITUDE: u8 = 71u8;
+    pub const PLAYER_SCORE: u8 = 82u8;
+    pub const OPPONENT_SCORE: u8 = 83u8;
+    pub const OPPONENT_NAME: u8 = 84u8;
+    #[doc = " * stroke_count: stroke_type enum used as the index"]
+    #[doc = " * units: counts"]
+    pub const STROKE_COUNT: u8 = 85u8;
+    #[doc = " * zone_count: zone number used as the index"]
+    #[doc = " * units: counts"]
+    pub const ZONE_COUNT: u8 = 86u8;
+    #[doc = " * units: m/s"]
+    pub const MAX_BALL_SPEED: u8 = 87u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_BALL_SPEED: u8 = 88u8;
+    #[doc = " * units: mm"]
+    pub const AVG_VERTICAL_OSCILLATION: u8 = 89u8;
+    #[doc = " * units: percent"]
+    pub const AVG_STANCE_TIME_PERCENT: u8 = 90u8;
+    #[doc = " * units: ms"]
+    pub const AVG_STANCE_TIME: u8 = 91u8;
+    #[doc = " * avg_fractional_cadence: fractional part of the avg_cadence"]
+    #[doc = " * units: rpm"]
+    pub const AVG_FRACTIONAL_CADENCE: u8 = 92u8;
+    #[doc = " * max_fractional_cadence: fractional part of the max_cadence"]
+    #[doc = " * units: rpm"]
+    pub const MAX_FRACTIONAL_CADENCE: u8 = 93u8;
+    #[doc = " * total_fractional_cycles: fractional part of the total_cycles"]
+    #[doc = " * units: cycles"]
+    pub const TOTAL_FRACTIONAL_CYCLES: u8 = 94u8;
+    #[doc = " * avg_total_hemoglobin_conc: Avg saturated and unsaturated hemoglobin"]
+    #[doc = " * units: g/dL"]
+    pub const AVG_TOTAL_HEMOGLOBIN_CONC: u8 = 95u8;
+    #[doc = " * min_total_hemoglobin_conc: Min saturated and unsaturated hemoglobin"]
+    #[doc = " * units: g/dL"]
+    pub const MIN_TOTAL_HEMOGLOBIN_CONC: u8 = 96u8;
+    #[doc = " * max_total_hemoglobin_conc: Max saturated and unsaturated hemoglobin"]
+    #[doc = " * units: g/dL"]
+    pub const MAX_TOTAL_HEMOGLOBIN_CONC: u8 = 97u8;
+    #[doc = " * avg_saturated_hemoglobin_percent: Avg percentage of hemoglobin saturated with oxygen"]
+    #[doc = " * units: %"]
+    pub const AVG_SATURATED_HEMOGLOBIN_PERCENT: u8 = 98u8;
+    #[doc = " * min_saturated_hemoglobin_percent: Min percentage of hemoglobin saturated with oxygen"]
+    #[doc = " * units: %"]
+    pub const MIN_SATURATED_HEMOGLOBIN_PERCENT: u8 = 99u8;
+    #[doc = " * max_saturated_hemoglobin_percent: Max percentage of hemoglobin saturated with oxygen"]
+    #[doc = " * units: %"]
+    pub const MAX_SATURATED_HEMOGLOBIN_PERCENT: u8 = 100u8;
+    #[doc = " * units: percent"]
+    pub const AVG_LEFT_TORQUE_EFFECTIVENESS: u8 = 101u8;
+    #[doc = " * units: percent"]
+    pub const AVG_RIGHT_TORQUE_EFFECTIVENESS: u8 = 102u8;
+    #[doc = " * units: percent"]
+    pub const AVG_LEFT_PEDAL_SMOOTHNESS: u8 = 103u8;
+    #[doc = " * units: percent"]
+    pub const AVG_RIGHT_PEDAL_SMOOTHNESS: u8 = 104u8;
+    #[doc = " * units: percent"]
+    pub const AVG_COMBINED_PEDAL_SMOOTHNESS: u8 = 105u8;
+    #[doc = " * sport_profile_name: Sport name from associated sport mesg"]
+    pub const SPORT_PROFILE_NAME: u8 = 110u8;
+    pub const SPORT_INDEX: u8 = 111u8;
+    #[doc = " * time_standing: Total time spend in the standing position"]
+    #[doc = " * units: s"]
+    pub const TIME_STANDING: u8 = 112u8;
+    #[doc = " * stand_count: Number of transitions to the standing state"]
+    pub const STAND_COUNT: u8 = 113u8;
+    #[doc = " * avg_left_pco: Average platform center offset Left"]
+    #[doc = " * units: mm"]
+    pub const AVG_LEFT_PCO: u8 = 114u8;
+    #[doc = " * avg_right_pco: Average platform center offset Right"]
+    #[doc = " * units: mm"]
+    pub const AVG_RIGHT_PCO: u8 = 115u8;
+    #[doc = " * avg_left_power_phase: Average left power phase angles. Indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const AVG_LEFT_POWER_PHASE: u8 = 116u8;
+    #[doc = " * avg_left_power_phase_peak: Average left power phase peak angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const AVG_LEFT_POWER_PHASE_PEAK: u8 = 117u8;
+    #[doc = " * avg_right_power_phase: Average right power phase angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const AVG_RIGHT_POWER_PHASE: u8 = 118u8;
+    #[doc = " * avg_right_power_phase_peak: Average right power phase peak angles data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const AVG_RIGHT_POWER_PHASE_PEAK: u8 = 119u8;
+    #[doc = " * avg_power_position: Average power by position. Data value indexes defined by rider_position_type."]
+    #[doc = " * units: watts"]
+    pub const AVG_POWER_POSITION: u8 = 120u8;
+    #[doc = " * max_power_position: Maximum power by position. Data value indexes defined by rider_position_type."]
+    #[doc = " * units: watts"]
+    pub const MAX_POWER_POSITION: u8 = 121u8;
+    #[doc = " * avg_cadence_position: Average cadence by position. Data value indexes defined by rider_position_type."]
+    #[doc = " * units: rpm"]
+    pub const AVG_CADENCE_POSITION: u8 = 122u8;
+    #[doc = " * max_cadence_position: Maximum cadence by position. Data value indexes defined by rider_position_type."]
+    #[doc = " * units: rpm"]
+    pub const MAX_CADENCE_POSITION: u8 = 123u8;
+    #[doc = " * enhanced_avg_speed: total_distance / total_timer_time"]
+    #[doc = " * units: m/s"]
+    pub const ENHANCED_AVG_SPEED: u8 = 124u8;
+    #[doc = " * units: m/s"]
+    pub const ENHANCED_MAX_SPEED: u8 = 125u8;
+    #[doc = " * units: m"]
+    pub const ENHANCED_AVG_ALTITUDE: u8 = 126u8;
+    #[doc = " * units: m"]
+    pub const ENHANCED_MIN_ALTITUDE: u8 = 127u8;
+    #[doc = " * units: m"]
+    pub const ENHANCED_MAX_ALTITUDE: u8 = 128u8;
+    #[doc = " * avg_lev_motor_power: lev average motor power during session"]
+    #[doc = " * units: watts"]
+    pub const AVG_LEV_MOTOR_POWER: u8 = 129u8;
+    #[doc = " * max_lev_motor_power: lev maximum motor power during session"]
+    #[doc = " * units: watts"]
+    pub const MAX_LEV_MOTOR_POWER: u8 = 130u8;
+    #[doc = " * lev_battery_consumption: lev battery consumption during session"]
+    #[doc = " * units: percent"]
+    pub const LEV_BATTERY_CONSUMPTION: u8 = 131u8;
+    #[doc = " * units: percent"]
+    pub const AVG_VERTICAL_RATIO: u8 = 132u8;
+    #[doc = " * units: percent"]
+    pub const AVG_STANCE_TIME_BALANCE: u8 = 133u8;
+    #[doc = " * units: mm"]
+    pub const AVG_STEP_LENGTH: u8 = 134u8;
+    pub const TOTAL_ANAEROBIC_TRAINING_EFFECT: u8 = 137u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_VAM: u8 = 139u8;
+    #[doc = " * avg_depth: 0 if above water"]
+    #[doc = " * units: m"]
+    pub const AVG_DEPTH: u8 = 140u8;
+    #[doc = " * max_depth: 0 if above water"]
+    #[doc = " * units: m"]
+    pub const MAX_DEPTH: u8 = 141u8;
+    #[doc = " * surface_interval: Time since end of last dive"]
+    #[doc = " * units: s"]
+    pub const SURFACE_INTERVAL: u8 = 142u8;
+    #[doc = " * units: percent"]
+    pub const START_CNS: u8 = 143u8;
+    #[doc = " * units: percent"]
+    pub const END_CNS: u8 = 144u8;
+    #[doc = " * units: percent"]
+    pub const START_N2: u8 = 145u8;
+    #[doc = " * units: percent"]
+    pub const END_N2: u8 = 146u8;
+    pub const AVG_RESPIRATION_RATE: u8 = 147u8;
+    pub const MAX_RESPIRATION_RATE: u8 = 148u8;
+    pub const MIN_RESPIRATION_RATE: u8 = 149u8;
+    #[doc = " * units: C"]
+    pub const MIN_TEMPERATURE: u8 = 150u8;
+    #[doc = " * units: OTUs"]
+    pub const O2_TOXICITY: u8 = 155u8;
+    pub const DIVE_NUMBER: u8 = 156u8;
+    pub const TRAINING_LOAD_PEAK: u8 = 168u8;
+    #[doc = " * units: Breaths/min"]
+    pub const ENHANCED_AVG_RESPIRATION_RATE: u8 = 169u8;
+    #[doc = " * units: Breaths/min"]
+    pub const ENHANCED_MAX_RESPIRATION_RATE: u8 = 170u8;
+    pub const ENHANCED_MIN_RESPIRATION_RATE: u8 = 180u8;
+    #[doc = " * total_grit: The grit score estimates how challenging a route could be for a cyclist in terms of time spent going over sharp turns or large grade slopes."]
+    #[doc = " * units: kGrit"]
+    pub const TOTAL_GRIT: u8 = 181u8;
+    #[doc = " * total_flow: The flow score estimates how long distance wise a cyclist deaccelerates over intervals where deacceleration is unnecessary such as smooth turns or small grade angle intervals."]
+    #[doc = " * units: Flow"]
+    pub const TOTAL_FLOW: u8 = 182u8;
+    pub const JUMP_COUNT: u8 = 183u8;
+    #[doc = " * avg_grit: The grit score estimates how challenging a route could be for a cyclist in terms of time spent going over sharp turns or large grade slopes."]
+    #[doc = " * units: kGrit"]
+    pub const AVG_GRIT: u8 = 186u8;
+    #[doc = " * avg_flow: The flow score estimates how long distance wise a cyclist deaccelerates over intervals where deacceleration is unnecessary such as smooth turns or small grade angle intervals."]
+    #[doc = " * units: Flow"]
+    pub const AVG_FLOW: u8 = 187u8;
+    #[doc = " * workout_feel: A 0-100 scale representing how a user felt while performing a workout. Low values are considered feeling bad, while high values are good."]
+    pub const WORKOUT_FEEL: u8 = 192u8;
+    #[doc = " * workout_rpe: Common Borg CR10 / 0-10 RPE scale, multiplied 10x.. Aggregate score for all workouts in a single session."]
+    pub const WORKOUT_RPE: u8 = 193u8;
+    #[doc = " * avg_spo2: Average SPO2 for the monitoring session"]
+    #[doc = " * units: percent"]
+    pub const AVG_SPO2: u8 = 194u8;
+    #[doc = " * avg_stress: Average stress for the monitoring session"]
+    #[doc = " * units: percent"]
+    pub const AVG_STRESS: u8 = 195u8;
+    #[doc = " * sdrr_hrv: Standard deviation of R-R interval (SDRR) - Heart rate variability measure most useful for wellness users."]
+    #[doc = " * units: mS"]
+    pub const SDRR_HRV: u8 = 197u8;
+    #[doc = " * rmssd_hrv: Root mean square successive difference (RMSSD) - Heart rate variability measure most useful for athletes"]
+    #[doc = " * units: mS"]
+    pub const RMSSD_HRV: u8 = 198u8;
+    #[doc = " * total_fractional_ascent: fractional part of total_ascent"]
+    #[doc = " * units: m"]
+    pub const TOTAL_FRACTIONAL_ASCENT: u8 = 199u8;
+    #[doc = " * total_fractional_descent: fractional part of total_descent"]
+    #[doc = " * units: m"]
+    pub const TOTAL_FRACTIONAL_DESCENT: u8 = 200u8;
+    #[doc = " * units: C"]
+    pub const AVG_CORE_TEMPERATURE: u8 = 208u8;
+    #[doc = " * units: C"]
+    pub const MIN_CORE_TEMPERATURE: u8 = 209u8;
+    #[doc = " * units: C"]
+    pub const MAX_CORE_TEMPERATURE: u8 = 210u8;
+    #[doc = " * timestamp: Sesson end time."]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+    #[doc = " * message_index: Selected bit is set for the current session."]
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "lap message definition"]
+pub mod lap {
+    pub const EVENT: u8 = 0u8;
+    pub const EVENT_TYPE: u8 = 1u8;
+    pub const START_TIME: u8 = 2u8;
+    #[doc = " * units: semicircles"]
+    pub const START_POSITION_LAT: u8 = 3u8;
+    #[doc = " * units: semicircles"]
+    pub const START_POSITION_LONG: u8 = 4u8;
+    #[doc = " * units: semicircles"]
+    pub const END_POSITION_LAT: u8 = 5u8;
+    #[doc = " * units: semicircles"]
+    pub const END_POSITION_LONG: u8 = 6u8;
+    #[doc = " * total_elapsed_time: Time (includes pauses)"]
+    #[doc = " * units: s"]
+    pub const TOTAL_ELAPSED_TIME: u8 = 7u8;
+    #[doc = " * total_timer_time: Timer Time (excludes pauses)"]
+    #[doc = " * units: s"]
+    pub const TOTAL_TIMER_TIME: u8 = 8u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_DISTANCE: u8 = 9u8;
+    #[doc = " * units: cycles"]
+    pub const TOTAL_CYCLES: u8 = 10u8;
+    #[doc = " * units: kcal"]
+    pub const TOTAL_CALORIES: u8 = 11u8;
+    #[doc = " * total_fat_calories: If New Leaf"]
+    #[doc = " * units: kcal"]
+    pub const TOTAL_FAT_CALORIES: u8 = 12u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_SPEED: u8 = 13u8;
+    #[doc = " * units: m/s"]
+    pub const MAX_SPEED: u8 = 14u8;
+    #[doc = " * units: bpm"]
+    pub const AVG_HEART_RATE: u8 = 15u8;
+    #[doc = " * units: bpm"]
+    pub const MAX_HEART_RATE: u8 = 16u8;
+    #[doc = " * avg_cadence: total_cycles / total_timer_time if non_zero_avg_cadence otherwise total_cycles / total_elapsed_time"]
+    #[doc = " * units: rpm"]
+    pub const AVG_CADENCE: u8 = 17u8;
+    #[doc = " * units: rpm"]
+    pub const MAX_CADENCE: u8 = 18u8;
+    #[doc = " * avg_power: total_power / total_timer_time if non_zero_avg_power otherwise total_power / total_elapsed_time"]
+    #[doc = " * units: watts"]
+    pub const AVG_POWER: u8 = 19u8;
+    #[doc = " * units: watts"]
+    pub const MAX_POWER: u8 = 20u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_ASCENT: u8 = 21u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_DESCENT: u8 = 22u8;
+    pub const INTENSITY: u8 = 23u8;
+    pub const LAP_TRIGGER: u8 = 24u8;
+    pub const SPORT: u8 = 25u8;
+    pub const EVENT_GROUP: u8 = 26u8;
+    #[doc = " * num_lengths: # of lengths of swim pool"]
+    #[doc = " * units: lengths"]
+    pub const NUM_LENGTHS: u8 = 32u8;
+    #[doc = " * units: watts"]
+    pub const NORMALIZED_POWER: u8 = 33u8;
+    pub const LEFT_RIGHT_BALANCE: u8 = 34u8;
+    pub const FIRST_LENGTH_INDEX: u8 = 35u8;
+    #[doc = " * units: m"]
+    pub const AVG_STROKE_DISTANCE: u8 = 37u8;
+    pub const SWIM_STROKE: u8 = 38u8;
+    pub const SUB_SPORT: u8 = 39u8;
+    #[doc = " * num_active_lengths: # of active lengths of swim pool"]
+    #[doc = " * units: lengths"]
+    pub const NUM_ACTIVE_LENGTHS: u8 = 40u8;
+    #[doc = " * units: J"]
+    pub const TOTAL_WORK: u8 = 41u8;
+    #[doc = " * units: m"]
+    pub const AVG_ALTITUDE: u8 = 42u8;
+    #[doc = " * units: m"]
+    pub const MAX_ALTITUDE: u8 = 43u8;
+    #[doc = " * units: m"]
+    pub const GPS_ACCURACY: u8 = 44u8;
+    #[doc = " * units: %"]
+    pub const AVG_GRADE: u8 = 45u8;
+    #[doc = " * units: %"]
+    pub const AVG_POS_GRADE: u8 = 46u8;
+    #[doc = " * units: %"]
+    pub const AVG_NEG_GRADE: u8 = 47u8;
+    #[doc = " * units: %"]
+    pub const MAX_POS_GRADE: u8 = 48u8;
+    #[doc = " * units: %"]
+    pub const MAX_NEG_GRADE: u8 = 49u8;
+    #[doc = " * units: C"]
+    pub const AVG_TEMPERATURE: u8 = 50u8;
+    #[doc = " * units: C"]
+    pub const MAX_TEMPERATURE: u8 = 51u8;
+    #[doc = " * units: s"]
+    pub const TOTAL_MOVING_TIME: u8 = 52u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_POS_VERTICAL_SPEED: u8 = 53u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_NEG_VERTICAL_SPEED: u8 = 54u8;
+    #[doc = " * units: m/s"]
+    pub const MAX_POS_VERTICAL_SPEED: u8 = 55u8;
+    #[doc = " * units: m/s"]
+    pub const MAX_NEG_VERTICAL_SPEED: u8 = 56u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_HR_ZONE: u8 = 57u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_SPEED_ZONE: u8 = 58u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_CADENCE_ZONE: u8 = 59u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_POWER_ZONE: u8 = 60u8;
+    pub const REPETITION_NUM: u8 = 61u8;
+    #[doc = " * units: m"]
+    pub const MIN_ALTITUDE: u8 = 62u8;
+    #[doc = " * units: bpm"]
+    pub const MIN_HEART_RATE: u8 = 63u8;
+    pub const WKT_STEP_INDEX: u8 = 71u8;
+    pub const OPPONENT_SCORE: u8 = 74u8;
+    #[doc = " * stroke_count: stroke_type enum used as the index"]
+    #[doc = " * units: counts"]
+    pub const STROKE_COUNT: u8 = 75u8;
+    #[doc = " * zone_count: zone number used as the index"]
+    #[doc = " * units: counts"]
+    pub const ZONE_COUNT: u8 = 76u8;
+    #[doc = " * units: mm"]
+    pub const AVG_VERTICAL_OSCILLATION: u8 = 77u8;
+    #[doc = " * units: percent"]
+    pub const AVG_STANCE_TIME_PERCENT: u8 = 78u8;
+    #[doc = " * units: ms"]
+    pub const AVG_STANCE_TIME: u8 = 79u8;
+    #[doc = " * avg_fractional_cadence: fractional part of the avg_cadence"]
+    #[doc = " * units: rpm"]
+    pub const AVG_FRACTIONAL_CADENCE: u8 = 80u8;
+    #[doc = " * max_fractional_cadence: fractional part of the max_cadence"]
+    #[doc = " * units: rpm"]
+    pub const MAX_FRACTIONAL_CADENCE: u8 = 81u8;
+    #[doc = " * total_fractional_cycles: fractional part of the total_cycles"]
+    #[doc = " * units: cycles"]
+    pub const TOTAL_FRACTIONAL_CYCLES: u8 = 82u8;
+    pub const PLAYER_SCORE: u8 = 83u8;
+    #[doc = " * avg_total_hemoglobin_conc: Avg saturated and unsaturated hemoglobin"]
+    #[doc = " * units: g/dL"]
+    pub const AVG_TOTAL_HEMOGLOBIN_CONC: u8 = 84u8;
+    #[doc = " * min_total_hemoglobin_conc: Min saturated and unsaturated hemoglobin"]
+    #[doc = " * units: g/dL"]
+    pub const MIN_TOTAL_HEMOGLOBIN_CONC: u8 = 85u8;
+    #[doc = " * max_total_hemoglobin_conc: Max saturated and unsaturated hemoglobin"]
+    #[doc = " * units: g/dL"]
+    pub const MAX_TOTAL_HEMOGLOBIN_CONC: u8 = 86u8;
+    #[doc = " * avg_saturated_hemoglobin_percent: Avg percentage of hemoglobin saturated with oxygen"]
+    #[doc = " * units: %"]
+    pub const AVG_SATURATED_HEMOGLOBIN_PERCENT: u8 = 87u8;
+    #[doc = " * min_saturated_hemoglobin_percent: Min percentage of hemoglobin saturated with oxygen"]
+    #[doc = " * units: %"]
+    pub const MIN_SATURATED_HEMOGLOBIN_PERCENT: u8 = 88u8;
+    #[doc = " * max_saturated_hemoglobin_percent: Max percentage of hemoglobin saturated with oxygen"]
+    #[doc = " * units: %"]
+    pub const MAX_SATURATED_HEMOGLOBIN_PERCENT: u8 = 89u8;
+    #[doc = " * units: percent"]
+    pub const AVG_LEFT_TORQUE_EFFECTIVENESS: u8 = 91u8;
+    #[doc = " * units: percent"]
+    pub const AVG_RIGHT_TORQUE_EFFECTIVENESS: u8 = 92u8;
+    #[doc = " * units: percent"]
+    pub const AVG_LEFT_PEDAL_SMOOTHNESS: u8 = 93u8;
+    #[doc = " * units: percent"]
+    pub const AVG_RIGHT_PEDAL_SMOOTHNESS: u8 = 94u8;
+    #[doc = " * units: percent"]
+    pub const AVG_COMBINED_PEDAL_SMOOTHNESS: u8 = 95u8;
+    #[doc = " * time_standing: Total time spent in the standing position"]
+    #[doc = " * units: s"]
+    pub const TIME_STANDING: u8 = 98u8;
+    #[doc = " * stand_count: Number of transitions to the standing state"]
+    pub const STAND_COUNT: u8 = 99u8;
+    #[doc = " * avg_left_pco: Average left platform center offset"]
+    #[doc = " * units: mm"]
+    pub const AVG_LEFT_PCO: u8 = 100u8;
+    #[doc = " * avg_right_pco: Average right platform center offset"]
+    #[doc = " * units: mm"]
+    pub const AVG_RIGHT_PCO: u8 = 101u8;
+    #[doc = " * avg_left_power_phase: Average left power phase angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const AVG_LEFT_POWER_PHASE: u8 = 102u8;
+    #[doc = " * avg_left_power_phase_peak: Average left power phase peak angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const AVG_LEFT_POWER_PHASE_PEAK: u8 = 103u8;
+    #[doc = " * avg_right_power_phase: Average right power phase angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const AVG_RIGHT_POWER_PHASE: u8 = 104u8;
+    #[doc = " * avg_right_power_phase_peak: Average right power phase peak angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const AVG_RIGHT_POWER_PHASE_PEAK: u8 = 105u8;
+    #[doc = " * avg_power_position: Average power by position. Data value indexes defined by rider_position_type."]
+    #[doc = " * units: watts"]
+    pub const AVG_POWER_POSITION: u8 = 106u8;
+    #[doc = " * max_power_position: Maximum power by position. Data value indexes defined by rider_position_type."]
+    #[doc = " * units: watts"]
+    pub const MAX_POWER_POSITION: u8 = 107u8;
+    #[doc = " * avg_cadence_position: Average cadence by position. Data value indexes defined by rider_position_type."]
+    #[doc = " * units: rpm"]
+    pub const AVG_CADENCE_POSITION: u8 = 108u8;
+    #[doc = " * max_cadence_position: Maximum cadence by position. Data value indexes defined by rider_position_type."]
+    #[doc = " * units: rpm"]
+    pub const MAX_CADENCE_POSITION: u8 = 109u8;
+    #[doc = " * units: m/s"]
+    pub const ENHANCED_AVG_SPEED: u8 = 110u8;
+    #[doc = " * units: m/s"]
+    pub const ENHANCED_MAX_SPEED: u8 = 111u8;
+    #[doc = " * units: m"]
+    pub const ENHANCED_AVG_ALTITUDE: u8 = 112u8;
+    #[doc = " * units: m"]
+    pub const ENHANCED_MIN_ALTITUDE: u8 = 113u8;
+    #[doc = " * units: m"]
+    pub const ENHANCED_MAX_ALTITUDE: u8 = 114u8;
+    #[doc = " * avg_lev_motor_power: lev average motor power during lap"]
+    #[doc = " * units: watts"]
+    pub const AVG_LEV_MOTOR_POWER: u8 = 115u8;
+    #[doc = " * max_lev_motor_power: lev maximum motor power during lap"]
+    #[doc = " * units: watts"]
+    pub const MAX_LEV_MOTOR_POWER: u8 = 116u8;
+    #[doc = " * lev_battery_consumption: lev battery consumption during lap"]
+    #[doc = " * units: percent"]
+    pub const LEV_BATTERY_CONSUMPTION: u8 = 117u8;
+    #[doc = " * units: percent"]
+    pub const AVG_VERTICAL_RATIO: u8 = 118u8;
+    #[doc = " * units: percent"]
+    pub const AVG_STANCE_TIME_BALANCE: u8 = 119u8;
+    #[doc = " * units: mm"]
+    pub const AVG_STEP_LENGTH: u8 = 120u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_VAM: u8 = 121u8;
+    #[doc = " * avg_depth: 0 if above water"]
+    #[doc = " * units: m"]
+    pub const AVG_DEPTH: u8 = 122u8;
+    #[doc = " * max_depth: 0 if above water"]
+    #[doc = " * units: m"]
+    pub const MAX_DEPTH: u8 = 123u8;
+    #[doc = " * units: C"]
+    pub const MIN_TEMPERATURE: u8 = 124u8;
+    #[doc = " * units: Breaths/min"]
+    pub const ENHANCED_AVG_RESPIRATION_RATE: u8 = 136u8;
+    #[doc = " * units: Breaths/min"]
+    pub const ENHANCED_MAX_RESPIRATION_RATE: u8 = 137u8;
+    pub const AVG_RESPIRATION_RATE: u8 = 147u8;
+    pub const MAX_RESPIRATION_RATE: u8 = 148u8;
+    #[doc = " * total_grit: The grit score estimates how challenging a route could be for a cyclist in terms of time spent going over sharp turns or large grade slopes."]
+    #[doc = " * units: kGrit"]
+    pub const TOTAL_GRIT: u8 = 149u8;
+    #[doc = " * total_flow: The flow score estimates how long distance wise a cyclist deaccelerates over intervals where deacceleration is unnecessary such as smooth turns or small grade angle intervals."]
+    #[doc = " * units: Flow"]
+    pub const TOTAL_FLOW: u8 = 150u8;
+    pub const JUMP_COUNT: u8 = 151u8;
+    #[doc = " * avg_grit: The grit score estimates how challenging a route could be for a cyclist in terms of time spent going over sharp turns or large grade slopes."]
+    #[doc = " * units: kGrit"]
+    pub const AVG_GRIT: u8 = 153u8;
+    #[doc = " * avg_flow: The flow score estimates how long distance wise a cyclist deaccelerates over intervals where deacceleration is unnecessary such as smooth turns or small grade angle intervals."]
+    #[doc = " * units: Flow"]
+    pub const AVG_FLOW: u8 = 154u8;
+    #[doc = " * total_fractional_ascent: fractional part of total_ascent"]
+    #[doc = " * units: m"]
+    pub const TOTAL_FRACTIONAL_ASCENT: u8 = 156u8;
+    #[doc = " * total_fractional_descent: fractional part of total_descent"]
+    #[doc = " * units: m"]
+    pub const TOTAL_FRACTIONAL_DESCENT: u8 = 157u8;
+    #[doc = " * units: C"]
+    pub const AVG_CORE_TEMPERATURE: u8 = 158u8;
+    #[doc = " * units: C"]
+    pub const MIN_CORE_TEMPERATURE: u8 = 159u8;
+    #[doc = " * units: C"]
+    pub const MAX_CORE_TEMPERATURE: u8 = 160u8;
+    #[doc = " * timestamp: Lap end time."]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "length message definition"]
+pub mod length {
+    pub const EVENT: u8 = 0u8;
+    pub const EVENT_TYPE: u8 = 1u8;
+    pub const START_TIME: u8 = 2u8;
+    #[doc = " * units: s"]
+    pub const TOTAL_ELAPSED_TIME: u8 = 3u8;
+    #[doc = " * units: s"]
+    pub const TOTAL_TIMER_TIME: u8 = 4u8;
+    #[doc = " * units: strokes"]
+    pub const TOTAL_STROKES: u8 = 5u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_SPEED: u8 = 6u8;
+    #[doc = " * units: swim_stroke"]
+    pub const SWIM_STROKE: u8 = 7u8;
+    #[doc = " * units: strokes/min"]
+    pub const AVG_SWIMMING_CADENCE: u8 = 9u8;
+    pub const EVENT_GROUP: u8 = 10u8;
+    #[doc = " * units: kcal"]
+    pub const TOTAL_CALORIES: u8 = 11u8;
+    pub const LENGTH_TYPE: u8 = 12u8;
+    pub const PLAYER_SCORE: u8 = 18u8;
+    pub const OPPONENT_SCORE: u8 = 19u8;
+    #[doc = " * stroke_count: stroke_type enum used as the index"]
+    #[doc = " * units: counts"]
+    pub const STROKE_COUNT: u8 = 20u8;
+    #[doc = " * zone_count: zone number used as the index"]
+    #[doc = " * units: counts"]
+    pub const ZONE_COUNT: u8 = 21u8;
+    #[doc = " * units: Breaths/min"]
+    pub const ENHANCED_AVG_RESPIRATION_RATE: u8 = 22u8;
+    #[doc = " * units: Breaths/min"]
+    pub const ENHANCED_MAX_RESPIRATION_RATE: u8 = 23u8;
+    pub const AVG_RESPIRATION_RATE: u8 = 24u8;
+    pub const MAX_RESPIRATION_RATE: u8 = 25u8;
+    pub const TIMESTAMP: u8 = 253u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "record message definition"]
+pub mod record {
+    #[doc = " * units: semicircles"]
+    pub const POSITION_LAT: u8 = 0u8;
+    #[doc = " * units: semicircles"]
+    pub const POSITION_LONG: u8 = 1u8;
+    #[doc = " * units: m"]
+    pub const ALTITUDE: u8 = 2u8;
+    #[doc = " * units: bpm"]
+    pub const HEART_RATE: u8 = 3u8;
+    #[doc = " * units: rpm"]
+    pub const CADENCE: u8 = 4u8;
+    #[doc = " * units: m"]
+    pub const DISTANCE: u8 = 5u8;
+    #[doc = " * units: m/s"]
+    pub const SPEED: u8 = 6u8;
+    #[doc = " * units: watts"]
+    pub const POWER: u8 = 7u8;
+    #[doc = " * units: m/s,m"]
+    pub const COMPRESSED_SPEED_DISTANCE: u8 = 8u8;
+    #[doc = " * units: %"]
+    pub const GRADE: u8 = 9u8;
+    #[doc = " * resistance: Relative. 0 is none 254 is Max."]
+    pub const RESISTANCE: u8 = 10u8;
+    #[doc = " * units: s"]
+    pub const TIME_FROM_COURSE: u8 = 11u8;
+    #[doc = " * units: m"]
+    pub const CYCLE_LENGTH: u8 = 12u8;
+    #[doc = " * units: C"]
+    pub const TEMPERATURE: u8 = 13u8;
+    #[doc = " * speed_1s: Speed at 1s intervals. Timestamp field indicates time of last array element."]
+    #[doc = " * units: m/s"]
+    pub const SPEED_1S: u8 = 17u8;
+    #[doc = " * units: cycles"]
+    pub const CYCLES: u8 = 18u8;
+    #[doc = " * units: cycles"]
+    pub const TOTAL_CYCLES: u8 = 19u8;
+    #[doc = " * units: watts"]
+    pub const COMPRESSED_ACCUMULATED_POWER: u8 = 28u8;
+    #[doc = " * units: watts"]
+    pub const ACCUMULATED_POWER: u8 = 29u8;
+    pub const LEFT_RIGHT_BALANCE: u8 = 30u8;
+    #[doc = " * units: m"]
+    pub const GPS_ACCURACY: u8 = 31u8;
+    #[doc = " * units: m/s"]
+    pub const VERTICAL_SPEED: u8 = 32u8;
+    #[doc = " * units: kcal"]
+    pub const CALORIES: u8 = 33u8;
+    #[doc = " * units: mm"]
+    pub const VERTICAL_OSCILLATION: u8 = 39u8;
+    #[doc = " * units: percent"]
+    pub const STANCE_TIME_PERCENT: u8 = 40u8;
+    #[doc = " * units: ms"]
+    pub const STANCE_TIME: u8 = 41u8;
+    pub const ACTIVITY_TYPE: u8 = 42u8;
+    #[doc = " * units: percent"]
+    pub const LEFT_TORQUE_EFFECTIVENESS: u8 = 43u8;
+    #[doc = " * units: percent"]
+    pub const RIGHT_TORQUE_EFFECTIVENESS: u8 = 44u8;
+    #[doc = " * units: percent"]
+    pub const LEFT_PEDAL_SMOOTHNESS: u8 = 45u8;
+    #[doc = " * units: percent"]
+    pub const RIGHT_PEDAL_SMOOTHNESS: u8 = 46u8;
+    #[doc = " * units: percent"]
+    pub const COMBINED_PEDAL_SMOOTHNESS: u8 = 47u8;
+    #[doc = " * units: s"]
+    pub const TIME128: u8 = 48u8;
+    pub const STROKE_TYPE: u8 = 49u8;
+    pub const ZONE: u8 = 50u8;
+    #[doc = " * units: m/s"]
+    pub const BALL_SPEED: u8 = 51u8;
+    #[doc = " * cadence256: Log cadence and fractional cadence for backwards compatability"]
+    #[doc = " * units: rpm"]
+    pub const CADENCE256: u8 = 52u8;
+    #[doc = " * units: rpm"]
+    pub const FRACTIONAL_CADENCE: u8 = 53u8;
+    #[doc = " * total_hemoglobin_conc: Total saturated and unsaturated hemoglobin"]
+    #[doc = " * units: g/dL"]
+    pub const TOTAL_HEMOGLOBIN_CONC: u8 = 54u8;
+    #[doc = " * total_hemoglobin_conc_min: Min saturated and unsaturated hemoglobin"]
+    #[doc = " * units: g/dL"]
+    pub const TOTAL_HEMOGLOBIN_CONC_MIN: u8 = 55u8;
+    #[doc = " * total_hemoglobin_conc_max: Max saturated and unsaturated hemoglobin"]
+    #[doc = " * units: g/dL"]
+    pub const TOTAL_HEMOGLOBIN_CONC_MAX: u8 = 56u8;
+    #[doc = " * saturated_hemoglobin_percent: Percentage of hemoglobin saturated with oxygen"]
+    #[doc = " * units: %"]
+    pub const SATURATED_HEMOGLOBIN_PERCENT: u8 = 57u8;
+    #[doc = " * saturated_hemoglobin_percent_min: Min percentage of hemoglobin saturated with oxygen"]
+    #[doc = " * units: %"]
+    pub const SATURATED_HEMOGLOBIN_PERCENT_MIN: u8 = 58u8;
+    #[doc = " * saturated_hemoglobin_percent_max: Max percentage of hemoglobin saturated with oxygen"]
+    #[doc = " * units: %"]
+    pub const SATURATED_HEMOGLOBIN_PERCENT_MAX: u8 = 59u8;
+    pub const DEVICE_INDEX: u8 = 62u8;
+    #[doc = " * left_pco: Left platform center offset"]
+    #[doc = " * units: mm"]
+    pub const LEFT_PCO: u8 = 67u8;
+    #[doc = " * right_pco: Right platform center offset"]
+    #[doc = " * units: mm"]
+    pub const RIGHT_PCO: u8 = 68u8;
+    #[doc = " * left_power_phase: Left power phase angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const LEFT_POWER_PHASE: u8 = 69u8;
+    #[doc = " * left_power_phase_peak: Left power phase peak angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const LEFT_POWER_PHASE_PEAK: u8 = 70u8;
+    #[doc = " * right_power_phase: Right power phase angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const RIGHT_POWER_PHASE: u8 = 71u8;
+    #[doc = " * right_power_phase_peak: Right power phase peak angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const RIGHT_POWER_PHASE_PEAK: u8 = 72u8;
+    #[doc = " * units: m/s"]
+    pub const ENHANCED_SPEED: u8 = 73u8;
+    #[doc = " * units: m"]
+    pub const ENHANCED_ALTITUDE: u8 = 78u8;
+    #[doc = " * battery_soc: lev battery state of charge"]
+    #[doc = " * units: percent"]
+    pub const BATTERY_SOC: u8 = 81u8;
+    #[doc = " * motor_power: lev motor power"]
+    #[doc = " * units: watts"]
+    pub const MOTOR_POWER: u8 = 82u8;
+    #[doc = " * units: percent"]
+    pub const VERTICAL_RATIO: u8 = 83u8;
+    #[doc = " * units: percent"]
+    pub const STANCE_TIME_BALANCE: u8 = 84u8;
+    #[doc = " * units: mm"]
+    pub const STEP_LENGTH: u8 = 85u8;
+    #[doc = " * cycle_length16: Supports larger cycle sizes needed for paddlesports. Max cycle size: 655.35"]
+    #[doc = " * units: m"]
+    pub const CYCLE_LENGTH16: u8 = 87u8;
+    #[doc = " * absolute_pressure: Includes atmospheric pressure"]
+    #[doc = " * units: Pa"]
+    pub const ABSOLUTE_PRESSURE: u8 = 91u8;
+    #[doc = " * depth: 0 if above water"]
+    #[doc = " * units: m"]
+    pub const DEPTH: u8 = 92u8;
+    #[doc = " * next_stop_depth: 0 if above water"]
+    #[doc = " * units: m"]
+    pub const NEXT_STOP_DEPTH: u8 = 93u8;
+    #[doc = " * units: s"]
+    pub const NEXT_STOP_TIME: u8 = 94u8;
+    #[doc = " * units: s"]
+    pub const TIME_TO_SURFACE: u8 = 95u8;
+    #[doc = " * units: s"]
+    pub const NDL_TIME: u8 = 96u8;
+    #[doc = " * units: percent"]
+    pub const CNS_LOAD: u8 = 97u8;
+    #[doc = " * units: percent"]
+    pub const N2_LOAD: u8 = 98u8;
+    #[doc = " * units: s"]
+    pub const RESPIRATION_RATE: u8 = 99u8;
+    #[doc = " * units: Breaths/min"]
+    pub const ENHANCED_RESPIRATION_RATE: u8 = 108u8;
+    #[doc = " * grit: The grit score estimates how challenging a route could be for a cyclist in terms of time spent going over sharp turns or large grade slopes."]
+    pub const GRIT: u8 = 114u8;
+    #[doc = " * flow: The flow score estimates how long distance wise a cyclist deaccelerates over intervals where deacceleration is unnecessary such as smooth turns or small grade angle intervals."]
+    pub const FLOW: u8 = 115u8;
+    #[doc = " * current_stress: Current Stress value"]
+    pub const CURRENT_STRESS: u8 = 116u8;
+    #[doc = " * units: km"]
+    pub const EBIKE_TRAVEL_RANGE: u8 = 117u8;
+    #[doc = " * units: percent"]
+    pub const EBIKE_BATTERY_LEVEL: u8 = 118u8;
+    #[doc = " * units: depends on sensor"]
+    pub const EBIKE_ASSIST_MODE: u8 = 119u8;
+    #[doc = " * units: percent"]
+    pub const EBIKE_ASSIST_LEVEL_PERCENT: u8 = 120u8;
+    #[doc = " * units: s"]
+    pub const AIR_TIME_REMAINING: u8 = 123u8;
+    #[doc = " * pressure_sac: Pressure-based surface air consumption"]
+    #[doc = " * units: bar/min"]
+    pub const PRESSURE_SAC: u8 = 124u8;
+    #[doc = " * volume_sac: Volumetric surface air consumption"]
+    #[doc = " * units: L/min"]
+    pub const VOLUME_SAC: u8 = 125u8;
+    #[doc = " * rmv: Respiratory minute volume"]
+    #[doc = " * units: L/min"]
+    pub const RMV: u8 = 126u8;
+    #[doc = " * units: m/s"]
+    pub const ASCENT_RATE: u8 = 127u8;
+    #[doc = " * po2: Current partial pressure of oxygen"]
+    #[doc = " * units: percent"]
+    pub const PO2: u8 = 129u8;
+    #[doc = " * units: C"]
+    pub const CORE_TEMPERATURE: u8 = 139u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "event message definition"]
+pub mod event {
+    pub const EVENT: u8 = 0u8;
+    pub const EVENT_TYPE: u8 = 1u8;
+    pub const DATA16: u8 = 2u8;
+    pub const DATA: u8 = 3u8;
+    pub const EVENT_GROUP: u8 = 4u8;
+    #[doc = " * score: Do not populate directly. Autogenerated by decoder for sport_point subfield components"]
+    pub const SCORE: u8 = 7u8;
+    #[doc = " * opponent_score: Do not populate directly. Autogenerated by decoder for sport_point subfield components"]
+    pub const OPPONENT_SCORE: u8 = 8u8;
+    #[doc = " * front_gear_num: Do not populate directly. Autogenerated by decoder for gear_change subfield components. Front gear number. 1 is innermost."]
+    pub const FRONT_GEAR_NUM: u8 = 9u8;
+    #[doc = " * front_gear: Do not populate directly. Autogenerated by decoder for gear_change subfield components. Number of front teeth."]
+    pub const FRONT_GEAR: u8 = 10u8;
+    #[doc = " * rear_gear_num: Do not populate directly. Autogenerated by decoder for gear_change subfield components. Rear gear number. 1 is innermost."]
+    pub const REAR_GEAR_NUM: u8 = 11u8;
+    #[doc = " * rear_gear: Do not populate directly. Autogenerated by decoder for gear_change subfield components. Number of rear teeth."]
+    pub const REAR_GEAR: u8 = 12u8;
+    pub const DEVICE_INDEX: u8 = 13u8;
+    #[doc = " * activity_type: Activity Type associated with an auto_activity_detect event"]
+    pub const ACTIVITY_TYPE: u8 = 14u8;
+    #[doc = " * start_timestamp: Timestamp of when the event started"]
+    #[doc = " * units: s"]
+    pub const START_TIMESTAMP: u8 = 15u8;
+    #[doc = " * radar_threat_level_max: Do not populate directly. Autogenerated by decoder for threat_alert subfield components."]
+    pub const RADAR_THREAT_LEVEL_MAX: u8 = 21u8;
+    #[doc = " * radar_threat_count: Do not populate directly. Autogenerated by decoder for threat_alert subfield components."]
+    pub const RADAR_THREAT_COUNT: u8 = 22u8;
+    #[doc = " * radar_threat_avg_approach_speed: Do not populate directly. Autogenerated by decoder for radar_threat_alert subfield components"]
+    #[doc = " * units: m/s"]
+    pub const RADAR_THREAT_AVG_APPROACH_SPEED: u8 = 23u8;
+    #[doc = " * radar_threat_max_approach_speed: Do not populate directly. Autogenerated by decoder for radar_threat_alert subfield components"]
+    #[doc = " * units: m/s"]
+    pub const RADAR_THREAT_MAX_APPROACH_SPEED: u8 = 24u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "device_info message definition"]
+pub mod device_info {
+    pub const DEVICE_INDEX: u8 = 0u8;
+    pub const DEVICE_TYPE: u8 = 1u8;
+    pub const MANUFACTURER: u8 = 2u8;
+    pub const SERIAL_NUMBER: u8 = 3u8;
+    pub const PRODUCT: u8 = 4u8;
+    pub const SOFTWARE_VERSION: u8 = 5u8;
+    pub const HARDWARE_VERSION: u8 = 6u8;
+    #[doc = " * cum_operating_time: Reset by new battery or charge."]
+    #[doc = " * units: s"]
+    pub const CUM_OPERATING_TIME: u8 = 7u8;
+    #[doc = " * units: V"]
+    pub const BATTERY_VOLTAGE: u8 = 10u8;
+    pub const BATTERY_STATUS: u8 = 11u8;
+    #[doc = " * sensor_position: Indicates the location of the sensor"]
+    pub const SENSOR_POSITION: u8 = 18u8;
+    #[doc = " * descriptor: Used to describe the sensor or location"]
+    pub const DESCRIPTOR: u8 = 19u8;
+    pub const ANT_TRANSMISSION_TYPE: u8 = 20u8;
+    pub const ANT_DEVICE_NUMBER: u8 = 21u8;
+    pub const ANT_NETWORK: u8 = 22u8;
+    pub const SOURCE_TYPE: u8 = 25u8;
+    #[doc = " * product_name: Optional free form string to indicate the devices name or model"]
+    pub const PRODUCT_NAME: u8 = 27u8;
+    #[doc = " * units: %"]
+    pub const BATTERY_LEVEL: u8 = 32u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "device_aux_battery_info message definition"]
+pub mod device_aux_battery_info {
+    pub const DEVICE_INDEX: u8 = 0u8;
+    #[doc = " * units: V"]
+    pub const BATTERY_VOLTAGE: u8 = 1u8;
+    pub const BATTERY_STATUS: u8 = 2u8;
+    pub const BATTERY_IDENTIFIER: u8 = 3u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "Corresponds to file_id of workout or course."]
+pub mod training_file {
+    pub const TYPE: u8 = 0u8;
+    pub const MANUFACTURER: u8 = 1u8;
+    pub const PRODUCT: u8 = 2u8;
+    pub const SERIAL_NUMBER: u8 = 3u8;
+    pub const TIME_CREATED: u8 = 4u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "weather_conditions message definition"]
+pub mod weather_conditions {
+    #[doc = " * weather_report: Current or forecast"]
+    pub const WEATHER_REPORT: u8 = 0u8;
+    #[doc = " * units: C"]
+    pub const TEMPERATURE: u8 = 1u8;
+    #[doc = " * condition: Corresponds to GSC Response weatherIcon field"]
+    pub const CONDITION: u8 = 2u8;
+    #[doc = " * units: degrees"]
+    pub const WIND_DIRECTION: u8 = 3u8;
+    #[doc = " * units: m/s"]
+    pub const WIND_SPEED: u8 = 4u8;
+    #[doc = " * precipitation_probability: range 0-100"]
+    pub const PRECIPITATION_PROBABILITY: u8 = 5u8;
+    #[doc = " * temperature_feels_like: Heat Index if GCS heatIdx above or equal to 90F or wind chill if GCS windChill below or equal to 32F"]
+    #[doc = " * units: C"]
+    pub const TEMPERATURE_FEELS_LIKE: u8 = 6u8;
+    pub const RELATIVE_HUMIDITY: u8 = 7u8;
+    #[doc = " * location: string corresponding to GCS response location string"]
+    pub const LOCATION: u8 = 8u8;
+    pub const OBSERVED_AT_TIME: u8 = 9u8;
+    #[doc = " * units: semicircles"]
+    pub const OBSERVED_LOCATION_LAT: u8 = 10u8;
+    #[doc = " * units: semicircles"]
+    pub const OBSERVED_LOCATION_LONG: u8 = 11u8;
+    pub const DAY_OF_WEEK: u8 = 12u8;
+    #[doc = " * units: C"]
+    pub const HIGH_TEMPERATURE: u8 = 13u8;
+    #[doc = " * units: C"]
+    pub const LOW_TEMPERATURE: u8 = 14u8;
+    #[doc = " * timestamp: time of update for current conditions, else forecast time"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "weather_alert message definition"]
+pub mod weather_alert {
+    #[doc = " * report_id: Unique identifier from GCS report ID string, length is 12"]
+    pub const REPORT_ID: u8 = 0u8;
+    #[doc = " * issue_time: Time alert was issued"]
+    pub const ISSUE_TIME: u8 = 1u8;
+    #[doc = " * expire_time: Time alert expires"]
+    pub const EXPIRE_TIME: u8 = 2u8;
+    #[doc = " * severity: Warning, Watch, Advisory, Statement"]
+    pub const SEVERITY: u8 = 3u8;
+    #[doc = " * type: Tornado, Severe Thunderstorm, etc."]
+    pub const TYPE: u8 = 4u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "gps_metadata message definition"]
+pub mod gps_metadata {
+    #[doc = " * timestamp_ms: Millisecond part of the timestamp."]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    #[doc = " * units: semicircles"]
+    pub const POSITION_LAT: u8 = 1u8;
+    #[doc = " * units: semicircles"]
+    pub const POSITION_LONG: u8 = 2u8;
+    #[doc = " * units: m"]
+    pub const ENHANCED_ALTITUDE: u8 = 3u8;
+    #[doc = " * units: m/s"]
+    pub const ENHANCED_SPEED: u8 = 4u8;
+    #[doc = " * units: degrees"]
+    pub const HEADING: u8 = 5u8;
+    #[doc = " * utc_timestamp: Used to correlate UTC to system time if the timestamp of the message is in system time. This UTC time is derived from the GPS data."]
+    #[doc = " * units: s"]
+    pub const UTC_TIMESTAMP: u8 = 6u8;
+    #[doc = " * velocity: velocity[0] is lon velocity. Velocity[1] is lat velocity. Velocity[2] is altitude velocity."]
+    #[doc = " * units: m/s"]
+    pub const VELOCITY: u8 = 7u8;
+    #[doc = " * timestamp: Whole second part of the timestamp."]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "camera_event message definition"]
+pub mod camera_event {
+    #[doc = " * timestamp_ms: Millisecond part of the timestamp."]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    pub const CAMERA_EVENT_TYPE: u8 = 1u8;
+    pub const CAMERA_FILE_UUID: u8 = 2u8;
+    pub const CAMERA_ORIENTATION: u8 = 3u8;
+    #[doc = " * timestamp: Whole second part of the timestamp."]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "gyroscope_data message definition"]
+pub mod gyroscope_data {
+    #[doc = " * timestamp_ms: Millisecond part of the timestamp."]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    #[doc = " * sample_time_offset: Each time in the array describes the time at which the gyro sample with the corrosponding index was taken. Limited to 30 samples in each message. The samples may span across seconds. Array size must match the number of samples in gyro_x and gyro_y and gyro_z"]
+    #[doc = " * units: ms"]
+    pub const SAMPLE_TIME_OFFSET: u8 = 1u8;
+    #[doc = " * gyro_x: These are the raw ADC reading. Maximum number of samples is 30 in each message. The samples may span across seconds. A conversion will need to be done on this data once read."]
+    #[doc = " * units: counts"]
+    pub const GYRO_X: u8 = 2u8;
+    #[doc = " * gyro_y: These are the raw ADC reading. Maximum number of samples is 30 in each message. The samples may span across seconds. A conversion will need to be done on this data once read."]
+    #[doc = " * units: counts"]
+    pub const GYRO_Y: u8 = 3u8;
+    #[doc = " * gyro_z: These are the raw ADC reading. Maximum number of samples is 30 in each message. The samples may span across seconds. A conversion will need to be done on this data once read."]
+    #[doc = " * units: counts"]
+    pub const GYRO_Z: u8 = 4u8;
+    #[doc = " * calibrated_gyro_x: Calibrated gyro reading"]
+    #[doc = " * units: deg/s"]
+    pub const CALIBRATED_GYRO_X: u8 = 5u8;
+    #[doc = " * calibrated_gyro_y: Calibrated gyro reading"]
+    #[doc = " * units: deg/s"]
+    pub const CALIBRATED_GYRO_Y: u8 = 6u8;
+    #[doc = " * calibrated_gyro_z: Calibrated gyro reading"]
+    #[doc = " * units: deg/s"]
+    pub const CALIBRATED_GYRO_Z: u8 = 7u8;
+    #[doc = " * timestamp: Whole second part of the timestamp"]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "accelerometer_data message definition"]
+pub mod accelerometer_data {
+    #[doc = " * timestamp_ms: Millisecond part of the timestamp."]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    #[doc = " * sample_time_offset: Each time in the array describes the time at which the accelerometer sample with the corrosponding index was taken. Limited to 30 samples in each message. The samples may span across seconds. Array size must match the number of samples in accel_x and accel_y and accel_z"]
+    #[doc = " * units: ms"]
+    pub const SAMPLE_TIME_OFFSET: u8 = 1u8;
+    #[doc = " * accel_x: These are the raw ADC reading. Maximum number of samples is 30 in each message. The samples may span across seconds. A conversion will need to be done on this data once read."]
+    #[doc = " * units: counts"]
+    pub const ACCEL_X: u8 = 2u8;
+    #[doc = " * accel_y: These are the raw ADC reading. Maximum number of samples is 30 in each message. The samples may span across seconds. A conversion will need to be done on this data once read."]
+    #[doc = " * units: counts"]
+    pub const ACCEL_Y: u8 = 3u8;
+    #[doc = " * accel_z: These are the raw ADC reading. Maximum number of samples is 30 in each message. The samples may span across seconds. A conversion will need to be done on this data once read."]
+    #[doc = " * units: counts"]
+    pub const ACCEL_Z: u8 = 4u8;
+    #[doc = " * calibrated_accel_x: Calibrated accel reading"]
+    #[doc = " * units: g"]
+    pub const CALIBRATED_ACCEL_X: u8 = 5u8;
+    #[doc = " * calibrated_accel_y: Calibrated accel reading"]
+    #[doc = " * units: g"]
+    pub const CALIBRATED_ACCEL_Y: u8 = 6u8;
+    #[doc = " * calibrated_accel_z: Calibrated accel reading"]
+    #[doc = " * units: g"]
+    pub const CALIBRATED_ACCEL_Z: u8 = 7u8;
+    #[doc = " * compressed_calibrated_accel_x: Calibrated accel reading"]
+    #[doc = " * units: mG"]
+    pub const COMPRESSED_CALIBRATED_ACCEL_X: u8 = 8u8;
+    #[doc = " * compressed_calibrated_accel_y: Calibrated accel reading"]
+    #[doc = " * units: mG"]
+    pub const COMPRESSED_CALIBRATED_ACCEL_Y: u8 = 9u8;
+    #[doc = " * compressed_calibrated_accel_z: Calibrated accel reading"]
+    #[doc = " * units: mG"]
+    pub const COMPRESSED_CALIBRATED_ACCEL_Z: u8 = 10u8;
+    #[doc = " * timestamp: Whole second part of the timestamp"]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "magnetometer_data message definition"]
+pub mod magnetometer_data {
+    #[doc = " * timestamp_ms: Millisecond part of the timestamp."]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    #[doc = " * sample_time_offset: Each time in the array describes the time at which the compass sample with the corrosponding index was taken. Limited to 30 samples in each message. The samples may span across seconds. Array size must match the number of samples in cmps_x and cmps_y and cmps_z"]
+    #[doc = " * units: ms"]
+    pub const SAMPLE_TIME_OFFSET: u8 = 1u8;
+    #[doc = " * mag_x: These are the raw ADC reading. Maximum number of samples is 30 in each message. The samples may span across seconds. A conversion will need to be done on this data once read."]
+    #[doc = " * units: counts"]
+    pub const MAG_X: u8 = 2u8;
+    #[doc = " * mag_y: These are the raw ADC reading. Maximum number of samples is 30 in each message. The samples may span across seconds. A conversion will need to be done on this data once read."]
+    #[doc = " * units: counts"]
+    pub const MAG_Y: u8 = 3u8;
+    #[doc = " * mag_z: These are the raw ADC reading. Maximum number of samples is 30 in each message. The samples may span across seconds. A conversion will need to be done on this data once read."]
+    #[doc = " * units: counts"]
+    pub const MAG_Z: u8 = 4u8;
+    #[doc = " * calibrated_mag_x: Calibrated Magnetometer reading"]
+    #[doc = " * units: G"]
+    pub const CALIBRATED_MAG_X: u8 = 5u8;
+    #[doc = " * calibrated_mag_y: Calibrated Magnetometer reading"]
+    #[doc = " * units: G"]
+    pub const CALIBRATED_MAG_Y: u8 = 6u8;
+    #[doc = " * calibrated_mag_z: Calibrated Magnetometer reading"]
+    #[doc = " * units: G"]
+    pub const CALIBRATED_MAG_Z: u8 = 7u8;
+    #[doc = " * timestamp: Whole second part of the timestamp"]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "barometer_data message definition"]
+pub mod barometer_data {
+    #[doc = " * timestamp_ms: Millisecond part of the timestamp."]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    #[doc = " * sample_time_offset: Each time in the array describes the time at which the barometer sample with the corrosponding index was taken. The samples may span across seconds. Array size must match the number of samples in baro_cal"]
+    #[doc = " * units: ms"]
+    pub const SAMPLE_TIME_OFFSET: u8 = 1u8;
+    #[doc = " * baro_pres: These are the raw ADC reading. The samples may span across seconds. A conversion will need to be done on this data once read."]
+    #[doc = " * units: Pa"]
+    pub const BARO_PRES: u8 = 2u8;
+    #[doc = " * timestamp: Whole second part of the timestamp"]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "three_d_sensor_calibration message definition"]
+pub mod three_d_sensor_calibration {
+    #[doc = " * sensor_type: Indicates which sensor the calibration is for"]
+    pub const SENSOR_TYPE: u8 = 0u8;
+    #[doc = " * calibration_factor: Calibration factor used to convert from raw ADC value to degrees, g, etc."]
+    pub const CALIBRATION_FACTOR: u8 = 1u8;
+    #[doc = " * calibration_divisor: Calibration factor divisor"]
+    #[doc = " * units: counts"]
+    pub const CALIBRATION_DIVISOR: u8 = 2u8;
+    #[doc = " * level_shift: Level shift value used to shift the ADC value back into range"]
+    pub const LEVEL_SHIFT: u8 = 3u8;
+    #[doc = " * offset_cal: Internal calibration factors, one for each: xy, yx, zx"]
+    pub const OFFSET_CAL: u8 = 4u8;
+    #[doc = " * orientation_matrix: 3 x 3 rotation matrix (row major)"]
+    pub const ORIENTATION_MATRIX: u8 = 5u8;
+    #[doc = " * timestamp: Whole second part of the timestamp"]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "one_d_sensor_calibration message definition"]
+pub mod one_d_sensor_calibration {
+    #[doc = " * sensor_type: Indicates which sensor the calibration is for"]
+    pub const SENSOR_TYPE: u8 = 0u8;
+    #[doc = " * calibration_factor: Calibration factor used to convert from raw ADC value to degrees, g, etc."]
+    pub const CALIBRATION_FACTOR: u8 = 1u8;
+    #[doc = " * calibration_divisor: Calibration factor divisor"]
+    #[doc = " * units: counts"]
+    pub const CALIBRATION_DIVISOR: u8 = 2u8;
+    #[doc = " * level_shift: Level shift value used to shift the ADC value back into range"]
+    pub const LEVEL_SHIFT: u8 = 3u8;
+    #[doc = " * offset_cal: Internal Calibration factor"]
+    pub const OFFSET_CAL: u8 = 4u8;
+    #[doc = " * timestamp: Whole second part of the timestamp"]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "video_frame message definition"]
+pub mod video_frame {
+    #[doc = " * timestamp_ms: Millisecond part of the timestamp."]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    #[doc = " * frame_number: Number of the frame that the timestamp and timestamp_ms correlate to"]
+    pub const FRAME_NUMBER: u8 = 1u8;
+    #[doc = " * timestamp: Whole second part of the timestamp"]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "obdii_data message definition"]
+pub mod obdii_data {
+    #[doc = " * timestamp_ms: Fractional part of timestamp, added to timestamp"]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    #[doc = " * time_offset: Offset of PID reading [i] from start_timestamp+start_timestamp_ms. Readings may span accross seconds."]
+    #[doc = " * units: ms"]
+    pub const TIME_OFFSET: u8 = 1u8;
+    #[doc = " * pid: Parameter ID"]
+    pub const PID: u8 = 2u8;
+    #[doc = " * raw_data: Raw parameter data"]
+    pub const RAW_DATA: u8 = 3u8;
+    #[doc = " * pid_data_size: Optional, data size of PID[i]. If not specified refer to SAE J1979."]
+    pub const PID_DATA_SIZE: u8 = 4u8;
+    #[doc = " * system_time: System time associated with sample expressed in ms, can be used instead of time_offset. There will be a system_time value for each raw_data element. For multibyte pids the system_time is repeated."]
+    pub const SYSTEM_TIME: u8 = 5u8;
+    #[doc = " * start_timestamp: Timestamp of first sample recorded in the message. Used with time_offset to generate time of each sample"]
+    pub const START_TIMESTAMP: u8 = 6u8;
+    #[doc = " * start_timestamp_ms: Fractional part of start_timestamp"]
+    #[doc = " * units: ms"]
+    pub const START_TIMESTAMP_MS: u8 = 7u8;
+    #[doc = " * timestamp: Timestamp message was output"]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "nmea_sentence message definition"]
+pub mod nmea_sentence {
+    #[doc = " * timestamp_ms: Fractional part of timestamp, added to timestamp"]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    #[doc = " * sentence: NMEA sentence"]
+    pub const SENTENCE: u8 = 1u8;
+    #[doc = " * timestamp: Timestamp message was output"]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "aviation_attitude message definition"]
+pub mod aviation_attitude {
+    #[doc = " * timestamp_ms: Fractional part of timestamp, added to timestamp"]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    #[doc = " * system_time: System time associated with sample expressed in ms."]
+    #[doc = " * units: ms"]
+    pub const SYSTEM_TIME: u8 = 1u8;
+    #[doc = " * pitch: Range -PI/2 to +PI/2"]
+    #[doc = " * units: radians"]
+    pub const PITCH: u8 = 2u8;
+    #[doc = " * roll: Range -PI to +PI"]
+    #[doc = " * units: radians"]
+    pub const ROLL: u8 = 3u8;
+    #[doc = " * accel_lateral: Range -78.4 to +78.4 (-8 Gs to 8 Gs)"]
+    #[doc = " * units: m/s^2"]
+    pub const ACCEL_LATERAL: u8 = 4u8;
+    #[doc = " * accel_normal: Range -78.4 to +78.4 (-8 Gs to 8 Gs)"]
+    #[doc = " * units: m/s^2"]
+    pub const ACCEL_NORMAL: u8 = 5u8;
+    #[doc = " * turn_rate: Range -8.727 to +8.727 (-500 degs/sec to +500 degs/sec)"]
+    #[doc = " * units: radians/second"]
+    pub const TURN_RATE: u8 = 6u8;
+    pub const STAGE: u8 = 7u8;
+    #[doc = " * attitude_stage_complete: The percent complete of the current attitude stage. Set to 0 for attitude stages 0, 1 and 2 and to 100 for attitude stage 3 by AHRS modules that do not support it. Range - 100"]
+    #[doc = " * units: %"]
+    pub const ATTITUDE_STAGE_COMPLETE: u8 = 8u8;
+    #[doc = " * track: Track Angle/Heading Range 0 - 2pi"]
+    #[doc = " * units: radians"]
+    pub const TRACK: u8 = 9u8;
+    pub const VALIDITY: u8 = 10u8;
+    #[doc = " * timestamp: Timestamp message was output"]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "video message definition"]
+pub mod video {
+    pub const URL: u8 = 0u8;
+    pub const HOSTING_PROVIDER: u8 = 1u8;
+    #[doc = " * duration: Playback time of video"]
+    #[doc = " * units: ms"]
+    pub const DURATION: u8 = 2u8;
+}
+#[doc = "video_title message definition"]
+pub mod video_title {
+    #[doc = " * message_count: Total number of title parts"]
+    pub const MESSAGE_COUNT: u8 = 0u8;
+    pub const TEXT: u8 = 1u8;
+    #[doc = " * message_index: Long titles will be split into multiple parts"]
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "video_description message definition"]
+pub mod video_description {
+    #[doc = " * message_count: Total number of description parts"]
+    pub const MESSAGE_COUNT: u8 = 0u8;
+    pub const TEXT: u8 = 1u8;
+    #[doc = " * message_index: Long descriptions will be split into multiple parts"]
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "video_clip message definition"]
+pub mod video_clip {
+    pub const CLIP_NUMBER: u8 = 0u8;
+    pub const START_TIMESTAMP: u8 = 1u8;
+    pub const START_TIMESTAMP_MS: u8 = 2u8;
+    pub const END_TIMESTAMP: u8 = 3u8;
+    pub const END_TIMESTAMP_MS: u8 = 4u8;
+    #[doc = " * clip_start: Start of clip in video time"]
+    #[doc = " * units: ms"]
+    pub const CLIP_START: u8 = 6u8;
+    #[doc = " * clip_end: End of clip in video time"]
+    #[doc = " * units: ms"]
+    pub const CLIP_END: u8 = 7u8;
+}
+#[doc = "set message definition"]
+pub mod set {
+    #[doc = " * units: s"]
+    pub const DURATION: u8 = 0u8;
+    #[doc = " * repetitions: # of repitions of the movement"]
+    pub const REPETITIONS: u8 = 3u8;
+    #[doc = " * weight: Amount of weight applied for the set"]
+    #[doc = " * units: kg"]
+    pub const WEIGHT: u8 = 4u8;
+    pub const SET_TYPE: u8 = 5u8;
+    #[doc = " * start_time: Start time of the set"]
+    pub const START_TIME: u8 = 6u8;
+    pub const CATEGORY: u8 = 7u8;
+    #[doc = " * category_subtype: Based on the associated category, see [category]_exercise_names"]
+    pub const CATEGORY_SUBTYPE: u8 = 8u8;
+    pub const WEIGHT_DISPLAY_UNIT: u8 = 9u8;
+    pub const MESSAGE_INDEX: u8 = 10u8;
+    pub const WKT_STEP_INDEX: u8 = 11u8;
+    #[doc = " * timestamp: Timestamp of the set"]
+    pub const TIMESTAMP: u8 = 254u8;
+}
+#[doc = "jump message definition"]
+pub mod jump {
+    #[doc = " * units: m"]
+    pub const DISTANCE: u8 = 0u8;
+    #[doc = " * units: m"]
+    pub const HEIGHT: u8 = 1u8;
+    pub const ROTATIONS: u8 = 2u8;
+    #[doc = " * units: s"]
+    pub const HANG_TIME: u8 = 3u8;
+    #[doc = " * score: A score for a jump calculated based on hang time, rotations, and distance."]
+    pub const SCORE: u8 = 4u8;
+    #[doc = " * units: semicircles"]
+    pub const POSITION_LAT: u8 = 5u8;
+    #[doc = " * units: semicircles"]
+    pub const POSITION_LONG: u8 = 6u8;
+    #[doc = " * units: m/s"]
+    pub const SPEED: u8 = 7u8;
+    #[doc = " * units: m/s"]
+    pub const ENHANCED_SPEED: u8 = 8u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "split message definition"]
+pub mod split {
+    pub const SPLIT_TYPE: u8 = 0u8;
+    #[doc = " * units: s"]
+    pub const TOTAL_ELAPSED_TIME: u8 = 1u8;
+    #[doc = " * units: s"]
+    pub const TOTAL_TIMER_TIME: u8 = 2u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_DISTANCE: u8 = 3u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_SPEED: u8 = 4u8;
+    pub const START_TIME: u8 = 9u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_ASCENT: u8 = 13u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_DESCENT: u8 = 14u8;
+    #[doc = " * units: semicircles"]
+    pub const START_POSITION_LAT: u8 = 21u8;
+    #[doc = " * units: semicircles"]
+    pub const START_POSITION_LONG: u8 = 22u8;
+    #[doc = " * units: semicircles"]
+    pub const END_POSITION_LAT: u8 = 23u8;
+    #[doc = " * units: semicircles"]
+    pub const END_POSITION_LONG: u8 = 24u8;
+    #[doc = " * units: m/s"]
+    pub const MAX_SPEED: u8 = 25u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_VERT_SPEED: u8 = 26u8;
+    pub const END_TIME: u8 = 27u8;
+    #[doc = " * units: kcal"]
+    pub const TOTAL_CALORIES: u8 = 28u8;
+    #[doc = " * units: m"]
+    pub const START_ELEVATION: u8 = 74u8;
+    #[doc = " * units: s"]
+    pub const TOTAL_MOVING_TIME: u8 = 110u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "split_summary message definition"]
+pub mod split_summary {
+    pub const SPLIT_TYPE: u8 = 0u8;
+    pub const NUM_SPLITS: u8 = 3u8;
+    #[doc = " * units: s"]
+    pub const TOTAL_TIMER_TIME: u8 = 4u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_DISTANCE: u8 = 5u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_SPEED: u8 = 6u8;
+    #[doc = " * units: m/s"]
+    pub const MAX_SPEED: u8 = 7u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_ASCENT: u8 = 8u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_DESCENT: u8 = 9u8;
+    #[doc = " * units: bpm"]
+    pub const AVG_HEART_RATE: u8 = 10u8;
+    #[doc = " * units: bpm"]
+    pub const MAX_HEART_RATE: u8 = 11u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_VERT_SPEED: u8 = 12u8;
+    #[doc = " * units: kcal"]
+    pub const TOTAL_CALORIES: u8 = 13u8;
+    #[doc = " * units: s"]
+    pub const TOTAL_MOVING_TIME: u8 = 77u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "climb_pro message definition"]
+pub mod climb_pro {
+    #[doc = " * units: semicircles"]
+    pub const POSITION_LAT: u8 = 0u8;
+    #[doc = " * units: semicircles"]
+    pub const POSITION_LONG: u8 = 1u8;
+    pub const CLIMB_PRO_EVENT: u8 = 2u8;
+    pub const CLIMB_NUMBER: u8 = 3u8;
+    pub const CLIMB_CATEGORY: u8 = 4u8;
+    #[doc = " * units: m"]
+    pub const CURRENT_DIST: u8 = 5u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "Must be logged before developer field is used"]
+pub mod field_description {
+    pub const DEVELOPER_DATA_INDEX: u8 = 0u8;
+    pub const FIELD_DEFINITION_NUMBER: u8 = 1u8;
+    pub const FIT_BASE_TYPE_ID: u8 = 2u8;
+    pub const FIELD_NAME: u8 = 3u8;
+    pub const ARRAY: u8 = 4u8;
+    pub const COMPONENTS: u8 = 5u8;
+    pub const SCALE: u8 = 6u8;
+    pub const OFFSET: u8 = 7u8;
+    pub const UNITS: u8 = 8u8;
+    pub const BITS: u8 = 9u8;
+    pub const ACCUMULATE: u8 = 10u8;
+    pub const FIT_BASE_UNIT_ID: u8 = 13u8;
+    pub const NATIVE_MESG_NUM: u8 = 14u8;
+    pub const NATIVE_FIELD_NUM: u8 = 15u8;
+}
+#[doc = "Must be logged before field description"]
+pub mod developer_data_id {
+    pub const DEVELOPER_ID: u8 = 0u8;
+    pub const APPLICATION_ID: u8 = 1u8;
+    pub const MANUFACTURER_ID: u8 = 2u8;
+    pub const DEVELOPER_DATA_INDEX: u8 = 3u8;
+    pub const APPLICATION_VERSION: u8 = 4u8;
+}
+#[doc = "course message definition"]
+pub mod course {
+    pub const SPORT: u8 = 4u8;
+    pub const NAME: u8 = 5u8;
+    pub const CAPABILITIES: u8 = 6u8;
+    pub const SUB_SPORT: u8 = 7u8;
+}
+#[doc = "course_point message definition"]
+pub mod course_point {
+    pub const TIMESTAMP: u8 = 1u8;
+    #[doc = " * units: semicircles"]
+    pub const POSITION_LAT: u8 = 2u8;
+    #[doc = " * units: semicircles"]
+    pub const POSITION_LONG: u8 = 3u8;
+    #[doc = " * units: m"]
+    pub const DISTANCE: u8 = 4u8;
+    pub const TYPE: u8 = 5u8;
+    pub const NAME: u8 = 6u8;
+    pub const FAVORITE: u8 = 8u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "Unique Identification data for a segment file"]
+pub mod segment_id {
+    #[doc = " * name: Friendly name assigned to segment"]
+    pub const NAME: u8 = 0u8;
+    #[doc = " * uuid: UUID of the segment"]
+    pub const UUID: u8 = 1u8;
+    #[doc = " * sport: Sport associated with the segment"]
+    pub const SPORT: u8 = 2u8;
+    #[doc = " * enabled: Segment enabled for evaluation"]
+    pub const ENABLED: u8 = 3u8;
+    #[doc = " * user_profile_primary_key: Primary key of the user that created the segment"]
+    pub const USER_PROFILE_PRIMARY_KEY: u8 = 4u8;
+    #[doc = " * device_id: ID of the device that created the segment"]
+    pub const DEVICE_ID: u8 = 5u8;
+    #[doc = " * default_race_leader: Index for the Leader Board entry selected as the default race participant"]
+    pub const DEFAULT_RACE_LEADER: u8 = 6u8;
+    #[doc = " * delete_status: Indicates if any segments should be deleted"]
+    pub const DELETE_STATUS: u8 = 7u8;
+    #[doc = " * selection_type: Indicates how the segment was selected to be sent to the device"]
+    pub const SELECTION_TYPE: u8 = 8u8;
+}
+#[doc = "Unique Identification data for an individual segment leader within a segment file"]
+pub mod segment_leaderboard_entry {
+    #[doc = " * name: Friendly name assigned to leader"]
+    pub const NAME: u8 = 0u8;
+    #[doc = " * type: Leader classification"]
+    pub const TYPE: u8 = 1u8;
+    #[doc = " * group_primary_key: Primary user ID of this leader"]
+    pub const GROUP_PRIMARY_KEY: u8 = 2u8;
+    #[doc = " * activity_id: ID of the activity associated with this leader time"]
+    pub const ACTIVITY_ID: u8 = 3u8;
+    #[doc = " * segment_time: Segment Time (includes pauses)"]
+    #[doc = " * units: s"]
+    pub const SEGMENT_TIME: u8 = 4u8;
+    #[doc = " * activity_id_string: String version of the activity_id. 21 characters long, express in decimal"]
+    pub const ACTIVITY_ID_STRING: u8 = 5u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "Navigation and race evaluation point for a segment decribing a point along the segment path and time it took each segment leader to reach that point"]
+pub mod segment_point {
+    #[doc = " * units: semicircles"]
+    pub const POSITION_LAT: u8 = 1u8;
+    #[doc = " * units: semicircles"]
+    pub const POSITION_LONG: u8 = 2u8;
+    #[doc = " * distance: Accumulated distance along the segment at the described point"]
+    #[doc = " * units: m"]
+    pub const DISTANCE: u8 = 3u8;
+    #[doc = " * altitude: Accumulated altitude along the segment at the described point"]
+    #[doc = " * units: m"]
+    pub const ALTITUDE: u8 = 4u8;
+    #[doc = " * leader_time: Accumualted time each leader board member required to reach the described point. This value is zero for all leader board members at the starting point of the segment."]
+    #[doc = " * units: s"]
+    pub const LEADER_TIME: u8 = 5u8;
+    #[doc = " * enhanced_altitude: Accumulated altitude along the segment at the described point"]
+    #[doc = " * units: m"]
+    pub const ENHANCED_ALTITUDE: u8 = 6u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "segment_lap message definition"]
+pub mod segment_lap {
+    pub const EVENT: u8 = 0u8;
+    pub const EVENT_TYPE: u8 = 1u8;
+    pub const START_TIME: u8 = 2u8;
+    #[doc = " * units: semicircles"]
+    pub const START_POSITION_LAT: u8 = 3u8;
+    #[doc = " * units: semicircles"]
+    pub const START_POSITION_LONG: u8 = 4u8;
+    #[doc = " * units: semicircles"]
+    pub const END_POSITION_LAT: u8 = 5u8;
+    #[doc = " * units: semicircles"]
+    pub const END_POSITION_LONG: u8 = 6u8;
+    #[doc = " * total_elapsed_time: Time (includes pauses)"]
+    #[doc = " * units: s"]
+    pub const TOTAL_ELAPSED_TIME: u8 = 7u8;
+    #[doc = " * total_timer_time: Timer Time (excludes pauses)"]
+    #[doc = " * units: s"]
+    pub const TOTAL_TIMER_TIME: u8 = 8u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_DISTANCE: u8 = 9u8;
+    #[doc = " * units: cycles"]
+    pub const TOTAL_CYCLES: u8 = 10u8;
+    #[doc = " * units: kcal"]
+    pub const TOTAL_CALORIES: u8 = 11u8;
+    #[doc = " * total_fat_calories: If New Leaf"]
+    #[doc = " * units: kcal"]
+    pub const TOTAL_FAT_CALORIES: u8 = 12u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_SPEED: u8 = 13u8;
+    #[doc = " * units: m/s"]
+    pub const MAX_SPEED: u8 = 14u8;
+    #[doc = " * units: bpm"]
+    pub const AVG_HEART_RATE: u8 = 15u8;
+    #[doc = " * units: bpm"]
+    pub const MAX_HEART_RATE: u8 = 16u8;
+    #[doc = " * avg_cadence: total_cycles / total_timer_time if non_zero_avg_cadence otherwise total_cycles / total_elapsed_time"]
+    #[doc = " * units: rpm"]
+    pub const AVG_CADENCE: u8 = 17u8;
+    #[doc = " * units: rpm"]
+    pub const MAX_CADENCE: u8 = 18u8;
+    #[doc = " * avg_power: total_power / total_timer_time if non_zero_avg_power otherwise total_power / total_elapsed_time"]
+    #[doc = " * units: watts"]
+    pub const AVG_POWER: u8 = 19u8;
+    #[doc = " * units: watts"]
+    pub const MAX_POWER: u8 = 20u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_ASCENT: u8 = 21u8;
+    #[doc = " * units: m"]
+    pub const TOTAL_DESCENT: u8 = 22u8;
+    pub const SPORT: u8 = 23u8;
+    pub const EVENT_GROUP: u8 = 24u8;
+    #[doc = " * nec_lat: North east corner latitude."]
+    #[doc = " * units: semicircles"]
+    pub const NEC_LAT: u8 = 25u8;
+    #[doc = " * nec_long: North east corner longitude."]
+    #[doc = " * units: semicircles"]
+    pub const NEC_LONG: u8 = 26u8;
+    #[doc = " * swc_lat: South west corner latitude."]
+    #[doc = " * units: semicircles"]
+    pub const SWC_LAT: u8 = 27u8;
+    #[doc = " * swc_long: South west corner latitude."]
+    #[doc = " * units: semicircles"]
+    pub const SWC_LONG: u8 = 28u8;
+    pub const NAME: u8 = 29u8;
+    #[doc = " * units: watts"]
+    pub const NORMALIZED_POWER: u8 = 30u8;
+    pub const LEFT_RIGHT_BALANCE: u8 = 31u8;
+    pub const SUB_SPORT: u8 = 32u8;
+    #[doc = " * units: J"]
+    pub const TOTAL_WORK: u8 = 33u8;
+    #[doc = " * units: m"]
+    pub const AVG_ALTITUDE: u8 = 34u8;
+    #[doc = " * units: m"]
+    pub const MAX_ALTITUDE: u8 = 35u8;
+    #[doc = " * units: m"]
+    pub const GPS_ACCURACY: u8 = 36u8;
+    #[doc = " * units: %"]
+    pub const AVG_GRADE: u8 = 37u8;
+    #[doc = " * units: %"]
+    pub const AVG_POS_GRADE: u8 = 38u8;
+    #[doc = " * units: %"]
+    pub const AVG_NEG_GRADE: u8 = 39u8;
+    #[doc = " * units: %"]
+    pub const MAX_POS_GRADE: u8 = 40u8;
+    #[doc = " * units: %"]
+    pub const MAX_NEG_GRADE: u8 = 41u8;
+    #[doc = " * units: C"]
+    pub const AVG_TEMPERATURE: u8 = 42u8;
+    #[doc = " * units: C"]
+    pub const MAX_TEMPERATURE: u8 = 43u8;
+    #[doc = " * units: s"]
+    pub const TOTAL_MOVING_TIME: u8 = 44u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_POS_VERTICAL_SPEED: u8 = 45u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_NEG_VERTICAL_SPEED: u8 = 46u8;
+    #[doc = " * units: m/s"]
+    pub const MAX_POS_VERTICAL_SPEED: u8 = 47u8;
+    #[doc = " * units: m/s"]
+    pub const MAX_NEG_VERTICAL_SPEED: u8 = 48u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_HR_ZONE: u8 = 49u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_SPEED_ZONE: u8 = 50u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_CADENCE_ZONE: u8 = 51u8;
+    #[doc = " * units: s"]
+    pub const TIME_IN_POWER_ZONE: u8 = 52u8;
+    pub const REPETITION_NUM: u8 = 53u8;
+    #[doc = " * units: m"]
+    pub const MIN_ALTITUDE: u8 = 54u8;
+    #[doc = " * units: bpm"]
+    pub const MIN_HEART_RATE: u8 = 55u8;
+    #[doc = " * units: s"]
+    pub const ACTIVE_TIME: u8 = 56u8;
+    pub const WKT_STEP_INDEX: u8 = 57u8;
+    pub const SPORT_EVENT: u8 = 58u8;
+    #[doc = " * units: percent"]
+    pub const AVG_LEFT_TORQUE_EFFECTIVENESS: u8 = 59u8;
+    #[doc = " * units: percent"]
+    pub const AVG_RIGHT_TORQUE_EFFECTIVENESS: u8 = 60u8;
+    #[doc = " * units: percent"]
+    pub const AVG_LEFT_PEDAL_SMOOTHNESS: u8 = 61u8;
+    #[doc = " * units: percent"]
+    pub const AVG_RIGHT_PEDAL_SMOOTHNESS: u8 = 62u8;
+    #[doc = " * units: percent"]
+    pub const AVG_COMBINED_PEDAL_SMOOTHNESS: u8 = 63u8;
+    pub const STATUS: u8 = 64u8;
+    pub const UUID: u8 = 65u8;
+    #[doc = " * avg_fractional_cadence: fractional part of the avg_cadence"]
+    #[doc = " * units: rpm"]
+    pub const AVG_FRACTIONAL_CADENCE: u8 = 66u8;
+    #[doc = " * max_fractional_cadence: fractional part of the max_cadence"]
+    #[doc = " * units: rpm"]
+    pub const MAX_FRACTIONAL_CADENCE: u8 = 67u8;
+    #[doc = " * total_fractional_cycles: fractional part of the total_cycles"]
+    #[doc = " * units: cycles"]
+    pub const TOTAL_FRACTIONAL_CYCLES: u8 = 68u8;
+    pub const FRONT_GEAR_SHIFT_COUNT: u8 = 69u8;
+    pub const REAR_GEAR_SHIFT_COUNT: u8 = 70u8;
+    #[doc = " * time_standing: Total time spent in the standing position"]
+    #[doc = " * units: s"]
+    pub const TIME_STANDING: u8 = 71u8;
+    #[doc = " * stand_count: Number of transitions to the standing state"]
+    pub const STAND_COUNT: u8 = 72u8;
+    #[doc = " * avg_left_pco: Average left platform center offset"]
+    #[doc = " * units: mm"]
+    pub const AVG_LEFT_PCO: u8 = 73u8;
+    #[doc = " * avg_right_pco: Average right platform center offset"]
+    #[doc = " * units: mm"]
+    pub const AVG_RIGHT_PCO: u8 = 74u8;
+    #[doc = " * avg_left_power_phase: Average left power phase angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const AVG_LEFT_POWER_PHASE: u8 = 75u8;
+    #[doc = " * avg_left_power_phase_peak: Average left power phase peak angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const AVG_LEFT_POWER_PHASE_PEAK: u8 = 76u8;
+    #[doc = " * avg_right_power_phase: Average right power phase angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const AVG_RIGHT_POWER_PHASE: u8 = 77u8;
+    #[doc = " * avg_right_power_phase_peak: Average right power phase peak angles. Data value indexes defined by power_phase_type."]
+    #[doc = " * units: degrees"]
+    pub const AVG_RIGHT_POWER_PHASE_PEAK: u8 = 78u8;
+    #[doc = " * avg_power_position: Average power by position. Data value indexes defined by rider_position_type."]
+    #[doc = " * units: watts"]
+    pub const AVG_POWER_POSITION: u8 = 79u8;
+    #[doc = " * max_power_position: Maximum power by position. Data value indexes defined by rider_position_type."]
+    #[doc = " * units: watts"]
+    pub const MAX_POWER_POSITION: u8 = 80u8;
+    #[doc = " * avg_cadence_position: Average cadence by position. Data value indexes defined by rider_position_type."]
+    #[doc = " * units: rpm"]
+    pub const AVG_CADENCE_POSITION: u8 = 81u8;
+    #[doc = " * max_cadence_position: Maximum cadence by position. Data value indexes defined by rider_position_type."]
+    #[doc = " * units: rpm"]
+    pub const MAX_CADENCE_POSITION: u8 = 82u8;
+    #[doc = " * manufacturer: Manufacturer that produced the segment"]
+    pub const MANUFACTURER: u8 = 83u8;
+    #[doc = " * total_grit: The grit score estimates how challenging a route could be for a cyclist in terms of time spent going over sharp turns or large grade slopes."]
+    #[doc = " * units: kGrit"]
+    pub const TOTAL_GRIT: u8 = 84u8;
+    #[doc = " * total_flow: The flow score estimates how long distance wise a cyclist deaccelerates over intervals where deacceleration is unnecessary such as smooth turns or small grade angle intervals."]
+    #[doc = " * units: Flow"]
+    pub const TOTAL_FLOW: u8 = 85u8;
+    #[doc = " * avg_grit: The grit score estimates how challenging a route could be for a cyclist in terms of time spent going over sharp turns or large grade slopes."]
+    #[doc = " * units: kGrit"]
+    pub const AVG_GRIT: u8 = 86u8;
+    #[doc = " * avg_flow: The flow score estimates how long distance wise a cyclist deaccelerates over intervals where deacceleration is unnecessary such as smooth turns or small grade angle intervals."]
+    #[doc = " * units: Flow"]
+    pub const AVG_FLOW: u8 = 87u8;
+    #[doc = " * total_fractional_ascent: fractional part of total_ascent"]
+    #[doc = " * units: m"]
+    pub const TOTAL_FRACTIONAL_ASCENT: u8 = 89u8;
+    #[doc = " * total_fractional_descent: fractional part of total_descent"]
+    #[doc = " * units: m"]
+    pub const TOTAL_FRACTIONAL_DESCENT: u8 = 90u8;
+    #[doc = " * units: m"]
+    pub const ENHANCED_AVG_ALTITUDE: u8 = 91u8;
+    #[doc = " * units: m"]
+    pub const ENHANCED_MAX_ALTITUDE: u8 = 92u8;
+    #[doc = " * units: m"]
+    pub const ENHANCED_MIN_ALTITUDE: u8 = 93u8;
+    #[doc = " * timestamp: Lap end time."]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "Summary of the unique segment and leaderboard information associated with a segment file. This message is used to compile a segment list file describing all segment files on a device. The segment list file is used when refreshing the contents of a segment file with the latest available leaderboard information."]
+pub mod segment_file {
+    #[doc = " * file_uuid: UUID of the segment file"]
+    pub const FILE_UUID: u8 = 1u8;
+    #[doc = " * enabled: Enabled state of the segment file"]
+    pub const ENABLED: u8 = 3u8;
+    #[doc = " * user_profile_primary_key: Primary key of the user that created the segment file"]
+    pub const USER_PROFILE_PRIMARY_KEY: u8 = 4u8;
+    #[doc = " * leader_type: Leader type of each leader in the segment file"]
+    pub const LEADER_TYPE: u8 = 7u8;
+    #[doc = " * leader_group_primary_key: Group primary key of each leader in the segment file"]
+    pub const LEADER_GROUP_PRIMARY_KEY: u8 = 8u8;
+    #[doc = " * leader_activity_id: Activity ID of each leader in the segment file"]
+    pub const LEADER_ACTIVITY_ID: u8 = 9u8;
+    #[doc = " * leader_activity_id_string: String version of the activity ID of each leader in the segment file. 21 characters long for each ID, express in decimal"]
+    pub const LEADER_ACTIVITY_ID_STRING: u8 = 10u8;
+    #[doc = " * default_race_leader: Index for the Leader Board entry selected as the default race participant"]
+    pub const DEFAULT_RACE_LEADER: u8 = 11u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "workout message definition"]
+pub mod workout {
+    pub const SPORT: u8 = 4u8;
+    pub const CAPABILITIES: u8 = 5u8;
+    #[doc = " * num_valid_steps: number of valid steps"]
+    pub const NUM_VALID_STEPS: u8 = 6u8;
+    pub const WKT_NAME: u8 = 8u8;
+    pub const SUB_SPORT: u8 = 11u8;
+    #[doc = " * units: m"]
+    pub const POOL_LENGTH: u8 = 14u8;
+    pub const POOL_LENGTH_UNIT: u8 = 15u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "workout_session message definition"]
+pub mod workout_session {
+    pub const SPORT: u8 = 0u8;
+    pub const SUB_SPORT: u8 = 1u8;
+    pub const NUM_VALID_STEPS: u8 = 2u8;
+    pub const FIRST_STEP_INDEX: u8 = 3u8;
+    #[doc = " * units: m"]
+    pub const POOL_LENGTH: u8 = 4u8;
+    pub const POOL_LENGTH_UNIT: u8 = 5u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "workout_step message definition"]
+pub mod workout_step {
+    pub const WKT_STEP_NAME: u8 = 0u8;
+    pub const DURATION_TYPE: u8 = 1u8;
+    pub const DURATION_VALUE: u8 = 2u8;
+    pub const TARGET_TYPE: u8 = 3u8;
+    pub const TARGET_VALUE: u8 = 4u8;
+    pub const CUSTOM_TARGET_VALUE_LOW: u8 = 5u8;
+    pub const CUSTOM_TARGET_VALUE_HIGH: u8 = 6u8;
+    pub const INTENSITY: u8 = 7u8;
+    pub const NOTES: u8 = 8u8;
+    pub const EQUIPMENT: u8 = 9u8;
+    pub const EXERCISE_CATEGORY: u8 = 10u8;
+    pub const EXERCISE_NAME: u8 = 11u8;
+    #[doc = " * units: kg"]
+    pub const EXERCISE_WEIGHT: u8 = 12u8;
+    pub const WEIGHT_DISPLAY_UNIT: u8 = 13u8;
+    pub const SECONDARY_TARGET_TYPE: u8 = 19u8;
+    pub const SECONDARY_TARGET_VALUE: u8 = 20u8;
+    pub const SECONDARY_CUSTOM_TARGET_VALUE_LOW: u8 = 21u8;
+    pub const SECONDARY_CUSTOM_TARGET_VALUE_HIGH: u8 = 22u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "exercise_title message definition"]
+pub mod exercise_title {
+    pub const EXERCISE_CATEGORY: u8 = 0u8;
+    pub const EXERCISE_NAME: u8 = 1u8;
+    pub const WKT_STEP_NAME: u8 = 2u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "schedule message definition"]
+pub mod schedule {
+    #[doc = " * manufacturer: Corresponds to file_id of scheduled workout / course."]
+    pub const MANUFACTURER: u8 = 0u8;
+    #[doc = " * product: Corresponds to file_id of scheduled workout / course."]
+    pub const PRODUCT: u8 = 1u8;
+    #[doc = " * serial_number: Corresponds to file_id of scheduled workout / course."]
+    pub const SERIAL_NUMBER: u8 = 2u8;
+    #[doc = " * time_created: Corresponds to file_id of scheduled workout / course."]
+    pub const TIME_CREATED: u8 = 3u8;
+    #[doc = " * completed: TRUE if this activity has been started"]
+    pub const COMPLETED: u8 = 4u8;
+    pub const TYPE: u8 = 5u8;
+    pub const SCHEDULED_TIME: u8 = 6u8;
+}
+#[doc = "totals message definition"]
+pub mod totals {
+    #[doc = " * timer_time: Excludes pauses"]
+    #[doc = " * units: s"]
+    pub const TIMER_TIME: u8 = 0u8;
+    #[doc = " * units: m"]
+    pub const DISTANCE: u8 = 1u8;
+    #[doc = " * units: kcal"]
+    pub const CALORIES: u8 = 2u8;
+    pub const SPORT: u8 = 3u8;
+    #[doc = " * elapsed_time: Includes pauses"]
+    #[doc = " * units: s"]
+    pub const ELAPSED_TIME: u8 = 4u8;
+    pub const SESSIONS: u8 = 5u8;
+    #[doc = " * units: s"]
+    pub const ACTIVE_TIME: u8 = 6u8;
+    pub const SPORT_INDEX: u8 = 9u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+    pub const MESSAGE_INDEX: u8 = 254u8;
+}
+#[doc = "weight_scale message definition"]
+pub mod weight_scale {
+    #[doc = " * units: kg"]
+    pub const WEIGHT: u8 = 0u8;
+    #[doc = " * units: %"]
+    pub const PERCENT_FAT: u8 = 1u8;
+    #[doc = " * units: %"]
+    pub const PERCENT_HYDRATION: u8 = 2u8;
+    #[doc = " * units: kg"]
+    pub const VISCERAL_FAT_MASS: u8 = 3u8;
+    #[doc = " * units: kg"]
+    pub const BONE_MASS: u8 = 4u8;
+    #[doc = " * units: kg"]
+    pub const MUSCLE_MASS: u8 = 5u8;
+    #[doc = " * units: kcal/day"]
+    pub const BASAL_MET: u8 = 7u8;
+    pub const PHYSIQUE_RATING: u8 = 8u8;
+    #[doc = " * active_met: ~4kJ per kcal, 0.25 allows max 16384 kcal"]
+    #[doc = " * units: kcal/day"]
+    pub const ACTIVE_MET: u8 = 9u8;
+    #[doc = " * units: years"]
+    pub const METABOLIC_AGE: u8 = 10u8;
+    pub const VISCERAL_FAT_RATING: u8 = 11u8;
+    #[doc = " * user_profile_index: Associates this weight scale message to a user. This corresponds to the index of the user profile message in the weight scale file."]
+    pub const USER_PROFILE_INDEX: u8 = 12u8;
+    #[doc = " * units: kg/m^2"]
+    pub const BMI: u8 = 13u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "blood_pressure message definition"]
+pub mod blood_pressure {
+    #[doc = " * units: mmHg"]
+    pub const SYSTOLIC_PRESSURE: u8 = 0u8;
+    #[doc = " * units: mmHg"]
+    pub const DIASTOLIC_PRESSURE: u8 = 1u8;
+    #[doc = " * units: mmHg"]
+    pub const MEAN_ARTERIAL_PRESSURE: u8 = 2u8;
+    #[doc = " * units: mmHg"]
+    pub const MAP_3_SAMPLE_MEAN: u8 = 3u8;
+    #[doc = " * units: mmHg"]
+    pub const MAP_MORNING_VALUES: u8 = 4u8;
+    #[doc = " * units: mmHg"]
+    pub const MAP_EVENING_VALUES: u8 = 5u8;
+    #[doc = " * units: bpm"]
+    pub const HEART_RATE: u8 = 6u8;
+    pub const HEART_RATE_TYPE: u8 = 7u8;
+    pub const STATUS: u8 = 8u8;
+    #[doc = " * user_profile_index: Associates this blood pressure message to a user. This corresponds to the index of the user profile message in the blood pressure file."]
+    pub const USER_PROFILE_INDEX: u8 = 9u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "monitoring_info message definition"]
+pub mod monitoring_info {
+    #[doc = " * local_timestamp: Use to convert activity timestamps to local time if device does not support time zone and daylight savings time correction."]
+    #[doc = " * units: s"]
+    pub const LOCAL_TIMESTAMP: u8 = 0u8;
+    pub const ACTIVITY_TYPE: u8 = 1u8;
+    #[doc = " * cycles_to_distance: Indexed by activity_type"]
+    #[doc = " * units: m/cycle"]
+    pub const CYCLES_TO_DISTANCE: u8 = 3u8;
+    #[doc = " * cycles_to_calories: Indexed by activity_type"]
+    #[doc = " * units: kcal/cycle"]
+    pub const CYCLES_TO_CALORIES: u8 = 4u8;
+    #[doc = " * units: kcal / day"]
+    pub const RESTING_METABOLIC_RATE: u8 = 5u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "monitoring message definition"]
+pub mod monitoring {
+    #[doc = " * device_index: Associates this data to device_info message. Not required for file with single device (sensor)."]
+    pub const DEVICE_INDEX: u8 = 0u8;
+    #[doc = " * calories: Accumulated total calories. Maintained by MonitoringReader for each activity_type. See SDK documentation"]
+    #[doc = " * units: kcal"]
+    pub const CALORIES: u8 = 1u8;
+    #[doc = " * distance: Accumulated distance. Maintained by MonitoringReader for each activity_type. See SDK documentation."]
+    #[doc = " * units: m"]
+    pub const DISTANCE: u8 = 2u8;
+    #[doc = " * cycles: Accumulated cycles. Maintained by MonitoringReader for each activity_type. See SDK documentation."]
+    #[doc = " * units: cycles"]
+    pub const CYCLES: u8 = 3u8;
+    #[doc = " * units: s"]
+    pub const ACTIVE_TIME: u8 = 4u8;
+    pub const ACTIVITY_TYPE: u8 = 5u8;
+    pub const ACTIVITY_SUBTYPE: u8 = 6u8;
+    pub const ACTIVITY_LEVEL: u8 = 7u8;
+    #[doc = " * units: 100 * m"]
+    pub const DISTANCE_16: u8 = 8u8;
+    #[doc = " * units: 2 * cycles (steps)"]
+    pub const CYCLES_16: u8 = 9u8;
+    #[doc = " * units: s"]
+    pub const ACTIVE_TIME_16: u8 = 10u8;
+    #[doc = " * local_timestamp: Must align to logging interval, for example, time must be 00:00:00 for daily log."]
+    pub const LOCAL_TIMESTAMP: u8 = 11u8;
+    #[doc = " * temperature: Avg temperature during the logging interval ended at timestamp"]
+    #[doc = " * units: C"]
+    pub const TEMPERATURE: u8 = 12u8;
+    #[doc = " * temperature_min: Min temperature during the logging interval ended at timestamp"]
+    #[doc = " * units: C"]
+    pub const TEMPERATURE_MIN: u8 = 14u8;
+    #[doc = " * temperature_max: Max temperature during the logging interval ended at timestamp"]
+    #[doc = " * units: C"]
+    pub const TEMPERATURE_MAX: u8 = 15u8;
+    #[doc = " * activity_time: Indexed using minute_activity_level enum"]
+    #[doc = " * units: minutes"]
+    pub const ACTIVITY_TIME: u8 = 16u8;
+    #[doc = " * units: kcal"]
+    pub const ACTIVE_CALORIES: u8 = 19u8;
+    #[doc = " * current_activity_type_intensity: Indicates single type / intensity for duration since last monitoring message."]
+    pub const CURRENT_ACTIVITY_TYPE_INTENSITY: u8 = 24u8;
+    #[doc = " * units: min"]
+    pub const TIMESTAMP_MIN_8: u8 = 25u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP_16: u8 = 26u8;
+    #[doc = " * units: bpm"]
+    pub const HEART_RATE: u8 = 27u8;
+    pub const INTENSITY: u8 = 28u8;
+    #[doc = " * units: min"]
+    pub const DURATION_MIN: u8 = 29u8;
+    #[doc = " * units: s"]
+    pub const DURATION: u8 = 30u8;
+    #[doc = " * units: m"]
+    pub const ASCENT: u8 = 31u8;
+    #[doc = " * units: m"]
+    pub const DESCENT: u8 = 32u8;
+    #[doc = " * units: minutes"]
+    pub const MODERATE_ACTIVITY_MINUTES: u8 = 33u8;
+    #[doc = " * units: minutes"]
+    pub const VIGOROUS_ACTIVITY_MINUTES: u8 = 34u8;
+    #[doc = " * timestamp: Must align to logging interval, for example, time must be 00:00:00 for daily log."]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "monitoring_hr_data message definition"]
+pub mod monitoring_hr_data {
+    #[doc = " * resting_heart_rate: 7-day rolling average"]
+    #[doc = " * units: bpm"]
+    pub const RESTING_HEART_RATE: u8 = 0u8;
+    #[doc = " * current_day_resting_heart_rate: RHR for today only. (Feeds into 7-day average)"]
+    #[doc = " * units: bpm"]
+    pub const CURRENT_DAY_RESTING_HEART_RATE: u8 = 1u8;
+    #[doc = " * timestamp: Must align to logging interval, for example, time must be 00:00:00 for daily log."]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "spo2_data message definition"]
+pub mod spo2_data {
+    #[doc = " * units: percent"]
+    pub const READING_SPO2: u8 = 0u8;
+    pub const READING_CONFIDENCE: u8 = 1u8;
+    #[doc = " * mode: Mode when data was captured"]
+    pub const MODE: u8 = 2u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "hr message definition"]
+pub mod hr {
+    #[doc = " * units: s"]
+    pub const FRACTIONAL_TIMESTAMP: u8 = 0u8;
+    #[doc = " * units: s"]
+    pub const TIME256: u8 = 1u8;
+    #[doc = " * units: bpm"]
+    pub const FILTERED_BPM: u8 = 6u8;
+    #[doc = " * units: s"]
+    pub const EVENT_TIMESTAMP: u8 = 9u8;
+    #[doc = " * units: s"]
+    pub const EVENT_TIMESTAMP_12: u8 = 10u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "Value from 1 to 100 calculated by FirstBeat"]
+pub mod stress_level {
+    pub const STRESS_LEVEL_VALUE: u8 = 0u8;
+    #[doc = " * stress_level_time: Time stress score was calculated"]
+    #[doc = " * units: s"]
+    pub const STRESS_LEVEL_TIME: u8 = 1u8;
+}
+#[doc = "max_met_data message definition"]
+pub mod max_met_data {
+    #[doc = " * update_time: Time maxMET and vo2 were calculated"]
+    pub const UPDATE_TIME: u8 = 0u8;
+    #[doc = " * units: mL/kg/min"]
+    pub const VO2_MAX: u8 = 2u8;
+    pub const SPORT: u8 = 5u8;
+    pub const SUB_SPORT: u8 = 6u8;
+    pub const MAX_MET_CATEGORY: u8 = 8u8;
+    #[doc = " * calibrated_data: Indicates if calibrated data was used in the calculation"]
+    pub const CALIBRATED_DATA: u8 = 9u8;
+    #[doc = " * hr_source: Indicates if the estimate was obtained using a chest strap or wrist heart rate"]
+    pub const HR_SOURCE: u8 = 12u8;
+    #[doc = " * speed_source: Indidcates if the estimate was obtained using onboard GPS or connected GPS"]
+    pub const SPEED_SOURCE: u8 = 13u8;
+}
+#[doc = "Body battery data used for HSA custom data logging"]
+pub mod hsa_body_battery_data {
+    #[doc = " * processing_interval: Processing interval length in seconds"]
+    #[doc = " * units: s"]
+    pub const PROCESSING_INTERVAL: u8 = 0u8;
+    #[doc = " * level: Body battery level"]
+    #[doc = " * units: percent"]
+    pub const LEVEL: u8 = 1u8;
+    #[doc = " * charged: Body battery charged value"]
+    pub const CHARGED: u8 = 2u8;
+    #[doc = " * uncharged: Body battery uncharged value"]
+    pub const UNCHARGED: u8 = 3u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "HSA events"]
+pub mod hsa_event {
+    #[doc = " * event_id: Event ID"]
+    pub const EVENT_ID: u8 = 0u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "Raw accelerometer data used for HSA custom data logging"]
+pub mod hsa_accelerometer_data {
+    #[doc = " * timestamp_ms: Millisecond resolution of the timestamp"]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    #[doc = " * sampling_interval: Sampling Interval in Milliseconds"]
+    #[doc = " * units: ms"]
+    pub const SAMPLING_INTERVAL: u8 = 1u8;
+    #[doc = " * accel_x: X-Axis Measurement"]
+    #[doc = " * units: mG"]
+    pub const ACCEL_X: u8 = 2u8;
+    #[doc = " * accel_y: Y-Axis Measurement"]
+    #[doc = " * units: mG"]
+    pub const ACCEL_Y: u8 = 3u8;
+    #[doc = " * accel_z: Z-Axis Measurement"]
+    #[doc = " * units: mG"]
+    pub const ACCEL_Z: u8 = 4u8;
+    #[doc = " * timestamp_32k: 32 kHz timestamp"]
+    pub const TIMESTAMP_32K: u8 = 5u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "hsa_gyroscope_data message definition"]
+pub mod hsa_gyroscope_data {
+    #[doc = " * timestamp_ms: Millisecond resolution of the timestamp"]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    #[doc = " * sampling_interval: Sampling Interval in 32 kHz timescale"]
+    #[doc = " * units: 1/32768 s"]
+    pub const SAMPLING_INTERVAL: u8 = 1u8;
+    #[doc = " * gyro_x: X-Axis Measurement"]
+    #[doc = " * units: deg/s"]
+    pub const GYRO_X: u8 = 2u8;
+    #[doc = " * gyro_y: Y-Axis Measurement"]
+    #[doc = " * units: deg/s"]
+    pub const GYRO_Y: u8 = 3u8;
+    #[doc = " * gyro_z: Z-Axis Measurement"]
+    #[doc = " * units: deg/s"]
+    pub const GYRO_Z: u8 = 4u8;
+    #[doc = " * timestamp_32k: 32 kHz timestamp"]
+    #[doc = " * units: 1/32768 s"]
+    pub const TIMESTAMP_32K: u8 = 5u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "User's current daily step data used for HSA custom data logging"]
+pub mod hsa_step_data {
+    #[doc = " * processing_interval: Processing interval length in seconds"]
+    #[doc = " * units: s"]
+    pub const PROCESSING_INTERVAL: u8 = 0u8;
+    #[doc = " * steps: Total step sum"]
+    #[doc = " * units: steps"]
+    pub const STEPS: u8 = 1u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "User's current SpO2 data used for HSA custom data logging"]
+pub mod hsa_spo2_data {
+    #[doc = " * processing_interval: Processing interval length in seconds"]
+    #[doc = " * units: s"]
+    pub const PROCESSING_INTERVAL: u8 = 0u8;
+    #[doc = " * reading_spo2: SpO2 Reading"]
+    #[doc = " * units: percent"]
+    pub const READING_SPO2: u8 = 1u8;
+    #[doc = " * confidence: SpO2 Confidence"]
+    pub const CONFIDENCE: u8 = 2u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "User's current stress data used for HSA custom data logging"]
+pub mod hsa_stress_data {
+    #[doc = " * processing_interval: Processing interval length in seconds"]
+    #[doc = " * units: s"]
+    pub const PROCESSING_INTERVAL: u8 = 0u8;
+    #[doc = " * stress_level: Stress Level ( 0 - 100 ) -300 indicates invalid -200 indicates large motion -100 indicates off wrist"]
+    #[doc = " * units: s"]
+    pub const STRESS_LEVEL: u8 = 1u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "User's current respiration data used for HSA custom data logging"]
+pub mod hsa_respiration_data {
+    #[doc = " * processing_interval: Processing interval length in seconds"]
+    #[doc = " * units: s"]
+    pub const PROCESSING_INTERVAL: u8 = 0u8;
+    #[doc = " * respiration_rate: Breaths * 100 /min -300 indicates invalid -200 indicates large motion -100 indicates off wrist"]
+    #[doc = " * units: breaths/min"]
+    pub const RESPIRATION_RATE: u8 = 1u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "User's current heart rate data used for HSA custom data logging"]
+pub mod hsa_heart_rate_data {
+    #[doc = " * processing_interval: Processing interval length in seconds"]
+    #[doc = " * units: s"]
+    pub const PROCESSING_INTERVAL: u8 = 0u8;
+    #[doc = " * status: Status of measurements in buffer - 0 indicates SEARCHING 1 indicates LOCKED"]
+    pub const STATUS: u8 = 1u8;
+    #[doc = " * heart_rate: Beats / min"]
+    #[doc = " * units: bpm"]
+    pub const HEART_RATE: u8 = 2u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "Configuration data for HSA custom data logging"]
+pub mod hsa_configuration_data {
+    pub const DATA: u8 = 0u8;
+    #[doc = " * data_size: Size in bytes of data field"]
+    pub const DATA_SIZE: u8 = 1u8;
+    #[doc = " * timestamp: Encoded configuration data"]
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "Wrist temperature data used for HSA custom data logging"]
+pub mod hsa_wrist_temperature_data {
+    #[doc = " * processing_interval: Processing interval length in seconds"]
+    #[doc = " * units: s"]
+    pub const PROCESSING_INTERVAL: u8 = 0u8;
+    #[doc = " * value: Wrist temperature reading"]
+    #[doc = " * units: degC"]
+    pub const VALUE: u8 = 1u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "memo_glob message definition"]
+pub mod memo_glob {
+    #[doc = " * memo: Deprecated. Use data field."]
+    pub const MEMO: u8 = 0u8;
+    #[doc = " * mesg_num: Message Number of the parent message"]
+    pub const MESG_NUM: u8 = 1u8;
+    #[doc = " * parent_index: Index of mesg that this glob is associated with."]
+    pub const PARENT_INDEX: u8 = 2u8;
+    #[doc = " * field_num: Field within the parent that this glob is associated with"]
+    pub const FIELD_NUM: u8 = 3u8;
+    #[doc = " * data: Block of utf8 bytes. Note, mutltibyte characters may be split across adjoining memo_glob messages."]
+    pub const DATA: u8 = 4u8;
+    #[doc = " * part_index: Sequence number of memo blocks"]
+    pub const PART_INDEX: u8 = 250u8;
+}
+#[doc = "sleep_level message definition"]
+pub mod sleep_level {
+    pub const SLEEP_LEVEL: u8 = 0u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "ant_channel_id message definition"]
+pub mod ant_channel_id {
+    pub const CHANNEL_NUMBER: u8 = 0u8;
+    pub const DEVICE_TYPE: u8 = 1u8;
+    pub const DEVICE_NUMBER: u8 = 2u8;
+    pub const TRANSMISSION_TYPE: u8 = 3u8;
+    pub const DEVICE_INDEX: u8 = 4u8;
+}
+#[doc = "ant_rx message definition"]
+pub mod ant_rx {
+    #[doc = " * units: s"]
+    pub const FRACTIONAL_TIMESTAMP: u8 = 0u8;
+    pub const MESG_ID: u8 = 1u8;
+    pub const MESG_DATA: u8 = 2u8;
+    pub const CHANNEL_NUMBER: u8 = 3u8;
+    pub const DATA: u8 = 4u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "ant_tx message definition"]
+pub mod ant_tx {
+    #[doc = " * units: s"]
+    pub const FRACTIONAL_TIMESTAMP: u8 = 0u8;
+    pub const MESG_ID: u8 = 1u8;
+    pub const MESG_DATA: u8 = 2u8;
+    pub const CHANNEL_NUMBER: u8 = 3u8;
+    pub const DATA: u8 = 4u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "exd_screen_configuration message definition"]
+pub mod exd_screen_configuration {
+    pub const SCREEN_INDEX: u8 = 0u8;
+    #[doc = " * field_count: number of fields in screen"]
+    pub const FIELD_COUNT: u8 = 1u8;
+    pub const LAYOUT: u8 = 2u8;
+    pub const SCREEN_ENABLED: u8 = 3u8;
+}
+#[doc = "exd_data_field_configuration message definition"]
+pub mod exd_data_field_configuration {
+    pub const SCREEN_INDEX: u8 = 0u8;
+    pub const CONCEPT_FIELD: u8 = 1u8;
+    pub const FIELD_ID: u8 = 2u8;
+    pub const CONCEPT_COUNT: u8 = 3u8;
+    pub const DISPLAY_TYPE: u8 = 4u8;
+    pub const TITLE: u8 = 5u8;
+}
+#[doc = "exd_data_concept_configuration message definition"]
+pub mod exd_data_concept_configuration {
+    pub const SCREEN_INDEX: u8 = 0u8;
+    pub const CONCEPT_FIELD: u8 = 1u8;
+    pub const FIELD_ID: u8 = 2u8;
+    pub const CONCEPT_INDEX: u8 = 3u8;
+    pub const DATA_PAGE: u8 = 4u8;
+    pub const CONCEPT_KEY: u8 = 5u8;
+    pub const SCALING: u8 = 6u8;
+    pub const DATA_UNITS: u8 = 8u8;
+    pub const QUALIFIER: u8 = 9u8;
+    pub const DESCRIPTOR: u8 = 10u8;
+    pub const IS_SIGNED: u8 = 11u8;
+}
+#[doc = "dive_summary message definition"]
+pub mod dive_summary {
+    pub const REFERENCE_MESG: u8 = 0u8;
+    pub const REFERENCE_INDEX: u8 = 1u8;
+    #[doc = " * avg_depth: 0 if above water"]
+    #[doc = " * units: m"]
+    pub const AVG_DEPTH: u8 = 2u8;
+    #[doc = " * max_depth: 0 if above water"]
+    #[doc = " * units: m"]
+    pub const MAX_DEPTH: u8 = 3u8;
+    #[doc = " * surface_interval: Time since end of last dive"]
+    #[doc = " * units: s"]
+    pub const SURFACE_INTERVAL: u8 = 4u8;
+    #[doc = " * units: percent"]
+    pub const START_CNS: u8 = 5u8;
+    #[doc = " * units: percent"]
+    pub const END_CNS: u8 = 6u8;
+    #[doc = " * units: percent"]
+    pub const START_N2: u8 = 7u8;
+    #[doc = " * units: percent"]
+    pub const END_N2: u8 = 8u8;
+    #[doc = " * units: OTUs"]
+    pub const O2_TOXICITY: u8 = 9u8;
+    pub const DIVE_NUMBER: u8 = 10u8;
+    #[doc = " * units: s"]
+    pub const BOTTOM_TIME: u8 = 11u8;
+    #[doc = " * avg_pressure_sac: Average pressure-based surface air consumption"]
+    #[doc = " * units: bar/min"]
+    pub const AVG_PRESSURE_SAC: u8 = 12u8;
+    #[doc = " * avg_volume_sac: Average volumetric surface air consumption"]
+    #[doc = " * units: L/min"]
+    pub const AVG_VOLUME_SAC: u8 = 13u8;
+    #[doc = " * avg_rmv: Average respiratory minute volume"]
+    #[doc = " * units: L/min"]
+    pub const AVG_RMV: u8 = 14u8;
+    #[doc = " * descent_time: Time to reach deepest level stop"]
+    #[doc = " * units: s"]
+    pub const DESCENT_TIME: u8 = 15u8;
+    #[doc = " * ascent_time: Time after leaving bottom until reaching surface"]
+    #[doc = " * units: s"]
+    pub const ASCENT_TIME: u8 = 16u8;
+    #[doc = " * avg_ascent_rate: Average ascent rate, not including descents or stops"]
+    #[doc = " * units: m/s"]
+    pub const AVG_ASCENT_RATE: u8 = 17u8;
+    #[doc = " * avg_descent_rate: Average descent rate, not including ascents or stops"]
+    #[doc = " * units: m/s"]
+    pub const AVG_DESCENT_RATE: u8 = 22u8;
+    #[doc = " * max_ascent_rate: Maximum ascent rate"]
+    #[doc = " * units: m/s"]
+    pub const MAX_ASCENT_RATE: u8 = 23u8;
+    #[doc = " * max_descent_rate: Maximum descent rate"]
+    #[doc = " * units: m/s"]
+    pub const MAX_DESCENT_RATE: u8 = 24u8;
+    #[doc = " * hang_time: Time spent neither ascending nor descending"]
+    #[doc = " * units: s"]
+    pub const HANG_TIME: u8 = 25u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "Number of acclerometer zero crossings summed over the specified time interval"]
+pub mod aad_accel_features {
+    #[doc = " * time: Time interval length in seconds"]
+    #[doc = " * units: s"]
+    pub const TIME: u8 = 0u8;
+    #[doc = " * energy_total: Total accelerometer energy in the interval"]
+    pub const ENERGY_TOTAL: u8 = 1u8;
+    #[doc = " * zero_cross_cnt: Count of zero crossings"]
+    pub const ZERO_CROSS_CNT: u8 = 2u8;
+    #[doc = " * instance: Instance ID of zero crossing algorithm"]
+    pub const INSTANCE: u8 = 3u8;
+    #[doc = " * time_above_threshold: Total accelerometer time above threshold in the interval"]
+    #[doc = " * units: s"]
+    pub const TIME_ABOVE_THRESHOLD: u8 = 4u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "Heart rate variability"]
+pub mod hrv {
+    #[doc = " * time: Time between beats"]
+    #[doc = " * units: s"]
+    pub const TIME: u8 = 0u8;
+}
+#[doc = "Array of heart beat intervals"]
+pub mod beat_intervals {
+    #[doc = " * timestamp_ms: Milliseconds past date_time"]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    #[doc = " * time: Array of millisecond times between beats"]
+    #[doc = " * units: ms"]
+    pub const TIME: u8 = 1u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "hrv_status_summary message definition"]
+pub mod hrv_status_summary {
+    #[doc = " * weekly_average: 7 day RMSSD average over sleep"]
+    #[doc = " * units: ms"]
+    pub const WEEKLY_AVERAGE: u8 = 0u8;
+    #[doc = " * last_night_average: Last night RMSSD average over sleep"]
+    #[doc = " * units: ms"]
+    pub const LAST_NIGHT_AVERAGE: u8 = 1u8;
+    #[doc = " * last_night_5_min_high: 5 minute high RMSSD value over sleep"]
+    #[doc = " * units: ms"]
+    pub const LAST_NIGHT_5_MIN_HIGH: u8 = 2u8;
+    #[doc = " * baseline_low_upper: 3 week baseline, upper boundary of low HRV status"]
+    #[doc = " * units: ms"]
+    pub const BASELINE_LOW_UPPER: u8 = 3u8;
+    #[doc = " * baseline_balanced_lower: 3 week baseline, lower boundary of balanced HRV status"]
+    #[doc = " * units: ms"]
+    pub const BASELINE_BALANCED_LOWER: u8 = 4u8;
+    #[doc = " * baseline_balanced_upper: 3 week baseline, upper boundary of balanced HRV status"]
+    #[doc = " * units: ms"]
+    pub const BASELINE_BALANCED_UPPER: u8 = 5u8;
+    pub const STATUS: u8 = 6u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "hrv_value message definition"]
+pub mod hrv_value {
+    #[doc = " * value: 5 minute RMSSD"]
+    #[doc = " * units: ms"]
+    pub const VALUE: u8 = 0u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "Raw Beat-to-Beat Interval values"]
+pub mod raw_bbi {
+    #[doc = " * timestamp_ms: ms since last overnight_raw_bbi message"]
+    #[doc = " * units: ms"]
+    pub const TIMESTAMP_MS: u8 = 0u8;
+    #[doc = " * data: 1 bit for gap indicator, 1 bit for quality indicator, and 14 bits for Beat-to-Beat interval values in whole-integer millisecond resolution"]
+    pub const DATA: u8 = 1u8;
+    #[doc = " * time: Array of millisecond times between beats"]
+    #[doc = " * units: ms"]
+    pub const TIME: u8 = 2u8;
+    pub const QUALITY: u8 = 3u8;
+    pub const GAP: u8 = 4u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "respiration_rate message definition"]
+pub mod respiration_rate {
+    #[doc = " * respiration_rate: Breaths * 100 /min, -300 indicates invalid, -200 indicates large motion, -100 indicates off wrist"]
+    #[doc = " * units: breaths/min"]
+    pub const RESPIRATION_RATE: u8 = 0u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "Specifically used for XERO products."]
+pub mod chrono_shot_session {
+    #[doc = " * units: m/s"]
+    pub const MIN_SPEED: u8 = 0u8;
+    #[doc = " * units: m/s"]
+    pub const MAX_SPEED: u8 = 1u8;
+    #[doc = " * units: m/s"]
+    pub const AVG_SPEED: u8 = 2u8;
+    pub const SHOT_COUNT: u8 = 3u8;
+    pub const PROJECTILE_TYPE: u8 = 4u8;
+    #[doc = " * units: gr"]
+    pub const GRAIN_WEIGHT: u8 = 5u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "Specifically used for XERO products."]
+pub mod chrono_shot_data {
+    #[doc = " * units: m/s"]
+    pub const SHOT_SPEED: u8 = 0u8;
+    pub const SHOT_NUM: u8 = 1u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "tank_update message definition"]
+pub mod tank_update {
+    pub const SENSOR: u8 = 0u8;
+    #[doc = " * units: bar"]
+    pub const PRESSURE: u8 = 1u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "tank_summary message definition"]
+pub mod tank_summary {
+    pub const SENSOR: u8 = 0u8;
+    #[doc = " * units: bar"]
+    pub const START_PRESSURE: u8 = 1u8;
+    #[doc = " * units: bar"]
+    pub const END_PRESSURE: u8 = 2u8;
+    #[doc = " * units: L"]
+    pub const VOLUME_USED: u8 = 3u8;
+    #[doc = " * units: s"]
+    pub const TIMESTAMP: u8 = 253u8;
+}
+#[doc = "sleep_assessment message definition"]
+pub mod sleep_assessment {
+    #[doc = " * combined_awake_score: Average of awake_time_score and awakenings_count_score. If valid: 0 (worst) to 100 (best). If unknown: FIT_UINT8_INVALID."]
+    pub const COMBINED_AWAKE_SCORE: u8 = 0u8;
+    #[doc = " * awake_time_score: Score that evaluates the total time spent awake between sleep. If valid: 0 (worst) to 100 (best). If unknown: FIT_UINT8_INVALID."]
+    pub const AWAKE_TIME_SCORE: u8 = 1u8;
+    #[doc = " * awakenings_count_score: Score that evaluates the number of awakenings that interrupt sleep. If valid: 0 (worst) to 100 (best). If unknown: FIT_UINT8_INVALID."]
+    pub const AWAKENINGS_COUNT_SCORE: u8 = 2u8;
+    #[doc = " * deep_sleep_score: Score that evaluates the amount of deep sleep. If valid: 0 (worst) to 100 (best). If unknown: FIT_UINT8_INVALID."]
+    pub const DEEP_SLEEP_SCORE: u8 = 3u8;
+    #[doc = " * sleep_duration_score: Score that evaluates the quality of sleep based on sleep stages, heart-rate variability and possible awakenings during the night. If valid: 0 (worst) to 100 (best). If unknown: FIT_UINT8_INVALID."]
+    pub const SLEEP_DURATION_SCORE: u8 = 4u8;
+    #[doc = " * light_sleep_score: Score that evaluates the amount of light sleep. If valid: 0 (worst) to 100 (best). If unknown: FIT_UINT8_INVALID."]
+    pub const LIGHT_SLEEP_SCORE: u8 = 5u8;
+    #[doc = " * overall_sleep_score: Total score that summarizes the overall quality of sleep, combining sleep duration and quality. If valid: 0 (worst) to 100 (best). If unknown: FIT_UINT8_INVALID."]
+    pub const OVERALL_SLEEP_SCORE: u8 = 6u8;
+    #[doc = " * sleep_quality_score: Score that evaluates the quality of sleep based on sleep stages, heart-rate variability and possible awakenings during the night. If valid: 0 (worst) to 100 (best). If unknown: FIT_UINT8_INVALID."]
+    pub const SLEEP_QUALITY_SCORE: u8 = 7u8;
+    #[doc = " * sleep_recovery_score: Score that evaluates stress and recovery during sleep. If valid: 0 (worst) to 100 (best). If unknown: FIT_UINT8_INVALID."]
+    pub const SLEEP_RECOVERY_SCORE: u8 = 8u8;
+    #[doc = " * rem_sleep_score: Score that evaluates the amount of REM sleep. If valid: 0 (worst) to 100 (best). If unknown: FIT_UINT8_INVALID."]
+    pub const REM_SLEEP_SCORE: u8 = 9u8;
+    #[doc = " * sleep_restlessness_score: Score that evaluates the amount of restlessness during sleep. If valid: 0 (worst) to 100 (best). If unknown: FIT_UINT8_INVALID."]
+    pub const SLEEP_RESTLESSNESS_SCORE: u8 = 10u8;
+    #[doc = " * awakenings_count: The number of awakenings during sleep."]
+    pub const AWAKENINGS_COUNT: u8 = 11u8;
+    #[doc = " * interruptions_score: Score that evaluates the sleep interruptions. If valid: 0 (worst) to 100 (best). If unknown: FIT_UINT8_INVALID."]
+    pub const INTERRUPTIONS_SCORE: u8 = 14u8;
+    #[doc = " * average_stress_during_sleep: Excludes stress during awake periods in the sleep window"]
+    pub const AVERAGE_STRESS_DURING_SLEEP: u8 = 15u8;
+}
+#[doc = "skin_temp_overnight message definition"]
+pub mod skin_temp_overnight {
+    pub const LOCAL_TIMESTAMP: u8 = 0u8;
+    #[doc = " * average_deviation: The average overnight deviation from baseline temperature in degrees C"]
+    pub const AVERAGE_DEVIATION: u8 = 1u8;
+    #[doc = " * average_7_day_deviation: The average 7 day overnight deviation from baseline temperature in degrees C"]
+    pub const AVERAGE_7_DAY_DEVIATION: u8 = 2u8;
+    #[doc = " * nightly_value: Final overnight temperature value"]
+    pub const NIGHTLY_VALUE: u8 = 4u8;
+    pub const TIMESTAMP: u8 = 253u8;
+}
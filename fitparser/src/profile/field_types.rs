@@ -1,9 +1,11 @@
 #![allow(missing_docs)]
 #![allow(dead_code)]
+#![allow(clippy::match_like_matches_macro)]
 #![allow(clippy::unreadable_literal)]
 #![doc = "Auto generated profile field types from FIT SDK Release: 21.141.00"]
 #![doc = "Not all of these may be used by the defined set of FIT messages"]
-use serde::{ser::Serializer, Serialize};
+use super::deserialize_variant;
+use serde::{de::Deserializer, ser::Serializer, Deserialize, Serialize};
 use std::{convert, fmt};
 #[doc = r" Describe all possible data types of a field"]
 #[doc = r""]
@@ -599,6 +601,611 @@ impl FieldDataType {
             _ => false,
         }
     }
+    #[doc = "Value of the named variant of an enum type, the reverse of `get_field_variant_as_string`"]
+    pub fn variant_value(self, name: &str) -> Option<i64> {
+        match self {
+            FieldDataType::File => File::variant_value(name),
+            FieldDataType::MesgNum => MesgNum::variant_value(name),
+            FieldDataType::Checksum => Checksum::variant_value(name),
+            FieldDataType::FileFlags => FileFlags::variant_value(name),
+            FieldDataType::MesgCount => MesgCount::variant_value(name),
+            FieldDataType::MessageIndex => MessageIndex::variant_value(name),
+            FieldDataType::DeviceIndex => DeviceIndex::variant_value(name),
+            FieldDataType::Gender => Gender::variant_value(name),
+            FieldDataType::Language => Language::variant_value(name),
+            FieldDataType::LanguageBits0 => LanguageBits0::variant_value(name),
+            FieldDataType::LanguageBits1 => LanguageBits1::variant_value(name),
+            FieldDataType::LanguageBits2 => LanguageBits2::variant_value(name),
+            FieldDataType::LanguageBits3 => LanguageBits3::variant_value(name),
+            FieldDataType::LanguageBits4 => LanguageBits4::variant_value(name),
+            FieldDataType::TimeZone => TimeZone::variant_value(name),
+            FieldDataType::DisplayMeasure => DisplayMeasure::variant_value(name),
+            FieldDataType::DisplayHeart => DisplayHeart::variant_value(name),
+            FieldDataType::DisplayPower => DisplayPower::variant_value(name),
+            FieldDataType::DisplayPosition => DisplayPosition::variant_value(name),
+            FieldDataType::Switch => Switch::variant_value(name),
+            FieldDataType::Sport => Sport::variant_value(name),
+            FieldDataType::SportBits0 => SportBits0::variant_value(name),
+            FieldDataType::SportBits1 => SportBits1::variant_value(name),
+            FieldDataType::SportBits2 => SportBits2::variant_value(name),
+            FieldDataType::SportBits3 => SportBits3::variant_value(name),
+            FieldDataType::SportBits4 => SportBits4::variant_value(name),
+            FieldDataType::SportBits5 => SportBits5::variant_value(name),
+            FieldDataType::SportBits6 => SportBits6::variant_value(name),
+            FieldDataType::SubSport => SubSport::variant_value(name),
+            FieldDataType::SportEvent => SportEvent::variant_value(name),
+            FieldDataType::Activity => Activity::variant_value(name),
+            FieldDataType::Intensity => Intensity::variant_value(name),
+            FieldDataType::SessionTrigger => SessionTrigger::variant_value(name),
+            FieldDataType::AutolapTrigger => AutolapTrigger::variant_value(name),
+            FieldDataType::LapTrigger => LapTrigger::variant_value(name),
+            FieldDataType::TimeMode => TimeMode::variant_value(name),
+            FieldDataType::BacklightMode => BacklightMode::variant_value(name),
+            FieldDataType::DateMode => DateMode::variant_value(name),
+            FieldDataType::BacklightTimeout => BacklightTimeout::variant_value(name),
+            FieldDataType::Event => Event::variant_value(name),
+            FieldDataType::EventType => EventType::variant_value(name),
+            FieldDataType::TimerTrigger => TimerTrigger::variant_value(name),
+            FieldDataType::FitnessEquipmentState => FitnessEquipmentState::variant_value(name),
+            FieldDataType::Tone => Tone::variant_value(name),
+            FieldDataType::Autoscroll => Autoscroll::variant_value(name),
+            FieldDataType::ActivityClass => ActivityClass::variant_value(name),
+            FieldDataType::HrZoneCalc => HrZoneCalc::variant_value(name),
+            FieldDataType::PwrZoneCalc => PwrZoneCalc::variant_value(name),
+            FieldDataType::WktStepDuration => WktStepDuration::variant_value(name),
+            FieldDataType::WktStepTarget => WktStepTarget::variant_value(name),
+            FieldDataType::Goal => Goal::variant_value(name),
+            FieldDataType::GoalRecurrence => GoalRecurrence::variant_value(name),
+            FieldDataType::GoalSource => GoalSource::variant_value(name),
+            FieldDataType::Schedule => Schedule::variant_value(name),
+            FieldDataType::CoursePoint => CoursePoint::variant_value(name),
+            FieldDataType::Manufacturer => Manufacturer::variant_value(name),
+            FieldDataType::GarminProduct => GarminProduct::variant_value(name),
+            FieldDataType::AntplusDeviceType => AntplusDeviceType::variant_value(name),
+            FieldDataType::AntNetwork => AntNetwork::variant_value(name),
+            FieldDataType::WorkoutCapabilities => WorkoutCapabilities::variant_value(name),
+            FieldDataType::BatteryStatus => BatteryStatus::variant_value(name),
+            FieldDataType::HrType => HrType::variant_value(name),
+            FieldDataType::CourseCapabilities => CourseCapabilities::variant_value(name),
+            FieldDataType::Weight => Weight::variant_value(name),
+            FieldDataType::WorkoutHr => WorkoutHr::variant_value(name),
+            FieldDataType::WorkoutPower => WorkoutPower::variant_value(name),
+            FieldDataType::BpStatus => BpStatus::variant_value(name),
+            FieldDataType::UserLocalId => UserLocalId::variant_value(name),
+            FieldDataType::SwimStroke => SwimStroke::variant_value(name),
+            FieldDataType::ActivityType => ActivityType::variant_value(name),
+            FieldDataType::ActivitySubtype => ActivitySubtype::variant_value(name),
+            FieldDataType::ActivityLevel => ActivityLevel::variant_value(name),
+            FieldDataType::Side => Side::variant_value(name),
+            FieldDataType::LeftRightBalance => LeftRightBalance::variant_value(name),
+            FieldDataType::LeftRightBalance100 => LeftRightBalance100::variant_value(name),
+            FieldDataType::LengthType => LengthType::variant_value(name),
+            FieldDataType::DayOfWeek => DayOfWeek::variant_value(name),
+            FieldDataType::ConnectivityCapabilities => {
+                ConnectivityCapabilities::variant_value(name)
+            }
+            FieldDataType::WeatherReport => WeatherReport::variant_value(name),
+            FieldDataType::WeatherStatus => WeatherStatus::variant_value(name),
+            FieldDataType::WeatherSeverity => WeatherSeverity::variant_value(name),
+            FieldDataType::WeatherSevereType => WeatherSevereType::variant_value(name),
+            FieldDataType::StrokeType => StrokeType::variant_value(name),
+            FieldDataType::BodyLocation => BodyLocation::variant_value(name),
+            FieldDataType::SegmentLapStatus => SegmentLapStatus::variant_value(name),
+            FieldDataType::SegmentLeaderboardType => SegmentLeaderboardType::variant_value(name),
+            FieldDataType::SegmentDeleteStatus => SegmentDeleteStatus::variant_value(name),
+            FieldDataType::SegmentSelectionType => SegmentSelectionType::variant_value(name),
+            FieldDataType::SourceType => SourceType::variant_value(name),
+            FieldDataType::LocalDeviceType => LocalDeviceType::variant_value(name),
+            FieldDataType::BleDeviceType => BleDeviceType::variant_value(name),
+            FieldDataType::AntChannelId => AntChannelId::variant_value(name),
+            FieldDataType::DisplayOrientation => DisplayOrientation::variant_value(name),
+            FieldDataType::WorkoutEquipment => WorkoutEquipment::variant_value(name),
+            FieldDataType::WatchfaceMode => WatchfaceMode::variant_value(name),
+            FieldDataType::DigitalWatchfaceLayout => DigitalWatchfaceLayout::variant_value(name),
+            FieldDataType::AnalogWatchfaceLayout => AnalogWatchfaceLayout::variant_value(name),
+            FieldDataType::RiderPositionType => RiderPositionType::variant_value(name),
+            FieldDataType::PowerPhaseType => PowerPhaseType::variant_value(name),
+            FieldDataType::CameraEventType => CameraEventType::variant_value(name),
+            FieldDataType::SensorType => SensorType::variant_value(name),
+            FieldDataType::BikeLightNetworkConfigType => {
+                BikeLightNetworkConfigType::variant_value(name)
+            }
+            FieldDataType::CommTimeoutType => CommTimeoutType::variant_value(name),
+            FieldDataType::CameraOrientationType => CameraOrientationType::variant_value(name),
+            FieldDataType::AttitudeStage => AttitudeStage::variant_value(name),
+            FieldDataType::AttitudeValidity => AttitudeValidity::variant_value(name),
+            FieldDataType::AutoSyncFrequency => AutoSyncFrequency::variant_value(name),
+            FieldDataType::ExdLayout => ExdLayout::variant_value(name),
+            FieldDataType::ExdDisplayType => ExdDisplayType::variant_value(name),
+            FieldDataType::ExdDataUnits => ExdDataUnits::variant_value(name),
+            FieldDataType::ExdQualifiers => ExdQualifiers::variant_value(name),
+            FieldDataType::ExdDescriptors => ExdDescriptors::variant_value(name),
+            FieldDataType::AutoActivityDetect => AutoActivityDetect::variant_value(name),
+            FieldDataType::SupportedExdScreenLayouts => {
+                SupportedExdScreenLayouts::variant_value(name)
+            }
+            FieldDataType::FitBaseType => FitBaseType::variant_value(name),
+            FieldDataType::TurnType => TurnType::variant_value(name),
+            FieldDataType::BikeLightBeamAngleMode => BikeLightBeamAngleMode::variant_value(name),
+            FieldDataType::FitBaseUnit => FitBaseUnit::variant_value(name),
+            FieldDataType::SetType => SetType::variant_value(name),
+            FieldDataType::MaxMetCategory => MaxMetCategory::variant_value(name),
+            FieldDataType::ExerciseCategory => ExerciseCategory::variant_value(name),
+            FieldDataType::BenchPressExerciseName => BenchPressExerciseName::variant_value(name),
+            FieldDataType::CalfRaiseExerciseName => CalfRaiseExerciseName::variant_value(name),
+            FieldDataType::CardioExerciseName => CardioExerciseName::variant_value(name),
+            FieldDataType::CarryExerciseName => CarryExerciseName::variant_value(name),
+            FieldDataType::ChopExerciseName => ChopExerciseName::variant_value(name),
+            FieldDataType::CoreExerciseName => CoreExerciseName::variant_value(name),
+            FieldDataType::CrunchExerciseName => CrunchExerciseName::variant_value(name),
+            FieldDataType::CurlExerciseName => CurlExerciseName::variant_value(name),
+            FieldDataType::DeadliftExerciseName => DeadliftExerciseName::variant_value(name),
+            FieldDataType::FlyeExerciseName => FlyeExerciseName::variant_value(name),
+            FieldDataType::HipRaiseExerciseName => HipRaiseExerciseName::variant_value(name),
+            FieldDataType::HipStabilityExerciseName => {
+                HipStabilityExerciseName::variant_value(name)
+            }
+            FieldDataType::HipSwingExerciseName => HipSwingExerciseName::variant_value(name),
+            FieldDataType::HyperextensionExerciseName => {
+                HyperextensionExerciseName::variant_value(name)
+            }
+            FieldDataType::LateralRaiseExerciseName => {
+                LateralRaiseExerciseName::variant_value(name)
+            }
+            FieldDataType::LegCurlExerciseName => LegCurlExerciseName::variant_value(name),
+            FieldDataType::LegRaiseExerciseName => LegRaiseExerciseName::variant_value(name),
+            FieldDataType::LungeExerciseName => LungeExerciseName::variant_value(name),
+            FieldDataType::OlympicLiftExerciseName => OlympicLiftExerciseName::variant_value(name),
+            FieldDataType::PlankExerciseName => PlankExerciseName::variant_value(name),
+            FieldDataType::PlyoExerciseName => PlyoExerciseName::variant_value(name),
+            FieldDataType::PullUpExerciseName => PullUpExerciseName::variant_value(name),
+            FieldDataType::PushUpExerciseName => PushUpExerciseName::variant_value(name),
+            FieldDataType::RowExerciseName => RowExerciseName::variant_value(name),
+            FieldDataType::ShoulderPressExerciseName => {
+                ShoulderPressExerciseName::variant_value(name)
+            }
+            FieldDataType::ShoulderStabilityExerciseName => {
+                ShoulderStabilityExerciseName::variant_value(name)
+            }
+            FieldDataType::ShrugExerciseName => ShrugExerciseName::variant_value(name),
+            FieldDataType::SitUpExerciseName => SitUpExerciseName::variant_value(name),
+            FieldDataType::SquatExerciseName => SquatExerciseName::variant_value(name),
+            FieldDataType::TotalBodyExerciseName => TotalBodyExerciseName::variant_value(name),
+            FieldDataType::TricepsExtensionExerciseName => {
+                TricepsExtensionExerciseName::variant_value(name)
+            }
+            FieldDataType::WarmUpExerciseName => WarmUpExerciseName::variant_value(name),
+            FieldDataType::RunExerciseName => RunExerciseName::variant_value(name),
+            FieldDataType::WaterType => WaterType::variant_value(name),
+            FieldDataType::TissueModelType => TissueModelType::variant_value(name),
+            FieldDataType::DiveGasStatus => DiveGasStatus::variant_value(name),
+            FieldDataType::DiveAlert => DiveAlert::variant_value(name),
+            FieldDataType::DiveAlarmType => DiveAlarmType::variant_value(name),
+            FieldDataType::DiveBacklightMode => DiveBacklightMode::variant_value(name),
+            FieldDataType::SleepLevel => SleepLevel::variant_value(name),
+            FieldDataType::Spo2MeasurementType => Spo2MeasurementType::variant_value(name),
+            FieldDataType::CcrSetpointSwitchMode => CcrSetpointSwitchMode::variant_value(name),
+            FieldDataType::DiveGasMode => DiveGasMode::variant_value(name),
+            FieldDataType::ProjectileType => ProjectileType::variant_value(name),
+            FieldDataType::FaveroProduct => FaveroProduct::variant_value(name),
+            FieldDataType::SplitType => SplitType::variant_value(name),
+            FieldDataType::ClimbProEvent => ClimbProEvent::variant_value(name),
+            FieldDataType::GasConsumptionRateType => GasConsumptionRateType::variant_value(name),
+            FieldDataType::TapSensitivity => TapSensitivity::variant_value(name),
+            FieldDataType::RadarThreatLevelType => RadarThreatLevelType::variant_value(name),
+            FieldDataType::MaxMetSpeedSource => MaxMetSpeedSource::variant_value(name),
+            FieldDataType::MaxMetHeartRateSource => MaxMetHeartRateSource::variant_value(name),
+            FieldDataType::HrvStatus => HrvStatus::variant_value(name),
+            FieldDataType::NoFlyTimeMode => NoFlyTimeMode::variant_value(name),
+            _ => None,
+        }
+    }
+    #[doc = " Name of the type in the FIT profile, e.g. `sport`, stable across releases even when"]
+    #[doc = " the Rust identifier of the type changes"]
+    pub fn type_name(self) -> &'static str {
+        match self {
+            FieldDataType::Bool => "bool",
+            FieldDataType::SInt8 => "sint8",
+            FieldDataType::UInt8 => "uint8",
+            FieldDataType::SInt16 => "sint16",
+            FieldDataType::UInt16 => "uint16",
+            FieldDataType::SInt32 => "sint32",
+            FieldDataType::UInt32 => "uint32",
+            FieldDataType::String => "string",
+            FieldDataType::Float32 => "float32",
+            FieldDataType::Float64 => "float64",
+            FieldDataType::UInt8z => "uint8z",
+            FieldDataType::UInt16z => "uint16z",
+            FieldDataType::UInt32z => "uint32z",
+            FieldDataType::Byte => "byte",
+            FieldDataType::SInt64 => "sint64",
+            FieldDataType::UInt64 => "uint64",
+            FieldDataType::UInt64z => "uint64z",
+            FieldDataType::File => "file",
+            FieldDataType::MesgNum => "mesg_num",
+            FieldDataType::Checksum => "checksum",
+            FieldDataType::FileFlags => "file_flags",
+            FieldDataType::MesgCount => "mesg_count",
+            FieldDataType::DateTime => "date_time",
+            FieldDataType::LocalDateTime => "local_date_time",
+            FieldDataType::MessageIndex => "message_index",
+            FieldDataType::DeviceIndex => "device_index",
+            FieldDataType::Gender => "gender",
+            FieldDataType::Language => "language",
+            FieldDataType::LanguageBits0 => "language_bits_0",
+            FieldDataType::LanguageBits1 => "language_bits_1",
+            FieldDataType::LanguageBits2 => "language_bits_2",
+            FieldDataType::LanguageBits3 => "language_bits_3",
+            FieldDataType::LanguageBits4 => "language_bits_4",
+            FieldDataType::TimeZone => "time_zone",
+            FieldDataType::DisplayMeasure => "display_measure",
+            FieldDataType::DisplayHeart => "display_heart",
+            FieldDataType::DisplayPower => "display_power",
+            FieldDataType::DisplayPosition => "display_position",
+            FieldDataType::Switch => "switch",
+            FieldDataType::Sport => "sport",
+            FieldDataType::SportBits0 => "sport_bits_0",
+            FieldDataType::SportBits1 => "sport_bits_1",
+            FieldDataType::SportBits2 => "sport_bits_2",
+            FieldDataType::SportBits3 => "sport_bits_3",
+            FieldDataType::SportBits4 => "sport_bits_4",
+            FieldDataType::SportBits5 => "sport_bits_5",
+            FieldDataType::SportBits6 => "sport_bits_6",
+            FieldDataType::SubSport => "sub_sport",
+            FieldDataType::SportEvent => "sport_event",
+            FieldDataType::Activity => "activity",
+            FieldDataType::Intensity => "intensity",
+            FieldDataType::SessionTrigger => "session_trigger",
+            FieldDataType::AutolapTrigger => "autolap_trigger",
+            FieldDataType::LapTrigger => "lap_trigger",
+            FieldDataType::TimeMode => "time_mode",
+            FieldDataType::BacklightMode => "backlight_mode",
+            FieldDataType::DateMode => "date_mode",
+            FieldDataType::BacklightTimeout => "backlight_timeout",
+            FieldDataType::Event => "event",
+            FieldDataType::EventType => "event_type",
+            FieldDataType::TimerTrigger => "timer_trigger",
+            FieldDataType::FitnessEquipmentState => "fitness_equipment_state",
+            FieldDataType::Tone => "tone",
+            FieldDataType::Autoscroll => "autoscroll",
+            FieldDataType::ActivityClass => "activity_class",
+            FieldDataType::HrZoneCalc => "hr_zone_calc",
+            FieldDataType::PwrZoneCalc => "pwr_zone_calc",
+            FieldDataType::WktStepDuration => "wkt_step_duration",
+            FieldDataType::WktStepTarget => "wkt_step_target",
+            FieldDataType::Goal => "goal",
+            FieldDataType::GoalRecurrence => "goal_recurrence",
+            FieldDataType::GoalSource => "goal_source",
+            FieldDataType::Schedule => "schedule",
+            FieldDataType::CoursePoint => "course_point",
+            FieldDataType::Manufacturer => "manufacturer",
+            FieldDataType::GarminProduct => "garmin_product",
+            FieldDataType::AntplusDeviceType => "antplus_device_type",
+            FieldDataType::AntNetwork => "ant_network",
+            FieldDataType::WorkoutCapabilities => "workout_capabilities",
+            FieldDataType::BatteryStatus => "battery_status",
+            FieldDataType::HrType => "hr_type",
+            FieldDataType::CourseCapabilities => "course_capabilities",
+            FieldDataType::Weight => "weight",
+            FieldDataType::WorkoutHr => "workout_hr",
+            FieldDataType::WorkoutPower => "workout_power",
+            FieldDataType::BpStatus => "bp_status",
+            FieldDataType::UserLocalId => "user_local_id",
+            FieldDataType::SwimStroke => "swim_stroke",
+            FieldDataType::ActivityType => "activity_type",
+            FieldDataType::ActivitySubtype => "activity_subtype",
+            FieldDataType::ActivityLevel => "activity_level",
+            FieldDataType::Side => "side",
+            FieldDataType::LeftRightBalance => "left_right_balance",
+            FieldDataType::LeftRightBalance100 => "left_right_balance_100",
+            FieldDataType::LengthType => "length_type",
+            FieldDataType::DayOfWeek => "day_of_week",
+            FieldDataType::ConnectivityCapabilities => "connectivity_capabilities",
+            FieldDataType::WeatherReport => "weather_report",
+            FieldDataType::WeatherStatus => "weather_status",
+            FieldDataType::WeatherSeverity => "weather_severity",
+            FieldDataType::WeatherSevereType => "weather_severe_type",
+            FieldDataType::TimeIntoDay => "time_into_day",
+            FieldDataType::LocaltimeIntoDay => "localtime_into_day",
+            FieldDataType::StrokeType => "stroke_type",
+            FieldDataType::BodyLocation => "body_location",
+            FieldDataType::SegmentLapStatus => "segment_lap_status",
+            FieldDataType::SegmentLeaderboardType => "segment_leaderboard_type",
+            FieldDataType::SegmentDeleteStatus => "segment_delete_status",
+            FieldDataType::SegmentSelectionType => "segment_selection_type",
+            FieldDataType::SourceType => "source_type",
+            FieldDataType::LocalDeviceType => "local_device_type",
+            FieldDataType::BleDeviceType => "ble_device_type",
+            FieldDataType::AntChannelId => "ant_channel_id",
+            FieldDataType::DisplayOrientation => "display_orientation",
+            FieldDataType::WorkoutEquipment => "workout_equipment",
+            FieldDataType::WatchfaceMode => "watchface_mode",
+            FieldDataType::DigitalWatchfaceLayout => "digital_watchface_layout",
+            FieldDataType::AnalogWatchfaceLayout => "analog_watchface_layout",
+            FieldDataType::RiderPositionType => "rider_position_type",
+            FieldDataType::PowerPhaseType => "power_phase_type",
+            FieldDataType::CameraEventType => "camera_event_type",
+            FieldDataType::SensorType => "sensor_type",
+            FieldDataType::BikeLightNetworkConfigType => "bike_light_network_config_type",
+            FieldDataType::CommTimeoutType => "comm_timeout_type",
+            FieldDataType::CameraOrientationType => "camera_orientation_type",
+            FieldDataType::AttitudeStage => "attitude_stage",
+            FieldDataType::AttitudeValidity => "attitude_validity",
+            FieldDataType::AutoSyncFrequency => "auto_sync_frequency",
+            FieldDataType::ExdLayout => "exd_layout",
+            FieldDataType::ExdDisplayType => "exd_display_type",
+            FieldDataType::ExdDataUnits => "exd_data_units",
+            FieldDataType::ExdQualifiers => "exd_qualifiers",
+            FieldDataType::ExdDescriptors => "exd_descriptors",
+            FieldDataType::AutoActivityDetect => "auto_activity_detect",
+            FieldDataType::SupportedExdScreenLayouts => "supported_exd_screen_layouts",
+            FieldDataType::FitBaseType => "fit_base_type",
+            FieldDataType::TurnType => "turn_type",
+            FieldDataType::BikeLightBeamAngleMode => "bike_light_beam_angle_mode",
+            FieldDataType::FitBaseUnit => "fit_base_unit",
+            FieldDataType::SetType => "set_type",
+            FieldDataType::MaxMetCategory => "max_met_category",
+            FieldDataType::ExerciseCategory => "exercise_category",
+            FieldDataType::BenchPressExerciseName => "bench_press_exercise_name",
+            FieldDataType::CalfRaiseExerciseName => "calf_raise_exercise_name",
+            FieldDataType::CardioExerciseName => "cardio_exercise_name",
+            FieldDataType::CarryExerciseName => "carry_exercise_name",
+            FieldDataType::ChopExerciseName => "chop_exercise_name",
+            FieldDataType::CoreExerciseName => "core_exercise_name",
+            FieldDataType::CrunchExerciseName => "crunch_exercise_name",
+            FieldDataType::CurlExerciseName => "curl_exercise_name",
+            FieldDataType::DeadliftExerciseName => "deadlift_exercise_name",
+            FieldDataType::FlyeExerciseName => "flye_exercise_name",
+            FieldDataType::HipRaiseExerciseName => "hip_raise_exercise_name",
+            FieldDataType::HipStabilityExerciseName => "hip_stability_exercise_name",
+            FieldDataType::HipSwingExerciseName => "hip_swing_exercise_name",
+            FieldDataType::HyperextensionExerciseName => "hyperextension_exercise_name",
+            FieldDataType::LateralRaiseExerciseName => "lateral_raise_exercise_name",
+            FieldDataType::LegCurlExerciseName => "leg_curl_exercise_name",
+            FieldDataType::LegRaiseExerciseName => "leg_raise_exercise_name",
+            FieldDataType::LungeExerciseName => "lunge_exercise_name",
+            FieldDataType::OlympicLiftExerciseName => "olympic_lift_exercise_name",
+            FieldDataType::PlankExerciseName => "plank_exercise_name",
+            FieldDataType::PlyoExerciseName => "plyo_exercise_name",
+            FieldDataType::PullUpExerciseName => "pull_up_exercise_name",
+            FieldDataType::PushUpExerciseName => "push_up_exercise_name",
+            FieldDataType::RowExerciseName => "row_exercise_name",
+            FieldDataType::ShoulderPressExerciseName => "shoulder_press_exercise_name",
+            FieldDataType::ShoulderStabilityExerciseName => "shoulder_stability_exercise_name",
+            FieldDataType::ShrugExerciseName => "shrug_exercise_name",
+            FieldDataType::SitUpExerciseName => "sit_up_exercise_name",
+            FieldDataType::SquatExerciseName => "squat_exercise_name",
+            FieldDataType::TotalBodyExerciseName => "total_body_exercise_name",
+            FieldDataType::TricepsExtensionExerciseName => "triceps_extension_exercise_name",
+            FieldDataType::WarmUpExerciseName => "warm_up_exercise_name",
+            FieldDataType::RunExerciseName => "run_exercise_name",
+            FieldDataType::WaterType => "water_type",
+            FieldDataType::TissueModelType => "tissue_model_type",
+            FieldDataType::DiveGasStatus => "dive_gas_status",
+            FieldDataType::DiveAlert => "dive_alert",
+            FieldDataType::DiveAlarmType => "dive_alarm_type",
+            FieldDataType::DiveBacklightMode => "dive_backlight_mode",
+            FieldDataType::SleepLevel => "sleep_level",
+            FieldDataType::Spo2MeasurementType => "spo2_measurement_type",
+            FieldDataType::CcrSetpointSwitchMode => "ccr_setpoint_switch_mode",
+            FieldDataType::DiveGasMode => "dive_gas_mode",
+            FieldDataType::ProjectileType => "projectile_type",
+            FieldDataType::FaveroProduct => "favero_product",
+            FieldDataType::SplitType => "split_type",
+            FieldDataType::ClimbProEvent => "climb_pro_event",
+            FieldDataType::GasConsumptionRateType => "gas_consumption_rate_type",
+            FieldDataType::TapSensitivity => "tap_sensitivity",
+            FieldDataType::RadarThreatLevelType => "radar_threat_level_type",
+            FieldDataType::MaxMetSpeedSource => "max_met_speed_source",
+            FieldDataType::MaxMetHeartRateSource => "max_met_heart_rate_source",
+            FieldDataType::HrvStatus => "hrv_status",
+            FieldDataType::NoFlyTimeMode => "no_fly_time_mode",
+        }
+    }
+    #[doc = " Look up a type by its name in the FIT profile"]
+    pub fn from_type_name(name: &str) -> Option<Self> {
+        match name {
+            "bool" => Some(FieldDataType::Bool),
+            "sint8" => Some(FieldDataType::SInt8),
+            "uint8" => Some(FieldDataType::UInt8),
+            "sint16" => Some(FieldDataType::SInt16),
+            "uint16" => Some(FieldDataType::UInt16),
+            "sint32" => Some(FieldDataType::SInt32),
+            "uint32" => Some(FieldDataType::UInt32),
+            "string" => Some(FieldDataType::String),
+            "float32" => Some(FieldDataType::Float32),
+            "float64" => Some(FieldDataType::Float64),
+            "uint8z" => Some(FieldDataType::UInt8z),
+            "uint16z" => Some(FieldDataType::UInt16z),
+            "uint32z" => Some(FieldDataType::UInt32z),
+            "byte" => Some(FieldDataType::Byte),
+            "sint64" => Some(FieldDataType::SInt64),
+            "uint64" => Some(FieldDataType::UInt64),
+            "uint64z" => Some(FieldDataType::UInt64z),
+            "file" => Some(FieldDataType::File),
+            "mesg_num" => Some(FieldDataType::MesgNum),
+            "checksum" => Some(FieldDataType::Checksum),
+            "file_flags" => Some(FieldDataType::FileFlags),
+            "mesg_count" => Some(FieldDataType::MesgCount),
+            "date_time" => Some(FieldDataType::DateTime),
+            "local_date_time" => Some(FieldDataType::LocalDateTime),
+            "message_index" => Some(FieldDataType::MessageIndex),
+            "device_index" => Some(FieldDataType::DeviceIndex),
+            "gender" => Some(FieldDataType::Gender),
+            "language" => Some(FieldDataType::Language),
+            "language_bits_0" => Some(FieldDataType::LanguageBits0),
+            "language_bits_1" => Some(FieldDataType::LanguageBits1),
+            "language_bits_2" => Some(FieldDataType::LanguageBits2),
+            "language_bits_3" => Some(FieldDataType::LanguageBits3),
+            "language_bits_4" => Some(FieldDataType::LanguageBits4),
+            "time_zone" => Some(FieldDataType::TimeZone),
+            "display_measure" => Some(FieldDataType::DisplayMeasure),
+            "display_heart" => Some(FieldDataType::DisplayHeart),
+            "display_power" => Some(FieldDataType::DisplayPower),
+            "display_position" => Some(FieldDataType::DisplayPosition),
+            "switch" => Some(FieldDataType::Switch),
+            "sport" => Some(FieldDataType::Sport),
+            "sport_bits_0" => Some(FieldDataType::SportBits0),
+            "sport_bits_1" => Some(FieldDataType::SportBits1),
+            "sport_bits_2" => Some(FieldDataType::SportBits2),
+            "sport_bits_3" => Some(FieldDataType::SportBits3),
+            "sport_bits_4" => Some(FieldDataType::SportBits4),
+            "sport_bits_5" => Some(FieldDataType::SportBits5),
+            "sport_bits_6" => Some(FieldDataType::SportBits6),
+            "sub_sport" => Some(FieldDataType::SubSport),
+            "sport_event" => Some(FieldDataType::SportEvent),
+            "activity" => Some(FieldDataType::Activity),
+            "intensity" => Some(FieldDataType::Intensity),
+            "session_trigger" => Some(FieldDataType::SessionTrigger),
+            "autolap_trigger" => Some(FieldDataType::AutolapTrigger),
+            "lap_trigger" => Some(FieldDataType::LapTrigger),
+            "time_mode" => Some(FieldDataType::TimeMode),
+            "backlight_mode" => Some(FieldDataType::BacklightMode),
+            "date_mode" => Some(FieldDataType::DateMode),
+            "backlight_timeout" => Some(FieldDataType::BacklightTimeout),
+            "event" => Some(FieldDataType::Event),
+            "event_type" => Some(FieldDataType::EventType),
+            "timer_trigger" => Some(FieldDataType::TimerTrigger),
+            "fitness_equipment_state" => Some(FieldDataType::FitnessEquipmentState),
+            "tone" => Some(FieldDataType::Tone),
+            "autoscroll" => Some(FieldDataType::Autoscroll),
+            "activity_class" => Some(FieldDataType::ActivityClass),
+            "hr_zone_calc" => Some(FieldDataType::HrZoneCalc),
+            "pwr_zone_calc" => Some(FieldDataType::PwrZoneCalc),
+            "wkt_step_duration" => Some(FieldDataType::WktStepDuration),
+            "wkt_step_target" => Some(FieldDataType::WktStepTarget),
+            "goal" => Some(FieldDataType::Goal),
+            "goal_recurrence" => Some(FieldDataType::GoalRecurrence),
+            "goal_source" => Some(FieldDataType::GoalSource),
+            "schedule" => Some(FieldDataType::Schedule),
+            "course_point" => Some(FieldDataType::CoursePoint),
+            "manufacturer" => Some(FieldDataType::Manufacturer),
+            "garmin_product" => Some(FieldDataType::GarminProduct),
+            "antplus_device_type" => Some(FieldDataType::AntplusDeviceType),
+            "ant_network" => Some(FieldDataType::AntNetwork),
+            "workout_capabilities" => Some(FieldDataType::WorkoutCapabilities),
+            "battery_status" => Some(FieldDataType::BatteryStatus),
+            "hr_type" => Some(FieldDataType::HrType),
+            "course_capabilities" => Some(FieldDataType::CourseCapabilities),
+            "weight" => Some(FieldDataType::Weight),
+            "workout_hr" => Some(FieldDataType::WorkoutHr),
+            "workout_power" => Some(FieldDataType::WorkoutPower),
+            "bp_status" => Some(FieldDataType::BpStatus),
+            "user_local_id" => Some(FieldDataType::UserLocalId),
+            "swim_stroke" => Some(FieldDataType::SwimStroke),
+            "activity_type" => Some(FieldDataType::ActivityType),
+            "activity_subtype" => Some(FieldDataType::ActivitySubtype),
+            "activity_level" => Some(FieldDataType::ActivityLevel),
+            "side" => Some(FieldDataType::Side),
+            "left_right_balance" => Some(FieldDataType::LeftRightBalance),
+            "left_right_balance_100" => Some(FieldDataType::LeftRightBalance100),
+            "length_type" => Some(FieldDataType::LengthType),
+            "day_of_week" => Some(FieldDataType::DayOfWeek),
+            "connectivity_capabilities" => Some(FieldDataType::ConnectivityCapabilities),
+            "weather_report" => Some(FieldDataType::WeatherReport),
+            "weather_status" => Some(FieldDataType::WeatherStatus),
+            "weather_severity" => Some(FieldDataType::WeatherSeverity),
+            "weather_severe_type" => Some(FieldDataType::WeatherSevereType),
+            "time_into_day" => Some(FieldDataType::TimeIntoDay),
+            "localtime_into_day" => Some(FieldDataType::LocaltimeIntoDay),
+            "stroke_type" => Some(FieldDataType::StrokeType),
+            "body_location" => Some(FieldDataType::BodyLocation),
+            "segment_lap_status" => Some(FieldDataType::SegmentLapStatus),
+            "segment_leaderboard_type" => Some(FieldDataType::SegmentLeaderboardType),
+            "segment_delete_status" => Some(FieldDataType::SegmentDeleteStatus),
+            "segment_selection_type" => Some(FieldDataType::SegmentSelectionType),
+            "source_type" => Some(FieldDataType::SourceType),
+            "local_device_type" => Some(FieldDataType::LocalDeviceType),
+            "ble_device_type" => Some(FieldDataType::BleDeviceType),
+            "ant_channel_id" => Some(FieldDataType::AntChannelId),
+            "display_orientation" => Some(FieldDataType::DisplayOrientation),
+            "workout_equipment" => Some(FieldDataType::WorkoutEquipment),
+            "watchface_mode" => Some(FieldDataType::WatchfaceMode),
+            "digital_watchface_layout" => Some(FieldDataType::DigitalWatchfaceLayout),
+            "analog_watchface_layout" => Some(FieldDataType::AnalogWatchfaceLayout),
+            "rider_position_type" => Some(FieldDataType::RiderPositionType),
+            "power_phase_type" => Some(FieldDataType::PowerPhaseType),
+            "camera_event_type" => Some(FieldDataType::CameraEventType),
+            "sensor_type" => Some(FieldDataType::SensorType),
+            "bike_light_network_config_type" => Some(FieldDataType::BikeLightNetworkConfigType),
+            "comm_timeout_type" => Some(FieldDataType::CommTimeoutType),
+            "camera_orientation_type" => Some(FieldDataType::CameraOrientationType),
+            "attitude_stage" => Some(FieldDataType::AttitudeStage),
+            "attitude_validity" => Some(FieldDataType::AttitudeValidity),
+            "auto_sync_frequency" => Some(FieldDataType::AutoSyncFrequency),
+            "exd_layout" => Some(FieldDataType::ExdLayout),
+            "exd_display_type" => Some(FieldDataType::ExdDisplayType),
+            "exd_data_units" => Some(FieldDataType::ExdDataUnits),
+            "exd_qualifiers" => Some(FieldDataType::ExdQualifiers),
+            "exd_descriptors" => Some(FieldDataType::ExdDescriptors),
+            "auto_activity_detect" => Some(FieldDataType::AutoActivityDetect),
+            "supported_exd_screen_layouts" => Some(FieldDataType::SupportedExdScreenLayouts),
+            "fit_base_type" => Some(FieldDataType::FitBaseType),
+            "turn_type" => Some(FieldDataType::TurnType),
+            "bike_light_beam_angle_mode" => Some(FieldDataType::BikeLightBeamAngleMode),
+            "fit_base_unit" => Some(FieldDataType::FitBaseUnit),
+            "set_type" => Some(FieldDataType::SetType),
+            "max_met_category" => Some(FieldDataType::MaxMetCategory),
+            "exercise_category" => Some(FieldDataType::ExerciseCategory),
+            "bench_press_exercise_name" => Some(FieldDataType::BenchPressExerciseName),
+            "calf_raise_exercise_name" => Some(FieldDataType::CalfRaiseExerciseName),
+            "cardio_exercise_name" => Some(FieldDataType::CardioExerciseName),
+            "carry_exercise_name" => Some(FieldDataType::CarryExerciseName),
+            "chop_exercise_name" => Some(FieldDataType::ChopExerciseName),
+            "core_exercise_name" => Some(FieldDataType::CoreExerciseName),
+            "crunch_exercise_name" => Some(FieldDataType::CrunchExerciseName),
+            "curl_exercise_name" => Some(FieldDataType::CurlExerciseName),
+            "deadlift_exercise_name" => Some(FieldDataType::DeadliftExerciseName),
+            "flye_exercise_name" => Some(FieldDataType::FlyeExerciseName),
+            "hip_raise_exercise_name" => Some(FieldDataType::HipRaiseExerciseName),
+            "hip_stability_exercise_name" => Some(FieldDataType::HipStabilityExerciseName),
+            "hip_swing_exercise_name" => Some(FieldDataType::HipSwingExerciseName),
+            "hyperextension_exercise_name" => Some(FieldDataType::HyperextensionExerciseName),
+            "lateral_raise_exercise_name" => Some(FieldDataType::LateralRaiseExerciseName),
+            "leg_curl_exercise_name" => Some(FieldDataType::LegCurlExerciseName),
+            "leg_raise_exercise_name" => Some(FieldDataType::LegRaiseExerciseName),
+            "lunge_exercise_name" => Some(FieldDataType::LungeExerciseName),
+            "olympic_lift_exercise_name" => Some(FieldDataType::OlympicLiftExerciseName),
+            "plank_exercise_name" => Some(FieldDataType::PlankExerciseName),
+            "plyo_exercise_name" => Some(FieldDataType::PlyoExerciseName),
+            "pull_up_exercise_name" => Some(FieldDataType::PullUpExerciseName),
+            "push_up_exercise_name" => Some(FieldDataType::PushUpExerciseName),
+            "row_exercise_name" => Some(FieldDataType::RowExerciseName),
+            "shoulder_press_exercise_name" => Some(FieldDataType::ShoulderPressExerciseName),
+            "shoulder_stability_exercise_name" => {
+                Some(FieldDataType::ShoulderStabilityExerciseName)
+            }
+            "shrug_exercise_name" => Some(FieldDataType::ShrugExerciseName),
+            "sit_up_exercise_name" => Some(FieldDataType::SitUpExerciseName),
+            "squat_exercise_name" => Some(FieldDataType::SquatExerciseName),
+            "total_body_exercise_name" => Some(FieldDataType::TotalBodyExerciseName),
+            "triceps_extension_exercise_name" => Some(FieldDataType::TricepsExtensionExerciseName),
+            "warm_up_exercise_name" => Some(FieldDataType::WarmUpExerciseName),
+            "run_exercise_name" => Some(FieldDataType::RunExerciseName),
+            "water_type" => Some(FieldDataType::WaterType),
+            "tissue_model_type" => Some(FieldDataType::TissueModelType),
+            "dive_gas_status" => Some(FieldDataType::DiveGasStatus),
+            "dive_alert" => Some(FieldDataType::DiveAlert),
+            "dive_alarm_type" => Some(FieldDataType::DiveAlarmType),
+            "dive_backlight_mode" => Some(FieldDataType::DiveBacklightMode),
+            "sleep_level" => Some(FieldDataType::SleepLevel),
+            "spo2_measurement_type" => Some(FieldDataType::Spo2MeasurementType),
+            "ccr_setpoint_switch_mode" => Some(FieldDataType::CcrSetpointSwitchMode),
+            "dive_gas_mode" => Some(FieldDataType::DiveGasMode),
+            "projectile_type" => Some(FieldDataType::ProjectileType),
+            "favero_product" => Some(FieldDataType::FaveroProduct),
+            "split_type" => Some(FieldDataType::SplitType),
+            "climb_pro_event" => Some(FieldDataType::ClimbProEvent),
+            "gas_consumption_rate_type" => Some(FieldDataType::GasConsumptionRateType),
+            "tap_sensitivity" => Some(FieldDataType::TapSensitivity),
+            "radar_threat_level_type" => Some(FieldDataType::RadarThreatLevelType),
+            "max_met_speed_source" => Some(FieldDataType::MaxMetSpeedSource),
+            "max_met_heart_rate_source" => Some(FieldDataType::MaxMetHeartRateSource),
+            "hrv_status" => Some(FieldDataType::HrvStatus),
+            "no_fly_time_mode" => Some(FieldDataType::NoFlyTimeMode),
+            _ => None,
+        }
+    }
 }
 pub fn get_field_variant_as_string(field_type: FieldDataType, value: i64) -> String {
     match field_type {
@@ -867,6 +1474,31 @@ impl File {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "device" => Some(1i64),
+            "settings" => Some(2i64),
+            "sport" => Some(3i64),
+            "activity" => Some(4i64),
+            "workout" => Some(5i64),
+            "course" => Some(6i64),
+            "schedules" => Some(7i64),
+            "weight" => Some(9i64),
+            "totals" => Some(10i64),
+            "goals" => Some(11i64),
+            "blood_pressure" => Some(14i64),
+            "monitoring_a" => Some(15i64),
+            "activity_summary" => Some(20i64),
+            "monitoring_daily" => Some(28i64),
+            "monitoring_b" => Some(32i64),
+            "segment" => Some(34i64),
+            "segment_list" => Some(35i64),
+            "exd_configuration" => Some(40i64),
+            "mfg_range_min" => Some(247i64),
+            "mfg_range_max" => Some(254i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             File::Device => 1,
@@ -895,6 +1527,33 @@ impl File {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            File::Device => 1,
+            File::Settings => 2,
+            File::Sport => 3,
+            File::Activity => 4,
+            File::Workout => 5,
+            File::Course => 6,
+            File::Schedules => 7,
+            File::Weight => 9,
+            File::Totals => 10,
+            File::Goals => 11,
+            File::BloodPressure => 14,
+            File::MonitoringA => 15,
+            File::ActivitySummary => 20,
+            File::MonitoringDaily => 28,
+            File::MonitoringB => 32,
+            File::Segment => 34,
+            File::SegmentList => 35,
+            File::ExdConfiguration => 40,
+            File::MfgRangeMin => 247,
+            File::MfgRangeMax => 254,
+            File::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for File {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -963,6 +1622,15 @@ impl Serialize for File {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for File {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::File, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum MesgNum {
     FileId,
@@ -1220,6 +1888,133 @@ impl MesgNum {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "file_id" => Some(0i64),
+            "capabilities" => Some(1i64),
+            "device_settings" => Some(2i64),
+            "user_profile" => Some(3i64),
+            "hrm_profile" => Some(4i64),
+            "sdm_profile" => Some(5i64),
+            "bike_profile" => Some(6i64),
+            "zones_target" => Some(7i64),
+            "hr_zone" => Some(8i64),
+            "power_zone" => Some(9i64),
+            "met_zone" => Some(10i64),
+            "sport" => Some(12i64),
+            "goal" => Some(15i64),
+            "session" => Some(18i64),
+            "lap" => Some(19i64),
+            "record" => Some(20i64),
+            "event" => Some(21i64),
+            "device_info" => Some(23i64),
+            "workout" => Some(26i64),
+            "workout_step" => Some(27i64),
+            "schedule" => Some(28i64),
+            "weight_scale" => Some(30i64),
+            "course" => Some(31i64),
+            "course_point" => Some(32i64),
+            "totals" => Some(33i64),
+            "activity" => Some(34i64),
+            "software" => Some(35i64),
+            "file_capabilities" => Some(37i64),
+            "mesg_capabilities" => Some(38i64),
+            "field_capabilities" => Some(39i64),
+            "file_creator" => Some(49i64),
+            "blood_pressure" => Some(51i64),
+            "speed_zone" => Some(53i64),
+            "monitoring" => Some(55i64),
+            "training_file" => Some(72i64),
+            "hrv" => Some(78i64),
+            "ant_rx" => Some(80i64),
+            "ant_tx" => Some(81i64),
+            "ant_channel_id" => Some(82i64),
+            "length" => Some(101i64),
+            "monitoring_info" => Some(103i64),
+            "pad" => Some(105i64),
+            "slave_device" => Some(106i64),
+            "connectivity" => Some(127i64),
+            "weather_conditions" => Some(128i64),
+            "weather_alert" => Some(129i64),
+            "cadence_zone" => Some(131i64),
+            "hr" => Some(132i64),
+            "segment_lap" => Some(142i64),
+            "memo_glob" => Some(145i64),
+            "segment_id" => Some(148i64),
+            "segment_leaderboard_entry" => Some(149i64),
+            "segment_point" => Some(150i64),
+            "segment_file" => Some(151i64),
+            "workout_session" => Some(158i64),
+            "watchface_settings" => Some(159i64),
+            "gps_metadata" => Some(160i64),
+            "camera_event" => Some(161i64),
+            "timestamp_correlation" => Some(162i64),
+            "gyroscope_data" => Some(164i64),
+            "accelerometer_data" => Some(165i64),
+            "three_d_sensor_calibration" => Some(167i64),
+            "video_frame" => Some(169i64),
+            "obdii_data" => Some(174i64),
+            "nmea_sentence" => Some(177i64),
+            "aviation_attitude" => Some(178i64),
+            "video" => Some(184i64),
+            "video_title" => Some(185i64),
+            "video_description" => Some(186i64),
+            "video_clip" => Some(187i64),
+            "ohr_settings" => Some(188i64),
+            "exd_screen_configuration" => Some(200i64),
+            "exd_data_field_configuration" => Some(201i64),
+            "exd_data_concept_configuration" => Some(202i64),
+            "field_description" => Some(206i64),
+            "developer_data_id" => Some(207i64),
+            "magnetometer_data" => Some(208i64),
+            "barometer_data" => Some(209i64),
+            "one_d_sensor_calibration" => Some(210i64),
+            "monitoring_hr_data" => Some(211i64),
+            "time_in_zone" => Some(216i64),
+            "set" => Some(225i64),
+            "stress_level" => Some(227i64),
+            "max_met_data" => Some(229i64),
+            "dive_settings" => Some(258i64),
+            "dive_gas" => Some(259i64),
+            "dive_alarm" => Some(262i64),
+            "exercise_title" => Some(264i64),
+            "dive_summary" => Some(268i64),
+            "spo2_data" => Some(269i64),
+            "sleep_level" => Some(275i64),
+            "jump" => Some(285i64),
+            "aad_accel_features" => Some(289i64),
+            "beat_intervals" => Some(290i64),
+            "respiration_rate" => Some(297i64),
+            "hsa_accelerometer_data" => Some(302i64),
+            "hsa_step_data" => Some(304i64),
+            "hsa_spo2_data" => Some(305i64),
+            "hsa_stress_data" => Some(306i64),
+            "hsa_respiration_data" => Some(307i64),
+            "hsa_heart_rate_data" => Some(308i64),
+            "split" => Some(312i64),
+            "split_summary" => Some(313i64),
+            "hsa_body_battery_data" => Some(314i64),
+            "hsa_event" => Some(315i64),
+            "climb_pro" => Some(317i64),
+            "tank_update" => Some(319i64),
+            "tank_summary" => Some(323i64),
+            "sleep_assessment" => Some(346i64),
+            "hrv_status_summary" => Some(370i64),
+            "hrv_value" => Some(371i64),
+            "raw_bbi" => Some(372i64),
+            "device_aux_battery_info" => Some(375i64),
+            "hsa_gyroscope_data" => Some(376i64),
+            "chrono_shot_session" => Some(387i64),
+            "chrono_shot_data" => Some(388i64),
+            "hsa_configuration_data" => Some(389i64),
+            "dive_apnea_alarm" => Some(393i64),
+            "skin_temp_overnight" => Some(398i64),
+            "hsa_wrist_temperature_data" => Some(409i64),
+            "mfg_range_min" => Some(65280i64),
+            "mfg_range_max" => Some(65534i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             MesgNum::FileId => 0,
@@ -1350,6 +2145,135 @@ impl MesgNum {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            MesgNum::FileId => 0,
+            MesgNum::Capabilities => 1,
+            MesgNum::DeviceSettings => 2,
+            MesgNum::UserProfile => 3,
+            MesgNum::HrmProfile => 4,
+            MesgNum::SdmProfile => 5,
+            MesgNum::BikeProfile => 6,
+            MesgNum::ZonesTarget => 7,
+            MesgNum::HrZone => 8,
+            MesgNum::PowerZone => 9,
+            MesgNum::MetZone => 10,
+            MesgNum::Sport => 12,
+            MesgNum::Goal => 15,
+            MesgNum::Session => 18,
+            MesgNum::Lap => 19,
+            MesgNum::Record => 20,
+            MesgNum::Event => 21,
+            MesgNum::DeviceInfo => 23,
+            MesgNum::Workout => 26,
+            MesgNum::WorkoutStep => 27,
+            MesgNum::Schedule => 28,
+            MesgNum::WeightScale => 30,
+            MesgNum::Course => 31,
+            MesgNum::CoursePoint => 32,
+            MesgNum::Totals => 33,
+            MesgNum::Activity => 34,
+            MesgNum::Software => 35,
+            MesgNum::FileCapabilities => 37,
+            MesgNum::MesgCapabilities => 38,
+            MesgNum::FieldCapabilities => 39,
+            MesgNum::FileCreator => 49,
+            MesgNum::BloodPressure => 51,
+            MesgNum::SpeedZone => 53,
+            MesgNum::Monitoring => 55,
+            MesgNum::TrainingFile => 72,
+            MesgNum::Hrv => 78,
+            MesgNum::AntRx => 80,
+            MesgNum::AntTx => 81,
+            MesgNum::AntChannelId => 82,
+            MesgNum::Length => 101,
+            MesgNum::MonitoringInfo => 103,
+            MesgNum::Pad => 105,
+            MesgNum::SlaveDevice => 106,
+            MesgNum::Connectivity => 127,
+            MesgNum::WeatherConditions => 128,
+            MesgNum::WeatherAlert => 129,
+            MesgNum::CadenceZone => 131,
+            MesgNum::Hr => 132,
+            MesgNum::SegmentLap => 142,
+            MesgNum::MemoGlob => 145,
+            MesgNum::SegmentId => 148,
+            MesgNum::SegmentLeaderboardEntry => 149,
+            MesgNum::SegmentPoint => 150,
+            MesgNum::SegmentFile => 151,
+            MesgNum::WorkoutSession => 158,
+            MesgNum::WatchfaceSettings => 159,
+            MesgNum::GpsMetadata => 160,
+            MesgNum::CameraEvent => 161,
+            MesgNum::TimestampCorrelation => 162,
+            MesgNum::GyroscopeData => 164,
+            MesgNum::AccelerometerData => 165,
+            MesgNum::ThreeDSensorCalibration => 167,
+            MesgNum::VideoFrame => 169,
+            MesgNum::ObdiiData => 174,
+            MesgNum::NmeaSentence => 177,
+            MesgNum::AviationAttitude => 178,
+            MesgNum::Video => 184,
+            MesgNum::VideoTitle => 185,
+            MesgNum::VideoDescription => 186,
+            MesgNum::VideoClip => 187,
+            MesgNum::OhrSettings => 188,
+            MesgNum::ExdScreenConfiguration => 200,
+            MesgNum::ExdDataFieldConfiguration => 201,
+            MesgNum::ExdDataConceptConfiguration => 202,
+            MesgNum::FieldDescription => 206,
+            MesgNum::DeveloperDataId => 207,
+            MesgNum::MagnetometerData => 208,
+            MesgNum::BarometerData => 209,
+            MesgNum::OneDSensorCalibration => 210,
+            MesgNum::MonitoringHrData => 211,
+            MesgNum::TimeInZone => 216,
+            MesgNum::Set => 225,
+            MesgNum::StressLevel => 227,
+            MesgNum::MaxMetData => 229,
+            MesgNum::DiveSettings => 258,
+            MesgNum::DiveGas => 259,
+            MesgNum::DiveAlarm => 262,
+            MesgNum::ExerciseTitle => 264,
+            MesgNum::DiveSummary => 268,
+            MesgNum::Spo2Data => 269,
+            MesgNum::SleepLevel => 275,
+            MesgNum::Jump => 285,
+            MesgNum::AadAccelFeatures => 289,
+            MesgNum::BeatIntervals => 290,
+            MesgNum::RespirationRate => 297,
+            MesgNum::HsaAccelerometerData => 302,
+            MesgNum::HsaStepData => 304,
+            MesgNum::HsaSpo2Data => 305,
+            MesgNum::HsaStressData => 306,
+            MesgNum::HsaRespirationData => 307,
+            MesgNum::HsaHeartRateData => 308,
+            MesgNum::Split => 312,
+            MesgNum::SplitSummary => 313,
+            MesgNum::HsaBodyBatteryData => 314,
+            MesgNum::HsaEvent => 315,
+            MesgNum::ClimbPro => 317,
+            MesgNum::TankUpdate => 319,
+            MesgNum::TankSummary => 323,
+            MesgNum::SleepAssessment => 346,
+            MesgNum::HrvStatusSummary => 370,
+            MesgNum::HrvValue => 371,
+            MesgNum::RawBbi => 372,
+            MesgNum::DeviceAuxBatteryInfo => 375,
+            MesgNum::HsaGyroscopeData => 376,
+            MesgNum::ChronoShotSession => 387,
+            MesgNum::ChronoShotData => 388,
+            MesgNum::HsaConfigurationData => 389,
+            MesgNum::DiveApneaAlarm => 393,
+            MesgNum::SkinTempOvernight => 398,
+            MesgNum::HsaWristTemperatureData => 409,
+            MesgNum::MfgRangeMin => 65280,
+            MesgNum::MfgRangeMax => 65534,
+            MesgNum::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for MesgNum {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1625,6 +2549,15 @@ impl Serialize for MesgNum {
         }
     }
 }
+impl<'de> Deserialize<'de> for MesgNum {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::MesgNum, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Checksum {
     #[doc = "Allows clear of checksum for flash memory where can only write 1 to 0 without erasing sector."]
@@ -1641,6 +2574,13 @@ impl Checksum {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "clear" => Some(0i64),
+            "ok" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Checksum::Clear => 0,
@@ -1651,6 +2591,15 @@ impl Checksum {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Checksum::Clear => 0,
+            Checksum::Ok => 1,
+            Checksum::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Checksum {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1686,6 +2635,15 @@ impl Serialize for Checksum {
         }
     }
 }
+impl<'de> Deserialize<'de> for Checksum {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Checksum, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum FileFlags {
     Read,
@@ -1702,6 +2660,14 @@ impl FileFlags {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "read" => Some(2i64),
+            "write" => Some(4i64),
+            "erase" => Some(8i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             FileFlags::Read => 2,
@@ -1713,6 +2679,16 @@ impl FileFlags {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            FileFlags::Read => 2,
+            FileFlags::Write => 4,
+            FileFlags::Erase => 8,
+            FileFlags::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for FileFlags {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1750,6 +2726,15 @@ impl Serialize for FileFlags {
         }
     }
 }
+impl<'de> Deserialize<'de> for FileFlags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::FileFlags, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum MesgCount {
     NumPerFile,
@@ -1766,6 +2751,14 @@ impl MesgCount {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "num_per_file" => Some(0i64),
+            "max_per_file" => Some(1i64),
+            "max_per_file_type" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             MesgCount::NumPerFile => 0,
@@ -1777,6 +2770,16 @@ impl MesgCount {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            MesgCount::NumPerFile => 0,
+            MesgCount::MaxPerFile => 1,
+            MesgCount::MaxPerFileType => 2,
+            MesgCount::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for MesgCount {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1811,6 +2814,15 @@ impl Serialize for MesgCount {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for MesgCount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::MesgCount, invalid).map(Self::from)
+    }
+}
 #[doc = "seconds since UTC 00:00 Dec 31 1989"]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DateTime {
@@ -1825,6 +2837,12 @@ impl DateTime {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "min" => Some(268435456i64),
+            _ => None,
+        }
+    }
     pub fn as_u32(self) -> u32 {
         match self {
             DateTime::Min => 268435456,
@@ -1834,6 +2852,14 @@ impl DateTime {
     pub fn as_i64(self) -> i64 {
         self.as_u32() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DateTime::Min => 268435456,
+            DateTime::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1867,6 +2893,15 @@ impl Serialize for DateTime {
         }
     }
 }
+impl<'de> Deserialize<'de> for DateTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u32::MAX);
+        deserialize_variant(deserializer, FieldDataType::DateTime, invalid).map(Self::from)
+    }
+}
 #[doc = "seconds since 00:00 Dec 31 1989 in local time zone"]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LocalDateTime {
@@ -1881,6 +2916,12 @@ impl LocalDateTime {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "min" => Some(268435456i64),
+            _ => None,
+        }
+    }
     pub fn as_u32(self) -> u32 {
         match self {
             LocalDateTime::Min => 268435456,
@@ -1890,6 +2931,14 @@ impl LocalDateTime {
     pub fn as_i64(self) -> i64 {
         self.as_u32() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LocalDateTime::Min => 268435456,
+            LocalDateTime::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LocalDateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1923,6 +2972,15 @@ impl Serialize for LocalDateTime {
         }
     }
 }
+impl<'de> Deserialize<'de> for LocalDateTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u32::MAX);
+        deserialize_variant(deserializer, FieldDataType::LocalDateTime, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum MessageIndex {
     #[doc = "index"]
@@ -1942,6 +3000,14 @@ impl MessageIndex {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "mask" => Some(4095i64),
+            "reserved" => Some(28672i64),
+            "selected" => Some(32768i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             MessageIndex::Mask => 4095,
@@ -1953,6 +3019,16 @@ impl MessageIndex {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            MessageIndex::Mask => 4095,
+            MessageIndex::Reserved => 28672,
+            MessageIndex::Selected => 32768,
+            MessageIndex::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for MessageIndex {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1990,6 +3066,15 @@ impl Serialize for MessageIndex {
         }
     }
 }
+impl<'de> Deserialize<'de> for MessageIndex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::MessageIndex, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DeviceIndex {
     #[doc = "Creator of the file is always device index 0."]
@@ -2003,6 +3088,12 @@ impl DeviceIndex {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "creator" => Some(0i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             DeviceIndex::Creator => 0,
@@ -2012,6 +3103,14 @@ impl DeviceIndex {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DeviceIndex::Creator => 0,
+            DeviceIndex::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DeviceIndex {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -2045,6 +3144,15 @@ impl Serialize for DeviceIndex {
         }
     }
 }
+impl<'de> Deserialize<'de> for DeviceIndex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DeviceIndex, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Gender {
     Female,
@@ -2059,6 +3167,13 @@ impl Gender {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "female" => Some(0i64),
+            "male" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Gender::Female => 0,
@@ -2069,6 +3184,15 @@ impl Gender {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Gender::Female => 0,
+            Gender::Male => 1,
+            Gender::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Gender {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -2101,6 +3225,15 @@ impl Serialize for Gender {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for Gender {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Gender, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Language {
     English,
@@ -2189,6 +3322,50 @@ impl Language {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "english" => Some(0i64),
+            "french" => Some(1i64),
+            "italian" => Some(2i64),
+            "german" => Some(3i64),
+            "spanish" => Some(4i64),
+            "croatian" => Some(5i64),
+            "czech" => Some(6i64),
+            "danish" => Some(7i64),
+            "dutch" => Some(8i64),
+            "finnish" => Some(9i64),
+            "greek" => Some(10i64),
+            "hungarian" => Some(11i64),
+            "norwegian" => Some(12i64),
+            "polish" => Some(13i64),
+            "portuguese" => Some(14i64),
+            "slovakian" => Some(15i64),
+            "slovenian" => Some(16i64),
+            "swedish" => Some(17i64),
+            "russian" => Some(18i64),
+            "turkish" => Some(19i64),
+            "latvian" => Some(20i64),
+            "ukrainian" => Some(21i64),
+            "arabic" => Some(22i64),
+            "farsi" => Some(23i64),
+            "bulgarian" => Some(24i64),
+            "romanian" => Some(25i64),
+            "chinese" => Some(26i64),
+            "japanese" => Some(27i64),
+            "korean" => Some(28i64),
+            "taiwanese" => Some(29i64),
+            "thai" => Some(30i64),
+            "hebrew" => Some(31i64),
+            "brazilian_portuguese" => Some(32i64),
+            "indonesian" => Some(33i64),
+            "malaysian" => Some(34i64),
+            "vietnamese" => Some(35i64),
+            "burmese" => Some(36i64),
+            "mongolian" => Some(37i64),
+            "custom" => Some(254i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Language::English => 0,
@@ -2236,6 +3413,52 @@ impl Language {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Language::English => 0,
+            Language::French => 1,
+            Language::Italian => 2,
+            Language::German => 3,
+            Language::Spanish => 4,
+            Language::Croatian => 5,
+            Language::Czech => 6,
+            Language::Danish => 7,
+            Language::Dutch => 8,
+            Language::Finnish => 9,
+            Language::Greek => 10,
+            Language::Hungarian => 11,
+            Language::Norwegian => 12,
+            Language::Polish => 13,
+            Language::Portuguese => 14,
+            Language::Slovakian => 15,
+            Language::Slovenian => 16,
+            Language::Swedish => 17,
+            Language::Russian => 18,
+            Language::Turkish => 19,
+            Language::Latvian => 20,
+            Language::Ukrainian => 21,
+            Language::Arabic => 22,
+            Language::Farsi => 23,
+            Language::Bulgarian => 24,
+            Language::Romanian => 25,
+            Language::Chinese => 26,
+            Language::Japanese => 27,
+            Language::Korean => 28,
+            Language::Taiwanese => 29,
+            Language::Thai => 30,
+            Language::Hebrew => 31,
+            Language::BrazilianPortuguese => 32,
+            Language::Indonesian => 33,
+            Language::Malaysian => 34,
+            Language::Vietnamese => 35,
+            Language::Burmese => 36,
+            Language::Mongolian => 37,
+            Language::Custom => 254,
+            Language::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Language {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -2342,6 +3565,15 @@ impl Serialize for Language {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for Language {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Language, invalid).map(Self::from)
+    }
+}
 #[doc = "Bit field corresponding to language enum type (1 << language)."]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LanguageBits0 {
@@ -2369,6 +3601,19 @@ impl LanguageBits0 {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "english" => Some(1i64),
+            "french" => Some(2i64),
+            "italian" => Some(4i64),
+            "german" => Some(8i64),
+            "spanish" => Some(16i64),
+            "croatian" => Some(32i64),
+            "czech" => Some(64i64),
+            "danish" => Some(128i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             LanguageBits0::English => 1,
@@ -2385,6 +3630,21 @@ impl LanguageBits0 {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LanguageBits0::English => 1,
+            LanguageBits0::French => 2,
+            LanguageBits0::Italian => 4,
+            LanguageBits0::German => 8,
+            LanguageBits0::Spanish => 16,
+            LanguageBits0::Croatian => 32,
+            LanguageBits0::Czech => 64,
+            LanguageBits0::Danish => 128,
+            LanguageBits0::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LanguageBits0 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -2432,6 +3692,15 @@ impl Serialize for LanguageBits0 {
         }
     }
 }
+impl<'de> Deserialize<'de> for LanguageBits0 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::LanguageBits0, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LanguageBits1 {
     Dutch,
@@ -2458,6 +3727,19 @@ impl LanguageBits1 {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "dutch" => Some(1i64),
+            "finnish" => Some(2i64),
+            "greek" => Some(4i64),
+            "hungarian" => Some(8i64),
+            "norwegian" => Some(16i64),
+            "polish" => Some(32i64),
+            "portuguese" => Some(64i64),
+            "slovakian" => Some(128i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             LanguageBits1::Dutch => 1,
@@ -2474,6 +3756,21 @@ impl LanguageBits1 {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LanguageBits1::Dutch => 1,
+            LanguageBits1::Finnish => 2,
+            LanguageBits1::Greek => 4,
+            LanguageBits1::Hungarian => 8,
+            LanguageBits1::Norwegian => 16,
+            LanguageBits1::Polish => 32,
+            LanguageBits1::Portuguese => 64,
+            LanguageBits1::Slovakian => 128,
+            LanguageBits1::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LanguageBits1 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -2521,6 +3818,15 @@ impl Serialize for LanguageBits1 {
         }
     }
 }
+impl<'de> Deserialize<'de> for LanguageBits1 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::LanguageBits1, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LanguageBits2 {
     Slovenian,
@@ -2547,6 +3853,19 @@ impl LanguageBits2 {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "slovenian" => Some(1i64),
+            "swedish" => Some(2i64),
+            "russian" => Some(4i64),
+            "turkish" => Some(8i64),
+            "latvian" => Some(16i64),
+            "ukrainian" => Some(32i64),
+            "arabic" => Some(64i64),
+            "farsi" => Some(128i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             LanguageBits2::Slovenian => 1,
@@ -2563,6 +3882,21 @@ impl LanguageBits2 {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LanguageBits2::Slovenian => 1,
+            LanguageBits2::Swedish => 2,
+            LanguageBits2::Russian => 4,
+            LanguageBits2::Turkish => 8,
+            LanguageBits2::Latvian => 16,
+            LanguageBits2::Ukrainian => 32,
+            LanguageBits2::Arabic => 64,
+            LanguageBits2::Farsi => 128,
+            LanguageBits2::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LanguageBits2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -2610,6 +3944,15 @@ impl Serialize for LanguageBits2 {
         }
     }
 }
+impl<'de> Deserialize<'de> for LanguageBits2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::LanguageBits2, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LanguageBits3 {
     Bulgarian,
@@ -2636,6 +3979,19 @@ impl LanguageBits3 {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "bulgarian" => Some(1i64),
+            "romanian" => Some(2i64),
+            "chinese" => Some(4i64),
+            "japanese" => Some(8i64),
+            "korean" => Some(16i64),
+            "taiwanese" => Some(32i64),
+            "thai" => Some(64i64),
+            "hebrew" => Some(128i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             LanguageBits3::Bulgarian => 1,
@@ -2652,6 +4008,21 @@ impl LanguageBits3 {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LanguageBits3::Bulgarian => 1,
+            LanguageBits3::Romanian => 2,
+            LanguageBits3::Chinese => 4,
+            LanguageBits3::Japanese => 8,
+            LanguageBits3::Korean => 16,
+            LanguageBits3::Taiwanese => 32,
+            LanguageBits3::Thai => 64,
+            LanguageBits3::Hebrew => 128,
+            LanguageBits3::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LanguageBits3 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -2699,6 +4070,15 @@ impl Serialize for LanguageBits3 {
         }
     }
 }
+impl<'de> Deserialize<'de> for LanguageBits3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::LanguageBits3, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LanguageBits4 {
     BrazilianPortuguese,
@@ -2721,6 +4101,17 @@ impl LanguageBits4 {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "brazilian_portuguese" => Some(1i64),
+            "indonesian" => Some(2i64),
+            "malaysian" => Some(4i64),
+            "vietnamese" => Some(8i64),
+            "burmese" => Some(16i64),
+            "mongolian" => Some(32i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             LanguageBits4::BrazilianPortuguese => 1,
@@ -2735,6 +4126,19 @@ impl LanguageBits4 {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LanguageBits4::BrazilianPortuguese => 1,
+            LanguageBits4::Indonesian => 2,
+            LanguageBits4::Malaysian => 4,
+            LanguageBits4::Vietnamese => 8,
+            LanguageBits4::Burmese => 16,
+            LanguageBits4::Mongolian => 32,
+            LanguageBits4::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LanguageBits4 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -2778,6 +4182,15 @@ impl Serialize for LanguageBits4 {
         }
     }
 }
+impl<'de> Deserialize<'de> for LanguageBits4 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::LanguageBits4, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum TimeZone {
     Almaty,
@@ -3000,6 +4413,117 @@ impl TimeZone {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "almaty" => Some(0i64),
+            "bangkok" => Some(1i64),
+            "bombay" => Some(2i64),
+            "brasilia" => Some(3i64),
+            "cairo" => Some(4i64),
+            "cape_verde_is" => Some(5i64),
+            "darwin" => Some(6i64),
+            "eniwetok" => Some(7i64),
+            "fiji" => Some(8i64),
+            "hong_kong" => Some(9i64),
+            "islamabad" => Some(10i64),
+            "kabul" => Some(11i64),
+            "magadan" => Some(12i64),
+            "mid_atlantic" => Some(13i64),
+            "moscow" => Some(14i64),
+            "muscat" => Some(15i64),
+            "newfoundland" => Some(16i64),
+            "samoa" => Some(17i64),
+            "sydney" => Some(18i64),
+            "tehran" => Some(19i64),
+            "tokyo" => Some(20i64),
+            "us_alaska" => Some(21i64),
+            "us_atlantic" => Some(22i64),
+            "us_central" => Some(23i64),
+            "us_eastern" => Some(24i64),
+            "us_hawaii" => Some(25i64),
+            "us_mountain" => Some(26i64),
+            "us_pacific" => Some(27i64),
+            "other" => Some(28i64),
+            "auckland" => Some(29i64),
+            "kathmandu" => Some(30i64),
+            "europe_western_wet" => Some(31i64),
+            "europe_central_cet" => Some(32i64),
+            "europe_eastern_eet" => Some(33i64),
+            "jakarta" => Some(34i64),
+            "perth" => Some(35i64),
+            "adelaide" => Some(36i64),
+            "brisbane" => Some(37i64),
+            "tasmania" => Some(38i64),
+            "iceland" => Some(39i64),
+            "amsterdam" => Some(40i64),
+            "athens" => Some(41i64),
+            "barcelona" => Some(42i64),
+            "berlin" => Some(43i64),
+            "brussels" => Some(44i64),
+            "budapest" => Some(45i64),
+            "copenhagen" => Some(46i64),
+            "dublin" => Some(47i64),
+            "helsinki" => Some(48i64),
+            "lisbon" => Some(49i64),
+            "london" => Some(50i64),
+            "madrid" => Some(51i64),
+            "munich" => Some(52i64),
+            "oslo" => Some(53i64),
+            "paris" => Some(54i64),
+            "prague" => Some(55i64),
+            "reykjavik" => Some(56i64),
+            "rome" => Some(57i64),
+            "stockholm" => Some(58i64),
+            "vienna" => Some(59i64),
+            "warsaw" => Some(60i64),
+            "zurich" => Some(61i64),
+            "quebec" => Some(62i64),
+            "ontario" => Some(63i64),
+            "manitoba" => Some(64i64),
+            "saskatchewan" => Some(65i64),
+            "alberta" => Some(66i64),
+            "british_columbia" => Some(67i64),
+            "boise" => Some(68i64),
+            "boston" => Some(69i64),
+            "chicago" => Some(70i64),
+            "dallas" => Some(71i64),
+            "denver" => Some(72i64),
+            "kansas_city" => Some(73i64),
+            "las_vegas" => Some(74i64),
+            "los_angeles" => Some(75i64),
+            "miami" => Some(76i64),
+            "minneapolis" => Some(77i64),
+            "new_york" => Some(78i64),
+            "new_orleans" => Some(79i64),
+            "phoenix" => Some(80i64),
+            "santa_fe" => Some(81i64),
+            "seattle" => Some(82i64),
+            "washington_dc" => Some(83i64),
+            "us_arizona" => Some(84i64),
+            "chita" => Some(85i64),
+            "ekaterinburg" => Some(86i64),
+            "irkutsk" => Some(87i64),
+            "kaliningrad" => Some(88i64),
+            "krasnoyarsk" => Some(89i64),
+            "novosibirsk" => Some(90i64),
+            "petropavlovsk_kamchatskiy" => Some(91i64),
+            "samara" => Some(92i64),
+            "vladivostok" => Some(93i64),
+            "mexico_central" => Some(94i64),
+            "mexico_mountain" => Some(95i64),
+            "mexico_pacific" => Some(96i64),
+            "cape_town" => Some(97i64),
+            "winkhoek" => Some(98i64),
+            "lagos" => Some(99i64),
+            "riyahd" => Some(100i64),
+            "venezuela" => Some(101i64),
+            "australia_lh" => Some(102i64),
+            "santiago" => Some(103i64),
+            "manual" => Some(253i64),
+            "automatic" => Some(254i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             TimeZone::Almaty => 0,
@@ -3114,6 +4638,119 @@ impl TimeZone {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            TimeZone::Almaty => 0,
+            TimeZone::Bangkok => 1,
+            TimeZone::Bombay => 2,
+            TimeZone::Brasilia => 3,
+            TimeZone::Cairo => 4,
+            TimeZone::CapeVerdeIs => 5,
+            TimeZone::Darwin => 6,
+            TimeZone::Eniwetok => 7,
+            TimeZone::Fiji => 8,
+            TimeZone::HongKong => 9,
+            TimeZone::Islamabad => 10,
+            TimeZone::Kabul => 11,
+            TimeZone::Magadan => 12,
+            TimeZone::MidAtlantic => 13,
+            TimeZone::Moscow => 14,
+            TimeZone::Muscat => 15,
+            TimeZone::Newfoundland => 16,
+            TimeZone::Samoa => 17,
+            TimeZone::Sydney => 18,
+            TimeZone::Tehran => 19,
+            TimeZone::Tokyo => 20,
+            TimeZone::UsAlaska => 21,
+            TimeZone::UsAtlantic => 22,
+            TimeZone::UsCentral => 23,
+            TimeZone::UsEastern => 24,
+            TimeZone::UsHawaii => 25,
+            TimeZone::UsMountain => 26,
+            TimeZone::UsPacific => 27,
+            TimeZone::Other => 28,
+            TimeZone::Auckland => 29,
+            TimeZone::Kathmandu => 30,
+            TimeZone::EuropeWesternWet => 31,
+            TimeZone::EuropeCentralCet => 32,
+            TimeZone::EuropeEasternEet => 33,
+            TimeZone::Jakarta => 34,
+            TimeZone::Perth => 35,
+            TimeZone::Adelaide => 36,
+            TimeZone::Brisbane => 37,
+            TimeZone::Tasmania => 38,
+            TimeZone::Iceland => 39,
+            TimeZone::Amsterdam => 40,
+            TimeZone::Athens => 41,
+            TimeZone::Barcelona => 42,
+            TimeZone::Berlin => 43,
+            TimeZone::Brussels => 44,
+            TimeZone::Budapest => 45,
+            TimeZone::Copenhagen => 46,
+            TimeZone::Dublin => 47,
+            TimeZone::Helsinki => 48,
+            TimeZone::Lisbon => 49,
+            TimeZone::London => 50,
+            TimeZone::Madrid => 51,
+            TimeZone::Munich => 52,
+            TimeZone::Oslo => 53,
+            TimeZone::Paris => 54,
+            TimeZone::Prague => 55,
+            TimeZone::Reykjavik => 56,
+            TimeZone::Rome => 57,
+            TimeZone::Stockholm => 58,
+            TimeZone::Vienna => 59,
+            TimeZone::Warsaw => 60,
+            TimeZone::Zurich => 61,
+            TimeZone::Quebec => 62,
+            TimeZone::Ontario => 63,
+            TimeZone::Manitoba => 64,
+            TimeZone::Saskatchewan => 65,
+            TimeZone::Alberta => 66,
+            TimeZone::BritishColumbia => 67,
+            TimeZone::Boise => 68,
+            TimeZone::Boston => 69,
+            TimeZone::Chicago => 70,
+            TimeZone::Dallas => 71,
+            TimeZone::Denver => 72,
+            TimeZone::KansasCity => 73,
+            TimeZone::LasVegas => 74,
+            TimeZone::LosAngeles => 75,
+            TimeZone::Miami => 76,
+            TimeZone::Minneapolis => 77,
+            TimeZone::NewYork => 78,
+            TimeZone::NewOrleans => 79,
+            TimeZone::Phoenix => 80,
+            TimeZone::SantaFe => 81,
+            TimeZone::Seattle => 82,
+            TimeZone::WashingtonDc => 83,
+            TimeZone::UsArizona => 84,
+            TimeZone::Chita => 85,
+            TimeZone::Ekaterinburg => 86,
+            TimeZone::Irkutsk => 87,
+            TimeZone::Kaliningrad => 88,
+            TimeZone::Krasnoyarsk => 89,
+            TimeZone::Novosibirsk => 90,
+            TimeZone::PetropavlovskKamchatskiy => 91,
+            TimeZone::Samara => 92,
+            TimeZone::Vladivostok => 93,
+            TimeZone::MexicoCentral => 94,
+            TimeZone::MexicoMountain => 95,
+            TimeZone::MexicoPacific => 96,
+            TimeZone::CapeTown => 97,
+            TimeZone::Winkhoek => 98,
+            TimeZone::Lagos => 99,
+            TimeZone::Riyahd => 100,
+            TimeZone::Venezuela => 101,
+            TimeZone::AustraliaLh => 102,
+            TimeZone::Santiago => 103,
+            TimeZone::Manual => 253,
+            TimeZone::Automatic => 254,
+            TimeZone::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for TimeZone {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -3354,6 +4991,15 @@ impl Serialize for TimeZone {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for TimeZone {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::TimeZone, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DisplayMeasure {
     Metric,
@@ -3370,7 +5016,15 @@ impl DisplayMeasure {
             _ => false,
         }
     }
-    pub fn as_u8(self) -> u8 {
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "metric" => Some(0i64),
+            "statute" => Some(1i64),
+            "nautical" => Some(2i64),
+            _ => None,
+        }
+    }
+    pub fn as_u8(self) -> u8 {
         match self {
             DisplayMeasure::Metric => 0,
             DisplayMeasure::Statute => 1,
@@ -3381,6 +5035,16 @@ impl DisplayMeasure {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DisplayMeasure::Metric => 0,
+            DisplayMeasure::Statute => 1,
+            DisplayMeasure::Nautical => 2,
+            DisplayMeasure::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DisplayMeasure {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -3415,6 +5079,15 @@ impl Serialize for DisplayMeasure {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for DisplayMeasure {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DisplayMeasure, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DisplayHeart {
     Bpm,
@@ -3431,6 +5104,14 @@ impl DisplayHeart {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "bpm" => Some(0i64),
+            "max" => Some(1i64),
+            "reserve" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             DisplayHeart::Bpm => 0,
@@ -3442,6 +5123,16 @@ impl DisplayHeart {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DisplayHeart::Bpm => 0,
+            DisplayHeart::Max => 1,
+            DisplayHeart::Reserve => 2,
+            DisplayHeart::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DisplayHeart {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -3476,6 +5167,15 @@ impl Serialize for DisplayHeart {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for DisplayHeart {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DisplayHeart, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DisplayPower {
     Watts,
@@ -3490,6 +5190,13 @@ impl DisplayPower {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "watts" => Some(0i64),
+            "percent_ftp" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             DisplayPower::Watts => 0,
@@ -3500,6 +5207,15 @@ impl DisplayPower {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DisplayPower::Watts => 0,
+            DisplayPower::PercentFtp => 1,
+            DisplayPower::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DisplayPower {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -3532,6 +5248,15 @@ impl Serialize for DisplayPower {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for DisplayPower {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DisplayPower, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DisplayPosition {
     #[doc = "dd.dddddd"]
@@ -3668,6 +5393,53 @@ impl DisplayPosition {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "degree" => Some(0i64),
+            "degree_minute" => Some(1i64),
+            "degree_minute_second" => Some(2i64),
+            "austrian_grid" => Some(3i64),
+            "british_grid" => Some(4i64),
+            "dutch_grid" => Some(5i64),
+            "hungarian_grid" => Some(6i64),
+            "finnish_grid" => Some(7i64),
+            "german_grid" => Some(8i64),
+            "icelandic_grid" => Some(9i64),
+            "indonesian_equatorial" => Some(10i64),
+            "indonesian_irian" => Some(11i64),
+            "indonesian_southern" => Some(12i64),
+            "india_zone_0" => Some(13i64),
+            "india_zone_IA" => Some(14i64),
+            "india_zone_IB" => Some(15i64),
+            "india_zone_IIA" => Some(16i64),
+            "india_zone_IIB" => Some(17i64),
+            "india_zone_IIIA" => Some(18i64),
+            "india_zone_IIIB" => Some(19i64),
+            "india_zone_IVA" => Some(20i64),
+            "india_zone_IVB" => Some(21i64),
+            "irish_transverse" => Some(22i64),
+            "irish_grid" => Some(23i64),
+            "loran" => Some(24i64),
+            "maidenhead_grid" => Some(25i64),
+            "mgrs_grid" => Some(26i64),
+            "new_zealand_grid" => Some(27i64),
+            "new_zealand_transverse" => Some(28i64),
+            "qatar_grid" => Some(29i64),
+            "modified_swedish_grid" => Some(30i64),
+            "swedish_grid" => Some(31i64),
+            "south_african_grid" => Some(32i64),
+            "swiss_grid" => Some(33i64),
+            "taiwan_grid" => Some(34i64),
+            "united_states_grid" => Some(35i64),
+            "utm_ups_grid" => Some(36i64),
+            "west_malayan" => Some(37i64),
+            "borneo_rso" => Some(38i64),
+            "estonian_grid" => Some(39i64),
+            "latvian_grid" => Some(40i64),
+            "swedish_ref_99_grid" => Some(41i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             DisplayPosition::Degree => 0,
@@ -3718,6 +5490,55 @@ impl DisplayPosition {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DisplayPosition::Degree => 0,
+            DisplayPosition::DegreeMinute => 1,
+            DisplayPosition::DegreeMinuteSecond => 2,
+            DisplayPosition::AustrianGrid => 3,
+            DisplayPosition::BritishGrid => 4,
+            DisplayPosition::DutchGrid => 5,
+            DisplayPosition::HungarianGrid => 6,
+            DisplayPosition::FinnishGrid => 7,
+            DisplayPosition::GermanGrid => 8,
+            DisplayPosition::IcelandicGrid => 9,
+            DisplayPosition::IndonesianEquatorial => 10,
+            DisplayPosition::IndonesianIrian => 11,
+            DisplayPosition::IndonesianSouthern => 12,
+            DisplayPosition::IndiaZone0 => 13,
+            DisplayPosition::IndiaZoneIA => 14,
+            DisplayPosition::IndiaZoneIB => 15,
+            DisplayPosition::IndiaZoneIIA => 16,
+            DisplayPosition::IndiaZoneIIB => 17,
+            DisplayPosition::IndiaZoneIIIA => 18,
+            DisplayPosition::IndiaZoneIIIB => 19,
+            DisplayPosition::IndiaZoneIVA => 20,
+            DisplayPosition::IndiaZoneIVB => 21,
+            DisplayPosition::IrishTransverse => 22,
+            DisplayPosition::IrishGrid => 23,
+            DisplayPosition::Loran => 24,
+            DisplayPosition::MaidenheadGrid => 25,
+            DisplayPosition::MgrsGrid => 26,
+            DisplayPosition::NewZealandGrid => 27,
+            DisplayPosition::NewZealandTransverse => 28,
+            DisplayPosition::QatarGrid => 29,
+            DisplayPosition::ModifiedSwedishGrid => 30,
+            DisplayPosition::SwedishGrid => 31,
+            DisplayPosition::SouthAfricanGrid => 32,
+            DisplayPosition::SwissGrid => 33,
+            DisplayPosition::TaiwanGrid => 34,
+            DisplayPosition::UnitedStatesGrid => 35,
+            DisplayPosition::UtmUpsGrid => 36,
+            DisplayPosition::WestMalayan => 37,
+            DisplayPosition::BorneoRso => 38,
+            DisplayPosition::EstonianGrid => 39,
+            DisplayPosition::LatvianGrid => 40,
+            DisplayPosition::SwedishRef99Grid => 41,
+            DisplayPosition::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DisplayPosition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -3830,6 +5651,15 @@ impl Serialize for DisplayPosition {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for DisplayPosition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DisplayPosition, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Switch {
     Off,
@@ -3846,6 +5676,14 @@ impl Switch {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "off" => Some(0i64),
+            "on" => Some(1i64),
+            "auto" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Switch::Off => 0,
@@ -3857,6 +5695,16 @@ impl Switch {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Switch::Off => 0,
+            Switch::On => 1,
+            Switch::Auto => 2,
+            Switch::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Switch {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -3891,6 +5739,15 @@ impl Serialize for Switch {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for Switch {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Switch, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Sport {
     Generic,
@@ -4041,6 +5898,80 @@ impl Sport {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "generic" => Some(0i64),
+            "running" => Some(1i64),
+            "cycling" => Some(2i64),
+            "transition" => Some(3i64),
+            "fitness_equipment" => Some(4i64),
+            "swimming" => Some(5i64),
+            "basketball" => Some(6i64),
+            "soccer" => Some(7i64),
+            "tennis" => Some(8i64),
+            "american_football" => Some(9i64),
+            "training" => Some(10i64),
+            "walking" => Some(11i64),
+            "cross_country_skiing" => Some(12i64),
+            "alpine_skiing" => Some(13i64),
+            "snowboarding" => Some(14i64),
+            "rowing" => Some(15i64),
+            "mountaineering" => Some(16i64),
+            "hiking" => Some(17i64),
+            "multisport" => Some(18i64),
+            "paddling" => Some(19i64),
+            "flying" => Some(20i64),
+            "e_biking" => Some(21i64),
+            "motorcycling" => Some(22i64),
+            "boating" => Some(23i64),
+            "driving" => Some(24i64),
+            "golf" => Some(25i64),
+            "hang_gliding" => Some(26i64),
+            "horseback_riding" => Some(27i64),
+            "hunting" => Some(28i64),
+            "fishing" => Some(29i64),
+            "inline_skating" => Some(30i64),
+            "rock_climbing" => Some(31i64),
+            "sailing" => Some(32i64),
+            "ice_skating" => Some(33i64),
+            "sky_diving" => Some(34i64),
+            "snowshoeing" => Some(35i64),
+            "snowmobiling" => Some(36i64),
+            "stand_up_paddleboarding" => Some(37i64),
+            "surfing" => Some(38i64),
+            "wakeboarding" => Some(39i64),
+            "water_skiing" => Some(40i64),
+            "kayaking" => Some(41i64),
+            "rafting" => Some(42i64),
+            "windsurfing" => Some(43i64),
+            "kitesurfing" => Some(44i64),
+            "tactical" => Some(45i64),
+            "jumpmaster" => Some(46i64),
+            "boxing" => Some(47i64),
+            "floor_climbing" => Some(48i64),
+            "baseball" => Some(49i64),
+            "diving" => Some(53i64),
+            "hiit" => Some(62i64),
+            "racket" => Some(64i64),
+            "wheelchair_push_walk" => Some(65i64),
+            "wheelchair_push_run" => Some(66i64),
+            "meditation" => Some(67i64),
+            "disc_golf" => Some(69i64),
+            "cricket" => Some(71i64),
+            "rugby" => Some(72i64),
+            "hockey" => Some(73i64),
+            "lacrosse" => Some(74i64),
+            "volleyball" => Some(75i64),
+            "water_tubing" => Some(76i64),
+            "wakesurfing" => Some(77i64),
+            "mixed_martial_arts" => Some(80i64),
+            "snorkeling" => Some(82i64),
+            "dance" => Some(83i64),
+            "jump_rope" => Some(84i64),
+            "all" => Some(254i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Sport::Generic => 0,
@@ -4118,6 +6049,82 @@ impl Sport {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Sport::Generic => 0,
+            Sport::Running => 1,
+            Sport::Cycling => 2,
+            Sport::Transition => 3,
+            Sport::FitnessEquipment => 4,
+            Sport::Swimming => 5,
+            Sport::Basketball => 6,
+            Sport::Soccer => 7,
+            Sport::Tennis => 8,
+            Sport::AmericanFootball => 9,
+            Sport::Training => 10,
+            Sport::Walking => 11,
+            Sport::CrossCountrySkiing => 12,
+            Sport::AlpineSkiing => 13,
+            Sport::Snowboarding => 14,
+            Sport::Rowing => 15,
+            Sport::Mountaineering => 16,
+            Sport::Hiking => 17,
+            Sport::Multisport => 18,
+            Sport::Paddling => 19,
+            Sport::Flying => 20,
+            Sport::EBiking => 21,
+            Sport::Motorcycling => 22,
+            Sport::Boating => 23,
+            Sport::Driving => 24,
+            Sport::Golf => 25,
+            Sport::HangGliding => 26,
+            Sport::HorsebackRiding => 27,
+            Sport::Hunting => 28,
+            Sport::Fishing => 29,
+            Sport::InlineSkating => 30,
+            Sport::RockClimbing => 31,
+            Sport::Sailing => 32,
+            Sport::IceSkating => 33,
+            Sport::SkyDiving => 34,
+            Sport::Snowshoeing => 35,
+            Sport::Snowmobiling => 36,
+            Sport::StandUpPaddleboarding => 37,
+            Sport::Surfing => 38,
+            Sport::Wakeboarding => 39,
+            Sport::WaterSkiing => 40,
+            Sport::Kayaking => 41,
+            Sport::Rafting => 42,
+            Sport::Windsurfing => 43,
+            Sport::Kitesurfing => 44,
+            Sport::Tactical => 45,
+            Sport::Jumpmaster => 46,
+            Sport::Boxing => 47,
+            Sport::FloorClimbing => 48,
+            Sport::Baseball => 49,
+            Sport::Diving => 53,
+            Sport::Hiit => 62,
+            Sport::Racket => 64,
+            Sport::WheelchairPushWalk => 65,
+            Sport::WheelchairPushRun => 66,
+            Sport::Meditation => 67,
+            Sport::DiscGolf => 69,
+            Sport::Cricket => 71,
+            Sport::Rugby => 72,
+            Sport::Hockey => 73,
+            Sport::Lacrosse => 74,
+            Sport::Volleyball => 75,
+            Sport::WaterTubing => 76,
+            Sport::Wakesurfing => 77,
+            Sport::MixedMartialArts => 80,
+            Sport::Snorkeling => 82,
+            Sport::Dance => 83,
+            Sport::JumpRope => 84,
+            Sport::All => 254,
+            Sport::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Sport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -4284,6 +6291,15 @@ impl Serialize for Sport {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for Sport {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Sport, invalid).map(Self::from)
+    }
+}
 #[doc = "Bit field corresponding to sport enum type (1 << sport)."]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportBits0 {
@@ -4312,6 +6328,19 @@ impl SportBits0 {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "generic" => Some(1i64),
+            "running" => Some(2i64),
+            "cycling" => Some(4i64),
+            "transition" => Some(8i64),
+            "fitness_equipment" => Some(16i64),
+            "swimming" => Some(32i64),
+            "basketball" => Some(64i64),
+            "soccer" => Some(128i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SportBits0::Generic => 1,
@@ -4328,6 +6357,21 @@ impl SportBits0 {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SportBits0::Generic => 1,
+            SportBits0::Running => 2,
+            SportBits0::Cycling => 4,
+            SportBits0::Transition => 8,
+            SportBits0::FitnessEquipment => 16,
+            SportBits0::Swimming => 32,
+            SportBits0::Basketball => 64,
+            SportBits0::Soccer => 128,
+            SportBits0::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SportBits0 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -4375,6 +6419,15 @@ impl Serialize for SportBits0 {
         }
     }
 }
+impl<'de> Deserialize<'de> for SportBits0 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SportBits0, invalid).map(Self::from)
+    }
+}
 #[doc = "Bit field corresponding to sport enum type (1 << (sport-8))."]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportBits1 {
@@ -4402,6 +6455,19 @@ impl SportBits1 {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "tennis" => Some(1i64),
+            "american_football" => Some(2i64),
+            "training" => Some(4i64),
+            "walking" => Some(8i64),
+            "cross_country_skiing" => Some(16i64),
+            "alpine_skiing" => Some(32i64),
+            "snowboarding" => Some(64i64),
+            "rowing" => Some(128i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SportBits1::Tennis => 1,
@@ -4418,6 +6484,21 @@ impl SportBits1 {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SportBits1::Tennis => 1,
+            SportBits1::AmericanFootball => 2,
+            SportBits1::Training => 4,
+            SportBits1::Walking => 8,
+            SportBits1::CrossCountrySkiing => 16,
+            SportBits1::AlpineSkiing => 32,
+            SportBits1::Snowboarding => 64,
+            SportBits1::Rowing => 128,
+            SportBits1::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SportBits1 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -4465,6 +6546,15 @@ impl Serialize for SportBits1 {
         }
     }
 }
+impl<'de> Deserialize<'de> for SportBits1 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SportBits1, invalid).map(Self::from)
+    }
+}
 #[doc = "Bit field corresponding to sport enum type (1 << (sport-16))."]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportBits2 {
@@ -4492,6 +6582,19 @@ impl SportBits2 {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "mountaineering" => Some(1i64),
+            "hiking" => Some(2i64),
+            "multisport" => Some(4i64),
+            "paddling" => Some(8i64),
+            "flying" => Some(16i64),
+            "e_biking" => Some(32i64),
+            "motorcycling" => Some(64i64),
+            "boating" => Some(128i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SportBits2::Mountaineering => 1,
@@ -4508,6 +6611,21 @@ impl SportBits2 {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SportBits2::Mountaineering => 1,
+            SportBits2::Hiking => 2,
+            SportBits2::Multisport => 4,
+            SportBits2::Paddling => 8,
+            SportBits2::Flying => 16,
+            SportBits2::EBiking => 32,
+            SportBits2::Motorcycling => 64,
+            SportBits2::Boating => 128,
+            SportBits2::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SportBits2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -4555,6 +6673,15 @@ impl Serialize for SportBits2 {
         }
     }
 }
+impl<'de> Deserialize<'de> for SportBits2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SportBits2, invalid).map(Self::from)
+    }
+}
 #[doc = "Bit field corresponding to sport enum type (1 << (sport-24))."]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportBits3 {
@@ -4582,6 +6709,19 @@ impl SportBits3 {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "driving" => Some(1i64),
+            "golf" => Some(2i64),
+            "hang_gliding" => Some(4i64),
+            "horseback_riding" => Some(8i64),
+            "hunting" => Some(16i64),
+            "fishing" => Some(32i64),
+            "inline_skating" => Some(64i64),
+            "rock_climbing" => Some(128i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SportBits3::Driving => 1,
@@ -4598,6 +6738,21 @@ impl SportBits3 {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SportBits3::Driving => 1,
+            SportBits3::Golf => 2,
+            SportBits3::HangGliding => 4,
+            SportBits3::HorsebackRiding => 8,
+            SportBits3::Hunting => 16,
+            SportBits3::Fishing => 32,
+            SportBits3::InlineSkating => 64,
+            SportBits3::RockClimbing => 128,
+            SportBits3::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SportBits3 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -4645,6 +6800,15 @@ impl Serialize for SportBits3 {
         }
     }
 }
+impl<'de> Deserialize<'de> for SportBits3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SportBits3, invalid).map(Self::from)
+    }
+}
 #[doc = "Bit field corresponding to sport enum type (1 << (sport-32))."]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportBits4 {
@@ -4672,6 +6836,19 @@ impl SportBits4 {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "sailing" => Some(1i64),
+            "ice_skating" => Some(2i64),
+            "sky_diving" => Some(4i64),
+            "snowshoeing" => Some(8i64),
+            "snowmobiling" => Some(16i64),
+            "stand_up_paddleboarding" => Some(32i64),
+            "surfing" => Some(64i64),
+            "wakeboarding" => Some(128i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SportBits4::Sailing => 1,
@@ -4688,15 +6865,30 @@ impl SportBits4 {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
-}
-impl fmt::Display for SportBits4 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self {
-            SportBits4::Sailing => write!(f, "sailing"),
-            SportBits4::IceSkating => write!(f, "ice_skating"),
-            SportBits4::SkyDiving => write!(f, "sky_diving"),
-            SportBits4::Snowshoeing => write!(f, "snowshoeing"),
-            SportBits4::Snowmobiling => write!(f, "snowmobiling"),
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SportBits4::Sailing => 1,
+            SportBits4::IceSkating => 2,
+            SportBits4::SkyDiving => 4,
+            SportBits4::Snowshoeing => 8,
+            SportBits4::Snowmobiling => 16,
+            SportBits4::StandUpPaddleboarding => 32,
+            SportBits4::Surfing => 64,
+            SportBits4::Wakeboarding => 128,
+            SportBits4::Value(value) => value as i64,
+        }
+    }
+}
+impl fmt::Display for SportBits4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            SportBits4::Sailing => write!(f, "sailing"),
+            SportBits4::IceSkating => write!(f, "ice_skating"),
+            SportBits4::SkyDiving => write!(f, "sky_diving"),
+            SportBits4::Snowshoeing => write!(f, "snowshoeing"),
+            SportBits4::Snowmobiling => write!(f, "snowmobiling"),
             SportBits4::StandUpPaddleboarding => write!(f, "stand_up_paddleboarding"),
             SportBits4::Surfing => write!(f, "surfing"),
             SportBits4::Wakeboarding => write!(f, "wakeboarding"),
@@ -4735,6 +6927,15 @@ impl Serialize for SportBits4 {
         }
     }
 }
+impl<'de> Deserialize<'de> for SportBits4 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SportBits4, invalid).map(Self::from)
+    }
+}
 #[doc = "Bit field corresponding to sport enum type (1 << (sport-40))."]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportBits5 {
@@ -4762,6 +6963,19 @@ impl SportBits5 {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "water_skiing" => Some(1i64),
+            "kayaking" => Some(2i64),
+            "rafting" => Some(4i64),
+            "windsurfing" => Some(8i64),
+            "kitesurfing" => Some(16i64),
+            "tactical" => Some(32i64),
+            "jumpmaster" => Some(64i64),
+            "boxing" => Some(128i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SportBits5::WaterSkiing => 1,
@@ -4778,6 +6992,21 @@ impl SportBits5 {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SportBits5::WaterSkiing => 1,
+            SportBits5::Kayaking => 2,
+            SportBits5::Rafting => 4,
+            SportBits5::Windsurfing => 8,
+            SportBits5::Kitesurfing => 16,
+            SportBits5::Tactical => 32,
+            SportBits5::Jumpmaster => 64,
+            SportBits5::Boxing => 128,
+            SportBits5::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SportBits5 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -4825,6 +7054,15 @@ impl Serialize for SportBits5 {
         }
     }
 }
+impl<'de> Deserialize<'de> for SportBits5 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SportBits5, invalid).map(Self::from)
+    }
+}
 #[doc = "Bit field corresponding to sport enum type (1 << (sport-48))."]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportBits6 {
@@ -4838,6 +7076,12 @@ impl SportBits6 {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "floor_climbing" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SportBits6::FloorClimbing => 1,
@@ -4847,6 +7091,14 @@ impl SportBits6 {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SportBits6::FloorClimbing => 1,
+            SportBits6::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SportBits6 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -4880,6 +7132,15 @@ impl Serialize for SportBits6 {
         }
     }
 }
+impl<'de> Deserialize<'de> for SportBits6 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SportBits6, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SubSport {
     Generic,
@@ -5142,6 +7403,100 @@ impl SubSport {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "generic" => Some(0i64),
+            "treadmill" => Some(1i64),
+            "street" => Some(2i64),
+            "trail" => Some(3i64),
+            "track" => Some(4i64),
+            "spin" => Some(5i64),
+            "indoor_cycling" => Some(6i64),
+            "road" => Some(7i64),
+            "mountain" => Some(8i64),
+            "downhill" => Some(9i64),
+            "recumbent" => Some(10i64),
+            "cyclocross" => Some(11i64),
+            "hand_cycling" => Some(12i64),
+            "track_cycling" => Some(13i64),
+            "indoor_rowing" => Some(14i64),
+            "elliptical" => Some(15i64),
+            "stair_climbing" => Some(16i64),
+            "lap_swimming" => Some(17i64),
+            "open_water" => Some(18i64),
+            "flexibility_training" => Some(19i64),
+            "strength_training" => Some(20i64),
+            "warm_up" => Some(21i64),
+            "match" => Some(22i64),
+            "exercise" => Some(23i64),
+            "challenge" => Some(24i64),
+            "indoor_skiing" => Some(25i64),
+            "cardio_training" => Some(26i64),
+            "indoor_walking" => Some(27i64),
+            "e_bike_fitness" => Some(28i64),
+            "bmx" => Some(29i64),
+            "casual_walking" => Some(30i64),
+            "speed_walking" => Some(31i64),
+            "bike_to_run_transition" => Some(32i64),
+            "run_to_bike_transition" => Some(33i64),
+            "swim_to_bike_transition" => Some(34i64),
+            "atv" => Some(35i64),
+            "motocross" => Some(36i64),
+            "backcountry" => Some(37i64),
+            "resort" => Some(38i64),
+            "rc_drone" => Some(39i64),
+            "wingsuit" => Some(40i64),
+            "whitewater" => Some(41i64),
+            "skate_skiing" => Some(42i64),
+            "yoga" => Some(43i64),
+            "pilates" => Some(44i64),
+            "indoor_running" => Some(45i64),
+            "gravel_cycling" => Some(46i64),
+            "e_bike_mountain" => Some(47i64),
+            "commuting" => Some(48i64),
+            "mixed_surface" => Some(49i64),
+            "navigate" => Some(50i64),
+            "track_me" => Some(51i64),
+            "map" => Some(52i64),
+            "single_gas_diving" => Some(53i64),
+            "multi_gas_diving" => Some(54i64),
+            "gauge_diving" => Some(55i64),
+            "apnea_diving" => Some(56i64),
+            "apnea_hunting" => Some(57i64),
+            "virtual_activity" => Some(58i64),
+            "obstacle" => Some(59i64),
+            "breathing" => Some(62i64),
+            "sail_race" => Some(65i64),
+            "ultra" => Some(67i64),
+            "indoor_climbing" => Some(68i64),
+            "bouldering" => Some(69i64),
+            "hiit" => Some(70i64),
+            "amrap" => Some(73i64),
+            "emom" => Some(74i64),
+            "tabata" => Some(75i64),
+            "pickleball" => Some(84i64),
+            "padel" => Some(85i64),
+            "indoor_wheelchair_walk" => Some(86i64),
+            "indoor_wheelchair_run" => Some(87i64),
+            "indoor_hand_cycling" => Some(88i64),
+            "squash" => Some(94i64),
+            "badminton" => Some(95i64),
+            "racquetball" => Some(96i64),
+            "table_tennis" => Some(97i64),
+            "fly_canopy" => Some(110i64),
+            "fly_paraglide" => Some(111i64),
+            "fly_paramotor" => Some(112i64),
+            "fly_pressurized" => Some(113i64),
+            "fly_navigate" => Some(114i64),
+            "fly_timer" => Some(115i64),
+            "fly_altimeter" => Some(116i64),
+            "fly_wx" => Some(117i64),
+            "fly_vfr" => Some(118i64),
+            "fly_ifr" => Some(119i64),
+            "all" => Some(254i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SubSport::Generic => 0,
@@ -5239,6 +7594,102 @@ impl SubSport {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SubSport::Generic => 0,
+            SubSport::Treadmill => 1,
+            SubSport::Street => 2,
+            SubSport::Trail => 3,
+            SubSport::Track => 4,
+            SubSport::Spin => 5,
+            SubSport::IndoorCycling => 6,
+            SubSport::Road => 7,
+            SubSport::Mountain => 8,
+            SubSport::Downhill => 9,
+            SubSport::Recumbent => 10,
+            SubSport::Cyclocross => 11,
+            SubSport::HandCycling => 12,
+            SubSport::TrackCycling => 13,
+            SubSport::IndoorRowing => 14,
+            SubSport::Elliptical => 15,
+            SubSport::StairClimbing => 16,
+            SubSport::LapSwimming => 17,
+            SubSport::OpenWater => 18,
+            SubSport::FlexibilityTraining => 19,
+            SubSport::StrengthTraining => 20,
+            SubSport::WarmUp => 21,
+            SubSport::Match => 22,
+            SubSport::Exercise => 23,
+            SubSport::Challenge => 24,
+            SubSport::IndoorSkiing => 25,
+            SubSport::CardioTraining => 26,
+            SubSport::IndoorWalking => 27,
+            SubSport::EBikeFitness => 28,
+            SubSport::Bmx => 29,
+            SubSport::CasualWalking => 30,
+            SubSport::SpeedWalking => 31,
+            SubSport::BikeToRunTransition => 32,
+            SubSport::RunToBikeTransition => 33,
+            SubSport::SwimToBikeTransition => 34,
+            SubSport::Atv => 35,
+            SubSport::Motocross => 36,
+            SubSport::Backcountry => 37,
+            SubSport::Resort => 38,
+            SubSport::RcDrone => 39,
+            SubSport::Wingsuit => 40,
+            SubSport::Whitewater => 41,
+            SubSport::SkateSkiing => 42,
+            SubSport::Yoga => 43,
+            SubSport::Pilates => 44,
+            SubSport::IndoorRunning => 45,
+            SubSport::GravelCycling => 46,
+            SubSport::EBikeMountain => 47,
+            SubSport::Commuting => 48,
+            SubSport::MixedSurface => 49,
+            SubSport::Navigate => 50,
+            SubSport::TrackMe => 51,
+            SubSport::Map => 52,
+            SubSport::SingleGasDiving => 53,
+            SubSport::MultiGasDiving => 54,
+            SubSport::GaugeDiving => 55,
+            SubSport::ApneaDiving => 56,
+            SubSport::ApneaHunting => 57,
+            SubSport::VirtualActivity => 58,
+            SubSport::Obstacle => 59,
+            SubSport::Breathing => 62,
+            SubSport::SailRace => 65,
+            SubSport::Ultra => 67,
+            SubSport::IndoorClimbing => 68,
+            SubSport::Bouldering => 69,
+            SubSport::Hiit => 70,
+            SubSport::Amrap => 73,
+            SubSport::Emom => 74,
+            SubSport::Tabata => 75,
+            SubSport::Pickleball => 84,
+            SubSport::Padel => 85,
+            SubSport::IndoorWheelchairWalk => 86,
+            SubSport::IndoorWheelchairRun => 87,
+            SubSport::IndoorHandCycling => 88,
+            SubSport::Squash => 94,
+            SubSport::Badminton => 95,
+            SubSport::Racquetball => 96,
+            SubSport::TableTennis => 97,
+            SubSport::FlyCanopy => 110,
+            SubSport::FlyParaglide => 111,
+            SubSport::FlyParamotor => 112,
+            SubSport::FlyPressurized => 113,
+            SubSport::FlyNavigate => 114,
+            SubSport::FlyTimer => 115,
+            SubSport::FlyAltimeter => 116,
+            SubSport::FlyWx => 117,
+            SubSport::FlyVfr => 118,
+            SubSport::FlyIfr => 119,
+            SubSport::All => 254,
+            SubSport::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SubSport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -5445,6 +7896,15 @@ impl Serialize for SubSport {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for SubSport {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SubSport, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportEvent {
     Uncategorized,
@@ -5473,6 +7933,20 @@ impl SportEvent {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "uncategorized" => Some(0i64),
+            "geocaching" => Some(1i64),
+            "fitness" => Some(2i64),
+            "recreation" => Some(3i64),
+            "race" => Some(4i64),
+            "special_event" => Some(5i64),
+            "training" => Some(6i64),
+            "transportation" => Some(7i64),
+            "touring" => Some(8i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SportEvent::Uncategorized => 0,
@@ -5490,6 +7964,22 @@ impl SportEvent {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SportEvent::Uncategorized => 0,
+            SportEvent::Geocaching => 1,
+            SportEvent::Fitness => 2,
+            SportEvent::Recreation => 3,
+            SportEvent::Race => 4,
+            SportEvent::SpecialEvent => 5,
+            SportEvent::Training => 6,
+            SportEvent::Transportation => 7,
+            SportEvent::Touring => 8,
+            SportEvent::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SportEvent {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -5536,6 +8026,15 @@ impl Serialize for SportEvent {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for SportEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SportEvent, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Activity {
     Manual,
@@ -5550,6 +8049,13 @@ impl Activity {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "manual" => Some(0i64),
+            "auto_multi_sport" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Activity::Manual => 0,
@@ -5560,6 +8066,15 @@ impl Activity {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Activity::Manual => 0,
+            Activity::AutoMultiSport => 1,
+            Activity::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Activity {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -5592,6 +8107,15 @@ impl Serialize for Activity {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for Activity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Activity, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Intensity {
     Active,
@@ -5616,6 +8140,18 @@ impl Intensity {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "active" => Some(0i64),
+            "rest" => Some(1i64),
+            "warmup" => Some(2i64),
+            "cooldown" => Some(3i64),
+            "recovery" => Some(4i64),
+            "interval" => Some(5i64),
+            "other" => Some(6i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Intensity::Active => 0,
@@ -5631,6 +8167,20 @@ impl Intensity {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Intensity::Active => 0,
+            Intensity::Rest => 1,
+            Intensity::Warmup => 2,
+            Intensity::Cooldown => 3,
+            Intensity::Recovery => 4,
+            Intensity::Interval => 5,
+            Intensity::Other => 6,
+            Intensity::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Intensity {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -5673,6 +8223,15 @@ impl Serialize for Intensity {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for Intensity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Intensity, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SessionTrigger {
     ActivityEnd,
@@ -5694,6 +8253,15 @@ impl SessionTrigger {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "activity_end" => Some(0i64),
+            "manual" => Some(1i64),
+            "auto_multi_sport" => Some(2i64),
+            "fitness_equipment" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SessionTrigger::ActivityEnd => 0,
@@ -5706,6 +8274,17 @@ impl SessionTrigger {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SessionTrigger::ActivityEnd => 0,
+            SessionTrigger::Manual => 1,
+            SessionTrigger::AutoMultiSport => 2,
+            SessionTrigger::FitnessEquipment => 3,
+            SessionTrigger::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SessionTrigger {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -5742,6 +8321,15 @@ impl Serialize for SessionTrigger {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for SessionTrigger {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SessionTrigger, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum AutolapTrigger {
     Time,
@@ -5766,6 +8354,18 @@ impl AutolapTrigger {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "time" => Some(0i64),
+            "distance" => Some(1i64),
+            "position_start" => Some(2i64),
+            "position_lap" => Some(3i64),
+            "position_waypoint" => Some(4i64),
+            "position_marked" => Some(5i64),
+            "off" => Some(6i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             AutolapTrigger::Time => 0,
@@ -5781,6 +8381,20 @@ impl AutolapTrigger {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            AutolapTrigger::Time => 0,
+            AutolapTrigger::Distance => 1,
+            AutolapTrigger::PositionStart => 2,
+            AutolapTrigger::PositionLap => 3,
+            AutolapTrigger::PositionWaypoint => 4,
+            AutolapTrigger::PositionMarked => 5,
+            AutolapTrigger::Off => 6,
+            AutolapTrigger::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for AutolapTrigger {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -5823,6 +8437,15 @@ impl Serialize for AutolapTrigger {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for AutolapTrigger {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::AutolapTrigger, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LapTrigger {
     Manual,
@@ -5851,6 +8474,20 @@ impl LapTrigger {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "manual" => Some(0i64),
+            "time" => Some(1i64),
+            "distance" => Some(2i64),
+            "position_start" => Some(3i64),
+            "position_lap" => Some(4i64),
+            "position_waypoint" => Some(5i64),
+            "position_marked" => Some(6i64),
+            "session_end" => Some(7i64),
+            "fitness_equipment" => Some(8i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             LapTrigger::Manual => 0,
@@ -5868,6 +8505,22 @@ impl LapTrigger {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LapTrigger::Manual => 0,
+            LapTrigger::Time => 1,
+            LapTrigger::Distance => 2,
+            LapTrigger::PositionStart => 3,
+            LapTrigger::PositionLap => 4,
+            LapTrigger::PositionWaypoint => 5,
+            LapTrigger::PositionMarked => 6,
+            LapTrigger::SessionEnd => 7,
+            LapTrigger::FitnessEquipment => 8,
+            LapTrigger::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LapTrigger {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -5914,6 +8567,15 @@ impl Serialize for LapTrigger {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for LapTrigger {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::LapTrigger, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum TimeMode {
     Hour12,
@@ -5938,6 +8600,17 @@ impl TimeMode {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "hour12" => Some(0i64),
+            "hour24" => Some(1i64),
+            "military" => Some(2i64),
+            "hour_12_with_seconds" => Some(3i64),
+            "hour_24_with_seconds" => Some(4i64),
+            "utc" => Some(5i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             TimeMode::Hour12 => 0,
@@ -5952,6 +8625,19 @@ impl TimeMode {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            TimeMode::Hour12 => 0,
+            TimeMode::Hour24 => 1,
+            TimeMode::Military => 2,
+            TimeMode::Hour12WithSeconds => 3,
+            TimeMode::Hour24WithSeconds => 4,
+            TimeMode::Utc => 5,
+            TimeMode::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for TimeMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -5992,6 +8678,15 @@ impl Serialize for TimeMode {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for TimeMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::TimeMode, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum BacklightMode {
     Off,
@@ -6016,6 +8711,18 @@ impl BacklightMode {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "off" => Some(0i64),
+            "manual" => Some(1i64),
+            "key_and_messages" => Some(2i64),
+            "auto_brightness" => Some(3i64),
+            "smart_notifications" => Some(4i64),
+            "key_and_messages_night" => Some(5i64),
+            "key_and_messages_and_smart_notifications" => Some(6i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             BacklightMode::Off => 0,
@@ -6031,6 +8738,20 @@ impl BacklightMode {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            BacklightMode::Off => 0,
+            BacklightMode::Manual => 1,
+            BacklightMode::KeyAndMessages => 2,
+            BacklightMode::AutoBrightness => 3,
+            BacklightMode::SmartNotifications => 4,
+            BacklightMode::KeyAndMessagesNight => 5,
+            BacklightMode::KeyAndMessagesAndSmartNotifications => 6,
+            BacklightMode::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for BacklightMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -6075,6 +8796,15 @@ impl Serialize for BacklightMode {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for BacklightMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::BacklightMode, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DateMode {
     DayMonth,
@@ -6089,6 +8819,13 @@ impl DateMode {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "day_month" => Some(0i64),
+            "month_day" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             DateMode::DayMonth => 0,
@@ -6099,6 +8836,15 @@ impl DateMode {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DateMode::DayMonth => 0,
+            DateMode::MonthDay => 1,
+            DateMode::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DateMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -6131,6 +8877,15 @@ impl Serialize for DateMode {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for DateMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DateMode, invalid).map(Self::from)
+    }
+}
 #[doc = "Timeout in seconds."]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum BacklightTimeout {
@@ -6145,6 +8900,12 @@ impl BacklightTimeout {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "infinite" => Some(0i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             BacklightTimeout::Infinite => 0,
@@ -6154,6 +8915,14 @@ impl BacklightTimeout {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            BacklightTimeout::Infinite => 0,
+            BacklightTimeout::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for BacklightTimeout {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -6187,6 +8956,15 @@ impl Serialize for BacklightTimeout {
         }
     }
 }
+impl<'de> Deserialize<'de> for BacklightTimeout {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::BacklightTimeout, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Event {
     #[doc = "Group 0. Start / stop_all"]
@@ -6335,6 +9113,57 @@ impl Event {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "timer" => Some(0i64),
+            "workout" => Some(3i64),
+            "workout_step" => Some(4i64),
+            "power_down" => Some(5i64),
+            "power_up" => Some(6i64),
+            "off_course" => Some(7i64),
+            "session" => Some(8i64),
+            "lap" => Some(9i64),
+            "course_point" => Some(10i64),
+            "battery" => Some(11i64),
+            "virtual_partner_pace" => Some(12i64),
+            "hr_high_alert" => Some(13i64),
+            "hr_low_alert" => Some(14i64),
+            "speed_high_alert" => Some(15i64),
+            "speed_low_alert" => Some(16i64),
+            "cad_high_alert" => Some(17i64),
+            "cad_low_alert" => Some(18i64),
+            "power_high_alert" => Some(19i64),
+            "power_low_alert" => Some(20i64),
+            "recovery_hr" => Some(21i64),
+            "battery_low" => Some(22i64),
+            "time_duration_alert" => Some(23i64),
+            "distance_duration_alert" => Some(24i64),
+            "calorie_duration_alert" => Some(25i64),
+            "activity" => Some(26i64),
+            "fitness_equipment" => Some(27i64),
+            "length" => Some(28i64),
+            "user_marker" => Some(32i64),
+            "sport_point" => Some(33i64),
+            "calibration" => Some(36i64),
+            "front_gear_change" => Some(42i64),
+            "rear_gear_change" => Some(43i64),
+            "rider_position_change" => Some(44i64),
+            "elev_high_alert" => Some(45i64),
+            "elev_low_alert" => Some(46i64),
+            "comm_timeout" => Some(47i64),
+            "auto_activity_detect" => Some(54i64),
+            "dive_alert" => Some(56i64),
+            "dive_gas_switched" => Some(57i64),
+            "tank_pressure_reserve" => Some(71i64),
+            "tank_pressure_critical" => Some(72i64),
+            "tank_lost" => Some(73i64),
+            "radar_threat_alert" => Some(75i64),
+            "tank_battery_low" => Some(76i64),
+            "tank_pod_connected" => Some(81i64),
+            "tank_pod_disconnected" => Some(82i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Event::Timer => 0,
@@ -6389,6 +9218,59 @@ impl Event {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Event::Timer => 0,
+            Event::Workout => 3,
+            Event::WorkoutStep => 4,
+            Event::PowerDown => 5,
+            Event::PowerUp => 6,
+            Event::OffCourse => 7,
+            Event::Session => 8,
+            Event::Lap => 9,
+            Event::CoursePoint => 10,
+            Event::Battery => 11,
+            Event::VirtualPartnerPace => 12,
+            Event::HrHighAlert => 13,
+            Event::HrLowAlert => 14,
+            Event::SpeedHighAlert => 15,
+            Event::SpeedLowAlert => 16,
+            Event::CadHighAlert => 17,
+            Event::CadLowAlert => 18,
+            Event::PowerHighAlert => 19,
+            Event::PowerLowAlert => 20,
+            Event::RecoveryHr => 21,
+            Event::BatteryLow => 22,
+            Event::TimeDurationAlert => 23,
+            Event::DistanceDurationAlert => 24,
+            Event::CalorieDurationAlert => 25,
+            Event::Activity => 26,
+            Event::FitnessEquipment => 27,
+            Event::Length => 28,
+            Event::UserMarker => 32,
+            Event::SportPoint => 33,
+            Event::Calibration => 36,
+            Event::FrontGearChange => 42,
+            Event::RearGearChange => 43,
+            Event::RiderPositionChange => 44,
+            Event::ElevHighAlert => 45,
+            Event::ElevLowAlert => 46,
+            Event::CommTimeout => 47,
+            Event::AutoActivityDetect => 54,
+            Event::DiveAlert => 56,
+            Event::DiveGasSwitched => 57,
+            Event::TankPressureReserve => 71,
+            Event::TankPressureCritical => 72,
+            Event::TankLost => 73,
+            Event::RadarThreatAlert => 75,
+            Event::TankBatteryLow => 76,
+            Event::TankPodConnected => 81,
+            Event::TankPodDisconnected => 82,
+            Event::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Event {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -6509,6 +9391,15 @@ impl Serialize for Event {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Event, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum EventType {
     Start,
@@ -6539,6 +9430,21 @@ impl EventType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "start" => Some(0i64),
+            "stop" => Some(1i64),
+            "consecutive_depreciated" => Some(2i64),
+            "marker" => Some(3i64),
+            "stop_all" => Some(4i64),
+            "begin_depreciated" => Some(5i64),
+            "end_depreciated" => Some(6i64),
+            "end_all_depreciated" => Some(7i64),
+            "stop_disable" => Some(8i64),
+            "stop_disable_all" => Some(9i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             EventType::Start => 0,
@@ -6557,6 +9463,23 @@ impl EventType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            EventType::Start => 0,
+            EventType::Stop => 1,
+            EventType::ConsecutiveDepreciated => 2,
+            EventType::Marker => 3,
+            EventType::StopAll => 4,
+            EventType::BeginDepreciated => 5,
+            EventType::EndDepreciated => 6,
+            EventType::EndAllDepreciated => 7,
+            EventType::StopDisable => 8,
+            EventType::StopDisableAll => 9,
+            EventType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for EventType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -6605,6 +9528,15 @@ impl Serialize for EventType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::EventType, invalid).map(Self::from)
+    }
+}
 #[doc = "timer event data"]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum TimerTrigger {
@@ -6622,6 +9554,14 @@ impl TimerTrigger {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "manual" => Some(0i64),
+            "auto" => Some(1i64),
+            "fitness_equipment" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             TimerTrigger::Manual => 0,
@@ -6633,6 +9573,16 @@ impl TimerTrigger {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            TimerTrigger::Manual => 0,
+            TimerTrigger::Auto => 1,
+            TimerTrigger::FitnessEquipment => 2,
+            TimerTrigger::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for TimerTrigger {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -6667,6 +9617,15 @@ impl Serialize for TimerTrigger {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for TimerTrigger {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::TimerTrigger, invalid).map(Self::from)
+    }
+}
 #[doc = "fitness equipment event data"]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum FitnessEquipmentState {
@@ -6687,6 +9646,15 @@ impl FitnessEquipmentState {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "ready" => Some(0i64),
+            "in_use" => Some(1i64),
+            "paused" => Some(2i64),
+            "unknown" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             FitnessEquipmentState::Ready => 0,
@@ -6699,6 +9667,17 @@ impl FitnessEquipmentState {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            FitnessEquipmentState::Ready => 0,
+            FitnessEquipmentState::InUse => 1,
+            FitnessEquipmentState::Paused => 2,
+            FitnessEquipmentState::Unknown => 3,
+            FitnessEquipmentState::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for FitnessEquipmentState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -6735,6 +9714,16 @@ impl Serialize for FitnessEquipmentState {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for FitnessEquipmentState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::FitnessEquipmentState, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Tone {
     Off,
@@ -6753,6 +9742,15 @@ impl Tone {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "off" => Some(0i64),
+            "tone" => Some(1i64),
+            "vibrate" => Some(2i64),
+            "tone_and_vibrate" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Tone::Off => 0,
@@ -6765,6 +9763,17 @@ impl Tone {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Tone::Off => 0,
+            Tone::Tone => 1,
+            Tone::Vibrate => 2,
+            Tone::ToneAndVibrate => 3,
+            Tone::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Tone {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -6801,6 +9810,15 @@ impl Serialize for Tone {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for Tone {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Tone, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Autoscroll {
     None,
@@ -6819,6 +9837,15 @@ impl Autoscroll {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "none" => Some(0i64),
+            "slow" => Some(1i64),
+            "medium" => Some(2i64),
+            "fast" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Autoscroll::None => 0,
@@ -6831,6 +9858,17 @@ impl Autoscroll {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Autoscroll::None => 0,
+            Autoscroll::Slow => 1,
+            Autoscroll::Medium => 2,
+            Autoscroll::Fast => 3,
+            Autoscroll::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Autoscroll {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -6867,6 +9905,15 @@ impl Serialize for Autoscroll {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for Autoscroll {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Autoscroll, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ActivityClass {
     LevelMax,
@@ -6884,6 +9931,14 @@ impl ActivityClass {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "level_max" => Some(100i64),
+            "level" => Some(127i64),
+            "athlete" => Some(128i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             ActivityClass::LevelMax => 100,
@@ -6895,6 +9950,16 @@ impl ActivityClass {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ActivityClass::LevelMax => 100,
+            ActivityClass::Level => 127,
+            ActivityClass::Athlete => 128,
+            ActivityClass::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ActivityClass {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -6929,6 +9994,15 @@ impl Serialize for ActivityClass {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for ActivityClass {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::ActivityClass, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum HrZoneCalc {
     Custom,
@@ -6947,6 +10021,15 @@ impl HrZoneCalc {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "custom" => Some(0i64),
+            "percent_max_hr" => Some(1i64),
+            "percent_hrr" => Some(2i64),
+            "percent_lthr" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             HrZoneCalc::Custom => 0,
@@ -6959,6 +10042,17 @@ impl HrZoneCalc {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            HrZoneCalc::Custom => 0,
+            HrZoneCalc::PercentMaxHr => 1,
+            HrZoneCalc::PercentHrr => 2,
+            HrZoneCalc::PercentLthr => 3,
+            HrZoneCalc::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for HrZoneCalc {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -6995,6 +10089,15 @@ impl Serialize for HrZoneCalc {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for HrZoneCalc {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::HrZoneCalc, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum PwrZoneCalc {
     Custom,
@@ -7009,6 +10112,13 @@ impl PwrZoneCalc {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "custom" => Some(0i64),
+            "percent_ftp" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             PwrZoneCalc::Custom => 0,
@@ -7019,6 +10129,15 @@ impl PwrZoneCalc {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            PwrZoneCalc::Custom => 0,
+            PwrZoneCalc::PercentFtp => 1,
+            PwrZoneCalc::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for PwrZoneCalc {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -7051,6 +10170,15 @@ impl Serialize for PwrZoneCalc {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for PwrZoneCalc {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::PwrZoneCalc, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WktStepDuration {
     Time,
@@ -7123,6 +10251,42 @@ impl WktStepDuration {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "time" => Some(0i64),
+            "distance" => Some(1i64),
+            "hr_less_than" => Some(2i64),
+            "hr_greater_than" => Some(3i64),
+            "calories" => Some(4i64),
+            "open" => Some(5i64),
+            "repeat_until_steps_cmplt" => Some(6i64),
+            "repeat_until_time" => Some(7i64),
+            "repeat_until_distance" => Some(8i64),
+            "repeat_until_calories" => Some(9i64),
+            "repeat_until_hr_less_than" => Some(10i64),
+            "repeat_until_hr_greater_than" => Some(11i64),
+            "repeat_until_power_less_than" => Some(12i64),
+            "repeat_until_power_greater_than" => Some(13i64),
+            "power_less_than" => Some(14i64),
+            "power_greater_than" => Some(15i64),
+            "training_peaks_tss" => Some(16i64),
+            "repeat_until_power_last_lap_less_than" => Some(17i64),
+            "repeat_until_max_power_last_lap_less_than" => Some(18i64),
+            "power_3s_less_than" => Some(19i64),
+            "power_10s_less_than" => Some(20i64),
+            "power_30s_less_than" => Some(21i64),
+            "power_3s_greater_than" => Some(22i64),
+            "power_10s_greater_than" => Some(23i64),
+            "power_30s_greater_than" => Some(24i64),
+            "power_lap_less_than" => Some(25i64),
+            "power_lap_greater_than" => Some(26i64),
+            "repeat_until_training_peaks_tss" => Some(27i64),
+            "repetition_time" => Some(28i64),
+            "reps" => Some(29i64),
+            "time_only" => Some(31i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             WktStepDuration::Time => 0,
@@ -7162,6 +10326,44 @@ impl WktStepDuration {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            WktStepDuration::Time => 0,
+            WktStepDuration::Distance => 1,
+            WktStepDuration::HrLessThan => 2,
+            WktStepDuration::HrGreaterThan => 3,
+            WktStepDuration::Calories => 4,
+            WktStepDuration::Open => 5,
+            WktStepDuration::RepeatUntilStepsCmplt => 6,
+            WktStepDuration::RepeatUntilTime => 7,
+            WktStepDuration::RepeatUntilDistance => 8,
+            WktStepDuration::RepeatUntilCalories => 9,
+            WktStepDuration::RepeatUntilHrLessThan => 10,
+            WktStepDuration::RepeatUntilHrGreaterThan => 11,
+            WktStepDuration::RepeatUntilPowerLessThan => 12,
+            WktStepDuration::RepeatUntilPowerGreaterThan => 13,
+            WktStepDuration::PowerLessThan => 14,
+            WktStepDuration::PowerGreaterThan => 15,
+            WktStepDuration::TrainingPeaksTss => 16,
+            WktStepDuration::RepeatUntilPowerLastLapLessThan => 17,
+            WktStepDuration::RepeatUntilMaxPowerLastLapLessThan => 18,
+            WktStepDuration::Power3sLessThan => 19,
+            WktStepDuration::Power10sLessThan => 20,
+            WktStepDuration::Power30sLessThan => 21,
+            WktStepDuration::Power3sGreaterThan => 22,
+            WktStepDuration::Power10sGreaterThan => 23,
+            WktStepDuration::Power30sGreaterThan => 24,
+            WktStepDuration::PowerLapLessThan => 25,
+            WktStepDuration::PowerLapGreaterThan => 26,
+            WktStepDuration::RepeatUntilTrainingPeaksTss => 27,
+            WktStepDuration::RepetitionTime => 28,
+            WktStepDuration::Reps => 29,
+            WktStepDuration::TimeOnly => 31,
+            WktStepDuration::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for WktStepDuration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -7260,6 +10462,15 @@ impl Serialize for WktStepDuration {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for WktStepDuration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::WktStepDuration, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WktStepTarget {
     Speed,
@@ -7298,6 +10509,25 @@ impl WktStepTarget {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "speed" => Some(0i64),
+            "heart_rate" => Some(1i64),
+            "open" => Some(2i64),
+            "cadence" => Some(3i64),
+            "power" => Some(4i64),
+            "grade" => Some(5i64),
+            "resistance" => Some(6i64),
+            "power_3s" => Some(7i64),
+            "power_10s" => Some(8i64),
+            "power_30s" => Some(9i64),
+            "power_lap" => Some(10i64),
+            "swim_stroke" => Some(11i64),
+            "speed_lap" => Some(12i64),
+            "heart_rate_lap" => Some(13i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             WktStepTarget::Speed => 0,
@@ -7320,22 +10550,43 @@ impl WktStepTarget {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
-}
-impl fmt::Display for WktStepTarget {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self {
-            WktStepTarget::Speed => write!(f, "speed"),
-            WktStepTarget::HeartRate => write!(f, "heart_rate"),
-            WktStepTarget::Open => write!(f, "open"),
-            WktStepTarget::Cadence => write!(f, "cadence"),
-            WktStepTarget::Power => write!(f, "power"),
-            WktStepTarget::Grade => write!(f, "grade"),
-            WktStepTarget::Resistance => write!(f, "resistance"),
-            WktStepTarget::Power3s => write!(f, "power_3s"),
-            WktStepTarget::Power10s => write!(f, "power_10s"),
-            WktStepTarget::Power30s => write!(f, "power_30s"),
-            WktStepTarget::PowerLap => write!(f, "power_lap"),
-            WktStepTarget::SwimStroke => write!(f, "swim_stroke"),
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            WktStepTarget::Speed => 0,
+            WktStepTarget::HeartRate => 1,
+            WktStepTarget::Open => 2,
+            WktStepTarget::Cadence => 3,
+            WktStepTarget::Power => 4,
+            WktStepTarget::Grade => 5,
+            WktStepTarget::Resistance => 6,
+            WktStepTarget::Power3s => 7,
+            WktStepTarget::Power10s => 8,
+            WktStepTarget::Power30s => 9,
+            WktStepTarget::PowerLap => 10,
+            WktStepTarget::SwimStroke => 11,
+            WktStepTarget::SpeedLap => 12,
+            WktStepTarget::HeartRateLap => 13,
+            WktStepTarget::UnknownVariant(value) => value as i64,
+        }
+    }
+}
+impl fmt::Display for WktStepTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            WktStepTarget::Speed => write!(f, "speed"),
+            WktStepTarget::HeartRate => write!(f, "heart_rate"),
+            WktStepTarget::Open => write!(f, "open"),
+            WktStepTarget::Cadence => write!(f, "cadence"),
+            WktStepTarget::Power => write!(f, "power"),
+            WktStepTarget::Grade => write!(f, "grade"),
+            WktStepTarget::Resistance => write!(f, "resistance"),
+            WktStepTarget::Power3s => write!(f, "power_3s"),
+            WktStepTarget::Power10s => write!(f, "power_10s"),
+            WktStepTarget::Power30s => write!(f, "power_30s"),
+            WktStepTarget::PowerLap => write!(f, "power_lap"),
+            WktStepTarget::SwimStroke => write!(f, "swim_stroke"),
             WktStepTarget::SpeedLap => write!(f, "speed_lap"),
             WktStepTarget::HeartRateLap => write!(f, "heart_rate_lap"),
             WktStepTarget::UnknownVariant(value) => write!(f, "unknown_variant_{}", value),
@@ -7376,6 +10627,15 @@ impl Serialize for WktStepTarget {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for WktStepTarget {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::WktStepTarget, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Goal {
     Time,
@@ -7400,6 +10660,18 @@ impl Goal {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "time" => Some(0i64),
+            "distance" => Some(1i64),
+            "calories" => Some(2i64),
+            "frequency" => Some(3i64),
+            "steps" => Some(4i64),
+            "ascent" => Some(5i64),
+            "active_minutes" => Some(6i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Goal::Time => 0,
@@ -7415,6 +10687,20 @@ impl Goal {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Goal::Time => 0,
+            Goal::Distance => 1,
+            Goal::Calories => 2,
+            Goal::Frequency => 3,
+            Goal::Steps => 4,
+            Goal::Ascent => 5,
+            Goal::ActiveMinutes => 6,
+            Goal::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Goal {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -7457,6 +10743,15 @@ impl Serialize for Goal {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for Goal {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Goal, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum GoalRecurrence {
     Off,
@@ -7479,6 +10774,17 @@ impl GoalRecurrence {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "off" => Some(0i64),
+            "daily" => Some(1i64),
+            "weekly" => Some(2i64),
+            "monthly" => Some(3i64),
+            "yearly" => Some(4i64),
+            "custom" => Some(5i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             GoalRecurrence::Off => 0,
@@ -7493,6 +10799,19 @@ impl GoalRecurrence {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            GoalRecurrence::Off => 0,
+            GoalRecurrence::Daily => 1,
+            GoalRecurrence::Weekly => 2,
+            GoalRecurrence::Monthly => 3,
+            GoalRecurrence::Yearly => 4,
+            GoalRecurrence::Custom => 5,
+            GoalRecurrence::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for GoalRecurrence {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -7533,6 +10852,15 @@ impl Serialize for GoalRecurrence {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for GoalRecurrence {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::GoalRecurrence, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum GoalSource {
     #[doc = "Device generated"]
@@ -7552,6 +10880,14 @@ impl GoalSource {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "auto" => Some(0i64),
+            "community" => Some(1i64),
+            "user" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             GoalSource::Auto => 0,
@@ -7563,6 +10899,16 @@ impl GoalSource {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            GoalSource::Auto => 0,
+            GoalSource::Community => 1,
+            GoalSource::User => 2,
+            GoalSource::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for GoalSource {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -7597,6 +10943,15 @@ impl Serialize for GoalSource {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for GoalSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::GoalSource, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Schedule {
     Workout,
@@ -7611,6 +10966,13 @@ impl Schedule {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "workout" => Some(0i64),
+            "course" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Schedule::Workout => 0,
@@ -7621,6 +10983,15 @@ impl Schedule {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Schedule::Workout => 0,
+            Schedule::Course => 1,
+            Schedule::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Schedule {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -7653,6 +11024,15 @@ impl Serialize for Schedule {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for Schedule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Schedule, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum CoursePoint {
     Generic,
@@ -7770,6 +11150,64 @@ impl CoursePoint {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "generic" => Some(0i64),
+            "summit" => Some(1i64),
+            "valley" => Some(2i64),
+            "water" => Some(3i64),
+            "food" => Some(4i64),
+            "danger" => Some(5i64),
+            "left" => Some(6i64),
+            "right" => Some(7i64),
+            "straight" => Some(8i64),
+            "first_aid" => Some(9i64),
+            "fourth_category" => Some(10i64),
+            "third_category" => Some(11i64),
+            "second_category" => Some(12i64),
+            "first_category" => Some(13i64),
+            "hors_category" => Some(14i64),
+            "sprint" => Some(15i64),
+            "left_fork" => Some(16i64),
+            "right_fork" => Some(17i64),
+            "middle_fork" => Some(18i64),
+            "slight_left" => Some(19i64),
+            "sharp_left" => Some(20i64),
+            "slight_right" => Some(21i64),
+            "sharp_right" => Some(22i64),
+            "u_turn" => Some(23i64),
+            "segment_start" => Some(24i64),
+            "segment_end" => Some(25i64),
+            "campsite" => Some(27i64),
+            "aid_station" => Some(28i64),
+            "rest_area" => Some(29i64),
+            "general_distance" => Some(30i64),
+            "service" => Some(31i64),
+            "energy_gel" => Some(32i64),
+            "sports_drink" => Some(33i64),
+            "mile_marker" => Some(34i64),
+            "checkpoint" => Some(35i64),
+            "shelter" => Some(36i64),
+            "meeting_spot" => Some(37i64),
+            "overlook" => Some(38i64),
+            "toilet" => Some(39i64),
+            "shower" => Some(40i64),
+            "gear" => Some(41i64),
+            "sharp_curve" => Some(42i64),
+            "steep_incline" => Some(43i64),
+            "tunnel" => Some(44i64),
+            "bridge" => Some(45i64),
+            "obstacle" => Some(46i64),
+            "crossing" => Some(47i64),
+            "store" => Some(48i64),
+            "transition" => Some(49i64),
+            "navaid" => Some(50i64),
+            "transport" => Some(51i64),
+            "alert" => Some(52i64),
+            "info" => Some(53i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             CoursePoint::Generic => 0,
@@ -7831,6 +11269,66 @@ impl CoursePoint {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            CoursePoint::Generic => 0,
+            CoursePoint::Summit => 1,
+            CoursePoint::Valley => 2,
+            CoursePoint::Water => 3,
+            CoursePoint::Food => 4,
+            CoursePoint::Danger => 5,
+            CoursePoint::Left => 6,
+            CoursePoint::Right => 7,
+            CoursePoint::Straight => 8,
+            CoursePoint::FirstAid => 9,
+            CoursePoint::FourthCategory => 10,
+            CoursePoint::ThirdCategory => 11,
+            CoursePoint::SecondCategory => 12,
+            CoursePoint::FirstCategory => 13,
+            CoursePoint::HorsCategory => 14,
+            CoursePoint::Sprint => 15,
+            CoursePoint::LeftFork => 16,
+            CoursePoint::RightFork => 17,
+            CoursePoint::MiddleFork => 18,
+            CoursePoint::SlightLeft => 19,
+            CoursePoint::SharpLeft => 20,
+            CoursePoint::SlightRight => 21,
+            CoursePoint::SharpRight => 22,
+            CoursePoint::UTurn => 23,
+            CoursePoint::SegmentStart => 24,
+            CoursePoint::SegmentEnd => 25,
+            CoursePoint::Campsite => 27,
+            CoursePoint::AidStation => 28,
+            CoursePoint::RestArea => 29,
+            CoursePoint::GeneralDistance => 30,
+            CoursePoint::Service => 31,
+            CoursePoint::EnergyGel => 32,
+            CoursePoint::SportsDrink => 33,
+            CoursePoint::MileMarker => 34,
+            CoursePoint::Checkpoint => 35,
+            CoursePoint::Shelter => 36,
+            CoursePoint::MeetingSpot => 37,
+            CoursePoint::Overlook => 38,
+            CoursePoint::Toilet => 39,
+            CoursePoint::Shower => 40,
+            CoursePoint::Gear => 41,
+            CoursePoint::SharpCurve => 42,
+            CoursePoint::SteepIncline => 43,
+            CoursePoint::Tunnel => 44,
+            CoursePoint::Bridge => 45,
+            CoursePoint::Obstacle => 46,
+            CoursePoint::Crossing => 47,
+            CoursePoint::Store => 48,
+            CoursePoint::Transition => 49,
+            CoursePoint::Navaid => 50,
+            CoursePoint::Transport => 51,
+            CoursePoint::Alert => 52,
+            CoursePoint::Info => 53,
+            CoursePoint::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for CoursePoint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -7965,6 +11463,15 @@ impl Serialize for CoursePoint {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for CoursePoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::CoursePoint, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Manufacturer {
     Garmin,
@@ -8431,6 +11938,234 @@ impl Manufacturer {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "garmin" => Some(1i64),
+            "garmin_fr405_antfs" => Some(2i64),
+            "zephyr" => Some(3i64),
+            "dayton" => Some(4i64),
+            "idt" => Some(5i64),
+            "srm" => Some(6i64),
+            "quarq" => Some(7i64),
+            "ibike" => Some(8i64),
+            "saris" => Some(9i64),
+            "spark_hk" => Some(10i64),
+            "tanita" => Some(11i64),
+            "echowell" => Some(12i64),
+            "dynastream_oem" => Some(13i64),
+            "nautilus" => Some(14i64),
+            "dynastream" => Some(15i64),
+            "timex" => Some(16i64),
+            "metrigear" => Some(17i64),
+            "xelic" => Some(18i64),
+            "beurer" => Some(19i64),
+            "cardiosport" => Some(20i64),
+            "a_and_d" => Some(21i64),
+            "hmm" => Some(22i64),
+            "suunto" => Some(23i64),
+            "thita_elektronik" => Some(24i64),
+            "gpulse" => Some(25i64),
+            "clean_mobile" => Some(26i64),
+            "pedal_brain" => Some(27i64),
+            "peaksware" => Some(28i64),
+            "saxonar" => Some(29i64),
+            "lemond_fitness" => Some(30i64),
+            "dexcom" => Some(31i64),
+            "wahoo_fitness" => Some(32i64),
+            "octane_fitness" => Some(33i64),
+            "archinoetics" => Some(34i64),
+            "the_hurt_box" => Some(35i64),
+            "citizen_systems" => Some(36i64),
+            "magellan" => Some(37i64),
+            "osynce" => Some(38i64),
+            "holux" => Some(39i64),
+            "concept2" => Some(40i64),
+            "shimano" => Some(41i64),
+            "one_giant_leap" => Some(42i64),
+            "ace_sensor" => Some(43i64),
+            "brim_brothers" => Some(44i64),
+            "xplova" => Some(45i64),
+            "perception_digital" => Some(46i64),
+            "bf1systems" => Some(47i64),
+            "pioneer" => Some(48i64),
+            "spantec" => Some(49i64),
+            "metalogics" => Some(50i64),
+            "4iiiis" => Some(51i64),
+            "seiko_epson" => Some(52i64),
+            "seiko_epson_oem" => Some(53i64),
+            "ifor_powell" => Some(54i64),
+            "maxwell_guider" => Some(55i64),
+            "star_trac" => Some(56i64),
+            "breakaway" => Some(57i64),
+            "alatech_technology_ltd" => Some(58i64),
+            "mio_technology_europe" => Some(59i64),
+            "rotor" => Some(60i64),
+            "geonaute" => Some(61i64),
+            "id_bike" => Some(62i64),
+            "specialized" => Some(63i64),
+            "wtek" => Some(64i64),
+            "physical_enterprises" => Some(65i64),
+            "north_pole_engineering" => Some(66i64),
+            "bkool" => Some(67i64),
+            "cateye" => Some(68i64),
+            "stages_cycling" => Some(69i64),
+            "sigmasport" => Some(70i64),
+            "tomtom" => Some(71i64),
+            "peripedal" => Some(72i64),
+            "wattbike" => Some(73i64),
+            "moxy" => Some(76i64),
+            "ciclosport" => Some(77i64),
+            "powerbahn" => Some(78i64),
+            "acorn_projects_aps" => Some(79i64),
+            "lifebeam" => Some(80i64),
+            "bontrager" => Some(81i64),
+            "wellgo" => Some(82i64),
+            "scosche" => Some(83i64),
+            "magura" => Some(84i64),
+            "woodway" => Some(85i64),
+            "elite" => Some(86i64),
+            "nielsen_kellerman" => Some(87i64),
+            "dk_city" => Some(88i64),
+            "tacx" => Some(89i64),
+            "direction_technology" => Some(90i64),
+            "magtonic" => Some(91i64),
+            "1partcarbon" => Some(92i64),
+            "inside_ride_technologies" => Some(93i64),
+            "sound_of_motion" => Some(94i64),
+            "stryd" => Some(95i64),
+            "icg" => Some(96i64),
+            "MiPulse" => Some(97i64),
+            "bsx_athletics" => Some(98i64),
+            "look" => Some(99i64),
+            "campagnolo_srl" => Some(100i64),
+            "body_bike_smart" => Some(101i64),
+            "praxisworks" => Some(102i64),
+            "limits_technology" => Some(103i64),
+            "topaction_technology" => Some(104i64),
+            "cosinuss" => Some(105i64),
+            "fitcare" => Some(106i64),
+            "magene" => Some(107i64),
+            "giant_manufacturing_co" => Some(108i64),
+            "tigrasport" => Some(109i64),
+            "salutron" => Some(110i64),
+            "technogym" => Some(111i64),
+            "bryton_sensors" => Some(112i64),
+            "latitude_limited" => Some(113i64),
+            "soaring_technology" => Some(114i64),
+            "igpsport" => Some(115i64),
+            "thinkrider" => Some(116i64),
+            "gopher_sport" => Some(117i64),
+            "waterrower" => Some(118i64),
+            "orangetheory" => Some(119i64),
+            "inpeak" => Some(120i64),
+            "kinetic" => Some(121i64),
+            "johnson_health_tech" => Some(122i64),
+            "polar_electro" => Some(123i64),
+            "seesense" => Some(124i64),
+            "nci_technology" => Some(125i64),
+            "iqsquare" => Some(126i64),
+            "leomo" => Some(127i64),
+            "ifit_com" => Some(128i64),
+            "coros_byte" => Some(129i64),
+            "versa_design" => Some(130i64),
+            "chileaf" => Some(131i64),
+            "cycplus" => Some(132i64),
+            "gravaa_byte" => Some(133i64),
+            "sigeyi" => Some(134i64),
+            "coospo" => Some(135i64),
+            "geoid" => Some(136i64),
+            "bosch" => Some(137i64),
+            "kyto" => Some(138i64),
+            "kinetic_sports" => Some(139i64),
+            "decathlon_byte" => Some(140i64),
+            "tq_systems" => Some(141i64),
+            "tag_heuer" => Some(142i64),
+            "keiser_fitness" => Some(143i64),
+            "zwift_byte" => Some(144i64),
+            "porsche_ep" => Some(145i64),
+            "blackbird" => Some(146i64),
+            "meilan_byte" => Some(147i64),
+            "ezon" => Some(148i64),
+            "laisi" => Some(149i64),
+            "myzone" => Some(150i64),
+            "development" => Some(255i64),
+            "healthandlife" => Some(257i64),
+            "lezyne" => Some(258i64),
+            "scribe_labs" => Some(259i64),
+            "zwift" => Some(260i64),
+            "watteam" => Some(261i64),
+            "recon" => Some(262i64),
+            "favero_electronics" => Some(263i64),
+            "dynovelo" => Some(264i64),
+            "strava" => Some(265i64),
+            "precor" => Some(266i64),
+            "bryton" => Some(267i64),
+            "sram" => Some(268i64),
+            "navman" => Some(269i64),
+            "cobi" => Some(270i64),
+            "spivi" => Some(271i64),
+            "mio_magellan" => Some(272i64),
+            "evesports" => Some(273i64),
+            "sensitivus_gauge" => Some(274i64),
+            "podoon" => Some(275i64),
+            "life_time_fitness" => Some(276i64),
+            "falco_e_motors" => Some(277i64),
+            "minoura" => Some(278i64),
+            "cycliq" => Some(279i64),
+            "luxottica" => Some(280i64),
+            "trainer_road" => Some(281i64),
+            "the_sufferfest" => Some(282i64),
+            "fullspeedahead" => Some(283i64),
+            "virtualtraining" => Some(284i64),
+            "feedbacksports" => Some(285i64),
+            "omata" => Some(286i64),
+            "vdo" => Some(287i64),
+            "magneticdays" => Some(288i64),
+            "hammerhead" => Some(289i64),
+            "kinetic_by_kurt" => Some(290i64),
+            "shapelog" => Some(291i64),
+            "dabuziduo" => Some(292i64),
+            "jetblack" => Some(293i64),
+            "coros" => Some(294i64),
+            "virtugo" => Some(295i64),
+            "velosense" => Some(296i64),
+            "cycligentinc" => Some(297i64),
+            "trailforks" => Some(298i64),
+            "mahle_ebikemotion" => Some(299i64),
+            "nurvv" => Some(300i64),
+            "microprogram" => Some(301i64),
+            "zone5cloud" => Some(302i64),
+            "greenteg" => Some(303i64),
+            "yamaha_motors" => Some(304i64),
+            "whoop" => Some(305i64),
+            "gravaa" => Some(306i64),
+            "onelap" => Some(307i64),
+            "monark_exercise" => Some(308i64),
+            "form" => Some(309i64),
+            "decathlon" => Some(310i64),
+            "syncros" => Some(311i64),
+            "heatup" => Some(312i64),
+            "cannondale" => Some(313i64),
+            "true_fitness" => Some(314i64),
+            "RGT_cycling" => Some(315i64),
+            "vasa" => Some(316i64),
+            "race_republic" => Some(317i64),
+            "fazua" => Some(318i64),
+            "oreka_training" => Some(319i64),
+            "lsec" => Some(320i64),
+            "lululemon_studio" => Some(321i64),
+            "shanyue" => Some(322i64),
+            "spinning_mda" => Some(323i64),
+            "hilldating" => Some(324i64),
+            "aero_sensor" => Some(325i64),
+            "nike" => Some(326i64),
+            "magicshine" => Some(327i64),
+            "ictrainer" => Some(328i64),
+            "absolute_cycling" => Some(329i64),
+            "actigraphcorp" => Some(5759i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             Manufacturer::Garmin => 1,
@@ -8662,107 +12397,337 @@ impl Manufacturer {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
-}
-impl fmt::Display for Manufacturer {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self {
-            Manufacturer::Garmin => write!(f, "garmin"),
-            Manufacturer::GarminFr405Antfs => write!(f, "garmin_fr405_antfs"),
-            Manufacturer::Zephyr => write!(f, "zephyr"),
-            Manufacturer::Dayton => write!(f, "dayton"),
-            Manufacturer::Idt => write!(f, "idt"),
-            Manufacturer::Srm => write!(f, "srm"),
-            Manufacturer::Quarq => write!(f, "quarq"),
-            Manufacturer::Ibike => write!(f, "ibike"),
-            Manufacturer::Saris => write!(f, "saris"),
-            Manufacturer::SparkHk => write!(f, "spark_hk"),
-            Manufacturer::Tanita => write!(f, "tanita"),
-            Manufacturer::Echowell => write!(f, "echowell"),
-            Manufacturer::DynastreamOem => write!(f, "dynastream_oem"),
-            Manufacturer::Nautilus => write!(f, "nautilus"),
-            Manufacturer::Dynastream => write!(f, "dynastream"),
-            Manufacturer::Timex => write!(f, "timex"),
-            Manufacturer::Metrigear => write!(f, "metrigear"),
-            Manufacturer::Xelic => write!(f, "xelic"),
-            Manufacturer::Beurer => write!(f, "beurer"),
-            Manufacturer::Cardiosport => write!(f, "cardiosport"),
-            Manufacturer::AAndD => write!(f, "a_and_d"),
-            Manufacturer::Hmm => write!(f, "hmm"),
-            Manufacturer::Suunto => write!(f, "suunto"),
-            Manufacturer::ThitaElektronik => write!(f, "thita_elektronik"),
-            Manufacturer::Gpulse => write!(f, "gpulse"),
-            Manufacturer::CleanMobile => write!(f, "clean_mobile"),
-            Manufacturer::PedalBrain => write!(f, "pedal_brain"),
-            Manufacturer::Peaksware => write!(f, "peaksware"),
-            Manufacturer::Saxonar => write!(f, "saxonar"),
-            Manufacturer::LemondFitness => write!(f, "lemond_fitness"),
-            Manufacturer::Dexcom => write!(f, "dexcom"),
-            Manufacturer::WahooFitness => write!(f, "wahoo_fitness"),
-            Manufacturer::OctaneFitness => write!(f, "octane_fitness"),
-            Manufacturer::Archinoetics => write!(f, "archinoetics"),
-            Manufacturer::TheHurtBox => write!(f, "the_hurt_box"),
-            Manufacturer::CitizenSystems => write!(f, "citizen_systems"),
-            Manufacturer::Magellan => write!(f, "magellan"),
-            Manufacturer::Osynce => write!(f, "osynce"),
-            Manufacturer::Holux => write!(f, "holux"),
-            Manufacturer::Concept2 => write!(f, "concept2"),
-            Manufacturer::Shimano => write!(f, "shimano"),
-            Manufacturer::OneGiantLeap => write!(f, "one_giant_leap"),
-            Manufacturer::AceSensor => write!(f, "ace_sensor"),
-            Manufacturer::BrimBrothers => write!(f, "brim_brothers"),
-            Manufacturer::Xplova => write!(f, "xplova"),
-            Manufacturer::PerceptionDigital => write!(f, "perception_digital"),
-            Manufacturer::Bf1systems => write!(f, "bf1systems"),
-            Manufacturer::Pioneer => write!(f, "pioneer"),
-            Manufacturer::Spantec => write!(f, "spantec"),
-            Manufacturer::Metalogics => write!(f, "metalogics"),
-            Manufacturer::Name4iiiis => write!(f, "4iiiis"),
-            Manufacturer::SeikoEpson => write!(f, "seiko_epson"),
-            Manufacturer::SeikoEpsonOem => write!(f, "seiko_epson_oem"),
-            Manufacturer::IforPowell => write!(f, "ifor_powell"),
-            Manufacturer::MaxwellGuider => write!(f, "maxwell_guider"),
-            Manufacturer::StarTrac => write!(f, "star_trac"),
-            Manufacturer::Breakaway => write!(f, "breakaway"),
-            Manufacturer::AlatechTechnologyLtd => write!(f, "alatech_technology_ltd"),
-            Manufacturer::MioTechnologyEurope => write!(f, "mio_technology_europe"),
-            Manufacturer::Rotor => write!(f, "rotor"),
-            Manufacturer::Geonaute => write!(f, "geonaute"),
-            Manufacturer::IdBike => write!(f, "id_bike"),
-            Manufacturer::Specialized => write!(f, "specialized"),
-            Manufacturer::Wtek => write!(f, "wtek"),
-            Manufacturer::PhysicalEnterprises => write!(f, "physical_enterprises"),
-            Manufacturer::NorthPoleEngineering => write!(f, "north_pole_engineering"),
-            Manufacturer::Bkool => write!(f, "bkool"),
-            Manufacturer::Cateye => write!(f, "cateye"),
-            Manufacturer::StagesCycling => write!(f, "stages_cycling"),
-            Manufacturer::Sigmasport => write!(f, "sigmasport"),
-            Manufacturer::Tomtom => write!(f, "tomtom"),
-            Manufacturer::Peripedal => write!(f, "peripedal"),
-            Manufacturer::Wattbike => write!(f, "wattbike"),
-            Manufacturer::Moxy => write!(f, "moxy"),
-            Manufacturer::Ciclosport => write!(f, "ciclosport"),
-            Manufacturer::Powerbahn => write!(f, "powerbahn"),
-            Manufacturer::AcornProjectsAps => write!(f, "acorn_projects_aps"),
-            Manufacturer::Lifebeam => write!(f, "lifebeam"),
-            Manufacturer::Bontrager => write!(f, "bontrager"),
-            Manufacturer::Wellgo => write!(f, "wellgo"),
-            Manufacturer::Scosche => write!(f, "scosche"),
-            Manufacturer::Magura => write!(f, "magura"),
-            Manufacturer::Woodway => write!(f, "woodway"),
-            Manufacturer::Elite => write!(f, "elite"),
-            Manufacturer::NielsenKellerman => write!(f, "nielsen_kellerman"),
-            Manufacturer::DkCity => write!(f, "dk_city"),
-            Manufacturer::Tacx => write!(f, "tacx"),
-            Manufacturer::DirectionTechnology => write!(f, "direction_technology"),
-            Manufacturer::Magtonic => write!(f, "magtonic"),
-            Manufacturer::Name1partcarbon => write!(f, "1partcarbon"),
-            Manufacturer::InsideRideTechnologies => write!(f, "inside_ride_technologies"),
-            Manufacturer::SoundOfMotion => write!(f, "sound_of_motion"),
-            Manufacturer::Stryd => write!(f, "stryd"),
-            Manufacturer::Icg => write!(f, "icg"),
-            Manufacturer::MiPulse => write!(f, "MiPulse"),
-            Manufacturer::BsxAthletics => write!(f, "bsx_athletics"),
-            Manufacturer::Look => write!(f, "look"),
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Manufacturer::Garmin => 1,
+            Manufacturer::GarminFr405Antfs => 2,
+            Manufacturer::Zephyr => 3,
+            Manufacturer::Dayton => 4,
+            Manufacturer::Idt => 5,
+            Manufacturer::Srm => 6,
+            Manufacturer::Quarq => 7,
+            Manufacturer::Ibike => 8,
+            Manufacturer::Saris => 9,
+            Manufacturer::SparkHk => 10,
+            Manufacturer::Tanita => 11,
+            Manufacturer::Echowell => 12,
+            Manufacturer::DynastreamOem => 13,
+            Manufacturer::Nautilus => 14,
+            Manufacturer::Dynastream => 15,
+            Manufacturer::Timex => 16,
+            Manufacturer::Metrigear => 17,
+            Manufacturer::Xelic => 18,
+            Manufacturer::Beurer => 19,
+            Manufacturer::Cardiosport => 20,
+            Manufacturer::AAndD => 21,
+            Manufacturer::Hmm => 22,
+            Manufacturer::Suunto => 23,
+            Manufacturer::ThitaElektronik => 24,
+            Manufacturer::Gpulse => 25,
+            Manufacturer::CleanMobile => 26,
+            Manufacturer::PedalBrain => 27,
+            Manufacturer::Peaksware => 28,
+            Manufacturer::Saxonar => 29,
+            Manufacturer::LemondFitness => 30,
+            Manufacturer::Dexcom => 31,
+            Manufacturer::WahooFitness => 32,
+            Manufacturer::OctaneFitness => 33,
+            Manufacturer::Archinoetics => 34,
+            Manufacturer::TheHurtBox => 35,
+            Manufacturer::CitizenSystems => 36,
+            Manufacturer::Magellan => 37,
+            Manufacturer::Osynce => 38,
+            Manufacturer::Holux => 39,
+            Manufacturer::Concept2 => 40,
+            Manufacturer::Shimano => 41,
+            Manufacturer::OneGiantLeap => 42,
+            Manufacturer::AceSensor => 43,
+            Manufacturer::BrimBrothers => 44,
+            Manufacturer::Xplova => 45,
+            Manufacturer::PerceptionDigital => 46,
+            Manufacturer::Bf1systems => 47,
+            Manufacturer::Pioneer => 48,
+            Manufacturer::Spantec => 49,
+            Manufacturer::Metalogics => 50,
+            Manufacturer::Name4iiiis => 51,
+            Manufacturer::SeikoEpson => 52,
+            Manufacturer::SeikoEpsonOem => 53,
+            Manufacturer::IforPowell => 54,
+            Manufacturer::MaxwellGuider => 55,
+            Manufacturer::StarTrac => 56,
+            Manufacturer::Breakaway => 57,
+            Manufacturer::AlatechTechnologyLtd => 58,
+            Manufacturer::MioTechnologyEurope => 59,
+            Manufacturer::Rotor => 60,
+            Manufacturer::Geonaute => 61,
+            Manufacturer::IdBike => 62,
+            Manufacturer::Specialized => 63,
+            Manufacturer::Wtek => 64,
+            Manufacturer::PhysicalEnterprises => 65,
+            Manufacturer::NorthPoleEngineering => 66,
+            Manufacturer::Bkool => 67,
+            Manufacturer::Cateye => 68,
+            Manufacturer::StagesCycling => 69,
+            Manufacturer::Sigmasport => 70,
+            Manufacturer::Tomtom => 71,
+            Manufacturer::Peripedal => 72,
+            Manufacturer::Wattbike => 73,
+            Manufacturer::Moxy => 76,
+            Manufacturer::Ciclosport => 77,
+            Manufacturer::Powerbahn => 78,
+            Manufacturer::AcornProjectsAps => 79,
+            Manufacturer::Lifebeam => 80,
+            Manufacturer::Bontrager => 81,
+            Manufacturer::Wellgo => 82,
+            Manufacturer::Scosche => 83,
+            Manufacturer::Magura => 84,
+            Manufacturer::Woodway => 85,
+            Manufacturer::Elite => 86,
+            Manufacturer::NielsenKellerman => 87,
+            Manufacturer::DkCity => 88,
+            Manufacturer::Tacx => 89,
+            Manufacturer::DirectionTechnology => 90,
+            Manufacturer::Magtonic => 91,
+            Manufacturer::Name1partcarbon => 92,
+            Manufacturer::InsideRideTechnologies => 93,
+            Manufacturer::SoundOfMotion => 94,
+            Manufacturer::Stryd => 95,
+            Manufacturer::Icg => 96,
+            Manufacturer::MiPulse => 97,
+            Manufacturer::BsxAthletics => 98,
+            Manufacturer::Look => 99,
+            Manufacturer::CampagnoloSrl => 100,
+            Manufacturer::BodyBikeSmart => 101,
+            Manufacturer::Praxisworks => 102,
+            Manufacturer::LimitsTechnology => 103,
+            Manufacturer::TopactionTechnology => 104,
+            Manufacturer::Cosinuss => 105,
+            Manufacturer::Fitcare => 106,
+            Manufacturer::Magene => 107,
+            Manufacturer::GiantManufacturingCo => 108,
+            Manufacturer::Tigrasport => 109,
+            Manufacturer::Salutron => 110,
+            Manufacturer::Technogym => 111,
+            Manufacturer::BrytonSensors => 112,
+            Manufacturer::LatitudeLimited => 113,
+            Manufacturer::SoaringTechnology => 114,
+            Manufacturer::Igpsport => 115,
+            Manufacturer::Thinkrider => 116,
+            Manufacturer::GopherSport => 117,
+            Manufacturer::Waterrower => 118,
+            Manufacturer::Orangetheory => 119,
+            Manufacturer::Inpeak => 120,
+            Manufacturer::Kinetic => 121,
+            Manufacturer::JohnsonHealthTech => 122,
+            Manufacturer::PolarElectro => 123,
+            Manufacturer::Seesense => 124,
+            Manufacturer::NciTechnology => 125,
+            Manufacturer::Iqsquare => 126,
+            Manufacturer::Leomo => 127,
+            Manufacturer::IfitCom => 128,
+            Manufacturer::CorosByte => 129,
+            Manufacturer::VersaDesign => 130,
+            Manufacturer::Chileaf => 131,
+            Manufacturer::Cycplus => 132,
+            Manufacturer::GravaaByte => 133,
+            Manufacturer::Sigeyi => 134,
+            Manufacturer::Coospo => 135,
+            Manufacturer::Geoid => 136,
+            Manufacturer::Bosch => 137,
+            Manufacturer::Kyto => 138,
+            Manufacturer::KineticSports => 139,
+            Manufacturer::DecathlonByte => 140,
+            Manufacturer::TqSystems => 141,
+            Manufacturer::TagHeuer => 142,
+            Manufacturer::KeiserFitness => 143,
+            Manufacturer::ZwiftByte => 144,
+            Manufacturer::PorscheEp => 145,
+            Manufacturer::Blackbird => 146,
+            Manufacturer::MeilanByte => 147,
+            Manufacturer::Ezon => 148,
+            Manufacturer::Laisi => 149,
+            Manufacturer::Myzone => 150,
+            Manufacturer::Development => 255,
+            Manufacturer::Healthandlife => 257,
+            Manufacturer::Lezyne => 258,
+            Manufacturer::ScribeLabs => 259,
+            Manufacturer::Zwift => 260,
+            Manufacturer::Watteam => 261,
+            Manufacturer::Recon => 262,
+            Manufacturer::FaveroElectronics => 263,
+            Manufacturer::Dynovelo => 264,
+            Manufacturer::Strava => 265,
+            Manufacturer::Precor => 266,
+            Manufacturer::Bryton => 267,
+            Manufacturer::Sram => 268,
+            Manufacturer::Navman => 269,
+            Manufacturer::Cobi => 270,
+            Manufacturer::Spivi => 271,
+            Manufacturer::MioMagellan => 272,
+            Manufacturer::Evesports => 273,
+            Manufacturer::SensitivusGauge => 274,
+            Manufacturer::Podoon => 275,
+            Manufacturer::LifeTimeFitness => 276,
+            Manufacturer::FalcoEMotors => 277,
+            Manufacturer::Minoura => 278,
+            Manufacturer::Cycliq => 279,
+            Manufacturer::Luxottica => 280,
+            Manufacturer::TrainerRoad => 281,
+            Manufacturer::TheSufferfest => 282,
+            Manufacturer::Fullspeedahead => 283,
+            Manufacturer::Virtualtraining => 284,
+            Manufacturer::Feedbacksports => 285,
+            Manufacturer::Omata => 286,
+            Manufacturer::Vdo => 287,
+            Manufacturer::Magneticdays => 288,
+            Manufacturer::Hammerhead => 289,
+            Manufacturer::KineticByKurt => 290,
+            Manufacturer::Shapelog => 291,
+            Manufacturer::Dabuziduo => 292,
+            Manufacturer::Jetblack => 293,
+            Manufacturer::Coros => 294,
+            Manufacturer::Virtugo => 295,
+            Manufacturer::Velosense => 296,
+            Manufacturer::Cycligentinc => 297,
+            Manufacturer::Trailforks => 298,
+            Manufacturer::MahleEbikemotion => 299,
+            Manufacturer::Nurvv => 300,
+            Manufacturer::Microprogram => 301,
+            Manufacturer::Zone5cloud => 302,
+            Manufacturer::Greenteg => 303,
+            Manufacturer::YamahaMotors => 304,
+            Manufacturer::Whoop => 305,
+            Manufacturer::Gravaa => 306,
+            Manufacturer::Onelap => 307,
+            Manufacturer::MonarkExercise => 308,
+            Manufacturer::Form => 309,
+            Manufacturer::Decathlon => 310,
+            Manufacturer::Syncros => 311,
+            Manufacturer::Heatup => 312,
+            Manufacturer::Cannondale => 313,
+            Manufacturer::TrueFitness => 314,
+            Manufacturer::RGTCycling => 315,
+            Manufacturer::Vasa => 316,
+            Manufacturer::RaceRepublic => 317,
+            Manufacturer::Fazua => 318,
+            Manufacturer::OrekaTraining => 319,
+            Manufacturer::Lsec => 320,
+            Manufacturer::LululemonStudio => 321,
+            Manufacturer::Shanyue => 322,
+            Manufacturer::SpinningMda => 323,
+            Manufacturer::Hilldating => 324,
+            Manufacturer::AeroSensor => 325,
+            Manufacturer::Nike => 326,
+            Manufacturer::Magicshine => 327,
+            Manufacturer::Ictrainer => 328,
+            Manufacturer::AbsoluteCycling => 329,
+            Manufacturer::Actigraphcorp => 5759,
+            Manufacturer::Value(value) => value as i64,
+        }
+    }
+}
+impl fmt::Display for Manufacturer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            Manufacturer::Garmin => write!(f, "garmin"),
+            Manufacturer::GarminFr405Antfs => write!(f, "garmin_fr405_antfs"),
+            Manufacturer::Zephyr => write!(f, "zephyr"),
+            Manufacturer::Dayton => write!(f, "dayton"),
+            Manufacturer::Idt => write!(f, "idt"),
+            Manufacturer::Srm => write!(f, "srm"),
+            Manufacturer::Quarq => write!(f, "quarq"),
+            Manufacturer::Ibike => write!(f, "ibike"),
+            Manufacturer::Saris => write!(f, "saris"),
+            Manufacturer::SparkHk => write!(f, "spark_hk"),
+            Manufacturer::Tanita => write!(f, "tanita"),
+            Manufacturer::Echowell => write!(f, "echowell"),
+            Manufacturer::DynastreamOem => write!(f, "dynastream_oem"),
+            Manufacturer::Nautilus => write!(f, "nautilus"),
+            Manufacturer::Dynastream => write!(f, "dynastream"),
+            Manufacturer::Timex => write!(f, "timex"),
+            Manufacturer::Metrigear => write!(f, "metrigear"),
+            Manufacturer::Xelic => write!(f, "xelic"),
+            Manufacturer::Beurer => write!(f, "beurer"),
+            Manufacturer::Cardiosport => write!(f, "cardiosport"),
+            Manufacturer::AAndD => write!(f, "a_and_d"),
+            Manufacturer::Hmm => write!(f, "hmm"),
+            Manufacturer::Suunto => write!(f, "suunto"),
+            Manufacturer::ThitaElektronik => write!(f, "thita_elektronik"),
+            Manufacturer::Gpulse => write!(f, "gpulse"),
+            Manufacturer::CleanMobile => write!(f, "clean_mobile"),
+            Manufacturer::PedalBrain => write!(f, "pedal_brain"),
+            Manufacturer::Peaksware => write!(f, "peaksware"),
+            Manufacturer::Saxonar => write!(f, "saxonar"),
+            Manufacturer::LemondFitness => write!(f, "lemond_fitness"),
+            Manufacturer::Dexcom => write!(f, "dexcom"),
+            Manufacturer::WahooFitness => write!(f, "wahoo_fitness"),
+            Manufacturer::OctaneFitness => write!(f, "octane_fitness"),
+            Manufacturer::Archinoetics => write!(f, "archinoetics"),
+            Manufacturer::TheHurtBox => write!(f, "the_hurt_box"),
+            Manufacturer::CitizenSystems => write!(f, "citizen_systems"),
+            Manufacturer::Magellan => write!(f, "magellan"),
+            Manufacturer::Osynce => write!(f, "osynce"),
+            Manufacturer::Holux => write!(f, "holux"),
+            Manufacturer::Concept2 => write!(f, "concept2"),
+            Manufacturer::Shimano => write!(f, "shimano"),
+            Manufacturer::OneGiantLeap => write!(f, "one_giant_leap"),
+            Manufacturer::AceSensor => write!(f, "ace_sensor"),
+            Manufacturer::BrimBrothers => write!(f, "brim_brothers"),
+            Manufacturer::Xplova => write!(f, "xplova"),
+            Manufacturer::PerceptionDigital => write!(f, "perception_digital"),
+            Manufacturer::Bf1systems => write!(f, "bf1systems"),
+            Manufacturer::Pioneer => write!(f, "pioneer"),
+            Manufacturer::Spantec => write!(f, "spantec"),
+            Manufacturer::Metalogics => write!(f, "metalogics"),
+            Manufacturer::Name4iiiis => write!(f, "4iiiis"),
+            Manufacturer::SeikoEpson => write!(f, "seiko_epson"),
+            Manufacturer::SeikoEpsonOem => write!(f, "seiko_epson_oem"),
+            Manufacturer::IforPowell => write!(f, "ifor_powell"),
+            Manufacturer::MaxwellGuider => write!(f, "maxwell_guider"),
+            Manufacturer::StarTrac => write!(f, "star_trac"),
+            Manufacturer::Breakaway => write!(f, "breakaway"),
+            Manufacturer::AlatechTechnologyLtd => write!(f, "alatech_technology_ltd"),
+            Manufacturer::MioTechnologyEurope => write!(f, "mio_technology_europe"),
+            Manufacturer::Rotor => write!(f, "rotor"),
+            Manufacturer::Geonaute => write!(f, "geonaute"),
+            Manufacturer::IdBike => write!(f, "id_bike"),
+            Manufacturer::Specialized => write!(f, "specialized"),
+            Manufacturer::Wtek => write!(f, "wtek"),
+            Manufacturer::PhysicalEnterprises => write!(f, "physical_enterprises"),
+            Manufacturer::NorthPoleEngineering => write!(f, "north_pole_engineering"),
+            Manufacturer::Bkool => write!(f, "bkool"),
+            Manufacturer::Cateye => write!(f, "cateye"),
+            Manufacturer::StagesCycling => write!(f, "stages_cycling"),
+            Manufacturer::Sigmasport => write!(f, "sigmasport"),
+            Manufacturer::Tomtom => write!(f, "tomtom"),
+            Manufacturer::Peripedal => write!(f, "peripedal"),
+            Manufacturer::Wattbike => write!(f, "wattbike"),
+            Manufacturer::Moxy => write!(f, "moxy"),
+            Manufacturer::Ciclosport => write!(f, "ciclosport"),
+            Manufacturer::Powerbahn => write!(f, "powerbahn"),
+            Manufacturer::AcornProjectsAps => write!(f, "acorn_projects_aps"),
+            Manufacturer::Lifebeam => write!(f, "lifebeam"),
+            Manufacturer::Bontrager => write!(f, "bontrager"),
+            Manufacturer::Wellgo => write!(f, "wellgo"),
+            Manufacturer::Scosche => write!(f, "scosche"),
+            Manufacturer::Magura => write!(f, "magura"),
+            Manufacturer::Woodway => write!(f, "woodway"),
+            Manufacturer::Elite => write!(f, "elite"),
+            Manufacturer::NielsenKellerman => write!(f, "nielsen_kellerman"),
+            Manufacturer::DkCity => write!(f, "dk_city"),
+            Manufacturer::Tacx => write!(f, "tacx"),
+            Manufacturer::DirectionTechnology => write!(f, "direction_technology"),
+            Manufacturer::Magtonic => write!(f, "magtonic"),
+            Manufacturer::Name1partcarbon => write!(f, "1partcarbon"),
+            Manufacturer::InsideRideTechnologies => write!(f, "inside_ride_technologies"),
+            Manufacturer::SoundOfMotion => write!(f, "sound_of_motion"),
+            Manufacturer::Stryd => write!(f, "stryd"),
+            Manufacturer::Icg => write!(f, "icg"),
+            Manufacturer::MiPulse => write!(f, "MiPulse"),
+            Manufacturer::BsxAthletics => write!(f, "bsx_athletics"),
+            Manufacturer::Look => write!(f, "look"),
             Manufacturer::CampagnoloSrl => write!(f, "campagnolo_srl"),
             Manufacturer::BodyBikeSmart => write!(f, "body_bike_smart"),
             Manufacturer::Praxisworks => write!(f, "praxisworks"),
@@ -9139,6 +13104,15 @@ impl Serialize for Manufacturer {
         }
     }
 }
+impl<'de> Deserialize<'de> for Manufacturer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::Manufacturer, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum GarminProduct {
     ApproachG12Asia,
@@ -10055,7 +14029,888 @@ impl GarminProduct {
             _ => false,
         }
     }
-    pub fn as_u16(self) -> u16 {
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "approach_g12_asia" => Some(1i64),
+            "axh01" => Some(2i64),
+            "axb01" => Some(3i64),
+            "axb02" => Some(4i64),
+            "hrm2ss" => Some(5i64),
+            "dsi_alf02" => Some(6i64),
+            "hrm3ss" => Some(7i64),
+            "hrm_run_single_byte_product_id" => Some(8i64),
+            "bsm" => Some(9i64),
+            "bcm" => Some(10i64),
+            "axs01" => Some(11i64),
+            "hrm_tri_single_byte_product_id" => Some(12i64),
+            "hrm4_run_single_byte_product_id" => Some(13i64),
+            "fr225_single_byte_product_id" => Some(14i64),
+            "gen3_bsm_single_byte_product_id" => Some(15i64),
+            "gen3_bcm_single_byte_product_id" => Some(16i64),
+            "hrm_fit_single_byte_product_id" => Some(22i64),
+            "OHR" => Some(255i64),
+            "fr301_china" => Some(473i64),
+            "fr301_japan" => Some(474i64),
+            "fr301_korea" => Some(475i64),
+            "fr301_taiwan" => Some(494i64),
+            "fr405" => Some(717i64),
+            "fr50" => Some(782i64),
+            "fr405_japan" => Some(987i64),
+            "fr60" => Some(988i64),
+            "dsi_alf01" => Some(1011i64),
+            "fr310xt" => Some(1018i64),
+            "edge500" => Some(1036i64),
+            "fr110" => Some(1124i64),
+            "edge800" => Some(1169i64),
+            "edge500_taiwan" => Some(1199i64),
+            "edge500_japan" => Some(1213i64),
+            "chirp" => Some(1253i64),
+            "fr110_japan" => Some(1274i64),
+            "edge200" => Some(1325i64),
+            "fr910xt" => Some(1328i64),
+            "edge800_taiwan" => Some(1333i64),
+            "edge800_japan" => Some(1334i64),
+            "alf04" => Some(1341i64),
+            "fr610" => Some(1345i64),
+            "fr210_japan" => Some(1360i64),
+            "vector_ss" => Some(1380i64),
+            "vector_cp" => Some(1381i64),
+            "edge800_china" => Some(1386i64),
+            "edge500_china" => Some(1387i64),
+            "approach_g10" => Some(1405i64),
+            "fr610_japan" => Some(1410i64),
+            "edge500_korea" => Some(1422i64),
+            "fr70" => Some(1436i64),
+            "fr310xt_4t" => Some(1446i64),
+            "amx" => Some(1461i64),
+            "fr10" => Some(1482i64),
+            "edge800_korea" => Some(1497i64),
+            "swim" => Some(1499i64),
+            "fr910xt_china" => Some(1537i64),
+            "fenix" => Some(1551i64),
+            "edge200_taiwan" => Some(1555i64),
+            "edge510" => Some(1561i64),
+            "edge810" => Some(1567i64),
+            "tempe" => Some(1570i64),
+            "fr910xt_japan" => Some(1600i64),
+            "fr620" => Some(1623i64),
+            "fr220" => Some(1632i64),
+            "fr910xt_korea" => Some(1664i64),
+            "fr10_japan" => Some(1688i64),
+            "edge810_japan" => Some(1721i64),
+            "virb_elite" => Some(1735i64),
+            "edge_touring" => Some(1736i64),
+            "edge510_japan" => Some(1742i64),
+            "hrm_tri" => Some(1743i64),
+            "hrm_run" => Some(1752i64),
+            "fr920xt" => Some(1765i64),
+            "edge510_asia" => Some(1821i64),
+            "edge810_china" => Some(1822i64),
+            "edge810_taiwan" => Some(1823i64),
+            "edge1000" => Some(1836i64),
+            "vivo_fit" => Some(1837i64),
+            "virb_remote" => Some(1853i64),
+            "vivo_ki" => Some(1885i64),
+            "fr15" => Some(1903i64),
+            "vivo_active" => Some(1907i64),
+            "edge510_korea" => Some(1918i64),
+            "fr620_japan" => Some(1928i64),
+            "fr620_china" => Some(1929i64),
+            "fr220_japan" => Some(1930i64),
+            "fr220_china" => Some(1931i64),
+            "approach_s6" => Some(1936i64),
+            "vivo_smart" => Some(1956i64),
+            "fenix2" => Some(1967i64),
+            "epix" => Some(1988i64),
+            "fenix3" => Some(2050i64),
+            "edge1000_taiwan" => Some(2052i64),
+            "edge1000_japan" => Some(2053i64),
+            "fr15_japan" => Some(2061i64),
+            "edge520" => Some(2067i64),
+            "edge1000_china" => Some(2070i64),
+            "fr620_russia" => Some(2072i64),
+            "fr220_russia" => Some(2073i64),
+            "vector_s" => Some(2079i64),
+            "edge1000_korea" => Some(2100i64),
+            "fr920xt_taiwan" => Some(2130i64),
+            "fr920xt_china" => Some(2131i64),
+            "fr920xt_japan" => Some(2132i64),
+            "virbx" => Some(2134i64),
+            "vivo_smart_apac" => Some(2135i64),
+            "etrex_touch" => Some(2140i64),
+            "edge25" => Some(2147i64),
+            "fr25" => Some(2148i64),
+            "vivo_fit2" => Some(2150i64),
+            "fr225" => Some(2153i64),
+            "fr630" => Some(2156i64),
+            "fr230" => Some(2157i64),
+            "fr735xt" => Some(2158i64),
+            "vivo_active_apac" => Some(2160i64),
+            "vector_2" => Some(2161i64),
+            "vector_2s" => Some(2162i64),
+            "virbxe" => Some(2172i64),
+            "fr620_taiwan" => Some(2173i64),
+            "fr220_taiwan" => Some(2174i64),
+            "truswing" => Some(2175i64),
+            "d2airvenu" => Some(2187i64),
+            "fenix3_china" => Some(2188i64),
+            "fenix3_twn" => Some(2189i64),
+            "varia_headlight" => Some(2192i64),
+            "varia_taillight_old" => Some(2193i64),
+            "edge_explore_1000" => Some(2204i64),
+            "fr225_asia" => Some(2219i64),
+            "varia_radar_taillight" => Some(2225i64),
+            "varia_radar_display" => Some(2226i64),
+            "edge20" => Some(2238i64),
+            "edge520_asia" => Some(2260i64),
+            "edge520_japan" => Some(2261i64),
+            "d2_bravo" => Some(2262i64),
+            "approach_s20" => Some(2266i64),
+            "vivo_smart2" => Some(2271i64),
+            "edge1000_thai" => Some(2274i64),
+            "varia_remote" => Some(2276i64),
+            "edge25_asia" => Some(2288i64),
+            "edge25_jpn" => Some(2289i64),
+            "edge20_asia" => Some(2290i64),
+            "approach_x40" => Some(2292i64),
+            "fenix3_japan" => Some(2293i64),
+            "vivo_smart_emea" => Some(2294i64),
+            "fr630_asia" => Some(2310i64),
+            "fr630_jpn" => Some(2311i64),
+            "fr230_jpn" => Some(2313i64),
+            "hrm4_run" => Some(2327i64),
+            "epix_japan" => Some(2332i64),
+            "vivo_active_hr" => Some(2337i64),
+            "approach_g12" => Some(2343i64),
+            "vivo_smart_gps_hr" => Some(2347i64),
+            "vivo_smart_hr" => Some(2348i64),
+            "vivo_smart_hr_asia" => Some(2361i64),
+            "vivo_smart_gps_hr_asia" => Some(2362i64),
+            "vivo_move" => Some(2368i64),
+            "varia_taillight" => Some(2379i64),
+            "fr235_asia" => Some(2396i64),
+            "fr235_japan" => Some(2397i64),
+            "varia_vision" => Some(2398i64),
+            "vivo_fit3" => Some(2406i64),
+            "fenix3_korea" => Some(2407i64),
+            "fenix3_sea" => Some(2408i64),
+            "fenix3_hr" => Some(2413i64),
+            "virb_ultra_30" => Some(2417i64),
+            "index_smart_scale" => Some(2429i64),
+            "fr235" => Some(2431i64),
+            "fenix3_chronos" => Some(2432i64),
+            "oregon7xx" => Some(2441i64),
+            "rino7xx" => Some(2444i64),
+            "epix_korea" => Some(2457i64),
+            "fenix3_hr_chn" => Some(2473i64),
+            "fenix3_hr_twn" => Some(2474i64),
+            "fenix3_hr_jpn" => Some(2475i64),
+            "fenix3_hr_sea" => Some(2476i64),
+            "fenix3_hr_kor" => Some(2477i64),
+            "nautix" => Some(2496i64),
+            "vivo_active_hr_apac" => Some(2497i64),
+            "fr35" => Some(2503i64),
+            "oregon7xx_ww" => Some(2512i64),
+            "edge_820" => Some(2530i64),
+            "edge_explore_820" => Some(2531i64),
+            "fr735xt_apac" => Some(2533i64),
+            "fr735xt_japan" => Some(2534i64),
+            "fenix5s" => Some(2544i64),
+            "d2_bravo_titanium" => Some(2547i64),
+            "varia_ut800" => Some(2567i64),
+            "running_dynamics_pod" => Some(2593i64),
+            "edge_820_china" => Some(2599i64),
+            "edge_820_japan" => Some(2600i64),
+            "fenix5x" => Some(2604i64),
+            "vivo_fit_jr" => Some(2606i64),
+            "vivo_smart3" => Some(2622i64),
+            "vivo_sport" => Some(2623i64),
+            "edge_820_taiwan" => Some(2628i64),
+            "edge_820_korea" => Some(2629i64),
+            "edge_820_sea" => Some(2630i64),
+            "fr35_hebrew" => Some(2650i64),
+            "approach_s60" => Some(2656i64),
+            "fr35_apac" => Some(2667i64),
+            "fr35_japan" => Some(2668i64),
+            "fenix3_chronos_asia" => Some(2675i64),
+            "virb_360" => Some(2687i64),
+            "fr935" => Some(2691i64),
+            "fenix5" => Some(2697i64),
+            "vivoactive3" => Some(2700i64),
+            "edge_1030" => Some(2713i64),
+            "fr35_sea" => Some(2727i64),
+            "fr235_china_nfc" => Some(2733i64),
+            "foretrex_601_701" => Some(2769i64),
+            "vivo_move_hr" => Some(2772i64),
+            "vector_3" => Some(2787i64),
+            "fenix5_asia" => Some(2796i64),
+            "fenix5s_asia" => Some(2797i64),
+            "fenix5x_asia" => Some(2798i64),
+            "approach_z80" => Some(2806i64),
+            "fr35_korea" => Some(2814i64),
+            "d2charlie" => Some(2819i64),
+            "vivo_smart3_apac" => Some(2831i64),
+            "vivo_sport_apac" => Some(2832i64),
+            "fr935_asia" => Some(2833i64),
+            "descent" => Some(2859i64),
+            "vivo_fit4" => Some(2878i64),
+            "fr645" => Some(2886i64),
+            "fr645m" => Some(2888i64),
+            "fr30" => Some(2891i64),
+            "fenix5s_plus" => Some(2900i64),
+            "Edge_130" => Some(2909i64),
+            "edge_1030_asia" => Some(2924i64),
+            "vivosmart_4" => Some(2927i64),
+            "vivo_move_hr_asia" => Some(2945i64),
+            "approach_x10" => Some(2962i64),
+            "fr30_asia" => Some(2977i64),
+            "vivoactive3m_w" => Some(2988i64),
+            "fr645_asia" => Some(3003i64),
+            "fr645m_asia" => Some(3004i64),
+            "edge_explore" => Some(3011i64),
+            "gpsmap66" => Some(3028i64),
+            "approach_s10" => Some(3049i64),
+            "vivoactive3m_l" => Some(3066i64),
+            "approach_g80" => Some(3085i64),
+            "edge_130_asia" => Some(3092i64),
+            "edge_1030_bontrager" => Some(3095i64),
+            "fenix5_plus" => Some(3110i64),
+            "fenix5x_plus" => Some(3111i64),
+            "edge_520_plus" => Some(3112i64),
+            "fr945" => Some(3113i64),
+            "edge_530" => Some(3121i64),
+            "edge_830" => Some(3122i64),
+            "instinct_esports" => Some(3126i64),
+            "fenix5s_plus_apac" => Some(3134i64),
+            "fenix5x_plus_apac" => Some(3135i64),
+            "edge_520_plus_apac" => Some(3142i64),
+            "descent_t1" => Some(3143i64),
+            "fr235l_asia" => Some(3144i64),
+            "fr245_asia" => Some(3145i64),
+            "vivo_active3m_apac" => Some(3163i64),
+            "gen3_bsm" => Some(3192i64),
+            "gen3_bcm" => Some(3193i64),
+            "vivo_smart4_asia" => Some(3218i64),
+            "vivoactive4_small" => Some(3224i64),
+            "vivoactive4_large" => Some(3225i64),
+            "venu" => Some(3226i64),
+            "marq_driver" => Some(3246i64),
+            "marq_aviator" => Some(3247i64),
+            "marq_captain" => Some(3248i64),
+            "marq_commander" => Some(3249i64),
+            "marq_expedition" => Some(3250i64),
+            "marq_athlete" => Some(3251i64),
+            "descent_mk2" => Some(3258i64),
+            "gpsmap66i" => Some(3284i64),
+            "fenix6S_sport" => Some(3287i64),
+            "fenix6S" => Some(3288i64),
+            "fenix6_sport" => Some(3289i64),
+            "fenix6" => Some(3290i64),
+            "fenix6x" => Some(3291i64),
+            "hrm_dual" => Some(3299i64),
+            "hrm_pro" => Some(3300i64),
+            "vivo_move3_premium" => Some(3308i64),
+            "approach_s40" => Some(3314i64),
+            "fr245m_asia" => Some(3321i64),
+            "edge_530_apac" => Some(3349i64),
+            "edge_830_apac" => Some(3350i64),
+            "vivo_move3" => Some(3378i64),
+            "vivo_active4_small_asia" => Some(3387i64),
+            "vivo_active4_large_asia" => Some(3388i64),
+            "vivo_active4_oled_asia" => Some(3389i64),
+            "swim2" => Some(3405i64),
+            "marq_driver_asia" => Some(3420i64),
+            "marq_aviator_asia" => Some(3421i64),
+            "vivo_move3_asia" => Some(3422i64),
+            "fr945_asia" => Some(3441i64),
+            "vivo_active3t_chn" => Some(3446i64),
+            "marq_captain_asia" => Some(3448i64),
+            "marq_commander_asia" => Some(3449i64),
+            "marq_expedition_asia" => Some(3450i64),
+            "marq_athlete_asia" => Some(3451i64),
+            "instinct_solar" => Some(3466i64),
+            "fr45_asia" => Some(3469i64),
+            "vivoactive3_daimler" => Some(3473i64),
+            "legacy_rey" => Some(3498i64),
+            "legacy_darth_vader" => Some(3499i64),
+            "legacy_captain_marvel" => Some(3500i64),
+            "legacy_first_avenger" => Some(3501i64),
+            "fenix6s_sport_asia" => Some(3512i64),
+            "fenix6s_asia" => Some(3513i64),
+            "fenix6_sport_asia" => Some(3514i64),
+            "fenix6_asia" => Some(3515i64),
+            "fenix6x_asia" => Some(3516i64),
+            "legacy_captain_marvel_asia" => Some(3535i64),
+            "legacy_first_avenger_asia" => Some(3536i64),
+            "legacy_rey_asia" => Some(3537i64),
+            "legacy_darth_vader_asia" => Some(3538i64),
+            "descent_mk2s" => Some(3542i64),
+            "edge_130_plus" => Some(3558i64),
+            "edge_1030_plus" => Some(3570i64),
+            "rally_200" => Some(3578i64),
+            "fr745" => Some(3589i64),
+            "venusq" => Some(3600i64),
+            "lily" => Some(3615i64),
+            "marq_adventurer" => Some(3624i64),
+            "enduro" => Some(3638i64),
+            "swim2_apac" => Some(3639i64),
+            "marq_adventurer_asia" => Some(3648i64),
+            "fr945_lte" => Some(3652i64),
+            "descent_mk2_asia" => Some(3702i64),
+            "venu2" => Some(3703i64),
+            "venu2s" => Some(3704i64),
+            "venu_daimler_asia" => Some(3737i64),
+            "marq_golfer" => Some(3739i64),
+            "venu_daimler" => Some(3740i64),
+            "fr745_asia" => Some(3794i64),
+            "varia_rct715" => Some(3808i64),
+            "lily_asia" => Some(3809i64),
+            "edge_1030_plus_asia" => Some(3812i64),
+            "edge_130_plus_asia" => Some(3813i64),
+            "approach_s12" => Some(3823i64),
+            "venusq_asia" => Some(3837i64),
+            "edge_1040" => Some(3843i64),
+            "marq_golfer_asia" => Some(3850i64),
+            "venu2_plus" => Some(3851i64),
+            "gnss" => Some(3865i64),
+            "fr55" => Some(3869i64),
+            "enduro_asia" => Some(3872i64),
+            "instinct_2" => Some(3888i64),
+            "fenix7s" => Some(3905i64),
+            "fenix7" => Some(3906i64),
+            "fenix7x" => Some(3907i64),
+            "fenix7s_apac" => Some(3908i64),
+            "fenix7_apac" => Some(3909i64),
+            "fenix7x_apac" => Some(3910i64),
+            "descent_mk2s_asia" => Some(3930i64),
+            "approach_s42" => Some(3934i64),
+            "epix_gen2" => Some(3943i64),
+            "epix_gen2_apac" => Some(3944i64),
+            "venu2s_asia" => Some(3949i64),
+            "venu2_asia" => Some(3950i64),
+            "fr945_lte_asia" => Some(3978i64),
+            "vivo_move_sport" => Some(3982i64),
+            "vivomove_trend" => Some(3983i64),
+            "approach_S12_asia" => Some(3986i64),
+            "fr255_music" => Some(3990i64),
+            "fr255_small_music" => Some(3991i64),
+            "fr255" => Some(3992i64),
+            "fr255_small" => Some(3993i64),
+            "approach_s42_asia" => Some(4002i64),
+            "descent_g1" => Some(4005i64),
+            "venu2_plus_asia" => Some(4017i64),
+            "fr955" => Some(4024i64),
+            "fr55_asia" => Some(4033i64),
+            "edge_540" => Some(4061i64),
+            "edge_840" => Some(4062i64),
+            "vivosmart_5" => Some(4063i64),
+            "instinct_2_asia" => Some(4071i64),
+            "marq_gen2" => Some(4105i64),
+            "venusq2" => Some(4115i64),
+            "venusq2music" => Some(4116i64),
+            "marq_gen2_aviator" => Some(4124i64),
+            "d2_air_x10" => Some(4125i64),
+            "hrm_pro_plus" => Some(4130i64),
+            "descent_g1_asia" => Some(4132i64),
+            "tactix7" => Some(4135i64),
+            "instinct_crossover" => Some(4155i64),
+            "edge_explore2" => Some(4169i64),
+            "descent_mk3" => Some(4222i64),
+            "descent_mk3i" => Some(4223i64),
+            "approach_s70" => Some(4233i64),
+            "fr265_large" => Some(4257i64),
+            "fr265_small" => Some(4258i64),
+            "venu3" => Some(4260i64),
+            "venu3s" => Some(4261i64),
+            "tacx_neo_smart" => Some(4265i64),
+            "tacx_neo2_smart" => Some(4266i64),
+            "tacx_neo2_t_smart" => Some(4267i64),
+            "tacx_neo_smart_bike" => Some(4268i64),
+            "tacx_satori_smart" => Some(4269i64),
+            "tacx_flow_smart" => Some(4270i64),
+            "tacx_vortex_smart" => Some(4271i64),
+            "tacx_bushido_smart" => Some(4272i64),
+            "tacx_genius_smart" => Some(4273i64),
+            "tacx_flux_flux_s_smart" => Some(4274i64),
+            "tacx_flux2_smart" => Some(4275i64),
+            "tacx_magnum" => Some(4276i64),
+            "edge_1040_asia" => Some(4305i64),
+            "epix_gen2_pro_42" => Some(4312i64),
+            "epix_gen2_pro_47" => Some(4313i64),
+            "epix_gen2_pro_51" => Some(4314i64),
+            "fr965" => Some(4315i64),
+            "enduro2" => Some(4341i64),
+            "fenix7s_pro_solar" => Some(4374i64),
+            "fenix7_pro_solar" => Some(4375i64),
+            "fenix7x_pro_solar" => Some(4376i64),
+            "lily2" => Some(4380i64),
+            "instinct_2x" => Some(4394i64),
+            "vivoactive5" => Some(4426i64),
+            "fr165" => Some(4432i64),
+            "fr165_music" => Some(4433i64),
+            "descent_t2" => Some(4442i64),
+            "hrm_fit" => Some(4446i64),
+            "marq_gen2_commander" => Some(4472i64),
+            "d2_mach1_pro" => Some(4556i64),
+            "sdm4" => Some(10007i64),
+            "edge_remote" => Some(10014i64),
+            "training_center" => Some(20119i64),
+            "tacx_training_app_win" => Some(20533i64),
+            "tacx_training_app_mac" => Some(20534i64),
+            "tacx_training_app_mac_catalyst" => Some(20565i64),
+            "tacx_training_app_android" => Some(30045i64),
+            "tacx_training_app_ios" => Some(30046i64),
+            "tacx_training_app_legacy" => Some(30047i64),
+            "connectiq_simulator" => Some(65531i64),
+            "android_antplus_plugin" => Some(65532i64),
+            "connect" => Some(65534i64),
+            _ => None,
+        }
+    }
+    pub fn as_u16(self) -> u16 {
+        match self {
+            GarminProduct::ApproachG12Asia => 1,
+            GarminProduct::Axh01 => 2,
+            GarminProduct::Axb01 => 3,
+            GarminProduct::Axb02 => 4,
+            GarminProduct::Hrm2ss => 5,
+            GarminProduct::DsiAlf02 => 6,
+            GarminProduct::Hrm3ss => 7,
+            GarminProduct::HrmRunSingleByteProductId => 8,
+            GarminProduct::Bsm => 9,
+            GarminProduct::Bcm => 10,
+            GarminProduct::Axs01 => 11,
+            GarminProduct::HrmTriSingleByteProductId => 12,
+            GarminProduct::Hrm4RunSingleByteProductId => 13,
+            GarminProduct::Fr225SingleByteProductId => 14,
+            GarminProduct::Gen3BsmSingleByteProductId => 15,
+            GarminProduct::Gen3BcmSingleByteProductId => 16,
+            GarminProduct::HrmFitSingleByteProductId => 22,
+            GarminProduct::OHR => 255,
+            GarminProduct::Fr301China => 473,
+            GarminProduct::Fr301Japan => 474,
+            GarminProduct::Fr301Korea => 475,
+            GarminProduct::Fr301Taiwan => 494,
+            GarminProduct::Fr405 => 717,
+            GarminProduct::Fr50 => 782,
+            GarminProduct::Fr405Japan => 987,
+            GarminProduct::Fr60 => 988,
+            GarminProduct::DsiAlf01 => 1011,
+            GarminProduct::Fr310xt => 1018,
+            GarminProduct::Edge500 => 1036,
+            GarminProduct::Fr110 => 1124,
+            GarminProduct::Edge800 => 1169,
+            GarminProduct::Edge500Taiwan => 1199,
+            GarminProduct::Edge500Japan => 1213,
+            GarminProduct::Chirp => 1253,
+            GarminProduct::Fr110Japan => 1274,
+            GarminProduct::Edge200 => 1325,
+            GarminProduct::Fr910xt => 1328,
+            GarminProduct::Edge800Taiwan => 1333,
+            GarminProduct::Edge800Japan => 1334,
+            GarminProduct::Alf04 => 1341,
+            GarminProduct::Fr610 => 1345,
+            GarminProduct::Fr210Japan => 1360,
+            GarminProduct::VectorSs => 1380,
+            GarminProduct::VectorCp => 1381,
+            GarminProduct::Edge800China => 1386,
+            GarminProduct::Edge500China => 1387,
+            GarminProduct::ApproachG10 => 1405,
+            GarminProduct::Fr610Japan => 1410,
+            GarminProduct::Edge500Korea => 1422,
+            GarminProduct::Fr70 => 1436,
+            GarminProduct::Fr310xt4t => 1446,
+            GarminProduct::Amx => 1461,
+            GarminProduct::Fr10 => 1482,
+            GarminProduct::Edge800Korea => 1497,
+            GarminProduct::Swim => 1499,
+            GarminProduct::Fr910xtChina => 1537,
+            GarminProduct::Fenix => 1551,
+            GarminProduct::Edge200Taiwan => 1555,
+            GarminProduct::Edge510 => 1561,
+            GarminProduct::Edge810 => 1567,
+            GarminProduct::Tempe => 1570,
+            GarminProduct::Fr910xtJapan => 1600,
+            GarminProduct::Fr620 => 1623,
+            GarminProduct::Fr220 => 1632,
+            GarminProduct::Fr910xtKorea => 1664,
+            GarminProduct::Fr10Japan => 1688,
+            GarminProduct::Edge810Japan => 1721,
+            GarminProduct::VirbElite => 1735,
+            GarminProduct::EdgeTouring => 1736,
+            GarminProduct::Edge510Japan => 1742,
+            GarminProduct::HrmTri => 1743,
+            GarminProduct::HrmRun => 1752,
+            GarminProduct::Fr920xt => 1765,
+            GarminProduct::Edge510Asia => 1821,
+            GarminProduct::Edge810China => 1822,
+            GarminProduct::Edge810Taiwan => 1823,
+            GarminProduct::Edge1000 => 1836,
+            GarminProduct::VivoFit => 1837,
+            GarminProduct::VirbRemote => 1853,
+            GarminProduct::VivoKi => 1885,
+            GarminProduct::Fr15 => 1903,
+            GarminProduct::VivoActive => 1907,
+            GarminProduct::Edge510Korea => 1918,
+            GarminProduct::Fr620Japan => 1928,
+            GarminProduct::Fr620China => 1929,
+            GarminProduct::Fr220Japan => 1930,
+            GarminProduct::Fr220China => 1931,
+            GarminProduct::ApproachS6 => 1936,
+            GarminProduct::VivoSmart => 1956,
+            GarminProduct::Fenix2 => 1967,
+            GarminProduct::Epix => 1988,
+            GarminProduct::Fenix3 => 2050,
+            GarminProduct::Edge1000Taiwan => 2052,
+            GarminProduct::Edge1000Japan => 2053,
+            GarminProduct::Fr15Japan => 2061,
+            GarminProduct::Edge520 => 2067,
+            GarminProduct::Edge1000China => 2070,
+            GarminProduct::Fr620Russia => 2072,
+            GarminProduct::Fr220Russia => 2073,
+            GarminProduct::VectorS => 2079,
+            GarminProduct::Edge1000Korea => 2100,
+            GarminProduct::Fr920xtTaiwan => 2130,
+            GarminProduct::Fr920xtChina => 2131,
+            GarminProduct::Fr920xtJapan => 2132,
+            GarminProduct::Virbx => 2134,
+            GarminProduct::VivoSmartApac => 2135,
+            GarminProduct::EtrexTouch => 2140,
+            GarminProduct::Edge25 => 2147,
+            GarminProduct::Fr25 => 2148,
+            GarminProduct::VivoFit2 => 2150,
+            GarminProduct::Fr225 => 2153,
+            GarminProduct::Fr630 => 2156,
+            GarminProduct::Fr230 => 2157,
+            GarminProduct::Fr735xt => 2158,
+            GarminProduct::VivoActiveApac => 2160,
+            GarminProduct::Vector2 => 2161,
+            GarminProduct::Vector2s => 2162,
+            GarminProduct::Virbxe => 2172,
+            GarminProduct::Fr620Taiwan => 2173,
+            GarminProduct::Fr220Taiwan => 2174,
+            GarminProduct::Truswing => 2175,
+            GarminProduct::D2airvenu => 2187,
+            GarminProduct::Fenix3China => 2188,
+            GarminProduct::Fenix3Twn => 2189,
+            GarminProduct::VariaHeadlight => 2192,
+            GarminProduct::VariaTaillightOld => 2193,
+            GarminProduct::EdgeExplore1000 => 2204,
+            GarminProduct::Fr225Asia => 2219,
+            GarminProduct::VariaRadarTaillight => 2225,
+            GarminProduct::VariaRadarDisplay => 2226,
+            GarminProduct::Edge20 => 2238,
+            GarminProduct::Edge520Asia => 2260,
+            GarminProduct::Edge520Japan => 2261,
+            GarminProduct::D2Bravo => 2262,
+            GarminProduct::ApproachS20 => 2266,
+            GarminProduct::VivoSmart2 => 2271,
+            GarminProduct::Edge1000Thai => 2274,
+            GarminProduct::VariaRemote => 2276,
+            GarminProduct::Edge25Asia => 2288,
+            GarminProduct::Edge25Jpn => 2289,
+            GarminProduct::Edge20Asia => 2290,
+            GarminProduct::ApproachX40 => 2292,
+            GarminProduct::Fenix3Japan => 2293,
+            GarminProduct::VivoSmartEmea => 2294,
+            GarminProduct::Fr630Asia => 2310,
+            GarminProduct::Fr630Jpn => 2311,
+            GarminProduct::Fr230Jpn => 2313,
+            GarminProduct::Hrm4Run => 2327,
+            GarminProduct::EpixJapan => 2332,
+            GarminProduct::VivoActiveHr => 2337,
+            GarminProduct::ApproachG12 => 2343,
+            GarminProduct::VivoSmartGpsHr => 2347,
+            GarminProduct::VivoSmartHr => 2348,
+            GarminProduct::VivoSmartHrAsia => 2361,
+            GarminProduct::VivoSmartGpsHrAsia => 2362,
+            GarminProduct::VivoMove => 2368,
+            GarminProduct::VariaTaillight => 2379,
+            GarminProduct::Fr235Asia => 2396,
+            GarminProduct::Fr235Japan => 2397,
+            GarminProduct::VariaVision => 2398,
+            GarminProduct::VivoFit3 => 2406,
+            GarminProduct::Fenix3Korea => 2407,
+            GarminProduct::Fenix3Sea => 2408,
+            GarminProduct::Fenix3Hr => 2413,
+            GarminProduct::VirbUltra30 => 2417,
+            GarminProduct::IndexSmartScale => 2429,
+            GarminProduct::Fr235 => 2431,
+            GarminProduct::Fenix3Chronos => 2432,
+            GarminProduct::Oregon7xx => 2441,
+            GarminProduct::Rino7xx => 2444,
+            GarminProduct::EpixKorea => 2457,
+            GarminProduct::Fenix3HrChn => 2473,
+            GarminProduct::Fenix3HrTwn => 2474,
+            GarminProduct::Fenix3HrJpn => 2475,
+            GarminProduct::Fenix3HrSea => 2476,
+            GarminProduct::Fenix3HrKor => 2477,
+            GarminProduct::Nautix => 2496,
+            GarminProduct::VivoActiveHrApac => 2497,
+            GarminProduct::Fr35 => 2503,
+            GarminProduct::Oregon7xxWw => 2512,
+            GarminProduct::Edge820 => 2530,
+            GarminProduct::EdgeExplore820 => 2531,
+            GarminProduct::Fr735xtApac => 2533,
+            GarminProduct::Fr735xtJapan => 2534,
+            GarminProduct::Fenix5s => 2544,
+            GarminProduct::D2BravoTitanium => 2547,
+            GarminProduct::VariaUt800 => 2567,
+            GarminProduct::RunningDynamicsPod => 2593,
+            GarminProduct::Edge820China => 2599,
+            GarminProduct::Edge820Japan => 2600,
+            GarminProduct::Fenix5x => 2604,
+            GarminProduct::VivoFitJr => 2606,
+            GarminProduct::VivoSmart3 => 2622,
+            GarminProduct::VivoSport => 2623,
+            GarminProduct::Edge820Taiwan => 2628,
+            GarminProduct::Edge820Korea => 2629,
+            GarminProduct::Edge820Sea => 2630,
+            GarminProduct::Fr35Hebrew => 2650,
+            GarminProduct::ApproachS60 => 2656,
+            GarminProduct::Fr35Apac => 2667,
+            GarminProduct::Fr35Japan => 2668,
+            GarminProduct::Fenix3ChronosAsia => 2675,
+            GarminProduct::Virb360 => 2687,
+            GarminProduct::Fr935 => 2691,
+            GarminProduct::Fenix5 => 2697,
+            GarminProduct::Vivoactive3 => 2700,
+            GarminProduct::Edge1030 => 2713,
+            GarminProduct::Fr35Sea => 2727,
+            GarminProduct::Fr235ChinaNfc => 2733,
+            GarminProduct::Foretrex601701 => 2769,
+            GarminProduct::VivoMoveHr => 2772,
+            GarminProduct::Vector3 => 2787,
+            GarminProduct::Fenix5Asia => 2796,
+            GarminProduct::Fenix5sAsia => 2797,
+            GarminProduct::Fenix5xAsia => 2798,
+            GarminProduct::ApproachZ80 => 2806,
+            GarminProduct::Fr35Korea => 2814,
+            GarminProduct::D2charlie => 2819,
+            GarminProduct::VivoSmart3Apac => 2831,
+            GarminProduct::VivoSportApac => 2832,
+            GarminProduct::Fr935Asia => 2833,
+            GarminProduct::Descent => 2859,
+            GarminProduct::VivoFit4 => 2878,
+            GarminProduct::Fr645 => 2886,
+            GarminProduct::Fr645m => 2888,
+            GarminProduct::Fr30 => 2891,
+            GarminProduct::Fenix5sPlus => 2900,
+            GarminProduct::Edge130 => 2909,
+            GarminProduct::Edge1030Asia => 2924,
+            GarminProduct::Vivosmart4 => 2927,
+            GarminProduct::VivoMoveHrAsia => 2945,
+            GarminProduct::ApproachX10 => 2962,
+            GarminProduct::Fr30Asia => 2977,
+            GarminProduct::Vivoactive3mW => 2988,
+            GarminProduct::Fr645Asia => 3003,
+            GarminProduct::Fr645mAsia => 3004,
+            GarminProduct::EdgeExplore => 3011,
+            GarminProduct::Gpsmap66 => 3028,
+            GarminProduct::ApproachS10 => 3049,
+            GarminProduct::Vivoactive3mL => 3066,
+            GarminProduct::ApproachG80 => 3085,
+            GarminProduct::Edge130Asia => 3092,
+            GarminProduct::Edge1030Bontrager => 3095,
+            GarminProduct::Fenix5Plus => 3110,
+            GarminProduct::Fenix5xPlus => 3111,
+            GarminProduct::Edge520Plus => 3112,
+            GarminProduct::Fr945 => 3113,
+            GarminProduct::Edge530 => 3121,
+            GarminProduct::Edge830 => 3122,
+            GarminProduct::InstinctEsports => 3126,
+            GarminProduct::Fenix5sPlusApac => 3134,
+            GarminProduct::Fenix5xPlusApac => 3135,
+            GarminProduct::Edge520PlusApac => 3142,
+            GarminProduct::DescentT1 => 3143,
+            GarminProduct::Fr235lAsia => 3144,
+            GarminProduct::Fr245Asia => 3145,
+            GarminProduct::VivoActive3mApac => 3163,
+            GarminProduct::Gen3Bsm => 3192,
+            GarminProduct::Gen3Bcm => 3193,
+            GarminProduct::VivoSmart4Asia => 3218,
+            GarminProduct::Vivoactive4Small => 3224,
+            GarminProduct::Vivoactive4Large => 3225,
+            GarminProduct::Venu => 3226,
+            GarminProduct::MarqDriver => 3246,
+            GarminProduct::MarqAviator => 3247,
+            GarminProduct::MarqCaptain => 3248,
+            GarminProduct::MarqCommander => 3249,
+            GarminProduct::MarqExpedition => 3250,
+            GarminProduct::MarqAthlete => 3251,
+            GarminProduct::DescentMk2 => 3258,
+            GarminProduct::Gpsmap66i => 3284,
+            GarminProduct::Fenix6SSport => 3287,
+            GarminProduct::Fenix6S => 3288,
+            GarminProduct::Fenix6Sport => 3289,
+            GarminProduct::Fenix6 => 3290,
+            GarminProduct::Fenix6x => 3291,
+            GarminProduct::HrmDual => 3299,
+            GarminProduct::HrmPro => 3300,
+            GarminProduct::VivoMove3Premium => 3308,
+            GarminProduct::ApproachS40 => 3314,
+            GarminProduct::Fr245mAsia => 3321,
+            GarminProduct::Edge530Apac => 3349,
+            GarminProduct::Edge830Apac => 3350,
+            GarminProduct::VivoMove3 => 3378,
+            GarminProduct::VivoActive4SmallAsia => 3387,
+            GarminProduct::VivoActive4LargeAsia => 3388,
+            GarminProduct::VivoActive4OledAsia => 3389,
+            GarminProduct::Swim2 => 3405,
+            GarminProduct::MarqDriverAsia => 3420,
+            GarminProduct::MarqAviatorAsia => 3421,
+            GarminProduct::VivoMove3Asia => 3422,
+            GarminProduct::Fr945Asia => 3441,
+            GarminProduct::VivoActive3tChn => 3446,
+            GarminProduct::MarqCaptainAsia => 3448,
+            GarminProduct::MarqCommanderAsia => 3449,
+            GarminProduct::MarqExpeditionAsia => 3450,
+            GarminProduct::MarqAthleteAsia => 3451,
+            GarminProduct::InstinctSolar => 3466,
+            GarminProduct::Fr45Asia => 3469,
+            GarminProduct::Vivoactive3Daimler => 3473,
+            GarminProduct::LegacyRey => 3498,
+            GarminProduct::LegacyDarthVader => 3499,
+            GarminProduct::LegacyCaptainMarvel => 3500,
+            GarminProduct::LegacyFirstAvenger => 3501,
+            GarminProduct::Fenix6sSportAsia => 3512,
+            GarminProduct::Fenix6sAsia => 3513,
+            GarminProduct::Fenix6SportAsia => 3514,
+            GarminProduct::Fenix6Asia => 3515,
+            GarminProduct::Fenix6xAsia => 3516,
+            GarminProduct::LegacyCaptainMarvelAsia => 3535,
+            GarminProduct::LegacyFirstAvengerAsia => 3536,
+            GarminProduct::LegacyReyAsia => 3537,
+            GarminProduct::LegacyDarthVaderAsia => 3538,
+            GarminProduct::DescentMk2s => 3542,
+            GarminProduct::Edge130Plus => 3558,
+            GarminProduct::Edge1030Plus => 3570,
+            GarminProduct::Rally200 => 3578,
+            GarminProduct::Fr745 => 3589,
+            GarminProduct::Venusq => 3600,
+            GarminProduct::Lily => 3615,
+            GarminProduct::MarqAdventurer => 3624,
+            GarminProduct::Enduro => 3638,
+            GarminProduct::Swim2Apac => 3639,
+            GarminProduct::MarqAdventurerAsia => 3648,
+            GarminProduct::Fr945Lte => 3652,
+            GarminProduct::DescentMk2Asia => 3702,
+            GarminProduct::Venu2 => 3703,
+            GarminProduct::Venu2s => 3704,
+            GarminProduct::VenuDaimlerAsia => 3737,
+            GarminProduct::MarqGolfer => 3739,
+            GarminProduct::VenuDaimler => 3740,
+            GarminProduct::Fr745Asia => 3794,
+            GarminProduct::VariaRct715 => 3808,
+            GarminProduct::LilyAsia => 3809,
+            GarminProduct::Edge1030PlusAsia => 3812,
+            GarminProduct::Edge130PlusAsia => 3813,
+            GarminProduct::ApproachS12 => 3823,
+            GarminProduct::VenusqAsia => 3837,
+            GarminProduct::Edge1040 => 3843,
+            GarminProduct::MarqGolferAsia => 3850,
+            GarminProduct::Venu2Plus => 3851,
+            GarminProduct::Gnss => 3865,
+            GarminProduct::Fr55 => 3869,
+            GarminProduct::EnduroAsia => 3872,
+            GarminProduct::Instinct2 => 3888,
+            GarminProduct::Fenix7s => 3905,
+            GarminProduct::Fenix7 => 3906,
+            GarminProduct::Fenix7x => 3907,
+            GarminProduct::Fenix7sApac => 3908,
+            GarminProduct::Fenix7Apac => 3909,
+            GarminProduct::Fenix7xApac => 3910,
+            GarminProduct::DescentMk2sAsia => 3930,
+            GarminProduct::ApproachS42 => 3934,
+            GarminProduct::EpixGen2 => 3943,
+            GarminProduct::EpixGen2Apac => 3944,
+            GarminProduct::Venu2sAsia => 3949,
+            GarminProduct::Venu2Asia => 3950,
+            GarminProduct::Fr945LteAsia => 3978,
+            GarminProduct::VivoMoveSport => 3982,
+            GarminProduct::VivomoveTrend => 3983,
+            GarminProduct::ApproachS12Asia => 3986,
+            GarminProduct::Fr255Music => 3990,
+            GarminProduct::Fr255SmallMusic => 3991,
+            GarminProduct::Fr255 => 3992,
+            GarminProduct::Fr255Small => 3993,
+            GarminProduct::ApproachS42Asia => 4002,
+            GarminProduct::DescentG1 => 4005,
+            GarminProduct::Venu2PlusAsia => 4017,
+            GarminProduct::Fr955 => 4024,
+            GarminProduct::Fr55Asia => 4033,
+            GarminProduct::Edge540 => 4061,
+            GarminProduct::Edge840 => 4062,
+            GarminProduct::Vivosmart5 => 4063,
+            GarminProduct::Instinct2Asia => 4071,
+            GarminProduct::MarqGen2 => 4105,
+            GarminProduct::Venusq2 => 4115,
+            GarminProduct::Venusq2music => 4116,
+            GarminProduct::MarqGen2Aviator => 4124,
+            GarminProduct::D2AirX10 => 4125,
+            GarminProduct::HrmProPlus => 4130,
+            GarminProduct::DescentG1Asia => 4132,
+            GarminProduct::Tactix7 => 4135,
+            GarminProduct::InstinctCrossover => 4155,
+            GarminProduct::EdgeExplore2 => 4169,
+            GarminProduct::DescentMk3 => 4222,
+            GarminProduct::DescentMk3i => 4223,
+            GarminProduct::ApproachS70 => 4233,
+            GarminProduct::Fr265Large => 4257,
+            GarminProduct::Fr265Small => 4258,
+            GarminProduct::Venu3 => 4260,
+            GarminProduct::Venu3s => 4261,
+            GarminProduct::TacxNeoSmart => 4265,
+            GarminProduct::TacxNeo2Smart => 4266,
+            GarminProduct::TacxNeo2TSmart => 4267,
+            GarminProduct::TacxNeoSmartBike => 4268,
+            GarminProduct::TacxSatoriSmart => 4269,
+            GarminProduct::TacxFlowSmart => 4270,
+            GarminProduct::TacxVortexSmart => 4271,
+            GarminProduct::TacxBushidoSmart => 4272,
+            GarminProduct::TacxGeniusSmart => 4273,
+            GarminProduct::TacxFluxFluxSSmart => 4274,
+            GarminProduct::TacxFlux2Smart => 4275,
+            GarminProduct::TacxMagnum => 4276,
+            GarminProduct::Edge1040Asia => 4305,
+            GarminProduct::EpixGen2Pro42 => 4312,
+            GarminProduct::EpixGen2Pro47 => 4313,
+            GarminProduct::EpixGen2Pro51 => 4314,
+            GarminProduct::Fr965 => 4315,
+            GarminProduct::Enduro2 => 4341,
+            GarminProduct::Fenix7sProSolar => 4374,
+            GarminProduct::Fenix7ProSolar => 4375,
+            GarminProduct::Fenix7xProSolar => 4376,
+            GarminProduct::Lily2 => 4380,
+            GarminProduct::Instinct2x => 4394,
+            GarminProduct::Vivoactive5 => 4426,
+            GarminProduct::Fr165 => 4432,
+            GarminProduct::Fr165Music => 4433,
+            GarminProduct::DescentT2 => 4442,
+            GarminProduct::HrmFit => 4446,
+            GarminProduct::MarqGen2Commander => 4472,
+            GarminProduct::D2Mach1Pro => 4556,
+            GarminProduct::Sdm4 => 10007,
+            GarminProduct::EdgeRemote => 10014,
+            GarminProduct::TrainingCenter => 20119,
+            GarminProduct::TacxTrainingAppWin => 20533,
+            GarminProduct::TacxTrainingAppMac => 20534,
+            GarminProduct::TacxTrainingAppMacCatalyst => 20565,
+            GarminProduct::TacxTrainingAppAndroid => 30045,
+            GarminProduct::TacxTrainingAppIos => 30046,
+            GarminProduct::TacxTrainingAppLegacy => 30047,
+            GarminProduct::ConnectiqSimulator => 65531,
+            GarminProduct::AndroidAntplusPlugin => 65532,
+            GarminProduct::Connect => 65534,
+            GarminProduct::Value(value) => value,
+        }
+    }
+    pub fn as_i64(self) -> i64 {
+        self.as_u16() as i64
+    }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
         match self {
             GarminProduct::ApproachG12Asia => 1,
             GarminProduct::Axh01 => 2,
@@ -10490,12 +15345,9 @@ impl GarminProduct {
             GarminProduct::ConnectiqSimulator => 65531,
             GarminProduct::AndroidAntplusPlugin => 65532,
             GarminProduct::Connect => 65534,
-            GarminProduct::Value(value) => value,
+            GarminProduct::Value(value) => value as i64,
         }
     }
-    pub fn as_i64(self) -> i64 {
-        self.as_u16() as i64
-    }
 }
 impl fmt::Display for GarminProduct {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -11401,6 +16253,15 @@ impl Serialize for GarminProduct {
         }
     }
 }
+impl<'de> Deserialize<'de> for GarminProduct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::GarminProduct, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum AntplusDeviceType {
     Antfs,
@@ -11461,6 +16322,36 @@ impl AntplusDeviceType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "antfs" => Some(1i64),
+            "bike_power" => Some(11i64),
+            "environment_sensor_legacy" => Some(12i64),
+            "multi_sport_speed_distance" => Some(15i64),
+            "control" => Some(16i64),
+            "fitness_equipment" => Some(17i64),
+            "blood_pressure" => Some(18i64),
+            "geocache_node" => Some(19i64),
+            "light_electric_vehicle" => Some(20i64),
+            "env_sensor" => Some(25i64),
+            "racquet" => Some(26i64),
+            "control_hub" => Some(27i64),
+            "muscle_oxygen" => Some(31i64),
+            "shifting" => Some(34i64),
+            "bike_light_main" => Some(35i64),
+            "bike_light_shared" => Some(36i64),
+            "exd" => Some(38i64),
+            "bike_radar" => Some(40i64),
+            "bike_aero" => Some(46i64),
+            "weight_scale" => Some(119i64),
+            "heart_rate" => Some(120i64),
+            "bike_speed_cadence" => Some(121i64),
+            "bike_cadence" => Some(122i64),
+            "bike_speed" => Some(123i64),
+            "stride_speed_distance" => Some(124i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             AntplusDeviceType::Antfs => 1,
@@ -11494,6 +16385,38 @@ impl AntplusDeviceType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            AntplusDeviceType::Antfs => 1,
+            AntplusDeviceType::BikePower => 11,
+            AntplusDeviceType::EnvironmentSensorLegacy => 12,
+            AntplusDeviceType::MultiSportSpeedDistance => 15,
+            AntplusDeviceType::Control => 16,
+            AntplusDeviceType::FitnessEquipment => 17,
+            AntplusDeviceType::BloodPressure => 18,
+            AntplusDeviceType::GeocacheNode => 19,
+            AntplusDeviceType::LightElectricVehicle => 20,
+            AntplusDeviceType::EnvSensor => 25,
+            AntplusDeviceType::Racquet => 26,
+            AntplusDeviceType::ControlHub => 27,
+            AntplusDeviceType::MuscleOxygen => 31,
+            AntplusDeviceType::Shifting => 34,
+            AntplusDeviceType::BikeLightMain => 35,
+            AntplusDeviceType::BikeLightShared => 36,
+            AntplusDeviceType::Exd => 38,
+            AntplusDeviceType::BikeRadar => 40,
+            AntplusDeviceType::BikeAero => 46,
+            AntplusDeviceType::WeightScale => 119,
+            AntplusDeviceType::HeartRate => 120,
+            AntplusDeviceType::BikeSpeedCadence => 121,
+            AntplusDeviceType::BikeCadence => 122,
+            AntplusDeviceType::BikeSpeed => 123,
+            AntplusDeviceType::StrideSpeedDistance => 124,
+            AntplusDeviceType::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for AntplusDeviceType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -11575,6 +16498,15 @@ impl Serialize for AntplusDeviceType {
         }
     }
 }
+impl<'de> Deserialize<'de> for AntplusDeviceType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::AntplusDeviceType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum AntNetwork {
     Public,
@@ -11593,6 +16525,15 @@ impl AntNetwork {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "public" => Some(0i64),
+            "antplus" => Some(1i64),
+            "antfs" => Some(2i64),
+            "private" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             AntNetwork::Public => 0,
@@ -11605,6 +16546,17 @@ impl AntNetwork {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            AntNetwork::Public => 0,
+            AntNetwork::Antplus => 1,
+            AntNetwork::Antfs => 2,
+            AntNetwork::Private => 3,
+            AntNetwork::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for AntNetwork {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -11641,6 +16593,15 @@ impl Serialize for AntNetwork {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for AntNetwork {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::AntNetwork, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WorkoutCapabilities {
     Interval,
@@ -11687,6 +16648,25 @@ impl WorkoutCapabilities {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "interval" => Some(1i64),
+            "custom" => Some(2i64),
+            "fitness_equipment" => Some(4i64),
+            "firstbeat" => Some(8i64),
+            "new_leaf" => Some(16i64),
+            "tcx" => Some(32i64),
+            "speed" => Some(128i64),
+            "heart_rate" => Some(256i64),
+            "distance" => Some(512i64),
+            "cadence" => Some(1024i64),
+            "power" => Some(2048i64),
+            "grade" => Some(4096i64),
+            "resistance" => Some(8192i64),
+            "protected" => Some(16384i64),
+            _ => None,
+        }
+    }
     pub fn as_u32(self) -> u32 {
         match self {
             WorkoutCapabilities::Interval => 1,
@@ -11709,6 +16689,27 @@ impl WorkoutCapabilities {
     pub fn as_i64(self) -> i64 {
         self.as_u32() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            WorkoutCapabilities::Interval => 1,
+            WorkoutCapabilities::Custom => 2,
+            WorkoutCapabilities::FitnessEquipment => 4,
+            WorkoutCapabilities::Firstbeat => 8,
+            WorkoutCapabilities::NewLeaf => 16,
+            WorkoutCapabilities::Tcx => 32,
+            WorkoutCapabilities::Speed => 128,
+            WorkoutCapabilities::HeartRate => 256,
+            WorkoutCapabilities::Distance => 512,
+            WorkoutCapabilities::Cadence => 1024,
+            WorkoutCapabilities::Power => 2048,
+            WorkoutCapabilities::Grade => 4096,
+            WorkoutCapabilities::Resistance => 8192,
+            WorkoutCapabilities::Protected => 16384,
+            WorkoutCapabilities::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for WorkoutCapabilities {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -11768,6 +16769,16 @@ impl Serialize for WorkoutCapabilities {
         }
     }
 }
+impl<'de> Deserialize<'de> for WorkoutCapabilities {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u32::MAX);
+        deserialize_variant(deserializer, FieldDataType::WorkoutCapabilities, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum BatteryStatus {
     New,
@@ -11792,6 +16803,18 @@ impl BatteryStatus {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "new" => Some(1i64),
+            "good" => Some(2i64),
+            "ok" => Some(3i64),
+            "low" => Some(4i64),
+            "critical" => Some(5i64),
+            "charging" => Some(6i64),
+            "unknown" => Some(7i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             BatteryStatus::New => 1,
@@ -11807,6 +16830,20 @@ impl BatteryStatus {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            BatteryStatus::New => 1,
+            BatteryStatus::Good => 2,
+            BatteryStatus::Ok => 3,
+            BatteryStatus::Low => 4,
+            BatteryStatus::Critical => 5,
+            BatteryStatus::Charging => 6,
+            BatteryStatus::Unknown => 7,
+            BatteryStatus::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for BatteryStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -11852,6 +16889,15 @@ impl Serialize for BatteryStatus {
         }
     }
 }
+impl<'de> Deserialize<'de> for BatteryStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::BatteryStatus, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum HrType {
     Normal,
@@ -11866,6 +16912,13 @@ impl HrType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "normal" => Some(0i64),
+            "irregular" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             HrType::Normal => 0,
@@ -11876,6 +16929,15 @@ impl HrType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            HrType::Normal => 0,
+            HrType::Irregular => 1,
+            HrType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for HrType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -11908,6 +16970,15 @@ impl Serialize for HrType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for HrType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::HrType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum CourseCapabilities {
     Processed,
@@ -11943,6 +17014,23 @@ impl CourseCapabilities {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "processed" => Some(1i64),
+            "valid" => Some(2i64),
+            "time" => Some(4i64),
+            "distance" => Some(8i64),
+            "position" => Some(16i64),
+            "heart_rate" => Some(32i64),
+            "power" => Some(64i64),
+            "cadence" => Some(128i64),
+            "training" => Some(256i64),
+            "navigation" => Some(512i64),
+            "bikeway" => Some(1024i64),
+            "aviation" => Some(4096i64),
+            _ => None,
+        }
+    }
     pub fn as_u32(self) -> u32 {
         match self {
             CourseCapabilities::Processed => 1,
@@ -11963,6 +17051,25 @@ impl CourseCapabilities {
     pub fn as_i64(self) -> i64 {
         self.as_u32() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            CourseCapabilities::Processed => 1,
+            CourseCapabilities::Valid => 2,
+            CourseCapabilities::Time => 4,
+            CourseCapabilities::Distance => 8,
+            CourseCapabilities::Position => 16,
+            CourseCapabilities::HeartRate => 32,
+            CourseCapabilities::Power => 64,
+            CourseCapabilities::Cadence => 128,
+            CourseCapabilities::Training => 256,
+            CourseCapabilities::Navigation => 512,
+            CourseCapabilities::Bikeway => 1024,
+            CourseCapabilities::Aviation => 4096,
+            CourseCapabilities::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for CourseCapabilities {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12018,6 +17125,16 @@ impl Serialize for CourseCapabilities {
         }
     }
 }
+impl<'de> Deserialize<'de> for CourseCapabilities {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u32::MAX);
+        deserialize_variant(deserializer, FieldDataType::CourseCapabilities, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Weight {
     Calculating,
@@ -12030,6 +17147,12 @@ impl Weight {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "calculating" => Some(65534i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             Weight::Calculating => 65534,
@@ -12039,6 +17162,14 @@ impl Weight {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Weight::Calculating => 65534,
+            Weight::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Weight {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12072,6 +17203,15 @@ impl Serialize for Weight {
         }
     }
 }
+impl<'de> Deserialize<'de> for Weight {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::Weight, invalid).map(Self::from)
+    }
+}
 #[doc = "0 - 100 indicates% of max hr; >100 indicates bpm (255 max) plus 100"]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WorkoutHr {
@@ -12085,6 +17225,12 @@ impl WorkoutHr {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "bpm_offset" => Some(100i64),
+            _ => None,
+        }
+    }
     pub fn as_u32(self) -> u32 {
         match self {
             WorkoutHr::BpmOffset => 100,
@@ -12094,6 +17240,14 @@ impl WorkoutHr {
     pub fn as_i64(self) -> i64 {
         self.as_u32() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            WorkoutHr::BpmOffset => 100,
+            WorkoutHr::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for WorkoutHr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12127,6 +17281,15 @@ impl Serialize for WorkoutHr {
         }
     }
 }
+impl<'de> Deserialize<'de> for WorkoutHr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u32::MAX);
+        deserialize_variant(deserializer, FieldDataType::WorkoutHr, invalid).map(Self::from)
+    }
+}
 #[doc = "0 - 1000 indicates % of functional threshold power; >1000 indicates watts plus 1000."]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WorkoutPower {
@@ -12140,6 +17303,12 @@ impl WorkoutPower {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "watts_offset" => Some(1000i64),
+            _ => None,
+        }
+    }
     pub fn as_u32(self) -> u32 {
         match self {
             WorkoutPower::WattsOffset => 1000,
@@ -12149,6 +17318,14 @@ impl WorkoutPower {
     pub fn as_i64(self) -> i64 {
         self.as_u32() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            WorkoutPower::WattsOffset => 1000,
+            WorkoutPower::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for WorkoutPower {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12182,6 +17359,15 @@ impl Serialize for WorkoutPower {
         }
     }
 }
+impl<'de> Deserialize<'de> for WorkoutPower {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u32::MAX);
+        deserialize_variant(deserializer, FieldDataType::WorkoutPower, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum BpStatus {
     NoError,
@@ -12202,6 +17388,16 @@ impl BpStatus {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "no_error" => Some(0i64),
+            "error_incomplete_data" => Some(1i64),
+            "error_no_measurement" => Some(2i64),
+            "error_data_out_of_range" => Some(3i64),
+            "error_irregular_heart_rate" => Some(4i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             BpStatus::NoError => 0,
@@ -12215,6 +17411,18 @@ impl BpStatus {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            BpStatus::NoError => 0,
+            BpStatus::ErrorIncompleteData => 1,
+            BpStatus::ErrorNoMeasurement => 2,
+            BpStatus::ErrorDataOutOfRange => 3,
+            BpStatus::ErrorIrregularHeartRate => 4,
+            BpStatus::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for BpStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12253,6 +17461,15 @@ impl Serialize for BpStatus {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for BpStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::BpStatus, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum UserLocalId {
     LocalMin,
@@ -12275,6 +17492,17 @@ impl UserLocalId {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "local_min" => Some(0i64),
+            "local_max" => Some(15i64),
+            "stationary_min" => Some(16i64),
+            "stationary_max" => Some(255i64),
+            "portable_min" => Some(256i64),
+            "portable_max" => Some(65534i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             UserLocalId::LocalMin => 0,
@@ -12289,6 +17517,19 @@ impl UserLocalId {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            UserLocalId::LocalMin => 0,
+            UserLocalId::LocalMax => 15,
+            UserLocalId::StationaryMin => 16,
+            UserLocalId::StationaryMax => 255,
+            UserLocalId::PortableMin => 256,
+            UserLocalId::PortableMax => 65534,
+            UserLocalId::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for UserLocalId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12332,6 +17573,15 @@ impl Serialize for UserLocalId {
         }
     }
 }
+impl<'de> Deserialize<'de> for UserLocalId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::UserLocalId, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SwimStroke {
     Freestyle,
@@ -12357,6 +17607,18 @@ impl SwimStroke {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "freestyle" => Some(0i64),
+            "backstroke" => Some(1i64),
+            "breaststroke" => Some(2i64),
+            "butterfly" => Some(3i64),
+            "drill" => Some(4i64),
+            "mixed" => Some(5i64),
+            "im" => Some(6i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SwimStroke::Freestyle => 0,
@@ -12372,6 +17634,20 @@ impl SwimStroke {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SwimStroke::Freestyle => 0,
+            SwimStroke::Backstroke => 1,
+            SwimStroke::Breaststroke => 2,
+            SwimStroke::Butterfly => 3,
+            SwimStroke::Drill => 4,
+            SwimStroke::Mixed => 5,
+            SwimStroke::Im => 6,
+            SwimStroke::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SwimStroke {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12414,6 +17690,15 @@ impl Serialize for SwimStroke {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for SwimStroke {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SwimStroke, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ActivityType {
     Generic,
@@ -12444,6 +17729,20 @@ impl ActivityType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "generic" => Some(0i64),
+            "running" => Some(1i64),
+            "cycling" => Some(2i64),
+            "transition" => Some(3i64),
+            "fitness_equipment" => Some(4i64),
+            "swimming" => Some(5i64),
+            "walking" => Some(6i64),
+            "sedentary" => Some(8i64),
+            "all" => Some(254i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             ActivityType::Generic => 0,
@@ -12461,6 +17760,22 @@ impl ActivityType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ActivityType::Generic => 0,
+            ActivityType::Running => 1,
+            ActivityType::Cycling => 2,
+            ActivityType::Transition => 3,
+            ActivityType::FitnessEquipment => 4,
+            ActivityType::Swimming => 5,
+            ActivityType::Walking => 6,
+            ActivityType::Sedentary => 8,
+            ActivityType::All => 254,
+            ActivityType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ActivityType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12507,6 +17822,15 @@ impl Serialize for ActivityType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for ActivityType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::ActivityType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ActivitySubtype {
     Generic,
@@ -12575,7 +17899,62 @@ impl ActivitySubtype {
             _ => false,
         }
     }
-    pub fn as_u8(self) -> u8 {
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "generic" => Some(0i64),
+            "treadmill" => Some(1i64),
+            "street" => Some(2i64),
+            "trail" => Some(3i64),
+            "track" => Some(4i64),
+            "spin" => Some(5i64),
+            "indoor_cycling" => Some(6i64),
+            "road" => Some(7i64),
+            "mountain" => Some(8i64),
+            "downhill" => Some(9i64),
+            "recumbent" => Some(10i64),
+            "cyclocross" => Some(11i64),
+            "hand_cycling" => Some(12i64),
+            "track_cycling" => Some(13i64),
+            "indoor_rowing" => Some(14i64),
+            "elliptical" => Some(15i64),
+            "stair_climbing" => Some(16i64),
+            "lap_swimming" => Some(17i64),
+            "open_water" => Some(18i64),
+            "all" => Some(254i64),
+            _ => None,
+        }
+    }
+    pub fn as_u8(self) -> u8 {
+        match self {
+            ActivitySubtype::Generic => 0,
+            ActivitySubtype::Treadmill => 1,
+            ActivitySubtype::Street => 2,
+            ActivitySubtype::Trail => 3,
+            ActivitySubtype::Track => 4,
+            ActivitySubtype::Spin => 5,
+            ActivitySubtype::IndoorCycling => 6,
+            ActivitySubtype::Road => 7,
+            ActivitySubtype::Mountain => 8,
+            ActivitySubtype::Downhill => 9,
+            ActivitySubtype::Recumbent => 10,
+            ActivitySubtype::Cyclocross => 11,
+            ActivitySubtype::HandCycling => 12,
+            ActivitySubtype::TrackCycling => 13,
+            ActivitySubtype::IndoorRowing => 14,
+            ActivitySubtype::Elliptical => 15,
+            ActivitySubtype::StairClimbing => 16,
+            ActivitySubtype::LapSwimming => 17,
+            ActivitySubtype::OpenWater => 18,
+            ActivitySubtype::All => 254,
+            ActivitySubtype::UnknownVariant(value) => value,
+        }
+    }
+    pub fn as_i64(self) -> i64 {
+        self.as_u8() as i64
+    }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
         match self {
             ActivitySubtype::Generic => 0,
             ActivitySubtype::Treadmill => 1,
@@ -12597,12 +17976,9 @@ impl ActivitySubtype {
             ActivitySubtype::LapSwimming => 17,
             ActivitySubtype::OpenWater => 18,
             ActivitySubtype::All => 254,
-            ActivitySubtype::UnknownVariant(value) => value,
+            ActivitySubtype::UnknownVariant(value) => value as i64,
         }
     }
-    pub fn as_i64(self) -> i64 {
-        self.as_u8() as i64
-    }
 }
 impl fmt::Display for ActivitySubtype {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12671,6 +18047,15 @@ impl Serialize for ActivitySubtype {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for ActivitySubtype {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::ActivitySubtype, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ActivityLevel {
     Low,
@@ -12687,6 +18072,14 @@ impl ActivityLevel {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "low" => Some(0i64),
+            "medium" => Some(1i64),
+            "high" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             ActivityLevel::Low => 0,
@@ -12698,6 +18091,16 @@ impl ActivityLevel {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ActivityLevel::Low => 0,
+            ActivityLevel::Medium => 1,
+            ActivityLevel::High => 2,
+            ActivityLevel::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ActivityLevel {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12732,6 +18135,15 @@ impl Serialize for ActivityLevel {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for ActivityLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::ActivityLevel, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Side {
     Right,
@@ -12746,6 +18158,13 @@ impl Side {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "right" => Some(0i64),
+            "left" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Side::Right => 0,
@@ -12756,6 +18175,15 @@ impl Side {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Side::Right => 0,
+            Side::Left => 1,
+            Side::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Side {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12788,6 +18216,15 @@ impl Serialize for Side {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for Side {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Side, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LeftRightBalance {
     #[doc = "% contribution"]
@@ -12804,6 +18241,13 @@ impl LeftRightBalance {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "mask" => Some(127i64),
+            "right" => Some(128i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             LeftRightBalance::Mask => 127,
@@ -12814,6 +18258,15 @@ impl LeftRightBalance {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LeftRightBalance::Mask => 127,
+            LeftRightBalance::Right => 128,
+            LeftRightBalance::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LeftRightBalance {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12849,6 +18302,15 @@ impl Serialize for LeftRightBalance {
         }
     }
 }
+impl<'de> Deserialize<'de> for LeftRightBalance {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::LeftRightBalance, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LeftRightBalance100 {
     #[doc = "% contribution scaled by 100"]
@@ -12865,6 +18327,13 @@ impl LeftRightBalance100 {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "mask" => Some(16383i64),
+            "right" => Some(32768i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             LeftRightBalance100::Mask => 16383,
@@ -12875,6 +18344,15 @@ impl LeftRightBalance100 {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LeftRightBalance100::Mask => 16383,
+            LeftRightBalance100::Right => 32768,
+            LeftRightBalance100::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LeftRightBalance100 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12910,6 +18388,16 @@ impl Serialize for LeftRightBalance100 {
         }
     }
 }
+impl<'de> Deserialize<'de> for LeftRightBalance100 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::LeftRightBalance100, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LengthType {
     #[doc = "Rest period. Length with no strokes"]
@@ -12926,6 +18414,13 @@ impl LengthType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "idle" => Some(0i64),
+            "active" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             LengthType::Idle => 0,
@@ -12936,6 +18431,15 @@ impl LengthType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LengthType::Idle => 0,
+            LengthType::Active => 1,
+            LengthType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LengthType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -12968,6 +18472,15 @@ impl Serialize for LengthType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for LengthType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::LengthType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DayOfWeek {
     Sunday,
@@ -12992,6 +18505,18 @@ impl DayOfWeek {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "sunday" => Some(0i64),
+            "monday" => Some(1i64),
+            "tuesday" => Some(2i64),
+            "wednesday" => Some(3i64),
+            "thursday" => Some(4i64),
+            "friday" => Some(5i64),
+            "saturday" => Some(6i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             DayOfWeek::Sunday => 0,
@@ -13007,6 +18532,20 @@ impl DayOfWeek {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DayOfWeek::Sunday => 0,
+            DayOfWeek::Monday => 1,
+            DayOfWeek::Tuesday => 2,
+            DayOfWeek::Wednesday => 3,
+            DayOfWeek::Thursday => 4,
+            DayOfWeek::Friday => 5,
+            DayOfWeek::Saturday => 6,
+            DayOfWeek::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DayOfWeek {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -13049,6 +18588,15 @@ impl Serialize for DayOfWeek {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for DayOfWeek {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DayOfWeek, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ConnectivityCapabilities {
     Bluetooth,
@@ -13132,6 +18680,43 @@ impl ConnectivityCapabilities {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "bluetooth" => Some(1i64),
+            "bluetooth_le" => Some(2i64),
+            "ant" => Some(4i64),
+            "activity_upload" => Some(8i64),
+            "course_download" => Some(16i64),
+            "workout_download" => Some(32i64),
+            "live_track" => Some(64i64),
+            "weather_conditions" => Some(128i64),
+            "weather_alerts" => Some(256i64),
+            "gps_ephemeris_download" => Some(512i64),
+            "explicit_archive" => Some(1024i64),
+            "setup_incomplete" => Some(2048i64),
+            "continue_sync_after_software_update" => Some(4096i64),
+            "connect_iq_app_download" => Some(8192i64),
+            "golf_course_download" => Some(16384i64),
+            "device_initiates_sync" => Some(32768i64),
+            "connect_iq_watch_app_download" => Some(65536i64),
+            "connect_iq_widget_download" => Some(131072i64),
+            "connect_iq_watch_face_download" => Some(262144i64),
+            "connect_iq_data_field_download" => Some(524288i64),
+            "connect_iq_app_managment" => Some(1048576i64),
+            "swing_sensor" => Some(2097152i64),
+            "swing_sensor_remote" => Some(4194304i64),
+            "incident_detection" => Some(8388608i64),
+            "audio_prompts" => Some(16777216i64),
+            "wifi_verification" => Some(33554432i64),
+            "true_up" => Some(67108864i64),
+            "find_my_watch" => Some(134217728i64),
+            "remote_manual_sync" => Some(268435456i64),
+            "live_track_auto_start" => Some(536870912i64),
+            "live_track_messaging" => Some(1073741824i64),
+            "instant_input" => Some(2147483648i64),
+            _ => None,
+        }
+    }
     pub fn as_u32(self) -> u32 {
         match self {
             ConnectivityCapabilities::Bluetooth => 1,
@@ -13172,6 +18757,45 @@ impl ConnectivityCapabilities {
     pub fn as_i64(self) -> i64 {
         self.as_u32() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ConnectivityCapabilities::Bluetooth => 1,
+            ConnectivityCapabilities::BluetoothLe => 2,
+            ConnectivityCapabilities::Ant => 4,
+            ConnectivityCapabilities::ActivityUpload => 8,
+            ConnectivityCapabilities::CourseDownload => 16,
+            ConnectivityCapabilities::WorkoutDownload => 32,
+            ConnectivityCapabilities::LiveTrack => 64,
+            ConnectivityCapabilities::WeatherConditions => 128,
+            ConnectivityCapabilities::WeatherAlerts => 256,
+            ConnectivityCapabilities::GpsEphemerisDownload => 512,
+            ConnectivityCapabilities::ExplicitArchive => 1024,
+            ConnectivityCapabilities::SetupIncomplete => 2048,
+            ConnectivityCapabilities::ContinueSyncAfterSoftwareUpdate => 4096,
+            ConnectivityCapabilities::ConnectIqAppDownload => 8192,
+            ConnectivityCapabilities::GolfCourseDownload => 16384,
+            ConnectivityCapabilities::DeviceInitiatesSync => 32768,
+            ConnectivityCapabilities::ConnectIqWatchAppDownload => 65536,
+            ConnectivityCapabilities::ConnectIqWidgetDownload => 131072,
+            ConnectivityCapabilities::ConnectIqWatchFaceDownload => 262144,
+            ConnectivityCapabilities::ConnectIqDataFieldDownload => 524288,
+            ConnectivityCapabilities::ConnectIqAppManagment => 1048576,
+            ConnectivityCapabilities::SwingSensor => 2097152,
+            ConnectivityCapabilities::SwingSensorRemote => 4194304,
+            ConnectivityCapabilities::IncidentDetection => 8388608,
+            ConnectivityCapabilities::AudioPrompts => 16777216,
+            ConnectivityCapabilities::WifiVerification => 33554432,
+            ConnectivityCapabilities::TrueUp => 67108864,
+            ConnectivityCapabilities::FindMyWatch => 134217728,
+            ConnectivityCapabilities::RemoteManualSync => 268435456,
+            ConnectivityCapabilities::LiveTrackAutoStart => 536870912,
+            ConnectivityCapabilities::LiveTrackMessaging => 1073741824,
+            ConnectivityCapabilities::InstantInput => 2147483648,
+            ConnectivityCapabilities::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ConnectivityCapabilities {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -13279,6 +18903,20 @@ impl Serialize for ConnectivityCapabilities {
         }
     }
 }
+impl<'de> Deserialize<'de> for ConnectivityCapabilities {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u32::MAX);
+        deserialize_variant(
+            deserializer,
+            FieldDataType::ConnectivityCapabilities,
+            invalid,
+        )
+        .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WeatherReport {
     Current,
@@ -13295,6 +18933,14 @@ impl WeatherReport {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "current" => Some(0i64),
+            "hourly_forecast" => Some(1i64),
+            "daily_forecast" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             WeatherReport::Current => 0,
@@ -13306,6 +18952,16 @@ impl WeatherReport {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            WeatherReport::Current => 0,
+            WeatherReport::HourlyForecast => 1,
+            WeatherReport::DailyForecast => 2,
+            WeatherReport::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for WeatherReport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -13340,6 +18996,15 @@ impl Serialize for WeatherReport {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for WeatherReport {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::WeatherReport, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WeatherStatus {
     Clear,
@@ -13392,6 +19057,32 @@ impl WeatherStatus {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "clear" => Some(0i64),
+            "partly_cloudy" => Some(1i64),
+            "mostly_cloudy" => Some(2i64),
+            "rain" => Some(3i64),
+            "snow" => Some(4i64),
+            "windy" => Some(5i64),
+            "thunderstorms" => Some(6i64),
+            "wintry_mix" => Some(7i64),
+            "fog" => Some(8i64),
+            "hazy" => Some(11i64),
+            "hail" => Some(12i64),
+            "scattered_showers" => Some(13i64),
+            "scattered_thunderstorms" => Some(14i64),
+            "unknown_precipitation" => Some(15i64),
+            "light_rain" => Some(16i64),
+            "heavy_rain" => Some(17i64),
+            "light_snow" => Some(18i64),
+            "heavy_snow" => Some(19i64),
+            "light_rain_snow" => Some(20i64),
+            "heavy_rain_snow" => Some(21i64),
+            "cloudy" => Some(22i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             WeatherStatus::Clear => 0,
@@ -13421,6 +19112,34 @@ impl WeatherStatus {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            WeatherStatus::Clear => 0,
+            WeatherStatus::PartlyCloudy => 1,
+            WeatherStatus::MostlyCloudy => 2,
+            WeatherStatus::Rain => 3,
+            WeatherStatus::Snow => 4,
+            WeatherStatus::Windy => 5,
+            WeatherStatus::Thunderstorms => 6,
+            WeatherStatus::WintryMix => 7,
+            WeatherStatus::Fog => 8,
+            WeatherStatus::Hazy => 11,
+            WeatherStatus::Hail => 12,
+            WeatherStatus::ScatteredShowers => 13,
+            WeatherStatus::ScatteredThunderstorms => 14,
+            WeatherStatus::UnknownPrecipitation => 15,
+            WeatherStatus::LightRain => 16,
+            WeatherStatus::HeavyRain => 17,
+            WeatherStatus::LightSnow => 18,
+            WeatherStatus::HeavySnow => 19,
+            WeatherStatus::LightRainSnow => 20,
+            WeatherStatus::HeavyRainSnow => 21,
+            WeatherStatus::Cloudy => 22,
+            WeatherStatus::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for WeatherStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -13491,6 +19210,15 @@ impl Serialize for WeatherStatus {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for WeatherStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::WeatherStatus, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WeatherSeverity {
     Unknown,
@@ -13511,6 +19239,16 @@ impl WeatherSeverity {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "unknown" => Some(0i64),
+            "warning" => Some(1i64),
+            "watch" => Some(2i64),
+            "advisory" => Some(3i64),
+            "statement" => Some(4i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             WeatherSeverity::Unknown => 0,
@@ -13524,6 +19262,18 @@ impl WeatherSeverity {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            WeatherSeverity::Unknown => 0,
+            WeatherSeverity::Warning => 1,
+            WeatherSeverity::Watch => 2,
+            WeatherSeverity::Advisory => 3,
+            WeatherSeverity::Statement => 4,
+            WeatherSeverity::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for WeatherSeverity {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -13562,6 +19312,15 @@ impl Serialize for WeatherSeverity {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for WeatherSeverity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::WeatherSeverity, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WeatherSevereType {
     Unspecified,
@@ -13742,7 +19501,192 @@ impl WeatherSevereType {
             _ => false,
         }
     }
-    pub fn as_u8(self) -> u8 {
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "unspecified" => Some(0i64),
+            "tornado" => Some(1i64),
+            "tsunami" => Some(2i64),
+            "hurricane" => Some(3i64),
+            "extreme_wind" => Some(4i64),
+            "typhoon" => Some(5i64),
+            "inland_hurricane" => Some(6i64),
+            "hurricane_force_wind" => Some(7i64),
+            "waterspout" => Some(8i64),
+            "severe_thunderstorm" => Some(9i64),
+            "wreckhouse_winds" => Some(10i64),
+            "les_suetes_wind" => Some(11i64),
+            "avalanche" => Some(12i64),
+            "flash_flood" => Some(13i64),
+            "tropical_storm" => Some(14i64),
+            "inland_tropical_storm" => Some(15i64),
+            "blizzard" => Some(16i64),
+            "ice_storm" => Some(17i64),
+            "freezing_rain" => Some(18i64),
+            "debris_flow" => Some(19i64),
+            "flash_freeze" => Some(20i64),
+            "dust_storm" => Some(21i64),
+            "high_wind" => Some(22i64),
+            "winter_storm" => Some(23i64),
+            "heavy_freezing_spray" => Some(24i64),
+            "extreme_cold" => Some(25i64),
+            "wind_chill" => Some(26i64),
+            "cold_wave" => Some(27i64),
+            "heavy_snow_alert" => Some(28i64),
+            "lake_effect_blowing_snow" => Some(29i64),
+            "snow_squall" => Some(30i64),
+            "lake_effect_snow" => Some(31i64),
+            "winter_weather" => Some(32i64),
+            "sleet" => Some(33i64),
+            "snowfall" => Some(34i64),
+            "snow_and_blowing_snow" => Some(35i64),
+            "blowing_snow" => Some(36i64),
+            "snow_alert" => Some(37i64),
+            "arctic_outflow" => Some(38i64),
+            "freezing_drizzle" => Some(39i64),
+            "storm" => Some(40i64),
+            "storm_surge" => Some(41i64),
+            "rainfall" => Some(42i64),
+            "areal_flood" => Some(43i64),
+            "coastal_flood" => Some(44i64),
+            "lakeshore_flood" => Some(45i64),
+            "excessive_heat" => Some(46i64),
+            "heat" => Some(47i64),
+            "weather" => Some(48i64),
+            "high_heat_and_humidity" => Some(49i64),
+            "humidex_and_health" => Some(50i64),
+            "humidex" => Some(51i64),
+            "gale" => Some(52i64),
+            "freezing_spray" => Some(53i64),
+            "special_marine" => Some(54i64),
+            "squall" => Some(55i64),
+            "strong_wind" => Some(56i64),
+            "lake_wind" => Some(57i64),
+            "marine_weather" => Some(58i64),
+            "wind" => Some(59i64),
+            "small_craft_hazardous_seas" => Some(60i64),
+            "hazardous_seas" => Some(61i64),
+            "small_craft" => Some(62i64),
+            "small_craft_winds" => Some(63i64),
+            "small_craft_rough_bar" => Some(64i64),
+            "high_water_level" => Some(65i64),
+            "ashfall" => Some(66i64),
+            "freezing_fog" => Some(67i64),
+            "dense_fog" => Some(68i64),
+            "dense_smoke" => Some(69i64),
+            "blowing_dust" => Some(70i64),
+            "hard_freeze" => Some(71i64),
+            "freeze" => Some(72i64),
+            "frost" => Some(73i64),
+            "fire_weather" => Some(74i64),
+            "flood" => Some(75i64),
+            "rip_tide" => Some(76i64),
+            "high_surf" => Some(77i64),
+            "smog" => Some(78i64),
+            "air_quality" => Some(79i64),
+            "brisk_wind" => Some(80i64),
+            "air_stagnation" => Some(81i64),
+            "low_water" => Some(82i64),
+            "hydrological" => Some(83i64),
+            "special_weather" => Some(84i64),
+            _ => None,
+        }
+    }
+    pub fn as_u8(self) -> u8 {
+        match self {
+            WeatherSevereType::Unspecified => 0,
+            WeatherSevereType::Tornado => 1,
+            WeatherSevereType::Tsunami => 2,
+            WeatherSevereType::Hurricane => 3,
+            WeatherSevereType::ExtremeWind => 4,
+            WeatherSevereType::Typhoon => 5,
+            WeatherSevereType::InlandHurricane => 6,
+            WeatherSevereType::HurricaneForceWind => 7,
+            WeatherSevereType::Waterspout => 8,
+            WeatherSevereType::SevereThunderstorm => 9,
+            WeatherSevereType::WreckhouseWinds => 10,
+            WeatherSevereType::LesSuetesWind => 11,
+            WeatherSevereType::Avalanche => 12,
+            WeatherSevereType::FlashFlood => 13,
+            WeatherSevereType::TropicalStorm => 14,
+            WeatherSevereType::InlandTropicalStorm => 15,
+            WeatherSevereType::Blizzard => 16,
+            WeatherSevereType::IceStorm => 17,
+            WeatherSevereType::FreezingRain => 18,
+            WeatherSevereType::DebrisFlow => 19,
+            WeatherSevereType::FlashFreeze => 20,
+            WeatherSevereType::DustStorm => 21,
+            WeatherSevereType::HighWind => 22,
+            WeatherSevereType::WinterStorm => 23,
+            WeatherSevereType::HeavyFreezingSpray => 24,
+            WeatherSevereType::ExtremeCold => 25,
+            WeatherSevereType::WindChill => 26,
+            WeatherSevereType::ColdWave => 27,
+            WeatherSevereType::HeavySnowAlert => 28,
+            WeatherSevereType::LakeEffectBlowingSnow => 29,
+            WeatherSevereType::SnowSquall => 30,
+            WeatherSevereType::LakeEffectSnow => 31,
+            WeatherSevereType::WinterWeather => 32,
+            WeatherSevereType::Sleet => 33,
+            WeatherSevereType::Snowfall => 34,
+            WeatherSevereType::SnowAndBlowingSnow => 35,
+            WeatherSevereType::BlowingSnow => 36,
+            WeatherSevereType::SnowAlert => 37,
+            WeatherSevereType::ArcticOutflow => 38,
+            WeatherSevereType::FreezingDrizzle => 39,
+            WeatherSevereType::Storm => 40,
+            WeatherSevereType::StormSurge => 41,
+            WeatherSevereType::Rainfall => 42,
+            WeatherSevereType::ArealFlood => 43,
+            WeatherSevereType::CoastalFlood => 44,
+            WeatherSevereType::LakeshoreFlood => 45,
+            WeatherSevereType::ExcessiveHeat => 46,
+            WeatherSevereType::Heat => 47,
+            WeatherSevereType::Weather => 48,
+            WeatherSevereType::HighHeatAndHumidity => 49,
+            WeatherSevereType::HumidexAndHealth => 50,
+            WeatherSevereType::Humidex => 51,
+            WeatherSevereType::Gale => 52,
+            WeatherSevereType::FreezingSpray => 53,
+            WeatherSevereType::SpecialMarine => 54,
+            WeatherSevereType::Squall => 55,
+            WeatherSevereType::StrongWind => 56,
+            WeatherSevereType::LakeWind => 57,
+            WeatherSevereType::MarineWeather => 58,
+            WeatherSevereType::Wind => 59,
+            WeatherSevereType::SmallCraftHazardousSeas => 60,
+            WeatherSevereType::HazardousSeas => 61,
+            WeatherSevereType::SmallCraft => 62,
+            WeatherSevereType::SmallCraftWinds => 63,
+            WeatherSevereType::SmallCraftRoughBar => 64,
+            WeatherSevereType::HighWaterLevel => 65,
+            WeatherSevereType::Ashfall => 66,
+            WeatherSevereType::FreezingFog => 67,
+            WeatherSevereType::DenseFog => 68,
+            WeatherSevereType::DenseSmoke => 69,
+            WeatherSevereType::BlowingDust => 70,
+            WeatherSevereType::HardFreeze => 71,
+            WeatherSevereType::Freeze => 72,
+            WeatherSevereType::Frost => 73,
+            WeatherSevereType::FireWeather => 74,
+            WeatherSevereType::Flood => 75,
+            WeatherSevereType::RipTide => 76,
+            WeatherSevereType::HighSurf => 77,
+            WeatherSevereType::Smog => 78,
+            WeatherSevereType::AirQuality => 79,
+            WeatherSevereType::BriskWind => 80,
+            WeatherSevereType::AirStagnation => 81,
+            WeatherSevereType::LowWater => 82,
+            WeatherSevereType::Hydrological => 83,
+            WeatherSevereType::SpecialWeather => 84,
+            WeatherSevereType::UnknownVariant(value) => value,
+        }
+    }
+    pub fn as_i64(self) -> i64 {
+        self.as_u8() as i64
+    }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
         match self {
             WeatherSevereType::Unspecified => 0,
             WeatherSevereType::Tornado => 1,
@@ -13829,12 +19773,9 @@ impl WeatherSevereType {
             WeatherSevereType::LowWater => 82,
             WeatherSevereType::Hydrological => 83,
             WeatherSevereType::SpecialWeather => 84,
-            WeatherSevereType::UnknownVariant(value) => value,
+            WeatherSevereType::UnknownVariant(value) => value as i64,
         }
     }
-    pub fn as_i64(self) -> i64 {
-        self.as_u8() as i64
-    }
 }
 impl fmt::Display for WeatherSevereType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -14033,6 +19974,15 @@ impl Serialize for WeatherSevereType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for WeatherSevereType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::WeatherSevereType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum StrokeType {
     NoEvent,
@@ -14056,6 +20006,17 @@ impl StrokeType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "no_event" => Some(0i64),
+            "other" => Some(1i64),
+            "serve" => Some(2i64),
+            "forehand" => Some(3i64),
+            "backhand" => Some(4i64),
+            "smash" => Some(5i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             StrokeType::NoEvent => 0,
@@ -14070,6 +20031,19 @@ impl StrokeType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            StrokeType::NoEvent => 0,
+            StrokeType::Other => 1,
+            StrokeType::Serve => 2,
+            StrokeType::Forehand => 3,
+            StrokeType::Backhand => 4,
+            StrokeType::Smash => 5,
+            StrokeType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for StrokeType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -14110,6 +20084,15 @@ impl Serialize for StrokeType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for StrokeType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::StrokeType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum BodyLocation {
     LeftLeg,
@@ -14204,6 +20187,51 @@ impl BodyLocation {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "left_leg" => Some(0i64),
+            "left_calf" => Some(1i64),
+            "left_shin" => Some(2i64),
+            "left_hamstring" => Some(3i64),
+            "left_quad" => Some(4i64),
+            "left_glute" => Some(5i64),
+            "right_leg" => Some(6i64),
+            "right_calf" => Some(7i64),
+            "right_shin" => Some(8i64),
+            "right_hamstring" => Some(9i64),
+            "right_quad" => Some(10i64),
+            "right_glute" => Some(11i64),
+            "torso_back" => Some(12i64),
+            "left_lower_back" => Some(13i64),
+            "left_upper_back" => Some(14i64),
+            "right_lower_back" => Some(15i64),
+            "right_upper_back" => Some(16i64),
+            "torso_front" => Some(17i64),
+            "left_abdomen" => Some(18i64),
+            "left_chest" => Some(19i64),
+            "right_abdomen" => Some(20i64),
+            "right_chest" => Some(21i64),
+            "left_arm" => Some(22i64),
+            "left_shoulder" => Some(23i64),
+            "left_bicep" => Some(24i64),
+            "left_tricep" => Some(25i64),
+            "left_brachioradialis" => Some(26i64),
+            "left_forearm_extensors" => Some(27i64),
+            "right_arm" => Some(28i64),
+            "right_shoulder" => Some(29i64),
+            "right_bicep" => Some(30i64),
+            "right_tricep" => Some(31i64),
+            "right_brachioradialis" => Some(32i64),
+            "right_forearm_extensors" => Some(33i64),
+            "neck" => Some(34i64),
+            "throat" => Some(35i64),
+            "waist_mid_back" => Some(36i64),
+            "waist_front" => Some(37i64),
+            "waist_left" => Some(38i64),
+            "waist_right" => Some(39i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             BodyLocation::LeftLeg => 0,
@@ -14252,6 +20280,53 @@ impl BodyLocation {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            BodyLocation::LeftLeg => 0,
+            BodyLocation::LeftCalf => 1,
+            BodyLocation::LeftShin => 2,
+            BodyLocation::LeftHamstring => 3,
+            BodyLocation::LeftQuad => 4,
+            BodyLocation::LeftGlute => 5,
+            BodyLocation::RightLeg => 6,
+            BodyLocation::RightCalf => 7,
+            BodyLocation::RightShin => 8,
+            BodyLocation::RightHamstring => 9,
+            BodyLocation::RightQuad => 10,
+            BodyLocation::RightGlute => 11,
+            BodyLocation::TorsoBack => 12,
+            BodyLocation::LeftLowerBack => 13,
+            BodyLocation::LeftUpperBack => 14,
+            BodyLocation::RightLowerBack => 15,
+            BodyLocation::RightUpperBack => 16,
+            BodyLocation::TorsoFront => 17,
+            BodyLocation::LeftAbdomen => 18,
+            BodyLocation::LeftChest => 19,
+            BodyLocation::RightAbdomen => 20,
+            BodyLocation::RightChest => 21,
+            BodyLocation::LeftArm => 22,
+            BodyLocation::LeftShoulder => 23,
+            BodyLocation::LeftBicep => 24,
+            BodyLocation::LeftTricep => 25,
+            BodyLocation::LeftBrachioradialis => 26,
+            BodyLocation::LeftForearmExtensors => 27,
+            BodyLocation::RightArm => 28,
+            BodyLocation::RightShoulder => 29,
+            BodyLocation::RightBicep => 30,
+            BodyLocation::RightTricep => 31,
+            BodyLocation::RightBrachioradialis => 32,
+            BodyLocation::RightForearmExtensors => 33,
+            BodyLocation::Neck => 34,
+            BodyLocation::Throat => 35,
+            BodyLocation::WaistMidBack => 36,
+            BodyLocation::WaistFront => 37,
+            BodyLocation::WaistLeft => 38,
+            BodyLocation::WaistRight => 39,
+            BodyLocation::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for BodyLocation {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -14360,6 +20435,15 @@ impl Serialize for BodyLocation {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for BodyLocation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::BodyLocation, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SegmentLapStatus {
     End,
@@ -14374,6 +20458,13 @@ impl SegmentLapStatus {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "end" => Some(0i64),
+            "fail" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SegmentLapStatus::End => 0,
@@ -14384,6 +20475,15 @@ impl SegmentLapStatus {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SegmentLapStatus::End => 0,
+            SegmentLapStatus::Fail => 1,
+            SegmentLapStatus::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SegmentLapStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -14416,6 +20516,15 @@ impl Serialize for SegmentLapStatus {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for SegmentLapStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SegmentLapStatus, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SegmentLeaderboardType {
     Overall,
@@ -14456,6 +20565,26 @@ impl SegmentLeaderboardType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "overall" => Some(0i64),
+            "personal_best" => Some(1i64),
+            "connections" => Some(2i64),
+            "group" => Some(3i64),
+            "challenger" => Some(4i64),
+            "kom" => Some(5i64),
+            "qom" => Some(6i64),
+            "pr" => Some(7i64),
+            "goal" => Some(8i64),
+            "carrot" => Some(9i64),
+            "club_leader" => Some(10i64),
+            "rival" => Some(11i64),
+            "last" => Some(12i64),
+            "recent_best" => Some(13i64),
+            "course_record" => Some(14i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SegmentLeaderboardType::Overall => 0,
@@ -14479,6 +20608,28 @@ impl SegmentLeaderboardType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SegmentLeaderboardType::Overall => 0,
+            SegmentLeaderboardType::PersonalBest => 1,
+            SegmentLeaderboardType::Connections => 2,
+            SegmentLeaderboardType::Group => 3,
+            SegmentLeaderboardType::Challenger => 4,
+            SegmentLeaderboardType::Kom => 5,
+            SegmentLeaderboardType::Qom => 6,
+            SegmentLeaderboardType::Pr => 7,
+            SegmentLeaderboardType::Goal => 8,
+            SegmentLeaderboardType::Carrot => 9,
+            SegmentLeaderboardType::ClubLeader => 10,
+            SegmentLeaderboardType::Rival => 11,
+            SegmentLeaderboardType::Last => 12,
+            SegmentLeaderboardType::RecentBest => 13,
+            SegmentLeaderboardType::CourseRecord => 14,
+            SegmentLeaderboardType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SegmentLeaderboardType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -14537,6 +20688,16 @@ impl Serialize for SegmentLeaderboardType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for SegmentLeaderboardType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SegmentLeaderboardType, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SegmentDeleteStatus {
     DoNotDelete,
@@ -14553,6 +20714,14 @@ impl SegmentDeleteStatus {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "do_not_delete" => Some(0i64),
+            "delete_one" => Some(1i64),
+            "delete_all" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SegmentDeleteStatus::DoNotDelete => 0,
@@ -14564,6 +20733,16 @@ impl SegmentDeleteStatus {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SegmentDeleteStatus::DoNotDelete => 0,
+            SegmentDeleteStatus::DeleteOne => 1,
+            SegmentDeleteStatus::DeleteAll => 2,
+            SegmentDeleteStatus::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SegmentDeleteStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -14598,6 +20777,16 @@ impl Serialize for SegmentDeleteStatus {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for SegmentDeleteStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SegmentDeleteStatus, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SegmentSelectionType {
     Starred,
@@ -14612,6 +20801,13 @@ impl SegmentSelectionType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "starred" => Some(0i64),
+            "suggested" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SegmentSelectionType::Starred => 0,
@@ -14622,6 +20818,15 @@ impl SegmentSelectionType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SegmentSelectionType::Starred => 0,
+            SegmentSelectionType::Suggested => 1,
+            SegmentSelectionType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SegmentSelectionType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -14654,6 +20859,16 @@ impl Serialize for SegmentSelectionType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for SegmentSelectionType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SegmentSelectionType, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SourceType {
     #[doc = "External device connected with ANT"]
@@ -14682,6 +20897,17 @@ impl SourceType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "ant" => Some(0i64),
+            "antplus" => Some(1i64),
+            "bluetooth" => Some(2i64),
+            "bluetooth_low_energy" => Some(3i64),
+            "wifi" => Some(4i64),
+            "local" => Some(5i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SourceType::Ant => 0,
@@ -14696,6 +20922,19 @@ impl SourceType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SourceType::Ant => 0,
+            SourceType::Antplus => 1,
+            SourceType::Bluetooth => 2,
+            SourceType::BluetoothLowEnergy => 3,
+            SourceType::Wifi => 4,
+            SourceType::Local => 5,
+            SourceType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SourceType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -14736,6 +20975,15 @@ impl Serialize for SourceType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SourceType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LocalDeviceType {
     #[doc = "Onboard gps receiver"]
@@ -14770,6 +21018,19 @@ impl LocalDeviceType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "gps" => Some(0i64),
+            "glonass" => Some(1i64),
+            "gps_glonass" => Some(2i64),
+            "accelerometer" => Some(3i64),
+            "barometer" => Some(4i64),
+            "temperature" => Some(5i64),
+            "whr" => Some(10i64),
+            "sensor_hub" => Some(12i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             LocalDeviceType::Gps => 0,
@@ -14786,6 +21047,21 @@ impl LocalDeviceType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LocalDeviceType::Gps => 0,
+            LocalDeviceType::Glonass => 1,
+            LocalDeviceType::GpsGlonass => 2,
+            LocalDeviceType::Accelerometer => 3,
+            LocalDeviceType::Barometer => 4,
+            LocalDeviceType::Temperature => 5,
+            LocalDeviceType::Whr => 10,
+            LocalDeviceType::SensorHub => 12,
+            LocalDeviceType::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LocalDeviceType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -14833,6 +21109,15 @@ impl Serialize for LocalDeviceType {
         }
     }
 }
+impl<'de> Deserialize<'de> for LocalDeviceType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::LocalDeviceType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum BleDeviceType {
     #[doc = "GPS that is provided over a proprietary bluetooth service"]
@@ -14861,6 +21146,19 @@ impl BleDeviceType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "connected_gps" => Some(0i64),
+            "heart_rate" => Some(1i64),
+            "bike_power" => Some(2i64),
+            "bike_speed_cadence" => Some(3i64),
+            "bike_speed" => Some(4i64),
+            "bike_cadence" => Some(5i64),
+            "footpod" => Some(6i64),
+            "bike_trainer" => Some(7i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             BleDeviceType::ConnectedGps => 0,
@@ -14877,6 +21175,21 @@ impl BleDeviceType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            BleDeviceType::ConnectedGps => 0,
+            BleDeviceType::HeartRate => 1,
+            BleDeviceType::BikePower => 2,
+            BleDeviceType::BikeSpeedCadence => 3,
+            BleDeviceType::BikeSpeed => 4,
+            BleDeviceType::BikeCadence => 5,
+            BleDeviceType::Footpod => 6,
+            BleDeviceType::BikeTrainer => 7,
+            BleDeviceType::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for BleDeviceType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -14924,6 +21237,15 @@ impl Serialize for BleDeviceType {
         }
     }
 }
+impl<'de> Deserialize<'de> for BleDeviceType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::BleDeviceType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum AntChannelId {
     AntDeviceNumber,
@@ -14942,6 +21264,15 @@ impl AntChannelId {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "ant_device_number" => Some(65535i64),
+            "ant_device_type" => Some(16711680i64),
+            "ant_transmission_type_lower_nibble" => Some(251658240i64),
+            "ant_extended_device_number_upper_nibble" => Some(4026531840i64),
+            _ => None,
+        }
+    }
     pub fn as_u32(self) -> u32 {
         match self {
             AntChannelId::AntDeviceNumber => 65535,
@@ -14954,6 +21285,17 @@ impl AntChannelId {
     pub fn as_i64(self) -> i64 {
         self.as_u32() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            AntChannelId::AntDeviceNumber => 65535,
+            AntChannelId::AntDeviceType => 16711680,
+            AntChannelId::AntTransmissionTypeLowerNibble => 251658240,
+            AntChannelId::AntExtendedDeviceNumberUpperNibble => 4026531840,
+            AntChannelId::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for AntChannelId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -14997,6 +21339,15 @@ impl Serialize for AntChannelId {
         }
     }
 }
+impl<'de> Deserialize<'de> for AntChannelId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u32::MAX);
+        deserialize_variant(deserializer, FieldDataType::AntChannelId, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DisplayOrientation {
     #[doc = "automatic if the device supports it"]
@@ -15020,6 +21371,16 @@ impl DisplayOrientation {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "auto" => Some(0i64),
+            "portrait" => Some(1i64),
+            "landscape" => Some(2i64),
+            "portrait_flipped" => Some(3i64),
+            "landscape_flipped" => Some(4i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             DisplayOrientation::Auto => 0,
@@ -15033,6 +21394,18 @@ impl DisplayOrientation {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DisplayOrientation::Auto => 0,
+            DisplayOrientation::Portrait => 1,
+            DisplayOrientation::Landscape => 2,
+            DisplayOrientation::PortraitFlipped => 3,
+            DisplayOrientation::LandscapeFlipped => 4,
+            DisplayOrientation::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DisplayOrientation {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -15071,6 +21444,16 @@ impl Serialize for DisplayOrientation {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for DisplayOrientation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DisplayOrientation, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WorkoutEquipment {
     None,
@@ -15093,6 +21476,17 @@ impl WorkoutEquipment {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "none" => Some(0i64),
+            "swim_fins" => Some(1i64),
+            "swim_kickboard" => Some(2i64),
+            "swim_paddles" => Some(3i64),
+            "swim_pull_buoy" => Some(4i64),
+            "swim_snorkel" => Some(5i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             WorkoutEquipment::None => 0,
@@ -15107,6 +21501,19 @@ impl WorkoutEquipment {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            WorkoutEquipment::None => 0,
+            WorkoutEquipment::SwimFins => 1,
+            WorkoutEquipment::SwimKickboard => 2,
+            WorkoutEquipment::SwimPaddles => 3,
+            WorkoutEquipment::SwimPullBuoy => 4,
+            WorkoutEquipment::SwimSnorkel => 5,
+            WorkoutEquipment::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for WorkoutEquipment {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -15147,6 +21554,15 @@ impl Serialize for WorkoutEquipment {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for WorkoutEquipment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::WorkoutEquipment, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WatchfaceMode {
     Digital,
@@ -15165,6 +21581,15 @@ impl WatchfaceMode {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "digital" => Some(0i64),
+            "analog" => Some(1i64),
+            "connect_iq" => Some(2i64),
+            "disabled" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             WatchfaceMode::Digital => 0,
@@ -15177,6 +21602,17 @@ impl WatchfaceMode {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            WatchfaceMode::Digital => 0,
+            WatchfaceMode::Analog => 1,
+            WatchfaceMode::ConnectIq => 2,
+            WatchfaceMode::Disabled => 3,
+            WatchfaceMode::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for WatchfaceMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -15213,6 +21649,15 @@ impl Serialize for WatchfaceMode {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for WatchfaceMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::WatchfaceMode, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DigitalWatchfaceLayout {
     Traditional,
@@ -15229,6 +21674,14 @@ impl DigitalWatchfaceLayout {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "traditional" => Some(0i64),
+            "modern" => Some(1i64),
+            "bold" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             DigitalWatchfaceLayout::Traditional => 0,
@@ -15240,6 +21693,16 @@ impl DigitalWatchfaceLayout {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DigitalWatchfaceLayout::Traditional => 0,
+            DigitalWatchfaceLayout::Modern => 1,
+            DigitalWatchfaceLayout::Bold => 2,
+            DigitalWatchfaceLayout::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DigitalWatchfaceLayout {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -15274,6 +21737,16 @@ impl Serialize for DigitalWatchfaceLayout {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for DigitalWatchfaceLayout {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DigitalWatchfaceLayout, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum AnalogWatchfaceLayout {
     Minimal,
@@ -15290,6 +21763,14 @@ impl AnalogWatchfaceLayout {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "minimal" => Some(0i64),
+            "traditional" => Some(1i64),
+            "modern" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             AnalogWatchfaceLayout::Minimal => 0,
@@ -15301,6 +21782,16 @@ impl AnalogWatchfaceLayout {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            AnalogWatchfaceLayout::Minimal => 0,
+            AnalogWatchfaceLayout::Traditional => 1,
+            AnalogWatchfaceLayout::Modern => 2,
+            AnalogWatchfaceLayout::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for AnalogWatchfaceLayout {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -15335,6 +21826,16 @@ impl Serialize for AnalogWatchfaceLayout {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for AnalogWatchfaceLayout {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::AnalogWatchfaceLayout, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum RiderPositionType {
     Seated,
@@ -15353,6 +21854,15 @@ impl RiderPositionType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "seated" => Some(0i64),
+            "standing" => Some(1i64),
+            "transition_to_seated" => Some(2i64),
+            "transition_to_standing" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             RiderPositionType::Seated => 0,
@@ -15365,6 +21875,17 @@ impl RiderPositionType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            RiderPositionType::Seated => 0,
+            RiderPositionType::Standing => 1,
+            RiderPositionType::TransitionToSeated => 2,
+            RiderPositionType::TransitionToStanding => 3,
+            RiderPositionType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for RiderPositionType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -15401,6 +21922,15 @@ impl Serialize for RiderPositionType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for RiderPositionType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::RiderPositionType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum PowerPhaseType {
     PowerPhaseStartAngle,
@@ -15419,6 +21949,15 @@ impl PowerPhaseType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "power_phase_start_angle" => Some(0i64),
+            "power_phase_end_angle" => Some(1i64),
+            "power_phase_arc_length" => Some(2i64),
+            "power_phase_center" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             PowerPhaseType::PowerPhaseStartAngle => 0,
@@ -15431,6 +21970,17 @@ impl PowerPhaseType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            PowerPhaseType::PowerPhaseStartAngle => 0,
+            PowerPhaseType::PowerPhaseEndAngle => 1,
+            PowerPhaseType::PowerPhaseArcLength => 2,
+            PowerPhaseType::PowerPhaseCenter => 3,
+            PowerPhaseType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for PowerPhaseType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -15467,6 +22017,15 @@ impl Serialize for PowerPhaseType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for PowerPhaseType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::PowerPhaseType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum CameraEventType {
     #[doc = "Start of video recording"]
@@ -15510,6 +22069,24 @@ impl CameraEventType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "video_start" => Some(0i64),
+            "video_split" => Some(1i64),
+            "video_end" => Some(2i64),
+            "photo_taken" => Some(3i64),
+            "video_second_stream_start" => Some(4i64),
+            "video_second_stream_split" => Some(5i64),
+            "video_second_stream_end" => Some(6i64),
+            "video_split_start" => Some(7i64),
+            "video_second_stream_split_start" => Some(8i64),
+            "video_pause" => Some(11i64),
+            "video_second_stream_pause" => Some(12i64),
+            "video_resume" => Some(13i64),
+            "video_second_stream_resume" => Some(14i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             CameraEventType::VideoStart => 0,
@@ -15531,6 +22108,26 @@ impl CameraEventType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            CameraEventType::VideoStart => 0,
+            CameraEventType::VideoSplit => 1,
+            CameraEventType::VideoEnd => 2,
+            CameraEventType::PhotoTaken => 3,
+            CameraEventType::VideoSecondStreamStart => 4,
+            CameraEventType::VideoSecondStreamSplit => 5,
+            CameraEventType::VideoSecondStreamEnd => 6,
+            CameraEventType::VideoSplitStart => 7,
+            CameraEventType::VideoSecondStreamSplitStart => 8,
+            CameraEventType::VideoPause => 11,
+            CameraEventType::VideoSecondStreamPause => 12,
+            CameraEventType::VideoResume => 13,
+            CameraEventType::VideoSecondStreamResume => 14,
+            CameraEventType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for CameraEventType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -15587,6 +22184,15 @@ impl Serialize for CameraEventType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for CameraEventType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::CameraEventType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SensorType {
     Accelerometer,
@@ -15606,6 +22212,15 @@ impl SensorType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "accelerometer" => Some(0i64),
+            "gyroscope" => Some(1i64),
+            "compass" => Some(2i64),
+            "barometer" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SensorType::Accelerometer => 0,
@@ -15618,6 +22233,17 @@ impl SensorType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SensorType::Accelerometer => 0,
+            SensorType::Gyroscope => 1,
+            SensorType::Compass => 2,
+            SensorType::Barometer => 3,
+            SensorType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SensorType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -15654,6 +22280,15 @@ impl Serialize for SensorType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for SensorType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SensorType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum BikeLightNetworkConfigType {
     Auto,
@@ -15672,6 +22307,15 @@ impl BikeLightNetworkConfigType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "auto" => Some(0i64),
+            "individual" => Some(4i64),
+            "high_visibility" => Some(5i64),
+            "trail" => Some(6i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             BikeLightNetworkConfigType::Auto => 0,
@@ -15684,6 +22328,17 @@ impl BikeLightNetworkConfigType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            BikeLightNetworkConfigType::Auto => 0,
+            BikeLightNetworkConfigType::Individual => 4,
+            BikeLightNetworkConfigType::HighVisibility => 5,
+            BikeLightNetworkConfigType::Trail => 6,
+            BikeLightNetworkConfigType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for BikeLightNetworkConfigType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -15722,6 +22377,20 @@ impl Serialize for BikeLightNetworkConfigType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for BikeLightNetworkConfigType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(
+            deserializer,
+            FieldDataType::BikeLightNetworkConfigType,
+            invalid,
+        )
+        .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum CommTimeoutType {
     #[doc = "Timeout pairing to any device"]
@@ -15744,6 +22413,15 @@ impl CommTimeoutType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "wildcard_pairing_timeout" => Some(0i64),
+            "pairing_timeout" => Some(1i64),
+            "connection_lost" => Some(2i64),
+            "connection_timeout" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             CommTimeoutType::WildcardPairingTimeout => 0,
@@ -15756,6 +22434,17 @@ impl CommTimeoutType {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            CommTimeoutType::WildcardPairingTimeout => 0,
+            CommTimeoutType::PairingTimeout => 1,
+            CommTimeoutType::ConnectionLost => 2,
+            CommTimeoutType::ConnectionTimeout => 3,
+            CommTimeoutType::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for CommTimeoutType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -15795,6 +22484,15 @@ impl Serialize for CommTimeoutType {
         }
     }
 }
+impl<'de> Deserialize<'de> for CommTimeoutType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::CommTimeoutType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum CameraOrientationType {
     CameraOrientation0,
@@ -15813,6 +22511,15 @@ impl CameraOrientationType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "camera_orientation_0" => Some(0i64),
+            "camera_orientation_90" => Some(1i64),
+            "camera_orientation_180" => Some(2i64),
+            "camera_orientation_270" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             CameraOrientationType::CameraOrientation0 => 0,
@@ -15825,6 +22532,17 @@ impl CameraOrientationType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            CameraOrientationType::CameraOrientation0 => 0,
+            CameraOrientationType::CameraOrientation90 => 1,
+            CameraOrientationType::CameraOrientation180 => 2,
+            CameraOrientationType::CameraOrientation270 => 3,
+            CameraOrientationType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for CameraOrientationType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -15861,6 +22579,16 @@ impl Serialize for CameraOrientationType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for CameraOrientationType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::CameraOrientationType, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum AttitudeStage {
     Failed,
@@ -15879,6 +22607,15 @@ impl AttitudeStage {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "failed" => Some(0i64),
+            "aligning" => Some(1i64),
+            "degraded" => Some(2i64),
+            "valid" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             AttitudeStage::Failed => 0,
@@ -15891,6 +22628,17 @@ impl AttitudeStage {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            AttitudeStage::Failed => 0,
+            AttitudeStage::Aligning => 1,
+            AttitudeStage::Degraded => 2,
+            AttitudeStage::Valid => 3,
+            AttitudeStage::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for AttitudeStage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -15927,6 +22675,15 @@ impl Serialize for AttitudeStage {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for AttitudeStage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::AttitudeStage, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum AttitudeValidity {
     TrackAngleHeadingValid,
@@ -15963,6 +22720,24 @@ impl AttitudeValidity {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "track_angle_heading_valid" => Some(1i64),
+            "pitch_valid" => Some(2i64),
+            "roll_valid" => Some(4i64),
+            "lateral_body_accel_valid" => Some(8i64),
+            "normal_body_accel_valid" => Some(16i64),
+            "turn_rate_valid" => Some(32i64),
+            "hw_fail" => Some(64i64),
+            "mag_invalid" => Some(128i64),
+            "no_gps" => Some(256i64),
+            "gps_invalid" => Some(512i64),
+            "solution_coasting" => Some(1024i64),
+            "true_track_angle" => Some(2048i64),
+            "magnetic_heading" => Some(4096i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             AttitudeValidity::TrackAngleHeadingValid => 1,
@@ -15984,6 +22759,26 @@ impl AttitudeValidity {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            AttitudeValidity::TrackAngleHeadingValid => 1,
+            AttitudeValidity::PitchValid => 2,
+            AttitudeValidity::RollValid => 4,
+            AttitudeValidity::LateralBodyAccelValid => 8,
+            AttitudeValidity::NormalBodyAccelValid => 16,
+            AttitudeValidity::TurnRateValid => 32,
+            AttitudeValidity::HwFail => 64,
+            AttitudeValidity::MagInvalid => 128,
+            AttitudeValidity::NoGps => 256,
+            AttitudeValidity::GpsInvalid => 512,
+            AttitudeValidity::SolutionCoasting => 1024,
+            AttitudeValidity::TrueTrackAngle => 2048,
+            AttitudeValidity::MagneticHeading => 4096,
+            AttitudeValidity::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for AttitudeValidity {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -16041,6 +22836,15 @@ impl Serialize for AttitudeValidity {
         }
     }
 }
+impl<'de> Deserialize<'de> for AttitudeValidity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::AttitudeValidity, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum AutoSyncFrequency {
     Never,
@@ -16061,6 +22865,16 @@ impl AutoSyncFrequency {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "never" => Some(0i64),
+            "occasionally" => Some(1i64),
+            "frequent" => Some(2i64),
+            "once_a_day" => Some(3i64),
+            "remote" => Some(4i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             AutoSyncFrequency::Never => 0,
@@ -16074,6 +22888,18 @@ impl AutoSyncFrequency {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            AutoSyncFrequency::Never => 0,
+            AutoSyncFrequency::Occasionally => 1,
+            AutoSyncFrequency::Frequent => 2,
+            AutoSyncFrequency::OnceADay => 3,
+            AutoSyncFrequency::Remote => 4,
+            AutoSyncFrequency::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for AutoSyncFrequency {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -16112,6 +22938,15 @@ impl Serialize for AutoSyncFrequency {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for AutoSyncFrequency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::AutoSyncFrequency, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ExdLayout {
     FullScreen,
@@ -16141,6 +22976,20 @@ impl ExdLayout {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "full_screen" => Some(0i64),
+            "half_vertical" => Some(1i64),
+            "half_horizontal" => Some(2i64),
+            "half_vertical_right_split" => Some(3i64),
+            "half_horizontal_bottom_split" => Some(4i64),
+            "full_quarter_split" => Some(5i64),
+            "half_vertical_left_split" => Some(6i64),
+            "half_horizontal_top_split" => Some(7i64),
+            "dynamic" => Some(8i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             ExdLayout::FullScreen => 0,
@@ -16158,6 +23007,22 @@ impl ExdLayout {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ExdLayout::FullScreen => 0,
+            ExdLayout::HalfVertical => 1,
+            ExdLayout::HalfHorizontal => 2,
+            ExdLayout::HalfVerticalRightSplit => 3,
+            ExdLayout::HalfHorizontalBottomSplit => 4,
+            ExdLayout::FullQuarterSplit => 5,
+            ExdLayout::HalfVerticalLeftSplit => 6,
+            ExdLayout::HalfHorizontalTopSplit => 7,
+            ExdLayout::Dynamic => 8,
+            ExdLayout::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ExdLayout {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -16204,6 +23069,15 @@ impl Serialize for ExdLayout {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for ExdLayout {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::ExdLayout, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ExdDisplayType {
     Numerical,
@@ -16236,6 +23110,22 @@ impl ExdDisplayType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "numerical" => Some(0i64),
+            "simple" => Some(1i64),
+            "graph" => Some(2i64),
+            "bar" => Some(3i64),
+            "circle_graph" => Some(4i64),
+            "virtual_partner" => Some(5i64),
+            "balance" => Some(6i64),
+            "string_list" => Some(7i64),
+            "string" => Some(8i64),
+            "simple_dynamic_icon" => Some(9i64),
+            "gauge" => Some(10i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             ExdDisplayType::Numerical => 0,
@@ -16255,6 +23145,24 @@ impl ExdDisplayType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ExdDisplayType::Numerical => 0,
+            ExdDisplayType::Simple => 1,
+            ExdDisplayType::Graph => 2,
+            ExdDisplayType::Bar => 3,
+            ExdDisplayType::CircleGraph => 4,
+            ExdDisplayType::VirtualPartner => 5,
+            ExdDisplayType::Balance => 6,
+            ExdDisplayType::StringList => 7,
+            ExdDisplayType::String => 8,
+            ExdDisplayType::SimpleDynamicIcon => 9,
+            ExdDisplayType::Gauge => 10,
+            ExdDisplayType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ExdDisplayType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -16305,6 +23213,15 @@ impl Serialize for ExdDisplayType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for ExdDisplayType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::ExdDisplayType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ExdDataUnits {
     NoUnits,
@@ -16415,7 +23332,122 @@ impl ExdDataUnits {
             _ => false,
         }
     }
-    pub fn as_u8(self) -> u8 {
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "no_units" => Some(0i64),
+            "laps" => Some(1i64),
+            "miles_per_hour" => Some(2i64),
+            "kilometers_per_hour" => Some(3i64),
+            "feet_per_hour" => Some(4i64),
+            "meters_per_hour" => Some(5i64),
+            "degrees_celsius" => Some(6i64),
+            "degrees_farenheit" => Some(7i64),
+            "zone" => Some(8i64),
+            "gear" => Some(9i64),
+            "rpm" => Some(10i64),
+            "bpm" => Some(11i64),
+            "degrees" => Some(12i64),
+            "millimeters" => Some(13i64),
+            "meters" => Some(14i64),
+            "kilometers" => Some(15i64),
+            "feet" => Some(16i64),
+            "yards" => Some(17i64),
+            "kilofeet" => Some(18i64),
+            "miles" => Some(19i64),
+            "time" => Some(20i64),
+            "enum_turn_type" => Some(21i64),
+            "percent" => Some(22i64),
+            "watts" => Some(23i64),
+            "watts_per_kilogram" => Some(24i64),
+            "enum_battery_status" => Some(25i64),
+            "enum_bike_light_beam_angle_mode" => Some(26i64),
+            "enum_bike_light_battery_status" => Some(27i64),
+            "enum_bike_light_network_config_type" => Some(28i64),
+            "lights" => Some(29i64),
+            "seconds" => Some(30i64),
+            "minutes" => Some(31i64),
+            "hours" => Some(32i64),
+            "calories" => Some(33i64),
+            "kilojoules" => Some(34i64),
+            "milliseconds" => Some(35i64),
+            "second_per_mile" => Some(36i64),
+            "second_per_kilometer" => Some(37i64),
+            "centimeter" => Some(38i64),
+            "enum_course_point" => Some(39i64),
+            "bradians" => Some(40i64),
+            "enum_sport" => Some(41i64),
+            "inches_hg" => Some(42i64),
+            "mm_hg" => Some(43i64),
+            "mbars" => Some(44i64),
+            "hecto_pascals" => Some(45i64),
+            "feet_per_min" => Some(46i64),
+            "meters_per_min" => Some(47i64),
+            "meters_per_sec" => Some(48i64),
+            "eight_cardinal" => Some(49i64),
+            _ => None,
+        }
+    }
+    pub fn as_u8(self) -> u8 {
+        match self {
+            ExdDataUnits::NoUnits => 0,
+            ExdDataUnits::Laps => 1,
+            ExdDataUnits::MilesPerHour => 2,
+            ExdDataUnits::KilometersPerHour => 3,
+            ExdDataUnits::FeetPerHour => 4,
+            ExdDataUnits::MetersPerHour => 5,
+            ExdDataUnits::DegreesCelsius => 6,
+            ExdDataUnits::DegreesFarenheit => 7,
+            ExdDataUnits::Zone => 8,
+            ExdDataUnits::Gear => 9,
+            ExdDataUnits::Rpm => 10,
+            ExdDataUnits::Bpm => 11,
+            ExdDataUnits::Degrees => 12,
+            ExdDataUnits::Millimeters => 13,
+            ExdDataUnits::Meters => 14,
+            ExdDataUnits::Kilometers => 15,
+            ExdDataUnits::Feet => 16,
+            ExdDataUnits::Yards => 17,
+            ExdDataUnits::Kilofeet => 18,
+            ExdDataUnits::Miles => 19,
+            ExdDataUnits::Time => 20,
+            ExdDataUnits::EnumTurnType => 21,
+            ExdDataUnits::Percent => 22,
+            ExdDataUnits::Watts => 23,
+            ExdDataUnits::WattsPerKilogram => 24,
+            ExdDataUnits::EnumBatteryStatus => 25,
+            ExdDataUnits::EnumBikeLightBeamAngleMode => 26,
+            ExdDataUnits::EnumBikeLightBatteryStatus => 27,
+            ExdDataUnits::EnumBikeLightNetworkConfigType => 28,
+            ExdDataUnits::Lights => 29,
+            ExdDataUnits::Seconds => 30,
+            ExdDataUnits::Minutes => 31,
+            ExdDataUnits::Hours => 32,
+            ExdDataUnits::Calories => 33,
+            ExdDataUnits::Kilojoules => 34,
+            ExdDataUnits::Milliseconds => 35,
+            ExdDataUnits::SecondPerMile => 36,
+            ExdDataUnits::SecondPerKilometer => 37,
+            ExdDataUnits::Centimeter => 38,
+            ExdDataUnits::EnumCoursePoint => 39,
+            ExdDataUnits::Bradians => 40,
+            ExdDataUnits::EnumSport => 41,
+            ExdDataUnits::InchesHg => 42,
+            ExdDataUnits::MmHg => 43,
+            ExdDataUnits::Mbars => 44,
+            ExdDataUnits::HectoPascals => 45,
+            ExdDataUnits::FeetPerMin => 46,
+            ExdDataUnits::MetersPerMin => 47,
+            ExdDataUnits::MetersPerSec => 48,
+            ExdDataUnits::EightCardinal => 49,
+            ExdDataUnits::UnknownVariant(value) => value,
+        }
+    }
+    pub fn as_i64(self) -> i64 {
+        self.as_u8() as i64
+    }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
         match self {
             ExdDataUnits::NoUnits => 0,
             ExdDataUnits::Laps => 1,
@@ -16467,12 +23499,9 @@ impl ExdDataUnits {
             ExdDataUnits::MetersPerMin => 47,
             ExdDataUnits::MetersPerSec => 48,
             ExdDataUnits::EightCardinal => 49,
-            ExdDataUnits::UnknownVariant(value) => value,
+            ExdDataUnits::UnknownVariant(value) => value as i64,
         }
     }
-    pub fn as_i64(self) -> i64 {
-        self.as_u8() as i64
-    }
 }
 impl fmt::Display for ExdDataUnits {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -16605,6 +23634,15 @@ impl Serialize for ExdDataUnits {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for ExdDataUnits {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::ExdDataUnits, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ExdQualifiers {
     NoQualifier,
@@ -16703,6 +23741,55 @@ impl ExdQualifiers {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "no_qualifier" => Some(0i64),
+            "instantaneous" => Some(1i64),
+            "average" => Some(2i64),
+            "lap" => Some(3i64),
+            "maximum" => Some(4i64),
+            "maximum_average" => Some(5i64),
+            "maximum_lap" => Some(6i64),
+            "last_lap" => Some(7i64),
+            "average_lap" => Some(8i64),
+            "to_destination" => Some(9i64),
+            "to_go" => Some(10i64),
+            "to_next" => Some(11i64),
+            "next_course_point" => Some(12i64),
+            "total" => Some(13i64),
+            "three_second_average" => Some(14i64),
+            "ten_second_average" => Some(15i64),
+            "thirty_second_average" => Some(16i64),
+            "percent_maximum" => Some(17i64),
+            "percent_maximum_average" => Some(18i64),
+            "lap_percent_maximum" => Some(19i64),
+            "elapsed" => Some(20i64),
+            "sunrise" => Some(21i64),
+            "sunset" => Some(22i64),
+            "compared_to_virtual_partner" => Some(23i64),
+            "maximum_24h" => Some(24i64),
+            "minimum_24h" => Some(25i64),
+            "minimum" => Some(26i64),
+            "first" => Some(27i64),
+            "second" => Some(28i64),
+            "third" => Some(29i64),
+            "shifter" => Some(30i64),
+            "last_sport" => Some(31i64),
+            "moving" => Some(32i64),
+            "stopped" => Some(33i64),
+            "estimated_total" => Some(34i64),
+            "zone_9" => Some(242i64),
+            "zone_8" => Some(243i64),
+            "zone_7" => Some(244i64),
+            "zone_6" => Some(245i64),
+            "zone_5" => Some(246i64),
+            "zone_4" => Some(247i64),
+            "zone_3" => Some(248i64),
+            "zone_2" => Some(249i64),
+            "zone_1" => Some(250i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             ExdQualifiers::NoQualifier => 0,
@@ -16755,6 +23842,57 @@ impl ExdQualifiers {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ExdQualifiers::NoQualifier => 0,
+            ExdQualifiers::Instantaneous => 1,
+            ExdQualifiers::Average => 2,
+            ExdQualifiers::Lap => 3,
+            ExdQualifiers::Maximum => 4,
+            ExdQualifiers::MaximumAverage => 5,
+            ExdQualifiers::MaximumLap => 6,
+            ExdQualifiers::LastLap => 7,
+            ExdQualifiers::AverageLap => 8,
+            ExdQualifiers::ToDestination => 9,
+            ExdQualifiers::ToGo => 10,
+            ExdQualifiers::ToNext => 11,
+            ExdQualifiers::NextCoursePoint => 12,
+            ExdQualifiers::Total => 13,
+            ExdQualifiers::ThreeSecondAverage => 14,
+            ExdQualifiers::TenSecondAverage => 15,
+            ExdQualifiers::ThirtySecondAverage => 16,
+            ExdQualifiers::PercentMaximum => 17,
+            ExdQualifiers::PercentMaximumAverage => 18,
+            ExdQualifiers::LapPercentMaximum => 19,
+            ExdQualifiers::Elapsed => 20,
+            ExdQualifiers::Sunrise => 21,
+            ExdQualifiers::Sunset => 22,
+            ExdQualifiers::ComparedToVirtualPartner => 23,
+            ExdQualifiers::Maximum24h => 24,
+            ExdQualifiers::Minimum24h => 25,
+            ExdQualifiers::Minimum => 26,
+            ExdQualifiers::First => 27,
+            ExdQualifiers::Second => 28,
+            ExdQualifiers::Third => 29,
+            ExdQualifiers::Shifter => 30,
+            ExdQualifiers::LastSport => 31,
+            ExdQualifiers::Moving => 32,
+            ExdQualifiers::Stopped => 33,
+            ExdQualifiers::EstimatedTotal => 34,
+            ExdQualifiers::Zone9 => 242,
+            ExdQualifiers::Zone8 => 243,
+            ExdQualifiers::Zone7 => 244,
+            ExdQualifiers::Zone6 => 245,
+            ExdQualifiers::Zone5 => 246,
+            ExdQualifiers::Zone4 => 247,
+            ExdQualifiers::Zone3 => 248,
+            ExdQualifiers::Zone2 => 249,
+            ExdQualifiers::Zone1 => 250,
+            ExdQualifiers::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ExdQualifiers {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -16871,6 +24009,15 @@ impl Serialize for ExdQualifiers {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for ExdQualifiers {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::ExdQualifiers, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ExdDescriptors {
     BikeLightBatteryStatus,
@@ -17076,6 +24223,108 @@ impl ExdDescriptors {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "bike_light_battery_status" => Some(0i64),
+            "beam_angle_status" => Some(1i64),
+            "batery_level" => Some(2i64),
+            "light_network_mode" => Some(3i64),
+            "number_lights_connected" => Some(4i64),
+            "cadence" => Some(5i64),
+            "distance" => Some(6i64),
+            "estimated_time_of_arrival" => Some(7i64),
+            "heading" => Some(8i64),
+            "time" => Some(9i64),
+            "battery_level" => Some(10i64),
+            "trainer_resistance" => Some(11i64),
+            "trainer_target_power" => Some(12i64),
+            "time_seated" => Some(13i64),
+            "time_standing" => Some(14i64),
+            "elevation" => Some(15i64),
+            "grade" => Some(16i64),
+            "ascent" => Some(17i64),
+            "descent" => Some(18i64),
+            "vertical_speed" => Some(19i64),
+            "di2_battery_level" => Some(20i64),
+            "front_gear" => Some(21i64),
+            "rear_gear" => Some(22i64),
+            "gear_ratio" => Some(23i64),
+            "heart_rate" => Some(24i64),
+            "heart_rate_zone" => Some(25i64),
+            "time_in_heart_rate_zone" => Some(26i64),
+            "heart_rate_reserve" => Some(27i64),
+            "calories" => Some(28i64),
+            "gps_accuracy" => Some(29i64),
+            "gps_signal_strength" => Some(30i64),
+            "temperature" => Some(31i64),
+            "time_of_day" => Some(32i64),
+            "balance" => Some(33i64),
+            "pedal_smoothness" => Some(34i64),
+            "power" => Some(35i64),
+            "functional_threshold_power" => Some(36i64),
+            "intensity_factor" => Some(37i64),
+            "work" => Some(38i64),
+            "power_ratio" => Some(39i64),
+            "normalized_power" => Some(40i64),
+            "training_stress_Score" => Some(41i64),
+            "time_on_zone" => Some(42i64),
+            "speed" => Some(43i64),
+            "laps" => Some(44i64),
+            "reps" => Some(45i64),
+            "workout_step" => Some(46i64),
+            "course_distance" => Some(47i64),
+            "navigation_distance" => Some(48i64),
+            "course_estimated_time_of_arrival" => Some(49i64),
+            "navigation_estimated_time_of_arrival" => Some(50i64),
+            "course_time" => Some(51i64),
+            "navigation_time" => Some(52i64),
+            "course_heading" => Some(53i64),
+            "navigation_heading" => Some(54i64),
+            "power_zone" => Some(55i64),
+            "torque_effectiveness" => Some(56i64),
+            "timer_time" => Some(57i64),
+            "power_weight_ratio" => Some(58i64),
+            "left_platform_center_offset" => Some(59i64),
+            "right_platform_center_offset" => Some(60i64),
+            "left_power_phase_start_angle" => Some(61i64),
+            "right_power_phase_start_angle" => Some(62i64),
+            "left_power_phase_finish_angle" => Some(63i64),
+            "right_power_phase_finish_angle" => Some(64i64),
+            "gears" => Some(65i64),
+            "pace" => Some(66i64),
+            "training_effect" => Some(67i64),
+            "vertical_oscillation" => Some(68i64),
+            "vertical_ratio" => Some(69i64),
+            "ground_contact_time" => Some(70i64),
+            "left_ground_contact_time_balance" => Some(71i64),
+            "right_ground_contact_time_balance" => Some(72i64),
+            "stride_length" => Some(73i64),
+            "running_cadence" => Some(74i64),
+            "performance_condition" => Some(75i64),
+            "course_type" => Some(76i64),
+            "time_in_power_zone" => Some(77i64),
+            "navigation_turn" => Some(78i64),
+            "course_location" => Some(79i64),
+            "navigation_location" => Some(80i64),
+            "compass" => Some(81i64),
+            "gear_combo" => Some(82i64),
+            "muscle_oxygen" => Some(83i64),
+            "icon" => Some(84i64),
+            "compass_heading" => Some(85i64),
+            "gps_heading" => Some(86i64),
+            "gps_elevation" => Some(87i64),
+            "anaerobic_training_effect" => Some(88i64),
+            "course" => Some(89i64),
+            "off_course" => Some(90i64),
+            "glide_ratio" => Some(91i64),
+            "vertical_distance" => Some(92i64),
+            "vmg" => Some(93i64),
+            "ambient_pressure" => Some(94i64),
+            "pressure" => Some(95i64),
+            "vam" => Some(96i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             ExdDescriptors::BikeLightBatteryStatus => 0,
@@ -17181,6 +24430,110 @@ impl ExdDescriptors {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ExdDescriptors::BikeLightBatteryStatus => 0,
+            ExdDescriptors::BeamAngleStatus => 1,
+            ExdDescriptors::BateryLevel => 2,
+            ExdDescriptors::LightNetworkMode => 3,
+            ExdDescriptors::NumberLightsConnected => 4,
+            ExdDescriptors::Cadence => 5,
+            ExdDescriptors::Distance => 6,
+            ExdDescriptors::EstimatedTimeOfArrival => 7,
+            ExdDescriptors::Heading => 8,
+            ExdDescriptors::Time => 9,
+            ExdDescriptors::BatteryLevel => 10,
+            ExdDescriptors::TrainerResistance => 11,
+            ExdDescriptors::TrainerTargetPower => 12,
+            ExdDescriptors::TimeSeated => 13,
+            ExdDescriptors::TimeStanding => 14,
+            ExdDescriptors::Elevation => 15,
+            ExdDescriptors::Grade => 16,
+            ExdDescriptors::Ascent => 17,
+            ExdDescriptors::Descent => 18,
+            ExdDescriptors::VerticalSpeed => 19,
+            ExdDescriptors::Di2BatteryLevel => 20,
+            ExdDescriptors::FrontGear => 21,
+            ExdDescriptors::RearGear => 22,
+            ExdDescriptors::GearRatio => 23,
+            ExdDescriptors::HeartRate => 24,
+            ExdDescriptors::HeartRateZone => 25,
+            ExdDescriptors::TimeInHeartRateZone => 26,
+            ExdDescriptors::HeartRateReserve => 27,
+            ExdDescriptors::Calories => 28,
+            ExdDescriptors::GpsAccuracy => 29,
+            ExdDescriptors::GpsSignalStrength => 30,
+            ExdDescriptors::Temperature => 31,
+            ExdDescriptors::TimeOfDay => 32,
+            ExdDescriptors::Balance => 33,
+            ExdDescriptors::PedalSmoothness => 34,
+            ExdDescriptors::Power => 35,
+            ExdDescriptors::FunctionalThresholdPower => 36,
+            ExdDescriptors::IntensityFactor => 37,
+            ExdDescriptors::Work => 38,
+            ExdDescriptors::PowerRatio => 39,
+            ExdDescriptors::NormalizedPower => 40,
+            ExdDescriptors::TrainingStressScore => 41,
+            ExdDescriptors::TimeOnZone => 42,
+            ExdDescriptors::Speed => 43,
+            ExdDescriptors::Laps => 44,
+            ExdDescriptors::Reps => 45,
+            ExdDescriptors::WorkoutStep => 46,
+            ExdDescriptors::CourseDistance => 47,
+            ExdDescriptors::NavigationDistance => 48,
+            ExdDescriptors::CourseEstimatedTimeOfArrival => 49,
+            ExdDescriptors::NavigationEstimatedTimeOfArrival => 50,
+            ExdDescriptors::CourseTime => 51,
+            ExdDescriptors::NavigationTime => 52,
+            ExdDescriptors::CourseHeading => 53,
+            ExdDescriptors::NavigationHeading => 54,
+            ExdDescriptors::PowerZone => 55,
+            ExdDescriptors::TorqueEffectiveness => 56,
+            ExdDescriptors::TimerTime => 57,
+            ExdDescriptors::PowerWeightRatio => 58,
+            ExdDescriptors::LeftPlatformCenterOffset => 59,
+            ExdDescriptors::RightPlatformCenterOffset => 60,
+            ExdDescriptors::LeftPowerPhaseStartAngle => 61,
+            ExdDescriptors::RightPowerPhaseStartAngle => 62,
+            ExdDescriptors::LeftPowerPhaseFinishAngle => 63,
+            ExdDescriptors::RightPowerPhaseFinishAngle => 64,
+            ExdDescriptors::Gears => 65,
+            ExdDescriptors::Pace => 66,
+            ExdDescriptors::TrainingEffect => 67,
+            ExdDescriptors::VerticalOscillation => 68,
+            ExdDescriptors::VerticalRatio => 69,
+            ExdDescriptors::GroundContactTime => 70,
+            ExdDescriptors::LeftGroundContactTimeBalance => 71,
+            ExdDescriptors::RightGroundContactTimeBalance => 72,
+            ExdDescriptors::StrideLength => 73,
+            ExdDescriptors::RunningCadence => 74,
+            ExdDescriptors::PerformanceCondition => 75,
+            ExdDescriptors::CourseType => 76,
+            ExdDescriptors::TimeInPowerZone => 77,
+            ExdDescriptors::NavigationTurn => 78,
+            ExdDescriptors::CourseLocation => 79,
+            ExdDescriptors::NavigationLocation => 80,
+            ExdDescriptors::Compass => 81,
+            ExdDescriptors::GearCombo => 82,
+            ExdDescriptors::MuscleOxygen => 83,
+            ExdDescriptors::Icon => 84,
+            ExdDescriptors::CompassHeading => 85,
+            ExdDescriptors::GpsHeading => 86,
+            ExdDescriptors::GpsElevation => 87,
+            ExdDescriptors::AnaerobicTrainingEffect => 88,
+            ExdDescriptors::Course => 89,
+            ExdDescriptors::OffCourse => 90,
+            ExdDescriptors::GlideRatio => 91,
+            ExdDescriptors::VerticalDistance => 92,
+            ExdDescriptors::Vmg => 93,
+            ExdDescriptors::AmbientPressure => 94,
+            ExdDescriptors::Pressure => 95,
+            ExdDescriptors::Vam => 96,
+            ExdDescriptors::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ExdDescriptors {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -17413,6 +24766,15 @@ impl Serialize for ExdDescriptors {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for ExdDescriptors {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::ExdDescriptors, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum AutoActivityDetect {
     None,
@@ -17437,6 +24799,18 @@ impl AutoActivityDetect {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "none" => Some(0i64),
+            "running" => Some(1i64),
+            "cycling" => Some(2i64),
+            "swimming" => Some(4i64),
+            "walking" => Some(8i64),
+            "elliptical" => Some(32i64),
+            "sedentary" => Some(1024i64),
+            _ => None,
+        }
+    }
     pub fn as_u32(self) -> u32 {
         match self {
             AutoActivityDetect::None => 0,
@@ -17452,6 +24826,20 @@ impl AutoActivityDetect {
     pub fn as_i64(self) -> i64 {
         self.as_u32() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            AutoActivityDetect::None => 0,
+            AutoActivityDetect::Running => 1,
+            AutoActivityDetect::Cycling => 2,
+            AutoActivityDetect::Swimming => 4,
+            AutoActivityDetect::Walking => 8,
+            AutoActivityDetect::Elliptical => 32,
+            AutoActivityDetect::Sedentary => 1024,
+            AutoActivityDetect::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for AutoActivityDetect {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -17497,6 +24885,16 @@ impl Serialize for AutoActivityDetect {
         }
     }
 }
+impl<'de> Deserialize<'de> for AutoActivityDetect {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u32::MAX);
+        deserialize_variant(deserializer, FieldDataType::AutoActivityDetect, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SupportedExdScreenLayouts {
     FullScreen,
@@ -17523,6 +24921,19 @@ impl SupportedExdScreenLayouts {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "full_screen" => Some(1i64),
+            "half_vertical" => Some(2i64),
+            "half_horizontal" => Some(4i64),
+            "half_vertical_right_split" => Some(8i64),
+            "half_horizontal_bottom_split" => Some(16i64),
+            "full_quarter_split" => Some(32i64),
+            "half_vertical_left_split" => Some(64i64),
+            "half_horizontal_top_split" => Some(128i64),
+            _ => None,
+        }
+    }
     pub fn as_u32(self) -> u32 {
         match self {
             SupportedExdScreenLayouts::FullScreen => 1,
@@ -17539,6 +24950,21 @@ impl SupportedExdScreenLayouts {
     pub fn as_i64(self) -> i64 {
         self.as_u32() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SupportedExdScreenLayouts::FullScreen => 1,
+            SupportedExdScreenLayouts::HalfVertical => 2,
+            SupportedExdScreenLayouts::HalfHorizontal => 4,
+            SupportedExdScreenLayouts::HalfVerticalRightSplit => 8,
+            SupportedExdScreenLayouts::HalfHorizontalBottomSplit => 16,
+            SupportedExdScreenLayouts::FullQuarterSplit => 32,
+            SupportedExdScreenLayouts::HalfVerticalLeftSplit => 64,
+            SupportedExdScreenLayouts::HalfHorizontalTopSplit => 128,
+            SupportedExdScreenLayouts::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SupportedExdScreenLayouts {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -17594,6 +25020,20 @@ impl Serialize for SupportedExdScreenLayouts {
         }
     }
 }
+impl<'de> Deserialize<'de> for SupportedExdScreenLayouts {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u32::MAX);
+        deserialize_variant(
+            deserializer,
+            FieldDataType::SupportedExdScreenLayouts,
+            invalid,
+        )
+        .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum FitBaseType {
     Enum,
@@ -17638,7 +25078,56 @@ impl FitBaseType {
             _ => false,
         }
     }
-    pub fn as_u8(self) -> u8 {
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "enum" => Some(0i64),
+            "sint8" => Some(1i64),
+            "uint8" => Some(2i64),
+            "string" => Some(7i64),
+            "uint8z" => Some(10i64),
+            "byte" => Some(13i64),
+            "sint16" => Some(131i64),
+            "uint16" => Some(132i64),
+            "sint32" => Some(133i64),
+            "uint32" => Some(134i64),
+            "float32" => Some(136i64),
+            "float64" => Some(137i64),
+            "uint16z" => Some(139i64),
+            "uint32z" => Some(140i64),
+            "sint64" => Some(142i64),
+            "uint64" => Some(143i64),
+            "uint64z" => Some(144i64),
+            _ => None,
+        }
+    }
+    pub fn as_u8(self) -> u8 {
+        match self {
+            FitBaseType::Enum => 0,
+            FitBaseType::Sint8 => 1,
+            FitBaseType::Uint8 => 2,
+            FitBaseType::String => 7,
+            FitBaseType::Uint8z => 10,
+            FitBaseType::Byte => 13,
+            FitBaseType::Sint16 => 131,
+            FitBaseType::Uint16 => 132,
+            FitBaseType::Sint32 => 133,
+            FitBaseType::Uint32 => 134,
+            FitBaseType::Float32 => 136,
+            FitBaseType::Float64 => 137,
+            FitBaseType::Uint16z => 139,
+            FitBaseType::Uint32z => 140,
+            FitBaseType::Sint64 => 142,
+            FitBaseType::Uint64 => 143,
+            FitBaseType::Uint64z => 144,
+            FitBaseType::Value(value) => value,
+        }
+    }
+    pub fn as_i64(self) -> i64 {
+        self.as_u8() as i64
+    }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
         match self {
             FitBaseType::Enum => 0,
             FitBaseType::Sint8 => 1,
@@ -17657,12 +25146,9 @@ impl FitBaseType {
             FitBaseType::Sint64 => 142,
             FitBaseType::Uint64 => 143,
             FitBaseType::Uint64z => 144,
-            FitBaseType::Value(value) => value,
+            FitBaseType::Value(value) => value as i64,
         }
     }
-    pub fn as_i64(self) -> i64 {
-        self.as_u8() as i64
-    }
 }
 impl fmt::Display for FitBaseType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -17728,6 +25214,15 @@ impl Serialize for FitBaseType {
         }
     }
 }
+impl<'de> Deserialize<'de> for FitBaseType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::FitBaseType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum TurnType {
     ArrivingIdx,
@@ -17814,6 +25309,49 @@ impl TurnType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "arriving_idx" => Some(0i64),
+            "arriving_left_idx" => Some(1i64),
+            "arriving_right_idx" => Some(2i64),
+            "arriving_via_idx" => Some(3i64),
+            "arriving_via_left_idx" => Some(4i64),
+            "arriving_via_right_idx" => Some(5i64),
+            "bear_keep_left_idx" => Some(6i64),
+            "bear_keep_right_idx" => Some(7i64),
+            "continue_idx" => Some(8i64),
+            "exit_left_idx" => Some(9i64),
+            "exit_right_idx" => Some(10i64),
+            "ferry_idx" => Some(11i64),
+            "roundabout_45_idx" => Some(12i64),
+            "roundabout_90_idx" => Some(13i64),
+            "roundabout_135_idx" => Some(14i64),
+            "roundabout_180_idx" => Some(15i64),
+            "roundabout_225_idx" => Some(16i64),
+            "roundabout_270_idx" => Some(17i64),
+            "roundabout_315_idx" => Some(18i64),
+            "roundabout_360_idx" => Some(19i64),
+            "roundabout_neg_45_idx" => Some(20i64),
+            "roundabout_neg_90_idx" => Some(21i64),
+            "roundabout_neg_135_idx" => Some(22i64),
+            "roundabout_neg_180_idx" => Some(23i64),
+            "roundabout_neg_225_idx" => Some(24i64),
+            "roundabout_neg_270_idx" => Some(25i64),
+            "roundabout_neg_315_idx" => Some(26i64),
+            "roundabout_neg_360_idx" => Some(27i64),
+            "roundabout_generic_idx" => Some(28i64),
+            "roundabout_neg_generic_idx" => Some(29i64),
+            "sharp_turn_left_idx" => Some(30i64),
+            "sharp_turn_right_idx" => Some(31i64),
+            "turn_left_idx" => Some(32i64),
+            "turn_right_idx" => Some(33i64),
+            "uturn_left_idx" => Some(34i64),
+            "uturn_right_idx" => Some(35i64),
+            "icon_inv_idx" => Some(36i64),
+            "icon_idx_cnt" => Some(37i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             TurnType::ArrivingIdx => 0,
@@ -17860,6 +25398,51 @@ impl TurnType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            TurnType::ArrivingIdx => 0,
+            TurnType::ArrivingLeftIdx => 1,
+            TurnType::ArrivingRightIdx => 2,
+            TurnType::ArrivingViaIdx => 3,
+            TurnType::ArrivingViaLeftIdx => 4,
+            TurnType::ArrivingViaRightIdx => 5,
+            TurnType::BearKeepLeftIdx => 6,
+            TurnType::BearKeepRightIdx => 7,
+            TurnType::ContinueIdx => 8,
+            TurnType::ExitLeftIdx => 9,
+            TurnType::ExitRightIdx => 10,
+            TurnType::FerryIdx => 11,
+            TurnType::Roundabout45Idx => 12,
+            TurnType::Roundabout90Idx => 13,
+            TurnType::Roundabout135Idx => 14,
+            TurnType::Roundabout180Idx => 15,
+            TurnType::Roundabout225Idx => 16,
+            TurnType::Roundabout270Idx => 17,
+            TurnType::Roundabout315Idx => 18,
+            TurnType::Roundabout360Idx => 19,
+            TurnType::RoundaboutNeg45Idx => 20,
+            TurnType::RoundaboutNeg90Idx => 21,
+            TurnType::RoundaboutNeg135Idx => 22,
+            TurnType::RoundaboutNeg180Idx => 23,
+            TurnType::RoundaboutNeg225Idx => 24,
+            TurnType::RoundaboutNeg270Idx => 25,
+            TurnType::RoundaboutNeg315Idx => 26,
+            TurnType::RoundaboutNeg360Idx => 27,
+            TurnType::RoundaboutGenericIdx => 28,
+            TurnType::RoundaboutNegGenericIdx => 29,
+            TurnType::SharpTurnLeftIdx => 30,
+            TurnType::SharpTurnRightIdx => 31,
+            TurnType::TurnLeftIdx => 32,
+            TurnType::TurnRightIdx => 33,
+            TurnType::UturnLeftIdx => 34,
+            TurnType::UturnRightIdx => 35,
+            TurnType::IconInvIdx => 36,
+            TurnType::IconIdxCnt => 37,
+            TurnType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for TurnType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -17964,6 +25547,15 @@ impl Serialize for TurnType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for TurnType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::TurnType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum BikeLightBeamAngleMode {
     Manual,
@@ -17978,6 +25570,13 @@ impl BikeLightBeamAngleMode {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "manual" => Some(0i64),
+            "auto" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             BikeLightBeamAngleMode::Manual => 0,
@@ -17988,6 +25587,15 @@ impl BikeLightBeamAngleMode {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            BikeLightBeamAngleMode::Manual => 0,
+            BikeLightBeamAngleMode::Auto => 1,
+            BikeLightBeamAngleMode::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for BikeLightBeamAngleMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -18023,6 +25631,16 @@ impl Serialize for BikeLightBeamAngleMode {
         }
     }
 }
+impl<'de> Deserialize<'de> for BikeLightBeamAngleMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::BikeLightBeamAngleMode, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum FitBaseUnit {
     Other,
@@ -18039,6 +25657,14 @@ impl FitBaseUnit {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "other" => Some(0i64),
+            "kilogram" => Some(1i64),
+            "pound" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             FitBaseUnit::Other => 0,
@@ -18050,6 +25676,16 @@ impl FitBaseUnit {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            FitBaseUnit::Other => 0,
+            FitBaseUnit::Kilogram => 1,
+            FitBaseUnit::Pound => 2,
+            FitBaseUnit::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for FitBaseUnit {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -18087,6 +25723,15 @@ impl Serialize for FitBaseUnit {
         }
     }
 }
+impl<'de> Deserialize<'de> for FitBaseUnit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::FitBaseUnit, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SetType {
     Rest,
@@ -18101,6 +25746,13 @@ impl SetType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "rest" => Some(0i64),
+            "active" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SetType::Rest => 0,
@@ -18111,6 +25763,15 @@ impl SetType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SetType::Rest => 0,
+            SetType::Active => 1,
+            SetType::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SetType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -18146,6 +25807,15 @@ impl Serialize for SetType {
         }
     }
 }
+impl<'de> Deserialize<'de> for SetType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SetType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum MaxMetCategory {
     Generic,
@@ -18160,6 +25830,13 @@ impl MaxMetCategory {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "generic" => Some(0i64),
+            "cycling" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             MaxMetCategory::Generic => 0,
@@ -18170,6 +25847,15 @@ impl MaxMetCategory {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            MaxMetCategory::Generic => 0,
+            MaxMetCategory::Cycling => 1,
+            MaxMetCategory::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for MaxMetCategory {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -18202,6 +25888,15 @@ impl Serialize for MaxMetCategory {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for MaxMetCategory {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::MaxMetCategory, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ExerciseCategory {
     BenchPress,
@@ -18280,6 +25975,45 @@ impl ExerciseCategory {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "bench_press" => Some(0i64),
+            "calf_raise" => Some(1i64),
+            "cardio" => Some(2i64),
+            "carry" => Some(3i64),
+            "chop" => Some(4i64),
+            "core" => Some(5i64),
+            "crunch" => Some(6i64),
+            "curl" => Some(7i64),
+            "deadlift" => Some(8i64),
+            "flye" => Some(9i64),
+            "hip_raise" => Some(10i64),
+            "hip_stability" => Some(11i64),
+            "hip_swing" => Some(12i64),
+            "hyperextension" => Some(13i64),
+            "lateral_raise" => Some(14i64),
+            "leg_curl" => Some(15i64),
+            "leg_raise" => Some(16i64),
+            "lunge" => Some(17i64),
+            "olympic_lift" => Some(18i64),
+            "plank" => Some(19i64),
+            "plyo" => Some(20i64),
+            "pull_up" => Some(21i64),
+            "push_up" => Some(22i64),
+            "row" => Some(23i64),
+            "shoulder_press" => Some(24i64),
+            "shoulder_stability" => Some(25i64),
+            "shrug" => Some(26i64),
+            "sit_up" => Some(27i64),
+            "squat" => Some(28i64),
+            "total_body" => Some(29i64),
+            "triceps_extension" => Some(30i64),
+            "warm_up" => Some(31i64),
+            "run" => Some(32i64),
+            "unknown" => Some(65534i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             ExerciseCategory::BenchPress => 0,
@@ -18322,6 +26056,47 @@ impl ExerciseCategory {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ExerciseCategory::BenchPress => 0,
+            ExerciseCategory::CalfRaise => 1,
+            ExerciseCategory::Cardio => 2,
+            ExerciseCategory::Carry => 3,
+            ExerciseCategory::Chop => 4,
+            ExerciseCategory::Core => 5,
+            ExerciseCategory::Crunch => 6,
+            ExerciseCategory::Curl => 7,
+            ExerciseCategory::Deadlift => 8,
+            ExerciseCategory::Flye => 9,
+            ExerciseCategory::HipRaise => 10,
+            ExerciseCategory::HipStability => 11,
+            ExerciseCategory::HipSwing => 12,
+            ExerciseCategory::Hyperextension => 13,
+            ExerciseCategory::LateralRaise => 14,
+            ExerciseCategory::LegCurl => 15,
+            ExerciseCategory::LegRaise => 16,
+            ExerciseCategory::Lunge => 17,
+            ExerciseCategory::OlympicLift => 18,
+            ExerciseCategory::Plank => 19,
+            ExerciseCategory::Plyo => 20,
+            ExerciseCategory::PullUp => 21,
+            ExerciseCategory::PushUp => 22,
+            ExerciseCategory::Row => 23,
+            ExerciseCategory::ShoulderPress => 24,
+            ExerciseCategory::ShoulderStability => 25,
+            ExerciseCategory::Shrug => 26,
+            ExerciseCategory::SitUp => 27,
+            ExerciseCategory::Squat => 28,
+            ExerciseCategory::TotalBody => 29,
+            ExerciseCategory::TricepsExtension => 30,
+            ExerciseCategory::WarmUp => 31,
+            ExerciseCategory::Run => 32,
+            ExerciseCategory::Unknown => 65534,
+            ExerciseCategory::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ExerciseCategory {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -18421,6 +26196,15 @@ impl Serialize for ExerciseCategory {
         }
     }
 }
+impl<'de> Deserialize<'de> for ExerciseCategory {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::ExerciseCategory, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum BenchPressExerciseName {
     AlternatingDumbbellChestPressOnSwissBall,
@@ -18485,6 +26269,38 @@ impl BenchPressExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "alternating_dumbbell_chest_press_on_swiss_ball" => Some(0i64),
+            "barbell_bench_press" => Some(1i64),
+            "barbell_board_bench_press" => Some(2i64),
+            "barbell_floor_press" => Some(3i64),
+            "close_grip_barbell_bench_press" => Some(4i64),
+            "decline_dumbbell_bench_press" => Some(5i64),
+            "dumbbell_bench_press" => Some(6i64),
+            "dumbbell_floor_press" => Some(7i64),
+            "incline_barbell_bench_press" => Some(8i64),
+            "incline_dumbbell_bench_press" => Some(9i64),
+            "incline_smith_machine_bench_press" => Some(10i64),
+            "isometric_barbell_bench_press" => Some(11i64),
+            "kettlebell_chest_press" => Some(12i64),
+            "neutral_grip_dumbbell_bench_press" => Some(13i64),
+            "neutral_grip_dumbbell_incline_bench_press" => Some(14i64),
+            "one_arm_floor_press" => Some(15i64),
+            "weighted_one_arm_floor_press" => Some(16i64),
+            "partial_lockout" => Some(17i64),
+            "reverse_grip_barbell_bench_press" => Some(18i64),
+            "reverse_grip_incline_bench_press" => Some(19i64),
+            "single_arm_cable_chest_press" => Some(20i64),
+            "single_arm_dumbbell_bench_press" => Some(21i64),
+            "smith_machine_bench_press" => Some(22i64),
+            "swiss_ball_dumbbell_chest_press" => Some(23i64),
+            "triple_stop_barbell_bench_press" => Some(24i64),
+            "wide_grip_barbell_bench_press" => Some(25i64),
+            "alternating_dumbbell_chest_press" => Some(26i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             BenchPressExerciseName::AlternatingDumbbellChestPressOnSwissBall => 0,
@@ -18520,6 +26336,40 @@ impl BenchPressExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            BenchPressExerciseName::AlternatingDumbbellChestPressOnSwissBall => 0,
+            BenchPressExerciseName::BarbellBenchPress => 1,
+            BenchPressExerciseName::BarbellBoardBenchPress => 2,
+            BenchPressExerciseName::BarbellFloorPress => 3,
+            BenchPressExerciseName::CloseGripBarbellBenchPress => 4,
+            BenchPressExerciseName::DeclineDumbbellBenchPress => 5,
+            BenchPressExerciseName::DumbbellBenchPress => 6,
+            BenchPressExerciseName::DumbbellFloorPress => 7,
+            BenchPressExerciseName::InclineBarbellBenchPress => 8,
+            BenchPressExerciseName::InclineDumbbellBenchPress => 9,
+            BenchPressExerciseName::InclineSmithMachineBenchPress => 10,
+            BenchPressExerciseName::IsometricBarbellBenchPress => 11,
+            BenchPressExerciseName::KettlebellChestPress => 12,
+            BenchPressExerciseName::NeutralGripDumbbellBenchPress => 13,
+            BenchPressExerciseName::NeutralGripDumbbellInclineBenchPress => 14,
+            BenchPressExerciseName::OneArmFloorPress => 15,
+            BenchPressExerciseName::WeightedOneArmFloorPress => 16,
+            BenchPressExerciseName::PartialLockout => 17,
+            BenchPressExerciseName::ReverseGripBarbellBenchPress => 18,
+            BenchPressExerciseName::ReverseGripInclineBenchPress => 19,
+            BenchPressExerciseName::SingleArmCableChestPress => 20,
+            BenchPressExerciseName::SingleArmDumbbellBenchPress => 21,
+            BenchPressExerciseName::SmithMachineBenchPress => 22,
+            BenchPressExerciseName::SwissBallDumbbellChestPress => 23,
+            BenchPressExerciseName::TripleStopBarbellBenchPress => 24,
+            BenchPressExerciseName::WideGripBarbellBenchPress => 25,
+            BenchPressExerciseName::AlternatingDumbbellChestPress => 26,
+            BenchPressExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for BenchPressExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -18645,6 +26495,16 @@ impl Serialize for BenchPressExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for BenchPressExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::BenchPressExerciseName, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum CalfRaiseExerciseName {
     Name3WayCalfRaise,
@@ -18697,6 +26557,32 @@ impl CalfRaiseExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "3_way_calf_raise" => Some(0i64),
+            "3_way_weighted_calf_raise" => Some(1i64),
+            "3_way_single_leg_calf_raise" => Some(2i64),
+            "3_way_weighted_single_leg_calf_raise" => Some(3i64),
+            "donkey_calf_raise" => Some(4i64),
+            "weighted_donkey_calf_raise" => Some(5i64),
+            "seated_calf_raise" => Some(6i64),
+            "weighted_seated_calf_raise" => Some(7i64),
+            "seated_dumbbell_toe_raise" => Some(8i64),
+            "single_leg_bent_knee_calf_raise" => Some(9i64),
+            "weighted_single_leg_bent_knee_calf_raise" => Some(10i64),
+            "single_leg_decline_push_up" => Some(11i64),
+            "single_leg_donkey_calf_raise" => Some(12i64),
+            "weighted_single_leg_donkey_calf_raise" => Some(13i64),
+            "single_leg_hip_raise_with_knee_hold" => Some(14i64),
+            "single_leg_standing_calf_raise" => Some(15i64),
+            "single_leg_standing_dumbbell_calf_raise" => Some(16i64),
+            "standing_barbell_calf_raise" => Some(17i64),
+            "standing_calf_raise" => Some(18i64),
+            "weighted_standing_calf_raise" => Some(19i64),
+            "standing_dumbbell_calf_raise" => Some(20i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             CalfRaiseExerciseName::Name3WayCalfRaise => 0,
@@ -18726,6 +26612,34 @@ impl CalfRaiseExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            CalfRaiseExerciseName::Name3WayCalfRaise => 0,
+            CalfRaiseExerciseName::Name3WayWeightedCalfRaise => 1,
+            CalfRaiseExerciseName::Name3WaySingleLegCalfRaise => 2,
+            CalfRaiseExerciseName::Name3WayWeightedSingleLegCalfRaise => 3,
+            CalfRaiseExerciseName::DonkeyCalfRaise => 4,
+            CalfRaiseExerciseName::WeightedDonkeyCalfRaise => 5,
+            CalfRaiseExerciseName::SeatedCalfRaise => 6,
+            CalfRaiseExerciseName::WeightedSeatedCalfRaise => 7,
+            CalfRaiseExerciseName::SeatedDumbbellToeRaise => 8,
+            CalfRaiseExerciseName::SingleLegBentKneeCalfRaise => 9,
+            CalfRaiseExerciseName::WeightedSingleLegBentKneeCalfRaise => 10,
+            CalfRaiseExerciseName::SingleLegDeclinePushUp => 11,
+            CalfRaiseExerciseName::SingleLegDonkeyCalfRaise => 12,
+            CalfRaiseExerciseName::WeightedSingleLegDonkeyCalfRaise => 13,
+            CalfRaiseExerciseName::SingleLegHipRaiseWithKneeHold => 14,
+            CalfRaiseExerciseName::SingleLegStandingCalfRaise => 15,
+            CalfRaiseExerciseName::SingleLegStandingDumbbellCalfRaise => 16,
+            CalfRaiseExerciseName::StandingBarbellCalfRaise => 17,
+            CalfRaiseExerciseName::StandingCalfRaise => 18,
+            CalfRaiseExerciseName::WeightedStandingCalfRaise => 19,
+            CalfRaiseExerciseName::StandingDumbbellCalfRaise => 20,
+            CalfRaiseExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for CalfRaiseExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -18831,6 +26745,16 @@ impl Serialize for CalfRaiseExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for CalfRaiseExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::CalfRaiseExerciseName, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum CardioExerciseName {
     BobAndWeaveCircle,
@@ -18885,6 +26809,33 @@ impl CardioExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "bob_and_weave_circle" => Some(0i64),
+            "weighted_bob_and_weave_circle" => Some(1i64),
+            "cardio_core_crawl" => Some(2i64),
+            "weighted_cardio_core_crawl" => Some(3i64),
+            "double_under" => Some(4i64),
+            "weighted_double_under" => Some(5i64),
+            "jump_rope" => Some(6i64),
+            "weighted_jump_rope" => Some(7i64),
+            "jump_rope_crossover" => Some(8i64),
+            "weighted_jump_rope_crossover" => Some(9i64),
+            "jump_rope_jog" => Some(10i64),
+            "weighted_jump_rope_jog" => Some(11i64),
+            "jumping_jacks" => Some(12i64),
+            "weighted_jumping_jacks" => Some(13i64),
+            "ski_moguls" => Some(14i64),
+            "weighted_ski_moguls" => Some(15i64),
+            "split_jacks" => Some(16i64),
+            "weighted_split_jacks" => Some(17i64),
+            "squat_jacks" => Some(18i64),
+            "weighted_squat_jacks" => Some(19i64),
+            "triple_under" => Some(20i64),
+            "weighted_triple_under" => Some(21i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             CardioExerciseName::BobAndWeaveCircle => 0,
@@ -18915,6 +26866,35 @@ impl CardioExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            CardioExerciseName::BobAndWeaveCircle => 0,
+            CardioExerciseName::WeightedBobAndWeaveCircle => 1,
+            CardioExerciseName::CardioCoreCrawl => 2,
+            CardioExerciseName::WeightedCardioCoreCrawl => 3,
+            CardioExerciseName::DoubleUnder => 4,
+            CardioExerciseName::WeightedDoubleUnder => 5,
+            CardioExerciseName::JumpRope => 6,
+            CardioExerciseName::WeightedJumpRope => 7,
+            CardioExerciseName::JumpRopeCrossover => 8,
+            CardioExerciseName::WeightedJumpRopeCrossover => 9,
+            CardioExerciseName::JumpRopeJog => 10,
+            CardioExerciseName::WeightedJumpRopeJog => 11,
+            CardioExerciseName::JumpingJacks => 12,
+            CardioExerciseName::WeightedJumpingJacks => 13,
+            CardioExerciseName::SkiMoguls => 14,
+            CardioExerciseName::WeightedSkiMoguls => 15,
+            CardioExerciseName::SplitJacks => 16,
+            CardioExerciseName::WeightedSplitJacks => 17,
+            CardioExerciseName::SquatJacks => 18,
+            CardioExerciseName::WeightedSquatJacks => 19,
+            CardioExerciseName::TripleUnder => 20,
+            CardioExerciseName::WeightedTripleUnder => 21,
+            CardioExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for CardioExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -18994,6 +26974,16 @@ impl Serialize for CardioExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for CardioExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::CardioExerciseName, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum CarryExerciseName {
     BarHolds,
@@ -19014,6 +27004,16 @@ impl CarryExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "bar_holds" => Some(0i64),
+            "farmers_walk" => Some(1i64),
+            "farmers_walk_on_toes" => Some(2i64),
+            "hex_dumbbell_hold" => Some(3i64),
+            "overhead_carry" => Some(4i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             CarryExerciseName::BarHolds => 0,
@@ -19027,6 +27027,18 @@ impl CarryExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            CarryExerciseName::BarHolds => 0,
+            CarryExerciseName::FarmersWalk => 1,
+            CarryExerciseName::FarmersWalkOnToes => 2,
+            CarryExerciseName::HexDumbbellHold => 3,
+            CarryExerciseName::OverheadCarry => 4,
+            CarryExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for CarryExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -19068,6 +27080,15 @@ impl Serialize for CarryExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for CarryExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::CarryExerciseName, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ChopExerciseName {
     CablePullThrough,
@@ -19124,6 +27145,34 @@ impl ChopExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "cable_pull_through" => Some(0i64),
+            "cable_rotational_lift" => Some(1i64),
+            "cable_woodchop" => Some(2i64),
+            "cross_chop_to_knee" => Some(3i64),
+            "weighted_cross_chop_to_knee" => Some(4i64),
+            "dumbbell_chop" => Some(5i64),
+            "half_kneeling_rotation" => Some(6i64),
+            "weighted_half_kneeling_rotation" => Some(7i64),
+            "half_kneeling_rotational_chop" => Some(8i64),
+            "half_kneeling_rotational_reverse_chop" => Some(9i64),
+            "half_kneeling_stability_chop" => Some(10i64),
+            "half_kneeling_stability_reverse_chop" => Some(11i64),
+            "kneeling_rotational_chop" => Some(12i64),
+            "kneeling_rotational_reverse_chop" => Some(13i64),
+            "kneeling_stability_chop" => Some(14i64),
+            "kneeling_woodchopper" => Some(15i64),
+            "medicine_ball_wood_chops" => Some(16i64),
+            "power_squat_chops" => Some(17i64),
+            "weighted_power_squat_chops" => Some(18i64),
+            "standing_rotational_chop" => Some(19i64),
+            "standing_split_rotational_chop" => Some(20i64),
+            "standing_split_rotational_reverse_chop" => Some(21i64),
+            "standing_stability_reverse_chop" => Some(22i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             ChopExerciseName::CablePullThrough => 0,
@@ -19155,6 +27204,36 @@ impl ChopExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ChopExerciseName::CablePullThrough => 0,
+            ChopExerciseName::CableRotationalLift => 1,
+            ChopExerciseName::CableWoodchop => 2,
+            ChopExerciseName::CrossChopToKnee => 3,
+            ChopExerciseName::WeightedCrossChopToKnee => 4,
+            ChopExerciseName::DumbbellChop => 5,
+            ChopExerciseName::HalfKneelingRotation => 6,
+            ChopExerciseName::WeightedHalfKneelingRotation => 7,
+            ChopExerciseName::HalfKneelingRotationalChop => 8,
+            ChopExerciseName::HalfKneelingRotationalReverseChop => 9,
+            ChopExerciseName::HalfKneelingStabilityChop => 10,
+            ChopExerciseName::HalfKneelingStabilityReverseChop => 11,
+            ChopExerciseName::KneelingRotationalChop => 12,
+            ChopExerciseName::KneelingRotationalReverseChop => 13,
+            ChopExerciseName::KneelingStabilityChop => 14,
+            ChopExerciseName::KneelingWoodchopper => 15,
+            ChopExerciseName::MedicineBallWoodChops => 16,
+            ChopExerciseName::PowerSquatChops => 17,
+            ChopExerciseName::WeightedPowerSquatChops => 18,
+            ChopExerciseName::StandingRotationalChop => 19,
+            ChopExerciseName::StandingSplitRotationalChop => 20,
+            ChopExerciseName::StandingSplitRotationalReverseChop => 21,
+            ChopExerciseName::StandingStabilityReverseChop => 22,
+            ChopExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ChopExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -19250,6 +27329,15 @@ impl Serialize for ChopExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for ChopExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::ChopExerciseName, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum CoreExerciseName {
     AbsJabs,
@@ -19409,6 +27497,84 @@ impl CoreExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "abs_jabs" => Some(0i64),
+            "weighted_abs_jabs" => Some(1i64),
+            "alternating_plate_reach" => Some(2i64),
+            "barbell_rollout" => Some(3i64),
+            "weighted_barbell_rollout" => Some(4i64),
+            "body_bar_oblique_twist" => Some(5i64),
+            "cable_core_press" => Some(6i64),
+            "cable_side_bend" => Some(7i64),
+            "side_bend" => Some(8i64),
+            "weighted_side_bend" => Some(9i64),
+            "crescent_circle" => Some(10i64),
+            "weighted_crescent_circle" => Some(11i64),
+            "cycling_russian_twist" => Some(12i64),
+            "weighted_cycling_russian_twist" => Some(13i64),
+            "elevated_feet_russian_twist" => Some(14i64),
+            "weighted_elevated_feet_russian_twist" => Some(15i64),
+            "half_turkish_get_up" => Some(16i64),
+            "kettlebell_windmill" => Some(17i64),
+            "kneeling_ab_wheel" => Some(18i64),
+            "weighted_kneeling_ab_wheel" => Some(19i64),
+            "modified_front_lever" => Some(20i64),
+            "open_knee_tucks" => Some(21i64),
+            "weighted_open_knee_tucks" => Some(22i64),
+            "side_abs_leg_lift" => Some(23i64),
+            "weighted_side_abs_leg_lift" => Some(24i64),
+            "swiss_ball_jackknife" => Some(25i64),
+            "weighted_swiss_ball_jackknife" => Some(26i64),
+            "swiss_ball_pike" => Some(27i64),
+            "weighted_swiss_ball_pike" => Some(28i64),
+            "swiss_ball_rollout" => Some(29i64),
+            "weighted_swiss_ball_rollout" => Some(30i64),
+            "triangle_hip_press" => Some(31i64),
+            "weighted_triangle_hip_press" => Some(32i64),
+            "trx_suspended_jackknife" => Some(33i64),
+            "weighted_trx_suspended_jackknife" => Some(34i64),
+            "u_boat" => Some(35i64),
+            "weighted_u_boat" => Some(36i64),
+            "windmill_switches" => Some(37i64),
+            "weighted_windmill_switches" => Some(38i64),
+            "alternating_slide_out" => Some(39i64),
+            "weighted_alternating_slide_out" => Some(40i64),
+            "ghd_back_extensions" => Some(41i64),
+            "weighted_ghd_back_extensions" => Some(42i64),
+            "overhead_walk" => Some(43i64),
+            "inchworm" => Some(44i64),
+            "weighted_modified_front_lever" => Some(45i64),
+            "russian_twist" => Some(46i64),
+            "abdominal_leg_rotations" => Some(47i64),
+            "arm_and_leg_extension_on_knees" => Some(48i64),
+            "bicycle" => Some(49i64),
+            "bicep_curl_with_leg_extension" => Some(50i64),
+            "cat_cow" => Some(51i64),
+            "corkscrew" => Some(52i64),
+            "criss_cross" => Some(53i64),
+            "criss_cross_with_ball" => Some(54i64),
+            "double_leg_stretch" => Some(55i64),
+            "knee_folds" => Some(56i64),
+            "lower_lift" => Some(57i64),
+            "neck_pull" => Some(58i64),
+            "pelvic_clocks" => Some(59i64),
+            "roll_over" => Some(60i64),
+            "roll_up" => Some(61i64),
+            "rolling" => Some(62i64),
+            "rowing_1" => Some(63i64),
+            "rowing_2" => Some(64i64),
+            "scissors" => Some(65i64),
+            "single_leg_circles" => Some(66i64),
+            "single_leg_stretch" => Some(67i64),
+            "snake_twist_1_and_2" => Some(68i64),
+            "swan" => Some(69i64),
+            "swimming" => Some(70i64),
+            "teaser" => Some(71i64),
+            "the_hundred" => Some(72i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             CoreExerciseName::AbsJabs => 0,
@@ -19490,6 +27656,86 @@ impl CoreExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            CoreExerciseName::AbsJabs => 0,
+            CoreExerciseName::WeightedAbsJabs => 1,
+            CoreExerciseName::AlternatingPlateReach => 2,
+            CoreExerciseName::BarbellRollout => 3,
+            CoreExerciseName::WeightedBarbellRollout => 4,
+            CoreExerciseName::BodyBarObliqueTwist => 5,
+            CoreExerciseName::CableCorePress => 6,
+            CoreExerciseName::CableSideBend => 7,
+            CoreExerciseName::SideBend => 8,
+            CoreExerciseName::WeightedSideBend => 9,
+            CoreExerciseName::CrescentCircle => 10,
+            CoreExerciseName::WeightedCrescentCircle => 11,
+            CoreExerciseName::CyclingRussianTwist => 12,
+            CoreExerciseName::WeightedCyclingRussianTwist => 13,
+            CoreExerciseName::ElevatedFeetRussianTwist => 14,
+            CoreExerciseName::WeightedElevatedFeetRussianTwist => 15,
+            CoreExerciseName::HalfTurkishGetUp => 16,
+            CoreExerciseName::KettlebellWindmill => 17,
+            CoreExerciseName::KneelingAbWheel => 18,
+            CoreExerciseName::WeightedKneelingAbWheel => 19,
+            CoreExerciseName::ModifiedFrontLever => 20,
+            CoreExerciseName::OpenKneeTucks => 21,
+            CoreExerciseName::WeightedOpenKneeTucks => 22,
+            CoreExerciseName::SideAbsLegLift => 23,
+            CoreExerciseName::WeightedSideAbsLegLift => 24,
+            CoreExerciseName::SwissBallJackknife => 25,
+            CoreExerciseName::WeightedSwissBallJackknife => 26,
+            CoreExerciseName::SwissBallPike => 27,
+            CoreExerciseName::WeightedSwissBallPike => 28,
+            CoreExerciseName::SwissBallRollout => 29,
+            CoreExerciseName::WeightedSwissBallRollout => 30,
+            CoreExerciseName::TriangleHipPress => 31,
+            CoreExerciseName::WeightedTriangleHipPress => 32,
+            CoreExerciseName::TrxSuspendedJackknife => 33,
+            CoreExerciseName::WeightedTrxSuspendedJackknife => 34,
+            CoreExerciseName::UBoat => 35,
+            CoreExerciseName::WeightedUBoat => 36,
+            CoreExerciseName::WindmillSwitches => 37,
+            CoreExerciseName::WeightedWindmillSwitches => 38,
+            CoreExerciseName::AlternatingSlideOut => 39,
+            CoreExerciseName::WeightedAlternatingSlideOut => 40,
+            CoreExerciseName::GhdBackExtensions => 41,
+            CoreExerciseName::WeightedGhdBackExtensions => 42,
+            CoreExerciseName::OverheadWalk => 43,
+            CoreExerciseName::Inchworm => 44,
+            CoreExerciseName::WeightedModifiedFrontLever => 45,
+            CoreExerciseName::RussianTwist => 46,
+            CoreExerciseName::AbdominalLegRotations => 47,
+            CoreExerciseName::ArmAndLegExtensionOnKnees => 48,
+            CoreExerciseName::Bicycle => 49,
+            CoreExerciseName::BicepCurlWithLegExtension => 50,
+            CoreExerciseName::CatCow => 51,
+            CoreExerciseName::Corkscrew => 52,
+            CoreExerciseName::CrissCross => 53,
+            CoreExerciseName::CrissCrossWithBall => 54,
+            CoreExerciseName::DoubleLegStretch => 55,
+            CoreExerciseName::KneeFolds => 56,
+            CoreExerciseName::LowerLift => 57,
+            CoreExerciseName::NeckPull => 58,
+            CoreExerciseName::PelvicClocks => 59,
+            CoreExerciseName::RollOver => 60,
+            CoreExerciseName::RollUp => 61,
+            CoreExerciseName::Rolling => 62,
+            CoreExerciseName::Rowing1 => 63,
+            CoreExerciseName::Rowing2 => 64,
+            CoreExerciseName::Scissors => 65,
+            CoreExerciseName::SingleLegCircles => 66,
+            CoreExerciseName::SingleLegStretch => 67,
+            CoreExerciseName::SnakeTwist1And2 => 68,
+            CoreExerciseName::Swan => 69,
+            CoreExerciseName::Swimming => 70,
+            CoreExerciseName::Teaser => 71,
+            CoreExerciseName::TheHundred => 72,
+            CoreExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for CoreExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -19685,6 +27931,15 @@ impl Serialize for CoreExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for CoreExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::CoreExerciseName, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum CrunchExerciseName {
     BicycleCrunch,
@@ -19865,6 +28120,96 @@ impl CrunchExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "bicycle_crunch" => Some(0i64),
+            "cable_crunch" => Some(1i64),
+            "circular_arm_crunch" => Some(2i64),
+            "crossed_arms_crunch" => Some(3i64),
+            "weighted_crossed_arms_crunch" => Some(4i64),
+            "cross_leg_reverse_crunch" => Some(5i64),
+            "weighted_cross_leg_reverse_crunch" => Some(6i64),
+            "crunch_chop" => Some(7i64),
+            "weighted_crunch_chop" => Some(8i64),
+            "double_crunch" => Some(9i64),
+            "weighted_double_crunch" => Some(10i64),
+            "elbow_to_knee_crunch" => Some(11i64),
+            "weighted_elbow_to_knee_crunch" => Some(12i64),
+            "flutter_kicks" => Some(13i64),
+            "weighted_flutter_kicks" => Some(14i64),
+            "foam_roller_reverse_crunch_on_bench" => Some(15i64),
+            "weighted_foam_roller_reverse_crunch_on_bench" => Some(16i64),
+            "foam_roller_reverse_crunch_with_dumbbell" => Some(17i64),
+            "foam_roller_reverse_crunch_with_medicine_ball" => Some(18i64),
+            "frog_press" => Some(19i64),
+            "hanging_knee_raise_oblique_crunch" => Some(20i64),
+            "weighted_hanging_knee_raise_oblique_crunch" => Some(21i64),
+            "hip_crossover" => Some(22i64),
+            "weighted_hip_crossover" => Some(23i64),
+            "hollow_rock" => Some(24i64),
+            "weighted_hollow_rock" => Some(25i64),
+            "incline_reverse_crunch" => Some(26i64),
+            "weighted_incline_reverse_crunch" => Some(27i64),
+            "kneeling_cable_crunch" => Some(28i64),
+            "kneeling_cross_crunch" => Some(29i64),
+            "weighted_kneeling_cross_crunch" => Some(30i64),
+            "kneeling_oblique_cable_crunch" => Some(31i64),
+            "knees_to_elbow" => Some(32i64),
+            "leg_extensions" => Some(33i64),
+            "weighted_leg_extensions" => Some(34i64),
+            "leg_levers" => Some(35i64),
+            "mcgill_curl_up" => Some(36i64),
+            "weighted_mcgill_curl_up" => Some(37i64),
+            "modified_pilates_roll_up_with_ball" => Some(38i64),
+            "weighted_modified_pilates_roll_up_with_ball" => Some(39i64),
+            "pilates_crunch" => Some(40i64),
+            "weighted_pilates_crunch" => Some(41i64),
+            "pilates_roll_up_with_ball" => Some(42i64),
+            "weighted_pilates_roll_up_with_ball" => Some(43i64),
+            "raised_legs_crunch" => Some(44i64),
+            "weighted_raised_legs_crunch" => Some(45i64),
+            "reverse_crunch" => Some(46i64),
+            "weighted_reverse_crunch" => Some(47i64),
+            "reverse_crunch_on_a_bench" => Some(48i64),
+            "weighted_reverse_crunch_on_a_bench" => Some(49i64),
+            "reverse_curl_and_lift" => Some(50i64),
+            "weighted_reverse_curl_and_lift" => Some(51i64),
+            "rotational_lift" => Some(52i64),
+            "weighted_rotational_lift" => Some(53i64),
+            "seated_alternating_reverse_crunch" => Some(54i64),
+            "weighted_seated_alternating_reverse_crunch" => Some(55i64),
+            "seated_leg_u" => Some(56i64),
+            "weighted_seated_leg_u" => Some(57i64),
+            "side_to_side_crunch_and_weave" => Some(58i64),
+            "weighted_side_to_side_crunch_and_weave" => Some(59i64),
+            "single_leg_reverse_crunch" => Some(60i64),
+            "weighted_single_leg_reverse_crunch" => Some(61i64),
+            "skater_crunch_cross" => Some(62i64),
+            "weighted_skater_crunch_cross" => Some(63i64),
+            "standing_cable_crunch" => Some(64i64),
+            "standing_side_crunch" => Some(65i64),
+            "step_climb" => Some(66i64),
+            "weighted_step_climb" => Some(67i64),
+            "swiss_ball_crunch" => Some(68i64),
+            "swiss_ball_reverse_crunch" => Some(69i64),
+            "weighted_swiss_ball_reverse_crunch" => Some(70i64),
+            "swiss_ball_russian_twist" => Some(71i64),
+            "weighted_swiss_ball_russian_twist" => Some(72i64),
+            "swiss_ball_side_crunch" => Some(73i64),
+            "weighted_swiss_ball_side_crunch" => Some(74i64),
+            "thoracic_crunches_on_foam_roller" => Some(75i64),
+            "weighted_thoracic_crunches_on_foam_roller" => Some(76i64),
+            "triceps_crunch" => Some(77i64),
+            "weighted_bicycle_crunch" => Some(78i64),
+            "weighted_crunch" => Some(79i64),
+            "weighted_swiss_ball_crunch" => Some(80i64),
+            "toes_to_bar" => Some(81i64),
+            "weighted_toes_to_bar" => Some(82i64),
+            "crunch" => Some(83i64),
+            "straight_leg_crunch_with_ball" => Some(84i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             CrunchExerciseName::BicycleCrunch => 0,
@@ -19958,6 +28303,98 @@ impl CrunchExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            CrunchExerciseName::BicycleCrunch => 0,
+            CrunchExerciseName::CableCrunch => 1,
+            CrunchExerciseName::CircularArmCrunch => 2,
+            CrunchExerciseName::CrossedArmsCrunch => 3,
+            CrunchExerciseName::WeightedCrossedArmsCrunch => 4,
+            CrunchExerciseName::CrossLegReverseCrunch => 5,
+            CrunchExerciseName::WeightedCrossLegReverseCrunch => 6,
+            CrunchExerciseName::CrunchChop => 7,
+            CrunchExerciseName::WeightedCrunchChop => 8,
+            CrunchExerciseName::DoubleCrunch => 9,
+            CrunchExerciseName::WeightedDoubleCrunch => 10,
+            CrunchExerciseName::ElbowToKneeCrunch => 11,
+            CrunchExerciseName::WeightedElbowToKneeCrunch => 12,
+            CrunchExerciseName::FlutterKicks => 13,
+            CrunchExerciseName::WeightedFlutterKicks => 14,
+            CrunchExerciseName::FoamRollerReverseCrunchOnBench => 15,
+            CrunchExerciseName::WeightedFoamRollerReverseCrunchOnBench => 16,
+            CrunchExerciseName::FoamRollerReverseCrunchWithDumbbell => 17,
+            CrunchExerciseName::FoamRollerReverseCrunchWithMedicineBall => 18,
+            CrunchExerciseName::FrogPress => 19,
+            CrunchExerciseName::HangingKneeRaiseObliqueCrunch => 20,
+            CrunchExerciseName::WeightedHangingKneeRaiseObliqueCrunch => 21,
+            CrunchExerciseName::HipCrossover => 22,
+            CrunchExerciseName::WeightedHipCrossover => 23,
+            CrunchExerciseName::HollowRock => 24,
+            CrunchExerciseName::WeightedHollowRock => 25,
+            CrunchExerciseName::InclineReverseCrunch => 26,
+            CrunchExerciseName::WeightedInclineReverseCrunch => 27,
+            CrunchExerciseName::KneelingCableCrunch => 28,
+            CrunchExerciseName::KneelingCrossCrunch => 29,
+            CrunchExerciseName::WeightedKneelingCrossCrunch => 30,
+            CrunchExerciseName::KneelingObliqueCableCrunch => 31,
+            CrunchExerciseName::KneesToElbow => 32,
+            CrunchExerciseName::LegExtensions => 33,
+            CrunchExerciseName::WeightedLegExtensions => 34,
+            CrunchExerciseName::LegLevers => 35,
+            CrunchExerciseName::McgillCurlUp => 36,
+            CrunchExerciseName::WeightedMcgillCurlUp => 37,
+            CrunchExerciseName::ModifiedPilatesRollUpWithBall => 38,
+            CrunchExerciseName::WeightedModifiedPilatesRollUpWithBall => 39,
+            CrunchExerciseName::PilatesCrunch => 40,
+            CrunchExerciseName::WeightedPilatesCrunch => 41,
+            CrunchExerciseName::PilatesRollUpWithBall => 42,
+            CrunchExerciseName::WeightedPilatesRollUpWithBall => 43,
+            CrunchExerciseName::RaisedLegsCrunch => 44,
+            CrunchExerciseName::WeightedRaisedLegsCrunch => 45,
+            CrunchExerciseName::ReverseCrunch => 46,
+            CrunchExerciseName::WeightedReverseCrunch => 47,
+            CrunchExerciseName::ReverseCrunchOnABench => 48,
+            CrunchExerciseName::WeightedReverseCrunchOnABench => 49,
+            CrunchExerciseName::ReverseCurlAndLift => 50,
+            CrunchExerciseName::WeightedReverseCurlAndLift => 51,
+            CrunchExerciseName::RotationalLift => 52,
+            CrunchExerciseName::WeightedRotationalLift => 53,
+            CrunchExerciseName::SeatedAlternatingReverseCrunch => 54,
+            CrunchExerciseName::WeightedSeatedAlternatingReverseCrunch => 55,
+            CrunchExerciseName::SeatedLegU => 56,
+            CrunchExerciseName::WeightedSeatedLegU => 57,
+            CrunchExerciseName::SideToSideCrunchAndWeave => 58,
+            CrunchExerciseName::WeightedSideToSideCrunchAndWeave => 59,
+            CrunchExerciseName::SingleLegReverseCrunch => 60,
+            CrunchExerciseName::WeightedSingleLegReverseCrunch => 61,
+            CrunchExerciseName::SkaterCrunchCross => 62,
+            CrunchExerciseName::WeightedSkaterCrunchCross => 63,
+            CrunchExerciseName::StandingCableCrunch => 64,
+            CrunchExerciseName::StandingSideCrunch => 65,
+            CrunchExerciseName::StepClimb => 66,
+            CrunchExerciseName::WeightedStepClimb => 67,
+            CrunchExerciseName::SwissBallCrunch => 68,
+            CrunchExerciseName::SwissBallReverseCrunch => 69,
+            CrunchExerciseName::WeightedSwissBallReverseCrunch => 70,
+            CrunchExerciseName::SwissBallRussianTwist => 71,
+            CrunchExerciseName::WeightedSwissBallRussianTwist => 72,
+            CrunchExerciseName::SwissBallSideCrunch => 73,
+            CrunchExerciseName::WeightedSwissBallSideCrunch => 74,
+            CrunchExerciseName::ThoracicCrunchesOnFoamRoller => 75,
+            CrunchExerciseName::WeightedThoracicCrunchesOnFoamRoller => 76,
+            CrunchExerciseName::TricepsCrunch => 77,
+            CrunchExerciseName::WeightedBicycleCrunch => 78,
+            CrunchExerciseName::WeightedCrunch => 79,
+            CrunchExerciseName::WeightedSwissBallCrunch => 80,
+            CrunchExerciseName::ToesToBar => 81,
+            CrunchExerciseName::WeightedToesToBar => 82,
+            CrunchExerciseName::Crunch => 83,
+            CrunchExerciseName::StraightLegCrunchWithBall => 84,
+            CrunchExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for CrunchExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -20219,6 +28656,16 @@ impl Serialize for CrunchExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for CrunchExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::CrunchExerciseName, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum CurlExerciseName {
     AlternatingDumbbellBicepsCurl,
@@ -20317,7 +28764,110 @@ impl CurlExerciseName {
             _ => false,
         }
     }
-    pub fn as_u16(self) -> u16 {
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "alternating_dumbbell_biceps_curl" => Some(0i64),
+            "alternating_dumbbell_biceps_curl_on_swiss_ball" => Some(1i64),
+            "alternating_incline_dumbbell_biceps_curl" => Some(2i64),
+            "barbell_biceps_curl" => Some(3i64),
+            "barbell_reverse_wrist_curl" => Some(4i64),
+            "barbell_wrist_curl" => Some(5i64),
+            "behind_the_back_barbell_reverse_wrist_curl" => Some(6i64),
+            "behind_the_back_one_arm_cable_curl" => Some(7i64),
+            "cable_biceps_curl" => Some(8i64),
+            "cable_hammer_curl" => Some(9i64),
+            "cheating_barbell_biceps_curl" => Some(10i64),
+            "close_grip_ez_bar_biceps_curl" => Some(11i64),
+            "cross_body_dumbbell_hammer_curl" => Some(12i64),
+            "dead_hang_biceps_curl" => Some(13i64),
+            "decline_hammer_curl" => Some(14i64),
+            "dumbbell_biceps_curl_with_static_hold" => Some(15i64),
+            "dumbbell_hammer_curl" => Some(16i64),
+            "dumbbell_reverse_wrist_curl" => Some(17i64),
+            "dumbbell_wrist_curl" => Some(18i64),
+            "ez_bar_preacher_curl" => Some(19i64),
+            "forward_bend_biceps_curl" => Some(20i64),
+            "hammer_curl_to_press" => Some(21i64),
+            "incline_dumbbell_biceps_curl" => Some(22i64),
+            "incline_offset_thumb_dumbbell_curl" => Some(23i64),
+            "kettlebell_biceps_curl" => Some(24i64),
+            "lying_concentration_cable_curl" => Some(25i64),
+            "one_arm_preacher_curl" => Some(26i64),
+            "plate_pinch_curl" => Some(27i64),
+            "preacher_curl_with_cable" => Some(28i64),
+            "reverse_ez_bar_curl" => Some(29i64),
+            "reverse_grip_wrist_curl" => Some(30i64),
+            "reverse_grip_barbell_biceps_curl" => Some(31i64),
+            "seated_alternating_dumbbell_biceps_curl" => Some(32i64),
+            "seated_dumbbell_biceps_curl" => Some(33i64),
+            "seated_reverse_dumbbell_curl" => Some(34i64),
+            "split_stance_offset_pinky_dumbbell_curl" => Some(35i64),
+            "standing_alternating_dumbbell_curls" => Some(36i64),
+            "standing_dumbbell_biceps_curl" => Some(37i64),
+            "standing_ez_bar_biceps_curl" => Some(38i64),
+            "static_curl" => Some(39i64),
+            "swiss_ball_dumbbell_overhead_triceps_extension" => Some(40i64),
+            "swiss_ball_ez_bar_preacher_curl" => Some(41i64),
+            "twisting_standing_dumbbell_biceps_curl" => Some(42i64),
+            "wide_grip_ez_bar_biceps_curl" => Some(43i64),
+            _ => None,
+        }
+    }
+    pub fn as_u16(self) -> u16 {
+        match self {
+            CurlExerciseName::AlternatingDumbbellBicepsCurl => 0,
+            CurlExerciseName::AlternatingDumbbellBicepsCurlOnSwissBall => 1,
+            CurlExerciseName::AlternatingInclineDumbbellBicepsCurl => 2,
+            CurlExerciseName::BarbellBicepsCurl => 3,
+            CurlExerciseName::BarbellReverseWristCurl => 4,
+            CurlExerciseName::BarbellWristCurl => 5,
+            CurlExerciseName::BehindTheBackBarbellReverseWristCurl => 6,
+            CurlExerciseName::BehindTheBackOneArmCableCurl => 7,
+            CurlExerciseName::CableBicepsCurl => 8,
+            CurlExerciseName::CableHammerCurl => 9,
+            CurlExerciseName::CheatingBarbellBicepsCurl => 10,
+            CurlExerciseName::CloseGripEzBarBicepsCurl => 11,
+            CurlExerciseName::CrossBodyDumbbellHammerCurl => 12,
+            CurlExerciseName::DeadHangBicepsCurl => 13,
+            CurlExerciseName::DeclineHammerCurl => 14,
+            CurlExerciseName::DumbbellBicepsCurlWithStaticHold => 15,
+            CurlExerciseName::DumbbellHammerCurl => 16,
+            CurlExerciseName::DumbbellReverseWristCurl => 17,
+            CurlExerciseName::DumbbellWristCurl => 18,
+            CurlExerciseName::EzBarPreacherCurl => 19,
+            CurlExerciseName::ForwardBendBicepsCurl => 20,
+            CurlExerciseName::HammerCurlToPress => 21,
+            CurlExerciseName::InclineDumbbellBicepsCurl => 22,
+            CurlExerciseName::InclineOffsetThumbDumbbellCurl => 23,
+            CurlExerciseName::KettlebellBicepsCurl => 24,
+            CurlExerciseName::LyingConcentrationCableCurl => 25,
+            CurlExerciseName::OneArmPreacherCurl => 26,
+            CurlExerciseName::PlatePinchCurl => 27,
+            CurlExerciseName::PreacherCurlWithCable => 28,
+            CurlExerciseName::ReverseEzBarCurl => 29,
+            CurlExerciseName::ReverseGripWristCurl => 30,
+            CurlExerciseName::ReverseGripBarbellBicepsCurl => 31,
+            CurlExerciseName::SeatedAlternatingDumbbellBicepsCurl => 32,
+            CurlExerciseName::SeatedDumbbellBicepsCurl => 33,
+            CurlExerciseName::SeatedReverseDumbbellCurl => 34,
+            CurlExerciseName::SplitStanceOffsetPinkyDumbbellCurl => 35,
+            CurlExerciseName::StandingAlternatingDumbbellCurls => 36,
+            CurlExerciseName::StandingDumbbellBicepsCurl => 37,
+            CurlExerciseName::StandingEzBarBicepsCurl => 38,
+            CurlExerciseName::StaticCurl => 39,
+            CurlExerciseName::SwissBallDumbbellOverheadTricepsExtension => 40,
+            CurlExerciseName::SwissBallEzBarPreacherCurl => 41,
+            CurlExerciseName::TwistingStandingDumbbellBicepsCurl => 42,
+            CurlExerciseName::WideGripEzBarBicepsCurl => 43,
+            CurlExerciseName::Value(value) => value,
+        }
+    }
+    pub fn as_i64(self) -> i64 {
+        self.as_u16() as i64
+    }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
         match self {
             CurlExerciseName::AlternatingDumbbellBicepsCurl => 0,
             CurlExerciseName::AlternatingDumbbellBicepsCurlOnSwissBall => 1,
@@ -20363,12 +28913,9 @@ impl CurlExerciseName {
             CurlExerciseName::SwissBallEzBarPreacherCurl => 41,
             CurlExerciseName::TwistingStandingDumbbellBicepsCurl => 42,
             CurlExerciseName::WideGripEzBarBicepsCurl => 43,
-            CurlExerciseName::Value(value) => value,
+            CurlExerciseName::Value(value) => value as i64,
         }
     }
-    pub fn as_i64(self) -> i64 {
-        self.as_u16() as i64
-    }
 }
 impl fmt::Display for CurlExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -20530,6 +29077,15 @@ impl Serialize for CurlExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for CurlExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::CurlExerciseName, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DeadliftExerciseName {
     BarbellDeadlift,
@@ -20578,6 +29134,30 @@ impl DeadliftExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "barbell_deadlift" => Some(0i64),
+            "barbell_straight_leg_deadlift" => Some(1i64),
+            "dumbbell_deadlift" => Some(2i64),
+            "dumbbell_single_leg_deadlift_to_row" => Some(3i64),
+            "dumbbell_straight_leg_deadlift" => Some(4i64),
+            "kettlebell_floor_to_shelf" => Some(5i64),
+            "one_arm_one_leg_deadlift" => Some(6i64),
+            "rack_pull" => Some(7i64),
+            "rotational_dumbbell_straight_leg_deadlift" => Some(8i64),
+            "single_arm_deadlift" => Some(9i64),
+            "single_leg_barbell_deadlift" => Some(10i64),
+            "single_leg_barbell_straight_leg_deadlift" => Some(11i64),
+            "single_leg_deadlift_with_barbell" => Some(12i64),
+            "single_leg_rdl_circuit" => Some(13i64),
+            "single_leg_romanian_deadlift_with_dumbbell" => Some(14i64),
+            "sumo_deadlift" => Some(15i64),
+            "sumo_deadlift_high_pull" => Some(16i64),
+            "trap_bar_deadlift" => Some(17i64),
+            "wide_grip_barbell_deadlift" => Some(18i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             DeadliftExerciseName::BarbellDeadlift => 0,
@@ -20605,6 +29185,32 @@ impl DeadliftExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DeadliftExerciseName::BarbellDeadlift => 0,
+            DeadliftExerciseName::BarbellStraightLegDeadlift => 1,
+            DeadliftExerciseName::DumbbellDeadlift => 2,
+            DeadliftExerciseName::DumbbellSingleLegDeadliftToRow => 3,
+            DeadliftExerciseName::DumbbellStraightLegDeadlift => 4,
+            DeadliftExerciseName::KettlebellFloorToShelf => 5,
+            DeadliftExerciseName::OneArmOneLegDeadlift => 6,
+            DeadliftExerciseName::RackPull => 7,
+            DeadliftExerciseName::RotationalDumbbellStraightLegDeadlift => 8,
+            DeadliftExerciseName::SingleArmDeadlift => 9,
+            DeadliftExerciseName::SingleLegBarbellDeadlift => 10,
+            DeadliftExerciseName::SingleLegBarbellStraightLegDeadlift => 11,
+            DeadliftExerciseName::SingleLegDeadliftWithBarbell => 12,
+            DeadliftExerciseName::SingleLegRdlCircuit => 13,
+            DeadliftExerciseName::SingleLegRomanianDeadliftWithDumbbell => 14,
+            DeadliftExerciseName::SumoDeadlift => 15,
+            DeadliftExerciseName::SumoDeadliftHighPull => 16,
+            DeadliftExerciseName::TrapBarDeadlift => 17,
+            DeadliftExerciseName::WideGripBarbellDeadlift => 18,
+            DeadliftExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DeadliftExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -20692,6 +29298,16 @@ impl Serialize for DeadliftExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for DeadliftExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::DeadliftExerciseName, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum FlyeExerciseName {
     CableCrossover,
@@ -20722,6 +29338,21 @@ impl FlyeExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "cable_crossover" => Some(0i64),
+            "decline_dumbbell_flye" => Some(1i64),
+            "dumbbell_flye" => Some(2i64),
+            "incline_dumbbell_flye" => Some(3i64),
+            "kettlebell_flye" => Some(4i64),
+            "kneeling_rear_flye" => Some(5i64),
+            "single_arm_standing_cable_reverse_flye" => Some(6i64),
+            "swiss_ball_dumbbell_flye" => Some(7i64),
+            "arm_rotations" => Some(8i64),
+            "hug_a_tree" => Some(9i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             FlyeExerciseName::CableCrossover => 0,
@@ -20740,6 +29371,23 @@ impl FlyeExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            FlyeExerciseName::CableCrossover => 0,
+            FlyeExerciseName::DeclineDumbbellFlye => 1,
+            FlyeExerciseName::DumbbellFlye => 2,
+            FlyeExerciseName::InclineDumbbellFlye => 3,
+            FlyeExerciseName::KettlebellFlye => 4,
+            FlyeExerciseName::KneelingRearFlye => 5,
+            FlyeExerciseName::SingleArmStandingCableReverseFlye => 6,
+            FlyeExerciseName::SwissBallDumbbellFlye => 7,
+            FlyeExerciseName::ArmRotations => 8,
+            FlyeExerciseName::HugATree => 9,
+            FlyeExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for FlyeExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -20793,6 +29441,15 @@ impl Serialize for FlyeExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for FlyeExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::FlyeExerciseName, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum HipRaiseExerciseName {
     BarbellHipThrustOnFloor,
@@ -20905,6 +29562,61 @@ impl HipRaiseExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "barbell_hip_thrust_on_floor" => Some(0i64),
+            "barbell_hip_thrust_with_bench" => Some(1i64),
+            "bent_knee_swiss_ball_reverse_hip_raise" => Some(2i64),
+            "weighted_bent_knee_swiss_ball_reverse_hip_raise" => Some(3i64),
+            "bridge_with_leg_extension" => Some(4i64),
+            "weighted_bridge_with_leg_extension" => Some(5i64),
+            "clam_bridge" => Some(6i64),
+            "front_kick_tabletop" => Some(7i64),
+            "weighted_front_kick_tabletop" => Some(8i64),
+            "hip_extension_and_cross" => Some(9i64),
+            "weighted_hip_extension_and_cross" => Some(10i64),
+            "hip_raise" => Some(11i64),
+            "weighted_hip_raise" => Some(12i64),
+            "hip_raise_with_feet_on_swiss_ball" => Some(13i64),
+            "weighted_hip_raise_with_feet_on_swiss_ball" => Some(14i64),
+            "hip_raise_with_head_on_bosu_ball" => Some(15i64),
+            "weighted_hip_raise_with_head_on_bosu_ball" => Some(16i64),
+            "hip_raise_with_head_on_swiss_ball" => Some(17i64),
+            "weighted_hip_raise_with_head_on_swiss_ball" => Some(18i64),
+            "hip_raise_with_knee_squeeze" => Some(19i64),
+            "weighted_hip_raise_with_knee_squeeze" => Some(20i64),
+            "incline_rear_leg_extension" => Some(21i64),
+            "weighted_incline_rear_leg_extension" => Some(22i64),
+            "kettlebell_swing" => Some(23i64),
+            "marching_hip_raise" => Some(24i64),
+            "weighted_marching_hip_raise" => Some(25i64),
+            "marching_hip_raise_with_feet_on_a_swiss_ball" => Some(26i64),
+            "weighted_marching_hip_raise_with_feet_on_a_swiss_ball" => Some(27i64),
+            "reverse_hip_raise" => Some(28i64),
+            "weighted_reverse_hip_raise" => Some(29i64),
+            "single_leg_hip_raise" => Some(30i64),
+            "weighted_single_leg_hip_raise" => Some(31i64),
+            "single_leg_hip_raise_with_foot_on_bench" => Some(32i64),
+            "weighted_single_leg_hip_raise_with_foot_on_bench" => Some(33i64),
+            "single_leg_hip_raise_with_foot_on_bosu_ball" => Some(34i64),
+            "weighted_single_leg_hip_raise_with_foot_on_bosu_ball" => Some(35i64),
+            "single_leg_hip_raise_with_foot_on_foam_roller" => Some(36i64),
+            "weighted_single_leg_hip_raise_with_foot_on_foam_roller" => Some(37i64),
+            "single_leg_hip_raise_with_foot_on_medicine_ball" => Some(38i64),
+            "weighted_single_leg_hip_raise_with_foot_on_medicine_ball" => Some(39i64),
+            "single_leg_hip_raise_with_head_on_bosu_ball" => Some(40i64),
+            "weighted_single_leg_hip_raise_with_head_on_bosu_ball" => Some(41i64),
+            "weighted_clam_bridge" => Some(42i64),
+            "single_leg_swiss_ball_hip_raise_and_leg_curl" => Some(43i64),
+            "clams" => Some(44i64),
+            "inner_thigh_circles" => Some(45i64),
+            "inner_thigh_side_lift" => Some(46i64),
+            "leg_circles" => Some(47i64),
+            "leg_lift" => Some(48i64),
+            "leg_lift_in_external_rotation" => Some(49i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             HipRaiseExerciseName::BarbellHipThrustOnFloor => 0,
@@ -20963,6 +29675,63 @@ impl HipRaiseExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            HipRaiseExerciseName::BarbellHipThrustOnFloor => 0,
+            HipRaiseExerciseName::BarbellHipThrustWithBench => 1,
+            HipRaiseExerciseName::BentKneeSwissBallReverseHipRaise => 2,
+            HipRaiseExerciseName::WeightedBentKneeSwissBallReverseHipRaise => 3,
+            HipRaiseExerciseName::BridgeWithLegExtension => 4,
+            HipRaiseExerciseName::WeightedBridgeWithLegExtension => 5,
+            HipRaiseExerciseName::ClamBridge => 6,
+            HipRaiseExerciseName::FrontKickTabletop => 7,
+            HipRaiseExerciseName::WeightedFrontKickTabletop => 8,
+            HipRaiseExerciseName::HipExtensionAndCross => 9,
+            HipRaiseExerciseName::WeightedHipExtensionAndCross => 10,
+            HipRaiseExerciseName::HipRaise => 11,
+            HipRaiseExerciseName::WeightedHipRaise => 12,
+            HipRaiseExerciseName::HipRaiseWithFeetOnSwissBall => 13,
+            HipRaiseExerciseName::WeightedHipRaiseWithFeetOnSwissBall => 14,
+            HipRaiseExerciseName::HipRaiseWithHeadOnBosuBall => 15,
+            HipRaiseExerciseName::WeightedHipRaiseWithHeadOnBosuBall => 16,
+            HipRaiseExerciseName::HipRaiseWithHeadOnSwissBall => 17,
+            HipRaiseExerciseName::WeightedHipRaiseWithHeadOnSwissBall => 18,
+            HipRaiseExerciseName::HipRaiseWithKneeSqueeze => 19,
+            HipRaiseExerciseName::WeightedHipRaiseWithKneeSqueeze => 20,
+            HipRaiseExerciseName::InclineRearLegExtension => 21,
+            HipRaiseExerciseName::WeightedInclineRearLegExtension => 22,
+            HipRaiseExerciseName::KettlebellSwing => 23,
+            HipRaiseExerciseName::MarchingHipRaise => 24,
+            HipRaiseExerciseName::WeightedMarchingHipRaise => 25,
+            HipRaiseExerciseName::MarchingHipRaiseWithFeetOnASwissBall => 26,
+            HipRaiseExerciseName::WeightedMarchingHipRaiseWithFeetOnASwissBall => 27,
+            HipRaiseExerciseName::ReverseHipRaise => 28,
+            HipRaiseExerciseName::WeightedReverseHipRaise => 29,
+            HipRaiseExerciseName::SingleLegHipRaise => 30,
+            HipRaiseExerciseName::WeightedSingleLegHipRaise => 31,
+            HipRaiseExerciseName::SingleLegHipRaiseWithFootOnBench => 32,
+            HipRaiseExerciseName::WeightedSingleLegHipRaiseWithFootOnBench => 33,
+            HipRaiseExerciseName::SingleLegHipRaiseWithFootOnBosuBall => 34,
+            HipRaiseExerciseName::WeightedSingleLegHipRaiseWithFootOnBosuBall => 35,
+            HipRaiseExerciseName::SingleLegHipRaiseWithFootOnFoamRoller => 36,
+            HipRaiseExerciseName::WeightedSingleLegHipRaiseWithFootOnFoamRoller => 37,
+            HipRaiseExerciseName::SingleLegHipRaiseWithFootOnMedicineBall => 38,
+            HipRaiseExerciseName::WeightedSingleLegHipRaiseWithFootOnMedicineBall => 39,
+            HipRaiseExerciseName::SingleLegHipRaiseWithHeadOnBosuBall => 40,
+            HipRaiseExerciseName::WeightedSingleLegHipRaiseWithHeadOnBosuBall => 41,
+            HipRaiseExerciseName::WeightedClamBridge => 42,
+            HipRaiseExerciseName::SingleLegSwissBallHipRaiseAndLegCurl => 43,
+            HipRaiseExerciseName::Clams => 44,
+            HipRaiseExerciseName::InnerThighCircles => 45,
+            HipRaiseExerciseName::InnerThighSideLift => 46,
+            HipRaiseExerciseName::LegCircles => 47,
+            HipRaiseExerciseName::LegLift => 48,
+            HipRaiseExerciseName::LegLiftInExternalRotation => 49,
+            HipRaiseExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for HipRaiseExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -21163,6 +29932,16 @@ impl Serialize for HipRaiseExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for HipRaiseExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::HipRaiseExerciseName, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum HipStabilityExerciseName {
     BandSideLyingLegRaise,
@@ -21241,6 +30020,45 @@ impl HipStabilityExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "band_side_lying_leg_raise" => Some(0i64),
+            "dead_bug" => Some(1i64),
+            "weighted_dead_bug" => Some(2i64),
+            "external_hip_raise" => Some(3i64),
+            "weighted_external_hip_raise" => Some(4i64),
+            "fire_hydrant_kicks" => Some(5i64),
+            "weighted_fire_hydrant_kicks" => Some(6i64),
+            "hip_circles" => Some(7i64),
+            "weighted_hip_circles" => Some(8i64),
+            "inner_thigh_lift" => Some(9i64),
+            "weighted_inner_thigh_lift" => Some(10i64),
+            "lateral_walks_with_band_at_ankles" => Some(11i64),
+            "pretzel_side_kick" => Some(12i64),
+            "weighted_pretzel_side_kick" => Some(13i64),
+            "prone_hip_internal_rotation" => Some(14i64),
+            "weighted_prone_hip_internal_rotation" => Some(15i64),
+            "quadruped" => Some(16i64),
+            "quadruped_hip_extension" => Some(17i64),
+            "weighted_quadruped_hip_extension" => Some(18i64),
+            "quadruped_with_leg_lift" => Some(19i64),
+            "weighted_quadruped_with_leg_lift" => Some(20i64),
+            "side_lying_leg_raise" => Some(21i64),
+            "weighted_side_lying_leg_raise" => Some(22i64),
+            "sliding_hip_adduction" => Some(23i64),
+            "weighted_sliding_hip_adduction" => Some(24i64),
+            "standing_adduction" => Some(25i64),
+            "weighted_standing_adduction" => Some(26i64),
+            "standing_cable_hip_abduction" => Some(27i64),
+            "standing_hip_abduction" => Some(28i64),
+            "weighted_standing_hip_abduction" => Some(29i64),
+            "standing_rear_leg_raise" => Some(30i64),
+            "weighted_standing_rear_leg_raise" => Some(31i64),
+            "supine_hip_internal_rotation" => Some(32i64),
+            "weighted_supine_hip_internal_rotation" => Some(33i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             HipStabilityExerciseName::BandSideLyingLegRaise => 0,
@@ -21283,6 +30101,47 @@ impl HipStabilityExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            HipStabilityExerciseName::BandSideLyingLegRaise => 0,
+            HipStabilityExerciseName::DeadBug => 1,
+            HipStabilityExerciseName::WeightedDeadBug => 2,
+            HipStabilityExerciseName::ExternalHipRaise => 3,
+            HipStabilityExerciseName::WeightedExternalHipRaise => 4,
+            HipStabilityExerciseName::FireHydrantKicks => 5,
+            HipStabilityExerciseName::WeightedFireHydrantKicks => 6,
+            HipStabilityExerciseName::HipCircles => 7,
+            HipStabilityExerciseName::WeightedHipCircles => 8,
+            HipStabilityExerciseName::InnerThighLift => 9,
+            HipStabilityExerciseName::WeightedInnerThighLift => 10,
+            HipStabilityExerciseName::LateralWalksWithBandAtAnkles => 11,
+            HipStabilityExerciseName::PretzelSideKick => 12,
+            HipStabilityExerciseName::WeightedPretzelSideKick => 13,
+            HipStabilityExerciseName::ProneHipInternalRotation => 14,
+            HipStabilityExerciseName::WeightedProneHipInternalRotation => 15,
+            HipStabilityExerciseName::Quadruped => 16,
+            HipStabilityExerciseName::QuadrupedHipExtension => 17,
+            HipStabilityExerciseName::WeightedQuadrupedHipExtension => 18,
+            HipStabilityExerciseName::QuadrupedWithLegLift => 19,
+            HipStabilityExerciseName::WeightedQuadrupedWithLegLift => 20,
+            HipStabilityExerciseName::SideLyingLegRaise => 21,
+            HipStabilityExerciseName::WeightedSideLyingLegRaise => 22,
+            HipStabilityExerciseName::SlidingHipAdduction => 23,
+            HipStabilityExerciseName::WeightedSlidingHipAdduction => 24,
+            HipStabilityExerciseName::StandingAdduction => 25,
+            HipStabilityExerciseName::WeightedStandingAdduction => 26,
+            HipStabilityExerciseName::StandingCableHipAbduction => 27,
+            HipStabilityExerciseName::StandingHipAbduction => 28,
+            HipStabilityExerciseName::WeightedStandingHipAbduction => 29,
+            HipStabilityExerciseName::StandingRearLegRaise => 30,
+            HipStabilityExerciseName::WeightedStandingRearLegRaise => 31,
+            HipStabilityExerciseName::SupineHipInternalRotation => 32,
+            HipStabilityExerciseName::WeightedSupineHipInternalRotation => 33,
+            HipStabilityExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for HipStabilityExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -21418,6 +30277,20 @@ impl Serialize for HipStabilityExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for HipStabilityExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(
+            deserializer,
+            FieldDataType::HipStabilityExerciseName,
+            invalid,
+        )
+        .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum HipSwingExerciseName {
     SingleArmKettlebellSwing,
@@ -21434,6 +30307,14 @@ impl HipSwingExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "single_arm_kettlebell_swing" => Some(0i64),
+            "single_arm_dumbbell_swing" => Some(1i64),
+            "step_out_swing" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             HipSwingExerciseName::SingleArmKettlebellSwing => 0,
@@ -21445,6 +30326,16 @@ impl HipSwingExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            HipSwingExerciseName::SingleArmKettlebellSwing => 0,
+            HipSwingExerciseName::SingleArmDumbbellSwing => 1,
+            HipSwingExerciseName::StepOutSwing => 2,
+            HipSwingExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for HipSwingExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -21484,6 +30375,16 @@ impl Serialize for HipSwingExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for HipSwingExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::HipSwingExerciseName, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum HyperextensionExerciseName {
     BackExtensionWithOppositeArmAndLegReach,
@@ -21575,6 +30476,51 @@ impl HyperextensionExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "back_extension_with_opposite_arm_and_leg_reach" => Some(0i64),
+            "weighted_back_extension_with_opposite_arm_and_leg_reach" => Some(1i64),
+            "base_rotations" => Some(2i64),
+            "weighted_base_rotations" => Some(3i64),
+            "bent_knee_reverse_hyperextension" => Some(4i64),
+            "weighted_bent_knee_reverse_hyperextension" => Some(5i64),
+            "hollow_hold_and_roll" => Some(6i64),
+            "weighted_hollow_hold_and_roll" => Some(7i64),
+            "kicks" => Some(8i64),
+            "weighted_kicks" => Some(9i64),
+            "knee_raises" => Some(10i64),
+            "weighted_knee_raises" => Some(11i64),
+            "kneeling_superman" => Some(12i64),
+            "weighted_kneeling_superman" => Some(13i64),
+            "lat_pull_down_with_row" => Some(14i64),
+            "medicine_ball_deadlift_to_reach" => Some(15i64),
+            "one_arm_one_leg_row" => Some(16i64),
+            "one_arm_row_with_band" => Some(17i64),
+            "overhead_lunge_with_medicine_ball" => Some(18i64),
+            "plank_knee_tucks" => Some(19i64),
+            "weighted_plank_knee_tucks" => Some(20i64),
+            "side_step" => Some(21i64),
+            "weighted_side_step" => Some(22i64),
+            "single_leg_back_extension" => Some(23i64),
+            "weighted_single_leg_back_extension" => Some(24i64),
+            "spine_extension" => Some(25i64),
+            "weighted_spine_extension" => Some(26i64),
+            "static_back_extension" => Some(27i64),
+            "weighted_static_back_extension" => Some(28i64),
+            "superman_from_floor" => Some(29i64),
+            "weighted_superman_from_floor" => Some(30i64),
+            "swiss_ball_back_extension" => Some(31i64),
+            "weighted_swiss_ball_back_extension" => Some(32i64),
+            "swiss_ball_hyperextension" => Some(33i64),
+            "weighted_swiss_ball_hyperextension" => Some(34i64),
+            "swiss_ball_opposite_arm_and_leg_lift" => Some(35i64),
+            "weighted_swiss_ball_opposite_arm_and_leg_lift" => Some(36i64),
+            "superman_on_swiss_ball" => Some(37i64),
+            "cobra" => Some(38i64),
+            "supine_floor_barre" => Some(39i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             HyperextensionExerciseName::BackExtensionWithOppositeArmAndLegReach => 0,
@@ -21623,6 +30569,53 @@ impl HyperextensionExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            HyperextensionExerciseName::BackExtensionWithOppositeArmAndLegReach => 0,
+            HyperextensionExerciseName::WeightedBackExtensionWithOppositeArmAndLegReach => 1,
+            HyperextensionExerciseName::BaseRotations => 2,
+            HyperextensionExerciseName::WeightedBaseRotations => 3,
+            HyperextensionExerciseName::BentKneeReverseHyperextension => 4,
+            HyperextensionExerciseName::WeightedBentKneeReverseHyperextension => 5,
+            HyperextensionExerciseName::HollowHoldAndRoll => 6,
+            HyperextensionExerciseName::WeightedHollowHoldAndRoll => 7,
+            HyperextensionExerciseName::Kicks => 8,
+            HyperextensionExerciseName::WeightedKicks => 9,
+            HyperextensionExerciseName::KneeRaises => 10,
+            HyperextensionExerciseName::WeightedKneeRaises => 11,
+            HyperextensionExerciseName::KneelingSuperman => 12,
+            HyperextensionExerciseName::WeightedKneelingSuperman => 13,
+            HyperextensionExerciseName::LatPullDownWithRow => 14,
+            HyperextensionExerciseName::MedicineBallDeadliftToReach => 15,
+            HyperextensionExerciseName::OneArmOneLegRow => 16,
+            HyperextensionExerciseName::OneArmRowWithBand => 17,
+            HyperextensionExerciseName::OverheadLungeWithMedicineBall => 18,
+            HyperextensionExerciseName::PlankKneeTucks => 19,
+            HyperextensionExerciseName::WeightedPlankKneeTucks => 20,
+            HyperextensionExerciseName::SideStep => 21,
+            HyperextensionExerciseName::WeightedSideStep => 22,
+            HyperextensionExerciseName::SingleLegBackExtension => 23,
+            HyperextensionExerciseName::WeightedSingleLegBackExtension => 24,
+            HyperextensionExerciseName::SpineExtension => 25,
+            HyperextensionExerciseName::WeightedSpineExtension => 26,
+            HyperextensionExerciseName::StaticBackExtension => 27,
+            HyperextensionExerciseName::WeightedStaticBackExtension => 28,
+            HyperextensionExerciseName::SupermanFromFloor => 29,
+            HyperextensionExerciseName::WeightedSupermanFromFloor => 30,
+            HyperextensionExerciseName::SwissBallBackExtension => 31,
+            HyperextensionExerciseName::WeightedSwissBallBackExtension => 32,
+            HyperextensionExerciseName::SwissBallHyperextension => 33,
+            HyperextensionExerciseName::WeightedSwissBallHyperextension => 34,
+            HyperextensionExerciseName::SwissBallOppositeArmAndLegLift => 35,
+            HyperextensionExerciseName::WeightedSwissBallOppositeArmAndLegLift => 36,
+            HyperextensionExerciseName::SupermanOnSwissBall => 37,
+            HyperextensionExerciseName::Cobra => 38,
+            HyperextensionExerciseName::SupineFloorBarre => 39,
+            HyperextensionExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for HyperextensionExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -21776,6 +30769,20 @@ impl Serialize for HyperextensionExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for HyperextensionExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(
+            deserializer,
+            FieldDataType::HyperextensionExerciseName,
+            invalid,
+        )
+        .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LateralRaiseExerciseName {
     Name45DegreeCableExternalRotation,
@@ -21854,7 +30861,90 @@ impl LateralRaiseExerciseName {
             _ => false,
         }
     }
-    pub fn as_u16(self) -> u16 {
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "45_degree_cable_external_rotation" => Some(0i64),
+            "alternating_lateral_raise_with_static_hold" => Some(1i64),
+            "bar_muscle_up" => Some(2i64),
+            "bent_over_lateral_raise" => Some(3i64),
+            "cable_diagonal_raise" => Some(4i64),
+            "cable_front_raise" => Some(5i64),
+            "calorie_row" => Some(6i64),
+            "combo_shoulder_raise" => Some(7i64),
+            "dumbbell_diagonal_raise" => Some(8i64),
+            "dumbbell_v_raise" => Some(9i64),
+            "front_raise" => Some(10i64),
+            "leaning_dumbbell_lateral_raise" => Some(11i64),
+            "lying_dumbbell_raise" => Some(12i64),
+            "muscle_up" => Some(13i64),
+            "one_arm_cable_lateral_raise" => Some(14i64),
+            "overhand_grip_rear_lateral_raise" => Some(15i64),
+            "plate_raises" => Some(16i64),
+            "ring_dip" => Some(17i64),
+            "weighted_ring_dip" => Some(18i64),
+            "ring_muscle_up" => Some(19i64),
+            "weighted_ring_muscle_up" => Some(20i64),
+            "rope_climb" => Some(21i64),
+            "weighted_rope_climb" => Some(22i64),
+            "scaption" => Some(23i64),
+            "seated_lateral_raise" => Some(24i64),
+            "seated_rear_lateral_raise" => Some(25i64),
+            "side_lying_lateral_raise" => Some(26i64),
+            "standing_lift" => Some(27i64),
+            "suspended_row" => Some(28i64),
+            "underhand_grip_rear_lateral_raise" => Some(29i64),
+            "wall_slide" => Some(30i64),
+            "weighted_wall_slide" => Some(31i64),
+            "arm_circles" => Some(32i64),
+            "shaving_the_head" => Some(33i64),
+            _ => None,
+        }
+    }
+    pub fn as_u16(self) -> u16 {
+        match self {
+            LateralRaiseExerciseName::Name45DegreeCableExternalRotation => 0,
+            LateralRaiseExerciseName::AlternatingLateralRaiseWithStaticHold => 1,
+            LateralRaiseExerciseName::BarMuscleUp => 2,
+            LateralRaiseExerciseName::BentOverLateralRaise => 3,
+            LateralRaiseExerciseName::CableDiagonalRaise => 4,
+            LateralRaiseExerciseName::CableFrontRaise => 5,
+            LateralRaiseExerciseName::CalorieRow => 6,
+            LateralRaiseExerciseName::ComboShoulderRaise => 7,
+            LateralRaiseExerciseName::DumbbellDiagonalRaise => 8,
+            LateralRaiseExerciseName::DumbbellVRaise => 9,
+            LateralRaiseExerciseName::FrontRaise => 10,
+            LateralRaiseExerciseName::LeaningDumbbellLateralRaise => 11,
+            LateralRaiseExerciseName::LyingDumbbellRaise => 12,
+            LateralRaiseExerciseName::MuscleUp => 13,
+            LateralRaiseExerciseName::OneArmCableLateralRaise => 14,
+            LateralRaiseExerciseName::OverhandGripRearLateralRaise => 15,
+            LateralRaiseExerciseName::PlateRaises => 16,
+            LateralRaiseExerciseName::RingDip => 17,
+            LateralRaiseExerciseName::WeightedRingDip => 18,
+            LateralRaiseExerciseName::RingMuscleUp => 19,
+            LateralRaiseExerciseName::WeightedRingMuscleUp => 20,
+            LateralRaiseExerciseName::RopeClimb => 21,
+            LateralRaiseExerciseName::WeightedRopeClimb => 22,
+            LateralRaiseExerciseName::Scaption => 23,
+            LateralRaiseExerciseName::SeatedLateralRaise => 24,
+            LateralRaiseExerciseName::SeatedRearLateralRaise => 25,
+            LateralRaiseExerciseName::SideLyingLateralRaise => 26,
+            LateralRaiseExerciseName::StandingLift => 27,
+            LateralRaiseExerciseName::SuspendedRow => 28,
+            LateralRaiseExerciseName::UnderhandGripRearLateralRaise => 29,
+            LateralRaiseExerciseName::WallSlide => 30,
+            LateralRaiseExerciseName::WeightedWallSlide => 31,
+            LateralRaiseExerciseName::ArmCircles => 32,
+            LateralRaiseExerciseName::ShavingTheHead => 33,
+            LateralRaiseExerciseName::Value(value) => value,
+        }
+    }
+    pub fn as_i64(self) -> i64 {
+        self.as_u16() as i64
+    }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
         match self {
             LateralRaiseExerciseName::Name45DegreeCableExternalRotation => 0,
             LateralRaiseExerciseName::AlternatingLateralRaiseWithStaticHold => 1,
@@ -21890,12 +30980,9 @@ impl LateralRaiseExerciseName {
             LateralRaiseExerciseName::WeightedWallSlide => 31,
             LateralRaiseExerciseName::ArmCircles => 32,
             LateralRaiseExerciseName::ShavingTheHead => 33,
-            LateralRaiseExerciseName::Value(value) => value,
+            LateralRaiseExerciseName::Value(value) => value as i64,
         }
     }
-    pub fn as_i64(self) -> i64 {
-        self.as_u16() as i64
-    }
 }
 impl fmt::Display for LateralRaiseExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -22011,6 +31098,20 @@ impl Serialize for LateralRaiseExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for LateralRaiseExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(
+            deserializer,
+            FieldDataType::LateralRaiseExerciseName,
+            invalid,
+        )
+        .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LegCurlExerciseName {
     LegCurl,
@@ -22045,6 +31146,23 @@ impl LegCurlExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "leg_curl" => Some(0i64),
+            "weighted_leg_curl" => Some(1i64),
+            "good_morning" => Some(2i64),
+            "seated_barbell_good_morning" => Some(3i64),
+            "single_leg_barbell_good_morning" => Some(4i64),
+            "single_leg_sliding_leg_curl" => Some(5i64),
+            "sliding_leg_curl" => Some(6i64),
+            "split_barbell_good_morning" => Some(7i64),
+            "split_stance_extension" => Some(8i64),
+            "staggered_stance_good_morning" => Some(9i64),
+            "swiss_ball_hip_raise_and_leg_curl" => Some(10i64),
+            "zercher_good_morning" => Some(11i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             LegCurlExerciseName::LegCurl => 0,
@@ -22065,6 +31183,25 @@ impl LegCurlExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LegCurlExerciseName::LegCurl => 0,
+            LegCurlExerciseName::WeightedLegCurl => 1,
+            LegCurlExerciseName::GoodMorning => 2,
+            LegCurlExerciseName::SeatedBarbellGoodMorning => 3,
+            LegCurlExerciseName::SingleLegBarbellGoodMorning => 4,
+            LegCurlExerciseName::SingleLegSlidingLegCurl => 5,
+            LegCurlExerciseName::SlidingLegCurl => 6,
+            LegCurlExerciseName::SplitBarbellGoodMorning => 7,
+            LegCurlExerciseName::SplitStanceExtension => 8,
+            LegCurlExerciseName::StaggeredStanceGoodMorning => 9,
+            LegCurlExerciseName::SwissBallHipRaiseAndLegCurl => 10,
+            LegCurlExerciseName::ZercherGoodMorning => 11,
+            LegCurlExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LegCurlExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -22130,6 +31267,16 @@ impl Serialize for LegCurlExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for LegCurlExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::LegCurlExerciseName, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LegRaiseExerciseName {
     HangingKneeRaise,
@@ -22184,6 +31331,33 @@ impl LegRaiseExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "hanging_knee_raise" => Some(0i64),
+            "hanging_leg_raise" => Some(1i64),
+            "weighted_hanging_leg_raise" => Some(2i64),
+            "hanging_single_leg_raise" => Some(3i64),
+            "weighted_hanging_single_leg_raise" => Some(4i64),
+            "kettlebell_leg_raises" => Some(5i64),
+            "leg_lowering_drill" => Some(6i64),
+            "weighted_leg_lowering_drill" => Some(7i64),
+            "lying_straight_leg_raise" => Some(8i64),
+            "weighted_lying_straight_leg_raise" => Some(9i64),
+            "medicine_ball_leg_drops" => Some(10i64),
+            "quadruped_leg_raise" => Some(11i64),
+            "weighted_quadruped_leg_raise" => Some(12i64),
+            "reverse_leg_raise" => Some(13i64),
+            "weighted_reverse_leg_raise" => Some(14i64),
+            "reverse_leg_raise_on_swiss_ball" => Some(15i64),
+            "weighted_reverse_leg_raise_on_swiss_ball" => Some(16i64),
+            "single_leg_lowering_drill" => Some(17i64),
+            "weighted_single_leg_lowering_drill" => Some(18i64),
+            "weighted_hanging_knee_raise" => Some(19i64),
+            "lateral_stepover" => Some(20i64),
+            "weighted_lateral_stepover" => Some(21i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             LegRaiseExerciseName::HangingKneeRaise => 0,
@@ -22214,6 +31388,35 @@ impl LegRaiseExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LegRaiseExerciseName::HangingKneeRaise => 0,
+            LegRaiseExerciseName::HangingLegRaise => 1,
+            LegRaiseExerciseName::WeightedHangingLegRaise => 2,
+            LegRaiseExerciseName::HangingSingleLegRaise => 3,
+            LegRaiseExerciseName::WeightedHangingSingleLegRaise => 4,
+            LegRaiseExerciseName::KettlebellLegRaises => 5,
+            LegRaiseExerciseName::LegLoweringDrill => 6,
+            LegRaiseExerciseName::WeightedLegLoweringDrill => 7,
+            LegRaiseExerciseName::LyingStraightLegRaise => 8,
+            LegRaiseExerciseName::WeightedLyingStraightLegRaise => 9,
+            LegRaiseExerciseName::MedicineBallLegDrops => 10,
+            LegRaiseExerciseName::QuadrupedLegRaise => 11,
+            LegRaiseExerciseName::WeightedQuadrupedLegRaise => 12,
+            LegRaiseExerciseName::ReverseLegRaise => 13,
+            LegRaiseExerciseName::WeightedReverseLegRaise => 14,
+            LegRaiseExerciseName::ReverseLegRaiseOnSwissBall => 15,
+            LegRaiseExerciseName::WeightedReverseLegRaiseOnSwissBall => 16,
+            LegRaiseExerciseName::SingleLegLoweringDrill => 17,
+            LegRaiseExerciseName::WeightedSingleLegLoweringDrill => 18,
+            LegRaiseExerciseName::WeightedHangingKneeRaise => 19,
+            LegRaiseExerciseName::LateralStepover => 20,
+            LegRaiseExerciseName::WeightedLateralStepover => 21,
+            LegRaiseExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LegRaiseExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -22309,6 +31512,16 @@ impl Serialize for LegRaiseExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for LegRaiseExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::LegRaiseExerciseName, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LungeExerciseName {
     OverheadLunge,
@@ -22481,6 +31694,92 @@ impl LungeExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "overhead_lunge" => Some(0i64),
+            "lunge_matrix" => Some(1i64),
+            "weighted_lunge_matrix" => Some(2i64),
+            "alternating_barbell_forward_lunge" => Some(3i64),
+            "alternating_dumbbell_lunge_with_reach" => Some(4i64),
+            "back_foot_elevated_dumbbell_split_squat" => Some(5i64),
+            "barbell_box_lunge" => Some(6i64),
+            "barbell_bulgarian_split_squat" => Some(7i64),
+            "barbell_crossover_lunge" => Some(8i64),
+            "barbell_front_split_squat" => Some(9i64),
+            "barbell_lunge" => Some(10i64),
+            "barbell_reverse_lunge" => Some(11i64),
+            "barbell_side_lunge" => Some(12i64),
+            "barbell_split_squat" => Some(13i64),
+            "core_control_rear_lunge" => Some(14i64),
+            "diagonal_lunge" => Some(15i64),
+            "drop_lunge" => Some(16i64),
+            "dumbbell_box_lunge" => Some(17i64),
+            "dumbbell_bulgarian_split_squat" => Some(18i64),
+            "dumbbell_crossover_lunge" => Some(19i64),
+            "dumbbell_diagonal_lunge" => Some(20i64),
+            "dumbbell_lunge" => Some(21i64),
+            "dumbbell_lunge_and_rotation" => Some(22i64),
+            "dumbbell_overhead_bulgarian_split_squat" => Some(23i64),
+            "dumbbell_reverse_lunge_to_high_knee_and_press" => Some(24i64),
+            "dumbbell_side_lunge" => Some(25i64),
+            "elevated_front_foot_barbell_split_squat" => Some(26i64),
+            "front_foot_elevated_dumbbell_split_squat" => Some(27i64),
+            "gunslinger_lunge" => Some(28i64),
+            "lawnmower_lunge" => Some(29i64),
+            "low_lunge_with_isometric_adduction" => Some(30i64),
+            "low_side_to_side_lunge" => Some(31i64),
+            "lunge" => Some(32i64),
+            "weighted_lunge" => Some(33i64),
+            "lunge_with_arm_reach" => Some(34i64),
+            "lunge_with_diagonal_reach" => Some(35i64),
+            "lunge_with_side_bend" => Some(36i64),
+            "offset_dumbbell_lunge" => Some(37i64),
+            "offset_dumbbell_reverse_lunge" => Some(38i64),
+            "overhead_bulgarian_split_squat" => Some(39i64),
+            "overhead_dumbbell_reverse_lunge" => Some(40i64),
+            "overhead_dumbbell_split_squat" => Some(41i64),
+            "overhead_lunge_with_rotation" => Some(42i64),
+            "reverse_barbell_box_lunge" => Some(43i64),
+            "reverse_box_lunge" => Some(44i64),
+            "reverse_dumbbell_box_lunge" => Some(45i64),
+            "reverse_dumbbell_crossover_lunge" => Some(46i64),
+            "reverse_dumbbell_diagonal_lunge" => Some(47i64),
+            "reverse_lunge_with_reach_back" => Some(48i64),
+            "weighted_reverse_lunge_with_reach_back" => Some(49i64),
+            "reverse_lunge_with_twist_and_overhead_reach" => Some(50i64),
+            "weighted_reverse_lunge_with_twist_and_overhead_reach" => Some(51i64),
+            "reverse_sliding_box_lunge" => Some(52i64),
+            "weighted_reverse_sliding_box_lunge" => Some(53i64),
+            "reverse_sliding_lunge" => Some(54i64),
+            "weighted_reverse_sliding_lunge" => Some(55i64),
+            "runners_lunge_to_balance" => Some(56i64),
+            "weighted_runners_lunge_to_balance" => Some(57i64),
+            "shifting_side_lunge" => Some(58i64),
+            "side_and_crossover_lunge" => Some(59i64),
+            "weighted_side_and_crossover_lunge" => Some(60i64),
+            "side_lunge" => Some(61i64),
+            "weighted_side_lunge" => Some(62i64),
+            "side_lunge_and_press" => Some(63i64),
+            "side_lunge_jump_off" => Some(64i64),
+            "side_lunge_sweep" => Some(65i64),
+            "weighted_side_lunge_sweep" => Some(66i64),
+            "side_lunge_to_crossover_tap" => Some(67i64),
+            "weighted_side_lunge_to_crossover_tap" => Some(68i64),
+            "side_to_side_lunge_chops" => Some(69i64),
+            "weighted_side_to_side_lunge_chops" => Some(70i64),
+            "siff_jump_lunge" => Some(71i64),
+            "weighted_siff_jump_lunge" => Some(72i64),
+            "single_arm_reverse_lunge_and_press" => Some(73i64),
+            "sliding_lateral_lunge" => Some(74i64),
+            "weighted_sliding_lateral_lunge" => Some(75i64),
+            "walking_barbell_lunge" => Some(76i64),
+            "walking_dumbbell_lunge" => Some(77i64),
+            "walking_lunge" => Some(78i64),
+            "weighted_walking_lunge" => Some(79i64),
+            "wide_grip_overhead_barbell_split_squat" => Some(80i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             LungeExerciseName::OverheadLunge => 0,
@@ -22570,6 +31869,94 @@ impl LungeExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            LungeExerciseName::OverheadLunge => 0,
+            LungeExerciseName::LungeMatrix => 1,
+            LungeExerciseName::WeightedLungeMatrix => 2,
+            LungeExerciseName::AlternatingBarbellForwardLunge => 3,
+            LungeExerciseName::AlternatingDumbbellLungeWithReach => 4,
+            LungeExerciseName::BackFootElevatedDumbbellSplitSquat => 5,
+            LungeExerciseName::BarbellBoxLunge => 6,
+            LungeExerciseName::BarbellBulgarianSplitSquat => 7,
+            LungeExerciseName::BarbellCrossoverLunge => 8,
+            LungeExerciseName::BarbellFrontSplitSquat => 9,
+            LungeExerciseName::BarbellLunge => 10,
+            LungeExerciseName::BarbellReverseLunge => 11,
+            LungeExerciseName::BarbellSideLunge => 12,
+            LungeExerciseName::BarbellSplitSquat => 13,
+            LungeExerciseName::CoreControlRearLunge => 14,
+            LungeExerciseName::DiagonalLunge => 15,
+            LungeExerciseName::DropLunge => 16,
+            LungeExerciseName::DumbbellBoxLunge => 17,
+            LungeExerciseName::DumbbellBulgarianSplitSquat => 18,
+            LungeExerciseName::DumbbellCrossoverLunge => 19,
+            LungeExerciseName::DumbbellDiagonalLunge => 20,
+            LungeExerciseName::DumbbellLunge => 21,
+            LungeExerciseName::DumbbellLungeAndRotation => 22,
+            LungeExerciseName::DumbbellOverheadBulgarianSplitSquat => 23,
+            LungeExerciseName::DumbbellReverseLungeToHighKneeAndPress => 24,
+            LungeExerciseName::DumbbellSideLunge => 25,
+            LungeExerciseName::ElevatedFrontFootBarbellSplitSquat => 26,
+            LungeExerciseName::FrontFootElevatedDumbbellSplitSquat => 27,
+            LungeExerciseName::GunslingerLunge => 28,
+            LungeExerciseName::LawnmowerLunge => 29,
+            LungeExerciseName::LowLungeWithIsometricAdduction => 30,
+            LungeExerciseName::LowSideToSideLunge => 31,
+            LungeExerciseName::Lunge => 32,
+            LungeExerciseName::WeightedLunge => 33,
+            LungeExerciseName::LungeWithArmReach => 34,
+            LungeExerciseName::LungeWithDiagonalReach => 35,
+            LungeExerciseName::LungeWithSideBend => 36,
+            LungeExerciseName::OffsetDumbbellLunge => 37,
+            LungeExerciseName::OffsetDumbbellReverseLunge => 38,
+            LungeExerciseName::OverheadBulgarianSplitSquat => 39,
+            LungeExerciseName::OverheadDumbbellReverseLunge => 40,
+            LungeExerciseName::OverheadDumbbellSplitSquat => 41,
+            LungeExerciseName::OverheadLungeWithRotation => 42,
+            LungeExerciseName::ReverseBarbellBoxLunge => 43,
+            LungeExerciseName::ReverseBoxLunge => 44,
+            LungeExerciseName::ReverseDumbbellBoxLunge => 45,
+            LungeExerciseName::ReverseDumbbellCrossoverLunge => 46,
+            LungeExerciseName::ReverseDumbbellDiagonalLunge => 47,
+            LungeExerciseName::ReverseLungeWithReachBack => 48,
+            LungeExerciseName::WeightedReverseLungeWithReachBack => 49,
+            LungeExerciseName::ReverseLungeWithTwistAndOverheadReach => 50,
+            LungeExerciseName::WeightedReverseLungeWithTwistAndOverheadReach => 51,
+            LungeExerciseName::ReverseSlidingBoxLunge => 52,
+            LungeExerciseName::WeightedReverseSlidingBoxLunge => 53,
+            LungeExerciseName::ReverseSlidingLunge => 54,
+            LungeExerciseName::WeightedReverseSlidingLunge => 55,
+            LungeExerciseName::RunnersLungeToBalance => 56,
+            LungeExerciseName::WeightedRunnersLungeToBalance => 57,
+            LungeExerciseName::ShiftingSideLunge => 58,
+            LungeExerciseName::SideAndCrossoverLunge => 59,
+            LungeExerciseName::WeightedSideAndCrossoverLunge => 60,
+            LungeExerciseName::SideLunge => 61,
+            LungeExerciseName::WeightedSideLunge => 62,
+            LungeExerciseName::SideLungeAndPress => 63,
+            LungeExerciseName::SideLungeJumpOff => 64,
+            LungeExerciseName::SideLungeSweep => 65,
+            LungeExerciseName::WeightedSideLungeSweep => 66,
+            LungeExerciseName::SideLungeToCrossoverTap => 67,
+            LungeExerciseName::WeightedSideLungeToCrossoverTap => 68,
+            LungeExerciseName::SideToSideLungeChops => 69,
+            LungeExerciseName::WeightedSideToSideLungeChops => 70,
+            LungeExerciseName::SiffJumpLunge => 71,
+            LungeExerciseName::WeightedSiffJumpLunge => 72,
+            LungeExerciseName::SingleArmReverseLungeAndPress => 73,
+            LungeExerciseName::SlidingLateralLunge => 74,
+            LungeExerciseName::WeightedSlidingLateralLunge => 75,
+            LungeExerciseName::WalkingBarbellLunge => 76,
+            LungeExerciseName::WalkingDumbbellLunge => 77,
+            LungeExerciseName::WalkingLunge => 78,
+            LungeExerciseName::WeightedWalkingLunge => 79,
+            LungeExerciseName::WideGripOverheadBarbellSplitSquat => 80,
+            LungeExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for LungeExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -22823,6 +32210,15 @@ impl Serialize for LungeExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for LungeExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::LungeExerciseName, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum OlympicLiftExerciseName {
     BarbellHangPowerClean,
@@ -22875,6 +32271,32 @@ impl OlympicLiftExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "barbell_hang_power_clean" => Some(0i64),
+            "barbell_hang_squat_clean" => Some(1i64),
+            "barbell_power_clean" => Some(2i64),
+            "barbell_power_snatch" => Some(3i64),
+            "barbell_squat_clean" => Some(4i64),
+            "clean_and_jerk" => Some(5i64),
+            "barbell_hang_power_snatch" => Some(6i64),
+            "barbell_hang_pull" => Some(7i64),
+            "barbell_high_pull" => Some(8i64),
+            "barbell_snatch" => Some(9i64),
+            "barbell_split_jerk" => Some(10i64),
+            "clean" => Some(11i64),
+            "dumbbell_clean" => Some(12i64),
+            "dumbbell_hang_pull" => Some(13i64),
+            "one_hand_dumbbell_split_snatch" => Some(14i64),
+            "push_jerk" => Some(15i64),
+            "single_arm_dumbbell_snatch" => Some(16i64),
+            "single_arm_hang_snatch" => Some(17i64),
+            "single_arm_kettlebell_snatch" => Some(18i64),
+            "split_jerk" => Some(19i64),
+            "squat_clean_and_jerk" => Some(20i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             OlympicLiftExerciseName::BarbellHangPowerClean => 0,
@@ -22904,6 +32326,34 @@ impl OlympicLiftExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            OlympicLiftExerciseName::BarbellHangPowerClean => 0,
+            OlympicLiftExerciseName::BarbellHangSquatClean => 1,
+            OlympicLiftExerciseName::BarbellPowerClean => 2,
+            OlympicLiftExerciseName::BarbellPowerSnatch => 3,
+            OlympicLiftExerciseName::BarbellSquatClean => 4,
+            OlympicLiftExerciseName::CleanAndJerk => 5,
+            OlympicLiftExerciseName::BarbellHangPowerSnatch => 6,
+            OlympicLiftExerciseName::BarbellHangPull => 7,
+            OlympicLiftExerciseName::BarbellHighPull => 8,
+            OlympicLiftExerciseName::BarbellSnatch => 9,
+            OlympicLiftExerciseName::BarbellSplitJerk => 10,
+            OlympicLiftExerciseName::Clean => 11,
+            OlympicLiftExerciseName::DumbbellClean => 12,
+            OlympicLiftExerciseName::DumbbellHangPull => 13,
+            OlympicLiftExerciseName::OneHandDumbbellSplitSnatch => 14,
+            OlympicLiftExerciseName::PushJerk => 15,
+            OlympicLiftExerciseName::SingleArmDumbbellSnatch => 16,
+            OlympicLiftExerciseName::SingleArmHangSnatch => 17,
+            OlympicLiftExerciseName::SingleArmKettlebellSnatch => 18,
+            OlympicLiftExerciseName::SplitJerk => 19,
+            OlympicLiftExerciseName::SquatCleanAndJerk => 20,
+            OlympicLiftExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for OlympicLiftExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -22985,6 +32435,20 @@ impl Serialize for OlympicLiftExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for OlympicLiftExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(
+            deserializer,
+            FieldDataType::OlympicLiftExerciseName,
+            invalid,
+        )
+        .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum PlankExerciseName {
     Name45DegreePlank,
@@ -23265,7 +32729,292 @@ impl PlankExerciseName {
             _ => false,
         }
     }
-    pub fn as_u16(self) -> u16 {
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "45_degree_plank" => Some(0i64),
+            "weighted_45_degree_plank" => Some(1i64),
+            "90_degree_static_hold" => Some(2i64),
+            "weighted_90_degree_static_hold" => Some(3i64),
+            "bear_crawl" => Some(4i64),
+            "weighted_bear_crawl" => Some(5i64),
+            "cross_body_mountain_climber" => Some(6i64),
+            "weighted_cross_body_mountain_climber" => Some(7i64),
+            "elbow_plank_pike_jacks" => Some(8i64),
+            "weighted_elbow_plank_pike_jacks" => Some(9i64),
+            "elevated_feet_plank" => Some(10i64),
+            "weighted_elevated_feet_plank" => Some(11i64),
+            "elevator_abs" => Some(12i64),
+            "weighted_elevator_abs" => Some(13i64),
+            "extended_plank" => Some(14i64),
+            "weighted_extended_plank" => Some(15i64),
+            "full_plank_passe_twist" => Some(16i64),
+            "weighted_full_plank_passe_twist" => Some(17i64),
+            "inching_elbow_plank" => Some(18i64),
+            "weighted_inching_elbow_plank" => Some(19i64),
+            "inchworm_to_side_plank" => Some(20i64),
+            "weighted_inchworm_to_side_plank" => Some(21i64),
+            "kneeling_plank" => Some(22i64),
+            "weighted_kneeling_plank" => Some(23i64),
+            "kneeling_side_plank_with_leg_lift" => Some(24i64),
+            "weighted_kneeling_side_plank_with_leg_lift" => Some(25i64),
+            "lateral_roll" => Some(26i64),
+            "weighted_lateral_roll" => Some(27i64),
+            "lying_reverse_plank" => Some(28i64),
+            "weighted_lying_reverse_plank" => Some(29i64),
+            "medicine_ball_mountain_climber" => Some(30i64),
+            "weighted_medicine_ball_mountain_climber" => Some(31i64),
+            "modified_mountain_climber_and_extension" => Some(32i64),
+            "weighted_modified_mountain_climber_and_extension" => Some(33i64),
+            "mountain_climber" => Some(34i64),
+            "weighted_mountain_climber" => Some(35i64),
+            "mountain_climber_on_sliding_discs" => Some(36i64),
+            "weighted_mountain_climber_on_sliding_discs" => Some(37i64),
+            "mountain_climber_with_feet_on_bosu_ball" => Some(38i64),
+            "weighted_mountain_climber_with_feet_on_bosu_ball" => Some(39i64),
+            "mountain_climber_with_hands_on_bench" => Some(40i64),
+            "mountain_climber_with_hands_on_swiss_ball" => Some(41i64),
+            "weighted_mountain_climber_with_hands_on_swiss_ball" => Some(42i64),
+            "plank" => Some(43i64),
+            "plank_jacks_with_feet_on_sliding_discs" => Some(44i64),
+            "weighted_plank_jacks_with_feet_on_sliding_discs" => Some(45i64),
+            "plank_knee_twist" => Some(46i64),
+            "weighted_plank_knee_twist" => Some(47i64),
+            "plank_pike_jumps" => Some(48i64),
+            "weighted_plank_pike_jumps" => Some(49i64),
+            "plank_pikes" => Some(50i64),
+            "weighted_plank_pikes" => Some(51i64),
+            "plank_to_stand_up" => Some(52i64),
+            "weighted_plank_to_stand_up" => Some(53i64),
+            "plank_with_arm_raise" => Some(54i64),
+            "weighted_plank_with_arm_raise" => Some(55i64),
+            "plank_with_knee_to_elbow" => Some(56i64),
+            "weighted_plank_with_knee_to_elbow" => Some(57i64),
+            "plank_with_oblique_crunch" => Some(58i64),
+            "weighted_plank_with_oblique_crunch" => Some(59i64),
+            "plyometric_side_plank" => Some(60i64),
+            "weighted_plyometric_side_plank" => Some(61i64),
+            "rolling_side_plank" => Some(62i64),
+            "weighted_rolling_side_plank" => Some(63i64),
+            "side_kick_plank" => Some(64i64),
+            "weighted_side_kick_plank" => Some(65i64),
+            "side_plank" => Some(66i64),
+            "weighted_side_plank" => Some(67i64),
+            "side_plank_and_row" => Some(68i64),
+            "weighted_side_plank_and_row" => Some(69i64),
+            "side_plank_lift" => Some(70i64),
+            "weighted_side_plank_lift" => Some(71i64),
+            "side_plank_with_elbow_on_bosu_ball" => Some(72i64),
+            "weighted_side_plank_with_elbow_on_bosu_ball" => Some(73i64),
+            "side_plank_with_feet_on_bench" => Some(74i64),
+            "weighted_side_plank_with_feet_on_bench" => Some(75i64),
+            "side_plank_with_knee_circle" => Some(76i64),
+            "weighted_side_plank_with_knee_circle" => Some(77i64),
+            "side_plank_with_knee_tuck" => Some(78i64),
+            "weighted_side_plank_with_knee_tuck" => Some(79i64),
+            "side_plank_with_leg_lift" => Some(80i64),
+            "weighted_side_plank_with_leg_lift" => Some(81i64),
+            "side_plank_with_reach_under" => Some(82i64),
+            "weighted_side_plank_with_reach_under" => Some(83i64),
+            "single_leg_elevated_feet_plank" => Some(84i64),
+            "weighted_single_leg_elevated_feet_plank" => Some(85i64),
+            "single_leg_flex_and_extend" => Some(86i64),
+            "weighted_single_leg_flex_and_extend" => Some(87i64),
+            "single_leg_side_plank" => Some(88i64),
+            "weighted_single_leg_side_plank" => Some(89i64),
+            "spiderman_plank" => Some(90i64),
+            "weighted_spiderman_plank" => Some(91i64),
+            "straight_arm_plank" => Some(92i64),
+            "weighted_straight_arm_plank" => Some(93i64),
+            "straight_arm_plank_with_shoulder_touch" => Some(94i64),
+            "weighted_straight_arm_plank_with_shoulder_touch" => Some(95i64),
+            "swiss_ball_plank" => Some(96i64),
+            "weighted_swiss_ball_plank" => Some(97i64),
+            "swiss_ball_plank_leg_lift" => Some(98i64),
+            "weighted_swiss_ball_plank_leg_lift" => Some(99i64),
+            "swiss_ball_plank_leg_lift_and_hold" => Some(100i64),
+            "swiss_ball_plank_with_feet_on_bench" => Some(101i64),
+            "weighted_swiss_ball_plank_with_feet_on_bench" => Some(102i64),
+            "swiss_ball_prone_jackknife" => Some(103i64),
+            "weighted_swiss_ball_prone_jackknife" => Some(104i64),
+            "swiss_ball_side_plank" => Some(105i64),
+            "weighted_swiss_ball_side_plank" => Some(106i64),
+            "three_way_plank" => Some(107i64),
+            "weighted_three_way_plank" => Some(108i64),
+            "towel_plank_and_knee_in" => Some(109i64),
+            "weighted_towel_plank_and_knee_in" => Some(110i64),
+            "t_stabilization" => Some(111i64),
+            "weighted_t_stabilization" => Some(112i64),
+            "turkish_get_up_to_side_plank" => Some(113i64),
+            "weighted_turkish_get_up_to_side_plank" => Some(114i64),
+            "two_point_plank" => Some(115i64),
+            "weighted_two_point_plank" => Some(116i64),
+            "weighted_plank" => Some(117i64),
+            "wide_stance_plank_with_diagonal_arm_lift" => Some(118i64),
+            "weighted_wide_stance_plank_with_diagonal_arm_lift" => Some(119i64),
+            "wide_stance_plank_with_diagonal_leg_lift" => Some(120i64),
+            "weighted_wide_stance_plank_with_diagonal_leg_lift" => Some(121i64),
+            "wide_stance_plank_with_leg_lift" => Some(122i64),
+            "weighted_wide_stance_plank_with_leg_lift" => Some(123i64),
+            "wide_stance_plank_with_opposite_arm_and_leg_lift" => Some(124i64),
+            "weighted_mountain_climber_with_hands_on_bench" => Some(125i64),
+            "weighted_swiss_ball_plank_leg_lift_and_hold" => Some(126i64),
+            "weighted_wide_stance_plank_with_opposite_arm_and_leg_lift" => Some(127i64),
+            "plank_with_feet_on_swiss_ball" => Some(128i64),
+            "side_plank_to_plank_with_reach_under" => Some(129i64),
+            "bridge_with_glute_lower_lift" => Some(130i64),
+            "bridge_one_leg_bridge" => Some(131i64),
+            "plank_with_arm_variations" => Some(132i64),
+            "plank_with_leg_lift" => Some(133i64),
+            "reverse_plank_with_leg_pull" => Some(134i64),
+            _ => None,
+        }
+    }
+    pub fn as_u16(self) -> u16 {
+        match self {
+            PlankExerciseName::Name45DegreePlank => 0,
+            PlankExerciseName::Weighted45DegreePlank => 1,
+            PlankExerciseName::Name90DegreeStaticHold => 2,
+            PlankExerciseName::Weighted90DegreeStaticHold => 3,
+            PlankExerciseName::BearCrawl => 4,
+            PlankExerciseName::WeightedBearCrawl => 5,
+            PlankExerciseName::CrossBodyMountainClimber => 6,
+            PlankExerciseName::WeightedCrossBodyMountainClimber => 7,
+            PlankExerciseName::ElbowPlankPikeJacks => 8,
+            PlankExerciseName::WeightedElbowPlankPikeJacks => 9,
+            PlankExerciseName::ElevatedFeetPlank => 10,
+            PlankExerciseName::WeightedElevatedFeetPlank => 11,
+            PlankExerciseName::ElevatorAbs => 12,
+            PlankExerciseName::WeightedElevatorAbs => 13,
+            PlankExerciseName::ExtendedPlank => 14,
+            PlankExerciseName::WeightedExtendedPlank => 15,
+            PlankExerciseName::FullPlankPasseTwist => 16,
+            PlankExerciseName::WeightedFullPlankPasseTwist => 17,
+            PlankExerciseName::InchingElbowPlank => 18,
+            PlankExerciseName::WeightedInchingElbowPlank => 19,
+            PlankExerciseName::InchwormToSidePlank => 20,
+            PlankExerciseName::WeightedInchwormToSidePlank => 21,
+            PlankExerciseName::KneelingPlank => 22,
+            PlankExerciseName::WeightedKneelingPlank => 23,
+            PlankExerciseName::KneelingSidePlankWithLegLift => 24,
+            PlankExerciseName::WeightedKneelingSidePlankWithLegLift => 25,
+            PlankExerciseName::LateralRoll => 26,
+            PlankExerciseName::WeightedLateralRoll => 27,
+            PlankExerciseName::LyingReversePlank => 28,
+            PlankExerciseName::WeightedLyingReversePlank => 29,
+            PlankExerciseName::MedicineBallMountainClimber => 30,
+            PlankExerciseName::WeightedMedicineBallMountainClimber => 31,
+            PlankExerciseName::ModifiedMountainClimberAndExtension => 32,
+            PlankExerciseName::WeightedModifiedMountainClimberAndExtension => 33,
+            PlankExerciseName::MountainClimber => 34,
+            PlankExerciseName::WeightedMountainClimber => 35,
+            PlankExerciseName::MountainClimberOnSlidingDiscs => 36,
+            PlankExerciseName::WeightedMountainClimberOnSlidingDiscs => 37,
+            PlankExerciseName::MountainClimberWithFeetOnBosuBall => 38,
+            PlankExerciseName::WeightedMountainClimberWithFeetOnBosuBall => 39,
+            PlankExerciseName::MountainClimberWithHandsOnBench => 40,
+            PlankExerciseName::MountainClimberWithHandsOnSwissBall => 41,
+            PlankExerciseName::WeightedMountainClimberWithHandsOnSwissBall => 42,
+            PlankExerciseName::Plank => 43,
+            PlankExerciseName::PlankJacksWithFeetOnSlidingDiscs => 44,
+            PlankExerciseName::WeightedPlankJacksWithFeetOnSlidingDiscs => 45,
+            PlankExerciseName::PlankKneeTwist => 46,
+            PlankExerciseName::WeightedPlankKneeTwist => 47,
+            PlankExerciseName::PlankPikeJumps => 48,
+            PlankExerciseName::WeightedPlankPikeJumps => 49,
+            PlankExerciseName::PlankPikes => 50,
+            PlankExerciseName::WeightedPlankPikes => 51,
+            PlankExerciseName::PlankToStandUp => 52,
+            PlankExerciseName::WeightedPlankToStandUp => 53,
+            PlankExerciseName::PlankWithArmRaise => 54,
+            PlankExerciseName::WeightedPlankWithArmRaise => 55,
+            PlankExerciseName::PlankWithKneeToElbow => 56,
+            PlankExerciseName::WeightedPlankWithKneeToElbow => 57,
+            PlankExerciseName::PlankWithObliqueCrunch => 58,
+            PlankExerciseName::WeightedPlankWithObliqueCrunch => 59,
+            PlankExerciseName::PlyometricSidePlank => 60,
+            PlankExerciseName::WeightedPlyometricSidePlank => 61,
+            PlankExerciseName::RollingSidePlank => 62,
+            PlankExerciseName::WeightedRollingSidePlank => 63,
+            PlankExerciseName::SideKickPlank => 64,
+            PlankExerciseName::WeightedSideKickPlank => 65,
+            PlankExerciseName::SidePlank => 66,
+            PlankExerciseName::WeightedSidePlank => 67,
+            PlankExerciseName::SidePlankAndRow => 68,
+            PlankExerciseName::WeightedSidePlankAndRow => 69,
+            PlankExerciseName::SidePlankLift => 70,
+            PlankExerciseName::WeightedSidePlankLift => 71,
+            PlankExerciseName::SidePlankWithElbowOnBosuBall => 72,
+            PlankExerciseName::WeightedSidePlankWithElbowOnBosuBall => 73,
+            PlankExerciseName::SidePlankWithFeetOnBench => 74,
+            PlankExerciseName::WeightedSidePlankWithFeetOnBench => 75,
+            PlankExerciseName::SidePlankWithKneeCircle => 76,
+            PlankExerciseName::WeightedSidePlankWithKneeCircle => 77,
+            PlankExerciseName::SidePlankWithKneeTuck => 78,
+            PlankExerciseName::WeightedSidePlankWithKneeTuck => 79,
+            PlankExerciseName::SidePlankWithLegLift => 80,
+            PlankExerciseName::WeightedSidePlankWithLegLift => 81,
+            PlankExerciseName::SidePlankWithReachUnder => 82,
+            PlankExerciseName::WeightedSidePlankWithReachUnder => 83,
+            PlankExerciseName::SingleLegElevatedFeetPlank => 84,
+            PlankExerciseName::WeightedSingleLegElevatedFeetPlank => 85,
+            PlankExerciseName::SingleLegFlexAndExtend => 86,
+            PlankExerciseName::WeightedSingleLegFlexAndExtend => 87,
+            PlankExerciseName::SingleLegSidePlank => 88,
+            PlankExerciseName::WeightedSingleLegSidePlank => 89,
+            PlankExerciseName::SpidermanPlank => 90,
+            PlankExerciseName::WeightedSpidermanPlank => 91,
+            PlankExerciseName::StraightArmPlank => 92,
+            PlankExerciseName::WeightedStraightArmPlank => 93,
+            PlankExerciseName::StraightArmPlankWithShoulderTouch => 94,
+            PlankExerciseName::WeightedStraightArmPlankWithShoulderTouch => 95,
+            PlankExerciseName::SwissBallPlank => 96,
+            PlankExerciseName::WeightedSwissBallPlank => 97,
+            PlankExerciseName::SwissBallPlankLegLift => 98,
+            PlankExerciseName::WeightedSwissBallPlankLegLift => 99,
+            PlankExerciseName::SwissBallPlankLegLiftAndHold => 100,
+            PlankExerciseName::SwissBallPlankWithFeetOnBench => 101,
+            PlankExerciseName::WeightedSwissBallPlankWithFeetOnBench => 102,
+            PlankExerciseName::SwissBallProneJackknife => 103,
+            PlankExerciseName::WeightedSwissBallProneJackknife => 104,
+            PlankExerciseName::SwissBallSidePlank => 105,
+            PlankExerciseName::WeightedSwissBallSidePlank => 106,
+            PlankExerciseName::ThreeWayPlank => 107,
+            PlankExerciseName::WeightedThreeWayPlank => 108,
+            PlankExerciseName::TowelPlankAndKneeIn => 109,
+            PlankExerciseName::WeightedTowelPlankAndKneeIn => 110,
+            PlankExerciseName::TStabilization => 111,
+            PlankExerciseName::WeightedTStabilization => 112,
+            PlankExerciseName::TurkishGetUpToSidePlank => 113,
+            PlankExerciseName::WeightedTurkishGetUpToSidePlank => 114,
+            PlankExerciseName::TwoPointPlank => 115,
+            PlankExerciseName::WeightedTwoPointPlank => 116,
+            PlankExerciseName::WeightedPlank => 117,
+            PlankExerciseName::WideStancePlankWithDiagonalArmLift => 118,
+            PlankExerciseName::WeightedWideStancePlankWithDiagonalArmLift => 119,
+            PlankExerciseName::WideStancePlankWithDiagonalLegLift => 120,
+            PlankExerciseName::WeightedWideStancePlankWithDiagonalLegLift => 121,
+            PlankExerciseName::WideStancePlankWithLegLift => 122,
+            PlankExerciseName::WeightedWideStancePlankWithLegLift => 123,
+            PlankExerciseName::WideStancePlankWithOppositeArmAndLegLift => 124,
+            PlankExerciseName::WeightedMountainClimberWithHandsOnBench => 125,
+            PlankExerciseName::WeightedSwissBallPlankLegLiftAndHold => 126,
+            PlankExerciseName::WeightedWideStancePlankWithOppositeArmAndLegLift => 127,
+            PlankExerciseName::PlankWithFeetOnSwissBall => 128,
+            PlankExerciseName::SidePlankToPlankWithReachUnder => 129,
+            PlankExerciseName::BridgeWithGluteLowerLift => 130,
+            PlankExerciseName::BridgeOneLegBridge => 131,
+            PlankExerciseName::PlankWithArmVariations => 132,
+            PlankExerciseName::PlankWithLegLift => 133,
+            PlankExerciseName::ReversePlankWithLegPull => 134,
+            PlankExerciseName::Value(value) => value,
+        }
+    }
+    pub fn as_i64(self) -> i64 {
+        self.as_u16() as i64
+    }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
         match self {
             PlankExerciseName::Name45DegreePlank => 0,
             PlankExerciseName::Weighted45DegreePlank => 1,
@@ -23402,12 +33151,9 @@ impl PlankExerciseName {
             PlankExerciseName::PlankWithArmVariations => 132,
             PlankExerciseName::PlankWithLegLift => 133,
             PlankExerciseName::ReversePlankWithLegPull => 134,
-            PlankExerciseName::Value(value) => value,
+            PlankExerciseName::Value(value) => value as i64,
         }
     }
-    pub fn as_i64(self) -> i64 {
-        self.as_u16() as i64
-    }
 }
 impl fmt::Display for PlankExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -23834,6 +33580,15 @@ impl Serialize for PlankExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for PlankExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::PlankExerciseName, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum PlyoExerciseName {
     AlternatingJumpLunge,
@@ -23910,6 +33665,44 @@ impl PlyoExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "alternating_jump_lunge" => Some(0i64),
+            "weighted_alternating_jump_lunge" => Some(1i64),
+            "barbell_jump_squat" => Some(2i64),
+            "body_weight_jump_squat" => Some(3i64),
+            "weighted_jump_squat" => Some(4i64),
+            "cross_knee_strike" => Some(5i64),
+            "weighted_cross_knee_strike" => Some(6i64),
+            "depth_jump" => Some(7i64),
+            "weighted_depth_jump" => Some(8i64),
+            "dumbbell_jump_squat" => Some(9i64),
+            "dumbbell_split_jump" => Some(10i64),
+            "front_knee_strike" => Some(11i64),
+            "weighted_front_knee_strike" => Some(12i64),
+            "high_box_jump" => Some(13i64),
+            "weighted_high_box_jump" => Some(14i64),
+            "isometric_explosive_body_weight_jump_squat" => Some(15i64),
+            "weighted_isometric_explosive_jump_squat" => Some(16i64),
+            "lateral_leap_and_hop" => Some(17i64),
+            "weighted_lateral_leap_and_hop" => Some(18i64),
+            "lateral_plyo_squats" => Some(19i64),
+            "weighted_lateral_plyo_squats" => Some(20i64),
+            "lateral_slide" => Some(21i64),
+            "weighted_lateral_slide" => Some(22i64),
+            "medicine_ball_overhead_throws" => Some(23i64),
+            "medicine_ball_side_throw" => Some(24i64),
+            "medicine_ball_slam" => Some(25i64),
+            "side_to_side_medicine_ball_throws" => Some(26i64),
+            "side_to_side_shuffle_jump" => Some(27i64),
+            "weighted_side_to_side_shuffle_jump" => Some(28i64),
+            "squat_jump_onto_box" => Some(29i64),
+            "weighted_squat_jump_onto_box" => Some(30i64),
+            "squat_jumps_in_and_out" => Some(31i64),
+            "weighted_squat_jumps_in_and_out" => Some(32i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             PlyoExerciseName::AlternatingJumpLunge => 0,
@@ -23951,6 +33744,46 @@ impl PlyoExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            PlyoExerciseName::AlternatingJumpLunge => 0,
+            PlyoExerciseName::WeightedAlternatingJumpLunge => 1,
+            PlyoExerciseName::BarbellJumpSquat => 2,
+            PlyoExerciseName::BodyWeightJumpSquat => 3,
+            PlyoExerciseName::WeightedJumpSquat => 4,
+            PlyoExerciseName::CrossKneeStrike => 5,
+            PlyoExerciseName::WeightedCrossKneeStrike => 6,
+            PlyoExerciseName::DepthJump => 7,
+            PlyoExerciseName::WeightedDepthJump => 8,
+            PlyoExerciseName::DumbbellJumpSquat => 9,
+            PlyoExerciseName::DumbbellSplitJump => 10,
+            PlyoExerciseName::FrontKneeStrike => 11,
+            PlyoExerciseName::WeightedFrontKneeStrike => 12,
+            PlyoExerciseName::HighBoxJump => 13,
+            PlyoExerciseName::WeightedHighBoxJump => 14,
+            PlyoExerciseName::IsometricExplosiveBodyWeightJumpSquat => 15,
+            PlyoExerciseName::WeightedIsometricExplosiveJumpSquat => 16,
+            PlyoExerciseName::LateralLeapAndHop => 17,
+            PlyoExerciseName::WeightedLateralLeapAndHop => 18,
+            PlyoExerciseName::LateralPlyoSquats => 19,
+            PlyoExerciseName::WeightedLateralPlyoSquats => 20,
+            PlyoExerciseName::LateralSlide => 21,
+            PlyoExerciseName::WeightedLateralSlide => 22,
+            PlyoExerciseName::MedicineBallOverheadThrows => 23,
+            PlyoExerciseName::MedicineBallSideThrow => 24,
+            PlyoExerciseName::MedicineBallSlam => 25,
+            PlyoExerciseName::SideToSideMedicineBallThrows => 26,
+            PlyoExerciseName::SideToSideShuffleJump => 27,
+            PlyoExerciseName::WeightedSideToSideShuffleJump => 28,
+            PlyoExerciseName::SquatJumpOntoBox => 29,
+            PlyoExerciseName::WeightedSquatJumpOntoBox => 30,
+            PlyoExerciseName::SquatJumpsInAndOut => 31,
+            PlyoExerciseName::WeightedSquatJumpsInAndOut => 32,
+            PlyoExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for PlyoExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -24066,6 +33899,15 @@ impl Serialize for PlyoExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for PlyoExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::PlyoExerciseName, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum PullUpExerciseName {
     BandedPullUps,
@@ -24154,6 +33996,50 @@ impl PullUpExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "banded_pull_ups" => Some(0i64),
+            "30_degree_lat_pulldown" => Some(1i64),
+            "band_assisted_chin_up" => Some(2i64),
+            "close_grip_chin_up" => Some(3i64),
+            "weighted_close_grip_chin_up" => Some(4i64),
+            "close_grip_lat_pulldown" => Some(5i64),
+            "crossover_chin_up" => Some(6i64),
+            "weighted_crossover_chin_up" => Some(7i64),
+            "ez_bar_pullover" => Some(8i64),
+            "hanging_hurdle" => Some(9i64),
+            "weighted_hanging_hurdle" => Some(10i64),
+            "kneeling_lat_pulldown" => Some(11i64),
+            "kneeling_underhand_grip_lat_pulldown" => Some(12i64),
+            "lat_pulldown" => Some(13i64),
+            "mixed_grip_chin_up" => Some(14i64),
+            "weighted_mixed_grip_chin_up" => Some(15i64),
+            "mixed_grip_pull_up" => Some(16i64),
+            "weighted_mixed_grip_pull_up" => Some(17i64),
+            "reverse_grip_pulldown" => Some(18i64),
+            "standing_cable_pullover" => Some(19i64),
+            "straight_arm_pulldown" => Some(20i64),
+            "swiss_ball_ez_bar_pullover" => Some(21i64),
+            "towel_pull_up" => Some(22i64),
+            "weighted_towel_pull_up" => Some(23i64),
+            "weighted_pull_up" => Some(24i64),
+            "wide_grip_lat_pulldown" => Some(25i64),
+            "wide_grip_pull_up" => Some(26i64),
+            "weighted_wide_grip_pull_up" => Some(27i64),
+            "burpee_pull_up" => Some(28i64),
+            "weighted_burpee_pull_up" => Some(29i64),
+            "jumping_pull_ups" => Some(30i64),
+            "weighted_jumping_pull_ups" => Some(31i64),
+            "kipping_pull_up" => Some(32i64),
+            "weighted_kipping_pull_up" => Some(33i64),
+            "l_pull_up" => Some(34i64),
+            "weighted_l_pull_up" => Some(35i64),
+            "suspended_chin_up" => Some(36i64),
+            "weighted_suspended_chin_up" => Some(37i64),
+            "pull_up" => Some(38i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             PullUpExerciseName::BandedPullUps => 0,
@@ -24201,6 +34087,52 @@ impl PullUpExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            PullUpExerciseName::BandedPullUps => 0,
+            PullUpExerciseName::Name30DegreeLatPulldown => 1,
+            PullUpExerciseName::BandAssistedChinUp => 2,
+            PullUpExerciseName::CloseGripChinUp => 3,
+            PullUpExerciseName::WeightedCloseGripChinUp => 4,
+            PullUpExerciseName::CloseGripLatPulldown => 5,
+            PullUpExerciseName::CrossoverChinUp => 6,
+            PullUpExerciseName::WeightedCrossoverChinUp => 7,
+            PullUpExerciseName::EzBarPullover => 8,
+            PullUpExerciseName::HangingHurdle => 9,
+            PullUpExerciseName::WeightedHangingHurdle => 10,
+            PullUpExerciseName::KneelingLatPulldown => 11,
+            PullUpExerciseName::KneelingUnderhandGripLatPulldown => 12,
+            PullUpExerciseName::LatPulldown => 13,
+            PullUpExerciseName::MixedGripChinUp => 14,
+            PullUpExerciseName::WeightedMixedGripChinUp => 15,
+            PullUpExerciseName::MixedGripPullUp => 16,
+            PullUpExerciseName::WeightedMixedGripPullUp => 17,
+            PullUpExerciseName::ReverseGripPulldown => 18,
+            PullUpExerciseName::StandingCablePullover => 19,
+            PullUpExerciseName::StraightArmPulldown => 20,
+            PullUpExerciseName::SwissBallEzBarPullover => 21,
+            PullUpExerciseName::TowelPullUp => 22,
+            PullUpExerciseName::WeightedTowelPullUp => 23,
+            PullUpExerciseName::WeightedPullUp => 24,
+            PullUpExerciseName::WideGripLatPulldown => 25,
+            PullUpExerciseName::WideGripPullUp => 26,
+            PullUpExerciseName::WeightedWideGripPullUp => 27,
+            PullUpExerciseName::BurpeePullUp => 28,
+            PullUpExerciseName::WeightedBurpeePullUp => 29,
+            PullUpExerciseName::JumpingPullUps => 30,
+            PullUpExerciseName::WeightedJumpingPullUps => 31,
+            PullUpExerciseName::KippingPullUp => 32,
+            PullUpExerciseName::WeightedKippingPullUp => 33,
+            PullUpExerciseName::LPullUp => 34,
+            PullUpExerciseName::WeightedLPullUp => 35,
+            PullUpExerciseName::SuspendedChinUp => 36,
+            PullUpExerciseName::WeightedSuspendedChinUp => 37,
+            PullUpExerciseName::PullUp => 38,
+            PullUpExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for PullUpExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -24312,6 +34244,16 @@ impl Serialize for PullUpExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for PullUpExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::PullUpExerciseName, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum PushUpExerciseName {
     ChestPressWithBand,
@@ -24480,7 +34422,180 @@ impl PushUpExerciseName {
             _ => false,
         }
     }
-    pub fn as_u16(self) -> u16 {
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "chest_press_with_band" => Some(0i64),
+            "alternating_staggered_push_up" => Some(1i64),
+            "weighted_alternating_staggered_push_up" => Some(2i64),
+            "alternating_hands_medicine_ball_push_up" => Some(3i64),
+            "weighted_alternating_hands_medicine_ball_push_up" => Some(4i64),
+            "bosu_ball_push_up" => Some(5i64),
+            "weighted_bosu_ball_push_up" => Some(6i64),
+            "clapping_push_up" => Some(7i64),
+            "weighted_clapping_push_up" => Some(8i64),
+            "close_grip_medicine_ball_push_up" => Some(9i64),
+            "weighted_close_grip_medicine_ball_push_up" => Some(10i64),
+            "close_hands_push_up" => Some(11i64),
+            "weighted_close_hands_push_up" => Some(12i64),
+            "decline_push_up" => Some(13i64),
+            "weighted_decline_push_up" => Some(14i64),
+            "diamond_push_up" => Some(15i64),
+            "weighted_diamond_push_up" => Some(16i64),
+            "explosive_crossover_push_up" => Some(17i64),
+            "weighted_explosive_crossover_push_up" => Some(18i64),
+            "explosive_push_up" => Some(19i64),
+            "weighted_explosive_push_up" => Some(20i64),
+            "feet_elevated_side_to_side_push_up" => Some(21i64),
+            "weighted_feet_elevated_side_to_side_push_up" => Some(22i64),
+            "hand_release_push_up" => Some(23i64),
+            "weighted_hand_release_push_up" => Some(24i64),
+            "handstand_push_up" => Some(25i64),
+            "weighted_handstand_push_up" => Some(26i64),
+            "incline_push_up" => Some(27i64),
+            "weighted_incline_push_up" => Some(28i64),
+            "isometric_explosive_push_up" => Some(29i64),
+            "weighted_isometric_explosive_push_up" => Some(30i64),
+            "judo_push_up" => Some(31i64),
+            "weighted_judo_push_up" => Some(32i64),
+            "kneeling_push_up" => Some(33i64),
+            "weighted_kneeling_push_up" => Some(34i64),
+            "medicine_ball_chest_pass" => Some(35i64),
+            "medicine_ball_push_up" => Some(36i64),
+            "weighted_medicine_ball_push_up" => Some(37i64),
+            "one_arm_push_up" => Some(38i64),
+            "weighted_one_arm_push_up" => Some(39i64),
+            "weighted_push_up" => Some(40i64),
+            "push_up_and_row" => Some(41i64),
+            "weighted_push_up_and_row" => Some(42i64),
+            "push_up_plus" => Some(43i64),
+            "weighted_push_up_plus" => Some(44i64),
+            "push_up_with_feet_on_swiss_ball" => Some(45i64),
+            "weighted_push_up_with_feet_on_swiss_ball" => Some(46i64),
+            "push_up_with_one_hand_on_medicine_ball" => Some(47i64),
+            "weighted_push_up_with_one_hand_on_medicine_ball" => Some(48i64),
+            "shoulder_push_up" => Some(49i64),
+            "weighted_shoulder_push_up" => Some(50i64),
+            "single_arm_medicine_ball_push_up" => Some(51i64),
+            "weighted_single_arm_medicine_ball_push_up" => Some(52i64),
+            "spiderman_push_up" => Some(53i64),
+            "weighted_spiderman_push_up" => Some(54i64),
+            "stacked_feet_push_up" => Some(55i64),
+            "weighted_stacked_feet_push_up" => Some(56i64),
+            "staggered_hands_push_up" => Some(57i64),
+            "weighted_staggered_hands_push_up" => Some(58i64),
+            "suspended_push_up" => Some(59i64),
+            "weighted_suspended_push_up" => Some(60i64),
+            "swiss_ball_push_up" => Some(61i64),
+            "weighted_swiss_ball_push_up" => Some(62i64),
+            "swiss_ball_push_up_plus" => Some(63i64),
+            "weighted_swiss_ball_push_up_plus" => Some(64i64),
+            "t_push_up" => Some(65i64),
+            "weighted_t_push_up" => Some(66i64),
+            "triple_stop_push_up" => Some(67i64),
+            "weighted_triple_stop_push_up" => Some(68i64),
+            "wide_hands_push_up" => Some(69i64),
+            "weighted_wide_hands_push_up" => Some(70i64),
+            "parallette_handstand_push_up" => Some(71i64),
+            "weighted_parallette_handstand_push_up" => Some(72i64),
+            "ring_handstand_push_up" => Some(73i64),
+            "weighted_ring_handstand_push_up" => Some(74i64),
+            "ring_push_up" => Some(75i64),
+            "weighted_ring_push_up" => Some(76i64),
+            "push_up" => Some(77i64),
+            "pilates_pushup" => Some(78i64),
+            _ => None,
+        }
+    }
+    pub fn as_u16(self) -> u16 {
+        match self {
+            PushUpExerciseName::ChestPressWithBand => 0,
+            PushUpExerciseName::AlternatingStaggeredPushUp => 1,
+            PushUpExerciseName::WeightedAlternatingStaggeredPushUp => 2,
+            PushUpExerciseName::AlternatingHandsMedicineBallPushUp => 3,
+            PushUpExerciseName::WeightedAlternatingHandsMedicineBallPushUp => 4,
+            PushUpExerciseName::BosuBallPushUp => 5,
+            PushUpExerciseName::WeightedBosuBallPushUp => 6,
+            PushUpExerciseName::ClappingPushUp => 7,
+            PushUpExerciseName::WeightedClappingPushUp => 8,
+            PushUpExerciseName::CloseGripMedicineBallPushUp => 9,
+            PushUpExerciseName::WeightedCloseGripMedicineBallPushUp => 10,
+            PushUpExerciseName::CloseHandsPushUp => 11,
+            PushUpExerciseName::WeightedCloseHandsPushUp => 12,
+            PushUpExerciseName::DeclinePushUp => 13,
+            PushUpExerciseName::WeightedDeclinePushUp => 14,
+            PushUpExerciseName::DiamondPushUp => 15,
+            PushUpExerciseName::WeightedDiamondPushUp => 16,
+            PushUpExerciseName::ExplosiveCrossoverPushUp => 17,
+            PushUpExerciseName::WeightedExplosiveCrossoverPushUp => 18,
+            PushUpExerciseName::ExplosivePushUp => 19,
+            PushUpExerciseName::WeightedExplosivePushUp => 20,
+            PushUpExerciseName::FeetElevatedSideToSidePushUp => 21,
+            PushUpExerciseName::WeightedFeetElevatedSideToSidePushUp => 22,
+            PushUpExerciseName::HandReleasePushUp => 23,
+            PushUpExerciseName::WeightedHandReleasePushUp => 24,
+            PushUpExerciseName::HandstandPushUp => 25,
+            PushUpExerciseName::WeightedHandstandPushUp => 26,
+            PushUpExerciseName::InclinePushUp => 27,
+            PushUpExerciseName::WeightedInclinePushUp => 28,
+            PushUpExerciseName::IsometricExplosivePushUp => 29,
+            PushUpExerciseName::WeightedIsometricExplosivePushUp => 30,
+            PushUpExerciseName::JudoPushUp => 31,
+            PushUpExerciseName::WeightedJudoPushUp => 32,
+            PushUpExerciseName::KneelingPushUp => 33,
+            PushUpExerciseName::WeightedKneelingPushUp => 34,
+            PushUpExerciseName::MedicineBallChestPass => 35,
+            PushUpExerciseName::MedicineBallPushUp => 36,
+            PushUpExerciseName::WeightedMedicineBallPushUp => 37,
+            PushUpExerciseName::OneArmPushUp => 38,
+            PushUpExerciseName::WeightedOneArmPushUp => 39,
+            PushUpExerciseName::WeightedPushUp => 40,
+            PushUpExerciseName::PushUpAndRow => 41,
+            PushUpExerciseName::WeightedPushUpAndRow => 42,
+            PushUpExerciseName::PushUpPlus => 43,
+            PushUpExerciseName::WeightedPushUpPlus => 44,
+            PushUpExerciseName::PushUpWithFeetOnSwissBall => 45,
+            PushUpExerciseName::WeightedPushUpWithFeetOnSwissBall => 46,
+            PushUpExerciseName::PushUpWithOneHandOnMedicineBall => 47,
+            PushUpExerciseName::WeightedPushUpWithOneHandOnMedicineBall => 48,
+            PushUpExerciseName::ShoulderPushUp => 49,
+            PushUpExerciseName::WeightedShoulderPushUp => 50,
+            PushUpExerciseName::SingleArmMedicineBallPushUp => 51,
+            PushUpExerciseName::WeightedSingleArmMedicineBallPushUp => 52,
+            PushUpExerciseName::SpidermanPushUp => 53,
+            PushUpExerciseName::WeightedSpidermanPushUp => 54,
+            PushUpExerciseName::StackedFeetPushUp => 55,
+            PushUpExerciseName::WeightedStackedFeetPushUp => 56,
+            PushUpExerciseName::StaggeredHandsPushUp => 57,
+            PushUpExerciseName::WeightedStaggeredHandsPushUp => 58,
+            PushUpExerciseName::SuspendedPushUp => 59,
+            PushUpExerciseName::WeightedSuspendedPushUp => 60,
+            PushUpExerciseName::SwissBallPushUp => 61,
+            PushUpExerciseName::WeightedSwissBallPushUp => 62,
+            PushUpExerciseName::SwissBallPushUpPlus => 63,
+            PushUpExerciseName::WeightedSwissBallPushUpPlus => 64,
+            PushUpExerciseName::TPushUp => 65,
+            PushUpExerciseName::WeightedTPushUp => 66,
+            PushUpExerciseName::TripleStopPushUp => 67,
+            PushUpExerciseName::WeightedTripleStopPushUp => 68,
+            PushUpExerciseName::WideHandsPushUp => 69,
+            PushUpExerciseName::WeightedWideHandsPushUp => 70,
+            PushUpExerciseName::ParalletteHandstandPushUp => 71,
+            PushUpExerciseName::WeightedParalletteHandstandPushUp => 72,
+            PushUpExerciseName::RingHandstandPushUp => 73,
+            PushUpExerciseName::WeightedRingHandstandPushUp => 74,
+            PushUpExerciseName::RingPushUp => 75,
+            PushUpExerciseName::WeightedRingPushUp => 76,
+            PushUpExerciseName::PushUp => 77,
+            PushUpExerciseName::PilatesPushup => 78,
+            PushUpExerciseName::Value(value) => value,
+        }
+    }
+    pub fn as_i64(self) -> i64 {
+        self.as_u16() as i64
+    }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
         match self {
             PushUpExerciseName::ChestPressWithBand => 0,
             PushUpExerciseName::AlternatingStaggeredPushUp => 1,
@@ -24561,12 +34676,9 @@ impl PushUpExerciseName {
             PushUpExerciseName::WeightedRingPushUp => 76,
             PushUpExerciseName::PushUp => 77,
             PushUpExerciseName::PilatesPushup => 78,
-            PushUpExerciseName::Value(value) => value,
+            PushUpExerciseName::Value(value) => value as i64,
         }
     }
-    pub fn as_i64(self) -> i64 {
-        self.as_u16() as i64
-    }
 }
 impl fmt::Display for PushUpExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -24812,6 +34924,16 @@ impl Serialize for PushUpExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for PushUpExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::PushUpExerciseName, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum RowExerciseName {
     BarbellStraightLegDeadliftToRow,
@@ -24890,6 +35012,45 @@ impl RowExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "barbell_straight_leg_deadlift_to_row" => Some(0i64),
+            "cable_row_standing" => Some(1i64),
+            "dumbbell_row" => Some(2i64),
+            "elevated_feet_inverted_row" => Some(3i64),
+            "weighted_elevated_feet_inverted_row" => Some(4i64),
+            "face_pull" => Some(5i64),
+            "face_pull_with_external_rotation" => Some(6i64),
+            "inverted_row_with_feet_on_swiss_ball" => Some(7i64),
+            "weighted_inverted_row_with_feet_on_swiss_ball" => Some(8i64),
+            "kettlebell_row" => Some(9i64),
+            "modified_inverted_row" => Some(10i64),
+            "weighted_modified_inverted_row" => Some(11i64),
+            "neutral_grip_alternating_dumbbell_row" => Some(12i64),
+            "one_arm_bent_over_row" => Some(13i64),
+            "one_legged_dumbbell_row" => Some(14i64),
+            "renegade_row" => Some(15i64),
+            "reverse_grip_barbell_row" => Some(16i64),
+            "rope_handle_cable_row" => Some(17i64),
+            "seated_cable_row" => Some(18i64),
+            "seated_dumbbell_row" => Some(19i64),
+            "single_arm_cable_row" => Some(20i64),
+            "single_arm_cable_row_and_rotation" => Some(21i64),
+            "single_arm_inverted_row" => Some(22i64),
+            "weighted_single_arm_inverted_row" => Some(23i64),
+            "single_arm_neutral_grip_dumbbell_row" => Some(24i64),
+            "single_arm_neutral_grip_dumbbell_row_and_rotation" => Some(25i64),
+            "suspended_inverted_row" => Some(26i64),
+            "weighted_suspended_inverted_row" => Some(27i64),
+            "t_bar_row" => Some(28i64),
+            "towel_grip_inverted_row" => Some(29i64),
+            "weighted_towel_grip_inverted_row" => Some(30i64),
+            "underhand_grip_cable_row" => Some(31i64),
+            "v_grip_cable_row" => Some(32i64),
+            "wide_grip_seated_cable_row" => Some(33i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             RowExerciseName::BarbellStraightLegDeadliftToRow => 0,
@@ -24932,6 +35093,47 @@ impl RowExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            RowExerciseName::BarbellStraightLegDeadliftToRow => 0,
+            RowExerciseName::CableRowStanding => 1,
+            RowExerciseName::DumbbellRow => 2,
+            RowExerciseName::ElevatedFeetInvertedRow => 3,
+            RowExerciseName::WeightedElevatedFeetInvertedRow => 4,
+            RowExerciseName::FacePull => 5,
+            RowExerciseName::FacePullWithExternalRotation => 6,
+            RowExerciseName::InvertedRowWithFeetOnSwissBall => 7,
+            RowExerciseName::WeightedInvertedRowWithFeetOnSwissBall => 8,
+            RowExerciseName::KettlebellRow => 9,
+            RowExerciseName::ModifiedInvertedRow => 10,
+            RowExerciseName::WeightedModifiedInvertedRow => 11,
+            RowExerciseName::NeutralGripAlternatingDumbbellRow => 12,
+            RowExerciseName::OneArmBentOverRow => 13,
+            RowExerciseName::OneLeggedDumbbellRow => 14,
+            RowExerciseName::RenegadeRow => 15,
+            RowExerciseName::ReverseGripBarbellRow => 16,
+            RowExerciseName::RopeHandleCableRow => 17,
+            RowExerciseName::SeatedCableRow => 18,
+            RowExerciseName::SeatedDumbbellRow => 19,
+            RowExerciseName::SingleArmCableRow => 20,
+            RowExerciseName::SingleArmCableRowAndRotation => 21,
+            RowExerciseName::SingleArmInvertedRow => 22,
+            RowExerciseName::WeightedSingleArmInvertedRow => 23,
+            RowExerciseName::SingleArmNeutralGripDumbbellRow => 24,
+            RowExerciseName::SingleArmNeutralGripDumbbellRowAndRotation => 25,
+            RowExerciseName::SuspendedInvertedRow => 26,
+            RowExerciseName::WeightedSuspendedInvertedRow => 27,
+            RowExerciseName::TBarRow => 28,
+            RowExerciseName::TowelGripInvertedRow => 29,
+            RowExerciseName::WeightedTowelGripInvertedRow => 30,
+            RowExerciseName::UnderhandGripCableRow => 31,
+            RowExerciseName::VGripCableRow => 32,
+            RowExerciseName::WideGripSeatedCableRow => 33,
+            RowExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for RowExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -25057,6 +35259,15 @@ impl Serialize for RowExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for RowExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::RowExerciseName, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ShoulderPressExerciseName {
     AlternatingDumbbellShoulderPress,
@@ -25115,6 +35326,35 @@ impl ShoulderPressExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "alternating_dumbbell_shoulder_press" => Some(0i64),
+            "arnold_press" => Some(1i64),
+            "barbell_front_squat_to_push_press" => Some(2i64),
+            "barbell_push_press" => Some(3i64),
+            "barbell_shoulder_press" => Some(4i64),
+            "dead_curl_press" => Some(5i64),
+            "dumbbell_alternating_shoulder_press_and_twist" => Some(6i64),
+            "dumbbell_hammer_curl_to_lunge_to_press" => Some(7i64),
+            "dumbbell_push_press" => Some(8i64),
+            "floor_inverted_shoulder_press" => Some(9i64),
+            "weighted_floor_inverted_shoulder_press" => Some(10i64),
+            "inverted_shoulder_press" => Some(11i64),
+            "weighted_inverted_shoulder_press" => Some(12i64),
+            "one_arm_push_press" => Some(13i64),
+            "overhead_barbell_press" => Some(14i64),
+            "overhead_dumbbell_press" => Some(15i64),
+            "seated_barbell_shoulder_press" => Some(16i64),
+            "seated_dumbbell_shoulder_press" => Some(17i64),
+            "single_arm_dumbbell_shoulder_press" => Some(18i64),
+            "single_arm_step_up_and_press" => Some(19i64),
+            "smith_machine_overhead_press" => Some(20i64),
+            "split_stance_hammer_curl_to_press" => Some(21i64),
+            "swiss_ball_dumbbell_shoulder_press" => Some(22i64),
+            "weight_plate_front_raise" => Some(23i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             ShoulderPressExerciseName::AlternatingDumbbellShoulderPress => 0,
@@ -25147,6 +35387,37 @@ impl ShoulderPressExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ShoulderPressExerciseName::AlternatingDumbbellShoulderPress => 0,
+            ShoulderPressExerciseName::ArnoldPress => 1,
+            ShoulderPressExerciseName::BarbellFrontSquatToPushPress => 2,
+            ShoulderPressExerciseName::BarbellPushPress => 3,
+            ShoulderPressExerciseName::BarbellShoulderPress => 4,
+            ShoulderPressExerciseName::DeadCurlPress => 5,
+            ShoulderPressExerciseName::DumbbellAlternatingShoulderPressAndTwist => 6,
+            ShoulderPressExerciseName::DumbbellHammerCurlToLungeToPress => 7,
+            ShoulderPressExerciseName::DumbbellPushPress => 8,
+            ShoulderPressExerciseName::FloorInvertedShoulderPress => 9,
+            ShoulderPressExerciseName::WeightedFloorInvertedShoulderPress => 10,
+            ShoulderPressExerciseName::InvertedShoulderPress => 11,
+            ShoulderPressExerciseName::WeightedInvertedShoulderPress => 12,
+            ShoulderPressExerciseName::OneArmPushPress => 13,
+            ShoulderPressExerciseName::OverheadBarbellPress => 14,
+            ShoulderPressExerciseName::OverheadDumbbellPress => 15,
+            ShoulderPressExerciseName::SeatedBarbellShoulderPress => 16,
+            ShoulderPressExerciseName::SeatedDumbbellShoulderPress => 17,
+            ShoulderPressExerciseName::SingleArmDumbbellShoulderPress => 18,
+            ShoulderPressExerciseName::SingleArmStepUpAndPress => 19,
+            ShoulderPressExerciseName::SmithMachineOverheadPress => 20,
+            ShoulderPressExerciseName::SplitStanceHammerCurlToPress => 21,
+            ShoulderPressExerciseName::SwissBallDumbbellShoulderPress => 22,
+            ShoulderPressExerciseName::WeightPlateFrontRaise => 23,
+            ShoulderPressExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ShoulderPressExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -25260,6 +35531,20 @@ impl Serialize for ShoulderPressExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for ShoulderPressExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(
+            deserializer,
+            FieldDataType::ShoulderPressExerciseName,
+            invalid,
+        )
+        .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ShoulderStabilityExerciseName {
     Name90DegreeCableExternalRotation,
@@ -25336,6 +35621,44 @@ impl ShoulderStabilityExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "90_degree_cable_external_rotation" => Some(0i64),
+            "band_external_rotation" => Some(1i64),
+            "band_internal_rotation" => Some(2i64),
+            "bent_arm_lateral_raise_and_external_rotation" => Some(3i64),
+            "cable_external_rotation" => Some(4i64),
+            "dumbbell_face_pull_with_external_rotation" => Some(5i64),
+            "floor_i_raise" => Some(6i64),
+            "weighted_floor_i_raise" => Some(7i64),
+            "floor_t_raise" => Some(8i64),
+            "weighted_floor_t_raise" => Some(9i64),
+            "floor_y_raise" => Some(10i64),
+            "weighted_floor_y_raise" => Some(11i64),
+            "incline_i_raise" => Some(12i64),
+            "weighted_incline_i_raise" => Some(13i64),
+            "incline_l_raise" => Some(14i64),
+            "weighted_incline_l_raise" => Some(15i64),
+            "incline_t_raise" => Some(16i64),
+            "weighted_incline_t_raise" => Some(17i64),
+            "incline_w_raise" => Some(18i64),
+            "weighted_incline_w_raise" => Some(19i64),
+            "incline_y_raise" => Some(20i64),
+            "weighted_incline_y_raise" => Some(21i64),
+            "lying_external_rotation" => Some(22i64),
+            "seated_dumbbell_external_rotation" => Some(23i64),
+            "standing_l_raise" => Some(24i64),
+            "swiss_ball_i_raise" => Some(25i64),
+            "weighted_swiss_ball_i_raise" => Some(26i64),
+            "swiss_ball_t_raise" => Some(27i64),
+            "weighted_swiss_ball_t_raise" => Some(28i64),
+            "swiss_ball_w_raise" => Some(29i64),
+            "weighted_swiss_ball_w_raise" => Some(30i64),
+            "swiss_ball_y_raise" => Some(31i64),
+            "weighted_swiss_ball_y_raise" => Some(32i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             ShoulderStabilityExerciseName::Name90DegreeCableExternalRotation => 0,
@@ -25377,6 +35700,46 @@ impl ShoulderStabilityExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ShoulderStabilityExerciseName::Name90DegreeCableExternalRotation => 0,
+            ShoulderStabilityExerciseName::BandExternalRotation => 1,
+            ShoulderStabilityExerciseName::BandInternalRotation => 2,
+            ShoulderStabilityExerciseName::BentArmLateralRaiseAndExternalRotation => 3,
+            ShoulderStabilityExerciseName::CableExternalRotation => 4,
+            ShoulderStabilityExerciseName::DumbbellFacePullWithExternalRotation => 5,
+            ShoulderStabilityExerciseName::FloorIRaise => 6,
+            ShoulderStabilityExerciseName::WeightedFloorIRaise => 7,
+            ShoulderStabilityExerciseName::FloorTRaise => 8,
+            ShoulderStabilityExerciseName::WeightedFloorTRaise => 9,
+            ShoulderStabilityExerciseName::FloorYRaise => 10,
+            ShoulderStabilityExerciseName::WeightedFloorYRaise => 11,
+            ShoulderStabilityExerciseName::InclineIRaise => 12,
+            ShoulderStabilityExerciseName::WeightedInclineIRaise => 13,
+            ShoulderStabilityExerciseName::InclineLRaise => 14,
+            ShoulderStabilityExerciseName::WeightedInclineLRaise => 15,
+            ShoulderStabilityExerciseName::InclineTRaise => 16,
+            ShoulderStabilityExerciseName::WeightedInclineTRaise => 17,
+            ShoulderStabilityExerciseName::InclineWRaise => 18,
+            ShoulderStabilityExerciseName::WeightedInclineWRaise => 19,
+            ShoulderStabilityExerciseName::InclineYRaise => 20,
+            ShoulderStabilityExerciseName::WeightedInclineYRaise => 21,
+            ShoulderStabilityExerciseName::LyingExternalRotation => 22,
+            ShoulderStabilityExerciseName::SeatedDumbbellExternalRotation => 23,
+            ShoulderStabilityExerciseName::StandingLRaise => 24,
+            ShoulderStabilityExerciseName::SwissBallIRaise => 25,
+            ShoulderStabilityExerciseName::WeightedSwissBallIRaise => 26,
+            ShoulderStabilityExerciseName::SwissBallTRaise => 27,
+            ShoulderStabilityExerciseName::WeightedSwissBallTRaise => 28,
+            ShoulderStabilityExerciseName::SwissBallWRaise => 29,
+            ShoulderStabilityExerciseName::WeightedSwissBallWRaise => 30,
+            ShoulderStabilityExerciseName::SwissBallYRaise => 31,
+            ShoulderStabilityExerciseName::WeightedSwissBallYRaise => 32,
+            ShoulderStabilityExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ShoulderStabilityExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -25514,6 +35877,20 @@ impl Serialize for ShoulderStabilityExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for ShoulderStabilityExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(
+            deserializer,
+            FieldDataType::ShoulderStabilityExerciseName,
+            invalid,
+        )
+        .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ShrugExerciseName {
     BarbellJumpShrug,
@@ -25558,6 +35935,28 @@ impl ShrugExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "barbell_jump_shrug" => Some(0i64),
+            "barbell_shrug" => Some(1i64),
+            "barbell_upright_row" => Some(2i64),
+            "behind_the_back_smith_machine_shrug" => Some(3i64),
+            "dumbbell_jump_shrug" => Some(4i64),
+            "dumbbell_shrug" => Some(5i64),
+            "dumbbell_upright_row" => Some(6i64),
+            "incline_dumbbell_shrug" => Some(7i64),
+            "overhead_barbell_shrug" => Some(8i64),
+            "overhead_dumbbell_shrug" => Some(9i64),
+            "scaption_and_shrug" => Some(10i64),
+            "scapular_retraction" => Some(11i64),
+            "serratus_chair_shrug" => Some(12i64),
+            "weighted_serratus_chair_shrug" => Some(13i64),
+            "serratus_shrug" => Some(14i64),
+            "weighted_serratus_shrug" => Some(15i64),
+            "wide_grip_jump_shrug" => Some(16i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             ShrugExerciseName::BarbellJumpShrug => 0,
@@ -25583,6 +35982,30 @@ impl ShrugExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ShrugExerciseName::BarbellJumpShrug => 0,
+            ShrugExerciseName::BarbellShrug => 1,
+            ShrugExerciseName::BarbellUprightRow => 2,
+            ShrugExerciseName::BehindTheBackSmithMachineShrug => 3,
+            ShrugExerciseName::DumbbellJumpShrug => 4,
+            ShrugExerciseName::DumbbellShrug => 5,
+            ShrugExerciseName::DumbbellUprightRow => 6,
+            ShrugExerciseName::InclineDumbbellShrug => 7,
+            ShrugExerciseName::OverheadBarbellShrug => 8,
+            ShrugExerciseName::OverheadDumbbellShrug => 9,
+            ShrugExerciseName::ScaptionAndShrug => 10,
+            ShrugExerciseName::ScapularRetraction => 11,
+            ShrugExerciseName::SerratusChairShrug => 12,
+            ShrugExerciseName::WeightedSerratusChairShrug => 13,
+            ShrugExerciseName::SerratusShrug => 14,
+            ShrugExerciseName::WeightedSerratusShrug => 15,
+            ShrugExerciseName::WideGripJumpShrug => 16,
+            ShrugExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ShrugExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -25652,6 +36075,15 @@ impl Serialize for ShrugExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for ShrugExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::ShrugExerciseName, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SitUpExerciseName {
     AlternatingSitUp,
@@ -25738,6 +36170,49 @@ impl SitUpExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "alternating_sit_up" => Some(0i64),
+            "weighted_alternating_sit_up" => Some(1i64),
+            "bent_knee_v_up" => Some(2i64),
+            "weighted_bent_knee_v_up" => Some(3i64),
+            "butterfly_sit_up" => Some(4i64),
+            "weighted_butterfly_situp" => Some(5i64),
+            "cross_punch_roll_up" => Some(6i64),
+            "weighted_cross_punch_roll_up" => Some(7i64),
+            "crossed_arms_sit_up" => Some(8i64),
+            "weighted_crossed_arms_sit_up" => Some(9i64),
+            "get_up_sit_up" => Some(10i64),
+            "weighted_get_up_sit_up" => Some(11i64),
+            "hovering_sit_up" => Some(12i64),
+            "weighted_hovering_sit_up" => Some(13i64),
+            "kettlebell_sit_up" => Some(14i64),
+            "medicine_ball_alternating_v_up" => Some(15i64),
+            "medicine_ball_sit_up" => Some(16i64),
+            "medicine_ball_v_up" => Some(17i64),
+            "modified_sit_up" => Some(18i64),
+            "negative_sit_up" => Some(19i64),
+            "one_arm_full_sit_up" => Some(20i64),
+            "reclining_circle" => Some(21i64),
+            "weighted_reclining_circle" => Some(22i64),
+            "reverse_curl_up" => Some(23i64),
+            "weighted_reverse_curl_up" => Some(24i64),
+            "single_leg_swiss_ball_jackknife" => Some(25i64),
+            "weighted_single_leg_swiss_ball_jackknife" => Some(26i64),
+            "the_teaser" => Some(27i64),
+            "the_teaser_weighted" => Some(28i64),
+            "three_part_roll_down" => Some(29i64),
+            "weighted_three_part_roll_down" => Some(30i64),
+            "v_up" => Some(31i64),
+            "weighted_v_up" => Some(32i64),
+            "weighted_russian_twist_on_swiss_ball" => Some(33i64),
+            "weighted_sit_up" => Some(34i64),
+            "x_abs" => Some(35i64),
+            "weighted_x_abs" => Some(36i64),
+            "sit_up" => Some(37i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             SitUpExerciseName::AlternatingSitUp => 0,
@@ -25784,6 +36259,51 @@ impl SitUpExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SitUpExerciseName::AlternatingSitUp => 0,
+            SitUpExerciseName::WeightedAlternatingSitUp => 1,
+            SitUpExerciseName::BentKneeVUp => 2,
+            SitUpExerciseName::WeightedBentKneeVUp => 3,
+            SitUpExerciseName::ButterflySitUp => 4,
+            SitUpExerciseName::WeightedButterflySitup => 5,
+            SitUpExerciseName::CrossPunchRollUp => 6,
+            SitUpExerciseName::WeightedCrossPunchRollUp => 7,
+            SitUpExerciseName::CrossedArmsSitUp => 8,
+            SitUpExerciseName::WeightedCrossedArmsSitUp => 9,
+            SitUpExerciseName::GetUpSitUp => 10,
+            SitUpExerciseName::WeightedGetUpSitUp => 11,
+            SitUpExerciseName::HoveringSitUp => 12,
+            SitUpExerciseName::WeightedHoveringSitUp => 13,
+            SitUpExerciseName::KettlebellSitUp => 14,
+            SitUpExerciseName::MedicineBallAlternatingVUp => 15,
+            SitUpExerciseName::MedicineBallSitUp => 16,
+            SitUpExerciseName::MedicineBallVUp => 17,
+            SitUpExerciseName::ModifiedSitUp => 18,
+            SitUpExerciseName::NegativeSitUp => 19,
+            SitUpExerciseName::OneArmFullSitUp => 20,
+            SitUpExerciseName::RecliningCircle => 21,
+            SitUpExerciseName::WeightedRecliningCircle => 22,
+            SitUpExerciseName::ReverseCurlUp => 23,
+            SitUpExerciseName::WeightedReverseCurlUp => 24,
+            SitUpExerciseName::SingleLegSwissBallJackknife => 25,
+            SitUpExerciseName::WeightedSingleLegSwissBallJackknife => 26,
+            SitUpExerciseName::TheTeaser => 27,
+            SitUpExerciseName::TheTeaserWeighted => 28,
+            SitUpExerciseName::ThreePartRollDown => 29,
+            SitUpExerciseName::WeightedThreePartRollDown => 30,
+            SitUpExerciseName::VUp => 31,
+            SitUpExerciseName::WeightedVUp => 32,
+            SitUpExerciseName::WeightedRussianTwistOnSwissBall => 33,
+            SitUpExerciseName::WeightedSitUp => 34,
+            SitUpExerciseName::XAbs => 35,
+            SitUpExerciseName::WeightedXAbs => 36,
+            SitUpExerciseName::SitUp => 37,
+            SitUpExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SitUpExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -25905,6 +36425,15 @@ impl Serialize for SitUpExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for SitUpExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::SitUpExerciseName, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SquatExerciseName {
     LegPress,
@@ -26100,7 +36629,206 @@ impl SquatExerciseName {
             _ => false,
         }
     }
-    pub fn as_u16(self) -> u16 {
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "leg_press" => Some(0i64),
+            "back_squat_with_body_bar" => Some(1i64),
+            "back_squats" => Some(2i64),
+            "weighted_back_squats" => Some(3i64),
+            "balancing_squat" => Some(4i64),
+            "weighted_balancing_squat" => Some(5i64),
+            "barbell_back_squat" => Some(6i64),
+            "barbell_box_squat" => Some(7i64),
+            "barbell_front_squat" => Some(8i64),
+            "barbell_hack_squat" => Some(9i64),
+            "barbell_hang_squat_snatch" => Some(10i64),
+            "barbell_lateral_step_up" => Some(11i64),
+            "barbell_quarter_squat" => Some(12i64),
+            "barbell_siff_squat" => Some(13i64),
+            "barbell_squat_snatch" => Some(14i64),
+            "barbell_squat_with_heels_raised" => Some(15i64),
+            "barbell_stepover" => Some(16i64),
+            "barbell_step_up" => Some(17i64),
+            "bench_squat_with_rotational_chop" => Some(18i64),
+            "weighted_bench_squat_with_rotational_chop" => Some(19i64),
+            "body_weight_wall_squat" => Some(20i64),
+            "weighted_wall_squat" => Some(21i64),
+            "box_step_squat" => Some(22i64),
+            "weighted_box_step_squat" => Some(23i64),
+            "braced_squat" => Some(24i64),
+            "crossed_arm_barbell_front_squat" => Some(25i64),
+            "crossover_dumbbell_step_up" => Some(26i64),
+            "dumbbell_front_squat" => Some(27i64),
+            "dumbbell_split_squat" => Some(28i64),
+            "dumbbell_squat" => Some(29i64),
+            "dumbbell_squat_clean" => Some(30i64),
+            "dumbbell_stepover" => Some(31i64),
+            "dumbbell_step_up" => Some(32i64),
+            "elevated_single_leg_squat" => Some(33i64),
+            "weighted_elevated_single_leg_squat" => Some(34i64),
+            "figure_four_squats" => Some(35i64),
+            "weighted_figure_four_squats" => Some(36i64),
+            "goblet_squat" => Some(37i64),
+            "kettlebell_squat" => Some(38i64),
+            "kettlebell_swing_overhead" => Some(39i64),
+            "kettlebell_swing_with_flip_to_squat" => Some(40i64),
+            "lateral_dumbbell_step_up" => Some(41i64),
+            "one_legged_squat" => Some(42i64),
+            "overhead_dumbbell_squat" => Some(43i64),
+            "overhead_squat" => Some(44i64),
+            "partial_single_leg_squat" => Some(45i64),
+            "weighted_partial_single_leg_squat" => Some(46i64),
+            "pistol_squat" => Some(47i64),
+            "weighted_pistol_squat" => Some(48i64),
+            "plie_slides" => Some(49i64),
+            "weighted_plie_slides" => Some(50i64),
+            "plie_squat" => Some(51i64),
+            "weighted_plie_squat" => Some(52i64),
+            "prisoner_squat" => Some(53i64),
+            "weighted_prisoner_squat" => Some(54i64),
+            "single_leg_bench_get_up" => Some(55i64),
+            "weighted_single_leg_bench_get_up" => Some(56i64),
+            "single_leg_bench_squat" => Some(57i64),
+            "weighted_single_leg_bench_squat" => Some(58i64),
+            "single_leg_squat_on_swiss_ball" => Some(59i64),
+            "weighted_single_leg_squat_on_swiss_ball" => Some(60i64),
+            "squat" => Some(61i64),
+            "weighted_squat" => Some(62i64),
+            "squats_with_band" => Some(63i64),
+            "staggered_squat" => Some(64i64),
+            "weighted_staggered_squat" => Some(65i64),
+            "step_up" => Some(66i64),
+            "weighted_step_up" => Some(67i64),
+            "suitcase_squats" => Some(68i64),
+            "sumo_squat" => Some(69i64),
+            "sumo_squat_slide_in" => Some(70i64),
+            "weighted_sumo_squat_slide_in" => Some(71i64),
+            "sumo_squat_to_high_pull" => Some(72i64),
+            "sumo_squat_to_stand" => Some(73i64),
+            "weighted_sumo_squat_to_stand" => Some(74i64),
+            "sumo_squat_with_rotation" => Some(75i64),
+            "weighted_sumo_squat_with_rotation" => Some(76i64),
+            "swiss_ball_body_weight_wall_squat" => Some(77i64),
+            "weighted_swiss_ball_wall_squat" => Some(78i64),
+            "thrusters" => Some(79i64),
+            "uneven_squat" => Some(80i64),
+            "weighted_uneven_squat" => Some(81i64),
+            "waist_slimming_squat" => Some(82i64),
+            "wall_ball" => Some(83i64),
+            "wide_stance_barbell_squat" => Some(84i64),
+            "wide_stance_goblet_squat" => Some(85i64),
+            "zercher_squat" => Some(86i64),
+            "kbs_overhead" => Some(87i64),
+            "squat_and_side_kick" => Some(88i64),
+            "squat_jumps_in_n_out" => Some(89i64),
+            "pilates_plie_squats_parallel_turned_out_flat_and_heels" => Some(90i64),
+            "releve_straight_leg_and_knee_bent_with_one_leg_variation" => Some(91i64),
+            _ => None,
+        }
+    }
+    pub fn as_u16(self) -> u16 {
+        match self {
+            SquatExerciseName::LegPress => 0,
+            SquatExerciseName::BackSquatWithBodyBar => 1,
+            SquatExerciseName::BackSquats => 2,
+            SquatExerciseName::WeightedBackSquats => 3,
+            SquatExerciseName::BalancingSquat => 4,
+            SquatExerciseName::WeightedBalancingSquat => 5,
+            SquatExerciseName::BarbellBackSquat => 6,
+            SquatExerciseName::BarbellBoxSquat => 7,
+            SquatExerciseName::BarbellFrontSquat => 8,
+            SquatExerciseName::BarbellHackSquat => 9,
+            SquatExerciseName::BarbellHangSquatSnatch => 10,
+            SquatExerciseName::BarbellLateralStepUp => 11,
+            SquatExerciseName::BarbellQuarterSquat => 12,
+            SquatExerciseName::BarbellSiffSquat => 13,
+            SquatExerciseName::BarbellSquatSnatch => 14,
+            SquatExerciseName::BarbellSquatWithHeelsRaised => 15,
+            SquatExerciseName::BarbellStepover => 16,
+            SquatExerciseName::BarbellStepUp => 17,
+            SquatExerciseName::BenchSquatWithRotationalChop => 18,
+            SquatExerciseName::WeightedBenchSquatWithRotationalChop => 19,
+            SquatExerciseName::BodyWeightWallSquat => 20,
+            SquatExerciseName::WeightedWallSquat => 21,
+            SquatExerciseName::BoxStepSquat => 22,
+            SquatExerciseName::WeightedBoxStepSquat => 23,
+            SquatExerciseName::BracedSquat => 24,
+            SquatExerciseName::CrossedArmBarbellFrontSquat => 25,
+            SquatExerciseName::CrossoverDumbbellStepUp => 26,
+            SquatExerciseName::DumbbellFrontSquat => 27,
+            SquatExerciseName::DumbbellSplitSquat => 28,
+            SquatExerciseName::DumbbellSquat => 29,
+            SquatExerciseName::DumbbellSquatClean => 30,
+            SquatExerciseName::DumbbellStepover => 31,
+            SquatExerciseName::DumbbellStepUp => 32,
+            SquatExerciseName::ElevatedSingleLegSquat => 33,
+            SquatExerciseName::WeightedElevatedSingleLegSquat => 34,
+            SquatExerciseName::FigureFourSquats => 35,
+            SquatExerciseName::WeightedFigureFourSquats => 36,
+            SquatExerciseName::GobletSquat => 37,
+            SquatExerciseName::KettlebellSquat => 38,
+            SquatExerciseName::KettlebellSwingOverhead => 39,
+            SquatExerciseName::KettlebellSwingWithFlipToSquat => 40,
+            SquatExerciseName::LateralDumbbellStepUp => 41,
+            SquatExerciseName::OneLeggedSquat => 42,
+            SquatExerciseName::OverheadDumbbellSquat => 43,
+            SquatExerciseName::OverheadSquat => 44,
+            SquatExerciseName::PartialSingleLegSquat => 45,
+            SquatExerciseName::WeightedPartialSingleLegSquat => 46,
+            SquatExerciseName::PistolSquat => 47,
+            SquatExerciseName::WeightedPistolSquat => 48,
+            SquatExerciseName::PlieSlides => 49,
+            SquatExerciseName::WeightedPlieSlides => 50,
+            SquatExerciseName::PlieSquat => 51,
+            SquatExerciseName::WeightedPlieSquat => 52,
+            SquatExerciseName::PrisonerSquat => 53,
+            SquatExerciseName::WeightedPrisonerSquat => 54,
+            SquatExerciseName::SingleLegBenchGetUp => 55,
+            SquatExerciseName::WeightedSingleLegBenchGetUp => 56,
+            SquatExerciseName::SingleLegBenchSquat => 57,
+            SquatExerciseName::WeightedSingleLegBenchSquat => 58,
+            SquatExerciseName::SingleLegSquatOnSwissBall => 59,
+            SquatExerciseName::WeightedSingleLegSquatOnSwissBall => 60,
+            SquatExerciseName::Squat => 61,
+            SquatExerciseName::WeightedSquat => 62,
+            SquatExerciseName::SquatsWithBand => 63,
+            SquatExerciseName::StaggeredSquat => 64,
+            SquatExerciseName::WeightedStaggeredSquat => 65,
+            SquatExerciseName::StepUp => 66,
+            SquatExerciseName::WeightedStepUp => 67,
+            SquatExerciseName::SuitcaseSquats => 68,
+            SquatExerciseName::SumoSquat => 69,
+            SquatExerciseName::SumoSquatSlideIn => 70,
+            SquatExerciseName::WeightedSumoSquatSlideIn => 71,
+            SquatExerciseName::SumoSquatToHighPull => 72,
+            SquatExerciseName::SumoSquatToStand => 73,
+            SquatExerciseName::WeightedSumoSquatToStand => 74,
+            SquatExerciseName::SumoSquatWithRotation => 75,
+            SquatExerciseName::WeightedSumoSquatWithRotation => 76,
+            SquatExerciseName::SwissBallBodyWeightWallSquat => 77,
+            SquatExerciseName::WeightedSwissBallWallSquat => 78,
+            SquatExerciseName::Thrusters => 79,
+            SquatExerciseName::UnevenSquat => 80,
+            SquatExerciseName::WeightedUnevenSquat => 81,
+            SquatExerciseName::WaistSlimmingSquat => 82,
+            SquatExerciseName::WallBall => 83,
+            SquatExerciseName::WideStanceBarbellSquat => 84,
+            SquatExerciseName::WideStanceGobletSquat => 85,
+            SquatExerciseName::ZercherSquat => 86,
+            SquatExerciseName::KbsOverhead => 87,
+            SquatExerciseName::SquatAndSideKick => 88,
+            SquatExerciseName::SquatJumpsInNOut => 89,
+            SquatExerciseName::PilatesPlieSquatsParallelTurnedOutFlatAndHeels => 90,
+            SquatExerciseName::ReleveStraightLegAndKneeBentWithOneLegVariation => 91,
+            SquatExerciseName::Value(value) => value,
+        }
+    }
+    pub fn as_i64(self) -> i64 {
+        self.as_u16() as i64
+    }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
         match self {
             SquatExerciseName::LegPress => 0,
             SquatExerciseName::BackSquatWithBodyBar => 1,
@@ -26194,12 +36922,9 @@ impl SquatExerciseName {
             SquatExerciseName::SquatJumpsInNOut => 89,
             SquatExerciseName::PilatesPlieSquatsParallelTurnedOutFlatAndHeels => 90,
             SquatExerciseName::ReleveStraightLegAndKneeBentWithOneLegVariation => 91,
-            SquatExerciseName::Value(value) => value,
+            SquatExerciseName::Value(value) => value as i64,
         }
     }
-    pub fn as_i64(self) -> i64 {
-        self.as_u16() as i64
-    }
 }
 impl fmt::Display for SquatExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -26452,6 +37177,15 @@ impl Serialize for SquatExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for SquatExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::SquatExerciseName, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum TotalBodyExerciseName {
     Burpee,
@@ -26488,6 +37222,24 @@ impl TotalBodyExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "burpee" => Some(0i64),
+            "weighted_burpee" => Some(1i64),
+            "burpee_box_jump" => Some(2i64),
+            "weighted_burpee_box_jump" => Some(3i64),
+            "high_pull_burpee" => Some(4i64),
+            "man_makers" => Some(5i64),
+            "one_arm_burpee" => Some(6i64),
+            "squat_thrusts" => Some(7i64),
+            "weighted_squat_thrusts" => Some(8i64),
+            "squat_plank_push_up" => Some(9i64),
+            "weighted_squat_plank_push_up" => Some(10i64),
+            "standing_t_rotation_balance" => Some(11i64),
+            "weighted_standing_t_rotation_balance" => Some(12i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             TotalBodyExerciseName::Burpee => 0,
@@ -26509,6 +37261,26 @@ impl TotalBodyExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            TotalBodyExerciseName::Burpee => 0,
+            TotalBodyExerciseName::WeightedBurpee => 1,
+            TotalBodyExerciseName::BurpeeBoxJump => 2,
+            TotalBodyExerciseName::WeightedBurpeeBoxJump => 3,
+            TotalBodyExerciseName::HighPullBurpee => 4,
+            TotalBodyExerciseName::ManMakers => 5,
+            TotalBodyExerciseName::OneArmBurpee => 6,
+            TotalBodyExerciseName::SquatThrusts => 7,
+            TotalBodyExerciseName::WeightedSquatThrusts => 8,
+            TotalBodyExerciseName::SquatPlankPushUp => 9,
+            TotalBodyExerciseName::WeightedSquatPlankPushUp => 10,
+            TotalBodyExerciseName::StandingTRotationBalance => 11,
+            TotalBodyExerciseName::WeightedStandingTRotationBalance => 12,
+            TotalBodyExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for TotalBodyExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -26572,6 +37344,16 @@ impl Serialize for TotalBodyExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for TotalBodyExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::TotalBodyExerciseName, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum TricepsExtensionExerciseName {
     BenchDip,
@@ -26664,6 +37446,52 @@ impl TricepsExtensionExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "bench_dip" => Some(0i64),
+            "weighted_bench_dip" => Some(1i64),
+            "body_weight_dip" => Some(2i64),
+            "cable_kickback" => Some(3i64),
+            "cable_lying_triceps_extension" => Some(4i64),
+            "cable_overhead_triceps_extension" => Some(5i64),
+            "dumbbell_kickback" => Some(6i64),
+            "dumbbell_lying_triceps_extension" => Some(7i64),
+            "ez_bar_overhead_triceps_extension" => Some(8i64),
+            "incline_dip" => Some(9i64),
+            "weighted_incline_dip" => Some(10i64),
+            "incline_ez_bar_lying_triceps_extension" => Some(11i64),
+            "lying_dumbbell_pullover_to_extension" => Some(12i64),
+            "lying_ez_bar_triceps_extension" => Some(13i64),
+            "lying_triceps_extension_to_close_grip_bench_press" => Some(14i64),
+            "overhead_dumbbell_triceps_extension" => Some(15i64),
+            "reclining_triceps_press" => Some(16i64),
+            "reverse_grip_pressdown" => Some(17i64),
+            "reverse_grip_triceps_pressdown" => Some(18i64),
+            "rope_pressdown" => Some(19i64),
+            "seated_barbell_overhead_triceps_extension" => Some(20i64),
+            "seated_dumbbell_overhead_triceps_extension" => Some(21i64),
+            "seated_ez_bar_overhead_triceps_extension" => Some(22i64),
+            "seated_single_arm_overhead_dumbbell_extension" => Some(23i64),
+            "single_arm_dumbbell_overhead_triceps_extension" => Some(24i64),
+            "single_dumbbell_seated_overhead_triceps_extension" => Some(25i64),
+            "single_leg_bench_dip_and_kick" => Some(26i64),
+            "weighted_single_leg_bench_dip_and_kick" => Some(27i64),
+            "single_leg_dip" => Some(28i64),
+            "weighted_single_leg_dip" => Some(29i64),
+            "static_lying_triceps_extension" => Some(30i64),
+            "suspended_dip" => Some(31i64),
+            "weighted_suspended_dip" => Some(32i64),
+            "swiss_ball_dumbbell_lying_triceps_extension" => Some(33i64),
+            "swiss_ball_ez_bar_lying_triceps_extension" => Some(34i64),
+            "swiss_ball_ez_bar_overhead_triceps_extension" => Some(35i64),
+            "tabletop_dip" => Some(36i64),
+            "weighted_tabletop_dip" => Some(37i64),
+            "triceps_extension_on_floor" => Some(38i64),
+            "triceps_pressdown" => Some(39i64),
+            "weighted_dip" => Some(40i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             TricepsExtensionExerciseName::BenchDip => 0,
@@ -26713,6 +37541,54 @@ impl TricepsExtensionExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            TricepsExtensionExerciseName::BenchDip => 0,
+            TricepsExtensionExerciseName::WeightedBenchDip => 1,
+            TricepsExtensionExerciseName::BodyWeightDip => 2,
+            TricepsExtensionExerciseName::CableKickback => 3,
+            TricepsExtensionExerciseName::CableLyingTricepsExtension => 4,
+            TricepsExtensionExerciseName::CableOverheadTricepsExtension => 5,
+            TricepsExtensionExerciseName::DumbbellKickback => 6,
+            TricepsExtensionExerciseName::DumbbellLyingTricepsExtension => 7,
+            TricepsExtensionExerciseName::EzBarOverheadTricepsExtension => 8,
+            TricepsExtensionExerciseName::InclineDip => 9,
+            TricepsExtensionExerciseName::WeightedInclineDip => 10,
+            TricepsExtensionExerciseName::InclineEzBarLyingTricepsExtension => 11,
+            TricepsExtensionExerciseName::LyingDumbbellPulloverToExtension => 12,
+            TricepsExtensionExerciseName::LyingEzBarTricepsExtension => 13,
+            TricepsExtensionExerciseName::LyingTricepsExtensionToCloseGripBenchPress => 14,
+            TricepsExtensionExerciseName::OverheadDumbbellTricepsExtension => 15,
+            TricepsExtensionExerciseName::RecliningTricepsPress => 16,
+            TricepsExtensionExerciseName::ReverseGripPressdown => 17,
+            TricepsExtensionExerciseName::ReverseGripTricepsPressdown => 18,
+            TricepsExtensionExerciseName::RopePressdown => 19,
+            TricepsExtensionExerciseName::SeatedBarbellOverheadTricepsExtension => 20,
+            TricepsExtensionExerciseName::SeatedDumbbellOverheadTricepsExtension => 21,
+            TricepsExtensionExerciseName::SeatedEzBarOverheadTricepsExtension => 22,
+            TricepsExtensionExerciseName::SeatedSingleArmOverheadDumbbellExtension => 23,
+            TricepsExtensionExerciseName::SingleArmDumbbellOverheadTricepsExtension => 24,
+            TricepsExtensionExerciseName::SingleDumbbellSeatedOverheadTricepsExtension => 25,
+            TricepsExtensionExerciseName::SingleLegBenchDipAndKick => 26,
+            TricepsExtensionExerciseName::WeightedSingleLegBenchDipAndKick => 27,
+            TricepsExtensionExerciseName::SingleLegDip => 28,
+            TricepsExtensionExerciseName::WeightedSingleLegDip => 29,
+            TricepsExtensionExerciseName::StaticLyingTricepsExtension => 30,
+            TricepsExtensionExerciseName::SuspendedDip => 31,
+            TricepsExtensionExerciseName::WeightedSuspendedDip => 32,
+            TricepsExtensionExerciseName::SwissBallDumbbellLyingTricepsExtension => 33,
+            TricepsExtensionExerciseName::SwissBallEzBarLyingTricepsExtension => 34,
+            TricepsExtensionExerciseName::SwissBallEzBarOverheadTricepsExtension => 35,
+            TricepsExtensionExerciseName::TabletopDip => 36,
+            TricepsExtensionExerciseName::WeightedTabletopDip => 37,
+            TricepsExtensionExerciseName::TricepsExtensionOnFloor => 38,
+            TricepsExtensionExerciseName::TricepsPressdown => 39,
+            TricepsExtensionExerciseName::WeightedDip => 40,
+            TricepsExtensionExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for TricepsExtensionExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -26880,6 +37756,20 @@ impl Serialize for TricepsExtensionExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for TricepsExtensionExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(
+            deserializer,
+            FieldDataType::TricepsExtensionExerciseName,
+            invalid,
+        )
+        .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WarmUpExerciseName {
     QuadrupedRocking,
@@ -26953,6 +37843,42 @@ impl WarmUpExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "quadruped_rocking" => Some(0i64),
+            "neck_tilts" => Some(1i64),
+            "ankle_circles" => Some(2i64),
+            "ankle_dorsiflexion_with_band" => Some(3i64),
+            "ankle_internal_rotation" => Some(4i64),
+            "arm_circles" => Some(5i64),
+            "bent_over_reach_to_sky" => Some(6i64),
+            "cat_camel" => Some(7i64),
+            "elbow_to_foot_lunge" => Some(8i64),
+            "forward_and_backward_leg_swings" => Some(9i64),
+            "groiners" => Some(10i64),
+            "inverted_hamstring_stretch" => Some(11i64),
+            "lateral_duck_under" => Some(12i64),
+            "neck_rotations" => Some(13i64),
+            "opposite_arm_and_leg_balance" => Some(14i64),
+            "reach_roll_and_lift" => Some(15i64),
+            "scorpion" => Some(16i64),
+            "shoulder_circles" => Some(17i64),
+            "side_to_side_leg_swings" => Some(18i64),
+            "sleeper_stretch" => Some(19i64),
+            "slide_out" => Some(20i64),
+            "swiss_ball_hip_crossover" => Some(21i64),
+            "swiss_ball_reach_roll_and_lift" => Some(22i64),
+            "swiss_ball_windshield_wipers" => Some(23i64),
+            "thoracic_rotation" => Some(24i64),
+            "walking_high_kicks" => Some(25i64),
+            "walking_high_knees" => Some(26i64),
+            "walking_knee_hugs" => Some(27i64),
+            "walking_leg_cradles" => Some(28i64),
+            "walkout" => Some(29i64),
+            "walkout_from_push_up_position" => Some(30i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             WarmUpExerciseName::QuadrupedRocking => 0,
@@ -26992,6 +37918,44 @@ impl WarmUpExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            WarmUpExerciseName::QuadrupedRocking => 0,
+            WarmUpExerciseName::NeckTilts => 1,
+            WarmUpExerciseName::AnkleCircles => 2,
+            WarmUpExerciseName::AnkleDorsiflexionWithBand => 3,
+            WarmUpExerciseName::AnkleInternalRotation => 4,
+            WarmUpExerciseName::ArmCircles => 5,
+            WarmUpExerciseName::BentOverReachToSky => 6,
+            WarmUpExerciseName::CatCamel => 7,
+            WarmUpExerciseName::ElbowToFootLunge => 8,
+            WarmUpExerciseName::ForwardAndBackwardLegSwings => 9,
+            WarmUpExerciseName::Groiners => 10,
+            WarmUpExerciseName::InvertedHamstringStretch => 11,
+            WarmUpExerciseName::LateralDuckUnder => 12,
+            WarmUpExerciseName::NeckRotations => 13,
+            WarmUpExerciseName::OppositeArmAndLegBalance => 14,
+            WarmUpExerciseName::ReachRollAndLift => 15,
+            WarmUpExerciseName::Scorpion => 16,
+            WarmUpExerciseName::ShoulderCircles => 17,
+            WarmUpExerciseName::SideToSideLegSwings => 18,
+            WarmUpExerciseName::SleeperStretch => 19,
+            WarmUpExerciseName::SlideOut => 20,
+            WarmUpExerciseName::SwissBallHipCrossover => 21,
+            WarmUpExerciseName::SwissBallReachRollAndLift => 22,
+            WarmUpExerciseName::SwissBallWindshieldWipers => 23,
+            WarmUpExerciseName::ThoracicRotation => 24,
+            WarmUpExerciseName::WalkingHighKicks => 25,
+            WarmUpExerciseName::WalkingHighKnees => 26,
+            WarmUpExerciseName::WalkingKneeHugs => 27,
+            WarmUpExerciseName::WalkingLegCradles => 28,
+            WarmUpExerciseName::Walkout => 29,
+            WarmUpExerciseName::WalkoutFromPushUpPosition => 30,
+            WarmUpExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for WarmUpExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -27097,6 +38061,16 @@ impl Serialize for WarmUpExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for WarmUpExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::WarmUpExerciseName, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum RunExerciseName {
     Run,
@@ -27115,6 +38089,15 @@ impl RunExerciseName {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "run" => Some(0i64),
+            "walk" => Some(1i64),
+            "jog" => Some(2i64),
+            "sprint" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             RunExerciseName::Run => 0,
@@ -27127,6 +38110,17 @@ impl RunExerciseName {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            RunExerciseName::Run => 0,
+            RunExerciseName::Walk => 1,
+            RunExerciseName::Jog => 2,
+            RunExerciseName::Sprint => 3,
+            RunExerciseName::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for RunExerciseName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -27166,6 +38160,15 @@ impl Serialize for RunExerciseName {
         }
     }
 }
+impl<'de> Deserialize<'de> for RunExerciseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::RunExerciseName, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WaterType {
     Fresh,
@@ -27184,6 +38187,15 @@ impl WaterType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "fresh" => Some(0i64),
+            "salt" => Some(1i64),
+            "en13319" => Some(2i64),
+            "custom" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             WaterType::Fresh => 0,
@@ -27196,6 +38208,17 @@ impl WaterType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            WaterType::Fresh => 0,
+            WaterType::Salt => 1,
+            WaterType::En13319 => 2,
+            WaterType::Custom => 3,
+            WaterType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for WaterType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -27232,6 +38255,15 @@ impl Serialize for WaterType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for WaterType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::WaterType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum TissueModelType {
     #[doc = "Buhlmann's decompression algorithm, version C"]
@@ -27245,6 +38277,12 @@ impl TissueModelType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "zhl_16c" => Some(0i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             TissueModelType::Zhl16c => 0,
@@ -27254,6 +38292,14 @@ impl TissueModelType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            TissueModelType::Zhl16c => 0,
+            TissueModelType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for TissueModelType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -27284,6 +38330,15 @@ impl Serialize for TissueModelType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for TissueModelType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::TissueModelType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DiveGasStatus {
     Disabled,
@@ -27300,6 +38355,14 @@ impl DiveGasStatus {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "disabled" => Some(0i64),
+            "enabled" => Some(1i64),
+            "backup_only" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             DiveGasStatus::Disabled => 0,
@@ -27311,6 +38374,16 @@ impl DiveGasStatus {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DiveGasStatus::Disabled => 0,
+            DiveGasStatus::Enabled => 1,
+            DiveGasStatus::BackupOnly => 2,
+            DiveGasStatus::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DiveGasStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -27345,6 +38418,15 @@ impl Serialize for DiveGasStatus {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for DiveGasStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DiveGasStatus, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DiveAlert {
     NdlReached,
@@ -27440,7 +38522,100 @@ impl DiveAlert {
             _ => false,
         }
     }
-    pub fn as_u8(self) -> u8 {
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "ndl_reached" => Some(0i64),
+            "gas_switch_prompted" => Some(1i64),
+            "near_surface" => Some(2i64),
+            "approaching_ndl" => Some(3i64),
+            "po2_warn" => Some(4i64),
+            "po2_crit_high" => Some(5i64),
+            "po2_crit_low" => Some(6i64),
+            "time_alert" => Some(7i64),
+            "depth_alert" => Some(8i64),
+            "deco_ceiling_broken" => Some(9i64),
+            "deco_complete" => Some(10i64),
+            "safety_stop_broken" => Some(11i64),
+            "safety_stop_complete" => Some(12i64),
+            "cns_warning" => Some(13i64),
+            "cns_critical" => Some(14i64),
+            "otu_warning" => Some(15i64),
+            "otu_critical" => Some(16i64),
+            "ascent_critical" => Some(17i64),
+            "alert_dismissed_by_key" => Some(18i64),
+            "alert_dismissed_by_timeout" => Some(19i64),
+            "battery_low" => Some(20i64),
+            "battery_critical" => Some(21i64),
+            "safety_stop_started" => Some(22i64),
+            "approaching_first_deco_stop" => Some(23i64),
+            "setpoint_switch_auto_low" => Some(24i64),
+            "setpoint_switch_auto_high" => Some(25i64),
+            "setpoint_switch_manual_low" => Some(26i64),
+            "setpoint_switch_manual_high" => Some(27i64),
+            "auto_setpoint_switch_ignored" => Some(28i64),
+            "switched_to_open_circuit" => Some(29i64),
+            "switched_to_closed_circuit" => Some(30i64),
+            "tank_battery_low" => Some(32i64),
+            "po2_ccr_dil_low" => Some(33i64),
+            "deco_stop_cleared" => Some(34i64),
+            "apnea_neutral_buoyancy" => Some(35i64),
+            "apnea_target_depth" => Some(36i64),
+            "apnea_surface" => Some(37i64),
+            "apnea_high_speed" => Some(38i64),
+            "apnea_low_speed" => Some(39i64),
+            _ => None,
+        }
+    }
+    pub fn as_u8(self) -> u8 {
+        match self {
+            DiveAlert::NdlReached => 0,
+            DiveAlert::GasSwitchPrompted => 1,
+            DiveAlert::NearSurface => 2,
+            DiveAlert::ApproachingNdl => 3,
+            DiveAlert::Po2Warn => 4,
+            DiveAlert::Po2CritHigh => 5,
+            DiveAlert::Po2CritLow => 6,
+            DiveAlert::TimeAlert => 7,
+            DiveAlert::DepthAlert => 8,
+            DiveAlert::DecoCeilingBroken => 9,
+            DiveAlert::DecoComplete => 10,
+            DiveAlert::SafetyStopBroken => 11,
+            DiveAlert::SafetyStopComplete => 12,
+            DiveAlert::CnsWarning => 13,
+            DiveAlert::CnsCritical => 14,
+            DiveAlert::OtuWarning => 15,
+            DiveAlert::OtuCritical => 16,
+            DiveAlert::AscentCritical => 17,
+            DiveAlert::AlertDismissedByKey => 18,
+            DiveAlert::AlertDismissedByTimeout => 19,
+            DiveAlert::BatteryLow => 20,
+            DiveAlert::BatteryCritical => 21,
+            DiveAlert::SafetyStopStarted => 22,
+            DiveAlert::ApproachingFirstDecoStop => 23,
+            DiveAlert::SetpointSwitchAutoLow => 24,
+            DiveAlert::SetpointSwitchAutoHigh => 25,
+            DiveAlert::SetpointSwitchManualLow => 26,
+            DiveAlert::SetpointSwitchManualHigh => 27,
+            DiveAlert::AutoSetpointSwitchIgnored => 28,
+            DiveAlert::SwitchedToOpenCircuit => 29,
+            DiveAlert::SwitchedToClosedCircuit => 30,
+            DiveAlert::TankBatteryLow => 32,
+            DiveAlert::Po2CcrDilLow => 33,
+            DiveAlert::DecoStopCleared => 34,
+            DiveAlert::ApneaNeutralBuoyancy => 35,
+            DiveAlert::ApneaTargetDepth => 36,
+            DiveAlert::ApneaSurface => 37,
+            DiveAlert::ApneaHighSpeed => 38,
+            DiveAlert::ApneaLowSpeed => 39,
+            DiveAlert::UnknownVariant(value) => value,
+        }
+    }
+    pub fn as_i64(self) -> i64 {
+        self.as_u8() as i64
+    }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
         match self {
             DiveAlert::NdlReached => 0,
             DiveAlert::GasSwitchPrompted => 1,
@@ -27481,12 +38656,9 @@ impl DiveAlert {
             DiveAlert::ApneaSurface => 37,
             DiveAlert::ApneaHighSpeed => 38,
             DiveAlert::ApneaLowSpeed => 39,
-            DiveAlert::UnknownVariant(value) => value,
+            DiveAlert::UnknownVariant(value) => value as i64,
         }
     }
-    pub fn as_i64(self) -> i64 {
-        self.as_u8() as i64
-    }
 }
 impl fmt::Display for DiveAlert {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -27593,6 +38765,15 @@ impl Serialize for DiveAlert {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for DiveAlert {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DiveAlert, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DiveAlarmType {
     #[doc = "Alarm when a certain depth is crossed"]
@@ -27612,6 +38793,14 @@ impl DiveAlarmType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "depth" => Some(0i64),
+            "time" => Some(1i64),
+            "speed" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             DiveAlarmType::Depth => 0,
@@ -27623,6 +38812,16 @@ impl DiveAlarmType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DiveAlarmType::Depth => 0,
+            DiveAlarmType::Time => 1,
+            DiveAlarmType::Speed => 2,
+            DiveAlarmType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DiveAlarmType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -27657,6 +38856,15 @@ impl Serialize for DiveAlarmType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for DiveAlarmType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DiveAlarmType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DiveBacklightMode {
     AtDepth,
@@ -27671,6 +38879,13 @@ impl DiveBacklightMode {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "at_depth" => Some(0i64),
+            "always_on" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             DiveBacklightMode::AtDepth => 0,
@@ -27681,6 +38896,15 @@ impl DiveBacklightMode {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DiveBacklightMode::AtDepth => 0,
+            DiveBacklightMode::AlwaysOn => 1,
+            DiveBacklightMode::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DiveBacklightMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -27713,6 +38937,15 @@ impl Serialize for DiveBacklightMode {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for DiveBacklightMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DiveBacklightMode, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SleepLevel {
     Unmeasurable,
@@ -27733,6 +38966,16 @@ impl SleepLevel {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "unmeasurable" => Some(0i64),
+            "awake" => Some(1i64),
+            "light" => Some(2i64),
+            "deep" => Some(3i64),
+            "rem" => Some(4i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SleepLevel::Unmeasurable => 0,
@@ -27746,6 +38989,18 @@ impl SleepLevel {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SleepLevel::Unmeasurable => 0,
+            SleepLevel::Awake => 1,
+            SleepLevel::Light => 2,
+            SleepLevel::Deep => 3,
+            SleepLevel::Rem => 4,
+            SleepLevel::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SleepLevel {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -27784,6 +39039,15 @@ impl Serialize for SleepLevel {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for SleepLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SleepLevel, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Spo2MeasurementType {
     OffWrist,
@@ -27802,6 +39066,15 @@ impl Spo2MeasurementType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "off_wrist" => Some(0i64),
+            "spot_check" => Some(1i64),
+            "continuous_check" => Some(2i64),
+            "periodic" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             Spo2MeasurementType::OffWrist => 0,
@@ -27814,6 +39087,17 @@ impl Spo2MeasurementType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            Spo2MeasurementType::OffWrist => 0,
+            Spo2MeasurementType::SpotCheck => 1,
+            Spo2MeasurementType::ContinuousCheck => 2,
+            Spo2MeasurementType::Periodic => 3,
+            Spo2MeasurementType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for Spo2MeasurementType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -27850,6 +39134,16 @@ impl Serialize for Spo2MeasurementType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for Spo2MeasurementType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::Spo2MeasurementType, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum CcrSetpointSwitchMode {
     #[doc = "User switches setpoints manually"]
@@ -27866,6 +39160,13 @@ impl CcrSetpointSwitchMode {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "manual" => Some(0i64),
+            "automatic" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             CcrSetpointSwitchMode::Manual => 0,
@@ -27876,6 +39177,15 @@ impl CcrSetpointSwitchMode {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            CcrSetpointSwitchMode::Manual => 0,
+            CcrSetpointSwitchMode::Automatic => 1,
+            CcrSetpointSwitchMode::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for CcrSetpointSwitchMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -27908,6 +39218,16 @@ impl Serialize for CcrSetpointSwitchMode {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for CcrSetpointSwitchMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::CcrSetpointSwitchMode, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DiveGasMode {
     OpenCircuit,
@@ -27922,6 +39242,13 @@ impl DiveGasMode {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "open_circuit" => Some(0i64),
+            "closed_circuit_diluent" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             DiveGasMode::OpenCircuit => 0,
@@ -27932,6 +39259,15 @@ impl DiveGasMode {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            DiveGasMode::OpenCircuit => 0,
+            DiveGasMode::ClosedCircuitDiluent => 1,
+            DiveGasMode::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for DiveGasMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -27964,6 +39300,15 @@ impl Serialize for DiveGasMode {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for DiveGasMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::DiveGasMode, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ProjectileType {
     #[doc = "Arrow projectile type"]
@@ -27992,6 +39337,17 @@ impl ProjectileType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "arrow" => Some(0i64),
+            "rifle_cartridge" => Some(1i64),
+            "pistol_cartridge" => Some(2i64),
+            "shotshell" => Some(3i64),
+            "air_rifle_pellet" => Some(4i64),
+            "other" => Some(5i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             ProjectileType::Arrow => 0,
@@ -28006,6 +39362,19 @@ impl ProjectileType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ProjectileType::Arrow => 0,
+            ProjectileType::RifleCartridge => 1,
+            ProjectileType::PistolCartridge => 2,
+            ProjectileType::Shotshell => 3,
+            ProjectileType::AirRiflePellet => 4,
+            ProjectileType::Other => 5,
+            ProjectileType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ProjectileType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -28046,6 +39415,15 @@ impl Serialize for ProjectileType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for ProjectileType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::ProjectileType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum FaveroProduct {
     AssiomaUno,
@@ -28060,6 +39438,13 @@ impl FaveroProduct {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "assioma_uno" => Some(10i64),
+            "assioma_duo" => Some(12i64),
+            _ => None,
+        }
+    }
     pub fn as_u16(self) -> u16 {
         match self {
             FaveroProduct::AssiomaUno => 10,
@@ -28070,6 +39455,15 @@ impl FaveroProduct {
     pub fn as_i64(self) -> i64 {
         self.as_u16() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            FaveroProduct::AssiomaUno => 10,
+            FaveroProduct::AssiomaDuo => 12,
+            FaveroProduct::Value(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for FaveroProduct {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -28105,6 +39499,15 @@ impl Serialize for FaveroProduct {
         }
     }
 }
+impl<'de> Deserialize<'de> for FaveroProduct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u16::MAX);
+        deserialize_variant(deserializer, FieldDataType::FaveroProduct, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SplitType {
     AscentSplit,
@@ -28161,6 +39564,32 @@ impl SplitType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "ascent_split" => Some(1i64),
+            "descent_split" => Some(2i64),
+            "interval_active" => Some(3i64),
+            "interval_rest" => Some(4i64),
+            "interval_warmup" => Some(5i64),
+            "interval_cooldown" => Some(6i64),
+            "interval_recovery" => Some(7i64),
+            "interval_other" => Some(8i64),
+            "climb_active" => Some(9i64),
+            "climb_rest" => Some(10i64),
+            "surf_active" => Some(11i64),
+            "run_active" => Some(12i64),
+            "run_rest" => Some(13i64),
+            "workout_round" => Some(14i64),
+            "rwd_run" => Some(17i64),
+            "rwd_walk" => Some(18i64),
+            "windsurf_active" => Some(21i64),
+            "rwd_stand" => Some(22i64),
+            "transition" => Some(23i64),
+            "ski_lift_split" => Some(28i64),
+            "ski_run_split" => Some(29i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             SplitType::AscentSplit => 1,
@@ -28190,6 +39619,34 @@ impl SplitType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            SplitType::AscentSplit => 1,
+            SplitType::DescentSplit => 2,
+            SplitType::IntervalActive => 3,
+            SplitType::IntervalRest => 4,
+            SplitType::IntervalWarmup => 5,
+            SplitType::IntervalCooldown => 6,
+            SplitType::IntervalRecovery => 7,
+            SplitType::IntervalOther => 8,
+            SplitType::ClimbActive => 9,
+            SplitType::ClimbRest => 10,
+            SplitType::SurfActive => 11,
+            SplitType::RunActive => 12,
+            SplitType::RunRest => 13,
+            SplitType::WorkoutRound => 14,
+            SplitType::RwdRun => 17,
+            SplitType::RwdWalk => 18,
+            SplitType::WindsurfActive => 21,
+            SplitType::RwdStand => 22,
+            SplitType::Transition => 23,
+            SplitType::SkiLiftSplit => 28,
+            SplitType::SkiRunSplit => 29,
+            SplitType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for SplitType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -28260,6 +39717,15 @@ impl Serialize for SplitType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for SplitType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::SplitType, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum ClimbProEvent {
     Approach,
@@ -28276,6 +39742,14 @@ impl ClimbProEvent {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "approach" => Some(0i64),
+            "start" => Some(1i64),
+            "complete" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             ClimbProEvent::Approach => 0,
@@ -28287,6 +39761,16 @@ impl ClimbProEvent {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            ClimbProEvent::Approach => 0,
+            ClimbProEvent::Start => 1,
+            ClimbProEvent::Complete => 2,
+            ClimbProEvent::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for ClimbProEvent {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -28321,6 +39805,15 @@ impl Serialize for ClimbProEvent {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for ClimbProEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::ClimbProEvent, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum GasConsumptionRateType {
     #[doc = "Pressure-based Surface Air Consumption"]
@@ -28340,6 +39833,14 @@ impl GasConsumptionRateType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "pressure_sac" => Some(0i64),
+            "volume_sac" => Some(1i64),
+            "rmv" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             GasConsumptionRateType::PressureSac => 0,
@@ -28351,6 +39852,16 @@ impl GasConsumptionRateType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            GasConsumptionRateType::PressureSac => 0,
+            GasConsumptionRateType::VolumeSac => 1,
+            GasConsumptionRateType::Rmv => 2,
+            GasConsumptionRateType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for GasConsumptionRateType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -28385,6 +39896,16 @@ impl Serialize for GasConsumptionRateType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for GasConsumptionRateType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::GasConsumptionRateType, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum TapSensitivity {
     High,
@@ -28401,6 +39922,14 @@ impl TapSensitivity {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "high" => Some(0i64),
+            "medium" => Some(1i64),
+            "low" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             TapSensitivity::High => 0,
@@ -28412,6 +39941,16 @@ impl TapSensitivity {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            TapSensitivity::High => 0,
+            TapSensitivity::Medium => 1,
+            TapSensitivity::Low => 2,
+            TapSensitivity::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for TapSensitivity {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -28446,6 +39985,15 @@ impl Serialize for TapSensitivity {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for TapSensitivity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::TapSensitivity, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum RadarThreatLevelType {
     ThreatUnknown,
@@ -28464,6 +40012,15 @@ impl RadarThreatLevelType {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "threat_unknown" => Some(0i64),
+            "threat_none" => Some(1i64),
+            "threat_approaching" => Some(2i64),
+            "threat_approaching_fast" => Some(3i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             RadarThreatLevelType::ThreatUnknown => 0,
@@ -28476,6 +40033,17 @@ impl RadarThreatLevelType {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            RadarThreatLevelType::ThreatUnknown => 0,
+            RadarThreatLevelType::ThreatNone => 1,
+            RadarThreatLevelType::ThreatApproaching => 2,
+            RadarThreatLevelType::ThreatApproachingFast => 3,
+            RadarThreatLevelType::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for RadarThreatLevelType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -28512,6 +40080,16 @@ impl Serialize for RadarThreatLevelType {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for RadarThreatLevelType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::RadarThreatLevelType, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum MaxMetSpeedSource {
     OnboardGps,
@@ -28528,6 +40106,14 @@ impl MaxMetSpeedSource {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "onboard_gps" => Some(0i64),
+            "connected_gps" => Some(1i64),
+            "cadence" => Some(2i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             MaxMetSpeedSource::OnboardGps => 0,
@@ -28539,6 +40125,16 @@ impl MaxMetSpeedSource {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            MaxMetSpeedSource::OnboardGps => 0,
+            MaxMetSpeedSource::ConnectedGps => 1,
+            MaxMetSpeedSource::Cadence => 2,
+            MaxMetSpeedSource::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for MaxMetSpeedSource {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -28573,6 +40169,15 @@ impl Serialize for MaxMetSpeedSource {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for MaxMetSpeedSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::MaxMetSpeedSource, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum MaxMetHeartRateSource {
     #[doc = "Wrist Heart Rate Monitor"]
@@ -28589,6 +40194,13 @@ impl MaxMetHeartRateSource {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "whr" => Some(0i64),
+            "hrm" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             MaxMetHeartRateSource::Whr => 0,
@@ -28599,6 +40211,15 @@ impl MaxMetHeartRateSource {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            MaxMetHeartRateSource::Whr => 0,
+            MaxMetHeartRateSource::Hrm => 1,
+            MaxMetHeartRateSource::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for MaxMetHeartRateSource {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -28631,6 +40252,16 @@ impl Serialize for MaxMetHeartRateSource {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for MaxMetHeartRateSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::MaxMetHeartRateSource, invalid)
+            .map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum HrvStatus {
     None,
@@ -28651,6 +40282,16 @@ impl HrvStatus {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "none" => Some(0i64),
+            "poor" => Some(1i64),
+            "low" => Some(2i64),
+            "unbalanced" => Some(3i64),
+            "balanced" => Some(4i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             HrvStatus::None => 0,
@@ -28664,6 +40305,18 @@ impl HrvStatus {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            HrvStatus::None => 0,
+            HrvStatus::Poor => 1,
+            HrvStatus::Low => 2,
+            HrvStatus::Unbalanced => 3,
+            HrvStatus::Balanced => 4,
+            HrvStatus::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for HrvStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -28702,6 +40355,15 @@ impl Serialize for HrvStatus {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for HrvStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::HrvStatus, invalid).map(Self::from)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum NoFlyTimeMode {
     #[doc = "Standard Diver Alert Network no-fly guidance"]
@@ -28718,6 +40380,13 @@ impl NoFlyTimeMode {
             _ => false,
         }
     }
+    pub fn variant_value(name: &str) -> Option<i64> {
+        match name {
+            "standard" => Some(0i64),
+            "flat_24_hours" => Some(1i64),
+            _ => None,
+        }
+    }
     pub fn as_u8(self) -> u8 {
         match self {
             NoFlyTimeMode::Standard => 0,
@@ -28728,6 +40397,15 @@ impl NoFlyTimeMode {
     pub fn as_i64(self) -> i64 {
         self.as_u8() as i64
     }
+    #[doc = " Numeric code of the variant in the FIT profile, stable across releases even when the"]
+    #[doc = " Rust identifier of the variant changes"]
+    pub const fn code(self) -> i64 {
+        match self {
+            NoFlyTimeMode::Standard => 0,
+            NoFlyTimeMode::Flat24Hours => 1,
+            NoFlyTimeMode::UnknownVariant(value) => value as i64,
+        }
+    }
 }
 impl fmt::Display for NoFlyTimeMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -28760,3 +40438,12 @@ impl Serialize for NoFlyTimeMode {
         serializer.serialize_str(&self.to_string())
     }
 }
+impl<'de> Deserialize<'de> for NoFlyTimeMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let invalid = i64::from(u8::MAX);
+        deserialize_variant(deserializer, FieldDataType::NoFlyTimeMode, invalid).map(Self::from)
+    }
+}
@@ -0,0 +1,486 @@
+#![doc = "Auto generated groups of messages from FIT SDK Release: 21.141.00"]
+use super::field_types::MesgNum;
+impl MesgNum {
+    #[doc = "Messages recorded in activity files"]
+    pub const ACTIVITY_MESSAGES: &'static [MesgNum] = &[
+        MesgNum::Activity,
+        MesgNum::Session,
+        MesgNum::Lap,
+        MesgNum::Length,
+        MesgNum::Record,
+        MesgNum::Event,
+        MesgNum::DeviceInfo,
+        MesgNum::DeviceAuxBatteryInfo,
+        MesgNum::TrainingFile,
+        MesgNum::WeatherConditions,
+        MesgNum::WeatherAlert,
+        MesgNum::GpsMetadata,
+        MesgNum::CameraEvent,
+        MesgNum::GyroscopeData,
+        MesgNum::AccelerometerData,
+        MesgNum::MagnetometerData,
+        MesgNum::BarometerData,
+        MesgNum::ThreeDSensorCalibration,
+        MesgNum::OneDSensorCalibration,
+        MesgNum::VideoFrame,
+        MesgNum::ObdiiData,
+        MesgNum::NmeaSentence,
+        MesgNum::AviationAttitude,
+        MesgNum::Video,
+        MesgNum::VideoTitle,
+        MesgNum::VideoDescription,
+        MesgNum::VideoClip,
+        MesgNum::Set,
+        MesgNum::Jump,
+        MesgNum::Split,
+        MesgNum::SplitSummary,
+        MesgNum::ClimbPro,
+        MesgNum::FieldDescription,
+        MesgNum::DeveloperDataId,
+        MesgNum::TimeInZone,
+        MesgNum::Hrv,
+        MesgNum::Hr,
+        MesgNum::DiveSummary,
+        MesgNum::TankUpdate,
+        MesgNum::TankSummary,
+    ];
+    #[doc = "Check if the message is one of `ACTIVITY_MESSAGES`"]
+    pub fn is_activity_message(self) -> bool {
+        matches!(
+            self,
+            MesgNum::Activity
+                | MesgNum::Session
+                | MesgNum::Lap
+                | MesgNum::Length
+                | MesgNum::Record
+                | MesgNum::Event
+                | MesgNum::DeviceInfo
+                | MesgNum::DeviceAuxBatteryInfo
+                | MesgNum::TrainingFile
+                | MesgNum::WeatherConditions
+                | MesgNum::WeatherAlert
+                | MesgNum::GpsMetadata
+                | MesgNum::CameraEvent
+                | MesgNum::GyroscopeData
+                | MesgNum::AccelerometerData
+                | MesgNum::MagnetometerData
+                | MesgNum::BarometerData
+                | MesgNum::ThreeDSensorCalibration
+                | MesgNum::OneDSensorCalibration
+                | MesgNum::VideoFrame
+                | MesgNum::ObdiiData
+                | MesgNum::NmeaSentence
+                | MesgNum::AviationAttitude
+                | MesgNum::Video
+                | MesgNum::VideoTitle
+                | MesgNum::VideoDescription
+                | MesgNum::VideoClip
+                | MesgNum::Set
+                | MesgNum::Jump
+                | MesgNum::Split
+                | MesgNum::SplitSummary
+                | MesgNum::ClimbPro
+                | MesgNum::FieldDescription
+                | MesgNum::DeveloperDataId
+                | MesgNum::TimeInZone
+                | MesgNum::Hrv
+                | MesgNum::Hr
+                | MesgNum::DiveSummary
+                | MesgNum::TankUpdate
+                | MesgNum::TankSummary
+        )
+    }
+    #[doc = "Messages recorded in monitoring files, i.e. all day wellness tracking"]
+    pub const MONITORING_MESSAGES: &'static [MesgNum] = &[
+        MesgNum::MonitoringInfo,
+        MesgNum::Monitoring,
+        MesgNum::MonitoringHrData,
+        MesgNum::Spo2Data,
+        MesgNum::Hr,
+        MesgNum::StressLevel,
+        MesgNum::MaxMetData,
+        MesgNum::HsaBodyBatteryData,
+        MesgNum::HsaEvent,
+        MesgNum::HsaAccelerometerData,
+        MesgNum::HsaGyroscopeData,
+        MesgNum::HsaStepData,
+        MesgNum::HsaSpo2Data,
+        MesgNum::HsaStressData,
+        MesgNum::HsaRespirationData,
+        MesgNum::HsaHeartRateData,
+        MesgNum::HsaConfigurationData,
+        MesgNum::HsaWristTemperatureData,
+    ];
+    #[doc = "Check if the message is one of `MONITORING_MESSAGES`"]
+    pub fn is_monitoring_message(self) -> bool {
+        matches!(
+            self,
+            MesgNum::MonitoringInfo
+                | MesgNum::Monitoring
+                | MesgNum::MonitoringHrData
+                | MesgNum::Spo2Data
+                | MesgNum::Hr
+                | MesgNum::StressLevel
+                | MesgNum::MaxMetData
+                | MesgNum::HsaBodyBatteryData
+                | MesgNum::HsaEvent
+                | MesgNum::HsaAccelerometerData
+                | MesgNum::HsaGyroscopeData
+                | MesgNum::HsaStepData
+                | MesgNum::HsaSpo2Data
+                | MesgNum::HsaStressData
+                | MesgNum::HsaRespirationData
+                | MesgNum::HsaHeartRateData
+                | MesgNum::HsaConfigurationData
+                | MesgNum::HsaWristTemperatureData
+        )
+    }
+    #[doc = "Messages summarizing a span of an activity rather than a single point in time"]
+    pub const SESSION_LEVEL_MESSAGES: &'static [MesgNum] = &[
+        MesgNum::Activity,
+        MesgNum::Session,
+        MesgNum::Lap,
+        MesgNum::Length,
+        MesgNum::Split,
+        MesgNum::SplitSummary,
+        MesgNum::SegmentLap,
+        MesgNum::TimeInZone,
+        MesgNum::DiveSummary,
+        MesgNum::TankSummary,
+    ];
+    #[doc = "Check if the message is one of `SESSION_LEVEL_MESSAGES`"]
+    pub fn is_session_level_message(self) -> bool {
+        matches!(
+            self,
+            MesgNum::Activity
+                | MesgNum::Session
+                | MesgNum::Lap
+                | MesgNum::Length
+                | MesgNum::Split
+                | MesgNum::SplitSummary
+                | MesgNum::SegmentLap
+                | MesgNum::TimeInZone
+                | MesgNum::DiveSummary
+                | MesgNum::TankSummary
+        )
+    }
+    #[doc = "Same as `is_session_level_message`"]
+    pub fn is_session_level(self) -> bool {
+        self.is_session_level_message()
+    }
+    #[doc = "Messages holding data sampled at a single point in time"]
+    pub const RECORD_LEVEL_MESSAGES: &'static [MesgNum] = &[
+        MesgNum::Record,
+        MesgNum::Event,
+        MesgNum::Hrv,
+        MesgNum::Hr,
+        MesgNum::GpsMetadata,
+        MesgNum::CameraEvent,
+        MesgNum::GyroscopeData,
+        MesgNum::AccelerometerData,
+        MesgNum::MagnetometerData,
+        MesgNum::BarometerData,
+        MesgNum::ObdiiData,
+        MesgNum::NmeaSentence,
+        MesgNum::AviationAttitude,
+        MesgNum::VideoFrame,
+        MesgNum::WeatherConditions,
+        MesgNum::WeatherAlert,
+        MesgNum::Set,
+        MesgNum::Jump,
+        MesgNum::ClimbPro,
+        MesgNum::TankUpdate,
+        MesgNum::BeatIntervals,
+        MesgNum::RespirationRate,
+        MesgNum::Monitoring,
+        MesgNum::MonitoringHrData,
+        MesgNum::Spo2Data,
+        MesgNum::StressLevel,
+    ];
+    #[doc = "Check if the message is one of `RECORD_LEVEL_MESSAGES`"]
+    pub fn is_record_level_message(self) -> bool {
+        matches!(
+            self,
+            MesgNum::Record
+                | MesgNum::Event
+                | MesgNum::Hrv
+                | MesgNum::Hr
+                | MesgNum::GpsMetadata
+                | MesgNum::CameraEvent
+                | MesgNum::GyroscopeData
+                | MesgNum::AccelerometerData
+                | MesgNum::MagnetometerData
+                | MesgNum::BarometerData
+                | MesgNum::ObdiiData
+                | MesgNum::NmeaSentence
+                | MesgNum::AviationAttitude
+                | MesgNum::VideoFrame
+                | MesgNum::WeatherConditions
+                | MesgNum::WeatherAlert
+                | MesgNum::Set
+                | MesgNum::Jump
+                | MesgNum::ClimbPro
+                | MesgNum::TankUpdate
+                | MesgNum::BeatIntervals
+                | MesgNum::RespirationRate
+                | MesgNum::Monitoring
+                | MesgNum::MonitoringHrData
+                | MesgNum::Spo2Data
+                | MesgNum::StressLevel
+        )
+    }
+    #[doc = "Same as `is_record_level_message`"]
+    pub fn is_record_level(self) -> bool {
+        self.is_record_level_message()
+    }
+    #[doc = "Messages holding device, user, sport and sensor settings"]
+    pub const SETTINGS_MESSAGES: &'static [MesgNum] = &[
+        MesgNum::DeviceSettings,
+        MesgNum::UserProfile,
+        MesgNum::HrmProfile,
+        MesgNum::SdmProfile,
+        MesgNum::BikeProfile,
+        MesgNum::ZonesTarget,
+        MesgNum::HrZone,
+        MesgNum::PowerZone,
+        MesgNum::MetZone,
+        MesgNum::SpeedZone,
+        MesgNum::CadenceZone,
+        MesgNum::Sport,
+        MesgNum::WatchfaceSettings,
+        MesgNum::OhrSettings,
+        MesgNum::DiveSettings,
+        MesgNum::DiveGas,
+        MesgNum::DiveAlarm,
+    ];
+    #[doc = "Check if the message is one of `SETTINGS_MESSAGES`"]
+    pub fn is_settings_message(self) -> bool {
+        matches!(
+            self,
+            MesgNum::DeviceSettings
+                | MesgNum::UserProfile
+                | MesgNum::HrmProfile
+                | MesgNum::SdmProfile
+                | MesgNum::BikeProfile
+                | MesgNum::ZonesTarget
+                | MesgNum::HrZone
+                | MesgNum::PowerZone
+                | MesgNum::MetZone
+                | MesgNum::SpeedZone
+                | MesgNum::CadenceZone
+                | MesgNum::Sport
+                | MesgNum::WatchfaceSettings
+                | MesgNum::OhrSettings
+                | MesgNum::DiveSettings
+                | MesgNum::DiveGas
+                | MesgNum::DiveAlarm
+        )
+    }
+    #[doc = "Messages describing a course apart from its track points"]
+    pub const COURSE_MESSAGES: &'static [MesgNum] = &[MesgNum::Course, MesgNum::CoursePoint];
+    #[doc = "Check if the message is one of `COURSE_MESSAGES`"]
+    pub fn is_course_message(self) -> bool {
+        matches!(self, MesgNum::Course | MesgNum::CoursePoint)
+    }
+    #[doc = "Messages describing a structured workout"]
+    pub const WORKOUT_MESSAGES: &'static [MesgNum] = &[
+        MesgNum::Workout,
+        MesgNum::WorkoutSession,
+        MesgNum::WorkoutStep,
+        MesgNum::ExerciseTitle,
+    ];
+    #[doc = "Check if the message is one of `WORKOUT_MESSAGES`"]
+    pub fn is_workout_message(self) -> bool {
+        matches!(
+            self,
+            MesgNum::Workout
+                | MesgNum::WorkoutSession
+                | MesgNum::WorkoutStep
+                | MesgNum::ExerciseTitle
+        )
+    }
+    #[doc = "Messages defining developer data fields"]
+    pub const DEVELOPER_MESSAGES: &'static [MesgNum] =
+        &[MesgNum::DeveloperDataId, MesgNum::FieldDescription];
+    #[doc = "Check if the message is one of `DEVELOPER_MESSAGES`"]
+    pub fn is_developer_message(self) -> bool {
+        matches!(self, MesgNum::DeveloperDataId | MesgNum::FieldDescription)
+    }
+    #[doc = "Messages holding raw motion and pressure sensor samples and their calibration"]
+    pub const SENSOR_DATA_MESSAGES: &'static [MesgNum] = &[
+        MesgNum::GyroscopeData,
+        MesgNum::AccelerometerData,
+        MesgNum::MagnetometerData,
+        MesgNum::BarometerData,
+        MesgNum::ThreeDSensorCalibration,
+        MesgNum::OneDSensorCalibration,
+    ];
+    #[doc = "Check if the message is one of `SENSOR_DATA_MESSAGES`"]
+    pub fn is_sensor_data_message(self) -> bool {
+        matches!(
+            self,
+            MesgNum::GyroscopeData
+                | MesgNum::AccelerometerData
+                | MesgNum::MagnetometerData
+                | MesgNum::BarometerData
+                | MesgNum::ThreeDSensorCalibration
+                | MesgNum::OneDSensorCalibration
+        )
+    }
+    #[doc = "Every message number named by the profile, including the bounds of the manufacturer specific range"]
+    pub const VARIANTS: &'static [MesgNum] = &[
+        MesgNum::FileId,
+        MesgNum::Capabilities,
+        MesgNum::DeviceSettings,
+        MesgNum::UserProfile,
+        MesgNum::HrmProfile,
+        MesgNum::SdmProfile,
+        MesgNum::BikeProfile,
+        MesgNum::ZonesTarget,
+        MesgNum::HrZone,
+        MesgNum::PowerZone,
+        MesgNum::MetZone,
+        MesgNum::Sport,
+        MesgNum::Goal,
+        MesgNum::Session,
+        MesgNum::Lap,
+        MesgNum::Record,
+        MesgNum::Event,
+        MesgNum::DeviceInfo,
+        MesgNum::Workout,
+        MesgNum::WorkoutStep,
+        MesgNum::Schedule,
+        MesgNum::WeightScale,
+        MesgNum::Course,
+        MesgNum::CoursePoint,
+        MesgNum::Totals,
+        MesgNum::Activity,
+        MesgNum::Software,
+        MesgNum::FileCapabilities,
+        MesgNum::MesgCapabilities,
+        MesgNum::FieldCapabilities,
+        MesgNum::FileCreator,
+        MesgNum::BloodPressure,
+        MesgNum::SpeedZone,
+        MesgNum::Monitoring,
+        MesgNum::TrainingFile,
+        MesgNum::Hrv,
+        MesgNum::AntRx,
+        MesgNum::AntTx,
+        MesgNum::AntChannelId,
+        MesgNum::Length,
+        MesgNum::MonitoringInfo,
+        MesgNum::Pad,
+        MesgNum::SlaveDevice,
+        MesgNum::Connectivity,
+        MesgNum::WeatherConditions,
+        MesgNum::WeatherAlert,
+        MesgNum::CadenceZone,
+        MesgNum::Hr,
+        MesgNum::SegmentLap,
+        MesgNum::MemoGlob,
+        MesgNum::SegmentId,
+        MesgNum::SegmentLeaderboardEntry,
+        MesgNum::SegmentPoint,
+        MesgNum::SegmentFile,
+        MesgNum::WorkoutSession,
+        MesgNum::WatchfaceSettings,
+        MesgNum::GpsMetadata,
+        MesgNum::CameraEvent,
+        MesgNum::TimestampCorrelation,
+        MesgNum::GyroscopeData,
+        MesgNum::AccelerometerData,
+        MesgNum::ThreeDSensorCalibration,
+        MesgNum::VideoFrame,
+        MesgNum::ObdiiData,
+        MesgNum::NmeaSentence,
+        MesgNum::AviationAttitude,
+        MesgNum::Video,
+        MesgNum::VideoTitle,
+        MesgNum::VideoDescription,
+        MesgNum::VideoClip,
+        MesgNum::OhrSettings,
+        MesgNum::ExdScreenConfiguration,
+        MesgNum::ExdDataFieldConfiguration,
+        MesgNum::ExdDataConceptConfiguration,
+        MesgNum::FieldDescription,
+        MesgNum::DeveloperDataId,
+        MesgNum::MagnetometerData,
+        MesgNum::BarometerData,
+        MesgNum::OneDSensorCalibration,
+        MesgNum::MonitoringHrData,
+        MesgNum::TimeInZone,
+        MesgNum::Set,
+        MesgNum::StressLevel,
+        MesgNum::MaxMetData,
+        MesgNum::DiveSettings,
+        MesgNum::DiveGas,
+        MesgNum::DiveAlarm,
+        MesgNum::ExerciseTitle,
+        MesgNum::DiveSummary,
+        MesgNum::Spo2Data,
+        MesgNum::SleepLevel,
+        MesgNum::Jump,
+        MesgNum::AadAccelFeatures,
+        MesgNum::BeatIntervals,
+        MesgNum::RespirationRate,
+        MesgNum::HsaAccelerometerData,
+        MesgNum::HsaStepData,
+        MesgNum::HsaSpo2Data,
+        MesgNum::HsaStressData,
+        MesgNum::HsaRespirationData,
+        MesgNum::HsaHeartRateData,
+        MesgNum::Split,
+        MesgNum::SplitSummary,
+        MesgNum::HsaBodyBatteryData,
+        MesgNum::HsaEvent,
+        MesgNum::ClimbPro,
+        MesgNum::TankUpdate,
+        MesgNum::TankSummary,
+        MesgNum::SleepAssessment,
+        MesgNum::HrvStatusSummary,
+        MesgNum::HrvValue,
+        MesgNum::RawBbi,
+        MesgNum::DeviceAuxBatteryInfo,
+        MesgNum::HsaGyroscopeData,
+        MesgNum::ChronoShotSession,
+        MesgNum::ChronoShotData,
+        MesgNum::HsaConfigurationData,
+        MesgNum::DiveApneaAlarm,
+        MesgNum::SkinTempOvernight,
+        MesgNum::HsaWristTemperatureData,
+        MesgNum::MfgRangeMin,
+        MesgNum::MfgRangeMax,
+    ];
+    #[doc = "Description of the message from the profile, `None` for messages without one"]
+    pub fn doc_string(self) -> Option<&'static str> {
+        match self {
+            MesgNum::FileId => Some("Must be first message in file."),
+            MesgNum::TrainingFile => Some("Corresponds to file_id of workout or course."),
+            MesgNum::FieldDescription => Some("Must be logged before developer field is used"),
+            MesgNum::DeveloperDataId => Some("Must be logged before field description"),
+            MesgNum::SegmentId => Some("Unique Identification data for a segment file"),
+            MesgNum::SegmentLeaderboardEntry => Some("Unique Identification data for an individual segment leader within a segment file"),
+            MesgNum::SegmentPoint => Some("Navigation and race evaluation point for a segment decribing a point along the segment path and time it took each segment leader to reach that point"),
+            MesgNum::SegmentFile => Some("Summary of the unique segment and leaderboard information associated with a segment file. This message is used to compile a segment list file describing all segment files on a device. The segment list file is used when refreshing the contents of a segment file with the latest available leaderboard information."),
+            MesgNum::StressLevel => Some("Value from 1 to 100 calculated by FirstBeat"),
+            MesgNum::HsaBodyBatteryData => Some("Body battery data used for HSA custom data logging"),
+            MesgNum::HsaEvent => Some("HSA events"),
+            MesgNum::HsaAccelerometerData => Some("Raw accelerometer data used for HSA custom data logging"),
+            MesgNum::HsaStepData => Some("User's current daily step data used for HSA custom data logging"),
+            MesgNum::HsaSpo2Data => Some("User's current SpO2 data used for HSA custom data logging"),
+            MesgNum::HsaStressData => Some("User's current stress data used for HSA custom data logging"),
+            MesgNum::HsaRespirationData => Some("User's current respiration data used for HSA custom data logging"),
+            MesgNum::HsaHeartRateData => Some("User's current heart rate data used for HSA custom data logging"),
+            MesgNum::HsaConfigurationData => Some("Configuration data for HSA custom data logging"),
+            MesgNum::HsaWristTemperatureData => Some("Wrist temperature data used for HSA custom data logging"),
+            MesgNum::AadAccelFeatures => Some("Number of acclerometer zero crossings summed over the specified time interval"),
+            MesgNum::Hrv => Some("Heart rate variability"),
+            MesgNum::BeatIntervals => Some("Array of heart beat intervals"),
+            MesgNum::RawBbi => Some("Raw Beat-to-Beat Interval values"),
+            MesgNum::ChronoShotSession => Some("Specifically used for XERO products."),
+            MesgNum::ChronoShotData => Some("Specifically used for XERO products."),
+            _ => None,
+        }
+    }
+}
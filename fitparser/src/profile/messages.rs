@@ -0,0 +1,5993 @@
+#![doc = "Auto generated typed messages from FIT SDK Release: 21.141.00"]
+#![allow(missing_docs)]
+#![allow(clippy::single_match)]
+use super::field_types::*;
+use super::{enum_value, field_value, profile_fields, FromValue};
+use crate::error::{Error, Result};
+use crate::{FitDataRecord, Value};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+#[doc = "Typed fields of a `file_id` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FileIdMessage {
+    pub r#type: Option<File>,
+    pub manufacturer: Option<Manufacturer>,
+    pub favero_product: Option<FaveroProduct>,
+    pub garmin_product: Option<GarminProduct>,
+    pub product: Option<u16>,
+    pub serial_number: Option<u32>,
+    pub time_created: Option<crate::timestamp::Timestamp>,
+    pub number: Option<u16>,
+    pub product_name: Option<String>,
+}
+impl TryFrom<&FitDataRecord> for FileIdMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::FileId)? {
+            match field.name() {
+                "type" => message.r#type = field_value(record, field)?,
+                "manufacturer" => message.manufacturer = field_value(record, field)?,
+                "favero_product" => message.favero_product = field_value(record, field)?,
+                "garmin_product" => message.garmin_product = field_value(record, field)?,
+                "product" => message.product = field_value(record, field)?,
+                "serial_number" => message.serial_number = field_value(record, field)?,
+                "time_created" => message.time_created = field_value(record, field)?,
+                "number" => message.number = field_value(record, field)?,
+                "product_name" => message.product_name = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `file_creator` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FileCreatorMessage {
+    pub software_version: Option<u16>,
+    pub hardware_version: Option<u8>,
+}
+impl TryFrom<&FitDataRecord> for FileCreatorMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::FileCreator)? {
+            match field.name() {
+                "software_version" => message.software_version = field_value(record, field)?,
+                "hardware_version" => message.hardware_version = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `timestamp_correlation` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TimestampCorrelationMessage {
+    pub fractional_timestamp: Option<f64>,
+    pub system_timestamp: Option<crate::timestamp::Timestamp>,
+    pub fractional_system_timestamp: Option<f64>,
+    pub local_timestamp: Option<crate::timestamp::Timestamp>,
+    pub timestamp_ms: Option<u16>,
+    pub system_timestamp_ms: Option<u16>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for TimestampCorrelationMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::TimestampCorrelation)? {
+            match field.name() {
+                "fractional_timestamp" => {
+                    message.fractional_timestamp = field_value(record, field)?
+                }
+                "system_timestamp" => message.system_timestamp = field_value(record, field)?,
+                "fractional_system_timestamp" => {
+                    message.fractional_system_timestamp = field_value(record, field)?
+                }
+                "local_timestamp" => message.local_timestamp = field_value(record, field)?,
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "system_timestamp_ms" => message.system_timestamp_ms = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `software` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SoftwareMessage {
+    pub version: Option<f64>,
+    pub part_number: Option<String>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SoftwareMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Software)? {
+            match field.name() {
+                "version" => message.version = field_value(record, field)?,
+                "part_number" => message.part_number = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `slave_device` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SlaveDeviceMessage {
+    pub manufacturer: Option<Manufacturer>,
+    pub favero_product: Option<FaveroProduct>,
+    pub garmin_product: Option<GarminProduct>,
+    pub product: Option<u16>,
+}
+impl TryFrom<&FitDataRecord> for SlaveDeviceMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::SlaveDevice)? {
+            match field.name() {
+                "manufacturer" => message.manufacturer = field_value(record, field)?,
+                "favero_product" => message.favero_product = field_value(record, field)?,
+                "garmin_product" => message.garmin_product = field_value(record, field)?,
+                "product" => message.product = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `capabilities` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CapabilitiesMessage {
+    pub languages: Option<Vec<u8>>,
+    pub sports: Option<Vec<SportBits0>>,
+    pub workouts_supported: Option<WorkoutCapabilities>,
+    pub connectivity_supported: Option<ConnectivityCapabilities>,
+}
+impl TryFrom<&FitDataRecord> for CapabilitiesMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Capabilities)? {
+            match field.name() {
+                "languages" => message.languages = field_value(record, field)?,
+                "sports" => message.sports = field_value(record, field)?,
+                "workouts_supported" => message.workouts_supported = field_value(record, field)?,
+                "connectivity_supported" => {
+                    message.connectivity_supported = field_value(record, field)?
+                }
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `file_capabilities` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FileCapabilitiesMessage {
+    pub r#type: Option<File>,
+    pub flags: Option<FileFlags>,
+    pub directory: Option<String>,
+    pub max_count: Option<u16>,
+    pub max_size: Option<u32>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for FileCapabilitiesMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::FileCapabilities)? {
+            match field.name() {
+                "type" => message.r#type = field_value(record, field)?,
+                "flags" => message.flags = field_value(record, field)?,
+                "directory" => message.directory = field_value(record, field)?,
+                "max_count" => message.max_count = field_value(record, field)?,
+                "max_size" => message.max_size = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `mesg_capabilities` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MesgCapabilitiesMessage {
+    pub file: Option<File>,
+    pub mesg_num: Option<MesgNum>,
+    pub count_type: Option<MesgCount>,
+    pub num_per_file: Option<u16>,
+    pub max_per_file: Option<u16>,
+    pub max_per_file_type: Option<u16>,
+    pub count: Option<u16>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for MesgCapabilitiesMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::MesgCapabilities)? {
+            match field.name() {
+                "file" => message.file = field_value(record, field)?,
+                "mesg_num" => message.mesg_num = field_value(record, field)?,
+                "count_type" => message.count_type = field_value(record, field)?,
+                "num_per_file" => message.num_per_file = field_value(record, field)?,
+                "max_per_file" => message.max_per_file = field_value(record, field)?,
+                "max_per_file_type" => message.max_per_file_type = field_value(record, field)?,
+                "count" => message.count = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `field_capabilities` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FieldCapabilitiesMessage {
+    pub file: Option<File>,
+    pub mesg_num: Option<MesgNum>,
+    pub field_num: Option<u8>,
+    pub count: Option<u16>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for FieldCapabilitiesMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::FieldCapabilities)? {
+            match field.name() {
+                "file" => message.file = field_value(record, field)?,
+                "mesg_num" => message.mesg_num = field_value(record, field)?,
+                "field_num" => message.field_num = field_value(record, field)?,
+                "count" => message.count = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `device_settings` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeviceSettingsMessage {
+    pub active_time_zone: Option<u8>,
+    pub utc_offset: Option<u32>,
+    pub time_offset: Option<u32>,
+    pub time_mode: Option<TimeMode>,
+    pub time_zone_offset: Option<f64>,
+    pub backlight_mode: Option<BacklightMode>,
+    pub activity_tracker_enabled: Option<bool>,
+    pub clock_time: Option<crate::timestamp::Timestamp>,
+    pub pages_enabled: Option<u16>,
+    pub move_alert_enabled: Option<bool>,
+    pub date_mode: Option<DateMode>,
+    pub display_orientation: Option<DisplayOrientation>,
+    pub mounting_side: Option<Side>,
+    pub default_page: Option<u16>,
+    pub autosync_min_steps: Option<u16>,
+    pub autosync_min_time: Option<u16>,
+    pub lactate_threshold_autodetect_enabled: Option<bool>,
+    pub ble_auto_upload_enabled: Option<bool>,
+    pub auto_sync_frequency: Option<AutoSyncFrequency>,
+    pub auto_activity_detect: Option<AutoActivityDetect>,
+    pub number_of_screens: Option<u8>,
+    pub smart_notification_display_orientation: Option<DisplayOrientation>,
+    pub tap_interface: Option<Switch>,
+    pub tap_sensitivity: Option<TapSensitivity>,
+}
+impl TryFrom<&FitDataRecord> for DeviceSettingsMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::DeviceSettings)? {
+            match field.name() {
+                "active_time_zone" => message.active_time_zone = field_value(record, field)?,
+                "utc_offset" => message.utc_offset = field_value(record, field)?,
+                "time_offset" => message.time_offset = field_value(record, field)?,
+                "time_mode" => message.time_mode = field_value(record, field)?,
+                "time_zone_offset" => message.time_zone_offset = field_value(record, field)?,
+                "backlight_mode" => message.backlight_mode = field_value(record, field)?,
+                "activity_tracker_enabled" => {
+                    message.activity_tracker_enabled = field_value(record, field)?
+                }
+                "clock_time" => message.clock_time = field_value(record, field)?,
+                "pages_enabled" => message.pages_enabled = field_value(record, field)?,
+                "move_alert_enabled" => message.move_alert_enabled = field_value(record, field)?,
+                "date_mode" => message.date_mode = field_value(record, field)?,
+                "display_orientation" => message.display_orientation = field_value(record, field)?,
+                "mounting_side" => message.mounting_side = field_value(record, field)?,
+                "default_page" => message.default_page = field_value(record, field)?,
+                "autosync_min_steps" => message.autosync_min_steps = field_value(record, field)?,
+                "autosync_min_time" => message.autosync_min_time = field_value(record, field)?,
+                "lactate_threshold_autodetect_enabled" => {
+                    message.lactate_threshold_autodetect_enabled = field_value(record, field)?
+                }
+                "ble_auto_upload_enabled" => {
+                    message.ble_auto_upload_enabled = field_value(record, field)?
+                }
+                "auto_sync_frequency" => message.auto_sync_frequency = field_value(record, field)?,
+                "auto_activity_detect" => {
+                    message.auto_activity_detect = field_value(record, field)?
+                }
+                "number_of_screens" => message.number_of_screens = field_value(record, field)?,
+                "smart_notification_display_orientation" => {
+                    message.smart_notification_display_orientation = field_value(record, field)?
+                }
+                "tap_interface" => message.tap_interface = field_value(record, field)?,
+                "tap_sensitivity" => message.tap_sensitivity = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `user_profile` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserProfileMessage {
+    pub friendly_name: Option<String>,
+    pub gender: Option<Gender>,
+    pub age: Option<u8>,
+    pub height: Option<f64>,
+    pub weight: Option<f64>,
+    pub language: Option<Language>,
+    pub elev_setting: Option<DisplayMeasure>,
+    pub weight_setting: Option<DisplayMeasure>,
+    pub resting_heart_rate: Option<u8>,
+    pub default_max_running_heart_rate: Option<u8>,
+    pub default_max_biking_heart_rate: Option<u8>,
+    pub default_max_heart_rate: Option<u8>,
+    pub hr_setting: Option<DisplayHeart>,
+    pub speed_setting: Option<DisplayMeasure>,
+    pub dist_setting: Option<DisplayMeasure>,
+    pub power_setting: Option<DisplayPower>,
+    pub activity_class: Option<ActivityClass>,
+    pub position_setting: Option<DisplayPosition>,
+    pub temperature_setting: Option<DisplayMeasure>,
+    pub local_id: Option<UserLocalId>,
+    pub global_id: Option<Vec<u8>>,
+    pub wake_time: Option<u32>,
+    pub sleep_time: Option<u32>,
+    pub height_setting: Option<DisplayMeasure>,
+    pub user_running_step_length: Option<f64>,
+    pub user_walking_step_length: Option<f64>,
+    pub depth_setting: Option<DisplayMeasure>,
+    pub dive_count: Option<u32>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for UserProfileMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::UserProfile)? {
+            match field.name() {
+                "friendly_name" => message.friendly_name = field_value(record, field)?,
+                "gender" => message.gender = field_value(record, field)?,
+                "age" => message.age = field_value(record, field)?,
+                "height" => message.height = field_value(record, field)?,
+                "weight" => message.weight = field_value(record, field)?,
+                "language" => message.language = field_value(record, field)?,
+                "elev_setting" => message.elev_setting = field_value(record, field)?,
+                "weight_setting" => message.weight_setting = field_value(record, field)?,
+                "resting_heart_rate" => message.resting_heart_rate = field_value(record, field)?,
+                "default_max_running_heart_rate" => {
+                    message.default_max_running_heart_rate = field_value(record, field)?
+                }
+                "default_max_biking_heart_rate" => {
+                    message.default_max_biking_heart_rate = field_value(record, field)?
+                }
+                "default_max_heart_rate" => {
+                    message.default_max_heart_rate = field_value(record, field)?
+                }
+                "hr_setting" => message.hr_setting = field_value(record, field)?,
+                "speed_setting" => message.speed_setting = field_value(record, field)?,
+                "dist_setting" => message.dist_setting = field_value(record, field)?,
+                "power_setting" => message.power_setting = field_value(record, field)?,
+                "activity_class" => message.activity_class = field_value(record, field)?,
+                "position_setting" => message.position_setting = field_value(record, field)?,
+                "temperature_setting" => message.temperature_setting = field_value(record, field)?,
+                "local_id" => message.local_id = field_value(record, field)?,
+                "global_id" => message.global_id = field_value(record, field)?,
+                "wake_time" => message.wake_time = field_value(record, field)?,
+                "sleep_time" => message.sleep_time = field_value(record, field)?,
+                "height_setting" => message.height_setting = field_value(record, field)?,
+                "user_running_step_length" => {
+                    message.user_running_step_length = field_value(record, field)?
+                }
+                "user_walking_step_length" => {
+                    message.user_walking_step_length = field_value(record, field)?
+                }
+                "depth_setting" => message.depth_setting = field_value(record, field)?,
+                "dive_count" => message.dive_count = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hrm_profile` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HrmProfileMessage {
+    pub enabled: Option<bool>,
+    pub hrm_ant_id: Option<u16>,
+    pub log_hrv: Option<bool>,
+    pub hrm_ant_id_trans_type: Option<u8>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for HrmProfileMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HrmProfile)? {
+            match field.name() {
+                "enabled" => message.enabled = field_value(record, field)?,
+                "hrm_ant_id" => message.hrm_ant_id = field_value(record, field)?,
+                "log_hrv" => message.log_hrv = field_value(record, field)?,
+                "hrm_ant_id_trans_type" => {
+                    message.hrm_ant_id_trans_type = field_value(record, field)?
+                }
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `sdm_profile` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SdmProfileMessage {
+    pub enabled: Option<bool>,
+    pub sdm_ant_id: Option<u16>,
+    pub sdm_cal_factor: Option<f64>,
+    pub odometer: Option<f64>,
+    pub speed_source: Option<bool>,
+    pub sdm_ant_id_trans_type: Option<u8>,
+    pub odometer_rollover: Option<u8>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SdmProfileMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::SdmProfile)? {
+            match field.name() {
+                "enabled" => message.enabled = field_value(record, field)?,
+                "sdm_ant_id" => message.sdm_ant_id = field_value(record, field)?,
+                "sdm_cal_factor" => message.sdm_cal_factor = field_value(record, field)?,
+                "odometer" => message.odometer = field_value(record, field)?,
+                "speed_source" => message.speed_source = field_value(record, field)?,
+                "sdm_ant_id_trans_type" => {
+                    message.sdm_ant_id_trans_type = field_value(record, field)?
+                }
+                "odometer_rollover" => message.odometer_rollover = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `bike_profile` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BikeProfileMessage {
+    pub name: Option<String>,
+    pub sport: Option<Sport>,
+    pub sub_sport: Option<SubSport>,
+    pub odometer: Option<f64>,
+    pub bike_spd_ant_id: Option<u16>,
+    pub bike_cad_ant_id: Option<u16>,
+    pub bike_spdcad_ant_id: Option<u16>,
+    pub bike_power_ant_id: Option<u16>,
+    pub custom_wheelsize: Option<f64>,
+    pub auto_wheelsize: Option<f64>,
+    pub bike_weight: Option<f64>,
+    pub power_cal_factor: Option<f64>,
+    pub auto_wheel_cal: Option<bool>,
+    pub auto_power_zero: Option<bool>,
+    pub id: Option<u8>,
+    pub spd_enabled: Option<bool>,
+    pub cad_enabled: Option<bool>,
+    pub spdcad_enabled: Option<bool>,
+    pub power_enabled: Option<bool>,
+    pub crank_length: Option<f64>,
+    pub enabled: Option<bool>,
+    pub bike_spd_ant_id_trans_type: Option<u8>,
+    pub bike_cad_ant_id_trans_type: Option<u8>,
+    pub bike_spdcad_ant_id_trans_type: Option<u8>,
+    pub bike_power_ant_id_trans_type: Option<u8>,
+    pub odometer_rollover: Option<u8>,
+    pub front_gear_num: Option<u8>,
+    pub front_gear: Option<Vec<u8>>,
+    pub rear_gear_num: Option<u8>,
+    pub rear_gear: Option<Vec<u8>>,
+    pub shimano_di2_enabled: Option<bool>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for BikeProfileMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::BikeProfile)? {
+            match field.name() {
+                "name" => message.name = field_value(record, field)?,
+                "sport" => message.sport = field_value(record, field)?,
+                "sub_sport" => message.sub_sport = field_value(record, field)?,
+                "odometer" => message.odometer = field_value(record, field)?,
+                "bike_spd_ant_id" => message.bike_spd_ant_id = field_value(record, field)?,
+                "bike_cad_ant_id" => message.bike_cad_ant_id = field_value(record, field)?,
+                "bike_spdcad_ant_id" => message.bike_spdcad_ant_id = field_value(record, field)?,
+                "bike_power_ant_id" => message.bike_power_ant_id = field_value(record, field)?,
+                "custom_wheelsize" => message.custom_wheelsize = field_value(record, field)?,
+                "auto_wheelsize" => message.auto_wheelsize = field_value(record, field)?,
+                "bike_weight" => message.bike_weight = field_value(record, field)?,
+                "power_cal_factor" => message.power_cal_factor = field_value(record, field)?,
+                "auto_wheel_cal" => message.auto_wheel_cal = field_value(record, field)?,
+                "auto_power_zero" => message.auto_power_zero = field_value(record, field)?,
+                "id" => message.id = field_value(record, field)?,
+                "spd_enabled" => message.spd_enabled = field_value(record, field)?,
+                "cad_enabled" => message.cad_enabled = field_value(record, field)?,
+                "spdcad_enabled" => message.spdcad_enabled = field_value(record, field)?,
+                "power_enabled" => message.power_enabled = field_value(record, field)?,
+                "crank_length" => message.crank_length = field_value(record, field)?,
+                "enabled" => message.enabled = field_value(record, field)?,
+                "bike_spd_ant_id_trans_type" => {
+                    message.bike_spd_ant_id_trans_type = field_value(record, field)?
+                }
+                "bike_cad_ant_id_trans_type" => {
+                    message.bike_cad_ant_id_trans_type = field_value(record, field)?
+                }
+                "bike_spdcad_ant_id_trans_type" => {
+                    message.bike_spdcad_ant_id_trans_type = field_value(record, field)?
+                }
+                "bike_power_ant_id_trans_type" => {
+                    message.bike_power_ant_id_trans_type = field_value(record, field)?
+                }
+                "odometer_rollover" => message.odometer_rollover = field_value(record, field)?,
+                "front_gear_num" => message.front_gear_num = field_value(record, field)?,
+                "front_gear" => message.front_gear = field_value(record, field)?,
+                "rear_gear_num" => message.rear_gear_num = field_value(record, field)?,
+                "rear_gear" => message.rear_gear = field_value(record, field)?,
+                "shimano_di2_enabled" => message.shimano_di2_enabled = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `connectivity` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConnectivityMessage {
+    pub bluetooth_enabled: Option<bool>,
+    pub bluetooth_le_enabled: Option<bool>,
+    pub ant_enabled: Option<bool>,
+    pub name: Option<String>,
+    pub live_tracking_enabled: Option<bool>,
+    pub weather_conditions_enabled: Option<bool>,
+    pub weather_alerts_enabled: Option<bool>,
+    pub auto_activity_upload_enabled: Option<bool>,
+    pub course_download_enabled: Option<bool>,
+    pub workout_download_enabled: Option<bool>,
+    pub gps_ephemeris_download_enabled: Option<bool>,
+    pub incident_detection_enabled: Option<bool>,
+    pub grouptrack_enabled: Option<bool>,
+}
+impl TryFrom<&FitDataRecord> for ConnectivityMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Connectivity)? {
+            match field.name() {
+                "bluetooth_enabled" => message.bluetooth_enabled = field_value(record, field)?,
+                "bluetooth_le_enabled" => {
+                    message.bluetooth_le_enabled = field_value(record, field)?
+                }
+                "ant_enabled" => message.ant_enabled = field_value(record, field)?,
+                "name" => message.name = field_value(record, field)?,
+                "live_tracking_enabled" => {
+                    message.live_tracking_enabled = field_value(record, field)?
+                }
+                "weather_conditions_enabled" => {
+                    message.weather_conditions_enabled = field_value(record, field)?
+                }
+                "weather_alerts_enabled" => {
+                    message.weather_alerts_enabled = field_value(record, field)?
+                }
+                "auto_activity_upload_enabled" => {
+                    message.auto_activity_upload_enabled = field_value(record, field)?
+                }
+                "course_download_enabled" => {
+                    message.course_download_enabled = field_value(record, field)?
+                }
+                "workout_download_enabled" => {
+                    message.workout_download_enabled = field_value(record, field)?
+                }
+                "gps_ephemeris_download_enabled" => {
+                    message.gps_ephemeris_download_enabled = field_value(record, field)?
+                }
+                "incident_detection_enabled" => {
+                    message.incident_detection_enabled = field_value(record, field)?
+                }
+                "grouptrack_enabled" => message.grouptrack_enabled = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `watchface_settings` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WatchfaceSettingsMessage {
+    pub mode: Option<WatchfaceMode>,
+    pub digital_layout: Option<DigitalWatchfaceLayout>,
+    pub analog_layout: Option<AnalogWatchfaceLayout>,
+    pub layout: Option<Vec<u8>>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for WatchfaceSettingsMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::WatchfaceSettings)? {
+            match field.name() {
+                "mode" => message.mode = field_value(record, field)?,
+                "digital_layout" => message.digital_layout = field_value(record, field)?,
+                "analog_layout" => message.analog_layout = field_value(record, field)?,
+                "layout" => message.layout = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `ohr_settings` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OhrSettingsMessage {
+    pub enabled: Option<Switch>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for OhrSettingsMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::OhrSettings)? {
+            match field.name() {
+                "enabled" => message.enabled = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `time_in_zone` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TimeInZoneMessage {
+    pub reference_mesg: Option<MesgNum>,
+    pub reference_index: Option<MessageIndex>,
+    pub time_in_hr_zone: Option<Vec<f64>>,
+    pub time_in_speed_zone: Option<Vec<f64>>,
+    pub time_in_cadence_zone: Option<Vec<f64>>,
+    pub time_in_power_zone: Option<Vec<f64>>,
+    pub hr_zone_high_boundary: Option<Vec<u8>>,
+    pub speed_zone_high_boundary: Option<Vec<f64>>,
+    pub cadence_zone_high_bondary: Option<Vec<u8>>,
+    pub power_zone_high_boundary: Option<Vec<u16>>,
+    pub hr_calc_type: Option<HrZoneCalc>,
+    pub max_heart_rate: Option<u8>,
+    pub resting_heart_rate: Option<u8>,
+    pub threshold_heart_rate: Option<u8>,
+    pub pwr_calc_type: Option<PwrZoneCalc>,
+    pub functional_threshold_power: Option<u16>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for TimeInZoneMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::TimeInZone)? {
+            match field.name() {
+                "reference_mesg" => message.reference_mesg = field_value(record, field)?,
+                "reference_index" => message.reference_index = field_value(record, field)?,
+                "time_in_hr_zone" => message.time_in_hr_zone = field_value(record, field)?,
+                "time_in_speed_zone" => message.time_in_speed_zone = field_value(record, field)?,
+                "time_in_cadence_zone" => {
+                    message.time_in_cadence_zone = field_value(record, field)?
+                }
+                "time_in_power_zone" => message.time_in_power_zone = field_value(record, field)?,
+                "hr_zone_high_boundary" => {
+                    message.hr_zone_high_boundary = field_value(record, field)?
+                }
+                "speed_zone_high_boundary" => {
+                    message.speed_zone_high_boundary = field_value(record, field)?
+                }
+                "cadence_zone_high_bondary" => {
+                    message.cadence_zone_high_bondary = field_value(record, field)?
+                }
+                "power_zone_high_boundary" => {
+                    message.power_zone_high_boundary = field_value(record, field)?
+                }
+                "hr_calc_type" => message.hr_calc_type = field_value(record, field)?,
+                "max_heart_rate" => message.max_heart_rate = field_value(record, field)?,
+                "resting_heart_rate" => message.resting_heart_rate = field_value(record, field)?,
+                "threshold_heart_rate" => {
+                    message.threshold_heart_rate = field_value(record, field)?
+                }
+                "pwr_calc_type" => message.pwr_calc_type = field_value(record, field)?,
+                "functional_threshold_power" => {
+                    message.functional_threshold_power = field_value(record, field)?
+                }
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `zones_target` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ZonesTargetMessage {
+    pub max_heart_rate: Option<u8>,
+    pub threshold_heart_rate: Option<u8>,
+    pub functional_threshold_power: Option<u16>,
+    pub hr_calc_type: Option<HrZoneCalc>,
+    pub pwr_calc_type: Option<PwrZoneCalc>,
+}
+impl TryFrom<&FitDataRecord> for ZonesTargetMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::ZonesTarget)? {
+            match field.name() {
+                "max_heart_rate" => message.max_heart_rate = field_value(record, field)?,
+                "threshold_heart_rate" => {
+                    message.threshold_heart_rate = field_value(record, field)?
+                }
+                "functional_threshold_power" => {
+                    message.functional_threshold_power = field_value(record, field)?
+                }
+                "hr_calc_type" => message.hr_calc_type = field_value(record, field)?,
+                "pwr_calc_type" => message.pwr_calc_type = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `sport` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SportMessage {
+    pub sport: Option<Sport>,
+    pub sub_sport: Option<SubSport>,
+    pub name: Option<String>,
+}
+impl TryFrom<&FitDataRecord> for SportMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Sport)? {
+            match field.name() {
+                "sport" => message.sport = field_value(record, field)?,
+                "sub_sport" => message.sub_sport = field_value(record, field)?,
+                "name" => message.name = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hr_zone` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HrZoneMessage {
+    pub high_bpm: Option<u8>,
+    pub name: Option<String>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for HrZoneMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HrZone)? {
+            match field.name() {
+                "high_bpm" => message.high_bpm = field_value(record, field)?,
+                "name" => message.name = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `speed_zone` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpeedZoneMessage {
+    pub high_value: Option<f64>,
+    pub name: Option<String>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SpeedZoneMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::SpeedZone)? {
+            match field.name() {
+                "high_value" => message.high_value = field_value(record, field)?,
+                "name" => message.name = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `cadence_zone` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CadenceZoneMessage {
+    pub high_value: Option<u8>,
+    pub name: Option<String>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for CadenceZoneMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::CadenceZone)? {
+            match field.name() {
+                "high_value" => message.high_value = field_value(record, field)?,
+                "name" => message.name = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `power_zone` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PowerZoneMessage {
+    pub high_value: Option<u16>,
+    pub name: Option<String>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for PowerZoneMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::PowerZone)? {
+            match field.name() {
+                "high_value" => message.high_value = field_value(record, field)?,
+                "name" => message.name = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `met_zone` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetZoneMessage {
+    pub high_bpm: Option<u8>,
+    pub calories: Option<f64>,
+    pub fat_calories: Option<f64>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for MetZoneMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::MetZone)? {
+            match field.name() {
+                "high_bpm" => message.high_bpm = field_value(record, field)?,
+                "calories" => message.calories = field_value(record, field)?,
+                "fat_calories" => message.fat_calories = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `dive_settings` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiveSettingsMessage {
+    pub name: Option<String>,
+    pub model: Option<TissueModelType>,
+    pub gf_low: Option<u8>,
+    pub gf_high: Option<u8>,
+    pub water_type: Option<WaterType>,
+    pub water_density: Option<f32>,
+    pub po2_warn: Option<f64>,
+    pub po2_critical: Option<f64>,
+    pub po2_deco: Option<f64>,
+    pub safety_stop_enabled: Option<bool>,
+    pub bottom_depth: Option<f32>,
+    pub bottom_time: Option<u32>,
+    pub apnea_countdown_enabled: Option<bool>,
+    pub apnea_countdown_time: Option<u32>,
+    pub backlight_mode: Option<DiveBacklightMode>,
+    pub backlight_brightness: Option<u8>,
+    pub backlight_timeout: Option<BacklightTimeout>,
+    pub repeat_dive_interval: Option<u16>,
+    pub safety_stop_time: Option<u16>,
+    pub heart_rate_source_type: Option<SourceType>,
+    pub heart_rate_antplus_device_type: Option<AntplusDeviceType>,
+    pub heart_rate_local_device_type: Option<LocalDeviceType>,
+    pub heart_rate_source: Option<u8>,
+    pub travel_gas: Option<MessageIndex>,
+    pub ccr_low_setpoint_switch_mode: Option<CcrSetpointSwitchMode>,
+    pub ccr_low_setpoint: Option<f64>,
+    pub ccr_low_setpoint_depth: Option<f64>,
+    pub ccr_high_setpoint_switch_mode: Option<CcrSetpointSwitchMode>,
+    pub ccr_high_setpoint: Option<f64>,
+    pub ccr_high_setpoint_depth: Option<f64>,
+    pub gas_consumption_display: Option<GasConsumptionRateType>,
+    pub up_key_enabled: Option<bool>,
+    pub dive_sounds: Option<Tone>,
+    pub last_stop_multiple: Option<f64>,
+    pub no_fly_time_mode: Option<NoFlyTimeMode>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for DiveSettingsMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::DiveSettings)? {
+            match field.name() {
+                "name" => message.name = field_value(record, field)?,
+                "model" => message.model = field_value(record, field)?,
+                "gf_low" => message.gf_low = field_value(record, field)?,
+                "gf_high" => message.gf_high = field_value(record, field)?,
+                "water_type" => message.water_type = field_value(record, field)?,
+                "water_density" => message.water_density = field_value(record, field)?,
+                "po2_warn" => message.po2_warn = field_value(record, field)?,
+                "po2_critical" => message.po2_critical = field_value(record, field)?,
+                "po2_deco" => message.po2_deco = field_value(record, field)?,
+                "safety_stop_enabled" => message.safety_stop_enabled = field_value(record, field)?,
+                "bottom_depth" => message.bottom_depth = field_value(record, field)?,
+                "bottom_time" => message.bottom_time = field_value(record, field)?,
+                "apnea_countdown_enabled" => {
+                    message.apnea_countdown_enabled = field_value(record, field)?
+                }
+                "apnea_countdown_time" => {
+                    message.apnea_countdown_time = field_value(record, field)?
+                }
+                "backlight_mode" => message.backlight_mode = field_value(record, field)?,
+                "backlight_brightness" => {
+                    message.backlight_brightness = field_value(record, field)?
+                }
+                "backlight_timeout" => message.backlight_timeout = field_value(record, field)?,
+                "repeat_dive_interval" => {
+                    message.repeat_dive_interval = field_value(record, field)?
+                }
+                "safety_stop_time" => message.safety_stop_time = field_value(record, field)?,
+                "heart_rate_source_type" => {
+                    message.heart_rate_source_type = field_value(record, field)?
+                }
+                "heart_rate_antplus_device_type" => {
+                    message.heart_rate_antplus_device_type = field_value(record, field)?
+                }
+                "heart_rate_local_device_type" => {
+                    message.heart_rate_local_device_type = field_value(record, field)?
+                }
+                "heart_rate_source" => message.heart_rate_source = field_value(record, field)?,
+                "travel_gas" => message.travel_gas = field_value(record, field)?,
+                "ccr_low_setpoint_switch_mode" => {
+                    message.ccr_low_setpoint_switch_mode = field_value(record, field)?
+                }
+                "ccr_low_setpoint" => message.ccr_low_setpoint = field_value(record, field)?,
+                "ccr_low_setpoint_depth" => {
+                    message.ccr_low_setpoint_depth = field_value(record, field)?
+                }
+                "ccr_high_setpoint_switch_mode" => {
+                    message.ccr_high_setpoint_switch_mode = field_value(record, field)?
+                }
+                "ccr_high_setpoint" => message.ccr_high_setpoint = field_value(record, field)?,
+                "ccr_high_setpoint_depth" => {
+                    message.ccr_high_setpoint_depth = field_value(record, field)?
+                }
+                "gas_consumption_display" => {
+                    message.gas_consumption_display = field_value(record, field)?
+                }
+                "up_key_enabled" => message.up_key_enabled = field_value(record, field)?,
+                "dive_sounds" => message.dive_sounds = field_value(record, field)?,
+                "last_stop_multiple" => message.last_stop_multiple = field_value(record, field)?,
+                "no_fly_time_mode" => message.no_fly_time_mode = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `dive_alarm` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiveAlarmMessage {
+    pub depth: Option<f64>,
+    pub time: Option<i32>,
+    pub enabled: Option<bool>,
+    pub alarm_type: Option<DiveAlarmType>,
+    pub sound: Option<Tone>,
+    pub dive_types: Option<SubSport>,
+    pub id: Option<u32>,
+    pub popup_enabled: Option<bool>,
+    pub trigger_on_descent: Option<bool>,
+    pub trigger_on_ascent: Option<bool>,
+    pub repeating: Option<bool>,
+    pub speed: Option<f64>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for DiveAlarmMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::DiveAlarm)? {
+            match field.name() {
+                "depth" => message.depth = field_value(record, field)?,
+                "time" => message.time = field_value(record, field)?,
+                "enabled" => message.enabled = field_value(record, field)?,
+                "alarm_type" => message.alarm_type = field_value(record, field)?,
+                "sound" => message.sound = field_value(record, field)?,
+                "dive_types" => message.dive_types = field_value(record, field)?,
+                "id" => message.id = field_value(record, field)?,
+                "popup_enabled" => message.popup_enabled = field_value(record, field)?,
+                "trigger_on_descent" => message.trigger_on_descent = field_value(record, field)?,
+                "trigger_on_ascent" => message.trigger_on_ascent = field_value(record, field)?,
+                "repeating" => message.repeating = field_value(record, field)?,
+                "speed" => message.speed = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `dive_apnea_alarm` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiveApneaAlarmMessage {
+    pub depth: Option<f64>,
+    pub time: Option<i32>,
+    pub enabled: Option<bool>,
+    pub alarm_type: Option<DiveAlarmType>,
+    pub sound: Option<Tone>,
+    pub dive_types: Option<SubSport>,
+    pub id: Option<u32>,
+    pub popup_enabled: Option<bool>,
+    pub trigger_on_descent: Option<bool>,
+    pub trigger_on_ascent: Option<bool>,
+    pub repeating: Option<bool>,
+    pub speed: Option<f64>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for DiveApneaAlarmMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::DiveApneaAlarm)? {
+            match field.name() {
+                "depth" => message.depth = field_value(record, field)?,
+                "time" => message.time = field_value(record, field)?,
+                "enabled" => message.enabled = field_value(record, field)?,
+                "alarm_type" => message.alarm_type = field_value(record, field)?,
+                "sound" => message.sound = field_value(record, field)?,
+                "dive_types" => message.dive_types = field_value(record, field)?,
+                "id" => message.id = field_value(record, field)?,
+                "popup_enabled" => message.popup_enabled = field_value(record, field)?,
+                "trigger_on_descent" => message.trigger_on_descent = field_value(record, field)?,
+                "trigger_on_ascent" => message.trigger_on_ascent = field_value(record, field)?,
+                "repeating" => message.repeating = field_value(record, field)?,
+                "speed" => message.speed = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `dive_gas` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiveGasMessage {
+    pub helium_content: Option<u8>,
+    pub oxygen_content: Option<u8>,
+    pub status: Option<DiveGasStatus>,
+    pub mode: Option<DiveGasMode>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for DiveGasMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::DiveGas)? {
+            match field.name() {
+                "helium_content" => message.helium_content = field_value(record, field)?,
+                "oxygen_content" => message.oxygen_content = field_value(record, field)?,
+                "status" => message.status = field_value(record, field)?,
+                "mode" => message.mode = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `goal` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GoalMessage {
+    pub sport: Option<Sport>,
+    pub sub_sport: Option<SubSport>,
+    pub start_date: Option<crate::timestamp::Timestamp>,
+    pub end_date: Option<crate::timestamp::Timestamp>,
+    pub r#type: Option<Goal>,
+    pub value: Option<u32>,
+    pub repeat: Option<bool>,
+    pub target_value: Option<u32>,
+    pub recurrence: Option<GoalRecurrence>,
+    pub recurrence_value: Option<u16>,
+    pub enabled: Option<bool>,
+    pub source: Option<GoalSource>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for GoalMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Goal)? {
+            match field.name() {
+                "sport" => message.sport = field_value(record, field)?,
+                "sub_sport" => message.sub_sport = field_value(record, field)?,
+                "start_date" => message.start_date = field_value(record, field)?,
+                "end_date" => message.end_date = field_value(record, field)?,
+                "type" => message.r#type = field_value(record, field)?,
+                "value" => message.value = field_value(record, field)?,
+                "repeat" => message.repeat = field_value(record, field)?,
+                "target_value" => message.target_value = field_value(record, field)?,
+                "recurrence" => message.recurrence = field_value(record, field)?,
+                "recurrence_value" => message.recurrence_value = field_value(record, field)?,
+                "enabled" => message.enabled = field_value(record, field)?,
+                "source" => message.source = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `activity` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ActivityMessage {
+    pub total_timer_time: Option<f64>,
+    pub num_sessions: Option<u16>,
+    pub r#type: Option<Activity>,
+    pub event: Option<Event>,
+    pub event_type: Option<EventType>,
+    pub local_timestamp: Option<crate::timestamp::Timestamp>,
+    pub event_group: Option<u8>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for ActivityMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Activity)? {
+            match field.name() {
+                "total_timer_time" => message.total_timer_time = field_value(record, field)?,
+                "num_sessions" => message.num_sessions = field_value(record, field)?,
+                "type" => message.r#type = field_value(record, field)?,
+                "event" => message.event = field_value(record, field)?,
+                "event_type" => message.event_type = field_value(record, field)?,
+                "local_timestamp" => message.local_timestamp = field_value(record, field)?,
+                "event_group" => message.event_group = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `session` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionMessage {
+    pub event: Option<Event>,
+    pub event_type: Option<EventType>,
+    pub start_time: Option<crate::timestamp::Timestamp>,
+    pub start_position_lat: Option<i32>,
+    pub start_position_long: Option<i32>,
+    pub sport: Option<Sport>,
+    pub sub_sport: Option<SubSport>,
+    pub total_elapsed_time: Option<f64>,
+    pub total_timer_time: Option<f64>,
+    pub total_distance: Option<f64>,
+    pub total_strides: Option<u32>,
+    pub total_strokes: Option<u32>,
+    pub total_cycles: Option<u32>,
+    pub total_calories: Option<u16>,
+    pub total_fat_calories: Option<u16>,
+    pub avg_speed: Option<f64>,
+    pub max_speed: Option<f64>,
+    pub avg_heart_rate: Option<u8>,
+    pub max_heart_rate: Option<u8>,
+    pub avg_running_cadence: Option<u8>,
+    pub avg_cadence: Option<u8>,
+    pub max_running_cadence: Option<u8>,
+    pub max_cadence: Option<u8>,
+    pub avg_power: Option<u16>,
+    pub max_power: Option<u16>,
+    pub total_ascent: Option<u16>,
+    pub total_descent: Option<u16>,
+    pub total_training_effect: Option<f64>,
+    pub first_lap_index: Option<u16>,
+    pub num_laps: Option<u16>,
+    pub event_group: Option<u8>,
+    pub trigger: Option<SessionTrigger>,
+    pub nec_lat: Option<i32>,
+    pub nec_long: Option<i32>,
+    pub swc_lat: Option<i32>,
+    pub swc_long: Option<i32>,
+    pub num_lengths: Option<u16>,
+    pub normalized_power: Option<u16>,
+    pub training_stress_score: Option<f64>,
+    pub intensity_factor: Option<f64>,
+    pub left_right_balance: Option<LeftRightBalance100>,
+    pub end_position_lat: Option<i32>,
+    pub end_position_long: Option<i32>,
+    pub avg_stroke_count: Option<f64>,
+    pub avg_stroke_distance: Option<f64>,
+    pub swim_stroke: Option<SwimStroke>,
+    pub pool_length: Option<f64>,
+    pub threshold_power: Option<u16>,
+    pub pool_length_unit: Option<DisplayMeasure>,
+    pub num_active_lengths: Option<u16>,
+    pub total_work: Option<u32>,
+    pub avg_altitude: Option<f64>,
+    pub max_altitude: Option<f64>,
+    pub gps_accuracy: Option<u8>,
+    pub avg_grade: Option<f64>,
+    pub avg_pos_grade: Option<f64>,
+    pub avg_neg_grade: Option<f64>,
+    pub max_pos_grade: Option<f64>,
+    pub max_neg_grade: Option<f64>,
+    pub avg_temperature: Option<i8>,
+    pub max_temperature: Option<i8>,
+    pub total_moving_time: Option<f64>,
+    pub avg_pos_vertical_speed: Option<f64>,
+    pub avg_neg_vertical_speed: Option<f64>,
+    pub max_pos_vertical_speed: Option<f64>,
+    pub max_neg_vertical_speed: Option<f64>,
+    pub min_heart_rate: Option<u8>,
+    pub time_in_hr_zone: Option<Vec<f64>>,
+    pub time_in_speed_zone: Option<Vec<f64>>,
+    pub time_in_cadence_zone: Option<Vec<f64>>,
+    pub time_in_power_zone: Option<Vec<f64>>,
+    pub avg_lap_time: Option<f64>,
+    pub best_lap_index: Option<u16>,
+    pub min_altitude: Option<f64>,
+    pub player_score: Option<u16>,
+    pub opponent_score: Option<u16>,
+    pub opponent_name: Option<String>,
+    pub stroke_count: Option<u16>,
+    pub zone_count: Option<u16>,
+    pub max_ball_speed: Option<f64>,
+    pub avg_ball_speed: Option<f64>,
+    pub avg_vertical_oscillation: Option<f64>,
+    pub avg_stance_time_percent: Option<f64>,
+    pub avg_stance_time: Option<f64>,
+    pub avg_fractional_cadence: Option<f64>,
+    pub max_fractional_cadence: Option<f64>,
+    pub total_fractional_cycles: Option<f64>,
+    pub avg_total_hemoglobin_conc: Option<Vec<f64>>,
+    pub min_total_hemoglobin_conc: Option<Vec<f64>>,
+    pub max_total_hemoglobin_conc: Option<Vec<f64>>,
+    pub avg_saturated_hemoglobin_percent: Option<Vec<f64>>,
+    pub min_saturated_hemoglobin_percent: Option<Vec<f64>>,
+    pub max_saturated_hemoglobin_percent: Option<Vec<f64>>,
+    pub avg_left_torque_effectiveness: Option<f64>,
+    pub avg_right_torque_effectiveness: Option<f64>,
+    pub avg_left_pedal_smoothness: Option<f64>,
+    pub avg_right_pedal_smoothness: Option<f64>,
+    pub avg_combined_pedal_smoothness: Option<f64>,
+    pub sport_profile_name: Option<String>,
+    pub sport_index: Option<u8>,
+    pub time_standing: Option<f64>,
+    pub stand_count: Option<u16>,
+    pub avg_left_pco: Option<i8>,
+    pub avg_right_pco: Option<i8>,
+    pub avg_left_power_phase: Option<Vec<f64>>,
+    pub avg_left_power_phase_peak: Option<Vec<f64>>,
+    pub avg_right_power_phase: Option<Vec<f64>>,
+    pub avg_right_power_phase_peak: Option<Vec<f64>>,
+    pub avg_power_position: Option<Vec<u16>>,
+    pub max_power_position: Option<Vec<u16>>,
+    pub avg_cadence_position: Option<Vec<u8>>,
+    pub max_cadence_position: Option<Vec<u8>>,
+    pub enhanced_avg_speed: Option<f64>,
+    pub enhanced_max_speed: Option<f64>,
+    pub enhanced_avg_altitude: Option<f64>,
+    pub enhanced_min_altitude: Option<f64>,
+    pub enhanced_max_altitude: Option<f64>,
+    pub avg_lev_motor_power: Option<u16>,
+    pub max_lev_motor_power: Option<u16>,
+    pub lev_battery_consumption: Option<f64>,
+    pub avg_vertical_ratio: Option<f64>,
+    pub avg_stance_time_balance: Option<f64>,
+    pub avg_step_length: Option<f64>,
+    pub total_anaerobic_training_effect: Option<f64>,
+    pub avg_vam: Option<f64>,
+    pub avg_depth: Option<f64>,
+    pub max_depth: Option<f64>,
+    pub surface_interval: Option<u32>,
+    pub start_cns: Option<u8>,
+    pub end_cns: Option<u8>,
+    pub start_n2: Option<u16>,
+    pub end_n2: Option<u16>,
+    pub avg_respiration_rate: Option<u8>,
+    pub max_respiration_rate: Option<u8>,
+    pub min_respiration_rate: Option<u8>,
+    pub min_temperature: Option<i8>,
+    pub o2_toxicity: Option<u16>,
+    pub dive_number: Option<u32>,
+    pub training_load_peak: Option<f64>,
+    pub enhanced_avg_respiration_rate: Option<f64>,
+    pub enhanced_max_respiration_rate: Option<f64>,
+    pub enhanced_min_respiration_rate: Option<f64>,
+    pub total_grit: Option<f32>,
+    pub total_flow: Option<f32>,
+    pub jump_count: Option<u16>,
+    pub avg_grit: Option<f32>,
+    pub avg_flow: Option<f32>,
+    pub workout_feel: Option<u8>,
+    pub workout_rpe: Option<u8>,
+    pub avg_spo2: Option<u8>,
+    pub avg_stress: Option<u8>,
+    pub sdrr_hrv: Option<u8>,
+    pub rmssd_hrv: Option<u8>,
+    pub total_fractional_ascent: Option<f64>,
+    pub total_fractional_descent: Option<f64>,
+    pub avg_core_temperature: Option<f64>,
+    pub min_core_temperature: Option<f64>,
+    pub max_core_temperature: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SessionMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Session)? {
+            match field.name() {
+                "event" => message.event = field_value(record, field)?,
+                "event_type" => message.event_type = field_value(record, field)?,
+                "start_time" => message.start_time = field_value(record, field)?,
+                "start_position_lat" => message.start_position_lat = field_value(record, field)?,
+                "start_position_long" => message.start_position_long = field_value(record, field)?,
+                "sport" => message.sport = field_value(record, field)?,
+                "sub_sport" => message.sub_sport = field_value(record, field)?,
+                "total_elapsed_time" => message.total_elapsed_time = field_value(record, field)?,
+                "total_timer_time" => message.total_timer_time = field_value(record, field)?,
+                "total_distance" => message.total_distance = field_value(record, field)?,
+                "total_strides" => message.total_strides = field_value(record, field)?,
+                "total_strokes" => message.total_strokes = field_value(record, field)?,
+                "total_cycles" => message.total_cycles = field_value(record, field)?,
+                "total_calories" => message.total_calories = field_value(record, field)?,
+                "total_fat_calories" => message.total_fat_calories = field_value(record, field)?,
+                "avg_speed" => message.avg_speed = field_value(record, field)?,
+                "max_speed" => message.max_speed = field_value(record, field)?,
+                "avg_heart_rate" => message.avg_heart_rate = field_value(record, field)?,
+                "max_heart_rate" => message.max_heart_rate = field_value(record, field)?,
+                "avg_running_cadence" => message.avg_running_cadence = field_value(record, field)?,
+                "avg_cadence" => message.avg_cadence = field_value(record, field)?,
+                "max_running_cadence" => message.max_running_cadence = field_value(record, field)?,
+                "max_cadence" => message.max_cadence = field_value(record, field)?,
+                "avg_power" => message.avg_power = field_value(record, field)?,
+                "max_power" => message.max_power = field_value(record, field)?,
+                "total_ascent" => message.total_ascent = field_value(record, field)?,
+                "total_descent" => message.total_descent = field_value(record, field)?,
+                "total_training_effect" => {
+                    message.total_training_effect = field_value(record, field)?
+                }
+                "first_lap_index" => message.first_lap_index = field_value(record, field)?,
+                "num_laps" => message.num_laps = field_value(record, field)?,
+                "event_group" => message.event_group = field_value(record, field)?,
+                "trigger" => message.trigger = field_value(record, field)?,
+                "nec_lat" => message.nec_lat = field_value(record, field)?,
+                "nec_long" => message.nec_long = field_value(record, field)?,
+                "swc_lat" => message.swc_lat = field_value(record, field)?,
+                "swc_long" => message.swc_long = field_value(record, field)?,
+                "num_lengths" => message.num_lengths = field_value(record, field)?,
+                "normalized_power" => message.normalized_power = field_value(record, field)?,
+                "training_stress_score" => {
+                    message.training_stress_score = field_value(record, field)?
+                }
+                "intensity_factor" => message.intensity_factor = field_value(record, field)?,
+                "left_right_balance" => message.left_right_balance = field_value(record, field)?,
+                "end_position_lat" => message.end_position_lat = field_value(record, field)?,
+                "end_position_long" => message.end_position_long = field_value(record, field)?,
+                "avg_stroke_count" => message.avg_stroke_count = field_value(record, field)?,
+                "avg_stroke_distance" => message.avg_stroke_distance = field_value(record, field)?,
+                "swim_stroke" => message.swim_stroke = field_value(record, field)?,
+                "pool_length" => message.pool_length = field_value(record, field)?,
+                "threshold_power" => message.threshold_power = field_value(record, field)?,
+                "pool_length_unit" => message.pool_length_unit = field_value(record, field)?,
+                "num_active_lengths" => message.num_active_lengths = field_value(record, field)?,
+                "total_work" => message.total_work = field_value(record, field)?,
+                "avg_altitude" => message.avg_altitude = field_value(record, field)?,
+                "max_altitude" => message.max_altitude = field_value(record, field)?,
+                "gps_accuracy" => message.gps_accuracy = field_value(record, field)?,
+                "avg_grade" => message.avg_grade = field_value(record, field)?,
+                "avg_pos_grade" => message.avg_pos_grade = field_value(record, field)?,
+                "avg_neg_grade" => message.avg_neg_grade = field_value(record, field)?,
+                "max_pos_grade" => message.max_pos_grade = field_value(record, field)?,
+                "max_neg_grade" => message.max_neg_grade = field_value(record, field)?,
+                "avg_temperature" => message.avg_temperature = field_value(record, field)?,
+                "max_temperature" => message.max_temperature = field_value(record, field)?,
+                "total_moving_time" => message.total_moving_time = field_value(record, field)?,
+                "avg_pos_vertical_speed" => {
+                    message.avg_pos_vertical_speed = field_value(record, field)?
+                }
+                "avg_neg_vertical_speed" => {
+                    message.avg_neg_vertical_speed = field_value(record, field)?
+                }
+                "max_pos_vertical_speed" => {
+                    message.max_pos_vertical_speed = field_value(record, field)?
+                }
+                "max_neg_vertical_speed" => {
+                    message.max_neg_vertical_speed = field_value(record, field)?
+                }
+                "min_heart_rate" => message.min_heart_rate = field_value(record, field)?,
+                "time_in_hr_zone" => message.time_in_hr_zone = field_value(record, field)?,
+                "time_in_speed_zone" => message.time_in_speed_zone = field_value(record, field)?,
+                "time_in_cadence_zone" => {
+                    message.time_in_cadence_zone = field_value(record, field)?
+                }
+                "time_in_power_zone" => message.time_in_power_zone = field_value(record, field)?,
+                "avg_lap_time" => message.avg_lap_time = field_value(record, field)?,
+                "best_lap_index" => message.best_lap_index = field_value(record, field)?,
+                "min_altitude" => message.min_altitude = field_value(record, field)?,
+                "player_score" => message.player_score = field_value(record, field)?,
+                "opponent_score" => message.opponent_score = field_value(record, field)?,
+                "opponent_name" => message.opponent_name = field_value(record, field)?,
+                "stroke_count" => message.stroke_count = field_value(record, field)?,
+                "zone_count" => message.zone_count = field_value(record, field)?,
+                "max_ball_speed" => message.max_ball_speed = field_value(record, field)?,
+                "avg_ball_speed" => message.avg_ball_speed = field_value(record, field)?,
+                "avg_vertical_oscillation" => {
+                    message.avg_vertical_oscillation = field_value(record, field)?
+                }
+                "avg_stance_time_percent" => {
+                    message.avg_stance_time_percent = field_value(record, field)?
+                }
+                "avg_stance_time" => message.avg_stance_time = field_value(record, field)?,
+                "avg_fractional_cadence" => {
+                    message.avg_fractional_cadence = field_value(record, field)?
+                }
+                "max_fractional_cadence" => {
+                    message.max_fractional_cadence = field_value(record, field)?
+                }
+                "total_fractional_cycles" => {
+                    message.total_fractional_cycles = field_value(record, field)?
+                }
+                "avg_total_hemoglobin_conc" => {
+                    message.avg_total_hemoglobin_conc = field_value(record, field)?
+                }
+                "min_total_hemoglobin_conc" => {
+                    message.min_total_hemoglobin_conc = field_value(record, field)?
+                }
+                "max_total_hemoglobin_conc" => {
+                    message.max_total_hemoglobin_conc = field_value(record, field)?
+                }
+                "avg_saturated_hemoglobin_percent" => {
+                    message.avg_saturated_hemoglobin_percent = field_value(record, field)?
+                }
+                "min_saturated_hemoglobin_percent" => {
+                    message.min_saturated_hemoglobin_percent = field_value(record, field)?
+                }
+                "max_saturated_hemoglobin_percent" => {
+                    message.max_saturated_hemoglobin_percent = field_value(record, field)?
+                }
+                "avg_left_torque_effectiveness" => {
+                    message.avg_left_torque_effectiveness = field_value(record, field)?
+                }
+                "avg_right_torque_effectiveness" => {
+                    message.avg_right_torque_effectiveness = field_value(record, field)?
+                }
+                "avg_left_pedal_smoothness" => {
+                    message.avg_left_pedal_smoothness = field_value(record, field)?
+                }
+                "avg_right_pedal_smoothness" => {
+                    message.avg_right_pedal_smoothness = field_value(record, field)?
+                }
+                "avg_combined_pedal_smoothness" => {
+                    message.avg_combined_pedal_smoothness = field_value(record, field)?
+                }
+                "sport_profile_name" => message.sport_profile_name = field_value(record, field)?,
+                "sport_index" => message.sport_index = field_value(record, field)?,
+                "time_standing" => message.time_standing = field_value(record, field)?,
+                "stand_count" => message.stand_count = field_value(record, field)?,
+                "avg_left_pco" => message.avg_left_pco = field_value(record, field)?,
+                "avg_right_pco" => message.avg_right_pco = field_value(record, field)?,
+                "avg_left_power_phase" => {
+                    message.avg_left_power_phase = field_value(record, field)?
+                }
+                "avg_left_power_phase_peak" => {
+                    message.avg_left_power_phase_peak = field_value(record, field)?
+                }
+                "avg_right_power_phase" => {
+                    message.avg_right_power_phase = field_value(record, field)?
+                }
+                "avg_right_power_phase_peak" => {
+                    message.avg_right_power_phase_peak = field_value(record, field)?
+                }
+                "avg_power_position" => message.avg_power_position = field_value(record, field)?,
+                "max_power_position" => message.max_power_position = field_value(record, field)?,
+                "avg_cadence_position" => {
+                    message.avg_cadence_position = field_value(record, field)?
+                }
+                "max_cadence_position" => {
+                    message.max_cadence_position = field_value(record, field)?
+                }
+                "enhanced_avg_speed" => message.enhanced_avg_speed = field_value(record, field)?,
+                "enhanced_max_speed" => message.enhanced_max_speed = field_value(record, field)?,
+                "enhanced_avg_altitude" => {
+                    message.enhanced_avg_altitude = field_value(record, field)?
+                }
+                "enhanced_min_altitude" => {
+                    message.enhanced_min_altitude = field_value(record, field)?
+                }
+                "enhanced_max_altitude" => {
+                    message.enhanced_max_altitude = field_value(record, field)?
+                }
+                "avg_lev_motor_power" => message.avg_lev_motor_power = field_value(record, field)?,
+                "max_lev_motor_power" => message.max_lev_motor_power = field_value(record, field)?,
+                "lev_battery_consumption" => {
+                    message.lev_battery_consumption = field_value(record, field)?
+                }
+                "avg_vertical_ratio" => message.avg_vertical_ratio = field_value(record, field)?,
+                "avg_stance_time_balance" => {
+                    message.avg_stance_time_balance = field_value(record, field)?
+                }
+                "avg_step_length" => message.avg_step_length = field_value(record, field)?,
+                "total_anaerobic_training_effect" => {
+                    message.total_anaerobic_training_effect = field_value(record, field)?
+                }
+                "avg_vam" => message.avg_vam = field_value(record, field)?,
+                "avg_depth" => message.avg_depth = field_value(record, field)?,
+                "max_depth" => message.max_depth = field_value(record, field)?,
+                "surface_interval" => message.surface_interval = field_value(record, field)?,
+                "start_cns" => message.start_cns = field_value(record, field)?,
+                "end_cns" => message.end_cns = field_value(record, field)?,
+                "start_n2" => message.start_n2 = field_value(record, field)?,
+                "end_n2" => message.end_n2 = field_value(record, field)?,
+                "avg_respiration_rate" => {
+                    message.avg_respiration_rate = field_value(record, field)?
+                }
+                "max_respiration_rate" => {
+                    message.max_respiration_rate = field_value(record, field)?
+                }
+                "min_respiration_rate" => {
+                    message.min_respiration_rate = field_value(record, field)?
+                }
+                "min_temperature" => message.min_temperature = field_value(record, field)?,
+                "o2_toxicity" => message.o2_toxicity = field_value(record, field)?,
+                "dive_number" => message.dive_number = field_value(record, field)?,
+                "training_load_peak" => message.training_load_peak = field_value(record, field)?,
+                "enhanced_avg_respiration_rate" => {
+                    message.enhanced_avg_respiration_rate = field_value(record, field)?
+                }
+                "enhanced_max_respiration_rate" => {
+                    message.enhanced_max_respiration_rate = field_value(record, field)?
+                }
+                "enhanced_min_respiration_rate" => {
+                    message.enhanced_min_respiration_rate = field_value(record, field)?
+                }
+                "total_grit" => message.total_grit = field_value(record, field)?,
+                "total_flow" => message.total_flow = field_value(record, field)?,
+                "jump_count" => message.jump_count = field_value(record, field)?,
+                "avg_grit" => message.avg_grit = field_value(record, field)?,
+                "avg_flow" => message.avg_flow = field_value(record, field)?,
+                "workout_feel" => message.workout_feel = field_value(record, field)?,
+                "workout_rpe" => message.workout_rpe = field_value(record, field)?,
+                "avg_spo2" => message.avg_spo2 = field_value(record, field)?,
+                "avg_stress" => message.avg_stress = field_value(record, field)?,
+                "sdrr_hrv" => message.sdrr_hrv = field_value(record, field)?,
+                "rmssd_hrv" => message.rmssd_hrv = field_value(record, field)?,
+                "total_fractional_ascent" => {
+                    message.total_fractional_ascent = field_value(record, field)?
+                }
+                "total_fractional_descent" => {
+                    message.total_fractional_descent = field_value(record, field)?
+                }
+                "avg_core_temperature" => {
+                    message.avg_core_temperature = field_value(record, field)?
+                }
+                "min_core_temperature" => {
+                    message.min_core_temperature = field_value(record, field)?
+                }
+                "max_core_temperature" => {
+                    message.max_core_temperature = field_value(record, field)?
+                }
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `lap` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LapMessage {
+    pub event: Option<Event>,
+    pub event_type: Option<EventType>,
+    pub start_time: Option<crate::timestamp::Timestamp>,
+    pub start_position_lat: Option<i32>,
+    pub start_position_long: Option<i32>,
+    pub end_position_lat: Option<i32>,
+    pub end_position_long: Option<i32>,
+    pub total_elapsed_time: Option<f64>,
+    pub total_timer_time: Option<f64>,
+    pub total_distance: Option<f64>,
+    pub total_strides: Option<u32>,
+    pub total_strokes: Option<u32>,
+    pub total_cycles: Option<u32>,
+    pub total_calories: Option<u16>,
+    pub total_fat_calories: Option<u16>,
+    pub avg_speed: Option<f64>,
+    pub max_speed: Option<f64>,
+    pub avg_heart_rate: Option<u8>,
+    pub max_heart_rate: Option<u8>,
+    pub avg_running_cadence: Option<u8>,
+    pub avg_cadence: Option<u8>,
+    pub max_running_cadence: Option<u8>,
+    pub max_cadence: Option<u8>,
+    pub avg_power: Option<u16>,
+    pub max_power: Option<u16>,
+    pub total_ascent: Option<u16>,
+    pub total_descent: Option<u16>,
+    pub intensity: Option<Intensity>,
+    pub lap_trigger: Option<LapTrigger>,
+    pub sport: Option<Sport>,
+    pub event_group: Option<u8>,
+    pub num_lengths: Option<u16>,
+    pub normalized_power: Option<u16>,
+    pub left_right_balance: Option<LeftRightBalance100>,
+    pub first_length_index: Option<u16>,
+    pub avg_stroke_distance: Option<f64>,
+    pub swim_stroke: Option<SwimStroke>,
+    pub sub_sport: Option<SubSport>,
+    pub num_active_lengths: Option<u16>,
+    pub total_work: Option<u32>,
+    pub avg_altitude: Option<f64>,
+    pub max_altitude: Option<f64>,
+    pub gps_accuracy: Option<u8>,
+    pub avg_grade: Option<f64>,
+    pub avg_pos_grade: Option<f64>,
+    pub avg_neg_grade: Option<f64>,
+    pub max_pos_grade: Option<f64>,
+    pub max_neg_grade: Option<f64>,
+    pub avg_temperature: Option<i8>,
+    pub max_temperature: Option<i8>,
+    pub total_moving_time: Option<f64>,
+    pub avg_pos_vertical_speed: Option<f64>,
+    pub avg_neg_vertical_speed: Option<f64>,
+    pub max_pos_vertical_speed: Option<f64>,
+    pub max_neg_vertical_speed: Option<f64>,
+    pub time_in_hr_zone: Option<Vec<f64>>,
+    pub time_in_speed_zone: Option<Vec<f64>>,
+    pub time_in_cadence_zone: Option<Vec<f64>>,
+    pub time_in_power_zone: Option<Vec<f64>>,
+    pub repetition_num: Option<u16>,
+    pub min_altitude: Option<f64>,
+    pub min_heart_rate: Option<u8>,
+    pub wkt_step_index: Option<MessageIndex>,
+    pub opponent_score: Option<u16>,
+    pub stroke_count: Option<u16>,
+    pub zone_count: Option<u16>,
+    pub avg_vertical_oscillation: Option<f64>,
+    pub avg_stance_time_percent: Option<f64>,
+    pub avg_stance_time: Option<f64>,
+    pub avg_fractional_cadence: Option<f64>,
+    pub max_fractional_cadence: Option<f64>,
+    pub total_fractional_cycles: Option<f64>,
+    pub player_score: Option<u16>,
+    pub avg_total_hemoglobin_conc: Option<Vec<f64>>,
+    pub min_total_hemoglobin_conc: Option<Vec<f64>>,
+    pub max_total_hemoglobin_conc: Option<Vec<f64>>,
+    pub avg_saturated_hemoglobin_percent: Option<Vec<f64>>,
+    pub min_saturated_hemoglobin_percent: Option<Vec<f64>>,
+    pub max_saturated_hemoglobin_percent: Option<Vec<f64>>,
+    pub avg_left_torque_effectiveness: Option<f64>,
+    pub avg_right_torque_effectiveness: Option<f64>,
+    pub avg_left_pedal_smoothness: Option<f64>,
+    pub avg_right_pedal_smoothness: Option<f64>,
+    pub avg_combined_pedal_smoothness: Option<f64>,
+    pub time_standing: Option<f64>,
+    pub stand_count: Option<u16>,
+    pub avg_left_pco: Option<i8>,
+    pub avg_right_pco: Option<i8>,
+    pub avg_left_power_phase: Option<Vec<f64>>,
+    pub avg_left_power_phase_peak: Option<Vec<f64>>,
+    pub avg_right_power_phase: Option<Vec<f64>>,
+    pub avg_right_power_phase_peak: Option<Vec<f64>>,
+    pub avg_power_position: Option<Vec<u16>>,
+    pub max_power_position: Option<Vec<u16>>,
+    pub avg_cadence_position: Option<Vec<u8>>,
+    pub max_cadence_position: Option<Vec<u8>>,
+    pub enhanced_avg_speed: Option<f64>,
+    pub enhanced_max_speed: Option<f64>,
+    pub enhanced_avg_altitude: Option<f64>,
+    pub enhanced_min_altitude: Option<f64>,
+    pub enhanced_max_altitude: Option<f64>,
+    pub avg_lev_motor_power: Option<u16>,
+    pub max_lev_motor_power: Option<u16>,
+    pub lev_battery_consumption: Option<f64>,
+    pub avg_vertical_ratio: Option<f64>,
+    pub avg_stance_time_balance: Option<f64>,
+    pub avg_step_length: Option<f64>,
+    pub avg_vam: Option<f64>,
+    pub avg_depth: Option<f64>,
+    pub max_depth: Option<f64>,
+    pub min_temperature: Option<i8>,
+    pub enhanced_avg_respiration_rate: Option<f64>,
+    pub enhanced_max_respiration_rate: Option<f64>,
+    pub avg_respiration_rate: Option<u8>,
+    pub max_respiration_rate: Option<u8>,
+    pub total_grit: Option<f32>,
+    pub total_flow: Option<f32>,
+    pub jump_count: Option<u16>,
+    pub avg_grit: Option<f32>,
+    pub avg_flow: Option<f32>,
+    pub total_fractional_ascent: Option<f64>,
+    pub total_fractional_descent: Option<f64>,
+    pub avg_core_temperature: Option<f64>,
+    pub min_core_temperature: Option<f64>,
+    pub max_core_temperature: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for LapMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Lap)? {
+            match field.name() {
+                "event" => message.event = field_value(record, field)?,
+                "event_type" => message.event_type = field_value(record, field)?,
+                "start_time" => message.start_time = field_value(record, field)?,
+                "start_position_lat" => message.start_position_lat = field_value(record, field)?,
+                "start_position_long" => message.start_position_long = field_value(record, field)?,
+                "end_position_lat" => message.end_position_lat = field_value(record, field)?,
+                "end_position_long" => message.end_position_long = field_value(record, field)?,
+                "total_elapsed_time" => message.total_elapsed_time = field_value(record, field)?,
+                "total_timer_time" => message.total_timer_time = field_value(record, field)?,
+                "total_distance" => message.total_distance = field_value(record, field)?,
+                "total_strides" => message.total_strides = field_value(record, field)?,
+                "total_strokes" => message.total_strokes = field_value(record, field)?,
+                "total_cycles" => message.total_cycles = field_value(record, field)?,
+                "total_calories" => message.total_calories = field_value(record, field)?,
+                "total_fat_calories" => message.total_fat_calories = field_value(record, field)?,
+                "avg_speed" => message.avg_speed = field_value(record, field)?,
+                "max_speed" => message.max_speed = field_value(record, field)?,
+                "avg_heart_rate" => message.avg_heart_rate = field_value(record, field)?,
+                "max_heart_rate" => message.max_heart_rate = field_value(record, field)?,
+                "avg_running_cadence" => message.avg_running_cadence = field_value(record, field)?,
+                "avg_cadence" => message.avg_cadence = field_value(record, field)?,
+                "max_running_cadence" => message.max_running_cadence = field_value(record, field)?,
+                "max_cadence" => message.max_cadence = field_value(record, field)?,
+                "avg_power" => message.avg_power = field_value(record, field)?,
+                "max_power" => message.max_power = field_value(record, field)?,
+                "total_ascent" => message.total_ascent = field_value(record, field)?,
+                "total_descent" => message.total_descent = field_value(record, field)?,
+                "intensity" => message.intensity = field_value(record, field)?,
+                "lap_trigger" => message.lap_trigger = field_value(record, field)?,
+                "sport" => message.sport = field_value(record, field)?,
+                "event_group" => message.event_group = field_value(record, field)?,
+                "num_lengths" => message.num_lengths = field_value(record, field)?,
+                "normalized_power" => message.normalized_power = field_value(record, field)?,
+                "left_right_balance" => message.left_right_balance = field_value(record, field)?,
+                "first_length_index" => message.first_length_index = field_value(record, field)?,
+                "avg_stroke_distance" => message.avg_stroke_distance = field_value(record, field)?,
+                "swim_stroke" => message.swim_stroke = field_value(record, field)?,
+                "sub_sport" => message.sub_sport = field_value(record, field)?,
+                "num_active_lengths" => message.num_active_lengths = field_value(record, field)?,
+                "total_work" => message.total_work = field_value(record, field)?,
+                "avg_altitude" => message.avg_altitude = field_value(record, field)?,
+                "max_altitude" => message.max_altitude = field_value(record, field)?,
+                "gps_accuracy" => message.gps_accuracy = field_value(record, field)?,
+                "avg_grade" => message.avg_grade = field_value(record, field)?,
+                "avg_pos_grade" => message.avg_pos_grade = field_value(record, field)?,
+                "avg_neg_grade" => message.avg_neg_grade = field_value(record, field)?,
+                "max_pos_grade" => message.max_pos_grade = field_value(record, field)?,
+                "max_neg_grade" => message.max_neg_grade = field_value(record, field)?,
+                "avg_temperature" => message.avg_temperature = field_value(record, field)?,
+                "max_temperature" => message.max_temperature = field_value(record, field)?,
+                "total_moving_time" => message.total_moving_time = field_value(record, field)?,
+                "avg_pos_vertical_speed" => {
+                    message.avg_pos_vertical_speed = field_value(record, field)?
+                }
+                "avg_neg_vertical_speed" => {
+                    message.avg_neg_vertical_speed = field_value(record, field)?
+                }
+                "max_pos_vertical_speed" => {
+                    message.max_pos_vertical_speed = field_value(record, field)?
+                }
+                "max_neg_vertical_speed" => {
+                    message.max_neg_vertical_speed = field_value(record, field)?
+                }
+                "time_in_hr_zone" => message.time_in_hr_zone = field_value(record, field)?,
+                "time_in_speed_zone" => message.time_in_speed_zone = field_value(record, field)?,
+                "time_in_cadence_zone" => {
+                    message.time_in_cadence_zone = field_value(record, field)?
+                }
+                "time_in_power_zone" => message.time_in_power_zone = field_value(record, field)?,
+                "repetition_num" => message.repetition_num = field_value(record, field)?,
+                "min_altitude" => message.min_altitude = field_value(record, field)?,
+                "min_heart_rate" => message.min_heart_rate = field_value(record, field)?,
+                "wkt_step_index" => message.wkt_step_index = field_value(record, field)?,
+                "opponent_score" => message.opponent_score = field_value(record, field)?,
+                "stroke_count" => message.stroke_count = field_value(record, field)?,
+                "zone_count" => message.zone_count = field_value(record, field)?,
+                "avg_vertical_oscillation" => {
+                    message.avg_vertical_oscillation = field_value(record, field)?
+                }
+                "avg_stance_time_percent" => {
+                    message.avg_stance_time_percent = field_value(record, field)?
+                }
+                "avg_stance_time" => message.avg_stance_time = field_value(record, field)?,
+                "avg_fractional_cadence" => {
+                    message.avg_fractional_cadence = field_value(record, field)?
+                }
+                "max_fractional_cadence" => {
+                    message.max_fractional_cadence = field_value(record, field)?
+                }
+                "total_fractional_cycles" => {
+                    message.total_fractional_cycles = field_value(record, field)?
+                }
+                "player_score" => message.player_score = field_value(record, field)?,
+                "avg_total_hemoglobin_conc" => {
+                    message.avg_total_hemoglobin_conc = field_value(record, field)?
+                }
+                "min_total_hemoglobin_conc" => {
+                    message.min_total_hemoglobin_conc = field_value(record, field)?
+                }
+                "max_total_hemoglobin_conc" => {
+                    message.max_total_hemoglobin_conc = field_value(record, field)?
+                }
+                "avg_saturated_hemoglobin_percent" => {
+                    message.avg_saturated_hemoglobin_percent = field_value(record, field)?
+                }
+                "min_saturated_hemoglobin_percent" => {
+                    message.min_saturated_hemoglobin_percent = field_value(record, field)?
+                }
+                "max_saturated_hemoglobin_percent" => {
+                    message.max_saturated_hemoglobin_percent = field_value(record, field)?
+                }
+                "avg_left_torque_effectiveness" => {
+                    message.avg_left_torque_effectiveness = field_value(record, field)?
+                }
+                "avg_right_torque_effectiveness" => {
+                    message.avg_right_torque_effectiveness = field_value(record, field)?
+                }
+                "avg_left_pedal_smoothness" => {
+                    message.avg_left_pedal_smoothness = field_value(record, field)?
+                }
+                "avg_right_pedal_smoothness" => {
+                    message.avg_right_pedal_smoothness = field_value(record, field)?
+                }
+                "avg_combined_pedal_smoothness" => {
+                    message.avg_combined_pedal_smoothness = field_value(record, field)?
+                }
+                "time_standing" => message.time_standing = field_value(record, field)?,
+                "stand_count" => message.stand_count = field_value(record, field)?,
+                "avg_left_pco" => message.avg_left_pco = field_value(record, field)?,
+                "avg_right_pco" => message.avg_right_pco = field_value(record, field)?,
+                "avg_left_power_phase" => {
+                    message.avg_left_power_phase = field_value(record, field)?
+                }
+                "avg_left_power_phase_peak" => {
+                    message.avg_left_power_phase_peak = field_value(record, field)?
+                }
+                "avg_right_power_phase" => {
+                    message.avg_right_power_phase = field_value(record, field)?
+                }
+                "avg_right_power_phase_peak" => {
+                    message.avg_right_power_phase_peak = field_value(record, field)?
+                }
+                "avg_power_position" => message.avg_power_position = field_value(record, field)?,
+                "max_power_position" => message.max_power_position = field_value(record, field)?,
+                "avg_cadence_position" => {
+                    message.avg_cadence_position = field_value(record, field)?
+                }
+                "max_cadence_position" => {
+                    message.max_cadence_position = field_value(record, field)?
+                }
+                "enhanced_avg_speed" => message.enhanced_avg_speed = field_value(record, field)?,
+                "enhanced_max_speed" => message.enhanced_max_speed = field_value(record, field)?,
+                "enhanced_avg_altitude" => {
+                    message.enhanced_avg_altitude = field_value(record, field)?
+                }
+                "enhanced_min_altitude" => {
+                    message.enhanced_min_altitude = field_value(record, field)?
+                }
+                "enhanced_max_altitude" => {
+                    message.enhanced_max_altitude = field_value(record, field)?
+                }
+                "avg_lev_motor_power" => message.avg_lev_motor_power = field_value(record, field)?,
+                "max_lev_motor_power" => message.max_lev_motor_power = field_value(record, field)?,
+                "lev_battery_consumption" => {
+                    message.lev_battery_consumption = field_value(record, field)?
+                }
+                "avg_vertical_ratio" => message.avg_vertical_ratio = field_value(record, field)?,
+                "avg_stance_time_balance" => {
+                    message.avg_stance_time_balance = field_value(record, field)?
+                }
+                "avg_step_length" => message.avg_step_length = field_value(record, field)?,
+                "avg_vam" => message.avg_vam = field_value(record, field)?,
+                "avg_depth" => message.avg_depth = field_value(record, field)?,
+                "max_depth" => message.max_depth = field_value(record, field)?,
+                "min_temperature" => message.min_temperature = field_value(record, field)?,
+                "enhanced_avg_respiration_rate" => {
+                    message.enhanced_avg_respiration_rate = field_value(record, field)?
+                }
+                "enhanced_max_respiration_rate" => {
+                    message.enhanced_max_respiration_rate = field_value(record, field)?
+                }
+                "avg_respiration_rate" => {
+                    message.avg_respiration_rate = field_value(record, field)?
+                }
+                "max_respiration_rate" => {
+                    message.max_respiration_rate = field_value(record, field)?
+                }
+                "total_grit" => message.total_grit = field_value(record, field)?,
+                "total_flow" => message.total_flow = field_value(record, field)?,
+                "jump_count" => message.jump_count = field_value(record, field)?,
+                "avg_grit" => message.avg_grit = field_value(record, field)?,
+                "avg_flow" => message.avg_flow = field_value(record, field)?,
+                "total_fractional_ascent" => {
+                    message.total_fractional_ascent = field_value(record, field)?
+                }
+                "total_fractional_descent" => {
+                    message.total_fractional_descent = field_value(record, field)?
+                }
+                "avg_core_temperature" => {
+                    message.avg_core_temperature = field_value(record, field)?
+                }
+                "min_core_temperature" => {
+                    message.min_core_temperature = field_value(record, field)?
+                }
+                "max_core_temperature" => {
+                    message.max_core_temperature = field_value(record, field)?
+                }
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `length` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LengthMessage {
+    pub event: Option<Event>,
+    pub event_type: Option<EventType>,
+    pub start_time: Option<crate::timestamp::Timestamp>,
+    pub total_elapsed_time: Option<f64>,
+    pub total_timer_time: Option<f64>,
+    pub total_strokes: Option<u16>,
+    pub avg_speed: Option<f64>,
+    pub swim_stroke: Option<SwimStroke>,
+    pub avg_swimming_cadence: Option<u8>,
+    pub event_group: Option<u8>,
+    pub total_calories: Option<u16>,
+    pub length_type: Option<LengthType>,
+    pub player_score: Option<u16>,
+    pub opponent_score: Option<u16>,
+    pub stroke_count: Option<u16>,
+    pub zone_count: Option<u16>,
+    pub enhanced_avg_respiration_rate: Option<f64>,
+    pub enhanced_max_respiration_rate: Option<f64>,
+    pub avg_respiration_rate: Option<u8>,
+    pub max_respiration_rate: Option<u8>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for LengthMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Length)? {
+            match field.name() {
+                "event" => message.event = field_value(record, field)?,
+                "event_type" => message.event_type = field_value(record, field)?,
+                "start_time" => message.start_time = field_value(record, field)?,
+                "total_elapsed_time" => message.total_elapsed_time = field_value(record, field)?,
+                "total_timer_time" => message.total_timer_time = field_value(record, field)?,
+                "total_strokes" => message.total_strokes = field_value(record, field)?,
+                "avg_speed" => message.avg_speed = field_value(record, field)?,
+                "swim_stroke" => message.swim_stroke = field_value(record, field)?,
+                "avg_swimming_cadence" => {
+                    message.avg_swimming_cadence = field_value(record, field)?
+                }
+                "event_group" => message.event_group = field_value(record, field)?,
+                "total_calories" => message.total_calories = field_value(record, field)?,
+                "length_type" => message.length_type = field_value(record, field)?,
+                "player_score" => message.player_score = field_value(record, field)?,
+                "opponent_score" => message.opponent_score = field_value(record, field)?,
+                "stroke_count" => message.stroke_count = field_value(record, field)?,
+                "zone_count" => message.zone_count = field_value(record, field)?,
+                "enhanced_avg_respiration_rate" => {
+                    message.enhanced_avg_respiration_rate = field_value(record, field)?
+                }
+                "enhanced_max_respiration_rate" => {
+                    message.enhanced_max_respiration_rate = field_value(record, field)?
+                }
+                "avg_respiration_rate" => {
+                    message.avg_respiration_rate = field_value(record, field)?
+                }
+                "max_respiration_rate" => {
+                    message.max_respiration_rate = field_value(record, field)?
+                }
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `record` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecordMessage {
+    pub position_lat: Option<i32>,
+    pub position_long: Option<i32>,
+    pub altitude: Option<f64>,
+    pub heart_rate: Option<u8>,
+    pub cadence: Option<u8>,
+    pub distance: Option<f64>,
+    pub speed: Option<f64>,
+    pub power: Option<u16>,
+    pub compressed_speed_distance: Option<Vec<u8>>,
+    pub grade: Option<f64>,
+    pub resistance: Option<u8>,
+    pub time_from_course: Option<f64>,
+    pub cycle_length: Option<f64>,
+    pub temperature: Option<i8>,
+    pub speed_1s: Option<Vec<f64>>,
+    pub cycles: Option<u8>,
+    pub total_cycles: Option<u32>,
+    pub compressed_accumulated_power: Option<u16>,
+    pub accumulated_power: Option<u32>,
+    pub left_right_balance: Option<LeftRightBalance>,
+    pub gps_accuracy: Option<u8>,
+    pub vertical_speed: Option<f64>,
+    pub calories: Option<u16>,
+    pub vertical_oscillation: Option<f64>,
+    pub stance_time_percent: Option<f64>,
+    pub stance_time: Option<f64>,
+    pub activity_type: Option<ActivityType>,
+    pub left_torque_effectiveness: Option<f64>,
+    pub right_torque_effectiveness: Option<f64>,
+    pub left_pedal_smoothness: Option<f64>,
+    pub right_pedal_smoothness: Option<f64>,
+    pub combined_pedal_smoothness: Option<f64>,
+    pub time128: Option<f64>,
+    pub stroke_type: Option<StrokeType>,
+    pub zone: Option<u8>,
+    pub ball_speed: Option<f64>,
+    pub cadence256: Option<f64>,
+    pub fractional_cadence: Option<f64>,
+    pub total_hemoglobin_conc: Option<f64>,
+    pub total_hemoglobin_conc_min: Option<f64>,
+    pub total_hemoglobin_conc_max: Option<f64>,
+    pub saturated_hemoglobin_percent: Option<f64>,
+    pub saturated_hemoglobin_percent_min: Option<f64>,
+    pub saturated_hemoglobin_percent_max: Option<f64>,
+    pub device_index: Option<DeviceIndex>,
+    pub left_pco: Option<i8>,
+    pub right_pco: Option<i8>,
+    pub left_power_phase: Option<Vec<f64>>,
+    pub left_power_phase_peak: Option<Vec<f64>>,
+    pub right_power_phase: Option<Vec<f64>>,
+    pub right_power_phase_peak: Option<Vec<f64>>,
+    pub enhanced_speed: Option<f64>,
+    pub enhanced_altitude: Option<f64>,
+    pub battery_soc: Option<f64>,
+    pub motor_power: Option<u16>,
+    pub vertical_ratio: Option<f64>,
+    pub stance_time_balance: Option<f64>,
+    pub step_length: Option<f64>,
+    pub cycle_length16: Option<f64>,
+    pub absolute_pressure: Option<u32>,
+    pub depth: Option<f64>,
+    pub next_stop_depth: Option<f64>,
+    pub next_stop_time: Option<u32>,
+    pub time_to_surface: Option<u32>,
+    pub ndl_time: Option<u32>,
+    pub cns_load: Option<u8>,
+    pub n2_load: Option<u16>,
+    pub respiration_rate: Option<u8>,
+    pub enhanced_respiration_rate: Option<f64>,
+    pub grit: Option<f32>,
+    pub flow: Option<f32>,
+    pub current_stress: Option<f64>,
+    pub ebike_travel_range: Option<u16>,
+    pub ebike_battery_level: Option<u8>,
+    pub ebike_assist_mode: Option<u8>,
+    pub ebike_assist_level_percent: Option<u8>,
+    pub air_time_remaining: Option<u32>,
+    pub pressure_sac: Option<f64>,
+    pub volume_sac: Option<f64>,
+    pub rmv: Option<f64>,
+    pub ascent_rate: Option<f64>,
+    pub po2: Option<f64>,
+    pub core_temperature: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for RecordMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Record)? {
+            match field.name() {
+                "position_lat" => message.position_lat = field_value(record, field)?,
+                "position_long" => message.position_long = field_value(record, field)?,
+                "altitude" => message.altitude = field_value(record, field)?,
+                "heart_rate" => message.heart_rate = field_value(record, field)?,
+                "cadence" => message.cadence = field_value(record, field)?,
+                "distance" => message.distance = field_value(record, field)?,
+                "speed" => message.speed = field_value(record, field)?,
+                "power" => message.power = field_value(record, field)?,
+                "compressed_speed_distance" => {
+                    message.compressed_speed_distance = field_value(record, field)?
+                }
+                "grade" => message.grade = field_value(record, field)?,
+                "resistance" => message.resistance = field_value(record, field)?,
+                "time_from_course" => message.time_from_course = field_value(record, field)?,
+                "cycle_length" => message.cycle_length = field_value(record, field)?,
+                "temperature" => message.temperature = field_value(record, field)?,
+                "speed_1s" => message.speed_1s = field_value(record, field)?,
+                "cycles" => message.cycles = field_value(record, field)?,
+                "total_cycles" => message.total_cycles = field_value(record, field)?,
+                "compressed_accumulated_power" => {
+                    message.compressed_accumulated_power = field_value(record, field)?
+                }
+                "accumulated_power" => message.accumulated_power = field_value(record, field)?,
+                "left_right_balance" => message.left_right_balance = field_value(record, field)?,
+                "gps_accuracy" => message.gps_accuracy = field_value(record, field)?,
+                "vertical_speed" => message.vertical_speed = field_value(record, field)?,
+                "calories" => message.calories = field_value(record, field)?,
+                "vertical_oscillation" => {
+                    message.vertical_oscillation = field_value(record, field)?
+                }
+                "stance_time_percent" => message.stance_time_percent = field_value(record, field)?,
+                "stance_time" => message.stance_time = field_value(record, field)?,
+                "activity_type" => message.activity_type = field_value(record, field)?,
+                "left_torque_effectiveness" => {
+                    message.left_torque_effectiveness = field_value(record, field)?
+                }
+                "right_torque_effectiveness" => {
+                    message.right_torque_effectiveness = field_value(record, field)?
+                }
+                "left_pedal_smoothness" => {
+                    message.left_pedal_smoothness = field_value(record, field)?
+                }
+                "right_pedal_smoothness" => {
+                    message.right_pedal_smoothness = field_value(record, field)?
+                }
+                "combined_pedal_smoothness" => {
+                    message.combined_pedal_smoothness = field_value(record, field)?
+                }
+                "time128" => message.time128 = field_value(record, field)?,
+                "stroke_type" => message.stroke_type = field_value(record, field)?,
+                "zone" => message.zone = field_value(record, field)?,
+                "ball_speed" => message.ball_speed = field_value(record, field)?,
+                "cadence256" => message.cadence256 = field_value(record, field)?,
+                "fractional_cadence" => message.fractional_cadence = field_value(record, field)?,
+                "total_hemoglobin_conc" => {
+                    message.total_hemoglobin_conc = field_value(record, field)?
+                }
+                "total_hemoglobin_conc_min" => {
+                    message.total_hemoglobin_conc_min = field_value(record, field)?
+                }
+                "total_hemoglobin_conc_max" => {
+                    message.total_hemoglobin_conc_max = field_value(record, field)?
+                }
+                "saturated_hemoglobin_percent" => {
+                    message.saturated_hemoglobin_percent = field_value(record, field)?
+                }
+                "saturated_hemoglobin_percent_min" => {
+                    message.saturated_hemoglobin_percent_min = field_value(record, field)?
+                }
+                "saturated_hemoglobin_percent_max" => {
+                    message.saturated_hemoglobin_percent_max = field_value(record, field)?
+                }
+                "device_index" => message.device_index = field_value(record, field)?,
+                "left_pco" => message.left_pco = field_value(record, field)?,
+                "right_pco" => message.right_pco = field_value(record, field)?,
+                "left_power_phase" => message.left_power_phase = field_value(record, field)?,
+                "left_power_phase_peak" => {
+                    message.left_power_phase_peak = field_value(record, field)?
+                }
+                "right_power_phase" => message.right_power_phase = field_value(record, field)?,
+                "right_power_phase_peak" => {
+                    message.right_power_phase_peak = field_value(record, field)?
+                }
+                "enhanced_speed" => message.enhanced_speed = field_value(record, field)?,
+                "enhanced_altitude" => message.enhanced_altitude = field_value(record, field)?,
+                "battery_soc" => message.battery_soc = field_value(record, field)?,
+                "motor_power" => message.motor_power = field_value(record, field)?,
+                "vertical_ratio" => message.vertical_ratio = field_value(record, field)?,
+                "stance_time_balance" => message.stance_time_balance = field_value(record, field)?,
+                "step_length" => message.step_length = field_value(record, field)?,
+                "cycle_length16" => message.cycle_length16 = field_value(record, field)?,
+                "absolute_pressure" => message.absolute_pressure = field_value(record, field)?,
+                "depth" => message.depth = field_value(record, field)?,
+                "next_stop_depth" => message.next_stop_depth = field_value(record, field)?,
+                "next_stop_time" => message.next_stop_time = field_value(record, field)?,
+                "time_to_surface" => message.time_to_surface = field_value(record, field)?,
+                "ndl_time" => message.ndl_time = field_value(record, field)?,
+                "cns_load" => message.cns_load = field_value(record, field)?,
+                "n2_load" => message.n2_load = field_value(record, field)?,
+                "respiration_rate" => message.respiration_rate = field_value(record, field)?,
+                "enhanced_respiration_rate" => {
+                    message.enhanced_respiration_rate = field_value(record, field)?
+                }
+                "grit" => message.grit = field_value(record, field)?,
+                "flow" => message.flow = field_value(record, field)?,
+                "current_stress" => message.current_stress = field_value(record, field)?,
+                "ebike_travel_range" => message.ebike_travel_range = field_value(record, field)?,
+                "ebike_battery_level" => message.ebike_battery_level = field_value(record, field)?,
+                "ebike_assist_mode" => message.ebike_assist_mode = field_value(record, field)?,
+                "ebike_assist_level_percent" => {
+                    message.ebike_assist_level_percent = field_value(record, field)?
+                }
+                "air_time_remaining" => message.air_time_remaining = field_value(record, field)?,
+                "pressure_sac" => message.pressure_sac = field_value(record, field)?,
+                "volume_sac" => message.volume_sac = field_value(record, field)?,
+                "rmv" => message.rmv = field_value(record, field)?,
+                "ascent_rate" => message.ascent_rate = field_value(record, field)?,
+                "po2" => message.po2 = field_value(record, field)?,
+                "core_temperature" => message.core_temperature = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `event` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EventMessage {
+    pub event: Option<Event>,
+    pub event_type: Option<EventType>,
+    pub data16: Option<u16>,
+    pub timer_trigger: Option<TimerTrigger>,
+    pub course_point_index: Option<MessageIndex>,
+    pub battery_level: Option<f64>,
+    pub virtual_partner_speed: Option<f64>,
+    pub hr_high_alert: Option<u8>,
+    pub hr_low_alert: Option<u8>,
+    pub speed_high_alert: Option<f64>,
+    pub speed_low_alert: Option<f64>,
+    pub cad_high_alert: Option<u16>,
+    pub cad_low_alert: Option<u16>,
+    pub power_high_alert: Option<u16>,
+    pub power_low_alert: Option<u16>,
+    pub time_duration_alert: Option<f64>,
+    pub distance_duration_alert: Option<f64>,
+    pub calorie_duration_alert: Option<u32>,
+    pub fitness_equipment_state: Option<FitnessEquipmentState>,
+    pub sport_point: Option<u32>,
+    pub gear_change_data: Option<u32>,
+    pub rider_position: Option<RiderPositionType>,
+    pub comm_timeout: Option<CommTimeoutType>,
+    pub dive_alert: Option<DiveAlert>,
+    pub auto_activity_detect_duration: Option<u16>,
+    pub radar_threat_alert: Option<u32>,
+    pub data: Option<u32>,
+    pub event_group: Option<u8>,
+    pub score: Option<u16>,
+    pub opponent_score: Option<u16>,
+    pub front_gear_num: Option<u8>,
+    pub front_gear: Option<u8>,
+    pub rear_gear_num: Option<u8>,
+    pub rear_gear: Option<u8>,
+    pub device_index: Option<DeviceIndex>,
+    pub activity_type: Option<ActivityType>,
+    pub auto_activity_detect_start_timestamp: Option<crate::timestamp::Timestamp>,
+    pub start_timestamp: Option<crate::timestamp::Timestamp>,
+    pub radar_threat_level_max: Option<RadarThreatLevelType>,
+    pub radar_threat_count: Option<u8>,
+    pub radar_threat_avg_approach_speed: Option<f64>,
+    pub radar_threat_max_approach_speed: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for EventMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Event)? {
+            match field.name() {
+                "event" => message.event = field_value(record, field)?,
+                "event_type" => message.event_type = field_value(record, field)?,
+                "data16" => message.data16 = field_value(record, field)?,
+                "timer_trigger" => message.timer_trigger = field_value(record, field)?,
+                "course_point_index" => message.course_point_index = field_value(record, field)?,
+                "battery_level" => message.battery_level = field_value(record, field)?,
+                "virtual_partner_speed" => {
+                    message.virtual_partner_speed = field_value(record, field)?
+                }
+                "hr_high_alert" => message.hr_high_alert = field_value(record, field)?,
+                "hr_low_alert" => message.hr_low_alert = field_value(record, field)?,
+                "speed_high_alert" => message.speed_high_alert = field_value(record, field)?,
+                "speed_low_alert" => message.speed_low_alert = field_value(record, field)?,
+                "cad_high_alert" => message.cad_high_alert = field_value(record, field)?,
+                "cad_low_alert" => message.cad_low_alert = field_value(record, field)?,
+                "power_high_alert" => message.power_high_alert = field_value(record, field)?,
+                "power_low_alert" => message.power_low_alert = field_value(record, field)?,
+                "time_duration_alert" => message.time_duration_alert = field_value(record, field)?,
+                "distance_duration_alert" => {
+                    message.distance_duration_alert = field_value(record, field)?
+                }
+                "calorie_duration_alert" => {
+                    message.calorie_duration_alert = field_value(record, field)?
+                }
+                "fitness_equipment_state" => {
+                    message.fitness_equipment_state = field_value(record, field)?
+                }
+                "sport_point" => message.sport_point = field_value(record, field)?,
+                "gear_change_data" => message.gear_change_data = field_value(record, field)?,
+                "rider_position" => message.rider_position = field_value(record, field)?,
+                "comm_timeout" => message.comm_timeout = field_value(record, field)?,
+                "dive_alert" => message.dive_alert = field_value(record, field)?,
+                "auto_activity_detect_duration" => {
+                    message.auto_activity_detect_duration = field_value(record, field)?
+                }
+                "radar_threat_alert" => message.radar_threat_alert = field_value(record, field)?,
+                "data" => message.data = field_value(record, field)?,
+                "event_group" => message.event_group = field_value(record, field)?,
+                "score" => message.score = field_value(record, field)?,
+                "opponent_score" => message.opponent_score = field_value(record, field)?,
+                "front_gear_num" => message.front_gear_num = field_value(record, field)?,
+                "front_gear" => message.front_gear = field_value(record, field)?,
+                "rear_gear_num" => message.rear_gear_num = field_value(record, field)?,
+                "rear_gear" => message.rear_gear = field_value(record, field)?,
+                "device_index" => message.device_index = field_value(record, field)?,
+                "activity_type" => message.activity_type = field_value(record, field)?,
+                "auto_activity_detect_start_timestamp" => {
+                    message.auto_activity_detect_start_timestamp = field_value(record, field)?
+                }
+                "start_timestamp" => message.start_timestamp = field_value(record, field)?,
+                "radar_threat_level_max" => {
+                    message.radar_threat_level_max = field_value(record, field)?
+                }
+                "radar_threat_count" => message.radar_threat_count = field_value(record, field)?,
+                "radar_threat_avg_approach_speed" => {
+                    message.radar_threat_avg_approach_speed = field_value(record, field)?
+                }
+                "radar_threat_max_approach_speed" => {
+                    message.radar_threat_max_approach_speed = field_value(record, field)?
+                }
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `device_info` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeviceInfoMessage {
+    pub device_index: Option<DeviceIndex>,
+    pub ble_device_type: Option<BleDeviceType>,
+    pub antplus_device_type: Option<AntplusDeviceType>,
+    pub ant_device_type: Option<u8>,
+    pub local_device_type: Option<LocalDeviceType>,
+    pub device_type: Option<u8>,
+    pub manufacturer: Option<Manufacturer>,
+    pub serial_number: Option<u32>,
+    pub favero_product: Option<FaveroProduct>,
+    pub garmin_product: Option<GarminProduct>,
+    pub product: Option<u16>,
+    pub software_version: Option<f64>,
+    pub hardware_version: Option<u8>,
+    pub cum_operating_time: Option<u32>,
+    pub battery_voltage: Option<f64>,
+    pub battery_status: Option<BatteryStatus>,
+    pub sensor_position: Option<BodyLocation>,
+    pub descriptor: Option<String>,
+    pub ant_transmission_type: Option<u8>,
+    pub ant_device_number: Option<u16>,
+    pub ant_network: Option<AntNetwork>,
+    pub source_type: Option<SourceType>,
+    pub product_name: Option<String>,
+    pub battery_level: Option<u8>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for DeviceInfoMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::DeviceInfo)? {
+            match field.name() {
+                "device_index" => message.device_index = field_value(record, field)?,
+                "ble_device_type" => message.ble_device_type = field_value(record, field)?,
+                "antplus_device_type" => message.antplus_device_type = field_value(record, field)?,
+                "ant_device_type" => message.ant_device_type = field_value(record, field)?,
+                "local_device_type" => message.local_device_type = field_value(record, field)?,
+                "device_type" => message.device_type = field_value(record, field)?,
+                "manufacturer" => message.manufacturer = field_value(record, field)?,
+                "serial_number" => message.serial_number = field_value(record, field)?,
+                "favero_product" => message.favero_product = field_value(record, field)?,
+                "garmin_product" => message.garmin_product = field_value(record, field)?,
+                "product" => message.product = field_value(record, field)?,
+                "software_version" => message.software_version = field_value(record, field)?,
+                "hardware_version" => message.hardware_version = field_value(record, field)?,
+                "cum_operating_time" => message.cum_operating_time = field_value(record, field)?,
+                "battery_voltage" => message.battery_voltage = field_value(record, field)?,
+                "battery_status" => message.battery_status = field_value(record, field)?,
+                "sensor_position" => message.sensor_position = field_value(record, field)?,
+                "descriptor" => message.descriptor = field_value(record, field)?,
+                "ant_transmission_type" => {
+                    message.ant_transmission_type = field_value(record, field)?
+                }
+                "ant_device_number" => message.ant_device_number = field_value(record, field)?,
+                "ant_network" => message.ant_network = field_value(record, field)?,
+                "source_type" => message.source_type = field_value(record, field)?,
+                "product_name" => message.product_name = field_value(record, field)?,
+                "battery_level" => message.battery_level = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `device_aux_battery_info` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeviceAuxBatteryInfoMessage {
+    pub device_index: Option<DeviceIndex>,
+    pub battery_voltage: Option<f64>,
+    pub battery_status: Option<BatteryStatus>,
+    pub battery_identifier: Option<u8>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for DeviceAuxBatteryInfoMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::DeviceAuxBatteryInfo)? {
+            match field.name() {
+                "device_index" => message.device_index = field_value(record, field)?,
+                "battery_voltage" => message.battery_voltage = field_value(record, field)?,
+                "battery_status" => message.battery_status = field_value(record, field)?,
+                "battery_identifier" => message.battery_identifier = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `training_file` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrainingFileMessage {
+    pub r#type: Option<File>,
+    pub manufacturer: Option<Manufacturer>,
+    pub favero_product: Option<FaveroProduct>,
+    pub garmin_product: Option<GarminProduct>,
+    pub product: Option<u16>,
+    pub serial_number: Option<u32>,
+    pub time_created: Option<crate::timestamp::Timestamp>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for TrainingFileMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::TrainingFile)? {
+            match field.name() {
+                "type" => message.r#type = field_value(record, field)?,
+                "manufacturer" => message.manufacturer = field_value(record, field)?,
+                "favero_product" => message.favero_product = field_value(record, field)?,
+                "garmin_product" => message.garmin_product = field_value(record, field)?,
+                "product" => message.product = field_value(record, field)?,
+                "serial_number" => message.serial_number = field_value(record, field)?,
+                "time_created" => message.time_created = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `weather_conditions` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeatherConditionsMessage {
+    pub weather_report: Option<WeatherReport>,
+    pub temperature: Option<i8>,
+    pub condition: Option<WeatherStatus>,
+    pub wind_direction: Option<u16>,
+    pub wind_speed: Option<f64>,
+    pub precipitation_probability: Option<u8>,
+    pub temperature_feels_like: Option<i8>,
+    pub relative_humidity: Option<u8>,
+    pub location: Option<String>,
+    pub observed_at_time: Option<crate::timestamp::Timestamp>,
+    pub observed_location_lat: Option<i32>,
+    pub observed_location_long: Option<i32>,
+    pub day_of_week: Option<DayOfWeek>,
+    pub high_temperature: Option<i8>,
+    pub low_temperature: Option<i8>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for WeatherConditionsMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::WeatherConditions)? {
+            match field.name() {
+                "weather_report" => message.weather_report = field_value(record, field)?,
+                "temperature" => message.temperature = field_value(record, field)?,
+                "condition" => message.condition = field_value(record, field)?,
+                "wind_direction" => message.wind_direction = field_value(record, field)?,
+                "wind_speed" => message.wind_speed = field_value(record, field)?,
+                "precipitation_probability" => {
+                    message.precipitation_probability = field_value(record, field)?
+                }
+                "temperature_feels_like" => {
+                    message.temperature_feels_like = field_value(record, field)?
+                }
+                "relative_humidity" => message.relative_humidity = field_value(record, field)?,
+                "location" => message.location = field_value(record, field)?,
+                "observed_at_time" => message.observed_at_time = field_value(record, field)?,
+                "observed_location_lat" => {
+                    message.observed_location_lat = field_value(record, field)?
+                }
+                "observed_location_long" => {
+                    message.observed_location_long = field_value(record, field)?
+                }
+                "day_of_week" => message.day_of_week = field_value(record, field)?,
+                "high_temperature" => message.high_temperature = field_value(record, field)?,
+                "low_temperature" => message.low_temperature = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `weather_alert` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeatherAlertMessage {
+    pub report_id: Option<String>,
+    pub issue_time: Option<crate::timestamp::Timestamp>,
+    pub expire_time: Option<crate::timestamp::Timestamp>,
+    pub severity: Option<WeatherSeverity>,
+    pub r#type: Option<WeatherSevereType>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for WeatherAlertMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::WeatherAlert)? {
+            match field.name() {
+                "report_id" => message.report_id = field_value(record, field)?,
+                "issue_time" => message.issue_time = field_value(record, field)?,
+                "expire_time" => message.expire_time = field_value(record, field)?,
+                "severity" => message.severity = field_value(record, field)?,
+                "type" => message.r#type = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `gps_metadata` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GpsMetadataMessage {
+    pub timestamp_ms: Option<u16>,
+    pub position_lat: Option<i32>,
+    pub position_long: Option<i32>,
+    pub enhanced_altitude: Option<f64>,
+    pub enhanced_speed: Option<f64>,
+    pub heading: Option<f64>,
+    pub utc_timestamp: Option<crate::timestamp::Timestamp>,
+    pub velocity: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for GpsMetadataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::GpsMetadata)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "position_lat" => message.position_lat = field_value(record, field)?,
+                "position_long" => message.position_long = field_value(record, field)?,
+                "enhanced_altitude" => message.enhanced_altitude = field_value(record, field)?,
+                "enhanced_speed" => message.enhanced_speed = field_value(record, field)?,
+                "heading" => message.heading = field_value(record, field)?,
+                "utc_timestamp" => message.utc_timestamp = field_value(record, field)?,
+                "velocity" => message.velocity = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `camera_event` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CameraEventMessage {
+    pub timestamp_ms: Option<u16>,
+    pub camera_event_type: Option<CameraEventType>,
+    pub camera_file_uuid: Option<String>,
+    pub camera_orientation: Option<CameraOrientationType>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for CameraEventMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::CameraEvent)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "camera_event_type" => message.camera_event_type = field_value(record, field)?,
+                "camera_file_uuid" => message.camera_file_uuid = field_value(record, field)?,
+                "camera_orientation" => message.camera_orientation = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `gyroscope_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GyroscopeDataMessage {
+    pub timestamp_ms: Option<u16>,
+    pub sample_time_offset: Option<Vec<u16>>,
+    pub gyro_x: Option<Vec<u16>>,
+    pub gyro_y: Option<Vec<u16>>,
+    pub gyro_z: Option<Vec<u16>>,
+    pub calibrated_gyro_x: Option<Vec<f32>>,
+    pub calibrated_gyro_y: Option<Vec<f32>>,
+    pub calibrated_gyro_z: Option<Vec<f32>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for GyroscopeDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::GyroscopeData)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "sample_time_offset" => message.sample_time_offset = field_value(record, field)?,
+                "gyro_x" => message.gyro_x = field_value(record, field)?,
+                "gyro_y" => message.gyro_y = field_value(record, field)?,
+                "gyro_z" => message.gyro_z = field_value(record, field)?,
+                "calibrated_gyro_x" => message.calibrated_gyro_x = field_value(record, field)?,
+                "calibrated_gyro_y" => message.calibrated_gyro_y = field_value(record, field)?,
+                "calibrated_gyro_z" => message.calibrated_gyro_z = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `accelerometer_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AccelerometerDataMessage {
+    pub timestamp_ms: Option<u16>,
+    pub sample_time_offset: Option<Vec<u16>>,
+    pub accel_x: Option<Vec<u16>>,
+    pub accel_y: Option<Vec<u16>>,
+    pub accel_z: Option<Vec<u16>>,
+    pub calibrated_accel_x: Option<Vec<f32>>,
+    pub calibrated_accel_y: Option<Vec<f32>>,
+    pub calibrated_accel_z: Option<Vec<f32>>,
+    pub compressed_calibrated_accel_x: Option<Vec<i16>>,
+    pub compressed_calibrated_accel_y: Option<Vec<i16>>,
+    pub compressed_calibrated_accel_z: Option<Vec<i16>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for AccelerometerDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::AccelerometerData)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "sample_time_offset" => message.sample_time_offset = field_value(record, field)?,
+                "accel_x" => message.accel_x = field_value(record, field)?,
+                "accel_y" => message.accel_y = field_value(record, field)?,
+                "accel_z" => message.accel_z = field_value(record, field)?,
+                "calibrated_accel_x" => message.calibrated_accel_x = field_value(record, field)?,
+                "calibrated_accel_y" => message.calibrated_accel_y = field_value(record, field)?,
+                "calibrated_accel_z" => message.calibrated_accel_z = field_value(record, field)?,
+                "compressed_calibrated_accel_x" => {
+                    message.compressed_calibrated_accel_x = field_value(record, field)?
+                }
+                "compressed_calibrated_accel_y" => {
+                    message.compressed_calibrated_accel_y = field_value(record, field)?
+                }
+                "compressed_calibrated_accel_z" => {
+                    message.compressed_calibrated_accel_z = field_value(record, field)?
+                }
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `magnetometer_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MagnetometerDataMessage {
+    pub timestamp_ms: Option<u16>,
+    pub sample_time_offset: Option<Vec<u16>>,
+    pub mag_x: Option<Vec<u16>>,
+    pub mag_y: Option<Vec<u16>>,
+    pub mag_z: Option<Vec<u16>>,
+    pub calibrated_mag_x: Option<Vec<f32>>,
+    pub calibrated_mag_y: Option<Vec<f32>>,
+    pub calibrated_mag_z: Option<Vec<f32>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for MagnetometerDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::MagnetometerData)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "sample_time_offset" => message.sample_time_offset = field_value(record, field)?,
+                "mag_x" => message.mag_x = field_value(record, field)?,
+                "mag_y" => message.mag_y = field_value(record, field)?,
+                "mag_z" => message.mag_z = field_value(record, field)?,
+                "calibrated_mag_x" => message.calibrated_mag_x = field_value(record, field)?,
+                "calibrated_mag_y" => message.calibrated_mag_y = field_value(record, field)?,
+                "calibrated_mag_z" => message.calibrated_mag_z = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `barometer_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BarometerDataMessage {
+    pub timestamp_ms: Option<u16>,
+    pub sample_time_offset: Option<Vec<u16>>,
+    pub baro_pres: Option<Vec<u32>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for BarometerDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::BarometerData)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "sample_time_offset" => message.sample_time_offset = field_value(record, field)?,
+                "baro_pres" => message.baro_pres = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `three_d_sensor_calibration` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThreeDSensorCalibrationMessage {
+    pub sensor_type: Option<SensorType>,
+    pub accel_cal_factor: Option<u32>,
+    pub gyro_cal_factor: Option<u32>,
+    pub calibration_factor: Option<u32>,
+    pub calibration_divisor: Option<u32>,
+    pub level_shift: Option<u32>,
+    pub offset_cal: Option<Vec<i32>>,
+    pub orientation_matrix: Option<Vec<f64>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for ThreeDSensorCalibrationMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::ThreeDSensorCalibration)? {
+            match field.name() {
+                "sensor_type" => message.sensor_type = field_value(record, field)?,
+                "accel_cal_factor" => message.accel_cal_factor = field_value(record, field)?,
+                "gyro_cal_factor" => message.gyro_cal_factor = field_value(record, field)?,
+                "calibration_factor" => message.calibration_factor = field_value(record, field)?,
+                "calibration_divisor" => message.calibration_divisor = field_value(record, field)?,
+                "level_shift" => message.level_shift = field_value(record, field)?,
+                "offset_cal" => message.offset_cal = field_value(record, field)?,
+                "orientation_matrix" => message.orientation_matrix = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `one_d_sensor_calibration` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OneDSensorCalibrationMessage {
+    pub sensor_type: Option<SensorType>,
+    pub baro_cal_factor: Option<u32>,
+    pub calibration_factor: Option<u32>,
+    pub calibration_divisor: Option<u32>,
+    pub level_shift: Option<u32>,
+    pub offset_cal: Option<i32>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for OneDSensorCalibrationMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::OneDSensorCalibration)? {
+            match field.name() {
+                "sensor_type" => message.sensor_type = field_value(record, field)?,
+                "baro_cal_factor" => message.baro_cal_factor = field_value(record, field)?,
+                "calibration_factor" => message.calibration_factor = field_value(record, field)?,
+                "calibration_divisor" => message.calibration_divisor = field_value(record, field)?,
+                "level_shift" => message.level_shift = field_value(record, field)?,
+                "offset_cal" => message.offset_cal = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `video_frame` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct VideoFrameMessage {
+    pub timestamp_ms: Option<u16>,
+    pub frame_number: Option<u32>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for VideoFrameMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::VideoFrame)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "frame_number" => message.frame_number = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `obdii_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ObdiiDataMessage {
+    pub timestamp_ms: Option<u16>,
+    pub time_offset: Option<Vec<u16>>,
+    pub pid: Option<Vec<u8>>,
+    pub raw_data: Option<Vec<u8>>,
+    pub pid_data_size: Option<Vec<u8>>,
+    pub system_time: Option<Vec<u32>>,
+    pub start_timestamp: Option<crate::timestamp::Timestamp>,
+    pub start_timestamp_ms: Option<u16>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for ObdiiDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::ObdiiData)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "time_offset" => message.time_offset = field_value(record, field)?,
+                "pid" => message.pid = field_value(record, field)?,
+                "raw_data" => message.raw_data = field_value(record, field)?,
+                "pid_data_size" => message.pid_data_size = field_value(record, field)?,
+                "system_time" => message.system_time = field_value(record, field)?,
+                "start_timestamp" => message.start_timestamp = field_value(record, field)?,
+                "start_timestamp_ms" => message.start_timestamp_ms = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `nmea_sentence` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct NmeaSentenceMessage {
+    pub timestamp_ms: Option<u16>,
+    pub sentence: Option<String>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for NmeaSentenceMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::NmeaSentence)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "sentence" => message.sentence = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `aviation_attitude` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AviationAttitudeMessage {
+    pub timestamp_ms: Option<u16>,
+    pub system_time: Option<Vec<u32>>,
+    pub pitch: Option<Vec<f64>>,
+    pub roll: Option<Vec<f64>>,
+    pub accel_lateral: Option<Vec<f64>>,
+    pub accel_normal: Option<Vec<f64>>,
+    pub turn_rate: Option<Vec<f64>>,
+    pub stage: Option<Vec<AttitudeStage>>,
+    pub attitude_stage_complete: Option<Vec<u8>>,
+    pub track: Option<Vec<f64>>,
+    pub validity: Option<Vec<AttitudeValidity>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for AviationAttitudeMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::AviationAttitude)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "system_time" => message.system_time = field_value(record, field)?,
+                "pitch" => message.pitch = field_value(record, field)?,
+                "roll" => message.roll = field_value(record, field)?,
+                "accel_lateral" => message.accel_lateral = field_value(record, field)?,
+                "accel_normal" => message.accel_normal = field_value(record, field)?,
+                "turn_rate" => message.turn_rate = field_value(record, field)?,
+                "stage" => message.stage = field_value(record, field)?,
+                "attitude_stage_complete" => {
+                    message.attitude_stage_complete = field_value(record, field)?
+                }
+                "track" => message.track = field_value(record, field)?,
+                "validity" => message.validity = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `video` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct VideoMessage {
+    pub url: Option<String>,
+    pub hosting_provider: Option<String>,
+    pub duration: Option<u32>,
+}
+impl TryFrom<&FitDataRecord> for VideoMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Video)? {
+            match field.name() {
+                "url" => message.url = field_value(record, field)?,
+                "hosting_provider" => message.hosting_provider = field_value(record, field)?,
+                "duration" => message.duration = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `video_title` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct VideoTitleMessage {
+    pub message_count: Option<u16>,
+    pub text: Option<String>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for VideoTitleMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::VideoTitle)? {
+            match field.name() {
+                "message_count" => message.message_count = field_value(record, field)?,
+                "text" => message.text = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `video_description` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct VideoDescriptionMessage {
+    pub message_count: Option<u16>,
+    pub text: Option<String>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for VideoDescriptionMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::VideoDescription)? {
+            match field.name() {
+                "message_count" => message.message_count = field_value(record, field)?,
+                "text" => message.text = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `video_clip` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct VideoClipMessage {
+    pub clip_number: Option<u16>,
+    pub start_timestamp: Option<crate::timestamp::Timestamp>,
+    pub start_timestamp_ms: Option<u16>,
+    pub end_timestamp: Option<crate::timestamp::Timestamp>,
+    pub end_timestamp_ms: Option<u16>,
+    pub clip_start: Option<u32>,
+    pub clip_end: Option<u32>,
+}
+impl TryFrom<&FitDataRecord> for VideoClipMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::VideoClip)? {
+            match field.name() {
+                "clip_number" => message.clip_number = field_value(record, field)?,
+                "start_timestamp" => message.start_timestamp = field_value(record, field)?,
+                "start_timestamp_ms" => message.start_timestamp_ms = field_value(record, field)?,
+                "end_timestamp" => message.end_timestamp = field_value(record, field)?,
+                "end_timestamp_ms" => message.end_timestamp_ms = field_value(record, field)?,
+                "clip_start" => message.clip_start = field_value(record, field)?,
+                "clip_end" => message.clip_end = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `set` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SetMessage {
+    pub duration: Option<f64>,
+    pub repetitions: Option<u16>,
+    pub weight: Option<f64>,
+    pub set_type: Option<SetType>,
+    pub start_time: Option<crate::timestamp::Timestamp>,
+    pub category: Option<ExerciseCategory>,
+    pub category_subtype: Option<u16>,
+    pub weight_display_unit: Option<FitBaseUnit>,
+    pub message_index: Option<MessageIndex>,
+    pub wkt_step_index: Option<MessageIndex>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for SetMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Set)? {
+            match field.name() {
+                "duration" => message.duration = field_value(record, field)?,
+                "repetitions" => message.repetitions = field_value(record, field)?,
+                "weight" => message.weight = field_value(record, field)?,
+                "set_type" => message.set_type = field_value(record, field)?,
+                "start_time" => message.start_time = field_value(record, field)?,
+                "category" => message.category = field_value(record, field)?,
+                "category_subtype" => message.category_subtype = field_value(record, field)?,
+                "weight_display_unit" => message.weight_display_unit = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                "wkt_step_index" => message.wkt_step_index = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `jump` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct JumpMessage {
+    pub distance: Option<f32>,
+    pub height: Option<f32>,
+    pub rotations: Option<u8>,
+    pub hang_time: Option<f32>,
+    pub score: Option<f32>,
+    pub position_lat: Option<i32>,
+    pub position_long: Option<i32>,
+    pub speed: Option<f64>,
+    pub enhanced_speed: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for JumpMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Jump)? {
+            match field.name() {
+                "distance" => message.distance = field_value(record, field)?,
+                "height" => message.height = field_value(record, field)?,
+                "rotations" => message.rotations = field_value(record, field)?,
+                "hang_time" => message.hang_time = field_value(record, field)?,
+                "score" => message.score = field_value(record, field)?,
+                "position_lat" => message.position_lat = field_value(record, field)?,
+                "position_long" => message.position_long = field_value(record, field)?,
+                "speed" => message.speed = field_value(record, field)?,
+                "enhanced_speed" => message.enhanced_speed = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `split` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SplitMessage {
+    pub split_type: Option<SplitType>,
+    pub total_elapsed_time: Option<f64>,
+    pub total_timer_time: Option<f64>,
+    pub total_distance: Option<f64>,
+    pub avg_speed: Option<f64>,
+    pub start_time: Option<crate::timestamp::Timestamp>,
+    pub total_ascent: Option<u16>,
+    pub total_descent: Option<u16>,
+    pub start_position_lat: Option<i32>,
+    pub start_position_long: Option<i32>,
+    pub end_position_lat: Option<i32>,
+    pub end_position_long: Option<i32>,
+    pub max_speed: Option<f64>,
+    pub avg_vert_speed: Option<f64>,
+    pub end_time: Option<crate::timestamp::Timestamp>,
+    pub total_calories: Option<u32>,
+    pub start_elevation: Option<f64>,
+    pub total_moving_time: Option<f64>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SplitMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Split)? {
+            match field.name() {
+                "split_type" => message.split_type = field_value(record, field)?,
+                "total_elapsed_time" => message.total_elapsed_time = field_value(record, field)?,
+                "total_timer_time" => message.total_timer_time = field_value(record, field)?,
+                "total_distance" => message.total_distance = field_value(record, field)?,
+                "avg_speed" => message.avg_speed = field_value(record, field)?,
+                "start_time" => message.start_time = field_value(record, field)?,
+                "total_ascent" => message.total_ascent = field_value(record, field)?,
+                "total_descent" => message.total_descent = field_value(record, field)?,
+                "start_position_lat" => message.start_position_lat = field_value(record, field)?,
+                "start_position_long" => message.start_position_long = field_value(record, field)?,
+                "end_position_lat" => message.end_position_lat = field_value(record, field)?,
+                "end_position_long" => message.end_position_long = field_value(record, field)?,
+                "max_speed" => message.max_speed = field_value(record, field)?,
+                "avg_vert_speed" => message.avg_vert_speed = field_value(record, field)?,
+                "end_time" => message.end_time = field_value(record, field)?,
+                "total_calories" => message.total_calories = field_value(record, field)?,
+                "start_elevation" => message.start_elevation = field_value(record, field)?,
+                "total_moving_time" => message.total_moving_time = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `split_summary` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SplitSummaryMessage {
+    pub split_type: Option<SplitType>,
+    pub num_splits: Option<u16>,
+    pub total_timer_time: Option<f64>,
+    pub total_distance: Option<f64>,
+    pub avg_speed: Option<f64>,
+    pub max_speed: Option<f64>,
+    pub total_ascent: Option<u16>,
+    pub total_descent: Option<u16>,
+    pub avg_heart_rate: Option<u8>,
+    pub max_heart_rate: Option<u8>,
+    pub avg_vert_speed: Option<f64>,
+    pub total_calories: Option<u32>,
+    pub total_moving_time: Option<f64>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SplitSummaryMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::SplitSummary)? {
+            match field.name() {
+                "split_type" => message.split_type = field_value(record, field)?,
+                "num_splits" => message.num_splits = field_value(record, field)?,
+                "total_timer_time" => message.total_timer_time = field_value(record, field)?,
+                "total_distance" => message.total_distance = field_value(record, field)?,
+                "avg_speed" => message.avg_speed = field_value(record, field)?,
+                "max_speed" => message.max_speed = field_value(record, field)?,
+                "total_ascent" => message.total_ascent = field_value(record, field)?,
+                "total_descent" => message.total_descent = field_value(record, field)?,
+                "avg_heart_rate" => message.avg_heart_rate = field_value(record, field)?,
+                "max_heart_rate" => message.max_heart_rate = field_value(record, field)?,
+                "avg_vert_speed" => message.avg_vert_speed = field_value(record, field)?,
+                "total_calories" => message.total_calories = field_value(record, field)?,
+                "total_moving_time" => message.total_moving_time = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `climb_pro` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClimbProMessage {
+    pub position_lat: Option<i32>,
+    pub position_long: Option<i32>,
+    pub climb_pro_event: Option<ClimbProEvent>,
+    pub climb_number: Option<u16>,
+    pub climb_category: Option<u8>,
+    pub current_dist: Option<f32>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for ClimbProMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::ClimbPro)? {
+            match field.name() {
+                "position_lat" => message.position_lat = field_value(record, field)?,
+                "position_long" => message.position_long = field_value(record, field)?,
+                "climb_pro_event" => message.climb_pro_event = field_value(record, field)?,
+                "climb_number" => message.climb_number = field_value(record, field)?,
+                "climb_category" => message.climb_category = field_value(record, field)?,
+                "current_dist" => message.current_dist = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `field_description` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FieldDescriptionMessage {
+    pub developer_data_index: Option<u8>,
+    pub field_definition_number: Option<u8>,
+    pub fit_base_type_id: Option<FitBaseType>,
+    pub field_name: Option<String>,
+    pub array: Option<u8>,
+    pub components: Option<String>,
+    pub scale: Option<u8>,
+    pub offset: Option<i8>,
+    pub units: Option<String>,
+    pub bits: Option<String>,
+    pub accumulate: Option<String>,
+    pub fit_base_unit_id: Option<FitBaseUnit>,
+    pub native_mesg_num: Option<MesgNum>,
+    pub native_field_num: Option<u8>,
+}
+impl TryFrom<&FitDataRecord> for FieldDescriptionMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::FieldDescription)? {
+            match field.name() {
+                "developer_data_index" => {
+                    message.developer_data_index = field_value(record, field)?
+                }
+                "field_definition_number" => {
+                    message.field_definition_number = field_value(record, field)?
+                }
+                "fit_base_type_id" => message.fit_base_type_id = field_value(record, field)?,
+                "field_name" => message.field_name = field_value(record, field)?,
+                "array" => message.array = field_value(record, field)?,
+                "components" => message.components = field_value(record, field)?,
+                "scale" => message.scale = field_value(record, field)?,
+                "offset" => message.offset = field_value(record, field)?,
+                "units" => message.units = field_value(record, field)?,
+                "bits" => message.bits = field_value(record, field)?,
+                "accumulate" => message.accumulate = field_value(record, field)?,
+                "fit_base_unit_id" => message.fit_base_unit_id = field_value(record, field)?,
+                "native_mesg_num" => message.native_mesg_num = field_value(record, field)?,
+                "native_field_num" => message.native_field_num = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `developer_data_id` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeveloperDataIdMessage {
+    pub developer_id: Option<Vec<u8>>,
+    pub application_id: Option<Vec<u8>>,
+    pub manufacturer_id: Option<Manufacturer>,
+    pub developer_data_index: Option<u8>,
+    pub application_version: Option<u32>,
+}
+impl TryFrom<&FitDataRecord> for DeveloperDataIdMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::DeveloperDataId)? {
+            match field.name() {
+                "developer_id" => message.developer_id = field_value(record, field)?,
+                "application_id" => message.application_id = field_value(record, field)?,
+                "manufacturer_id" => message.manufacturer_id = field_value(record, field)?,
+                "developer_data_index" => {
+                    message.developer_data_index = field_value(record, field)?
+                }
+                "application_version" => message.application_version = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `course` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CourseMessage {
+    pub sport: Option<Sport>,
+    pub name: Option<String>,
+    pub capabilities: Option<CourseCapabilities>,
+    pub sub_sport: Option<SubSport>,
+}
+impl TryFrom<&FitDataRecord> for CourseMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Course)? {
+            match field.name() {
+                "sport" => message.sport = field_value(record, field)?,
+                "name" => message.name = field_value(record, field)?,
+                "capabilities" => message.capabilities = field_value(record, field)?,
+                "sub_sport" => message.sub_sport = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `course_point` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CoursePointMessage {
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+    pub position_lat: Option<i32>,
+    pub position_long: Option<i32>,
+    pub distance: Option<f64>,
+    pub r#type: Option<CoursePoint>,
+    pub name: Option<String>,
+    pub favorite: Option<bool>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for CoursePointMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::CoursePoint)? {
+            match field.name() {
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                "position_lat" => message.position_lat = field_value(record, field)?,
+                "position_long" => message.position_long = field_value(record, field)?,
+                "distance" => message.distance = field_value(record, field)?,
+                "type" => message.r#type = field_value(record, field)?,
+                "name" => message.name = field_value(record, field)?,
+                "favorite" => message.favorite = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `segment_id` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SegmentIdMessage {
+    pub name: Option<String>,
+    pub uuid: Option<String>,
+    pub sport: Option<Sport>,
+    pub enabled: Option<bool>,
+    pub user_profile_primary_key: Option<u32>,
+    pub device_id: Option<u32>,
+    pub default_race_leader: Option<u8>,
+    pub delete_status: Option<SegmentDeleteStatus>,
+    pub selection_type: Option<SegmentSelectionType>,
+}
+impl TryFrom<&FitDataRecord> for SegmentIdMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::SegmentId)? {
+            match field.name() {
+                "name" => message.name = field_value(record, field)?,
+                "uuid" => message.uuid = field_value(record, field)?,
+                "sport" => message.sport = field_value(record, field)?,
+                "enabled" => message.enabled = field_value(record, field)?,
+                "user_profile_primary_key" => {
+                    message.user_profile_primary_key = field_value(record, field)?
+                }
+                "device_id" => message.device_id = field_value(record, field)?,
+                "default_race_leader" => message.default_race_leader = field_value(record, field)?,
+                "delete_status" => message.delete_status = field_value(record, field)?,
+                "selection_type" => message.selection_type = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `segment_leaderboard_entry` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SegmentLeaderboardEntryMessage {
+    pub name: Option<String>,
+    pub r#type: Option<SegmentLeaderboardType>,
+    pub group_primary_key: Option<u32>,
+    pub activity_id: Option<u32>,
+    pub segment_time: Option<f64>,
+    pub activity_id_string: Option<String>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SegmentLeaderboardEntryMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::SegmentLeaderboardEntry)? {
+            match field.name() {
+                "name" => message.name = field_value(record, field)?,
+                "type" => message.r#type = field_value(record, field)?,
+                "group_primary_key" => message.group_primary_key = field_value(record, field)?,
+                "activity_id" => message.activity_id = field_value(record, field)?,
+                "segment_time" => message.segment_time = field_value(record, field)?,
+                "activity_id_string" => message.activity_id_string = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `segment_point` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SegmentPointMessage {
+    pub position_lat: Option<i32>,
+    pub position_long: Option<i32>,
+    pub distance: Option<f64>,
+    pub altitude: Option<f64>,
+    pub leader_time: Option<f64>,
+    pub enhanced_altitude: Option<f64>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SegmentPointMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::SegmentPoint)? {
+            match field.name() {
+                "position_lat" => message.position_lat = field_value(record, field)?,
+                "position_long" => message.position_long = field_value(record, field)?,
+                "distance" => message.distance = field_value(record, field)?,
+                "altitude" => message.altitude = field_value(record, field)?,
+                "leader_time" => message.leader_time = field_value(record, field)?,
+                "enhanced_altitude" => message.enhanced_altitude = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `segment_lap` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SegmentLapMessage {
+    pub event: Option<Event>,
+    pub event_type: Option<EventType>,
+    pub start_time: Option<crate::timestamp::Timestamp>,
+    pub start_position_lat: Option<i32>,
+    pub start_position_long: Option<i32>,
+    pub end_position_lat: Option<i32>,
+    pub end_position_long: Option<i32>,
+    pub total_elapsed_time: Option<f64>,
+    pub total_timer_time: Option<f64>,
+    pub total_distance: Option<f64>,
+    pub total_strokes: Option<u32>,
+    pub total_cycles: Option<u32>,
+    pub total_calories: Option<u16>,
+    pub total_fat_calories: Option<u16>,
+    pub avg_speed: Option<f64>,
+    pub max_speed: Option<f64>,
+    pub avg_heart_rate: Option<u8>,
+    pub max_heart_rate: Option<u8>,
+    pub avg_cadence: Option<u8>,
+    pub max_cadence: Option<u8>,
+    pub avg_power: Option<u16>,
+    pub max_power: Option<u16>,
+    pub total_ascent: Option<u16>,
+    pub total_descent: Option<u16>,
+    pub sport: Option<Sport>,
+    pub event_group: Option<u8>,
+    pub nec_lat: Option<i32>,
+    pub nec_long: Option<i32>,
+    pub swc_lat: Option<i32>,
+    pub swc_long: Option<i32>,
+    pub name: Option<String>,
+    pub normalized_power: Option<u16>,
+    pub left_right_balance: Option<LeftRightBalance100>,
+    pub sub_sport: Option<SubSport>,
+    pub total_work: Option<u32>,
+    pub avg_altitude: Option<f64>,
+    pub max_altitude: Option<f64>,
+    pub gps_accuracy: Option<u8>,
+    pub avg_grade: Option<f64>,
+    pub avg_pos_grade: Option<f64>,
+    pub avg_neg_grade: Option<f64>,
+    pub max_pos_grade: Option<f64>,
+    pub max_neg_grade: Option<f64>,
+    pub avg_temperature: Option<i8>,
+    pub max_temperature: Option<i8>,
+    pub total_moving_time: Option<f64>,
+    pub avg_pos_vertical_speed: Option<f64>,
+    pub avg_neg_vertical_speed: Option<f64>,
+    pub max_pos_vertical_speed: Option<f64>,
+    pub max_neg_vertical_speed: Option<f64>,
+    pub time_in_hr_zone: Option<Vec<f64>>,
+    pub time_in_speed_zone: Option<Vec<f64>>,
+    pub time_in_cadence_zone: Option<Vec<f64>>,
+    pub time_in_power_zone: Option<Vec<f64>>,
+    pub repetition_num: Option<u16>,
+    pub min_altitude: Option<f64>,
+    pub min_heart_rate: Option<u8>,
+    pub active_time: Option<f64>,
+    pub wkt_step_index: Option<MessageIndex>,
+    pub sport_event: Option<SportEvent>,
+    pub avg_left_torque_effectiveness: Option<f64>,
+    pub avg_right_torque_effectiveness: Option<f64>,
+    pub avg_left_pedal_smoothness: Option<f64>,
+    pub avg_right_pedal_smoothness: Option<f64>,
+    pub avg_combined_pedal_smoothness: Option<f64>,
+    pub status: Option<SegmentLapStatus>,
+    pub uuid: Option<String>,
+    pub avg_fractional_cadence: Option<f64>,
+    pub max_fractional_cadence: Option<f64>,
+    pub total_fractional_cycles: Option<f64>,
+    pub front_gear_shift_count: Option<u16>,
+    pub rear_gear_shift_count: Option<u16>,
+    pub time_standing: Option<f64>,
+    pub stand_count: Option<u16>,
+    pub avg_left_pco: Option<i8>,
+    pub avg_right_pco: Option<i8>,
+    pub avg_left_power_phase: Option<f64>,
+    pub avg_left_power_phase_peak: Option<f64>,
+    pub avg_right_power_phase: Option<f64>,
+    pub avg_right_power_phase_peak: Option<f64>,
+    pub avg_power_position: Option<u16>,
+    pub max_power_position: Option<u16>,
+    pub avg_cadence_position: Option<u8>,
+    pub max_cadence_position: Option<u8>,
+    pub manufacturer: Option<Manufacturer>,
+    pub total_grit: Option<f32>,
+    pub total_flow: Option<f32>,
+    pub avg_grit: Option<f32>,
+    pub avg_flow: Option<f32>,
+    pub total_fractional_ascent: Option<f64>,
+    pub total_fractional_descent: Option<f64>,
+    pub enhanced_avg_altitude: Option<f64>,
+    pub enhanced_max_altitude: Option<f64>,
+    pub enhanced_min_altitude: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SegmentLapMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::SegmentLap)? {
+            match field.name() {
+                "event" => message.event = field_value(record, field)?,
+                "event_type" => message.event_type = field_value(record, field)?,
+                "start_time" => message.start_time = field_value(record, field)?,
+                "start_position_lat" => message.start_position_lat = field_value(record, field)?,
+                "start_position_long" => message.start_position_long = field_value(record, field)?,
+                "end_position_lat" => message.end_position_lat = field_value(record, field)?,
+                "end_position_long" => message.end_position_long = field_value(record, field)?,
+                "total_elapsed_time" => message.total_elapsed_time = field_value(record, field)?,
+                "total_timer_time" => message.total_timer_time = field_value(record, field)?,
+                "total_distance" => message.total_distance = field_value(record, field)?,
+                "total_strokes" => message.total_strokes = field_value(record, field)?,
+                "total_cycles" => message.total_cycles = field_value(record, field)?,
+                "total_calories" => message.total_calories = field_value(record, field)?,
+                "total_fat_calories" => message.total_fat_calories = field_value(record, field)?,
+                "avg_speed" => message.avg_speed = field_value(record, field)?,
+                "max_speed" => message.max_speed = field_value(record, field)?,
+                "avg_heart_rate" => message.avg_heart_rate = field_value(record, field)?,
+                "max_heart_rate" => message.max_heart_rate = field_value(record, field)?,
+                "avg_cadence" => message.avg_cadence = field_value(record, field)?,
+                "max_cadence" => message.max_cadence = field_value(record, field)?,
+                "avg_power" => message.avg_power = field_value(record, field)?,
+                "max_power" => message.max_power = field_value(record, field)?,
+                "total_ascent" => message.total_ascent = field_value(record, field)?,
+                "total_descent" => message.total_descent = field_value(record, field)?,
+                "sport" => message.sport = field_value(record, field)?,
+                "event_group" => message.event_group = field_value(record, field)?,
+                "nec_lat" => message.nec_lat = field_value(record, field)?,
+                "nec_long" => message.nec_long = field_value(record, field)?,
+                "swc_lat" => message.swc_lat = field_value(record, field)?,
+                "swc_long" => message.swc_long = field_value(record, field)?,
+                "name" => message.name = field_value(record, field)?,
+                "normalized_power" => message.normalized_power = field_value(record, field)?,
+                "left_right_balance" => message.left_right_balance = field_value(record, field)?,
+                "sub_sport" => message.sub_sport = field_value(record, field)?,
+                "total_work" => message.total_work = field_value(record, field)?,
+                "avg_altitude" => message.avg_altitude = field_value(record, field)?,
+                "max_altitude" => message.max_altitude = field_value(record, field)?,
+                "gps_accuracy" => message.gps_accuracy = field_value(record, field)?,
+                "avg_grade" => message.avg_grade = field_value(record, field)?,
+                "avg_pos_grade" => message.avg_pos_grade = field_value(record, field)?,
+                "avg_neg_grade" => message.avg_neg_grade = field_value(record, field)?,
+                "max_pos_grade" => message.max_pos_grade = field_value(record, field)?,
+                "max_neg_grade" => message.max_neg_grade = field_value(record, field)?,
+                "avg_temperature" => message.avg_temperature = field_value(record, field)?,
+                "max_temperature" => message.max_temperature = field_value(record, field)?,
+                "total_moving_time" => message.total_moving_time = field_value(record, field)?,
+                "avg_pos_vertical_speed" => {
+                    message.avg_pos_vertical_speed = field_value(record, field)?
+                }
+                "avg_neg_vertical_speed" => {
+                    message.avg_neg_vertical_speed = field_value(record, field)?
+                }
+                "max_pos_vertical_speed" => {
+                    message.max_pos_vertical_speed = field_value(record, field)?
+                }
+                "max_neg_vertical_speed" => {
+                    message.max_neg_vertical_speed = field_value(record, field)?
+                }
+                "time_in_hr_zone" => message.time_in_hr_zone = field_value(record, field)?,
+                "time_in_speed_zone" => message.time_in_speed_zone = field_value(record, field)?,
+                "time_in_cadence_zone" => {
+                    message.time_in_cadence_zone = field_value(record, field)?
+                }
+                "time_in_power_zone" => message.time_in_power_zone = field_value(record, field)?,
+                "repetition_num" => message.repetition_num = field_value(record, field)?,
+                "min_altitude" => message.min_altitude = field_value(record, field)?,
+                "min_heart_rate" => message.min_heart_rate = field_value(record, field)?,
+                "active_time" => message.active_time = field_value(record, field)?,
+                "wkt_step_index" => message.wkt_step_index = field_value(record, field)?,
+                "sport_event" => message.sport_event = field_value(record, field)?,
+                "avg_left_torque_effectiveness" => {
+                    message.avg_left_torque_effectiveness = field_value(record, field)?
+                }
+                "avg_right_torque_effectiveness" => {
+                    message.avg_right_torque_effectiveness = field_value(record, field)?
+                }
+                "avg_left_pedal_smoothness" => {
+                    message.avg_left_pedal_smoothness = field_value(record, field)?
+                }
+                "avg_right_pedal_smoothness" => {
+                    message.avg_right_pedal_smoothness = field_value(record, field)?
+                }
+                "avg_combined_pedal_smoothness" => {
+                    message.avg_combined_pedal_smoothness = field_value(record, field)?
+                }
+                "status" => message.status = field_value(record, field)?,
+                "uuid" => message.uuid = field_value(record, field)?,
+                "avg_fractional_cadence" => {
+                    message.avg_fractional_cadence = field_value(record, field)?
+                }
+                "max_fractional_cadence" => {
+                    message.max_fractional_cadence = field_value(record, field)?
+                }
+                "total_fractional_cycles" => {
+                    message.total_fractional_cycles = field_value(record, field)?
+                }
+                "front_gear_shift_count" => {
+                    message.front_gear_shift_count = field_value(record, field)?
+                }
+                "rear_gear_shift_count" => {
+                    message.rear_gear_shift_count = field_value(record, field)?
+                }
+                "time_standing" => message.time_standing = field_value(record, field)?,
+                "stand_count" => message.stand_count = field_value(record, field)?,
+                "avg_left_pco" => message.avg_left_pco = field_value(record, field)?,
+                "avg_right_pco" => message.avg_right_pco = field_value(record, field)?,
+                "avg_left_power_phase" => {
+                    message.avg_left_power_phase = field_value(record, field)?
+                }
+                "avg_left_power_phase_peak" => {
+                    message.avg_left_power_phase_peak = field_value(record, field)?
+                }
+                "avg_right_power_phase" => {
+                    message.avg_right_power_phase = field_value(record, field)?
+                }
+                "avg_right_power_phase_peak" => {
+                    message.avg_right_power_phase_peak = field_value(record, field)?
+                }
+                "avg_power_position" => message.avg_power_position = field_value(record, field)?,
+                "max_power_position" => message.max_power_position = field_value(record, field)?,
+                "avg_cadence_position" => {
+                    message.avg_cadence_position = field_value(record, field)?
+                }
+                "max_cadence_position" => {
+                    message.max_cadence_position = field_value(record, field)?
+                }
+                "manufacturer" => message.manufacturer = field_value(record, field)?,
+                "total_grit" => message.total_grit = field_value(record, field)?,
+                "total_flow" => message.total_flow = field_value(record, field)?,
+                "avg_grit" => message.avg_grit = field_value(record, field)?,
+                "avg_flow" => message.avg_flow = field_value(record, field)?,
+                "total_fractional_ascent" => {
+                    message.total_fractional_ascent = field_value(record, field)?
+                }
+                "total_fractional_descent" => {
+                    message.total_fractional_descent = field_value(record, field)?
+                }
+                "enhanced_avg_altitude" => {
+                    message.enhanced_avg_altitude = field_value(record, field)?
+                }
+                "enhanced_max_altitude" => {
+                    message.enhanced_max_altitude = field_value(record, field)?
+                }
+                "enhanced_min_altitude" => {
+                    message.enhanced_min_altitude = field_value(record, field)?
+                }
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `segment_file` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SegmentFileMessage {
+    pub file_uuid: Option<String>,
+    pub enabled: Option<bool>,
+    pub user_profile_primary_key: Option<u32>,
+    pub leader_type: Option<SegmentLeaderboardType>,
+    pub leader_group_primary_key: Option<u32>,
+    pub leader_activity_id: Option<u32>,
+    pub leader_activity_id_string: Option<String>,
+    pub default_race_leader: Option<u8>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SegmentFileMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::SegmentFile)? {
+            match field.name() {
+                "file_uuid" => message.file_uuid = field_value(record, field)?,
+                "enabled" => message.enabled = field_value(record, field)?,
+                "user_profile_primary_key" => {
+                    message.user_profile_primary_key = field_value(record, field)?
+                }
+                "leader_type" => message.leader_type = field_value(record, field)?,
+                "leader_group_primary_key" => {
+                    message.leader_group_primary_key = field_value(record, field)?
+                }
+                "leader_activity_id" => message.leader_activity_id = field_value(record, field)?,
+                "leader_activity_id_string" => {
+                    message.leader_activity_id_string = field_value(record, field)?
+                }
+                "default_race_leader" => message.default_race_leader = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `workout` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkoutMessage {
+    pub sport: Option<Sport>,
+    pub capabilities: Option<WorkoutCapabilities>,
+    pub num_valid_steps: Option<u16>,
+    pub wkt_name: Option<String>,
+    pub sub_sport: Option<SubSport>,
+    pub pool_length: Option<f64>,
+    pub pool_length_unit: Option<DisplayMeasure>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for WorkoutMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Workout)? {
+            match field.name() {
+                "sport" => message.sport = field_value(record, field)?,
+                "capabilities" => message.capabilities = field_value(record, field)?,
+                "num_valid_steps" => message.num_valid_steps = field_value(record, field)?,
+                "wkt_name" => message.wkt_name = field_value(record, field)?,
+                "sub_sport" => message.sub_sport = field_value(record, field)?,
+                "pool_length" => message.pool_length = field_value(record, field)?,
+                "pool_length_unit" => message.pool_length_unit = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `workout_session` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkoutSessionMessage {
+    pub sport: Option<Sport>,
+    pub sub_sport: Option<SubSport>,
+    pub num_valid_steps: Option<u16>,
+    pub first_step_index: Option<u16>,
+    pub pool_length: Option<f64>,
+    pub pool_length_unit: Option<DisplayMeasure>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for WorkoutSessionMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::WorkoutSession)? {
+            match field.name() {
+                "sport" => message.sport = field_value(record, field)?,
+                "sub_sport" => message.sub_sport = field_value(record, field)?,
+                "num_valid_steps" => message.num_valid_steps = field_value(record, field)?,
+                "first_step_index" => message.first_step_index = field_value(record, field)?,
+                "pool_length" => message.pool_length = field_value(record, field)?,
+                "pool_length_unit" => message.pool_length_unit = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `workout_step` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkoutStepMessage {
+    pub wkt_step_name: Option<String>,
+    pub duration_type: Option<WktStepDuration>,
+    pub duration_time: Option<f64>,
+    pub duration_distance: Option<f64>,
+    pub duration_hr: Option<WorkoutHr>,
+    pub duration_calories: Option<u32>,
+    pub duration_step: Option<u32>,
+    pub duration_power: Option<WorkoutPower>,
+    pub duration_reps: Option<u32>,
+    pub duration_value: Option<u32>,
+    pub target_type: Option<WktStepTarget>,
+    pub target_speed_zone: Option<u32>,
+    pub target_hr_zone: Option<u32>,
+    pub target_cadence_zone: Option<u32>,
+    pub target_power_zone: Option<u32>,
+    pub repeat_steps: Option<u32>,
+    pub repeat_time: Option<f64>,
+    pub repeat_distance: Option<f64>,
+    pub repeat_calories: Option<u32>,
+    pub repeat_hr: Option<WorkoutHr>,
+    pub repeat_power: Option<WorkoutPower>,
+    pub target_stroke_type: Option<SwimStroke>,
+    pub target_value: Option<u32>,
+    pub custom_target_speed_low: Option<f64>,
+    pub custom_target_heart_rate_low: Option<WorkoutHr>,
+    pub custom_target_cadence_low: Option<u32>,
+    pub custom_target_power_low: Option<WorkoutPower>,
+    pub custom_target_value_low: Option<u32>,
+    pub custom_target_speed_high: Option<f64>,
+    pub custom_target_heart_rate_high: Option<WorkoutHr>,
+    pub custom_target_cadence_high: Option<u32>,
+    pub custom_target_power_high: Option<WorkoutPower>,
+    pub custom_target_value_high: Option<u32>,
+    pub intensity: Option<Intensity>,
+    pub notes: Option<String>,
+    pub equipment: Option<WorkoutEquipment>,
+    pub exercise_category: Option<ExerciseCategory>,
+    pub exercise_name: Option<u16>,
+    pub exercise_weight: Option<f64>,
+    pub weight_display_unit: Option<FitBaseUnit>,
+    pub secondary_target_type: Option<WktStepTarget>,
+    pub secondary_target_speed_zone: Option<u32>,
+    pub secondary_target_hr_zone: Option<u32>,
+    pub secondary_target_cadence_zone: Option<u32>,
+    pub secondary_target_power_zone: Option<u32>,
+    pub secondary_target_stroke_type: Option<SwimStroke>,
+    pub secondary_target_value: Option<u32>,
+    pub secondary_custom_target_speed_low: Option<f64>,
+    pub secondary_custom_target_heart_rate_low: Option<WorkoutHr>,
+    pub secondary_custom_target_cadence_low: Option<u32>,
+    pub secondary_custom_target_power_low: Option<WorkoutPower>,
+    pub secondary_custom_target_value_low: Option<u32>,
+    pub secondary_custom_target_speed_high: Option<f64>,
+    pub secondary_custom_target_heart_rate_high: Option<WorkoutHr>,
+    pub secondary_custom_target_cadence_high: Option<u32>,
+    pub secondary_custom_target_power_high: Option<WorkoutPower>,
+    pub secondary_custom_target_value_high: Option<u32>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for WorkoutStepMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::WorkoutStep)? {
+            match field.name() {
+                "wkt_step_name" => message.wkt_step_name = field_value(record, field)?,
+                "duration_type" => message.duration_type = field_value(record, field)?,
+                "duration_time" => message.duration_time = field_value(record, field)?,
+                "duration_distance" => message.duration_distance = field_value(record, field)?,
+                "duration_hr" => message.duration_hr = field_value(record, field)?,
+                "duration_calories" => message.duration_calories = field_value(record, field)?,
+                "duration_step" => message.duration_step = field_value(record, field)?,
+                "duration_power" => message.duration_power = field_value(record, field)?,
+                "duration_reps" => message.duration_reps = field_value(record, field)?,
+                "duration_value" => message.duration_value = field_value(record, field)?,
+                "target_type" => message.target_type = field_value(record, field)?,
+                "target_speed_zone" => message.target_speed_zone = field_value(record, field)?,
+                "target_hr_zone" => message.target_hr_zone = field_value(record, field)?,
+                "target_cadence_zone" => message.target_cadence_zone = field_value(record, field)?,
+                "target_power_zone" => message.target_power_zone = field_value(record, field)?,
+                "repeat_steps" => message.repeat_steps = field_value(record, field)?,
+                "repeat_time" => message.repeat_time = field_value(record, field)?,
+                "repeat_distance" => message.repeat_distance = field_value(record, field)?,
+                "repeat_calories" => message.repeat_calories = field_value(record, field)?,
+                "repeat_hr" => message.repeat_hr = field_value(record, field)?,
+                "repeat_power" => message.repeat_power = field_value(record, field)?,
+                "target_stroke_type" => message.target_stroke_type = field_value(record, field)?,
+                "target_value" => message.target_value = field_value(record, field)?,
+                "custom_target_speed_low" => {
+                    message.custom_target_speed_low = field_value(record, field)?
+                }
+                "custom_target_heart_rate_low" => {
+                    message.custom_target_heart_rate_low = field_value(record, field)?
+                }
+                "custom_target_cadence_low" => {
+                    message.custom_target_cadence_low = field_value(record, field)?
+                }
+                "custom_target_power_low" => {
+                    message.custom_target_power_low = field_value(record, field)?
+                }
+                "custom_target_value_low" => {
+                    message.custom_target_value_low = field_value(record, field)?
+                }
+                "custom_target_speed_high" => {
+                    message.custom_target_speed_high = field_value(record, field)?
+                }
+                "custom_target_heart_rate_high" => {
+                    message.custom_target_heart_rate_high = field_value(record, field)?
+                }
+                "custom_target_cadence_high" => {
+                    message.custom_target_cadence_high = field_value(record, field)?
+                }
+                "custom_target_power_high" => {
+                    message.custom_target_power_high = field_value(record, field)?
+                }
+                "custom_target_value_high" => {
+                    message.custom_target_value_high = field_value(record, field)?
+                }
+                "intensity" => message.intensity = field_value(record, field)?,
+                "notes" => message.notes = field_value(record, field)?,
+                "equipment" => message.equipment = field_value(record, field)?,
+                "exercise_category" => message.exercise_category = field_value(record, field)?,
+                "exercise_name" => message.exercise_name = field_value(record, field)?,
+                "exercise_weight" => message.exercise_weight = field_value(record, field)?,
+                "weight_display_unit" => message.weight_display_unit = field_value(record, field)?,
+                "secondary_target_type" => {
+                    message.secondary_target_type = field_value(record, field)?
+                }
+                "secondary_target_speed_zone" => {
+                    message.secondary_target_speed_zone = field_value(record, field)?
+                }
+                "secondary_target_hr_zone" => {
+                    message.secondary_target_hr_zone = field_value(record, field)?
+                }
+                "secondary_target_cadence_zone" => {
+                    message.secondary_target_cadence_zone = field_value(record, field)?
+                }
+                "secondary_target_power_zone" => {
+                    message.secondary_target_power_zone = field_value(record, field)?
+                }
+                "secondary_target_stroke_type" => {
+                    message.secondary_target_stroke_type = field_value(record, field)?
+                }
+                "secondary_target_value" => {
+                    message.secondary_target_value = field_value(record, field)?
+                }
+                "secondary_custom_target_speed_low" => {
+                    message.secondary_custom_target_speed_low = field_value(record, field)?
+                }
+                "secondary_custom_target_heart_rate_low" => {
+                    message.secondary_custom_target_heart_rate_low = field_value(record, field)?
+                }
+                "secondary_custom_target_cadence_low" => {
+                    message.secondary_custom_target_cadence_low = field_value(record, field)?
+                }
+                "secondary_custom_target_power_low" => {
+                    message.secondary_custom_target_power_low = field_value(record, field)?
+                }
+                "secondary_custom_target_value_low" => {
+                    message.secondary_custom_target_value_low = field_value(record, field)?
+                }
+                "secondary_custom_target_speed_high" => {
+                    message.secondary_custom_target_speed_high = field_value(record, field)?
+                }
+                "secondary_custom_target_heart_rate_high" => {
+                    message.secondary_custom_target_heart_rate_high = field_value(record, field)?
+                }
+                "secondary_custom_target_cadence_high" => {
+                    message.secondary_custom_target_cadence_high = field_value(record, field)?
+                }
+                "secondary_custom_target_power_high" => {
+                    message.secondary_custom_target_power_high = field_value(record, field)?
+                }
+                "secondary_custom_target_value_high" => {
+                    message.secondary_custom_target_value_high = field_value(record, field)?
+                }
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `exercise_title` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExerciseTitleMessage {
+    pub exercise_category: Option<ExerciseCategory>,
+    pub exercise_name: Option<u16>,
+    pub wkt_step_name: Option<String>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for ExerciseTitleMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::ExerciseTitle)? {
+            match field.name() {
+                "exercise_category" => message.exercise_category = field_value(record, field)?,
+                "exercise_name" => message.exercise_name = field_value(record, field)?,
+                "wkt_step_name" => message.wkt_step_name = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `schedule` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleMessage {
+    pub manufacturer: Option<Manufacturer>,
+    pub favero_product: Option<FaveroProduct>,
+    pub garmin_product: Option<GarminProduct>,
+    pub product: Option<u16>,
+    pub serial_number: Option<u32>,
+    pub time_created: Option<crate::timestamp::Timestamp>,
+    pub completed: Option<bool>,
+    pub r#type: Option<Schedule>,
+    pub scheduled_time: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for ScheduleMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Schedule)? {
+            match field.name() {
+                "manufacturer" => message.manufacturer = field_value(record, field)?,
+                "favero_product" => message.favero_product = field_value(record, field)?,
+                "garmin_product" => message.garmin_product = field_value(record, field)?,
+                "product" => message.product = field_value(record, field)?,
+                "serial_number" => message.serial_number = field_value(record, field)?,
+                "time_created" => message.time_created = field_value(record, field)?,
+                "completed" => message.completed = field_value(record, field)?,
+                "type" => message.r#type = field_value(record, field)?,
+                "scheduled_time" => message.scheduled_time = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `totals` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TotalsMessage {
+    pub timer_time: Option<u32>,
+    pub distance: Option<u32>,
+    pub calories: Option<u32>,
+    pub sport: Option<Sport>,
+    pub elapsed_time: Option<u32>,
+    pub sessions: Option<u16>,
+    pub active_time: Option<u32>,
+    pub sport_index: Option<u8>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+    pub message_index: Option<MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for TotalsMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Totals)? {
+            match field.name() {
+                "timer_time" => message.timer_time = field_value(record, field)?,
+                "distance" => message.distance = field_value(record, field)?,
+                "calories" => message.calories = field_value(record, field)?,
+                "sport" => message.sport = field_value(record, field)?,
+                "elapsed_time" => message.elapsed_time = field_value(record, field)?,
+                "sessions" => message.sessions = field_value(record, field)?,
+                "active_time" => message.active_time = field_value(record, field)?,
+                "sport_index" => message.sport_index = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                "message_index" => message.message_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `weight_scale` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeightScaleMessage {
+    pub weight: Option<Weight>,
+    pub percent_fat: Option<f64>,
+    pub percent_hydration: Option<f64>,
+    pub visceral_fat_mass: Option<f64>,
+    pub bone_mass: Option<f64>,
+    pub muscle_mass: Option<f64>,
+    pub basal_met: Option<f64>,
+    pub physique_rating: Option<u8>,
+    pub active_met: Option<f64>,
+    pub metabolic_age: Option<u8>,
+    pub visceral_fat_rating: Option<u8>,
+    pub user_profile_index: Option<MessageIndex>,
+    pub bmi: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for WeightScaleMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::WeightScale)? {
+            match field.name() {
+                "weight" => message.weight = field_value(record, field)?,
+                "percent_fat" => message.percent_fat = field_value(record, field)?,
+                "percent_hydration" => message.percent_hydration = field_value(record, field)?,
+                "visceral_fat_mass" => message.visceral_fat_mass = field_value(record, field)?,
+                "bone_mass" => message.bone_mass = field_value(record, field)?,
+                "muscle_mass" => message.muscle_mass = field_value(record, field)?,
+                "basal_met" => message.basal_met = field_value(record, field)?,
+                "physique_rating" => message.physique_rating = field_value(record, field)?,
+                "active_met" => message.active_met = field_value(record, field)?,
+                "metabolic_age" => message.metabolic_age = field_value(record, field)?,
+                "visceral_fat_rating" => message.visceral_fat_rating = field_value(record, field)?,
+                "user_profile_index" => message.user_profile_index = field_value(record, field)?,
+                "bmi" => message.bmi = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `blood_pressure` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BloodPressureMessage {
+    pub systolic_pressure: Option<u16>,
+    pub diastolic_pressure: Option<u16>,
+    pub mean_arterial_pressure: Option<u16>,
+    pub map_3_sample_mean: Option<u16>,
+    pub map_morning_values: Option<u16>,
+    pub map_evening_values: Option<u16>,
+    pub heart_rate: Option<u8>,
+    pub heart_rate_type: Option<HrType>,
+    pub status: Option<BpStatus>,
+    pub user_profile_index: Option<MessageIndex>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for BloodPressureMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::BloodPressure)? {
+            match field.name() {
+                "systolic_pressure" => message.systolic_pressure = field_value(record, field)?,
+                "diastolic_pressure" => message.diastolic_pressure = field_value(record, field)?,
+                "mean_arterial_pressure" => {
+                    message.mean_arterial_pressure = field_value(record, field)?
+                }
+                "map_3_sample_mean" => message.map_3_sample_mean = field_value(record, field)?,
+                "map_morning_values" => message.map_morning_values = field_value(record, field)?,
+                "map_evening_values" => message.map_evening_values = field_value(record, field)?,
+                "heart_rate" => message.heart_rate = field_value(record, field)?,
+                "heart_rate_type" => message.heart_rate_type = field_value(record, field)?,
+                "status" => message.status = field_value(record, field)?,
+                "user_profile_index" => message.user_profile_index = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `monitoring_info` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MonitoringInfoMessage {
+    pub local_timestamp: Option<crate::timestamp::Timestamp>,
+    pub activity_type: Option<Vec<ActivityType>>,
+    pub cycles_to_distance: Option<Vec<f64>>,
+    pub cycles_to_calories: Option<Vec<f64>>,
+    pub resting_metabolic_rate: Option<u16>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for MonitoringInfoMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::MonitoringInfo)? {
+            match field.name() {
+                "local_timestamp" => message.local_timestamp = field_value(record, field)?,
+                "activity_type" => message.activity_type = field_value(record, field)?,
+                "cycles_to_distance" => message.cycles_to_distance = field_value(record, field)?,
+                "cycles_to_calories" => message.cycles_to_calories = field_value(record, field)?,
+                "resting_metabolic_rate" => {
+                    message.resting_metabolic_rate = field_value(record, field)?
+                }
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `monitoring` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MonitoringMessage {
+    pub device_index: Option<DeviceIndex>,
+    pub calories: Option<u16>,
+    pub distance: Option<f64>,
+    pub steps: Option<u32>,
+    pub strokes: Option<f64>,
+    pub cycles: Option<f64>,
+    pub active_time: Option<f64>,
+    pub activity_type: Option<ActivityType>,
+    pub activity_subtype: Option<ActivitySubtype>,
+    pub activity_level: Option<ActivityLevel>,
+    pub distance_16: Option<u16>,
+    pub cycles_16: Option<u16>,
+    pub active_time_16: Option<u16>,
+    pub local_timestamp: Option<crate::timestamp::Timestamp>,
+    pub temperature: Option<f64>,
+    pub temperature_min: Option<f64>,
+    pub temperature_max: Option<f64>,
+    pub activity_time: Option<u16>,
+    pub active_calories: Option<u16>,
+    pub current_activity_type_intensity: Option<Vec<u8>>,
+    pub timestamp_min_8: Option<u8>,
+    pub timestamp_16: Option<u16>,
+    pub heart_rate: Option<u8>,
+    pub intensity: Option<f64>,
+    pub duration_min: Option<u16>,
+    pub duration: Option<u32>,
+    pub ascent: Option<f64>,
+    pub descent: Option<f64>,
+    pub moderate_activity_minutes: Option<u16>,
+    pub vigorous_activity_minutes: Option<u16>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for MonitoringMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Monitoring)? {
+            match field.name() {
+                "device_index" => message.device_index = field_value(record, field)?,
+                "calories" => message.calories = field_value(record, field)?,
+                "distance" => message.distance = field_value(record, field)?,
+                "steps" => message.steps = field_value(record, field)?,
+                "strokes" => message.strokes = field_value(record, field)?,
+                "cycles" => message.cycles = field_value(record, field)?,
+                "active_time" => message.active_time = field_value(record, field)?,
+                "activity_type" => message.activity_type = field_value(record, field)?,
+                "activity_subtype" => message.activity_subtype = field_value(record, field)?,
+                "activity_level" => message.activity_level = field_value(record, field)?,
+                "distance_16" => message.distance_16 = field_value(record, field)?,
+                "cycles_16" => message.cycles_16 = field_value(record, field)?,
+                "active_time_16" => message.active_time_16 = field_value(record, field)?,
+                "local_timestamp" => message.local_timestamp = field_value(record, field)?,
+                "temperature" => message.temperature = field_value(record, field)?,
+                "temperature_min" => message.temperature_min = field_value(record, field)?,
+                "temperature_max" => message.temperature_max = field_value(record, field)?,
+                "activity_time" => message.activity_time = field_value(record, field)?,
+                "active_calories" => message.active_calories = field_value(record, field)?,
+                "current_activity_type_intensity" => {
+                    message.current_activity_type_intensity = field_value(record, field)?
+                }
+                "timestamp_min_8" => message.timestamp_min_8 = field_value(record, field)?,
+                "timestamp_16" => message.timestamp_16 = field_value(record, field)?,
+                "heart_rate" => message.heart_rate = field_value(record, field)?,
+                "intensity" => message.intensity = field_value(record, field)?,
+                "duration_min" => message.duration_min = field_value(record, field)?,
+                "duration" => message.duration = field_value(record, field)?,
+                "ascent" => message.ascent = field_value(record, field)?,
+                "descent" => message.descent = field_value(record, field)?,
+                "moderate_activity_minutes" => {
+                    message.moderate_activity_minutes = field_value(record, field)?
+                }
+                "vigorous_activity_minutes" => {
+                    message.vigorous_activity_minutes = field_value(record, field)?
+                }
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `monitoring_hr_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MonitoringHrDataMessage {
+    pub resting_heart_rate: Option<u8>,
+    pub current_day_resting_heart_rate: Option<u8>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for MonitoringHrDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::MonitoringHrData)? {
+            match field.name() {
+                "resting_heart_rate" => message.resting_heart_rate = field_value(record, field)?,
+                "current_day_resting_heart_rate" => {
+                    message.current_day_resting_heart_rate = field_value(record, field)?
+                }
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `spo2_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Spo2DataMessage {
+    pub reading_spo2: Option<u8>,
+    pub reading_confidence: Option<u8>,
+    pub mode: Option<Spo2MeasurementType>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for Spo2DataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Spo2Data)? {
+            match field.name() {
+                "reading_spo2" => message.reading_spo2 = field_value(record, field)?,
+                "reading_confidence" => message.reading_confidence = field_value(record, field)?,
+                "mode" => message.mode = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hr` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HrMessage {
+    pub fractional_timestamp: Option<f64>,
+    pub time256: Option<f64>,
+    pub filtered_bpm: Option<Vec<u8>>,
+    pub event_timestamp: Option<Vec<f64>>,
+    pub event_timestamp_12: Option<Vec<u8>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HrMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Hr)? {
+            match field.name() {
+                "fractional_timestamp" => {
+                    message.fractional_timestamp = field_value(record, field)?
+                }
+                "time256" => message.time256 = field_value(record, field)?,
+                "filtered_bpm" => message.filtered_bpm = field_value(record, field)?,
+                "event_timestamp" => message.event_timestamp = field_value(record, field)?,
+                "event_timestamp_12" => message.event_timestamp_12 = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `stress_level` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StressLevelMessage {
+    pub stress_level_value: Option<i16>,
+    pub stress_level_time: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for StressLevelMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::StressLevel)? {
+            match field.name() {
+                "stress_level_value" => message.stress_level_value = field_value(record, field)?,
+                "stress_level_time" => message.stress_level_time = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `max_met_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MaxMetDataMessage {
+    pub update_time: Option<crate::timestamp::Timestamp>,
+    pub vo2_max: Option<f64>,
+    pub sport: Option<Sport>,
+    pub sub_sport: Option<SubSport>,
+    pub max_met_category: Option<MaxMetCategory>,
+    pub calibrated_data: Option<bool>,
+    pub hr_source: Option<MaxMetHeartRateSource>,
+    pub speed_source: Option<MaxMetSpeedSource>,
+}
+impl TryFrom<&FitDataRecord> for MaxMetDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::MaxMetData)? {
+            match field.name() {
+                "update_time" => message.update_time = field_value(record, field)?,
+                "vo2_max" => message.vo2_max = field_value(record, field)?,
+                "sport" => message.sport = field_value(record, field)?,
+                "sub_sport" => message.sub_sport = field_value(record, field)?,
+                "max_met_category" => message.max_met_category = field_value(record, field)?,
+                "calibrated_data" => message.calibrated_data = field_value(record, field)?,
+                "hr_source" => message.hr_source = field_value(record, field)?,
+                "speed_source" => message.speed_source = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hsa_body_battery_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HsaBodyBatteryDataMessage {
+    pub processing_interval: Option<u16>,
+    pub level: Option<Vec<i8>>,
+    pub charged: Option<Vec<i16>>,
+    pub uncharged: Option<Vec<i16>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HsaBodyBatteryDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HsaBodyBatteryData)? {
+            match field.name() {
+                "processing_interval" => message.processing_interval = field_value(record, field)?,
+                "level" => message.level = field_value(record, field)?,
+                "charged" => message.charged = field_value(record, field)?,
+                "uncharged" => message.uncharged = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hsa_event` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HsaEventMessage {
+    pub event_id: Option<u8>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HsaEventMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HsaEvent)? {
+            match field.name() {
+                "event_id" => message.event_id = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hsa_accelerometer_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HsaAccelerometerDataMessage {
+    pub timestamp_ms: Option<u16>,
+    pub sampling_interval: Option<u16>,
+    pub accel_x: Option<Vec<f64>>,
+    pub accel_y: Option<Vec<f64>>,
+    pub accel_z: Option<Vec<f64>>,
+    pub timestamp_32k: Option<u32>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HsaAccelerometerDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HsaAccelerometerData)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "sampling_interval" => message.sampling_interval = field_value(record, field)?,
+                "accel_x" => message.accel_x = field_value(record, field)?,
+                "accel_y" => message.accel_y = field_value(record, field)?,
+                "accel_z" => message.accel_z = field_value(record, field)?,
+                "timestamp_32k" => message.timestamp_32k = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hsa_gyroscope_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HsaGyroscopeDataMessage {
+    pub timestamp_ms: Option<u16>,
+    pub sampling_interval: Option<u16>,
+    pub gyro_x: Option<Vec<f64>>,
+    pub gyro_y: Option<Vec<f64>>,
+    pub gyro_z: Option<Vec<f64>>,
+    pub timestamp_32k: Option<u32>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HsaGyroscopeDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HsaGyroscopeData)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "sampling_interval" => message.sampling_interval = field_value(record, field)?,
+                "gyro_x" => message.gyro_x = field_value(record, field)?,
+                "gyro_y" => message.gyro_y = field_value(record, field)?,
+                "gyro_z" => message.gyro_z = field_value(record, field)?,
+                "timestamp_32k" => message.timestamp_32k = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hsa_step_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HsaStepDataMessage {
+    pub processing_interval: Option<u16>,
+    pub steps: Option<Vec<u32>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HsaStepDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HsaStepData)? {
+            match field.name() {
+                "processing_interval" => message.processing_interval = field_value(record, field)?,
+                "steps" => message.steps = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hsa_spo2_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HsaSpo2DataMessage {
+    pub processing_interval: Option<u16>,
+    pub reading_spo2: Option<Vec<u8>>,
+    pub confidence: Option<Vec<u8>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HsaSpo2DataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HsaSpo2Data)? {
+            match field.name() {
+                "processing_interval" => message.processing_interval = field_value(record, field)?,
+                "reading_spo2" => message.reading_spo2 = field_value(record, field)?,
+                "confidence" => message.confidence = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hsa_stress_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HsaStressDataMessage {
+    pub processing_interval: Option<u16>,
+    pub stress_level: Option<Vec<i8>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HsaStressDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HsaStressData)? {
+            match field.name() {
+                "processing_interval" => message.processing_interval = field_value(record, field)?,
+                "stress_level" => message.stress_level = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hsa_respiration_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HsaRespirationDataMessage {
+    pub processing_interval: Option<u16>,
+    pub respiration_rate: Option<Vec<f64>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HsaRespirationDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HsaRespirationData)? {
+            match field.name() {
+                "processing_interval" => message.processing_interval = field_value(record, field)?,
+                "respiration_rate" => message.respiration_rate = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hsa_heart_rate_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HsaHeartRateDataMessage {
+    pub processing_interval: Option<u16>,
+    pub status: Option<u8>,
+    pub heart_rate: Option<Vec<u8>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HsaHeartRateDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HsaHeartRateData)? {
+            match field.name() {
+                "processing_interval" => message.processing_interval = field_value(record, field)?,
+                "status" => message.status = field_value(record, field)?,
+                "heart_rate" => message.heart_rate = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hsa_configuration_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HsaConfigurationDataMessage {
+    pub data: Option<Vec<u8>>,
+    pub data_size: Option<u8>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HsaConfigurationDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HsaConfigurationData)? {
+            match field.name() {
+                "data" => message.data = field_value(record, field)?,
+                "data_size" => message.data_size = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hsa_wrist_temperature_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HsaWristTemperatureDataMessage {
+    pub processing_interval: Option<u16>,
+    pub value: Option<Vec<f64>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HsaWristTemperatureDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HsaWristTemperatureData)? {
+            match field.name() {
+                "processing_interval" => message.processing_interval = field_value(record, field)?,
+                "value" => message.value = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `memo_glob` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MemoGlobMessage {
+    pub memo: Option<Vec<u8>>,
+    pub mesg_num: Option<MesgNum>,
+    pub parent_index: Option<MessageIndex>,
+    pub field_num: Option<u8>,
+    pub data: Option<u8>,
+    pub part_index: Option<u32>,
+}
+impl TryFrom<&FitDataRecord> for MemoGlobMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::MemoGlob)? {
+            match field.name() {
+                "memo" => message.memo = field_value(record, field)?,
+                "mesg_num" => message.mesg_num = field_value(record, field)?,
+                "parent_index" => message.parent_index = field_value(record, field)?,
+                "field_num" => message.field_num = field_value(record, field)?,
+                "data" => message.data = field_value(record, field)?,
+                "part_index" => message.part_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `sleep_level` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SleepLevelMessage {
+    pub sleep_level: Option<SleepLevel>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for SleepLevelMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::SleepLevel)? {
+            match field.name() {
+                "sleep_level" => message.sleep_level = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `ant_channel_id` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AntChannelIdMessage {
+    pub channel_number: Option<u8>,
+    pub device_type: Option<u8>,
+    pub device_number: Option<u16>,
+    pub transmission_type: Option<u8>,
+    pub device_index: Option<DeviceIndex>,
+}
+impl TryFrom<&FitDataRecord> for AntChannelIdMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::AntChannelId)? {
+            match field.name() {
+                "channel_number" => message.channel_number = field_value(record, field)?,
+                "device_type" => message.device_type = field_value(record, field)?,
+                "device_number" => message.device_number = field_value(record, field)?,
+                "transmission_type" => message.transmission_type = field_value(record, field)?,
+                "device_index" => message.device_index = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `ant_rx` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AntRxMessage {
+    pub fractional_timestamp: Option<f64>,
+    pub mesg_id: Option<Vec<u8>>,
+    pub mesg_data: Option<Vec<u8>>,
+    pub channel_number: Option<u8>,
+    pub data: Option<Vec<u8>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for AntRxMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::AntRx)? {
+            match field.name() {
+                "fractional_timestamp" => {
+                    message.fractional_timestamp = field_value(record, field)?
+                }
+                "mesg_id" => message.mesg_id = field_value(record, field)?,
+                "mesg_data" => message.mesg_data = field_value(record, field)?,
+                "channel_number" => message.channel_number = field_value(record, field)?,
+                "data" => message.data = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `ant_tx` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AntTxMessage {
+    pub fractional_timestamp: Option<f64>,
+    pub mesg_id: Option<Vec<u8>>,
+    pub mesg_data: Option<Vec<u8>>,
+    pub channel_number: Option<u8>,
+    pub data: Option<Vec<u8>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for AntTxMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::AntTx)? {
+            match field.name() {
+                "fractional_timestamp" => {
+                    message.fractional_timestamp = field_value(record, field)?
+                }
+                "mesg_id" => message.mesg_id = field_value(record, field)?,
+                "mesg_data" => message.mesg_data = field_value(record, field)?,
+                "channel_number" => message.channel_number = field_value(record, field)?,
+                "data" => message.data = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `exd_screen_configuration` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExdScreenConfigurationMessage {
+    pub screen_index: Option<u8>,
+    pub field_count: Option<u8>,
+    pub layout: Option<ExdLayout>,
+    pub screen_enabled: Option<bool>,
+}
+impl TryFrom<&FitDataRecord> for ExdScreenConfigurationMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::ExdScreenConfiguration)? {
+            match field.name() {
+                "screen_index" => message.screen_index = field_value(record, field)?,
+                "field_count" => message.field_count = field_value(record, field)?,
+                "layout" => message.layout = field_value(record, field)?,
+                "screen_enabled" => message.screen_enabled = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `exd_data_field_configuration` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExdDataFieldConfigurationMessage {
+    pub screen_index: Option<u8>,
+    pub concept_field: Option<Vec<u8>>,
+    pub field_id: Option<u8>,
+    pub concept_count: Option<u8>,
+    pub display_type: Option<ExdDisplayType>,
+    pub title: Option<String>,
+}
+impl TryFrom<&FitDataRecord> for ExdDataFieldConfigurationMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::ExdDataFieldConfiguration)? {
+            match field.name() {
+                "screen_index" => message.screen_index = field_value(record, field)?,
+                "concept_field" => message.concept_field = field_value(record, field)?,
+                "field_id" => message.field_id = field_value(record, field)?,
+                "concept_count" => message.concept_count = field_value(record, field)?,
+                "display_type" => message.display_type = field_value(record, field)?,
+                "title" => message.title = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `exd_data_concept_configuration` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExdDataConceptConfigurationMessage {
+    pub screen_index: Option<u8>,
+    pub concept_field: Option<Vec<u8>>,
+    pub field_id: Option<u8>,
+    pub concept_index: Option<u8>,
+    pub data_page: Option<u8>,
+    pub concept_key: Option<u8>,
+    pub scaling: Option<u8>,
+    pub data_units: Option<ExdDataUnits>,
+    pub qualifier: Option<ExdQualifiers>,
+    pub descriptor: Option<ExdDescriptors>,
+    pub is_signed: Option<bool>,
+}
+impl TryFrom<&FitDataRecord> for ExdDataConceptConfigurationMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::ExdDataConceptConfiguration)? {
+            match field.name() {
+                "screen_index" => message.screen_index = field_value(record, field)?,
+                "concept_field" => message.concept_field = field_value(record, field)?,
+                "field_id" => message.field_id = field_value(record, field)?,
+                "concept_index" => message.concept_index = field_value(record, field)?,
+                "data_page" => message.data_page = field_value(record, field)?,
+                "concept_key" => message.concept_key = field_value(record, field)?,
+                "scaling" => message.scaling = field_value(record, field)?,
+                "data_units" => message.data_units = field_value(record, field)?,
+                "qualifier" => message.qualifier = field_value(record, field)?,
+                "descriptor" => message.descriptor = field_value(record, field)?,
+                "is_signed" => message.is_signed = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `dive_summary` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiveSummaryMessage {
+    pub reference_mesg: Option<MesgNum>,
+    pub reference_index: Option<MessageIndex>,
+    pub avg_depth: Option<f64>,
+    pub max_depth: Option<f64>,
+    pub surface_interval: Option<u32>,
+    pub start_cns: Option<u8>,
+    pub end_cns: Option<u8>,
+    pub start_n2: Option<u16>,
+    pub end_n2: Option<u16>,
+    pub o2_toxicity: Option<u16>,
+    pub dive_number: Option<u32>,
+    pub bottom_time: Option<f64>,
+    pub avg_pressure_sac: Option<f64>,
+    pub avg_volume_sac: Option<f64>,
+    pub avg_rmv: Option<f64>,
+    pub descent_time: Option<f64>,
+    pub ascent_time: Option<f64>,
+    pub avg_ascent_rate: Option<f64>,
+    pub avg_descent_rate: Option<f64>,
+    pub max_ascent_rate: Option<f64>,
+    pub max_descent_rate: Option<f64>,
+    pub hang_time: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for DiveSummaryMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::DiveSummary)? {
+            match field.name() {
+                "reference_mesg" => message.reference_mesg = field_value(record, field)?,
+                "reference_index" => message.reference_index = field_value(record, field)?,
+                "avg_depth" => message.avg_depth = field_value(record, field)?,
+                "max_depth" => message.max_depth = field_value(record, field)?,
+                "surface_interval" => message.surface_interval = field_value(record, field)?,
+                "start_cns" => message.start_cns = field_value(record, field)?,
+                "end_cns" => message.end_cns = field_value(record, field)?,
+                "start_n2" => message.start_n2 = field_value(record, field)?,
+                "end_n2" => message.end_n2 = field_value(record, field)?,
+                "o2_toxicity" => message.o2_toxicity = field_value(record, field)?,
+                "dive_number" => message.dive_number = field_value(record, field)?,
+                "bottom_time" => message.bottom_time = field_value(record, field)?,
+                "avg_pressure_sac" => message.avg_pressure_sac = field_value(record, field)?,
+                "avg_volume_sac" => message.avg_volume_sac = field_value(record, field)?,
+                "avg_rmv" => message.avg_rmv = field_value(record, field)?,
+                "descent_time" => message.descent_time = field_value(record, field)?,
+                "ascent_time" => message.ascent_time = field_value(record, field)?,
+                "avg_ascent_rate" => message.avg_ascent_rate = field_value(record, field)?,
+                "avg_descent_rate" => message.avg_descent_rate = field_value(record, field)?,
+                "max_ascent_rate" => message.max_ascent_rate = field_value(record, field)?,
+                "max_descent_rate" => message.max_descent_rate = field_value(record, field)?,
+                "hang_time" => message.hang_time = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `aad_accel_features` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AadAccelFeaturesMessage {
+    pub time: Option<u16>,
+    pub energy_total: Option<u32>,
+    pub zero_cross_cnt: Option<u16>,
+    pub instance: Option<u8>,
+    pub time_above_threshold: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for AadAccelFeaturesMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::AadAccelFeatures)? {
+            match field.name() {
+                "time" => message.time = field_value(record, field)?,
+                "energy_total" => message.energy_total = field_value(record, field)?,
+                "zero_cross_cnt" => message.zero_cross_cnt = field_value(record, field)?,
+                "instance" => message.instance = field_value(record, field)?,
+                "time_above_threshold" => {
+                    message.time_above_threshold = field_value(record, field)?
+                }
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hrv` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HrvMessage {
+    pub time: Option<Vec<f64>>,
+}
+impl TryFrom<&FitDataRecord> for HrvMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::Hrv)? {
+            match field.name() {
+                "time" => message.time = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `beat_intervals` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BeatIntervalsMessage {
+    pub timestamp_ms: Option<u16>,
+    pub time: Option<Vec<u16>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for BeatIntervalsMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::BeatIntervals)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "time" => message.time = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hrv_status_summary` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HrvStatusSummaryMessage {
+    pub weekly_average: Option<f64>,
+    pub last_night_average: Option<f64>,
+    pub last_night_5_min_high: Option<f64>,
+    pub baseline_low_upper: Option<f64>,
+    pub baseline_balanced_lower: Option<f64>,
+    pub baseline_balanced_upper: Option<f64>,
+    pub status: Option<HrvStatus>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HrvStatusSummaryMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HrvStatusSummary)? {
+            match field.name() {
+                "weekly_average" => message.weekly_average = field_value(record, field)?,
+                "last_night_average" => message.last_night_average = field_value(record, field)?,
+                "last_night_5_min_high" => {
+                    message.last_night_5_min_high = field_value(record, field)?
+                }
+                "baseline_low_upper" => message.baseline_low_upper = field_value(record, field)?,
+                "baseline_balanced_lower" => {
+                    message.baseline_balanced_lower = field_value(record, field)?
+                }
+                "baseline_balanced_upper" => {
+                    message.baseline_balanced_upper = field_value(record, field)?
+                }
+                "status" => message.status = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `hrv_value` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HrvValueMessage {
+    pub value: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for HrvValueMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::HrvValue)? {
+            match field.name() {
+                "value" => message.value = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `raw_bbi` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RawBbiMessage {
+    pub timestamp_ms: Option<u16>,
+    pub data: Option<Vec<u16>>,
+    pub time: Option<Vec<u16>>,
+    pub quality: Option<Vec<u8>>,
+    pub gap: Option<Vec<u8>>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for RawBbiMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::RawBbi)? {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = field_value(record, field)?,
+                "data" => message.data = field_value(record, field)?,
+                "time" => message.time = field_value(record, field)?,
+                "quality" => message.quality = field_value(record, field)?,
+                "gap" => message.gap = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `respiration_rate` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RespirationRateMessage {
+    pub respiration_rate: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for RespirationRateMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::RespirationRate)? {
+            match field.name() {
+                "respiration_rate" => message.respiration_rate = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `chrono_shot_session` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChronoShotSessionMessage {
+    pub min_speed: Option<f64>,
+    pub max_speed: Option<f64>,
+    pub avg_speed: Option<f64>,
+    pub shot_count: Option<u16>,
+    pub projectile_type: Option<ProjectileType>,
+    pub grain_weight: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for ChronoShotSessionMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::ChronoShotSession)? {
+            match field.name() {
+                "min_speed" => message.min_speed = field_value(record, field)?,
+                "max_speed" => message.max_speed = field_value(record, field)?,
+                "avg_speed" => message.avg_speed = field_value(record, field)?,
+                "shot_count" => message.shot_count = field_value(record, field)?,
+                "projectile_type" => message.projectile_type = field_value(record, field)?,
+                "grain_weight" => message.grain_weight = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `chrono_shot_data` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChronoShotDataMessage {
+    pub shot_speed: Option<f64>,
+    pub shot_num: Option<u16>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for ChronoShotDataMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::ChronoShotData)? {
+            match field.name() {
+                "shot_speed" => message.shot_speed = field_value(record, field)?,
+                "shot_num" => message.shot_num = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `tank_update` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TankUpdateMessage {
+    pub sensor: Option<AntChannelId>,
+    pub pressure: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for TankUpdateMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::TankUpdate)? {
+            match field.name() {
+                "sensor" => message.sensor = field_value(record, field)?,
+                "pressure" => message.pressure = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `tank_summary` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TankSummaryMessage {
+    pub sensor: Option<AntChannelId>,
+    pub start_pressure: Option<f64>,
+    pub end_pressure: Option<f64>,
+    pub volume_used: Option<f64>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for TankSummaryMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::TankSummary)? {
+            match field.name() {
+                "sensor" => message.sensor = field_value(record, field)?,
+                "start_pressure" => message.start_pressure = field_value(record, field)?,
+                "end_pressure" => message.end_pressure = field_value(record, field)?,
+                "volume_used" => message.volume_used = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `sleep_assessment` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SleepAssessmentMessage {
+    pub combined_awake_score: Option<u8>,
+    pub awake_time_score: Option<u8>,
+    pub awakenings_count_score: Option<u8>,
+    pub deep_sleep_score: Option<u8>,
+    pub sleep_duration_score: Option<u8>,
+    pub light_sleep_score: Option<u8>,
+    pub overall_sleep_score: Option<u8>,
+    pub sleep_quality_score: Option<u8>,
+    pub sleep_recovery_score: Option<u8>,
+    pub rem_sleep_score: Option<u8>,
+    pub sleep_restlessness_score: Option<u8>,
+    pub awakenings_count: Option<u8>,
+    pub interruptions_score: Option<u8>,
+    pub average_stress_during_sleep: Option<f64>,
+}
+impl TryFrom<&FitDataRecord> for SleepAssessmentMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::SleepAssessment)? {
+            match field.name() {
+                "combined_awake_score" => {
+                    message.combined_awake_score = field_value(record, field)?
+                }
+                "awake_time_score" => message.awake_time_score = field_value(record, field)?,
+                "awakenings_count_score" => {
+                    message.awakenings_count_score = field_value(record, field)?
+                }
+                "deep_sleep_score" => message.deep_sleep_score = field_value(record, field)?,
+                "sleep_duration_score" => {
+                    message.sleep_duration_score = field_value(record, field)?
+                }
+                "light_sleep_score" => message.light_sleep_score = field_value(record, field)?,
+                "overall_sleep_score" => message.overall_sleep_score = field_value(record, field)?,
+                "sleep_quality_score" => message.sleep_quality_score = field_value(record, field)?,
+                "sleep_recovery_score" => {
+                    message.sleep_recovery_score = field_value(record, field)?
+                }
+                "rem_sleep_score" => message.rem_sleep_score = field_value(record, field)?,
+                "sleep_restlessness_score" => {
+                    message.sleep_restlessness_score = field_value(record, field)?
+                }
+                "awakenings_count" => message.awakenings_count = field_value(record, field)?,
+                "interruptions_score" => message.interruptions_score = field_value(record, field)?,
+                "average_stress_during_sleep" => {
+                    message.average_stress_during_sleep = field_value(record, field)?
+                }
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+#[doc = "Typed fields of a `skin_temp_overnight` message"]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SkinTempOvernightMessage {
+    pub local_timestamp: Option<crate::timestamp::Timestamp>,
+    pub average_deviation: Option<f32>,
+    pub average_7_day_deviation: Option<f32>,
+    pub nightly_value: Option<f32>,
+    pub timestamp: Option<crate::timestamp::Timestamp>,
+}
+impl TryFrom<&FitDataRecord> for SkinTempOvernightMessage {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        let mut message = Self::default();
+        for field in profile_fields(record, MesgNum::SkinTempOvernight)? {
+            match field.name() {
+                "local_timestamp" => message.local_timestamp = field_value(record, field)?,
+                "average_deviation" => message.average_deviation = field_value(record, field)?,
+                "average_7_day_deviation" => {
+                    message.average_7_day_deviation = field_value(record, field)?
+                }
+                "nightly_value" => message.nightly_value = field_value(record, field)?,
+                "timestamp" => message.timestamp = field_value(record, field)?,
+                _ => {}
+            }
+        }
+        Ok(message)
+    }
+}
+impl FromValue for Activity {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Activity, value).map(Self::from)
+    }
+}
+impl FromValue for ActivityClass {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::ActivityClass, value).map(Self::from)
+    }
+}
+impl FromValue for ActivityLevel {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::ActivityLevel, value).map(Self::from)
+    }
+}
+impl FromValue for ActivitySubtype {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::ActivitySubtype, value).map(Self::from)
+    }
+}
+impl FromValue for ActivityType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::ActivityType, value).map(Self::from)
+    }
+}
+impl FromValue for AnalogWatchfaceLayout {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::AnalogWatchfaceLayout, value).map(Self::from)
+    }
+}
+impl FromValue for AntChannelId {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::AntChannelId, value).map(Self::from)
+    }
+}
+impl FromValue for AntNetwork {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::AntNetwork, value).map(Self::from)
+    }
+}
+impl FromValue for AntplusDeviceType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::AntplusDeviceType, value).map(Self::from)
+    }
+}
+impl FromValue for AttitudeStage {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::AttitudeStage, value).map(Self::from)
+    }
+}
+impl FromValue for AttitudeValidity {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::AttitudeValidity, value).map(Self::from)
+    }
+}
+impl FromValue for AutoActivityDetect {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::AutoActivityDetect, value).map(Self::from)
+    }
+}
+impl FromValue for AutoSyncFrequency {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::AutoSyncFrequency, value).map(Self::from)
+    }
+}
+impl FromValue for BacklightMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::BacklightMode, value).map(Self::from)
+    }
+}
+impl FromValue for BacklightTimeout {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::BacklightTimeout, value).map(Self::from)
+    }
+}
+impl FromValue for BatteryStatus {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::BatteryStatus, value).map(Self::from)
+    }
+}
+impl FromValue for BleDeviceType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::BleDeviceType, value).map(Self::from)
+    }
+}
+impl FromValue for BodyLocation {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::BodyLocation, value).map(Self::from)
+    }
+}
+impl FromValue for BpStatus {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::BpStatus, value).map(Self::from)
+    }
+}
+impl FromValue for CameraEventType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::CameraEventType, value).map(Self::from)
+    }
+}
+impl FromValue for CameraOrientationType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::CameraOrientationType, value).map(Self::from)
+    }
+}
+impl FromValue for CcrSetpointSwitchMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::CcrSetpointSwitchMode, value).map(Self::from)
+    }
+}
+impl FromValue for ClimbProEvent {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::ClimbProEvent, value).map(Self::from)
+    }
+}
+impl FromValue for CommTimeoutType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::CommTimeoutType, value).map(Self::from)
+    }
+}
+impl FromValue for ConnectivityCapabilities {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::ConnectivityCapabilities, value).map(Self::from)
+    }
+}
+impl FromValue for CourseCapabilities {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::CourseCapabilities, value).map(Self::from)
+    }
+}
+impl FromValue for CoursePoint {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::CoursePoint, value).map(Self::from)
+    }
+}
+impl FromValue for DateMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DateMode, value).map(Self::from)
+    }
+}
+impl FromValue for DayOfWeek {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DayOfWeek, value).map(Self::from)
+    }
+}
+impl FromValue for DeviceIndex {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DeviceIndex, value).map(Self::from)
+    }
+}
+impl FromValue for DigitalWatchfaceLayout {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DigitalWatchfaceLayout, value).map(Self::from)
+    }
+}
+impl FromValue for DisplayHeart {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DisplayHeart, value).map(Self::from)
+    }
+}
+impl FromValue for DisplayMeasure {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DisplayMeasure, value).map(Self::from)
+    }
+}
+impl FromValue for DisplayOrientation {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DisplayOrientation, value).map(Self::from)
+    }
+}
+impl FromValue for DisplayPosition {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DisplayPosition, value).map(Self::from)
+    }
+}
+impl FromValue for DisplayPower {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DisplayPower, value).map(Self::from)
+    }
+}
+impl FromValue for DiveAlarmType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DiveAlarmType, value).map(Self::from)
+    }
+}
+impl FromValue for DiveAlert {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DiveAlert, value).map(Self::from)
+    }
+}
+impl FromValue for DiveBacklightMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DiveBacklightMode, value).map(Self::from)
+    }
+}
+impl FromValue for DiveGasMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DiveGasMode, value).map(Self::from)
+    }
+}
+impl FromValue for DiveGasStatus {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::DiveGasStatus, value).map(Self::from)
+    }
+}
+impl FromValue for Event {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Event, value).map(Self::from)
+    }
+}
+impl FromValue for EventType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::EventType, value).map(Self::from)
+    }
+}
+impl FromValue for ExdDataUnits {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::ExdDataUnits, value).map(Self::from)
+    }
+}
+impl FromValue for ExdDescriptors {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::ExdDescriptors, value).map(Self::from)
+    }
+}
+impl FromValue for ExdDisplayType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::ExdDisplayType, value).map(Self::from)
+    }
+}
+impl FromValue for ExdLayout {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::ExdLayout, value).map(Self::from)
+    }
+}
+impl FromValue for ExdQualifiers {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::ExdQualifiers, value).map(Self::from)
+    }
+}
+impl FromValue for ExerciseCategory {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::ExerciseCategory, value).map(Self::from)
+    }
+}
+impl FromValue for FaveroProduct {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::FaveroProduct, value).map(Self::from)
+    }
+}
+impl FromValue for File {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::File, value).map(Self::from)
+    }
+}
+impl FromValue for FileFlags {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::FileFlags, value).map(Self::from)
+    }
+}
+impl FromValue for FitBaseType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::FitBaseType, value).map(Self::from)
+    }
+}
+impl FromValue for FitBaseUnit {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::FitBaseUnit, value).map(Self::from)
+    }
+}
+impl FromValue for FitnessEquipmentState {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::FitnessEquipmentState, value).map(Self::from)
+    }
+}
+impl FromValue for GarminProduct {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::GarminProduct, value).map(Self::from)
+    }
+}
+impl FromValue for GasConsumptionRateType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::GasConsumptionRateType, value).map(Self::from)
+    }
+}
+impl FromValue for Gender {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Gender, value).map(Self::from)
+    }
+}
+impl FromValue for Goal {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Goal, value).map(Self::from)
+    }
+}
+impl FromValue for GoalRecurrence {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::GoalRecurrence, value).map(Self::from)
+    }
+}
+impl FromValue for GoalSource {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::GoalSource, value).map(Self::from)
+    }
+}
+impl FromValue for HrType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::HrType, value).map(Self::from)
+    }
+}
+impl FromValue for HrZoneCalc {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::HrZoneCalc, value).map(Self::from)
+    }
+}
+impl FromValue for HrvStatus {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::HrvStatus, value).map(Self::from)
+    }
+}
+impl FromValue for Intensity {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Intensity, value).map(Self::from)
+    }
+}
+impl FromValue for Language {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Language, value).map(Self::from)
+    }
+}
+impl FromValue for LapTrigger {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::LapTrigger, value).map(Self::from)
+    }
+}
+impl FromValue for LeftRightBalance {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::LeftRightBalance, value).map(Self::from)
+    }
+}
+impl FromValue for LeftRightBalance100 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::LeftRightBalance100, value).map(Self::from)
+    }
+}
+impl FromValue for LengthType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::LengthType, value).map(Self::from)
+    }
+}
+impl FromValue for LocalDeviceType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::LocalDeviceType, value).map(Self::from)
+    }
+}
+impl FromValue for Manufacturer {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Manufacturer, value).map(Self::from)
+    }
+}
+impl FromValue for MaxMetCategory {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::MaxMetCategory, value).map(Self::from)
+    }
+}
+impl FromValue for MaxMetHeartRateSource {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::MaxMetHeartRateSource, value).map(Self::from)
+    }
+}
+impl FromValue for MaxMetSpeedSource {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::MaxMetSpeedSource, value).map(Self::from)
+    }
+}
+impl FromValue for MesgCount {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::MesgCount, value).map(Self::from)
+    }
+}
+impl FromValue for MesgNum {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::MesgNum, value).map(Self::from)
+    }
+}
+impl FromValue for MessageIndex {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::MessageIndex, value).map(Self::from)
+    }
+}
+impl FromValue for NoFlyTimeMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::NoFlyTimeMode, value).map(Self::from)
+    }
+}
+impl FromValue for ProjectileType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::ProjectileType, value).map(Self::from)
+    }
+}
+impl FromValue for PwrZoneCalc {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::PwrZoneCalc, value).map(Self::from)
+    }
+}
+impl FromValue for RadarThreatLevelType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::RadarThreatLevelType, value).map(Self::from)
+    }
+}
+impl FromValue for RiderPositionType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::RiderPositionType, value).map(Self::from)
+    }
+}
+impl FromValue for Schedule {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Schedule, value).map(Self::from)
+    }
+}
+impl FromValue for SegmentDeleteStatus {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SegmentDeleteStatus, value).map(Self::from)
+    }
+}
+impl FromValue for SegmentLapStatus {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SegmentLapStatus, value).map(Self::from)
+    }
+}
+impl FromValue for SegmentLeaderboardType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SegmentLeaderboardType, value).map(Self::from)
+    }
+}
+impl FromValue for SegmentSelectionType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SegmentSelectionType, value).map(Self::from)
+    }
+}
+impl FromValue for SensorType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SensorType, value).map(Self::from)
+    }
+}
+impl FromValue for SessionTrigger {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SessionTrigger, value).map(Self::from)
+    }
+}
+impl FromValue for SetType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SetType, value).map(Self::from)
+    }
+}
+impl FromValue for Side {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Side, value).map(Self::from)
+    }
+}
+impl FromValue for SleepLevel {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SleepLevel, value).map(Self::from)
+    }
+}
+impl FromValue for SourceType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SourceType, value).map(Self::from)
+    }
+}
+impl FromValue for SplitType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SplitType, value).map(Self::from)
+    }
+}
+impl FromValue for Spo2MeasurementType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Spo2MeasurementType, value).map(Self::from)
+    }
+}
+impl FromValue for Sport {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Sport, value).map(Self::from)
+    }
+}
+impl FromValue for SportBits0 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SportBits0, value).map(Self::from)
+    }
+}
+impl FromValue for SportEvent {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SportEvent, value).map(Self::from)
+    }
+}
+impl FromValue for StrokeType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::StrokeType, value).map(Self::from)
+    }
+}
+impl FromValue for SubSport {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SubSport, value).map(Self::from)
+    }
+}
+impl FromValue for SwimStroke {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::SwimStroke, value).map(Self::from)
+    }
+}
+impl FromValue for Switch {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Switch, value).map(Self::from)
+    }
+}
+impl FromValue for TapSensitivity {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::TapSensitivity, value).map(Self::from)
+    }
+}
+impl FromValue for TimeMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::TimeMode, value).map(Self::from)
+    }
+}
+impl FromValue for TimerTrigger {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::TimerTrigger, value).map(Self::from)
+    }
+}
+impl FromValue for TissueModelType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::TissueModelType, value).map(Self::from)
+    }
+}
+impl FromValue for Tone {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Tone, value).map(Self::from)
+    }
+}
+impl FromValue for UserLocalId {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::UserLocalId, value).map(Self::from)
+    }
+}
+impl FromValue for WatchfaceMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::WatchfaceMode, value).map(Self::from)
+    }
+}
+impl FromValue for WaterType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::WaterType, value).map(Self::from)
+    }
+}
+impl FromValue for WeatherReport {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::WeatherReport, value).map(Self::from)
+    }
+}
+impl FromValue for WeatherSevereType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::WeatherSevereType, value).map(Self::from)
+    }
+}
+impl FromValue for WeatherSeverity {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::WeatherSeverity, value).map(Self::from)
+    }
+}
+impl FromValue for WeatherStatus {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::WeatherStatus, value).map(Self::from)
+    }
+}
+impl FromValue for Weight {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::Weight, value).map(Self::from)
+    }
+}
+impl FromValue for WktStepDuration {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::WktStepDuration, value).map(Self::from)
+    }
+}
+impl FromValue for WktStepTarget {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::WktStepTarget, value).map(Self::from)
+    }
+}
+impl FromValue for WorkoutCapabilities {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::WorkoutCapabilities, value).map(Self::from)
+    }
+}
+impl FromValue for WorkoutEquipment {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::WorkoutEquipment, value).map(Self::from)
+    }
+}
+impl FromValue for WorkoutHr {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::WorkoutHr, value).map(Self::from)
+    }
+}
+impl FromValue for WorkoutPower {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_value(FieldDataType::WorkoutPower, value).map(Self::from)
+    }
+}
@@ -1,18 +1,60 @@
 //! Defines the FIT profile used to convert raw parser output into final values that can be
 //! interpreted without using the FIT profile.
-use crate::de::DecodeOption;
+use crate::de::{BaseType, DecodeOption};
 use crate::error::{ErrorKind, Result};
-use crate::{FitDataField, Value};
-use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone};
-use std::collections::{HashMap, HashSet};
+use crate::geo::semicircles_to_degrees;
+use crate::ser::FIT_EPOCH_OFFSET;
+use crate::timestamp::{self, LocalTimestamp, Timestamp, UnixSeconds};
+use crate::{FitDataField, FitDataRecord, Value};
+#[cfg(not(compact_profile))]
+use serde::de::{self, Unexpected, Visitor};
+use std::collections::HashSet;
 use std::convert::TryInto;
-use std::f64::EPSILON;
+use std::fmt;
+#[cfg(not(compact_profile))]
+use std::sync::{PoisonError, RwLock};
 
 pub mod field_types;
-pub use field_types::{get_field_variant_as_string, FieldDataType, MesgNum};
+#[cfg(not(compact_profile))]
+pub use field_types::get_field_variant_as_string;
+pub use field_types::{FieldDataType, MesgNum};
 
 pub mod decode;
-pub use decode::VERSION;
+pub use decode::{PROFILE_VERSION, VERSION};
+
+pub mod encode;
+pub use encode::{field_info, field_name, subfields};
+
+pub mod field_numbers;
+
+mod message_groups;
+
+pub mod messages;
+
+pub mod projection;
+pub use projection::{MissingValue, Projection};
+
+pub mod units;
+pub use units::{Unit, UnitCategory};
+
+/// A compact profile has no message names, records still need their kind displayed in errors and
+/// serialized, so it's their global message number
+#[cfg(compact_profile)]
+impl fmt::Display for MesgNum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_u16())
+    }
+}
+
+#[cfg(compact_profile)]
+impl serde::Serialize for MesgNum {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u16(self.as_u16())
+    }
+}
 
 impl Value {
     /// Convert the value into a vector of bytes
@@ -27,7 +69,10 @@ impl Value {
             Value::SInt32(val) => val.to_ne_bytes().to_vec(),
             Value::UInt32(val) => val.to_ne_bytes().to_vec(),
             Value::String(val) => val.as_bytes().to_vec(),
-            Value::Timestamp(val) => val.timestamp().to_ne_bytes().to_vec(),
+            Value::Timestamp(val) => val.unix_seconds().to_ne_bytes().to_vec(),
+            Value::UtcTimestamp(val) => val.unix_seconds().to_ne_bytes().to_vec(),
+            Value::LocalTimestamp(val) => val.unix_seconds().to_ne_bytes().to_vec(),
+            Value::Duration(val) => val.as_secs_f64().to_ne_bytes().to_vec(),
             Value::Float32(val) => val.to_ne_bytes().to_vec(),
             Value::Float64(val) => val.to_ne_bytes().to_vec(),
             Value::UInt8z(val) => vec![*val],
@@ -41,6 +86,402 @@ impl Value {
     }
 }
 
+/// Profile definition of a field, used to turn a decoded value back into the value stored in a
+/// FIT file
+#[derive(Clone, Copy, Debug)]
+pub struct FieldInfo {
+    /// Field definition number, subfields use the number of the field they are part of
+    pub number: u8,
+    /// Type the value is decoded as
+    pub field_type: FieldDataType,
+    /// FIT base type id the field is defined with
+    pub base_type: u8,
+    /// Scale applied to the stored value
+    pub scale: f64,
+    /// Offset applied to the stored value
+    pub offset: f64,
+    /// Units of the decoded value
+    pub units: &'static str,
+}
+
+impl FieldInfo {
+    pub(crate) const fn new(
+        number: u8,
+        field_type: FieldDataType,
+        base_type: u8,
+        scale: f64,
+        offset: f64,
+        units: &'static str,
+    ) -> Self {
+        FieldInfo {
+            number,
+            field_type,
+            base_type,
+            scale,
+            offset,
+            units,
+        }
+    }
+}
+
+/// Alternative interpretation of a field, active while another field of the message, the
+/// reference field, holds a specific value. E.g. the `data` field of an `event` message is
+/// `gear_change_data` while its `event` field is `rear_gear_change`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SubField {
+    /// Name the field is decoded with while the subfield is active
+    pub name: &'static str,
+    /// Definition number of the reference field
+    pub reference: u8,
+    /// Code of the reference value activating the subfield
+    pub value: i64,
+}
+
+/// Subfield of a field that is active in a record, the first subfield whose reference field
+/// holds its reference value
+pub fn active_subfield(record: &FitDataRecord, number: u8) -> Option<&'static SubField> {
+    let kind = record.kind();
+    subfields(kind, number).iter().find(|sub| {
+        let reference = match record
+            .fields()
+            .iter()
+            .find(|f| f.number() == sub.reference && !f.is_developer_field() && !f.is_unknown())
+        {
+            Some(reference) => reference,
+            None => return false,
+        };
+        field_info(kind, reference.name())
+            .and_then(|info| enum_value(info.field_type, reference.value()))
+            == Some(sub.value)
+    })
+}
+
+/// Rename and rescale the fields of a record to the subfields that are active, like the decoder
+/// does. This is needed for records that didn't come from the decoder or were edited since, e.g.
+/// records built with the generic name of a field, read back from JSON or whose reference field
+/// was changed. Values are interpreted by the name of their field and converted through the
+/// value stored in the file, fields without an active subfield get the name of the field they
+/// are part of. Values that can't be converted, e.g. strings, are left as they are.
+pub fn resolve_subfields(record: &mut FitDataRecord) {
+    let kind = record.kind();
+    let mut resolved = Vec::new();
+    for (index, field) in record.fields().iter().enumerate() {
+        if field.is_developer_field() || field.is_unknown() {
+            continue;
+        }
+        let number = field.number();
+        if subfields(kind, number).is_empty() {
+            continue;
+        }
+        let name = match active_subfield(record, number) {
+            Some(sub) => sub.name,
+            None => match field_name(kind, number) {
+                Some(name) => name,
+                None => continue,
+            },
+        };
+        if name == field.name() {
+            continue;
+        }
+        let (from, to) = match (field_info(kind, field.name()), field_info(kind, name)) {
+            (Some(from), Some(to)) => (from, to),
+            _ => continue,
+        };
+        if let Some(value) = subfield_value(field.value(), &from, &to) {
+            let mut subfield =
+                FitDataField::new(name.to_string(), number, value, to.units.to_string());
+            subfield.set_raw_value(field.raw_value().cloned());
+            resolved.push((index, subfield));
+        }
+    }
+    for (index, field) in resolved {
+        record.fields_mut()[index] = field;
+    }
+}
+
+/// Convert a value decoded as one interpretation of a field into another, going through the
+/// value stored in the file
+fn subfield_value(value: &Value, from: &FieldInfo, to: &FieldInfo) -> Option<Value> {
+    let raw = match value {
+        Value::Array(values) => {
+            return values
+                .iter()
+                .map(|v| subfield_value(v, from, to))
+                .collect::<Option<_>>()
+                .map(Value::Array)
+        }
+        Value::String(_) if from.field_type.is_enum_type() => {
+            enum_value(from.field_type, value)? as f64
+        }
+        Value::Duration(duration) => {
+            (duration.as_secs_f64() / seconds_per_unit(from.units)? + from.offset) * from.scale
+        }
+        Value::String(_)
+        | Value::Timestamp(_)
+        | Value::UtcTimestamp(_)
+        | Value::LocalTimestamp(_) => return Some(value.clone()),
+        _ => (TryInto::<f64>::try_into(value.clone()).ok()? + from.offset) * from.scale,
+    };
+    if to.field_type.is_enum_type() {
+        return Some(variant_to_value(to.field_type, raw.round() as i64));
+    }
+    if let (Value::Duration(_), Some(seconds_per_unit)) = (value, seconds_per_unit(to.units)) {
+        let seconds = (raw / to.scale - to.offset) * seconds_per_unit;
+        return std::time::Duration::try_from_secs_f64(seconds)
+            .ok()
+            .map(Value::Duration);
+    }
+    if (to.scale - 1.0).abs() > f64::EPSILON || to.offset.abs() > f64::EPSILON {
+        Some(Value::Float64(raw / to.scale - to.offset))
+    } else {
+        Value::Float64(raw.round())
+            .coerce_to(BaseType::from(to.base_type))
+            .ok()
+    }
+}
+
+/// Value of a variant of a profile type as decoding returns it, its name if it has one
+#[cfg_attr(compact_profile, allow(unused_variables))]
+pub(crate) fn variant_to_value(field_type: FieldDataType, code: i64) -> Value {
+    #[cfg(not(compact_profile))]
+    if field_type.is_named_variant(code) {
+        return Value::String(get_field_variant_as_string(field_type, code));
+    }
+    Value::SInt64(code)
+}
+
+/// Code of a named variant looked up by the name of its type in the FIT profile, e.g.
+/// `variant_code("sport", "cycling")` is `Some(2)`. Storing `(type_name, code)` pairs keeps
+/// them valid across releases, even when the Rust identifiers generated for a type change.
+#[cfg(not(compact_profile))]
+pub fn variant_code(type_name: &str, variant_name: &str) -> Option<i64> {
+    FieldDataType::from_type_name(type_name)?.variant_value(variant_name)
+}
+
+/// Name of the variant with the code, looked up by the name of its type in the FIT profile.
+/// `None` if the type doesn't exist or the code has no name.
+#[cfg(not(compact_profile))]
+pub fn variant_name(type_name: &str, code: i64) -> Option<String> {
+    let field_type = FieldDataType::from_type_name(type_name)?;
+    field_type
+        .is_named_variant(code)
+        .then(|| get_field_variant_as_string(field_type, code))
+}
+
+/// Function told about a variant name that a type of the profile doesn't define, called with the
+/// profile name of the type and the unknown name
+#[cfg(not(compact_profile))]
+pub type UnknownVariantHook = fn(type_name: &str, name: &str);
+
+#[cfg(not(compact_profile))]
+static UNKNOWN_VARIANT_HOOK: RwLock<Option<UnknownVariantHook>> = RwLock::new(None);
+
+/// Install a hook called whenever an unknown variant name is deserialized, e.g. to log JSON that
+/// was written with a newer profile. `None` removes the hook.
+#[cfg(not(compact_profile))]
+pub fn set_unknown_variant_hook(hook: Option<UnknownVariantHook>) {
+    *UNKNOWN_VARIANT_HOOK
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = hook;
+}
+
+#[cfg(not(compact_profile))]
+fn unknown_variant(field_type: FieldDataType, name: &str) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(
+        type_name = field_type.type_name(),
+        name,
+        "unknown variant name"
+    );
+    let hook = *UNKNOWN_VARIANT_HOOK
+        .read()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some(hook) = hook {
+        hook(field_type.type_name(), name);
+    }
+}
+
+/// Deserialize the value of a profile enum from either its variant name or its number. Names the
+/// profile doesn't define are reported to the unknown variant hook and become the invalid value
+/// so a document written with a newer profile still loads.
+#[cfg(not(compact_profile))]
+pub(crate) fn deserialize_variant<'de, D>(
+    deserializer: D,
+    field_type: FieldDataType,
+    invalid: i64,
+) -> std::result::Result<i64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct VariantVisitor(FieldDataType, i64);
+
+    impl<'de> Visitor<'de> for VariantVisitor {
+        type Value = i64;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a {} variant name or value", self.0.type_name())
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> std::result::Result<i64, E> {
+            Ok(value)
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> std::result::Result<i64, E> {
+            value
+                .try_into()
+                .map_err(|_| E::invalid_value(Unexpected::Unsigned(value), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, name: &str) -> std::result::Result<i64, E> {
+            Ok(self.0.variant_value(name).unwrap_or_else(|| {
+                unknown_variant(self.0, name);
+                self.1
+            }))
+        }
+    }
+
+    deserializer.deserialize_any(VariantVisitor(field_type, invalid))
+}
+
+/// Conversion of a decoded value into the type of a field of a typed message
+pub(crate) trait FromValue: Sized {
+    /// Convert the value, `None` if it doesn't fit into the type
+    fn from_value(value: &Value) -> Option<Self>;
+}
+
+macro_rules! integer_from_value {
+    ($($typ:ty),*) => {
+        $(impl FromValue for $typ {
+            fn from_value(value: &Value) -> Option<Self> {
+                let val: i64 = value.try_into().ok()?;
+                val.try_into().ok()
+            }
+        })*
+    };
+}
+
+integer_from_value!(i8, u8, i16, u16, i32, u32, i64);
+
+impl FromValue for u64 {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::UInt64(val) | Value::UInt64z(val) => Some(*val),
+            _ => i64::from_value(value)?.try_into().ok(),
+        }
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: &Value) -> Option<Self> {
+        i64::from_value(value).map(|val| val != 0)
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Timestamp(_)
+            | Value::UtcTimestamp(_)
+            | Value::LocalTimestamp(_)
+            | Value::String(_)
+            | Value::Array(_) => None,
+            _ => value.clone().try_into().ok(),
+        }
+    }
+}
+
+impl FromValue for f32 {
+    fn from_value(value: &Value) -> Option<Self> {
+        f64::from_value(value).map(|val| val as f32)
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(val) => Some(val.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromValue for Timestamp {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Timestamp(val) => Some(*val),
+            Value::UtcTimestamp(val) => Some(timestamp::utc_to_local(*val)),
+            Value::LocalTimestamp(val) => timestamp::from_wall_clock(val),
+            _ => None,
+        }
+    }
+}
+
+impl FromValue for LocalTimestamp {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Timestamp(val) => Some(timestamp::to_wall_clock(val)),
+            Value::LocalTimestamp(val) => Some(*val),
+            _ => None,
+        }
+    }
+}
+
+/// Array fields also accept a single value, a FIT file may store an array of length one
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Array(vals) => vals.iter().map(T::from_value).collect(),
+            _ => T::from_value(value).map(|val| vec![val]),
+        }
+    }
+}
+
+/// Value of a variant of a profile type, decoded either as its name or its number
+#[cfg_attr(compact_profile, allow(unused_variables))]
+pub(crate) fn enum_value(field_type: FieldDataType, value: &Value) -> Option<i64> {
+    match value {
+        #[cfg(not(compact_profile))]
+        Value::String(name) => field_type.variant_value(name),
+        _ => value.try_into().ok(),
+    }
+}
+
+/// Fields of a record defined by the profile, fails if the record is a different message
+pub(crate) fn profile_fields(
+    record: &FitDataRecord,
+    kind: MesgNum,
+) -> Result<impl Iterator<Item = &FitDataField>> {
+    if record.kind() != kind {
+        return Err(ErrorKind::ValueError(format!(
+            "a {} message can't be converted into a {} message",
+            record.kind(),
+            kind
+        ))
+        .into());
+    }
+    Ok(record
+        .fields()
+        .iter()
+        .filter(|f| !f.is_developer_field() && !f.is_unknown()))
+}
+
+/// Value of a field converted into the type of a typed message field
+pub(crate) fn field_value<T: FromValue>(
+    record: &FitDataRecord,
+    field: &FitDataField,
+) -> Result<Option<T>> {
+    match T::from_value(field.value()) {
+        Some(val) => Ok(Some(val)),
+        None => Err(ErrorKind::ValueError(format!(
+            "value {} of field '{}' of message {} has an unexpected type",
+            field.value(),
+            field.name(),
+            record.kind()
+        ))
+        .into()),
+    }
+}
+
 /// Stores the timestamp offset from the FIT reference date in seconds
 #[derive(Debug, Copy, Clone)]
 pub enum TimestampField {
@@ -60,20 +501,42 @@ impl TimestampField {
     }
 
     /// converts offset value into a proper timestamp
-    fn to_date_time(self) -> DateTime<Local> {
+    fn to_date_time(self) -> Timestamp {
         // reference date defined in FIT profile, it's either in UTC or local TZ
-        let ref_date = NaiveDate::from_ymd_opt(1989, 12, 31)
-            .map(|d: NaiveDate| d.and_hms_opt(0, 0, 0))
-            .flatten()
-            .unwrap();
         match self {
             Self::Local(value) => {
-                TimeZone::from_local_datetime(&Local, &ref_date).unwrap() + Duration::seconds(value)
-            }
-            Self::Utc(value) => {
-                TimeZone::from_utc_datetime(&Local, &ref_date) + Duration::seconds(value)
+                let time = timestamp::wall_clock(value + FIT_EPOCH_OFFSET);
+                timestamp::from_wall_clock(&time).unwrap()
             }
+            Self::Utc(value) => timestamp::from_fit_seconds(value),
+        }
+    }
+}
+
+/// Value of a `date_time` field, kept in UTC with `DecodeOption::UtcTimestamps`
+pub(crate) fn date_time(seconds: i64, options: &HashSet<DecodeOption>) -> Value {
+    let time = TimestampField::Utc(seconds).to_date_time();
+    if options.contains(&DecodeOption::UtcTimestamps) {
+        Value::UtcTimestamp(timestamp::to_utc(&time))
+    } else {
+        Value::Timestamp(time)
+    }
+}
+
+/// Value of a `local_date_time` field, interpreted at the offset of
+/// `DecodeOption::LocalTimeOffset` or returned as wall clock time with
+/// `DecodeOption::NaiveLocalTimes`, in the local time zone of the machine otherwise
+fn local_date_time(seconds: i64, options: &HashSet<DecodeOption>) -> Value {
+    let offset = options.iter().find_map(|option| match option {
+        DecodeOption::LocalTimeOffset(offset) => Some(*offset),
+        _ => None,
+    });
+    match offset {
+        Some(offset) => Value::Timestamp(timestamp::from_fit_seconds(seconds - offset as i64)),
+        None if options.contains(&DecodeOption::NaiveLocalTimes) => {
+            Value::LocalTimestamp(timestamp::wall_clock(seconds + FIT_EPOCH_OFFSET))
         }
+        None => Value::from(TimestampField::Local(seconds)),
     }
 }
 
@@ -109,101 +572,10 @@ fn extract_component(input: &[u8], mut offset: usize, nbits: usize) -> ((&[u8],
     }
 }
 
-/// Increment the stored field value
-pub fn calculate_cumulative_value(
-    accumulate_fields: &mut HashMap<u32, Value>,
-    msg_num: u16,
-    def_num: u8,
-    value: Value,
-) -> Result<Value> {
-    // use macro to duplicate same type only addition logic
-    macro_rules! only_add_like_values {
-        ($key:ident, $val:ident, $stored_value:ident, $variant:ident) => {
-            if let Value::$variant(other) = value {
-                let value = Value::$variant($val + other);
-                accumulate_fields.insert($key, value.clone());
-                Ok(value)
-            } else {
-                Err(ErrorKind::ValueError(format!(
-                    "Mixed type addition {} and {} cannot be combined",
-                    $stored_value, value
-                ))
-                .into())
-            }
-        };
-    }
-
-    let key = (msg_num as u32) << 8 | def_num as u32;
-    if let Some(stored_value) = accumulate_fields.get(&key) {
-        match stored_value {
-            Value::Timestamp(_) => {
-                // TODO: fix this, probably done as u32 math but I probably need to keep timestamps
-                // as u32 values until the "11th hour" so to speak to deal with them more easily.
-                Err(ErrorKind::ValueError("Cannot accumlate timestamp fields".to_string()).into())
-            }
-            Value::Byte(val) => only_add_like_values!(key, val, stored_value, Byte),
-            Value::Enum(_) => {
-                Err(ErrorKind::ValueError("Cannot accumlate enum fields".to_string()).into())
-            }
-            Value::SInt8(val) => only_add_like_values!(key, val, stored_value, SInt8),
-            Value::UInt8(val) => only_add_like_values!(key, val, stored_value, UInt8),
-            Value::UInt8z(val) => only_add_like_values!(key, val, stored_value, UInt8z),
-            Value::SInt16(val) => only_add_like_values!(key, val, stored_value, SInt16),
-            Value::UInt16(val) => only_add_like_values!(key, val, stored_value, UInt16),
-            Value::UInt16z(val) => only_add_like_values!(key, val, stored_value, UInt16z),
-            Value::SInt32(val) => only_add_like_values!(key, val, stored_value, SInt32),
-            Value::UInt32(val) => only_add_like_values!(key, val, stored_value, UInt32),
-            Value::UInt32z(val) => only_add_like_values!(key, val, stored_value, UInt32z),
-            Value::SInt64(val) => only_add_like_values!(key, val, stored_value, SInt64),
-            Value::UInt64(val) => only_add_like_values!(key, val, stored_value, UInt64),
-            Value::UInt64z(val) => only_add_like_values!(key, val, stored_value, UInt64z),
-            Value::Float32(val) => only_add_like_values!(key, val, stored_value, Float32),
-            Value::Float64(val) => only_add_like_values!(key, val, stored_value, Float64),
-            Value::String(_) => {
-                Err(ErrorKind::ValueError("Cannot accumlate string fields".to_string()).into())
-            }
-            // add arrays by value if they are equal in length, we'll have to find FIT files
-            // to validate this behavior against the SDK. The Java SDK also always does this
-            // with longs, which makes sense I supppose since floats are large enough to store
-            // the explicit value so were always going to be working with integers for accumlated
-            // fields.
-            Value::Array(vals) => {
-                if let Value::Array(other_vals) = value {
-                    if vals.len() == other_vals.len() {
-                        let mut new_vals = Vec::with_capacity(vals.len());
-                        for (v1, v2) in vals.iter().zip(other_vals.iter()) {
-                            let v1_i64: i64 = v1.try_into()?;
-                            let v2_i64: i64 = v2.try_into()?;
-                            new_vals.push(Value::SInt64(v1_i64 + v2_i64));
-                        }
-                        accumulate_fields.insert(key, Value::Array(new_vals.clone()));
-                        Ok(Value::Array(new_vals))
-                    } else {
-                        Err(ErrorKind::ValueError(format!(
-                            "Array lengths differ, {} != {}",
-                            vals.len(),
-                            other_vals.len()
-                        ))
-                        .into())
-                    }
-                } else {
-                    Err(ErrorKind::ValueError(format!(
-                        "Mixed type addition {} and {} cannot be combined",
-                        stored_value, value
-                    ))
-                    .into())
-                }
-            }
-        }
-    } else {
-        accumulate_fields.insert(key, value.clone());
-        Ok(value)
-    }
-}
-
 /// Build a data field using the provided FIT profile information
 #[allow(clippy::too_many_arguments)]
 pub fn data_field_with_info(
+    mesg_num: MesgNum,
     def_number: u8,
     name: &str,
     data_type: FieldDataType,
@@ -213,27 +585,98 @@ pub fn data_field_with_info(
     value: Value,
     options: &HashSet<DecodeOption>,
 ) -> Result<FitDataField> {
+    let is_raw = options.contains(&DecodeOption::SkipScaleAndOffset(mesg_num, def_number));
+    let (scale, offset) = if is_raw { (1.0, 0.0) } else { (scale, offset) };
+    let raw_value = options
+        .contains(&DecodeOption::KeepRawValues)
+        .then(|| value.clone());
     let value = convert_value(data_type, scale, offset, value, options)?;
-    Ok(FitDataField::new(
-        name.to_string(),
-        def_number,
-        value,
-        units.to_string(),
-    ))
+    let duration = if options.contains(&DecodeOption::ReturnDurations) && !is_raw {
+        duration_value(&value, units)
+    } else {
+        None
+    };
+    let degrees = options.contains(&DecodeOption::ReturnDegrees) && !is_raw;
+    let mut field = match duration {
+        Some(duration) => {
+            FitDataField::new(name.to_string(), def_number, duration, "s".to_string())
+        }
+        None if degrees && units == "semicircles" => FitDataField::new(
+            name.to_string(),
+            def_number,
+            degrees_value(value),
+            "degrees".to_string(),
+        ),
+        None => FitDataField::new(name.to_string(), def_number, value, units.to_string()),
+    };
+    field.set_raw_value(raw_value);
+    Ok(field)
+}
+
+/// Number of seconds in a unit measuring a span of time
+pub(crate) fn seconds_per_unit(units: &str) -> Option<f64> {
+    match units {
+        "s" => Some(1.0),
+        "ms" => Some(0.001),
+        _ => None,
+    }
+}
+
+/// Convert a value measured in the units into a duration, `None` if the units aren't seconds or
+/// milliseconds or the value can't be a duration, e.g. a negative offset
+fn duration_value(value: &Value, units: &str) -> Option<Value> {
+    let seconds_per_unit = seconds_per_unit(units)?;
+    match value {
+        Value::Array(values) => values
+            .iter()
+            .map(|v| duration_value(v, units))
+            .collect::<Option<_>>()
+            .map(Value::Array),
+        Value::String(_)
+        | Value::Timestamp(_)
+        | Value::UtcTimestamp(_)
+        | Value::LocalTimestamp(_)
+        | Value::Duration(_) => None,
+        _ => {
+            let seconds = TryInto::<f64>::try_into(value.clone()).ok()? * seconds_per_unit;
+            std::time::Duration::try_from_secs_f64(seconds)
+                .ok()
+                .map(Value::Duration)
+        }
+    }
+}
+
+/// Convert a value measured in semicircles into decimal degrees, values that aren't numbers are
+/// kept as they are
+fn degrees_value(value: Value) -> Value {
+    match value {
+        Value::Array(values) => Value::Array(values.into_iter().map(degrees_value).collect()),
+        Value::String(_)
+        | Value::Timestamp(_)
+        | Value::UtcTimestamp(_)
+        | Value::LocalTimestamp(_)
+        | Value::Duration(_) => value,
+        _ => match TryInto::<f64>::try_into(value.clone()) {
+            Ok(val) => Value::Float64(semicircles_to_degrees(val)),
+            Err(_) => value,
+        },
+    }
 }
 
 /// Create an "unknown" field as a placeholder if we don't have any field information
 pub fn unknown_field(field_def_num: u8, value: Value) -> FitDataField {
-    FitDataField::new(
+    let mut field = FitDataField::new(
         format!("unknown_field_{}", field_def_num),
         field_def_num,
         value,
         String::new(),
-    )
+    );
+    field.set_unknown(true);
+    field
 }
 
 /// Applies any necessary value conversions based on the field specification
-fn convert_value(
+pub(crate) fn convert_value(
     field_type: FieldDataType,
     scale: f64,
     offset: f64,
@@ -253,14 +696,10 @@ fn convert_value(
     // just dump the reference timestamp by passing it a 0
     match field_type {
         FieldDataType::DateTime => {
-            return Ok(Value::from(TimestampField::Utc(
-                value.try_into().unwrap_or(0),
-            )));
+            return Ok(date_time(value.try_into().unwrap_or(0), options));
         }
         FieldDataType::LocalDateTime => {
-            return Ok(Value::from(TimestampField::Local(
-                value.try_into().unwrap_or(0),
-            )));
+            return Ok(local_date_time(value.try_into().unwrap_or(0), options));
         }
         _ => (),
     }
@@ -270,10 +709,8 @@ fn convert_value(
         let val: i64 = value.try_into()?;
         if options.contains(&DecodeOption::ReturnNumericEnumValues) {
             Ok(Value::SInt64(val))
-        } else if field_type.is_named_variant(val) {
-            Ok(Value::String(get_field_variant_as_string(field_type, val)))
         } else {
-            Ok(Value::SInt64(val))
+            Ok(variant_to_value(field_type, val))
         }
     } else {
         apply_scale_and_offset(value, scale, offset)
@@ -281,10 +718,334 @@ fn convert_value(
 }
 
 fn apply_scale_and_offset(value: Value, scale: f64, offset: f64) -> Result<Value> {
-    if ((scale - 1.0).abs() > EPSILON) || ((offset - 0.0).abs() > EPSILON) {
+    if ((scale - 1.0).abs() > f64::EPSILON) || ((offset - 0.0).abs() > f64::EPSILON) {
         let val: f64 = value.try_into()?;
         Ok(Value::Float64(val / scale - offset))
     } else {
         Ok(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::field_types::Sport;
+
+    #[test]
+    #[cfg(not(compact_profile))]
+    fn variant_registry() {
+        assert_eq!(Sport::Cycling.code(), 2);
+        assert_eq!(Sport::from(Sport::Running.code()), Sport::Running);
+        assert_eq!(
+            variant_code("sport", "cycling"),
+            Some(Sport::Cycling.code())
+        );
+        assert_eq!(variant_name("sport", 2).as_deref(), Some("cycling"));
+        assert_eq!(variant_name("sport", 250), None);
+        assert_eq!(variant_code("no_such_type", "cycling"), None);
+        assert_eq!(
+            variant_code("mesg_num", "record"),
+            Some(MesgNum::Record.code())
+        );
+        for field_type in [FieldDataType::UInt8, FieldDataType::LanguageBits0] {
+            let name = field_type.type_name();
+            assert_eq!(
+                FieldDataType::from_type_name(name).map(FieldDataType::type_name),
+                Some(name)
+            );
+        }
+    }
+
+    #[test]
+    fn typed_messages() {
+        use crate::profile::messages::{RecordMessage, SessionMessage};
+        use std::convert::TryFrom;
+
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let records = crate::from_bytes(data).unwrap();
+        let record = records.iter().find(|r| r.is_kind(MesgNum::Record)).unwrap();
+        let message = RecordMessage::try_from(record).unwrap();
+        assert_eq!(
+            message.heart_rate.map(Value::UInt8).as_ref(),
+            record.field("heart_rate").map(FitDataField::value)
+        );
+        assert_eq!(
+            message.timestamp.map(Value::Timestamp).as_ref(),
+            record.field("timestamp").map(FitDataField::value)
+        );
+        assert!(message.distance.is_some());
+
+        let session = records
+            .iter()
+            .find(|r| r.is_kind(MesgNum::Session))
+            .unwrap();
+        let message = SessionMessage::try_from(session).unwrap();
+        assert_eq!(message.sport, Some(Sport::Cycling));
+        assert!(RecordMessage::try_from(session).is_err());
+
+        #[cfg(not(compact_profile))]
+        {
+            let json = serde_json::to_string(&message).unwrap();
+            assert_eq!(
+                serde_json::from_str::<SessionMessage>(&json).unwrap(),
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn duration_values() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let options = [DecodeOption::ReturnDurations].into_iter().collect();
+        let records = crate::de::from_bytes_with_options(data, &options).unwrap();
+        let plain = crate::from_bytes(data).unwrap();
+        let lap = records.iter().find(|r| r.is_kind(MesgNum::Lap)).unwrap();
+        let timer = lap.field("total_timer_time").unwrap();
+        assert!(matches!(timer.value(), Value::Duration(_)));
+        assert_eq!(timer.units(), "s");
+        let plain_lap = plain.iter().find(|r| r.is_kind(MesgNum::Lap)).unwrap();
+        let plain_timer = plain_lap.field("total_timer_time").unwrap();
+        assert_eq!(timer.duration(), plain_timer.duration());
+        assert_eq!(
+            serde_json::to_value(timer.value()).unwrap(),
+            serde_json::to_value(plain_timer.value()).unwrap()
+        );
+
+        // durations are encoded in the units of the profile
+        let encoded = crate::ser::to_bytes(&records).unwrap();
+        let decoded = crate::from_bytes(&encoded).unwrap();
+        let json = serde_json::to_string(&records).unwrap();
+        let restored: Vec<FitDataRecord> = serde_json::from_str(&json).unwrap();
+        let reencoded = crate::from_bytes(&crate::ser::to_bytes(&restored).unwrap()).unwrap();
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            serde_json::to_value(&reencoded).unwrap()
+        );
+        let lap = decoded.iter().find(|r| r.is_kind(MesgNum::Lap)).unwrap();
+        assert_eq!(
+            lap.field("total_timer_time").unwrap().value(),
+            plain_timer.value()
+        );
+    }
+
+    #[test]
+    fn degree_values() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let options = [DecodeOption::ReturnDegrees].into_iter().collect();
+        let records = crate::de::from_bytes_with_options(data, &options).unwrap();
+        let plain = crate::from_bytes(data).unwrap();
+        let (record, plain_record) = records
+            .iter()
+            .zip(&plain)
+            .find(|(r, _)| r.is_kind(MesgNum::Record) && r.field("position_lat").is_some())
+            .unwrap();
+        let lat = record.field("position_lat").unwrap();
+        assert_eq!(lat.units(), "degrees");
+        assert!(matches!(lat.value(), Value::Float64(_)));
+        assert_eq!(
+            lat.degrees(),
+            plain_record.field("position_lat").unwrap().degrees()
+        );
+        assert_eq!(
+            crate::geo::record_position(record),
+            crate::geo::record_position(plain_record)
+        );
+        assert!(records
+            .iter()
+            .flat_map(|r| r.fields())
+            .all(|f| f.units() != "semicircles"));
+
+        // degrees are encoded back into semicircles, also after a round trip through JSON
+        let json = serde_json::to_string(&records).unwrap();
+        let restored: Vec<FitDataRecord> = serde_json::from_str(&json).unwrap();
+        for records in [&records, &restored] {
+            let encoded = crate::ser::to_bytes(records).unwrap();
+            let decoded = crate::from_bytes(&encoded).unwrap();
+            let plain = crate::from_bytes(&crate::ser::to_bytes(&plain).unwrap()).unwrap();
+            assert_eq!(
+                serde_json::to_value(&decoded).unwrap(),
+                serde_json::to_value(&plain).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn raw_values() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let options = [DecodeOption::KeepRawValues].into_iter().collect();
+        let records = crate::de::from_bytes_with_options(data, &options).unwrap();
+        let record = records.iter().find(|r| r.is_kind(MesgNum::Record)).unwrap();
+        let altitude = record.field("enhanced_altitude").unwrap();
+        let raw: f64 = altitude.raw_value().unwrap().clone().try_into().unwrap();
+        assert_eq!(altitude.value(), &Value::Float64(raw / 5.0 - 500.0));
+        let sport = records.iter().find_map(|r| r.field("sport")).unwrap();
+        assert!(matches!(sport.value(), Value::String(_)));
+        assert!(matches!(sport.raw_value(), Some(Value::Enum(_))));
+        assert!(crate::from_bytes(data)
+            .unwrap()
+            .iter()
+            .flat_map(|r| r.fields())
+            .all(|f| f.raw_value().is_none()));
+
+        // the raw values are encoded as is, also after a round trip through JSON
+        let json = serde_json::to_string(&records).unwrap();
+        let restored: Vec<FitDataRecord> = serde_json::from_str(&json).unwrap();
+        for records in [&records, &restored] {
+            let encoded = crate::ser::to_bytes(records).unwrap();
+            let decoded = crate::de::from_bytes_with_options(&encoded, &options).unwrap();
+            assert_eq!(
+                serde_json::to_value(&decoded).unwrap(),
+                serde_json::to_value(records).unwrap()
+            );
+        }
+
+        let mut field = altitude.clone();
+        field.set_value(Value::Float64(100.0));
+        assert_eq!(field.raw_value(), None);
+    }
+
+    #[test]
+    #[cfg(not(compact_profile))]
+    fn deserialize_unknown_variants() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static UNKNOWN: AtomicUsize = AtomicUsize::new(0);
+
+        set_unknown_variant_hook(Some(|type_name, name| {
+            assert_eq!((type_name, name), ("sport", "underwater_cycling"));
+            UNKNOWN.fetch_add(1, Ordering::SeqCst);
+        }));
+        let sports: Vec<Sport> =
+            serde_json::from_str(r#"["cycling", 1, 200, "underwater_cycling"]"#).unwrap();
+        set_unknown_variant_hook(None);
+        assert_eq!(
+            sports,
+            [
+                Sport::Cycling,
+                Sport::Running,
+                Sport::from(200u8),
+                Sport::from(255u8)
+            ]
+        );
+        assert_eq!(UNKNOWN.load(Ordering::SeqCst), 1);
+        assert!(serde_json::from_str::<Sport>("1.5").is_err());
+    }
+
+    #[test]
+    fn message_groups() {
+        assert!(MesgNum::Session.is_session_level_message());
+        assert!(MesgNum::Session.is_activity_message());
+        assert!(!MesgNum::Record.is_session_level_message());
+        assert!(MesgNum::Monitoring.is_monitoring_message());
+        assert!(MesgNum::Hr.is_monitoring_message() && MesgNum::Hr.is_activity_message());
+        assert!(MesgNum::FieldDescription.is_developer_message());
+        assert!(!MesgNum::from(0xFF00u16).is_activity_message());
+        for &kind in MesgNum::SETTINGS_MESSAGES {
+            assert!(kind.is_settings_message());
+        }
+        assert!(MesgNum::Record.is_record_level() && !MesgNum::Record.is_session_level());
+        assert!(MesgNum::Lap.is_session_level() && !MesgNum::Lap.is_record_level());
+        assert_eq!(MesgNum::VARIANTS.len(), 122);
+        #[cfg(not(compact_profile))]
+        {
+            assert!(MesgNum::VARIANTS
+                .iter()
+                .all(|kind| MesgNum::is_named_variant(kind.as_i64())));
+            assert_eq!(
+                MesgNum::FileId.doc_string(),
+                Some("Must be first message in file.")
+            );
+            assert_eq!(MesgNum::Record.doc_string(), None);
+        }
+    }
+
+    #[test]
+    fn subfield_components() {
+        let mut file_id = FitDataRecord::new(MesgNum::FileId);
+        file_id.push(FitDataField::new(
+            "type".to_string(),
+            0,
+            Value::String("activity".to_string()),
+            String::new(),
+        ));
+        let mut event = FitDataRecord::new(MesgNum::Event);
+        for (name, number, value) in [
+            ("event", 0, Value::String("rear_gear_change".to_string())),
+            ("event_type", 1, Value::String("marker".to_string())),
+            ("data", 3, Value::UInt32(0x0b11_0c02)),
+        ] {
+            event.push(FitDataField::new(
+                name.to_string(),
+                number,
+                value,
+                String::new(),
+            ));
+        }
+        let encoded = crate::ser::to_bytes(&[file_id, event]).unwrap();
+
+        // the gear_change_data subfield is split into the gears packed into it
+        let records = crate::from_bytes(&encoded).unwrap();
+        let gears: Vec<_> = ["rear_gear_num", "rear_gear", "front_gear_num", "front_gear"]
+            .into_iter()
+            .map(|name| records[1].field(name).map(|f| f.value().clone()))
+            .collect();
+        assert_eq!(gears, [2, 12, 17, 11].map(|gear| Some(Value::UInt64(gear))));
+        assert!(records[1].field("gear_change_data").is_none());
+
+        let options = [DecodeOption::KeepCompositeFields].into_iter().collect();
+        let records = crate::de::from_bytes_with_options(&encoded, &options).unwrap();
+        assert!(records[1].field("gear_change_data").is_some());
+        assert!(records[1].field("rear_gear").is_some());
+    }
+
+    #[test]
+    fn resolve_workout_subfields() {
+        let mut step = FitDataRecord::new(MesgNum::WorkoutStep);
+        for (name, number, value) in [
+            ("duration_type", 1, Value::String("time".to_string())),
+            ("duration_value", 2, Value::UInt32(300_000)),
+        ] {
+            step.push(FitDataField::new(
+                name.to_string(),
+                number,
+                value,
+                String::new(),
+            ));
+        }
+        assert_eq!(
+            active_subfield(&step, 2).map(|sub| sub.name),
+            Some("duration_time")
+        );
+        resolve_subfields(&mut step);
+        let field = step.field("duration_time").unwrap();
+        assert_eq!(field.value(), &Value::Float64(300.0));
+        assert_eq!(field.units(), "s");
+
+        // changing the reference field moves the value to the new subfield
+        step.fields_mut()[0].set_value(Value::String("distance".to_string()));
+        resolve_subfields(&mut step);
+        assert_eq!(
+            step.field("duration_distance").map(|f| f.value()),
+            Some(&Value::Float64(3000.0))
+        );
+        step.fields_mut()[0].set_value(Value::String("open".to_string()));
+        resolve_subfields(&mut step);
+        assert_eq!(
+            step.field("duration_value").map(|f| f.value()),
+            Some(&Value::UInt32(300_000))
+        );
+
+        // the decoder already resolved the subfields of a decoded file
+        for data in [
+            &include_bytes!("../../tests/fixtures/WorkoutCustomTargetValues.fit")[..],
+            &include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit")[..],
+        ] {
+            let decoded = crate::from_bytes(data).unwrap();
+            let mut resolved = decoded.clone();
+            resolved.iter_mut().for_each(resolve_subfields);
+            assert_eq!(
+                serde_json::to_string(&resolved).unwrap(),
+                serde_json::to_string(&decoded).unwrap()
+            );
+        }
+    }
+}
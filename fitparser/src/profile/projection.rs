@@ -0,0 +1,236 @@
+//! Project decoded messages into the typed structs of `profile::messages` while filling in the
+//! channels a message doesn't have a value for.
+//!
+//! Devices don't record every channel in every message, e.g. a sensor drops out for a few
+//! seconds or smart recording skips unchanged values. Analyses need different treatment of these
+//! gaps, so a strategy is picked per channel: leave the value empty, drop the message, repeat the
+//! last value or interpolate between the surrounding values.
+use super::{field_info, MesgNum};
+use crate::de::BaseType;
+use crate::error::{Error, Result};
+use crate::timestamp;
+use crate::{FitDataField, FitDataRecord, Value};
+use std::collections::{BTreeSet, HashMap};
+use std::convert::{TryFrom, TryInto};
+
+/// How a channel missing from a message is filled in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MissingValue {
+    /// Leave the channel empty so the typed field is `None`
+    #[default]
+    Keep,
+    /// Drop messages missing the channel
+    SkipRecord,
+    /// Repeat the last value of the channel, messages before the first value stay empty
+    ForwardFill,
+    /// Interpolate linearly between the values before and after the gap using the timestamps of
+    /// the messages, or their position when they aren't timestamped. Values that aren't numbers
+    /// are forward filled and gaps at either end stay empty.
+    Interpolate,
+}
+
+/// Strategies for filling in missing channels while projecting messages into typed structs
+#[derive(Clone, Debug, Default)]
+pub struct Projection {
+    default: MissingValue,
+    channels: HashMap<String, MissingValue>,
+}
+
+impl Projection {
+    /// Create a projection leaving missing channels empty
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Strategy for every channel without one of its own
+    pub fn default_strategy(mut self, strategy: MissingValue) -> Self {
+        self.default = strategy;
+        self
+    }
+
+    /// Strategy for a single channel given by its field name, e.g. `heart_rate`
+    pub fn channel(mut self, name: &str, strategy: MissingValue) -> Self {
+        self.channels.insert(name.to_string(), strategy);
+        self
+    }
+
+    /// Strategy used for a channel
+    pub fn strategy(&self, name: &str) -> MissingValue {
+        self.channels.get(name).copied().unwrap_or(self.default)
+    }
+
+    /// Copy of the messages of a kind with their missing channels filled in, messages of other
+    /// kinds are left out. The channels are every field present in any of the messages along
+    /// with the channels given a strategy.
+    pub fn fill(&self, records: &[FitDataRecord], kind: MesgNum) -> Vec<FitDataRecord> {
+        let mut messages: Vec<FitDataRecord> = records
+            .iter()
+            .filter(|r| r.is_kind(kind))
+            .cloned()
+            .collect();
+        let mut names: BTreeSet<String> = self.channels.keys().cloned().collect();
+        names.extend(
+            messages
+                .iter()
+                .flat_map(|r| r.fields().iter().map(|f| f.name().to_string())),
+        );
+        let mut skip = Vec::new();
+        for name in &names {
+            match self.strategy(name) {
+                MissingValue::Keep => {}
+                MissingValue::SkipRecord => skip.push(name.as_str()),
+                MissingValue::ForwardFill => forward_fill(&mut messages, name),
+                MissingValue::Interpolate => interpolate(&mut messages, kind, name),
+            }
+        }
+        messages.retain(|r| skip.iter().all(|name| r.field(name).is_some()));
+        messages
+    }
+
+    /// Fill in the missing channels of the messages of a kind and convert them into typed
+    /// messages, e.g. `RecordMessage` for `MesgNum::Record`
+    pub fn project<T>(&self, records: &[FitDataRecord], kind: MesgNum) -> Result<Vec<T>>
+    where
+        T: for<'a> TryFrom<&'a FitDataRecord, Error = Error>,
+    {
+        self.fill(records, kind).iter().map(T::try_from).collect()
+    }
+}
+
+fn forward_fill(messages: &mut [FitDataRecord], name: &str) {
+    let mut last: Option<FitDataField> = None;
+    for message in messages.iter_mut() {
+        match message.field(name) {
+            Some(field) => last = Some(field.clone()),
+            None => {
+                if let Some(field) = &last {
+                    message.push(field.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Time of a message in seconds, used as the axis to interpolate along
+fn message_time(message: &FitDataRecord) -> Option<f64> {
+    match message.field("timestamp").map(|f| f.value()) {
+        Some(Value::Timestamp(time)) => Some(timestamp::unix_millis(time) as f64 / 1e3),
+        _ => None,
+    }
+}
+
+fn interpolate(messages: &mut [FitDataRecord], kind: MesgNum, name: &str) {
+    let present: Vec<usize> = (0..messages.len())
+        .filter(|&i| messages[i].field(name).is_some())
+        .collect();
+    // fall back to the position of the messages unless every message is timestamped
+    let times: Option<Vec<f64>> = messages.iter().map(message_time).collect();
+    let position = |i: usize| times.as_ref().map_or(i as f64, |times| times[i]);
+    for pair in present.windows(2) {
+        let (before, after) = (pair[0], pair[1]);
+        let field = messages[before].field(name).cloned().unwrap();
+        let start: Option<f64> = field.value().clone().try_into().ok();
+        let end: Option<f64> = messages[after]
+            .field(name)
+            .and_then(|f| f.value().clone().try_into().ok());
+        let (x0, x1) = (position(before), position(after));
+        for (index, message) in messages.iter_mut().enumerate().take(after).skip(before + 1) {
+            let value = match (start, end) {
+                (Some(start), Some(end)) if x1 > x0 => {
+                    let t = (position(index) - x0) / (x1 - x0);
+                    numeric_value(kind, &field, start + (end - start) * t)
+                }
+                _ => field.value().clone(),
+            };
+            let mut filled = field.clone();
+            filled.set_value(value);
+            message.push(filled);
+        }
+    }
+}
+
+/// Value of an interpolated number in the variant the field is decoded with, integer fields are
+/// rounded to the nearest integer
+fn numeric_value(kind: MesgNum, field: &FitDataField, value: f64) -> Value {
+    let info = match field_info(kind, field.name()) {
+        Some(info) if !field.is_developer_field() => info,
+        _ => return Value::Float64(value),
+    };
+    let is_scaled = (info.scale - 1.0).abs() > f64::EPSILON || info.offset.abs() > f64::EPSILON;
+    match BaseType::from(info.base_type) {
+        _ if is_scaled => Value::Float64(value),
+        base_type @ (BaseType::Float32 | BaseType::Float64) => Value::Float64(value)
+            .coerce_to(base_type)
+            .unwrap_or(Value::Float64(value)),
+        base_type => Value::Float64(value.round())
+            .coerce_to(base_type)
+            .unwrap_or(Value::Float64(value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::messages::RecordMessage;
+
+    #[test]
+    fn fill_missing_channels() {
+        // heart rate and power drop out in the middle, the third second is missing entirely
+        let records: Vec<FitDataRecord> = [(0, Some(100), Some(200)), (1, None, None)]
+            .into_iter()
+            .chain([(3, None, Some(230)), (4, Some(140), None)])
+            .map(|(second, heart_rate, power)| {
+                let mut record = FitDataRecord::new(MesgNum::Record);
+                record.push(FitDataField::new(
+                    "timestamp".to_string(),
+                    253,
+                    Value::Timestamp(timestamp::local(1_600_000_000 + second)),
+                    "s".to_string(),
+                ));
+                if let Some(heart_rate) = heart_rate {
+                    record.push(FitDataField::new(
+                        "heart_rate".to_string(),
+                        3,
+                        Value::UInt8(heart_rate),
+                        "bpm".to_string(),
+                    ));
+                }
+                if let Some(power) = power {
+                    record.push(FitDataField::new(
+                        "power".to_string(),
+                        7,
+                        Value::UInt16(power),
+                        "watts".to_string(),
+                    ));
+                }
+                record
+            })
+            .collect();
+
+        let typed: Vec<RecordMessage> = Projection::new()
+            .project(&records, MesgNum::Record)
+            .unwrap();
+        let heart_rates: Vec<_> = typed.iter().map(|m| m.heart_rate).collect();
+        assert_eq!(heart_rates, vec![Some(100), None, None, Some(140)]);
+
+        let projection = Projection::new()
+            .channel("heart_rate", MissingValue::Interpolate)
+            .channel("power", MissingValue::ForwardFill);
+        let typed: Vec<RecordMessage> = projection.project(&records, MesgNum::Record).unwrap();
+        let heart_rates: Vec<_> = typed.iter().map(|m| m.heart_rate).collect();
+        assert_eq!(
+            heart_rates,
+            vec![Some(100), Some(110), Some(130), Some(140)]
+        );
+        let powers: Vec<_> = typed.iter().map(|m| m.power).collect();
+        assert_eq!(powers, vec![Some(200), Some(200), Some(230), Some(230)]);
+
+        let projection = Projection::new()
+            .default_strategy(MissingValue::ForwardFill)
+            .channel("power", MissingValue::SkipRecord);
+        let typed: Vec<RecordMessage> = projection.project(&records, MesgNum::Record).unwrap();
+        assert_eq!(typed.len(), 2);
+        assert_eq!(typed[1].heart_rate, Some(100));
+        assert_eq!(projection.strategy("cadence"), MissingValue::ForwardFill);
+    }
+}
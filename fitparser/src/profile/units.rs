@@ -0,0 +1,420 @@
+#![doc = "Auto generated units from FIT SDK Release: 21.141.00"]
+use serde::{ser::Serializer, Serialize};
+use std::fmt;
+#[doc = "Dimension measured by a unit"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UnitCategory {
+    #[doc = "Acceleration, e.g. m/s^2 or g-force"]
+    Acceleration,
+    #[doc = "Angles and positions, e.g. degrees or semicircles"]
+    Angle,
+    #[doc = "Rotation speed, e.g. deg/s"]
+    AngularVelocity,
+    #[doc = "Mass per volume, e.g. g/dL"]
+    Concentration,
+    #[doc = "Plain counts of events, e.g. steps or strokes"]
+    Count,
+    #[doc = "Amount of data, e.g. bytes"]
+    Data,
+    #[doc = "Energy, e.g. kcal or J"]
+    Energy,
+    #[doc = "Energy per unit of time, e.g. kcal/day"]
+    EnergyRate,
+    #[doc = "Events per unit of time, e.g. bpm or rpm"]
+    Frequency,
+    #[doc = "Lengths and distances, e.g. m or km"]
+    Length,
+    #[doc = "Magnetic field strength, e.g. gauss"]
+    MagneticField,
+    #[doc = "Mass, e.g. kg"]
+    Mass,
+    #[doc = "Power, e.g. watts"]
+    Power,
+    #[doc = "Pressure, e.g. Pa or mmHg"]
+    Pressure,
+    #[doc = "Pressure change per unit of time, e.g. bar/min"]
+    PressureRate,
+    #[doc = "Ratios, e.g. %"]
+    Ratio,
+    #[doc = "Dimensionless training and terrain scores, e.g. tss"]
+    Score,
+    #[doc = "Speed, e.g. m/s"]
+    Speed,
+    #[doc = "Temperature, e.g. degC"]
+    Temperature,
+    #[doc = "Durations, e.g. s or ms"]
+    Time,
+    #[doc = "Electric potential, e.g. V"]
+    Voltage,
+    #[doc = "Volume, e.g. L"]
+    Volume,
+    #[doc = "Volume per unit of time, e.g. L/min"]
+    VolumeRate,
+    #[doc = "Units without a physical dimension or with a mixed meaning"]
+    Other,
+}
+#[doc = "Units used by the fields of the FIT profile"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Unit {
+    #[doc = "`bar`"]
+    Bar,
+    #[doc = "`bar/min`"]
+    BarPerMinute,
+    #[doc = "`bpm`"]
+    Bpm,
+    #[doc = "`breaths/min`, `Breaths/min`"]
+    BreathsPerMinute,
+    #[doc = "`bytes`"]
+    Bytes,
+    #[doc = "`calories`"]
+    Calories,
+    #[doc = "`degC`, `C`"]
+    Celsius,
+    #[doc = "`counts`"]
+    Counts,
+    #[doc = "`cycles`"]
+    Cycles,
+    #[doc = "`degrees`"]
+    Degrees,
+    #[doc = "`deg/s`"]
+    DegreesPerSecond,
+    #[doc = "`depends on sensor`"]
+    DependsOnSensor,
+    #[doc = "`2 * cycles (steps)`"]
+    DoubleCycles,
+    #[doc = "`Flow`"]
+    Flow,
+    #[doc = "`g`"]
+    GForce,
+    #[doc = "`G`"]
+    Gauss,
+    #[doc = "`gr`"]
+    Grains,
+    #[doc = "`g/dL`"]
+    GramsPerDeciliter,
+    #[doc = "`hr`"]
+    Hours,
+    #[doc = "`100 * m`"]
+    HundredMeters,
+    #[doc = "`if`"]
+    IntensityFactor,
+    #[doc = "`J`"]
+    Joules,
+    #[doc = "`kGrit`"]
+    KiloGrit,
+    #[doc = "`kcal`"]
+    Kilocalories,
+    #[doc = "`kcal/cycle`"]
+    KilocaloriesPerCycle,
+    #[doc = "`kcal/day`, `kcal / day`"]
+    KilocaloriesPerDay,
+    #[doc = "`kcal / min`"]
+    KilocaloriesPerMinute,
+    #[doc = "`kg`"]
+    Kilograms,
+    #[doc = "`kg/m^3`"]
+    KilogramsPerCubicMeter,
+    #[doc = "`kg/m^2`"]
+    KilogramsPerSquareMeter,
+    #[doc = "`km`"]
+    Kilometers,
+    #[doc = "`lengths`"]
+    Lengths,
+    #[doc = "`L`"]
+    Liters,
+    #[doc = "`L/min`"]
+    LitersPerMinute,
+    #[doc = "`m`"]
+    Meters,
+    #[doc = "`m/cycle`"]
+    MetersPerCycle,
+    #[doc = "`m/s`, `mps`"]
+    MetersPerSecond,
+    #[doc = "`m/s^2`"]
+    MetersPerSecondSquared,
+    #[doc = "`mG`"]
+    MilliGForce,
+    #[doc = "`mL/kg/min`"]
+    MillilitersPerKilogramPerMinute,
+    #[doc = "`mm`"]
+    Millimeters,
+    #[doc = "`mmHg`"]
+    MillimetersOfMercury,
+    #[doc = "`ms`, `mS`"]
+    Milliseconds,
+    #[doc = "`min`, `minutes`"]
+    Minutes,
+    #[doc = "`OTUs`"]
+    OxygenToxicityUnits,
+    #[doc = "`Pa`"]
+    Pascals,
+    #[doc = "`%`, `percent`"]
+    Percent,
+    #[doc = "`% or bpm`"]
+    PercentOrBpm,
+    #[doc = "`% or watts`"]
+    PercentOrWatts,
+    #[doc = "`radians`"]
+    Radians,
+    #[doc = "`radians/second`"]
+    RadiansPerSecond,
+    #[doc = "`rpm`"]
+    Rpm,
+    #[doc = "`s`"]
+    Seconds,
+    #[doc = "`1/32768 s`"]
+    Seconds32768th,
+    #[doc = "`semicircles`"]
+    Semicircles,
+    #[doc = "`steps`"]
+    Steps,
+    #[doc = "`strides`"]
+    Strides,
+    #[doc = "`strides/min`"]
+    StridesPerMinute,
+    #[doc = "`strokes`"]
+    Strokes,
+    #[doc = "`strokes/lap`"]
+    StrokesPerLap,
+    #[doc = "`strokes/min`"]
+    StrokesPerMinute,
+    #[doc = "`swim_stroke`"]
+    SwimStroke,
+    #[doc = "`tss`"]
+    TrainingStressScore,
+    #[doc = "`V`"]
+    Volts,
+    #[doc = "`watts`"]
+    Watts,
+    #[doc = "`years`"]
+    Years,
+}
+impl Unit {
+    #[doc = "Parse the units string of a decoded field, `None` for strings the profile doesn't use"]
+    pub fn from_units(units: &str) -> Option<Self> {
+        match units {
+            "bar" => Some(Unit::Bar),
+            "bar/min" => Some(Unit::BarPerMinute),
+            "bpm" => Some(Unit::Bpm),
+            "breaths/min" | "Breaths/min" => Some(Unit::BreathsPerMinute),
+            "bytes" => Some(Unit::Bytes),
+            "calories" => Some(Unit::Calories),
+            "degC" | "C" => Some(Unit::Celsius),
+            "counts" => Some(Unit::Counts),
+            "cycles" => Some(Unit::Cycles),
+            "degrees" => Some(Unit::Degrees),
+            "deg/s" => Some(Unit::DegreesPerSecond),
+            "depends on sensor" => Some(Unit::DependsOnSensor),
+            "2 * cycles (steps)" => Some(Unit::DoubleCycles),
+            "Flow" => Some(Unit::Flow),
+            "g" => Some(Unit::GForce),
+            "G" => Some(Unit::Gauss),
+            "gr" => Some(Unit::Grains),
+            "g/dL" => Some(Unit::GramsPerDeciliter),
+            "hr" => Some(Unit::Hours),
+            "100 * m" => Some(Unit::HundredMeters),
+            "if" => Some(Unit::IntensityFactor),
+            "J" => Some(Unit::Joules),
+            "kGrit" => Some(Unit::KiloGrit),
+            "kcal" => Some(Unit::Kilocalories),
+            "kcal/cycle" => Some(Unit::KilocaloriesPerCycle),
+            "kcal/day" | "kcal / day" => Some(Unit::KilocaloriesPerDay),
+            "kcal / min" => Some(Unit::KilocaloriesPerMinute),
+            "kg" => Some(Unit::Kilograms),
+            "kg/m^3" => Some(Unit::KilogramsPerCubicMeter),
+            "kg/m^2" => Some(Unit::KilogramsPerSquareMeter),
+            "km" => Some(Unit::Kilometers),
+            "lengths" => Some(Unit::Lengths),
+            "L" => Some(Unit::Liters),
+            "L/min" => Some(Unit::LitersPerMinute),
+            "m" => Some(Unit::Meters),
+            "m/cycle" => Some(Unit::MetersPerCycle),
+            "m/s" | "mps" => Some(Unit::MetersPerSecond),
+            "m/s^2" => Some(Unit::MetersPerSecondSquared),
+            "mG" => Some(Unit::MilliGForce),
+            "mL/kg/min" => Some(Unit::MillilitersPerKilogramPerMinute),
+            "mm" => Some(Unit::Millimeters),
+            "mmHg" => Some(Unit::MillimetersOfMercury),
+            "ms" | "mS" => Some(Unit::Milliseconds),
+            "min" | "minutes" => Some(Unit::Minutes),
+            "OTUs" => Some(Unit::OxygenToxicityUnits),
+            "Pa" => Some(Unit::Pascals),
+            "%" | "percent" => Some(Unit::Percent),
+            "% or bpm" => Some(Unit::PercentOrBpm),
+            "% or watts" => Some(Unit::PercentOrWatts),
+            "radians" => Some(Unit::Radians),
+            "radians/second" => Some(Unit::RadiansPerSecond),
+            "rpm" => Some(Unit::Rpm),
+            "s" => Some(Unit::Seconds),
+            "1/32768 s" => Some(Unit::Seconds32768th),
+            "semicircles" => Some(Unit::Semicircles),
+            "steps" => Some(Unit::Steps),
+            "strides" => Some(Unit::Strides),
+            "strides/min" => Some(Unit::StridesPerMinute),
+            "strokes" => Some(Unit::Strokes),
+            "strokes/lap" => Some(Unit::StrokesPerLap),
+            "strokes/min" => Some(Unit::StrokesPerMinute),
+            "swim_stroke" => Some(Unit::SwimStroke),
+            "tss" => Some(Unit::TrainingStressScore),
+            "V" => Some(Unit::Volts),
+            "watts" => Some(Unit::Watts),
+            "years" => Some(Unit::Years),
+            _ => None,
+        }
+    }
+    #[doc = "Units string as written by the FIT profile"]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Unit::Bar => "bar",
+            Unit::BarPerMinute => "bar/min",
+            Unit::Bpm => "bpm",
+            Unit::BreathsPerMinute => "breaths/min",
+            Unit::Bytes => "bytes",
+            Unit::Calories => "calories",
+            Unit::Celsius => "degC",
+            Unit::Counts => "counts",
+            Unit::Cycles => "cycles",
+            Unit::Degrees => "degrees",
+            Unit::DegreesPerSecond => "deg/s",
+            Unit::DependsOnSensor => "depends on sensor",
+            Unit::DoubleCycles => "2 * cycles (steps)",
+            Unit::Flow => "Flow",
+            Unit::GForce => "g",
+            Unit::Gauss => "G",
+            Unit::Grains => "gr",
+            Unit::GramsPerDeciliter => "g/dL",
+            Unit::Hours => "hr",
+            Unit::HundredMeters => "100 * m",
+            Unit::IntensityFactor => "if",
+            Unit::Joules => "J",
+            Unit::KiloGrit => "kGrit",
+            Unit::Kilocalories => "kcal",
+            Unit::KilocaloriesPerCycle => "kcal/cycle",
+            Unit::KilocaloriesPerDay => "kcal/day",
+            Unit::KilocaloriesPerMinute => "kcal / min",
+            Unit::Kilograms => "kg",
+            Unit::KilogramsPerCubicMeter => "kg/m^3",
+            Unit::KilogramsPerSquareMeter => "kg/m^2",
+            Unit::Kilometers => "km",
+            Unit::Lengths => "lengths",
+            Unit::Liters => "L",
+            Unit::LitersPerMinute => "L/min",
+            Unit::Meters => "m",
+            Unit::MetersPerCycle => "m/cycle",
+            Unit::MetersPerSecond => "m/s",
+            Unit::MetersPerSecondSquared => "m/s^2",
+            Unit::MilliGForce => "mG",
+            Unit::MillilitersPerKilogramPerMinute => "mL/kg/min",
+            Unit::Millimeters => "mm",
+            Unit::MillimetersOfMercury => "mmHg",
+            Unit::Milliseconds => "ms",
+            Unit::Minutes => "min",
+            Unit::OxygenToxicityUnits => "OTUs",
+            Unit::Pascals => "Pa",
+            Unit::Percent => "%",
+            Unit::PercentOrBpm => "% or bpm",
+            Unit::PercentOrWatts => "% or watts",
+            Unit::Radians => "radians",
+            Unit::RadiansPerSecond => "radians/second",
+            Unit::Rpm => "rpm",
+            Unit::Seconds => "s",
+            Unit::Seconds32768th => "1/32768 s",
+            Unit::Semicircles => "semicircles",
+            Unit::Steps => "steps",
+            Unit::Strides => "strides",
+            Unit::StridesPerMinute => "strides/min",
+            Unit::Strokes => "strokes",
+            Unit::StrokesPerLap => "strokes/lap",
+            Unit::StrokesPerMinute => "strokes/min",
+            Unit::SwimStroke => "swim_stroke",
+            Unit::TrainingStressScore => "tss",
+            Unit::Volts => "V",
+            Unit::Watts => "watts",
+            Unit::Years => "years",
+        }
+    }
+    #[doc = "Dimension measured by the unit"]
+    pub fn category(self) -> UnitCategory {
+        match self {
+            Unit::Bar => UnitCategory::Pressure,
+            Unit::BarPerMinute => UnitCategory::PressureRate,
+            Unit::Bpm => UnitCategory::Frequency,
+            Unit::BreathsPerMinute => UnitCategory::Frequency,
+            Unit::Bytes => UnitCategory::Data,
+            Unit::Calories => UnitCategory::Energy,
+            Unit::Celsius => UnitCategory::Temperature,
+            Unit::Counts => UnitCategory::Count,
+            Unit::Cycles => UnitCategory::Count,
+            Unit::Degrees => UnitCategory::Angle,
+            Unit::DegreesPerSecond => UnitCategory::AngularVelocity,
+            Unit::DependsOnSensor => UnitCategory::Other,
+            Unit::DoubleCycles => UnitCategory::Count,
+            Unit::Flow => UnitCategory::Score,
+            Unit::GForce => UnitCategory::Acceleration,
+            Unit::Gauss => UnitCategory::MagneticField,
+            Unit::Grains => UnitCategory::Mass,
+            Unit::GramsPerDeciliter => UnitCategory::Concentration,
+            Unit::Hours => UnitCategory::Time,
+            Unit::HundredMeters => UnitCategory::Length,
+            Unit::IntensityFactor => UnitCategory::Score,
+            Unit::Joules => UnitCategory::Energy,
+            Unit::KiloGrit => UnitCategory::Score,
+            Unit::Kilocalories => UnitCategory::Energy,
+            Unit::KilocaloriesPerCycle => UnitCategory::Other,
+            Unit::KilocaloriesPerDay => UnitCategory::EnergyRate,
+            Unit::KilocaloriesPerMinute => UnitCategory::EnergyRate,
+            Unit::Kilograms => UnitCategory::Mass,
+            Unit::KilogramsPerCubicMeter => UnitCategory::Concentration,
+            Unit::KilogramsPerSquareMeter => UnitCategory::Other,
+            Unit::Kilometers => UnitCategory::Length,
+            Unit::Lengths => UnitCategory::Count,
+            Unit::Liters => UnitCategory::Volume,
+            Unit::LitersPerMinute => UnitCategory::VolumeRate,
+            Unit::Meters => UnitCategory::Length,
+            Unit::MetersPerCycle => UnitCategory::Length,
+            Unit::MetersPerSecond => UnitCategory::Speed,
+            Unit::MetersPerSecondSquared => UnitCategory::Acceleration,
+            Unit::MilliGForce => UnitCategory::Acceleration,
+            Unit::MillilitersPerKilogramPerMinute => UnitCategory::VolumeRate,
+            Unit::Millimeters => UnitCategory::Length,
+            Unit::MillimetersOfMercury => UnitCategory::Pressure,
+            Unit::Milliseconds => UnitCategory::Time,
+            Unit::Minutes => UnitCategory::Time,
+            Unit::OxygenToxicityUnits => UnitCategory::Score,
+            Unit::Pascals => UnitCategory::Pressure,
+            Unit::Percent => UnitCategory::Ratio,
+            Unit::PercentOrBpm => UnitCategory::Other,
+            Unit::PercentOrWatts => UnitCategory::Other,
+            Unit::Radians => UnitCategory::Angle,
+            Unit::RadiansPerSecond => UnitCategory::AngularVelocity,
+            Unit::Rpm => UnitCategory::Frequency,
+            Unit::Seconds => UnitCategory::Time,
+            Unit::Seconds32768th => UnitCategory::Time,
+            Unit::Semicircles => UnitCategory::Angle,
+            Unit::Steps => UnitCategory::Count,
+            Unit::Strides => UnitCategory::Count,
+            Unit::StridesPerMinute => UnitCategory::Frequency,
+            Unit::Strokes => UnitCategory::Count,
+            Unit::StrokesPerLap => UnitCategory::Other,
+            Unit::StrokesPerMinute => UnitCategory::Frequency,
+            Unit::SwimStroke => UnitCategory::Other,
+            Unit::TrainingStressScore => UnitCategory::Score,
+            Unit::Volts => UnitCategory::Voltage,
+            Unit::Watts => UnitCategory::Power,
+            Unit::Years => UnitCategory::Time,
+        }
+    }
+}
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for Unit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,529 @@
+//! Record live sensor data, e.g. from a smart trainer over BLE FTMS or ANT+, straight into an
+//! activity FIT file.
+//!
+//! `Recorder` writes a `record` message for every sample as it arrives, laps are closed on
+//! demand and `finish` adds the lap, session and activity summaries before wrapping everything
+//! into a file that can be uploaded to any platform accepting FIT activities.
+use crate::analysis::WeightedAverage;
+use crate::error::{ErrorKind, Result};
+use crate::geo::degrees_to_semicircles;
+use crate::profile::field_numbers::{activity, event, file_id, lap, record, session};
+use crate::profile::field_types::{File, Sport};
+use crate::profile::MesgNum;
+use crate::ser::{encode_message, file_bytes, DefinitionState, FileIdPolicy};
+use crate::timestamp::from_chrono;
+use crate::{FitDataField, FitDataRecord, Value};
+use chrono::{DateTime, Local};
+
+/// A single reading of the sensors
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sample {
+    /// Time the sample was taken
+    pub timestamp: DateTime<Local>,
+    /// Heart rate in bpm
+    pub heart_rate: Option<u8>,
+    /// Power in watts
+    pub power: Option<u16>,
+    /// Cadence in rpm
+    pub cadence: Option<u8>,
+    /// Speed in m/s, the distance is integrated from the speed
+    pub speed: Option<f64>,
+    /// Latitude and longitude in decimal degrees
+    pub position: Option<(f64, f64)>,
+    /// Altitude in meters
+    pub altitude: Option<f64>,
+}
+
+impl Sample {
+    /// Create a sample without any readings
+    pub fn new(timestamp: DateTime<Local>) -> Self {
+        Sample {
+            timestamp,
+            heart_rate: None,
+            power: None,
+            cadence: None,
+            speed: None,
+            position: None,
+            altitude: None,
+        }
+    }
+}
+
+/// Running statistics of a channel, the average is weighted by the time each sample is held
+#[derive(Clone, Copy, Debug, Default)]
+struct Stat {
+    average: WeightedAverage,
+    count: u32,
+    max: f64,
+}
+
+impl Stat {
+    fn add(&mut self, time: DateTime<Local>, value: f64) {
+        self.average.add(time, value);
+        self.count += 1;
+        self.max = self.max.max(value);
+    }
+
+    fn avg(&self, end: DateTime<Local>) -> Option<f64> {
+        self.average.average_until(end)
+    }
+
+    fn max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+}
+
+/// Totals of a lap or the whole session
+#[derive(Clone, Debug)]
+struct Totals {
+    start: DateTime<Local>,
+    distance: f64,
+    heart_rate: Stat,
+    power: Stat,
+    cadence: Stat,
+    speed: Stat,
+}
+
+impl Totals {
+    fn new(start: DateTime<Local>) -> Self {
+        Totals {
+            start,
+            distance: 0.0,
+            heart_rate: Stat::default(),
+            power: Stat::default(),
+            cadence: Stat::default(),
+            speed: Stat::default(),
+        }
+    }
+
+    fn add(&mut self, sample: &Sample, distance: f64) {
+        let time = sample.timestamp;
+        self.distance += distance;
+        if let Some(value) = sample.heart_rate {
+            self.heart_rate.add(time, f64::from(value));
+        }
+        if let Some(value) = sample.power {
+            self.power.add(time, f64::from(value));
+        }
+        if let Some(value) = sample.cadence {
+            self.cadence.add(time, f64::from(value));
+        }
+        if let Some(value) = sample.speed {
+            self.speed.add(time, value);
+        }
+    }
+}
+
+/// Build a message from raw field values, the names are only informative since the encoder
+/// writes the field numbers
+fn message(kind: MesgNum, fields: Vec<(&str, u8, Option<Value>)>) -> FitDataRecord {
+    let mut record = FitDataRecord::new(kind);
+    for (name, number, value) in fields {
+        if let Some(value) = value {
+            record.push(FitDataField::new(
+                name.to_string(),
+                number,
+                value,
+                String::new(),
+            ));
+        }
+    }
+    record
+}
+
+fn seconds_scaled(start: DateTime<Local>, end: DateTime<Local>) -> Value {
+    Value::UInt32((end - start).num_milliseconds().max(0) as u32)
+}
+
+/// Records samples into an activity FIT file
+#[derive(Clone, Debug)]
+pub struct Recorder {
+    sport: Sport,
+    definitions: DefinitionState,
+    data: Vec<u8>,
+    last: Option<Sample>,
+    distance: f64,
+    lap: Totals,
+    session: Totals,
+    laps: u16,
+}
+
+impl Recorder {
+    /// Start recording an activity of the given sport, the timer starts at `start`
+    pub fn new(start: DateTime<Local>, sport: Sport) -> Result<Self> {
+        Self::with_file_id(start, sport, &FileIdPolicy::default())
+    }
+
+    /// Start recording an activity with the identity of the `file_id` message set by the policy
+    pub fn with_file_id(
+        start: DateTime<Local>,
+        sport: Sport,
+        policy: &FileIdPolicy,
+    ) -> Result<Self> {
+        let mut recorder = Recorder {
+            sport,
+            definitions: DefinitionState::new(),
+            data: Vec::new(),
+            last: None,
+            distance: 0.0,
+            lap: Totals::new(start),
+            session: Totals::new(start),
+            laps: 0,
+        };
+        recorder.write(message(
+            MesgNum::FileId,
+            vec![
+                (
+                    "type",
+                    file_id::TYPE,
+                    Some(Value::Enum(File::Activity.as_u8())),
+                ),
+                (
+                    "manufacturer",
+                    file_id::MANUFACTURER,
+                    Some(Value::UInt16(policy.manufacturer.as_u16())),
+                ),
+                (
+                    "product",
+                    file_id::PRODUCT,
+                    Some(Value::UInt16(policy.product)),
+                ),
+                (
+                    "serial_number",
+                    file_id::SERIAL_NUMBER,
+                    policy.serial_number().map(Value::UInt32z),
+                ),
+                (
+                    "time_created",
+                    file_id::TIME_CREATED,
+                    Some(Value::Timestamp(policy.time_created(from_chrono(start)))),
+                ),
+            ],
+        ))?;
+        recorder.timer_event(start, 0)?;
+        Ok(recorder)
+    }
+
+    fn write(&mut self, record: FitDataRecord) -> Result<()> {
+        let data = encode_message(&record, &mut self.definitions)?;
+        self.data.extend(data);
+        Ok(())
+    }
+
+    /// Write a timer event, event type 0 starts the timer and 4 stops every timer
+    fn timer_event(&mut self, timestamp: DateTime<Local>, event_type: u8) -> Result<()> {
+        self.write(message(
+            MesgNum::Event,
+            vec![
+                (
+                    "timestamp",
+                    event::TIMESTAMP,
+                    Some(Value::Timestamp(from_chrono(timestamp))),
+                ),
+                ("event", event::EVENT, Some(Value::Enum(0))),
+                (
+                    "event_type",
+                    event::EVENT_TYPE,
+                    Some(Value::Enum(event_type)),
+                ),
+                ("event_group", event::EVENT_GROUP, Some(Value::UInt8(0))),
+            ],
+        ))
+    }
+
+    fn check_time(&self, timestamp: DateTime<Local>) -> Result<()> {
+        let previous = self
+            .last
+            .as_ref()
+            .map_or(self.lap.start, |s| s.timestamp)
+            .max(self.lap.start);
+        if timestamp < previous {
+            return Err(ErrorKind::EncodeError(format!(
+                "timestamp {} is before the previous one at {}",
+                timestamp, previous
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Add a sample, samples must be added in chronological order
+    pub fn sample(&mut self, sample: Sample) -> Result<()> {
+        self.check_time(sample.timestamp)?;
+        // integrate the distance using the speed of the previous sample
+        let distance = match &self.last {
+            Some(last) => {
+                let seconds = (sample.timestamp - last.timestamp).num_milliseconds() as f64 / 1e3;
+                last.speed.unwrap_or(0.0) * seconds
+            }
+            None => 0.0,
+        };
+        self.distance += distance;
+        self.lap.add(&sample, distance);
+        self.session.add(&sample, distance);
+
+        self.write(message(
+            MesgNum::Record,
+            vec![
+                (
+                    "timestamp",
+                    record::TIMESTAMP,
+                    Some(Value::Timestamp(from_chrono(sample.timestamp))),
+                ),
+                (
+                    "position_lat",
+                    record::POSITION_LAT,
+                    sample
+                        .position
+                        .map(|(lat, _)| Value::SInt32(degrees_to_semicircles(lat))),
+                ),
+                (
+                    "position_long",
+                    record::POSITION_LONG,
+                    sample
+                        .position
+                        .map(|(_, lon)| Value::SInt32(degrees_to_semicircles(lon))),
+                ),
+                (
+                    "altitude",
+                    record::ALTITUDE,
+                    sample.altitude.map(|a| {
+                        Value::UInt16(((a + 500.0) * 5.0).round().clamp(0.0, 65534.0) as u16)
+                    }),
+                ),
+                (
+                    "heart_rate",
+                    record::HEART_RATE,
+                    sample.heart_rate.map(Value::UInt8),
+                ),
+                ("cadence", record::CADENCE, sample.cadence.map(Value::UInt8)),
+                (
+                    "distance",
+                    record::DISTANCE,
+                    Some(Value::UInt32((self.distance * 100.0).round() as u32)),
+                ),
+                (
+                    "speed",
+                    record::SPEED,
+                    sample
+                        .speed
+                        .map(|s| Value::UInt16((s * 1000.0).round().min(65534.0) as u16)),
+                ),
+                ("power", record::POWER, sample.power.map(Value::UInt16)),
+            ],
+        ))?;
+        self.last = Some(sample);
+        Ok(())
+    }
+
+    /// Close the current lap at the timestamp and start the next one
+    pub fn lap(&mut self, timestamp: DateTime<Local>) -> Result<()> {
+        self.check_time(timestamp)?;
+        let totals = std::mem::replace(&mut self.lap, Totals::new(timestamp));
+        let lap = self.summary(MesgNum::Lap, &totals, timestamp);
+        self.write(lap)?;
+        self.laps += 1;
+        Ok(())
+    }
+
+    /// Build the `lap` or `session` summary of the totals
+    fn summary(&self, kind: MesgNum, totals: &Totals, end: DateTime<Local>) -> FitDataRecord {
+        let is_lap = kind == MesgNum::Lap;
+        let pick = |lap_field: u8, session_field: u8| {
+            if is_lap {
+                lap_field
+            } else {
+                session_field
+            }
+        };
+        let u8_value = |v: f64| Value::UInt8(v.round() as u8);
+        let u16_value = |v: f64| Value::UInt16(v.round() as u16);
+        let speed_value = |v: f64| Value::UInt16((v * 1000.0).round().min(65534.0) as u16);
+        let mut fields = vec![
+            (
+                "timestamp",
+                lap::TIMESTAMP,
+                Some(Value::Timestamp(from_chrono(end))),
+            ),
+            (
+                "event",
+                lap::EVENT,
+                Some(Value::Enum(if is_lap { 9 } else { 8 })),
+            ),
+            ("event_type", lap::EVENT_TYPE, Some(Value::Enum(1))),
+            (
+                "start_time",
+                lap::START_TIME,
+                Some(Value::Timestamp(from_chrono(totals.start))),
+            ),
+            (
+                "total_elapsed_time",
+                lap::TOTAL_ELAPSED_TIME,
+                Some(seconds_scaled(totals.start, end)),
+            ),
+            (
+                "total_timer_time",
+                lap::TOTAL_TIMER_TIME,
+                Some(seconds_scaled(totals.start, end)),
+            ),
+            (
+                "total_distance",
+                lap::TOTAL_DISTANCE,
+                Some(Value::UInt32((totals.distance * 100.0).round() as u32)),
+            ),
+            (
+                "sport",
+                pick(lap::SPORT, session::SPORT),
+                Some(Value::Enum(self.sport.as_u8())),
+            ),
+            (
+                "avg_speed",
+                pick(lap::AVG_SPEED, session::AVG_SPEED),
+                totals.speed.avg(end).map(speed_value),
+            ),
+            (
+                "max_speed",
+                pick(lap::MAX_SPEED, session::MAX_SPEED),
+                totals.speed.max().map(speed_value),
+            ),
+            (
+                "avg_heart_rate",
+                pick(lap::AVG_HEART_RATE, session::AVG_HEART_RATE),
+                totals.heart_rate.avg(end).map(u8_value),
+            ),
+            (
+                "max_heart_rate",
+                pick(lap::MAX_HEART_RATE, session::MAX_HEART_RATE),
+                totals.heart_rate.max().map(u8_value),
+            ),
+            (
+                "avg_cadence",
+                pick(lap::AVG_CADENCE, session::AVG_CADENCE),
+                totals.cadence.avg(end).map(u8_value),
+            ),
+            (
+                "max_cadence",
+                pick(lap::MAX_CADENCE, session::MAX_CADENCE),
+                totals.cadence.max().map(u8_value),
+            ),
+            (
+                "avg_power",
+                pick(lap::AVG_POWER, session::AVG_POWER),
+                totals.power.avg(end).map(u16_value),
+            ),
+            (
+                "max_power",
+                pick(lap::MAX_POWER, session::MAX_POWER),
+                totals.power.max().map(u16_value),
+            ),
+        ];
+        if is_lap {
+            fields.push((
+                "message_index",
+                lap::MESSAGE_INDEX,
+                Some(Value::UInt16(self.laps)),
+            ));
+        } else {
+            fields.push((
+                "message_index",
+                session::MESSAGE_INDEX,
+                Some(Value::UInt16(0)),
+            ));
+            fields.push((
+                "first_lap_index",
+                session::FIRST_LAP_INDEX,
+                Some(Value::UInt16(0)),
+            ));
+            fields.push((
+                "num_laps",
+                session::NUM_LAPS,
+                Some(Value::UInt16(self.laps)),
+            ));
+        }
+        message(kind, fields)
+    }
+
+    /// Stop the timer at the timestamp, close the last lap and return the encoded FIT file
+    pub fn finish(mut self, timestamp: DateTime<Local>) -> Result<Vec<u8>> {
+        self.lap(timestamp)?;
+        self.timer_event(timestamp, 4)?;
+        let session = self.summary(MesgNum::Session, &self.session, timestamp);
+        self.write(session)?;
+        let local_offset = i64::from(timestamp.offset().local_minus_utc());
+        self.write(message(
+            MesgNum::Activity,
+            vec![
+                (
+                    "timestamp",
+                    activity::TIMESTAMP,
+                    Some(Value::Timestamp(from_chrono(timestamp))),
+                ),
+                (
+                    "total_timer_time",
+                    activity::TOTAL_TIMER_TIME,
+                    Some(seconds_scaled(self.session.start, timestamp)),
+                ),
+                (
+                    "num_sessions",
+                    activity::NUM_SESSIONS,
+                    Some(Value::UInt16(1)),
+                ),
+                ("type", activity::TYPE, Some(Value::Enum(0))),
+                ("event", activity::EVENT, Some(Value::Enum(26))),
+                ("event_type", activity::EVENT_TYPE, Some(Value::Enum(1))),
+                (
+                    "local_timestamp",
+                    activity::LOCAL_TIMESTAMP,
+                    Some(Value::UInt32(
+                        (timestamp.timestamp() + local_offset - crate::ser::FIT_EPOCH_OFFSET)
+                            as u32,
+                    )),
+                ),
+            ],
+        ))?;
+        Ok(file_bytes(&self.data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn record_trainer_ride() {
+        let start = Local.timestamp_opt(1_600_000_000, 0).unwrap();
+        let mut recorder = Recorder::new(start, Sport::Cycling).unwrap();
+        for second in 0..120 {
+            let mut sample = Sample::new(start + Duration::seconds(second));
+            sample.heart_rate = Some(120 + (second / 10) as u8);
+            sample.power = Some(200);
+            sample.speed = Some(10.0);
+            recorder.sample(sample).unwrap();
+            if second == 59 {
+                recorder.lap(start + Duration::seconds(60)).unwrap();
+            }
+        }
+        assert!(recorder.sample(Sample::new(start)).is_err());
+        let file = recorder.finish(start + Duration::seconds(120)).unwrap();
+
+        let records = from_bytes(&file).unwrap();
+        let count = |kind| records.iter().filter(|r| r.is_kind(kind)).count();
+        assert_eq!(count(MesgNum::Record), 120);
+        assert_eq!(count(MesgNum::Lap), 2);
+        assert_eq!(count(MesgNum::Event), 2);
+        let session = records
+            .iter()
+            .find(|r| r.is_kind(MesgNum::Session))
+            .unwrap();
+        let value = |name| session.field(name).unwrap().value().clone();
+        assert_eq!(value("sport"), Value::String("cycling".to_string()));
+        assert_eq!(value("total_distance"), Value::Float64(1190.0));
+        assert_eq!(value("total_timer_time"), Value::Float64(120.0));
+        assert_eq!(value("avg_power"), Value::UInt16(200));
+        assert_eq!(value("max_heart_rate"), Value::UInt8(131));
+        assert_eq!(value("num_laps"), Value::UInt16(2));
+        assert_eq!(records.last().unwrap().kind(), MesgNum::Activity);
+    }
+}
@@ -0,0 +1,97 @@
+//! Repair FIT files that can't be loaded because the device didn't finish writing them.
+//!
+//! Devices that crash, run out of battery or abandon an activity often leave a file with a data
+//! size of zero or one that doesn't match the data written, and without the trailing CRC. The
+//! messages themselves are usually intact, so fixing the header and CRC is enough for Garmin
+//! Connect and other platforms to accept the file again.
+use crate::de::crc::{caculate_crc, update_crc};
+use crate::de::parser::{fit_message, FitMessage};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Walk the messages of the data section and return the end offset of every complete message,
+/// stopping at the first message that can't be parsed
+fn message_ends(data: &[u8]) -> Vec<usize> {
+    let mut definitions = HashMap::new();
+    let mut ends = Vec::new();
+    let mut input = data;
+    while !input.is_empty() {
+        input = match fit_message(input, &definitions) {
+            Ok((remaining, FitMessage::Definition(definition))) => {
+                definitions.insert(definition.local_message_number(), Arc::new(definition));
+                remaining
+            }
+            Ok((remaining, FitMessage::Data(_))) => remaining,
+            _ => break,
+        };
+        ends.push(data.len() - input.len());
+    }
+    ends
+}
+
+/// Rewrite the data size in the header of a FIT file and recompute the header and file CRCs.
+///
+/// The data section ends where the header says if a complete message ends there, otherwise at
+/// the end of the last complete message, which drops a message cut off mid-write along with
+/// anything following the data like a stale CRC. Only the first file of chained files is kept.
+/// Input that doesn't start with a FIT header is returned unchanged, a file that is already
+/// valid is returned as it is.
+pub fn fix_header_and_crc(bytes: &[u8]) -> Vec<u8> {
+    let header_size = match bytes.first() {
+        Some(&size) if size >= 12 && bytes.len() >= size as usize => size as usize,
+        _ => return bytes.to_vec(),
+    };
+    if &bytes[8..12] != b".FIT" {
+        return bytes.to_vec();
+    }
+    let data = &bytes[header_size..];
+    let declared = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize;
+    let ends = message_ends(data);
+    let data_size = if ends.binary_search(&declared).is_ok() {
+        declared
+    } else {
+        ends.last().copied().unwrap_or(0)
+    };
+
+    let mut file = bytes[..header_size].to_vec();
+    file[4..8].copy_from_slice(&(data_size as u32).to_le_bytes());
+    if header_size >= 14 {
+        let header_crc = caculate_crc(&file[..12]);
+        file[12..14].copy_from_slice(&header_crc.to_le_bytes());
+    }
+    file.extend_from_slice(&data[..data_size]);
+    let crc = update_crc(0, &file);
+    file.extend_from_slice(&crc.to_le_bytes());
+    file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+
+    #[test]
+    fn repair_abandoned_file() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        assert_eq!(fix_header_and_crc(data), data.to_vec());
+        let expected = from_bytes(data).unwrap().len();
+
+        // a device that never finished the file, data size zero and no CRC
+        let mut abandoned = data[..data.len() - 2].to_vec();
+        abandoned[4..8].copy_from_slice(&[0; 4]);
+        assert!(from_bytes(&abandoned).is_err());
+        assert_eq!(fix_header_and_crc(&abandoned), data.to_vec());
+
+        // cut off in the middle of a message
+        let truncated = &abandoned[..abandoned.len() - 50];
+        let repaired = fix_header_and_crc(truncated);
+        assert!(repaired.len() < truncated.len() + 2);
+        let records = from_bytes(&repaired).unwrap();
+        assert!(records.len() < expected && records.len() > expected - 10);
+
+        assert_eq!(
+            fix_header_and_crc(b"not a fit file"),
+            b"not a fit file".to_vec()
+        );
+    }
+}
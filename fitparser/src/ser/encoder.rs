@@ -0,0 +1,397 @@
+//! Encode decoded records back into a FIT file, reversing the conversions the FIT profile applies
+//! while decoding.
+use super::{encode_message, file_bytes, DefinitionState, FIT_EPOCH_OFFSET};
+use crate::de::BaseType;
+use crate::error::{ErrorKind, Result};
+use crate::geo::degrees_to_semicircles;
+use crate::profile::{enum_value, field_info, seconds_per_unit, FieldDataType, FieldInfo, MesgNum};
+use crate::timestamp::{self, UnixSeconds};
+use crate::{DeveloperFieldInfo, FitDataField, FitDataRecord, Value};
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+/// Write records as they are returned by `from_bytes` into a FIT file.
+///
+/// Definition messages are written whenever the layout of a message changes. Fields defined by the
+/// FIT profile are written with the base type of their profile definition, scaled values are
+/// converted back into integers, enum names into their values and timestamps into seconds since the
+/// FIT epoch. Developer fields are written with the base type of the `field_description` message
+/// defining them, if it was written before, with the scale and offset of the description reversed,
+/// and fields missing from the profile are written as they are stored in the record. The file
+/// decodes into the same records, although fields expanded from components are stored in their own
+/// field rather than the component they came from.
+#[derive(Clone, Debug, Default)]
+pub struct FitEncoder {
+    state: DefinitionState,
+    data: Vec<u8>,
+    /// Base type of each developer field by developer data index and field number
+    developer_types: HashMap<(u8, u8), u8>,
+}
+
+impl FitEncoder {
+    /// Create an encoder writing little endian messages
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write the messages in big endian order
+    pub fn big_endian(mut self, big_endian: bool) -> Self {
+        self.state = self.state.big_endian(big_endian);
+        self
+    }
+
+    /// Append a record to the file
+    pub fn write(&mut self, record: &FitDataRecord) -> Result<()> {
+        if record.is_kind(MesgNum::FieldDescription) {
+            self.add_field_description(record);
+        }
+        let mut raw = FitDataRecord::new(record.kind());
+        for field in record.fields() {
+            let raw_field = match field.developer_info() {
+                Some(info) => self.developer_field(info, field),
+                None => raw_field(record, field)?,
+            };
+            raw.push(raw_field);
+        }
+        let message = encode_message(&raw, &mut self.state)?;
+        self.data.extend(message);
+        Ok(())
+    }
+
+    /// Append every record to the file
+    pub fn write_all<'a, I>(&mut self, records: I) -> Result<()>
+    where
+        I: IntoIterator<Item = &'a FitDataRecord>,
+    {
+        records.into_iter().try_for_each(|r| self.write(r))
+    }
+
+    /// Number of bytes of the messages written so far, excluding the header and CRC
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if no messages have been written yet
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Finish the file by adding the header and the trailing CRC
+    pub fn finish(self) -> Vec<u8> {
+        file_bytes(&self.data)
+    }
+
+    fn add_field_description(&mut self, record: &FitDataRecord) {
+        let number = |name| {
+            let value: i64 = record.field(name)?.value().try_into().ok()?;
+            value.try_into().ok()
+        };
+        let base_type = record
+            .field("fit_base_type_id")
+            .and_then(|f| enum_value(FieldDataType::FitBaseType, f.value()))
+            .and_then(|v| v.try_into().ok());
+        if let (Some(index), Some(number), Some(base_type)) = (
+            number("developer_data_index"),
+            number("field_definition_number"),
+            base_type,
+        ) {
+            self.developer_types.insert((index, number), base_type);
+        }
+    }
+
+    /// Copy of a developer field with its values converted into the base type of its description,
+    /// e.g. after the record was deserialized from a format that doesn't keep the type. The scale
+    /// and offset of the description are reversed unless the raw value was kept.
+    fn developer_field(&self, info: &DeveloperFieldInfo, field: &FitDataField) -> FitDataField {
+        let mut field = field.clone();
+        let base_type = self
+            .developer_types
+            .get(&(info.developer_data_index, field.number()))
+            .copied()
+            .or(info.base_type);
+        let value = match (field.raw_value(), info.scale, info.offset) {
+            (Some(raw), _, _) => raw.clone(),
+            (None, None, None) => field.value().clone(),
+            (None, scale, offset) => unscale_value(
+                field.value(),
+                scale.unwrap_or(1.0),
+                offset.unwrap_or(0.0),
+                base_type.map(BaseType::from),
+            ),
+        };
+        let value = match base_type {
+            Some(base_type) => value.coerce_to(BaseType::from(base_type)).unwrap_or(value),
+            None => value,
+        };
+        field.set_value(value);
+        field
+    }
+}
+
+/// Encode the records into a FIT file
+pub fn to_bytes(records: &[FitDataRecord]) -> Result<Vec<u8>> {
+    let mut encoder = FitEncoder::new();
+    encoder.write_all(records)?;
+    Ok(encoder.finish())
+}
+
+/// Copy of the field holding the value stored in a FIT file
+fn raw_field(record: &FitDataRecord, field: &FitDataField) -> Result<FitDataField> {
+    if field.is_developer_field() || field.is_unknown() {
+        return Ok(field.clone());
+    }
+    let info = match field_info(record.kind(), field.name()) {
+        Some(info) => info,
+        None => return Ok(field.clone()),
+    };
+    let raw = field
+        .raw_value()
+        .and_then(|raw| raw.coerce_to(BaseType::from(info.base_type)).ok());
+    // coordinates decoded with `DecodeOption::ReturnDegrees` are stored as semicircles
+    let semicircles;
+    let value = match (info.units, field.units()) {
+        ("semicircles", "degrees") => {
+            semicircles = semicircles_value(field.value());
+            &semicircles
+        }
+        _ => field.value(),
+    };
+    let value = match (raw, value) {
+        (Some(raw), _) => raw,
+        (None, Value::Array(values)) => Value::Array(
+            values
+                .iter()
+                .map(|v| raw_value(&info, v))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| invalid_value(record, field))?,
+        ),
+        (None, value) => raw_value(&info, value).ok_or_else(|| invalid_value(record, field))?,
+    };
+    Ok(FitDataField::new(
+        field.name().to_string(),
+        info.number,
+        value,
+        field.units().to_string(),
+    ))
+}
+
+/// Convert a coordinate in decimal degrees into semicircles, values that aren't numbers are kept
+fn semicircles_value(value: &Value) -> Value {
+    match value {
+        Value::Array(values) => Value::Array(values.iter().map(semicircles_value).collect()),
+        Value::String(_)
+        | Value::Timestamp(_)
+        | Value::UtcTimestamp(_)
+        | Value::LocalTimestamp(_)
+        | Value::Duration(_) => value.clone(),
+        _ => match TryInto::<f64>::try_into(value.clone()) {
+            Ok(val) => Value::SInt32(degrees_to_semicircles(val)),
+            Err(_) => value.clone(),
+        },
+    }
+}
+
+fn invalid_value(record: &FitDataRecord, field: &FitDataField) -> Box<ErrorKind> {
+    ErrorKind::EncodeError(format!(
+        "value {} of field '{}' of message {} can't be encoded",
+        field.value(),
+        field.name(),
+        record.kind()
+    ))
+    .into()
+}
+
+/// Reverse the conversion applied to a single value while decoding
+fn raw_value(info: &FieldInfo, value: &Value) -> Option<Value> {
+    match value {
+        Value::Timestamp(time) => {
+            let seconds = match info.field_type {
+                FieldDataType::LocalDateTime => timestamp::to_wall_clock(time).unix_seconds(),
+                _ => time.unix_seconds(),
+            };
+            coerce(info, Value::SInt64(seconds - FIT_EPOCH_OFFSET))
+        }
+        Value::UtcTimestamp(time) => {
+            coerce(info, Value::SInt64(time.unix_seconds() - FIT_EPOCH_OFFSET))
+        }
+        Value::LocalTimestamp(time) => {
+            coerce(info, Value::SInt64(time.unix_seconds() - FIT_EPOCH_OFFSET))
+        }
+        #[cfg(not(compact_profile))]
+        Value::String(name) if info.field_type.is_enum_type() => {
+            coerce(info, Value::SInt64(info.field_type.variant_value(name)?))
+        }
+        Value::Duration(duration) => {
+            let seconds_per_unit = seconds_per_unit(info.units)?;
+            raw_float_value(info, duration.as_secs_f64() / seconds_per_unit)
+        }
+        Value::String(_) | Value::Byte(_) => Some(value.clone()),
+        Value::Array(_) => None,
+        _ => {
+            let is_scaled =
+                (info.scale - 1.0).abs() > f64::EPSILON || info.offset.abs() > f64::EPSILON;
+            if is_scaled {
+                raw_float_value(info, value.clone().try_into().ok()?)
+            } else {
+                coerce(info, value.clone())
+            }
+        }
+    }
+}
+
+/// Reverse the scale and offset of a value, rounding it to an integer for integer base types
+fn raw_float_value(info: &FieldInfo, value: f64) -> Option<Value> {
+    let raw = (value + info.offset) * info.scale;
+    match BaseType::from(info.base_type) {
+        BaseType::Float32 | BaseType::Float64 => coerce(info, Value::Float64(raw)),
+        _ => coerce(info, Value::Float64(raw.round())),
+    }
+}
+
+/// Reverse the scale and offset of a developer field description, integer base types are
+/// rounded to the nearest stored value
+fn unscale_value(value: &Value, scale: f64, offset: f64, base_type: Option<BaseType>) -> Value {
+    match value {
+        Value::Array(values) => Value::Array(
+            values
+                .iter()
+                .map(|v| unscale_value(v, scale, offset, base_type))
+                .collect(),
+        ),
+        Value::String(_) | Value::Byte(_) => value.clone(),
+        _ => match TryInto::<f64>::try_into(value.clone()) {
+            Ok(val) => {
+                let raw = (val + offset) * scale;
+                match base_type {
+                    Some(BaseType::Float32 | BaseType::Float64) => Value::Float64(raw),
+                    _ => Value::Float64(raw.round()),
+                }
+            }
+            Err(_) => value.clone(),
+        },
+    }
+}
+
+/// Convert a value into the base type of the field
+fn coerce(info: &FieldInfo, value: Value) -> Option<Value> {
+    value.coerce_to(BaseType::from(info.base_type)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+
+    /// Fields of every record sorted by name, the position of fields expanded from components
+    /// changes once they are stored in their own field
+    fn sorted_fields(records: &[FitDataRecord]) -> Vec<(String, Vec<(String, String)>)> {
+        records
+            .iter()
+            .map(|r| {
+                let mut fields: Vec<_> = r
+                    .fields()
+                    .iter()
+                    .map(|f| {
+                        let value = serde_json::to_string(f.value()).unwrap();
+                        (f.name().to_string(), value)
+                    })
+                    .collect();
+                fields.sort();
+                (r.kind().to_string(), fields)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn round_trip() {
+        for data in [
+            &include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit")[..],
+            &include_bytes!("../../tests/fixtures/Activity.fit")[..],
+            &include_bytes!("../../tests/fixtures/Settings.fit")[..],
+            &include_bytes!("../../tests/fixtures/DeveloperData.fit")[..],
+            &include_bytes!("../../tests/fixtures/WorkoutRepeatSteps.fit")[..],
+        ] {
+            let records = from_bytes(data).unwrap();
+            let encoded = to_bytes(&records).unwrap();
+            let decoded = from_bytes(&encoded).unwrap();
+            assert_eq!(sorted_fields(&decoded), sorted_fields(&records));
+        }
+    }
+
+    #[test]
+    fn reverse_profile_conversions() {
+        let mut record = FitDataRecord::new(crate::profile::MesgNum::Record);
+        record.push(FitDataField::new(
+            "enhanced_altitude".to_string(),
+            78,
+            Value::Float64(120.4),
+            "m".to_string(),
+        ));
+        let raw = raw_field(&record, &record.fields()[0]).unwrap();
+        assert_eq!(raw.value(), &Value::UInt32(3102));
+
+        let mut file_id = FitDataRecord::new(crate::profile::MesgNum::FileId);
+        file_id.push(FitDataField::new(
+            "type".to_string(),
+            0,
+            Value::String("activity".to_string()),
+            String::new(),
+        ));
+        let raw = raw_field(&file_id, &file_id.fields()[0]).unwrap();
+        assert_eq!(raw.value(), &Value::Enum(4));
+
+        file_id.fields_mut()[0].set_value(Value::String("no_such_file".to_string()));
+        assert!(raw_field(&file_id, &file_id.fields()[0]).is_err());
+    }
+
+    #[test]
+    fn scaled_developer_fields() {
+        use crate::de::{from_bytes_with_options, DecodeOption};
+        use crate::profile::MesgNum;
+
+        let mut records =
+            from_bytes(include_bytes!("../../tests/fixtures/DeveloperData.fit")).unwrap();
+        let description = records
+            .iter_mut()
+            .find(|r| r.is_kind(MesgNum::FieldDescription))
+            .unwrap();
+        description.push(FitDataField::new(
+            "scale".to_string(),
+            6,
+            Value::UInt8(10),
+            String::new(),
+        ));
+        description.push(FitDataField::new(
+            "offset".to_string(),
+            7,
+            Value::SInt8(1),
+            String::new(),
+        ));
+        let encoded = to_bytes(&records).unwrap();
+        let decoded = from_bytes(&encoded).unwrap();
+        let field = decoded
+            .iter()
+            .find(|r| r.is_kind(MesgNum::Record))
+            .and_then(|r| r.field("doughnuts_earned"))
+            .unwrap();
+        assert_eq!(field.value(), &Value::Float64(-0.9));
+        let info = field.developer_info().unwrap();
+        assert_eq!(info.base_type, Some(0x01));
+        assert_eq!((info.scale, info.offset), (Some(10.0), Some(1.0)));
+
+        // the scale and offset are reversed when encoding the scaled values again
+        let again = from_bytes(&to_bytes(&decoded).unwrap()).unwrap();
+        assert_eq!(
+            serde_json::to_value(&again).unwrap(),
+            serde_json::to_value(&decoded).unwrap()
+        );
+        let options = [DecodeOption::KeepRawValues].into_iter().collect();
+        let decoded = from_bytes_with_options(&encoded, &options).unwrap();
+        let field = decoded
+            .iter()
+            .find(|r| r.is_kind(MesgNum::Record))
+            .and_then(|r| r.field("doughnuts_earned"))
+            .unwrap();
+        assert_eq!(field.raw_value(), Some(&Value::SInt8(1)));
+    }
+}
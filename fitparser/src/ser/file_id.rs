@@ -0,0 +1,196 @@
+//! Identity written into the `file_id` message of files created by this crate
+use crate::profile::field_types::{File, Manufacturer};
+use crate::profile::{variant_to_value, FieldDataType, MesgNum};
+use crate::timestamp::{self, Timestamp};
+use crate::{FitDataField, FitDataRecord, Value};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Source of the `time_created` of a file
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeCreated {
+    /// Start of the data in the file, e.g. the first timestamp of an activity
+    #[default]
+    Start,
+    /// Time the file is created
+    Now,
+    /// A fixed time
+    At(Timestamp),
+}
+
+/// Source of the `serial_number` of a file
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SerialNumber {
+    /// Don't write a serial number
+    #[default]
+    Omit,
+    /// A fixed serial number, 0 is the invalid value of the field and is omitted
+    Fixed(u32),
+    /// A random serial number for every file, so platforms don't mistake two files for the same
+    Random,
+}
+
+/// Values of the `file_id` message of files created by the recorder and importers. The default
+/// writes the development manufacturer, product 0, no serial number and the start of the data
+/// as creation time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileIdPolicy {
+    /// Manufacturer of the file
+    pub manufacturer: Manufacturer,
+    /// Product id of the manufacturer
+    pub product: u16,
+    /// Serial number of the device that created the file
+    pub serial_number: SerialNumber,
+    /// Time the file was created
+    pub time_created: TimeCreated,
+}
+
+impl Default for FileIdPolicy {
+    fn default() -> Self {
+        FileIdPolicy {
+            manufacturer: Manufacturer::Development,
+            product: 0,
+            serial_number: SerialNumber::Omit,
+            time_created: TimeCreated::Start,
+        }
+    }
+}
+
+impl FileIdPolicy {
+    /// Policy creating identical files from identical input regardless of when they are written,
+    /// for tests and fixtures: serial number 1 and the FIT epoch as creation time
+    pub fn reproducible() -> Self {
+        FileIdPolicy {
+            serial_number: SerialNumber::Fixed(1),
+            time_created: TimeCreated::At(timestamp::from_fit_seconds(0)),
+            ..Default::default()
+        }
+    }
+
+    /// The serial number to write, a new one on every call for `SerialNumber::Random`
+    pub fn serial_number(&self) -> Option<u32> {
+        match self.serial_number {
+            SerialNumber::Omit | SerialNumber::Fixed(0) => None,
+            SerialNumber::Fixed(serial) => Some(serial),
+            SerialNumber::Random => {
+                let mut hasher = RandomState::new().build_hasher();
+                let now = SystemTime::now().duration_since(UNIX_EPOCH);
+                hasher.write_u128(now.unwrap_or_default().as_nanos());
+                Some((hasher.finish() as u32).max(1))
+            }
+        }
+    }
+
+    /// The creation time of a file whose data starts at `start`
+    pub fn time_created(&self, start: Timestamp) -> Timestamp {
+        match self.time_created {
+            TimeCreated::Start => start,
+            TimeCreated::Now => timestamp::now(),
+            TimeCreated::At(time) => time,
+        }
+    }
+
+    /// The `file_id` message of a file of the given type whose data starts at `start`, holding
+    /// the values as `from_bytes` returns them so it can be written with `to_bytes`
+    pub fn record(&self, file_type: File, start: Timestamp) -> FitDataRecord {
+        let mut record = FitDataRecord::new(MesgNum::FileId);
+        record.push(FitDataField::new(
+            "type".to_string(),
+            0,
+            variant_to_value(FieldDataType::File, file_type.as_i64()),
+            String::new(),
+        ));
+        record.extend(self.fields(start));
+        record
+    }
+
+    /// Replace the identity of the `file_id` messages of existing records with the values of the
+    /// policy, keeping their file type and other fields. The `time_created` of a message is
+    /// taken as the start of its data.
+    pub fn apply(&self, records: &mut [FitDataRecord]) {
+        for record in records.iter_mut().filter(|r| r.is_kind(MesgNum::FileId)) {
+            let start = match record.field("time_created").map(|f| f.value()) {
+                Some(Value::Timestamp(time)) => *time,
+                _ => timestamp::now(),
+            };
+            let fields = record.fields_mut();
+            fields.retain(|f| !matches!(f.number(), 1..=4));
+            fields.extend(self.fields(start));
+            fields.sort_by_key(|f| f.number());
+        }
+    }
+
+    /// The `manufacturer`, `product`, `serial_number` and `time_created` fields
+    fn fields(&self, start: Timestamp) -> Vec<FitDataField> {
+        let field = |name: &str, number, value, units: &str| {
+            FitDataField::new(name.to_string(), number, value, units.to_string())
+        };
+        let mut fields = vec![
+            field(
+                "manufacturer",
+                1,
+                variant_to_value(FieldDataType::Manufacturer, self.manufacturer.as_i64()),
+                "",
+            ),
+            field("product", 2, Value::UInt16(self.product), ""),
+        ];
+        if let Some(serial) = self.serial_number() {
+            fields.push(field("serial_number", 3, Value::UInt32z(serial), ""));
+        }
+        fields.push(field(
+            "time_created",
+            4,
+            Value::Timestamp(self.time_created(start)),
+            "s",
+        ));
+        fields
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_bytes, ser};
+
+    #[test]
+    fn file_id_policies() {
+        // 2023-03-01 07:00:00 UTC
+        let start = timestamp::local(1_677_654_000);
+        let policy = FileIdPolicy {
+            manufacturer: Manufacturer::Garmin,
+            product: 3121,
+            serial_number: SerialNumber::Fixed(3_900_000_001),
+            ..Default::default()
+        };
+        let records = vec![policy.record(File::Course, start)];
+        let decoded = from_bytes(&ser::to_bytes(&records).unwrap()).unwrap();
+        let value = |name| decoded[0].field(name).expect(name).value().clone();
+        assert_eq!(value("type"), Value::String("course".to_string()));
+        assert_eq!(value("manufacturer"), Value::String("garmin".to_string()));
+        assert_eq!(value("serial_number"), Value::UInt32z(3_900_000_001));
+        assert_eq!(value("time_created"), Value::Timestamp(start));
+
+        let mut records = decoded;
+        FileIdPolicy::reproducible().apply(&mut records);
+        assert_eq!(
+            records[0].field("type").unwrap().value(),
+            &Value::String("course".to_string())
+        );
+        assert_eq!(
+            records[0].field("manufacturer").unwrap().value(),
+            &Value::String("development".to_string())
+        );
+        assert_eq!(
+            ser::to_bytes(&records).unwrap(),
+            ser::to_bytes(&[FileIdPolicy::reproducible().record(File::Course, start)]).unwrap()
+        );
+
+        let random = FileIdPolicy {
+            serial_number: SerialNumber::Random,
+            ..Default::default()
+        };
+        assert!(random.serial_number().is_some());
+        assert_eq!(FileIdPolicy::default().serial_number(), None);
+    }
+}
@@ -0,0 +1,350 @@
+//! Encode records into the FIT binary format.
+//!
+//! `encode_message` writes a single data message, preceded by a definition message when the
+//! layout of the record hasn't been defined yet, without any file header or CRC. This is what
+//! devices stream over ANT or BLE, a `DefinitionState` tracks the definitions the receiving
+//! side already knows about.
+//!
+//! Values are written as they are stored in the record using the base type matching their
+//! `Value` variant, the scale, offset and enum names of the FIT profile aren't reversed. Records
+//! to encode should hold raw values, e.g. built by hand or decoded with
+//! `DecodeOption::ReturnNumericEnumValues` and `DecodeOption::SkipScaleAndOffset`.
+//!
+//! `FitEncoder` writes whole files from records as `from_bytes` returns them, using the FIT
+//! profile to turn the decoded values back into raw values.
+use crate::de::crc::{caculate_crc, update_crc};
+use crate::error::{ErrorKind, Result};
+use crate::profile::PROFILE_VERSION;
+use crate::timestamp::UnixSeconds;
+use crate::{FitDataRecord, Value};
+
+mod encoder;
+mod file_id;
+pub use encoder::{to_bytes, FitEncoder};
+pub use file_id::{FileIdPolicy, SerialNumber, TimeCreated};
+
+/// Seconds between the unix epoch and the FIT epoch of 1989-12-31T00:00:00Z
+pub(crate) const FIT_EPOCH_OFFSET: i64 = 631_065_600;
+
+/// Layout of a definition message, used to reuse local message numbers
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Layout {
+    pub(crate) global: u16,
+    /// Field number, size and base type of each field
+    pub(crate) fields: Vec<(u8, u8, u8)>,
+    /// Field number, size and developer data index of each developer field
+    pub(crate) developer_fields: Vec<(u8, u8, u8)>,
+}
+
+/// Definition messages written so far, local message numbers are handed out round robin and
+/// a layout that is still defined is reused without writing a new definition message
+#[derive(Clone, Debug, Default)]
+pub struct DefinitionState {
+    big_endian: bool,
+    locals: Vec<Layout>,
+    next_local: usize,
+}
+
+impl DefinitionState {
+    /// Create a state without any definitions using little endian definitions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write definition messages and the values of following data messages in big endian order
+    pub fn big_endian(mut self, big_endian: bool) -> Self {
+        self.big_endian = big_endian;
+        self
+    }
+
+    /// Check if values are written in big endian order
+    pub fn is_big_endian(&self) -> bool {
+        self.big_endian
+    }
+
+    /// Forget every definition, e.g. when the receiving side starts a new stream, the next
+    /// message of every layout is preceded by its definition again
+    pub fn reset(&mut self) {
+        self.locals.clear();
+        self.next_local = 0;
+    }
+
+    /// Find the local message number already holding the layout or write a new definition
+    /// message, local numbers are handed out round robin below the limit
+    pub(crate) fn local_message_number(
+        &mut self,
+        layout: Layout,
+        limit: usize,
+        data: &mut Vec<u8>,
+    ) -> u8 {
+        if let Some(local) = self.locals.iter().take(limit).position(|l| *l == layout) {
+            return local as u8;
+        }
+        let local = if self.next_local < limit {
+            self.next_local
+        } else {
+            0
+        };
+        self.next_local = (local + 1) % 16;
+
+        let has_developer_fields = !layout.developer_fields.is_empty();
+        let mut header = 0x40 | local as u8;
+        if has_developer_fields {
+            header |= 0x20;
+        }
+        data.push(header);
+        data.push(0); // reserved
+        data.push(self.big_endian as u8);
+        if self.big_endian {
+            data.extend_from_slice(&layout.global.to_be_bytes());
+        } else {
+            data.extend_from_slice(&layout.global.to_le_bytes());
+        }
+        data.push(layout.fields.len() as u8);
+        for (number, size, base_type) in &layout.fields {
+            data.extend_from_slice(&[*number, *size, *base_type]);
+        }
+        if has_developer_fields {
+            data.push(layout.developer_fields.len() as u8);
+            for (number, size, index) in &layout.developer_fields {
+                data.extend_from_slice(&[*number, *size, *index]);
+            }
+        }
+
+        if local < self.locals.len() {
+            self.locals[local] = layout;
+        } else {
+            self.locals.push(layout);
+        }
+        local as u8
+    }
+}
+
+/// FIT base type id used to encode the value, arrays use the type of their first element
+pub(crate) fn base_type(value: &Value) -> u8 {
+    match value {
+        Value::Enum(_) => 0x00,
+        Value::SInt8(_) => 0x01,
+        Value::UInt8(_) => 0x02,
+        Value::SInt16(_) => 0x83,
+        Value::UInt16(_) => 0x84,
+        Value::SInt32(_) => 0x85,
+        Value::UInt32(_)
+        | Value::Timestamp(_)
+        | Value::UtcTimestamp(_)
+        | Value::LocalTimestamp(_) => 0x86,
+        Value::String(_) => 0x07,
+        Value::Float32(_) => 0x88,
+        Value::Float64(_) | Value::Duration(_) => 0x89,
+        Value::UInt8z(_) => 0x0A,
+        Value::UInt16z(_) => 0x8B,
+        Value::UInt32z(_) => 0x8C,
+        Value::Byte(_) => 0x0D,
+        Value::SInt64(_) => 0x8E,
+        Value::UInt64(_) => 0x8F,
+        Value::UInt64z(_) => 0x90,
+        Value::Array(values) => values.first().map(base_type).unwrap_or(0x0D),
+    }
+}
+
+/// Number of bytes used to encode the value, strings include the null terminator
+pub(crate) fn encoded_size(value: &Value) -> usize {
+    match value {
+        Value::Enum(_) | Value::SInt8(_) | Value::UInt8(_) | Value::UInt8z(_) | Value::Byte(_) => 1,
+        Value::SInt16(_) | Value::UInt16(_) | Value::UInt16z(_) => 2,
+        Value::SInt32(_)
+        | Value::UInt32(_)
+        | Value::UInt32z(_)
+        | Value::Float32(_)
+        | Value::Timestamp(_)
+        | Value::UtcTimestamp(_)
+        | Value::LocalTimestamp(_) => 4,
+        Value::SInt64(_)
+        | Value::UInt64(_)
+        | Value::UInt64z(_)
+        | Value::Float64(_)
+        | Value::Duration(_) => 8,
+        Value::String(s) => s.len() + 1,
+        Value::Array(values) => values.iter().map(encoded_size).sum(),
+    }
+}
+
+/// Seconds since the FIT epoch stored by a timestamp, an error for times before the epoch or too
+/// far after it to fit the 32 bits of a `date_time` field
+fn fit_seconds(seconds: i64) -> Result<u32> {
+    u32::try_from(seconds - FIT_EPOCH_OFFSET).map_err(|_| {
+        ErrorKind::ValueError(format!(
+            "timestamp {} is outside the range of FIT timestamps",
+            seconds
+        ))
+        .into()
+    })
+}
+
+/// Append the bytes of a value, timestamps outside the range of FIT timestamps are an error
+pub(crate) fn encode_value(data: &mut Vec<u8>, value: &Value, big_endian: bool) -> Result<()> {
+    macro_rules! bytes {
+        ($val:expr) => {
+            if big_endian {
+                data.extend_from_slice(&$val.to_be_bytes())
+            } else {
+                data.extend_from_slice(&$val.to_le_bytes())
+            }
+        };
+    }
+    match value {
+        Value::Enum(v) | Value::UInt8(v) | Value::UInt8z(v) | Value::Byte(v) => data.push(*v),
+        Value::SInt8(v) => data.push(*v as u8),
+        Value::SInt16(v) => bytes!(v),
+        Value::UInt16(v) | Value::UInt16z(v) => bytes!(v),
+        Value::SInt32(v) => bytes!(v),
+        Value::UInt32(v) | Value::UInt32z(v) => bytes!(v),
+        Value::Float32(v) => bytes!(v),
+        Value::Float64(v) => bytes!(v),
+        Value::SInt64(v) => bytes!(v),
+        Value::UInt64(v) | Value::UInt64z(v) => bytes!(v),
+        Value::Timestamp(v) => bytes!(fit_seconds(v.unix_seconds())?),
+        Value::UtcTimestamp(v) => bytes!(fit_seconds(v.unix_seconds())?),
+        Value::LocalTimestamp(v) => bytes!(fit_seconds(v.unix_seconds())?),
+        Value::Duration(v) => bytes!(v.as_secs_f64()),
+        Value::String(s) => {
+            data.extend_from_slice(s.as_bytes());
+            data.push(0);
+        }
+        Value::Array(values) => {
+            for value in values {
+                encode_value(data, value, big_endian)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Wrap the encoded messages into a file using a 14 byte header with a CRC and the trailing
+/// file CRC
+pub(crate) fn file_bytes(data: &[u8]) -> Vec<u8> {
+    let mut file = vec![14, 0x20];
+    file.extend_from_slice(&PROFILE_VERSION.to_le_bytes());
+    file.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    file.extend_from_slice(b".FIT");
+    let header_crc = caculate_crc(&file);
+    file.extend_from_slice(&header_crc.to_le_bytes());
+    file.extend_from_slice(data);
+    let crc = update_crc(0, &file);
+    file.extend_from_slice(&crc.to_le_bytes());
+    file
+}
+
+/// Size of a field value checked against the single byte size of a field definition
+fn field_size(name: &str, value: &Value) -> Result<u8> {
+    match encoded_size(value) {
+        0 => Err(ErrorKind::EncodeError(format!("field '{}' has no value", name)).into()),
+        size => u8::try_from(size).map_err(|_| {
+            ErrorKind::EncodeError(format!("field '{}' is {} bytes long", name, size)).into()
+        }),
+    }
+}
+
+/// Encode a record as a data message, preceded by its definition message unless the state
+/// already holds a definition with the same layout. Only the first field with a given
+/// definition number is written, fields expanded from components are written like any other
+/// field.
+pub fn encode_message(record: &FitDataRecord, state: &mut DefinitionState) -> Result<Vec<u8>> {
+    let mut layout = Layout {
+        global: record.global_message_number(),
+        fields: Vec::new(),
+        developer_fields: Vec::new(),
+    };
+    let mut values = Vec::new();
+    let mut developer_values = Vec::new();
+    for field in record.fields() {
+        let size = field_size(field.name(), field.value())?;
+        match field.developer_info() {
+            Some(info) => {
+                let key = (field.number(), size, info.developer_data_index);
+                if !layout
+                    .developer_fields
+                    .iter()
+                    .any(|f| f.0 == key.0 && f.2 == key.2)
+                {
+                    layout.developer_fields.push(key);
+                    developer_values.push(field.value());
+                }
+            }
+            None => {
+                if !layout.fields.iter().any(|f| f.0 == field.number()) {
+                    layout
+                        .fields
+                        .push((field.number(), size, base_type(field.value())));
+                    values.push(field.value());
+                }
+            }
+        }
+    }
+    if layout.fields.len() > 255 || layout.developer_fields.len() > 255 {
+        return Err(ErrorKind::EncodeError("message has more than 255 fields".to_string()).into());
+    }
+
+    // encode the values first so a value that can't be written leaves the state untouched
+    let mut encoded = Vec::new();
+    for value in values.into_iter().chain(developer_values) {
+        encode_value(&mut encoded, value, state.big_endian)?;
+    }
+    let mut data = Vec::new();
+    let local = state.local_message_number(layout, 16, &mut data);
+    data.push(local);
+    data.extend_from_slice(&encoded);
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::MesgNum;
+    use crate::timestamp;
+    use crate::{from_bytes, FitDataField};
+
+    fn field(name: &str, number: u8, value: Value) -> FitDataField {
+        FitDataField::new(name.to_string(), number, value, String::new())
+    }
+
+    #[test]
+    fn encode_stream() {
+        let mut file_id = FitDataRecord::new(MesgNum::FileId);
+        file_id.push(field("type", 0, Value::Enum(4)));
+        let mut record = FitDataRecord::new(MesgNum::Record);
+        record.push(field("heart_rate", 3, Value::UInt8(142)));
+        record.push(field("power", 7, Value::UInt16(250)));
+
+        let mut state = DefinitionState::new();
+        let mut data = encode_message(&file_id, &mut state).unwrap();
+        let first = encode_message(&record, &mut state).unwrap();
+        let second = encode_message(&record, &mut state).unwrap();
+        // definition of two fields followed by the data message of local message 1
+        assert_eq!(first.len(), 12 + 4);
+        assert_eq!(second, [1, 142, 250, 0]);
+        data.extend(first);
+        data.extend(second);
+
+        // the stream decodes once it's wrapped into a file
+        let records = from_bytes(&file_bytes(&data)).unwrap();
+        assert_eq!(records.len(), 3);
+        assert_eq!(
+            records[2].field("power").unwrap().value(),
+            &Value::UInt16(250)
+        );
+
+        let mut record = FitDataRecord::new(MesgNum::Record);
+        record.push(field("name", 1, Value::String("x".repeat(300))));
+        assert!(encode_message(&record, &mut state).is_err());
+
+        // timestamps before the FIT epoch can't be stored and don't define a message
+        let mut record = FitDataRecord::new(MesgNum::Record);
+        let time = timestamp::from_fit_seconds(-1);
+        record.push(field("timestamp", 253, Value::Timestamp(time)));
+        assert!(encode_message(&record, &mut state).is_err());
+        let time = timestamp::from_fit_seconds(0);
+        record.fields_mut()[0] = field("timestamp", 253, Value::Timestamp(time));
+        assert_eq!(encode_message(&record, &mut state).unwrap()[0], 0x42);
+    }
+}
@@ -0,0 +1,198 @@
+//! Compare the settings stored on a device, e.g. the settings files read before and after a
+//! firmware update or a sync.
+//!
+//! Only messages describing device, user, sport and sensor settings are compared, messages of the
+//! same kind are paired by their `message_index` field, or by their order when they don't have
+//! one. Timestamps and fields unknown to the FIT profile are ignored since they change without
+//! the user changing anything.
+use crate::format::default_registry;
+use crate::profile::MesgNum;
+use crate::{FitDataField, FitDataRecord, Value};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Messages holding device and user settings
+pub const SETTINGS_MESSAGES: &[MesgNum] = MesgNum::SETTINGS_MESSAGES;
+
+/// How a setting changed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// The setting only exists in the newer file
+    Added,
+    /// The setting only exists in the older file
+    Removed,
+    /// The setting has a different value
+    Changed,
+}
+
+/// A setting that differs between two settings files
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SettingChange {
+    /// Message holding the setting
+    #[serde(serialize_with = "crate::serialize_kind")]
+    pub message: MesgNum,
+    /// Instance of the message, the `message_index` or the position among messages of its kind
+    pub instance: usize,
+    /// Name of the changed field
+    pub field: String,
+    /// Units of the field
+    pub units: String,
+    /// Value in the older file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Value>,
+    /// Value in the newer file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Value>,
+}
+
+impl SettingChange {
+    /// How the setting changed
+    pub fn kind(&self) -> ChangeKind {
+        match (&self.before, &self.after) {
+            (None, _) => ChangeKind::Added,
+            (_, None) => ChangeKind::Removed,
+            _ => ChangeKind::Changed,
+        }
+    }
+
+    /// Field name in plain words, e.g. `auto_activity_detect` becomes `auto activity detect`
+    pub fn label(&self) -> String {
+        self.field.replace('_', " ")
+    }
+
+    fn format_value(&self, value: &Value) -> String {
+        default_registry().format(value, &self.units)
+    }
+}
+
+impl fmt::Display for SettingChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if self.instance > 0 {
+            write!(f, " #{}", self.instance)?;
+        }
+        write!(f, ": {} ", self.label())?;
+        match (&self.before, &self.after) {
+            (Some(before), Some(after)) => write!(
+                f,
+                "changed from {} to {}",
+                self.format_value(before),
+                self.format_value(after)
+            ),
+            (None, Some(after)) => write!(f, "set to {}", self.format_value(after)),
+            (Some(before), None) => write!(f, "no longer set, was {}", self.format_value(before)),
+            (None, None) => write!(f, "unchanged"),
+        }
+    }
+}
+
+/// Check if the record holds device or user settings
+pub fn is_settings_message(record: &FitDataRecord) -> bool {
+    record.kind().is_settings_message()
+}
+
+fn is_compared(field: &FitDataField) -> bool {
+    !field.is_unknown() && !matches!(field.name(), "timestamp" | "message_index")
+}
+
+/// Index the settings messages by their kind and instance
+fn settings_by_instance(records: &[FitDataRecord]) -> BTreeMap<(MesgNum, usize), &FitDataRecord> {
+    let mut counts: BTreeMap<MesgNum, usize> = BTreeMap::new();
+    let mut instances = BTreeMap::new();
+    for record in records.iter().filter(|r| is_settings_message(r)) {
+        let position = counts.entry(record.kind()).or_insert(0);
+        let instance = match record.field("message_index").map(|f| f.value()) {
+            Some(Value::UInt16(index)) => *index as usize,
+            _ => *position,
+        };
+        *position += 1;
+        instances.insert((record.kind(), instance), record);
+    }
+    instances
+}
+
+/// List every setting that was added, removed or changed between the older and newer records,
+/// ordered by message, instance and field name
+pub fn diff_settings(before: &[FitDataRecord], after: &[FitDataRecord]) -> Vec<SettingChange> {
+    let before = settings_by_instance(before);
+    let after = settings_by_instance(after);
+    let mut keys: Vec<&(MesgNum, usize)> = before.keys().chain(after.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    let mut changes = Vec::new();
+    for key in keys {
+        let mut fields: BTreeMap<&str, (Option<&FitDataField>, Option<&FitDataField>)> =
+            BTreeMap::new();
+        for field in before.get(key).iter().flat_map(|r| r.fields()) {
+            if is_compared(field) {
+                fields.entry(field.name()).or_default().0 = Some(field);
+            }
+        }
+        for field in after.get(key).iter().flat_map(|r| r.fields()) {
+            if is_compared(field) {
+                fields.entry(field.name()).or_default().1 = Some(field);
+            }
+        }
+        for (name, (old, new)) in fields {
+            if old.map(|f| f.value()) == new.map(|f| f.value()) {
+                continue;
+            }
+            let units = new.or(old).map_or("", |f| f.units());
+            changes.push(SettingChange {
+                message: key.0,
+                instance: key.1,
+                field: name.to_string(),
+                units: units.to_string(),
+                before: old.map(|f| f.value().clone()),
+                after: new.map(|f| f.value().clone()),
+            });
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_bytes;
+
+    #[test]
+    fn diff_user_profile() {
+        let data = include_bytes!("../../tests/fixtures/Settings.fit");
+        let before = from_bytes(data).unwrap();
+        assert!(diff_settings(&before, &before).is_empty());
+
+        let mut after = before.clone();
+        let profile = after
+            .iter_mut()
+            .find(|r| r.is_kind(MesgNum::UserProfile))
+            .unwrap();
+        profile.fields_mut().retain(|f| f.name() != "age");
+        for field in profile.fields_mut() {
+            if field.name() == "weight" {
+                field.set_value(Value::Float64(88.5));
+            }
+        }
+        profile.push(FitDataField::new(
+            "resting_heart_rate".to_string(),
+            8,
+            Value::UInt8(48),
+            "bpm".to_string(),
+        ));
+
+        let changes = diff_settings(&before, &after);
+        let text: Vec<String> = changes.iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            text,
+            [
+                "user_profile: age no longer set, was 28 years",
+                "user_profile: resting heart rate set to 48 bpm",
+                "user_profile: weight changed from 90 kg to 88.5 kg",
+            ]
+        );
+        assert_eq!(changes[1].kind(), ChangeKind::Added);
+    }
+}
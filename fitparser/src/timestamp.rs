@@ -0,0 +1,326 @@
+//! Date and time types of timestamp values.
+//!
+//! Timestamps are backed by [chrono](https://docs.rs/chrono) by default or by the
+//! [time](https://docs.rs/time) crate with the `time` feature. The modules computing with dates,
+//! such as `analysis` and `monitoring`, work with chrono internally and convert timestamp values
+//! with `to_chrono` and `from_chrono`, so they're available with either backend.
+//!
+//! The time crate can only look up the local time zone while the process runs a single thread on
+//! most unix systems, times are kept in UTC when the offset can't be determined.
+use crate::ser::FIT_EPOCH_OFFSET;
+use chrono::{DateTime, Local};
+#[cfg(not(feature = "time"))]
+use chrono::{NaiveDateTime, TimeZone, Utc};
+#[cfg(feature = "time")]
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+/// Timestamp converted to the local time zone, `Value::Timestamp`
+#[cfg(not(feature = "time"))]
+pub type Timestamp = DateTime<Local>;
+/// Timestamp converted to the local time zone, `Value::Timestamp`
+#[cfg(feature = "time")]
+pub type Timestamp = OffsetDateTime;
+
+/// Timestamp kept in UTC, `Value::UtcTimestamp`
+#[cfg(not(feature = "time"))]
+pub type UtcTimestamp = DateTime<Utc>;
+/// Timestamp kept in UTC, `Value::UtcTimestamp`
+#[cfg(feature = "time")]
+pub type UtcTimestamp = OffsetDateTime;
+
+/// Wall clock time without a time zone, `Value::LocalTimestamp`
+#[cfg(not(feature = "time"))]
+pub type LocalTimestamp = NaiveDateTime;
+/// Wall clock time without a time zone, `Value::LocalTimestamp`
+#[cfg(feature = "time")]
+pub type LocalTimestamp = PrimitiveDateTime;
+
+/// Offset of a time zone from UTC, see `DecodeOption::local_time_offset`
+#[cfg(not(feature = "time"))]
+pub type UtcOffset = chrono::FixedOffset;
+/// Offset of a time zone from UTC, see `DecodeOption::local_time_offset`
+#[cfg(feature = "time")]
+pub type UtcOffset = time::UtcOffset;
+
+/// Seconds since the unix epoch, wall clock times count as if they were UTC
+pub(crate) trait UnixSeconds {
+    fn unix_seconds(&self) -> i64;
+}
+
+#[cfg(not(feature = "time"))]
+impl<Tz: TimeZone> UnixSeconds for DateTime<Tz> {
+    fn unix_seconds(&self) -> i64 {
+        self.timestamp()
+    }
+}
+
+#[cfg(not(feature = "time"))]
+impl UnixSeconds for NaiveDateTime {
+    fn unix_seconds(&self) -> i64 {
+        self.and_utc().timestamp()
+    }
+}
+
+#[cfg(feature = "time")]
+impl UnixSeconds for OffsetDateTime {
+    fn unix_seconds(&self) -> i64 {
+        self.unix_timestamp()
+    }
+}
+
+#[cfg(feature = "time")]
+impl UnixSeconds for PrimitiveDateTime {
+    fn unix_seconds(&self) -> i64 {
+        self.assume_utc().unix_timestamp()
+    }
+}
+
+/// Milliseconds since the unix epoch of a timestamp
+#[cfg(not(feature = "time"))]
+pub(crate) fn unix_millis(time: &Timestamp) -> i64 {
+    time.timestamp_millis()
+}
+
+/// Milliseconds since the unix epoch of a timestamp
+#[cfg(feature = "time")]
+pub(crate) fn unix_millis(time: &Timestamp) -> i64 {
+    (time.unix_timestamp_nanos() / 1_000_000) as i64
+}
+
+/// Whether the build keeps every time in UTC. The `wasm` build doesn't look up the time zone of
+/// the browser, local date times hold the wall clock time of the device as if it were UTC.
+const ALWAYS_UTC: bool = cfg!(all(feature = "wasm", target_arch = "wasm32"));
+
+/// Timestamp in UTC of seconds since the unix epoch
+#[cfg(not(feature = "time"))]
+pub(crate) fn utc(seconds: i64) -> UtcTimestamp {
+    DateTime::from_timestamp(seconds, 0).unwrap_or_default()
+}
+
+/// Timestamp in UTC of seconds since the unix epoch
+#[cfg(feature = "time")]
+pub(crate) fn utc(seconds: i64) -> UtcTimestamp {
+    OffsetDateTime::from_unix_timestamp(seconds).unwrap_or(OffsetDateTime::UNIX_EPOCH)
+}
+
+/// Timestamp in the local time zone of seconds since the unix epoch
+pub(crate) fn local(seconds: i64) -> Timestamp {
+    utc_to_local(utc(seconds))
+}
+
+/// Timestamp in the local time zone of seconds since the FIT epoch, 1989-12-31 00:00:00 UTC
+pub(crate) fn from_fit_seconds(seconds: i64) -> Timestamp {
+    local(seconds + FIT_EPOCH_OFFSET)
+}
+
+/// Convert a UTC time into the local time zone
+#[cfg(not(feature = "time"))]
+pub(crate) fn utc_to_local(time: UtcTimestamp) -> Timestamp {
+    chrono_local(time)
+}
+
+/// Convert a chrono UTC time into the local time zone
+fn chrono_local(time: DateTime<chrono::Utc>) -> DateTime<Local> {
+    if ALWAYS_UTC {
+        DateTime::from_naive_utc_and_offset(
+            time.naive_utc(),
+            chrono::FixedOffset::east_opt(0).unwrap(),
+        )
+    } else {
+        time.with_timezone(&Local)
+    }
+}
+
+/// Convert a UTC time into the local time zone
+#[cfg(feature = "time")]
+pub(crate) fn utc_to_local(time: UtcTimestamp) -> Timestamp {
+    match time::UtcOffset::local_offset_at(time) {
+        Ok(offset) if !ALWAYS_UTC => time.to_offset(offset),
+        _ => time,
+    }
+}
+
+/// Convert a timestamp into UTC
+#[cfg(not(feature = "time"))]
+pub(crate) fn to_utc(time: &Timestamp) -> UtcTimestamp {
+    time.with_timezone(&Utc)
+}
+
+/// Convert a timestamp into UTC
+#[cfg(feature = "time")]
+pub(crate) fn to_utc(time: &Timestamp) -> UtcTimestamp {
+    time.to_offset(time::UtcOffset::UTC)
+}
+
+/// Timestamp as a chrono date time in the local time zone, for the modules computing with dates
+#[cfg(not(feature = "time"))]
+pub(crate) fn to_chrono(time: &Timestamp) -> DateTime<Local> {
+    *time
+}
+
+/// Timestamp as a chrono date time in the local time zone, for the modules computing with dates
+#[cfg(feature = "time")]
+pub(crate) fn to_chrono(time: &Timestamp) -> DateTime<Local> {
+    let utc = DateTime::from_timestamp(time.unix_timestamp(), time.nanosecond());
+    chrono_local(utc.unwrap_or_default())
+}
+
+/// Timestamp of a chrono date time computed by the modules working with dates
+#[cfg(not(feature = "time"))]
+pub(crate) fn from_chrono(time: DateTime<Local>) -> Timestamp {
+    time
+}
+
+/// Timestamp of a chrono date time computed by the modules working with dates
+#[cfg(feature = "time")]
+pub(crate) fn from_chrono(time: DateTime<Local>) -> Timestamp {
+    let nanos = time::Duration::nanoseconds(time.timestamp_subsec_nanos().into());
+    utc_to_local(utc(time.timestamp()) + nanos)
+}
+
+/// Wall clock time as a chrono date time, for the modules computing with dates
+pub(crate) fn wall_clock_to_chrono(time: &LocalTimestamp) -> chrono::NaiveDateTime {
+    let time = DateTime::from_timestamp(time.unix_seconds(), 0).unwrap_or_default();
+    time.naive_utc()
+}
+
+/// Wall clock time of seconds since the unix epoch
+#[cfg(not(feature = "time"))]
+pub(crate) fn wall_clock(seconds: i64) -> LocalTimestamp {
+    utc(seconds).naive_utc()
+}
+
+/// Wall clock time of seconds since the unix epoch
+#[cfg(feature = "time")]
+pub(crate) fn wall_clock(seconds: i64) -> LocalTimestamp {
+    let time = utc(seconds);
+    PrimitiveDateTime::new(time.date(), time.time())
+}
+
+/// Wall clock time shown by a timestamp
+#[cfg(not(feature = "time"))]
+pub(crate) fn to_wall_clock(time: &Timestamp) -> LocalTimestamp {
+    time.naive_local()
+}
+
+/// Wall clock time shown by a timestamp
+#[cfg(feature = "time")]
+pub(crate) fn to_wall_clock(time: &Timestamp) -> LocalTimestamp {
+    PrimitiveDateTime::new(time.date(), time.time())
+}
+
+/// Interpret a wall clock time in the local time zone, `None` if the time doesn't exist there.
+/// The earliest time is returned for wall clock times occurring twice.
+#[cfg(not(feature = "time"))]
+pub(crate) fn from_wall_clock(time: &LocalTimestamp) -> Option<Timestamp> {
+    if ALWAYS_UTC {
+        Some(utc_to_local(time.and_utc()))
+    } else {
+        Local.from_local_datetime(time).earliest()
+    }
+}
+
+/// Interpret a wall clock time in the local time zone using the offset in effect at the same
+/// time in UTC, which may be off by the change around changes of the offset
+#[cfg(feature = "time")]
+pub(crate) fn from_wall_clock(time: &LocalTimestamp) -> Option<Timestamp> {
+    match time::UtcOffset::local_offset_at(time.assume_utc()) {
+        Ok(offset) if !ALWAYS_UTC => Some(time.assume_offset(offset)),
+        _ => Some(time.assume_utc()),
+    }
+}
+
+/// Current time in the local time zone
+#[cfg(not(feature = "time"))]
+pub(crate) fn now() -> Timestamp {
+    Local::now()
+}
+
+/// Current time in the local time zone
+#[cfg(feature = "time")]
+pub(crate) fn now() -> Timestamp {
+    utc_to_local(OffsetDateTime::now_utc())
+}
+
+/// Seconds east of UTC of an offset
+#[cfg(not(feature = "time"))]
+pub(crate) fn offset_seconds(offset: UtcOffset) -> i32 {
+    offset.local_minus_utc()
+}
+
+/// Seconds east of UTC of an offset
+#[cfg(feature = "time")]
+pub(crate) fn offset_seconds(offset: UtcOffset) -> i32 {
+    offset.whole_seconds()
+}
+
+/// Parse a timestamp in RFC 3339 format into the local time zone
+#[cfg(not(feature = "time"))]
+pub(crate) fn parse_rfc3339(text: &str) -> Option<Timestamp> {
+    let time = DateTime::parse_from_rfc3339(text).ok()?;
+    Some(utc_to_local(time.with_timezone(&Utc)))
+}
+
+/// Parse a timestamp in RFC 3339 format into the local time zone
+#[cfg(feature = "time")]
+pub(crate) fn parse_rfc3339(text: &str) -> Option<Timestamp> {
+    let time = OffsetDateTime::parse(text, &time::format_description::well_known::Rfc3339).ok()?;
+    Some(utc_to_local(time))
+}
+
+/// Parse a wall clock time in the form it is serialized, e.g. `2023-03-01T07:00:00`
+#[cfg(not(feature = "time"))]
+pub(crate) fn parse_wall_clock(text: &str) -> Option<LocalTimestamp> {
+    text.parse().ok()
+}
+
+/// Parse a wall clock time in the form it is serialized, e.g. `2023-03-01T07:00:00`
+#[cfg(feature = "time")]
+pub(crate) fn parse_wall_clock(text: &str) -> Option<LocalTimestamp> {
+    PrimitiveDateTime::parse(text, WALL_CLOCK_FORMAT).ok()
+}
+
+/// Format of serialized wall clock times
+#[cfg(feature = "time")]
+const WALL_CLOCK_FORMAT: &[time::format_description::BorrowedFormatItem] =
+    time::macros::format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]");
+
+/// Serialize timestamps in RFC 3339 format like chrono does
+#[cfg(feature = "time")]
+pub(crate) fn serialize_timestamp<S>(
+    time: &OffsetDateTime,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    time::serde::rfc3339::serialize(time, serializer)
+}
+
+/// Serialize wall clock times like chrono does, e.g. `2023-03-01T07:00:00`
+#[cfg(feature = "time")]
+pub(crate) fn serialize_wall_clock<S>(
+    time: &PrimitiveDateTime,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::Error;
+    let text = time.format(WALL_CLOCK_FORMAT).map_err(S::Error::custom)?;
+    serializer.serialize_str(&text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let time = from_fit_seconds(1_000_000_000);
+        assert_eq!(time.unix_seconds(), 1_000_000_000 + FIT_EPOCH_OFFSET);
+        assert_eq!(to_utc(&time).unix_seconds(), time.unix_seconds());
+        assert_eq!(from_wall_clock(&to_wall_clock(&time)), Some(time));
+        assert_eq!(wall_clock(86_400 + 3_600).unix_seconds(), 86_400 + 3_600);
+    }
+}
@@ -0,0 +1,318 @@
+//! Check FIT files against the structural rules of the FIT protocol.
+//!
+//! The decoder is lenient where it can be, `conformance` instead reports every rule a file breaks
+//! along with the byte offset so it can serve as a test oracle for firmware producing FIT files.
+//! Each rule has a stable identifier that can be used to filter or track violations.
+use crate::de::crc::caculate_crc;
+use crate::de::parser::{fit_message, FitDefinitionMessage, FitMessage};
+use crate::profile::MesgNum;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Structural rules checked by `conformance`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Rule {
+    /// The header size must be 12 or 14 bytes
+    HeaderSize,
+    /// The header must contain the `.FIT` signature
+    HeaderSignature,
+    /// A non zero header CRC must match the first 12 header bytes
+    HeaderCrc,
+    /// The data size in the header must fit in the file, including the trailing CRC
+    DataSize,
+    /// The first data message of a file must be a `file_id` message
+    FileIdFirst,
+    /// A data message must use a local message number defined by an earlier definition message
+    DefinitionBeforeData,
+    /// Definition messages must have a zero reserved byte and an architecture of 0 or 1
+    DefinitionHeader,
+    /// Compressed timestamp headers require an earlier message with a full timestamp
+    CompressedTimestamp,
+    /// Every message must be complete and parsable
+    MessageStructure,
+    /// The CRC following the data records must match the header and data records
+    FileCrc,
+}
+
+impl Rule {
+    /// Stable identifier of the rule
+    pub fn id(&self) -> &'static str {
+        match self {
+            Rule::HeaderSize => "HDR-001",
+            Rule::HeaderSignature => "HDR-002",
+            Rule::HeaderCrc => "HDR-003",
+            Rule::DataSize => "HDR-004",
+            Rule::FileIdFirst => "MSG-001",
+            Rule::DefinitionBeforeData => "MSG-002",
+            Rule::DefinitionHeader => "MSG-003",
+            Rule::CompressedTimestamp => "MSG-004",
+            Rule::MessageStructure => "MSG-005",
+            Rule::FileCrc => "CRC-001",
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+/// A single broken rule
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    /// Rule that was broken
+    pub rule: Rule,
+    /// Absolute byte offset of the header or message breaking the rule
+    pub offset: usize,
+    /// Human readable description
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {} at {:#x}", self.rule, self.message, self.offset)
+    }
+}
+
+/// Result of checking a FIT stream, chained files are checked one after the other
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConformanceReport {
+    /// Number of FIT files found in the stream
+    pub files: usize,
+    /// Violations in the order they were found
+    pub violations: Vec<Violation>,
+}
+
+impl ConformanceReport {
+    /// Check if the stream did not break any rule
+    pub fn is_conformant(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Iterate over the violations of a single rule
+    pub fn violations_of(&self, rule: Rule) -> impl Iterator<Item = &Violation> {
+        self.violations.iter().filter(move |v| v.rule == rule)
+    }
+
+    fn push(&mut self, rule: Rule, offset: usize, message: String) {
+        self.violations.push(Violation {
+            rule,
+            offset,
+            message,
+        });
+    }
+}
+
+/// Check a FIT stream against the structural rules of the protocol. Checking stops at the first
+/// violation that makes the rest of the stream unreadable, e.g. a truncated message.
+pub fn conformance(bytes: &[u8]) -> ConformanceReport {
+    let mut report = ConformanceReport::default();
+    let mut start = 0;
+    while start < bytes.len() {
+        report.files += 1;
+        match check_file(bytes, start, &mut report) {
+            Some(next) => start = next,
+            None => break,
+        }
+    }
+    report
+}
+
+/// Check the file starting at the offset, returning the offset of the next chained file when
+/// parsing can continue
+fn check_file(bytes: &[u8], start: usize, report: &mut ConformanceReport) -> Option<usize> {
+    let file = &bytes[start..];
+    if file.len() < 12 {
+        report.push(
+            Rule::HeaderSize,
+            start,
+            format!("{} bytes are too short for a file header", file.len()),
+        );
+        return None;
+    }
+    let header_size = file[0] as usize;
+    if header_size != 12 && header_size != 14 {
+        report.push(
+            Rule::HeaderSize,
+            start,
+            format!("header size is {} bytes", header_size),
+        );
+        if header_size < 12 || header_size > file.len() {
+            return None;
+        }
+    }
+    if &file[8..12] != b".FIT" {
+        report.push(
+            Rule::HeaderSignature,
+            start + 8,
+            "missing .FIT signature".to_string(),
+        );
+        return None;
+    }
+    if header_size >= 14 {
+        let crc = u16::from_le_bytes([file[12], file[13]]);
+        let calculated = caculate_crc(&file[..12]);
+        if crc != 0 && crc != calculated {
+            report.push(
+                Rule::HeaderCrc,
+                start + 12,
+                format!("header CRC is {:#06x}, calculated {:#06x}", crc, calculated),
+            );
+        }
+    }
+
+    let data_size = u32::from_le_bytes([file[4], file[5], file[6], file[7]]) as usize;
+    let mut data_end = header_size + data_size;
+    let has_crc = data_end + 2 <= file.len();
+    if !has_crc {
+        report.push(
+            Rule::DataSize,
+            start + 4,
+            format!(
+                "data size of {} bytes exceeds the {} bytes available",
+                data_size,
+                file.len().saturating_sub(header_size + 2)
+            ),
+        );
+        data_end = file.len().saturating_sub(2).max(header_size);
+    }
+
+    check_messages(&file[..data_end], start, header_size, report);
+
+    if !has_crc {
+        return None;
+    }
+    let crc = u16::from_le_bytes([file[data_end], file[data_end + 1]]);
+    let calculated = caculate_crc(&file[..data_end]);
+    if crc != calculated {
+        report.push(
+            Rule::FileCrc,
+            start + data_end,
+            format!("file CRC is {:#06x}, calculated {:#06x}", crc, calculated),
+        );
+    }
+    Some(start + data_end + 2)
+}
+
+/// Check the messages in the data records of a single file
+fn check_messages(file: &[u8], start: usize, header_size: usize, report: &mut ConformanceReport) {
+    let mut definitions: HashMap<u8, Arc<FitDefinitionMessage>> = HashMap::new();
+    let mut seen_data = false;
+    let mut has_timestamp = false;
+    let mut position = header_size;
+    while position < file.len() {
+        let offset = start + position;
+        let input = &file[position..];
+        let is_definition = input[0] & 0xC0 == 0x40;
+        if is_definition && input.len() >= 3 && (input[1] != 0 || input[2] > 1) {
+            report.push(
+                Rule::DefinitionHeader,
+                offset,
+                format!(
+                    "reserved byte is {} and architecture is {}",
+                    input[1], input[2]
+                ),
+            );
+        }
+        let remaining = match fit_message(input, &definitions) {
+            Ok((remaining, FitMessage::Definition(definition))) => {
+                definitions.insert(definition.local_message_number(), Arc::new(definition));
+                remaining
+            }
+            Ok((remaining, FitMessage::Data(message))) => {
+                if !seen_data && MesgNum::from(message.global_message_number()) != MesgNum::FileId {
+                    report.push(
+                        Rule::FileIdFirst,
+                        offset,
+                        format!(
+                            "first data message has global message number {}",
+                            message.global_message_number()
+                        ),
+                    );
+                }
+                seen_data = true;
+                if message.time_offset().is_some() && !has_timestamp {
+                    report.push(
+                        Rule::CompressedTimestamp,
+                        offset,
+                        "compressed timestamp header without an earlier timestamp".to_string(),
+                    );
+                }
+                has_timestamp |= message.fields().contains_key(&253);
+                remaining
+            }
+            Ok((_, FitMessage::MissingDefinitionMessage(local))) => {
+                report.push(
+                    Rule::DefinitionBeforeData,
+                    offset,
+                    format!("no definition for local message number {}", local),
+                );
+                return;
+            }
+            Err(_) => {
+                report.push(
+                    Rule::MessageStructure,
+                    offset,
+                    "message is truncated or malformed".to_string(),
+                );
+                return;
+            }
+        };
+        position = file.len() - remaining.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::FitFileBuilder;
+    use crate::Value;
+
+    fn builder() -> FitFileBuilder {
+        FitFileBuilder::new()
+            .message(MesgNum::FileId, &[(0, Value::Enum(4))])
+            .message(
+                MesgNum::Record,
+                &[(253, Value::UInt32(1000)), (3, Value::UInt8(120))],
+            )
+            .compressed_timestamp_message(MesgNum::Record, 10, &[(3, Value::UInt8(121))])
+    }
+
+    #[test]
+    fn check_rules() {
+        let report = conformance(include_bytes!("../../tests/fixtures/Activity.fit"));
+        assert!(report.is_conformant(), "{:?}", report);
+        assert_eq!(report.files, 1);
+
+        let file = builder().build();
+        let report = conformance(&[file.clone(), file.clone()].concat());
+        assert!(report.is_conformant(), "{:?}", report);
+        assert_eq!(report.files, 2);
+
+        let mut corrupt = file.clone();
+        let last = corrupt.len() - 1;
+        corrupt[last] ^= 0xFF;
+        corrupt[13] ^= 0xFF;
+        let report = conformance(&corrupt);
+        let rules: Vec<&str> = report.violations.iter().map(|v| v.rule.id()).collect();
+        assert_eq!(rules, ["HDR-003", "CRC-001"]);
+
+        let file = FitFileBuilder::new()
+            .compressed_timestamp_message(MesgNum::Record, 10, &[(3, Value::UInt8(121))])
+            .build();
+        let report = conformance(&file);
+        assert_eq!(report.violations_of(Rule::FileIdFirst).count(), 1);
+        assert_eq!(report.violations_of(Rule::CompressedTimestamp).count(), 1);
+
+        // drop the first definition message so the file_id data message has none
+        let mut file = builder().build();
+        file.drain(14..23);
+        let report = conformance(&file);
+        let missing: Vec<_> = report.violations_of(Rule::DefinitionBeforeData).collect();
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].offset, 14);
+        assert_eq!(report.violations_of(Rule::DataSize).count(), 1);
+    }
+}
@@ -0,0 +1,35 @@
+//! JavaScript bindings to decode FIT files in the browser, enabled by the `wasm` feature.
+//!
+//! Build for the `wasm32-unknown-unknown` target with `wasm-bindgen`, e.g.
+//! `wasm-pack build --features wasm`, from a crate depending on `fitparser` or this one. The
+//! time zone of the browser isn't looked up, timestamps are decoded in UTC.
+use wasm_bindgen::prelude::*;
+
+/// Decode the bytes of a FIT file into the JSON array of its records, the same output as
+/// serializing the records of `from_bytes`. Files that can't be decoded return a JSON object
+/// holding the error message in its `error` field.
+#[wasm_bindgen]
+pub fn parse_fit_to_json(bytes: &[u8]) -> String {
+    let json = crate::from_bytes(bytes)
+        .map_err(|e| e.to_string())
+        .and_then(|records| serde_json::to_string(&records).map_err(|e| e.to_string()));
+    match json {
+        Ok(json) => json,
+        Err(message) => serde_json::json!({ "error": message }).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_to_json() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let json: serde_json::Value = serde_json::from_str(&parse_fit_to_json(data)).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 22);
+        let json: serde_json::Value =
+            serde_json::from_str(&parse_fit_to_json(&data[..20])).unwrap();
+        assert!(json["error"].is_string());
+    }
+}
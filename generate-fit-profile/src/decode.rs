@@ -50,9 +50,13 @@ fn decode_function_def(msg: &MessageDefinition) -> TokenStream {
 
     quote! {
         #(#comments)*
-        fn #fn_name(mesg_num: MesgNum, data_map: &mut HashMap<u8, Value>, accumlators: &mut HashMap<u32, Value>, options: &HashSet<DecodeOption>) -> Result<Vec<FitDataField>> {
+        fn #fn_name(mesg_num: MesgNum, data_map: &mut HashMap<u8, Value>, accumlators: &mut Accumulators, options: &HashSet<DecodeOption>) -> Result<Vec<FitDataField>> {
             let mut fields = Vec::new();
-            let mut entries: VecDeque<(u8, Value)> = data_map.iter().map(|(k, v)| (*k, v.clone())).collect();
+            // fields are processed in definition number order so the output doesn't depend on
+            // the iteration order of the hash map
+            let mut entries: Vec<(u8, Value)> = data_map.iter().map(|(k, v)| (*k, v.clone())).collect();
+            entries.sort_by_key(|(k, _)| *k);
+            let mut entries = VecDeque::from(entries);
             while let Some((def_num, value)) = entries.pop_front() {
                 match def_num {
                     #(#match_arms)*
@@ -247,7 +251,7 @@ fn subfield_deref(
         };
 
         deref_branches.push(quote!{
-                #elif #ref_field_ident::#ref_val_ident.as_i64() == data_map.get(&#ref_def_num).map(|v| v.try_into().ok()).flatten().unwrap_or(-1i64) {
+                #elif #ref_field_ident::#ref_val_ident.as_i64() == data_map.get(&#ref_def_num).and_then(|v| v.try_into().ok()).unwrap_or(-1i64) {
                     #body
                 }
             });
@@ -267,6 +271,21 @@ fn create_fn_def(mesg_def: &MessageDefinition, fld_def: &MessageFieldDefinition)
     let fld_type_variant = fld_def.field_type();
     let fld_type = quote!(FieldDataType::#fld_type_variant);
     let field_fn_name = format_ident!("{}_{}_field", mesg_def.function_name(), fld_def.name());
+    // only the fields components are accumulated into need accumlator code, values of the field
+    // stored in full reset the running total
+    let accumulator_code = if accumulated_fields(mesg_def).contains(&def_number) {
+        quote! {
+            let value = match accumulate {
+                Some(bits) => accumlators.accumulate(mesg_num, #def_number, value, bits, scale, offset)?,
+                None => {
+                    accumlators.set(mesg_num, #def_number, &value, scale, offset);
+                    value
+                }
+            };
+        }
+    } else {
+        quote!()
+    };
     // tokens to generate data field
     let data_field_call = if let Some(parent) = fld_def.parent_field() {
         let parent_name = parent.name();
@@ -276,36 +295,51 @@ fn create_fn_def(mesg_def: &MessageDefinition, fld_def: &MessageFieldDefinition)
             } else {
                 #name
             };
-            data_field_with_info(#def_number, name, #fld_type, scale, offset, units, value, options)
+            data_field_with_info(mesg_num, #def_number, name, #fld_type, scale, offset, units, value, options)
         }
     } else {
         quote! {
-            data_field_with_info(#def_number, #name, #fld_type, scale, offset, units, value, options)
+            data_field_with_info(mesg_num, #def_number, #name, #fld_type, scale, offset, units, value, options)
         }
     };
 
     quote! {
         fn #field_fn_name(mesg_num: MesgNum,
-            accumlators: &mut HashMap<u32, Value>,
+            accumlators: &mut Accumulators,
             options: &HashSet<DecodeOption>,
             data_map: &HashMap<u8, Value>,
-            accumulate: bool,
+            accumulate: Option<u8>,
             scale: f64,
             offset: f64,
             units: &'static str,
             value: Value
         ) -> Result<FitDataField> {
-            // accumlator field code
-            let value = if accumulate {
-                calculate_cumulative_value(accumlators, mesg_num.as_u16(), #def_number, value)?
-            } else {
-                value
-            };
+            #accumulator_code
             #data_field_call
         }
     }
 }
 
+/// Definition numbers of the fields of a message that components are accumulated into
+fn accumulated_fields(mesg_def: &MessageDefinition) -> HashSet<u8> {
+    fn visit(fld_def: &MessageFieldDefinition, accumulated: &mut HashSet<u8>) {
+        for (_, comp) in fld_def.components() {
+            if comp.accumulate().is_some() {
+                accumulated.insert(comp.def_number());
+            }
+            visit(comp, accumulated);
+        }
+        for (_, _, sub_fld) in fld_def.subfields() {
+            visit(sub_fld, accumulated);
+        }
+    }
+    let mut accumulated = HashSet::new();
+    for fld_def in mesg_def.field_map().values() {
+        visit(fld_def, &mut accumulated);
+    }
+    accumulated
+}
+
 fn generate_create_fn_call(
     mesg_def: &MessageDefinition,
     fld_def: &MessageFieldDefinition,
@@ -314,7 +348,10 @@ fn generate_create_fn_call(
     alt_offset: Option<f64>,
 ) -> TokenStream {
     let fn_name = format_ident!("{}_{}_field", mesg_def.function_name(), fld_def.name());
-    let acc = fld_def.accumulate();
+    let acc = match fld_def.accumulate() {
+        Some(bits) => quote!(Some(#bits)),
+        None => quote!(None),
+    };
     let scale = alt_scale.unwrap_or_else(|| fld_def.scale());
     let offset = alt_offset.unwrap_or_else(|| fld_def.offset());
     let units = fld_def.units();
@@ -333,9 +370,10 @@ fn unknown_mesg_fn() -> TokenStream {
             if options.contains(&DecodeOption::DropUnknownFields) {
                 return Ok(Vec::new());
             }
-            let fields = data_map.iter()
+            let mut fields: Vec<FitDataField> = data_map.iter()
                 .map(|(k, v)| unknown_field(*k, v.clone()))
                 .collect();
+            fields.sort_by_key(|f| f.number());
             Ok(fields)
         }
     }
@@ -347,7 +385,7 @@ fn mesg_num_to_mesg_decode_fn(messages: &[MessageDefinition]) -> TokenStream {
     quote! {
         impl MesgNum {
             /// Decode the raw values from a FitDataMessage based on the Global Message Number
-            pub fn decode_message(self, data_map: &mut HashMap<u8, Value>, accumlators: &mut HashMap<u32, Value>, options: &HashSet<DecodeOption>) -> Result<Vec<FitDataField>> {
+            pub fn decode_message(self, data_map: &mut HashMap<u8, Value>, accumlators: &mut Accumulators, options: &HashSet<DecodeOption>) -> Result<Vec<FitDataField>> {
                 match self {
                     #(MesgNum::#msg_variants => #fn_names(self, data_map, accumlators, options),)*
                     _ => unknown_message(data_map, options)
@@ -357,8 +395,18 @@ fn mesg_num_to_mesg_decode_fn(messages: &[MessageDefinition]) -> TokenStream {
     }
 }
 
+/// Profile version written into the FIT file header for an SDK version like "21.141.00", the SDK
+/// scales the major version by 1000 since minor versions went past 99
+fn header_profile_version(version: &str) -> u16 {
+    let mut parts = version.split('.').map(|p| p.parse::<u16>().unwrap_or(0));
+    let major = parts.next().unwrap_or(0);
+    let minor = parts.next().unwrap_or(0);
+    major * 1000 + minor
+}
+
 pub fn write_decode_file(profile: &FitProfile, out: &mut File) -> Result<(), std::io::Error> {
     let version = profile.version();
+    let profile_version = header_profile_version(version);
     let comment = format!(
         "//! Auto generated profile messages from FIT SDK Release: {}",
         version
@@ -370,13 +418,14 @@ pub fn write_decode_file(profile: &FitProfile, out: &mut File) -> Result<(), std
     let output = quote! {
         #![doc = #comment]
         #![allow(unused_variables)]
+        #![allow(clippy::if_same_then_else)]
+        #![allow(clippy::too_many_arguments)]
         use std::collections::{HashMap, HashSet, VecDeque};
         use std::convert::TryInto;
         use crate::{{FitDataField, Value}};
-        use crate::de::{{DecodeOption}};
+        use crate::de::{{Accumulators, DecodeOption}};
         use crate::error::{{Result}};
         use super::{
-            calculate_cumulative_value,
             data_field_with_info,
             extract_component,
             unknown_field
@@ -384,6 +433,8 @@ pub fn write_decode_file(profile: &FitProfile, out: &mut File) -> Result<(), std
         use super::field_types::*;
         #[doc = "FIT SDK version used to generate profile decoder"]
         pub const VERSION: &str = #version;
+        #[doc = "Profile version of the SDK as written into FIT file headers, major * 1000 + minor"]
+        pub const PROFILE_VERSION: u16 = #profile_version;
 
         #(#decode_fn_defs)*
 
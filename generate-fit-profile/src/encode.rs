@@ -0,0 +1,196 @@
+//! Functions to generate the profile lookups used to encode messages from the fit profile.
+use crate::parse::{FitProfile, MessageDefinition, MessageFieldDefinition};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{Error, Write};
+
+/// FIT base type id of a field type, profile types use the base type they are defined with
+fn base_type_id(profile: &FitProfile, field_type: &Ident) -> u8 {
+    match field_type.to_string().as_str() {
+        "Bool" => 0x00,
+        "SInt8" => 0x01,
+        "UInt8" => 0x02,
+        "SInt16" => 0x83,
+        "UInt16" => 0x84,
+        "SInt32" => 0x85,
+        "UInt32" => 0x86,
+        "String" => 0x07,
+        "Float32" => 0x88,
+        "Float64" => 0x89,
+        "UInt8z" => 0x0A,
+        "UInt16z" => 0x8B,
+        "UInt32z" => 0x8C,
+        "Byte" => 0x0D,
+        "SInt64" => 0x8E,
+        "UInt64" => 0x8F,
+        "UInt64z" => 0x90,
+        _ => {
+            let type_def = profile
+                .field_types()
+                .iter()
+                .find(|t| t.ident() == field_type)
+                .unwrap_or_else(|| panic!("Field type not found: {field_type}"));
+            if type_def.is_true_enum() {
+                return 0x00;
+            }
+            match type_def.base_type().to_string().as_str() {
+                "i8" => 0x01,
+                "u8" => 0x02,
+                "i16" => 0x83,
+                "u16" => 0x84,
+                "i32" => 0x85,
+                "u32" => 0x86,
+                other => panic!("unsupported base type for field type {field_type}: {other}"),
+            }
+        }
+    }
+}
+
+fn field_info_arm(
+    profile: &FitProfile,
+    def_number: u8,
+    fld: &MessageFieldDefinition,
+) -> TokenStream {
+    let name = fld.name();
+    let field_type = fld.field_type();
+    let base_type = base_type_id(profile, field_type);
+    let scale = fld.scale();
+    let offset = fld.offset();
+    let units = fld.units();
+    quote! {
+        #name => Some(FieldInfo::new(#def_number, FieldDataType::#field_type, #base_type, #scale, #offset, #units)),
+    }
+}
+
+fn message_field_info_arm(profile: &FitProfile, msg: &MessageDefinition) -> TokenStream {
+    let ident = msg.struct_ident();
+    let mut names = HashSet::new();
+    let mut arms = Vec::new();
+    for fld in msg.field_map().values() {
+        if names.insert(fld.name().to_string()) {
+            arms.push(field_info_arm(profile, fld.def_number(), fld));
+        }
+        for (_, _, sub_fld) in fld.subfields() {
+            // subfields are decoded with their own name but share the parent's number
+            if names.insert(sub_fld.name().to_string()) {
+                arms.push(field_info_arm(profile, fld.def_number(), sub_fld));
+            }
+        }
+    }
+    quote! {
+        MesgNum::#ident => match name {
+            #( #arms )*
+            _ => None,
+        },
+    }
+}
+
+fn message_field_name_arm(msg: &MessageDefinition) -> TokenStream {
+    let ident = msg.struct_ident();
+    let arms = msg.field_map().values().map(|fld| {
+        let def_number = fld.def_number();
+        let name = fld.name();
+        quote! { #def_number => Some(#name), }
+    });
+    quote! {
+        MesgNum::#ident => match number {
+            #( #arms )*
+            _ => None,
+        },
+    }
+}
+
+/// Code of the variant of a profile type a subfield is referenced by
+fn reference_value(profile: &FitProfile, field_type: &Ident, variant: &str) -> i64 {
+    let type_def = profile
+        .field_types()
+        .iter()
+        .find(|t| t.ident() == field_type)
+        .unwrap_or_else(|| panic!("Field type not found: {field_type}"));
+    type_def
+        .variant_map()
+        .iter()
+        .find(|(_, v)| v.ident() == variant)
+        .map(|(value, _)| *value)
+        .unwrap_or_else(|| panic!("Variant {variant} not found in {field_type}"))
+}
+
+fn message_subfields_arm(profile: &FitProfile, msg: &MessageDefinition) -> Option<TokenStream> {
+    let ident = msg.struct_ident();
+    let arms: Vec<TokenStream> = msg
+        .field_map()
+        .values()
+        .filter(|fld| !fld.subfields().is_empty())
+        .map(|fld| {
+            let def_number = fld.def_number();
+            // subfields are listed in profile order, the decoder picks the first one matching
+            let subfields = fld.subfields().iter().map(|(ref_name, ref_val, sub_fld)| {
+                let name = sub_fld.name();
+                let ref_field = msg.get_field_by_name(ref_name);
+                let reference = ref_field.def_number();
+                let value = reference_value(profile, ref_field.field_type(), ref_val);
+                quote! { SubField { name: #name, reference: #reference, value: #value }, }
+            });
+            quote! { #def_number => &[ #( #subfields )* ], }
+        })
+        .collect();
+    if arms.is_empty() {
+        return None;
+    }
+    Some(quote! {
+        MesgNum::#ident => match number {
+            #( #arms )*
+            _ => &[],
+        },
+    })
+}
+
+pub fn write_encode_file(profile: &FitProfile, out: &mut File) -> Result<(), Error> {
+    let comment = format!(
+        "Auto generated profile information used to encode messages from FIT SDK Release: {}",
+        profile.version()
+    );
+    let arms = profile
+        .messages()
+        .iter()
+        .map(|msg| message_field_info_arm(profile, msg));
+    let name_arms = profile.messages().iter().map(message_field_name_arm);
+    let subfield_arms = profile
+        .messages()
+        .iter()
+        .filter_map(|msg| message_subfields_arm(profile, msg));
+    let output = quote! {
+        #![doc = #comment]
+        use super::field_types::{FieldDataType, MesgNum};
+        use super::{FieldInfo, SubField};
+
+        /// Look up a field by the name it's decoded with, subfields are found by their own name
+        pub fn field_info(mesg_num: MesgNum, name: &str) -> Option<FieldInfo> {
+            match mesg_num {
+                #( #arms )*
+                _ => None,
+            }
+        }
+
+        /// Name of a field by its definition number, subfields are named after their parent field
+        pub fn field_name(mesg_num: MesgNum, number: u8) -> Option<&'static str> {
+            match mesg_num {
+                #( #name_arms )*
+                _ => None,
+            }
+        }
+
+        /// Subfields of a field in the order they are checked, the first one whose reference
+        /// field holds its reference value is active
+        pub fn subfields(mesg_num: MesgNum, number: u8) -> &'static [SubField] {
+            match mesg_num {
+                #( #subfield_arms )*
+                _ => &[],
+            }
+        }
+    };
+
+    write!(out, "{}", output)
+}
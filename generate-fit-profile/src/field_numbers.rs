@@ -0,0 +1,72 @@
+//! Functions to generate the field definition number constants from the fit profile.
+use crate::parse::{FitProfile, MessageDefinition, MessageFieldDefinition};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::fs::File;
+use std::io::{Error, Write};
+
+fn field_number_const(fld: &MessageFieldDefinition, compact: bool) -> TokenStream {
+    let ident = format_ident!("{}", fld.name().to_uppercase());
+    let def_number = fld.def_number();
+    if compact {
+        return quote! { pub const #ident: u8 = #def_number; };
+    }
+    let comment = fld.comment();
+    let units = if fld.units().is_empty() {
+        TokenStream::new()
+    } else {
+        let units = format!(" * units: {}", fld.units());
+        quote!(#[doc = #units])
+    };
+    quote! {
+        #comment
+        #units
+        pub const #ident: u8 = #def_number;
+    }
+}
+
+fn message_field_numbers_mod(msg: &MessageDefinition, compact: bool) -> TokenStream {
+    let comment = if compact {
+        TokenStream::new()
+    } else {
+        msg.comment().clone()
+    };
+    let mod_ident = format_ident!("{}", msg.name());
+    let consts = msg
+        .field_map()
+        .values()
+        .map(|fld| field_number_const(fld, compact));
+    quote! {
+        #comment
+        pub mod #mod_ident {
+            #( #consts )*
+        }
+    }
+}
+
+/// Write the field number constants, a compact profile leaves out the descriptions and units of
+/// the fields
+pub fn write_field_numbers_file(
+    profile: &FitProfile,
+    compact: bool,
+    out: &mut File,
+) -> Result<(), Error> {
+    let comment = format!(
+        "Auto generated field definition numbers from FIT SDK Release: {}{}",
+        profile.version(),
+        if compact { " (compact)" } else { "" }
+    );
+    let mods = profile
+        .messages()
+        .iter()
+        .map(|msg| message_field_numbers_mod(msg, compact));
+    let output = quote! {
+        #![allow(missing_docs)]
+        #![doc = #comment]
+        #![doc = "Each message has a module holding a constant per field, e.g. `record::HEART_RATE`"]
+
+        #( #mods )*
+    };
+
+    write!(out, "{}", output)
+}
@@ -20,6 +20,19 @@ fn field_type_enum_is_named_variant(field_type: &FieldTypeDefintion) -> TokenStr
     }
 }
 
+fn field_type_enum_variant_value(field_type: &FieldTypeDefintion) -> TokenStream {
+    let variant_names = field_type.variant_map().values().map(|v| v.name());
+    let variant_values = field_type.variant_map().keys();
+    quote! {
+        pub fn variant_value(name: &str) -> Option<i64> {
+            match name {
+                #( #variant_names => Some(#variant_values),)*
+                _ => None
+            }
+        }
+    }
+}
+
 fn field_type_enum_as_type(field_type: &FieldTypeDefintion) -> TokenStream {
     let ident = field_type.ident();
     let fn_ident = format_ident!("as_{}", field_type.base_type());
@@ -29,6 +42,9 @@ fn field_type_enum_as_type(field_type: &FieldTypeDefintion) -> TokenStream {
         .variant_map()
         .values()
         .map(FieldTypeVariant::value);
+    let variant_idents: Vec<_> = variant_idents.collect();
+    let variant_values: Vec<_> = variant_values.collect();
+    let variant_codes = &variant_values;
     let other_value_ident = field_type.other_value_field_name();
 
     quote! {
@@ -43,17 +59,32 @@ fn field_type_enum_as_type(field_type: &FieldTypeDefintion) -> TokenStream {
        pub fn as_i64(self) -> i64 {
         self.#fn_ident() as i64
        }
+
+        /// Numeric code of the variant in the FIT profile, stable across releases even when the
+        /// Rust identifier of the variant changes
+        pub const fn code(self) -> i64 {
+            match self {
+                #( #ident::#variant_idents => #variant_codes,)*
+                #ident::#other_value_ident(value) => value as i64
+            }
+        }
     }
 }
 
-fn field_type_enum_impl(field_type: &FieldTypeDefintion) -> TokenStream {
+fn field_type_enum_impl(field_type: &FieldTypeDefintion, compact: bool) -> TokenStream {
     let ident = field_type.ident();
     let is_named_variant = field_type_enum_is_named_variant(field_type);
+    let variant_value = if compact {
+        TokenStream::new()
+    } else {
+        field_type_enum_variant_value(field_type)
+    };
     let as_numeric_types = field_type_enum_as_type(field_type);
 
     quote! {
         impl #ident {
             #is_named_variant
+            #variant_value
             #as_numeric_types
         }
     }
@@ -139,6 +170,31 @@ fn field_type_enum_impl_serialize(field_type: &FieldTypeDefintion) -> TokenStrea
     }
 }
 
+/// Variant names missing from the profile deserialize into the invalid value of the base type,
+/// a compact profile only deserializes the numeric values
+fn field_type_enum_impl_deserialize(field_type: &FieldTypeDefintion, compact: bool) -> TokenStream {
+    let ident = field_type.ident();
+    let base_type = field_type.base_type();
+    if compact {
+        return quote! {
+            impl<'de> Deserialize<'de> for #ident {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+                    i64::deserialize(deserializer).map(Self::from)
+                }
+            }
+        };
+    }
+
+    quote! {
+        impl<'de> Deserialize<'de> for #ident {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+                let invalid = i64::from(#base_type::MAX);
+                deserialize_variant(deserializer, FieldDataType::#ident, invalid).map(Self::from)
+            }
+        }
+    }
+}
+
 fn field_type_enum_variant_line(variant: &FieldTypeVariant) -> TokenStream {
     let comment = variant.comment();
     let ident = variant.ident();
@@ -157,7 +213,7 @@ fn field_type_enum_other_value(field_type: &FieldTypeDefintion) -> TokenStream {
     }
 }
 
-fn field_type_enum(field_type: &FieldTypeDefintion) -> TokenStream {
+fn field_type_enum(field_type: &FieldTypeDefintion, compact: bool) -> TokenStream {
     if field_type.variant_map().is_empty() {
         return TokenStream::new();
     }
@@ -168,10 +224,19 @@ fn field_type_enum(field_type: &FieldTypeDefintion) -> TokenStream {
         .values()
         .map(field_type_enum_variant_line);
     let other_val = field_type_enum_other_value(field_type);
-    let enum_impl = field_type_enum_impl(field_type);
-    let impl_display = field_type_enum_impl_display(field_type);
+    let enum_impl = field_type_enum_impl(field_type, compact);
     let impl_from = field_type_enum_impl_from(field_type);
-    let impl_serialize = field_type_enum_impl_serialize(field_type);
+    let impl_deserialize = field_type_enum_impl_deserialize(field_type, compact);
+    // variant names are left out of compact profiles, along with displaying and serializing
+    // the variants by name
+    let (impl_display, impl_serialize) = if compact {
+        (TokenStream::new(), TokenStream::new())
+    } else {
+        (
+            field_type_enum_impl_display(field_type),
+            field_type_enum_impl_serialize(field_type),
+        )
+    };
 
     quote! {
         #comment
@@ -184,17 +249,26 @@ fn field_type_enum(field_type: &FieldTypeDefintion) -> TokenStream {
         #impl_display
         #impl_from
         #impl_serialize
+        #impl_deserialize
     }
 }
 
-fn generate_main_field_type_enum(field_types: &[FieldTypeDefintion]) -> TokenStream {
-    let base_types = vec![
+fn generate_main_field_type_enum(field_types: &[FieldTypeDefintion], compact: bool) -> TokenStream {
+    let base_types: Vec<_> = vec![
         "Bool", "SInt8", "UInt8", "SInt16", "UInt16", "SInt32", "UInt32", "String", "Float32",
         "Float64", "UInt8z", "UInt16z", "UInt32z", "Byte", "SInt64", "UInt64", "UInt64z",
     ]
     .into_iter()
-    .map(|s| format_ident!("{}", s));
-    let variants = field_types.iter().map(|f| f.ident());
+    .map(|s| format_ident!("{}", s))
+    .collect();
+    let variants: Vec<_> = field_types.iter().map(|f| f.ident()).collect();
+    // names of the types as they are written in the profile
+    let type_names: Vec<_> = base_types
+        .iter()
+        .map(|i| i.to_string().to_lowercase())
+        .chain(field_types.iter().map(|f| f.name().to_string()))
+        .collect();
+    let type_idents: Vec<_> = base_types.iter().chain(variants.iter().copied()).collect();
     let mut is_enum_force_false = HashSet::new();
     is_enum_force_false.insert("date_time".to_string());
     is_enum_force_false.insert("local_date_time".to_string());
@@ -204,6 +278,52 @@ fn generate_main_field_type_enum(field_types: &[FieldTypeDefintion]) -> TokenStr
         .filter(|f| !is_enum_force_false.contains(f.name()))
         .map(|f| f.ident())
         .collect();
+    // a compact profile has no names, neither of the variants nor of the types
+    let name_fns = if compact {
+        TokenStream::new()
+    } else {
+        quote! {
+            pub fn is_named_variant(self, value: i64) -> bool {
+                match self {
+                    #( FieldDataType::#filtered_field_idents => #filtered_field_idents::is_named_variant(value), )*
+                    _ => false
+                }
+            }
+            /// Value of the named variant of an enum type, the reverse of `get_field_variant_as_string`
+            pub fn variant_value(self, name: &str) -> Option<i64> {
+                match self {
+                    #( FieldDataType::#filtered_field_idents => #filtered_field_idents::variant_value(name), )*
+                    _ => None
+                }
+            }
+            /// Name of the type in the FIT profile, e.g. `sport`, stable across releases even when
+            /// the Rust identifier of the type changes
+            pub fn type_name(self) -> &'static str {
+                match self {
+                    #( FieldDataType::#type_idents => #type_names, )*
+                }
+            }
+            /// Look up a type by its name in the FIT profile
+            pub fn from_type_name(name: &str) -> Option<Self> {
+                match name {
+                    #( #type_names => Some(FieldDataType::#type_idents), )*
+                    _ => None
+                }
+            }
+        }
+    };
+    let variant_as_string = if compact {
+        TokenStream::new()
+    } else {
+        quote! {
+            pub fn get_field_variant_as_string(field_type: FieldDataType , value: i64) -> String {
+                match field_type {
+                    #( FieldDataType::#filtered_field_idents => #filtered_field_idents::from(value).to_string(), )*
+                    _ => format!("Undefined{}", value),
+                }
+            }
+        }
+    };
 
     quote! {
         /// Describe all possible data types of a field
@@ -223,38 +343,48 @@ fn generate_main_field_type_enum(field_types: &[FieldTypeDefintion]) -> TokenStr
                     _ => false
                 }
             }
-            pub fn is_named_variant(self, value: i64) -> bool {
-                match self {
-                    #( FieldDataType::#filtered_field_idents => #filtered_field_idents::is_named_variant(value), )*
-                    _ => false
-                }
-            }
-        }
-        pub fn get_field_variant_as_string(field_type: FieldDataType , value: i64) -> String {
-            match field_type {
-                #( FieldDataType::#filtered_field_idents => #filtered_field_idents::from(value).to_string(), )*
-                _ => format!("Undefined{}", value),
-            }
+            #name_fns
         }
+        #variant_as_string
     }
 }
 
-pub fn write_types_file(profile: &FitProfile, out: &mut File) -> Result<(), Error> {
+/// Write the field types, a compact profile leaves out the names of the variants and types. Its
+/// enums are neither displayed nor serialized and only deserialize from numbers, the build
+/// script of the library detects the marker in the module doc and leaves out the code relying
+/// on the names.
+pub fn write_types_file(profile: &FitProfile, compact: bool, out: &mut File) -> Result<(), Error> {
     let comment = format!(
-        "Auto generated profile field types from FIT SDK Release: {}",
-        profile.version()
+        "Auto generated profile field types from FIT SDK Release: {}{}",
+        profile.version(),
+        if compact { " (compact)" } else { "" }
     );
-    let main_enum = generate_main_field_type_enum(profile.field_types());
-    let field_type_enums = profile.field_types().iter().map(field_type_enum);
+    let main_enum = generate_main_field_type_enum(profile.field_types(), compact);
+    let field_type_enums = profile
+        .field_types()
+        .iter()
+        .map(|f| field_type_enum(f, compact));
+    let imports = if compact {
+        quote! {
+            use serde::{Deserialize, de::Deserializer};
+            use std::convert;
+        }
+    } else {
+        quote! {
+            use super::deserialize_variant;
+            use serde::{Deserialize, Serialize, de::Deserializer, ser::Serializer};
+            use std::{convert, fmt};
+        }
+    };
     let output = quote! {
         #![allow(missing_docs)]
         #![allow(dead_code)]
+        #![allow(clippy::match_like_matches_macro)]
         #![allow(clippy::unreadable_literal)]
         #![doc = #comment]
         #![doc = "Not all of these may be used by the defined set of FIT messages"]
 
-        use serde::{Serialize, ser::Serializer};
-        use std::{convert, fmt};
+        #imports
 
         #main_enum
 
@@ -0,0 +1,300 @@
+//! Functions to generate curated groups of messages with a predicate and a constant per group.
+use crate::parse::FitProfile;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::fs::File;
+use std::io::{Error, Write};
+
+/// A named group of messages, the profile only lists messages by the file type section they are
+/// defined in so the groups are maintained here
+struct MessageGroup {
+    name: &'static str,
+    comment: &'static str,
+    /// Shorter name of the predicate generated in addition to `is_{name}_message`
+    alias: Option<&'static str>,
+    messages: &'static [&'static str],
+}
+
+const MESSAGE_GROUPS: &[MessageGroup] = &[
+    MessageGroup {
+        name: "activity",
+        comment: "Messages recorded in activity files",
+        alias: None,
+        messages: &[
+            "activity",
+            "session",
+            "lap",
+            "length",
+            "record",
+            "event",
+            "device_info",
+            "device_aux_battery_info",
+            "training_file",
+            "weather_conditions",
+            "weather_alert",
+            "gps_metadata",
+            "camera_event",
+            "gyroscope_data",
+            "accelerometer_data",
+            "magnetometer_data",
+            "barometer_data",
+            "three_d_sensor_calibration",
+            "one_d_sensor_calibration",
+            "video_frame",
+            "obdii_data",
+            "nmea_sentence",
+            "aviation_attitude",
+            "video",
+            "video_title",
+            "video_description",
+            "video_clip",
+            "set",
+            "jump",
+            "split",
+            "split_summary",
+            "climb_pro",
+            "field_description",
+            "developer_data_id",
+            "time_in_zone",
+            "hrv",
+            "hr",
+            "dive_summary",
+            "tank_update",
+            "tank_summary",
+        ],
+    },
+    MessageGroup {
+        name: "monitoring",
+        comment: "Messages recorded in monitoring files, i.e. all day wellness tracking",
+        alias: None,
+        messages: &[
+            "monitoring_info",
+            "monitoring",
+            "monitoring_hr_data",
+            "spo2_data",
+            "hr",
+            "stress_level",
+            "max_met_data",
+            "hsa_body_battery_data",
+            "hsa_event",
+            "hsa_accelerometer_data",
+            "hsa_gyroscope_data",
+            "hsa_step_data",
+            "hsa_spo2_data",
+            "hsa_stress_data",
+            "hsa_respiration_data",
+            "hsa_heart_rate_data",
+            "hsa_configuration_data",
+            "hsa_wrist_temperature_data",
+        ],
+    },
+    MessageGroup {
+        name: "session_level",
+        comment: "Messages summarizing a span of an activity rather than a single point in time",
+        alias: Some("is_session_level"),
+        messages: &[
+            "activity",
+            "session",
+            "lap",
+            "length",
+            "split",
+            "split_summary",
+            "segment_lap",
+            "time_in_zone",
+            "dive_summary",
+            "tank_summary",
+        ],
+    },
+    MessageGroup {
+        name: "record_level",
+        comment: "Messages holding data sampled at a single point in time",
+        alias: Some("is_record_level"),
+        messages: &[
+            "record",
+            "event",
+            "hrv",
+            "hr",
+            "gps_metadata",
+            "camera_event",
+            "gyroscope_data",
+            "accelerometer_data",
+            "magnetometer_data",
+            "barometer_data",
+            "obdii_data",
+            "nmea_sentence",
+            "aviation_attitude",
+            "video_frame",
+            "weather_conditions",
+            "weather_alert",
+            "set",
+            "jump",
+            "climb_pro",
+            "tank_update",
+            "beat_intervals",
+            "respiration_rate",
+            "monitoring",
+            "monitoring_hr_data",
+            "spo2_data",
+            "stress_level",
+        ],
+    },
+    MessageGroup {
+        name: "settings",
+        comment: "Messages holding device, user, sport and sensor settings",
+        alias: None,
+        messages: &[
+            "device_settings",
+            "user_profile",
+            "hrm_profile",
+            "sdm_profile",
+            "bike_profile",
+            "zones_target",
+            "hr_zone",
+            "power_zone",
+            "met_zone",
+            "speed_zone",
+            "cadence_zone",
+            "sport",
+            "watchface_settings",
+            "ohr_settings",
+            "dive_settings",
+            "dive_gas",
+            "dive_alarm",
+        ],
+    },
+    MessageGroup {
+        name: "course",
+        comment: "Messages describing a course apart from its track points",
+        alias: None,
+        messages: &["course", "course_point"],
+    },
+    MessageGroup {
+        name: "workout",
+        comment: "Messages describing a structured workout",
+        alias: None,
+        messages: &[
+            "workout",
+            "workout_session",
+            "workout_step",
+            "exercise_title",
+        ],
+    },
+    MessageGroup {
+        name: "developer",
+        comment: "Messages defining developer data fields",
+        alias: None,
+        messages: &["developer_data_id", "field_description"],
+    },
+    MessageGroup {
+        name: "sensor_data",
+        comment: "Messages holding raw motion and pressure sensor samples and their calibration",
+        alias: None,
+        messages: &[
+            "gyroscope_data",
+            "accelerometer_data",
+            "magnetometer_data",
+            "barometer_data",
+            "three_d_sensor_calibration",
+            "one_d_sensor_calibration",
+        ],
+    },
+];
+
+fn message_group(profile: &FitProfile, group: &MessageGroup) -> TokenStream {
+    let idents: Vec<_> = group
+        .messages
+        .iter()
+        .filter_map(|name| {
+            let msg = profile.messages().iter().find(|m| m.name() == *name);
+            if msg.is_none() {
+                eprintln!("Message '{name}' of group '{}' not found", group.name);
+            }
+            msg.map(|m| m.struct_ident())
+        })
+        .collect();
+    let const_ident = format_ident!("{}_MESSAGES", group.name.to_uppercase());
+    let fn_ident = format_ident!("is_{}_message", group.name);
+    let const_comment = group.comment;
+    let fn_comment = format!("Check if the message is one of `{const_ident}`");
+    let alias = group.alias.map(|alias| {
+        let alias_ident = format_ident!("{}", alias);
+        let alias_comment = format!("Same as `{fn_ident}`");
+        quote! {
+            #[doc = #alias_comment]
+            pub fn #alias_ident(self) -> bool {
+                self.#fn_ident()
+            }
+        }
+    });
+    quote! {
+        #[doc = #const_comment]
+        pub const #const_ident: &'static [MesgNum] = &[#( MesgNum::#idents ),*];
+
+        #[doc = #fn_comment]
+        pub fn #fn_ident(self) -> bool {
+            matches!(self, #( MesgNum::#idents )|*)
+        }
+
+        #alias
+    }
+}
+
+/// Every named message number of the profile and the description of the messages that have one,
+/// a compact profile leaves out the descriptions
+fn message_variants(profile: &FitProfile, compact: bool) -> TokenStream {
+    let variants: Vec<_> = profile
+        .field_types()
+        .iter()
+        .find(|t| t.name() == "mesg_num")
+        .expect("profile defines the mesg_num type")
+        .variant_map()
+        .values()
+        .map(|v| v.ident())
+        .collect();
+    let (idents, descriptions): (Vec<_>, Vec<_>) = profile
+        .messages()
+        .iter()
+        .filter_map(|m| Some((m.struct_ident(), m.description()?)))
+        .unzip();
+    let doc_string = if compact {
+        TokenStream::new()
+    } else {
+        quote! {
+            #[doc = "Description of the message from the profile, `None` for messages without one"]
+            pub fn doc_string(self) -> Option<&'static str> {
+                match self {
+                    #( MesgNum::#idents => Some(#descriptions), )*
+                    _ => None,
+                }
+            }
+        }
+    };
+    quote! {
+        #[doc = "Every message number named by the profile, including the bounds of the manufacturer specific range"]
+        pub const VARIANTS: &'static [MesgNum] = &[#( MesgNum::#variants ),*];
+
+        #doc_string
+    }
+}
+
+pub fn write_groups_file(profile: &FitProfile, compact: bool, out: &mut File) -> Result<(), Error> {
+    let comment = format!(
+        "Auto generated groups of messages from FIT SDK Release: {}{}",
+        profile.version(),
+        if compact { " (compact)" } else { "" }
+    );
+    let groups = MESSAGE_GROUPS.iter().map(|g| message_group(profile, g));
+    let variants = message_variants(profile, compact);
+    let output = quote! {
+        #![doc = #comment]
+        use super::field_types::MesgNum;
+
+        impl MesgNum {
+            #( #groups )*
+
+            #variants
+        }
+    };
+
+    write!(out, "{}", output)
+}
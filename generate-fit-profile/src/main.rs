@@ -7,23 +7,60 @@ use structopt::StructOpt;
 
 mod decode;
 use crate::decode::write_decode_file;
+mod encode;
+use crate::encode::write_encode_file;
+mod field_numbers;
+use crate::field_numbers::write_field_numbers_file;
 mod field_types;
 use crate::field_types::write_types_file;
+mod groups;
+use crate::groups::write_groups_file;
+mod messages;
+use crate::messages::write_messages_file;
 mod parse;
 use crate::parse::parse_profile;
+mod report;
+use crate::report::ProfileDiff;
+mod units;
+use crate::units::write_units_file;
 
 /// Parse the Profile.xlsx included in the FIT SDK and update the related modules
 #[derive(Debug, StructOpt)]
 #[structopt(name = "update_profile")]
-struct Cli {
-    /// Path to Profile.xlsx file
-    #[structopt(name = "FILE", parse(from_os_str))]
-    profile_path: PathBuf,
-
-    /// Manually specify the SDK version, usually we can infer this from the path to the Profile.xlsx
-    /// file unless it's been moved
-    #[structopt(long)]
-    sdk_version: Option<String>,
+enum Cli {
+    /// Generate the profile modules of fitparser from a Profile.xlsx file
+    Generate {
+        /// Path to Profile.xlsx file
+        #[structopt(name = "FILE", parse(from_os_str))]
+        profile_path: PathBuf,
+
+        /// Manually specify the SDK version, usually we can infer this from the path to the
+        /// Profile.xlsx file unless it's been moved
+        #[structopt(long)]
+        sdk_version: Option<String>,
+
+        /// Generate a minimal profile without the names of enum variants and types or the
+        /// descriptions of messages and fields, enums are decoded as numbers and can't be
+        /// displayed or serialized. The library detects a compact profile when it's built.
+        /// Useful for embedded and Wasm builds where the name tables dominate the binary
+        /// size
+        #[structopt(long)]
+        compact: bool,
+    },
+    /// Report the messages, fields and types added, renamed or removed between two profiles
+    Diff {
+        /// Path to the Profile.xlsx file of the current SDK
+        #[structopt(name = "OLD", parse(from_os_str))]
+        old_path: PathBuf,
+
+        /// Path to the Profile.xlsx file of the SDK to update to
+        #[structopt(name = "NEW", parse(from_os_str))]
+        new_path: PathBuf,
+
+        /// Also write the report as JSON to this file
+        #[structopt(long, parse(from_os_str))]
+        json: Option<PathBuf>,
+    },
 }
 
 /// call rustfmt on a generated file to cleanup auto-gen code
@@ -34,26 +71,45 @@ fn rustfmt(fname: &PathBuf) {
         .unwrap_or_else(|_| panic!("failed to execute rustfmt on {fname:?}"));
 }
 
-fn run() -> Result<(), Box<dyn std::error::Error>> {
-    let opts = Cli::from_args();
-    let profile_fname = opts.profile_path;
-    let profile_vers = opts.sdk_version.map_or_else(
-        || {
-            profile_fname
-                .parent()
-                .and_then(std::path::Path::file_name)
-                .map_or_else(
-                    || String::from("unknown"),
-                    |dirname| {
-                        dirname
-                            .to_str()
-                            .expect("Unable to convert dirname to str")
-                            .replace("FitSDKRelease_", "")
-                    },
-                )
-        },
-        |vers| vers,
-    );
+/// Infer the SDK version from the name of the directory holding the Profile.xlsx file
+fn profile_version(profile_fname: &Path) -> String {
+    profile_fname
+        .parent()
+        .and_then(std::path::Path::file_name)
+        .map_or_else(
+            || String::from("unknown"),
+            |dirname| {
+                dirname
+                    .to_str()
+                    .expect("Unable to convert dirname to str")
+                    .replace("FitSDKRelease_", "")
+            },
+        )
+}
+
+fn diff(
+    old_fname: &PathBuf,
+    new_fname: &PathBuf,
+    json_fname: Option<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let old = parse_profile(old_fname, profile_version(old_fname))?;
+    let new = parse_profile(new_fname, profile_version(new_fname))?;
+    let report = ProfileDiff::new(&old, &new);
+    print!("{report}");
+    if let Some(json_fname) = json_fname {
+        eprintln!("Writing report: {:?}", &json_fname);
+        let out_file = File::create(&json_fname)?;
+        serde_json::to_writer_pretty(out_file, &report)?;
+    }
+    Ok(())
+}
+
+fn generate(
+    profile_fname: PathBuf,
+    sdk_version: Option<String>,
+    compact: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let profile_vers = sdk_version.unwrap_or_else(|| profile_version(&profile_fname));
     assert!(
         profile_vers.chars().all(|c| c.is_ascii_digit() || c == '.'),
         "Could not determine version from Profile.xslx path: '{profile_fname:?}' - %{profile_vers}%"
@@ -67,7 +123,7 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
     let types_fname = dest_dir.join("field_types.rs");
     eprintln!("Generating file: {:?}", &types_fname);
     let mut out_file = File::create(&types_fname)?;
-    write_types_file(&profile, &mut out_file)?;
+    write_types_file(&profile, compact, &mut out_file)?;
     rustfmt(&types_fname);
 
     let decode_fname = dest_dir.join("decode.rs");
@@ -76,9 +132,54 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
     write_decode_file(&profile, &mut out_file)?;
     rustfmt(&decode_fname);
 
+    let encode_fname = dest_dir.join("encode.rs");
+    eprintln!("Generating file: {:?}", &encode_fname);
+    let mut out_file = File::create(&encode_fname)?;
+    write_encode_file(&profile, &mut out_file)?;
+    rustfmt(&encode_fname);
+
+    let messages_fname = dest_dir.join("messages.rs");
+    eprintln!("Generating file: {:?}", &messages_fname);
+    let mut out_file = File::create(&messages_fname)?;
+    write_messages_file(&profile, compact, &mut out_file)?;
+    rustfmt(&messages_fname);
+
+    let groups_fname = dest_dir.join("message_groups.rs");
+    eprintln!("Generating file: {:?}", &groups_fname);
+    let mut out_file = File::create(&groups_fname)?;
+    write_groups_file(&profile, compact, &mut out_file)?;
+    rustfmt(&groups_fname);
+
+    let numbers_fname = dest_dir.join("field_numbers.rs");
+    eprintln!("Generating file: {:?}", &numbers_fname);
+    let mut out_file = File::create(&numbers_fname)?;
+    write_field_numbers_file(&profile, compact, &mut out_file)?;
+    rustfmt(&numbers_fname);
+
+    let units_fname = dest_dir.join("units.rs");
+    eprintln!("Generating file: {:?}", &units_fname);
+    let mut out_file = File::create(&units_fname)?;
+    write_units_file(&profile, compact, &mut out_file)?;
+    rustfmt(&units_fname);
+
     Ok(())
 }
 
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    match Cli::from_args() {
+        Cli::Generate {
+            profile_path,
+            sdk_version,
+            compact,
+        } => generate(profile_path, sdk_version, compact),
+        Cli::Diff {
+            old_path,
+            new_path,
+            json,
+        } => diff(&old_path, &new_path, json),
+    }
+}
+
 fn main() {
     std::process::exit(match run() {
         Ok(_) => 0,
@@ -0,0 +1,189 @@
+//! Functions to generate typed structs for every message of the fit profile.
+use crate::parse::{FitProfile, MessageDefinition, MessageFieldDefinition};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::{BTreeSet, HashSet};
+use std::fs::File;
+use std::io::{Error, Write};
+
+/// Field name as an identifier, keywords like `type` become raw identifiers
+fn field_ident(name: &str) -> Ident {
+    syn::parse_str::<Ident>(name).unwrap_or_else(|_| Ident::new_raw(name, Span::call_site()))
+}
+
+/// Primitive type a value of a FIT base type is decoded into
+fn base_rust_type(field_type: &Ident) -> Option<TokenStream> {
+    let typ = match field_type.to_string().as_str() {
+        "Bool" => quote! { bool },
+        "SInt8" => quote! { i8 },
+        "UInt8" | "UInt8z" | "Byte" => quote! { u8 },
+        "SInt16" => quote! { i16 },
+        "UInt16" | "UInt16z" => quote! { u16 },
+        "SInt32" => quote! { i32 },
+        "UInt32" | "UInt32z" => quote! { u32 },
+        "SInt64" => quote! { i64 },
+        "UInt64" | "UInt64z" => quote! { u64 },
+        "Float32" => quote! { f32 },
+        "Float64" => quote! { f64 },
+        "String" => quote! { String },
+        _ => return None,
+    };
+    Some(typ)
+}
+
+/// Rust type a decoded field is converted into, along with the profile type it uses if any.
+/// Scaled base types are decoded as floats while profile types ignore the scale, profile types
+/// without any variants are decoded like their base type.
+fn rust_type(profile: &FitProfile, fld: &MessageFieldDefinition) -> (TokenStream, Option<Ident>) {
+    let field_type = fld.field_type();
+    if field_type == "DateTime" || field_type == "LocalDateTime" {
+        return (quote! { crate::timestamp::Timestamp }, None);
+    }
+    let is_scaled = (fld.scale() - 1.0).abs() > f64::EPSILON || fld.offset().abs() > f64::EPSILON;
+    if let Some(typ) = base_rust_type(field_type) {
+        return if is_scaled {
+            (quote! { f64 }, None)
+        } else {
+            (typ, None)
+        };
+    }
+    let type_def = profile
+        .field_types()
+        .iter()
+        .find(|t| t.ident() == field_type)
+        .unwrap_or_else(|| panic!("Field type not found: {field_type}"));
+    if !type_def.variant_map().is_empty() {
+        (quote! { #field_type }, Some(field_type.clone()))
+    } else if is_scaled {
+        (quote! { f64 }, None)
+    } else {
+        let base_type = type_def.base_type();
+        (quote! { #base_type }, None)
+    }
+}
+
+/// Fields and subfields of a message by the name they are decoded with
+fn message_fields(msg: &MessageDefinition) -> Vec<&MessageFieldDefinition> {
+    let mut names = HashSet::new();
+    let mut fields = Vec::new();
+    for fld in msg.field_map().values() {
+        if names.insert(fld.name()) {
+            fields.push(fld);
+        }
+        for (_, _, sub_fld) in fld.subfields() {
+            if names.insert(sub_fld.name()) {
+                fields.push(sub_fld);
+            }
+        }
+    }
+    fields
+}
+
+fn message_struct(
+    profile: &FitProfile,
+    msg: &MessageDefinition,
+    compact: bool,
+    enums: &mut BTreeSet<String>,
+) -> TokenStream {
+    let ident = msg.struct_ident();
+    let struct_ident = format_ident!("{}Message", ident);
+    let comment = format!("Typed fields of a `{}` message", msg.name());
+    let mut members = Vec::new();
+    let mut arms = Vec::new();
+    for fld in message_fields(msg) {
+        let name = fld.name();
+        let fld_ident = field_ident(name);
+        let (typ, profile_type) = rust_type(profile, fld);
+        if let Some(profile_type) = profile_type {
+            enums.insert(profile_type.to_string());
+        }
+        // byte fields hold a blob rather than a single value
+        if fld.is_array() || fld.field_type() == "Byte" {
+            members.push(quote! { pub #fld_ident: Option<Vec<#typ>>, });
+        } else {
+            members.push(quote! { pub #fld_ident: Option<#typ>, });
+        }
+        arms.push(quote! { #name => message.#fld_ident = field_value(record, field)?, });
+    }
+    // the enums of a compact profile can't be serialized
+    let derives = if compact {
+        quote! { #[derive(Clone, Debug, Default, PartialEq)] }
+    } else {
+        quote! { #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)] }
+    };
+    quote! {
+        #[doc = #comment]
+        #derives
+        pub struct #struct_ident {
+            #( #members )*
+        }
+
+        impl TryFrom<&FitDataRecord> for #struct_ident {
+            type Error = Error;
+
+            fn try_from(record: &FitDataRecord) -> Result<Self> {
+                let mut message = Self::default();
+                for field in profile_fields(record, MesgNum::#ident)? {
+                    match field.name() {
+                        #( #arms )*
+                        _ => {}
+                    }
+                }
+                Ok(message)
+            }
+        }
+    }
+}
+
+fn enum_from_value_impl(name: &str) -> TokenStream {
+    let ident = format_ident!("{}", name);
+    quote! {
+        impl FromValue for #ident {
+            fn from_value(value: &Value) -> Option<Self> {
+                enum_value(FieldDataType::#ident, value).map(Self::from)
+            }
+        }
+    }
+}
+
+/// Write the typed messages, a compact profile leaves out the serde implementations
+pub fn write_messages_file(
+    profile: &FitProfile,
+    compact: bool,
+    out: &mut File,
+) -> Result<(), Error> {
+    let comment = format!(
+        "Auto generated typed messages from FIT SDK Release: {}{}",
+        profile.version(),
+        if compact { " (compact)" } else { "" }
+    );
+    let mut enums = BTreeSet::new();
+    let structs: Vec<_> = profile
+        .messages()
+        .iter()
+        .map(|msg| message_struct(profile, msg, compact, &mut enums))
+        .collect();
+    let impls = enums.iter().map(|name| enum_from_value_impl(name));
+    let serde_import = if compact {
+        TokenStream::new()
+    } else {
+        quote! { use serde::{Deserialize, Serialize}; }
+    };
+    let output = quote! {
+        #![doc = #comment]
+        #![allow(missing_docs)]
+        #![allow(clippy::single_match)]
+        use super::field_types::*;
+        use super::{enum_value, field_value, profile_fields, FromValue};
+        use crate::error::{Error, Result};
+        use crate::{FitDataRecord, Value};
+        #serde_import
+        use std::convert::TryFrom;
+
+        #( #structs )*
+
+        #( #impls )*
+    };
+
+    write!(out, "{}", output)
+}
@@ -135,6 +135,7 @@ pub struct MessageDefinition {
     name: String,
     struct_ident: Ident,
     comment: TokenStream,
+    description: Option<String>,
     field_map: BTreeMap<u8, MessageFieldDefinition>,
 }
 
@@ -144,7 +145,12 @@ impl MessageDefinition {
         Self {
             name: name.to_string(),
             struct_ident,
-            comment: doc_comment(comment.or_else(|| Some(format!("{} message definition", name)))),
+            comment: doc_comment(
+                comment
+                    .clone()
+                    .or_else(|| Some(format!("{} message definition", name))),
+            ),
+            description: comment,
             field_map: BTreeMap::new(),
         }
     }
@@ -165,6 +171,11 @@ impl MessageDefinition {
         &self.comment
     }
 
+    /// Comment of the message in the profile, `None` if it doesn't have one
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
     pub fn get_field_by_name(&self, name: &str) -> &MessageFieldDefinition {
         self.field_map()
             .values()
@@ -182,7 +193,8 @@ pub struct MessageFieldDefinition {
     scale: f64,
     offset: f64,
     units: String,
-    accumulate: bool,
+    /// Size in bits of a component accumulated across messages
+    accumulate: Option<u8>,
     parent_field: Option<Box<MessageFieldDefinition>>,
     subfields: Vec<(String, String, MessageFieldDefinition)>,
     components: Vec<(u8, MessageFieldDefinition)>,
@@ -200,7 +212,6 @@ impl MessageFieldDefinition {
         scale: f64,
         offset: f64,
         units: &str,
-        accumulate: bool,
         raw_components: Vec<MessageFieldComponent>,
         comment: Option<String>,
     ) -> Self {
@@ -212,7 +223,9 @@ impl MessageFieldDefinition {
             scale,
             offset,
             units: units.to_string(),
-            accumulate,
+            // the accumulate column lists a flag for each component, only the component values
+            // are accumulated and never the field they are packed into
+            accumulate: None,
             parent_field: None,
             subfields: Vec::new(),
             components: Vec::new(),
@@ -249,7 +262,7 @@ impl MessageFieldDefinition {
         &self.units
     }
 
-    pub const fn accumulate(&self) -> bool {
+    pub const fn accumulate(&self) -> Option<u8> {
         self.accumulate
     }
 
@@ -484,6 +497,7 @@ fn post_process_message(msg: MessageDefinition) -> MessageDefinition {
         name,
         struct_ident,
         comment,
+        description,
         field_map,
     } = msg;
     // we need this lookup to map components back to original field info without
@@ -502,6 +516,11 @@ fn post_process_message(msg: MessageDefinition) -> MessageDefinition {
         }
         let extra_fld_def = field_def.clone();
         for (_, _, sub_fld) in field_def.subfields_mut() {
+            // subfields can have components of their own, e.g. the gear numbers packed into
+            // the gear_change_data subfield of the event message
+            if !sub_fld.raw_components().is_empty() {
+                sub_fld.components = process_components(sub_fld, &name_to_field);
+            }
             sub_fld.set_parent_field(extra_fld_def.clone());
         }
         updated_field_map.insert(def_num, field_def);
@@ -511,6 +530,7 @@ fn post_process_message(msg: MessageDefinition) -> MessageDefinition {
         name,
         struct_ident,
         comment,
+        description,
         field_map: updated_field_map,
     }
 }
@@ -532,7 +552,7 @@ fn process_components(
             scale: comp_info.scale(),
             offset: comp_info.offset(),
             units: comp_info.units().to_owned(),
-            accumulate: comp_info.accumulate(),
+            accumulate: comp_info.accumulate().then_some(comp_info.bits()),
             parent_field: dest_field.parent_field().clone(),
             subfields: dest_field.subfields().to_vec(),
             components: process_components(dest_field, field_lookup),
@@ -568,7 +588,6 @@ fn new_message_field_definition(row: &[DataType]) -> MessageFieldDefinition {
         row[6].get_float().unwrap_or(1.0),
         row[7].get_float().unwrap_or(0.0),
         row[8].get_string().unwrap_or(""),
-        row[10].as_string().map_or(false, |v| v == "1"),
         components,
         comment,
     )
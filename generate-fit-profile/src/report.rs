@@ -0,0 +1,269 @@
+//! Compare two parsed profiles to report what an SDK update adds, renames and removes.
+use crate::parse::{FieldTypeDefintion, FitProfile, MessageDefinition};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// How a single message, field, type or variant changed between the profiles
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum Change {
+    Added { name: String },
+    Removed { name: String },
+    Renamed { from: String, to: String },
+}
+
+impl Change {
+    fn between(old: Option<&str>, new: Option<&str>) -> Option<Self> {
+        match (old, new) {
+            (None, Some(name)) => Some(Self::Added {
+                name: name.to_string(),
+            }),
+            (Some(name), None) => Some(Self::Removed {
+                name: name.to_string(),
+            }),
+            (Some(from), Some(to)) if from != to => Some(Self::Renamed {
+                from: from.to_string(),
+                to: to.to_string(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added { name } => write!(f, "+ {name}"),
+            Self::Removed { name } => write!(f, "- {name}"),
+            Self::Renamed { from, to } => write!(f, "~ {from} -> {to}"),
+        }
+    }
+}
+
+/// Change of a numbered item, i.e. a field definition number or a variant value
+#[derive(Clone, Debug, Serialize)]
+pub struct NumberedChange {
+    pub number: i64,
+    #[serde(flatten)]
+    pub change: Change,
+}
+
+/// Changes of a message and its fields, matched by message and field definition number
+#[derive(Clone, Debug, Serialize)]
+pub struct MessageDiff {
+    pub name: String,
+    pub number: Option<i64>,
+    pub change: Option<Change>,
+    pub fields: Vec<NumberedChange>,
+}
+
+/// Changes of a type and its variants, matched by type name and variant value
+#[derive(Clone, Debug, Serialize)]
+pub struct TypeDiff {
+    pub name: String,
+    pub change: Option<Change>,
+    pub variants: Vec<NumberedChange>,
+}
+
+/// Everything that changed between two profiles, subfields and components aren't compared
+#[derive(Clone, Debug, Serialize)]
+pub struct ProfileDiff {
+    pub old_version: String,
+    pub new_version: String,
+    pub messages: Vec<MessageDiff>,
+    pub types: Vec<TypeDiff>,
+}
+
+impl ProfileDiff {
+    pub fn new(old: &FitProfile, new: &FitProfile) -> Self {
+        Self {
+            old_version: old.version().to_string(),
+            new_version: new.version().to_string(),
+            messages: diff_messages(old, new),
+            types: diff_types(old, new),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty() && self.types.is_empty()
+    }
+}
+
+fn numbered_changes<'a>(
+    old: impl Iterator<Item = (i64, &'a str)>,
+    new: impl Iterator<Item = (i64, &'a str)>,
+) -> Vec<NumberedChange> {
+    let mut names: BTreeMap<i64, (Option<&str>, Option<&str>)> = BTreeMap::new();
+    for (number, name) in old {
+        names.entry(number).or_default().0 = Some(name);
+    }
+    for (number, name) in new {
+        names.entry(number).or_default().1 = Some(name);
+    }
+    names
+        .into_iter()
+        .filter_map(|(number, (old, new))| {
+            Change::between(old, new).map(|change| NumberedChange { number, change })
+        })
+        .collect()
+}
+
+/// Key messages by their global message number from the `mesg_num` type, falling back to the
+/// name for messages missing from it
+fn messages_by_key(profile: &FitProfile) -> BTreeMap<(i64, String), &MessageDefinition> {
+    let numbers: BTreeMap<&str, i64> = profile
+        .field_types()
+        .iter()
+        .find(|t| t.name() == "mesg_num")
+        .map(|t| {
+            t.variant_map()
+                .iter()
+                .map(|(value, variant)| (variant.name(), *value))
+                .collect()
+        })
+        .unwrap_or_default();
+    profile
+        .messages()
+        .iter()
+        .map(|msg| {
+            let key = match numbers.get(msg.name()) {
+                Some(number) => (*number, String::new()),
+                None => (-1, msg.name().to_string()),
+            };
+            (key, msg)
+        })
+        .collect()
+}
+
+fn field_names(msg: Option<&MessageDefinition>) -> impl Iterator<Item = (i64, &str)> {
+    msg.into_iter()
+        .flat_map(|m| m.field_map().values())
+        .map(|f| (i64::from(f.def_number()), f.name()))
+}
+
+fn diff_messages(old: &FitProfile, new: &FitProfile) -> Vec<MessageDiff> {
+    let old_messages = messages_by_key(old);
+    let new_messages = messages_by_key(new);
+    let mut keys: Vec<_> = old_messages.keys().chain(new_messages.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    keys.into_iter()
+        .filter_map(|key| {
+            let old_msg = old_messages.get(key).copied();
+            let new_msg = new_messages.get(key).copied();
+            let change = Change::between(old_msg.map(|m| m.name()), new_msg.map(|m| m.name()));
+            // fields of added or removed messages are implied by the message itself
+            let fields = if old_msg.is_some() && new_msg.is_some() {
+                numbered_changes(field_names(old_msg), field_names(new_msg))
+            } else {
+                Vec::new()
+            };
+            if change.is_none() && fields.is_empty() {
+                return None;
+            }
+            let name = new_msg.or(old_msg).map(|m| m.name().to_string())?;
+            Some(MessageDiff {
+                name,
+                number: (key.0 >= 0).then_some(key.0),
+                change,
+                fields,
+            })
+        })
+        .collect()
+}
+
+fn variant_names(field_type: Option<&FieldTypeDefintion>) -> impl Iterator<Item = (i64, &str)> {
+    field_type
+        .into_iter()
+        .flat_map(|t| t.variant_map().iter())
+        .map(|(value, variant)| (*value, variant.name()))
+}
+
+fn diff_types(old: &FitProfile, new: &FitProfile) -> Vec<TypeDiff> {
+    let old_types: BTreeMap<&str, &FieldTypeDefintion> =
+        old.field_types().iter().map(|t| (t.name(), t)).collect();
+    let new_types: BTreeMap<&str, &FieldTypeDefintion> =
+        new.field_types().iter().map(|t| (t.name(), t)).collect();
+    let mut names: Vec<_> = old_types.keys().chain(new_types.keys()).copied().collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let old_type = old_types.get(name).copied();
+            let new_type = new_types.get(name).copied();
+            let change = Change::between(old_type.map(|t| t.name()), new_type.map(|t| t.name()));
+            // variants of added or removed types are implied by the type itself
+            let variants = if change.is_none() {
+                numbered_changes(variant_names(old_type), variant_names(new_type))
+            } else {
+                Vec::new()
+            };
+            if change.is_none() && variants.is_empty() {
+                return None;
+            }
+            Some(TypeDiff {
+                name: name.to_string(),
+                change,
+                variants,
+            })
+        })
+        .collect()
+}
+
+impl fmt::Display for ProfileDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "FIT profile changes from {} to {}",
+            self.old_version, self.new_version
+        )?;
+        if self.is_empty() {
+            return writeln!(f, "\nNo messages, fields or types changed");
+        }
+        if !self.messages.is_empty() {
+            writeln!(f, "\nMessages")?;
+            for msg in &self.messages {
+                let number = msg.number.map(|n| format!(" ({n})")).unwrap_or_default();
+                match &msg.change {
+                    Some(change) => writeln!(f, "  {change}{number}")?,
+                    None => writeln!(f, "  {}{number}", msg.name)?,
+                }
+                for field in &msg.fields {
+                    writeln!(f, "    {} ({})", field.change, field.number)?;
+                }
+            }
+        }
+        if !self.types.is_empty() {
+            writeln!(f, "\nTypes")?;
+            for typ in &self.types {
+                match &typ.change {
+                    Some(change) => writeln!(f, "  {change}")?,
+                    None => writeln!(f, "  {}", typ.name)?,
+                }
+                for variant in &typ.variants {
+                    writeln!(f, "    {} ({})", variant.change, variant.number)?;
+                }
+            }
+        }
+        let count = |f: fn(&Change) -> bool| {
+            let messages = self
+                .messages
+                .iter()
+                .flat_map(|m| m.change.iter().chain(m.fields.iter().map(|c| &c.change)));
+            let types = self
+                .types
+                .iter()
+                .flat_map(|t| t.change.iter().chain(t.variants.iter().map(|c| &c.change)));
+            messages.chain(types).filter(|c| f(c)).count()
+        };
+        writeln!(
+            f,
+            "\n{} added, {} renamed, {} removed",
+            count(|c| matches!(c, Change::Added { .. })),
+            count(|c| matches!(c, Change::Renamed { .. })),
+            count(|c| matches!(c, Change::Removed { .. })),
+        )
+    }
+}
@@ -0,0 +1,305 @@
+//! Functions to generate the `Unit` enum from the unit strings used by the fit profile.
+use crate::parse::{FitProfile, MessageFieldDefinition};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::{Error, Write};
+
+/// Dimensional categories units are grouped into
+const CATEGORIES: &[(&str, &str)] = &[
+    ("Acceleration", "Acceleration, e.g. m/s^2 or g-force"),
+    ("Angle", "Angles and positions, e.g. degrees or semicircles"),
+    ("AngularVelocity", "Rotation speed, e.g. deg/s"),
+    ("Concentration", "Mass per volume, e.g. g/dL"),
+    ("Count", "Plain counts of events, e.g. steps or strokes"),
+    ("Data", "Amount of data, e.g. bytes"),
+    ("Energy", "Energy, e.g. kcal or J"),
+    ("EnergyRate", "Energy per unit of time, e.g. kcal/day"),
+    ("Frequency", "Events per unit of time, e.g. bpm or rpm"),
+    ("Length", "Lengths and distances, e.g. m or km"),
+    ("MagneticField", "Magnetic field strength, e.g. gauss"),
+    ("Mass", "Mass, e.g. kg"),
+    ("Power", "Power, e.g. watts"),
+    ("Pressure", "Pressure, e.g. Pa or mmHg"),
+    (
+        "PressureRate",
+        "Pressure change per unit of time, e.g. bar/min",
+    ),
+    ("Ratio", "Ratios, e.g. %"),
+    (
+        "Score",
+        "Dimensionless training and terrain scores, e.g. tss",
+    ),
+    ("Speed", "Speed, e.g. m/s"),
+    ("Temperature", "Temperature, e.g. degC"),
+    ("Time", "Durations, e.g. s or ms"),
+    ("Voltage", "Electric potential, e.g. V"),
+    ("Volume", "Volume, e.g. L"),
+    ("VolumeRate", "Volume per unit of time, e.g. L/min"),
+    (
+        "Other",
+        "Units without a physical dimension or with a mixed meaning",
+    ),
+];
+
+/// Known unit strings with their variant name and category. Strings sharing a variant are
+/// spellings of the same unit, the first one listed is used when displaying the unit.
+const UNITS: &[(&str, &str, &str)] = &[
+    ("%", "Percent", "Ratio"),
+    ("percent", "Percent", "Ratio"),
+    ("% or bpm", "PercentOrBpm", "Other"),
+    ("% or watts", "PercentOrWatts", "Other"),
+    ("1/32768 s", "Seconds32768th", "Time"),
+    ("100 * m", "HundredMeters", "Length"),
+    ("2 * cycles (steps)", "DoubleCycles", "Count"),
+    ("breaths/min", "BreathsPerMinute", "Frequency"),
+    ("Breaths/min", "BreathsPerMinute", "Frequency"),
+    ("degC", "Celsius", "Temperature"),
+    ("C", "Celsius", "Temperature"),
+    ("Flow", "Flow", "Score"),
+    ("G", "Gauss", "MagneticField"),
+    ("J", "Joules", "Energy"),
+    ("L", "Liters", "Volume"),
+    ("L/min", "LitersPerMinute", "VolumeRate"),
+    ("OTUs", "OxygenToxicityUnits", "Score"),
+    ("Pa", "Pascals", "Pressure"),
+    ("V", "Volts", "Voltage"),
+    ("bar", "Bar", "Pressure"),
+    ("bar/min", "BarPerMinute", "PressureRate"),
+    ("bpm", "Bpm", "Frequency"),
+    ("bytes", "Bytes", "Data"),
+    ("calories", "Calories", "Energy"),
+    ("counts", "Counts", "Count"),
+    ("cycles", "Cycles", "Count"),
+    ("deg/s", "DegreesPerSecond", "AngularVelocity"),
+    ("degrees", "Degrees", "Angle"),
+    ("depends on sensor", "DependsOnSensor", "Other"),
+    ("g", "GForce", "Acceleration"),
+    ("g/dL", "GramsPerDeciliter", "Concentration"),
+    ("gr", "Grains", "Mass"),
+    ("hr", "Hours", "Time"),
+    ("if", "IntensityFactor", "Score"),
+    ("kGrit", "KiloGrit", "Score"),
+    ("kcal", "Kilocalories", "Energy"),
+    ("kcal/day", "KilocaloriesPerDay", "EnergyRate"),
+    ("kcal / day", "KilocaloriesPerDay", "EnergyRate"),
+    ("kcal / min", "KilocaloriesPerMinute", "EnergyRate"),
+    ("kcal/cycle", "KilocaloriesPerCycle", "Other"),
+    ("kg", "Kilograms", "Mass"),
+    ("kg/m^2", "KilogramsPerSquareMeter", "Other"),
+    ("kg/m^3", "KilogramsPerCubicMeter", "Concentration"),
+    ("km", "Kilometers", "Length"),
+    ("lengths", "Lengths", "Count"),
+    ("m", "Meters", "Length"),
+    ("m/cycle", "MetersPerCycle", "Length"),
+    ("m/s", "MetersPerSecond", "Speed"),
+    ("mps", "MetersPerSecond", "Speed"),
+    ("m/s^2", "MetersPerSecondSquared", "Acceleration"),
+    ("mG", "MilliGForce", "Acceleration"),
+    ("mL/kg/min", "MillilitersPerKilogramPerMinute", "VolumeRate"),
+    ("ms", "Milliseconds", "Time"),
+    ("mS", "Milliseconds", "Time"),
+    ("min", "Minutes", "Time"),
+    ("minutes", "Minutes", "Time"),
+    ("mm", "Millimeters", "Length"),
+    ("mmHg", "MillimetersOfMercury", "Pressure"),
+    ("radians", "Radians", "Angle"),
+    ("radians/second", "RadiansPerSecond", "AngularVelocity"),
+    ("rpm", "Rpm", "Frequency"),
+    ("s", "Seconds", "Time"),
+    ("semicircles", "Semicircles", "Angle"),
+    ("steps", "Steps", "Count"),
+    ("strides", "Strides", "Count"),
+    ("strides/min", "StridesPerMinute", "Frequency"),
+    ("strokes", "Strokes", "Count"),
+    ("strokes/lap", "StrokesPerLap", "Other"),
+    ("strokes/min", "StrokesPerMinute", "Frequency"),
+    ("swim_stroke", "SwimStroke", "Other"),
+    ("tss", "TrainingStressScore", "Score"),
+    ("watts", "Watts", "Power"),
+    ("years", "Years", "Time"),
+];
+
+fn collect_field_units(field: &MessageFieldDefinition, units: &mut BTreeSet<String>) {
+    units.insert(field.units().to_string());
+    for (_, _, sub_field) in field.subfields() {
+        collect_field_units(sub_field, units);
+    }
+    for (_, component) in field.components() {
+        collect_field_units(component, units);
+    }
+}
+
+/// Gather every unit string used by a message field, subfield or component. Compound units of
+/// fields that get expanded into components, e.g. `m/s,m`, are left out.
+fn profile_units(profile: &FitProfile) -> BTreeSet<String> {
+    let mut units = BTreeSet::new();
+    for msg in profile.messages() {
+        for field in msg.field_map().values() {
+            collect_field_units(field, &mut units);
+        }
+    }
+    units.retain(|u| !u.is_empty() && !u.contains(','));
+    units
+}
+
+/// Build a variant name for units missing from the table, e.g. `kJ/h` becomes `KJH`
+fn fallback_variant(units: &str) -> String {
+    units
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut word = w.to_string();
+            if let Some(l) = word.get_mut(0..1) {
+                l.make_ascii_uppercase();
+            }
+            word
+        })
+        .collect()
+}
+
+/// Unit variant with the unit strings it's parsed from, the first one is its display name
+struct UnitVariant {
+    ident: Ident,
+    category: Ident,
+    spellings: Vec<String>,
+}
+
+fn unit_variants(units: &BTreeSet<String>) -> Vec<UnitVariant> {
+    let mut variants: BTreeMap<&str, UnitVariant> = BTreeMap::new();
+    for (spelling, variant, category) in UNITS {
+        if !units.contains(*spelling) {
+            continue;
+        }
+        variants
+            .entry(variant)
+            .or_insert_with(|| UnitVariant {
+                ident: Ident::new(variant, Span::call_site()),
+                category: Ident::new(category, Span::call_site()),
+                spellings: Vec::new(),
+            })
+            .spellings
+            .push((*spelling).to_string());
+    }
+    let mut fallback: Vec<UnitVariant> = units
+        .iter()
+        .filter(|u| !UNITS.iter().any(|(spelling, _, _)| spelling == u))
+        .map(|u| {
+            eprintln!("Unit '{u}' is missing from the units table, categorizing it as Other");
+            UnitVariant {
+                ident: Ident::new(&fallback_variant(u), Span::call_site()),
+                category: Ident::new("Other", Span::call_site()),
+                spellings: vec![u.clone()],
+            }
+        })
+        .collect();
+    let mut variants: Vec<UnitVariant> = variants.into_values().collect();
+    variants.append(&mut fallback);
+    variants
+}
+
+fn unit_category_enum() -> TokenStream {
+    let idents = CATEGORIES
+        .iter()
+        .map(|(name, _)| Ident::new(name, Span::call_site()));
+    let comments = CATEGORIES.iter().map(|(_, comment)| comment);
+    quote! {
+        #[doc = "Dimension measured by a unit"]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum UnitCategory {
+            #( #[doc = #comments] #idents, )*
+        }
+    }
+}
+
+fn unit_enum(variants: &[UnitVariant], compact: bool) -> TokenStream {
+    let idents: Vec<&Ident> = variants.iter().map(|v| &v.ident).collect();
+    let comments = variants
+        .iter()
+        .map(|v| format!("`{}`", v.spellings.join("`, `")));
+    let names: Vec<&String> = variants.iter().map(|v| &v.spellings[0]).collect();
+    let categories = variants.iter().map(|v| &v.category);
+    let spellings = variants.iter().map(|v| &v.spellings);
+    // a compact profile only parses the units of decoded fields, it doesn't write them
+    let (as_str, name_impls) = if compact {
+        (TokenStream::new(), TokenStream::new())
+    } else {
+        let as_str = quote! {
+            #[doc = "Units string as written by the FIT profile"]
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    #( Unit::#idents => #names, )*
+                }
+            }
+        };
+        let name_impls = quote! {
+            impl fmt::Display for Unit {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", self.as_str())
+                }
+            }
+            impl Serialize for Unit {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+                    serializer.serialize_str(self.as_str())
+                }
+            }
+        };
+        (as_str, name_impls)
+    };
+
+    quote! {
+        #[doc = "Units used by the fields of the FIT profile"]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum Unit {
+            #( #[doc = #comments] #idents, )*
+        }
+        impl Unit {
+            #[doc = "Parse the units string of a decoded field, `None` for strings the profile doesn't use"]
+            pub fn from_units(units: &str) -> Option<Self> {
+                match units {
+                    #( #(#spellings)|* => Some(Unit::#idents), )*
+                    _ => None
+                }
+            }
+            #as_str
+            #[doc = "Dimension measured by the unit"]
+            pub fn category(self) -> UnitCategory {
+                match self {
+                    #( Unit::#idents => UnitCategory::#categories, )*
+                }
+            }
+        }
+        #name_impls
+    }
+}
+
+pub fn write_units_file(profile: &FitProfile, compact: bool, out: &mut File) -> Result<(), Error> {
+    let comment = format!(
+        "Auto generated units from FIT SDK Release: {}{}",
+        profile.version(),
+        if compact { " (compact)" } else { "" }
+    );
+    let variants = unit_variants(&profile_units(profile));
+    let category_enum = unit_category_enum();
+    let unit_enum = unit_enum(&variants, compact);
+    let imports = if compact {
+        TokenStream::new()
+    } else {
+        quote! {
+            use serde::{Serialize, ser::Serializer};
+            use std::fmt;
+        }
+    };
+    let output = quote! {
+        #![doc = #comment]
+
+        #imports
+
+        #category_enum
+
+        #unit_enum
+    };
+
+    write!(out, "{}", output)
+}